@@ -0,0 +1,212 @@
+/*
+ * The C API of the Boa JavaScript engine.
+ *
+ * Link with the `boa_capi` library built by `cargo build -p boa_capi --release`: the static
+ * library `libboa_capi.a` or the shared library `libboa_capi.so` (`boa_capi.dll` on Windows,
+ * `libboa_capi.dylib` on macOS).
+ *
+ * Handles are not thread safe: a context and its values must be used from a single thread.
+ */
+
+#ifndef BOA_H
+#define BOA_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The state of the engine. */
+typedef struct BoaContext BoaContext;
+
+/* A JavaScript value. */
+typedef struct BoaValue BoaValue;
+
+/* A UTF-8 string returned by the engine. */
+typedef struct BoaString BoaString;
+
+/* The outcome of the fallible functions. */
+typedef enum BoaStatus {
+    /* The function succeeded. */
+    BOA_STATUS_OK = 0,
+    /* A script threw a value, returned in place of the result. */
+    BOA_STATUS_THROWN = 1,
+    /* An argument is a null pointer or is invalid. */
+    BOA_STATUS_INVALID_ARGUMENT = 2,
+    /* The engine panicked. The context should not be used anymore. */
+    BOA_STATUS_PANIC = 3,
+} BoaStatus;
+
+/* The type of a value. */
+typedef enum BoaType {
+    BOA_TYPE_UNDEFINED = 0,
+    BOA_TYPE_NULL = 1,
+    BOA_TYPE_BOOLEAN = 2,
+    BOA_TYPE_NUMBER = 3,
+    BOA_TYPE_STRING = 4,
+    BOA_TYPE_SYMBOL = 5,
+    BOA_TYPE_BIGINT = 6,
+    BOA_TYPE_OBJECT = 7,
+} BoaType;
+
+/* The kind of a thrown value. */
+typedef enum BoaErrorKind {
+    BOA_ERROR_KIND_ERROR = 0,
+    BOA_ERROR_KIND_AGGREGATE = 1,
+    BOA_ERROR_KIND_EVAL = 2,
+    BOA_ERROR_KIND_RANGE = 3,
+    BOA_ERROR_KIND_REFERENCE = 4,
+    BOA_ERROR_KIND_SUPPRESSED = 5,
+    BOA_ERROR_KIND_SYNTAX = 6,
+    BOA_ERROR_KIND_TYPE = 7,
+    BOA_ERROR_KIND_URI = 8,
+    /* A thrown value that isn't an error object. */
+    BOA_ERROR_KIND_VALUE = 9,
+} BoaErrorKind;
+
+/*
+ * A function implemented in C.
+ *
+ * The context, `this` and the arguments are borrowed for the duration of the call. The callback
+ * returns BOA_STATUS_OK with its return value in `result`, or BOA_STATUS_THROWN with the thrown
+ * value in `result`. The engine takes the ownership of the stored value; NULL means `undefined`.
+ */
+typedef BoaStatus (*BoaCallback)(BoaContext *context, const BoaValue *this_value,
+                                 const BoaValue *const *argv, size_t argc, void *user_data,
+                                 BoaValue **result);
+
+/* Destroys the user data of a callback. */
+typedef void (*BoaFreeCallback)(void *user_data);
+
+/* Contexts */
+
+BoaContext *boa_context_new(void);
+void boa_context_free(BoaContext *context);
+
+/*
+ * Evaluates the UTF-8 script `source` of `len` bytes, storing its completion value, or the value
+ * it throws, in `result`.
+ */
+BoaStatus boa_eval(BoaContext *context, const char *source, size_t len, BoaValue **result);
+
+/*
+ * Defines the global function `name`, calling `callback` with `user_data`. `free_user_data` may
+ * be NULL.
+ */
+BoaStatus boa_register_function(BoaContext *context, const char *name, size_t name_len,
+                                size_t length, BoaCallback callback, void *user_data,
+                                BoaFreeCallback free_user_data);
+
+/*
+ * Creates a function object calling `callback` with `user_data`, or returns NULL if `context` or
+ * `name` is NULL. `free_user_data` may be NULL.
+ */
+BoaValue *boa_function_new(BoaContext *context, const char *name, size_t name_len, size_t length,
+                           BoaCallback callback, void *user_data, BoaFreeCallback free_user_data);
+
+/* Values */
+
+BoaValue *boa_value_undefined(void);
+BoaValue *boa_value_null(void);
+BoaValue *boa_value_from_bool(bool boolean);
+BoaValue *boa_value_from_number(double number);
+BoaValue *boa_value_from_string(const char *text, size_t len);
+BoaValue *boa_value_clone(const BoaValue *value);
+void boa_value_free(BoaValue *value);
+
+BoaType boa_value_type(const BoaValue *value);
+bool boa_value_as_bool(const BoaValue *value, bool *out);
+bool boa_value_as_number(const BoaValue *value, double *out);
+
+/*
+ * Converts a value to a string like `String(value)`. If the conversion throws, `result` is set to
+ * NULL and the thrown value is stored in `thrown`, which may be NULL.
+ */
+BoaStatus boa_value_to_string(BoaContext *context, const BoaValue *value, BoaString **result,
+                              BoaValue **thrown);
+
+/* Objects */
+
+BoaValue *boa_object_new(BoaContext *context);
+/* Stores the value of the property, or the thrown value, in `result`. */
+BoaStatus boa_object_get(BoaContext *context, const BoaValue *object, const char *key,
+                         size_t key_len, BoaValue **result);
+/* Stores the thrown value in `thrown`, which may be NULL, if setting the property throws. */
+BoaStatus boa_object_set(BoaContext *context, const BoaValue *object, const char *key,
+                         size_t key_len, const BoaValue *value, BoaValue **thrown);
+/* Calls `function`. `this_value` may be NULL for `undefined`. */
+BoaStatus boa_call(BoaContext *context, const BoaValue *function, const BoaValue *this_value,
+                   const BoaValue *const *argv, size_t argc, BoaValue **result);
+
+/* Strings */
+
+const char *boa_string_data(const BoaString *string);
+size_t boa_string_len(const BoaString *string);
+void boa_string_free(BoaString *string);
+
+/* Errors */
+
+BoaErrorKind boa_error_kind(BoaContext *context, const BoaValue *error);
+BoaString *boa_error_message(BoaContext *context, const BoaValue *error);
+/* Returns NULL if the value has no stack trace. */
+BoaString *boa_error_stack(BoaContext *context, const BoaValue *error);
+
+/* Native modules */
+
+#define BOA_API_VERSION 1
+
+/* The functions of the API, by pointer, given to the native modules. */
+typedef struct BoaApi {
+    uint32_t version;
+    BoaStatus (*eval)(BoaContext *, const char *, size_t, BoaValue **);
+    BoaValue *(*value_undefined)(void);
+    BoaValue *(*value_null)(void);
+    BoaValue *(*value_from_bool)(bool);
+    BoaValue *(*value_from_number)(double);
+    BoaValue *(*value_from_string)(const char *, size_t);
+    BoaValue *(*value_clone)(const BoaValue *);
+    void (*value_free)(BoaValue *);
+    BoaType (*value_type)(const BoaValue *);
+    bool (*value_as_bool)(const BoaValue *, bool *);
+    bool (*value_as_number)(const BoaValue *, double *);
+    BoaStatus (*value_to_string)(BoaContext *, const BoaValue *, BoaString **, BoaValue **);
+    const char *(*string_data)(const BoaString *);
+    size_t (*string_len)(const BoaString *);
+    void (*string_free)(BoaString *);
+    BoaValue *(*object_new)(BoaContext *);
+    BoaStatus (*object_get)(BoaContext *, const BoaValue *, const char *, size_t, BoaValue **);
+    BoaStatus (*object_set)(BoaContext *, const BoaValue *, const char *, size_t,
+                            const BoaValue *, BoaValue **);
+    BoaStatus (*call)(BoaContext *, const BoaValue *, const BoaValue *, const BoaValue *const *,
+                      size_t, BoaValue **);
+    BoaValue *(*function_new)(BoaContext *, const char *, size_t, size_t, BoaCallback, void *,
+                              BoaFreeCallback);
+    BoaErrorKind (*error_kind)(BoaContext *, const BoaValue *);
+    BoaString *(*error_message)(BoaContext *, const BoaValue *);
+    BoaString *(*error_stack)(BoaContext *, const BoaValue *);
+} BoaApi;
+
+const BoaApi *boa_api(void);
+
+/*
+ * Defines the global `require` function, loading the native module `name` for the specifier
+ * "boa:name" from the library `libname.so` (`name.dll`, `libname.dylib`) of `directory`.
+ *
+ * A native module exports the two functions below. `boa_module_api_version` returns
+ * BOA_API_VERSION, and `boa_module_init` stores the exports of the module in `exports`, or the
+ * thrown value when it returns BOA_STATUS_THROWN.
+ *
+ *     uint32_t boa_module_api_version(void);
+ *     BoaStatus boa_module_init(const BoaApi *api, BoaContext *context, BoaValue **exports);
+ */
+BoaStatus boa_register_native_modules(BoaContext *context, const char *directory,
+                                      size_t directory_len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* BOA_H */
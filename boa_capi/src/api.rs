@@ -0,0 +1,123 @@
+//! The table of the functions of the API, given to the native modules.
+
+use crate::{
+    boa_call, boa_error_kind, boa_error_message, boa_error_stack, boa_eval, boa_function_new,
+    boa_object_get, boa_object_new, boa_object_set, boa_string_data, boa_string_free,
+    boa_string_len, boa_value_as_bool, boa_value_as_number, boa_value_clone, boa_value_free,
+    boa_value_from_bool, boa_value_from_number, boa_value_from_string, boa_value_null,
+    boa_value_to_string, boa_value_type, boa_value_undefined, BoaCallback, BoaContext,
+    BoaErrorKind, BoaFreeCallback, BoaStatus, BoaString, BoaType, BoaValue,
+};
+use std::{ffi::c_void, os::raw::c_char};
+
+/// The version of the [`BoaApi`] table.
+///
+/// It changes whenever the table or the behavior of its functions change in an incompatible way.
+pub const BOA_API_VERSION: u32 = 1;
+
+/// The functions of the API, by pointer.
+///
+/// Native modules receive this table instead of linking to the engine, so a module only depends
+/// on the version of the table, and not on the way the host application was built. Each field
+/// points to the function of the same name prefixed with `boa_`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct BoaApi {
+    /// The version of the table, [`BOA_API_VERSION`].
+    pub version: u32,
+    pub eval: unsafe extern "C" fn(
+        *mut BoaContext,
+        *const c_char,
+        usize,
+        *mut *mut BoaValue,
+    ) -> BoaStatus,
+    pub value_undefined: extern "C" fn() -> *mut BoaValue,
+    pub value_null: extern "C" fn() -> *mut BoaValue,
+    pub value_from_bool: extern "C" fn(bool) -> *mut BoaValue,
+    pub value_from_number: extern "C" fn(f64) -> *mut BoaValue,
+    pub value_from_string: unsafe extern "C" fn(*const c_char, usize) -> *mut BoaValue,
+    pub value_clone: unsafe extern "C" fn(*const BoaValue) -> *mut BoaValue,
+    pub value_free: unsafe extern "C" fn(*mut BoaValue),
+    pub value_type: unsafe extern "C" fn(*const BoaValue) -> BoaType,
+    pub value_as_bool: unsafe extern "C" fn(*const BoaValue, *mut bool) -> bool,
+    pub value_as_number: unsafe extern "C" fn(*const BoaValue, *mut f64) -> bool,
+    pub value_to_string: unsafe extern "C" fn(
+        *mut BoaContext,
+        *const BoaValue,
+        *mut *mut BoaString,
+        *mut *mut BoaValue,
+    ) -> BoaStatus,
+    pub string_data: unsafe extern "C" fn(*const BoaString) -> *const c_char,
+    pub string_len: unsafe extern "C" fn(*const BoaString) -> usize,
+    pub string_free: unsafe extern "C" fn(*mut BoaString),
+    pub object_new: unsafe extern "C" fn(*mut BoaContext) -> *mut BoaValue,
+    pub object_get: unsafe extern "C" fn(
+        *mut BoaContext,
+        *const BoaValue,
+        *const c_char,
+        usize,
+        *mut *mut BoaValue,
+    ) -> BoaStatus,
+    pub object_set: unsafe extern "C" fn(
+        *mut BoaContext,
+        *const BoaValue,
+        *const c_char,
+        usize,
+        *const BoaValue,
+        *mut *mut BoaValue,
+    ) -> BoaStatus,
+    pub call: unsafe extern "C" fn(
+        *mut BoaContext,
+        *const BoaValue,
+        *const BoaValue,
+        *const *const BoaValue,
+        usize,
+        *mut *mut BoaValue,
+    ) -> BoaStatus,
+    pub function_new: unsafe extern "C" fn(
+        *mut BoaContext,
+        *const c_char,
+        usize,
+        usize,
+        BoaCallback,
+        *mut c_void,
+        Option<BoaFreeCallback>,
+    ) -> *mut BoaValue,
+    pub error_kind: unsafe extern "C" fn(*mut BoaContext, *const BoaValue) -> BoaErrorKind,
+    pub error_message: unsafe extern "C" fn(*mut BoaContext, *const BoaValue) -> *mut BoaString,
+    pub error_stack: unsafe extern "C" fn(*mut BoaContext, *const BoaValue) -> *mut BoaString,
+}
+
+/// The table of the functions of this build of the API.
+pub static BOA_API: BoaApi = BoaApi {
+    version: BOA_API_VERSION,
+    eval: boa_eval,
+    value_undefined: boa_value_undefined,
+    value_null: boa_value_null,
+    value_from_bool: boa_value_from_bool,
+    value_from_number: boa_value_from_number,
+    value_from_string: boa_value_from_string,
+    value_clone: boa_value_clone,
+    value_free: boa_value_free,
+    value_type: boa_value_type,
+    value_as_bool: boa_value_as_bool,
+    value_as_number: boa_value_as_number,
+    value_to_string: boa_value_to_string,
+    string_data: boa_string_data,
+    string_len: boa_string_len,
+    string_free: boa_string_free,
+    object_new: boa_object_new,
+    object_get: boa_object_get,
+    object_set: boa_object_set,
+    call: boa_call,
+    function_new: boa_function_new,
+    error_kind: boa_error_kind,
+    error_message: boa_error_message,
+    error_stack: boa_error_stack,
+};
+
+/// Gets the table of the functions of the API.
+#[no_mangle]
+pub extern "C" fn boa_api() -> *const BoaApi {
+    &BOA_API
+}
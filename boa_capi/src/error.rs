@@ -0,0 +1,100 @@
+//! Inspection of the values thrown by scripts.
+
+use crate::{string, BoaContext, BoaString, BoaValue};
+use boa_engine::{error::JsErrorKind, JsError};
+use std::ptr;
+
+/// The kind of a thrown value, given by the builtin error constructor that created it.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoaErrorKind {
+    /// An `Error`, or an error object whose kind isn't recognized.
+    Error = 0,
+    /// An `AggregateError`.
+    Aggregate = 1,
+    /// An `EvalError`.
+    Eval = 2,
+    /// A `RangeError`.
+    Range = 3,
+    /// A `ReferenceError`.
+    Reference = 4,
+    /// A `SuppressedError`.
+    Suppressed = 5,
+    /// A `SyntaxError`.
+    Syntax = 6,
+    /// A `TypeError`.
+    Type = 7,
+    /// A `URIError`.
+    Uri = 8,
+    /// A thrown value that isn't an error object.
+    Value = 9,
+}
+
+impl From<JsErrorKind> for BoaErrorKind {
+    fn from(kind: JsErrorKind) -> Self {
+        match kind {
+            JsErrorKind::Error => Self::Error,
+            JsErrorKind::Aggregate => Self::Aggregate,
+            JsErrorKind::Eval => Self::Eval,
+            JsErrorKind::Range => Self::Range,
+            JsErrorKind::Reference => Self::Reference,
+            JsErrorKind::Suppressed => Self::Suppressed,
+            JsErrorKind::Syntax => Self::Syntax,
+            JsErrorKind::Type => Self::Type,
+            JsErrorKind::Uri => Self::Uri,
+            JsErrorKind::Value => Self::Value,
+        }
+    }
+}
+
+/// Gets the kind of a thrown value.
+///
+/// # Safety
+///
+/// `context` and `error` must be valid.
+#[no_mangle]
+pub unsafe extern "C" fn boa_error_kind(
+    context: *mut BoaContext,
+    error: *const BoaValue,
+) -> BoaErrorKind {
+    JsError::from_value((*error).clone())
+        .capture(&mut *context)
+        .kind()
+        .into()
+}
+
+/// Gets the description of a thrown value, like `TypeError: x is not a function`.
+///
+/// The string must be destroyed with [`boa_string_free`](crate::boa_string_free).
+///
+/// # Safety
+///
+/// `context` and `error` must be valid.
+#[no_mangle]
+pub unsafe extern "C" fn boa_error_message(
+    context: *mut BoaContext,
+    error: *const BoaValue,
+) -> *mut BoaString {
+    let error = JsError::from_value((*error).clone()).capture(&mut *context);
+    string::into_raw(error.to_string())
+}
+
+/// Gets the stack trace of a thrown error object, the description followed by a line per call
+/// frame.
+///
+/// Returns null if the value has no stack trace, like values that aren't error objects. The
+/// string must be destroyed with [`boa_string_free`](crate::boa_string_free).
+///
+/// # Safety
+///
+/// `context` and `error` must be valid.
+#[no_mangle]
+pub unsafe extern "C" fn boa_error_stack(
+    context: *mut BoaContext,
+    error: *const BoaValue,
+) -> *mut BoaString {
+    let error = JsError::from_value((*error).clone()).capture(&mut *context);
+    error
+        .stack()
+        .map_or(ptr::null_mut(), |stack| string::into_raw(stack.to_owned()))
+}
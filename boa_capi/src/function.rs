@@ -0,0 +1,168 @@
+//! Native functions implemented by C callbacks.
+
+use crate::{value, BoaContext, BoaStatus, BoaValue};
+use boa_engine::{object::FunctionBuilder, property::Attribute, Context, JsValue};
+use boa_gc::{unsafe_empty_trace, Finalize, Trace};
+use std::{ffi::c_void, os::raw::c_char, ptr, slice};
+
+/// A function implemented in C.
+///
+/// It receives the context, the `this` value, the `argc` arguments in `argv` and the user data
+/// given when registering it. The context, `this` and the arguments are borrowed for the duration
+/// of the call, and must not be destroyed.
+///
+/// It returns [`BoaStatus::Ok`] and stores its return value in `result`, or returns
+/// [`BoaStatus::Thrown`] and stores the thrown value in `result`. The engine takes the ownership
+/// of the stored value; storing null stands for `undefined`.
+pub type BoaCallback = unsafe extern "C" fn(
+    context: *mut BoaContext,
+    this: *const BoaValue,
+    argv: *const *const BoaValue,
+    argc: usize,
+    user_data: *mut c_void,
+    result: *mut *mut BoaValue,
+) -> BoaStatus;
+
+/// Destroys the user data of a callback, when the function object is collected.
+pub type BoaFreeCallback = unsafe extern "C" fn(user_data: *mut c_void);
+
+/// Creates a function object calling `callback` with `user_data`.
+///
+/// `name` is the UTF-8 text of `name_len` bytes, and `length` is the number of arguments the
+/// function expects. `free_user_data`, if not null, is called with `user_data` when the function
+/// object is collected or the context is destroyed. Returns null if `context` or `name` is null.
+///
+/// # Safety
+///
+/// `context` must be valid, and `name` must point to `name_len` readable bytes. `callback` must
+/// be safe to call with `user_data` as long as the context exists.
+#[no_mangle]
+pub unsafe extern "C" fn boa_function_new(
+    context: *mut BoaContext,
+    name: *const c_char,
+    name_len: usize,
+    length: usize,
+    callback: BoaCallback,
+    user_data: *mut c_void,
+    free_user_data: Option<BoaFreeCallback>,
+) -> *mut BoaValue {
+    if context.is_null() || name.is_null() {
+        return ptr::null_mut();
+    }
+    let name = String::from_utf8_lossy(slice::from_raw_parts(name.cast::<u8>(), name_len));
+    let function = FunctionBuilder::closure_with_captures(
+        &mut *context,
+        |this, args, callback: &mut Callback, context| callback.call(this, args, context),
+        Callback {
+            callback,
+            user_data,
+            free_user_data,
+        },
+    )
+    .name(name.as_ref())
+    .length(length)
+    .build();
+    value::into_raw(function.into())
+}
+
+/// Defines the global `name`, a function calling `callback` with `user_data`, created like
+/// [`boa_function_new`] does.
+///
+/// # Safety
+///
+/// The arguments must be valid for [`boa_function_new`].
+#[no_mangle]
+pub unsafe extern "C" fn boa_register_function(
+    context: *mut BoaContext,
+    name: *const c_char,
+    name_len: usize,
+    length: usize,
+    callback: BoaCallback,
+    user_data: *mut c_void,
+    free_user_data: Option<BoaFreeCallback>,
+) -> BoaStatus {
+    let function = boa_function_new(
+        context,
+        name,
+        name_len,
+        length,
+        callback,
+        user_data,
+        free_user_data,
+    );
+    if function.is_null() {
+        return BoaStatus::InvalidArgument;
+    }
+    let function = *Box::from_raw(function);
+    let name = String::from_utf8_lossy(slice::from_raw_parts(name.cast::<u8>(), name_len));
+    (*context).register_global_property(
+        name.as_ref(),
+        function,
+        Attribute::WRITABLE | Attribute::CONFIGURABLE,
+    );
+    BoaStatus::Ok
+}
+
+/// The captures of the function objects created by [`boa_register_function`].
+#[derive(Debug, Finalize)]
+struct Callback {
+    callback: BoaCallback,
+    user_data: *mut c_void,
+    free_user_data: Option<BoaFreeCallback>,
+}
+
+impl Callback {
+    fn call(
+        &self,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> Result<JsValue, JsValue> {
+        let argv = args
+            .iter()
+            .map(|arg| arg as *const JsValue)
+            .collect::<Vec<_>>();
+        let mut result = ptr::null_mut();
+
+        // SAFETY: The registration of the callback guarantees that it can be called with its user
+        // data, and the arguments outlive the call.
+        let status = unsafe {
+            (self.callback)(
+                context,
+                this,
+                argv.as_ptr(),
+                argv.len(),
+                self.user_data,
+                &mut result,
+            )
+        };
+
+        let value = if result.is_null() {
+            JsValue::undefined()
+        } else {
+            // SAFETY: The callback gives the ownership of the value it stores.
+            *unsafe { Box::from_raw(result) }
+        };
+        match status {
+            BoaStatus::Ok => Ok(value),
+            BoaStatus::Thrown => Err(value),
+            BoaStatus::InvalidArgument | BoaStatus::Panic => {
+                context.throw_error(format!("the native callback failed with {status:?}"))
+            }
+        }
+    }
+}
+
+impl Drop for Callback {
+    fn drop(&mut self) {
+        if let Some(free_user_data) = self.free_user_data {
+            // SAFETY: The user data is not used anymore once the function object is collected.
+            unsafe { free_user_data(self.user_data) }
+        }
+    }
+}
+
+// SAFETY: The callback and its user data hold no value of the engine.
+unsafe impl Trace for Callback {
+    unsafe_empty_trace!();
+}
@@ -0,0 +1,208 @@
+//! The C API of the Boa JavaScript engine, for embedding it in applications written in C, C++
+//! or any language calling C functions.
+//!
+//! The functions are declared in `include/boa.h`. The engine is used through opaque handles:
+//!
+//!  - a `BoaContext` holds the state of the engine, and is created by `boa_context_new` and
+//!    destroyed by `boa_context_free`;
+//!  - a `BoaValue` holds a JavaScript value, and is destroyed by `boa_value_free`;
+//!  - a `BoaString` holds a UTF-8 string returned by the engine, and is destroyed by
+//!    `boa_string_free`.
+//!
+//! Fallible functions return a [`BoaStatus`]. When a script throws, the thrown value is returned
+//! in place of the result, and can be inspected with the `boa_error_*` functions.
+//!
+//! ```c
+//! BoaContext *context = boa_context_new();
+//! BoaValue *result = NULL;
+//! const char *source = "1 + 2";
+//! if (boa_eval(context, source, strlen(source), &result) == BOA_STATUS_OK) {
+//!     double number;
+//!     boa_value_as_number(result, &number); /* 3 */
+//! }
+//! boa_value_free(result);
+//! boa_context_free(context);
+//! ```
+//!
+//! Handles are not thread safe: a context and its values must be used from a single thread.
+
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/boa-dev/boa/main/assets/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/boa-dev/boa/main/assets/logo.svg"
+)]
+#![cfg_attr(not(test), forbid(clippy::unwrap_used))]
+#![warn(
+    clippy::perf,
+    clippy::single_match_else,
+    clippy::dbg_macro,
+    clippy::doc_markdown,
+    clippy::wildcard_imports,
+    clippy::struct_excessive_bools,
+    clippy::doc_markdown,
+    clippy::semicolon_if_nothing_returned,
+    clippy::pedantic
+)]
+#![deny(
+    clippy::all,
+    clippy::cast_lossless,
+    clippy::redundant_closure_for_method_calls,
+    clippy::use_self,
+    clippy::unnested_or_patterns,
+    clippy::trivially_copy_pass_by_ref,
+    clippy::needless_pass_by_value,
+    clippy::match_wildcard_for_single_variants,
+    clippy::map_unwrap_or,
+    unused_qualifications,
+    unused_import_braces,
+    unused_lifetimes,
+    unreachable_pub,
+    trivial_numeric_casts,
+    // rustdoc,
+    missing_debug_implementations,
+    missing_copy_implementations,
+    deprecated_in_future,
+    meta_variable_misuse,
+    non_ascii_idents,
+    rust_2018_compatibility,
+    rust_2018_idioms,
+    future_incompatible,
+    nonstandard_style,
+)]
+#![allow(
+    clippy::module_name_repetitions,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss,
+    clippy::cast_possible_wrap,
+    clippy::cast_ptr_alignment,
+    clippy::missing_panics_doc,
+    clippy::too_many_lines,
+    clippy::unreadable_literal,
+    clippy::missing_inline_in_public_items,
+    clippy::cognitive_complexity,
+    clippy::must_use_candidate,
+    clippy::missing_errors_doc,
+    clippy::as_conversions,
+    clippy::let_unit_value,
+    rustdoc::missing_doc_code_examples
+)]
+
+mod api;
+mod error;
+mod function;
+mod module;
+mod object;
+mod string;
+mod value;
+
+#[cfg(test)]
+mod tests;
+
+pub use api::*;
+pub use error::*;
+pub use function::*;
+pub use module::*;
+pub use object::*;
+pub use string::*;
+pub use value::*;
+
+use boa_engine::{Context, JsValue};
+use std::{
+    os::raw::c_char,
+    panic::{catch_unwind, AssertUnwindSafe},
+    ptr, slice,
+};
+
+/// The state of the engine, a [`Context`] behind an opaque pointer.
+pub type BoaContext = Context;
+
+/// The outcome of the fallible functions of the API.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoaStatus {
+    /// The function succeeded.
+    Ok = 0,
+    /// A script threw a value, returned in place of the result.
+    Thrown = 1,
+    /// An argument is a null pointer or is invalid.
+    InvalidArgument = 2,
+    /// The engine panicked. The context should not be used anymore.
+    Panic = 3,
+}
+
+/// Creates a new context.
+///
+/// The context must be destroyed with [`boa_context_free`].
+#[no_mangle]
+pub extern "C" fn boa_context_new() -> *mut BoaContext {
+    match catch_unwind(Context::default) {
+        Ok(context) => Box::into_raw(Box::new(context)),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Destroys a context. Does nothing if `context` is null.
+///
+/// # Safety
+///
+/// `context` must be null or a context returned by [`boa_context_new`], not used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn boa_context_free(context: *mut BoaContext) {
+    if !context.is_null() {
+        drop(Box::from_raw(context));
+    }
+}
+
+/// Evaluates the UTF-8 script `source` of `len` bytes.
+///
+/// Stores the completion value of the script in `result`, or the thrown value if the script
+/// throws, including the `SyntaxError` of a script that cannot be parsed. The value must be
+/// destroyed with [`boa_value_free`].
+///
+/// # Safety
+///
+/// `context` must be a valid context, `source` must point to `len` readable bytes, and `result`
+/// must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn boa_eval(
+    context: *mut BoaContext,
+    source: *const c_char,
+    len: usize,
+    result: *mut *mut BoaValue,
+) -> BoaStatus {
+    if context.is_null() || (source.is_null() && len > 0) || result.is_null() {
+        return BoaStatus::InvalidArgument;
+    }
+    let context = &mut *context;
+    let source = if len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(source.cast::<u8>(), len)
+    };
+    guard(result, || context.eval(source))
+}
+
+/// Runs `f`, storing its value in `result` and catching the panics.
+///
+/// # Safety
+///
+/// `result` must be a valid pointer to write the value to.
+pub(crate) unsafe fn guard<F>(result: *mut *mut BoaValue, f: F) -> BoaStatus
+where
+    F: FnOnce() -> Result<JsValue, JsValue>,
+{
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(completion)) => {
+            *result = value::into_raw(completion);
+            BoaStatus::Ok
+        }
+        Ok(Err(thrown)) => {
+            *result = value::into_raw(thrown);
+            BoaStatus::Thrown
+        }
+        Err(_) => {
+            *result = ptr::null_mut();
+            BoaStatus::Panic
+        }
+    }
+}
@@ -0,0 +1,244 @@
+//! Native modules, shared libraries extending the engine through the [`BoaApi`] table.
+//!
+//! A native module is a shared library exporting two functions:
+//!
+//! ```c
+//! uint32_t boa_module_api_version(void);
+//! BoaStatus boa_module_init(const BoaApi *api, BoaContext *context, BoaValue **exports);
+//! ```
+//!
+//! `boa_module_api_version` returns the version of the table the module was written for,
+//! `BOA_API_VERSION`. `boa_module_init` is called once per context with the table of the host,
+//! and stores the exports of the module in `exports`, usually an object holding functions made
+//! with `api->function_new`. It returns `BOA_STATUS_THROWN` with the thrown value in `exports`
+//! to fail.
+//!
+//! Scripts load the module named `name` with `require("boa:name")` once the host enabled them with
+//! [`NativeModules::register_require`] or [`boa_register_native_modules`]. The library is looked
+//! up in the directory of the native modules, with the naming convention of the platform:
+//! `libname.so` on Linux, `libname.dylib` on macOS and `name.dll` on Windows.
+//!
+//! Libraries are never unloaded, since the functions they create can be called until the
+//! context is destroyed.
+
+use crate::{BoaApi, BoaContext, BoaStatus, BoaValue, BOA_API, BOA_API_VERSION};
+use boa_engine::{object::FunctionBuilder, property::Attribute, Context, JsResult, JsValue};
+use boa_gc::{custom_trace, Finalize, Trace};
+use libloading::Library;
+use std::{
+    collections::HashMap,
+    env::consts::{DLL_PREFIX, DLL_SUFFIX},
+    mem,
+    os::raw::{c_char, c_int},
+    path::{Path, PathBuf},
+    ptr, slice, str,
+};
+
+/// The prefix of the specifiers of native modules.
+pub const NATIVE_MODULE_PREFIX: &str = "boa:";
+
+/// The type of the `boa_module_api_version` function of a native module.
+pub type BoaModuleApiVersion = unsafe extern "C" fn() -> u32;
+
+/// The type of the `boa_module_init` function of a native module.
+pub type BoaModuleInit = unsafe extern "C" fn(
+    api: *const BoaApi,
+    context: *mut BoaContext,
+    exports: *mut *mut BoaValue,
+) -> BoaStatus;
+
+/// The `boa_module_init` function as called by the loader, which doesn't trust the returned
+/// status to be a valid [`BoaStatus`].
+type RawModuleInit = unsafe extern "C" fn(
+    api: *const BoaApi,
+    context: *mut BoaContext,
+    exports: *mut *mut BoaValue,
+) -> c_int;
+
+/// The native modules of a context, loaded from a directory and cached by name.
+#[derive(Debug)]
+pub struct NativeModules {
+    directory: PathBuf,
+    modules: HashMap<String, JsValue>,
+}
+
+impl NativeModules {
+    /// Creates the loader of the native modules found in `directory`.
+    #[inline]
+    pub fn new<P>(directory: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self {
+            directory: directory.into(),
+            modules: HashMap::new(),
+        }
+    }
+
+    /// Returns the directory of the native modules.
+    #[inline]
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Returns the path of the library of the module `name`.
+    pub fn library_path(&self, name: &str) -> PathBuf {
+        self.directory
+            .join(format!("{DLL_PREFIX}{name}{DLL_SUFFIX}"))
+    }
+
+    /// Loads the module `name`, returning its exports.
+    ///
+    /// The module is initialized on its first load, and later loads return the same exports.
+    /// Throws a `TypeError` if the name is not made of ASCII letters, digits, `_` and `-`, or if
+    /// the library can't be loaded, and the value thrown by the initialization of the module.
+    pub fn load(&mut self, name: &str, context: &mut Context) -> JsResult<JsValue> {
+        if let Some(exports) = self.modules.get(name) {
+            return Ok(exports.clone());
+        }
+        if name.is_empty()
+            || !name
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-')
+        {
+            return context.throw_type_error(format!("invalid native module name '{name}'"));
+        }
+
+        let path = self.library_path(name);
+        let init = match open_library(&path) {
+            Ok(init) => init,
+            Err(message) => {
+                return context.throw_type_error(format!(
+                    "cannot load native module '{name}' from {}: {message}",
+                    path.display()
+                ))
+            }
+        };
+        // SAFETY: The library declared the version of the table it expects, and is trusted to
+        // follow the ABI of native modules.
+        let exports = unsafe { initialize(init, context) }?;
+        self.modules.insert(name.to_owned(), exports.clone());
+        Ok(exports)
+    }
+
+    /// Defines the global `require` function, loading the native module `name` for the specifier
+    /// `"boa:name"`.
+    pub fn register_require(self, context: &mut Context) {
+        let require = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args, modules: &mut Self, context| {
+                let specifier = args
+                    .get(0)
+                    .cloned()
+                    .unwrap_or_default()
+                    .to_string(context)?;
+                match specifier.as_str().strip_prefix(NATIVE_MODULE_PREFIX) {
+                    Some(name) => modules.load(name, context),
+                    None => context.throw_type_error(format!(
+                        "cannot require '{specifier}': only the native modules \
+                         '{NATIVE_MODULE_PREFIX}<name>' can be required"
+                    )),
+                }
+            },
+            self,
+        )
+        .name("require")
+        .length(1)
+        .build();
+        context.register_global_property(
+            "require",
+            require,
+            Attribute::WRITABLE | Attribute::CONFIGURABLE,
+        );
+    }
+}
+
+impl Finalize for NativeModules {}
+
+// SAFETY: The exports of the modules are the only traced values.
+unsafe impl Trace for NativeModules {
+    custom_trace!(this, {
+        for exports in this.modules.values() {
+            mark(exports);
+        }
+    });
+}
+
+/// Opens the library at `path`, checks its version and returns its initialization function.
+///
+/// The library is never unloaded.
+fn open_library(path: &Path) -> Result<RawModuleInit, String> {
+    // SAFETY: Loading a library runs its initialization routines, which native modules are
+    // trusted to be safe.
+    let library = unsafe { Library::new(path) }.map_err(|e| e.to_string())?;
+
+    // SAFETY: The symbols have the types required by the ABI of native modules.
+    let init = unsafe {
+        let version = library
+            .get::<BoaModuleApiVersion>(b"boa_module_api_version\0")
+            .map_err(|e| e.to_string())?;
+        let version = version();
+        if version != BOA_API_VERSION {
+            return Err(format!(
+                "the module needs version {version} of the API, but the host provides version \
+                 {BOA_API_VERSION}"
+            ));
+        }
+        *library
+            .get::<RawModuleInit>(b"boa_module_init\0")
+            .map_err(|e| e.to_string())?
+    };
+
+    // The functions created by the module can be called until the context is destroyed.
+    mem::forget(library);
+    Ok(init)
+}
+
+/// Initializes a native module, returning its exports.
+///
+/// # Safety
+///
+/// `init` must follow the ABI of the `boa_module_init` function of native modules.
+pub(crate) unsafe fn initialize(init: RawModuleInit, context: &mut Context) -> JsResult<JsValue> {
+    let mut exports = ptr::null_mut();
+    let status = init(&BOA_API, context, &mut exports);
+
+    let value = if exports.is_null() {
+        JsValue::undefined()
+    } else {
+        *Box::from_raw(exports)
+    };
+    match status {
+        s if s == BoaStatus::Ok as c_int => Ok(value),
+        s if s == BoaStatus::Thrown as c_int => Err(value),
+        s => context.throw_error(format!(
+            "the initialization of the native module failed with status {s}"
+        )),
+    }
+}
+
+/// Defines the global `require` function of the context, loading the native modules found in
+/// the directory `directory`, the UTF-8 path of `directory_len` bytes.
+///
+/// See the [module documentation](self) for the ABI of native modules.
+///
+/// # Safety
+///
+/// `context` must be valid, and `directory` must point to `directory_len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn boa_register_native_modules(
+    context: *mut BoaContext,
+    directory: *const c_char,
+    directory_len: usize,
+) -> BoaStatus {
+    if context.is_null() || directory.is_null() {
+        return BoaStatus::InvalidArgument;
+    }
+    let directory = slice::from_raw_parts(directory.cast::<u8>(), directory_len);
+    let directory = match str::from_utf8(directory) {
+        Ok(directory) => directory,
+        Err(_) => return BoaStatus::InvalidArgument,
+    };
+    NativeModules::new(directory).register_require(&mut *context);
+    BoaStatus::Ok
+}
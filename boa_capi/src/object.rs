@@ -0,0 +1,132 @@
+//! Creation and manipulation of objects.
+
+use crate::{guard, value, BoaContext, BoaStatus, BoaValue};
+use boa_engine::JsValue;
+use std::{os::raw::c_char, ptr, slice};
+
+/// Creates an empty ordinary object.
+///
+/// Returns null if `context` is null.
+///
+/// # Safety
+///
+/// `context` must be null or a valid context.
+#[no_mangle]
+pub unsafe extern "C" fn boa_object_new(context: *mut BoaContext) -> *mut BoaValue {
+    match context.as_mut() {
+        Some(context) => value::into_raw(context.construct_object().into()),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Gets the property `key` of `object`, `key` being the UTF-8 text of `key_len` bytes.
+///
+/// Stores the value of the property in `result`, or the thrown value if a getter throws or
+/// `object` is not an object.
+///
+/// # Safety
+///
+/// `context` and `object` must be valid, `key` must point to `key_len` readable bytes, and
+/// `result` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn boa_object_get(
+    context: *mut BoaContext,
+    object: *const BoaValue,
+    key: *const c_char,
+    key_len: usize,
+    result: *mut *mut BoaValue,
+) -> BoaStatus {
+    if context.is_null() || object.is_null() || key.is_null() || result.is_null() {
+        return BoaStatus::InvalidArgument;
+    }
+    let context = &mut *context;
+    let key = String::from_utf8_lossy(slice::from_raw_parts(key.cast::<u8>(), key_len));
+    guard(result, || {
+        let object = (*object).to_object(context)?;
+        object.get(key.as_ref(), context)
+    })
+}
+
+/// Sets the property `key` of `object` to a copy of `value`, `key` being the UTF-8 text of
+/// `key_len` bytes.
+///
+/// If a setter throws or the property can't be set, stores the thrown value in `thrown`, if it
+/// is not null.
+///
+/// # Safety
+///
+/// `context`, `object` and `value` must be valid, `key` must point to `key_len` readable bytes,
+/// and `thrown` must be null or a valid pointer to write the thrown value to.
+#[no_mangle]
+pub unsafe extern "C" fn boa_object_set(
+    context: *mut BoaContext,
+    object: *const BoaValue,
+    key: *const c_char,
+    key_len: usize,
+    value: *const BoaValue,
+    thrown: *mut *mut BoaValue,
+) -> BoaStatus {
+    if context.is_null() || object.is_null() || key.is_null() || value.is_null() {
+        return BoaStatus::InvalidArgument;
+    }
+    let context = &mut *context;
+    let key = String::from_utf8_lossy(slice::from_raw_parts(key.cast::<u8>(), key_len));
+    let mut error = ptr::null_mut();
+    let status = guard(&mut error, || {
+        let object = (*object).to_object(context)?;
+        object.set(key.as_ref(), (*value).clone(), true, context)?;
+        Ok(JsValue::undefined())
+    });
+    forward_thrown(status, error, thrown);
+    status
+}
+
+/// Calls `function` with the `this` value `this_value` and the `argc` arguments in `argv`.
+///
+/// Stores the return value in `result`, or the thrown value if the call throws. A null
+/// `this_value` stands for `undefined`.
+///
+/// # Safety
+///
+/// `context` and `function` must be valid, `this_value` must be null or valid, `argv` must point
+/// to `argc` valid values, and `result` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn boa_call(
+    context: *mut BoaContext,
+    function: *const BoaValue,
+    this_value: *const BoaValue,
+    argv: *const *const BoaValue,
+    argc: usize,
+    result: *mut *mut BoaValue,
+) -> BoaStatus {
+    if context.is_null() || function.is_null() || (argv.is_null() && argc > 0) || result.is_null() {
+        return BoaStatus::InvalidArgument;
+    }
+    let context = &mut *context;
+    let this = this_value.as_ref().cloned().unwrap_or_default();
+    let args = if argc == 0 {
+        Vec::new()
+    } else {
+        slice::from_raw_parts(argv, argc)
+            .iter()
+            .map(|arg| (**arg).clone())
+            .collect()
+    };
+    guard(result, || match (*function).as_callable() {
+        Some(function) => function.call(&this, &args, context),
+        None => context.throw_type_error("the called value is not a function"),
+    })
+}
+
+/// Gives the thrown value to the caller if it asked for it, or destroys it.
+pub(crate) unsafe fn forward_thrown(
+    status: BoaStatus,
+    error: *mut BoaValue,
+    thrown: *mut *mut BoaValue,
+) {
+    if status == BoaStatus::Thrown && !thrown.is_null() {
+        *thrown = error;
+    } else {
+        value::boa_value_free(error);
+    }
+}
@@ -0,0 +1,50 @@
+//! The strings returned by the engine.
+
+use std::os::raw::c_char;
+
+/// A UTF-8 string returned by the engine, followed by a nul byte.
+///
+/// The string may contain nul bytes: use [`boa_string_len`] to get its length.
+#[derive(Debug)]
+pub struct BoaString {
+    bytes: Vec<u8>,
+}
+
+/// Moves a string to the heap, returning the pointer given to C.
+pub(crate) fn into_raw(string: String) -> *mut BoaString {
+    let mut bytes = string.into_bytes();
+    bytes.push(0);
+    Box::into_raw(Box::new(BoaString { bytes }))
+}
+
+/// Gets the nul-terminated UTF-8 bytes of a string, valid until the string is destroyed.
+///
+/// # Safety
+///
+/// `string` must be a valid string.
+#[no_mangle]
+pub unsafe extern "C" fn boa_string_data(string: *const BoaString) -> *const c_char {
+    (*string).bytes.as_ptr().cast()
+}
+
+/// Gets the length in bytes of a string, without the final nul byte.
+///
+/// # Safety
+///
+/// `string` must be a valid string.
+#[no_mangle]
+pub unsafe extern "C" fn boa_string_len(string: *const BoaString) -> usize {
+    (*string).bytes.len() - 1
+}
+
+/// Destroys a string. Does nothing if `string` is null.
+///
+/// # Safety
+///
+/// `string` must be null or a valid string, not used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn boa_string_free(string: *mut BoaString) {
+    if !string.is_null() {
+        drop(Box::from_raw(string));
+    }
+}
@@ -0,0 +1,249 @@
+use super::*;
+use std::{ffi::c_void, os::raw::c_int, ptr};
+
+/// Evaluates `source`, returning the status and the value.
+unsafe fn eval(context: *mut BoaContext, source: &str) -> (BoaStatus, *mut BoaValue) {
+    let mut result = ptr::null_mut();
+    let status = boa_eval(context, source.as_ptr().cast(), source.len(), &mut result);
+    (status, result)
+}
+
+/// Takes a string returned by the engine.
+unsafe fn take_string(string: *mut BoaString) -> String {
+    let bytes = slice::from_raw_parts(boa_string_data(string).cast::<u8>(), boa_string_len(string));
+    let text = String::from_utf8(bytes.to_vec()).unwrap();
+    boa_string_free(string);
+    text
+}
+
+#[test]
+fn eval_and_values() {
+    unsafe {
+        let context = boa_context_new();
+
+        let (status, value) = eval(context, "1 + 2");
+        assert_eq!(status, BoaStatus::Ok);
+        assert_eq!(boa_value_type(value), BoaType::Number);
+        let mut number = 0.0;
+        assert!(boa_value_as_number(value, &mut number));
+        assert_eq!(number as i32, 3);
+        let mut boolean = false;
+        assert!(!boa_value_as_bool(value, &mut boolean));
+        boa_value_free(value);
+
+        let (status, value) = eval(context, "[1, 'a']");
+        assert_eq!(status, BoaStatus::Ok);
+        assert_eq!(boa_value_type(value), BoaType::Object);
+        let mut string = ptr::null_mut();
+        let status = boa_value_to_string(context, value, &mut string, ptr::null_mut());
+        assert_eq!(status, BoaStatus::Ok);
+        assert_eq!(take_string(string), "1,a");
+        boa_value_free(value);
+
+        let text = "h\u{e9}";
+        let value = boa_value_from_string(text.as_ptr().cast(), text.len());
+        assert_eq!(boa_value_type(value), BoaType::String);
+        let status = boa_value_to_string(context, value, &mut string, ptr::null_mut());
+        assert_eq!(status, BoaStatus::Ok);
+        assert_eq!(take_string(string), text);
+        boa_value_free(value);
+
+        boa_context_free(context);
+    }
+}
+
+#[test]
+fn errors() {
+    unsafe {
+        let context = boa_context_new();
+
+        let (status, error) = eval(context, "null.x");
+        assert_eq!(status, BoaStatus::Thrown);
+        assert_eq!(boa_error_kind(context, error), BoaErrorKind::Type);
+        assert!(take_string(boa_error_message(context, error)).starts_with("TypeError: "));
+        boa_value_free(error);
+
+        let (status, error) = eval(context, "throw 42");
+        assert_eq!(status, BoaStatus::Thrown);
+        assert_eq!(boa_error_kind(context, error), BoaErrorKind::Value);
+        assert!(boa_error_stack(context, error).is_null());
+        boa_value_free(error);
+
+        let (status, value) = eval(context, "({ toString() { throw new RangeError('no') } })");
+        assert_eq!(status, BoaStatus::Ok);
+        let mut string = ptr::null_mut();
+        let mut thrown = ptr::null_mut();
+        let status = boa_value_to_string(context, value, &mut string, &mut thrown);
+        assert_eq!(status, BoaStatus::Thrown);
+        assert!(string.is_null());
+        assert_eq!(
+            take_string(boa_error_message(context, thrown)),
+            "RangeError: no"
+        );
+        boa_value_free(thrown);
+        boa_value_free(value);
+
+        let mut result = ptr::null_mut();
+        let status = boa_eval(ptr::null_mut(), ptr::null(), 0, &mut result);
+        assert_eq!(status, BoaStatus::InvalidArgument);
+
+        boa_context_free(context);
+    }
+}
+
+/// Sums its arguments, throwing if one is not a number, and counts its calls in the user data.
+unsafe extern "C" fn sum(
+    _context: *mut BoaContext,
+    _this: *const BoaValue,
+    argv: *const *const BoaValue,
+    argc: usize,
+    user_data: *mut c_void,
+    result: *mut *mut BoaValue,
+) -> BoaStatus {
+    *user_data.cast::<u32>() += 1;
+
+    let mut total = 0.0;
+    for &arg in slice::from_raw_parts(argv, argc) {
+        let mut number = 0.0;
+        if !boa_value_as_number(arg, &mut number) {
+            let message = "not a number";
+            *result = boa_value_from_string(message.as_ptr().cast(), message.len());
+            return BoaStatus::Thrown;
+        }
+        total += number;
+    }
+    *result = boa_value_from_number(total);
+    BoaStatus::Ok
+}
+
+#[test]
+fn callbacks() {
+    unsafe {
+        let context = boa_context_new();
+        let mut calls = 0_u32;
+        let name = "sum";
+        let status = boa_register_function(
+            context,
+            name.as_ptr().cast(),
+            name.len(),
+            2,
+            sum,
+            ptr::addr_of_mut!(calls).cast(),
+            None,
+        );
+        assert_eq!(status, BoaStatus::Ok);
+
+        let (status, value) = eval(context, "sum(1, 2, 3) + sum.length");
+        assert_eq!(status, BoaStatus::Ok);
+        let mut number = 0.0;
+        assert!(boa_value_as_number(value, &mut number));
+        assert_eq!(number as i32, 8);
+        boa_value_free(value);
+
+        let (status, value) = eval(context, "try { sum('a') } catch (e) { e }");
+        assert_eq!(status, BoaStatus::Ok);
+        assert_eq!(boa_value_type(value), BoaType::String);
+        boa_value_free(value);
+
+        assert_eq!(calls, 2);
+        boa_context_free(context);
+    }
+}
+
+/// Initializes a native module exporting `sum` through the table of the API.
+unsafe extern "C" fn init_math(
+    api: *const BoaApi,
+    context: *mut BoaContext,
+    exports: *mut *mut BoaValue,
+) -> c_int {
+    let api = &*api;
+    if api.version != BOA_API_VERSION {
+        return BoaStatus::InvalidArgument as c_int;
+    }
+    let object = (api.object_new)(context);
+    let name = "sum";
+    let function = (api.function_new)(
+        context,
+        name.as_ptr().cast(),
+        name.len(),
+        2,
+        sum,
+        Box::into_raw(Box::new(0_u32)).cast(),
+        Some(free_counter),
+    );
+    let status = (api.object_set)(
+        context,
+        object,
+        name.as_ptr().cast(),
+        name.len(),
+        function,
+        ptr::null_mut(),
+    );
+    (api.value_free)(function);
+    *exports = object;
+    status as c_int
+}
+
+/// Frees the call counter of a function created by [`init_math`].
+unsafe extern "C" fn free_counter(user_data: *mut c_void) {
+    drop(Box::from_raw(user_data.cast::<u32>()));
+}
+
+#[test]
+fn native_modules() {
+    unsafe {
+        let context = boa_context_new();
+        let exports = module::initialize(init_math, &mut *context).unwrap();
+        let key = "sum";
+        let mut function = ptr::null_mut();
+        let status = boa_object_get(
+            context,
+            &exports,
+            key.as_ptr().cast(),
+            key.len(),
+            &mut function,
+        );
+        assert_eq!(status, BoaStatus::Ok);
+        let args = [boa_value_from_number(1.0), boa_value_from_number(2.0)];
+        let args_ptrs = args.map(|arg| arg as *const BoaValue);
+        let mut result = ptr::null_mut();
+        let status = boa_call(
+            context,
+            function,
+            ptr::null(),
+            args_ptrs.as_ptr(),
+            args_ptrs.len(),
+            &mut result,
+        );
+        assert_eq!(status, BoaStatus::Ok);
+        let mut number = 0.0;
+        assert!(boa_value_as_number(result, &mut number));
+        assert_eq!(number as i32, 3);
+        for value in [result, function, args[0], args[1]] {
+            boa_value_free(value);
+        }
+
+        let mut modules = NativeModules::new("/nonexistent");
+        for name in ["", "../math", "missing"] {
+            let error = modules.load(name, &mut *context).unwrap_err();
+            assert_eq!(boa_error_kind(context, &error), BoaErrorKind::Type);
+        }
+
+        let directory = "/nonexistent";
+        let status =
+            boa_register_native_modules(context, directory.as_ptr().cast(), directory.len());
+        assert_eq!(status, BoaStatus::Ok);
+        let (status, value) = eval(
+            context,
+            "[typeof require, (() => { try { require('fs') } catch (e) { return e.name } })()]",
+        );
+        assert_eq!(status, BoaStatus::Ok);
+        let mut string = ptr::null_mut();
+        let status = boa_value_to_string(context, value, &mut string, ptr::null_mut());
+        assert_eq!(status, BoaStatus::Ok);
+        assert_eq!(take_string(string), "function,TypeError");
+        boa_value_free(value);
+
+        boa_context_free(context);
+    }
+}
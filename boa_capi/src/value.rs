@@ -0,0 +1,198 @@
+//! Creation and inspection of values.
+
+use crate::{guard, object, string, BoaContext, BoaStatus, BoaString};
+use boa_engine::JsValue;
+use std::{os::raw::c_char, ptr, slice};
+
+/// A JavaScript value, a [`JsValue`] behind an opaque pointer.
+pub type BoaValue = JsValue;
+
+/// The type of a value, as given by the `typeof` operator, except that `null` has its own type.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoaType {
+    /// `undefined`.
+    Undefined = 0,
+    /// `null`.
+    Null = 1,
+    /// A boolean.
+    Boolean = 2,
+    /// A number.
+    Number = 3,
+    /// A string.
+    String = 4,
+    /// A symbol.
+    Symbol = 5,
+    /// A `BigInt`.
+    BigInt = 6,
+    /// An object, including functions.
+    Object = 7,
+}
+
+/// Moves a value to the heap, returning the pointer given to C.
+pub(crate) fn into_raw(value: JsValue) -> *mut BoaValue {
+    Box::into_raw(Box::new(value))
+}
+
+/// Creates the value `undefined`.
+#[no_mangle]
+pub extern "C" fn boa_value_undefined() -> *mut BoaValue {
+    into_raw(JsValue::undefined())
+}
+
+/// Creates the value `null`.
+#[no_mangle]
+pub extern "C" fn boa_value_null() -> *mut BoaValue {
+    into_raw(JsValue::null())
+}
+
+/// Creates a boolean.
+#[no_mangle]
+pub extern "C" fn boa_value_from_bool(boolean: bool) -> *mut BoaValue {
+    into_raw(boolean.into())
+}
+
+/// Creates a number.
+#[no_mangle]
+pub extern "C" fn boa_value_from_number(number: f64) -> *mut BoaValue {
+    into_raw(number.into())
+}
+
+/// Creates a string from the UTF-8 text `text` of `len` bytes.
+///
+/// Invalid UTF-8 sequences are replaced with `U+FFFD`. Returns null if `text` is null and `len`
+/// is not zero.
+///
+/// # Safety
+///
+/// `text` must point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn boa_value_from_string(text: *const c_char, len: usize) -> *mut BoaValue {
+    if len == 0 {
+        return into_raw("".into());
+    }
+    if text.is_null() {
+        return ptr::null_mut();
+    }
+    let bytes = slice::from_raw_parts(text.cast::<u8>(), len);
+    into_raw(String::from_utf8_lossy(bytes).as_ref().into())
+}
+
+/// Copies a value. Both values must be destroyed.
+///
+/// Objects are shared between the copies: a change made through one is seen by the other.
+/// Returns null if `value` is null.
+///
+/// # Safety
+///
+/// `value` must be null or a valid value.
+#[no_mangle]
+pub unsafe extern "C" fn boa_value_clone(value: *const BoaValue) -> *mut BoaValue {
+    value
+        .as_ref()
+        .map_or(ptr::null_mut(), |value| into_raw(value.clone()))
+}
+
+/// Destroys a value. Does nothing if `value` is null.
+///
+/// # Safety
+///
+/// `value` must be null or a valid value, not used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn boa_value_free(value: *mut BoaValue) {
+    if !value.is_null() {
+        drop(Box::from_raw(value));
+    }
+}
+
+/// Gets the type of a value.
+///
+/// # Safety
+///
+/// `value` must be a valid value.
+#[no_mangle]
+pub unsafe extern "C" fn boa_value_type(value: *const BoaValue) -> BoaType {
+    match &*value {
+        JsValue::Undefined => BoaType::Undefined,
+        JsValue::Null => BoaType::Null,
+        JsValue::Boolean(_) => BoaType::Boolean,
+        JsValue::Integer(_) | JsValue::Rational(_) => BoaType::Number,
+        JsValue::String(_) => BoaType::String,
+        JsValue::Symbol(_) => BoaType::Symbol,
+        JsValue::BigInt(_) => BoaType::BigInt,
+        JsValue::Object(_) => BoaType::Object,
+    }
+}
+
+/// Gets the boolean held by `value`.
+///
+/// Returns `false` without writing to `out` if `value` is not a boolean.
+///
+/// # Safety
+///
+/// `value` must be a valid value, and `out` a valid pointer to write the boolean to.
+#[no_mangle]
+pub unsafe extern "C" fn boa_value_as_bool(value: *const BoaValue, out: *mut bool) -> bool {
+    match (*value).as_boolean() {
+        Some(boolean) if !out.is_null() => {
+            *out = boolean;
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Gets the number held by `value`.
+///
+/// Returns `false` without writing to `out` if `value` is not a number.
+///
+/// # Safety
+///
+/// `value` must be a valid value, and `out` a valid pointer to write the number to.
+#[no_mangle]
+pub unsafe extern "C" fn boa_value_as_number(value: *const BoaValue, out: *mut f64) -> bool {
+    match (*value).as_number() {
+        Some(number) if !out.is_null() => {
+            *out = number;
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Converts a value to a string, like `String(value)` does.
+///
+/// Stores the string in `result`, to be destroyed with [`boa_string_free`]. If the conversion
+/// throws, like for objects whose `toString` method throws, stores null in `result` and the thrown
+/// value in `thrown`, if it is not null.
+///
+/// # Safety
+///
+/// `context` and `value` must be valid, `result` must be a valid pointer to write the string to,
+/// and `thrown` must be null or a valid pointer to write the thrown value to.
+#[no_mangle]
+pub unsafe extern "C" fn boa_value_to_string(
+    context: *mut BoaContext,
+    value: *const BoaValue,
+    result: *mut *mut BoaString,
+    thrown: *mut *mut BoaValue,
+) -> BoaStatus {
+    if context.is_null() || value.is_null() || result.is_null() {
+        return BoaStatus::InvalidArgument;
+    }
+    *result = ptr::null_mut();
+
+    let context = &mut *context;
+    let mut string = None;
+    let mut error = ptr::null_mut();
+    let status = guard(&mut error, || {
+        let converted = (*value).to_string(context)?;
+        string = Some(converted.to_string());
+        Ok(JsValue::undefined())
+    });
+    object::forward_thrown(status, error, thrown);
+    if let Some(string) = string {
+        *result = string::into_raw(string);
+    }
+    status
+}
@@ -59,7 +59,11 @@
     rustdoc::missing_doc_code_examples
 )]
 
-use boa_engine::{syntax::ast::node::StatementList, Context};
+use boa_engine::{
+    syntax::{ast::node::StatementList, estree::ToEstree},
+    value::ValueFormatter,
+    Context,
+};
 use boa_interner::Interner;
 use clap::{ArgEnum, Parser};
 use colored::{Color, Colorize};
@@ -129,6 +133,12 @@ enum DumpFormat {
 
     // This is a pretty printed json format.
     JsonPretty,
+
+    // This is a minified ESTree json format, for JavaScript tooling.
+    Estree,
+
+    // This is a pretty printed ESTree json format.
+    EstreePretty,
 }
 
 /// Parses the the token stream into an AST and returns it.
@@ -173,6 +183,14 @@ where
                             .expect("could not convert AST to a pretty JSON string")
                     );
                 }
+                DumpFormat::Estree => println!("{}", ast.to_estree(&interner)),
+                DumpFormat::EstreePretty => {
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&ast.to_estree(&interner))
+                            .expect("could not convert ESTree to a pretty JSON string")
+                    );
+                }
             },
             // Default ast dumping format.
             None => println!("{ast:#?}"),
@@ -199,8 +217,11 @@ pub fn main() -> Result<(), std::io::Error> {
             }
         } else {
             match context.eval(&buffer) {
-                Ok(v) => println!("{}", v.display()),
-                Err(v) => eprintln!("Uncaught {}", v.display()),
+                Ok(v) => println!("{}", ValueFormatter::default().format(&v, &mut context)),
+                Err(v) => eprintln!(
+                    "Uncaught {}",
+                    ValueFormatter::default().format(&v, &mut context)
+                ),
             }
         }
     }
@@ -238,12 +259,17 @@ pub fn main() -> Result<(), std::io::Error> {
                         }
                     } else {
                         match context.eval(line.trim_end()) {
-                            Ok(v) => println!("{}", v.display()),
+                            Ok(v) => println!(
+                                "{}",
+                                ValueFormatter::default()
+                                    .colors(true)
+                                    .format(&v, &mut context)
+                            ),
                             Err(v) => {
                                 eprintln!(
                                     "{}: {}",
                                     "Uncaught".red(),
-                                    v.display().to_string().red()
+                                    ValueFormatter::default().format(&v, &mut context).red()
                                 );
                             }
                         }
@@ -0,0 +1,24 @@
+(function () {
+  let total = 0;
+  let flags = 0;
+
+  for (let i = 0; i < 2000; i++) {
+    let value = i % 7;
+    switch (value) {
+      case 0:
+        total += i * 2;
+        break;
+      case 1:
+        total -= i;
+        break;
+      default:
+        total = (total + value) | 0;
+    }
+    flags ^= i & 3 ? 1 : 2;
+    if (total > 100000 && !flags) {
+      total = total >> 1;
+    }
+  }
+
+  return total + flags;
+})();
@@ -81,7 +81,8 @@ full_benchmarks!(
     {"String Object Access", string_object_access},
     {"Arithmetic operations", arithmetic_operations},
     {"Clean js", clean_js},
-    {"Mini js", mini_js}
+    {"Mini js", mini_js},
+    {"Opcode dispatch", opcode_dispatch}
 );
 
 criterion_group!(
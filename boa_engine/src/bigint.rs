@@ -78,6 +78,60 @@ impl JsBigInt {
         self.inner.to_f64().unwrap_or(f64::INFINITY)
     }
 
+    /// Converts the `BigInt` to an `i64`, if it is in range.
+    #[inline]
+    pub fn to_i64(&self) -> Option<i64> {
+        self.inner.to_i64()
+    }
+
+    /// Converts the `BigInt` to an `u64`, if it is in range.
+    #[inline]
+    pub fn to_u64(&self) -> Option<u64> {
+        self.inner.to_u64()
+    }
+
+    /// Converts the `BigInt` to an `i128`, if it is in range.
+    #[inline]
+    pub fn to_i128(&self) -> Option<i128> {
+        self.inner.to_i128()
+    }
+
+    /// Converts the `BigInt` to an `u128`, if it is in range.
+    #[inline]
+    pub fn to_u128(&self) -> Option<u128> {
+        self.inner.to_u128()
+    }
+
+    /// Returns the two's complement representation of the `BigInt` in little-endian byte order,
+    /// with the fewest bytes that keep its sign.
+    #[inline]
+    pub fn to_signed_bytes_le(&self) -> Vec<u8> {
+        self.inner.to_signed_bytes_le()
+    }
+
+    /// Returns the two's complement representation of the `BigInt` in big-endian byte order,
+    /// with the fewest bytes that keep its sign.
+    #[inline]
+    pub fn to_signed_bytes_be(&self) -> Vec<u8> {
+        self.inner.to_signed_bytes_be()
+    }
+
+    /// Creates a `BigInt` from its two's complement representation in little-endian byte order.
+    ///
+    /// An empty slice is zero.
+    #[inline]
+    pub fn from_signed_bytes_le(bytes: &[u8]) -> Self {
+        Self::new(RawBigInt::from_signed_bytes_le(bytes))
+    }
+
+    /// Creates a `BigInt` from its two's complement representation in big-endian byte order.
+    ///
+    /// An empty slice is zero.
+    #[inline]
+    pub fn from_signed_bytes_be(bytes: &[u8]) -> Self {
+        Self::new(RawBigInt::from_signed_bytes_be(bytes))
+    }
+
     /// Converts a string to a `BigInt` with the specified radix.
     #[inline]
     pub fn from_string_radix(buf: &str, radix: u32) -> Option<Self> {
@@ -289,6 +343,13 @@ impl From<RawBigInt> for JsBigInt {
     }
 }
 
+impl From<JsBigInt> for RawBigInt {
+    #[inline]
+    fn from(value: JsBigInt) -> Self {
+        Rc::try_unwrap(value.inner).unwrap_or_else(|inner| inner.as_ref().clone())
+    }
+}
+
 impl From<Box<RawBigInt>> for JsBigInt {
     #[inline]
     fn from(value: Box<RawBigInt>) -> Self {
@@ -370,6 +431,24 @@ impl From<u64> for JsBigInt {
     }
 }
 
+impl From<i128> for JsBigInt {
+    #[inline]
+    fn from(value: i128) -> Self {
+        Self {
+            inner: Rc::new(RawBigInt::from(value)),
+        }
+    }
+}
+
+impl From<u128> for JsBigInt {
+    #[inline]
+    fn from(value: u128) -> Self {
+        Self {
+            inner: Rc::new(RawBigInt::from(value)),
+        }
+    }
+}
+
 impl From<isize> for JsBigInt {
     #[inline]
     fn from(value: isize) -> Self {
@@ -82,9 +82,9 @@ impl ArrayIterator {
         }
 
         let len = if let Some(f) = array_iterator.array.borrow().as_typed_array() {
-            if f.is_detached() {
+            if f.is_out_of_bounds() {
                 return context.throw_type_error(
-                    "Cannot get value from typed array that has a detached array buffer",
+                    "Cannot get value from typed array that has a detached or out of bounds array buffer",
                 );
             }
 
@@ -105,11 +105,17 @@ impl ArrayIterator {
         match array_iterator.kind {
             PropertyNameKind::Key => Ok(create_iter_result_object(index.into(), false, context)),
             PropertyNameKind::Value => {
-                let element_value = array_iterator.array.get(index, context)?;
+                let element_value = match Array::get_packed_element(&array_iterator.array, index) {
+                    Some(value) => value,
+                    None => array_iterator.array.get(index, context)?,
+                };
                 Ok(create_iter_result_object(element_value, false, context))
             }
             PropertyNameKind::KeyAndValue => {
-                let element_value = array_iterator.array.get(index, context)?;
+                let element_value = match Array::get_packed_element(&array_iterator.array, index) {
+                    Some(value) => value,
+                    None => array_iterator.array.get(index, context)?,
+                };
                 let result = Array::create_array_from_list([index.into(), element_value], context);
                 Ok(create_iter_result_object(result.into(), false, context))
             }
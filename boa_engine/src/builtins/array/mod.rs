@@ -10,6 +10,7 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array
 
 pub mod array_iterator;
+pub(crate) mod sort;
 #[cfg(test)]
 mod tests;
 
@@ -25,7 +26,7 @@ use crate::{
     context::intrinsics::StandardConstructors,
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
-        JsObject, ObjectData,
+        JsObject, Object, ObjectData,
     },
     property::{Attribute, PropertyDescriptor, PropertyNameKind},
     symbol::WellKnownSymbols,
@@ -101,6 +102,7 @@ impl BuiltIn for Array {
         .method(Self::pop, "pop", 0)
         .method(Self::join, "join", 1)
         .method(Self::to_string, "toString", 0)
+        .method(Self::to_locale_string, "toLocaleString", 0)
         .method(Self::reverse, "reverse", 0)
         .method(Self::shift, "shift", 0)
         .method(Self::unshift, "unshift", 1)
@@ -120,6 +122,10 @@ impl BuiltIn for Array {
         .method(Self::keys, "keys", 0)
         .method(Self::entries, "entries", 0)
         .method(Self::copy_within, "copyWithin", 2)
+        .method(Self::to_reversed, "toReversed", 0)
+        .method(Self::to_sorted, "toSorted", 1)
+        .method(Self::to_spliced, "toSpliced", 2)
+        .method(Self::with, "with", 2)
         // Static Methods
         .static_method(Self::from, "from", 1)
         .static_method(Self::is_array, "isArray", 1)
@@ -266,19 +272,143 @@ impl Array {
         // 2. Let array be ! ArrayCreate(0).
         let array = Self::array_create(0, None, context)
             .expect("creating an empty array with the default prototype must not fail");
+
         // 3. Let n be 0.
         // 4. For each element e of elements, do
-        for (i, elem) in elements.into_iter().enumerate() {
-            // a. Perform ! CreateDataPropertyOrThrow(array, ! ToString(𝔽(n)), e).
-            array
-                .create_data_property_or_throw(i, elem, context)
-                .expect("new array must be extensible");
-            // b. Set n to n + 1.
-        }
+        //     a. Perform ! CreateDataPropertyOrThrow(array, ! ToString(𝔽(n)), e).
+        //     b. Set n to n + 1.
+        Self::extend_new_array(&array, elements);
+
         // 5. Return array.
         array
     }
 
+    /// Appends `elements` to an array created by `ArrayCreate(0)`, like `CreateDataProperty`
+    /// would, with a single borrow of the array.
+    ///
+    /// The new array has no elements yet, so they are appended to its packed storage.
+    pub(crate) fn extend_new_array<I>(array: &JsObject, elements: I)
+    where
+        I: IntoIterator<Item = JsValue>,
+    {
+        let mut object = array.borrow_mut();
+        let properties = object.properties_mut();
+        let packed = properties
+            .packed_index_properties_mut()
+            .expect("a new array must have packed elements");
+        packed.extend(elements.into_iter().map(Self::packed_element));
+        let len = packed.len();
+        properties.insert(&"length".into(), Self::length_property(len));
+    }
+
+    /// Creates the property descriptor of an element created by `CreateDataProperty`.
+    fn packed_element(value: JsValue) -> PropertyDescriptor {
+        PropertyDescriptor::builder()
+            .value(value)
+            .writable(true)
+            .enumerable(true)
+            .configurable(true)
+            .build()
+    }
+
+    /// Creates the property descriptor of the writable `length` property of an array.
+    fn length_property(len: usize) -> PropertyDescriptor {
+        PropertyDescriptor::builder()
+            .value(len)
+            .writable(true)
+            .enumerable(false)
+            .configurable(false)
+            .build()
+    }
+
+    /// Returns the number of packed elements of `array`, if it is an array with a writable
+    /// `length` whose elements `0..length` are all stored in packed mode.
+    fn packed_length(array: &Object) -> Option<usize> {
+        if !array.is_array() {
+            return None;
+        }
+
+        let len = array.properties().packed_index_properties()?.len();
+        let length = array.properties().get(&"length".into())?;
+        if length.writable() == Some(true)
+            && length.value().and_then(JsValue::as_number) == Some(len as f64)
+        {
+            Some(len)
+        } else {
+            None
+        }
+    }
+
+    /// Checks if setting the indices past the end of `array` cannot call into JS, which is the
+    /// case if it is extensible and its prototype chain is the initial `Array.prototype` and
+    /// `Object.prototype`, without any indexed properties.
+    fn has_plain_prototype_chain(array: &Object, context: &Context) -> bool {
+        let array_prototype = context.intrinsics().constructors().array().prototype();
+        let object_prototype = context.intrinsics().constructors().object().prototype();
+
+        let is_plain = |object: &JsObject, prototype: &JsObject| {
+            let object = object.borrow();
+            object.properties().index_property_keys().len() == 0
+                && matches!(object.prototype(), Some(proto) if JsObject::equals(proto, prototype))
+        };
+
+        array.extensible()
+            && matches!(array.prototype(), Some(proto) if JsObject::equals(proto, &array_prototype))
+            && is_plain(&array_prototype, &object_prototype)
+            && object_prototype
+                .borrow()
+                .properties()
+                .index_property_keys()
+                .len()
+                == 0
+    }
+
+    /// Returns the value of the element `index` of `array`, if `array` is an array whose element
+    /// `index` is a data property stored in packed mode.
+    ///
+    /// This is the same value that `Get(array, index)` would return, without going through the
+    /// generic property lookup.
+    pub(crate) fn get_packed_element(array: &JsObject, index: usize) -> Option<JsValue> {
+        let array = array.borrow();
+        if !array.is_array() {
+            return None;
+        }
+        array
+            .properties()
+            .packed_index_properties()?
+            .get(index)?
+            .value()
+            .cloned()
+    }
+
+    /// Sets the element `index` of `array` to `value`, if `array` is an array whose element
+    /// `index` is a writable data property stored in packed mode, returning `true` on success.
+    ///
+    /// This has the same effect as `Set(array, index, value, throw)` in that case, without going
+    /// through the generic property lookup.
+    pub(crate) fn set_packed_element(array: &JsObject, index: usize, value: &JsValue) -> bool {
+        let mut array = array.borrow_mut();
+        if !array.is_array() {
+            return false;
+        }
+        let element = array
+            .properties_mut()
+            .packed_index_properties_mut()
+            .and_then(|packed| packed.get_mut(index));
+        match element {
+            Some(element) if element.writable() == Some(true) => {
+                *element = PropertyDescriptor::builder()
+                    .value(value.clone())
+                    .writable(true)
+                    .maybe_enumerable(element.enumerable())
+                    .maybe_configurable(element.configurable())
+                    .build();
+                true
+            }
+            _ => false,
+        }
+    }
+
     /// Utility function for concatenating array objects.
     ///
     /// Returns a Boolean valued property that if `true` indicates that
@@ -753,6 +883,25 @@ impl Array {
                 "the length + the number of arguments exceed the maximum safe integer limit",
             );
         }
+        // Fast path for packed arrays, whose elements can be appended directly when no setter
+        // can be observed.
+        {
+            let mut object = o.borrow_mut();
+            if Self::packed_length(&object) == Some(len as usize)
+                && Self::has_plain_prototype_chain(&object, context)
+                && len + arg_count <= u64::from(u32::MAX)
+            {
+                let properties = object.properties_mut();
+                properties
+                    .packed_index_properties_mut()
+                    .expect("the elements were checked to be packed")
+                    .extend(args.iter().cloned().map(Self::packed_element));
+                let len = len + arg_count;
+                properties.insert(&"length".into(), Self::length_property(len as usize));
+                return Ok(len.into());
+            }
+        }
+
         // 5. For each element E of items, do
         for element in args.iter().cloned() {
             // a. Perform ? Set(O, ! ToString(𝔽(len)), E, true).
@@ -789,6 +938,28 @@ impl Array {
             Ok(JsValue::undefined())
         // 4. Else,
         } else {
+            // Fast path for packed arrays, whose last element can be removed directly if it is a
+            // configurable data property.
+            {
+                let mut object = o.borrow_mut();
+                if Self::packed_length(&object) == Some(len) {
+                    let properties = object.properties_mut();
+                    let packed = properties
+                        .packed_index_properties_mut()
+                        .expect("the elements were checked to be packed");
+                    if packed.last().map_or(false, |last| {
+                        last.is_data_descriptor() && last.expect_configurable()
+                    }) {
+                        let element = packed
+                            .pop()
+                            .and_then(|last| last.value().cloned())
+                            .unwrap_or_default();
+                        properties.insert(&"length".into(), Self::length_property(len - 1));
+                        return Ok(element);
+                    }
+                }
+            }
+
             // a. Assert: len > 0.
             // b. Let newLen be 𝔽(len - 1).
             let new_len = len - 1;
@@ -935,6 +1106,64 @@ impl Array {
         }
     }
 
+    /// `Array.prototype.toLocaleString( [ locales [ , options ] ] )`
+    ///
+    /// The elements of the array are converted to Strings using their `toLocaleString` methods,
+    /// and these Strings are then concatenated, separated by commas.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sup-array.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/toLocaleString
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn to_locale_string(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let array be ? ToObject(this value).
+        let array = this.to_object(context)?;
+        // 2. Let len be ? ToLength(? Get(array, "length")).
+        let len = array.length_of_array_like(context)?;
+        // 3. Let separator be the implementation-defined list-separator String appropriate for the host environment's current locale (such as ", ").
+        let separator = ",";
+
+        // 4. Let R be the empty String.
+        let mut r = String::new();
+        // 5. Let k be 0.
+        // 6. Repeat, while k < len,
+        for k in 0..len {
+            // a. If k > 0, then
+            if k > 0 {
+                // i. Set R to the string-concatenation of R and separator.
+                r.push_str(separator);
+            }
+            // b. Let nextElement be ? Get(array, ! ToString(k)).
+            let next_element = array.get(k, context)?;
+            // c. If nextElement is not undefined or null, then
+            if !next_element.is_null_or_undefined() {
+                // i. Let S be ? ToString(? Invoke(nextElement, "toLocaleString", « locales, options »)).
+                let s = next_element
+                    .invoke(
+                        "toLocaleString",
+                        &[locales.clone(), options.clone()],
+                        context,
+                    )?
+                    .to_string(context)?;
+                // ii. Set R to the string-concatenation of R and S.
+                r.push_str(&s);
+            }
+            // d. Increase k by 1.
+        }
+        // 7. Return R.
+        Ok(r.into())
+    }
+
     /// `Array.prototype.reverse()`
     ///
     /// The elements of the array are rearranged so as to reverse their order.
@@ -1018,6 +1247,49 @@ impl Array {
         Ok(o.into())
     }
 
+    /// `Array.prototype.toReversed()`
+    ///
+    /// Returns a new array with the elements of this array in reverse order, leaving the original
+    /// untouched.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.toreversed
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/toReversed
+    pub(crate) fn to_reversed(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = o.length_of_array_like(context)?;
+
+        // 3. Let A be ? ArrayCreate(len).
+        let a = Self::array_create(len, None, context)?;
+
+        // 4. Let k be 0.
+        // 5. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let from be ! ToString(𝔽(len - k - 1)).
+            let from = len - k - 1;
+            // b. Let Pk be ! ToString(𝔽(k)).
+            // c. Let fromValue be ? Get(O, from).
+            let from_value = o.get(from, context)?;
+            // d. Perform ! CreateDataPropertyOrThrow(A, Pk, fromValue).
+            a.create_data_property_or_throw(k, from_value, context)
+                .expect("cannot fail for a newly created array");
+            // e. Set k to k + 1.
+        }
+
+        // 6. Return A.
+        Ok(a.into())
+    }
+
     /// `Array.prototype.shift()`
     ///
     /// The first element of the array is removed from the array and returned.
@@ -2197,6 +2469,113 @@ impl Array {
         Ok(JsValue::from(arr))
     }
 
+    /// `Array.prototype.toSpliced ( start, skipCount, ...items )`
+    ///
+    /// Returns a new array with `skipCount` elements starting at `start` replaced by `items`,
+    /// leaving the original untouched.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.tospliced
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/toSpliced
+    pub(crate) fn to_spliced(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = o.length_of_array_like(context)?;
+
+        let start = args.get(0);
+        let skip_count = args.get(1);
+        let items = args.get(2..).unwrap_or(&[]);
+
+        // 3. Let relativeStart be ? ToIntegerOrInfinity(start).
+        // 4. If relativeStart is -∞, let actualStart be 0.
+        // 5. Else if relativeStart < 0, let actualStart be max(len + relativeStart, 0).
+        // 6. Else, let actualStart be min(relativeStart, len).
+        let actual_start = Self::get_relative_start(context, start, len)?;
+
+        // 7. Let insertCount be the number of elements in items.
+        let insert_count = items.len();
+
+        let actual_skip_count = if start.is_none() {
+            // 8. If start is not present, then
+            //     a. Let actualSkipCount be 0.
+            0
+        } else if let Some(skip_count) = skip_count {
+            // 10. Else,
+            //     a. Let sc be ? ToIntegerOrInfinity(skipCount).
+            //     b. Let actualSkipCount be the result of clamping sc between 0 and len - actualStart.
+            let max = len - actual_start;
+            match skip_count.to_integer_or_infinity(context)? {
+                IntegerOrInfinity::Integer(i) => (i.max(0) as usize).min(max),
+                IntegerOrInfinity::PositiveInfinity => max,
+                IntegerOrInfinity::NegativeInfinity => 0,
+            }
+        } else {
+            // 9. Else if skipCount is not present, then
+            //     a. Let actualSkipCount be len - actualStart.
+            len - actual_start
+        };
+
+        // 11. Let newLen be len + insertCount - actualSkipCount.
+        let new_len = len + insert_count - actual_skip_count;
+
+        // 12. If newLen > 2^53 - 1, throw a TypeError exception.
+        if new_len > Number::MAX_SAFE_INTEGER as usize {
+            return context.throw_type_error("Target splice exceeded max safe integer value");
+        }
+
+        // 13. Let A be ? ArrayCreate(newLen).
+        let a = Self::array_create(new_len, None, context)?;
+
+        // 14. Let i be 0.
+        // 15. Let r be actualStart + actualSkipCount.
+        let mut r = actual_start + actual_skip_count;
+
+        // 16. Repeat, while i < actualStart,
+        for i in 0..actual_start {
+            // a. Let Pi be ! ToString(𝔽(i)).
+            // b. Let iValue be ? Get(O, Pi).
+            let i_value = o.get(i, context)?;
+            // c. Perform ! CreateDataPropertyOrThrow(A, Pi, iValue).
+            a.create_data_property_or_throw(i, i_value, context)
+                .expect("cannot fail for a newly created array");
+            // d. Set i to i + 1.
+        }
+
+        // 17. For each element E of items, do
+        for (i, item) in items.iter().enumerate() {
+            // a. Let Pi be ! ToString(𝔽(i)).
+            // b. Perform ! CreateDataPropertyOrThrow(A, Pi, E).
+            a.create_data_property_or_throw(actual_start + i, item.clone(), context)
+                .expect("cannot fail for a newly created array");
+            // c. Set i to i + 1.
+        }
+
+        // 18. Repeat, while i < newLen,
+        for i in (actual_start + insert_count)..new_len {
+            // a. Let Pi be ! ToString(𝔽(i)).
+            // b. Let from be ! ToString(𝔽(r)).
+            // c. Let fromValue be ? Get(O, from).
+            let from_value = o.get(r, context)?;
+            // d. Perform ! CreateDataPropertyOrThrow(A, Pi, fromValue).
+            a.create_data_property_or_throw(i, from_value, context)
+                .expect("cannot fail for a newly created array");
+            // e. Set i to i + 1.
+            // f. Set r to r + 1.
+            r += 1;
+        }
+
+        // 19. Return A.
+        Ok(a.into())
+    }
+
     /// `Array.prototype.filter( callback, [ thisArg ] )`
     ///
     /// For each element in the array the callback function is called, and a new
@@ -2342,111 +2721,218 @@ impl Array {
             }
         };
 
-        // Abstract method `SortCompare`.
-        //
-        // More information:
-        //  - [ECMAScript reference][spec]
-        //
-        // [spec]: https://tc39.es/ecma262/#sec-sortcompare
-        let sort_compare =
-            |x: &JsValue, y: &JsValue, context: &mut Context| -> JsResult<Ordering> {
-                match (x.is_undefined(), y.is_undefined()) {
-                    // 1. If x and y are both undefined, return +0𝔽.
-                    (true, true) => return Ok(Ordering::Equal),
-                    // 2. If x is undefined, return 1𝔽.
-                    (true, false) => return Ok(Ordering::Greater),
-                    // 3. If y is undefined, return -1𝔽.
-                    (false, true) => return Ok(Ordering::Less),
-                    _ => {}
-                }
-
-                // 4. If comparefn is not undefined, then
-                if let Some(cmp) = comparefn {
-                    let args = [x.clone(), y.clone()];
-                    // a. Let v be ? ToNumber(? Call(comparefn, undefined, « x, y »)).
-                    let v = cmp
-                        .call(&JsValue::Undefined, &args, context)?
-                        .to_number(context)?;
-                    // b. If v is NaN, return +0𝔽.
-                    // c. Return v.
-                    return Ok(v.partial_cmp(&0.0).unwrap_or(Ordering::Equal));
-                }
-                // 5. Let xString be ? ToString(x).
-                // 6. Let yString be ? ToString(y).
-                let x_str = x.to_string(context)?;
-                let y_str = y.to_string(context)?;
-
-                // 7. Let xSmaller be IsLessThan(xString, yString, true).
-                // 8. If xSmaller is true, return -1𝔽.
-                // 9. Let ySmaller be IsLessThan(yString, xString, true).
-                // 10. If ySmaller is true, return 1𝔽.
-                // 11. Return +0𝔽.
-
-                // NOTE: skipped IsLessThan because it just makes a lexicographic comparation
-                // when x and y are strings
-                Ok(x_str.cmp(&y_str))
-            };
-
         // 2. Let obj be ? ToObject(this value).
         let obj = this.to_object(context)?;
 
         // 3. Let len be ? LengthOfArrayLike(obj).
         let length = obj.length_of_array_like(context)?;
 
-        // 4. Let items be a new empty List.
-        let mut items = Vec::with_capacity(length);
+        // 4. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparefn and performs the following steps when called:
+        // 5. Let sortedList be ? SortIndexedProperties(obj, len, SortCompare, skip-holes).
+        let sorted = Self::sort_indexed_properties(&obj, length, comparefn, true, context)?;
 
-        // 5. Let k be 0.
-        // 6. Repeat, while k < len,
-        for k in 0..length {
+        // 6. Let itemCount be the number of elements in sortedList.
+        let item_count = sorted.len();
+
+        // 7. Let j be 0.
+        // 8. Repeat, while j < itemCount,
+        for (j, item) in sorted.into_iter().enumerate() {
+            // a. Perform ? Set(obj, ! ToString(𝔽(j)), sortedList[j], true).
+            obj.set(j, item, true, context)?;
+            // b. Set j to j + 1.
+        }
+
+        // 9. NOTE: The call to SortIndexedProperties in step 5 uses skip-holes. The remaining indices are deleted to preserve the number of holes that were detected and excluded from the sort.
+        // 10. Repeat, while j < len,
+        for j in item_count..length {
+            // a. Perform ? DeletePropertyOrThrow(obj, ! ToString(𝔽(j))).
+            obj.delete_property_or_throw(j, context)?;
+            // b. Set j to j + 1.
+        }
+
+        // 11. Return obj.
+        Ok(obj.into())
+    }
+
+    /// `Array.prototype.toSorted( comparefn )`
+    ///
+    /// Returns a new array with the elements of this array sorted, leaving the original untouched.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.tosorted
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/toSorted
+    pub(crate) fn to_sorted(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If comparefn is not undefined and IsCallable(comparefn) is false, throw a TypeError exception.
+        let comparefn = match args.get_or_undefined(0) {
+            JsValue::Object(ref obj) if obj.is_callable() => Some(obj),
+            JsValue::Undefined => None,
+            _ => {
+                return context.throw_type_error(
+                    "The comparison function must be either a function or undefined",
+                )
+            }
+        };
+
+        // 2. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+
+        // 3. Let len be ? LengthOfArrayLike(O).
+        let len = o.length_of_array_like(context)?;
+
+        // 4. Let A be ? ArrayCreate(len).
+        let a = Self::array_create(len, None, context)?;
+
+        // 5. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparefn and performs the following steps when called:
+        // 6. Let sortedList be ? SortIndexedProperties(O, len, SortCompare, read-through-holes).
+        let sorted = Self::sort_indexed_properties(&o, len, comparefn, false, context)?;
+
+        // 7. Let j be 0.
+        // 8. Repeat, while j < len,
+        for (j, item) in sorted.into_iter().enumerate() {
+            // a. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(j)), sortedList[j]).
+            a.create_data_property_or_throw(j, item, context)
+                .expect("cannot fail for a newly created array");
+            // b. Set j to j + 1.
+        }
+
+        // 9. Return A.
+        Ok(a.into())
+    }
+
+    /// Abstract operation `SortIndexedProperties ( obj, len, SortCompare, holes )`
+    ///
+    /// If `skip_holes` is `true`, indices that are not present on `obj` are skipped; otherwise
+    /// they are read through and sorted as `undefined`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sortindexedproperties
+    fn sort_indexed_properties(
+        obj: &JsObject,
+        len: usize,
+        comparefn: Option<&JsObject>,
+        skip_holes: bool,
+        context: &mut Context,
+    ) -> JsResult<Vec<JsValue>> {
+        // 1. Let items be a new empty List.
+        let mut items = Vec::with_capacity(len);
+
+        // 2. Let k be 0.
+        // 3. Repeat, while k < len,
+        for k in 0..len {
             // a. Let Pk be ! ToString(𝔽(k)).
-            // b. Let kPresent be ? HasProperty(obj, Pk).
-            // c. If kPresent is true, then
-            if obj.has_property(k, context)? {
+            // b. If holes is skip-holes, then
+            //     i. Let kRead be ? HasProperty(obj, Pk).
+            // c. Else,
+            //     i. Assert: holes is read-through-holes.
+            //     ii. Let kRead be true.
+            // d. If kRead is true, then
+            if !skip_holes || obj.has_property(k, context)? {
                 // i. Let kValue be ? Get(obj, Pk).
                 let kval = obj.get(k, context)?;
                 // ii. Append kValue to items.
                 items.push(kval);
             }
-            // d. Set k to k + 1.
+            // e. Set k to k + 1.
         }
 
-        // 7. Let itemCount be the number of elements in items.
-        let item_count = items.len();
-
-        // 8. Sort items using an implementation-defined sequence of calls to SortCompare.
+        // 4. Sort items using an implementation-defined sequence of calls to SortCompare.
         // If any such call returns an abrupt completion, stop before performing any further
         // calls to SortCompare or steps in this algorithm and return that completion.
-        let mut sort_err = Ok(());
-        items.sort_by(|x, y| {
-            if sort_err.is_ok() {
-                sort_compare(x, y, context).unwrap_or_else(|err| {
-                    sort_err = Err(err);
-                    Ordering::Equal
-                })
-            } else {
-                Ordering::Equal
+        //
+        // `CompareArrayElements` always places `undefined` after every other value, without
+        // calling `comparefn`, so the undefined values are moved to the end beforehand.
+        let undefined_count = items.iter().filter(|item| item.is_undefined()).count();
+        items.retain(|item| !item.is_undefined());
+
+        match comparefn {
+            Some(comparefn) => sort::timsort_by(&mut items, |x, y| {
+                Self::compare_array_elements(x, y, Some(comparefn), context)
+            })?,
+            // Strings are compared as is, since `ToString` would return them unchanged.
+            None if items.iter().all(JsValue::is_string) => {
+                sort::timsort_by(&mut items, |x, y| {
+                    Ok(x.as_string()
+                        .expect("all the items are strings")
+                        .cmp(y.as_string().expect("all the items are strings")))
+                })?;
             }
-        });
-        sort_err?;
-
-        // 9. Let j be 0.
-        // 10. Repeat, while j < itemCount,
-        for (j, item) in items.into_iter().enumerate() {
-            // a. Perform ? Set(obj, ! ToString(𝔽(j)), items[j], true).
-            obj.set(j, item, true, context)?;
-            // b. Set j to j + 1.
+            // `ToString` cannot call into JS for numbers, so every number is only converted
+            // once instead of on every comparison.
+            None if items.iter().all(JsValue::is_number) => {
+                let mut keyed = items
+                    .into_iter()
+                    .map(|item| Ok((item.to_string(context)?, item)))
+                    .collect::<JsResult<Vec<_>>>()?;
+                sort::timsort_by(&mut keyed, |(x, _), (y, _)| Ok(x.cmp(y)))?;
+                items = keyed.into_iter().map(|(_, item)| item).collect();
+            }
+            None => sort::timsort_by(&mut items, |x, y| {
+                Self::compare_array_elements(x, y, None, context)
+            })?,
         }
 
-        // 11. Repeat, while j < len,
-        for j in item_count..length {
-            // a. Perform ? DeletePropertyOrThrow(obj, ! ToString(𝔽(j))).
-            obj.delete_property_or_throw(j, context)?;
-            // b. Set j to j + 1.
+        items.resize(items.len() + undefined_count, JsValue::undefined());
+
+        // 5. Return items.
+        Ok(items)
+    }
+
+    /// Abstract operation `CompareArrayElements ( x, y, comparefn )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-comparearrayelements
+    fn compare_array_elements(
+        x: &JsValue,
+        y: &JsValue,
+        comparefn: Option<&JsObject>,
+        context: &mut Context,
+    ) -> JsResult<Ordering> {
+        match (x.is_undefined(), y.is_undefined()) {
+            // 1. If x and y are both undefined, return +0𝔽.
+            (true, true) => return Ok(Ordering::Equal),
+            // 2. If x is undefined, return 1𝔽.
+            (true, false) => return Ok(Ordering::Greater),
+            // 3. If y is undefined, return -1𝔽.
+            (false, true) => return Ok(Ordering::Less),
+            _ => {}
         }
 
-        // 12. Return obj.
-        Ok(obj.into())
+        // 4. If comparefn is not undefined, then
+        if let Some(cmp) = comparefn {
+            let args = [x.clone(), y.clone()];
+            // a. Let v be ? ToNumber(? Call(comparefn, undefined, « x, y »)).
+            let v = cmp
+                .call(&JsValue::Undefined, &args, context)?
+                .to_number(context)?;
+            // b. If v is NaN, return +0𝔽.
+            // c. Return v.
+            return Ok(v.partial_cmp(&0.0).unwrap_or(Ordering::Equal));
+        }
+        // 5. Let xString be ? ToString(x).
+        // 6. Let yString be ? ToString(y).
+        let x_str = x.to_string(context)?;
+        let y_str = y.to_string(context)?;
+
+        // 7. Let xSmaller be IsLessThan(xString, yString, true).
+        // 8. If xSmaller is true, return -1𝔽.
+        // 9. Let ySmaller be IsLessThan(yString, xString, true).
+        // 10. If ySmaller is true, return 1𝔽.
+        // 11. Return +0𝔽.
+
+        // NOTE: skipped IsLessThan because it just makes a lexicographic comparation
+        // when x and y are strings
+        Ok(x_str.cmp(&y_str))
     }
 
     /// `Array.prototype.reduce( callbackFn [ , initialValue ] )`
@@ -2727,6 +3213,69 @@ impl Array {
         Ok(o.into())
     }
 
+    /// `Array.prototype.with ( index, value )`
+    ///
+    /// Returns a new array with the element at `index` replaced by `value`, leaving the original
+    /// untouched.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.with
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/with
+    pub(crate) fn with(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = o.length_of_array_like(context)?;
+
+        // 3. Let relativeIndex be ? ToIntegerOrInfinity(index).
+        let relative_index = args.get_or_undefined(0).to_integer_or_infinity(context)?;
+
+        let actual_index = match relative_index {
+            // 4. If relativeIndex ≥ 0, let actualIndex be relativeIndex.
+            IntegerOrInfinity::Integer(i) if i >= 0 => Some(i as u64),
+            // 5. Else, let actualIndex be len + relativeIndex.
+            IntegerOrInfinity::Integer(i) => (len as u64).checked_sub(i.unsigned_abs()),
+            IntegerOrInfinity::PositiveInfinity | IntegerOrInfinity::NegativeInfinity => None,
+        };
+
+        // 6. If actualIndex ≥ len or actualIndex < 0, throw a RangeError exception.
+        let actual_index = match actual_index {
+            Some(index) if index < len as u64 => index as usize,
+            _ => return context.throw_range_error("Array.prototype.with index out of range"),
+        };
+
+        // 7. Let A be ? ArrayCreate(len).
+        let a = Self::array_create(len, None, context)?;
+
+        // 8. Let k be 0.
+        // 9. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. If k is actualIndex, let fromValue be value.
+            let from_value = if k == actual_index {
+                args.get_or_undefined(1).clone()
+            // c. Else, let fromValue be ? Get(O, Pk).
+            } else {
+                o.get(k, context)?
+            };
+            // d. Perform ! CreateDataPropertyOrThrow(A, Pk, fromValue).
+            a.create_data_property_or_throw(k, from_value, context)
+                .expect("cannot fail for a newly created array");
+            // e. Set k to k + 1.
+        }
+
+        // 10. Return A.
+        Ok(a.into())
+    }
+
     /// `Array.prototype.values( )`
     ///
     /// The values method returns an iterable that iterates over the values in the array.
@@ -2868,7 +3417,7 @@ impl Array {
     /// The initial value of the 'unscopables' data property is an ordinary object
     /// with the following boolean properties set to true:
     /// 'at', 'copyWithin', 'entries', 'fill', 'find', 'findIndex', 'flat',
-    /// 'flatMap', 'includes', 'keys', 'values'
+    /// 'flatMap', 'includes', 'keys', 'toReversed', 'toSorted', 'toSpliced', 'values'
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
@@ -2919,12 +3468,24 @@ impl Array {
         unscopable_list
             .create_data_property_or_throw("keys", true, context)
             .expect("CreateDataPropertyOrThrow for 'keys' must not fail");
-        // 12. Perform ! CreateDataPropertyOrThrow(unscopableList, "values", true).
+        // 12. Perform ! CreateDataPropertyOrThrow(unscopableList, "toReversed", true).
+        unscopable_list
+            .create_data_property_or_throw("toReversed", true, context)
+            .expect("CreateDataPropertyOrThrow for 'toReversed' must not fail");
+        // 13. Perform ! CreateDataPropertyOrThrow(unscopableList, "toSorted", true).
+        unscopable_list
+            .create_data_property_or_throw("toSorted", true, context)
+            .expect("CreateDataPropertyOrThrow for 'toSorted' must not fail");
+        // 14. Perform ! CreateDataPropertyOrThrow(unscopableList, "toSpliced", true).
+        unscopable_list
+            .create_data_property_or_throw("toSpliced", true, context)
+            .expect("CreateDataPropertyOrThrow for 'toSpliced' must not fail");
+        // 15. Perform ! CreateDataPropertyOrThrow(unscopableList, "values", true).
         unscopable_list
             .create_data_property_or_throw("values", true, context)
             .expect("CreateDataPropertyOrThrow for 'values' must not fail");
 
-        // 13. Return unscopableList.
+        // 16. Return unscopableList.
         unscopable_list
     }
 }
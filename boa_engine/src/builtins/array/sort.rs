@@ -0,0 +1,203 @@
+//! This module implements the stable sort used by `Array.prototype.sort` and friends.
+//!
+//! The algorithm is a TimSort: the input is split into natural runs, which are extended with a
+//! binary insertion sort up to a minimum length, and then merged following the TimSort stack
+//! invariants. Unlike `slice::sort_by`, the comparator is fallible, and sorting stops at the first
+//! comparison that returns an abrupt completion, as required by `SortIndexedProperties`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-sortindexedproperties
+
+use crate::JsResult;
+use std::cmp::Ordering;
+
+/// Slices shorter than this are sorted with a binary insertion sort.
+const MIN_MERGE: usize = 32;
+
+/// A run of already sorted elements, waiting to be merged.
+#[derive(Debug, Clone, Copy)]
+struct Run {
+    start: usize,
+    len: usize,
+}
+
+/// Sorts `items` in place using the fallible comparator `compare`.
+///
+/// The sort is stable: elements that compare as equal keep their relative order. If `compare`
+/// returns an error, no further comparisons are performed and the error is returned; the order
+/// of `items` is then unspecified.
+pub(crate) fn timsort_by<T, F>(items: &mut [T], mut compare: F) -> JsResult<()>
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> JsResult<Ordering>,
+{
+    let len = items.len();
+    if len < 2 {
+        return Ok(());
+    }
+
+    let min_run = min_run_length(len);
+    let mut runs: Vec<Run> = Vec::new();
+    let mut start = 0;
+    while start < len {
+        // Find the next natural run, and extend it to `min_run` if it is too short.
+        let mut run_len = count_run_and_make_ascending(&mut items[start..], &mut compare)?;
+        if run_len < min_run {
+            let forced = min_run.min(len - start);
+            binary_insertion_sort(&mut items[start..start + forced], run_len, &mut compare)?;
+            run_len = forced;
+        }
+
+        runs.push(Run {
+            start,
+            len: run_len,
+        });
+        merge_collapse(items, &mut runs, &mut compare)?;
+        start += run_len;
+    }
+
+    // Merge all the remaining runs.
+    while runs.len() > 1 {
+        merge_at(items, &mut runs, runs.len() - 2, &mut compare)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the minimum length of a run, such that `len / min_run` is a power of two or slightly
+/// less, which keeps the final merges balanced.
+fn min_run_length(mut len: usize) -> usize {
+    let mut remainder = 0;
+    while len >= MIN_MERGE {
+        remainder |= len & 1;
+        len >>= 1;
+    }
+    len + remainder
+}
+
+/// Returns the length of the run at the start of `items`, reversing it if it is strictly
+/// descending.
+///
+/// Descending runs must be strict, otherwise reversing them would break the stability.
+fn count_run_and_make_ascending<T, F>(items: &mut [T], compare: &mut F) -> JsResult<usize>
+where
+    F: FnMut(&T, &T) -> JsResult<Ordering>,
+{
+    if items.len() < 2 {
+        return Ok(items.len());
+    }
+
+    let mut end = 2;
+    if compare(&items[1], &items[0])? == Ordering::Less {
+        while end < items.len() && compare(&items[end], &items[end - 1])? == Ordering::Less {
+            end += 1;
+        }
+        items[..end].reverse();
+    } else {
+        while end < items.len() && compare(&items[end], &items[end - 1])? != Ordering::Less {
+            end += 1;
+        }
+    }
+
+    Ok(end)
+}
+
+/// Sorts `items` with a binary insertion sort, assuming its first `sorted` elements are already
+/// sorted.
+fn binary_insertion_sort<T, F>(items: &mut [T], sorted: usize, compare: &mut F) -> JsResult<()>
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> JsResult<Ordering>,
+{
+    for i in sorted.max(1)..items.len() {
+        let pivot = items[i].clone();
+
+        // Find the position after the last element that is not greater than the pivot.
+        let (mut low, mut high) = (0, i);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if compare(&pivot, &items[mid])? == Ordering::Less {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+
+        items[low..=i].rotate_right(1);
+    }
+
+    Ok(())
+}
+
+/// Merges the runs on top of the stack until the TimSort invariants hold again:
+///
+/// 1. `runs[n - 2].len > runs[n - 1].len + runs[n].len`
+/// 2. `runs[n - 1].len > runs[n].len`
+fn merge_collapse<T, F>(items: &mut [T], runs: &mut Vec<Run>, compare: &mut F) -> JsResult<()>
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> JsResult<Ordering>,
+{
+    while runs.len() > 1 {
+        let n = runs.len() - 2;
+        if (n > 0 && runs[n - 1].len <= runs[n].len + runs[n + 1].len)
+            || (n > 1 && runs[n - 2].len <= runs[n - 1].len + runs[n].len)
+        {
+            let n = if runs[n - 1].len < runs[n + 1].len {
+                n - 1
+            } else {
+                n
+            };
+            merge_at(items, runs, n, compare)?;
+        } else if runs[n].len <= runs[n + 1].len {
+            merge_at(items, runs, n, compare)?;
+        } else {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Merges the adjacent runs `runs[i]` and `runs[i + 1]`.
+fn merge_at<T, F>(items: &mut [T], runs: &mut Vec<Run>, i: usize, compare: &mut F) -> JsResult<()>
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> JsResult<Ordering>,
+{
+    let left = runs[i];
+    let right = runs.remove(i + 1);
+    runs[i].len += right.len;
+
+    let mid = right.start;
+    let end = right.start + right.len;
+
+    // The runs are already in order, which is common for partially sorted inputs.
+    if compare(&items[mid], &items[mid - 1])? != Ordering::Less {
+        return Ok(());
+    }
+
+    let buffer = items[left.start..mid].to_vec();
+    let (mut i, mut j, mut k) = (0, mid, left.start);
+    while i < buffer.len() && j < end {
+        // Take from the left run on ties, to keep the sort stable.
+        if compare(&items[j], &buffer[i])? == Ordering::Less {
+            items[k] = items[j].clone();
+            j += 1;
+        } else {
+            items[k] = buffer[i].clone();
+            i += 1;
+        }
+        k += 1;
+    }
+
+    // The remaining elements of the right run are already in place.
+    for value in &buffer[i..] {
+        items[k] = value.clone();
+        k += 1;
+    }
+
+    Ok(())
+}
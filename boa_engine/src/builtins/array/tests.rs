@@ -212,6 +212,33 @@ fn to_string() {
     assert_eq!(many, String::from("\"a,b,c\""));
 }
 
+#[cfg(feature = "intl")]
+#[test]
+fn to_locale_string() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(
+            &mut context,
+            "[1234.5, null, 'a', undefined, 10n].toLocaleString()"
+        ),
+        "\"1,234.5,,a,,10\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[0.5, 2].toLocaleString('en', { style: 'percent' })"
+        ),
+        "\"50%,200%\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[{ toLocaleString(locales, options) { return locales + options.x; } }].toLocaleString('en', { x: 1 })"
+        ),
+        "\"en1\""
+    );
+}
+
 #[test]
 fn every() {
     let mut context = Context::default();
@@ -1572,3 +1599,180 @@ fn array_sort() {
         "\"1,5,9,40,80,200,700\""
     );
 }
+
+#[test]
+fn array_change_by_copy() {
+    let mut context = Context::default();
+    let init = r#"
+        var arr = [3, , 1, 2];
+    "#;
+    forward(&mut context, init);
+
+    // toReversed
+    assert_eq!(
+        forward(&mut context, "arr.toReversed().join()"),
+        "\"2,1,,3\""
+    );
+    assert_eq!(forward(&mut context, "1 in arr.toReversed()"), "true");
+
+    // toSorted
+    assert_eq!(forward(&mut context, "arr.toSorted().join()"), "\"1,2,3,\"");
+    assert_eq!(
+        forward(&mut context, "arr.toSorted((a, b) => b - a).join()"),
+        "\"3,2,1,\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { arr.toSorted(1) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+
+    // toSpliced
+    assert_eq!(
+        forward(&mut context, "arr.toSpliced(1, 1, 'a', 'b').join()"),
+        "\"3,a,b,1,2\""
+    );
+    assert_eq!(
+        forward(&mut context, "arr.toSpliced(-1).join()"),
+        "\"3,,1\""
+    );
+    assert_eq!(forward(&mut context, "arr.toSpliced().length"), "4");
+    assert_eq!(forward(&mut context, "arr.toSpliced(1, -5).length"), "4");
+
+    // with
+    assert_eq!(
+        forward(&mut context, "arr.with(-1, 'x').join()"),
+        "\"3,,1,x\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { arr.with(4, 0) } catch (e) { e.name }"),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { arr.with(-5, 0) } catch (e) { e.name }"),
+        "\"RangeError\""
+    );
+
+    // The original array is left untouched.
+    assert_eq!(forward(&mut context, "arr.join()"), "\"3,,1,2\"");
+    assert_eq!(forward(&mut context, "1 in arr"), "false");
+}
+
+#[test]
+fn array_sort_stable_timsort() {
+    let mut context = Context::default();
+
+    // Numbers and strings are sorted by their string representation.
+    assert_eq!(
+        forward(&mut context, "[10, 9, 1, -1, 2.5].sort().join()"),
+        "\"-1,1,10,2.5,9\""
+    );
+    assert_eq!(
+        forward(&mut context, "['b', 'c', 'a', 'B'].sort().join()"),
+        "\"B,a,b,c\""
+    );
+
+    // Undefined values are placed after every other value, and holes are kept at the end.
+    let init = r#"
+        var arr = [3, undefined, , 1, undefined, 2];
+        arr.sort((a, b) => b - a);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "arr.length"), "6");
+    assert_eq!(forward(&mut context, "arr.join()"), "\"3,2,1,,,\"");
+    assert_eq!(forward(&mut context, "arr[4]"), "undefined");
+    assert_eq!(forward(&mut context, "4 in arr"), "true");
+    assert_eq!(forward(&mut context, "5 in arr"), "false");
+
+    // The sort is stable.
+    let init = r#"
+        var items = [];
+        for (let i = 0; i < 100; i++) {
+            items.push({ key: (i * 7) % 5, index: i });
+        }
+        items.sort((a, b) => a.key - b.key);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(
+            &mut context,
+            "items.every((item, i) => i === 0 || items[i - 1].key < item.key || items[i - 1].index < item.index)"
+        ),
+        "true"
+    );
+
+    // A throwing comparator stops the sort and leaves the array untouched.
+    let init = r#"
+        var calls = 0;
+        var untouched = [3, 2, 1];
+        var error;
+        try {
+            untouched.sort(() => {
+                calls++;
+                throw "stop";
+            });
+        } catch (e) {
+            error = e;
+        }
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "error"), "\"stop\"");
+    assert_eq!(forward(&mut context, "calls"), "1");
+    assert_eq!(forward(&mut context, "untouched.join()"), "\"3,2,1\"");
+}
+
+#[test]
+fn packed_elements() {
+    let mut context = Context::default();
+
+    let init = r#"
+        var arr = [];
+        for (let i = 0; i < 10; i++) {
+            arr.push(i);
+        }
+        arr[3] = 'x';
+        arr.pop();
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "arr.join()"), "\"0,1,2,x,4,5,6,7,8\"");
+    assert_eq!(forward(&mut context, "arr.length"), "9");
+
+    // Holes and deletions move the elements out of the packed storage.
+    assert_eq!(forward(&mut context, "arr[12] = 12; arr.length"), "13");
+    assert_eq!(forward(&mut context, "10 in arr"), "false");
+    assert_eq!(
+        forward(&mut context, "delete arr[0]; Object.keys(arr).join()"),
+        "\"1,2,3,4,5,6,7,8,12\""
+    );
+
+    // Frozen arrays cannot be modified through the fast paths.
+    let init = r#"
+        var frozen = Object.freeze([1, 2, 3]);
+        frozen[0] = 10;
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "frozen[0]"), "1");
+    assert_eq!(
+        forward(&mut context, "try { frozen.push(4) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { frozen.pop() } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(forward(&mut context, "frozen.length"), "3");
+
+    // Setters on the prototype chain are observed by `push`.
+    let init = r#"
+        var observed = [];
+        Object.defineProperty(Array.prototype, 1, {
+            set(v) { observed.push(v); },
+            configurable: true,
+        });
+        var withSetter = [0];
+        withSetter.push('a');
+        delete Array.prototype[1];
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "observed[0]"), "\"a\"");
+    assert_eq!(forward(&mut context, "1 in withSetter"), "false");
+}
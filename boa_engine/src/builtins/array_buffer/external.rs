@@ -0,0 +1,79 @@
+//! External Data Blocks, the `[[ArrayBufferData]]` of the `ArrayBuffer`s created over memory
+//! owned by the embedder.
+//!
+//! An external block takes ownership of any value that can be viewed as a mutable byte slice,
+//! like a `Vec<u8>`, a `Box<[u8]>`, a `bytes::BytesMut` or a writable memory map, and gives
+//! scripts access to its bytes without copying them. The value is handed back to the embedder
+//! when the buffer is detached with
+//! [`JsArrayBuffer::take_external`](crate::object::JsArrayBuffer::take_external).
+
+use std::{any::Any, fmt};
+
+/// An External Data Block, holding memory owned by the embedder.
+pub struct ExternalDataBlock {
+    memory: Box<dyn Any>,
+    bytes: fn(&dyn Any) -> &[u8],
+    bytes_mut: fn(&mut dyn Any) -> &mut [u8],
+}
+
+impl ExternalDataBlock {
+    /// Creates a block viewing the bytes of `memory`.
+    pub(crate) fn new<T>(memory: T) -> Self
+    where
+        T: AsRef<[u8]> + AsMut<[u8]> + 'static,
+    {
+        Self {
+            memory: Box::new(memory),
+            bytes: |memory| {
+                memory
+                    .downcast_ref::<T>()
+                    .expect("the memory of the block has the type it was created with")
+                    .as_ref()
+            },
+            bytes_mut: |memory| {
+                memory
+                    .downcast_mut::<T>()
+                    .expect("the memory of the block has the type it was created with")
+                    .as_mut()
+            },
+        }
+    }
+
+    /// Returns the bytes of the block.
+    #[inline]
+    pub(crate) fn bytes(&self) -> &[u8] {
+        (self.bytes)(&*self.memory)
+    }
+
+    /// Returns the bytes of the block, mutably.
+    #[inline]
+    pub(crate) fn bytes_mut(&mut self) -> &mut [u8] {
+        (self.bytes_mut)(&mut *self.memory)
+    }
+
+    /// Returns `true` if the memory of the block is a `T`.
+    #[inline]
+    pub(crate) fn is<T: 'static>(&self) -> bool {
+        self.memory.is::<T>()
+    }
+
+    /// Returns the memory of the block, or the block itself if its memory is not a `T`.
+    pub(crate) fn into_inner<T: 'static>(self) -> Result<T, Self> {
+        if self.is::<T>() {
+            Ok(*self
+                .memory
+                .downcast::<T>()
+                .expect("checked the type of the memory"))
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl fmt::Debug for ExternalDataBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExternalDataBlock")
+            .field("len", &self.bytes().len())
+            .finish_non_exhaustive()
+    }
+}
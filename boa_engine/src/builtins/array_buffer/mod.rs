@@ -1,6 +1,9 @@
+pub mod external;
+pub mod shared;
 #[cfg(test)]
 mod tests;
 
+use self::{external::ExternalDataBlock, shared::SharedDataBlock};
 use crate::{
     builtins::{typed_array::TypedArrayKind, BuiltIn, JsArgs},
     context::intrinsics::StandardConstructors,
@@ -13,15 +16,17 @@ use crate::{
     value::{IntegerOrInfinity, Numeric},
     Context, JsResult, JsValue,
 };
-use boa_gc::{Finalize, Trace};
+use boa_gc::{unsafe_empty_trace, Finalize, Trace};
 use boa_profiler::Profiler;
 use num_traits::{Signed, ToPrimitive};
+use std::{borrow::Cow, ops::Range};
 use tap::{Conv, Pipe};
 
-#[derive(Debug, Clone, Trace, Finalize)]
+#[derive(Debug, Trace, Finalize)]
 pub struct ArrayBuffer {
-    pub array_buffer_data: Option<Vec<u8>>,
+    pub array_buffer_data: Option<DataBlock>,
     pub array_buffer_byte_length: usize,
+    pub array_buffer_max_byte_length: Option<usize>,
     pub array_buffer_detach_key: JsValue,
 }
 
@@ -29,6 +34,114 @@ impl ArrayBuffer {
     pub(crate) fn array_buffer_byte_length(&self) -> usize {
         self.array_buffer_byte_length
     }
+
+    /// `IsFixedLengthArrayBuffer ( arrayBuffer )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isfixedlengtharraybuffer
+    pub(crate) fn is_fixed_length(&self) -> bool {
+        // 1. If arrayBuffer has an [[ArrayBufferMaxByteLength]] internal slot, return false.
+        // 2. Return true.
+        self.array_buffer_max_byte_length.is_none()
+    }
+
+    /// `25.2.2.2 IsSharedArrayBuffer ( obj )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-issharedarraybuffer
+    pub(crate) fn is_shared_array_buffer(&self) -> bool {
+        // 1. Let bufferData be obj.[[ArrayBufferData]].
+        // 2. If bufferData is null, return false.
+        // 3. If bufferData is a Data Block, return false.
+        // 4. Assert: bufferData is a Shared Data Block.
+        // 5. Return true.
+        matches!(self.array_buffer_data, Some(DataBlock::Shared(_)))
+    }
+}
+
+/// The `[[ArrayBufferData]]` of an `ArrayBuffer` or a `SharedArrayBuffer`.
+#[derive(Debug, Finalize)]
+pub enum DataBlock {
+    /// A Data Block, owned by a single `ArrayBuffer`.
+    Owned(Vec<u8>),
+    /// A Data Block over memory owned by the embedder, handed back to it when the `ArrayBuffer`
+    /// is detached.
+    External(ExternalDataBlock),
+    /// A Shared Data Block, which may be viewed by `SharedArrayBuffer`s of other agents.
+    Shared(SharedDataBlock),
+}
+
+// Safety: `DataBlock` does not contain any objects which needs to be traced,
+// so this is safe.
+unsafe impl Trace for DataBlock {
+    unsafe_empty_trace!();
+}
+
+impl DataBlock {
+    /// Returns the number of bytes of the block.
+    #[inline]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Owned(bytes) => bytes.len(),
+            Self::External(block) => block.bytes().len(),
+            Self::Shared(bytes) => bytes.len(),
+        }
+    }
+
+    /// Returns `true` if the block has no bytes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the bytes of the block in `range`.
+    pub(crate) fn bytes(&self, range: Range<usize>) -> Cow<'_, [u8]> {
+        match self {
+            Self::Owned(bytes) => Cow::Borrowed(&bytes[range]),
+            Self::External(block) => Cow::Borrowed(&block.bytes()[range]),
+            Self::Shared(bytes) => {
+                let mut buf = vec![0; range.len()];
+                bytes.read(range.start, &mut buf);
+                Cow::Owned(buf)
+            }
+        }
+    }
+
+    /// Calls `f` with the bytes of the block in `range`, storing back any modification.
+    ///
+    /// For Shared Data Blocks the modification is not atomic.
+    pub(crate) fn with_bytes_mut<R>(
+        &mut self,
+        range: Range<usize>,
+        f: impl FnOnce(&mut [u8]) -> R,
+    ) -> R {
+        match self {
+            Self::Owned(bytes) => f(&mut bytes[range]),
+            Self::External(block) => f(&mut block.bytes_mut()[range]),
+            Self::Shared(bytes) => {
+                let mut buf = vec![0; range.len()];
+                bytes.read(range.start, &mut buf);
+                let result = f(&mut buf);
+                bytes.write(range.start, &buf);
+                result
+            }
+        }
+    }
+
+    /// Copies `bytes` into the block, starting at `index`.
+    pub(crate) fn write(&mut self, index: usize, bytes: &[u8]) {
+        match self {
+            Self::Owned(block) => block[index..index + bytes.len()].copy_from_slice(bytes),
+            Self::External(block) => {
+                block.bytes_mut()[index..index + bytes.len()].copy_from_slice(bytes);
+            }
+            Self::Shared(block) => block.write(index, bytes),
+        }
+    }
 }
 
 impl BuiltIn for ArrayBuffer {
@@ -42,6 +155,24 @@ impl BuiltIn for ArrayBuffer {
             .constructor(false)
             .build();
 
+        let flag_attributes = Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE;
+
+        let get_byte_length = FunctionBuilder::native(context, Self::get_byte_length)
+            .name("get byteLength")
+            .build();
+
+        let get_max_byte_length = FunctionBuilder::native(context, Self::get_max_byte_length)
+            .name("get maxByteLength")
+            .build();
+
+        let get_resizable = FunctionBuilder::native(context, Self::get_resizable)
+            .name("get resizable")
+            .build();
+
+        let get_detached = FunctionBuilder::native(context, Self::get_detached)
+            .name("get detached")
+            .build();
+
         ConstructorBuilder::with_standard_constructor(
             context,
             Self::constructor,
@@ -56,8 +187,19 @@ impl BuiltIn for ArrayBuffer {
             Attribute::CONFIGURABLE,
         )
         .static_method(Self::is_view, "isView", 1)
-        .method(Self::byte_length, "byteLength", 0)
+        .accessor("byteLength", Some(get_byte_length), None, flag_attributes)
+        .accessor(
+            "maxByteLength",
+            Some(get_max_byte_length),
+            None,
+            flag_attributes,
+        )
+        .accessor("resizable", Some(get_resizable), None, flag_attributes)
+        .accessor("detached", Some(get_detached), None, flag_attributes)
+        .method(Self::resize, "resize", 1)
         .method(Self::slice, "slice", 2)
+        .method(Self::transfer, "transfer", 0)
+        .method(Self::transfer_to_fixed_length, "transferToFixedLength", 0)
         .property(
             WellKnownSymbols::to_string_tag(),
             Self::NAME,
@@ -72,7 +214,7 @@ impl BuiltIn for ArrayBuffer {
 impl ArrayBuffer {
     const LENGTH: usize = 1;
 
-    /// `25.1.3.1 ArrayBuffer ( length )`
+    /// `25.1.3.1 ArrayBuffer ( length [ , options ] )`
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
@@ -92,8 +234,40 @@ impl ArrayBuffer {
         // 2. Let byteLength be ? ToIndex(length).
         let byte_length = args.get_or_undefined(0).to_index(context)?;
 
-        // 3. Return ? AllocateArrayBuffer(NewTarget, byteLength).
-        Ok(Self::allocate(new_target, byte_length, context)?.into())
+        // 3. Let requestedMaxByteLength be ? GetArrayBufferMaxByteLengthOption(options).
+        let max_byte_length = Self::get_max_byte_length_option(args.get_or_undefined(1), context)?;
+
+        // 4. Return ? AllocateArrayBuffer(NewTarget, byteLength, requestedMaxByteLength).
+        Ok(Self::allocate(new_target, byte_length, max_byte_length, context)?.into())
+    }
+
+    /// `GetArrayBufferMaxByteLengthOption ( options )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getarraybuffermaxbytelengthoption
+    fn get_max_byte_length_option(
+        options: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Option<usize>> {
+        // 1. If Type(options) is not Object, return empty.
+        let options = if let Some(options) = options.as_object() {
+            options
+        } else {
+            return Ok(None);
+        };
+
+        // 2. Let maxByteLength be ? Get(options, "maxByteLength").
+        let max_byte_length = options.get("maxByteLength", context)?;
+
+        // 3. If maxByteLength is undefined, return empty.
+        if max_byte_length.is_undefined() {
+            return Ok(None);
+        }
+
+        // 4. Return ? ToIndex(maxByteLength).
+        max_byte_length.to_index(context).map(Some)
     }
 
     /// `25.1.4.3 get ArrayBuffer [ @@species ]`
@@ -133,7 +307,11 @@ impl ArrayBuffer {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.bytelength
-    fn byte_length(this: &JsValue, _args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    fn get_byte_length(
+        this: &JsValue,
+        _args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
         let obj = if let Some(obj) = this.as_object() {
@@ -148,8 +326,11 @@ impl ArrayBuffer {
             return context.throw_type_error("ArrayBuffer.byteLength called with invalid object");
         };
 
-        // TODO: Shared Array Buffer
         // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        if o.is_shared_array_buffer() {
+            return context
+                .throw_type_error("ArrayBuffer.byteLength called with a SharedArrayBuffer");
+        }
 
         // 4. If IsDetachedBuffer(O) is true, return +0𝔽.
         if Self::is_detached_buffer(o) {
@@ -161,6 +342,305 @@ impl ArrayBuffer {
         Ok(o.array_buffer_byte_length.into())
     }
 
+    /// `get ArrayBuffer.prototype.maxByteLength`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.maxbytelength
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/maxByteLength
+    fn get_max_byte_length(
+        this: &JsValue,
+        _args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        let obj = Self::this_array_buffer(this, "ArrayBuffer.maxByteLength", context)?;
+        let obj = obj.borrow();
+        let o = obj
+            .as_array_buffer()
+            .expect("checked that `this` is an ArrayBuffer");
+
+        // 4. If IsDetachedBuffer(O) is true, return +0𝔽.
+        if o.is_detached_buffer() {
+            return Ok(0.into());
+        }
+
+        // 5. If IsFixedLengthArrayBuffer(O) is true, then
+        //     a. Let length be O.[[ArrayBufferByteLength]].
+        // 6. Else,
+        //     a. Let length be O.[[ArrayBufferMaxByteLength]].
+        let length = o
+            .array_buffer_max_byte_length
+            .unwrap_or(o.array_buffer_byte_length);
+
+        // 7. Return 𝔽(length).
+        Ok(length.into())
+    }
+
+    /// `get ArrayBuffer.prototype.resizable`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.resizable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/resizable
+    fn get_resizable(
+        this: &JsValue,
+        _args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        let obj = Self::this_array_buffer(this, "ArrayBuffer.resizable", context)?;
+        let obj = obj.borrow();
+        let o = obj
+            .as_array_buffer()
+            .expect("checked that `this` is an ArrayBuffer");
+
+        // 4. If IsFixedLengthArrayBuffer(O) is false, return true; otherwise return false.
+        Ok((!o.is_fixed_length()).into())
+    }
+
+    /// `get ArrayBuffer.prototype.detached`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.detached
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/detached
+    fn get_detached(this: &JsValue, _args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        let obj = Self::this_array_buffer(this, "ArrayBuffer.detached", context)?;
+        let obj = obj.borrow();
+        let o = obj
+            .as_array_buffer()
+            .expect("checked that `this` is an ArrayBuffer");
+
+        // 4. Return IsDetachedBuffer(O).
+        Ok(o.is_detached_buffer().into())
+    }
+
+    /// `ArrayBuffer.prototype.resize ( newLength )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.resize
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/resize
+    fn resize(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferMaxByteLength]]).
+        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        let obj = Self::this_array_buffer(this, "ArrayBuffer.resize", context)?;
+        let max_byte_length = obj
+            .borrow()
+            .as_array_buffer()
+            .expect("checked that `this` is an ArrayBuffer")
+            .array_buffer_max_byte_length;
+        let max_byte_length = if let Some(max_byte_length) = max_byte_length {
+            max_byte_length
+        } else {
+            return context
+                .throw_type_error("ArrayBuffer.resize called with a fixed length buffer");
+        };
+
+        // 4. Let newByteLength be ? ToIndex(newLength).
+        let new_byte_length = args.get_or_undefined(0).to_index(context)?;
+
+        let mut obj = obj.borrow_mut();
+        let o = obj
+            .as_array_buffer_mut()
+            .expect("checked that `this` is an ArrayBuffer");
+
+        // 5. If IsDetachedBuffer(O) is true, throw a TypeError exception.
+        let block = match &mut o.array_buffer_data {
+            Some(DataBlock::Owned(block)) => block,
+            _ => return context.throw_type_error("ArrayBuffer.resize called with detached buffer"),
+        };
+
+        // 6. If newByteLength > O.[[ArrayBufferMaxByteLength]], throw a RangeError exception.
+        if new_byte_length > max_byte_length {
+            return context
+                .throw_range_error("ArrayBuffer.resize called with a length over maxByteLength");
+        }
+
+        // 7. Let hostHandled be ? HostResizeArrayBuffer(O, newByteLength).
+        // 8. If hostHandled is handled, return undefined.
+        // 9. Let oldBlock be O.[[ArrayBufferData]].
+        // 10. Let newBlock be ? CreateByteDataBlock(newByteLength).
+        // 11. Let copyLength be min(newByteLength, O.[[ArrayBufferByteLength]]).
+        // 12. Perform CopyDataBlockBytes(newBlock, 0, oldBlock, 0, copyLength).
+        // 13. NOTE: Neither creation of the new Data Block nor copying from the old Data Block are
+        //     observable. Implementations may implement this method as in-place growth or shrinkage.
+        // 14. Set O.[[ArrayBufferData]] to newBlock.
+        resize_byte_data_block(block, new_byte_length, context)?;
+
+        // 15. Set O.[[ArrayBufferByteLength]] to newByteLength.
+        o.array_buffer_byte_length = new_byte_length;
+
+        // 16. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `ArrayBuffer.prototype.transfer ( [ newLength ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.transfer
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/transfer
+    fn transfer(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Return ? ArrayBufferCopyAndDetach(O, newLength, preserve-resizability).
+        Self::copy_and_detach(this, args.get_or_undefined(0), true, context).map(Into::into)
+    }
+
+    /// `ArrayBuffer.prototype.transferToFixedLength ( [ newLength ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.transfertofixedlength
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/transferToFixedLength
+    fn transfer_to_fixed_length(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Return ? ArrayBufferCopyAndDetach(O, newLength, fixed-length).
+        Self::copy_and_detach(this, args.get_or_undefined(0), false, context).map(Into::into)
+    }
+
+    /// `ArrayBufferCopyAndDetach ( arrayBuffer, newLength, preserveResizability )`
+    ///
+    /// The bytes of the buffer are moved to the new `ArrayBuffer` instead of being copied.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffercopyanddetach
+    fn copy_and_detach(
+        array_buffer: &JsValue,
+        new_length: &JsValue,
+        preserve_resizability: bool,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Perform ? RequireInternalSlot(arrayBuffer, [[ArrayBufferData]]).
+        // 2. If IsSharedArrayBuffer(arrayBuffer) is true, throw a TypeError exception.
+        let obj = Self::this_array_buffer(array_buffer, "ArrayBuffer.transfer", context)?;
+
+        // 3. If newLength is undefined, then
+        //     a. Let newByteLength be arrayBuffer.[[ArrayBufferByteLength]].
+        // 4. Else,
+        //     a. Let newByteLength be ? ToIndex(newLength).
+        let new_byte_length = if new_length.is_undefined() {
+            obj.borrow()
+                .as_array_buffer()
+                .expect("checked that `this` is an ArrayBuffer")
+                .array_buffer_byte_length
+        } else {
+            new_length.to_index(context)?
+        };
+
+        let mut obj = obj.borrow_mut();
+        let o = obj
+            .as_array_buffer_mut()
+            .expect("checked that `this` is an ArrayBuffer");
+
+        // 5. If IsDetachedBuffer(arrayBuffer) is true, throw a TypeError exception.
+        if o.is_detached_buffer() {
+            return context.throw_type_error("ArrayBuffer.transfer called with detached buffer");
+        }
+
+        // 6. If preserveResizability is preserve-resizability and IsFixedLengthArrayBuffer(arrayBuffer)
+        //    is false, then
+        //     a. Let newMaxByteLength be arrayBuffer.[[ArrayBufferMaxByteLength]].
+        // 7. Else,
+        //     a. Let newMaxByteLength be empty.
+        let new_max_byte_length = if preserve_resizability {
+            o.array_buffer_max_byte_length
+        } else {
+            None
+        };
+
+        // 8. If arrayBuffer.[[ArrayBufferDetachKey]] is not undefined, throw a TypeError exception.
+        if !o.array_buffer_detach_key.is_undefined() {
+            return context
+                .throw_type_error("ArrayBuffer.transfer called with a non-detachable buffer");
+        }
+
+        // 9. Let newBuffer be ? AllocateArrayBuffer(%ArrayBuffer%, newByteLength, newMaxByteLength).
+        // 10. Let copyLength be min(newByteLength, arrayBuffer.[[ArrayBufferByteLength]]).
+        // 11. Let fromBlock be arrayBuffer.[[ArrayBufferData]].
+        // 12. Let toBlock be newBuffer.[[ArrayBufferData]].
+        // 13. Perform CopyDataBlockBytes(toBlock, 0, fromBlock, 0, copyLength).
+        // 14. NOTE: Neither creation of the new Data Block nor copying from the old Data Block are
+        //     observable. Implementations may implement this method as a zero-copy move or a realloc.
+        if matches!(new_max_byte_length, Some(max) if new_byte_length > max) {
+            return context.throw_range_error("ArrayBuffer length over maxByteLength");
+        }
+        match &mut o.array_buffer_data {
+            Some(DataBlock::Owned(block)) => {
+                resize_byte_data_block(block, new_byte_length, context)?;
+            }
+            _ => unreachable!("checked that the buffer is not detached or shared"),
+        }
+
+        // 15. Perform ! DetachArrayBuffer(arrayBuffer).
+        let block = o
+            .detach(&JsValue::undefined(), context)?
+            .expect("checked that the buffer is not detached");
+        drop(obj);
+
+        // 16. Return newBuffer.
+        let prototype = context
+            .intrinsics()
+            .constructors()
+            .array_buffer()
+            .prototype();
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::array_buffer(Self {
+                array_buffer_data: Some(block),
+                array_buffer_byte_length: new_byte_length,
+                array_buffer_max_byte_length: new_max_byte_length,
+                array_buffer_detach_key: JsValue::Undefined,
+            }),
+        ))
+    }
+
+    /// Returns `this` if it is an `ArrayBuffer`, or throws a `TypeError` if it is not an object,
+    /// not an `ArrayBuffer` or a `SharedArrayBuffer`.
+    fn this_array_buffer(
+        this: &JsValue,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        match this.as_object() {
+            Some(obj) => match obj.borrow().as_array_buffer() {
+                Some(buffer) if buffer.is_shared_array_buffer() => {
+                    context.throw_type_error(format!("{method} called with a SharedArrayBuffer"))
+                }
+                Some(_) => Ok(obj.clone()),
+                None => context.throw_type_error(format!("{method} called with invalid object")),
+            },
+            None => context.throw_type_error(format!("{method} called with non-object value")),
+        }
+    }
+
     /// `25.1.5.3 ArrayBuffer.prototype.slice ( start, end )`
     ///
     /// More information:
@@ -175,23 +655,28 @@ impl ArrayBuffer {
         } else {
             return context.throw_type_error("ArrayBuffer.slice called with non-object value");
         };
-        let obj_borrow = obj.borrow();
-        let o = if let Some(o) = obj_borrow.as_array_buffer() {
-            o
-        } else {
-            return context.throw_type_error("ArrayBuffer.slice called with invalid object");
-        };
-
-        // TODO: Shared Array Buffer
-        // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+        let len = {
+            let obj_borrow = obj.borrow();
+            let o = if let Some(o) = obj_borrow.as_array_buffer() {
+                o
+            } else {
+                return context.throw_type_error("ArrayBuffer.slice called with invalid object");
+            };
+
+            // 3. If IsSharedArrayBuffer(O) is true, throw a TypeError exception.
+            if o.is_shared_array_buffer() {
+                return context
+                    .throw_type_error("ArrayBuffer.slice called with a SharedArrayBuffer");
+            }
 
-        // 4. If IsDetachedBuffer(O) is true, throw a TypeError exception.
-        if Self::is_detached_buffer(o) {
-            return context.throw_type_error("ArrayBuffer.slice called with detached buffer");
-        }
+            // 4. If IsDetachedBuffer(O) is true, throw a TypeError exception.
+            if Self::is_detached_buffer(o) {
+                return context.throw_type_error("ArrayBuffer.slice called with detached buffer");
+            }
 
-        // 5. Let len be O.[[ArrayBufferByteLength]].
-        let len = o.array_buffer_byte_length as i64;
+            // 5. Let len be O.[[ArrayBufferByteLength]].
+            o.array_buffer_byte_length as i64
+        };
 
         // 6. Let relativeStart be ? ToIntegerOrInfinity(start).
         let relative_start = args.get_or_undefined(0).to_integer_or_infinity(context)?;
@@ -244,8 +729,11 @@ impl ArrayBuffer {
                 context.construct_type_error("ArrayBuffer constructor returned invalid object")
             })?;
 
-            // TODO: Shared Array Buffer
             // 18. If IsSharedArrayBuffer(new) is true, throw a TypeError exception.
+            if new_array_buffer.is_shared_array_buffer() {
+                return context
+                    .throw_type_error("ArrayBuffer constructor returned a SharedArrayBuffer");
+            }
 
             // 19. If IsDetachedBuffer(new) is true, throw a TypeError exception.
             if new_array_buffer.is_detached_buffer() {
@@ -268,8 +756,12 @@ impl ArrayBuffer {
             return context.throw_type_error("New ArrayBuffer length too small");
         }
 
-        // 22. NOTE: Side-effects of the above steps may have detached O.
+        // 22. NOTE: Side-effects of the above steps may have detached or resized O.
         // 23. If IsDetachedBuffer(O) is true, throw a TypeError exception.
+        let obj_borrow = obj.borrow();
+        let o = obj_borrow
+            .as_array_buffer()
+            .expect("Already checked that `obj` was an `ArrayBuffer`");
         if Self::is_detached_buffer(o) {
             return context
                 .throw_type_error("ArrayBuffer detached while ArrayBuffer.slice was running");
@@ -287,14 +779,24 @@ impl ArrayBuffer {
             .as_mut()
             .expect("ArrayBuffer cannot be detached here");
 
-        // 26. Perform CopyDataBlockBytes(toBuf, 0, fromBuf, first, newLen).
-        copy_data_block_bytes(to_buf, 0, from_buf, first as usize, new_len);
+        // 26. Let currentLen be O.[[ArrayBufferByteLength]].
+        let current_len = o.array_buffer_byte_length;
 
-        // 27. Return new.
+        // 27. If first < currentLen, then
+        let first = first as usize;
+        if first < current_len {
+            // a. Let count be min(newLen, currentLen - first).
+            let count = std::cmp::min(new_len, current_len - first);
+
+            // b. Perform CopyDataBlockBytes(toBuf, 0, fromBuf, first, count).
+            copy_data_block_bytes(to_buf, 0, from_buf, first, count);
+        }
+
+        // 28. Return new.
         Ok(new)
     }
 
-    /// `25.1.2.1 AllocateArrayBuffer ( constructor, byteLength )`
+    /// `25.1.2.1 AllocateArrayBuffer ( constructor, byteLength [ , maxByteLength ] )`
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
@@ -303,9 +805,19 @@ impl ArrayBuffer {
     pub(crate) fn allocate(
         constructor: &JsValue,
         byte_length: usize,
+        max_byte_length: Option<usize>,
         context: &mut Context,
     ) -> JsResult<JsObject> {
-        // 1. Let obj be ? OrdinaryCreateFromConstructor(constructor, "%ArrayBuffer.prototype%", « [[ArrayBufferData]], [[ArrayBufferByteLength]], [[ArrayBufferDetachKey]] »).
+        // 1. Let slots be « [[ArrayBufferData]], [[ArrayBufferByteLength]], [[ArrayBufferDetachKey]] ».
+        // 2. If maxByteLength is present and maxByteLength is not empty, let allocatingResizableBuffer be true; otherwise let allocatingResizableBuffer be false.
+        // 3. If allocatingResizableBuffer is true, then
+        //     a. If byteLength > maxByteLength, throw a RangeError exception.
+        //     b. Append [[ArrayBufferMaxByteLength]] to slots.
+        if matches!(max_byte_length, Some(max) if byte_length > max) {
+            return context.throw_range_error("ArrayBuffer length over maxByteLength");
+        }
+
+        // 4. Let obj be ? OrdinaryCreateFromConstructor(constructor, "%ArrayBuffer.prototype%", slots).
         let prototype = get_prototype_from_constructor(
             constructor,
             StandardConstructors::array_buffer,
@@ -314,18 +826,25 @@ impl ArrayBuffer {
         let obj = context.construct_object();
         obj.set_prototype(prototype.into());
 
-        // 2. Let block be ? CreateByteDataBlock(byteLength).
+        // 5. Let block be ? CreateByteDataBlock(byteLength).
+        // 6. Set obj.[[ArrayBufferData]] to block.
+        // 7. Set obj.[[ArrayBufferByteLength]] to byteLength.
+        // 8. If allocatingResizableBuffer is true, then
+        //     a. If it is not possible to create a Data Block block consisting of maxByteLength bytes, throw a RangeError exception.
+        //     b. NOTE: Resizable ArrayBuffers are designed to be implementable with in-place growth. Implementations may throw if, for example, virtual memory cannot be reserved up front.
+        //     c. Set obj.[[ArrayBufferMaxByteLength]] to maxByteLength.
+        //
+        // The block of a resizable buffer is grown on demand, so only `byteLength` bytes are
+        // allocated here.
         let block = create_byte_data_block(byte_length, context)?;
-
-        // 3. Set obj.[[ArrayBufferData]] to block.
-        // 4. Set obj.[[ArrayBufferByteLength]] to byteLength.
         obj.borrow_mut().data = ObjectData::array_buffer(Self {
-            array_buffer_data: Some(block),
+            array_buffer_data: Some(DataBlock::Owned(block)),
             array_buffer_byte_length: byte_length,
+            array_buffer_max_byte_length: max_byte_length,
             array_buffer_detach_key: JsValue::Undefined,
         });
 
-        // 5. Return obj.
+        // 9. Return obj.
         Ok(obj)
     }
 
@@ -341,6 +860,36 @@ impl ArrayBuffer {
         self.array_buffer_data.is_none()
     }
 
+    /// `25.1.2.3 DetachArrayBuffer ( arrayBuffer [ , key ] )`
+    ///
+    /// Returns the Data Block the buffer had before being detached.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-detacharraybuffer
+    pub(crate) fn detach(
+        &mut self,
+        key: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Option<DataBlock>> {
+        // 1. Assert: IsSharedArrayBuffer(arrayBuffer) is false.
+        debug_assert!(!self.is_shared_array_buffer());
+
+        // 2. If key is not present, set key to undefined.
+        // 3. If SameValue(arrayBuffer.[[ArrayBufferDetachKey]], key) is false, throw a TypeError exception.
+        if !JsValue::same_value(&self.array_buffer_detach_key, key) {
+            return context.throw_type_error("Cannot detach array buffer with different key");
+        }
+
+        // 4. Set arrayBuffer.[[ArrayBufferData]] to null.
+        // 5. Set arrayBuffer.[[ArrayBufferByteLength]] to 0.
+        self.array_buffer_byte_length = 0;
+
+        // 6. Return unused.
+        Ok(self.array_buffer_data.take())
+    }
+
     /// `25.1.2.4 CloneArrayBuffer ( srcBuffer, srcByteOffset, srcLength, cloneConstructor )`
     ///
     /// More information:
@@ -355,7 +904,7 @@ impl ArrayBuffer {
         context: &mut Context,
     ) -> JsResult<JsObject> {
         // 1. Let targetBuffer be ? AllocateArrayBuffer(cloneConstructor, srcLength).
-        let target_buffer = Self::allocate(clone_constructor, src_length, context)?;
+        let target_buffer = Self::allocate(clone_constructor, src_length, None, context)?;
 
         // 2. If IsDetachedBuffer(srcBuffer) is true, throw a TypeError exception.
         // 3. Let srcBlock be srcBuffer.[[ArrayBufferData]].
@@ -455,7 +1004,11 @@ impl ArrayBuffer {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-rawbytestonumeric
-    fn raw_bytes_to_numeric(t: TypedArrayKind, bytes: &[u8], is_little_endian: bool) -> JsValue {
+    pub(crate) fn raw_bytes_to_numeric(
+        t: TypedArrayKind,
+        bytes: &[u8],
+        is_little_endian: bool,
+    ) -> JsValue {
         let n: Numeric = match t {
             TypedArrayKind::Int8 => {
                 if is_little_endian {
@@ -573,19 +1126,27 @@ impl ArrayBuffer {
         // 4. Let elementSize be the Element Size value specified in Table 73 for Element Type type.
         let element_size = t.element_size();
 
-        // TODO: Shared Array Buffer
         // 5. If IsSharedArrayBuffer(arrayBuffer) is true, then
-
+        //     a. Let execution be the [[CandidateExecution]] field of the surrounding agent's Agent Record.
+        //     b. Let eventsRecord be the Agent Events Record of execution.[[EventsRecords]] whose [[AgentSignifier]] is AgentSignifier().
+        //     c. If isTypedArray is true and IsNoTearConfiguration(type, order) is true, let noTear be true; otherwise let noTear be false.
+        //     d. Let rawValue be a List of length elementSize whose elements are nondeterministically chosen byte values.
+        //     e. NOTE: In implementations, rawValue is the result of a non-atomic or atomic read instruction on the underlying hardware. The nondeterminism is a semantic prescription of the memory model to describe observable behaviour of hardware with weak consistency.
+        //     f. Let readEvent be ReadSharedMemory { [[Order]]: order, [[NoTear]]: noTear, [[Block]]: block, [[ByteIndex]]: byteIndex, [[ElementSize]]: elementSize }.
+        //     g. Append readEvent to eventsRecord.[[EventList]].
+        //     h. Append Chosen Value Record { [[Event]]: readEvent, [[ChosenValue]]: rawValue } to execution.[[ChosenValues]].
         // 6. Else, let rawValue be a List whose elements are bytes from block at indices byteIndex (inclusive) through byteIndex + elementSize (exclusive).
         // 7. Assert: The number of elements in rawValue is elementSize.
-        let raw_value = &block[byte_index..byte_index + element_size];
+        // NOTE: `SeqCst` reads of Shared Data Blocks are done while holding the critical section
+        // of the block, see `Atomics`.
+        let raw_value = block.bytes(byte_index..byte_index + element_size);
 
         // TODO: Agent Record [[LittleEndian]] filed
         // 8. If isLittleEndian is not present, set isLittleEndian to the value of the [[LittleEndian]] field of the surrounding agent's Agent Record.
         let is_little_endian = is_little_endian.unwrap_or(true);
 
         // 9. Return RawBytesToNumeric(type, rawValue, isLittleEndian).
-        Self::raw_bytes_to_numeric(t, raw_value, is_little_endian)
+        Self::raw_bytes_to_numeric(t, &raw_value, is_little_endian)
     }
 
     /// `25.1.2.11 NumericToRawBytes ( type, value, isLittleEndian )`
@@ -594,7 +1155,7 @@ impl ArrayBuffer {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-numerictorawbytes
-    fn numeric_to_raw_bytes(
+    pub(crate) fn numeric_to_raw_bytes(
         t: TypedArrayKind,
         value: &JsValue,
         is_little_endian: bool,
@@ -713,13 +1274,15 @@ impl ArrayBuffer {
         // 7. Let rawBytes be NumericToRawBytes(type, value, isLittleEndian).
         let raw_bytes = Self::numeric_to_raw_bytes(t, value, is_little_endian, context)?;
 
-        // TODO: Shared Array Buffer
         // 8. If IsSharedArrayBuffer(arrayBuffer) is true, then
-
+        //     a. Let execution be the [[CandidateExecution]] field of the surrounding agent's Agent Record.
+        //     b. Let eventsRecord be the Agent Events Record of execution.[[EventsRecords]] whose [[AgentSignifier]] is AgentSignifier().
+        //     c. If isTypedArray is true and IsNoTearConfiguration(type, order) is true, let noTear be true; otherwise let noTear be false.
+        //     d. Append WriteSharedMemory { [[Order]]: order, [[NoTear]]: noTear, [[Block]]: block, [[ByteIndex]]: byteIndex, [[ElementSize]]: elementSize, [[Payload]]: rawBytes } to eventsRecord.[[EventList]].
         // 9. Else, store the individual bytes of rawBytes into block, starting at block[byteIndex].
-        for (i, raw_byte) in raw_bytes.iter().enumerate() {
-            block[byte_index + i] = *raw_byte;
-        }
+        // NOTE: `SeqCst` writes to Shared Data Blocks are done while holding the critical section
+        // of the block, see `Atomics`.
+        block.write(byte_index, &raw_bytes);
 
         // 10. Return NormalCompletion(undefined).
         Ok(JsValue::undefined())
@@ -747,6 +1310,19 @@ pub fn create_byte_data_block(size: usize, context: &mut Context) -> JsResult<Ve
     Ok(data_block)
 }
 
+/// Resizes a Data Block to `size` bytes, zeroing any new byte.
+///
+/// Throws a `RangeError` if the block cannot be grown.
+fn resize_byte_data_block(block: &mut Vec<u8>, size: usize, context: &mut Context) -> JsResult<()> {
+    block
+        .try_reserve(size.saturating_sub(block.len()))
+        .map_err(|e| {
+            context.construct_range_error(format!("couldn't allocate the data block: {e}"))
+        })?;
+    block.resize(size, 0);
+    Ok(())
+}
+
 /// `6.2.8.3 CopyDataBlockBytes ( toBlock, toIndex, fromBlock, fromIndex, count )`
 ///
 /// More information:
@@ -754,11 +1330,11 @@ pub fn create_byte_data_block(size: usize, context: &mut Context) -> JsResult<Ve
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-copydatablockbytes
 fn copy_data_block_bytes(
-    to_block: &mut Vec<u8>,
-    mut to_index: usize,
-    from_block: &[u8],
-    mut from_index: usize,
-    mut count: usize,
+    to_block: &mut DataBlock,
+    to_index: usize,
+    from_block: &DataBlock,
+    from_index: usize,
+    count: usize,
 ) {
     // 1. Assert: fromBlock and toBlock are distinct values.
     // 2. Let fromSize be the number of bytes in fromBlock.
@@ -774,24 +1350,25 @@ fn copy_data_block_bytes(
     assert!(to_index + count <= to_size);
 
     // 6. Repeat, while count > 0,
-    while count > 0 {
-        // a. If fromBlock is a Shared Data Block, then
-        // TODO: Shared Data Block
-
-        // b. Else,
-        // i. Assert: toBlock is not a Shared Data Block.
-        // ii. Set toBlock[toIndex] to fromBlock[fromIndex].
-        to_block[to_index] = from_block[from_index];
-
-        // c. Set toIndex to toIndex + 1.
-        to_index += 1;
-
-        // d. Set fromIndex to fromIndex + 1.
-        from_index += 1;
-
-        // e. Set count to count - 1.
-        count -= 1;
-    }
+    //     a. If fromBlock is a Shared Data Block, then
+    //         i. Let execution be the [[CandidateExecution]] field of the surrounding agent's Agent Record.
+    //         ii. Let eventsRecord be the Agent Events Record of execution.[[EventsRecords]] whose [[AgentSignifier]] is AgentSignifier().
+    //         iii. Let bytes be a List whose sole element is a nondeterministically chosen byte value.
+    //         iv. NOTE: In implementations, bytes is the result of a non-atomic read instruction on the underlying hardware. The nondeterminism is a semantic prescription of the memory model to describe observable behaviour of hardware with weak consistency.
+    //         v. Let readEvent be ReadSharedMemory { [[Order]]: Unordered, [[NoTear]]: true, [[Block]]: fromBlock, [[ByteIndex]]: fromIndex, [[ElementSize]]: 1 }.
+    //         vi. Append readEvent to eventsRecord.[[EventList]].
+    //         vii. Append Chosen Value Record { [[Event]]: readEvent, [[ChosenValue]]: bytes } to execution.[[ChosenValues]].
+    //         viii. If toBlock is a Shared Data Block, then
+    //             1. Append WriteSharedMemory { [[Order]]: Unordered, [[NoTear]]: true, [[Block]]: toBlock, [[ByteIndex]]: toIndex, [[ElementSize]]: 1, [[Payload]]: bytes } to eventsRecord.[[EventList]].
+    //         ix. Else,
+    //             1. Set toBlock[toIndex] to bytes[0].
+    //     b. Else,
+    //         i. Assert: toBlock is not a Shared Data Block.
+    //         ii. Set toBlock[toIndex] to fromBlock[fromIndex].
+    //     c. Set toIndex to toIndex + 1.
+    //     d. Set fromIndex to fromIndex + 1.
+    //     e. Set count to count - 1.
+    to_block.write(to_index, &from_block.bytes(from_index..from_index + count));
 
     // 7. Return NormalCompletion(empty).
 }
@@ -0,0 +1,535 @@
+//! This module implements the global `SharedArrayBuffer` object, and the Shared Data Blocks
+//! backing it.
+//!
+//! A Shared Data Block is reference counted and thread safe, so it can be handed to a `Context`
+//! running on another thread, which can then create its own `SharedArrayBuffer` viewing the same
+//! memory with [`SharedArrayBuffer::create`].
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-sharedarraybuffer-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SharedArrayBuffer
+
+use crate::{
+    builtins::{
+        array_buffer::{ArrayBuffer, DataBlock},
+        BuiltIn, JsArgs,
+    },
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::IntegerOrInfinity,
+    Context, JsResult, JsValue,
+};
+use boa_profiler::Profiler;
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        Arc, Condvar, Mutex, MutexGuard, PoisonError,
+    },
+    time::{Duration, Instant},
+};
+use tap::{Conv, Pipe};
+
+/// A Shared Data Block, the `[[ArrayBufferData]]` of a `SharedArrayBuffer`.
+///
+/// Cloning a `SharedDataBlock` is cheap, and the clone refers to the same memory.
+#[derive(Clone)]
+pub struct SharedDataBlock {
+    inner: Arc<SharedDataBlockInner>,
+}
+
+struct SharedDataBlockInner {
+    bytes: Box<[AtomicU8]>,
+
+    /// Serializes the `Atomics` operations done on this block, and protects its waiter list.
+    critical_section: Mutex<WaiterList>,
+
+    /// Signaled when waiters of the block are notified.
+    condvar: Condvar,
+}
+
+/// The waiters of a Shared Data Block, in the order they started waiting.
+#[derive(Debug, Default)]
+pub(crate) struct WaiterList {
+    waiters: Vec<Waiter>,
+    next_id: u64,
+}
+
+#[derive(Debug)]
+struct Waiter {
+    id: u64,
+    byte_index: usize,
+    notified: bool,
+}
+
+/// The result of waiting on a Shared Data Block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WaitResult {
+    Ok,
+    TimedOut,
+}
+
+impl fmt::Debug for SharedDataBlock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedDataBlock")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl SharedDataBlock {
+    /// Creates a new Shared Data Block of `len` bytes, all set to zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the memory cannot be allocated.
+    #[must_use]
+    pub fn new(len: usize) -> Self {
+        Self::try_new(len).expect("couldn't allocate the shared data block")
+    }
+
+    /// Creates a new Shared Data Block of `len` bytes, all set to zero, or returns `None` if the
+    /// memory cannot be allocated.
+    #[must_use]
+    pub fn try_new(len: usize) -> Option<Self> {
+        let mut bytes = Vec::new();
+        bytes.try_reserve(len).ok()?;
+        bytes.resize_with(len, || AtomicU8::new(0));
+
+        Some(Self {
+            inner: Arc::new(SharedDataBlockInner {
+                bytes: bytes.into_boxed_slice(),
+                critical_section: Mutex::default(),
+                condvar: Condvar::new(),
+            }),
+        })
+    }
+
+    /// Returns the number of bytes of the block.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.inner.bytes.len()
+    }
+
+    /// Returns `true` if the block has no bytes.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.inner.bytes.is_empty()
+    }
+
+    /// Returns `true` if both blocks refer to the same memory.
+    #[inline]
+    #[must_use]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Copies the bytes of the block starting at `index` into `buf`.
+    ///
+    /// The read is not atomic: concurrent writes to the same bytes may be partially observed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bytes to read are out of the bounds of the block.
+    pub fn read(&self, index: usize, buf: &mut [u8]) {
+        for (byte, source) in buf
+            .iter_mut()
+            .zip(&self.inner.bytes[index..index + buf.len()])
+        {
+            *byte = source.load(Ordering::Relaxed);
+        }
+    }
+
+    /// Copies `bytes` into the block starting at `index`.
+    ///
+    /// The write is not atomic: concurrent reads of the same bytes may observe it partially.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bytes to write are out of the bounds of the block.
+    pub fn write(&self, index: usize, bytes: &[u8]) {
+        for (target, byte) in self.inner.bytes[index..index + bytes.len()]
+            .iter()
+            .zip(bytes)
+        {
+            target.store(*byte, Ordering::Relaxed);
+        }
+    }
+
+    /// Enters the critical section of the block.
+    ///
+    /// Every operation done while holding the returned guard is atomic with respect to the other
+    /// `Atomics` operations on this block.
+    pub(crate) fn lock(&self) -> MutexGuard<'_, WaiterList> {
+        // A panic while holding the lock cannot leave the waiter list in an invalid state.
+        self.inner
+            .critical_section
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Suspends the current thread until it is notified for `byte_index`, or `timeout` elapses.
+    ///
+    /// `guard` must be the critical section of this block, which is released while waiting.
+    pub(crate) fn wait(
+        &self,
+        mut guard: MutexGuard<'_, WaiterList>,
+        byte_index: usize,
+        timeout: Option<Duration>,
+    ) -> WaitResult {
+        let id = guard.next_id;
+        guard.next_id += 1;
+        guard.waiters.push(Waiter {
+            id,
+            byte_index,
+            notified: false,
+        });
+
+        let deadline = timeout.and_then(|timeout| Instant::now().checked_add(timeout));
+        loop {
+            let position = guard
+                .waiters
+                .iter()
+                .position(|waiter| waiter.id == id)
+                .expect("waiters are only removed by themselves");
+            if guard.waiters[position].notified {
+                guard.waiters.remove(position);
+                return WaitResult::Ok;
+            }
+
+            guard = match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        guard.waiters.remove(position);
+                        return WaitResult::TimedOut;
+                    }
+                    self.inner
+                        .condvar
+                        .wait_timeout(guard, deadline - now)
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .0
+                }
+                // There is no timeout, or it is too big to be represented and cannot elapse.
+                None => self
+                    .inner
+                    .condvar
+                    .wait(guard)
+                    .unwrap_or_else(PoisonError::into_inner),
+            };
+        }
+    }
+
+    /// Notifies at most `count` waiters waiting on `byte_index`, in the order they started
+    /// waiting, and returns the number of notified waiters.
+    pub(crate) fn notify(&self, byte_index: usize, count: Option<usize>) -> usize {
+        let mut guard = self.lock();
+        let count = count.unwrap_or(usize::MAX);
+        let mut notified = 0;
+        for waiter in guard
+            .waiters
+            .iter_mut()
+            .filter(|waiter| waiter.byte_index == byte_index && !waiter.notified)
+        {
+            if notified == count {
+                break;
+            }
+            waiter.notified = true;
+            notified += 1;
+        }
+        drop(guard);
+
+        if notified > 0 {
+            self.inner.condvar.notify_all();
+        }
+        notified
+    }
+}
+
+/// JavaScript `SharedArrayBuffer` built-in implementation.
+///
+/// `SharedArrayBuffer` objects are represented by an [`ArrayBuffer`] whose data is a
+/// [`DataBlock::Shared`].
+#[derive(Debug, Clone, Copy)]
+pub struct SharedArrayBuffer;
+
+impl BuiltIn for SharedArrayBuffer {
+    const NAME: &'static str = "SharedArrayBuffer";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let get_species = FunctionBuilder::native(context, Self::get_species)
+            .name("get [Symbol.species]")
+            .constructor(false)
+            .build();
+
+        let get_byte_length = FunctionBuilder::native(context, Self::byte_length)
+            .name("get byteLength")
+            .constructor(false)
+            .build();
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context
+                .intrinsics()
+                .constructors()
+                .shared_array_buffer()
+                .clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .static_accessor(
+            WellKnownSymbols::species(),
+            Some(get_species),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .accessor(
+            "byteLength",
+            Some(get_byte_length),
+            None,
+            Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+        )
+        .method(Self::slice, "slice", 2)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+impl SharedArrayBuffer {
+    const LENGTH: usize = 1;
+
+    /// Creates a new `SharedArrayBuffer` object viewing `data`.
+    ///
+    /// The block may come from a `SharedArrayBuffer` of another `Context`, possibly running on
+    /// another thread, see [`SharedArrayBuffer::data_block`].
+    pub fn create(data: SharedDataBlock, context: &mut Context) -> JsObject {
+        let prototype = context
+            .intrinsics()
+            .constructors()
+            .shared_array_buffer()
+            .prototype();
+
+        JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::array_buffer(ArrayBuffer {
+                array_buffer_byte_length: data.len(),
+                array_buffer_data: Some(DataBlock::Shared(data)),
+                array_buffer_max_byte_length: None,
+                array_buffer_detach_key: JsValue::Undefined,
+            }),
+        )
+    }
+
+    /// Returns the Shared Data Block of `object`, or `None` if it isn't a `SharedArrayBuffer`.
+    pub fn data_block(object: &JsObject) -> Option<SharedDataBlock> {
+        match object
+            .borrow()
+            .as_array_buffer()?
+            .array_buffer_data
+            .as_ref()?
+        {
+            DataBlock::Shared(data) => Some(data.clone()),
+            DataBlock::Owned(_) | DataBlock::External(_) => None,
+        }
+    }
+
+    /// `25.2.3.1 SharedArrayBuffer ( length )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sharedarraybuffer-length
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "SharedArrayBuffer.constructor called with undefined new target",
+            );
+        }
+
+        // 2. Let byteLength be ? ToIndex(length).
+        let byte_length = args.get_or_undefined(0).to_index(context)?;
+
+        // 3. Return ? AllocateSharedArrayBuffer(NewTarget, byteLength).
+        Ok(Self::allocate(new_target, byte_length, context)?.into())
+    }
+
+    /// `25.2.4.2 get SharedArrayBuffer [ @@species ]`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sharedarraybuffer-@@species
+    #[allow(clippy::unnecessary_wraps)]
+    fn get_species(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Return the this value.
+        Ok(this.clone())
+    }
+
+    /// `25.2.5.1 get SharedArrayBuffer.prototype.byteLength`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-sharedarraybuffer.prototype.bytelength
+    fn byte_length(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is false, throw a TypeError exception.
+        let data = this.as_object().and_then(Self::data_block).ok_or_else(|| {
+            context.construct_type_error(
+                "SharedArrayBuffer.prototype.byteLength called with invalid object",
+            )
+        })?;
+
+        // 4. Let length be O.[[ArrayBufferByteLength]].
+        // 5. Return 𝔽(length).
+        Ok(data.len().into())
+    }
+
+    /// `25.2.5.3 SharedArrayBuffer.prototype.slice ( start, end )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sharedarraybuffer.prototype.slice
+    fn slice(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[ArrayBufferData]]).
+        // 3. If IsSharedArrayBuffer(O) is false, throw a TypeError exception.
+        let obj = this.as_object().ok_or_else(|| {
+            context.construct_type_error("SharedArrayBuffer.slice called with non-object value")
+        })?;
+        let data = Self::data_block(obj).ok_or_else(|| {
+            context.construct_type_error("SharedArrayBuffer.slice called with invalid object")
+        })?;
+
+        // 4. Let len be O.[[ArrayBufferByteLength]].
+        let len = data.len() as i64;
+
+        // 5. Let relativeStart be ? ToIntegerOrInfinity(start).
+        let first = match args.get_or_undefined(0).to_integer_or_infinity(context)? {
+            // 6. If relativeStart is -∞, let first be 0.
+            IntegerOrInfinity::NegativeInfinity => 0,
+            // 7. Else if relativeStart < 0, let first be max(len + relativeStart, 0).
+            IntegerOrInfinity::Integer(i) if i < 0 => std::cmp::max(len + i, 0),
+            // 8. Else, let first be min(relativeStart, len).
+            IntegerOrInfinity::Integer(i) => std::cmp::min(i, len),
+            IntegerOrInfinity::PositiveInfinity => len,
+        };
+
+        // 9. If end is undefined, let relativeEnd be len; else let relativeEnd be ? ToIntegerOrInfinity(end).
+        let end = args.get_or_undefined(1);
+        let relative_end = if end.is_undefined() {
+            IntegerOrInfinity::Integer(len)
+        } else {
+            end.to_integer_or_infinity(context)?
+        };
+
+        let r#final = match relative_end {
+            // 10. If relativeEnd is -∞, let final be 0.
+            IntegerOrInfinity::NegativeInfinity => 0,
+            // 11. Else if relativeEnd < 0, let final be max(len + relativeEnd, 0).
+            IntegerOrInfinity::Integer(i) if i < 0 => std::cmp::max(len + i, 0),
+            // 12. Else, let final be min(relativeEnd, len).
+            IntegerOrInfinity::Integer(i) => std::cmp::min(i, len),
+            IntegerOrInfinity::PositiveInfinity => len,
+        };
+
+        // 13. Let newLen be max(final - first, 0).
+        let new_len = std::cmp::max(r#final - first, 0) as usize;
+
+        // 14. Let ctor be ? SpeciesConstructor(O, %SharedArrayBuffer%).
+        let ctor = obj.species_constructor(StandardConstructors::shared_array_buffer, context)?;
+
+        // 15. Let new be ? Construct(ctor, « 𝔽(newLen) »).
+        let new = ctor.construct(&[new_len.into()], &ctor.clone().into(), context)?;
+
+        // 16. Perform ? RequireInternalSlot(new, [[ArrayBufferData]]).
+        // 17. If IsSharedArrayBuffer(new) is false, throw a TypeError exception.
+        let new_data = new.as_object().and_then(Self::data_block).ok_or_else(|| {
+            context.construct_type_error("SharedArrayBuffer constructor returned invalid object")
+        })?;
+
+        // 18. If new.[[ArrayBufferData]] and O.[[ArrayBufferData]] are the same Shared Data Block values, throw a TypeError exception.
+        if new_data.ptr_eq(&data) {
+            return context
+                .throw_type_error("New SharedArrayBuffer is the same as this SharedArrayBuffer");
+        }
+
+        // 19. If new.[[ArrayBufferByteLength]] < newLen, throw a TypeError exception.
+        if new_data.len() < new_len {
+            return context.throw_type_error("New SharedArrayBuffer length too small");
+        }
+
+        // 20. Let fromBuf be O.[[ArrayBufferData]].
+        // 21. Let toBuf be new.[[ArrayBufferData]].
+        // 22. Perform CopyDataBlockBytes(toBuf, 0, fromBuf, first, newLen).
+        let mut bytes = vec![0; new_len];
+        data.read(first as usize, &mut bytes);
+        new_data.write(0, &bytes);
+
+        // 23. Return new.
+        Ok(new)
+    }
+
+    /// `25.2.2.1 AllocateSharedArrayBuffer ( constructor, byteLength )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-allocatesharedarraybuffer
+    pub(crate) fn allocate(
+        constructor: &JsValue,
+        byte_length: usize,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Let obj be ? OrdinaryCreateFromConstructor(constructor, "%SharedArrayBuffer.prototype%", « [[ArrayBufferData]], [[ArrayBufferByteLength]] »).
+        let prototype = get_prototype_from_constructor(
+            constructor,
+            StandardConstructors::shared_array_buffer,
+            context,
+        )?;
+
+        // 2. Let block be ? CreateSharedByteDataBlock(byteLength).
+        let block = SharedDataBlock::try_new(byte_length).ok_or_else(|| {
+            context.construct_range_error("couldn't allocate the shared data block")
+        })?;
+
+        // 3. Set obj.[[ArrayBufferData]] to block.
+        // 4. Set obj.[[ArrayBufferByteLength]] to byteLength.
+        // 5. Return obj.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::array_buffer(ArrayBuffer {
+                array_buffer_data: Some(DataBlock::Shared(block)),
+                array_buffer_byte_length: byte_length,
+                array_buffer_max_byte_length: None,
+                array_buffer_detach_key: JsValue::Undefined,
+            }),
+        ))
+    }
+}
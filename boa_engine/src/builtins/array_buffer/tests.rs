@@ -1,4 +1,5 @@
 use super::*;
+use crate::forward;
 
 #[test]
 fn ut_sunnyy_day_create_byte_data_block() {
@@ -13,3 +14,171 @@ fn ut_rainy_day_create_byte_data_block() {
 
     assert!(create_byte_data_block(usize::MAX, &mut context).is_err());
 }
+
+#[test]
+fn resizable_array_buffer() {
+    let mut context = Context::default();
+    let init = r#"
+        var buffer = new ArrayBuffer(2, { maxByteLength: 8 });
+        new Uint8Array(buffer).set([1, 2]);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "buffer.resizable"), "true");
+    assert_eq!(forward(&mut context, "buffer.maxByteLength"), "8");
+    assert_eq!(
+        forward(&mut context, "buffer.resize(4); buffer.byteLength"),
+        "4"
+    );
+    assert_eq!(
+        forward(&mut context, "new Uint8Array(buffer).join()"),
+        "\"1,2,0,0\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { buffer.resize(9); } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new ArrayBuffer(4).resize(2); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new ArrayBuffer(4, { maxByteLength: 2 }); } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "new ArrayBuffer(4).maxByteLength"),
+        "4"
+    );
+}
+
+#[test]
+fn transfer() {
+    let mut context = Context::default();
+    let init = r#"
+        var buffer = new ArrayBuffer(4, { maxByteLength: 8 });
+        new Uint8Array(buffer).set([1, 2, 3, 4]);
+        var moved = buffer.transfer(2);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "buffer.detached"), "true");
+    assert_eq!(forward(&mut context, "buffer.byteLength"), "0");
+    assert_eq!(
+        forward(&mut context, "new Uint8Array(moved).join()"),
+        "\"1,2\""
+    );
+    assert_eq!(forward(&mut context, "moved.resizable"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "let fixed = moved.transferToFixedLength(3); [fixed.resizable, fixed.byteLength]"
+        ),
+        "[ false, 3 ]"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { buffer.transfer(); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn slice_after_shrink() {
+    let mut context = Context::default();
+    let init = r#"
+        var buffer = new ArrayBuffer(4, { maxByteLength: 4 });
+        new Uint8Array(buffer).set([1, 2, 3, 4]);
+        buffer.constructor = {
+            [Symbol.species]: function (len) {
+                buffer.resize(2);
+                return new ArrayBuffer(len);
+            }
+        };
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "new Uint8Array(buffer.slice(1)).join()"),
+        "\"2,0,0\""
+    );
+}
+
+#[test]
+fn data_view_on_resizable_buffer() {
+    let mut context = Context::default();
+    let init = r#"
+        var buffer = new ArrayBuffer(4, { maxByteLength: 8 });
+        var tracking = new DataView(buffer, 2);
+        var fixed = new DataView(buffer, 0, 4);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "tracking.byteLength"), "2");
+    assert_eq!(
+        forward(
+            &mut context,
+            "buffer.resize(8); tracking.setUint8(5, 7); [tracking.byteLength, tracking.getUint8(5)]"
+        ),
+        "[ 6, 7 ]"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "buffer.resize(3); try { fixed.byteLength; } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { fixed.getUint8(0); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(forward(&mut context, "tracking.byteLength"), "1");
+}
+
+#[test]
+fn external_memory() {
+    use crate::{object::JsArrayBuffer, property::Attribute};
+
+    let mut context = Context::default();
+    let buffer = JsArrayBuffer::from_external(vec![1u8, 2, 3, 4], &mut context);
+    context.register_global_property("external", buffer.clone(), Attribute::all());
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Uint8Array(external).set([5, 6], 2); external.byteLength"
+        ),
+        "4"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { external.transfer(); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+
+    assert!(buffer.take_external::<Box<[u8]>>(&mut context).is_err());
+    assert!(buffer.take(&mut context).is_err());
+    assert_eq!(
+        buffer.take_external::<Vec<u8>>(&mut context).unwrap(),
+        [1, 2, 5, 6]
+    );
+    assert!(buffer.is_detached());
+    assert_eq!(forward(&mut context, "external.byteLength"), "0");
+
+    let owned = JsArrayBuffer::from_byte_block(vec![7; 3], &mut context);
+    assert_eq!(owned.byte_length(), 3);
+    assert_eq!(owned.take(&mut context).unwrap(), [7, 7, 7]);
+    assert!(owned.take(&mut context).is_err());
+}
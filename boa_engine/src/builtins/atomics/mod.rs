@@ -0,0 +1,696 @@
+//! This module implements the global `Atomics` object.
+//!
+//! The `Atomics` object provides atomic operations on the elements of integer typed arrays, and
+//! the `wait` and `notify` synchronization primitives for typed arrays viewing a
+//! `SharedArrayBuffer`.
+//!
+//! Atomic operations on a Shared Data Block are serialized by the critical section of the block,
+//! so they are atomic with respect to the atomic operations of every agent viewing the block.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-atomics-object
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics
+
+use crate::{
+    builtins::{
+        array_buffer::{
+            shared::{SharedDataBlock, WaitResult},
+            ArrayBuffer, DataBlock,
+        },
+        typed_array::{integer_indexed_object::ContentType, TypedArrayKind},
+        BuiltIn, JsArgs,
+    },
+    object::{JsObject, ObjectInitializer},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::IntegerOrInfinity,
+    Context, JsResult, JsValue,
+};
+use boa_profiler::Profiler;
+use std::time::Duration;
+use tap::{Conv, Pipe};
+
+#[cfg(test)]
+mod tests;
+
+/// Javascript `Atomics` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct Atomics;
+
+impl BuiltIn for Atomics {
+    const NAME: &'static str = "Atomics";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let to_string_tag = WellKnownSymbols::to_string_tag();
+
+        ObjectInitializer::new(context)
+            .function(Self::add, "add", 3)
+            .function(Self::and, "and", 3)
+            .function(Self::compare_exchange, "compareExchange", 4)
+            .function(Self::exchange, "exchange", 3)
+            .function(Self::is_lock_free, "isLockFree", 1)
+            .function(Self::load, "load", 2)
+            .function(Self::or, "or", 3)
+            .function(Self::store, "store", 3)
+            .function(Self::sub, "sub", 3)
+            .function(Self::wait, "wait", 4)
+            .function(Self::notify, "notify", 3)
+            .function(Self::xor, "xor", 3)
+            .property(
+                to_string_tag,
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build()
+            .conv::<JsValue>()
+            .pipe(Some)
+    }
+}
+
+/// An element of an integer typed array, validated for an atomic access.
+#[derive(Debug)]
+struct AtomicAccess {
+    /// The `[[ViewedArrayBuffer]]` of the typed array.
+    buffer: JsObject,
+    /// The element type of the typed array.
+    kind: TypedArrayKind,
+    /// The index of the element in the buffer.
+    byte_index: usize,
+}
+
+impl Atomics {
+    /// `Atomics.add ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.add
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/add
+    pub(crate) fn add(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let add be a new read-modify-write modification function with parameters (xBytes, yBytes) that captures typedArray and performs the following steps atomically when called:
+        //     a-i. Return the bytes of the sum of x and y, modulo 2^(8 × elementSize).
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, add).
+        Self::atomic_read_modify_write(args, u64::wrapping_add, context)
+    }
+
+    /// `Atomics.and ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.and
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/and
+    pub(crate) fn and(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let and be a new read-modify-write modification function with parameters (xBytes, yBytes) that captures nothing and performs the following steps atomically when called:
+        //     a. Return ByteListBitwiseOp(&, xBytes, yBytes).
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, and).
+        Self::atomic_read_modify_write(args, |x, y| x & y, context)
+    }
+
+    /// `Atomics.compareExchange ( typedArray, index, expectedValue, replacementValue )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.compareexchange
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/compareExchange
+    pub(crate) fn compare_exchange(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let byteIndexInBuffer be ? ValidateAtomicAccessOnIntegerTypedArray(typedArray, index).
+        let access = Self::validate_atomic_access_on_integer_typed_array(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            false,
+            context,
+        )?;
+
+        // 2. If typedArray.[[ContentType]] is BigInt, then
+        //     a. Let expected be ? ToBigInt(expectedValue).
+        //     b. Let replacement be ? ToBigInt(replacementValue).
+        // 3. Else,
+        //     a. Let expected be 𝔽(? ToIntegerOrInfinity(expectedValue)).
+        //     b. Let replacement be 𝔽(? ToIntegerOrInfinity(replacementValue)).
+        let expected = Self::to_element_value(access.kind, args.get_or_undefined(2), context)?;
+        let replacement = Self::to_element_value(access.kind, args.get_or_undefined(3), context)?;
+
+        // 4. Perform ? RevalidateAtomicAccess(typedArray, byteIndexInBuffer).
+        Self::revalidate_atomic_access(&access, context)?;
+
+        // 5. Let elementType be TypedArrayElementType(typedArray).
+        // 6. Let elementSize be TypedArrayElementSize(typedArray).
+        // 7. Let isLittleEndian be the value of the [[LittleEndian]] field of the surrounding agent's Agent Record.
+        // 8. Let expectedBytes be NumericToRawBytes(elementType, expected, isLittleEndian).
+        // 9. Let replacementBytes be NumericToRawBytes(elementType, replacement, isLittleEndian).
+        let expected = ArrayBuffer::numeric_to_raw_bytes(access.kind, &expected, true, context)?;
+        let replacement =
+            ArrayBuffer::numeric_to_raw_bytes(access.kind, &replacement, true, context)?;
+
+        // 10. If IsSharedArrayBuffer(buffer) is true, then
+        //     a. Let rawBytesRead be AtomicCompareExchangeInSharedBlock(block, byteIndexInBuffer, elementSize, expectedBytes, replacementBytes).
+        // 11. Else,
+        //     a. Let rawBytesRead be a List of length elementSize whose elements are the sequence of elementSize bytes starting with block[byteIndexInBuffer].
+        //     b. If ByteListEqual(rawBytesRead, expectedBytes) is true, then
+        //         i. Store the individual bytes of replacementBytes into block, starting at block[byteIndexInBuffer].
+        let raw_bytes_read = Self::get_modify_set_value(&access, |old| {
+            if old == expected {
+                Some(replacement)
+            } else {
+                None
+            }
+        });
+
+        // 12. Return RawBytesToNumeric(elementType, rawBytesRead, isLittleEndian).
+        Ok(ArrayBuffer::raw_bytes_to_numeric(
+            access.kind,
+            &raw_bytes_read,
+            true,
+        ))
+    }
+
+    /// `Atomics.exchange ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.exchange
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/exchange
+    pub(crate) fn exchange(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let second be a new read-modify-write modification function with parameters (oldBytes, newBytes) that captures nothing and performs the following steps atomically when called:
+        //     a. Return newBytes.
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, second).
+        Self::atomic_read_modify_write(args, |_, y| y, context)
+    }
+
+    /// `Atomics.isLockFree ( size )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.islockfree
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/isLockFree
+    pub(crate) fn is_lock_free(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let n be ? ToIntegerOrInfinity(size).
+        let n = args.get_or_undefined(0).to_integer_or_infinity(context)?;
+
+        // 2. Let AR be the Agent Record of the surrounding agent.
+        // 3. If n = 1, return AR.[[IsLockFree1]].
+        // 4. If n = 2, return AR.[[IsLockFree2]].
+        // 5. If n = 4, return true.
+        // 6. If n = 8, return AR.[[IsLockFree8]].
+        // 7. Return false.
+        // NOTE: Every atomic operation goes through the critical section of the Shared Data
+        // Block, so `[[IsLockFree1]]`, `[[IsLockFree2]]` and `[[IsLockFree8]]` are false.
+        Ok((n == 4).into())
+    }
+
+    /// `Atomics.load ( typedArray, index )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.load
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/load
+    pub(crate) fn load(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let byteIndexInBuffer be ? ValidateAtomicAccessOnIntegerTypedArray(typedArray, index).
+        let access = Self::validate_atomic_access_on_integer_typed_array(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            false,
+            context,
+        )?;
+
+        // 2. Perform ? RevalidateAtomicAccess(typedArray, byteIndexInBuffer).
+        Self::revalidate_atomic_access(&access, context)?;
+
+        // 3. Let buffer be typedArray.[[ViewedArrayBuffer]].
+        // 4. Let elementType be TypedArrayElementType(typedArray).
+        // 5. Return GetValueFromBuffer(buffer, byteIndexInBuffer, elementType, true, SeqCst).
+        let raw_value = Self::get_modify_set_value(&access, |_| None);
+        Ok(ArrayBuffer::raw_bytes_to_numeric(
+            access.kind,
+            &raw_value,
+            true,
+        ))
+    }
+
+    /// `Atomics.or ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.or
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/or
+    pub(crate) fn or(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let or be a new read-modify-write modification function with parameters (xBytes, yBytes) that captures nothing and performs the following steps atomically when called:
+        //     a. Return ByteListBitwiseOp(|, xBytes, yBytes).
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, or).
+        Self::atomic_read_modify_write(args, |x, y| x | y, context)
+    }
+
+    /// `Atomics.store ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.store
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/store
+    pub(crate) fn store(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let byteIndexInBuffer be ? ValidateAtomicAccessOnIntegerTypedArray(typedArray, index).
+        let access = Self::validate_atomic_access_on_integer_typed_array(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            false,
+            context,
+        )?;
+
+        // 2. If typedArray.[[ContentType]] is BigInt, let v be ? ToBigInt(value).
+        // 3. Otherwise, let v be 𝔽(? ToIntegerOrInfinity(value)).
+        let v = Self::to_element_value(access.kind, args.get_or_undefined(2), context)?;
+
+        // 4. Perform ? RevalidateAtomicAccess(typedArray, byteIndexInBuffer).
+        Self::revalidate_atomic_access(&access, context)?;
+
+        // 5. Let buffer be typedArray.[[ViewedArrayBuffer]].
+        // 6. Let elementType be TypedArrayElementType(typedArray).
+        // 7. Perform SetValueInBuffer(buffer, byteIndexInBuffer, elementType, v, true, SeqCst).
+        let raw_bytes = ArrayBuffer::numeric_to_raw_bytes(access.kind, &v, true, context)?;
+        Self::get_modify_set_value(&access, |_| Some(raw_bytes));
+
+        // 8. Return v.
+        Ok(v)
+    }
+
+    /// `Atomics.sub ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.sub
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/sub
+    pub(crate) fn sub(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let subtract be a new read-modify-write modification function with parameters (xBytes, yBytes) that captures typedArray and performs the following steps atomically when called:
+        //     a-i. Return the bytes of the difference of x and y, modulo 2^(8 × elementSize).
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, subtract).
+        Self::atomic_read_modify_write(args, u64::wrapping_sub, context)
+    }
+
+    /// `Atomics.wait ( typedArray, index, value, timeout )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.wait
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/wait
+    pub(crate) fn wait(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? DoWait(sync, typedArray, index, value, timeout).
+
+        // DoWait ( mode, typedArray, index, value, timeout )
+        // 1. Let taRecord be ? ValidateIntegerTypedArray(typedArray, true).
+        // 2. Let buffer be taRecord.[[Object]].[[ViewedArrayBuffer]].
+        // 3. If IsSharedArrayBuffer(buffer) is false, throw a TypeError exception.
+        // 4. Let i be ? ValidateAtomicAccess(taRecord, index).
+        let access = Self::validate_atomic_access_on_integer_typed_array(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            true,
+            context,
+        )?;
+        let block = Self::shared_data_block(&access).ok_or_else(|| {
+            context.construct_type_error("Atomics.wait called on a non-shared typed array")
+        })?;
+
+        // 5. Let arrayTypeName be typedArray.[[TypedArrayName]].
+        // 6. If arrayTypeName is "BigInt64Array", let v be ? ToBigInt64(value).
+        // 7. Else, let v be ? ToInt32(value).
+        let v = ArrayBuffer::numeric_to_raw_bytes(
+            access.kind,
+            args.get_or_undefined(2),
+            true,
+            context,
+        )?;
+
+        // 8. Let q be ? ToNumber(timeout).
+        let q = args.get_or_undefined(3).to_number(context)?;
+
+        // 9. If q is either NaN or +∞𝔽, let t be +∞; else if q is -∞𝔽, let t be 0; else let t be max(ℝ(q), 0).
+        // NOTE: Timeouts longer than about 30000 years are considered infinite.
+        let t = if q.is_nan() || q >= 1e15 {
+            None
+        } else {
+            Some(Duration::from_secs_f64(q.max(0.0) / 1000.0))
+        };
+
+        // 10. If mode is sync and AgentCanSuspend() is false, throw a TypeError exception.
+        // 11. Let block be buffer.[[ArrayBufferData]].
+        // 12. Let offset be typedArray.[[ByteOffset]].
+        // 13. Let byteIndexInBuffer be (i × 4) + offset.
+        // 14. Let WL be GetWaiterList(block, byteIndexInBuffer).
+        // 18. Perform EnterCriticalSection(WL).
+        let guard = block.lock();
+
+        // 19. Let elementType be TypedArrayElementType(typedArray).
+        // 20. Let w be GetValueFromBuffer(buffer, byteIndexInBuffer, elementType, true, SeqCst).
+        let mut w = vec![0; access.kind.element_size()];
+        block.read(access.byte_index, &mut w);
+
+        // 21. If v ≠ w, then
+        if v != w {
+            // a. Perform LeaveCriticalSection(WL).
+            drop(guard);
+
+            // b. If mode is sync, return "not-equal".
+            return Ok("not-equal".into());
+        }
+
+        // 22. Let W be AgentSignifier().
+        // 23. Let waiterRecord be a new Waiter Record { [[AgentSignifier]]: W, [[PromiseCapability]]: promiseCapability, [[TimeoutTime]]: timeoutTime, [[Result]]: "ok" }.
+        // 24. Perform AddWaiter(WL, waiterRecord).
+        // 25. If mode is sync, then
+        //     a. Perform SuspendThisAgent(WL, waiterRecord).
+        // 27. Perform LeaveCriticalSection(WL).
+        // 28. If mode is sync, return waiterRecord.[[Result]].
+        match block.wait(guard, access.byte_index, t) {
+            WaitResult::Ok => Ok("ok".into()),
+            WaitResult::TimedOut => Ok("timed-out".into()),
+        }
+    }
+
+    /// `Atomics.notify ( typedArray, index, count )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.notify
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/notify
+    pub(crate) fn notify(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let byteIndexInBuffer be ? ValidateAtomicAccessOnIntegerTypedArray(typedArray, index, true).
+        let access = Self::validate_atomic_access_on_integer_typed_array(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            true,
+            context,
+        )?;
+
+        // 2. If count is undefined, then
+        let count = if args.get_or_undefined(2).is_undefined() {
+            // a. Let c be +∞.
+            None
+        }
+        // 3. Else,
+        else {
+            // a. Let intCount be ? ToIntegerOrInfinity(count).
+            // b. Let c be max(intCount, 0).
+            match args.get_or_undefined(2).to_integer_or_infinity(context)? {
+                IntegerOrInfinity::PositiveInfinity => None,
+                IntegerOrInfinity::Integer(i) => Some(i.max(0) as usize),
+                IntegerOrInfinity::NegativeInfinity => Some(0),
+            }
+        };
+
+        // 4. Let buffer be typedArray.[[ViewedArrayBuffer]].
+        // 5. Let block be buffer.[[ArrayBufferData]].
+        // 6. If IsSharedArrayBuffer(buffer) is false, return +0𝔽.
+        let block = if let Some(block) = Self::shared_data_block(&access) {
+            block
+        } else {
+            return Ok(0.into());
+        };
+
+        // 7. Let WL be GetWaiterList(block, byteIndexInBuffer).
+        // 8. Perform EnterCriticalSection(WL).
+        // 9. Let S be RemoveWaiters(WL, c).
+        // 10. For each element W of S, do
+        //     a. Perform NotifyWaiter(WL, W).
+        // 11. Perform LeaveCriticalSection(WL).
+        // 12. Let n be the number of elements in S.
+        let n = block.notify(access.byte_index, count);
+
+        // 13. Return 𝔽(n).
+        Ok(n.into())
+    }
+
+    /// `Atomics.xor ( typedArray, index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.xor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics/xor
+    pub(crate) fn xor(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let xor be a new read-modify-write modification function with parameters (xBytes, yBytes) that captures nothing and performs the following steps atomically when called:
+        //     a. Return ByteListBitwiseOp(^, xBytes, yBytes).
+        // 2. Return ? AtomicReadModifyWrite(typedArray, index, value, xor).
+        Self::atomic_read_modify_write(args, |x, y| x ^ y, context)
+    }
+
+    /// Abstract operation `ValidateIntegerTypedArray ( typedArray, waitable )`, followed by
+    /// `ValidateAtomicAccess ( taRecord, requestIndex )`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-validateatomicaccessonintegertypedarray
+    fn validate_atomic_access_on_integer_typed_array(
+        typed_array: &JsValue,
+        request_index: &JsValue,
+        waitable: bool,
+        context: &mut Context,
+    ) -> JsResult<AtomicAccess> {
+        // ValidateIntegerTypedArray ( typedArray, waitable )
+        // 1. Let taRecord be ? ValidateTypedArray(typedArray, Unordered).
+        let (buffer, kind, byte_offset, length) = {
+            let obj = typed_array
+                .as_object()
+                .map(JsObject::borrow)
+                .filter(|obj| obj.is_typed_array())
+                .ok_or_else(|| context.construct_type_error("value is not a typed array"))?;
+            let o = obj
+                .as_typed_array()
+                .expect("checked that it is a typed array");
+            if o.is_out_of_bounds() {
+                return context
+                    .throw_type_error("Buffer of the typed array is detached or out of bounds");
+            }
+
+            (
+                o.viewed_array_buffer()
+                    .expect("Already checked for detached buffer")
+                    .clone(),
+                o.typed_array_name(),
+                o.byte_offset(),
+                o.array_length(),
+            )
+        };
+
+        // 2. NOTE: Bounds checking is not a synchronizing operation when typedArray's backing buffer is a growable SharedArrayBuffer.
+        // 3. If waitable is true, then
+        if waitable {
+            // a. If typedArray.[[TypedArrayName]] is neither "Int32Array" nor "BigInt64Array", throw a TypeError exception.
+            if !matches!(kind, TypedArrayKind::Int32 | TypedArrayKind::BigInt64) {
+                return context
+                    .throw_type_error("waiting is only supported on Int32Array and BigInt64Array");
+            }
+        }
+        // 4. Else,
+        else {
+            // a. Let type be TypedArrayElementType(typedArray).
+            // b. If IsUnclampedIntegerElementType(type) is false and IsBigIntElementType(type) is false, throw a TypeError exception.
+            if matches!(
+                kind,
+                TypedArrayKind::Uint8Clamped | TypedArrayKind::Float32 | TypedArrayKind::Float64
+            ) {
+                return context.throw_type_error(
+                    "atomic operations are only supported on integer typed arrays",
+                );
+            }
+        }
+
+        // ValidateAtomicAccess ( taRecord, requestIndex )
+        // 1. Let length be TypedArrayLength(taRecord).
+        // 2. Let accessIndex be ? ToIndex(requestIndex).
+        let access_index = request_index.to_index(context)?;
+
+        // 3. Assert: accessIndex ≥ 0.
+        // 4. If accessIndex ≥ length, throw a RangeError exception.
+        if access_index >= length {
+            return context.throw_range_error("index out of range of the typed array");
+        }
+
+        // 5. Let typedArray be taRecord.[[Object]].
+        // 6. Let elementSize be TypedArrayElementSize(typedArray).
+        // 7. Let offset be typedArray.[[ByteOffset]].
+        // 8. Return (accessIndex × elementSize) + offset.
+        Ok(AtomicAccess {
+            buffer,
+            kind,
+            byte_index: access_index * kind.element_size() + byte_offset,
+        })
+    }
+
+    /// Abstract operation `RevalidateAtomicAccess ( typedArray, byteIndexInBuffer )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-revalidateatomicaccess
+    fn revalidate_atomic_access(access: &AtomicAccess, context: &mut Context) -> JsResult<()> {
+        // 1. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(typedArray, Unordered).
+        // 2. NOTE: Bounds checking is not a synchronizing operation when typedArray's backing buffer is a growable SharedArrayBuffer.
+        // 3. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+        let buffer_byte_length = match access.buffer.borrow().as_array_buffer() {
+            Some(buffer) if !buffer.is_detached_buffer() => buffer.array_buffer_byte_length(),
+            _ => return context.throw_type_error("Buffer of the typed array is detached"),
+        };
+
+        // 4. Assert: byteIndexInBuffer ≥ typedArray.[[ByteOffset]].
+        // 5. If byteIndexInBuffer ≥ taRecord.[[CachedBufferByteLength]], throw a RangeError exception.
+        if access.byte_index + access.kind.element_size() > buffer_byte_length {
+            return context.throw_range_error("index out of range of the typed array");
+        }
+
+        // 6. Return unused.
+        Ok(())
+    }
+
+    /// Abstract operation `AtomicReadModifyWrite ( typedArray, index, value, op )`
+    ///
+    /// `op` is applied to the little endian integers represented by the bytes of the element
+    /// and the bytes of `value`, and only the low bytes of its result are stored.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomicreadmodifywrite
+    fn atomic_read_modify_write(
+        args: &[JsValue],
+        op: fn(u64, u64) -> u64,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let byteIndexInBuffer be ? ValidateAtomicAccessOnIntegerTypedArray(typedArray, index).
+        let access = Self::validate_atomic_access_on_integer_typed_array(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            false,
+            context,
+        )?;
+
+        // 2. If typedArray.[[ContentType]] is BigInt, let v be ? ToBigInt(value).
+        // 3. Otherwise, let v be 𝔽(? ToIntegerOrInfinity(value)).
+        let v = Self::to_element_value(access.kind, args.get_or_undefined(2), context)?;
+
+        // 4. Perform ? RevalidateAtomicAccess(typedArray, byteIndexInBuffer).
+        Self::revalidate_atomic_access(&access, context)?;
+
+        // 5. Let buffer be typedArray.[[ViewedArrayBuffer]].
+        // 6. Let elementType be TypedArrayElementType(typedArray).
+        // 7. Return GetModifySetValueInBuffer(buffer, byteIndexInBuffer, elementType, v, op).
+        let raw_bytes = ArrayBuffer::numeric_to_raw_bytes(access.kind, &v, true, context)?;
+        let raw_bytes_read = Self::get_modify_set_value(&access, |old| {
+            let mut x = [0; 8];
+            x[..old.len()].copy_from_slice(old);
+            let mut y = [0; 8];
+            y[..raw_bytes.len()].copy_from_slice(&raw_bytes);
+
+            let result = op(u64::from_le_bytes(x), u64::from_le_bytes(y)).to_le_bytes();
+            Some(result[..old.len()].to_vec())
+        });
+
+        Ok(ArrayBuffer::raw_bytes_to_numeric(
+            access.kind,
+            &raw_bytes_read,
+            true,
+        ))
+    }
+
+    /// Converts `value` to the numeric type of the elements of kind `kind`, without wrapping it
+    /// to the range of the element type.
+    fn to_element_value(
+        kind: TypedArrayKind,
+        value: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        if kind.content_type() == ContentType::BigInt {
+            // If typedArray.[[ContentType]] is BigInt, let v be ? ToBigInt(value).
+            Ok(value.to_bigint(context)?.into())
+        } else {
+            // Otherwise, let v be 𝔽(? ToIntegerOrInfinity(value)).
+            Ok(match value.to_integer_or_infinity(context)? {
+                IntegerOrInfinity::Integer(i) => JsValue::new(i as f64),
+                IntegerOrInfinity::PositiveInfinity => JsValue::new(f64::INFINITY),
+                IntegerOrInfinity::NegativeInfinity => JsValue::new(f64::NEG_INFINITY),
+            })
+        }
+    }
+
+    /// Returns the Shared Data Block viewed by `access`, if any.
+    fn shared_data_block(access: &AtomicAccess) -> Option<SharedDataBlock> {
+        match access
+            .buffer
+            .borrow()
+            .as_array_buffer()?
+            .array_buffer_data
+            .as_ref()?
+        {
+            DataBlock::Shared(block) => Some(block.clone()),
+            DataBlock::Owned(_) | DataBlock::External(_) => None,
+        }
+    }
+
+    /// Atomically replaces the raw bytes of the element of `access` with the result of `modify`,
+    /// if any, and returns the previous raw bytes.
+    ///
+    /// This is the `GetModifySetValueInBuffer` abstract operation, and also implements the
+    /// `SeqCst` reads and writes of `GetValueFromBuffer` and `SetValueInBuffer`.
+    fn get_modify_set_value(
+        access: &AtomicAccess,
+        modify: impl FnOnce(&[u8]) -> Option<Vec<u8>>,
+    ) -> Vec<u8> {
+        let shared = Self::shared_data_block(access);
+        let _critical_section = shared.as_ref().map(SharedDataBlock::lock);
+
+        let mut buffer = access.buffer.borrow_mut();
+        let block = buffer
+            .as_array_buffer_mut()
+            .and_then(|buffer| buffer.array_buffer_data.as_mut())
+            .expect("Already checked for detached buffer");
+
+        let range = access.byte_index..access.byte_index + access.kind.element_size();
+        let raw_bytes_read = block.bytes(range).into_owned();
+        if let Some(raw_bytes) = modify(&raw_bytes_read) {
+            block.write(access.byte_index, &raw_bytes);
+        }
+        raw_bytes_read
+    }
+}
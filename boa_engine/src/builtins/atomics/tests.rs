@@ -0,0 +1,174 @@
+use crate::{
+    builtins::array_buffer::shared::SharedArrayBuffer, forward, property::Attribute, Context,
+};
+
+#[test]
+fn read_modify_write() {
+    let mut context = Context::default();
+    let init = r#"
+        var ta = new Int8Array(new SharedArrayBuffer(4));
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "Atomics.store(ta, 0, 3.7)"), "3");
+    assert_eq!(forward(&mut context, "Atomics.add(ta, 0, 126)"), "3");
+    assert_eq!(forward(&mut context, "Atomics.load(ta, 0)"), "-127");
+    assert_eq!(forward(&mut context, "Atomics.sub(ta, 0, 2)"), "-127");
+    assert_eq!(forward(&mut context, "Atomics.load(ta, 0)"), "127");
+    assert_eq!(forward(&mut context, "Atomics.and(ta, 0, 0b1010)"), "127");
+    assert_eq!(forward(&mut context, "Atomics.or(ta, 0, 0b0101)"), "10");
+    assert_eq!(forward(&mut context, "Atomics.xor(ta, 0, 0b0011)"), "15");
+    assert_eq!(forward(&mut context, "Atomics.exchange(ta, 0, 1)"), "12");
+    assert_eq!(
+        forward(&mut context, "Atomics.compareExchange(ta, 0, 2, 5)"),
+        "1"
+    );
+    assert_eq!(
+        forward(&mut context, "Atomics.compareExchange(ta, 0, 1, 5)"),
+        "1"
+    );
+    assert_eq!(forward(&mut context, "ta[0]"), "5");
+}
+
+#[test]
+fn non_shared_and_bigint_arrays() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(
+            &mut context,
+            "let u16 = new Uint16Array(2); Atomics.add(u16, 1, 65537); u16[1]"
+        ),
+        "1"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "let big = new BigInt64Array(1); Atomics.sub(big, 0, 1n); Atomics.load(big, 0)"
+        ),
+        "-1n"
+    );
+    assert_eq!(
+        forward(&mut context, "Atomics.notify(new Int32Array(1), 0)"),
+        "0"
+    );
+}
+
+#[test]
+fn validation() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Atomics.add(new Float64Array(1), 0, 1); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Atomics.load(new Int32Array(1), 1); } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Atomics.wait(new Int32Array(4), 0, 0, 0); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Atomics.wait(new Int16Array(new SharedArrayBuffer(4)), 0, 0, 0); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "[1, 2, 4, 8].map(Atomics.isLockFree)"),
+        "[ false, false, true, false ]"
+    );
+}
+
+#[test]
+fn wait_and_notify() {
+    let mut context = Context::default();
+    let init = r#"
+        var ta = new Int32Array(new SharedArrayBuffer(8));
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "Atomics.wait(ta, 0, 1)"),
+        "\"not-equal\""
+    );
+    assert_eq!(
+        forward(&mut context, "Atomics.wait(ta, 0, 0, 0)"),
+        "\"timed-out\""
+    );
+    assert_eq!(forward(&mut context, "Atomics.notify(ta, 0, 1)"), "0");
+}
+
+#[test]
+fn shared_array_buffer() {
+    let mut context = Context::default();
+    let init = r#"
+        var sab = new SharedArrayBuffer(4);
+        new Uint8Array(sab).set([1, 2, 3, 4]);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "sab.byteLength"), "4");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(sab)"),
+        "\"[object SharedArrayBuffer]\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Uint8Array(sab.slice(1, 3)).join()"),
+        "\"2,3\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { ArrayBuffer.prototype.slice.call(sab); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "new DataView(sab).getUint16(0, true)"),
+        "513"
+    );
+}
+
+#[test]
+fn shared_between_threads() {
+    let mut context = Context::default();
+    forward(&mut context, "var sab = new SharedArrayBuffer(4);");
+    let sab = context
+        .global_object()
+        .clone()
+        .get("sab", &mut context)
+        .expect("sab is defined");
+    let block = SharedArrayBuffer::data_block(sab.as_object().expect("sab is an object"))
+        .expect("sab is a SharedArrayBuffer");
+
+    let threads: Vec<_> = (0..2)
+        .map(|_| {
+            let block = block.clone();
+            std::thread::spawn(move || {
+                let mut context = Context::default();
+                let sab = SharedArrayBuffer::create(block, &mut context);
+                context.register_global_property("sab", sab, Attribute::all());
+                forward(
+                    &mut context,
+                    "let ta = new Int32Array(sab); for (let i = 0; i < 1000; i++) Atomics.add(ta, 0, 1);",
+                );
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().expect("thread panicked");
+    }
+
+    assert_eq!(
+        forward(&mut context, "Atomics.load(new Int32Array(sab), 0)"),
+        "2000"
+    );
+}
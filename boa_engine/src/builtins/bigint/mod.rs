@@ -47,6 +47,7 @@ impl BuiltIn for BigInt {
         .name(Self::NAME)
         .length(Self::LENGTH)
         .method(Self::to_string, "toString", 0)
+        .method(Self::to_locale_string, "toLocaleString", 0)
         .method(Self::value_of, "valueOf", 0)
         .static_method(Self::as_int_n, "asIntN", 2)
         .static_method(Self::as_uint_n, "asUintN", 2)
@@ -190,6 +191,58 @@ impl BigInt {
         Ok(JsValue::new(x.to_string_radix(radix_mv as u32)))
     }
 
+    /// `BigInt.prototype.toLocaleString( [ locales [ , options ] ] )`
+    ///
+    /// The `toLocaleString()` method returns a string with a language-sensitive representation of this `BigInt`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sup-bigint.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt/toLocaleString
+    #[cfg(feature = "intl")]
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn to_locale_string(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        use crate::builtins::intl::NumberFormat;
+
+        // 1. Let x be ? thisBigIntValue(this value).
+        let x = Self::this_bigint_value(this, context)?;
+
+        // 2. Let numberFormat be ? Construct(%NumberFormat%, « locales, options »).
+        let number_format =
+            NumberFormat::new(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+
+        // 3. Return ? FormatNumeric(numberFormat, ℝ(x)).
+        Ok(number_format.format_bigint(&x).into())
+    }
+
+    /// `BigInt.prototype.toLocaleString( [ locales [ , options ] ] )`
+    ///
+    /// Without the `intl` feature there is no locale data, so the `BigInt` is formatted as with
+    /// `toString`, ignoring the arguments.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-bigint.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt/toLocaleString
+    #[cfg(not(feature = "intl"))]
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn to_locale_string(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let x = Self::this_bigint_value(this, context)?;
+        Ok(JsValue::new(x.to_string()))
+    }
+
     /// `BigInt.prototype.valueOf()`
     ///
     /// The `valueOf()` method returns the wrapped primitive value of a Number object.
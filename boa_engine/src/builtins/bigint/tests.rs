@@ -247,6 +247,39 @@ fn to_string() {
     assert_eq!(forward(&mut context, "1000n.toString(36)"), "\"rs\"");
 }
 
+#[cfg(feature = "intl")]
+#[test]
+fn to_locale_string() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "123456789012345678901234567890n.toLocaleString()"
+        ),
+        "\"123,456,789,012,345,678,901,234,567,890\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "(-5n).toLocaleString('en', { minimumFractionDigits: 2 })"
+        ),
+        "\"-5.00\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "3n.toLocaleString('en', { style: 'percent', useGrouping: false })"
+        ),
+        "\"300%\""
+    );
+    assert_throws(
+        &mut context,
+        "1n.toLocaleString('en', { style: 'currency' })",
+        "TypeError",
+    );
+}
+
 #[test]
 fn to_string_invalid_radix() {
     let mut context = Context::default();
@@ -411,3 +444,38 @@ fn remainder_by_zero() {
     let mut context = Context::default();
     assert_throws(&mut context, "1n % 0n", "RangeError");
 }
+
+#[test]
+fn host_conversions() {
+    use crate::{bigint::RawBigInt, JsBigInt};
+
+    let id = JsBigInt::from(u64::MAX);
+    assert_eq!(id.to_u64(), Some(u64::MAX));
+    assert_eq!(id.to_i64(), None);
+    assert_eq!(JsBigInt::from(-1).to_u64(), None);
+    assert_eq!(JsBigInt::from(i64::MIN).to_i64(), Some(i64::MIN));
+
+    let wide = JsBigInt::from(i128::MIN);
+    assert_eq!(wide.to_i128(), Some(i128::MIN));
+    assert_eq!(wide.to_u128(), None);
+    assert_eq!(JsBigInt::from(u128::MAX).to_u128(), Some(u128::MAX));
+
+    let raw = RawBigInt::from(wide.clone());
+    assert_eq!(JsBigInt::from(raw), wide);
+
+    assert_eq!(JsBigInt::from(-2).to_signed_bytes_le(), [0xfe]);
+    assert_eq!(JsBigInt::from(256).to_signed_bytes_be(), [0x01, 0x00]);
+    assert_eq!(
+        JsBigInt::from_signed_bytes_le(&[0x00, 0x80]),
+        JsBigInt::from(-32768)
+    );
+    assert_eq!(
+        JsBigInt::from_signed_bytes_be(&[0x00, 0xff]),
+        JsBigInt::from(255)
+    );
+    assert_eq!(JsBigInt::from_signed_bytes_le(&[]), JsBigInt::zero());
+    assert_eq!(
+        JsBigInt::from_signed_bytes_be(&wide.to_signed_bytes_be()),
+        wide
+    );
+}
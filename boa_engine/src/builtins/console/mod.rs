@@ -19,12 +19,12 @@ mod tests;
 use crate::{
     builtins::{BuiltIn, JsArgs},
     object::ObjectInitializer,
-    value::{display::display_obj, JsValue, Numeric},
+    value::{JsValue, Numeric, ValueFormatter},
     Context, JsResult, JsString,
 };
 use boa_profiler::Profiler;
+use chrono::Utc;
 use rustc_hash::FxHashMap;
-use std::time::SystemTime;
 use tap::{Conv, Pipe};
 
 /// This represents the different types of log messages.
@@ -50,13 +50,30 @@ pub(crate) fn logger(msg: LogMessage, console_state: &Console) {
     }
 }
 
+/// Formats an argument of the `console` methods: strings are printed as is, and the other values
+/// with the [`ValueFormatter`].
+fn format_arg(arg: &JsValue, context: &mut Context) -> String {
+    match arg {
+        JsValue::String(string) => string.to_string(),
+        _ => ValueFormatter::default().format(arg, context),
+    }
+}
+
 /// This represents the `console` formatter.
+///
+/// When the first argument is not a string, the arguments are formatted and joined by spaces.
 pub fn formatter(data: &[JsValue], context: &mut Context) -> JsResult<String> {
-    let target = data
-        .get(0)
-        .cloned()
-        .unwrap_or_default()
-        .to_string(context)?;
+    let target = match data.get(0) {
+        Some(JsValue::String(target)) => target.clone(),
+        Some(_) => {
+            let args = data
+                .iter()
+                .map(|arg| format_arg(arg, context))
+                .collect::<Vec<_>>();
+            return Ok(args.join(" "));
+        }
+        None => JsString::empty(),
+    };
 
     match data.len() {
         0 => Ok(String::new()),
@@ -84,10 +101,10 @@ pub fn formatter(data: &[JsValue], context: &mut Context) -> JsResult<String> {
                             formatted.push_str(&format!("{arg:.6}"));
                             arg_index += 1;
                         }
-                        /* object, FIXME: how to render this properly? */
+                        /* object */
                         'o' | 'O' => {
                             let arg = data.get_or_undefined(arg_index);
-                            formatted.push_str(&arg.display().to_string());
+                            formatted.push_str(&ValueFormatter::default().format(arg, context));
                             arg_index += 1;
                         }
                         /* string */
@@ -114,7 +131,8 @@ pub fn formatter(data: &[JsValue], context: &mut Context) -> JsResult<String> {
 
             /* unformatted data */
             for rest in data.iter().skip(arg_index) {
-                formatted.push_str(&format!(" {}", rest.to_string(context)?));
+                formatted.push(' ');
+                formatted.push_str(&format_arg(rest, context));
             }
 
             Ok(formatted)
@@ -407,10 +425,9 @@ impl Console {
 
     /// Returns current system time in ms.
     fn system_time_in_ms() -> u128 {
-        let now = SystemTime::now();
-        now.duration_since(SystemTime::UNIX_EPOCH)
-            .expect("negative duration")
-            .as_millis()
+        // `SystemTime` is not available on `wasm32-unknown-unknown`, where chrono reads the clock
+        // of the host instead.
+        Utc::now().timestamp_millis() as u128
     }
 
     /// `console.time(label)`
@@ -571,7 +588,7 @@ impl Console {
     #[allow(clippy::unnecessary_wraps)]
     pub(crate) fn dir(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         logger(
-            LogMessage::Info(display_obj(args.get_or_undefined(0), true)),
+            LogMessage::Info(ValueFormatter::default().format(args.get_or_undefined(0), context)),
             context.console(),
         );
         Ok(JsValue::undefined())
@@ -13,11 +13,15 @@ use crate::{
 use boa_gc::{Finalize, Trace};
 use tap::{Conv, Pipe};
 
+#[cfg(test)]
+mod tests;
+
 #[derive(Debug, Clone, Trace, Finalize)]
 pub struct DataView {
     viewed_array_buffer: JsObject,
     byte_length: usize,
     byte_offset: usize,
+    length_tracking: bool,
 }
 
 impl BuiltIn for DataView {
@@ -100,82 +104,192 @@ impl DataView {
     ) -> JsResult<JsValue> {
         let byte_length = args.get_or_undefined(2);
 
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error("new target is undefined");
+        }
+
+        // 2. Perform ? RequireInternalSlot(buffer, [[ArrayBufferData]]).
         let buffer_obj = args
             .get_or_undefined(0)
             .as_object()
+            .filter(|obj| obj.is_array_buffer())
             .ok_or_else(|| context.construct_type_error("buffer must be an ArrayBuffer"))?;
 
-        // 1. If NewTarget is undefined, throw a TypeError exception.
-        let (offset, view_byte_length) = {
-            if new_target.is_undefined() {
-                return context.throw_type_error("new target is undefined");
-            }
-            // 2. Perform ? RequireInternalSlot(buffer, [[ArrayBufferData]]).
+        // 3. Let offset be ? ToIndex(byteOffset).
+        let offset = args.get_or_undefined(1).to_index(context)?;
+
+        let (buffer_byte_length, buffer_is_fixed_length) = {
             let buffer_borrow = buffer_obj.borrow();
             let buffer = buffer_borrow
                 .as_array_buffer()
-                .ok_or_else(|| context.construct_type_error("buffer must be an ArrayBuffer"))?;
+                .expect("checked that the buffer is an ArrayBuffer");
 
-            // 3. Let offset be ? ToIndex(byteOffset).
-            let offset = args.get_or_undefined(1).to_index(context)?;
             // 4. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
             if buffer.is_detached_buffer() {
                 return context.throw_type_error("ArrayBuffer is detached");
             }
-            // 5. Let bufferByteLength be buffer.[[ArrayBufferByteLength]].
-            let buffer_byte_length = buffer.array_buffer_byte_length();
-            // 6. If offset > bufferByteLength, throw a RangeError exception.
-            if offset > buffer_byte_length {
-                return context
-                    .throw_range_error("Start offset is outside the bounds of the buffer");
+
+            // 5. Let bufferByteLength be ArrayBufferByteLength(buffer, seq-cst).
+            // 7. Let bufferIsFixedLength be IsFixedLengthArrayBuffer(buffer).
+            (buffer.array_buffer_byte_length(), buffer.is_fixed_length())
+        };
+
+        // 6. If offset > bufferByteLength, throw a RangeError exception.
+        if offset > buffer_byte_length {
+            return context.throw_range_error("Start offset is outside the bounds of the buffer");
+        }
+
+        // 8. If byteLength is undefined, then
+        let view_byte_length = if byte_length.is_undefined() {
+            // a. If bufferIsFixedLength is true, then
+            //     i. Let viewByteLength be bufferByteLength - offset.
+            // b. Else,
+            //     i. Let viewByteLength be auto.
+            None
+        }
+        // 9. Else,
+        else {
+            // a. Let viewByteLength be ? ToIndex(byteLength).
+            let view_byte_length = byte_length.to_index(context)?;
+
+            // b. If offset + viewByteLength > bufferByteLength, throw a RangeError exception.
+            if offset + view_byte_length > buffer_byte_length {
+                return context.throw_range_error("Invalid data view length");
             }
-            // 7. If byteLength is undefined, then
-            let view_byte_length = if byte_length.is_undefined() {
-                // a. Let viewByteLength be bufferByteLength - offset.
-                buffer_byte_length - offset
-            } else {
-                // 8.a. Let viewByteLength be ? ToIndex(byteLength).
-                let view_byte_length = byte_length.to_index(context)?;
-                // 8.b. If offset + viewByteLength > bufferByteLength, throw a RangeError exception.
-                if offset + view_byte_length > buffer_byte_length {
-                    return context.throw_range_error("Invalid data view length");
-                }
-
-                view_byte_length
-            };
-            (offset, view_byte_length)
+
+            Some(view_byte_length)
         };
 
-        // 9. Let O be ? OrdinaryCreateFromConstructor(NewTarget, "%DataView.prototype%", « [[DataView]], [[ViewedArrayBuffer]], [[ByteLength]], [[ByteOffset]] »).
+        // 10. Let O be ? OrdinaryCreateFromConstructor(NewTarget, "%DataView.prototype%", « [[DataView]], [[ViewedArrayBuffer]], [[ByteLength]], [[ByteOffset]] »).
         let prototype =
             get_prototype_from_constructor(new_target, StandardConstructors::data_view, context)?;
 
-        // 10. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
-        if buffer_obj
-            .borrow()
-            .as_array_buffer()
-            .ok_or_else(|| context.construct_type_error("buffer must be an ArrayBuffer"))?
-            .is_detached_buffer()
-        {
-            return context.throw_type_error("ArrayBuffer can't be detached");
+        let buffer_byte_length = {
+            let buffer_borrow = buffer_obj.borrow();
+            let buffer = buffer_borrow
+                .as_array_buffer()
+                .expect("checked that the buffer is an ArrayBuffer");
+
+            // 11. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
+            if buffer.is_detached_buffer() {
+                return context.throw_type_error("ArrayBuffer can't be detached");
+            }
+
+            // 12. Set bufferByteLength to ArrayBufferByteLength(buffer, seq-cst).
+            buffer.array_buffer_byte_length()
+        };
+
+        // 13. If offset > bufferByteLength, throw a RangeError exception.
+        if offset > buffer_byte_length {
+            return context.throw_range_error("Start offset is outside the bounds of the buffer");
+        }
+
+        // 14. If byteLength is not undefined, then
+        //     a. If offset + viewByteLength > bufferByteLength, throw a RangeError exception.
+        if matches!(view_byte_length, Some(length) if offset + length > buffer_byte_length) {
+            return context.throw_range_error("Invalid data view length");
         }
 
+        let length_tracking = view_byte_length.is_none() && !buffer_is_fixed_length;
         let obj = JsObject::from_proto_and_data(
             prototype,
             ObjectData::data_view(Self {
-                // 11. Set O.[[ViewedArrayBuffer]] to buffer.
+                // 15. Set O.[[ViewedArrayBuffer]] to buffer.
                 viewed_array_buffer: buffer_obj.clone(),
-                // 12. Set O.[[ByteLength]] to viewByteLength.
-                byte_length: view_byte_length,
-                // 13. Set O.[[ByteOffset]] to offset.
+                // 16. Set O.[[ByteLength]] to viewByteLength.
+                byte_length: view_byte_length.unwrap_or(buffer_byte_length - offset),
+                // 17. Set O.[[ByteOffset]] to offset.
                 byte_offset: offset,
+                length_tracking,
             }),
         );
 
-        // 14. Return O.
+        // 18. Return O.
         Ok(obj.into())
     }
 
+    /// Returns the current byte length of the viewed buffer, or `None` if it is detached.
+    fn buffer_byte_length(&self) -> Option<usize> {
+        let buffer = self.viewed_array_buffer.borrow();
+        let buffer = buffer
+            .as_array_buffer()
+            .expect("DataView must be constructed with an ArrayBuffer");
+        buffer
+            .array_buffer_data
+            .as_ref()
+            .map(|_| buffer.array_buffer_byte_length())
+    }
+
+    /// Abstract operation `IsViewOutOfBounds ( viewRecord )`.
+    ///
+    /// Detached buffers are always out of bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isviewoutofbounds
+    pub(crate) fn is_out_of_bounds(&self) -> bool {
+        // 1. Let view be viewRecord.[[Object]].
+        // 2. Let bufferByteLength be viewRecord.[[CachedBufferByteLength]].
+        // 3. Assert: IsDetachedBuffer(view.[[ViewedArrayBuffer]]) is true if and only if bufferByteLength is detached.
+        // 4. If bufferByteLength is detached, return true.
+        self.buffer_byte_length()
+            .map_or(true, |buffer_byte_length| {
+                // 5. Let byteOffsetStart be view.[[ByteOffset]].
+                // 6. If view.[[ByteLength]] is auto, then
+                //     a. Let byteOffsetEnd be bufferByteLength.
+                // 7. Else,
+                //     a. Let byteOffsetEnd be byteOffsetStart + view.[[ByteLength]].
+                // 8. If byteOffsetStart > bufferByteLength or byteOffsetEnd > bufferByteLength, return true.
+                // 9. Return false.
+                self.byte_offset > buffer_byte_length
+                    || (!self.length_tracking
+                        && self.byte_offset + self.byte_length > buffer_byte_length)
+            })
+    }
+
+    /// Abstract operation `GetViewByteLength ( viewRecord )`.
+    ///
+    /// Must only be called if the view is not out of bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getviewbytelength
+    fn view_byte_length(&self) -> usize {
+        // 1. Assert: IsViewOutOfBounds(viewRecord) is false.
+        // 2. Let view be viewRecord.[[Object]].
+        // 3. If view.[[ByteLength]] is not auto, return view.[[ByteLength]].
+        if !self.length_tracking {
+            return self.byte_length;
+        }
+
+        // 4. Assert: IsFixedLengthArrayBuffer(view.[[ViewedArrayBuffer]]) is false.
+        // 5. Let byteOffset be view.[[ByteOffset]].
+        // 6. Let byteLength be viewRecord.[[CachedBufferByteLength]].
+        // 7. Assert: byteLength is not detached.
+        // 8. Return byteLength - byteOffset.
+        self.buffer_byte_length()
+            .expect("the view must not be out of bounds")
+            - self.byte_offset
+    }
+
+    /// Gets the `[[ViewedArrayBuffer]]` of the view.
+    pub(crate) fn viewed_array_buffer(&self) -> &JsObject {
+        &self.viewed_array_buffer
+    }
+
+    /// Gets the `[[ByteOffset]]` of the view.
+    pub(crate) fn byte_offset(&self) -> usize {
+        self.byte_offset
+    }
+
+    /// Gets the `[[ByteLength]]` of the view, or `None` if it is `auto`.
+    pub(crate) fn byte_length(&self) -> Option<usize> {
+        (!self.length_tracking).then(|| self.byte_length)
+    }
+
     /// `25.3.4.1 get DataView.prototype.buffer`
     ///
     /// The buffer accessor property represents the `ArrayBuffer` or `SharedArrayBuffer` referenced
@@ -229,17 +343,13 @@ impl DataView {
             .and_then(|obj| obj.as_data_view())
             .ok_or_else(|| context.construct_type_error("`this` is not a DataView"))?;
         // 3. Assert: O has a [[ViewedArrayBuffer]] internal slot.
-        // 4. Let buffer be O.[[ViewedArrayBuffer]].
-        let buffer_borrow = dataview.viewed_array_buffer.borrow();
-        let borrow = buffer_borrow
-            .as_array_buffer()
-            .expect("DataView must be constructed with an ArrayBuffer");
-        // 5. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
-        if borrow.is_detached_buffer() {
-            return context.throw_type_error("ArrayBuffer is detached");
+        // 4. Let viewRecord be MakeDataViewWithBufferWitnessRecord(O, seq-cst).
+        // 5. If IsViewOutOfBounds(viewRecord) is true, throw a TypeError exception.
+        if dataview.is_out_of_bounds() {
+            return context.throw_type_error("DataView is out of bounds of its ArrayBuffer");
         }
-        // 6. Let size be O.[[ByteLength]].
-        let size = dataview.byte_length;
+        // 6. Let size be GetViewByteLength(viewRecord).
+        let size = dataview.view_byte_length();
         // 7. Return 𝔽(size).
         Ok(size.into())
     }
@@ -268,14 +378,10 @@ impl DataView {
             .and_then(|obj| obj.as_data_view())
             .ok_or_else(|| context.construct_type_error("`this` is not a DataView"))?;
         // 3. Assert: O has a [[ViewedArrayBuffer]] internal slot.
-        // 4. Let buffer be O.[[ViewedArrayBuffer]].
-        let buffer_borrow = dataview.viewed_array_buffer.borrow();
-        let borrow = buffer_borrow
-            .as_array_buffer()
-            .expect("DataView must be constructed with an ArrayBuffer");
-        // 5. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
-        if borrow.is_detached_buffer() {
-            return context.throw_type_error("ArrayBuffer is detached");
+        // 4. Let viewRecord be MakeDataViewWithBufferWitnessRecord(O, seq-cst).
+        // 5. If IsViewOutOfBounds(viewRecord) is true, throw a TypeError exception.
+        if dataview.is_out_of_bounds() {
+            return context.throw_type_error("DataView is out of bounds of its ArrayBuffer");
         }
         // 6. Let offset be O.[[ByteOffset]].
         let offset = dataview.byte_offset;
@@ -313,22 +419,23 @@ impl DataView {
         // 4. Set isLittleEndian to ! ToBoolean(isLittleEndian).
         let is_little_endian = is_little_endian.to_boolean();
 
-        // 5. Let buffer be view.[[ViewedArrayBuffer]].
-        let buffer = &view.viewed_array_buffer;
-        let buffer_borrow = buffer.borrow();
-        let buffer = buffer_borrow
-            .as_array_buffer()
-            .expect("Should be unreachable");
+        // 5. Let viewOffset be view.[[ByteOffset]].
+        let view_offset = view.byte_offset;
 
-        // 6. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
-        if buffer.is_detached_buffer() {
-            return context.throw_type_error("ArrayBuffer is detached");
+        // 6. Let viewRecord be MakeDataViewWithBufferWitnessRecord(view, unordered).
+        // 7. NOTE: Bounds checking is not a synchronizing operation when view's backing buffer is a growable SharedArrayBuffer.
+        // 8. If IsViewOutOfBounds(viewRecord) is true, throw a TypeError exception.
+        if view.is_out_of_bounds() {
+            return context.throw_type_error("DataView is out of bounds of its ArrayBuffer");
         }
-        // 7. Let viewOffset be view.[[ByteOffset]].
-        let view_offset = view.byte_offset;
 
-        // 8. Let viewSize be view.[[ByteLength]].
-        let view_size = view.byte_length;
+        // 9. Let viewSize be GetViewByteLength(viewRecord).
+        let view_size = view.view_byte_length();
+
+        let buffer_borrow = view.viewed_array_buffer.borrow();
+        let buffer = buffer_borrow
+            .as_array_buffer()
+            .expect("Should be unreachable");
 
         // 9. Let elementSize be the Element Size value specified in Table 72 for Element Type type.
         let element_size = t.element_size();
@@ -399,7 +506,7 @@ impl DataView {
         let byte_offset = args.get_or_undefined(0);
         let is_little_endian = args.get_or_undefined(1);
         // 1. Let v be the this value.
-        // 2. Return ? GetViewValue(v, byteOffset, littleEndian, BigInt64).
+        // 2. Return ? GetViewValue(v, byteOffset, littleEndian, BigUint64).
         Self::get_view_value(
             this,
             byte_offset,
@@ -428,7 +535,7 @@ impl DataView {
         let byte_offset = args.get_or_undefined(0);
         let is_little_endian = args.get_or_undefined(1);
         // 1. Let v be the this value.
-        // 2. Return ? GetViewValue(v, byteOffset, littleEndian, BigInt64).
+        // 2. Return ? GetViewValue(v, byteOffset, littleEndian, Float32).
         Self::get_view_value(
             this,
             byte_offset,
@@ -457,7 +564,7 @@ impl DataView {
         let byte_offset = args.get_or_undefined(0);
         let is_little_endian = args.get_or_undefined(1);
         // 1. Let v be the this value.
-        // 2. Return ? GetViewValue(v, byteOffset, littleEndian, BigInt64).
+        // 2. Return ? GetViewValue(v, byteOffset, littleEndian, Float64).
         Self::get_view_value(
             this,
             byte_offset,
@@ -486,7 +593,7 @@ impl DataView {
         let byte_offset = args.get_or_undefined(0);
         let is_little_endian = args.get_or_undefined(1);
         // 1. Let v be the this value.
-        // 2. Return ? GetViewValue(v, byteOffset, littleEndian, BigInt64).
+        // 2. Return ? GetViewValue(v, byteOffset, littleEndian, Int8).
         Self::get_view_value(
             this,
             byte_offset,
@@ -515,7 +622,7 @@ impl DataView {
         let byte_offset = args.get_or_undefined(0);
         let is_little_endian = args.get_or_undefined(1);
         // 1. Let v be the this value.
-        // 2. Return ? GetViewValue(v, byteOffset, littleEndian, BigInt64).
+        // 2. Return ? GetViewValue(v, byteOffset, littleEndian, Int16).
         Self::get_view_value(
             this,
             byte_offset,
@@ -544,7 +651,7 @@ impl DataView {
         let byte_offset = args.get_or_undefined(0);
         let is_little_endian = args.get_or_undefined(1);
         // 1. Let v be the this value.
-        // 2. Return ? GetViewValue(v, byteOffset, littleEndian, BigInt64).
+        // 2. Return ? GetViewValue(v, byteOffset, littleEndian, Int32).
         Self::get_view_value(
             this,
             byte_offset,
@@ -573,7 +680,7 @@ impl DataView {
         let byte_offset = args.get_or_undefined(0);
         let is_little_endian = args.get_or_undefined(1);
         // 1. Let v be the this value.
-        // 2. Return ? GetViewValue(v, byteOffset, littleEndian, BigInt64).
+        // 2. Return ? GetViewValue(v, byteOffset, littleEndian, Uint8).
         Self::get_view_value(
             this,
             byte_offset,
@@ -602,7 +709,7 @@ impl DataView {
         let byte_offset = args.get_or_undefined(0);
         let is_little_endian = args.get_or_undefined(1);
         // 1. Let v be the this value.
-        // 2. Return ? GetViewValue(v, byteOffset, littleEndian, BigInt64).
+        // 2. Return ? GetViewValue(v, byteOffset, littleEndian, Uint16).
         Self::get_view_value(
             this,
             byte_offset,
@@ -631,7 +738,7 @@ impl DataView {
         let byte_offset = args.get_or_undefined(0);
         let is_little_endian = args.get_or_undefined(1);
         // 1. Let v be the this value.
-        // 2. Return ? GetViewValue(v, byteOffset, littleEndian, BigInt64).
+        // 2. Return ? GetViewValue(v, byteOffset, littleEndian, Uint32).
         Self::get_view_value(
             this,
             byte_offset,
@@ -679,23 +786,23 @@ impl DataView {
 
         // 6. Set isLittleEndian to ! ToBoolean(isLittleEndian).
         let is_little_endian = is_little_endian.to_boolean();
-        // 7. Let buffer be view.[[ViewedArrayBuffer]].
-        let buffer = &view.viewed_array_buffer;
-        let mut buffer_borrow = buffer.borrow_mut();
-        let buffer = buffer_borrow
-            .as_array_buffer_mut()
-            .expect("Should be unreachable");
+        // 7. Let viewOffset be view.[[ByteOffset]].
+        let view_offset = view.byte_offset;
 
-        // 8. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
-        if buffer.is_detached_buffer() {
-            return context.throw_type_error("ArrayBuffer is detached");
+        // 8. Let viewRecord be MakeDataViewWithBufferWitnessRecord(view, unordered).
+        // 9. NOTE: Bounds checking is not a synchronizing operation when view's backing buffer is a growable SharedArrayBuffer.
+        // 10. If IsViewOutOfBounds(viewRecord) is true, throw a TypeError exception.
+        if view.is_out_of_bounds() {
+            return context.throw_type_error("DataView is out of bounds of its ArrayBuffer");
         }
 
-        // 9. Let viewOffset be view.[[ByteOffset]].
-        let view_offset = view.byte_offset;
+        // 11. Let viewSize be GetViewByteLength(viewRecord).
+        let view_size = view.view_byte_length();
 
-        // 10. Let viewSize be view.[[ByteLength]].
-        let view_size = view.byte_length;
+        let mut buffer_borrow = view.viewed_array_buffer.borrow_mut();
+        let buffer = buffer_borrow
+            .as_array_buffer_mut()
+            .expect("Should be unreachable");
 
         // 11. Let elementSize be the Element Size value specified in Table 72 for Element Type type.
         let element_size = t.element_size();
@@ -739,7 +846,7 @@ impl DataView {
         let value = args.get_or_undefined(1);
         let is_little_endian = args.get_or_undefined(2);
         // 1. Let v be the this value.
-        // 2. Return ? SetViewValue(v, byteOffset, littleEndian, BigUint64, value).
+        // 2. Return ? SetViewValue(v, byteOffset, littleEndian, BigInt64, value).
         Self::set_view_value(
             this,
             byte_offset,
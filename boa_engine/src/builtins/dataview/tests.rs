@@ -0,0 +1,135 @@
+use crate::{forward, object::JsDataView, Context};
+
+#[test]
+fn big_int_accessors() {
+    let mut context = Context::default();
+    let init = r#"
+        var view = new DataView(new ArrayBuffer(16));
+        view.setBigInt64(0, -2n);
+        view.setBigUint64(8, 2n ** 64n + 3n, true);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "view.getBigInt64(0)"), "-2n");
+    assert_eq!(
+        forward(&mut context, "view.getBigUint64(0)"),
+        "18446744073709551614n"
+    );
+    assert_eq!(forward(&mut context, "view.getBigUint64(8, true)"), "3n");
+    assert_eq!(
+        forward(&mut context, "view.getBigUint64(8)"),
+        "216172782113783808n"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { view.setBigInt64(0, 1); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { view.getBigInt64(9); } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn endianness() {
+    let mut context = Context::default();
+    let init = r#"
+        var view = new DataView(new ArrayBuffer(8), 2);
+        view.setUint16(0, 0x0102);
+        view.setInt32(2, -2, true);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "new Uint8Array(view.buffer).join()"),
+        "\"0,0,1,2,254,255,255,255\""
+    );
+    assert_eq!(forward(&mut context, "view.getUint16(0, true)"), "513");
+    assert_eq!(forward(&mut context, "view.getInt32(2)"), "-16777217");
+    assert_eq!(
+        forward(
+            &mut context,
+            "view.setFloat32(0, 1.5); [view.getFloat32(0), view.getUint8(0)]"
+        ),
+        "[ 1.5, 63 ]"
+    );
+}
+
+#[test]
+fn detached_buffer() {
+    let mut context = Context::default();
+    let init = r#"
+        var buffer = new ArrayBuffer(4);
+        var view = new DataView(buffer);
+        buffer.transfer();
+    "#;
+    forward(&mut context, init);
+    for code in [
+        "view.byteLength",
+        "view.byteOffset",
+        "view.getInt8(0)",
+        "view.setInt8(0, 1)",
+        "new DataView(buffer)",
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {code}; }} catch (e) {{ e.name }}")
+            ),
+            "\"TypeError\""
+        );
+    }
+    assert_eq!(
+        forward(
+            &mut context,
+            "let calls = 0; try { view.getInt8({ valueOf() { calls++; return 0; } }); } catch (e) { calls }"
+        ),
+        "1"
+    );
+}
+
+#[test]
+fn js_data_view() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        "var buffer = new ArrayBuffer(16); new DataView(buffer).setFloat64(8, Math.PI);",
+    );
+    let buffer = context
+        .global_object()
+        .clone()
+        .get("buffer", &mut context)
+        .expect("buffer is defined");
+    let buffer = buffer.as_object().expect("buffer is an object");
+
+    let view = JsDataView::from_js_array_buffer(buffer, Some(8), None, &mut context)
+        .expect("the view fits in the buffer");
+    assert_eq!(view.byte_offset(&mut context).unwrap(), 8);
+    assert_eq!(view.byte_length(&mut context).unwrap(), 8);
+    assert_eq!(
+        view.get_float64(0, false, &mut context).unwrap(),
+        std::f64::consts::PI
+    );
+
+    view.set_big_int64(0, i64::MIN, true, &mut context).unwrap();
+    assert_eq!(view.get_big_int64(0, true, &mut context).unwrap(), i64::MIN);
+    assert_eq!(view.get_uint8(7, false, &mut context).unwrap(), 0x80);
+    view.set_int16(0, -1, false, &mut context).unwrap();
+    assert_eq!(view.get_uint16(0, false, &mut context).unwrap(), u16::MAX);
+    assert!(view.get_uint32(6, false, &mut context).is_err());
+
+    let whole = JsDataView::from_object(
+        JsDataView::from_js_array_buffer(buffer, None, None, &mut context)
+            .unwrap()
+            .into(),
+        &mut context,
+    )
+    .unwrap();
+    assert_eq!(whole.byte_length(&mut context).unwrap(), 16);
+    assert!(JsDataView::from_object(buffer.clone(), &mut context).is_err());
+    assert!(JsDataView::from_js_array_buffer(buffer, Some(17), None, &mut context).is_err());
+}
@@ -1,9 +1,17 @@
 #[cfg(test)]
 mod tests;
 
+pub(crate) mod parser;
+
 use super::JsArgs;
 use crate::{
-    builtins::BuiltIn,
+    builtins::{
+        intl::{
+            date_time_format::{DateTimeDefaults, DateTimeRequired},
+            DateTimeFormat,
+        },
+        BuiltIn,
+    },
     context::intrinsics::StandardConstructors,
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
@@ -15,6 +23,7 @@ use crate::{
 use boa_gc::{unsafe_empty_trace, Finalize, Trace};
 use boa_profiler::Profiler;
 use chrono::{prelude::*, Duration, LocalResult};
+use parser::{MONTH_NAMES, WEEKDAY_NAMES};
 use std::fmt::Display;
 use tap::{Conv, Pipe};
 
@@ -38,6 +47,74 @@ macro_rules! check_normal_opt {
     };
 }
 
+/// Formats a year with at least four digits and a sign if it is negative.
+fn year_string(year: i32) -> String {
+    if year >= 0 {
+        format!("{year:04}")
+    } else {
+        format!("-{:04}", -year)
+    }
+}
+
+/// `DateString ( tv )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-datestring
+fn date_string(t: &NaiveDateTime) -> String {
+    // 1. Let weekday be the Name of the entry in Table 63 with the Number WeekDay(tv).
+    // 2. Let month be the Name of the entry in Table 64 with the Number MonthFromTime(tv).
+    // 3. Let day be ToZeroPaddedDecimalString(ℝ(DateFromTime(tv)), 2).
+    // 4. Let yv be YearFromTime(tv).
+    // 5. If yv is +0𝔽 or yv > +0𝔽, let yearSign be the empty String; otherwise, let yearSign be "-".
+    // 6. Let paddedYear be ToZeroPaddedDecimalString(abs(ℝ(yv)), 4).
+    // 7. Return the string-concatenation of weekday, the code unit 0x0020 (SPACE), month, the code unit 0x0020 (SPACE), day, the code unit 0x0020 (SPACE), yearSign, and paddedYear.
+    format!(
+        "{} {} {:02} {}",
+        WEEKDAY_NAMES[t.weekday().num_days_from_sunday() as usize],
+        MONTH_NAMES[t.month0() as usize],
+        t.day(),
+        year_string(t.year())
+    )
+}
+
+/// `TimeString ( tv )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-timestring
+fn time_string(t: &NaiveDateTime) -> String {
+    // 1. Let hour be ToZeroPaddedDecimalString(ℝ(HourFromTime(tv)), 2).
+    // 2. Let minute be ToZeroPaddedDecimalString(ℝ(MinFromTime(tv)), 2).
+    // 3. Let second be ToZeroPaddedDecimalString(ℝ(SecFromTime(tv)), 2).
+    // 4. Return the string-concatenation of hour, ":", minute, ":", second, the code unit 0x0020 (SPACE), and "GMT".
+    format!("{:02}:{:02}:{:02} GMT", t.hour(), t.minute(), t.second())
+}
+
+/// `TimeZoneString ( tv )`
+///
+/// The implementation-defined time zone name is omitted.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-timezoneestring
+fn time_zone_string(t: &DateTime<Local>) -> String {
+    // 1-3. Let offset be the offset of the local time zone at tv, in minutes.
+    let offset = t.offset().fix().local_minus_utc() / 60;
+
+    // 4. If offset ≥ +0𝔽, let offsetSign be "+"; otherwise, let offsetSign be "-".
+    let sign = if offset >= 0 { '+' } else { '-' };
+
+    // 5. Let offsetMin be ToZeroPaddedDecimalString(ℝ(MinFromTime(abs(offset))), 2).
+    // 6. Let offsetHour be ToZeroPaddedDecimalString(ℝ(HourFromTime(abs(offset))), 2).
+    // 7. Let tzName be an implementation-defined string that is either the empty String or the string-concatenation of the code unit 0x0020 (SPACE), the code unit 0x0028 (LEFT PARENTHESIS), an implementation-defined timezone name, and the code unit 0x0029 (RIGHT PARENTHESIS).
+    // 8. Return the string-concatenation of offsetSign, offsetHour, offsetMin, and tzName.
+    format!("{sign}{:02}{:02}", offset.abs() / 60, offset.abs() % 60)
+}
+
 #[inline]
 fn ignore_ambiguity<T>(result: LocalResult<T>) -> Option<T> {
     match result {
@@ -91,68 +168,74 @@ impl BuiltIn for Date {
     fn init(context: &mut Context) -> Option<JsValue> {
         let _timer = Profiler::global().start_event(Self::NAME, "init");
 
-        ConstructorBuilder::new(context, Self::constructor)
-            .name(Self::NAME)
-            .length(Self::LENGTH)
-            .method(getter_method!(get_date), "getDate", 0)
-            .method(getter_method!(get_day), "getDay", 0)
-            .method(getter_method!(get_full_year), "getFullYear", 0)
-            .method(getter_method!(get_hours), "getHours", 0)
-            .method(getter_method!(get_milliseconds), "getMilliseconds", 0)
-            .method(getter_method!(get_minutes), "getMinutes", 0)
-            .method(getter_method!(get_month), "getMonth", 0)
-            .method(getter_method!(get_seconds), "getSeconds", 0)
-            .method(getter_method!(get_time), "getTime", 0)
-            .method(getter_method!(get_year), "getYear", 0)
-            .method(Self::get_timezone_offset, "getTimezoneOffset", 0)
-            .method(getter_method!(get_utc_date), "getUTCDate", 0)
-            .method(getter_method!(get_utc_day), "getUTCDay", 0)
-            .method(getter_method!(get_utc_full_year), "getUTCFullYear", 0)
-            .method(getter_method!(get_utc_hours), "getUTCHours", 0)
-            .method(
-                getter_method!(get_utc_milliseconds),
-                "getUTCMilliseconds",
-                0,
-            )
-            .method(getter_method!(get_utc_minutes), "getUTCMinutes", 0)
-            .method(getter_method!(get_utc_month), "getUTCMonth", 0)
-            .method(getter_method!(get_utc_seconds), "getUTCSeconds", 0)
-            .method(Self::set_date, "setDate", 1)
-            .method(Self::set_full_year, "setFullYear", 3)
-            .method(Self::set_hours, "setHours", 4)
-            .method(Self::set_milliseconds, "setMilliseconds", 1)
-            .method(Self::set_minutes, "setMinutes", 3)
-            .method(Self::set_month, "setMonth", 2)
-            .method(Self::set_seconds, "setSeconds", 2)
-            .method(Self::set_year, "setYear", 1)
-            .method(Self::set_time, "setTime", 1)
-            .method(Self::set_utc_date, "setUTCDate", 1)
-            .method(Self::set_utc_full_year, "setUTCFullYear", 3)
-            .method(Self::set_utc_hours, "setUTCHours", 4)
-            .method(Self::set_utc_milliseconds, "setUTCMilliseconds", 1)
-            .method(Self::set_utc_minutes, "setUTCMinutes", 3)
-            .method(Self::set_utc_month, "setUTCMonth", 2)
-            .method(Self::set_utc_seconds, "setUTCSeconds", 2)
-            .method(Self::to_date_string, "toDateString", 0)
-            .method(getter_method!(to_gmt_string), "toGMTString", 0)
-            .method(Self::to_iso_string, "toISOString", 0)
-            .method(Self::to_json, "toJSON", 1)
-            // Locale strings
-            .method(Self::to_string, "toString", 0)
-            .method(Self::to_time_string, "toTimeString", 0)
-            .method(getter_method!(to_utc_string), "toUTCString", 0)
-            .method(getter_method!(value_of), "valueOf", 0)
-            .method(
-                Self::to_primitive,
-                (WellKnownSymbols::to_primitive(), "[Symbol.toPrimitive]"),
-                1,
-            )
-            .static_method(Self::now, "now", 0)
-            .static_method(Self::parse, "parse", 1)
-            .static_method(Self::utc, "UTC", 7)
-            .build()
-            .conv::<JsValue>()
-            .pipe(Some)
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().date().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .method(getter_method!(get_date), "getDate", 0)
+        .method(getter_method!(get_day), "getDay", 0)
+        .method(getter_method!(get_full_year), "getFullYear", 0)
+        .method(getter_method!(get_hours), "getHours", 0)
+        .method(getter_method!(get_milliseconds), "getMilliseconds", 0)
+        .method(getter_method!(get_minutes), "getMinutes", 0)
+        .method(getter_method!(get_month), "getMonth", 0)
+        .method(getter_method!(get_seconds), "getSeconds", 0)
+        .method(getter_method!(get_time), "getTime", 0)
+        .method(getter_method!(get_year), "getYear", 0)
+        .method(Self::get_timezone_offset, "getTimezoneOffset", 0)
+        .method(getter_method!(get_utc_date), "getUTCDate", 0)
+        .method(getter_method!(get_utc_day), "getUTCDay", 0)
+        .method(getter_method!(get_utc_full_year), "getUTCFullYear", 0)
+        .method(getter_method!(get_utc_hours), "getUTCHours", 0)
+        .method(
+            getter_method!(get_utc_milliseconds),
+            "getUTCMilliseconds",
+            0,
+        )
+        .method(getter_method!(get_utc_minutes), "getUTCMinutes", 0)
+        .method(getter_method!(get_utc_month), "getUTCMonth", 0)
+        .method(getter_method!(get_utc_seconds), "getUTCSeconds", 0)
+        .method(Self::set_date, "setDate", 1)
+        .method(Self::set_full_year, "setFullYear", 3)
+        .method(Self::set_hours, "setHours", 4)
+        .method(Self::set_milliseconds, "setMilliseconds", 1)
+        .method(Self::set_minutes, "setMinutes", 3)
+        .method(Self::set_month, "setMonth", 2)
+        .method(Self::set_seconds, "setSeconds", 2)
+        .method(Self::set_year, "setYear", 1)
+        .method(Self::set_time, "setTime", 1)
+        .method(Self::set_utc_date, "setUTCDate", 1)
+        .method(Self::set_utc_full_year, "setUTCFullYear", 3)
+        .method(Self::set_utc_hours, "setUTCHours", 4)
+        .method(Self::set_utc_milliseconds, "setUTCMilliseconds", 1)
+        .method(Self::set_utc_minutes, "setUTCMinutes", 3)
+        .method(Self::set_utc_month, "setUTCMonth", 2)
+        .method(Self::set_utc_seconds, "setUTCSeconds", 2)
+        .method(Self::to_date_string, "toDateString", 0)
+        .method(getter_method!(to_gmt_string), "toGMTString", 0)
+        .method(Self::to_iso_string, "toISOString", 0)
+        .method(Self::to_json, "toJSON", 1)
+        .method(Self::to_locale_date_string, "toLocaleDateString", 0)
+        .method(Self::to_locale_string, "toLocaleString", 0)
+        .method(Self::to_locale_time_string, "toLocaleTimeString", 0)
+        .method(Self::to_string, "toString", 0)
+        .method(Self::to_time_string, "toTimeString", 0)
+        .method(getter_method!(to_utc_string), "toUTCString", 0)
+        .method(getter_method!(value_of), "valueOf", 0)
+        .method(
+            Self::to_primitive,
+            (WellKnownSymbols::to_primitive(), "[Symbol.toPrimitive]"),
+            1,
+        )
+        .static_method(Self::now, "now", 0)
+        .static_method(Self::parse, "parse", 1)
+        .static_method(Self::utc, "UTC", 7)
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
     }
 }
 
@@ -337,7 +420,7 @@ impl Date {
             Ok(Self::make_date_string())
         } else {
             let prototype =
-                get_prototype_from_constructor(new_target, StandardConstructors::object, context)?;
+                get_prototype_from_constructor(new_target, StandardConstructors::date, context)?;
             Ok(if args.is_empty() {
                 Self::make_date_now(prototype)
             } else if args.len() == 1 {
@@ -396,10 +479,7 @@ impl Date {
         let tv = match this_time_value(value, context) {
             Ok(dt) => dt.0,
             _ => match value.to_primitive(context, PreferredType::Default)? {
-                JsValue::String(ref str) => match chrono::DateTime::parse_from_rfc3339(str) {
-                    Ok(dt) => Some(dt.naive_utc()),
-                    _ => None,
-                },
+                JsValue::String(ref str) => parser::parse(str),
                 tv => {
                     let tv = tv.to_number(context)?;
                     if tv.is_nan() {
@@ -1633,13 +1713,8 @@ impl Date {
         // 3. If tv is NaN, return "Invalid Date".
         // 4. Let t be LocalTime(tv).
         // 5. Return DateString(t).
-        if let Some(t) = tv.0 {
-            Ok(Local::now()
-                .timezone()
-                .from_utc_datetime(&t)
-                .format("%a %b %d %Y")
-                .to_string()
-                .into())
+        if let Some(t) = tv.to_local() {
+            Ok(date_string(&t.naive_local()).into())
         } else {
             Ok(JsString::from("Invalid Date").into())
         }
@@ -1677,16 +1752,34 @@ impl Date {
         _: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        if let Some(t) = this_time_value(this, context)?.0 {
-            Ok(Utc::now()
-                .timezone()
-                .from_utc_datetime(&t)
-                .format("%Y-%m-%dT%H:%M:%S.%3fZ")
-                .to_string()
-                .into())
+        // 1. Let O be this Date object.
+        // 2. Let tv be ? thisTimeValue(O).
+        // 3. If tv is not finite, throw a RangeError exception.
+        let t = if let Some(t) = this_time_value(this, context)?.0 {
+            t
         } else {
-            context.throw_range_error("Invalid time value")
-        }
+            return context.throw_range_error("Invalid time value");
+        };
+
+        // 4. Assert: tv is an integral Number.
+        // 5. If tv corresponds with a year that cannot be represented in the Date Time String Format, throw a RangeError exception.
+        // 6. Return a String representation of tv in the Date Time String Format on the UTC time scale, including all format elements and the UTC offset representation "Z".
+        let year = t.year();
+        let year = if (0..=9999).contains(&year) {
+            format!("{year:04}")
+        } else {
+            format!("{year:+07}")
+        };
+        Ok(format!(
+            "{year}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+            t.month(),
+            t.day(),
+            t.hour(),
+            t.minute(),
+            t.second(),
+            t.timestamp_subsec_millis()
+        )
+        .into())
     }
 
     /// `Date.prototype.toJSON()`
@@ -1719,6 +1812,113 @@ impl Date {
         context.call(&func, &o.into(), &[])
     }
 
+    /// Formats the this time value with a `DateTimeFormat` created from the `locales` and
+    /// `options` arguments.
+    fn to_locale_string_with(
+        this: &JsValue,
+        args: &[JsValue],
+        required: DateTimeRequired,
+        defaults: DateTimeDefaults,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let x be ? thisTimeValue(this value).
+        let x = this_time_value(this, context)?.get_time();
+
+        // 2. If x is NaN, return "Invalid Date".
+        if x.is_nan() {
+            return Ok(JsString::from("Invalid Date").into());
+        }
+
+        // 3. Let dateFormat be ? CreateDateTimeFormat(%DateTimeFormat%, locales, options, required, defaults).
+        let date_format = DateTimeFormat::new(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            required,
+            defaults,
+            context,
+        )?;
+
+        // 4. Return ! FormatDateTime(dateFormat, x).
+        Ok(date_format
+            .format(x)
+            .expect("the time value of a Date must be valid")
+            .into())
+    }
+
+    /// `Date.prototype.toLocaleDateString( [ locales [ , options ] ] )`
+    ///
+    /// The `toLocaleDateString()` method returns a string with a language sensitive representation of the date
+    /// portion of this date.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sup-date.prototype.tolocaledatestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleDateString
+    pub fn to_locale_date_string(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::to_locale_string_with(
+            this,
+            args,
+            DateTimeRequired::Date,
+            DateTimeDefaults::Date,
+            context,
+        )
+    }
+
+    /// `Date.prototype.toLocaleString( [ locales [ , options ] ] )`
+    ///
+    /// The `toLocaleString()` method returns a string with a language sensitive representation of this date.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sup-date.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleString
+    pub fn to_locale_string(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::to_locale_string_with(
+            this,
+            args,
+            DateTimeRequired::Any,
+            DateTimeDefaults::All,
+            context,
+        )
+    }
+
+    /// `Date.prototype.toLocaleTimeString( [ locales [ , options ] ] )`
+    ///
+    /// The `toLocaleTimeString()` method returns a string with a language sensitive representation of the time
+    /// portion of this date.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sup-date.prototype.tolocaletimestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleTimeString
+    pub fn to_locale_time_string(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::to_locale_string_with(
+            this,
+            args,
+            DateTimeRequired::Time,
+            DateTimeDefaults::Time,
+            context,
+        )
+    }
+
     /// `Date.prototype.toString()`
     ///
     /// The toString() method returns a string representing the specified Date object.
@@ -1735,13 +1935,15 @@ impl Date {
         let tv = this_time_value(this, context)?;
 
         // 2. Return ToDateString(tv).
-        if let Some(t) = tv.0 {
-            Ok(Local::now()
-                .timezone()
-                .from_utc_datetime(&t)
-                .format("%a %b %d %Y %H:%M:%S GMT%z")
-                .to_string()
-                .into())
+        if let Some(t) = tv.to_local() {
+            let local = t.naive_local();
+            Ok(format!(
+                "{} {}{}",
+                date_string(&local),
+                time_string(&local),
+                time_zone_string(&t)
+            )
+            .into())
         } else {
             Ok(JsString::from("Invalid Date").into())
         }
@@ -1771,13 +1973,8 @@ impl Date {
         // 3. If tv is NaN, return "Invalid Date".
         // 4. Let t be LocalTime(tv).
         // 5. Return the string-concatenation of TimeString(t) and TimeZoneString(tv).
-        if let Some(t) = tv.0 {
-            Ok(Local::now()
-                .timezone()
-                .from_utc_datetime(&t)
-                .format("%H:%M:%S GMT%z")
-                .to_string()
-                .into())
+        if let Some(t) = tv.to_local() {
+            Ok(format!("{}{}", time_string(&t.naive_local()), time_zone_string(&t)).into())
         } else {
             Ok(JsString::from("Invalid Date").into())
         }
@@ -1794,9 +1991,28 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.toutcstring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toUTCString
     pub fn to_utc_string(self) -> String {
-        self.to_utc().map_or_else(
+        // 1. Let O be this Date object.
+        // 2. Let tv be ? thisTimeValue(O).
+        // 3. If tv is NaN, return "Invalid Date".
+        // 4. Let weekday be the Name of the entry in Table 63 with the Number WeekDay(tv).
+        // 5. Let month be the Name of the entry in Table 64 with the Number MonthFromTime(tv).
+        // 6. Let day be ToZeroPaddedDecimalString(ℝ(DateFromTime(tv)), 2).
+        // 7. Let yv be YearFromTime(tv).
+        // 8. If yv is +0𝔽 or yv > +0𝔽, let yearSign be the empty String; otherwise, let yearSign be "-".
+        // 9. Let paddedYear be ToZeroPaddedDecimalString(abs(ℝ(yv)), 4).
+        // 10. Return the string-concatenation of weekday, ",", the code unit 0x0020 (SPACE), day, the code unit 0x0020 (SPACE), month, the code unit 0x0020 (SPACE), yearSign, paddedYear, the code unit 0x0020 (SPACE), and TimeString(tv).
+        self.0.map_or_else(
             || "Invalid Date".to_string(),
-            |date_time| date_time.format("%a, %d %b %Y %H:%M:%S GMT").to_string(),
+            |t| {
+                format!(
+                    "{}, {:02} {} {} {}",
+                    WEEKDAY_NAMES[t.weekday().num_days_from_sunday() as usize],
+                    t.day(),
+                    MONTH_NAMES[t.month0() as usize],
+                    year_string(t.year()),
+                    time_string(&t)
+                )
+            },
         )
     }
 
@@ -1842,17 +2058,15 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.parse
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/parse
     pub(crate) fn parse(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        // This method is implementation-defined and discouraged, so we just require the same format as the string
-        // constructor.
-
-        if args.is_empty() {
-            return Ok(JsValue::nan());
-        }
-
-        match DateTime::parse_from_rfc3339(&args[0].to_string(context)?) {
-            Ok(v) => Ok(JsValue::new(v.naive_utc().timestamp_millis() as f64)),
-            _ => Ok(JsValue::new(f64::NAN)),
-        }
+        // 1. Let string be ? ToString(string).
+        let string = args.get_or_undefined(0).to_string(context)?;
+
+        // 2. Parse string as a date-time string in the Date Time String Format, falling back to
+        //    the implementation-specific legacy formats, and return the time value or NaN.
+        Ok(parser::parse(&string)
+            .and_then(|t| Self::time_clip(t.timestamp_millis() as f64))
+            .unwrap_or(f64::NAN)
+            .into())
     }
 
     /// `Date.UTC()`
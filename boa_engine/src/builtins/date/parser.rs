@@ -0,0 +1,401 @@
+//! Parsing of date-time strings.
+//!
+//! This module implements the [Date Time String Format][spec] of ECMAScript, which is a
+//! simplification of the ISO 8601 extended format, and a fallback parser for the legacy formats
+//! produced by `Date.prototype.toString`, `Date.prototype.toUTCString` and RFC 2822.
+//!
+//! The parsers produce a [`DateTimeRecord`] of the parsed fields, which can then be resolved to an
+//! instant with [`DateTimeRecord::to_utc`].
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-date-time-string-format
+
+use crate::builtins::iso8601::{parse_date_time_string_format, Cursor, UtcOffset};
+use chrono::{prelude::*, Duration, LocalResult};
+
+/// The English abbreviations of the months, as used by `Date.prototype.toString`.
+pub(crate) const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// The English abbreviations of the days of the week, starting on Sunday.
+pub(crate) const WEEKDAY_NAMES: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+/// The fields of a parsed date-time string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct DateTimeRecord {
+    pub(crate) year: i32,
+    /// The month, from `1` to `12`.
+    pub(crate) month: u32,
+    /// The day of the month, from `1`.
+    pub(crate) day: u32,
+    pub(crate) hour: u32,
+    pub(crate) minute: u32,
+    pub(crate) second: u32,
+    pub(crate) millisecond: u32,
+    /// The offset from UTC in minutes, or `None` if the string is in local time.
+    pub(crate) offset: Option<i32>,
+}
+
+impl DateTimeRecord {
+    /// Creates a record of the start of the given day, in UTC.
+    fn from_date(year: i32, month: u32, day: u32) -> Self {
+        Self {
+            year,
+            month,
+            day,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            millisecond: 0,
+            offset: Some(0),
+        }
+    }
+
+    /// Returns the date and time of the record, without applying its offset.
+    ///
+    /// Returns `None` if the fields do not represent a valid date and time. An hour of `24` is
+    /// the start of the next day.
+    pub(crate) fn to_naive(&self) -> Option<NaiveDateTime> {
+        let date = NaiveDate::from_ymd_opt(self.year, self.month, self.day)?;
+        if self.hour == 24 {
+            if self.minute != 0 || self.second != 0 || self.millisecond != 0 {
+                return None;
+            }
+            return date.succ_opt().map(|date| date.and_hms(0, 0, 0));
+        }
+        date.and_hms_milli_opt(self.hour, self.minute, self.second, self.millisecond)
+    }
+
+    /// Resolves the record to a date and time in UTC, interpreting it in the local time zone if
+    /// it has no offset.
+    pub(crate) fn to_utc(&self) -> Option<NaiveDateTime> {
+        let date_time = self.to_naive()?;
+        match self.offset {
+            Some(offset) => date_time.checked_sub_signed(Duration::minutes(i64::from(offset))),
+            None => match Local.from_local_datetime(&date_time) {
+                LocalResult::Single(local) | LocalResult::Ambiguous(local, _) => {
+                    Some(local.naive_utc())
+                }
+                // Times skipped by a transition are interpreted with the offset before it.
+                LocalResult::None => {
+                    let before = date_time.checked_sub_signed(Duration::hours(1))?;
+                    Local
+                        .from_local_datetime(&before)
+                        .earliest()
+                        .map(|local| local.naive_utc() + Duration::hours(1))
+                }
+            },
+        }
+    }
+}
+
+/// Parses a date-time string in any of the supported formats, returning it in UTC.
+///
+/// The strict Date Time String Format is tried first, falling back to the legacy formats.
+pub(crate) fn parse(text: &str) -> Option<NaiveDateTime> {
+    parse_date_time_string(text)
+        .or_else(|| parse_legacy(text))?
+        .to_utc()
+}
+
+/// Parses a string in the Date Time String Format.
+///
+/// Date-only forms are interpreted as UTC, and date-time forms without an offset are interpreted
+/// as local time.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date-time-string-format
+pub(crate) fn parse_date_time_string(text: &str) -> Option<DateTimeRecord> {
+    let parsed = parse_date_time_string_format(text).ok()?;
+    let mut record =
+        DateTimeRecord::from_date(parsed.year, u32::from(parsed.month), u32::from(parsed.day));
+
+    if let Some(time) = parsed.time {
+        record.hour = u32::from(time.hour);
+        record.minute = u32::from(time.minute);
+        record.second = u32::from(time.second);
+        record.millisecond = time.subsecond_nanoseconds / 1_000_000;
+        record.offset = parsed.offset.map(|offset| match offset {
+            UtcOffset::Z => 0,
+            UtcOffset::Offset(nanoseconds) => (nanoseconds / 60_000_000_000) as i32,
+        });
+    }
+
+    // Validate the fields against the range of dates.
+    record.to_naive()?;
+    Some(record)
+}
+
+/// Parses a string in one of the legacy formats.
+///
+/// The recognized formats are the output of `Date.prototype.toString`, like
+/// `Tue Feb 01 2022 00:00:00 GMT+0100 (Central European Standard Time)`, the output of
+/// `Date.prototype.toUTCString` and RFC 2822, like `Tue, 01 Feb 2022 00:00:00 GMT`, and numeric
+/// dates like `2/1/2022 10:00 PM` or `2022/02/01`. Strings without a time zone are interpreted as
+/// local time.
+pub(crate) fn parse_legacy(text: &str) -> Option<DateTimeRecord> {
+    let mut cursor = Cursor::new(text);
+
+    let mut year = None;
+    let mut month = None;
+    let mut day = None;
+    let mut time = None;
+    let mut post_meridiem = None;
+    let mut offset = None;
+    // Numbers that are not part of a time or a numeric date, with their number of digits.
+    let mut numbers: Vec<(i32, usize)> = Vec::new();
+
+    while let Some(byte) = cursor.peek() {
+        match byte {
+            b' ' | b'\t' | b'\n' | b'\r' | b',' => {
+                cursor.next_byte();
+            }
+            // Comments, like the time zone name of `Date.prototype.toString`.
+            b'(' => {
+                let mut depth = 0;
+                while let Some(byte) = cursor.next_byte() {
+                    match byte {
+                        b'(' => depth += 1,
+                        b')' => depth -= 1,
+                        _ => {}
+                    }
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                if depth != 0 {
+                    return None;
+                }
+            }
+            b'a'..=b'z' | b'A'..=b'Z' => {
+                let word = cursor.word().to_ascii_lowercase();
+                if let Some(index) = month_index(&word) {
+                    if month.replace(index + 1).is_some() {
+                        return None;
+                    }
+                } else if is_weekday(&word) {
+                    // Weekdays are redundant, and ignored.
+                } else if word == b"am" || word == b"pm" {
+                    if post_meridiem.replace(word == b"pm").is_some() {
+                        return None;
+                    }
+                } else if matches!(&word[..], b"gmt" | b"utc" | b"ut" | b"z") {
+                    // An optional numeric offset can follow, as in `GMT+0100`.
+                    let numeric = if matches!(cursor.peek(), Some(b'+' | b'-')) {
+                        numeric_offset(&mut cursor)?
+                    } else {
+                        0
+                    };
+                    if offset.replace(numeric).is_some() {
+                        return None;
+                    }
+                } else if let Some(zone) = zone_offset(&word) {
+                    if offset.replace(zone).is_some() {
+                        return None;
+                    }
+                } else {
+                    return None;
+                }
+            }
+            // Offsets follow the time, and signs before it belong to years.
+            b'+' | b'-' if time.is_some() => {
+                if offset.replace(numeric_offset(&mut cursor)?).is_some() {
+                    return None;
+                }
+            }
+            b'+' | b'-' => {
+                cursor.next_byte();
+                let digits = cursor.digit_run();
+                if digits.is_empty() || year.is_some() {
+                    return None;
+                }
+                let value = parse_number(digits)?;
+                year = Some(if byte == b'-' { -value } else { value });
+            }
+            b'0'..=b'9' => {
+                let digits = cursor.digit_run();
+                let value = parse_number(digits)?;
+                if cursor.next_if(b':') {
+                    // HH:mm, HH:mm:ss or HH:mm:ss.sss
+                    if time.is_some() {
+                        return None;
+                    }
+                    let minute = cursor.digits(2)?;
+                    let (second, millisecond) = if cursor.next_if(b':') {
+                        let second = cursor.digits(2)?;
+                        let millisecond = if cursor.next_if(b'.') {
+                            cursor.fraction_millis()?
+                        } else {
+                            0
+                        };
+                        (second, millisecond)
+                    } else {
+                        (0, 0)
+                    };
+                    time = Some((value as u32, minute, second, millisecond));
+                } else if matches!(cursor.peek(), Some(b'/' | b'-')) && month.is_none() {
+                    // M/D/Y or Y/M/D
+                    let separator = cursor.next_byte()?;
+                    let second = parse_number(cursor.digit_run())?;
+                    if !cursor.next_if(separator) {
+                        return None;
+                    }
+                    let third_digits = cursor.digit_run();
+                    let third = parse_number(third_digits)?;
+                    if digits.len() >= 3 {
+                        year = Some(value);
+                        month = Some(second as u32);
+                        day = Some(third as u32);
+                    } else {
+                        month = Some(value as u32);
+                        day = Some(second as u32);
+                        year = Some(two_digit_year(third, third_digits.len()));
+                    }
+                } else {
+                    numbers.push((value, digits.len()));
+                }
+            }
+            _ => return None,
+        }
+    }
+
+    // Assign the remaining numbers to the day and the year, which is either the number that
+    // cannot be a day or the last one, as in `Feb 01 2022` and `01 Feb 2022`.
+    match (&numbers[..], day, year) {
+        (&[], Some(_), Some(_)) => {}
+        (&[(value, _)], None, Some(_)) => day = Some(value as u32),
+        (&[(value, length)], Some(_), None) => year = Some(two_digit_year(value, length)),
+        (&[(first, first_length), (second, second_length)], None, None) => {
+            if first > 31 || first_length >= 3 {
+                year = Some(two_digit_year(first, first_length));
+                day = Some(second as u32);
+            } else {
+                day = Some(first as u32);
+                year = Some(two_digit_year(second, second_length));
+            }
+        }
+        _ => return None,
+    }
+
+    let (mut hour, minute, second, millisecond) = time.unwrap_or((0, 0, 0, 0));
+    match post_meridiem {
+        Some(_) if time.is_none() || hour == 0 || hour > 12 => return None,
+        Some(false) if hour == 12 => hour = 0,
+        Some(true) if hour < 12 => hour += 12,
+        _ => {}
+    }
+    if hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+
+    let record = DateTimeRecord {
+        year: year?,
+        month: month?,
+        day: day?,
+        hour,
+        minute,
+        second,
+        millisecond,
+        offset,
+    };
+    record.to_naive()?;
+    Some(record)
+}
+
+/// Parses a decimal number, failing for empty or too long numbers.
+fn parse_number(digits: &[u8]) -> Option<i32> {
+    if digits.is_empty() || digits.len() > 6 {
+        return None;
+    }
+    Some(
+        digits
+            .iter()
+            .fold(0, |value, digit| value * 10 + i32::from(digit - b'0')),
+    )
+}
+
+/// Resolves years written with up to two digits, as specified by RFC 2822.
+fn two_digit_year(year: i32, length: usize) -> i32 {
+    match year {
+        0..=49 if length <= 2 => year + 2000,
+        50..=99 if length <= 2 => year + 1900,
+        _ => year,
+    }
+}
+
+/// Consumes a numeric offset like `+0100`, `+01:00` or `+01`, returning it in minutes.
+fn numeric_offset(cursor: &mut Cursor<'_>) -> Option<i32> {
+    let sign = if cursor.next_byte()? == b'-' { -1 } else { 1 };
+    let digits = cursor.digit_run();
+    let (hours, minutes) = match digits.len() {
+        1 | 2 => {
+            let hours = parse_number(digits)?;
+            let minutes = if cursor.next_if(b':') {
+                cursor.digits(2)? as i32
+            } else {
+                0
+            };
+            (hours, minutes)
+        }
+        4 => {
+            let value = parse_number(digits)?;
+            (value / 100, value % 100)
+        }
+        _ => return None,
+    };
+    (hours <= 23 && minutes <= 59).then(|| sign * (hours * 60 + minutes))
+}
+
+/// Returns the index of the month named by `word`, which can be abbreviated to three letters.
+fn month_index(word: &[u8]) -> Option<u32> {
+    const FULL_NAMES: [&[u8]; 12] = [
+        b"january",
+        b"february",
+        b"march",
+        b"april",
+        b"may",
+        b"june",
+        b"july",
+        b"august",
+        b"september",
+        b"october",
+        b"november",
+        b"december",
+    ];
+    if word.len() < 3 {
+        return None;
+    }
+    FULL_NAMES
+        .iter()
+        .position(|name| name.starts_with(word))
+        .map(|index| index as u32)
+}
+
+/// Returns `true` if `word` names a day of the week, which can be abbreviated to three letters.
+fn is_weekday(word: &[u8]) -> bool {
+    const FULL_NAMES: [&[u8]; 7] = [
+        b"sunday",
+        b"monday",
+        b"tuesday",
+        b"wednesday",
+        b"thursday",
+        b"friday",
+        b"saturday",
+    ];
+    word.len() >= 3 && FULL_NAMES.iter().any(|name| name.starts_with(word))
+}
+
+/// Returns the offset in minutes of the North American time zones of RFC 2822.
+fn zone_offset(word: &[u8]) -> Option<i32> {
+    let hours = match word {
+        b"edt" => -4,
+        b"est" | b"cdt" => -5,
+        b"cst" | b"mdt" => -6,
+        b"mst" | b"pdt" => -7,
+        b"pst" => -8,
+        _ => return None,
+    };
+    Some(hours * 60)
+}
@@ -204,6 +204,77 @@ fn date_ctor_parse_call() {
     assert_eq!(Ok(JsValue::new(1591634775779f64)), date_time);
 }
 
+#[test]
+fn date_ctor_parse_date_time_string_format() {
+    let mut context = Context::default();
+
+    for (src, expected) in [
+        ("Date.parse('2020')", 1_577_836_800_000f64),
+        ("Date.parse('2020-06')", 1_590_969_600_000f64),
+        ("Date.parse('2020-06-08')", 1_591_574_400_000f64),
+        ("Date.parse('2020-06-08T09:16Z')", 1_591_607_760_000f64),
+        (
+            "Date.parse('2020-06-08T24:00:00.000Z')",
+            1_591_660_800_000f64,
+        ),
+        (
+            "Date.parse('+002020-06-08T09:16:15.7791Z')",
+            1_591_607_775_779f64,
+        ),
+        (
+            "Date.parse('-000001-01-01T00:00:00Z')",
+            -62_198_755_200_000f64,
+        ),
+    ] {
+        assert_eq!(
+            forward_val(&mut context, src),
+            Ok(JsValue::new(expected)),
+            "{src}"
+        );
+    }
+
+    for src in [
+        "Date.parse('2020-13-01')",
+        "Date.parse('2020-02-30')",
+        "Date.parse('2020-06-08T24:00:01Z')",
+        "Date.parse('-000000-01-01T00:00:00Z')",
+        "Date.parse('2020-06-08T09:16:15.Z')",
+    ] {
+        assert_eq!(forward(&mut context, src), "NaN", "{src}");
+    }
+}
+
+#[test]
+fn date_ctor_parse_legacy_formats() {
+    let mut context = Context::default();
+
+    for src in [
+        "Date.parse('Mon Jun 08 2020 11:16:15 GMT+0200 (Central European Summer Time)')",
+        "Date.parse('Mon, 08 Jun 2020 09:16:15 GMT')",
+        "Date.parse('8 June 2020 02:16:15 PDT')",
+        "Date.parse('Jun 8, 2020 9:16:15 AM UTC')",
+    ] {
+        assert_eq!(
+            forward_val(&mut context, src),
+            Ok(JsValue::new(1_591_607_775_000f64)),
+            "{src}"
+        );
+    }
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "var d = new Date(2020, 5, 8, 9, 16, 15); Date.parse(d.toString()) === d.getTime()"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Date.parse(d.toUTCString()) === d.getTime()"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "Date.parse('Jun 2020')"), "NaN");
+}
+
 #[test]
 fn date_ctor_utc_call() {
     let mut context = Context::default();
@@ -1173,6 +1244,27 @@ fn date_proto_to_iso_string() {
     assert_eq!(JsValue::new("2020-07-08T09:16:15.779Z"), actual);
 }
 
+#[test]
+fn date_proto_to_iso_string_expanded_years() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Date(Date.UTC(10000, 0, 1)).toISOString()"
+        ),
+        "\"+010000-01-01T00:00:00.000Z\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Date(Date.UTC(-1, 0, 1)).toISOString()"),
+        "\"-000001-01-01T00:00:00.000Z\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Date(Date.UTC(-1, 0, 1)).toUTCString()"),
+        "\"Fri, 01 Jan -0001 00:00:00 GMT\""
+    );
+}
+
 #[test]
 fn date_proto_to_json() {
     let mut context = Context::default();
@@ -0,0 +1,377 @@
+//! This module implements the global `AsyncDisposableStack` object.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncDisposableStack
+
+use super::{DisposableStack, DisposeCapability, DisposeHint};
+use crate::{
+    builtins::{error::SuppressedError, promise::PromiseCapability, BuiltIn, JsArgs, Promise},
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Cell, Finalize, Gc, Trace};
+use boa_profiler::Profiler;
+use tap::{Conv, Pipe};
+
+/// The `AsyncDisposableStack` builtin object.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AsyncDisposableStack;
+
+impl BuiltIn for AsyncDisposableStack {
+    const NAME: &'static str = "AsyncDisposableStack";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let get_disposed = FunctionBuilder::native(context, Self::get_disposed)
+            .name("get disposed")
+            .constructor(false)
+            .build();
+
+        let dispose_async = FunctionBuilder::native(context, Self::dispose_async)
+            .name("disposeAsync")
+            .length(0)
+            .constructor(false)
+            .build();
+
+        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context
+                .intrinsics()
+                .constructors()
+                .async_disposable_stack()
+                .clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .accessor(
+            "disposed",
+            Some(get_disposed),
+            None,
+            Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+        )
+        .property("disposeAsync", dispose_async.clone(), attribute)
+        .property(WellKnownSymbols::async_dispose(), dispose_async, attribute)
+        .method(Self::r#use, "use", 1)
+        .method(Self::adopt, "adopt", 2)
+        .method(Self::defer, "defer", 1)
+        .method(Self::r#move, "move", 0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+/// The state of an asynchronous disposal, shared by the reactions awaiting each resource.
+#[derive(Debug, Trace, Finalize)]
+struct AsyncDisposal {
+    /// The resources that remain to be disposed.
+    capability: DisposeCapability,
+    /// The error thrown so far, if any.
+    error: Option<JsValue>,
+    /// The promise settled once every resource has been disposed.
+    promise_capability: PromiseCapability,
+}
+
+impl AsyncDisposableStack {
+    const LENGTH: usize = 0;
+
+    /// `AsyncDisposableStack ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncDisposableStack/AsyncDisposableStack
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin AsyncDisposableStack constructor without new is forbidden",
+            );
+        }
+
+        // 2. Let asyncDisposableStack be ? OrdinaryCreateFromConstructor(NewTarget, "%AsyncDisposableStack.prototype%", « [[AsyncDisposableState]], [[DisposeCapability]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::async_disposable_stack,
+            context,
+        )?;
+
+        // 3. Set asyncDisposableStack.[[AsyncDisposableState]] to pending.
+        // 4. Set asyncDisposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 5. Return asyncDisposableStack.
+        Ok(DisposableStack::create(prototype, DisposableStack::default(), true).into())
+    }
+
+    /// `get AsyncDisposableStack.prototype.disposed`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-get-asyncdisposablestack.prototype.disposed
+    pub(crate) fn get_disposed(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        DisposableStack::disposed(this, true, context)
+    }
+
+    /// `AsyncDisposableStack.prototype.use ( value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.use
+    pub(crate) fn r#use(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        DisposableStack::use_value(this, args, DisposeHint::Async, context)
+    }
+
+    /// `AsyncDisposableStack.prototype.adopt ( value, onDisposeAsync )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.adopt
+    pub(crate) fn adopt(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        DisposableStack::adopt_value(this, args, DisposeHint::Async, context)
+    }
+
+    /// `AsyncDisposableStack.prototype.defer ( onDisposeAsync )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.defer
+    pub(crate) fn defer(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        DisposableStack::defer_callback(this, args, DisposeHint::Async, context)
+    }
+
+    /// `AsyncDisposableStack.prototype.move ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.move
+    pub(crate) fn r#move(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        DisposableStack::move_resources(this, true, context)
+    }
+
+    /// `AsyncDisposableStack.prototype.disposeAsync ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.disposeAsync
+    pub(crate) fn dispose_async(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let promise_capability = PromiseCapability::new(
+            &context
+                .intrinsics()
+                .constructors()
+                .promise()
+                .constructor()
+                .into(),
+            context,
+        )
+        .expect("cannot fail with the %Promise% constructor");
+
+        // 3. If asyncDisposableStack does not have an [[AsyncDisposableState]] internal slot, then
+        let obj = match DisposableStack::this_stack(this, true, context) {
+            Ok(obj) => obj,
+            Err(error) => {
+                // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+                promise_capability
+                    .reject()
+                    .call(&JsValue::undefined(), &[error], context)
+                    .expect("cannot fail per spec");
+
+                // b. Return promiseCapability.[[Promise]].
+                return Ok(promise_capability.promise().clone().into());
+            }
+        };
+
+        let capability = {
+            let mut obj = obj.borrow_mut();
+            let stack = obj
+                .as_disposable_stack_mut()
+                .expect("brand was checked by `this_stack`");
+
+            // 4. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, then
+            if stack.disposed {
+                drop(obj);
+
+                // a. Perform ! Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+                promise_capability
+                    .resolve()
+                    .call(&JsValue::undefined(), &[JsValue::undefined()], context)
+                    .expect("cannot fail per spec");
+
+                // b. Return promiseCapability.[[Promise]].
+                return Ok(promise_capability.promise().clone().into());
+            }
+
+            // 5. Set asyncDisposableStack.[[AsyncDisposableState]] to disposed.
+            stack.disposed = true;
+            stack.capability.take()
+        };
+
+        // 6. Let result be DisposeResources(asyncDisposableStack.[[DisposeCapability]], NormalCompletion(undefined)).
+        // 7. IfAbruptRejectPromise(result, promiseCapability).
+        // 8. Perform ! Call(promiseCapability.[[Resolve]], undefined, « result »).
+        // 9. Return promiseCapability.[[Promise]].
+        let promise = promise_capability.promise().clone();
+        Self::continue_disposal(
+            &Gc::new(Cell::new(AsyncDisposal {
+                capability,
+                error: None,
+                promise_capability,
+            })),
+            context,
+        );
+        Ok(promise.into())
+    }
+
+    /// Disposes the remaining resources of an asynchronous `DisposeResources`, suspending at
+    /// the first result that has to be awaited.
+    ///
+    /// Once every resource is disposed, the promise of the disposal is rejected with the
+    /// resulting error, or resolved with `undefined` if no error was thrown.
+    fn continue_disposal(disposal: &Gc<Cell<AsyncDisposal>>, context: &mut Context) {
+        loop {
+            let resource = disposal.borrow_mut().capability.pop();
+
+            let resource = if let Some(resource) = resource {
+                resource
+            } else {
+                let (function, value) = {
+                    let disposal = disposal.borrow();
+                    let capability = &disposal.promise_capability;
+                    match &disposal.error {
+                        Some(error) => (capability.reject().clone(), error.clone()),
+                        None => (capability.resolve().clone(), JsValue::undefined()),
+                    }
+                };
+                function
+                    .call(&JsValue::undefined(), &[value], context)
+                    .expect("cannot fail per spec");
+                return;
+            };
+
+            // a. Let result be Dispose(resource.[[ResourceValue]], resource.[[Hint]], resource.[[DisposeMethod]]).
+            //     Dispose ( V, hint, method )
+            //     3. If hint is async-dispose, then
+            //         a. Perform ? Await(result).
+            let result = resource.dispose(context).and_then(|result| {
+                let result = if resource.is_async() {
+                    result
+                } else {
+                    JsValue::undefined()
+                };
+                Promise::promise_resolve(
+                    &context.intrinsics().constructors().promise().constructor(),
+                    result,
+                    context,
+                )
+            });
+
+            match result {
+                Ok(promise) => {
+                    let on_fulfilled = FunctionBuilder::closure_with_captures(
+                        context,
+                        |_, _, disposal: &mut Gc<Cell<AsyncDisposal>>, context| {
+                            Self::continue_disposal(disposal, context);
+                            Ok(JsValue::undefined())
+                        },
+                        disposal.clone(),
+                    )
+                    .name("")
+                    .length(1)
+                    .build();
+
+                    let on_rejected = FunctionBuilder::closure_with_captures(
+                        context,
+                        |_, args, disposal: &mut Gc<Cell<AsyncDisposal>>, context| {
+                            Self::suppress(disposal, args.get_or_undefined(0).clone(), context);
+                            Self::continue_disposal(disposal, context);
+                            Ok(JsValue::undefined())
+                        },
+                        disposal.clone(),
+                    )
+                    .name("")
+                    .length(1)
+                    .build();
+
+                    Promise::perform_promise_then(
+                        &promise,
+                        &on_fulfilled.into(),
+                        &on_rejected.into(),
+                        None,
+                        context,
+                    );
+                    return;
+                }
+                // b. If result.[[Type]] is throw, then
+                Err(error) => Self::suppress(disposal, error, context),
+            }
+        }
+    }
+
+    /// Records an error thrown while disposing a resource, suppressing the previous error.
+    fn suppress(disposal: &Gc<Cell<AsyncDisposal>>, error: JsValue, context: &mut Context) {
+        let suppressed = disposal.borrow_mut().error.take();
+        let error = match suppressed {
+            // i. If completion.[[Type]] is throw, then
+            //     1-6. Set completion to ThrowCompletion(a new SuppressedError).
+            Some(suppressed) => SuppressedError::create(error, suppressed, context),
+            // ii. Else,
+            //     1. Set completion to result.
+            None => error,
+        };
+        disposal.borrow_mut().error = Some(error);
+    }
+}
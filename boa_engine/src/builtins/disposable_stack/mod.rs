@@ -0,0 +1,691 @@
+//! This module implements the global `DisposableStack` object, and the resource disposal
+//! operations shared with `AsyncDisposableStack` and with `using` declarations.
+//!
+//! A `DisposableStack` aggregates disposable resources, disposing all of them in reverse order
+//! of registration when the stack itself is disposed.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DisposableStack
+
+mod async_disposable_stack;
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) use async_disposable_stack::AsyncDisposableStack;
+
+use super::{error::SuppressedError, BuiltIn, JsArgs};
+use crate::{
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use tap::{Conv, Pipe};
+
+/// The kind of disposal a resource was registered for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DisposeHint {
+    /// The resource is disposed by calling its `@@dispose` method.
+    Sync,
+    /// The resource is disposed by calling and awaiting its `@@asyncDispose` method.
+    Async,
+}
+
+/// A `DisposableResource` record.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposableresource-records
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct DisposableResource {
+    /// `[[ResourceValue]]`
+    value: JsValue,
+    /// `[[Hint]]`
+    #[unsafe_ignore_trace]
+    hint: DisposeHint,
+    /// `[[DisposeMethod]]`
+    method: Option<JsObject>,
+}
+
+impl DisposableResource {
+    /// `CreateDisposableResource ( V, hint )`
+    ///
+    /// Creates the resource for a value registered without an explicit dispose method, looking up
+    /// the method on the value itself. Returns `None` for `null` and `undefined` registered for
+    /// synchronous disposal, which are skipped.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-createdisposableresource
+    pub(crate) fn from_value(
+        value: &JsValue,
+        hint: DisposeHint,
+        context: &mut Context,
+    ) -> JsResult<Option<Self>> {
+        // 1. If V is either null or undefined, then
+        if value.is_null_or_undefined() {
+            // a. If hint is sync-dispose, return unused.
+            if hint == DisposeHint::Sync {
+                return Ok(None);
+            }
+
+            // b. Set V to undefined.
+            // c. Set method to undefined.
+            return Ok(Some(Self {
+                value: JsValue::undefined(),
+                hint,
+                method: None,
+            }));
+        }
+
+        // 2. Else,
+        //     a. If V is not an Object, throw a TypeError exception.
+        if !value.is_object() {
+            return context.throw_type_error("value to dispose is not an object");
+        }
+
+        //     b. Set method to ? GetDisposeMethod(V, hint).
+        //     c. If method is undefined, throw a TypeError exception.
+        let (method, hint) = match Self::get_dispose_method(value, hint, context)? {
+            Some(method) => method,
+            None => return context.throw_type_error("value to dispose has no dispose method"),
+        };
+
+        // 3. Return the DisposableResource Record { [[ResourceValue]]: V, [[Hint]]: hint, [[DisposeMethod]]: method }.
+        Ok(Some(Self {
+            value: value.clone(),
+            hint,
+            method: Some(method),
+        }))
+    }
+
+    /// `CreateDisposableResource ( V, hint, method )`
+    ///
+    /// Creates the resource for a dispose method registered without a value, such as the
+    /// callbacks of `adopt` and `defer`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-createdisposableresource
+    pub(crate) fn from_method(method: JsObject, hint: DisposeHint) -> Self {
+        Self {
+            value: JsValue::undefined(),
+            hint,
+            method: Some(method),
+        }
+    }
+
+    /// `GetDisposeMethod ( V, hint )`
+    ///
+    /// The returned hint is `Sync` when an asynchronous disposal falls back to `@@dispose`, in
+    /// which case the result of the method is not awaited.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-getdisposemethod
+    fn get_dispose_method(
+        value: &JsValue,
+        hint: DisposeHint,
+        context: &mut Context,
+    ) -> JsResult<Option<(JsObject, DisposeHint)>> {
+        // 1. If hint is async-dispose, then
+        if hint == DisposeHint::Async {
+            // a. Let method be ? GetMethod(V, @@asyncDispose).
+            // b. If method is undefined, then
+            //     i. Set method to ? GetMethod(V, @@dispose).
+            if let Some(method) = value.get_method(WellKnownSymbols::async_dispose(), context)? {
+                return Ok(Some((method, DisposeHint::Async)));
+            }
+        }
+
+        // 2. Else,
+        //     a. Let method be ? GetMethod(V, @@dispose).
+        // 3. Return method.
+        Ok(value
+            .get_method(WellKnownSymbols::dispose(), context)?
+            .map(|method| (method, DisposeHint::Sync)))
+    }
+
+    /// `Dispose ( V, hint, method )`
+    ///
+    /// Calls the dispose method of the resource, returning its result. Awaiting the result of
+    /// asynchronous disposals is left to the caller.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-dispose
+    pub(crate) fn dispose(&self, context: &mut Context) -> JsResult<JsValue> {
+        // 1. If method is undefined, let result be undefined.
+        // 2. Else, let result be ? Call(method, V).
+        match &self.method {
+            Some(method) => method.call(&self.value, &[], context),
+            None => Ok(JsValue::undefined()),
+        }
+    }
+
+    /// Whether the result of the dispose method has to be awaited.
+    pub(crate) fn is_async(&self) -> bool {
+        self.hint == DisposeHint::Async
+    }
+}
+
+/// A `DisposeCapability` record, holding the resources registered in a scope or stack.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposecapability-records
+#[derive(Debug, Clone, Default, Trace, Finalize)]
+pub(crate) struct DisposeCapability {
+    /// `[[DisposableResourceStack]]`
+    stack: Vec<DisposableResource>,
+}
+
+impl DisposeCapability {
+    /// `AddDisposableResource ( disposeCapability, V, hint [ , method ] )`
+    ///
+    /// The resource is created beforehand by [`DisposableResource::from_value`] or
+    /// [`DisposableResource::from_method`].
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-adddisposableresource
+    pub(crate) fn add(&mut self, resource: DisposableResource) {
+        // 3. Append resource to disposeCapability.[[DisposableResourceStack]].
+        self.stack.push(resource);
+    }
+
+    /// Takes the registered resources, leaving the capability empty.
+    pub(crate) fn take(&mut self) -> Self {
+        std::mem::take(self)
+    }
+
+    /// Removes the most recently registered resource.
+    pub(crate) fn pop(&mut self) -> Option<DisposableResource> {
+        self.stack.pop()
+    }
+
+    /// `DisposeResources ( disposeCapability, completion )`
+    ///
+    /// Disposes the resources synchronously, in reverse order of registration. An error thrown
+    /// while disposing replaces the completion, suppressing a previous error in a
+    /// `SuppressedError`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    pub(crate) fn dispose_resources(
+        self,
+        mut completion: JsResult<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. For each element resource of disposeCapability.[[DisposableResourceStack]], in reverse list order, do
+        for resource in self.stack.into_iter().rev() {
+            // a. Let result be Dispose(resource.[[ResourceValue]], resource.[[Hint]], resource.[[DisposeMethod]]).
+            // b. If result.[[Type]] is throw, then
+            if let Err(error) = resource.dispose(context) {
+                completion = Err(match completion {
+                    // i. If completion.[[Type]] is throw, then
+                    //     1. Set result to result.[[Value]].
+                    //     2. Let suppressed be completion.[[Value]].
+                    //     3. Let error be a newly created SuppressedError object.
+                    //     4. Perform CreateNonEnumerableDataPropertyOrThrow(error, "error", result).
+                    //     5. Perform CreateNonEnumerableDataPropertyOrThrow(error, "suppressed", suppressed).
+                    //     6. Set completion to ThrowCompletion(error).
+                    Err(suppressed) => SuppressedError::create(error, suppressed, context),
+                    // ii. Else,
+                    //     1. Set completion to result.
+                    Ok(_) => error,
+                });
+            }
+        }
+
+        // 2. NOTE: After disposeCapability has been disposed, it will never be used again.
+        // 3. Return completion.
+        completion
+    }
+}
+
+/// The internal slots of a `DisposableStack` or `AsyncDisposableStack` object.
+#[derive(Debug, Default, Trace, Finalize)]
+pub struct DisposableStack {
+    /// `[[DisposableState]]`, `true` when disposed.
+    disposed: bool,
+    /// `[[DisposeCapability]]`
+    capability: DisposeCapability,
+}
+
+impl BuiltIn for DisposableStack {
+    const NAME: &'static str = "DisposableStack";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let get_disposed = FunctionBuilder::native(context, Self::get_disposed)
+            .name("get disposed")
+            .constructor(false)
+            .build();
+
+        let dispose = FunctionBuilder::native(context, Self::dispose)
+            .name("dispose")
+            .length(0)
+            .constructor(false)
+            .build();
+
+        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context
+                .intrinsics()
+                .constructors()
+                .disposable_stack()
+                .clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .accessor(
+            "disposed",
+            Some(get_disposed),
+            None,
+            Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+        )
+        .property("dispose", dispose.clone(), attribute)
+        .property(WellKnownSymbols::dispose(), dispose, attribute)
+        .method(Self::r#use, "use", 1)
+        .method(Self::adopt, "adopt", 2)
+        .method(Self::defer, "defer", 1)
+        .method(Self::r#move, "move", 0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+/// The captures of the dispose callback created by `adopt`.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct AdoptCaptures {
+    value: JsValue,
+    on_dispose: JsObject,
+}
+
+impl DisposableStack {
+    const LENGTH: usize = 0;
+
+    /// Gets the `this` object, checking that it is a `DisposableStack` or an
+    /// `AsyncDisposableStack` depending on `is_async`.
+    fn this_stack(this: &JsValue, is_async: bool, context: &mut Context) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|obj| {
+                let obj = obj.borrow();
+                if is_async {
+                    obj.is_async_disposable_stack()
+                } else {
+                    obj.is_disposable_stack()
+                }
+            })
+            .cloned()
+            .ok_or_else(|| {
+                context.construct_type_error(if is_async {
+                    "'this' is not an AsyncDisposableStack"
+                } else {
+                    "'this' is not a DisposableStack"
+                })
+            })
+    }
+
+    /// Runs `f` with the internal slots of the stack `obj`, throwing a `ReferenceError` if the
+    /// stack has already been disposed.
+    fn with_pending_stack<R>(
+        obj: &JsObject,
+        context: &mut Context,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> JsResult<R> {
+        let mut obj = obj.borrow_mut();
+        let stack = obj
+            .as_disposable_stack_mut()
+            .expect("brand was checked by `this_stack`");
+        if stack.disposed {
+            drop(obj);
+            return context.throw_reference_error("the disposable stack is already disposed");
+        }
+        Ok(f(stack))
+    }
+
+    /// Creates a new stack object with the given prototype, for a `DisposableStack` or an
+    /// `AsyncDisposableStack` depending on `is_async`.
+    fn create(prototype: JsObject, stack: Self, is_async: bool) -> JsObject {
+        JsObject::from_proto_and_data(
+            prototype,
+            if is_async {
+                ObjectData::async_disposable_stack(stack)
+            } else {
+                ObjectData::disposable_stack(stack)
+            },
+        )
+    }
+
+    /// `DisposableStack ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DisposableStack/DisposableStack
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin DisposableStack constructor without new is forbidden",
+            );
+        }
+
+        // 2. Let disposableStack be ? OrdinaryCreateFromConstructor(NewTarget, "%DisposableStack.prototype%", « [[DisposableState]], [[DisposeCapability]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::disposable_stack,
+            context,
+        )?;
+
+        // 3. Set disposableStack.[[DisposableState]] to pending.
+        // 4. Set disposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 5. Return disposableStack.
+        Ok(Self::create(prototype, Self::default(), false).into())
+    }
+
+    /// `get DisposableStack.prototype.disposed`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-get-disposablestack.prototype.disposed
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DisposableStack/disposed
+    pub(crate) fn get_disposed(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::disposed(this, false, context)
+    }
+
+    /// Shared implementation of the `disposed` getters.
+    fn disposed(this: &JsValue, is_async: bool, context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let obj = Self::this_stack(this, is_async, context)?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, return true.
+        // 4. Otherwise, return false.
+        let disposed = obj
+            .borrow_mut()
+            .as_disposable_stack_mut()
+            .expect("brand was checked by `this_stack`")
+            .disposed;
+        Ok(disposed.into())
+    }
+
+    /// `DisposableStack.prototype.dispose ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.dispose
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DisposableStack/dispose
+    pub(crate) fn dispose(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let obj = Self::this_stack(this, false, context)?;
+
+        let capability = {
+            let mut obj = obj.borrow_mut();
+            let stack = obj
+                .as_disposable_stack_mut()
+                .expect("brand was checked by `this_stack`");
+
+            // 3. If disposableStack.[[DisposableState]] is disposed, return undefined.
+            if stack.disposed {
+                return Ok(JsValue::undefined());
+            }
+
+            // 4. Set disposableStack.[[DisposableState]] to disposed.
+            stack.disposed = true;
+            stack.capability.take()
+        };
+
+        // 5. Return DisposeResources(disposableStack.[[DisposeCapability]], NormalCompletion(undefined)).
+        capability.dispose_resources(Ok(JsValue::undefined()), context)
+    }
+
+    /// `DisposableStack.prototype.use ( value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.use
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DisposableStack/use
+    pub(crate) fn r#use(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::use_value(this, args, DisposeHint::Sync, context)
+    }
+
+    /// Shared implementation of the `use` methods.
+    fn use_value(
+        this: &JsValue,
+        args: &[JsValue],
+        hint: DisposeHint,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let obj = Self::this_stack(this, hint == DisposeHint::Async, context)?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        Self::with_pending_stack(&obj, context, |_| ())?;
+
+        // 4. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], value, sync-dispose).
+        let value = args.get_or_undefined(0);
+        if let Some(resource) = DisposableResource::from_value(value, hint, context)? {
+            Self::with_pending_stack(&obj, context, |stack| stack.capability.add(resource))?;
+        }
+
+        // 5. Return value.
+        Ok(value.clone())
+    }
+
+    /// `DisposableStack.prototype.adopt ( value, onDispose )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.adopt
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DisposableStack/adopt
+    pub(crate) fn adopt(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::adopt_value(this, args, DisposeHint::Sync, context)
+    }
+
+    /// Shared implementation of the `adopt` methods.
+    fn adopt_value(
+        this: &JsValue,
+        args: &[JsValue],
+        hint: DisposeHint,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let obj = Self::this_stack(this, hint == DisposeHint::Async, context)?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        Self::with_pending_stack(&obj, context, |_| ())?;
+
+        // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+        let value = args.get_or_undefined(0).clone();
+        let on_dispose = args
+            .get_or_undefined(1)
+            .as_callable()
+            .cloned()
+            .ok_or_else(|| context.construct_type_error("onDispose is not callable"))?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures value and onDispose and performs the following steps when called:
+        //     a. Return ? Call(onDispose, undefined, « value »).
+        // 6. Let F be CreateBuiltinFunction(closure, 0, "", « »).
+        let f = FunctionBuilder::closure_with_captures(
+            context,
+            |_, _, captures: &mut AdoptCaptures, context| {
+                captures
+                    .on_dispose
+                    .call(&JsValue::undefined(), &[captures.value.clone()], context)
+            },
+            AdoptCaptures {
+                value: value.clone(),
+                on_dispose,
+            },
+        )
+        .name("")
+        .length(0)
+        .build();
+
+        // 7. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], undefined, sync-dispose, F).
+        let resource = DisposableResource::from_method(f, hint);
+        Self::with_pending_stack(&obj, context, |stack| stack.capability.add(resource))?;
+
+        // 8. Return value.
+        Ok(value)
+    }
+
+    /// `DisposableStack.prototype.defer ( onDispose )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.defer
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DisposableStack/defer
+    pub(crate) fn defer(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::defer_callback(this, args, DisposeHint::Sync, context)
+    }
+
+    /// Shared implementation of the `defer` methods.
+    fn defer_callback(
+        this: &JsValue,
+        args: &[JsValue],
+        hint: DisposeHint,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let obj = Self::this_stack(this, hint == DisposeHint::Async, context)?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        Self::with_pending_stack(&obj, context, |_| ())?;
+
+        // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+        let on_dispose = args
+            .get_or_undefined(0)
+            .as_callable()
+            .cloned()
+            .ok_or_else(|| context.construct_type_error("onDispose is not callable"))?;
+
+        // 5. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], undefined, sync-dispose, onDispose).
+        let resource = DisposableResource::from_method(on_dispose, hint);
+        Self::with_pending_stack(&obj, context, |stack| stack.capability.add(resource))?;
+
+        // 6. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `DisposableStack.prototype.move ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.move
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DisposableStack/move
+    pub(crate) fn r#move(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::move_resources(this, false, context)
+    }
+
+    /// Shared implementation of the `move` methods.
+    fn move_resources(this: &JsValue, is_async: bool, context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let obj = Self::this_stack(this, is_async, context)?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        // 6. Set newDisposableStack.[[DisposeCapability]] to disposableStack.[[DisposeCapability]].
+        // 7. Set disposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 8. Set disposableStack.[[DisposableState]] to disposed.
+        let capability = Self::with_pending_stack(&obj, context, |stack| {
+            stack.disposed = true;
+            stack.capability.take()
+        })?;
+
+        // 4. Let newDisposableStack be ? OrdinaryCreateFromConstructor(%DisposableStack%, "%DisposableStack.prototype%", « [[DisposableState]], [[DisposeCapability]] »).
+        // 5. Set newDisposableStack.[[DisposableState]] to pending.
+        let constructors = context.intrinsics().constructors();
+        let prototype = if is_async {
+            constructors.async_disposable_stack().prototype()
+        } else {
+            constructors.disposable_stack().prototype()
+        };
+        let stack = Self {
+            disposed: false,
+            capability,
+        };
+
+        // 9. Return newDisposableStack.
+        Ok(Self::create(prototype, stack, is_async).into())
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{forward, Context};
+
+#[test]
+fn disposable_stack_dispose_order() {
+    let mut context = Context::default();
+    let init = r#"
+        var log = [];
+        var stack = new DisposableStack();
+        stack.use({ [Symbol.dispose]() { log.push("use"); } });
+        stack.adopt("value", (v) => log.push("adopt " + v));
+        stack.defer(() => log.push("defer"));
+        stack.dispose();
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"defer,adopt value,use\""
+    );
+    assert_eq!(forward(&mut context, "stack.disposed"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { stack.defer(() => {}); } catch (e) { e.name }"
+        ),
+        "\"ReferenceError\""
+    );
+    assert_eq!(
+        forward(&mut context, "stack.dispose === stack[Symbol.dispose]"),
+        "true"
+    );
+}
+
+#[test]
+fn disposable_stack_move() {
+    let mut context = Context::default();
+    let init = r#"
+        var log = [];
+        var stack = new DisposableStack();
+        stack.defer(() => log.push("moved"));
+        var other = stack.move();
+        stack.dispose();
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "log.length"), "0");
+    assert_eq!(forward(&mut context, "stack.disposed"), "true");
+    assert_eq!(forward(&mut context, "other.disposed"), "false");
+    forward(&mut context, "other.dispose();");
+    assert_eq!(forward(&mut context, "log.join()"), "\"moved\"");
+}
+
+#[test]
+fn disposable_stack_suppressed_error() {
+    let mut context = Context::default();
+    let init = r#"
+        var stack = new DisposableStack();
+        stack.defer(() => { throw "first"; });
+        stack.defer(() => { throw "second"; });
+        var error;
+        try { stack.dispose(); } catch (e) { error = e; }
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "error.name"), "\"SuppressedError\"");
+    assert_eq!(forward(&mut context, "error.error"), "\"first\"");
+    assert_eq!(forward(&mut context, "error.suppressed"), "\"second\"");
+    assert_eq!(
+        forward(&mut context, "error instanceof SuppressedError"),
+        "true"
+    );
+}
+
+#[test]
+fn using_declaration_disposes_in_reverse_order() {
+    let mut context = Context::default();
+    let init = r#"
+        var log = [];
+        function resource(name) {
+            return { [Symbol.dispose]() { log.push(name); } };
+        }
+        {
+            using a = resource("a"), b = resource("b");
+            using c = null;
+            log.push("body");
+        }
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "log.join()"), "\"body,b,a\"");
+}
+
+#[test]
+fn using_declaration_error_is_suppressed() {
+    let mut context = Context::default();
+    let init = r#"
+        var error;
+        try {
+            using a = { [Symbol.dispose]() { throw "dispose"; } };
+            throw "body";
+        } catch (e) {
+            error = e;
+        }
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "error.error"), "\"dispose\"");
+    assert_eq!(forward(&mut context, "error.suppressed"), "\"body\"");
+}
+
+#[test]
+fn using_declaration_requires_dispose_method() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { { using a = {}; } } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
@@ -13,10 +13,12 @@
 use crate::{
     builtins::BuiltIn,
     context::intrinsics::StandardConstructors,
+    error::format_stack,
     object::{
-        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
     },
-    property::Attribute,
+    property::{Attribute, PropertyDescriptor},
     Context, JsResult, JsString, JsValue,
 };
 use boa_profiler::Profiler;
@@ -26,6 +28,7 @@ pub(crate) mod aggregate;
 pub(crate) mod eval;
 pub(crate) mod range;
 pub(crate) mod reference;
+pub(crate) mod suppressed;
 pub(crate) mod syntax;
 pub(crate) mod r#type;
 pub(crate) mod uri;
@@ -38,6 +41,7 @@ pub(crate) use self::eval::EvalError;
 pub(crate) use self::r#type::TypeError;
 pub(crate) use self::range::RangeError;
 pub(crate) use self::reference::ReferenceError;
+pub(crate) use self::suppressed::SuppressedError;
 pub(crate) use self::syntax::SyntaxError;
 pub(crate) use self::uri::UriError;
 
@@ -54,6 +58,17 @@ impl BuiltIn for Error {
         let _timer = Profiler::global().start_event(Self::NAME, "init");
 
         let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+
+        let get_stack = FunctionBuilder::native(context, Self::get_stack)
+            .name("get stack")
+            .constructor(false)
+            .build();
+        let set_stack = FunctionBuilder::native(context, Self::set_stack)
+            .name("set stack")
+            .length(1)
+            .constructor(false)
+            .build();
+
         ConstructorBuilder::with_standard_constructor(
             context,
             Self::constructor,
@@ -64,6 +79,12 @@ impl BuiltIn for Error {
         .property("name", Self::NAME, attribute)
         .property("message", "", attribute)
         .method(Self::to_string, "toString", 0)
+        .accessor(
+            "stack",
+            Some(get_stack),
+            Some(set_stack),
+            Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
         .build()
         .conv::<JsValue>()
         .pipe(Some)
@@ -107,7 +128,7 @@ impl Error {
         // 2. Let O be ? OrdinaryCreateFromConstructor(newTarget, "%Error.prototype%", « [[ErrorData]] »).
         let prototype =
             get_prototype_from_constructor(new_target, StandardConstructors::error, context)?;
-        let o = JsObject::from_proto_and_data(prototype, ObjectData::error());
+        let o = JsObject::from_proto_and_data(prototype, ObjectData::error(context));
 
         // 3. If message is not undefined, then
         let message = args.get_or_undefined(0);
@@ -184,4 +205,64 @@ impl Error {
         // the code unit 0x0020 (SPACE), and msg.
         Ok(format!("{name}: {msg}").into())
     }
+
+    /// `get Error.prototype.stack`
+    ///
+    /// Returns the call stack captured when the error object was created, formatted as the
+    /// result of `Error.prototype.toString` followed by one line per frame, innermost first.
+    ///
+    /// Returns `undefined` if the `this` value is not an error object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error/stack
+    pub(crate) fn get_stack(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let frames = match this.as_object() {
+            Some(o) => match o.borrow().as_error_stack() {
+                Some(frames) => frames.to_vec(),
+                None => return Ok(JsValue::undefined()),
+            },
+            None => return Ok(JsValue::undefined()),
+        };
+
+        let header = Self::to_string(this, &[], context)?.to_string(context)?;
+        Ok(format_stack(&header, &frames).into())
+    }
+
+    /// `set Error.prototype.stack`
+    ///
+    /// Replaces the stack of the `this` object with an own data property holding the new value.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error/stack
+    pub(crate) fn set_stack(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let o = if let Some(o) = this.as_object() {
+            o
+        } else {
+            return context.throw_type_error("'this' is not an Object");
+        };
+
+        o.define_property_or_throw(
+            "stack",
+            PropertyDescriptor::builder()
+                .value(args.get_or_undefined(0).clone())
+                .writable(true)
+                .enumerable(false)
+                .configurable(true),
+            context,
+        )?;
+
+        Ok(JsValue::undefined())
+    }
 }
@@ -74,7 +74,7 @@ impl ReferenceError {
             StandardConstructors::reference_error,
             context,
         )?;
-        let o = JsObject::from_proto_and_data(prototype, ObjectData::error());
+        let o = JsObject::from_proto_and_data(prototype, ObjectData::error(context));
 
         // 3. If message is not undefined, then
         let message = args.get_or_undefined(0);
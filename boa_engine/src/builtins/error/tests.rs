@@ -1,4 +1,4 @@
-use crate::{forward, Context};
+use crate::{forward, forward_val, Context, JsError};
 
 #[test]
 fn error_to_string() {
@@ -84,3 +84,211 @@ fn uri_error_to_string() {
         "\"URIError\""
     );
 }
+
+#[test]
+fn error_cause() {
+    let mut context = Context::default();
+    let init = r#"
+        let errors = [Error, EvalError, RangeError, ReferenceError, SyntaxError, TypeError, URIError]
+            .map(C => new C('message', { cause: 'reason' }));
+        let aggregate = new AggregateError([1, 2], 'message', { cause: 'reason' });
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "errors.every(e => e.cause === 'reason')"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "errors.every(e => !Object.getOwnPropertyDescriptor(e, 'cause').enumerable)"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "aggregate.cause"), "\"reason\"");
+    assert_eq!(forward(&mut context, "aggregate.errors.length"), "2");
+    assert_eq!(
+        forward(&mut context, "Object.hasOwn(new Error('m', {}), 'cause')"),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.hasOwn(new Error('m', { cause: undefined }), 'cause')"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn aggregate_error_chaining() {
+    let mut context = Context::default();
+    let init = r#"
+        let inner = new AggregateError([new TypeError('a'), new RangeError('b')], 'inner');
+        let outer = new AggregateError(new Set([inner, 'c']), 'outer', { cause: inner });
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "outer.errors[0] === inner"), "true");
+    assert_eq!(forward(&mut context, "outer.cause === inner"), "true");
+    assert_eq!(forward(&mut context, "outer.errors[1]"), "\"c\"");
+    assert_eq!(
+        forward(&mut context, "outer.cause.errors.map(String).join()"),
+        "\"TypeError: a,RangeError: b\""
+    );
+    assert_eq!(forward(&mut context, "Object.keys(outer).length"), "0");
+}
+
+#[test]
+fn error_stack() {
+    let mut context = Context::default();
+    let init = r#"
+        function inner() { return new TypeError('boom'); }
+        function outer() { return inner(); }
+        let e = outer();
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "e.stack"),
+        "\"TypeError: boom\\n    at inner (2:35)\\n    at outer (3:35)\\n    at <main> (4:17)\""
+    );
+    assert_eq!(forward(&mut context, "Error.prototype.stack"), "undefined");
+    assert_eq!(
+        forward(&mut context, "e.stack = 'custom'; e.stack"),
+        "\"custom\""
+    );
+}
+
+#[test]
+fn error_stack_frames() {
+    let mut context = Context::default();
+    let error = forward_val(
+        &mut context,
+        "function thrower() { throw new Error('boom'); } thrower();",
+    )
+    .expect_err("the script should throw");
+
+    let frames = JsError::from(error).stack_frames();
+    let names: Vec<_> = frames
+        .iter()
+        .map(|frame| frame.function_name().to_string())
+        .collect();
+    assert_eq!(names, ["thrower", "<main>"]);
+    let offsets: Vec<_> = frames
+        .iter()
+        .map(|frame| {
+            let span = frame.span().expect("the frames should have a span");
+            (span.start().offset(), span.end().offset())
+        })
+        .collect();
+    assert_eq!(offsets, [(27, 44), (48, 57)]);
+
+    let error = forward_val(&mut context, "throw 1;").expect_err("the script should throw");
+    assert!(JsError::from(error).stack_frames().is_empty());
+}
+
+#[test]
+fn builtin_error_stack_frames() {
+    let mut context = Context::default();
+    let error = forward_val(
+        &mut context,
+        "function reduce() { return [].reduce((a, b) => a + b); } reduce();",
+    )
+    .expect_err("the script should throw");
+
+    let names: Vec<_> = JsError::from(error)
+        .stack_frames()
+        .iter()
+        .map(|frame| frame.function_name().to_string())
+        .collect();
+    assert_eq!(names, ["reduce", "<main>"]);
+
+    forward(
+        &mut context,
+        "var e; try { null.x; } catch (err) { e = err; }",
+    );
+    assert_eq!(forward(&mut context, "e instanceof TypeError"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "e.stack.split('\\n')[1].startsWith('    at <main>')"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn captured_error() {
+    use crate::error::{CapturedError, JsErrorKind};
+    use std::error::Error;
+
+    let mut context = Context::default();
+
+    let error = forward_val(
+        &mut context,
+        r#"
+            function ValidationError(message, options) {
+                return Reflect.construct(RangeError, [message, options], ValidationError);
+            }
+            Object.setPrototypeOf(ValidationError.prototype, RangeError.prototype);
+            function validate() {
+                throw new ValidationError("invalid", { cause: new Error("inner", { cause: 2 }) });
+            }
+            validate();
+        "#,
+    )
+    .expect_err("the script should throw");
+    let captured = JsError::from(error).capture(&mut context);
+    assert_eq!(captured.kind(), JsErrorKind::Range);
+    assert_eq!(captured.message(), "invalid");
+    assert_eq!(captured.to_string(), "RangeError: invalid");
+    assert!(captured.stack().unwrap().contains("at validate"));
+
+    let cause = captured.cause().unwrap();
+    assert_eq!(cause.kind(), JsErrorKind::Error);
+    assert_eq!(cause.cause().unwrap().kind(), JsErrorKind::Value);
+    assert_eq!(cause.cause().unwrap().to_string(), "uncaught 2");
+    assert_eq!(captured.source().unwrap().to_string(), "Error: inner");
+
+    let error = forward_val(
+        &mut context,
+        "let e = new TypeError('cyclic'); e.cause = e; throw e;",
+    )
+    .expect_err("the script should throw");
+    let mut captured = &JsError::from(error).capture(&mut context);
+    let mut depth = 0;
+    while let Some(cause) = captured.cause() {
+        captured = cause;
+        depth += 1;
+    }
+    assert_eq!(depth, 32);
+
+    let anyhow_error = anyhow::Error::new(
+        JsError::from(context.construct_type_error("bad")).capture(&mut context),
+    )
+    .context("while loading");
+    let error = JsError::from_std_error(&*anyhow_error, &mut context);
+    context.register_global_property(
+        "loadError",
+        error.into_value(),
+        crate::property::Attribute::all(),
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "loadError instanceof Error && loadError.message"
+        ),
+        "\"while loading\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "loadError.cause instanceof TypeError && loadError.cause.message"
+        ),
+        "\"bad\""
+    );
+
+    let round_trip: CapturedError =
+        JsError::from_std_error(&*anyhow_error, &mut context).capture(&mut context);
+    assert_eq!(round_trip.to_string(), "Error: while loading");
+    assert_eq!(round_trip.cause().unwrap().to_string(), "TypeError: bad");
+}
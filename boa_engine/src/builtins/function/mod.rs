@@ -272,6 +272,9 @@ impl BuiltInFunctionObject {
         _: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
+        // CreateDynamicFunction, step 5: Perform ? HostEnsureCanCompileStrings(calleeRealm).
+        context.ensure_can_compile_strings()?;
+
         let prototype =
             get_prototype_from_constructor(new_target, StandardConstructors::function, context)?;
 
@@ -2,7 +2,7 @@ use crate::{
     forward, forward_val,
     object::FunctionBuilder,
     property::{Attribute, PropertyDescriptor},
-    Context, JsString,
+    Context, JsString, JsValue,
 };
 
 #[allow(clippy::float_cmp)]
@@ -260,3 +260,83 @@ fn closure_capture_clone() {
 
     assert_eq!(forward(&mut context, "closure()"), "\"Hello world!\"");
 }
+
+#[test]
+fn host_ensure_can_compile_strings() {
+    use crate::{context::HostHooks, JsResult};
+    use std::rc::Rc;
+
+    #[derive(Debug)]
+    struct NoCompile;
+
+    impl HostHooks for NoCompile {
+        fn ensure_can_compile_strings(&self, context: &mut Context) -> JsResult<()> {
+            context.throw_eval_error("compiling strings is disabled")
+        }
+    }
+
+    let mut context = Context::builder().host_hooks(Rc::new(NoCompile)).build();
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Function('return 1'); } catch (e) { e.name + ': ' + e.message }"
+        ),
+        "\"EvalError: compiling strings is disabled\""
+    );
+}
+
+#[test]
+fn typed_function() {
+    use std::{cell::Cell, rc::Rc};
+
+    let mut context = Context::default();
+
+    let calls = Rc::new(Cell::new(0));
+    let counter = calls.clone();
+    context.register_global_typed_function("bytes", move |text: String, limit: f64| {
+        counter.set(counter.get() + 1);
+        if limit < 0.0 {
+            return Err("the limit is negative");
+        }
+        Ok(text.bytes().take(limit as usize).collect::<Vec<u8>>())
+    });
+    context
+        .register_global_typed_function("fail", || -> crate::JsResult<()> { Err("thrown".into()) });
+    context.register_global_typed_function("nothing", |_: Option<bool>| ());
+
+    assert_eq!(forward(&mut context, "bytes('abc', 2).join()"), "\"97,98\"");
+    assert_eq!(forward(&mut context, "bytes.length"), "2");
+    assert_eq!(forward(&mut context, "bytes.name"), "\"bytes\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { bytes('abc', -1) } catch (e) { `${e.name}: ${e.message}` }"
+        ),
+        "\"Error: the limit is negative\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { bytes(1, 2) } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { bytes('abc') } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(calls.get(), 2);
+
+    assert_eq!(
+        forward(&mut context, "try { fail() } catch (e) { e }"),
+        "\"thrown\""
+    );
+    assert_eq!(forward(&mut context, "nothing()"), "undefined");
+    assert_eq!(
+        forward(&mut context, "try { new nothing() } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+
+    let double = FunctionBuilder::typed(&mut context, |n: i32| n * 2).build();
+    assert_eq!(
+        double.call(&JsValue::undefined(), &[21.into()], &mut context),
+        Ok(42.into())
+    );
+}
@@ -34,15 +34,53 @@ pub(crate) enum GeneratorState {
 
 /// Holds all information that a generator needs to continue it's execution.
 ///
-/// All of the fields must be changed with those that are currently present in the
-/// context/vm before the generator execution starts/resumes and after it has ended/yielded.
+/// The call frame of a generator is allocated once, when the generator is created. Resuming the
+/// generator moves its frame, environments and stack into the context/vm, and suspending it moves
+/// them back, so nothing is copied between resumptions.
 #[derive(Debug, Clone, Finalize, Trace)]
 pub(crate) struct GeneratorContext {
     pub(crate) environments: DeclarativeEnvironmentStack,
-    pub(crate) call_frame: CallFrame,
+    /// The suspended frame of the generator, `None` while the generator is executing.
+    pub(crate) call_frame: Option<Box<CallFrame>>,
     pub(crate) stack: Vec<JsValue>,
 }
 
+impl GeneratorContext {
+    /// Resumes the generator until it yields or returns, pushing `value` to its stack if there is
+    /// one.
+    fn resume(
+        &mut self,
+        value: Option<JsValue>,
+        resume_kind: GeneratorResumeKind,
+        context: &mut Context,
+    ) -> JsResult<(JsValue, ReturnType)> {
+        std::mem::swap(&mut context.realm.environments, &mut self.environments);
+        std::mem::swap(&mut context.vm.stack, &mut self.stack);
+        context.vm.push_boxed_frame(
+            self.call_frame
+                .take()
+                .expect("suspended generator call frame must exist"),
+        );
+        if let Some(value) = value {
+            context.vm.push(value);
+        }
+        context.vm.frame_mut().generator_resume_kind = resume_kind;
+
+        let result = context.run();
+
+        self.call_frame = Some(
+            context
+                .vm
+                .pop_frame()
+                .expect("generator call frame must exist"),
+        );
+        std::mem::swap(&mut context.realm.environments, &mut self.environments);
+        std::mem::swap(&mut context.vm.stack, &mut self.stack);
+
+        result
+    }
+}
+
 /// The internal representation on a `Generator` object.
 #[derive(Debug, Clone, Finalize, Trace)]
 pub struct Generator {
@@ -249,29 +287,8 @@ impl Generator {
         let mut generator_context = generator_context_cell.borrow_mut();
         drop(generator_obj_mut);
 
-        std::mem::swap(
-            &mut context.realm.environments,
-            &mut generator_context.environments,
-        );
-        std::mem::swap(&mut context.vm.stack, &mut generator_context.stack);
-        context.vm.push_frame(generator_context.call_frame.clone());
-        if !first_execution {
-            context.vm.push(value);
-        }
-
-        context.vm.frame_mut().generator_resume_kind = GeneratorResumeKind::Normal;
-
-        let result = context.run();
-
-        generator_context.call_frame = *context
-            .vm
-            .pop_frame()
-            .expect("generator call frame must exist");
-        std::mem::swap(
-            &mut context.realm.environments,
-            &mut generator_context.environments,
-        );
-        std::mem::swap(&mut context.vm.stack, &mut generator_context.stack);
+        let value = (!first_execution).then(|| value.clone());
+        let result = generator_context.resume(value, GeneratorResumeKind::Normal, context);
 
         let mut generator_obj_mut = generator_obj.borrow_mut();
         let generator = generator_obj_mut
@@ -365,34 +382,14 @@ impl Generator {
         generator.state = GeneratorState::Executing;
         drop(generator_obj_mut);
 
-        std::mem::swap(
-            &mut context.realm.environments,
-            &mut generator_context.environments,
-        );
-        std::mem::swap(&mut context.vm.stack, &mut generator_context.stack);
-        context.vm.push_frame(generator_context.call_frame.clone());
-
         let result = match abrupt_completion {
             Ok(value) => {
-                context.vm.push(value);
-                context.vm.frame_mut().generator_resume_kind = GeneratorResumeKind::Return;
-                context.run()
+                generator_context.resume(Some(value), GeneratorResumeKind::Return, context)
             }
             Err(value) => {
-                context.vm.push(value);
-                context.vm.frame_mut().generator_resume_kind = GeneratorResumeKind::Throw;
-                context.run()
+                generator_context.resume(Some(value), GeneratorResumeKind::Throw, context)
             }
         };
-        generator_context.call_frame = *context
-            .vm
-            .pop_frame()
-            .expect("generator call frame must exist");
-        std::mem::swap(
-            &mut context.realm.environments,
-            &mut generator_context.environments,
-        );
-        std::mem::swap(&mut context.vm.stack, &mut generator_context.stack);
 
         let mut generator_obj_mut = generator_obj.borrow_mut();
         let generator = generator_obj_mut
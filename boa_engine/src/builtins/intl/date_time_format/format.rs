@@ -0,0 +1,497 @@
+//! Pattern selection and formatting of time values for `Intl.DateTimeFormat`.
+//!
+//! Only the `en` locale data is available, so every pattern produced here follows the `en-US`
+//! conventions of the CLDR.
+
+use super::DateTimeFormat;
+use chrono::{Datelike, Duration, Local, NaiveDateTime, Offset, TimeZone as _, Timelike};
+
+const WEEKDAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// The time zone used to compute the local time of a formatted time value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum TimeZone {
+    /// The time zone of the host.
+    Local,
+    /// Coordinated Universal Time.
+    Utc,
+    /// A fixed offset from UTC, in minutes.
+    Offset(i32),
+}
+
+impl TimeZone {
+    /// Parses a time zone identifier, returning `None` if it is not supported.
+    ///
+    /// Only the UTC aliases and UTC offsets (`±HH`, `±HHMM` or `±HH:MM`) can be resolved without
+    /// a time zone database.
+    pub(super) fn parse(identifier: &str) -> Option<Self> {
+        if ["utc", "etc/utc", "etc/gmt", "gmt"]
+            .iter()
+            .any(|alias| identifier.eq_ignore_ascii_case(alias))
+        {
+            return Some(Self::Utc);
+        }
+
+        let sign = match identifier.as_bytes().first()? {
+            b'+' => 1,
+            b'-' => -1,
+            _ => return None,
+        };
+        let rest = &identifier[1..];
+        let (hours, minutes) = match rest.len() {
+            2 => (rest, "00"),
+            4 => (&rest[..2], &rest[2..]),
+            5 if rest.as_bytes()[2] == b':' => (&rest[..2], &rest[3..]),
+            _ => return None,
+        };
+        if !hours
+            .bytes()
+            .chain(minutes.bytes())
+            .all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+        let hours: i32 = hours.parse().ok()?;
+        let minutes: i32 = minutes.parse().ok()?;
+        if hours > 23 || minutes > 59 {
+            return None;
+        }
+
+        Some(Self::Offset(sign * (hours * 60 + minutes)))
+    }
+
+    /// Returns the canonical identifier of this time zone.
+    ///
+    /// The host time zone is reported as its current offset from UTC.
+    pub(super) fn identifier(self) -> String {
+        let offset = match self {
+            Self::Utc => return "UTC".to_owned(),
+            Self::Offset(offset) => offset,
+            Self::Local => Local::now().offset().fix().local_minus_utc() / 60,
+        };
+        if offset == 0 {
+            return "UTC".to_owned();
+        }
+        let sign = if offset < 0 { '-' } else { '+' };
+        format!("{sign}{:02}:{:02}", offset.abs() / 60, offset.abs() % 60)
+    }
+
+    /// Converts a UTC date time to the wall clock time of this time zone, also returning the
+    /// offset from UTC in minutes.
+    fn to_local(self, utc: NaiveDateTime) -> (NaiveDateTime, i32) {
+        let offset = match self {
+            Self::Utc => 0,
+            Self::Offset(offset) => offset,
+            Self::Local => Local.offset_from_utc_datetime(&utc).local_minus_utc() / 60,
+        };
+        (utc + Duration::minutes(i64::from(offset)), offset)
+    }
+
+    /// Returns the name of this time zone in the given `timeZoneName` style.
+    fn name(self, style: &str, offset: i32) -> String {
+        if self == Self::Utc && !style.ends_with("Offset") {
+            return if style.starts_with("long") {
+                "Coordinated Universal Time".to_owned()
+            } else {
+                "UTC".to_owned()
+            };
+        }
+        if offset == 0 {
+            return "GMT".to_owned();
+        }
+
+        let sign = if offset < 0 { '-' } else { '+' };
+        let (hours, minutes) = (offset.abs() / 60, offset.abs() % 60);
+        if style.starts_with("long") {
+            format!("GMT{sign}{hours:02}:{minutes:02}")
+        } else if minutes == 0 {
+            format!("GMT{sign}{hours}")
+        } else {
+            format!("GMT{sign}{hours}:{minutes:02}")
+        }
+    }
+}
+
+/// A single formatted part, as returned by `formatToParts`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Part {
+    pub(super) kind: &'static str,
+    pub(super) value: String,
+}
+
+impl Part {
+    fn new<V: Into<String>>(kind: &'static str, value: V) -> Self {
+        Self {
+            kind,
+            value: value.into(),
+        }
+    }
+
+    pub(super) fn literal<V: Into<String>>(value: V) -> Self {
+        Self::new("literal", value)
+    }
+}
+
+/// A formatted time value, split into its date and time portions so that ranges can share the
+/// date portion.
+#[derive(Debug)]
+pub(super) struct Formatted {
+    pub(super) date: Vec<Part>,
+    pub(super) time: Vec<Part>,
+    pub(super) separator: &'static str,
+}
+
+impl Formatted {
+    /// Joins the date and time portions into a single list of parts.
+    pub(super) fn into_parts(self) -> Vec<Part> {
+        let mut parts = self.date;
+        if !parts.is_empty() && !self.time.is_empty() {
+            parts.push(Part::literal(self.separator));
+        }
+        parts.extend(self.time);
+        parts
+    }
+}
+
+/// Concatenates the values of a list of parts.
+pub(super) fn concat(parts: &[Part]) -> String {
+    parts.iter().map(|part| part.value.as_str()).collect()
+}
+
+/// The date and time components to format, after the styles have been expanded.
+#[derive(Debug, Default, Clone, Copy)]
+pub(super) struct Components {
+    pub(super) weekday: Option<&'static str>,
+    pub(super) era: Option<&'static str>,
+    pub(super) year: Option<&'static str>,
+    pub(super) month: Option<&'static str>,
+    pub(super) day: Option<&'static str>,
+    pub(super) day_period: Option<&'static str>,
+    pub(super) hour: Option<&'static str>,
+    pub(super) minute: Option<&'static str>,
+    pub(super) second: Option<&'static str>,
+    pub(super) fractional_second_digits: Option<u8>,
+    pub(super) time_zone_name: Option<&'static str>,
+}
+
+impl Components {
+    /// Returns the components used by the given `dateStyle` and `timeStyle`.
+    pub(super) fn from_styles(date_style: Option<&str>, time_style: Option<&str>) -> Self {
+        let mut components = Self::default();
+        match date_style {
+            Some("full") => {
+                components.weekday = Some("long");
+                components.month = Some("long");
+            }
+            Some("long") => components.month = Some("long"),
+            Some("medium") => components.month = Some("short"),
+            Some(_) => components.month = Some("numeric"),
+            None => {}
+        }
+        if date_style.is_some() {
+            components.day = Some("numeric");
+            components.year = Some(if date_style == Some("short") {
+                "2-digit"
+            } else {
+                "numeric"
+            });
+        }
+
+        if let Some(style) = time_style {
+            components.hour = Some("numeric");
+            components.minute = Some("2-digit");
+            if style != "short" {
+                components.second = Some("2-digit");
+            }
+            components.time_zone_name = match style {
+                "full" => Some("long"),
+                "long" => Some("short"),
+                _ => None,
+            };
+        }
+
+        components
+    }
+
+    /// Returns `true` if any date component is present.
+    pub(super) fn has_date(&self) -> bool {
+        self.weekday.is_some()
+            || self.era.is_some()
+            || self.year.is_some()
+            || self.month.is_some()
+            || self.day.is_some()
+    }
+
+    /// Returns `true` if any time component other than the time zone name is present.
+    pub(super) fn has_time(&self) -> bool {
+        self.day_period.is_some()
+            || self.hour.is_some()
+            || self.minute.is_some()
+            || self.second.is_some()
+            || self.fractional_second_digits.is_some()
+    }
+}
+
+/// Formats a number with at least `min_digits` digits.
+fn pad(value: i64, min_digits: usize) -> String {
+    format!("{value:0min_digits$}")
+}
+
+/// Returns the flexible day period of the given hour and minute, used by the `dayPeriod` option.
+fn flexible_day_period(hour: u32, minute: u32, style: &str) -> &'static str {
+    match (hour, minute) {
+        (12, 0) if style == "narrow" => "n",
+        (12, 0) => "noon",
+        (6..=11, _) => "in the morning",
+        (12..=17, _) => "in the afternoon",
+        (18..=20, _) => "in the evening",
+        _ => "at night",
+    }
+}
+
+/// Formats the date portion of `local`.
+fn format_date(components: &Components, local: &NaiveDateTime) -> Vec<Part> {
+    let mut parts = Vec::new();
+    let year = local.year();
+    let is_bce = year <= 0;
+
+    let year_part = components.year.map(|style| {
+        let year = if components.era.is_some() && is_bce {
+            1 - i64::from(year)
+        } else {
+            i64::from(year)
+        };
+        if style == "2-digit" {
+            Part::new("year", pad(year.rem_euclid(100), 2))
+        } else {
+            Part::new("year", year.to_string())
+        }
+    });
+    let day_part = components.day.map(|style| {
+        let digits = if style == "2-digit" { 2 } else { 1 };
+        Part::new("day", pad(i64::from(local.day()), digits))
+    });
+
+    if let Some(style) = components.weekday {
+        let name = WEEKDAYS[local.weekday().num_days_from_sunday() as usize];
+        let value = match style {
+            "long" => name,
+            "short" => &name[..3],
+            _ => &name[..1],
+        };
+        parts.push(Part::new("weekday", value));
+    }
+
+    let mut core = Vec::new();
+    match components.month {
+        Some(style @ ("numeric" | "2-digit")) => {
+            let digits = if style == "2-digit" { 2 } else { 1 };
+            core.push(Part::new("month", pad(i64::from(local.month()), digits)));
+            for part in [day_part, year_part].into_iter().flatten() {
+                core.push(Part::literal("/"));
+                core.push(part);
+            }
+        }
+        Some(style) => {
+            let name = MONTHS[local.month0() as usize];
+            let value = match style {
+                "long" => name,
+                "short" => &name[..3],
+                _ => &name[..1],
+            };
+            core.push(Part::new("month", value));
+            let has_day = day_part.is_some();
+            if let Some(day) = day_part {
+                core.push(Part::literal(" "));
+                core.push(day);
+            }
+            if let Some(year) = year_part {
+                core.push(Part::literal(if has_day { ", " } else { " " }));
+                core.push(year);
+            }
+        }
+        None => {
+            for part in [day_part, year_part].into_iter().flatten() {
+                if !core.is_empty() {
+                    core.push(Part::literal(" "));
+                }
+                core.push(part);
+            }
+        }
+    }
+
+    if let Some(style) = components.era {
+        let value = match (style, is_bce) {
+            ("long", false) => "Anno Domini",
+            ("long", true) => "Before Christ",
+            ("short", false) => "AD",
+            ("short", true) => "BC",
+            (_, false) => "A",
+            (_, true) => "B",
+        };
+        if !core.is_empty() {
+            core.push(Part::literal(" "));
+        }
+        core.push(Part::new("era", value));
+    }
+
+    if !parts.is_empty() && !core.is_empty() {
+        parts.push(Part::literal(", "));
+    }
+    parts.extend(core);
+    parts
+}
+
+/// Formats the time portion of `local`.
+fn format_time(
+    components: &Components,
+    hour_cycle: Option<&str>,
+    local: &NaiveDateTime,
+    time_zone: TimeZone,
+    offset: i32,
+) -> Vec<Part> {
+    let mut parts = Vec::new();
+    let hour = local.hour();
+    let twelve_hour = matches!(hour_cycle, Some("h11" | "h12"));
+
+    if let Some(style) = components.hour {
+        let value = match hour_cycle {
+            Some("h11") => hour % 12,
+            Some("h12") if hour % 12 == 0 => 12,
+            Some("h12") => hour % 12,
+            Some("h24") if hour == 0 => 24,
+            _ => hour,
+        };
+        let digits = if style == "2-digit" || !twelve_hour {
+            2
+        } else {
+            1
+        };
+        parts.push(Part::new("hour", pad(i64::from(value), digits)));
+    }
+
+    // Minutes and seconds are always padded when they follow another time component.
+    if let Some(style) = components.minute {
+        let digits = if style == "2-digit" || !parts.is_empty() {
+            2
+        } else {
+            1
+        };
+        if !parts.is_empty() {
+            parts.push(Part::literal(":"));
+        }
+        parts.push(Part::new("minute", pad(i64::from(local.minute()), digits)));
+    }
+
+    if let Some(style) = components.second {
+        let digits = if style == "2-digit" || !parts.is_empty() {
+            2
+        } else {
+            1
+        };
+        if !parts.is_empty() {
+            parts.push(Part::literal(":"));
+        }
+        parts.push(Part::new("second", pad(i64::from(local.second()), digits)));
+    }
+
+    if let Some(digits) = components.fractional_second_digits {
+        let millis = format!("{:03}", local.nanosecond() / 1_000_000 % 1000);
+        if !parts.is_empty() {
+            parts.push(Part::literal("."));
+        }
+        parts.push(Part::new(
+            "fractionalSecond",
+            &millis[..usize::from(digits)],
+        ));
+    }
+
+    if let Some(style) = components.day_period {
+        if components.hour.is_none() || twelve_hour {
+            if !parts.is_empty() {
+                parts.push(Part::literal(" "));
+            }
+            parts.push(Part::new(
+                "dayPeriod",
+                flexible_day_period(hour, local.minute(), style),
+            ));
+        }
+    } else if components.hour.is_some() && twelve_hour {
+        parts.push(Part::literal(" "));
+        parts.push(Part::new("dayPeriod", if hour < 12 { "AM" } else { "PM" }));
+    }
+
+    if let Some(style) = components.time_zone_name {
+        if !parts.is_empty() {
+            parts.push(Part::literal(" "));
+        }
+        parts.push(Part::new("timeZoneName", time_zone.name(style, offset)));
+    }
+
+    parts
+}
+
+/// Splits a time value into a UTC date time, or returns `None` if it cannot be represented.
+fn to_date_time(tv: f64) -> Option<NaiveDateTime> {
+    let millis = tv as i64;
+    NaiveDateTime::from_timestamp_opt(
+        millis.div_euclid(1000),
+        (millis.rem_euclid(1000) * 1_000_000) as u32,
+    )
+}
+
+impl DateTimeFormat {
+    /// `PartitionDateTimePattern ( dateTimeFormat, x )`
+    ///
+    /// `tv` must be a finite time value, already clipped to the valid range.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitiondatetimepattern
+    pub(super) fn partition(&self, tv: f64) -> Formatted {
+        let utc =
+            to_date_time(tv).expect("time values within the valid range must be representable");
+        let (local, offset) = self.time_zone.to_local(utc);
+
+        let separator = match (self.date_style, self.time_style) {
+            (Some("full" | "long"), Some(_)) => " at ",
+            _ => ", ",
+        };
+
+        Formatted {
+            date: format_date(&self.components, &local),
+            time: format_time(
+                &self.components,
+                self.hour_cycle,
+                &local,
+                self.time_zone,
+                offset,
+            ),
+            separator,
+        }
+    }
+}
@@ -0,0 +1,733 @@
+//! This module implements the global `Intl.DateTimeFormat` object.
+//!
+//! `Intl.DateTimeFormat` objects format dates and times in a language-sensitive way.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#datetimeformat-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat
+
+mod format;
+
+#[cfg(test)]
+mod tests;
+
+use self::format::{concat, Components, Part, TimeZone};
+use super::{
+    coerce_options_to_object, get_bool_option, get_number_option, get_option, is_unicode_type,
+    negotiation::{
+        canonicalize_locale_list, has_english_data, resolve_locale, supported_locales,
+        LocaleMatcher,
+    },
+};
+use crate::{
+    builtins::{date::Date, Array, BuiltIn, JsArgs},
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use chrono::Utc;
+use tap::{Conv, Pipe};
+
+/// The `required` argument of `CreateDateTimeFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DateTimeRequired {
+    Date,
+    Time,
+    Any,
+}
+
+/// The `defaults` argument of `CreateDateTimeFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DateTimeDefaults {
+    Date,
+    Time,
+    All,
+}
+
+/// The internal slots of an `Intl.DateTimeFormat` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct DateTimeFormat {
+    /// `[[Locale]]`
+    #[unsafe_ignore_trace]
+    locale: String,
+    /// `[[TimeZone]]`
+    #[unsafe_ignore_trace]
+    time_zone: TimeZone,
+    /// `[[HourCycle]]`
+    #[unsafe_ignore_trace]
+    hour_cycle: Option<&'static str>,
+    /// The fields of the `[[DateTimeFormat]]` record, with the styles expanded.
+    #[unsafe_ignore_trace]
+    components: Components,
+    /// `[[DateStyle]]`
+    #[unsafe_ignore_trace]
+    date_style: Option<&'static str>,
+    /// `[[TimeStyle]]`
+    #[unsafe_ignore_trace]
+    time_style: Option<&'static str>,
+    /// `[[BoundFormat]]`
+    bound_format: Option<JsObject>,
+}
+
+impl BuiltIn for DateTimeFormat {
+    const NAME: &'static str = "DateTimeFormat";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let get_format = FunctionBuilder::native(context, Self::get_format)
+            .name("get format")
+            .constructor(false)
+            .build();
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context
+                .intrinsics()
+                .constructors()
+                .date_time_format()
+                .clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .static_method(Self::supported_locales_of, "supportedLocalesOf", 1)
+        .accessor(
+            "format",
+            Some(get_format),
+            None,
+            Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+        )
+        .method(Self::format_to_parts, "formatToParts", 1)
+        .method(Self::format_range, "formatRange", 2)
+        .method(Self::resolved_options, "resolvedOptions", 0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            "Intl.DateTimeFormat",
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+impl DateTimeFormat {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `Intl.DateTimeFormat ( [ locales [ , options ] ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/DateTimeFormat
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, let newTarget be the active function object, else let newTarget be NewTarget.
+        let new_target = if new_target.is_undefined() {
+            context
+                .intrinsics()
+                .constructors()
+                .date_time_format()
+                .constructor()
+                .into()
+        } else {
+            new_target.clone()
+        };
+
+        // 2. Let dateTimeFormat be ? CreateDateTimeFormat(newTarget, locales, options, any, date).
+        let prototype = get_prototype_from_constructor(
+            &new_target,
+            StandardConstructors::date_time_format,
+            context,
+        )?;
+        let date_time_format = Self::new(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            DateTimeRequired::Any,
+            DateTimeDefaults::Date,
+            context,
+        )?;
+
+        // 3-4. Return dateTimeFormat.
+        Ok(
+            JsObject::from_proto_and_data(
+                prototype,
+                ObjectData::date_time_format(date_time_format),
+            )
+            .into(),
+        )
+    }
+
+    /// Abstract operation `CreateDateTimeFormat ( newTarget, locales, options, required, defaults )`
+    ///
+    /// Creates the internal slots of a `DateTimeFormat`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-createdatetimeformat
+    pub(crate) fn new(
+        locales: &JsValue,
+        options: &JsValue,
+        required: DateTimeRequired,
+        defaults: DateTimeDefaults,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        const TEXT: &[&str] = &["narrow", "short", "long"];
+        const NUMERIC: &[&str] = &["2-digit", "numeric"];
+        const STYLES: &[&str] = &["full", "long", "medium", "short"];
+
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(options, context)?;
+
+        // 5. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        let matcher = LocaleMatcher::from_options(&options, context)?;
+
+        // 7. Let calendar be ? GetOption(options, "calendar", string, empty, undefined).
+        // 8. If calendar is not undefined, then
+        //     a. If calendar cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        // 10. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+        // 11. If numberingSystem is not undefined, then
+        //     a. If numberingSystem cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        // Only the Gregorian calendar and the Latin digits are supported, so other values are ignored.
+        for property in ["calendar", "numberingSystem"] {
+            let value = options.get(property, context)?;
+            if !value.is_undefined() {
+                let value = value.to_string(context)?;
+                if !is_unicode_type(&value) {
+                    return context.throw_range_error(format!(
+                        "invalid value `{value}` for option `{property}`"
+                    ));
+                }
+            }
+        }
+
+        // 13. Let hour12 be ? GetOption(options, "hour12", boolean, empty, undefined).
+        let hour12 = get_bool_option(&options, "hour12", context)?;
+
+        // 14. Let hourCycle be ? GetOption(options, "hourCycle", string, « "h11", "h12", "h23", "h24" », undefined).
+        // 15. If hour12 is not undefined, then
+        //     a. Set hourCycle to null.
+        let hour_cycle = get_option(
+            &options,
+            "hourCycle",
+            &["h11", "h12", "h23", "h24"],
+            context,
+        )?
+        .filter(|_| hour12.is_none());
+
+        // 29. Let timeZone be ? Get(options, "timeZone").
+        let time_zone = options.get("timeZone", context)?;
+
+        // 30. If timeZone is undefined, then
+        let time_zone = if time_zone.is_undefined() {
+            // a. Set timeZone to DefaultTimeZone().
+            TimeZone::Local
+        } else {
+            // 31. Else,
+            // a. Set timeZone to ? ToString(timeZone).
+            let time_zone = time_zone.to_string(context)?;
+
+            // 32. If IsTimeZoneOffsetString(timeZone) is true, then ...
+            // 33. Else,
+            //     a. If IsValidTimeZoneName(timeZone) is false, throw a RangeError exception.
+            match TimeZone::parse(&time_zone) {
+                Some(time_zone) => time_zone,
+                None => {
+                    return context
+                        .throw_range_error(format!("unsupported time zone `{time_zone}`"))
+                }
+            }
+        };
+
+        // 36. For each row of Table 7, except the header row, in table order, do
+        //     a. Let prop be the name given in the Property column of the row.
+        //     b. If prop is "fractionalSecondDigits", then
+        //         i. Let value be ? GetNumberOption(options, "fractionalSecondDigits", 1, 3, undefined).
+        //     c. Else,
+        //         i. Let values be a List whose elements are the strings given in the Values column of the row.
+        //         ii. Let value be ? GetOption(options, prop, string, values, undefined).
+        //     d. Set formatOptions.[[<prop>]] to value.
+        let components = Components {
+            weekday: get_option(&options, "weekday", TEXT, context)?,
+            era: get_option(&options, "era", TEXT, context)?,
+            year: get_option(&options, "year", NUMERIC, context)?,
+            month: get_option(
+                &options,
+                "month",
+                &["numeric", "2-digit", "long", "short", "narrow"],
+                context,
+            )?,
+            day: get_option(&options, "day", NUMERIC, context)?,
+            day_period: get_option(&options, "dayPeriod", TEXT, context)?,
+            hour: get_option(&options, "hour", NUMERIC, context)?,
+            minute: get_option(&options, "minute", NUMERIC, context)?,
+            second: get_option(&options, "second", NUMERIC, context)?,
+            fractional_second_digits: get_number_option(
+                &options,
+                "fractionalSecondDigits",
+                1,
+                3,
+                context,
+            )?,
+            time_zone_name: get_option(
+                &options,
+                "timeZoneName",
+                &[
+                    "short",
+                    "long",
+                    "shortOffset",
+                    "longOffset",
+                    "shortGeneric",
+                    "longGeneric",
+                ],
+                context,
+            )?,
+        };
+
+        // 37. Let formatMatcher be ? GetOption(options, "formatMatcher", string, « "basic", "best fit" », "best fit").
+        get_option(&options, "formatMatcher", &["basic", "best fit"], context)?;
+
+        // 38. Let dateStyle be ? GetOption(options, "dateStyle", string, « "full", "long", "medium", "short" », undefined).
+        let date_style = get_option(&options, "dateStyle", STYLES, context)?;
+
+        // 40. Let timeStyle be ? GetOption(options, "timeStyle", string, « "full", "long", "medium", "short" », undefined).
+        let time_style = get_option(&options, "timeStyle", STYLES, context)?;
+
+        // 42. If dateStyle is not undefined or timeStyle is not undefined, then
+        let components = if date_style.is_some() || time_style.is_some() {
+            // a. If hasExplicitFormatComponents is true, then
+            if components.has_date() || components.has_time() || components.time_zone_name.is_some()
+            {
+                // i. Throw a TypeError exception.
+                return context.throw_type_error(
+                    "dateStyle and timeStyle cannot be used with explicit date or time components",
+                );
+            }
+
+            // b. If required is date and timeStyle is not undefined, then
+            if required == DateTimeRequired::Date && time_style.is_some() {
+                // i. Throw a TypeError exception.
+                return context.throw_type_error("timeStyle cannot be used to format only a date");
+            }
+
+            // c. If required is time and dateStyle is not undefined, then
+            if required == DateTimeRequired::Time && date_style.is_some() {
+                // i. Throw a TypeError exception.
+                return context.throw_type_error("dateStyle cannot be used to format only a time");
+            }
+
+            // d-e. Let bestFormat be DateTimeStyleFormat(dateStyle, timeStyle, styles).
+            Components::from_styles(date_style, time_style)
+        } else {
+            // 43. Else,
+            let mut components = components;
+
+            // a. Let needDefaults be true.
+            // b. If required is date or any, then
+            //     i. For each property name prop of « "weekday", "year", "month", "day" », do
+            //         1. Let value be formatOptions.[[<prop>]].
+            //         2. If value is not undefined, let needDefaults be false.
+            // c. If required is time or any, then
+            //     i. For each property name prop of « "dayPeriod", "hour", "minute", "second", "fractionalSecondDigits" », do
+            //         1. Let value be formatOptions.[[<prop>]].
+            //         2. If value is not undefined, let needDefaults be false.
+            let has_date = components.weekday.is_some()
+                || components.year.is_some()
+                || components.month.is_some()
+                || components.day.is_some();
+            let need_defaults = match required {
+                DateTimeRequired::Date => !has_date,
+                DateTimeRequired::Time => !components.has_time(),
+                DateTimeRequired::Any => !has_date && !components.has_time(),
+            };
+
+            // d. If needDefaults is true and defaults is either date or all, then
+            if need_defaults && defaults != DateTimeDefaults::Time {
+                // i. For each property name prop of « "year", "month", "day" », do
+                //     1. Set formatOptions.[[<prop>]] to "numeric".
+                components.year = Some("numeric");
+                components.month = Some("numeric");
+                components.day = Some("numeric");
+            }
+
+            // e. If needDefaults is true and defaults is either time or all, then
+            if need_defaults && defaults != DateTimeDefaults::Date {
+                // i. For each property name prop of « "hour", "minute", "second" », do
+                //     1. Set formatOptions.[[<prop>]] to "numeric".
+                components.hour = Some("numeric");
+                components.minute = Some("numeric");
+                components.second = Some("numeric");
+            }
+
+            components
+        };
+
+        // 44-47. If dateTimeFormat.[[Hour]] is undefined, set dateTimeFormat.[[HourCycle]] to undefined.
+        // Otherwise resolve the hour cycle from hour12, hourCycle and the locale default, which is
+        // h12 for all the available locales.
+        let hour_cycle = components.hour.map(|_| match hour12 {
+            Some(true) => "h12",
+            Some(false) => "h23",
+            None => hour_cycle.unwrap_or("h12"),
+        });
+
+        // 16-18. Let r be ResolveLocale(%DateTimeFormat%.[[AvailableLocales]], requestedLocales, opt, ...).
+        Ok(Self {
+            locale: resolve_locale(&requested_locales, matcher, has_english_data, context),
+            time_zone,
+            hour_cycle,
+            components,
+            date_style,
+            time_style,
+            bound_format: None,
+        })
+    }
+
+    /// Abstract operation `FormatDateTime ( dateTimeFormat, x )`
+    ///
+    /// Returns `None` if `x` is not a valid time value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatdatetime
+    pub(crate) fn format(&self, x: f64) -> Option<String> {
+        // 1. Let parts be ? PartitionDateTimePattern(dateTimeFormat, x).
+        // 2. Let result be the empty String.
+        // 3. For each Record { [[Type]], [[Value]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        // 4. Return result.
+        Some(concat(&self.partition(time_clip(x)?).into_parts()))
+    }
+
+    /// Gets the internal slots of the `this` `DateTimeFormat` object.
+    fn this_date_time_format(this: &JsValue, context: &mut Context) -> JsResult<Self> {
+        this.as_object()
+            .and_then(|obj| obj.borrow().as_date_time_format().cloned())
+            .ok_or_else(|| context.construct_type_error("'this' is not an Intl.DateTimeFormat"))
+    }
+
+    /// Converts the argument of a format method to a time value, using the current time if it
+    /// is undefined and throwing a `RangeError` if it is invalid.
+    fn to_time_value(date: &JsValue, context: &mut Context) -> JsResult<f64> {
+        // If date is undefined, then
+        let x = if date.is_undefined() {
+            // Let x be ! Call(%Date.now%, undefined).
+            Utc::now().timestamp_millis() as f64
+        } else {
+            // Else,
+            // Let x be ? ToNumber(date).
+            date.to_number(context)?
+        };
+
+        // PartitionDateTimePattern, 1. Let x be TimeClip(x).
+        // 2. If x is NaN, throw a RangeError exception.
+        match time_clip(x) {
+            Some(x) => Ok(x),
+            None => context.throw_range_error("invalid time value"),
+        }
+    }
+
+    /// `Intl.DateTimeFormat.supportedLocalesOf ( locales [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.supportedlocalesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let availableLocales be %DateTimeFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(args.get_or_undefined(0), context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales(
+            requested_locales,
+            args.get_or_undefined(1),
+            has_english_data,
+            context,
+        )
+    }
+
+    /// `get Intl.DateTimeFormat.prototype.format`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/format
+    fn get_format(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 3. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = Self::this_date_time_format(this, context)?;
+
+        // 4. If dtf.[[BoundFormat]] is undefined, then
+        if let Some(bound_format) = dtf.bound_format {
+            // 5. Return dtf.[[BoundFormat]].
+            return Ok(bound_format.into());
+        }
+
+        // a. Let F be a new built-in function object as defined in DateTime Format Functions.
+        // b. Set F.[[DateTimeFormat]] to dtf.
+        let bound_format = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args, dtf, context| {
+                // 1. Let dtf be F.[[DateTimeFormat]].
+                // 3. If date is not provided or is undefined, then
+                //     a. Let x be ! Call(%Date.now%, undefined).
+                // 4. Else,
+                //     a. Let x be ? ToNumber(date).
+                let x = Self::to_time_value(args.get_or_undefined(0), context)?;
+
+                // 5. Return ? FormatDateTime(dtf, x).
+                Ok(concat(&dtf.partition(x).into_parts()).into())
+            },
+            Self {
+                bound_format: None,
+                ..dtf
+            },
+        )
+        .length(1)
+        .build();
+
+        // c. Set dtf.[[BoundFormat]] to F.
+        this.as_object()
+            .expect("checked by `this_date_time_format`")
+            .borrow_mut()
+            .as_date_time_format_mut()
+            .expect("checked by `this_date_time_format`")
+            .bound_format = Some(bound_format.clone());
+
+        // 5. Return dtf.[[BoundFormat]].
+        Ok(bound_format.into())
+    }
+
+    /// `Intl.DateTimeFormat.prototype.formatToParts ( date )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DateTimeFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = Self::this_date_time_format(this, context)?;
+
+        // 3. If date is undefined, then
+        //     a. Let x be ! Call(%Date.now%, undefined).
+        // 4. Else,
+        //     a. Let x be ? ToNumber(date).
+        let x = Self::to_time_value(args.get_or_undefined(0), context)?;
+
+        // 5. Return ? FormatDateTimeToParts(dtf, x).
+        let parts = dtf.partition(x).into_parts();
+        Ok(parts_to_array(parts, context).into())
+    }
+
+    /// `Intl.DateTimeFormat.prototype.formatRange ( startDate, endDate )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.formatRange
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatRange
+    fn format_range(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dtf be this value.
+        // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = Self::this_date_time_format(this, context)?;
+
+        // 3. If startDate is undefined or endDate is undefined, throw a TypeError exception.
+        let start_date = args.get_or_undefined(0);
+        let end_date = args.get_or_undefined(1);
+        if start_date.is_undefined() || end_date.is_undefined() {
+            return context.throw_type_error("formatRange requires a start and an end date");
+        }
+
+        // 4. Let x be ? ToNumber(startDate).
+        // 5. Let y be ? ToNumber(endDate).
+        let x = Self::to_time_value(start_date, context)?;
+        let y = Self::to_time_value(end_date, context)?;
+
+        // 6. Return ? FormatDateTimeRange(dtf, x, y).
+        Ok(concat(&dtf.partition_range(x, y)).into())
+    }
+
+    /// Abstract operation `PartitionDateTimeRangePattern ( dateTimeFormat, x, y )`
+    ///
+    /// If both dates format to the same date portion, it is only written once and only the time
+    /// portions form the range.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitiondatetimerangepattern
+    fn partition_range(&self, x: f64, y: f64) -> Vec<Part> {
+        let start = self.partition(x);
+        let end = self.partition(y);
+
+        if start.date == end.date && start.time == end.time {
+            return start.into_parts();
+        }
+
+        let separator = Part::literal(" – ");
+
+        if start.date == end.date && !start.time.is_empty() {
+            let mut parts = start.date;
+            if !parts.is_empty() {
+                parts.push(Part::literal(start.separator));
+            }
+            parts.extend(start.time);
+            parts.push(separator);
+            parts.extend(end.time);
+            return parts;
+        }
+
+        let mut parts = start.into_parts();
+        parts.push(separator);
+        parts.extend(end.into_parts());
+        parts
+    }
+
+    /// `Intl.DateTimeFormat.prototype.resolvedOptions ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 3. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = Self::this_date_time_format(this, context)?;
+
+        // 4. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context.construct_object();
+
+        // 5. For each row of Table 8, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. If p is "hour12", then
+        //         i. Let hc be dtf.[[HourCycle]].
+        //         ii. If hc is "h11" or "h12", let v be true.
+        //         iii. Else if, hc is "h23" or "h24", let v be false.
+        //         iv. Else, let v be undefined.
+        //     c. Else,
+        //         i. Let v be the value of dtf's internal slot whose name is the Internal Slot value of the current row.
+        //     d. If the Internal Slot value of the current row is an Internal Slot value in Table 7, then
+        //         i. If dtf.[[DateStyle]] is not undefined or dtf.[[TimeStyle]] is not undefined, then
+        //             1. Let v be undefined.
+        //     e. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut entries: Vec<(&str, JsValue)> = vec![
+            ("locale", dtf.locale.into()),
+            ("calendar", "gregory".into()),
+            ("numberingSystem", "latn".into()),
+            ("timeZone", dtf.time_zone.identifier().into()),
+        ];
+        if let Some(hour_cycle) = dtf.hour_cycle {
+            entries.push(("hourCycle", hour_cycle.into()));
+            entries.push(("hour12", matches!(hour_cycle, "h11" | "h12").into()));
+        }
+        if dtf.date_style.is_none() && dtf.time_style.is_none() {
+            let components = dtf.components;
+            entries.extend(
+                [
+                    ("weekday", components.weekday),
+                    ("era", components.era),
+                    ("year", components.year),
+                    ("month", components.month),
+                    ("day", components.day),
+                    ("dayPeriod", components.day_period),
+                    ("hour", components.hour),
+                    ("minute", components.minute),
+                    ("second", components.second),
+                ]
+                .into_iter()
+                .filter_map(|(property, value)| Some((property, value?.into()))),
+            );
+            if let Some(digits) = components.fractional_second_digits {
+                entries.push(("fractionalSecondDigits", u32::from(digits).into()));
+            }
+            if let Some(time_zone_name) = components.time_zone_name {
+                entries.push(("timeZoneName", time_zone_name.into()));
+            }
+        }
+        if let Some(date_style) = dtf.date_style {
+            entries.push(("dateStyle", date_style.into()));
+        }
+        if let Some(time_style) = dtf.time_style {
+            entries.push(("timeStyle", time_style.into()));
+        }
+
+        for (property, value) in entries {
+            options
+                .create_data_property_or_throw(property, value, context)
+                .expect("CreateDataPropertyOrThrow cannot fail on a new ordinary object");
+        }
+
+        // 6. Return options.
+        Ok(options.into())
+    }
+}
+
+/// `TimeClip ( time )`, returning `None` for `NaN`.
+fn time_clip(x: f64) -> Option<f64> {
+    if x.is_finite() {
+        Date::time_clip(x.trunc())
+    } else {
+        None
+    }
+}
+
+/// Creates the array of part objects returned by `formatToParts`.
+fn parts_to_array(parts: Vec<Part>, context: &mut Context) -> JsObject {
+    let parts = parts
+        .into_iter()
+        .map(|part| {
+            let object = context.construct_object();
+            object
+                .create_data_property_or_throw("type", part.kind, context)
+                .expect("CreateDataPropertyOrThrow cannot fail on a new ordinary object");
+            object
+                .create_data_property_or_throw("value", part.value, context)
+                .expect("CreateDataPropertyOrThrow cannot fail on a new ordinary object");
+            object.into()
+        })
+        .collect::<Vec<JsValue>>();
+
+    Array::create_array_from_list(parts, context)
+}
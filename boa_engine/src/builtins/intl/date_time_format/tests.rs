@@ -0,0 +1,290 @@
+use crate::{forward, Context};
+
+const INIT: &str = r#"
+    var date = new Date(Date.UTC(2020, 0, 2, 13, 5, 9, 123));
+    function format(options, value) {
+        options.timeZone = options.timeZone || "UTC";
+        return new Intl.DateTimeFormat("en-US", options).format(value === undefined ? date : value);
+    }
+"#;
+
+#[test]
+fn default_format() {
+    let mut context = Context::default();
+    forward(&mut context, INIT);
+    assert_eq!(forward(&mut context, "format({})"), "\"1/2/2020\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Intl.DateTimeFormat().format(0) !== undefined"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn styles() {
+    let mut context = Context::default();
+    forward(&mut context, INIT);
+    assert_eq!(
+        forward(
+            &mut context,
+            "format({ dateStyle: 'full', timeStyle: 'long' })"
+        ),
+        "\"Thursday, January 2, 2020 at 1:05:09 PM UTC\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "format({ dateStyle: 'medium', timeStyle: 'short' })"
+        ),
+        "\"Jan 2, 2020, 1:05 PM\""
+    );
+    assert_eq!(
+        forward(&mut context, "format({ dateStyle: 'short' })"),
+        "\"1/2/20\""
+    );
+    assert_eq!(
+        forward(&mut context, "format({ timeStyle: 'full' })"),
+        "\"1:05:09 PM Coordinated Universal Time\""
+    );
+}
+
+#[test]
+fn components() {
+    let mut context = Context::default();
+    forward(&mut context, INIT);
+    assert_eq!(
+        forward(
+            &mut context,
+            "format({ hour: '2-digit', minute: '2-digit', hourCycle: 'h23' })"
+        ),
+        "\"13:05\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "format({ weekday: 'short', month: 'short', day: 'numeric', era: 'short' })"
+        ),
+        "\"Thu, Jan 2 AD\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "format({ hour: 'numeric', minute: 'numeric', second: 'numeric', fractionalSecondDigits: 2 })"
+        ),
+        "\"1:05:09.12 PM\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "format({ hour: 'numeric', dayPeriod: 'long' })"
+        ),
+        "\"1 in the afternoon\""
+    );
+}
+
+#[test]
+fn time_zones() {
+    let mut context = Context::default();
+    forward(&mut context, INIT);
+    assert_eq!(
+        forward(
+            &mut context,
+            "format({ hour: 'numeric', minute: 'numeric', timeZone: '+05:30', timeZoneName: 'short' })"
+        ),
+        "\"6:35 PM GMT+5:30\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "format({ day: 'numeric', hour: 'numeric', timeZone: '-1400' })"
+        ),
+        "\"1, 11 PM\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { format({ timeZone: 'Mars/Olympus_Mons' }) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn format_to_parts() {
+    let mut context = Context::default();
+    forward(&mut context, INIT);
+    let init = r#"
+        var parts = new Intl.DateTimeFormat("en-US", { month: "long", day: "numeric", timeZone: "UTC" })
+            .formatToParts(date);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "parts.map(p => p.type).join()"),
+        "\"month,literal,day\""
+    );
+    assert_eq!(
+        forward(&mut context, "parts.map(p => p.value).join('|')"),
+        "\"January| |2\""
+    );
+}
+
+#[test]
+fn format_range() {
+    let mut context = Context::default();
+    forward(&mut context, INIT);
+    let init = r#"
+        var dtf = new Intl.DateTimeFormat("en-US", {
+            year: "numeric", month: "short", day: "numeric",
+            hour: "numeric", minute: "numeric", timeZone: "UTC",
+        });
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "dtf.formatRange(date, date)"),
+        "\"Jan 2, 2020, 1:05 PM\""
+    );
+    assert_eq!(
+        forward(&mut context, "dtf.formatRange(date, date.getTime() + 7e6)"),
+        "\"Jan 2, 2020, 1:05 PM – 3:01 PM\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "dtf.formatRange(date, date.getTime() + 864e5)"
+        ),
+        "\"Jan 2, 2020, 1:05 PM – Jan 3, 2020, 1:05 PM\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { dtf.formatRange(date) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn bound_format() {
+    let mut context = Context::default();
+    forward(&mut context, INIT);
+    let init = r#"
+        var dtf = new Intl.DateTimeFormat("en-US", { timeZone: "UTC" });
+        var f = dtf.format;
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "f(date)"), "\"1/2/2020\"");
+    assert_eq!(forward(&mut context, "dtf.format === f"), "true");
+    assert_eq!(
+        forward(&mut context, "try { f(NaN) } catch (e) { e.name }"),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn resolved_options() {
+    let mut context = Context::default();
+    let init = r#"
+        var options = new Intl.DateTimeFormat("en-GB", { hour: "numeric", timeZone: "utc" })
+            .resolvedOptions();
+        var styled = new Intl.DateTimeFormat(undefined, { dateStyle: "long", hour12: false })
+            .resolvedOptions();
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "options.locale"), "\"en\"");
+    assert_eq!(forward(&mut context, "options.timeZone"), "\"UTC\"");
+    assert_eq!(forward(&mut context, "options.calendar"), "\"gregory\"");
+    assert_eq!(forward(&mut context, "options.hourCycle"), "\"h12\"");
+    assert_eq!(forward(&mut context, "options.hour12"), "true");
+    assert_eq!(forward(&mut context, "options.hour"), "\"numeric\"");
+    assert_eq!(forward(&mut context, "options.year"), "undefined");
+    assert_eq!(forward(&mut context, "styled.locale"), "\"en-US\"");
+    assert_eq!(forward(&mut context, "styled.dateStyle"), "\"long\"");
+    assert_eq!(forward(&mut context, "styled.month"), "undefined");
+    assert_eq!(forward(&mut context, "styled.hour12"), "undefined");
+}
+
+#[test]
+fn invalid_options() {
+    let mut context = Context::default();
+    forward(&mut context, INIT);
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { format({ dateStyle: 'short', year: 'numeric' }) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { format({ month: 'tiny' }) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { format({ fractionalSecondDigits: 4 }) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn supported_locales_of() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(
+            &mut context,
+            "Intl.DateTimeFormat.supportedLocalesOf(['en-GB', 'fr', 'en-US-u-hc-h23']).join()"
+        ),
+        "\"en-GB,en-US-u-hc-h23\""
+    );
+}
+
+#[test]
+fn date_to_locale_string() {
+    let mut context = Context::default();
+    forward(&mut context, INIT);
+    assert_eq!(
+        forward(
+            &mut context,
+            "date.toLocaleString('en-US', { timeZone: 'UTC' })"
+        ),
+        "\"1/2/2020, 1:05:09 PM\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "date.toLocaleDateString('en-US', { timeZone: 'UTC' })"
+        ),
+        "\"1/2/2020\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "date.toLocaleTimeString('en-US', { timeZone: 'UTC' })"
+        ),
+        "\"1:05:09 PM\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "date.toLocaleDateString('en-US', { timeZone: 'UTC', dateStyle: 'medium' })"
+        ),
+        "\"Jan 2, 2020\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { date.toLocaleDateString('en', { timeStyle: 'short' }) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Date(NaN).toLocaleString()"),
+        "\"Invalid Date\""
+    );
+}
@@ -0,0 +1,610 @@
+//! This module implements the global `Intl.DisplayNames` object.
+//!
+//! `Intl.DisplayNames` objects enable the consistent translation of language, region, script,
+//! currency, calendar and date time field display names.
+//!
+//! The language, region and script names come from the CLDR data of ICU4X. ICU4X has no display
+//! name data for currencies, calendars and date time fields, so their codes always resolve to
+//! the `fallback` option.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#intl-displaynames-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames
+
+#[cfg(test)]
+mod tests;
+
+use super::{
+    get_option,
+    negotiation::{
+        canonicalize_locale_list, is_well_formed_locale, resolve_icu_locale, supported_locales,
+        LocaleMatcher,
+    },
+};
+use crate::{
+    builtins::{BuiltIn, JsArgs},
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use icu_displaynames::{
+    DisplayNamesOptions, LanguageDisplay, LanguageDisplayNames, LocaleDisplayNamesFormatter,
+    RegionDisplayNames, ScriptDisplayNames, Style,
+};
+use icu_locid::subtags::{Region, Script};
+use icu_provider::{DataError, DataLocale};
+use std::fmt;
+use tap::{Conv, Pipe};
+
+/// The codes accepted by `IsValidDateTimeFieldCode ( field )`.
+const DATE_TIME_FIELDS: [&str; 12] = [
+    "era",
+    "year",
+    "quarter",
+    "month",
+    "weekOfYear",
+    "weekday",
+    "day",
+    "dayPeriod",
+    "hour",
+    "minute",
+    "second",
+    "timeZoneName",
+];
+
+/// The ICU4X display names of the type of an `Intl.DisplayNames` object.
+enum Names {
+    /// The names of languages, and the formatter combining them with the script, region and
+    /// variant names of a language identifier.
+    Language {
+        languages: LanguageDisplayNames,
+        formatter: LocaleDisplayNamesFormatter,
+    },
+    Region(RegionDisplayNames),
+    Script(ScriptDisplayNames),
+    /// A type without display name data.
+    None,
+}
+
+impl Names {
+    /// Loads the display names of `display_type` for `locale` from the provider of the context,
+    /// or from the compiled data if there is none.
+    fn try_new(
+        display_type: &str,
+        locale: &DataLocale,
+        options: DisplayNamesOptions,
+        context: &Context,
+    ) -> Result<Self, DataError> {
+        let provider = context.icu().provider();
+        Ok(match (display_type, provider) {
+            ("language", Some(provider)) => Self::Language {
+                languages: LanguageDisplayNames::try_new_unstable(provider, locale, options)?,
+                formatter: LocaleDisplayNamesFormatter::try_new_unstable(
+                    provider, locale, options,
+                )?,
+            },
+            ("language", None) => Self::Language {
+                languages: LanguageDisplayNames::try_new(locale, options)?,
+                formatter: LocaleDisplayNamesFormatter::try_new(locale, options)?,
+            },
+            ("region", Some(provider)) => Self::Region(RegionDisplayNames::try_new_unstable(
+                provider, locale, options,
+            )?),
+            ("region", None) => Self::Region(RegionDisplayNames::try_new(locale, options)?),
+            ("script", Some(provider)) => Self::Script(ScriptDisplayNames::try_new_unstable(
+                provider, locale, options,
+            )?),
+            ("script", None) => Self::Script(ScriptDisplayNames::try_new(locale, options)?),
+            _ => Self::None,
+        })
+    }
+}
+
+impl fmt::Debug for Names {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Language { .. } => "Names::Language(..)",
+            Self::Region(_) => "Names::Region(..)",
+            Self::Script(_) => "Names::Script(..)",
+            Self::None => "Names::None",
+        })
+    }
+}
+
+/// The internal slots of an `Intl.DisplayNames` object.
+#[derive(Debug, Trace, Finalize)]
+pub struct DisplayNames {
+    /// `[[Locale]]`
+    #[unsafe_ignore_trace]
+    locale: String,
+    /// `[[Style]]`
+    #[unsafe_ignore_trace]
+    style: &'static str,
+    /// `[[Type]]`
+    #[unsafe_ignore_trace]
+    display_type: &'static str,
+    /// `[[Fallback]]`
+    #[unsafe_ignore_trace]
+    fallback: &'static str,
+    /// `[[LanguageDisplay]]`, only present for the `language` type.
+    #[unsafe_ignore_trace]
+    language_display: Option<&'static str>,
+    /// `[[Fields]]`, the display names of the locale, style and type.
+    #[unsafe_ignore_trace]
+    names: Names,
+}
+
+impl BuiltIn for DisplayNames {
+    const NAME: &'static str = "DisplayNames";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().display_names().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .static_method(Self::supported_locales_of, "supportedLocalesOf", 1)
+        .method(Self::of, "of", 1)
+        .method(Self::resolved_options, "resolvedOptions", 0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            "Intl.DisplayNames",
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+impl DisplayNames {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 2;
+
+    /// `Intl.DisplayNames ( locales, options )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/DisplayNames
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error("Intl.DisplayNames constructor requires 'new'");
+        }
+
+        // 2. Let displayNames be ? OrdinaryCreateFromConstructor(NewTarget, "%DisplayNames.prototype%", « ... »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::display_names,
+            context,
+        )?;
+
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(args.get_or_undefined(0), context)?;
+
+        // 4. If options is undefined, throw a TypeError exception.
+        // 5. Let options be ? GetOptionsObject(options).
+        let options = match args.get_or_undefined(1) {
+            JsValue::Object(options) => options.clone(),
+            _ => {
+                return context
+                    .throw_type_error("Intl.DisplayNames requires an options object with a type")
+            }
+        };
+
+        // 7. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        let matcher = LocaleMatcher::from_options(&options, context)?;
+
+        // 9. Let r be ResolveLocale(%DisplayNames%.[[AvailableLocales]], requestedLocales, opt, ...).
+        let locale = resolve_icu_locale(&requested_locales, matcher, context);
+
+        // 10. Let style be ? GetOption(options, "style", string, « "narrow", "short", "long" », "long").
+        // 11. Set displayNames.[[Style]] to style.
+        let style =
+            get_option(&options, "style", &["narrow", "short", "long"], context)?.unwrap_or("long");
+
+        // 12. Let type be ? GetOption(options, "type", string, « "language", "region", "script", "currency", "calendar", "dateTimeField" », undefined).
+        // 13. If type is undefined, throw a TypeError exception.
+        // 14. Set displayNames.[[Type]] to type.
+        let display_type = match get_option(
+            &options,
+            "type",
+            &[
+                "language",
+                "region",
+                "script",
+                "currency",
+                "calendar",
+                "dateTimeField",
+            ],
+            context,
+        )? {
+            Some(display_type) => display_type,
+            None => return context.throw_type_error("Intl.DisplayNames requires a type option"),
+        };
+
+        // 15. Let fallback be ? GetOption(options, "fallback", string, « "code", "none" », "code").
+        // 16. Set displayNames.[[Fallback]] to fallback.
+        let fallback =
+            get_option(&options, "fallback", &["code", "none"], context)?.unwrap_or("code");
+
+        // 17. Set displayNames.[[Locale]] to r.[[locale]].
+        // 24. Let languageDisplay be ? GetOption(options, "languageDisplay", string, « "dialect", "standard" », "dialect").
+        let language_display = get_option(
+            &options,
+            "languageDisplay",
+            &["dialect", "standard"],
+            context,
+        )?
+        .unwrap_or("dialect");
+
+        // 25. If type is "language", then
+        //     a. Set displayNames.[[LanguageDisplay]] to languageDisplay.
+        let language_display = if display_type == "language" {
+            Some(language_display)
+        } else {
+            None
+        };
+
+        // 18. Let dataLocale be r.[[dataLocale]].
+        // 19. Let dataLocaleData be localeData.[[<dataLocale>]].
+        // 20. Let types be dataLocaleData.[[types]].
+        // 21. Assert: types is a Record (see 12.4.3).
+        // 22. Let typeFields be types.[[<type>]].
+        // 23. Assert: typeFields is a Record (see 12.4.3).
+        // 26. Let styleFields be typeFields.[[<style>]].
+        // 27. Assert: styleFields is a Record (see 12.4.3).
+        // 28. Set displayNames.[[Fields]] to styleFields.
+        let mut icu_options = DisplayNamesOptions::default();
+        icu_options.style = Some(match style {
+            "narrow" => Style::Narrow,
+            "short" => Style::Short,
+            _ => Style::Long,
+        });
+        if language_display == Some("standard") {
+            icu_options.language_display = LanguageDisplay::Standard;
+        }
+        let names = match Names::try_new(
+            display_type,
+            &DataLocale::from(&locale),
+            icu_options,
+            context,
+        ) {
+            Ok(names) => names,
+            Err(_) => {
+                return context
+                    .throw_range_error(format!("no display names available for locale `{locale}`"))
+            }
+        };
+
+        // 29. Return displayNames.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::display_names(Self {
+                locale: locale.to_string(),
+                style,
+                display_type,
+                fallback,
+                language_display,
+                names,
+            }),
+        )
+        .into())
+    }
+
+    /// Gets the `this` object, checking that it is an `Intl.DisplayNames` object.
+    fn this_display_names(this: &JsValue, context: &mut Context) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|obj| obj.borrow().as_display_names().is_some())
+            .cloned()
+            .ok_or_else(|| context.construct_type_error("'this' is not an Intl.DisplayNames"))
+    }
+
+    /// Gets the display name of a canonicalized code, if the locale data has one.
+    fn display_name(&self, code: &str) -> Option<String> {
+        match &self.names {
+            Names::Language {
+                languages,
+                formatter,
+            } => {
+                // The formatter falls back to the subtags it has no names for, so the code
+                // only has a display name if its language has one.
+                let locale = code
+                    .parse::<icu_locid::Locale>()
+                    .expect("the code must be canonicalized");
+                languages.of(locale.id.language)?;
+                Some(formatter.of(&locale).into_owned())
+            }
+            Names::Region(regions) => regions
+                .of(code.parse::<Region>().ok()?)
+                .map(ToOwned::to_owned),
+            Names::Script(scripts) => scripts
+                .of(code.parse::<Script>().ok()?)
+                .map(ToOwned::to_owned),
+            Names::None => None,
+        }
+    }
+
+    /// `Intl.DisplayNames.supportedLocalesOf ( locales [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let availableLocales be %DisplayNames%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(args.get_or_undefined(0), context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales(
+            requested_locales,
+            args.get_or_undefined(1),
+            is_well_formed_locale,
+            context,
+        )
+    }
+
+    /// `Intl.DisplayNames.prototype.of ( code )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.prototype.of
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/of
+    fn of(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let displayNames be this value.
+        // 2. Perform ? RequireInternalSlot(displayNames, [[InitializedDisplayNames]]).
+        let display_names = Self::this_display_names(this, context)?;
+
+        // 3. Let code be ? ToString(code).
+        let code = args.get_or_undefined(0).to_string(context)?;
+
+        let display_names = display_names.borrow();
+        let display_names = display_names
+            .as_display_names()
+            .expect("checked by `this_display_names`");
+
+        // 4. Set code to ? CanonicalCodeForDisplayNames(displayNames.[[Type]], code).
+        let code = match canonical_code_for_display_names(display_names.display_type, &code) {
+            Some(code) => code,
+            None => {
+                return context.throw_range_error(format!(
+                    "invalid {} code `{code}`",
+                    display_names.display_type
+                ))
+            }
+        };
+
+        // 5. Let fields be displayNames.[[Fields]].
+        // 6. If fields has a field [[<code>]], return fields.[[<code>]].
+        if let Some(name) = display_names.display_name(&code) {
+            return Ok(name.into());
+        }
+
+        // 7. If displayNames.[[Fallback]] is "code", return code.
+        if display_names.fallback == "code" {
+            return Ok(code.into());
+        }
+
+        // 8. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `Intl.DisplayNames.prototype.resolvedOptions ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.prototype.resolvedOptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let displayNames be this value.
+        // 2. Perform ? RequireInternalSlot(displayNames, [[InitializedDisplayNames]]).
+        let display_names = Self::this_display_names(this, context)?;
+        let display_names = display_names.borrow();
+        let display_names = display_names
+            .as_display_names()
+            .expect("checked by `this_display_names`");
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context.construct_object();
+
+        // 4. For each row of Table 8, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of displayNames's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let properties = [
+            ("locale", Some(display_names.locale.as_str())),
+            ("style", Some(display_names.style)),
+            ("type", Some(display_names.display_type)),
+            ("fallback", Some(display_names.fallback)),
+            ("languageDisplay", display_names.language_display),
+        ];
+        for (property, value) in properties {
+            if let Some(value) = value {
+                options
+                    .create_data_property_or_throw(property, value, context)
+                    .expect("CreateDataPropertyOrThrow cannot fail on a new ordinary object");
+            }
+        }
+
+        // 5. Return options.
+        Ok(options.into())
+    }
+}
+
+/// Abstract operation `CanonicalCodeForDisplayNames ( type, code )`
+///
+/// Returns `None` if `code` is not a well-formed code of the given type.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-canonicalcodefordisplaynames
+fn canonical_code_for_display_names(display_type: &str, code: &str) -> Option<String> {
+    match display_type {
+        // 1. If type is "language", then
+        //     a. If code does not match the unicode_language_id production, throw a RangeError exception.
+        //     b. If IsStructurallyValidLanguageTag(code) is false, throw a RangeError exception.
+        //     c. Return CanonicalizeUnicodeLocaleId(code).
+        "language" => LanguageId::parse(code).map(|id| id.to_string()),
+        // 2. If type is "region", then
+        //     a. If code does not match the unicode_region_subtag production, throw a RangeError exception.
+        //     b. Return the ASCII-uppercase of code.
+        "region" => is_region_subtag(code).then(|| code.to_ascii_uppercase()),
+        // 3. If type is "script", then
+        //     a. If code does not match the unicode_script_subtag production, throw a RangeError exception.
+        //     b. Assert: The length of code is 4, and every code unit of code represents an ASCII letter.
+        //     c. Let first be the ASCII-uppercase of the substring of code from 0 to 1.
+        //     d. Let rest be the ASCII-lowercase of the substring of code from 1.
+        //     e. Return the string-concatenation of first and rest.
+        "script" => is_script_subtag(code).then(|| title_case(code)),
+        // 4. If type is "calendar", then
+        //     a. If code does not match the Unicode Locale Identifier type nonterminal, throw a RangeError exception.
+        //     b. If code uses any of the backwards compatibility syntax described in Unicode Technical Standard #35 LDML § 3.3 BCP 47 Conformance, throw a RangeError exception.
+        //     c. Return the ASCII-lowercase of code.
+        "calendar" => {
+            let well_formed = code.split('-').all(|subtag| {
+                (3..=8).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+            });
+            well_formed.then(|| match code.to_ascii_lowercase().as_str() {
+                "gregorian" => "gregory".to_owned(),
+                "ethiopic-amete-alem" => "ethioaa".to_owned(),
+                code => code.to_owned(),
+            })
+        }
+        // 5. If type is "dateTimeField", then
+        //     a. If the result of IsValidDateTimeFieldCode(code) is false, throw a RangeError exception.
+        //     b. Return code.
+        "dateTimeField" => DATE_TIME_FIELDS.contains(&code).then(|| code.to_owned()),
+        // 6. Assert: type is "currency".
+        // 7. If IsWellFormedCurrencyCode(code) is false, throw a RangeError exception.
+        // 8. Return the ASCII-uppercase of code.
+        _ => (code.len() == 3 && code.bytes().all(|b| b.is_ascii_alphabetic()))
+            .then(|| code.to_ascii_uppercase()),
+    }
+}
+
+/// Returns `true` if `subtag` matches the `unicode_region_subtag` production.
+fn is_region_subtag(subtag: &str) -> bool {
+    (subtag.len() == 2 && subtag.bytes().all(|b| b.is_ascii_alphabetic()))
+        || (subtag.len() == 3 && subtag.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Returns `true` if `subtag` matches the `unicode_script_subtag` production.
+fn is_script_subtag(subtag: &str) -> bool {
+    subtag.len() == 4 && subtag.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+/// Returns `true` if `subtag` matches the `unicode_variant_subtag` production.
+fn is_variant_subtag(subtag: &str) -> bool {
+    let bytes = subtag.as_bytes();
+    match bytes.len() {
+        4 => bytes[0].is_ascii_digit() && bytes.iter().all(u8::is_ascii_alphanumeric),
+        5..=8 => bytes.iter().all(u8::is_ascii_alphanumeric),
+        _ => false,
+    }
+}
+
+/// Converts an ASCII subtag to title case, as used by script subtags.
+fn title_case(subtag: &str) -> String {
+    let mut result = subtag.to_ascii_lowercase();
+    result[..1].make_ascii_uppercase();
+    result
+}
+
+/// A `unicode_language_id`, with its subtags in canonical case and its variants sorted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LanguageId {
+    language: String,
+    script: Option<String>,
+    region: Option<String>,
+    variants: Vec<String>,
+}
+
+impl LanguageId {
+    /// Parses and canonicalizes a `unicode_language_id`, returning `None` if `code` is not a
+    /// structurally valid language identifier.
+    fn parse(code: &str) -> Option<Self> {
+        let mut subtags = code.split('-').peekable();
+
+        let language = subtags.next()?;
+        let well_formed = matches!(language.len(), 2 | 3 | 5..=8)
+            && language.bytes().all(|b| b.is_ascii_alphabetic());
+        if !well_formed || language.eq_ignore_ascii_case("root") {
+            return None;
+        }
+
+        let script = subtags
+            .next_if(|subtag| is_script_subtag(subtag))
+            .map(title_case);
+        let region = subtags
+            .next_if(|subtag| is_region_subtag(subtag))
+            .map(str::to_ascii_uppercase);
+
+        let mut variants = Vec::new();
+        for variant in subtags {
+            if !is_variant_subtag(variant) {
+                return None;
+            }
+            let variant = variant.to_ascii_lowercase();
+            if variants.contains(&variant) {
+                return None;
+            }
+            variants.push(variant);
+        }
+        variants.sort();
+
+        Some(Self {
+            language: language.to_ascii_lowercase(),
+            script,
+            region,
+            variants,
+        })
+    }
+}
+
+impl fmt::Display for LanguageId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.language)?;
+        for subtag in self
+            .script
+            .iter()
+            .chain(self.region.iter())
+            .chain(self.variants.iter())
+        {
+            write!(f, "-{subtag}")?;
+        }
+        Ok(())
+    }
+}
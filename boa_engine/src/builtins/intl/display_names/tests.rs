@@ -0,0 +1,219 @@
+use crate::{forward, Context};
+
+#[test]
+fn language_names() {
+    let mut context = Context::default();
+    let init = r#"
+        var dialect = new Intl.DisplayNames('en', { type: 'language' });
+        var standard = new Intl.DisplayNames('en', { type: 'language', languageDisplay: 'standard' });
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "dialect.of('fr')"), "\"French\"");
+    assert_eq!(
+        forward(&mut context, "dialect.of('en-US')"),
+        "\"American English\""
+    );
+    assert_eq!(
+        forward(&mut context, "dialect.of('fr-BE')"),
+        "\"French (Belgium)\""
+    );
+    assert_eq!(
+        forward(&mut context, "standard.of('en-US')"),
+        "\"English (United States)\""
+    );
+    assert_eq!(
+        forward(&mut context, "standard.of('EN-latn-us')"),
+        "\"English (Latin, United States)\""
+    );
+}
+
+#[test]
+fn region_and_script_names() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DisplayNames('en', { type: 'region' }).of('us')"
+        ),
+        "\"United States\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DisplayNames('en', { type: 'region', style: 'short' }).of('GB')"
+        ),
+        "\"UK\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DisplayNames('en', { type: 'region' }).of('419')"
+        ),
+        "\"Latin America\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DisplayNames('en', { type: 'script' }).of('latn')"
+        ),
+        "\"Latin\""
+    );
+}
+
+#[test]
+fn localized_names() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DisplayNames('de', { type: 'language' }).of('fr')"
+        ),
+        "\"Französisch\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DisplayNames('fr', { type: 'region' }).of('DE')"
+        ),
+        "\"Allemagne\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DisplayNames('es', { type: 'script' }).of('Cyrl')"
+        ),
+        "\"cirílico\""
+    );
+}
+
+#[test]
+fn fallback() {
+    let mut context = Context::default();
+    let init = r#"
+        var code = new Intl.DisplayNames('en', { type: 'region' });
+        var none = new Intl.DisplayNames('en', { type: 'region', fallback: 'none' });
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "code.of('xy')"), "\"XY\"");
+    assert_eq!(forward(&mut context, "none.of('xy')"), "undefined");
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DisplayNames('en', { type: 'language' }).of('qaa-latn')"
+        ),
+        "\"qaa-Latn\""
+    );
+}
+
+#[test]
+fn types_without_data() {
+    let mut context = Context::default();
+    let cases = [
+        ("currency", "'eur'", "\"EUR\""),
+        ("calendar", "'gregorian'", "\"gregory\""),
+        ("dateTimeField", "'weekOfYear'", "\"weekOfYear\""),
+    ];
+    for (display_type, code, expected) in cases {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("new Intl.DisplayNames('en', {{ type: '{display_type}' }}).of({code})")
+            ),
+            expected
+        );
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!(
+                    "new Intl.DisplayNames('en', {{ type: '{display_type}', fallback: 'none' }}).of({code})"
+                )
+            ),
+            "undefined"
+        );
+    }
+}
+
+#[test]
+fn invalid_codes() {
+    let mut context = Context::default();
+    let cases = [
+        ("language", "'root'"),
+        ("language", "'en-u-ca-gregory'"),
+        ("language", "'en_US'"),
+        ("region", "'USA'"),
+        ("script", "'Lat'"),
+        ("currency", "'US'"),
+        ("calendar", "'gregory_1'"),
+        ("dateTimeField", "'week'"),
+    ];
+    for (display_type, code) in cases {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!(
+                    "try {{ new Intl.DisplayNames('en', {{ type: '{display_type}' }}).of({code}) }} catch (e) {{ e.name }}"
+                )
+            ),
+            "\"RangeError\""
+        );
+    }
+}
+
+#[test]
+fn resolved_options() {
+    let mut context = Context::default();
+    let init = r#"
+        var language = new Intl.DisplayNames('en-GB', { type: 'language' }).resolvedOptions();
+        var region = new Intl.DisplayNames(undefined, { type: 'region', style: 'narrow', fallback: 'none' })
+            .resolvedOptions();
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "language.locale"), "\"en-GB\"");
+    assert_eq!(forward(&mut context, "language.style"), "\"long\"");
+    assert_eq!(forward(&mut context, "language.type"), "\"language\"");
+    assert_eq!(forward(&mut context, "language.fallback"), "\"code\"");
+    assert_eq!(
+        forward(&mut context, "language.languageDisplay"),
+        "\"dialect\""
+    );
+    assert_eq!(forward(&mut context, "region.locale"), "\"en-US\"");
+    assert_eq!(forward(&mut context, "region.style"), "\"narrow\"");
+    assert_eq!(forward(&mut context, "region.fallback"), "\"none\"");
+    assert_eq!(
+        forward(&mut context, "'languageDisplay' in region"),
+        "false"
+    );
+}
+
+#[test]
+fn invalid_construction() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Intl.DisplayNames('en', { type: 'region' }) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Intl.DisplayNames('en') } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Intl.DisplayNames('en', {}) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Intl.DisplayNames('en', { type: 'unit' }) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
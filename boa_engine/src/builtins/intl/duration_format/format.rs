@@ -0,0 +1,311 @@
+//! Formatting of durations for `Intl.DurationFormat`.
+//!
+//! Only the `en` locale data is available, so the numbers, units and lists produced here follow
+//! the `en` conventions of the CLDR.
+
+use super::{DurationFormat, DurationRecord, Unit};
+
+/// A single formatted part, as returned by `formatToParts`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Part {
+    pub(super) kind: &'static str,
+    pub(super) value: String,
+    /// The singular name of the unit the part belongs to, such as `"hour"`.
+    pub(super) unit: Option<&'static str>,
+}
+
+impl Part {
+    fn new<V: Into<String>>(kind: &'static str, value: V, unit: Option<&'static str>) -> Self {
+        Self {
+            kind,
+            value: value.into(),
+            unit,
+        }
+    }
+
+    fn literal<V: Into<String>>(value: V) -> Self {
+        Self::new("literal", value, None)
+    }
+}
+
+/// Concatenates the values of a list of parts.
+pub(super) fn concat(parts: &[Part]) -> String {
+    parts.iter().map(|part| part.value.as_str()).collect()
+}
+
+impl Unit {
+    /// Gets the singular name of the unit, as used by the `unit` style of `Intl.NumberFormat`.
+    fn singular(self) -> &'static str {
+        match self {
+            Self::Years => "year",
+            Self::Months => "month",
+            Self::Weeks => "week",
+            Self::Days => "day",
+            Self::Hours => "hour",
+            Self::Minutes => "minute",
+            Self::Seconds => "second",
+            Self::Milliseconds => "millisecond",
+            Self::Microseconds => "microsecond",
+            Self::Nanoseconds => "nanosecond",
+        }
+    }
+
+    /// Gets the separator between the number and the name of the unit, and the name of the unit,
+    /// for the `long`, `short` and `narrow` styles.
+    fn pattern(self, style: &str, one: bool) -> (&'static str, &'static str) {
+        match style {
+            "long" => {
+                let name = match (self, one) {
+                    (Self::Years, true) => "year",
+                    (Self::Years, false) => "years",
+                    (Self::Months, true) => "month",
+                    (Self::Months, false) => "months",
+                    (Self::Weeks, true) => "week",
+                    (Self::Weeks, false) => "weeks",
+                    (Self::Days, true) => "day",
+                    (Self::Days, false) => "days",
+                    (Self::Hours, true) => "hour",
+                    (Self::Hours, false) => "hours",
+                    (Self::Minutes, true) => "minute",
+                    (Self::Minutes, false) => "minutes",
+                    (Self::Seconds, true) => "second",
+                    (Self::Seconds, false) => "seconds",
+                    (Self::Milliseconds, true) => "millisecond",
+                    (Self::Milliseconds, false) => "milliseconds",
+                    (Self::Microseconds, true) => "microsecond",
+                    (Self::Microseconds, false) => "microseconds",
+                    (Self::Nanoseconds, true) => "nanosecond",
+                    (Self::Nanoseconds, false) => "nanoseconds",
+                };
+                (" ", name)
+            }
+            "narrow" => {
+                let name = match self {
+                    Self::Years => "y",
+                    Self::Months | Self::Minutes => "m",
+                    Self::Weeks => "w",
+                    Self::Days => "d",
+                    Self::Hours => "h",
+                    Self::Seconds => "s",
+                    Self::Milliseconds => "ms",
+                    Self::Microseconds => "μs",
+                    Self::Nanoseconds => "ns",
+                };
+                ("", name)
+            }
+            _ => {
+                let name = match (self, one) {
+                    (Self::Years, true) => "yr",
+                    (Self::Years, false) => "yrs",
+                    (Self::Months, true) => "mth",
+                    (Self::Months, false) => "mths",
+                    (Self::Weeks, true) => "wk",
+                    (Self::Weeks, false) => "wks",
+                    (Self::Days, true) => "day",
+                    (Self::Days, false) => "days",
+                    (Self::Hours, _) => "hr",
+                    (Self::Minutes, _) => "min",
+                    (Self::Seconds, _) => "sec",
+                    (Self::Milliseconds, _) => "ms",
+                    (Self::Microseconds, _) => "μs",
+                    (Self::Nanoseconds, _) => "ns",
+                };
+                (" ", name)
+            }
+        }
+    }
+}
+
+/// The value of a unit to format, with the smaller fractional units added to it.
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    unit: Unit,
+    style: &'static str,
+    display: &'static str,
+    /// The absolute value of the unit, in `10^-scale` units.
+    magnitude: u128,
+    /// The number of fractional digits of `magnitude`.
+    scale: u32,
+}
+
+impl Entry {
+    /// Returns `true` if the unit is formatted.
+    fn is_displayed(&self) -> bool {
+        self.magnitude != 0 || self.display == "always"
+    }
+
+    /// Returns `true` if the unit is formatted as a number, without its name.
+    fn is_numeric(&self) -> bool {
+        matches!(self.style, "numeric" | "2-digit")
+    }
+}
+
+/// Formats a number with the `en` conventions, as the parts of the `unit` style of
+/// `Intl.NumberFormat` with the `trunc` rounding mode.
+fn format_number(
+    entry: &Entry,
+    negative: bool,
+    fraction_digits: (usize, usize),
+    unit: &'static str,
+) -> Vec<Part> {
+    let (minimum_fraction_digits, maximum_fraction_digits) = fraction_digits;
+    let divisor = 10u128.pow(entry.scale);
+    let integer = (entry.magnitude / divisor).to_string();
+    let mut fraction = format!(
+        "{:0width$}",
+        entry.magnitude % divisor,
+        width = entry.scale as usize
+    );
+    if entry.scale == 0 {
+        fraction.clear();
+    }
+    fraction.truncate(maximum_fraction_digits);
+    while fraction.len() < minimum_fraction_digits {
+        fraction.push('0');
+    }
+    while fraction.len() > minimum_fraction_digits && fraction.ends_with('0') {
+        fraction.pop();
+    }
+
+    let minimum_integer_digits = if entry.style == "2-digit" { 2 } else { 1 };
+    let integer = format!("{integer:0>minimum_integer_digits$}");
+
+    let mut parts = Vec::new();
+    if negative {
+        parts.push(Part::new("minusSign", "-", Some(unit)));
+    }
+    let first_group = match integer.len() % 3 {
+        0 => 3,
+        len => len,
+    };
+    parts.push(Part::new("integer", &integer[..first_group], Some(unit)));
+    for start in (first_group..integer.len()).step_by(3) {
+        parts.push(Part::new("group", ",", Some(unit)));
+        parts.push(Part::new("integer", &integer[start..start + 3], Some(unit)));
+    }
+    if !fraction.is_empty() {
+        parts.push(Part::new("decimal", ".", Some(unit)));
+        parts.push(Part::new("fraction", fraction, Some(unit)));
+    }
+    parts
+}
+
+impl DurationFormat {
+    /// Abstract operation `PartitionDurationFormatPattern ( durationFormat, duration )`
+    ///
+    /// Only the first formatted unit shows the sign of a negative duration.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-partitiondurationformatpattern
+    pub(super) fn partition(&self, duration: &DurationRecord) -> Vec<Part> {
+        let mut entries = Vec::new();
+        for (index, unit) in Unit::ALL.into_iter().enumerate() {
+            let options = self.units[index];
+            if options.style == "fractional" {
+                break;
+            }
+
+            // If the next units are fractional, add them to the value of this unit.
+            let mut entry = Entry {
+                unit,
+                style: options.style,
+                display: options.display,
+                magnitude: duration.get(unit).abs() as u128,
+                scale: 0,
+            };
+            for next in Unit::ALL.into_iter().skip(index + 1) {
+                if self.units[next as usize].style != "fractional" {
+                    break;
+                }
+                entry.magnitude = entry.magnitude * 1000 + duration.get(next).abs() as u128;
+                entry.scale += 3;
+            }
+            entries.push(entry);
+
+            // The fractional units have been formatted.
+            if entry.scale > 0 {
+                break;
+            }
+        }
+
+        let fraction_digits = |entry: &Entry| -> (usize, usize) {
+            if entry.scale == 0 {
+                (0, 0)
+            } else {
+                self.fractional_digits
+                    .map_or((0, 9), |digits| (digits.into(), digits.into()))
+            }
+        };
+        let mut sign_pending = duration.sign() < 0;
+        let mut sign = || std::mem::replace(&mut sign_pending, false);
+
+        let mut elements: Vec<Vec<Part>> = Vec::new();
+        let mut index = 0;
+        while index < entries.len() {
+            let entry = entries[index];
+
+            // The consecutive numeric units are joined with the `:` time separator, and the
+            // units between two formatted numeric units are always formatted.
+            if entry.is_numeric() {
+                let run = entries[index..]
+                    .iter()
+                    .take_while(|entry| entry.is_numeric())
+                    .copied()
+                    .collect::<Vec<_>>();
+                index += run.len();
+
+                let first = run.iter().position(Entry::is_displayed);
+                let last = run.iter().rposition(Entry::is_displayed);
+                if let (Some(first), Some(last)) = (first, last) {
+                    let mut element = Vec::new();
+                    for entry in &run[first..=last] {
+                        if !element.is_empty() {
+                            element.push(Part::literal(":"));
+                        }
+                        element.extend(format_number(
+                            entry,
+                            sign(),
+                            fraction_digits(entry),
+                            entry.unit.singular(),
+                        ));
+                    }
+                    elements.push(element);
+                }
+                continue;
+            }
+
+            index += 1;
+            if !entry.is_displayed() {
+                continue;
+            }
+
+            let unit = entry.unit.singular();
+            let mut element = format_number(&entry, sign(), fraction_digits(&entry), unit);
+            let one = element
+                .iter()
+                .filter(|part| part.kind != "minusSign")
+                .map(|part| part.value.as_str())
+                .collect::<String>()
+                == "1";
+            let (separator, name) = entry.unit.pattern(entry.style, one);
+            if !separator.is_empty() {
+                element.push(Part::new("literal", separator, Some(unit)));
+            }
+            element.push(Part::new("unit", name, Some(unit)));
+            elements.push(element);
+        }
+
+        // Let lf be ! Construct(%ListFormat%, « durationFormat.[[Locale]], { type: "unit", style: listStyle } »).
+        let separator = if self.style == "narrow" { " " } else { ", " };
+        let mut parts = Vec::new();
+        for element in elements {
+            if !parts.is_empty() {
+                parts.push(Part::literal(separator));
+            }
+            parts.extend(element);
+        }
+        parts
+    }
+}
@@ -0,0 +1,718 @@
+//! This module implements the global `Intl.DurationFormat` object.
+//!
+//! `Intl.DurationFormat` formats durations, such as `1 hr, 46 min`, with the conventions of a
+//! locale. Only the `en` locale data is available.
+//!
+//! The durations are read as [`DurationRecord`]s from any object with duration fields, so that
+//! `Temporal.Duration` objects can be formatted as well.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-intl-duration-format/#durationformat-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat
+
+mod format;
+
+#[cfg(test)]
+mod tests;
+
+use self::format::concat;
+use super::{
+    get_number_option, get_option, get_options_object, is_unicode_type,
+    negotiation::{
+        canonicalize_locale_list, has_english_data, resolve_locale, supported_locales,
+        LocaleMatcher,
+    },
+};
+use crate::{
+    builtins::{Array, BuiltIn, JsArgs},
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use tap::{Conv, Pipe};
+
+/// A unit of a duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Unit {
+    Years,
+    Months,
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl Unit {
+    /// The units of a duration, from the largest to the smallest.
+    pub(crate) const ALL: [Self; 10] = [
+        Self::Years,
+        Self::Months,
+        Self::Weeks,
+        Self::Days,
+        Self::Hours,
+        Self::Minutes,
+        Self::Seconds,
+        Self::Milliseconds,
+        Self::Microseconds,
+        Self::Nanoseconds,
+    ];
+
+    /// Gets the name of the field of the unit in a duration, such as `"hours"`.
+    pub(crate) fn field(self) -> &'static str {
+        match self {
+            Self::Years => "years",
+            Self::Months => "months",
+            Self::Weeks => "weeks",
+            Self::Days => "days",
+            Self::Hours => "hours",
+            Self::Minutes => "minutes",
+            Self::Seconds => "seconds",
+            Self::Milliseconds => "milliseconds",
+            Self::Microseconds => "microseconds",
+            Self::Nanoseconds => "nanoseconds",
+        }
+    }
+
+    /// Gets the name of the display option of the unit, such as `"hoursDisplay"`.
+    fn display_field(self) -> &'static str {
+        match self {
+            Self::Years => "yearsDisplay",
+            Self::Months => "monthsDisplay",
+            Self::Weeks => "weeksDisplay",
+            Self::Days => "daysDisplay",
+            Self::Hours => "hoursDisplay",
+            Self::Minutes => "minutesDisplay",
+            Self::Seconds => "secondsDisplay",
+            Self::Milliseconds => "millisecondsDisplay",
+            Self::Microseconds => "microsecondsDisplay",
+            Self::Nanoseconds => "nanosecondsDisplay",
+        }
+    }
+
+    /// Gets the `Styles` and the `Digital Default` of the unit, in Table 3 of the
+    /// specification.
+    fn styles(self) -> (&'static [&'static str], &'static str) {
+        match self {
+            Self::Years | Self::Months | Self::Weeks | Self::Days => {
+                (&["long", "short", "narrow"], "short")
+            }
+            Self::Hours => (
+                &["long", "short", "narrow", "numeric", "2-digit"],
+                "numeric",
+            ),
+            Self::Minutes | Self::Seconds => (
+                &["long", "short", "narrow", "numeric", "2-digit"],
+                "2-digit",
+            ),
+            Self::Milliseconds | Self::Microseconds | Self::Nanoseconds => {
+                (&["long", "short", "narrow", "numeric"], "numeric")
+            }
+        }
+    }
+}
+
+/// A Duration Record, holding the value of every unit of a duration.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct DurationRecord {
+    values: [f64; 10],
+}
+
+impl DurationRecord {
+    /// Creates a duration record from the values of its units, from the largest to the smallest.
+    pub(crate) fn new(values: [f64; 10]) -> Self {
+        Self { values }
+    }
+
+    /// Gets the value of a unit of the duration.
+    pub(crate) fn get(&self, unit: Unit) -> f64 {
+        self.values[unit as usize]
+    }
+
+    /// Abstract operation `DurationSign ( duration )`
+    pub(crate) fn sign(&self) -> i8 {
+        self.values
+            .iter()
+            .find(|value| **value != 0.0)
+            .map_or(0, |value| if *value < 0.0 { -1 } else { 1 })
+    }
+
+    /// Abstract operation `IsValidDuration ( years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-isvalidduration
+    pub(crate) fn is_valid(&self) -> bool {
+        /// 2^53 seconds, in nanoseconds.
+        const MAX_NANOSECONDS: i128 = (1 << 53) * 1_000_000_000;
+
+        // 1. Let sign be DurationSign(...).
+        let sign = self.sign();
+
+        // 2. For each value v of « years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds », do
+        //     a. If 𝔽(v) is not finite, return false.
+        //     b. If v < 0 and sign > 0, return false.
+        //     c. If v > 0 and sign < 0, return false.
+        if self.values.iter().any(|value| {
+            !value.is_finite() || (*value < 0.0 && sign > 0) || (*value > 0.0 && sign < 0)
+        }) {
+            return false;
+        }
+
+        // 3. If abs(years) ≥ 2^32, return false.
+        // 4. If abs(months) ≥ 2^32, return false.
+        // 5. If abs(weeks) ≥ 2^32, return false.
+        if [Unit::Years, Unit::Months, Unit::Weeks]
+            .iter()
+            .any(|unit| self.get(*unit).abs() >= 2f64.powi(32))
+        {
+            return false;
+        }
+
+        // 6. Let normalizedSeconds be days × 86,400 + hours × 3600 + minutes × 60 + seconds + ℝ(𝔽(milliseconds)) × 10^-3 + ℝ(𝔽(microseconds)) × 10^-6 + ℝ(𝔽(nanoseconds)) × 10^-9.
+        // 7. If abs(normalizedSeconds) ≥ 2^53, return false.
+        let nanoseconds = [
+            (Unit::Days, 86_400_000_000_000),
+            (Unit::Hours, 3_600_000_000_000),
+            (Unit::Minutes, 60_000_000_000),
+            (Unit::Seconds, 1_000_000_000),
+            (Unit::Milliseconds, 1_000_000),
+            (Unit::Microseconds, 1000),
+            (Unit::Nanoseconds, 1),
+        ]
+        .iter()
+        .try_fold(0i128, |total, (unit, factor)| {
+            let value = self.get(*unit).abs();
+            if value >= MAX_NANOSECONDS as f64 {
+                return None;
+            }
+            (value as i128).checked_mul(*factor)?.checked_add(total)
+        });
+
+        // 8. Return true.
+        matches!(nanoseconds, Some(nanoseconds) if nanoseconds < MAX_NANOSECONDS)
+    }
+
+    /// Abstract operation `ToDurationRecord ( input )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-todurationrecord
+    pub(crate) fn from_value(input: &JsValue, context: &mut Context) -> JsResult<Self> {
+        // 1. If Type(input) is not Object, then
+        let input = match input {
+            JsValue::Object(input) => input,
+            //     a. If Type(input) is String, throw a RangeError exception.
+            JsValue::String(_) => {
+                return context.throw_range_error("duration strings are not supported")
+            }
+            //     b. Throw a TypeError exception.
+            _ => return context.throw_type_error("duration must be an object"),
+        };
+
+        // 2. Let result be a new Duration Record with each field set to 0.
+        let mut values = [0.0; 10];
+
+        // 3. Let any be false.
+        let mut any = false;
+
+        // 4. For each row of Table 1, except the header row, in table order, do
+        let mut units = Unit::ALL;
+        units.sort_unstable_by_key(|unit| unit.field());
+        for unit in units {
+            // a. Let valueSlot be the Value Slot value of the current row.
+            // b. Let unit be the Unit value of the current row.
+            // c. Let value be ? Get(input, unit).
+            let value = input.get(unit.field(), context)?;
+
+            // d. If value is not undefined, then
+            if !value.is_undefined() {
+                // i. Set any to true.
+                any = true;
+
+                // ii. Set value to ? ToIntegerIfIntegral(value).
+                let value = value.to_number(context)?;
+                if !value.is_finite() || value.fract() != 0.0 {
+                    return context.throw_range_error(format!(
+                        "the `{}` of a duration must be an integer",
+                        unit.field()
+                    ));
+                }
+
+                // iii. Set result.[[<valueSlot>]] to value.
+                // Adding `+0` turns `-0` into the mathematical value `0`.
+                values[unit as usize] = value + 0.0;
+            }
+        }
+
+        // 5. If any is false, throw a TypeError exception.
+        if !any {
+            return context.throw_type_error("duration must have at least one unit");
+        }
+
+        // 6. If IsValidDuration(...) is false, throw a RangeError exception.
+        let result = Self::new(values);
+        if !result.is_valid() {
+            return context.throw_range_error("invalid duration");
+        }
+
+        // 7. Return result.
+        Ok(result)
+    }
+}
+
+/// The style and display of a unit, in the internal slots of an `Intl.DurationFormat` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct UnitOptions {
+    /// `[[<Unit>Style]]`
+    style: &'static str,
+    /// `[[<Unit>Display]]`
+    display: &'static str,
+}
+
+/// The internal slots of an `Intl.DurationFormat` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct DurationFormat {
+    /// `[[Locale]]`
+    #[unsafe_ignore_trace]
+    locale: String,
+    /// `[[Style]]`
+    #[unsafe_ignore_trace]
+    style: &'static str,
+    /// The style and display of every unit, from the largest to the smallest.
+    #[unsafe_ignore_trace]
+    units: [UnitOptions; 10],
+    /// `[[FractionalDigits]]`
+    #[unsafe_ignore_trace]
+    fractional_digits: Option<u8>,
+}
+
+impl BuiltIn for DurationFormat {
+    const NAME: &'static str = "DurationFormat";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context
+                .intrinsics()
+                .constructors()
+                .duration_format()
+                .clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .static_method(Self::supported_locales_of, "supportedLocalesOf", 1)
+        .method(Self::format, "format", 1)
+        .method(Self::format_to_parts, "formatToParts", 1)
+        .method(Self::resolved_options, "resolvedOptions", 0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            "Intl.DurationFormat",
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+impl DurationFormat {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `Intl.DurationFormat ( [ locales [ , options ] ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-Intl.DurationFormat
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/DurationFormat
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error("Intl.DurationFormat constructor requires 'new'");
+        }
+
+        // 2. Let durationFormat be ? OrdinaryCreateFromConstructor(NewTarget, "%DurationFormatPrototype%", « ... »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::duration_format,
+            context,
+        )?;
+
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(args.get_or_undefined(0), context)?;
+
+        // 4. Let options be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1), context)?;
+
+        // 5. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        let matcher = LocaleMatcher::from_options(&options, context)?;
+
+        // 6. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+        // 7. If numberingSystem is not undefined, then
+        //     a. If numberingSystem does not match the Unicode Locale Identifier type nonterminal, throw a RangeError exception.
+        // Only the Latin digits are supported, so other values are ignored.
+        let numbering_system = options.get("numberingSystem", context)?;
+        if !numbering_system.is_undefined() {
+            let numbering_system = numbering_system.to_string(context)?;
+            if !is_unicode_type(&numbering_system) {
+                return context.throw_range_error(format!(
+                    "invalid value `{numbering_system}` for option `numberingSystem`"
+                ));
+            }
+        }
+
+        // 9. Let r be ResolveLocale(%DurationFormat%.[[AvailableLocales]], requestedLocales, opt, ...).
+        // 10. Let locale be r.[[locale]].
+        // 11. Set durationFormat.[[Locale]] to locale.
+        let locale = resolve_locale(&requested_locales, matcher, has_english_data, context);
+
+        // 13. Let style be ? GetOption(options, "style", string, « "long", "short", "narrow", "digital" », "short").
+        // 14. Set durationFormat.[[Style]] to style.
+        let style = get_option(
+            &options,
+            "style",
+            &["long", "short", "narrow", "digital"],
+            context,
+        )?
+        .unwrap_or("short");
+
+        // 15. Let prevStyle be the empty String.
+        let mut prev_style = "";
+
+        // 16. For each row of Table 3, except the header row, in table order, do
+        let mut units = [UnitOptions {
+            style,
+            display: "auto",
+        }; 10];
+        for (unit, unit_options) in Unit::ALL.into_iter().zip(&mut units) {
+            // a-d. Let unitOptions be ? GetDurationUnitOptions(unit, options, style, valueList, digitalBase, prevStyle).
+            *unit_options = get_duration_unit_options(unit, &options, style, prev_style, context)?;
+
+            // e. Set the value of the styleSlot slot of durationFormat to unitOptions.[[Style]].
+            // f. Set the value of the displaySlot slot of durationFormat to unitOptions.[[Display]].
+            // g. If unit is one of "hours", "minutes", "seconds", "milliseconds", or "microseconds", then
+            //     i. Set prevStyle to unitOptions.[[Style]].
+            if matches!(
+                unit,
+                Unit::Hours
+                    | Unit::Minutes
+                    | Unit::Seconds
+                    | Unit::Milliseconds
+                    | Unit::Microseconds
+            ) {
+                prev_style = unit_options.style;
+            }
+        }
+
+        // 17. Set durationFormat.[[FractionalDigits]] to ? GetNumberOption(options, "fractionalDigits", 0, 9, undefined).
+        let fractional_digits = get_number_option(&options, "fractionalDigits", 0, 9, context)?;
+
+        // 18. Return durationFormat.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::duration_format(Self {
+                locale,
+                style,
+                units,
+                fractional_digits,
+            }),
+        )
+        .into())
+    }
+
+    /// Formats a duration record, as `Intl.DurationFormat.prototype.format`.
+    pub(crate) fn format_record(&self, record: &DurationRecord) -> String {
+        concat(&self.partition(record))
+    }
+
+    /// Gets the `this` `Intl.DurationFormat` object.
+    fn this_duration_format(this: &JsValue, context: &mut Context) -> JsResult<Self> {
+        this.as_object()
+            .and_then(|obj| obj.borrow().as_duration_format().cloned())
+            .ok_or_else(|| context.construct_type_error("'this' is not an Intl.DurationFormat"))
+    }
+
+    /// `Intl.DurationFormat.supportedLocalesOf ( locales [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-Intl.DurationFormat.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let availableLocales be %DurationFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(args.get_or_undefined(0), context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales(
+            requested_locales,
+            args.get_or_undefined(1),
+            has_english_data,
+            context,
+        )
+    }
+
+    /// `Intl.DurationFormat.prototype.format ( duration )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-Intl.DurationFormat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/format
+    fn format(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let df be this value.
+        // 2. Perform ? RequireInternalSlot(df, [[InitializedDurationFormat]]).
+        let df = Self::this_duration_format(this, context)?;
+
+        // 3. Let record be ? ToDurationRecord(duration).
+        let record = DurationRecord::from_value(args.get_or_undefined(0), context)?;
+
+        // 4. Let parts be PartitionDurationFormatPattern(df, record).
+        // 5. Let result be the empty String.
+        // 6. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        // 7. Return result.
+        Ok(df.format_record(&record).into())
+    }
+
+    /// `Intl.DurationFormat.prototype.formatToParts ( duration )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-Intl.DurationFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let df be this value.
+        // 2. Perform ? RequireInternalSlot(df, [[InitializedDurationFormat]]).
+        let df = Self::this_duration_format(this, context)?;
+
+        // 3. Let record be ? ToDurationRecord(duration).
+        let record = DurationRecord::from_value(args.get_or_undefined(0), context)?;
+
+        // 4. Let parts be PartitionDurationFormatPattern(df, record).
+        let parts = df.partition(&record);
+
+        // 5. Let result be ! ArrayCreate(0).
+        // 6. Let n be 0.
+        // 7. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        //     a. Let obj be OrdinaryObjectCreate(%ObjectPrototype%).
+        //     b. Perform ! CreateDataPropertyOrThrow(obj, "type", part.[[Type]]).
+        //     c. Perform ! CreateDataPropertyOrThrow(obj, "value", part.[[Value]]).
+        //     d. If part.[[Unit]] is not empty, perform ! CreateDataPropertyOrThrow(obj, "unit", part.[[Unit]]).
+        //     e. Perform ! CreateDataPropertyOrThrow(result, ! ToString(n), obj).
+        //     f. Increment n by 1.
+        let parts = parts
+            .into_iter()
+            .map(|part| {
+                let object = context.construct_object();
+                object
+                    .create_data_property_or_throw("type", part.kind, context)
+                    .expect("CreateDataPropertyOrThrow cannot fail on a new ordinary object");
+                object
+                    .create_data_property_or_throw("value", part.value, context)
+                    .expect("CreateDataPropertyOrThrow cannot fail on a new ordinary object");
+                if let Some(unit) = part.unit {
+                    object
+                        .create_data_property_or_throw("unit", unit, context)
+                        .expect("CreateDataPropertyOrThrow cannot fail on a new ordinary object");
+                }
+                object.into()
+            })
+            .collect::<Vec<JsValue>>();
+
+        // 8. Return result.
+        Ok(Array::create_array_from_list(parts, context).into())
+    }
+
+    /// `Intl.DurationFormat.prototype.resolvedOptions ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-Intl.DurationFormat.prototype.resolvedOptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let df be the this value.
+        // 2. Perform ? RequireInternalSlot(df, [[InitializedDurationFormat]]).
+        let df = Self::this_duration_format(this, context)?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context.construct_object();
+
+        // 4. For each row of Table 2, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of df's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If p is one of the unit style properties and v is "fractional", set v to "numeric".
+        //     d. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut entries: Vec<(&str, JsValue)> = vec![
+            ("locale", df.locale.into()),
+            ("numberingSystem", "latn".into()),
+            ("style", df.style.into()),
+        ];
+        for (unit, unit_options) in Unit::ALL.into_iter().zip(df.units) {
+            let style = if unit_options.style == "fractional" {
+                "numeric"
+            } else {
+                unit_options.style
+            };
+            entries.push((unit.field(), style.into()));
+            entries.push((unit.display_field(), unit_options.display.into()));
+        }
+        if let Some(fractional_digits) = df.fractional_digits {
+            entries.push(("fractionalDigits", u32::from(fractional_digits).into()));
+        }
+        for (property, value) in entries {
+            options
+                .create_data_property_or_throw(property, value, context)
+                .expect("CreateDataPropertyOrThrow cannot fail on a new ordinary object");
+        }
+
+        // 5. Return options.
+        Ok(options.into())
+    }
+}
+
+/// Abstract operation `GetDurationUnitOptions ( unit, options, baseStyle, stylesList, digitalBase, prevStyle )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-intl-duration-format/#sec-getdurationunitoptions
+fn get_duration_unit_options(
+    unit: Unit,
+    options: &JsObject,
+    base_style: &'static str,
+    prev_style: &str,
+    context: &mut Context,
+) -> JsResult<UnitOptions> {
+    let (styles_list, digital_base) = unit.styles();
+    let is_numeric = |style: &str| matches!(style, "fractional" | "numeric" | "2-digit");
+
+    // 1. Let style be ? GetOption(options, unit, string, stylesList, undefined).
+    let style = get_option(options, unit.field(), styles_list, context)?;
+
+    // 2. Let displayDefault be "always".
+    let mut display_default = "always";
+
+    // 3. If style is undefined, then
+    let mut style = style.unwrap_or_else(|| {
+        // a. If baseStyle is "digital", then
+        if base_style == "digital" {
+            // i. If unit is not one of "hours", "minutes", or "seconds", then
+            if !matches!(unit, Unit::Hours | Unit::Minutes | Unit::Seconds) {
+                // 1. Set displayDefault to "auto".
+                display_default = "auto";
+            }
+            // ii. Set style to digitalBase.
+            digital_base
+        }
+        // b. Else if prevStyle is "fractional", "numeric" or "2-digit", then
+        else if is_numeric(prev_style) {
+            // i. If unit is not one of "minutes" or "seconds", then
+            if !matches!(unit, Unit::Minutes | Unit::Seconds) {
+                // 1. Set displayDefault to "auto".
+                display_default = "auto";
+            }
+            // ii. Set style to "numeric".
+            "numeric"
+        }
+        // c. Else,
+        else {
+            // i. Set displayDefault to "auto".
+            display_default = "auto";
+            // ii. Set style to baseStyle.
+            base_style
+        }
+    });
+
+    // 4. If style is "numeric" and unit is one of "milliseconds", "microseconds", or "nanoseconds", then
+    if style == "numeric"
+        && matches!(
+            unit,
+            Unit::Milliseconds | Unit::Microseconds | Unit::Nanoseconds
+        )
+    {
+        // a. Set style to "fractional".
+        style = "fractional";
+        // b. Set displayDefault to "auto".
+        display_default = "auto";
+    }
+
+    // 5. Let displayField be the string-concatenation of unit and "Display".
+    // 6. Let display be ? GetOption(options, displayField, string, « "auto", "always" », displayDefault).
+    let display = get_option(options, unit.display_field(), &["auto", "always"], context)?
+        .unwrap_or(display_default);
+
+    // 7. If display is "always" and style is "fractional", then
+    if display == "always" && style == "fractional" {
+        // a. Throw a RangeError exception.
+        return context.throw_range_error(format!(
+            "`{}` cannot be always displayed with a fractional style",
+            unit.field()
+        ));
+    }
+
+    // 8. If prevStyle is "fractional", then
+    //     a. If style is not "fractional", then
+    //         i. Throw a RangeError exception.
+    // 9. If prevStyle is "numeric" or "2-digit", then
+    //     a. If style is not "fractional", "numeric" or "2-digit", then
+    //         i. Throw a RangeError exception.
+    if (prev_style == "fractional" && style != "fractional")
+        || (is_numeric(prev_style) && !is_numeric(style))
+    {
+        return context.throw_range_error(format!(
+            "`{}` must have a numeric style after a numeric unit",
+            unit.field()
+        ));
+    }
+
+    //     b. If unit is "minutes" or "seconds", then
+    //         i. Set style to "2-digit".
+    if matches!(prev_style, "numeric" | "2-digit") && matches!(unit, Unit::Minutes | Unit::Seconds)
+    {
+        style = "2-digit";
+    }
+
+    // 10. Return the Record { [[Style]]: style, [[Display]]: display }.
+    Ok(UnitOptions { style, display })
+}
@@ -0,0 +1,200 @@
+use crate::{forward, Context};
+
+#[test]
+fn format_styles() {
+    let mut context = Context::default();
+    let init = r#"
+        var duration = { hours: 1, minutes: 46, seconds: 40 };
+        function format(options, value = duration) {
+            return new Intl.DurationFormat('en', options).format(value);
+        }
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "format()"),
+        "\"1 hr, 46 min, 40 sec\""
+    );
+    assert_eq!(
+        forward(&mut context, "format({ style: 'long' })"),
+        "\"1 hour, 46 minutes, 40 seconds\""
+    );
+    assert_eq!(
+        forward(&mut context, "format({ style: 'narrow' })"),
+        "\"1h 46m 40s\""
+    );
+    assert_eq!(
+        forward(&mut context, "format({ style: 'digital' })"),
+        "\"1:46:40\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "format({ style: 'long' }, { years: 2, days: 1, minutes: 0 })"
+        ),
+        "\"2 years, 1 day\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "format({ style: 'digital' }, { days: 3, seconds: 5 })"
+        ),
+        "\"3 days, 0:00:05\""
+    );
+    assert_eq!(
+        forward(&mut context, "format({}, { weeks: 1000 })"),
+        "\"1,000 wks\""
+    );
+}
+
+#[test]
+fn numeric_units() {
+    let mut context = Context::default();
+    let init = r#"
+        function format(options, value) {
+            return new Intl.DurationFormat('en', options).format(value);
+        }
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(
+            &mut context,
+            "format({ hours: 'numeric' }, { hours: 2, minutes: 5 })"
+        ),
+        "\"2:05:00\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "format({ style: 'digital' }, { seconds: 1, milliseconds: 500, nanoseconds: 7 })"
+        ),
+        "\"0:00:01.500000007\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "format({ style: 'digital', fractionalDigits: 2 }, { seconds: 1, milliseconds: 2599 })"
+        ),
+        "\"0:00:03.59\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "format({ milliseconds: 'numeric' }, { seconds: 1, milliseconds: 250 })"
+        ),
+        "\"1.25 sec\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "format({ style: 'digital' }, { hours: -1, minutes: -2 })"
+        ),
+        "\"-1:02:00\""
+    );
+}
+
+#[test]
+fn format_to_parts() {
+    let mut context = Context::default();
+    let init = r#"
+        var parts = new Intl.DurationFormat('en', { style: 'long' })
+            .formatToParts({ hours: 1, minutes: 2 });
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(
+            &mut context,
+            "parts.map(p => `${p.type}:${p.value}:${p.unit}`).join('|')"
+        ),
+        "\"integer:1:hour|literal: :hour|unit:hour:hour|literal:, :undefined|integer:2:minute|literal: :minute|unit:minutes:minute\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DurationFormat('en', { style: 'digital' }).formatToParts({ minutes: 3 }).map(p => p.value).join('|')"
+        ),
+        "\"0|:|03|:|00\""
+    );
+}
+
+#[test]
+fn resolved_options() {
+    let mut context = Context::default();
+    let init = r#"
+        var options = new Intl.DurationFormat('en-GB', { style: 'digital', fractionalDigits: 3 })
+            .resolvedOptions();
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "options.locale"), "\"en\"");
+    assert_eq!(forward(&mut context, "options.numberingSystem"), "\"latn\"");
+    assert_eq!(forward(&mut context, "options.style"), "\"digital\"");
+    assert_eq!(forward(&mut context, "options.years"), "\"short\"");
+    assert_eq!(forward(&mut context, "options.yearsDisplay"), "\"auto\"");
+    assert_eq!(forward(&mut context, "options.hours"), "\"numeric\"");
+    assert_eq!(forward(&mut context, "options.hoursDisplay"), "\"always\"");
+    assert_eq!(forward(&mut context, "options.minutes"), "\"2-digit\"");
+    assert_eq!(forward(&mut context, "options.milliseconds"), "\"numeric\"");
+    assert_eq!(forward(&mut context, "options.fractionalDigits"), "3");
+    assert_eq!(
+        forward(
+            &mut context,
+            "'fractionalDigits' in new Intl.DurationFormat().resolvedOptions()"
+        ),
+        "false"
+    );
+}
+
+#[test]
+fn invalid_options() {
+    let mut context = Context::default();
+    for code in [
+        "new Intl.DurationFormat('en', { style: 'tiny' })",
+        "new Intl.DurationFormat('en', { hours: 'numeric', minutes: 'long' })",
+        "new Intl.DurationFormat('en', { milliseconds: 'numeric', millisecondsDisplay: 'always' })",
+        "new Intl.DurationFormat('en', { fractionalDigits: 10 })",
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {code} }} catch (e) {{ e.name }}")
+            ),
+            "\"RangeError\""
+        );
+    }
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Intl.DurationFormat() } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Intl.DurationFormat('en', 'long') } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn invalid_durations() {
+    let mut context = Context::default();
+    forward(&mut context, "var df = new Intl.DurationFormat();");
+    for (duration, error) in [
+        ("'PT1H'", "RangeError"),
+        ("5", "TypeError"),
+        ("{}", "TypeError"),
+        ("{ hours: 1.5 }", "RangeError"),
+        ("{ hours: 1, minutes: -1 }", "RangeError"),
+        ("{ years: 2 ** 32 }", "RangeError"),
+        ("{ seconds: 2 ** 53 }", "RangeError"),
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ df.format({duration}) }} catch (e) {{ e.name }}")
+            ),
+            format!("\"{error}\"")
+        );
+    }
+}
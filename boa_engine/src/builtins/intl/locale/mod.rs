@@ -0,0 +1,581 @@
+//! This module implements the global `Intl.Locale` object.
+//!
+//! `Intl.Locale` objects represent a Unicode locale identifier, and expose its subtags and
+//! Unicode extension keywords, backed by the locale data of ICU4X.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#locale-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale
+
+#[cfg(test)]
+mod tests;
+
+use super::{
+    coerce_options_to_object, get_bool_option, get_option, get_string_option,
+    negotiation::parse_canonical_locale,
+};
+use crate::{
+    builtins::{function::NativeFunctionSignature, BuiltIn, JsArgs},
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsString, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use icu_locid::{
+    extensions::unicode::{key, Key, Value},
+    subtags::{Language, Region, Script},
+};
+use tap::{Conv, Pipe};
+
+/// The internal slots of an `Intl.Locale` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Locale {
+    /// `[[Locale]]`, from which the other internal slots are derived.
+    #[unsafe_ignore_trace]
+    locale: icu_locid::Locale,
+}
+
+impl BuiltIn for Locale {
+    const NAME: &'static str = "Locale";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let getters: [(&str, NativeFunctionSignature); 10] = [
+            ("baseName", Self::get_base_name),
+            ("calendar", Self::get_calendar),
+            ("caseFirst", Self::get_case_first),
+            ("collation", Self::get_collation),
+            ("hourCycle", Self::get_hour_cycle),
+            ("numeric", Self::get_numeric),
+            ("numberingSystem", Self::get_numbering_system),
+            ("language", Self::get_language),
+            ("script", Self::get_script),
+            ("region", Self::get_region),
+        ];
+        let getters = getters.map(|(name, getter)| {
+            let function = FunctionBuilder::native(context, getter)
+                .name(format!("get {name}"))
+                .constructor(false)
+                .build();
+            (name, function)
+        });
+
+        let mut constructor = ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().locale().clone(),
+        );
+        constructor
+            .name(Self::NAME)
+            .length(Self::LENGTH)
+            .method(Self::maximize, "maximize", 0)
+            .method(Self::minimize, "minimize", 0)
+            .method(Self::to_string, "toString", 0)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                "Intl.Locale",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            );
+        for (name, getter) in getters {
+            constructor.accessor(name, Some(getter), None, Attribute::CONFIGURABLE);
+        }
+        constructor.build().conv::<JsValue>().pipe(Some)
+    }
+}
+
+impl Locale {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Returns the string representation of the locale, `[[Locale]]`.
+    pub(crate) fn tag(&self) -> String {
+        self.locale.to_string()
+    }
+
+    /// Gets the value of a Unicode extension keyword of the locale, or `undefined`.
+    fn keyword(&self, key: Key) -> JsValue {
+        self.locale
+            .extensions
+            .unicode
+            .keywords
+            .get(&key)
+            .map_or_else(JsValue::undefined, |value| {
+                // `true` is the implicit value of a keyword without a value.
+                let value = value.to_string();
+                if value.is_empty() {
+                    "true".into()
+                } else {
+                    value.into()
+                }
+            })
+    }
+
+    /// `[[Numeric]]`, which is `true` if the `kn` keyword is present with a `true` value.
+    fn numeric(&self) -> bool {
+        self.locale
+            .extensions
+            .unicode
+            .keywords
+            .get(&key!("kn"))
+            .map_or(false, |value| value.to_string().is_empty())
+    }
+
+    /// `Intl.Locale ( tag [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/Locale
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error("Intl.Locale constructor requires 'new'");
+        }
+
+        // 2-6. Let locale be ? OrdinaryCreateFromConstructor(NewTarget, "%Locale.prototype%", internalSlotsList).
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardConstructors::locale, context)?;
+
+        let tag = args.get_or_undefined(0);
+
+        // 7. If Type(tag) is not String or Object, throw a TypeError exception.
+        if !(tag.is_string() || tag.is_object()) {
+            return context.throw_type_error("the locale tag must be a string or an object");
+        }
+
+        // 8. If Type(tag) is Object and tag has an [[InitializedLocale]] internal slot, then
+        //     a. Let tag be tag.[[Locale]].
+        let locale = tag
+            .as_object()
+            .and_then(|obj| obj.borrow().as_locale().map(|locale| locale.locale.clone()));
+        let mut locale = if let Some(locale) = locale {
+            locale
+        } else {
+            // 9. Else,
+            //     a. Let tag be ? ToString(tag).
+            let tag = tag.to_string(context)?;
+
+            // ApplyOptionsToTag, step 1: If IsStructurallyValidLanguageTag(tag) is false, throw a RangeError exception.
+            parse_canonical_locale(&tag, context).ok_or_else(|| {
+                context.construct_range_error(format!("`{tag}` is not a valid language tag"))
+            })?
+        };
+
+        // 10. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(args.get_or_undefined(1), context)?;
+
+        // 11. Set tag to ? ApplyOptionsToTag(tag, options).
+        apply_options_to_tag(&mut locale, &options, context)?;
+
+        // 12. Let opt be a new Record.
+        // 13. Let calendar be ? GetOption(options, "calendar", string, empty, undefined).
+        // 14. If calendar is not undefined, then
+        //     a. If calendar does not match the Unicode Locale Identifier type nonterminal, throw a RangeError exception.
+        // 15. Set opt.[[ca]] to calendar.
+        let calendar = get_type_option(&options, "calendar", context)?;
+
+        // 16. Let collation be ? GetOption(options, "collation", string, empty, undefined).
+        // 17. If collation is not undefined, then
+        //     a. If collation does not match the Unicode Locale Identifier type nonterminal, throw a RangeError exception.
+        // 18. Set opt.[[co]] to collation.
+        let collation = get_type_option(&options, "collation", context)?;
+
+        // 19. Let hc be ? GetOption(options, "hourCycle", string, « "h11", "h12", "h23", "h24" », undefined).
+        // 20. Set opt.[[hc]] to hc.
+        let hour_cycle = get_option(
+            &options,
+            "hourCycle",
+            &["h11", "h12", "h23", "h24"],
+            context,
+        )?
+        .map(type_value);
+
+        // 21. Let kf be ? GetOption(options, "caseFirst", string, « "upper", "lower", "false" », undefined).
+        // 22. Set opt.[[kf]] to kf.
+        let case_first = get_option(&options, "caseFirst", &["upper", "lower", "false"], context)?
+            .map(type_value);
+
+        // 23. Let kn be ? GetOption(options, "numeric", boolean, empty, undefined).
+        // 24. If kn is not undefined, set kn to ! ToString(kn).
+        // 25. Set opt.[[kn]] to kn.
+        let numeric = get_bool_option(&options, "numeric", context)?
+            .map(|numeric| type_value(if numeric { "true" } else { "false" }));
+
+        // 26. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+        // 27. If numberingSystem is not undefined, then
+        //     a. If numberingSystem does not match the Unicode Locale Identifier type nonterminal, throw a RangeError exception.
+        // 28. Set opt.[[nu]] to numberingSystem.
+        let numbering_system = get_type_option(&options, "numberingSystem", context)?;
+
+        // 29. Let r be ! ApplyUnicodeExtensionToTag(tag, opt, relevantExtensionKeys).
+        let keywords = &mut locale.extensions.unicode.keywords;
+        for (key, value) in [
+            (key!("ca"), calendar),
+            (key!("co"), collation),
+            (key!("hc"), hour_cycle),
+            (key!("kf"), case_first),
+            (key!("kn"), numeric),
+            (key!("nu"), numbering_system),
+        ] {
+            if let Some(value) = value {
+                keywords.set(key, value);
+            }
+        }
+
+        // 30-37. Set the internal slots of locale from r.
+        // 38. Return locale.
+        Ok(JsObject::from_proto_and_data(prototype, ObjectData::locale(Self { locale })).into())
+    }
+
+    /// Gets the `this` `Intl.Locale` object.
+    fn this_locale(this: &JsValue, context: &mut Context) -> JsResult<Self> {
+        this.as_object()
+            .and_then(|obj| obj.borrow().as_locale().cloned())
+            .ok_or_else(|| context.construct_type_error("'this' is not an Intl.Locale"))
+    }
+
+    /// Creates a new `Intl.Locale` object with the `%Locale.prototype%` prototype.
+    fn create(locale: icu_locid::Locale, context: &mut Context) -> JsValue {
+        JsObject::from_proto_and_data(
+            context.intrinsics().constructors().locale().prototype(),
+            ObjectData::locale(Self { locale }),
+        )
+        .into()
+    }
+
+    /// `get Intl.Locale.prototype.baseName`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.baseName
+    fn get_base_name(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        // 3. Let locale be loc.[[Locale]].
+        // 4. Return the longest prefix of locale matched by the unicode_language_id production.
+        Ok(Self::this_locale(this, context)?
+            .locale
+            .id
+            .to_string()
+            .into())
+    }
+
+    /// `get Intl.Locale.prototype.calendar`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.calendar
+    fn get_calendar(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        // 3. Return loc.[[Calendar]].
+        Ok(Self::this_locale(this, context)?.keyword(key!("ca")))
+    }
+
+    /// `get Intl.Locale.prototype.caseFirst`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.caseFirst
+    fn get_case_first(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        // 3. Return loc.[[CaseFirst]].
+        Ok(Self::this_locale(this, context)?.keyword(key!("kf")))
+    }
+
+    /// `get Intl.Locale.prototype.collation`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.collation
+    fn get_collation(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        // 3. Return loc.[[Collation]].
+        Ok(Self::this_locale(this, context)?.keyword(key!("co")))
+    }
+
+    /// `get Intl.Locale.prototype.hourCycle`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.hourCycle
+    fn get_hour_cycle(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        // 3. Return loc.[[HourCycle]].
+        Ok(Self::this_locale(this, context)?.keyword(key!("hc")))
+    }
+
+    /// `get Intl.Locale.prototype.numeric`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.numeric
+    fn get_numeric(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        // 3. Return loc.[[Numeric]].
+        Ok(Self::this_locale(this, context)?.numeric().into())
+    }
+
+    /// `get Intl.Locale.prototype.numberingSystem`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.numberingSystem
+    fn get_numbering_system(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        // 3. Return loc.[[NumberingSystem]].
+        Ok(Self::this_locale(this, context)?.keyword(key!("nu")))
+    }
+
+    /// `get Intl.Locale.prototype.language`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.language
+    fn get_language(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        // 3. Let locale be loc.[[Locale]].
+        // 4. Assert: locale matches the unicode_locale_id production.
+        // 5. Return the substring of locale corresponding to the unicode_language_subtag production of the unicode_language_id.
+        Ok(Self::this_locale(this, context)?
+            .locale
+            .id
+            .language
+            .as_str()
+            .into())
+    }
+
+    /// `get Intl.Locale.prototype.script`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.script
+    fn get_script(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        // 3. Let locale be loc.[[Locale]].
+        // 4. Assert: locale matches the unicode_locale_id production.
+        // 5. If the unicode_language_id production of locale does not contain the ["-" unicode_script_subtag] sequence, return undefined.
+        // 6. Return the substring of locale corresponding to the unicode_script_subtag production of the unicode_language_id.
+        Ok(Self::this_locale(this, context)?
+            .locale
+            .id
+            .script
+            .map_or_else(JsValue::undefined, |script| script.as_str().into()))
+    }
+
+    /// `get Intl.Locale.prototype.region`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.region
+    fn get_region(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        // 3. Let locale be loc.[[Locale]].
+        // 4. Assert: locale matches the unicode_locale_id production.
+        // 5. If the unicode_language_id production of locale does not contain the ["-" unicode_region_subtag] sequence, return undefined.
+        // 6. Return the substring of locale corresponding to the unicode_region_subtag production of the unicode_language_id.
+        Ok(Self::this_locale(this, context)?
+            .locale
+            .id
+            .region
+            .map_or_else(JsValue::undefined, |region| region.as_str().into()))
+    }
+
+    /// `Intl.Locale.prototype.maximize ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.maximize
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/maximize
+    fn maximize(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let mut locale = Self::this_locale(this, context)?.locale;
+
+        // 3. Let maximal be the result of the Add Likely Subtags algorithm applied to loc.[[Locale]].
+        //    If an error is signaled, set maximal to loc.[[Locale]].
+        context.icu().locale_expander().maximize(&mut locale);
+
+        // 4. Return ! Construct(%Locale%, maximal).
+        Ok(Self::create(locale, context))
+    }
+
+    /// `Intl.Locale.prototype.minimize ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.minimize
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/minimize
+    fn minimize(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let mut locale = Self::this_locale(this, context)?.locale;
+
+        // 3. Let minimal be the result of the Remove Likely Subtags algorithm applied to loc.[[Locale]].
+        //    If an error is signaled, set minimal to loc.[[Locale]].
+        context.icu().locale_expander().minimize(&mut locale);
+
+        // 4. Return ! Construct(%Locale%, minimal).
+        Ok(Self::create(locale, context))
+    }
+
+    /// `Intl.Locale.prototype.toString ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.Locale.prototype.toString
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/toString
+    fn to_string(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        // 3. Return loc.[[Locale]].
+        Ok(Self::this_locale(this, context)?.tag().into())
+    }
+}
+
+/// Abstract operation `ApplyOptionsToTag ( tag, options )`
+///
+/// `locale` must already be a structurally valid, canonicalized locale.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-apply-options-to-tag
+fn apply_options_to_tag(
+    locale: &mut icu_locid::Locale,
+    options: &JsObject,
+    context: &mut Context,
+) -> JsResult<()> {
+    // 2. Let language be ? GetOption(options, "language", string, empty, undefined).
+    // 3. If language is not undefined, then
+    //     a. If language does not match the unicode_language_subtag production, throw a RangeError exception.
+    let language = get_subtag_option::<Language>(options, "language", context)?;
+
+    // 4. Let script be ? GetOption(options, "script", string, empty, undefined).
+    // 5. If script is not undefined, then
+    //     a. If script does not match the unicode_script_subtag production, throw a RangeError exception.
+    let script = get_subtag_option::<Script>(options, "script", context)?;
+
+    // 6. Let region be ? GetOption(options, "region", string, empty, undefined).
+    // 7. If region is not undefined, then
+    //     a. If region does not match the unicode_region_subtag production, throw a RangeError exception.
+    let region = get_subtag_option::<Region>(options, "region", context)?;
+
+    // 8. Set tag to ! CanonicalizeUnicodeLocaleId(tag).
+    // 9. Assert: tag matches the unicode_locale_id production.
+    // 10. Let languageId be the longest prefix of tag matched by the unicode_language_id production.
+    // 11. If language is not undefined, then
+    //     a. Set languageId to languageId with the substring corresponding to the unicode_language_subtag production replaced by language.
+    if let Some(language) = language {
+        locale.id.language = language;
+    }
+
+    // 12. If script is not undefined, then
+    //     a. If languageId does not contain a unicode_script_subtag production, then
+    //         i. Set languageId to the string-concatenation of the unicode_language_subtag production of languageId, "-", script, and the rest of languageId.
+    //     b. Else,
+    //         i. Set languageId to languageId with the substring corresponding to the unicode_script_subtag production replaced by script.
+    if script.is_some() {
+        locale.id.script = script;
+    }
+
+    // 13. If region is not undefined, then
+    //     a. If languageId does not contain a unicode_region_subtag production, then
+    //         i. Set languageId to the string-concatenation of the unicode_language_subtag production of languageId, the substring corresponding to "-"` and the unicode_script_subtag production if present, "-", region, and the rest of languageId.
+    //     b. Else,
+    //         i. Set languageId to languageId with the substring corresponding to the unicode_region_subtag production replaced by region.
+    if region.is_some() {
+        locale.id.region = region;
+    }
+
+    // 14. Set tag to tag with the substring corresponding to the unicode_language_id production replaced by languageId.
+    // 15. Return ! CanonicalizeUnicodeLocaleId(tag).
+    context.icu().locale_canonicalizer().canonicalize(locale);
+    Ok(())
+}
+
+/// Abstract operation `GetOption ( options, property, "string", empty, undefined )`, for an
+/// option that must be a `unicode_language_subtag`, `unicode_script_subtag` or
+/// `unicode_region_subtag`.
+fn get_subtag_option<T: std::str::FromStr>(
+    options: &JsObject,
+    property: &str,
+    context: &mut Context,
+) -> JsResult<Option<T>> {
+    get_string_option(options, property, context)?
+        .map(|value| {
+            value.parse::<T>().map_err(|_| {
+                context.construct_range_error(format!(
+                    "invalid value `{value}` for option `{property}`"
+                ))
+            })
+        })
+        .transpose()
+}
+
+/// Abstract operation `GetOption ( options, property, "string", empty, undefined )`, for an
+/// option that must match the Unicode Locale Identifier `type` nonterminal.
+fn get_type_option(
+    options: &JsObject,
+    property: &str,
+    context: &mut Context,
+) -> JsResult<Option<Value>> {
+    get_string_option(options, property, context)?
+        .map(|value: JsString| {
+            Value::try_from_bytes(value.as_bytes()).map_err(|_| {
+                context.construct_range_error(format!(
+                    "invalid value `{value}` for option `{property}`"
+                ))
+            })
+        })
+        .transpose()
+}
+
+/// Converts one of the fixed option values to a keyword value.
+fn type_value(value: &str) -> Value {
+    Value::try_from_bytes(value.as_bytes()).expect("option values must be valid keyword values")
+}
@@ -0,0 +1,160 @@
+use crate::{forward, Context};
+
+#[test]
+fn construct() {
+    let mut context = Context::default();
+    let init = r#"
+        var locale = new Intl.Locale('EN-latn-us-u-ca-gregory-kn');
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "locale.toString()"),
+        "\"en-Latn-US-u-ca-gregory-kn\""
+    );
+    assert_eq!(forward(&mut context, "locale.baseName"), "\"en-Latn-US\"");
+    assert_eq!(forward(&mut context, "locale.language"), "\"en\"");
+    assert_eq!(forward(&mut context, "locale.script"), "\"Latn\"");
+    assert_eq!(forward(&mut context, "locale.region"), "\"US\"");
+    assert_eq!(forward(&mut context, "locale.calendar"), "\"gregory\"");
+    assert_eq!(forward(&mut context, "locale.numeric"), "true");
+    assert_eq!(forward(&mut context, "locale.collation"), "undefined");
+    assert_eq!(
+        forward(&mut context, "new Intl.Locale('fr').script"),
+        "undefined"
+    );
+}
+
+#[test]
+fn options() {
+    let mut context = Context::default();
+    let init = r#"
+        var locale = new Intl.Locale('en-US-u-ca-gregory', {
+            region: 'gb',
+            calendar: 'buddhist',
+            collation: 'emoji',
+            hourCycle: 'h23',
+            caseFirst: 'upper',
+            numeric: false,
+            numberingSystem: 'latn',
+        });
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "locale.toString()"),
+        "\"en-GB-u-ca-buddhist-co-emoji-hc-h23-kf-upper-kn-false-nu-latn\""
+    );
+    assert_eq!(forward(&mut context, "locale.calendar"), "\"buddhist\"");
+    assert_eq!(forward(&mut context, "locale.collation"), "\"emoji\"");
+    assert_eq!(forward(&mut context, "locale.hourCycle"), "\"h23\"");
+    assert_eq!(forward(&mut context, "locale.caseFirst"), "\"upper\"");
+    assert_eq!(forward(&mut context, "locale.numeric"), "false");
+    assert_eq!(forward(&mut context, "locale.numberingSystem"), "\"latn\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.Locale(new Intl.Locale('fr-CA'), { language: 'en', script: 'Latn' }).toString()"
+        ),
+        "\"en-Latn-CA\""
+    );
+}
+
+#[test]
+fn maximize_and_minimize() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(&mut context, "new Intl.Locale('en').maximize().toString()"),
+        "\"en-Latn-US\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.Locale('zh-TW-u-ca-roc').maximize().toString()"
+        ),
+        "\"zh-Hant-TW-u-ca-roc\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.Locale('en-Latn-US').minimize().toString()"
+        ),
+        "\"en\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.Locale('zh-Hant-TW').minimize().toString()"
+        ),
+        "\"zh-TW\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.Locale('en').maximize() instanceof Intl.Locale"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn canonicalization() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(&mut context, "new Intl.Locale('iw').language"),
+        "\"he\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Intl.getCanonicalLocales(['EN-us', 'iw', 'en-US']).join()"
+        ),
+        "\"en-US,he\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Intl.getCanonicalLocales(new Intl.Locale('fr-ca')).join()"
+        ),
+        "\"fr-CA\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Intl.getCanonicalLocales('en_US') } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn invalid_construction() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Intl.Locale('en') } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Intl.Locale(5) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    for code in [
+        "new Intl.Locale('en-')",
+        "new Intl.Locale('en', { language: '1234' })",
+        "new Intl.Locale('en', { region: 'USA' })",
+        "new Intl.Locale('en', { calendar: 'x' })",
+        "new Intl.Locale('en', { hourCycle: 'h25' })",
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {code} }} catch (e) {{ e.name }}")
+            ),
+            "\"RangeError\""
+        );
+    }
+}
@@ -7,15 +7,45 @@
 //!
 //! [spec]: https://tc39.es/ecma402/#intl-object
 
+pub mod date_time_format;
+#[cfg(feature = "intl")]
+pub mod display_names;
+pub mod duration_format;
+#[cfg(feature = "intl")]
+pub mod locale;
+pub(crate) mod negotiation;
+#[cfg(feature = "intl")]
+pub(crate) mod number_format;
+#[cfg(feature = "intl")]
+pub mod plural_rules;
+#[cfg(feature = "intl")]
+pub mod segmenter;
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) use date_time_format::DateTimeFormat;
+#[cfg(feature = "intl")]
+pub(crate) use display_names::DisplayNames;
+pub(crate) use duration_format::DurationFormat;
+#[cfg(feature = "intl")]
+pub(crate) use locale::Locale;
+#[cfg(feature = "intl")]
+pub(crate) use number_format::NumberFormat;
+#[cfg(feature = "intl")]
+pub(crate) use plural_rules::PluralRules;
+#[cfg(feature = "intl")]
+pub(crate) use segmenter::Segmenter;
+
 use crate::{
     builtins::{Array, BuiltIn, JsArgs},
-    object::ObjectInitializer,
+    object::{JsObject, ObjectData, ObjectInitializer},
     property::Attribute,
     symbol::WellKnownSymbols,
     Context, JsResult, JsString, JsValue,
 };
 use boa_profiler::Profiler;
-use indexmap::IndexSet;
+use negotiation::canonicalize_locale_list;
 use tap::{Conv, Pipe};
 
 /// JavaScript `Intl` object.
@@ -28,90 +58,52 @@ impl BuiltIn for Intl {
     fn init(context: &mut Context) -> Option<JsValue> {
         let _timer = Profiler::global().start_event(Self::NAME, "init");
 
-        let string_tag = WellKnownSymbols::to_string_tag();
-        ObjectInitializer::new(context)
-            .function(Self::get_canonical_locales, "getCanonicalLocales", 1)
-            .property(
-                string_tag,
-                Self::NAME,
-                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-            )
-            .build()
-            .conv::<JsValue>()
-            .pipe(Some)
-    }
-}
+        let date_time_format = DateTimeFormat::init(context)
+            .expect("initializing `Intl.DateTimeFormat` must return a constructor");
+        let duration_format = DurationFormat::init(context)
+            .expect("initializing `Intl.DurationFormat` must return a constructor");
 
-impl Intl {
-    fn canonicalize_locale(locale: &str) -> JsString {
-        JsString::new(locale)
-    }
+        #[cfg(feature = "intl")]
+        let display_names = DisplayNames::init(context)
+            .expect("initializing `Intl.DisplayNames` must return a constructor");
 
-    fn canonicalize_locale_list(
-        args: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<Vec<JsString>> {
-        // https://tc39.es/ecma402/#sec-canonicalizelocalelist
-        // 1. If locales is undefined, then
-        let locales = args.get_or_undefined(0);
-        if locales.is_undefined() {
-            // a. Return a new empty List.
-            return Ok(Vec::new());
-        }
+        #[cfg(feature = "intl")]
+        let locale =
+            Locale::init(context).expect("initializing `Intl.Locale` must return a constructor");
 
-        let locales = &args[0];
-
-        // 2. Let seen be a new empty List.
-        let mut seen = IndexSet::new();
-
-        // 3. If Type(locales) is String or Type(locales) is Object and locales has an [[InitializedLocale]] internal slot, then
-        // TODO: check if Type(locales) is object and handle the internal slots
-        let o = if locales.is_string() {
-            // a. Let O be CreateArrayFromList(« locales »).
-            Array::create_array_from_list([locales.clone()], context)
-        } else {
-            // 4. Else,
-            // a. Let O be ? ToObject(locales).
-            locales.to_object(context)?
-        };
-
-        // 5. Let len be ? ToLength(? Get(O, "length")).
-        let len = o.length_of_array_like(context)?;
-
-        // 6 Let k be 0.
-        // 7. Repeat, while k < len,
-        for k in 0..len {
-            // a. Let Pk be ToString(k).
-            // b. Let kPresent be ? HasProperty(O, Pk).
-            let k_present = o.has_property(k, context)?;
-            // c. If kPresent is true, then
-            if k_present {
-                // i. Let kValue be ? Get(O, Pk).
-                let k_value = o.get(k, context)?;
-                // ii. If Type(kValue) is not String or Object, throw a TypeError exception.
-                if !(k_value.is_object() || k_value.is_string()) {
-                    return context.throw_type_error("locale should be a String or Object");
-                }
-                // iii. If Type(kValue) is Object and kValue has an [[InitializedLocale]] internal slot, then
-                // TODO: handle checks for InitializedLocale internal slot (there should be an if statement here)
-                // 1. Let tag be kValue.[[Locale]].
-                // iv. Else,
-                // 1. Let tag be ? ToString(kValue).
-                let tag = k_value.to_string(context)?;
-                // v. If IsStructurallyValidLanguageTag(tag) is false, throw a RangeError exception.
-                // TODO: implement `IsStructurallyValidLanguageTag`
-
-                // vi. Let canonicalizedTag be CanonicalizeUnicodeLocaleId(tag).
-                seen.insert(Self::canonicalize_locale(&tag));
-                // vii. If canonicalizedTag is not an element of seen, append canonicalizedTag as the last element of seen.
-            }
-            // d. Increase k by 1.
-        }
+        #[cfg(feature = "intl")]
+        let plural_rules = PluralRules::init(context)
+            .expect("initializing `Intl.PluralRules` must return a constructor");
+
+        #[cfg(feature = "intl")]
+        let segmenter = Segmenter::init(context)
+            .expect("initializing `Intl.Segmenter` must return a constructor");
 
-        // 8. Return seen.
-        Ok(seen.into_iter().collect::<Vec<JsString>>())
+        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        let string_tag = WellKnownSymbols::to_string_tag();
+        let mut intl = ObjectInitializer::new(context);
+        intl.function(Self::get_canonical_locales, "getCanonicalLocales", 1)
+            .property(DateTimeFormat::NAME, date_time_format, attribute)
+            .property(DurationFormat::NAME, duration_format, attribute);
+
+        #[cfg(feature = "intl")]
+        intl.property(DisplayNames::NAME, display_names, attribute)
+            .property(Locale::NAME, locale, attribute)
+            .property(PluralRules::NAME, plural_rules, attribute)
+            .property(Segmenter::NAME, segmenter, attribute);
+
+        intl.property(
+            string_tag,
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
     }
+}
 
+impl Intl {
     /// Returns an array containing the canonical locale names.
     ///
     /// More information:
@@ -126,7 +118,7 @@ impl Intl {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let ll be ? CanonicalizeLocaleList(locales).
-        let ll = Self::canonicalize_locale_list(args, context)?;
+        let ll = canonicalize_locale_list(args.get_or_undefined(0), context)?;
         // 2. Return CreateArrayFromList(ll).
         Ok(JsValue::Object(Array::create_array_from_list(
             ll.into_iter().map(Into::into),
@@ -134,3 +126,170 @@ impl Intl {
         )))
     }
 }
+
+/// Abstract operation `CoerceOptionsToObject ( options )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-coerceoptionstoobject
+pub(crate) fn coerce_options_to_object(
+    options: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    // 1. If options is undefined, then
+    if options.is_undefined() {
+        // a. Return OrdinaryObjectCreate(null).
+        return Ok(JsObject::from_proto_and_data(None, ObjectData::ordinary()));
+    }
+
+    // 2. Return ? ToObject(options).
+    options.to_object(context)
+}
+
+/// Abstract operation `GetOptionsObject ( options )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-getoptionsobject
+pub(crate) fn get_options_object(options: &JsValue, context: &mut Context) -> JsResult<JsObject> {
+    match options {
+        // 1. If options is undefined, then
+        //     a. Return OrdinaryObjectCreate(null).
+        JsValue::Undefined => Ok(JsObject::from_proto_and_data(None, ObjectData::ordinary())),
+        // 2. If Type(options) is Object, then
+        //     a. Return options.
+        JsValue::Object(options) => Ok(options.clone()),
+        // 3. Throw a TypeError exception.
+        _ => context.throw_type_error("options must be an object or undefined"),
+    }
+}
+
+/// Abstract operation `GetOption ( options, property, "string", values, undefined )`
+///
+/// Returns the matching entry of `values`, or `None` if the option is not present.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-getoption
+pub(crate) fn get_option(
+    options: &JsObject,
+    property: &str,
+    values: &[&'static str],
+    context: &mut Context,
+) -> JsResult<Option<&'static str>> {
+    // 1. Let value be ? Get(options, property).
+    let value = options.get(property, context)?;
+
+    // 2. If value is undefined, then
+    if value.is_undefined() {
+        // a. Return default.
+        return Ok(None);
+    }
+
+    // 3-5. Set value to ? ToString(value).
+    let value = value.to_string(context)?;
+
+    // 6. If values is not empty and values does not contain value, throw a RangeError exception.
+    // 7. Return value.
+    match values.iter().find(|v| **v == value.as_str()) {
+        Some(value) => Ok(Some(value)),
+        None => {
+            context.throw_range_error(format!("invalid value `{value}` for option `{property}`"))
+        }
+    }
+}
+
+/// Abstract operation `GetOption ( options, property, "boolean", empty, undefined )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-getoption
+pub(crate) fn get_bool_option(
+    options: &JsObject,
+    property: &str,
+    context: &mut Context,
+) -> JsResult<Option<bool>> {
+    let value = options.get(property, context)?;
+    if value.is_undefined() {
+        return Ok(None);
+    }
+    Ok(Some(value.to_boolean()))
+}
+
+/// Abstract operation `GetOption ( options, property, "string", empty, undefined )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-getoption
+pub(crate) fn get_string_option(
+    options: &JsObject,
+    property: &str,
+    context: &mut Context,
+) -> JsResult<Option<JsString>> {
+    let value = options.get(property, context)?;
+    if value.is_undefined() {
+        return Ok(None);
+    }
+    value.to_string(context).map(Some)
+}
+
+/// Abstract operation `GetNumberOption ( options, property, minimum, maximum, undefined )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-getnumberoption
+pub(crate) fn get_number_option(
+    options: &JsObject,
+    property: &str,
+    minimum: u8,
+    maximum: u8,
+    context: &mut Context,
+) -> JsResult<Option<u8>> {
+    // 1. Let value be ? Get(options, property).
+    let value = options.get(property, context)?;
+
+    // 2. Return ? DefaultNumberOption(value, minimum, maximum, fallback).
+    default_number_option(&value, minimum, maximum, context)
+}
+
+/// Abstract operation `DefaultNumberOption ( value, minimum, maximum, undefined )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-defaultnumberoption
+pub(crate) fn default_number_option(
+    value: &JsValue,
+    minimum: u8,
+    maximum: u8,
+    context: &mut Context,
+) -> JsResult<Option<u8>> {
+    // 1. If value is undefined, return fallback.
+    if value.is_undefined() {
+        return Ok(None);
+    }
+
+    // 2. Set value to ? ToNumber(value).
+    let value = value.to_number(context)?;
+
+    // 3. If value is NaN or less than minimum or greater than maximum, throw a RangeError exception.
+    if value.is_nan() || value < f64::from(minimum) || value > f64::from(maximum) {
+        return context.throw_range_error("number option is out of range");
+    }
+
+    // 4. Return floor(value).
+    Ok(Some(value.floor() as u8))
+}
+
+/// Returns `true` if `value` matches the `type` nonterminal of Unicode locale identifiers.
+pub(crate) fn is_unicode_type(value: &str) -> bool {
+    value.split('-').all(|subtag| {
+        (3..=8).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+    })
+}
@@ -0,0 +1,424 @@
+//! Locale negotiation shared by the `Intl` services.
+//!
+//! Every `Intl` constructor canonicalizes the requested locales with
+//! [`canonicalize_locale_list`], then negotiates its locale with [`resolve_locale`] against the
+//! locales it has data for. The default locale and the locales supported by the embedder are
+//! configured per [`Context`], with [`Context::set_default_locale`] and
+//! [`Context::set_available_locales`].
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma402/#sec-locale-and-parameter-negotiation
+
+#[cfg(feature = "intl")]
+use super::Locale;
+use super::{coerce_options_to_object, get_option};
+use crate::{builtins::Array, object::JsObject, Context, JsResult, JsString, JsValue};
+use indexmap::IndexSet;
+
+/// The default locale of a [`Context`], and the locale every `Intl` service has data for.
+const DEFAULT_LOCALE: &str = "en-US";
+
+/// The locales for which the English-only services have locale data.
+const ENGLISH_LOCALES: [&str; 2] = ["en", "en-US"];
+
+/// The locale negotiation settings of a [`Context`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LocaleSettings {
+    /// The result of `DefaultLocale ( )`.
+    pub(crate) default_locale: String,
+    /// The locales supported by the embedder, or `None` if every locale with data is supported.
+    pub(crate) available_locales: Option<Vec<String>>,
+}
+
+impl Default for LocaleSettings {
+    fn default() -> Self {
+        Self {
+            default_locale: DEFAULT_LOCALE.to_owned(),
+            available_locales: None,
+        }
+    }
+}
+
+/// The locale matching algorithm requested with the `localeMatcher` option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LocaleMatcher {
+    /// The `LookupMatcher` algorithm of BCP 47.
+    Lookup,
+    /// The implementation defined best fit algorithm.
+    BestFit,
+}
+
+impl LocaleMatcher {
+    /// Reads the `localeMatcher` option, defaulting to `"best fit"`.
+    ///
+    /// `GetOption ( options, "localeMatcher", string, « "lookup", "best fit" », "best fit" )`
+    pub(crate) fn from_options(options: &JsObject, context: &mut Context) -> JsResult<Self> {
+        let matcher = get_option(options, "localeMatcher", &["lookup", "best fit"], context)?;
+        Ok(if matcher == Some("lookup") {
+            Self::Lookup
+        } else {
+            Self::BestFit
+        })
+    }
+}
+
+/// The `[[AvailableLocales]]` of a service: the locales it has data for, restricted to the
+/// locales supported by the embedder.
+#[derive(Clone, Copy)]
+struct AvailableLocales<'a> {
+    has_data: fn(&str) -> bool,
+    settings: &'a LocaleSettings,
+}
+
+impl AvailableLocales<'_> {
+    fn contains(self, locale: &str) -> bool {
+        (self.has_data)(locale)
+            && self
+                .settings
+                .available_locales
+                .as_ref()
+                .map_or(true, |available| {
+                    available
+                        .iter()
+                        .any(|available| available.eq_ignore_ascii_case(locale))
+                })
+    }
+}
+
+/// Abstract operations `IsStructurallyValidLanguageTag ( locale )` and
+/// `CanonicalizeUnicodeLocaleId ( locale )`
+///
+/// Returns `None` if `locale` is not a structurally valid language tag.
+#[cfg(feature = "intl")]
+pub(crate) fn canonicalize_locale(locale: &str, context: &Context) -> Option<JsString> {
+    parse_canonical_locale(locale, context).map(|locale| locale.to_string().into())
+}
+
+/// Without the `intl` feature there is no locale data to validate and canonicalize the tags, so
+/// they are returned as is.
+#[cfg(not(feature = "intl"))]
+#[allow(clippy::unnecessary_wraps)]
+pub(crate) fn canonicalize_locale(locale: &str, _context: &Context) -> Option<JsString> {
+    Some(JsString::new(locale))
+}
+
+/// Parses a structurally valid language tag and canonicalizes it, returning `None` if `tag` is
+/// not structurally valid.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-canonicalizeunicodelocaleid
+#[cfg(feature = "intl")]
+pub(crate) fn parse_canonical_locale(tag: &str, context: &Context) -> Option<icu_locid::Locale> {
+    // ICU4X also accepts `_` as a separator, which is not allowed in BCP 47 language tags.
+    if tag.contains('_') {
+        return None;
+    }
+    let mut locale = tag.parse::<icu_locid::Locale>().ok()?;
+    context
+        .icu()
+        .locale_canonicalizer()
+        .canonicalize(&mut locale);
+    Some(locale)
+}
+
+/// Abstract operation `CanonicalizeLocaleList ( locales )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-canonicalizelocalelist
+pub(crate) fn canonicalize_locale_list(
+    locales: &JsValue,
+    context: &mut Context,
+) -> JsResult<Vec<JsString>> {
+    // 1. If locales is undefined, then
+    if locales.is_undefined() {
+        // a. Return a new empty List.
+        return Ok(Vec::new());
+    }
+
+    // 2. Let seen be a new empty List.
+    let mut seen = IndexSet::new();
+
+    // 3. If Type(locales) is String or Type(locales) is Object and locales has an [[InitializedLocale]] internal slot, then
+    #[cfg(feature = "intl")]
+    let is_locale = locales
+        .as_object()
+        .map_or(false, |obj| obj.borrow().as_locale().is_some());
+    #[cfg(not(feature = "intl"))]
+    let is_locale = false;
+    let o = if locales.is_string() || is_locale {
+        // a. Let O be CreateArrayFromList(« locales »).
+        Array::create_array_from_list([locales.clone()], context)
+    } else {
+        // 4. Else,
+        // a. Let O be ? ToObject(locales).
+        locales.to_object(context)?
+    };
+
+    // 5. Let len be ? ToLength(? Get(O, "length")).
+    let len = o.length_of_array_like(context)?;
+
+    // 6 Let k be 0.
+    // 7. Repeat, while k < len,
+    for k in 0..len {
+        // a. Let Pk be ToString(k).
+        // b. Let kPresent be ? HasProperty(O, Pk).
+        let k_present = o.has_property(k, context)?;
+        // c. If kPresent is true, then
+        if k_present {
+            // i. Let kValue be ? Get(O, Pk).
+            let k_value = o.get(k, context)?;
+            // ii. If Type(kValue) is not String or Object, throw a TypeError exception.
+            if !(k_value.is_object() || k_value.is_string()) {
+                return context.throw_type_error("locale should be a String or Object");
+            }
+            // iii. If Type(kValue) is Object and kValue has an [[InitializedLocale]] internal slot, then
+            #[cfg(feature = "intl")]
+            if let Some(tag) = k_value
+                .as_object()
+                .and_then(|obj| obj.borrow().as_locale().map(Locale::tag))
+            {
+                // 1. Let tag be kValue.[[Locale]].
+                seen.insert(tag.into());
+                continue;
+            }
+
+            // iv. Else,
+            // 1. Let tag be ? ToString(kValue).
+            let tag = k_value.to_string(context)?;
+
+            // v. If IsStructurallyValidLanguageTag(tag) is false, throw a RangeError exception.
+            // vi. Let canonicalizedTag be CanonicalizeUnicodeLocaleId(tag).
+            let canonicalized_tag = canonicalize_locale(&tag, context).ok_or_else(|| {
+                context.construct_range_error(format!("`{tag}` is not a valid language tag"))
+            })?;
+
+            // vii. If canonicalizedTag is not an element of seen, append canonicalizedTag as the last element of seen.
+            seen.insert(canonicalized_tag);
+        }
+        // d. Increase k by 1.
+    }
+
+    // 8. Return seen.
+    Ok(seen.into_iter().collect::<Vec<JsString>>())
+}
+
+/// Returns `true` if the English-only locale data has data for `locale`.
+pub(crate) fn has_english_data(locale: &str) -> bool {
+    ENGLISH_LOCALES
+        .iter()
+        .any(|available| available.eq_ignore_ascii_case(locale))
+}
+
+/// Returns `true` if `locale` is a well-formed Unicode locale identifier.
+///
+/// ICU4X falls back to the closest locale with data, so every well-formed locale is available
+/// to the ICU4X backed services.
+#[cfg(feature = "intl")]
+pub(crate) fn is_well_formed_locale(locale: &str) -> bool {
+    locale.parse::<icu_locid::Locale>().is_ok()
+}
+
+/// Abstract operation `BestAvailableLocale ( availableLocales, locale )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-bestavailablelocale
+fn best_available_locale<'a>(available: AvailableLocales<'_>, locale: &'a str) -> Option<&'a str> {
+    // 1. Let candidate be locale.
+    let mut candidate = locale;
+
+    // 2. Repeat,
+    loop {
+        // a. If availableLocales contains candidate, return candidate.
+        if available.contains(candidate) {
+            return Some(candidate);
+        }
+
+        // b. Let pos be the character index of the last occurrence of "-" (U+002D) within candidate.
+        //    If that character does not occur, return undefined.
+        let mut pos = candidate.rfind('-')?;
+
+        // c. If pos ≥ 2 and the character "-" occurs at index pos - 2 of candidate, decrease pos by 2.
+        if pos >= 2 && candidate.as_bytes()[pos - 2] == b'-' {
+            pos -= 2;
+        }
+
+        // d. Let candidate be the substring of candidate from 0 to pos.
+        candidate = &candidate[..pos];
+    }
+}
+
+/// Removes the Unicode extension sequences of a language tag.
+fn without_extensions(locale: &str) -> &str {
+    locale.split("-u-").next().unwrap_or(locale)
+}
+
+/// Abstract operation `LookupMatcher ( availableLocales, requestedLocales )`
+///
+/// Returns the best available locale for the requested locales, ignoring their Unicode
+/// extension sequences, or `None` if none of them is available.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-lookupmatcher
+fn lookup_matcher(
+    available: AvailableLocales<'_>,
+    requested_locales: &[JsString],
+) -> Option<String> {
+    requested_locales.iter().find_map(|locale| {
+        best_available_locale(available, without_extensions(locale)).map(ToOwned::to_owned)
+    })
+}
+
+/// Abstract operation `BestFitMatcher ( availableLocales, requestedLocales )`
+///
+/// Like [`lookup_matcher`], but the requested locales are first matched with their likely
+/// script added, so `zh-TW` prefers an available `zh-Hant` over `zh`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-bestfitmatcher
+#[cfg(feature = "intl")]
+fn best_fit_matcher(
+    available: AvailableLocales<'_>,
+    requested_locales: &[JsString],
+    context: &Context,
+) -> Option<String> {
+    use icu_locid::LanguageIdentifier;
+
+    let expander = context.icu().locale_expander();
+    requested_locales.iter().find_map(|locale| {
+        let locale = without_extensions(locale);
+        if available.contains(locale) {
+            return Some(locale.to_owned());
+        }
+
+        let mut maximized = locale.parse::<LanguageIdentifier>().ok()?;
+        expander.maximize(&mut maximized);
+        let maximized = maximized.to_string();
+        best_available_locale(available, &maximized)
+            .map(ToOwned::to_owned)
+            .or_else(|| best_available_locale(available, locale).map(ToOwned::to_owned))
+    })
+}
+
+/// Without the `intl` feature there is no likely subtags data, so the best fit matcher is the
+/// lookup matcher.
+#[cfg(not(feature = "intl"))]
+fn best_fit_matcher(
+    available: AvailableLocales<'_>,
+    requested_locales: &[JsString],
+    _context: &Context,
+) -> Option<String> {
+    lookup_matcher(available, requested_locales)
+}
+
+/// Abstract operation `ResolveLocale ( availableLocales, requestedLocales, options, relevantExtensionKeys, localeData )`
+///
+/// Negotiates the locale of a service that has data for the locales accepted by `has_data`.
+/// If none of the requested locales is available, the default locale of the context is used,
+/// falling back to [`DEFAULT_LOCALE`] if the service has no data for it.
+///
+/// The Unicode extension sequences of the requested locales are not part of the resolved locale,
+/// since no service reads relevant extension keys from them.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-resolvelocale
+pub(crate) fn resolve_locale(
+    requested_locales: &[JsString],
+    matcher: LocaleMatcher,
+    has_data: fn(&str) -> bool,
+    context: &Context,
+) -> String {
+    let settings = context.locale_settings();
+    let available = AvailableLocales { has_data, settings };
+
+    // 1. Let matcher be options.[[localeMatcher]].
+    // 2. If matcher is "lookup", then
+    //     a. Let r be LookupMatcher(availableLocales, requestedLocales).
+    // 3. Else,
+    //     a. Let r be BestFitMatcher(availableLocales, requestedLocales).
+    let locale = match matcher {
+        LocaleMatcher::Lookup => lookup_matcher(available, requested_locales),
+        LocaleMatcher::BestFit => best_fit_matcher(available, requested_locales, context),
+    };
+
+    // LookupMatcher, step 3: Return DefaultLocale().
+    locale.unwrap_or_else(|| {
+        let data_only = AvailableLocales {
+            has_data,
+            settings: &LocaleSettings::default(),
+        };
+        best_available_locale(data_only, &settings.default_locale)
+            .unwrap_or(DEFAULT_LOCALE)
+            .to_owned()
+    })
+}
+
+/// Resolves the locale of an ICU4X backed service.
+#[cfg(feature = "intl")]
+pub(crate) fn resolve_icu_locale(
+    requested_locales: &[JsString],
+    matcher: LocaleMatcher,
+    context: &Context,
+) -> icu_locid::Locale {
+    resolve_locale(requested_locales, matcher, is_well_formed_locale, context)
+        .parse()
+        .expect("the resolved locale must be well-formed")
+}
+
+/// Abstract operation `SupportedLocales ( availableLocales, requestedLocales, options )`
+///
+/// `has_data` decides whether the service has locale data for a requested locale, without its
+/// Unicode extensions.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-supportedlocales
+pub(crate) fn supported_locales(
+    requested_locales: Vec<JsString>,
+    options: &JsValue,
+    has_data: fn(&str) -> bool,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. Set options to ? CoerceOptionsToObject(options).
+    let options = coerce_options_to_object(options, context)?;
+
+    // 2. Let matcher be ? GetOption(options, "localeMatcher", "string", « "lookup", "best fit" », "best fit").
+    let matcher = LocaleMatcher::from_options(&options, context)?;
+
+    // 3. If matcher is "best fit", then
+    //     a. Let supportedLocales be BestFitSupportedLocales(availableLocales, requestedLocales).
+    // 4. Else,
+    //     a. Let supportedLocales be LookupSupportedLocales(availableLocales, requestedLocales).
+    let available = AvailableLocales {
+        has_data,
+        settings: context.locale_settings(),
+    };
+    let supported = requested_locales
+        .into_iter()
+        .filter(|locale| match matcher {
+            LocaleMatcher::Lookup => {
+                best_available_locale(available, without_extensions(locale)).is_some()
+            }
+            LocaleMatcher::BestFit => {
+                best_fit_matcher(available, std::slice::from_ref(locale), context).is_some()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // 5. Return CreateArrayFromList(supportedLocales).
+    Ok(Array::create_array_from_list(supported.into_iter().map(Into::into), context).into())
+}
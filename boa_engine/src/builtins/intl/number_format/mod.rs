@@ -0,0 +1,552 @@
+//! This module implements the number formatting of the `Intl` object.
+//!
+//! The `Intl.NumberFormat` constructor is not exposed yet, but its formatting is used by the
+//! `toLocaleString` methods of `Number`, `BigInt` and `Array`. The digits, separators and signs
+//! come from the decimal symbols of the resolved locale in ICU4X.
+//!
+//! ICU4X has no data for the patterns of the `percent`, `currency` and `unit` styles, so they use
+//! fixed patterns: the percent sign follows the number, currencies are displayed with their ISO
+//! 4217 code before the number, and units with their identifier after it.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma402/#numberformat-objects
+
+#[cfg(test)]
+mod tests;
+
+use super::{
+    coerce_options_to_object, get_bool_option, get_number_option, get_option, get_string_option,
+    is_unicode_type,
+    negotiation::{canonicalize_locale_list, resolve_icu_locale, LocaleMatcher},
+};
+use crate::{
+    builtins::number::f64_to_fixed, object::JsObject, Context, JsBigInt, JsResult, JsValue,
+};
+use fixed_decimal::{FixedDecimal, Sign};
+use icu_decimal::{
+    options::{FixedDecimalFormatterOptions, GroupingStrategy},
+    FixedDecimalFormatter,
+};
+use icu_provider::DataLocale;
+
+/// The units that can be used with the `unit` style, from the table of sanctioned single unit
+/// identifiers.
+const SANCTIONED_UNITS: [&str; 45] = [
+    "acre",
+    "bit",
+    "byte",
+    "celsius",
+    "centimeter",
+    "day",
+    "degree",
+    "fahrenheit",
+    "fluid-ounce",
+    "foot",
+    "gallon",
+    "gigabit",
+    "gigabyte",
+    "gram",
+    "hectare",
+    "hour",
+    "inch",
+    "kilobit",
+    "kilobyte",
+    "kilogram",
+    "kilometer",
+    "liter",
+    "megabit",
+    "megabyte",
+    "meter",
+    "microsecond",
+    "mile",
+    "mile-scandinavian",
+    "milliliter",
+    "millimeter",
+    "millisecond",
+    "minute",
+    "month",
+    "nanosecond",
+    "ounce",
+    "percent",
+    "petabyte",
+    "pound",
+    "second",
+    "stone",
+    "terabit",
+    "terabyte",
+    "week",
+    "yard",
+    "year",
+];
+
+/// The `[[Style]]` of a number format, with its `[[Currency]]` or `[[Unit]]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Style {
+    Decimal,
+    Percent,
+    Currency(String),
+    Unit(String),
+}
+
+/// The `[[SignDisplay]]` of a number format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SignDisplay {
+    Auto,
+    Never,
+    Always,
+    ExceptZero,
+    Negative,
+}
+
+/// The internal slots of a number format.
+#[derive(Debug)]
+pub(crate) struct NumberFormat {
+    /// `[[Style]]`
+    style: Style,
+    /// `[[MinimumIntegerDigits]]`
+    minimum_integer_digits: usize,
+    /// `[[MinimumFractionDigits]]`
+    minimum_fraction_digits: usize,
+    /// `[[MaximumFractionDigits]]`
+    maximum_fraction_digits: usize,
+    /// `[[SignDisplay]]`
+    sign_display: SignDisplay,
+    /// The formatter of the decimal symbols of `[[Locale]]`, grouping the digits as requested
+    /// by `[[UseGrouping]]`.
+    formatter: FixedDecimalFormatter,
+}
+
+impl NumberFormat {
+    /// Abstract operation `InitializeNumberFormat ( numberFormat, locales, options )`
+    ///
+    /// The significant digits and the notation options are not supported yet, and are ignored.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-initializenumberformat
+    pub(crate) fn new(
+        locales: &JsValue,
+        options: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 1. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 2. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(options, context)?;
+
+        // 4. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        let matcher = LocaleMatcher::from_options(&options, context)?;
+
+        // 6. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+        // 7. If numberingSystem is not undefined, then
+        //     a. If numberingSystem cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        // Only the default numbering system of the locale is supported, so other values are ignored.
+        let numbering_system = options.get("numberingSystem", context)?;
+        if !numbering_system.is_undefined() {
+            let numbering_system = numbering_system.to_string(context)?;
+            if !is_unicode_type(&numbering_system) {
+                return context.throw_range_error(format!(
+                    "invalid value `{numbering_system}` for option `numberingSystem`"
+                ));
+            }
+        }
+
+        // 10. Let r be ResolveLocale(%NumberFormat%.[[AvailableLocales]], requestedLocales, opt, ...).
+        // 11. Set numberFormat.[[Locale]] to r.[[locale]].
+        let locale = resolve_icu_locale(&requested_locales, matcher, context);
+
+        // 14. Perform ? SetNumberFormatUnitOptions(numberFormat, options).
+        let style = set_unit_options(&options, context)?;
+
+        // 15. Let style be numberFormat.[[Style]].
+        // 16. If style is "currency", then
+        //     a. Let currency be numberFormat.[[Currency]].
+        //     b. Let cDigits be CurrencyDigits(currency).
+        //     c. Let mnfdDefault be cDigits.
+        //     d. Let mxfdDefault be cDigits.
+        // 17. Else,
+        //     a. Let mnfdDefault be 0.
+        //     b. If style is "percent", then
+        //         i. Let mxfdDefault be 0.
+        //     c. Else,
+        //         i. Let mxfdDefault be 3.
+        let (default_minimum_fraction_digits, default_maximum_fraction_digits) = match &style {
+            Style::Currency(currency) => {
+                let digits = currency_digits(currency);
+                (digits, digits)
+            }
+            Style::Percent => (0, 0),
+            Style::Decimal | Style::Unit(_) => (0, 3),
+        };
+
+        // 20. Perform ? SetNumberFormatDigitOptions(numberFormat, options, mnfdDefault, mxfdDefault, notation).
+        let (minimum_integer_digits, minimum_fraction_digits, maximum_fraction_digits) =
+            set_digit_options(
+                &options,
+                default_minimum_fraction_digits,
+                default_maximum_fraction_digits,
+                context,
+            )?;
+
+        // 25. Let useGrouping be ? GetOption(options, "useGrouping", boolean, empty, true).
+        // 26. Set numberFormat.[[UseGrouping]] to useGrouping.
+        let use_grouping = get_bool_option(&options, "useGrouping", context)?.unwrap_or(true);
+
+        // 27. Let signDisplay be ? GetOption(options, "signDisplay", string, « "auto", "never", "always", "exceptZero", "negative" », "auto").
+        // 28. Set numberFormat.[[SignDisplay]] to signDisplay.
+        let sign_display = match get_option(
+            &options,
+            "signDisplay",
+            &["auto", "never", "always", "exceptZero", "negative"],
+            context,
+        )? {
+            None | Some("auto") => SignDisplay::Auto,
+            Some("never") => SignDisplay::Never,
+            Some("always") => SignDisplay::Always,
+            Some("exceptZero") => SignDisplay::ExceptZero,
+            Some(_) => SignDisplay::Negative,
+        };
+
+        let mut formatter_options = FixedDecimalFormatterOptions::default();
+        if !use_grouping {
+            formatter_options.grouping_strategy = GroupingStrategy::Never;
+        }
+        let data_locale = DataLocale::from(&locale);
+        let formatter = match context.icu().provider() {
+            Some(provider) => {
+                FixedDecimalFormatter::try_new_unstable(provider, &data_locale, formatter_options)
+            }
+            None => FixedDecimalFormatter::try_new(&data_locale, formatter_options),
+        };
+        let formatter = match formatter {
+            Ok(formatter) => formatter,
+            Err(_) => {
+                return context.throw_range_error(format!(
+                    "no decimal symbols available for locale `{locale}`"
+                ))
+            }
+        };
+
+        Ok(Self {
+            style,
+            minimum_integer_digits,
+            minimum_fraction_digits,
+            maximum_fraction_digits,
+            sign_display,
+            formatter,
+        })
+    }
+
+    /// Abstract operation `FormatNumeric ( numberFormat, x )` for a Number value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatnumber
+    pub(crate) fn format(&self, x: f64) -> String {
+        let negative = x.is_sign_negative();
+
+        if x.is_nan() {
+            return self.with_affixes(format!("{}NaN", ascii_sign(self.sign(false, true))));
+        }
+        if x.is_infinite() {
+            return self.with_affixes(format!("{}∞", ascii_sign(self.sign(negative, false))));
+        }
+
+        let x = match self.style {
+            Style::Percent => x.abs() * 100.0,
+            _ => x.abs(),
+        };
+
+        // Values of 10^21 and more are integers, and `toFixed` returns them in the exponential notation.
+        let digits = if x < 1e21 {
+            f64_to_fixed(x, self.maximum_fraction_digits)
+        } else {
+            format!("{x:.0}")
+        };
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits.as_str(), ""));
+        self.format_digits(negative, integer, fraction)
+    }
+
+    /// Abstract operation `FormatNumeric ( numberFormat, x )` for a BigInt value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatnumber
+    pub(crate) fn format_bigint(&self, x: &JsBigInt) -> String {
+        let mut integer = x.to_string();
+        let negative = integer.starts_with('-');
+        if negative {
+            integer.remove(0);
+        }
+        if self.style == Style::Percent && !x.is_zero() {
+            integer.push_str("00");
+        }
+        self.format_digits(negative, &integer, "")
+    }
+
+    /// Formats the decimal digits of a rounded value, without leading zeros in `integer`.
+    fn format_digits(&self, negative: bool, integer: &str, fraction: &str) -> String {
+        let mut fraction = fraction.to_owned();
+        while fraction.len() > self.minimum_fraction_digits && fraction.ends_with('0') {
+            fraction.pop();
+        }
+        while fraction.len() < self.minimum_fraction_digits {
+            fraction.push('0');
+        }
+
+        let is_zero = integer.bytes().chain(fraction.bytes()).all(|b| b == b'0');
+        let digits = if fraction.is_empty() {
+            integer.to_owned()
+        } else {
+            format!("{integer}.{fraction}")
+        };
+        let mut decimal = digits
+            .parse::<FixedDecimal>()
+            .expect("the rounded digits must be a valid decimal");
+        decimal.pad_start(self.minimum_integer_digits as i16);
+        decimal.set_sign(self.sign(negative, is_zero));
+
+        self.with_affixes(self.formatter.format_to_string(&decimal))
+    }
+
+    /// Gets the sign displayed for a value, according to `[[SignDisplay]]`.
+    fn sign(&self, negative: bool, is_zero: bool) -> Sign {
+        let hide_zero = matches!(
+            self.sign_display,
+            SignDisplay::ExceptZero | SignDisplay::Negative
+        );
+        if self.sign_display == SignDisplay::Never || hide_zero && is_zero {
+            Sign::None
+        } else if negative {
+            Sign::Negative
+        } else if matches!(
+            self.sign_display,
+            SignDisplay::Always | SignDisplay::ExceptZero
+        ) {
+            Sign::Positive
+        } else {
+            Sign::None
+        }
+    }
+
+    /// Adds the percent sign, the currency code or the unit of the style to a formatted number.
+    fn with_affixes(&self, mut number: String) -> String {
+        match &self.style {
+            Style::Decimal => {}
+            Style::Percent => number.push('%'),
+            Style::Currency(currency) => {
+                // The currency code goes between the sign and the digits, as in `-EUR 1.00`.
+                let sign_len = number
+                    .find(|c: char| !matches!(c, '-' | '+' | '\u{2212}') && !is_bidi_mark(c))
+                    .unwrap_or(0);
+                number.insert_str(sign_len, &format!("{currency}\u{a0}"));
+            }
+            Style::Unit(unit) => {
+                number.push(' ');
+                number.push_str(unit);
+            }
+        }
+        number
+    }
+}
+
+/// Gets the ASCII representation of a sign, for the values that ICU4X doesn't format.
+fn ascii_sign(sign: Sign) -> &'static str {
+    match sign {
+        Sign::None => "",
+        Sign::Negative => "-",
+        Sign::Positive => "+",
+    }
+}
+
+/// Returns `true` if `c` is one of the bidi marks surrounding the signs of right-to-left locales.
+fn is_bidi_mark(c: char) -> bool {
+    matches!(c, '\u{200e}' | '\u{200f}' | '\u{61c}')
+}
+
+/// Abstract operation `SetNumberFormatUnitOptions ( intlObj, options )`
+///
+/// The `currencyDisplay`, `currencySign` and `unitDisplay` options are validated, but ICU4X has
+/// no currency and unit data to display them differently.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-setnumberformatunitoptions
+fn set_unit_options(options: &JsObject, context: &mut Context) -> JsResult<Style> {
+    // 1. Let style be ? GetOption(options, "style", string, « "decimal", "percent", "currency", "unit" », "decimal").
+    let style = get_option(
+        options,
+        "style",
+        &["decimal", "percent", "currency", "unit"],
+        context,
+    )?
+    .unwrap_or("decimal");
+
+    // 3. Let currency be ? GetOption(options, "currency", string, empty, undefined).
+    let currency = get_string_option(options, "currency", context)?;
+    match &currency {
+        // 4. If currency is undefined, then
+        //     a. If style is "currency", throw a TypeError exception.
+        None if style == "currency" => {
+            return context.throw_type_error("the `currency` style requires a `currency` option")
+        }
+        // 5. Else,
+        //     a. If IsWellFormedCurrencyCode(currency) is false, throw a RangeError exception.
+        Some(currency)
+            if currency.len() != 3 || !currency.bytes().all(|b| b.is_ascii_alphabetic()) =>
+        {
+            return context.throw_range_error(format!("invalid currency code `{currency}`"))
+        }
+        _ => {}
+    }
+
+    // 6. Let currencyDisplay be ? GetOption(options, "currencyDisplay", string, « "code", "symbol", "narrowSymbol", "name" », "symbol").
+    get_option(
+        options,
+        "currencyDisplay",
+        &["code", "symbol", "narrowSymbol", "name"],
+        context,
+    )?;
+
+    // 7. Let currencySign be ? GetOption(options, "currencySign", string, « "standard", "accounting" », "standard").
+    get_option(
+        options,
+        "currencySign",
+        &["standard", "accounting"],
+        context,
+    )?;
+
+    // 8. Let unit be ? GetOption(options, "unit", string, empty, undefined).
+    let unit = get_string_option(options, "unit", context)?;
+    match &unit {
+        // 9. If unit is undefined, then
+        //     a. If style is "unit", throw a TypeError exception.
+        None if style == "unit" => {
+            return context.throw_type_error("the `unit` style requires a `unit` option")
+        }
+        // 10. Else,
+        //     a. If IsWellFormedUnitIdentifier(unit) is false, throw a RangeError exception.
+        Some(unit) if !is_well_formed_unit_identifier(unit) => {
+            return context.throw_range_error(format!("invalid unit identifier `{unit}`"))
+        }
+        _ => {}
+    }
+
+    // 11. Let unitDisplay be ? GetOption(options, "unitDisplay", string, « "short", "narrow", "long" », "short").
+    get_option(
+        options,
+        "unitDisplay",
+        &["short", "narrow", "long"],
+        context,
+    )?;
+
+    // 12. If style is "currency", then
+    //     a. Set intlObj.[[Currency]] to the ASCII-uppercase of currency.
+    // 13. If style is "unit", then
+    //     a. Set intlObj.[[Unit]] to unit.
+    Ok(match (style, currency, unit) {
+        ("percent", _, _) => Style::Percent,
+        ("currency", Some(currency), _) => Style::Currency(currency.to_ascii_uppercase()),
+        ("unit", _, Some(unit)) => Style::Unit(unit.as_str().to_owned()),
+        _ => Style::Decimal,
+    })
+}
+
+/// Abstract operation `IsWellFormedUnitIdentifier ( unitIdentifier )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-iswellformedunitidentifier
+fn is_well_formed_unit_identifier(unit: &str) -> bool {
+    let is_sanctioned = |unit: &str| SANCTIONED_UNITS.contains(&unit);
+
+    // 1. If IsSanctionedSingleUnitIdentifier(unitIdentifier) is true, then
+    //     a. Return true.
+    // 2. Let i be StringIndexOf(unitIdentifier, "-per-", 0).
+    // 3. If i is -1 or StringIndexOf(unitIdentifier, "-per-", i + 1) is not -1, then
+    //     a. Return false.
+    // 4-7. Return true if the numerator and the denominator are sanctioned single unit identifiers.
+    match unit.split_once("-per-") {
+        None => is_sanctioned(unit),
+        Some((numerator, denominator)) => is_sanctioned(numerator) && is_sanctioned(denominator),
+    }
+}
+
+/// Abstract operation `CurrencyDigits ( currency )`
+///
+/// Returns the minor unit of the currency in ISO 4217, or 2 for the currencies that are not listed.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-currencydigits
+fn currency_digits(currency: &str) -> u8 {
+    match currency {
+        "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF" | "UGX"
+        | "UYI" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+        "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+        "CLF" | "UYW" => 4,
+        _ => 2,
+    }
+}
+
+/// Abstract operation `SetNumberFormatDigitOptions ( intlObj, options, mnfdDefault, mxfdDefault, notation )`
+///
+/// Returns the minimum integer digits, and the minimum and maximum fraction digits.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-setnfdigitoptions
+fn set_digit_options(
+    options: &JsObject,
+    default_minimum_fraction_digits: u8,
+    default_maximum_fraction_digits: u8,
+    context: &mut Context,
+) -> JsResult<(usize, usize, usize)> {
+    // 1. Let mnid be ? GetNumberOption(options, "minimumIntegerDigits,", 1, 21, 1).
+    let minimum_integer_digits =
+        get_number_option(options, "minimumIntegerDigits", 1, 21, context)?.unwrap_or(1);
+
+    // 2. Let mnfd be ? Get(options, "minimumFractionDigits").
+    // 3. Let mxfd be ? Get(options, "maximumFractionDigits").
+    let minimum_fraction_digits =
+        get_number_option(options, "minimumFractionDigits", 0, 100, context)?;
+    let maximum_fraction_digits =
+        get_number_option(options, "maximumFractionDigits", 0, 100, context)?;
+
+    // 16. If needFd is true, then
+    let (minimum_fraction_digits, maximum_fraction_digits) =
+        match (minimum_fraction_digits, maximum_fraction_digits) {
+            // b. If mnfd is undefined and mxfd is undefined, use the defaults.
+            (None, None) => (
+                default_minimum_fraction_digits,
+                default_maximum_fraction_digits,
+            ),
+            // c. If mnfd is undefined, set mnfd to min(mnfdDefault, mxfd).
+            (None, Some(maximum)) => (default_minimum_fraction_digits.min(maximum), maximum),
+            // d. Else if mxfd is undefined, set mxfd to max(mxfdDefault, mnfd).
+            (Some(minimum), None) => (minimum, default_maximum_fraction_digits.max(minimum)),
+            // e. Else if mnfd is greater than mxfd, throw a RangeError exception.
+            (Some(minimum), Some(maximum)) if minimum > maximum => {
+                return context.throw_range_error(
+                    "minimumFractionDigits must not be greater than maximumFractionDigits",
+                )
+            }
+            (Some(minimum), Some(maximum)) => (minimum, maximum),
+        };
+
+    Ok((
+        minimum_integer_digits.into(),
+        minimum_fraction_digits.into(),
+        maximum_fraction_digits.into(),
+    ))
+}
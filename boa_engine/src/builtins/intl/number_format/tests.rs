@@ -0,0 +1,135 @@
+use crate::{forward, Context};
+
+#[test]
+fn fraction_digits() {
+    let mut context = Context::default();
+    for (code, expected) in [
+        ("(1.0625).toLocaleString()", "1.063"),
+        (
+            "(2.5).toLocaleString('en', { maximumFractionDigits: 0 })",
+            "3",
+        ),
+        (
+            "(1.5).toLocaleString('en', { minimumFractionDigits: 3 })",
+            "1.500",
+        ),
+        (
+            "(1.23456).toLocaleString('en', { minimumFractionDigits: 4 })",
+            "1.2346",
+        ),
+        ("(1e21).toLocaleString()", "1,000,000,000,000,000,000,000"),
+        ("(0.125).toLocaleString('en', { style: 'percent' })", "13%"),
+        (
+            "(1234.5).toLocaleString('en', { useGrouping: false })",
+            "1234.5",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), format!("\"{expected}\""));
+    }
+}
+
+#[test]
+fn sign_display() {
+    let mut context = Context::default();
+    for (code, expected) in [
+        ("(-0).toLocaleString()", "-0"),
+        (
+            "(-0.0001).toLocaleString('en', { signDisplay: 'negative' })",
+            "0",
+        ),
+        (
+            "(0).toLocaleString('en', { signDisplay: 'exceptZero' })",
+            "0",
+        ),
+        (
+            "(1).toLocaleString('en', { signDisplay: 'exceptZero' })",
+            "+1",
+        ),
+        ("(-1).toLocaleString('en', { signDisplay: 'never' })", "1"),
+        ("(-Infinity).toLocaleString()", "-∞"),
+        (
+            "NaN.toLocaleString('en', { signDisplay: 'always' })",
+            "+NaN",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), format!("\"{expected}\""));
+    }
+}
+
+#[test]
+fn invalid_options() {
+    let mut context = Context::default();
+    for code in [
+        "(1).toLocaleString('en', { minimumFractionDigits: 3, maximumFractionDigits: 2 })",
+        "(1).toLocaleString('en', { minimumIntegerDigits: 0 })",
+        "(1).toLocaleString('en', { signDisplay: 'sometimes' })",
+        "(1).toLocaleString('en', { numberingSystem: 'a' })",
+        "(1).toLocaleString('en', { style: 'currency', currency: 'EURO' })",
+        "(1).toLocaleString('en', { currencyDisplay: 'long' })",
+        "(1).toLocaleString('en', { style: 'unit', unit: 'parsec' })",
+        "(1).toLocaleString('en', { style: 'unit', unit: 'meter-per-second-per-second' })",
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {code} }} catch (e) {{ e.name }}")
+            ),
+            "\"RangeError\""
+        );
+    }
+}
+
+#[test]
+fn missing_currency_or_unit() {
+    let mut context = Context::default();
+    for code in [
+        "(1).toLocaleString('en', { style: 'currency' })",
+        "(1).toLocaleString('en', { style: 'unit' })",
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {code} }} catch (e) {{ e.name }}")
+            ),
+            "\"TypeError\""
+        );
+    }
+}
+
+#[test]
+fn locale_symbols() {
+    let mut context = Context::default();
+    for (code, expected) in [
+        ("(1234.5).toLocaleString('de')", "1.234,5"),
+        ("(-1234.5).toLocaleString('fr')", "-1\u{202f}234,5"),
+        ("(1234567).toLocaleString('en-IN')", "12,34,567"),
+        ("(0.5).toLocaleString('de', { style: 'percent' })", "50%"),
+    ] {
+        assert_eq!(forward(&mut context, code), format!("\"{expected}\""));
+    }
+}
+
+#[test]
+fn currency_and_unit_styles() {
+    let mut context = Context::default();
+    for (code, expected) in [
+        (
+            "(1234.5).toLocaleString('en', { style: 'currency', currency: 'eur' })",
+            "EUR\u{a0}1,234.50",
+        ),
+        (
+            "(-5).toLocaleString('en', { style: 'currency', currency: 'JPY' })",
+            "-JPY\u{a0}5",
+        ),
+        (
+            "(1.5).toLocaleString('de', { style: 'currency', currency: 'KWD' })",
+            "KWD\u{a0}1,500",
+        ),
+        (
+            "(16).toLocaleString('en', { style: 'unit', unit: 'kilometer-per-hour' })",
+            "16 kilometer-per-hour",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), format!("\"{expected}\""));
+    }
+}
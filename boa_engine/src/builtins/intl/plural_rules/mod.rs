@@ -0,0 +1,540 @@
+//! This module implements the global `Intl.PluralRules` object.
+//!
+//! `Intl.PluralRules` objects enable plural-sensitive formatting and plural-related language
+//! rules, backed by the CLDR plural data of ICU4X.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#pluralrules-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules
+
+#[cfg(test)]
+mod tests;
+
+use super::{
+    coerce_options_to_object, default_number_option, get_number_option, get_option,
+    negotiation::{
+        canonicalize_locale_list, is_well_formed_locale, resolve_icu_locale, supported_locales,
+        LocaleMatcher,
+    },
+};
+use crate::{
+    builtins::{Array, BuiltIn, JsArgs},
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use icu_plurals::{PluralCategory, PluralOperands, PluralRuleType};
+use icu_provider::DataLocale;
+use tap::{Conv, Pipe};
+
+/// The rounding of the formatted number, as set by `SetNumberFormatDigitOptions`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Rounding {
+    /// `[[RoundingType]]` is `fractionDigits`.
+    FractionDigits { minimum: u8, maximum: u8 },
+    /// `[[RoundingType]]` is `significantDigits`.
+    SignificantDigits { minimum: u8, maximum: u8 },
+}
+
+/// The internal slots of an `Intl.PluralRules` object.
+#[derive(Debug, Trace, Finalize)]
+pub struct PluralRules {
+    /// `[[Locale]]`
+    #[unsafe_ignore_trace]
+    locale: String,
+    /// `[[Type]]`
+    #[unsafe_ignore_trace]
+    rule_type: &'static str,
+    /// `[[MinimumIntegerDigits]]`
+    #[unsafe_ignore_trace]
+    minimum_integer_digits: u8,
+    /// `[[RoundingType]]` and its digit limits.
+    #[unsafe_ignore_trace]
+    rounding: Rounding,
+    /// The CLDR plural rules of the locale and type.
+    #[unsafe_ignore_trace]
+    rules: icu_plurals::PluralRules,
+}
+
+impl BuiltIn for PluralRules {
+    const NAME: &'static str = "PluralRules";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().plural_rules().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .static_method(Self::supported_locales_of, "supportedLocalesOf", 1)
+        .method(Self::select, "select", 1)
+        .method(Self::select_range, "selectRange", 2)
+        .method(Self::resolved_options, "resolvedOptions", 0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            "Intl.PluralRules",
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+impl PluralRules {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `Intl.PluralRules ( [ locales [ , options ] ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.pluralrules
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules/PluralRules
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error("Intl.PluralRules constructor requires 'new'");
+        }
+
+        // 2. Let pluralRules be ? OrdinaryCreateFromConstructor(NewTarget, "%PluralRules.prototype%", « ... »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::plural_rules,
+            context,
+        )?;
+
+        // 3. Return ? InitializePluralRules(pluralRules, locales, options).
+        let plural_rules =
+            Self::initialize(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+        Ok(JsObject::from_proto_and_data(prototype, ObjectData::plural_rules(plural_rules)).into())
+    }
+
+    /// Abstract operation `InitializePluralRules ( pluralRules, locales, options )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-initializepluralrules
+    fn initialize(locales: &JsValue, options: &JsValue, context: &mut Context) -> JsResult<Self> {
+        // 1. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 2. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(options, context)?;
+
+        // 4. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        let matcher = LocaleMatcher::from_options(&options, context)?;
+
+        // 6. Let t be ? GetOption(options, "type", string, « "cardinal", "ordinal" », "cardinal").
+        // 7. Set pluralRules.[[Type]] to t.
+        let rule_type =
+            get_option(&options, "type", &["cardinal", "ordinal"], context)?.unwrap_or("cardinal");
+
+        // 8. Perform ? SetNumberFormatDigitOptions(pluralRules, options, +0𝔽, 3𝔽, "standard").
+        let (minimum_integer_digits, rounding) =
+            set_number_format_digit_options(&options, context)?;
+
+        // 9. Let localeData be %PluralRules%.[[LocaleData]].
+        // 10. Let r be ResolveLocale(%PluralRules%.[[AvailableLocales]], requestedLocales, opt, ...).
+        // 11. Set pluralRules.[[Locale]] to r.[[locale]].
+        let locale = resolve_icu_locale(&requested_locales, matcher, context);
+
+        let icu_rule_type = if rule_type == "ordinal" {
+            PluralRuleType::Ordinal
+        } else {
+            PluralRuleType::Cardinal
+        };
+        let data_locale = DataLocale::from(&locale);
+        let rules = match context.icu().provider() {
+            Some(provider) => {
+                icu_plurals::PluralRules::try_new_unstable(provider, &data_locale, icu_rule_type)
+            }
+            None => icu_plurals::PluralRules::try_new(&data_locale, icu_rule_type),
+        };
+        let rules = match rules {
+            Ok(rules) => rules,
+            Err(_) => {
+                return context
+                    .throw_range_error(format!("no plural rules available for locale `{locale}`"))
+            }
+        };
+
+        // 12. Return pluralRules.
+        Ok(Self {
+            locale: locale.to_string(),
+            rule_type,
+            minimum_integer_digits,
+            rounding,
+            rules,
+        })
+    }
+
+    /// Abstract operation `ResolvePlural ( pluralRules, n )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-resolveplural
+    fn resolve_plural(&self, n: f64) -> PluralCategory {
+        // 1. If n is not a finite Number, then
+        if !n.is_finite() {
+            // a. Return "other".
+            return PluralCategory::Other;
+        }
+
+        // 5. Let res be FormatNumericToString(pluralRules, n).
+        // 6. Let s be res.[[FormattedString]].
+        let s = self.format_numeric_to_string(n);
+
+        // 7-9. Let p be PluralRuleSelect(locale, type, n, operands).
+        s.parse::<PluralOperands>()
+            .map_or(PluralCategory::Other, |operands| {
+                self.rules.category_for(operands)
+            })
+    }
+
+    /// Abstract operation `FormatNumericToString ( intlObject, x )`
+    ///
+    /// The sign is dropped, since it does not affect the plural category.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatnumberstring
+    fn format_numeric_to_string(&self, x: f64) -> String {
+        let x = x.abs();
+        let formatted = match self.rounding {
+            Rounding::FractionDigits { minimum, maximum } => {
+                let formatted = format!("{:.*}", usize::from(maximum), x);
+                trim_fraction(formatted, usize::from(minimum))
+            }
+            Rounding::SignificantDigits { minimum, maximum } => {
+                if x == 0.0 {
+                    trim_fraction(
+                        format!("{:.*}", usize::from(minimum) - 1, x),
+                        usize::from(minimum) - 1,
+                    )
+                } else {
+                    let exponent = x.log10().floor() as i32;
+                    let decimals = i32::from(maximum) - 1 - exponent;
+                    if decimals >= 0 {
+                        let formatted = format!("{:.*}", decimals as usize, x);
+                        let minimum_decimals = (i32::from(minimum) - 1 - exponent).max(0);
+                        trim_fraction(formatted, minimum_decimals as usize)
+                    } else {
+                        let scale = 10f64.powi(-decimals);
+                        format!("{:.0}", (x / scale).round() * scale)
+                    }
+                }
+            }
+        };
+
+        // Pad the integer part with zeros up to the minimum integer digits.
+        let integer_digits = formatted.find('.').unwrap_or(formatted.len());
+        let padding = usize::from(self.minimum_integer_digits).saturating_sub(integer_digits);
+        format!("{}{formatted}", "0".repeat(padding))
+    }
+
+    /// Gets the `this` object, checking that it is an `Intl.PluralRules` object.
+    fn this_plural_rules(this: &JsValue, context: &mut Context) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|obj| obj.borrow().as_plural_rules().is_some())
+            .cloned()
+            .ok_or_else(|| context.construct_type_error("'this' is not an Intl.PluralRules"))
+    }
+
+    /// `Intl.PluralRules.supportedLocalesOf ( locales [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.pluralrules.supportedlocalesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let availableLocales be %PluralRules%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(args.get_or_undefined(0), context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales(
+            requested_locales,
+            args.get_or_undefined(1),
+            is_well_formed_locale,
+            context,
+        )
+    }
+
+    /// `Intl.PluralRules.prototype.select ( value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.pluralrules.prototype.select
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules/select
+    fn select(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let pr be the this value.
+        // 2. Perform ? RequireInternalSlot(pr, [[InitializedPluralRules]]).
+        let pr = Self::this_plural_rules(this, context)?;
+
+        // 3. Let n be ? ToNumber(value).
+        let n = args.get_or_undefined(0).to_number(context)?;
+
+        // 4. Return ! ResolvePlural(pr, n).[[PluralCategory]].
+        let pr = pr.borrow();
+        let pr = pr
+            .as_plural_rules()
+            .expect("checked by `this_plural_rules`");
+        Ok(category_name(pr.resolve_plural(n)).into())
+    }
+
+    /// `Intl.PluralRules.prototype.selectRange ( start, end )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.pluralrules.prototype.selectrange
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules/selectRange
+    fn select_range(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let pr be the this value.
+        // 2. Perform ? RequireInternalSlot(pr, [[InitializedPluralRules]]).
+        let pr = Self::this_plural_rules(this, context)?;
+
+        // 3. If start is undefined or end is undefined, throw a TypeError exception.
+        let start = args.get_or_undefined(0);
+        let end = args.get_or_undefined(1);
+        if start.is_undefined() || end.is_undefined() {
+            return context.throw_type_error("selectRange requires a start and an end value");
+        }
+
+        // 4. Let x be ? ToNumber(start).
+        // 5. Let y be ? ToNumber(end).
+        let x = start.to_number(context)?;
+        let y = end.to_number(context)?;
+
+        // 6. Return ? ResolvePluralRange(pr, x, y).
+        // ResolvePluralRange, 1. If x is NaN or y is NaN, throw a RangeError exception.
+        if x.is_nan() || y.is_nan() {
+            return context.throw_range_error("selectRange values cannot be NaN");
+        }
+
+        let pr = pr.borrow();
+        let pr = pr
+            .as_plural_rules()
+            .expect("checked by `this_plural_rules`");
+
+        // 3. Let xp be ! ResolvePlural(pluralRules, x).
+        // 4. Let yp be ! ResolvePlural(pluralRules, y).
+        // 5. If xp.[[FormattedString]] is yp.[[FormattedString]], then
+        //     a. Return xp.[[PluralCategory]].
+        // 6-8. Return PluralRuleSelectRange(locale, type, xp.[[PluralCategory]], yp.[[PluralCategory]]).
+        // The plural category of a range is the category of its end for the CLDR locales
+        // without specific range data, so the end category is used for all locales.
+        let category = if x.is_finite()
+            && y.is_finite()
+            && pr.format_numeric_to_string(x) == pr.format_numeric_to_string(y)
+        {
+            pr.resolve_plural(x)
+        } else {
+            pr.resolve_plural(y)
+        };
+
+        Ok(category_name(category).into())
+    }
+
+    /// `Intl.PluralRules.prototype.resolvedOptions ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.pluralrules.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/PluralRules/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let pr be the this value.
+        // 2. Perform ? RequireInternalSlot(pr, [[InitializedPluralRules]]).
+        let pr = Self::this_plural_rules(this, context)?;
+        let pr = pr.borrow();
+        let pr = pr
+            .as_plural_rules()
+            .expect("checked by `this_plural_rules`");
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        // 4. For each row of Table 13, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of pr's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut entries: Vec<(&str, JsValue)> = vec![
+            ("locale", pr.locale.clone().into()),
+            ("type", pr.rule_type.into()),
+            (
+                "minimumIntegerDigits",
+                u32::from(pr.minimum_integer_digits).into(),
+            ),
+        ];
+        match pr.rounding {
+            Rounding::FractionDigits { minimum, maximum } => {
+                entries.push(("minimumFractionDigits", u32::from(minimum).into()));
+                entries.push(("maximumFractionDigits", u32::from(maximum).into()));
+            }
+            Rounding::SignificantDigits { minimum, maximum } => {
+                entries.push(("minimumSignificantDigits", u32::from(minimum).into()));
+                entries.push(("maximumSignificantDigits", u32::from(maximum).into()));
+            }
+        }
+
+        // 5. Let pluralCategories be a List of Strings containing all possible results of
+        //    PluralRuleSelect for the selected locale pr.[[Locale]].
+        let plural_categories = pr
+            .rules
+            .categories()
+            .map(|category| category_name(category).into())
+            .collect::<Vec<JsValue>>();
+
+        let options = context.construct_object();
+        for (property, value) in entries {
+            options
+                .create_data_property_or_throw(property, value, context)
+                .expect("CreateDataPropertyOrThrow cannot fail on a new ordinary object");
+        }
+
+        // 6. Perform ! CreateDataProperty(options, "pluralCategories", CreateArrayFromList(pluralCategories)).
+        let plural_categories = Array::create_array_from_list(plural_categories, context);
+        options
+            .create_data_property_or_throw("pluralCategories", plural_categories, context)
+            .expect("CreateDataPropertyOrThrow cannot fail on a new ordinary object");
+
+        // 7. Return options.
+        Ok(options.into())
+    }
+}
+
+/// Abstract operation `SetNumberFormatDigitOptions ( intlObj, options, mnfdDefault, mxfdDefault, notation )`
+///
+/// Returns the minimum integer digits and the rounding of the plural rules, using the defaults
+/// of `Intl.PluralRules` (`mnfdDefault` is 0 and `mxfdDefault` is 3).
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-setnfdigitoptions
+fn set_number_format_digit_options(
+    options: &JsObject,
+    context: &mut Context,
+) -> JsResult<(u8, Rounding)> {
+    // 1. Let mnid be ? GetNumberOption(options, "minimumIntegerDigits,", 1, 21, 1).
+    let minimum_integer_digits =
+        get_number_option(options, "minimumIntegerDigits", 1, 21, context)?.unwrap_or(1);
+
+    // 2. Let mnfd be ? Get(options, "minimumFractionDigits").
+    // 3. Let mxfd be ? Get(options, "maximumFractionDigits").
+    // 4. Let mnsd be ? Get(options, "minimumSignificantDigits").
+    // 5. Let mxsd be ? Get(options, "maximumSignificantDigits").
+    let mnfd = options.get("minimumFractionDigits", context)?;
+    let mxfd = options.get("maximumFractionDigits", context)?;
+    let mnsd = options.get("minimumSignificantDigits", context)?;
+    let mxsd = options.get("maximumSignificantDigits", context)?;
+
+    // 6. Set intlObj.[[MinimumIntegerDigits]] to mnid.
+    // 7-8. If mnsd is not undefined or mxsd is not undefined, then
+    if !mnsd.is_undefined() || !mxsd.is_undefined() {
+        // a. Let mnsd be ? DefaultNumberOption(mnsd, 1, 21, 1).
+        let minimum = default_number_option(&mnsd, 1, 21, context)?.unwrap_or(1);
+
+        // b. Let mxsd be ? DefaultNumberOption(mxsd, mnsd, 21, 21).
+        let maximum = default_number_option(&mxsd, minimum, 21, context)?.unwrap_or(21);
+
+        // c. Set intlObj.[[MinimumSignificantDigits]] to mnsd.
+        // d. Set intlObj.[[MaximumSignificantDigits]] to mxsd.
+        return Ok((
+            minimum_integer_digits,
+            Rounding::SignificantDigits { minimum, maximum },
+        ));
+    }
+
+    // 9. If mnfd is not undefined or mxfd is not undefined, then
+    let (minimum, maximum) = if !mnfd.is_undefined() || !mxfd.is_undefined() {
+        // a. Let mnfd be ? DefaultNumberOption(mnfd, 0, 20, undefined).
+        let minimum = default_number_option(&mnfd, 0, 20, context)?;
+
+        // b. Let mxfd be ? DefaultNumberOption(mxfd, 0, 20, undefined).
+        let maximum = default_number_option(&mxfd, 0, 20, context)?;
+
+        match (minimum, maximum) {
+            // c. If mnfd is undefined, set mnfd to min(mnfdDefault, mxfd).
+            (None, Some(maximum)) => (0, maximum),
+            // d. Else if mxfd is undefined, set mxfd to max(mxfdDefault, mnfd).
+            (Some(minimum), None) => (minimum, minimum.max(3)),
+            // e. Else if mnfd is greater than mxfd, throw a RangeError exception.
+            (Some(minimum), Some(maximum)) if minimum > maximum => {
+                return context.throw_range_error(
+                    "minimumFractionDigits cannot be greater than maximumFractionDigits",
+                );
+            }
+            (minimum, maximum) => (minimum.unwrap_or(0), maximum.unwrap_or(3)),
+        }
+    } else {
+        // 10. Else,
+        //     a. Set intlObj.[[MinimumFractionDigits]] to mnfdDefault.
+        //     b. Set intlObj.[[MaximumFractionDigits]] to mxfdDefault.
+        (0, 3)
+    };
+
+    Ok((
+        minimum_integer_digits,
+        Rounding::FractionDigits { minimum, maximum },
+    ))
+}
+
+/// Removes the trailing zeros of the fraction of `formatted`, keeping at least
+/// `minimum_fraction_digits` fraction digits.
+fn trim_fraction(mut formatted: String, minimum_fraction_digits: usize) -> String {
+    if let Some(dot) = formatted.find('.') {
+        let minimum_len = dot + 1 + minimum_fraction_digits;
+        while formatted.len() > minimum_len && formatted.ends_with('0') {
+            formatted.pop();
+        }
+        if formatted.ends_with('.') {
+            formatted.pop();
+        }
+    }
+    formatted
+}
+
+/// Returns the name of a plural category.
+fn category_name(category: PluralCategory) -> &'static str {
+    match category {
+        PluralCategory::Zero => "zero",
+        PluralCategory::One => "one",
+        PluralCategory::Two => "two",
+        PluralCategory::Few => "few",
+        PluralCategory::Many => "many",
+        PluralCategory::Other => "other",
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{forward, Context};
+
+#[test]
+fn select_cardinal() {
+    let mut context = Context::default();
+    forward(&mut context, "var pr = new Intl.PluralRules('en-US');");
+    assert_eq!(forward(&mut context, "pr.select(1)"), "\"one\"");
+    assert_eq!(forward(&mut context, "pr.select(0)"), "\"other\"");
+    assert_eq!(forward(&mut context, "pr.select(2)"), "\"other\"");
+    assert_eq!(forward(&mut context, "pr.select(-1)"), "\"one\"");
+    assert_eq!(forward(&mut context, "pr.select(Infinity)"), "\"other\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.PluralRules('en', { minimumFractionDigits: 1 }).select(1)"
+        ),
+        "\"other\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Intl.PluralRules('ar').select(0)"),
+        "\"zero\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Intl.PluralRules('pl').select(5)"),
+        "\"many\""
+    );
+}
+
+#[test]
+fn select_ordinal() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        "var pr = new Intl.PluralRules('en', { type: 'ordinal' });",
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[1, 2, 3, 4, 11, 21, 22].map(n => pr.select(n)).join()"
+        ),
+        "\"one,two,few,other,other,one,two\""
+    );
+}
+
+#[test]
+fn select_range() {
+    let mut context = Context::default();
+    forward(&mut context, "var pr = new Intl.PluralRules('en');");
+    assert_eq!(forward(&mut context, "pr.selectRange(1, 1)"), "\"one\"");
+    assert_eq!(forward(&mut context, "pr.selectRange(0, 1)"), "\"one\"");
+    assert_eq!(forward(&mut context, "pr.selectRange(1, 5)"), "\"other\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { pr.selectRange(NaN, 1) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { pr.selectRange(1) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn resolved_options() {
+    let mut context = Context::default();
+    let init = r#"
+        var options = new Intl.PluralRules('en-US').resolvedOptions();
+        var significant = new Intl.PluralRules('en', { maximumSignificantDigits: 2 })
+            .resolvedOptions();
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "options.locale"), "\"en-US\"");
+    assert_eq!(forward(&mut context, "options.type"), "\"cardinal\"");
+    assert_eq!(forward(&mut context, "options.minimumIntegerDigits"), "1");
+    assert_eq!(forward(&mut context, "options.minimumFractionDigits"), "0");
+    assert_eq!(forward(&mut context, "options.maximumFractionDigits"), "3");
+    assert_eq!(
+        forward(&mut context, "options.pluralCategories.join()"),
+        "\"one,other\""
+    );
+    assert_eq!(
+        forward(&mut context, "significant.minimumSignificantDigits"),
+        "1"
+    );
+    assert_eq!(
+        forward(&mut context, "significant.maximumSignificantDigits"),
+        "2"
+    );
+    assert_eq!(
+        forward(&mut context, "significant.minimumFractionDigits"),
+        "undefined"
+    );
+}
+
+#[test]
+fn invalid_construction() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Intl.PluralRules() } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Intl.PluralRules('en', { type: 'plural' }) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Intl.PluralRules('en', { minimumFractionDigits: 3, maximumFractionDigits: 1 }) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
@@ -0,0 +1,228 @@
+//! This module implements the global `Intl.Segmenter` object.
+//!
+//! `Intl.Segmenter` objects enable locale-sensitive text segmentation, splitting a string into
+//! grapheme clusters, words or sentences.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#segmenter-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Segmenter
+
+pub(crate) mod segmentation;
+pub mod segments;
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) use segments::{SegmentIterator, Segments};
+
+use self::segmentation::Granularity;
+use super::{
+    coerce_options_to_object, get_option,
+    negotiation::{
+        canonicalize_locale_list, is_well_formed_locale, resolve_icu_locale, supported_locales,
+        LocaleMatcher,
+    },
+};
+use crate::{
+    builtins::{BuiltIn, JsArgs},
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use tap::{Conv, Pipe};
+
+/// The internal slots of an `Intl.Segmenter` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Segmenter {
+    /// `[[Locale]]`
+    #[unsafe_ignore_trace]
+    locale: String,
+    /// `[[SegmenterGranularity]]`
+    #[unsafe_ignore_trace]
+    granularity: Granularity,
+}
+
+impl BuiltIn for Segmenter {
+    const NAME: &'static str = "Segmenter";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().segmenter().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .static_method(Self::supported_locales_of, "supportedLocalesOf", 1)
+        .method(Self::segment, "segment", 1)
+        .method(Self::resolved_options, "resolvedOptions", 0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            "Intl.Segmenter",
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+impl Segmenter {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `Intl.Segmenter ( [ locales [ , options ] ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.segmenter
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Segmenter/Segmenter
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error("Intl.Segmenter constructor requires 'new'");
+        }
+
+        // 2. Let internalSlotsList be « [[InitializedSegmenter]], [[Locale]], [[SegmenterGranularity]] ».
+        // 3. Let segmenter be ? OrdinaryCreateFromConstructor(NewTarget, "%Segmenter.prototype%", internalSlotsList).
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardConstructors::segmenter, context)?;
+
+        // 4. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(args.get_or_undefined(0), context)?;
+
+        // 5. Let options be ? GetOptionsObject(options).
+        let options = coerce_options_to_object(args.get_or_undefined(1), context)?;
+
+        // 7. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        let matcher = LocaleMatcher::from_options(&options, context)?;
+
+        // 9. Let localeData be %Segmenter%.[[LocaleData]].
+        // 10. Let r be ResolveLocale(%Segmenter%.[[AvailableLocales]], requestedLocales, opt, ...).
+        // 11. Set segmenter.[[Locale]] to r.[[locale]].
+        let locale = resolve_icu_locale(&requested_locales, matcher, context).to_string();
+
+        // 12. Let granularity be ? GetOption(options, "granularity", string, « "grapheme", "word", "sentence" », "grapheme").
+        // 13. Set segmenter.[[SegmenterGranularity]] to granularity.
+        let granularity = get_option(
+            &options,
+            "granularity",
+            &["grapheme", "word", "sentence"],
+            context,
+        )?
+        .and_then(Granularity::from_name)
+        .unwrap_or(Granularity::Grapheme);
+
+        // 14. Return segmenter.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::segmenter(Self {
+                locale,
+                granularity,
+            }),
+        )
+        .into())
+    }
+
+    /// Gets the `this` `Intl.Segmenter` object.
+    fn this_segmenter(this: &JsValue, context: &mut Context) -> JsResult<Self> {
+        this.as_object()
+            .and_then(|obj| obj.borrow().as_segmenter().cloned())
+            .ok_or_else(|| context.construct_type_error("'this' is not an Intl.Segmenter"))
+    }
+
+    /// `Intl.Segmenter.supportedLocalesOf ( locales [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.segmenter.supportedlocalesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Segmenter/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let availableLocales be %Segmenter%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(args.get_or_undefined(0), context)?;
+
+        // 3. Return ? SupportedLocales(availableLocales, requestedLocales, options).
+        supported_locales(
+            requested_locales,
+            args.get_or_undefined(1),
+            is_well_formed_locale,
+            context,
+        )
+    }
+
+    /// `Intl.Segmenter.prototype.segment ( string )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.segmenter.prototype.segment
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Segmenter/segment
+    fn segment(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let segmenter be the this value.
+        // 2. Perform ? RequireInternalSlot(segmenter, [[InitializedSegmenter]]).
+        let segmenter = Self::this_segmenter(this, context)?;
+
+        // 3. Let string be ? ToString(string).
+        let string = args.get_or_undefined(0).to_string(context)?;
+
+        // 4. Return ! CreateSegmentsObject(segmenter, string).
+        Segments::create(string, segmenter.granularity, context)
+    }
+
+    /// `Intl.Segmenter.prototype.resolvedOptions ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.segmenter.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Segmenter/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let segmenter be the this value.
+        // 2. Perform ? RequireInternalSlot(segmenter, [[InitializedSegmenter]]).
+        let segmenter = Self::this_segmenter(this, context)?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context.construct_object();
+
+        // 4. For each row of Table 16, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of segmenter's internal slot whose name is the Internal Slot value of the current row.
+        //     c. Assert: v is not undefined.
+        //     d. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        options
+            .create_data_property_or_throw("locale", segmenter.locale, context)
+            .expect("CreateDataPropertyOrThrow cannot fail on a new ordinary object");
+        options
+            .create_data_property_or_throw("granularity", segmenter.granularity.name(), context)
+            .expect("CreateDataPropertyOrThrow cannot fail on a new ordinary object");
+
+        // 5. Return options.
+        Ok(options.into())
+    }
+}
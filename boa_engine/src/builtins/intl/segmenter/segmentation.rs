@@ -0,0 +1,115 @@
+//! Unicode text segmentation of UTF-16 strings, backed by ICU4X.
+//!
+//! This module does not depend on any JavaScript object, so the engine can use it wherever it
+//! needs to split a string into grapheme clusters, words or sentences.
+
+use crate::context::BoaProvider;
+use icu_provider::DataError;
+use icu_segmenter::{GraphemeClusterSegmenter, SentenceSegmenter, WordSegmenter};
+
+/// The granularity of a text segmentation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Granularity {
+    /// Extended grapheme clusters, as defined by UAX #29.
+    Grapheme,
+    /// Words, as defined by UAX #29 with dictionary support for languages without spaces.
+    Word,
+    /// Sentences, as defined by UAX #29.
+    Sentence,
+}
+
+impl Granularity {
+    /// Gets the granularity from its name, as accepted by the `granularity` option of
+    /// `Intl.Segmenter`.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "grapheme" => Some(Self::Grapheme),
+            "word" => Some(Self::Word),
+            "sentence" => Some(Self::Sentence),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the granularity.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Grapheme => "grapheme",
+            Self::Word => "word",
+            Self::Sentence => "sentence",
+        }
+    }
+}
+
+/// A segment of a UTF-16 string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Segment {
+    /// The index of the first code unit of the segment.
+    pub(crate) start: usize,
+    /// The index after the last code unit of the segment.
+    pub(crate) end: usize,
+    /// Whether the segment is a word, a number or another word-like sequence.
+    ///
+    /// This is only computed for the [`Granularity::Word`] granularity.
+    pub(crate) is_word_like: Option<bool>,
+}
+
+/// Splits the UTF-16 string `text` into consecutive segments of the given granularity.
+///
+/// The segmentation rules are loaded from `provider`, or from the compiled data if it is `None`.
+pub(crate) fn segment(
+    text: &[u16],
+    granularity: Granularity,
+    provider: Option<&BoaProvider>,
+) -> Result<Vec<Segment>, DataError> {
+    /// Converts a list of boundaries, starting at `0` and ending at the length of the text, to
+    /// a list of segments.
+    fn from_boundaries<I: Iterator<Item = usize>>(boundaries: I) -> Vec<Segment> {
+        let boundaries = boundaries.collect::<Vec<_>>();
+        boundaries
+            .windows(2)
+            .map(|window| Segment {
+                start: window[0],
+                end: window[1],
+                is_word_like: None,
+            })
+            .collect()
+    }
+
+    Ok(match granularity {
+        Granularity::Grapheme => {
+            let segmenter = match provider {
+                Some(provider) => GraphemeClusterSegmenter::try_new_unstable(provider)?,
+                None => GraphemeClusterSegmenter::new(),
+            };
+            from_boundaries(segmenter.segment_utf16(text))
+        }
+        Granularity::Sentence => {
+            let segmenter = match provider {
+                Some(provider) => SentenceSegmenter::try_new_unstable(provider)?,
+                None => SentenceSegmenter::new(),
+            };
+            from_boundaries(segmenter.segment_utf16(text))
+        }
+        Granularity::Word => {
+            let segmenter = match provider {
+                Some(provider) => WordSegmenter::try_new_auto_unstable(provider)?,
+                None => WordSegmenter::new_auto(),
+            };
+            let mut boundaries = segmenter.segment_utf16(text);
+            let mut segments = Vec::new();
+            let mut start = match boundaries.next() {
+                Some(start) => start,
+                None => return Ok(segments),
+            };
+            while let Some(end) = boundaries.next() {
+                segments.push(Segment {
+                    start,
+                    end,
+                    is_word_like: Some(boundaries.is_word_like()),
+                });
+                start = end;
+            }
+            segments
+        }
+    })
+}
@@ -0,0 +1,288 @@
+//! This module implements the `Segments` objects returned by `Intl.Segmenter.prototype.segment`
+//! and their `%SegmentIterator%` iterators.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma402/#sec-segments-objects
+
+use super::segmentation::{segment, Granularity, Segment};
+use crate::{
+    builtins::{function::make_builtin_fn, iterable::create_iter_result_object, JsArgs},
+    object::{JsObject, ObjectData, ObjectInitializer},
+    property::PropertyDescriptor,
+    symbol::WellKnownSymbols,
+    value::IntegerOrInfinity,
+    Context, JsResult, JsString, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use std::rc::Rc;
+
+/// A string split into segments, shared by a `Segments` object and its iterators.
+#[derive(Debug)]
+struct SegmentedString {
+    /// `[[SegmentsString]]`
+    input: JsString,
+    /// The UTF-16 code units of the string.
+    text: Vec<u16>,
+    /// The segments of the string, in order.
+    segments: Vec<Segment>,
+}
+
+impl SegmentedString {
+    /// Abstract operation `CreateSegmentDataObject ( segmenter, string, startIndex, endIndex )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-createsegmentdataobject
+    fn segment_data(&self, segment: Segment, context: &mut Context) -> JsValue {
+        // 1. Let len be the length of string.
+        // 2. Assert: startIndex ≥ 0.
+        // 3. Assert: endIndex ≤ len.
+        // 4. Assert: startIndex < endIndex.
+        // 5. Let result be OrdinaryObjectCreate(%Object.prototype%).
+        let result = context.construct_object();
+
+        // 6. Let segment be the substring of string from startIndex to endIndex.
+        let value = String::from_utf16_lossy(&self.text[segment.start..segment.end]);
+
+        // 7. Perform ! CreateDataPropertyOrThrow(result, "segment", segment).
+        result
+            .create_data_property_or_throw("segment", value, context)
+            .expect("CreateDataPropertyOrThrow cannot fail on a new ordinary object");
+
+        // 8. Perform ! CreateDataPropertyOrThrow(result, "index", 𝔽(startIndex)).
+        result
+            .create_data_property_or_throw("index", segment.start, context)
+            .expect("CreateDataPropertyOrThrow cannot fail on a new ordinary object");
+
+        // 9. Perform ! CreateDataPropertyOrThrow(result, "input", string).
+        result
+            .create_data_property_or_throw("input", self.input.clone(), context)
+            .expect("CreateDataPropertyOrThrow cannot fail on a new ordinary object");
+
+        // 10. Let granularity be segmenter.[[SegmenterGranularity]].
+        // 11. If granularity is "word", then
+        if let Some(is_word_like) = segment.is_word_like {
+            // a. Let isWordLike be a Boolean value indicating whether the segment in string is
+            //    "word-like" according to locale segmenter.[[Locale]].
+            // b. Perform ! CreateDataPropertyOrThrow(result, "isWordLike", isWordLike).
+            result
+                .create_data_property_or_throw("isWordLike", is_word_like, context)
+                .expect("CreateDataPropertyOrThrow cannot fail on a new ordinary object");
+        }
+
+        // 12. Return result.
+        result.into()
+    }
+}
+
+/// The internal slots of a `Segments` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Segments {
+    #[unsafe_ignore_trace]
+    string: Rc<SegmentedString>,
+}
+
+impl Segments {
+    /// Abstract operation `CreateSegmentsObject ( segmenter, string )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-createsegmentsobject
+    pub(crate) fn create(
+        input: JsString,
+        granularity: Granularity,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let text = input.encode_utf16().collect::<Vec<_>>();
+        let segments = segment(&text, granularity, context.icu().provider()).map_err(|err| {
+            context.construct_range_error(format!("no segmentation data available: {err}"))
+        })?;
+
+        // 1. Let internalSlotsList be « [[SegmentsSegmenter]], [[SegmentsString]] ».
+        // 2. Let segments be OrdinaryObjectCreate(%SegmentsPrototype%, internalSlotsList).
+        // 3. Set segments.[[SegmentsSegmenter]] to segmenter.
+        // 4. Set segments.[[SegmentsString]] to string.
+        // 5. Return segments.
+        Ok(JsObject::from_proto_and_data(
+            context.intrinsics().objects().segments_prototype(),
+            ObjectData::segments(Self {
+                string: Rc::new(SegmentedString {
+                    input,
+                    text,
+                    segments,
+                }),
+            }),
+        )
+        .into())
+    }
+
+    /// Gets the segmented string of the `this` `Segments` object.
+    fn this_segmented_string(
+        this: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Rc<SegmentedString>> {
+        this.as_object()
+            .and_then(|obj| obj.borrow().as_segments().map(|s| s.string.clone()))
+            .ok_or_else(|| context.construct_type_error("'this' is not a Segments object"))
+    }
+
+    /// `%SegmentsPrototype%.containing ( index )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-%segmentsprototype%.containing
+    fn containing(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let segments be the this value.
+        // 2. Perform ? RequireInternalSlot(segments, [[SegmentsSegmenter]]).
+        // 3. Let segmenter be segments.[[SegmentsSegmenter]].
+        // 4. Let string be segments.[[SegmentsString]].
+        let string = Self::this_segmented_string(this, context)?;
+
+        // 5. Let len be the length of string.
+        // 6. Let n be ? ToIntegerOrInfinity(index).
+        // 7. If n < 0 or n ≥ len, return undefined.
+        let n = match args.get_or_undefined(0).to_integer_or_infinity(context)? {
+            IntegerOrInfinity::Integer(n) if n >= 0 && (n as usize) < string.text.len() => {
+                n as usize
+            }
+            _ => return Ok(JsValue::undefined()),
+        };
+
+        // 8. Let startIndex be ! FindBoundary(segmenter, string, n, before).
+        // 9. Let endIndex be ! FindBoundary(segmenter, string, n, after).
+        // 10. Return ! CreateSegmentDataObject(segmenter, string, startIndex, endIndex).
+        let index = string.segments.partition_point(|segment| segment.end <= n);
+        let segment = string.segments[index];
+        Ok(string.segment_data(segment, context))
+    }
+
+    /// `%SegmentsPrototype% [ @@iterator ] ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-%segmentsprototype%-@@iterator
+    fn iterator(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let segments be the this value.
+        // 2. Perform ? RequireInternalSlot(segments, [[SegmentsSegmenter]]).
+        // 3. Let segmenter be segments.[[SegmentsSegmenter]].
+        // 4. Let string be segments.[[SegmentsString]].
+        let string = Self::this_segmented_string(this, context)?;
+
+        // 5. Return ! CreateSegmentIterator(segmenter, string).
+        Ok(JsObject::from_proto_and_data(
+            context
+                .intrinsics()
+                .objects()
+                .iterator_prototypes()
+                .segment_iterator(),
+            ObjectData::segment_iterator(SegmentIterator {
+                string,
+                next_segment: 0,
+            }),
+        )
+        .into())
+    }
+
+    /// Create the `%SegmentsPrototype%` object
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-%segmentsprototype%-object
+    pub(crate) fn create_prototype(context: &mut Context) -> JsObject {
+        let _timer = Profiler::global().start_event("Segments", "init");
+
+        ObjectInitializer::new(context)
+            .function(Self::containing, "containing", 1)
+            .function(
+                Self::iterator,
+                (WellKnownSymbols::iterator(), "[Symbol.iterator]"),
+                0,
+            )
+            .build()
+    }
+}
+
+/// The internal slots of a `%SegmentIterator%` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct SegmentIterator {
+    /// `[[IteratedString]]`
+    #[unsafe_ignore_trace]
+    string: Rc<SegmentedString>,
+    /// The index of the next segment to return, derived from `[[IteratedStringNextSegmentCodeUnitIndex]]`.
+    next_segment: usize,
+}
+
+impl SegmentIterator {
+    /// `%SegmentIteratorPrototype%.next ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-%segmentiteratorprototype%.next
+    fn next(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let iterator be the this value.
+        // 2. Perform ? RequireInternalSlot(iterator, [[IteratingSegmenter]]).
+        let mut iterator = this.as_object().map(JsObject::borrow_mut);
+        let iterator = iterator
+            .as_mut()
+            .and_then(|obj| obj.as_segment_iterator_mut())
+            .ok_or_else(|| context.construct_type_error("`this` is not a Segment Iterator"))?;
+
+        // 3-6. If startIndex ≥ len, then
+        //     a. Return CreateIterResultObject(undefined, true).
+        let segment = match iterator.string.segments.get(iterator.next_segment) {
+            Some(segment) => *segment,
+            None => {
+                return Ok(create_iter_result_object(
+                    JsValue::undefined(),
+                    true,
+                    context,
+                ))
+            }
+        };
+
+        // 7. Let endIndex be ! FindBoundary(segmenter, string, startIndex, after).
+        // 8. Set iterator.[[IteratedStringNextSegmentCodeUnitIndex]] to endIndex.
+        iterator.next_segment += 1;
+        let string = iterator.string.clone();
+
+        // 9. Let segmentData be ! CreateSegmentDataObject(segmenter, string, startIndex, endIndex).
+        // 10. Return CreateIterResultObject(segmentData, false).
+        let segment_data = string.segment_data(segment, context);
+        Ok(create_iter_result_object(segment_data, false, context))
+    }
+
+    /// Create the `%SegmentIteratorPrototype%` object
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-%segmentiteratorprototype%-object
+    pub(crate) fn create_prototype(
+        iterator_prototype: JsObject,
+        context: &mut Context,
+    ) -> JsObject {
+        let _timer = Profiler::global().start_event("Segment Iterator", "init");
+
+        let segment_iterator =
+            JsObject::from_proto_and_data(iterator_prototype, ObjectData::ordinary());
+        make_builtin_fn(Self::next, "next", &segment_iterator, 0, context);
+
+        let to_string_tag = WellKnownSymbols::to_string_tag();
+        let to_string_tag_property = PropertyDescriptor::builder()
+            .value("Segmenter String Iterator")
+            .writable(false)
+            .enumerable(false)
+            .configurable(true);
+        segment_iterator.insert(to_string_tag, to_string_tag_property);
+        segment_iterator
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{forward, Context};
+
+#[test]
+fn segment_graphemes() {
+    let mut context = Context::default();
+    forward(&mut context, "var segmenter = new Intl.Segmenter('en');");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.from(segmenter.segment('ae\u{301}🇪🇸'), s => s.segment).join('|')"
+        ),
+        "\"a|e\u{301}|🇪🇸\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.from(segmenter.segment('ae\u{301}🇪🇸'), s => s.index).join()"
+        ),
+        "\"0,1,3\""
+    );
+    assert_eq!(
+        forward(&mut context, "[...segmenter.segment('')].length"),
+        "0"
+    );
+}
+
+#[test]
+fn segment_words() {
+    let mut context = Context::default();
+    let init = r#"
+        var segmenter = new Intl.Segmenter('en', { granularity: 'word' });
+        var segments = [...segmenter.segment('Hello, world!')];
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "segments.map(s => s.segment).join('|')"),
+        "\"Hello|,| |world|!\""
+    );
+    assert_eq!(
+        forward(&mut context, "segments.map(s => s.isWordLike).join()"),
+        "\"true,false,false,true,false\""
+    );
+    assert_eq!(
+        forward(&mut context, "segments[3].input"),
+        "\"Hello, world!\""
+    );
+}
+
+#[test]
+fn segment_sentences() {
+    let mut context = Context::default();
+    let init = r#"
+        var segmenter = new Intl.Segmenter('en', { granularity: 'sentence' });
+        var segments = [...segmenter.segment('One. Two? Three!')];
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "segments.map(s => s.segment).join('|')"),
+        "\"One. |Two? |Three!\""
+    );
+    assert_eq!(
+        forward(&mut context, "'isWordLike' in segments[0]"),
+        "false"
+    );
+}
+
+#[test]
+fn containing() {
+    let mut context = Context::default();
+    let init = r#"
+        var segments = new Intl.Segmenter('en', { granularity: 'word' }).segment('Hello, world!');
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "segments.containing(8).segment"),
+        "\"world\""
+    );
+    assert_eq!(forward(&mut context, "segments.containing(8).index"), "7");
+    assert_eq!(
+        forward(&mut context, "segments.containing().segment"),
+        "\"Hello\""
+    );
+    assert_eq!(
+        forward(&mut context, "segments.containing(-1)"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut context, "segments.containing(13)"),
+        "undefined"
+    );
+}
+
+#[test]
+fn iterator() {
+    let mut context = Context::default();
+    let init = r#"
+        var iterator = new Intl.Segmenter().segment('ab')[Symbol.iterator]();
+        var first = iterator.next();
+        var second = iterator.next();
+        var last = iterator.next();
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "first.value.segment"), "\"a\"");
+    assert_eq!(forward(&mut context, "second.value.segment"), "\"b\"");
+    assert_eq!(forward(&mut context, "last.done"), "true");
+    assert_eq!(forward(&mut context, "last.value"), "undefined");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(iterator)"),
+        "\"[object Segmenter String Iterator]\""
+    );
+}
+
+#[test]
+fn resolved_options() {
+    let mut context = Context::default();
+    let init = r#"
+        var options = new Intl.Segmenter('fr-CA', { granularity: 'sentence' }).resolvedOptions();
+        var defaults = new Intl.Segmenter().resolvedOptions();
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "options.locale"), "\"fr-CA\"");
+    assert_eq!(forward(&mut context, "options.granularity"), "\"sentence\"");
+    assert_eq!(forward(&mut context, "defaults.locale"), "\"en-US\"");
+    assert_eq!(
+        forward(&mut context, "defaults.granularity"),
+        "\"grapheme\""
+    );
+}
+
+#[test]
+fn invalid_construction() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Intl.Segmenter() } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Intl.Segmenter('en', { granularity: 'line' }) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Intl.Segmenter.prototype.segment.call({}, 'a') } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
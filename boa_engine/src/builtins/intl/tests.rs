@@ -0,0 +1,138 @@
+use crate::{forward, Context};
+
+#[test]
+fn get_canonical_locales() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(
+            &mut context,
+            "Intl.getCanonicalLocales(['en-US', 'en-US', 'fr']).join()"
+        ),
+        "\"en-US,fr\""
+    );
+    assert_eq!(
+        forward(&mut context, "Intl.getCanonicalLocales().length"),
+        "0"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Intl.getCanonicalLocales([5]) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn locale_matcher() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DateTimeFormat(['fr', 'en-GB'], { localeMatcher: 'lookup' }).resolvedOptions().locale"
+        ),
+        "\"en\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Intl.DateTimeFormat('en', { localeMatcher: 'exact' }) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn default_locale() {
+    let mut context = Context::default();
+    assert_eq!(context.default_locale(), "en-US");
+    assert!(context.set_default_locale("en_GB").is_err());
+
+    context.set_default_locale("EN").unwrap();
+    assert_eq!(context.default_locale(), "en");
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DateTimeFormat('fr').resolvedOptions().locale"
+        ),
+        "\"en\""
+    );
+
+    // The English-only services have no data for the default locale, so they fall back to `en-US`.
+    context.set_default_locale("fr").unwrap();
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DurationFormat().resolvedOptions().locale"
+        ),
+        "\"en-US\""
+    );
+}
+
+#[test]
+fn available_locales() {
+    let mut context = Context::default();
+    assert!(context.set_available_locales(["en", "en_US"]).is_err());
+
+    context.set_available_locales(["en"]).unwrap();
+    assert_eq!(
+        forward(
+            &mut context,
+            "Intl.DateTimeFormat.supportedLocalesOf(['en-US', 'en-GB', 'fr']).join()"
+        ),
+        "\"en-US,en-GB\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.DateTimeFormat('en-US').resolvedOptions().locale"
+        ),
+        "\"en\""
+    );
+}
+
+#[cfg(feature = "intl")]
+#[test]
+fn best_fit_matcher() {
+    let mut context = Context::default();
+    context.set_available_locales(["en", "zh-Hant"]).unwrap();
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.PluralRules('zh-TW').resolvedOptions().locale"
+        ),
+        "\"zh-Hant\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.PluralRules('zh-TW', { localeMatcher: 'lookup' }).resolvedOptions().locale"
+        ),
+        "\"en-US\""
+    );
+}
+
+#[cfg(feature = "intl")]
+#[test]
+fn icu_provider() {
+    use crate::context::BoaProvider;
+    use icu_provider::{AnyProvider, AnyResponse, DataError, DataErrorKind, DataKey, DataRequest};
+
+    struct NoData;
+
+    impl AnyProvider for NoData {
+        fn load_any(&self, key: DataKey, req: DataRequest<'_>) -> Result<AnyResponse, DataError> {
+            Err(DataErrorKind::MissingDataKey.with_req(key, req))
+        }
+    }
+
+    assert!(Context::builder()
+        .icu_provider(BoaProvider::Any(Box::new(NoData)))
+        .is_err());
+
+    let mut context = Context::builder().build();
+    assert_eq!(
+        forward(&mut context, "new Intl.Locale('iw').maximize().toString()"),
+        "\"he-Hebr-IL\""
+    );
+}
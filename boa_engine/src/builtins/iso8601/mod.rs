@@ -0,0 +1,881 @@
+//! Parsing of the ISO 8601 date-time strings of `Date` and the Temporal API.
+//!
+//! This module implements two grammars on top of a shared [`Cursor`]:
+//!  - the Date Time String Format of ECMAScript, a simplification of the ISO 8601 extended
+//!    format, used by `Date.parse` and the `Date` constructor;
+//!  - the ISO 8601 grammar of the Temporal API, which adds the basic format, UTC offsets with
+//!    sub-minute precision, durations, and the time zone and calendar annotations of RFC 9557.
+//!
+//! The parsers report the first error of a string as a [`ParseError`], with its kind and its
+//! position in the string.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [Temporal reference][temporal]
+//!  - [RFC 9557][rfc]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-date-time-string-format
+//! [temporal]: https://tc39.es/proposal-temporal/#sec-temporal-iso8601grammar
+//! [rfc]: https://www.rfc-editor.org/rfc/rfc9557
+
+#[cfg(test)]
+mod tests;
+
+use std::fmt;
+
+/// The number of nanoseconds in a minute.
+const NS_PER_MINUTE: i64 = 60_000_000_000;
+
+/// The kinds of errors of the date-time strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ParseErrorKind {
+    /// The string ended in the middle of a component.
+    UnexpectedEnd,
+    /// A character was found where none was expected, such as after the end of a date-time.
+    UnexpectedCharacter,
+    /// A decimal digit was expected.
+    ExpectedDigit,
+    /// A specific character was expected.
+    ExpectedCharacter(char),
+    /// The year `0` was written as `-000000`.
+    InvalidYear,
+    /// The month is not between 1 and 12.
+    InvalidMonth,
+    /// The day is not a day of the month.
+    InvalidDay,
+    /// The hour is out of range.
+    InvalidHour,
+    /// The minute is not between 0 and 59.
+    InvalidMinute,
+    /// The second is out of range.
+    InvalidSecond,
+    /// The fraction of a second has more than 9 digits.
+    InvalidFraction,
+    /// A time string without the `T` designator is also a valid month-day or year-month.
+    AmbiguousTime,
+    /// A time is required, but the string only has a date.
+    MissingTime,
+    /// The `Z` designator is used where a wall-clock time is required.
+    UtcDesignator,
+    /// The time zone annotation is neither a time zone name nor a UTC offset in minutes.
+    InvalidTimeZone,
+    /// The time zone annotation is not the first annotation.
+    MisplacedTimeZone,
+    /// The key or the value of an annotation is malformed.
+    InvalidAnnotation,
+    /// An annotation with an unknown key is marked as critical with a `!`.
+    CriticalAnnotation,
+    /// There are several calendar annotations, and one of them is critical.
+    ConflictingCalendars,
+    /// A duration has no units.
+    EmptyDuration,
+    /// The units of a duration are not in decreasing order.
+    DurationUnitOrder,
+    /// A fraction is used on a date unit of a duration, or on a time unit other than the last.
+    DurationFraction,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd => f.write_str("unexpected end of the string"),
+            Self::UnexpectedCharacter => f.write_str("unexpected character"),
+            Self::ExpectedDigit => f.write_str("expected a digit"),
+            Self::ExpectedCharacter(expected) => write!(f, "expected `{expected}`"),
+            Self::InvalidYear => f.write_str("the year 0 must be written as +000000"),
+            Self::InvalidMonth => f.write_str("month out of range"),
+            Self::InvalidDay => f.write_str("day out of range"),
+            Self::InvalidHour => f.write_str("hour out of range"),
+            Self::InvalidMinute => f.write_str("minute out of range"),
+            Self::InvalidSecond => f.write_str("second out of range"),
+            Self::InvalidFraction => f.write_str("a fraction can have at most 9 digits"),
+            Self::AmbiguousTime => {
+                f.write_str("a time that could be a month-day or a year-month requires a `T`")
+            }
+            Self::MissingTime => f.write_str("a time is required"),
+            Self::UtcDesignator => f.write_str("the `Z` designator is not allowed"),
+            Self::InvalidTimeZone => f.write_str("invalid time zone annotation"),
+            Self::MisplacedTimeZone => {
+                f.write_str("the time zone annotation must be the first annotation")
+            }
+            Self::InvalidAnnotation => f.write_str("invalid annotation"),
+            Self::CriticalAnnotation => f.write_str("unknown critical annotation"),
+            Self::ConflictingCalendars => {
+                f.write_str("several calendar annotations must not be critical")
+            }
+            Self::EmptyDuration => f.write_str("a duration must have at least one unit"),
+            Self::DurationUnitOrder => f.write_str("the units of a duration must be in order"),
+            Self::DurationFraction => {
+                f.write_str("only the last time unit of a duration can have a fraction")
+            }
+        }
+    }
+}
+
+/// An error in a date-time string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ParseError {
+    pub(crate) kind: ParseErrorKind,
+    /// The position of the error, in bytes from the start of the string.
+    pub(crate) position: usize,
+}
+
+impl ParseError {
+    /// Creates an error of the given kind at a position.
+    pub(crate) fn new(kind: ParseErrorKind, position: usize) -> Self {
+        Self { kind, position }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.kind, self.position)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A cursor over the bytes of a date-time string.
+#[derive(Debug, Clone)]
+pub(crate) struct Cursor<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    /// Creates a cursor at the start of `text`.
+    pub(crate) fn new(text: &'a str) -> Self {
+        Self {
+            input: text.as_bytes(),
+            position: 0,
+        }
+    }
+
+    /// Gets the position of the cursor, in bytes from the start of the input.
+    pub(crate) fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the next byte, without consuming it.
+    pub(crate) fn peek(&self) -> Option<u8> {
+        self.input.get(self.position).copied()
+    }
+
+    /// Returns `true` if the whole input was consumed.
+    pub(crate) fn is_done(&self) -> bool {
+        self.position >= self.input.len()
+    }
+
+    /// Consumes the next byte.
+    pub(crate) fn next_byte(&mut self) -> Option<u8> {
+        let byte = self.peek()?;
+        self.position += 1;
+        Some(byte)
+    }
+
+    /// Consumes the next byte if it is `byte`, returning whether it was consumed.
+    pub(crate) fn next_if(&mut self, byte: u8) -> bool {
+        let found = self.peek() == Some(byte);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    /// Consumes exactly `count` decimal digits, returning their value.
+    pub(crate) fn digits(&mut self, count: usize) -> Option<u32> {
+        let digits = self.input.get(self.position..self.position + count)?;
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        self.position += count;
+        Some(
+            digits
+                .iter()
+                .fold(0, |value, digit| value * 10 + u32::from(digit - b'0')),
+        )
+    }
+
+    /// Consumes a run of decimal digits, returning them.
+    pub(crate) fn digit_run(&mut self) -> &'a [u8] {
+        let start = self.position;
+        while self.peek().map_or(false, |byte| byte.is_ascii_digit()) {
+            self.position += 1;
+        }
+        &self.input[start..self.position]
+    }
+
+    /// Consumes a run of ASCII letters, returning them.
+    pub(crate) fn word(&mut self) -> &'a [u8] {
+        let start = self.position;
+        while self.peek().map_or(false, |byte| byte.is_ascii_alphabetic()) {
+            self.position += 1;
+        }
+        &self.input[start..self.position]
+    }
+
+    /// Consumes the milliseconds of a fraction of a second, after the decimal separator.
+    ///
+    /// At least one digit is required, and digits after the third one are truncated.
+    pub(crate) fn fraction_millis(&mut self) -> Option<u32> {
+        let digits = self.digit_run();
+        if digits.is_empty() {
+            return None;
+        }
+        Some(
+            digits
+                .iter()
+                .chain(std::iter::repeat(&b'0'))
+                .take(3)
+                .fold(0, |value, digit| value * 10 + u32::from(digit - b'0')),
+        )
+    }
+
+    /// Creates an error at the position of the cursor, or an `UnexpectedEnd` error if the whole
+    /// input was consumed.
+    pub(crate) fn error(&self, kind: ParseErrorKind) -> ParseError {
+        if self.is_done() {
+            ParseError::new(ParseErrorKind::UnexpectedEnd, self.position)
+        } else {
+            ParseError::new(kind, self.position)
+        }
+    }
+
+    /// Consumes exactly `count` decimal digits, returning their value, or an error at the first
+    /// missing digit.
+    pub(crate) fn expect_digits(&mut self, count: usize) -> Result<u32, ParseError> {
+        for position in self.position..self.position + count {
+            match self.input.get(position) {
+                Some(byte) if byte.is_ascii_digit() => {}
+                Some(_) => {
+                    return Err(ParseError::new(ParseErrorKind::ExpectedDigit, position));
+                }
+                None => return Err(ParseError::new(ParseErrorKind::UnexpectedEnd, position)),
+            }
+        }
+        Ok(self.digits(count).expect("the digits were checked"))
+    }
+
+    /// Consumes `byte`, or returns an error.
+    pub(crate) fn expect(&mut self, byte: u8) -> Result<(), ParseError> {
+        if self.next_if(byte) {
+            Ok(())
+        } else {
+            Err(self.error(ParseErrorKind::ExpectedCharacter(char::from(byte))))
+        }
+    }
+
+    /// Returns an error if the input was not consumed entirely.
+    pub(crate) fn finish(&self) -> Result<(), ParseError> {
+        if self.is_done() {
+            Ok(())
+        } else {
+            Err(self.error(ParseErrorKind::UnexpectedCharacter))
+        }
+    }
+}
+
+/// Abstract operation `ISODaysInMonth ( year, month )`
+pub(crate) fn iso_days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        _ if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        _ => 28,
+    }
+}
+
+/// The UTC offset of a parsed date-time string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UtcOffset {
+    /// The `Z` designator.
+    Z,
+    /// A numeric offset, in nanoseconds.
+    Offset(i64),
+}
+
+/// The time of a parsed date-time string.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct ParsedTime {
+    pub(crate) hour: u8,
+    pub(crate) minute: u8,
+    pub(crate) second: u8,
+    /// The fraction of the second, in nanoseconds.
+    pub(crate) subsecond_nanoseconds: u32,
+}
+
+/// The fields of a parsed date-time string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ParsedDateTime {
+    pub(crate) year: i32,
+    pub(crate) month: u8,
+    pub(crate) day: u8,
+    /// The time, or `None` for a date-only string.
+    pub(crate) time: Option<ParsedTime>,
+    /// The UTC offset, or `None` if the string doesn't have one.
+    pub(crate) offset: Option<UtcOffset>,
+    /// The time zone annotation, such as `Europe/Paris` in `[Europe/Paris]`.
+    pub(crate) time_zone: Option<String>,
+    /// The calendar annotation, such as `iso8601` in `[u-ca=iso8601]`.
+    pub(crate) calendar: Option<String>,
+}
+
+/// Parses a string in the Date Time String Format of ECMAScript.
+///
+/// The month, the day and the time are optional, and the hour can be `24` at the end of a
+/// day. Only the `T` designator, the extended format and `±HH:mm` offsets are allowed, and the
+/// digits of the fraction of a second after the ninth one are ignored.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date-time-string-format
+pub(crate) fn parse_date_time_string_format(text: &str) -> Result<ParsedDateTime, ParseError> {
+    let mut cursor = Cursor::new(text);
+
+    // YYYY, or ±YYYYYY for expanded years.
+    let year = year(&mut cursor)?;
+
+    // -MM and -DD
+    let mut month = 1;
+    let mut day = 1;
+    if cursor.next_if(b'-') {
+        month = two_digits(&mut cursor, 1..=12, ParseErrorKind::InvalidMonth)?;
+        if cursor.next_if(b'-') {
+            let days = iso_days_in_month(year, month);
+            day = two_digits(&mut cursor, 1..=days, ParseErrorKind::InvalidDay)?;
+        }
+    }
+
+    // THH:mm, THH:mm:ss or THH:mm:ss.sss, then Z or ±HH:mm.
+    let mut time = None;
+    let mut offset = None;
+    if cursor.next_if(b'T') {
+        let hour_position = cursor.position();
+        let mut parsed = ParsedTime {
+            hour: two_digits(&mut cursor, 0..=24, ParseErrorKind::InvalidHour)?,
+            ..ParsedTime::default()
+        };
+        cursor.expect(b':')?;
+        parsed.minute = two_digits(&mut cursor, 0..=59, ParseErrorKind::InvalidMinute)?;
+        if cursor.next_if(b':') {
+            parsed.second = two_digits(&mut cursor, 0..=59, ParseErrorKind::InvalidSecond)?;
+            if cursor.next_if(b'.') {
+                parsed.subsecond_nanoseconds = fraction(&mut cursor, true)?;
+            }
+        }
+
+        // The hour 24 is only allowed as the end of the day.
+        if parsed.hour == 24
+            && (parsed.minute != 0 || parsed.second != 0 || parsed.subsecond_nanoseconds != 0)
+        {
+            return Err(ParseError::new(ParseErrorKind::InvalidHour, hour_position));
+        }
+        time = Some(parsed);
+
+        offset = match cursor.peek() {
+            Some(b'Z') => {
+                cursor.next_byte();
+                Some(UtcOffset::Z)
+            }
+            Some(sign @ (b'+' | b'-')) => {
+                cursor.next_byte();
+                let hours = two_digits(&mut cursor, 0..=23, ParseErrorKind::InvalidHour)?;
+                cursor.expect(b':')?;
+                let minutes = two_digits(&mut cursor, 0..=59, ParseErrorKind::InvalidMinute)?;
+                let offset = (i64::from(hours) * 60 + i64::from(minutes)) * NS_PER_MINUTE;
+                Some(UtcOffset::Offset(if sign == b'-' {
+                    -offset
+                } else {
+                    offset
+                }))
+            }
+            _ => None,
+        };
+    }
+
+    cursor.finish()?;
+    Ok(ParsedDateTime {
+        year,
+        month,
+        day,
+        time,
+        offset,
+        time_zone: None,
+        calendar: None,
+    })
+}
+
+/// Parses a date-time string of the Temporal API, with an optional time, UTC offset and
+/// annotations.
+pub(crate) fn parse_date_time(text: &str) -> Result<ParsedDateTime, ParseError> {
+    let mut cursor = Cursor::new(text);
+    let (year, month, day) = date(&mut cursor)?;
+
+    let time = if matches!(cursor.peek(), Some(b'T' | b't' | b' ')) {
+        cursor.next_byte();
+        Some(time(&mut cursor)?)
+    } else {
+        None
+    };
+
+    let offset = match cursor.peek() {
+        Some(b'Z' | b'z') if time.is_some() => {
+            cursor.next_byte();
+            Some(UtcOffset::Z)
+        }
+        Some(b'+' | b'-') if time.is_some() => Some(UtcOffset::Offset(utc_offset(&mut cursor)?)),
+        _ => None,
+    };
+
+    let (time_zone, calendar) = annotations(&mut cursor)?;
+    cursor.finish()?;
+
+    Ok(ParsedDateTime {
+        year,
+        month,
+        day,
+        time,
+        offset,
+        time_zone,
+        calendar,
+    })
+}
+
+/// Parses a time string, as a time with an optional `T` designator, UTC offset and annotations,
+/// or as a date-time string.
+///
+/// The times without a designator that are also valid month-day or year-month strings, such as
+/// `1214`, are rejected as ambiguous. The `Z` designator is rejected, as a time with it is not
+/// a wall-clock time.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-parsetemporaltimestring
+pub(crate) fn parse_time_string(text: &str) -> Result<ParsedTime, ParseError> {
+    let mut cursor = Cursor::new(text);
+
+    // The strings starting with a date are date-time strings.
+    let mut date_cursor = cursor.clone();
+    if date(&mut date_cursor).is_ok() {
+        let parsed = parse_date_time(text)?;
+        return match (parsed.time, parsed.offset) {
+            (_, Some(UtcOffset::Z)) => {
+                let position = text.find(|c| c == 'Z' || c == 'z').unwrap_or_default();
+                Err(ParseError::new(ParseErrorKind::UtcDesignator, position))
+            }
+            (Some(time), _) => Ok(time),
+            (None, _) => Err(ParseError::new(
+                ParseErrorKind::MissingTime,
+                date_cursor.position(),
+            )),
+        };
+    }
+
+    let designator = cursor.next_if(b'T') || cursor.next_if(b't');
+    if !designator && is_ambiguous_time(text) {
+        return Err(ParseError::new(ParseErrorKind::AmbiguousTime, 0));
+    }
+    let time = time(&mut cursor)?;
+    match cursor.peek() {
+        Some(b'Z' | b'z') => return Err(cursor.error(ParseErrorKind::UtcDesignator)),
+        Some(b'+' | b'-') => {
+            utc_offset(&mut cursor)?;
+        }
+        _ => {}
+    }
+    annotations(&mut cursor)?;
+    cursor.finish()?;
+    Ok(time)
+}
+
+/// Parses a string made of a UTC offset only, returning it in nanoseconds.
+pub(crate) fn parse_utc_offset(text: &str) -> Result<i64, ParseError> {
+    let mut cursor = Cursor::new(text);
+    let offset = utc_offset(&mut cursor)?;
+    cursor.finish()?;
+    Ok(offset)
+}
+
+/// Returns `true` if a time string without annotations is also a valid month-day, as `MM-DD`
+/// or `MMDD`, or a valid year-month, as `YYYY-MM` or `YYYYMM`.
+fn is_ambiguous_time(text: &str) -> bool {
+    let text = text.split('[').next().unwrap_or_default().as_bytes();
+    let digits = |bytes: &[u8]| -> Option<u32> {
+        if bytes.is_empty() || !bytes.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        Some(
+            bytes
+                .iter()
+                .fold(0, |value, digit| value * 10 + u32::from(digit - b'0')),
+        )
+    };
+
+    let (first, second) = match text {
+        [a, b, b'-', c, d] | [a, b, c, d] => (digits(&[*a, *b]), digits(&[*c, *d])),
+        [a, b, c, d, b'-', e, f] | [a, b, c, d, e, f] => {
+            return digits(&[*a, *b, *c, *d]).is_some()
+                && digits(&[*e, *f]).map_or(false, |month| (1..=12).contains(&month));
+        }
+        _ => return false,
+    };
+    match (first, second) {
+        (Some(month), Some(day)) => {
+            (1..=12).contains(&month)
+                && (1..=u32::from(iso_days_in_month(2000, month as u8))).contains(&day)
+        }
+        _ => false,
+    }
+}
+
+/// Parses a year, as `YYYY` or as an expanded year `±YYYYYY`.
+fn year(cursor: &mut Cursor<'_>) -> Result<i32, ParseError> {
+    match cursor.peek() {
+        Some(sign @ (b'+' | b'-')) => {
+            cursor.next_byte();
+            let position = cursor.position();
+            let year = cursor.expect_digits(6)? as i32;
+            // The representation of the year 0 as -000000 is invalid.
+            match sign {
+                b'-' if year == 0 => Err(ParseError::new(ParseErrorKind::InvalidYear, position)),
+                b'-' => Ok(-year),
+                _ => Ok(year),
+            }
+        }
+        _ => Ok(cursor.expect_digits(4)? as i32),
+    }
+}
+
+/// Parses two digits, returning an error of the given kind if they are out of `range`.
+fn two_digits(
+    cursor: &mut Cursor<'_>,
+    range: std::ops::RangeInclusive<u8>,
+    kind: ParseErrorKind,
+) -> Result<u8, ParseError> {
+    let position = cursor.position();
+    let value = cursor.expect_digits(2)? as u8;
+    if range.contains(&value) {
+        Ok(value)
+    } else {
+        Err(ParseError::new(kind, position))
+    }
+}
+
+/// Parses a date, as `YYYY-MM-DD`, `YYYYMMDD` or with an expanded year as `±YYYYYY-MM-DD`.
+fn date(cursor: &mut Cursor<'_>) -> Result<(i32, u8, u8), ParseError> {
+    let year = year(cursor)?;
+    let extended = cursor.next_if(b'-');
+    let month = two_digits(cursor, 1..=12, ParseErrorKind::InvalidMonth)?;
+    if extended {
+        cursor.expect(b'-')?;
+    }
+    let day = two_digits(
+        cursor,
+        1..=iso_days_in_month(year, month),
+        ParseErrorKind::InvalidDay,
+    )?;
+    Ok((year, month, day))
+}
+
+/// Parses a time, as `HH`, `HH:mm`, `HH:mm:ss`, `HHmmss` or with a fraction of the second.
+fn time(cursor: &mut Cursor<'_>) -> Result<ParsedTime, ParseError> {
+    let mut time = ParsedTime {
+        hour: two_digits(cursor, 0..=23, ParseErrorKind::InvalidHour)?,
+        ..ParsedTime::default()
+    };
+
+    let extended = cursor.next_if(b':');
+    if extended || cursor.peek().map_or(false, |byte| byte.is_ascii_digit()) {
+        time.minute = two_digits(cursor, 0..=59, ParseErrorKind::InvalidMinute)?;
+        let has_seconds = if extended {
+            cursor.next_if(b':')
+        } else {
+            cursor.peek().map_or(false, |byte| byte.is_ascii_digit())
+        };
+        if has_seconds {
+            time.second = two_digits(cursor, 0..=60, ParseErrorKind::InvalidSecond)?;
+            if cursor.next_if(b'.') || cursor.next_if(b',') {
+                time.subsecond_nanoseconds = fraction(cursor, false)?;
+            }
+        }
+    }
+
+    // A leap second is treated as the last second of the minute.
+    time.second = time.second.min(59);
+    Ok(time)
+}
+
+/// Parses the digits of a fraction, after the decimal separator, in nanoseconds.
+///
+/// The digits after the ninth one are ignored if `truncate` is `true`, and an error otherwise.
+fn fraction(cursor: &mut Cursor<'_>, truncate: bool) -> Result<u32, ParseError> {
+    let position = cursor.position();
+    let digits = cursor.digit_run();
+    if digits.is_empty() {
+        return Err(cursor.error(ParseErrorKind::ExpectedDigit));
+    }
+    if digits.len() > 9 && !truncate {
+        return Err(ParseError::new(ParseErrorKind::InvalidFraction, position));
+    }
+    Ok(digits
+        .iter()
+        .chain(std::iter::repeat(&b'0'))
+        .take(9)
+        .fold(0, |value, digit| value * 10 + u32::from(digit - b'0')))
+}
+
+/// Parses a UTC offset, as `±HH`, `±HH:mm`, `±HHmm`, or with seconds and a fraction of the
+/// second, returning it in nanoseconds.
+fn utc_offset(cursor: &mut Cursor<'_>) -> Result<i64, ParseError> {
+    let sign = match cursor.peek() {
+        Some(b'+') => 1,
+        Some(b'-') => -1,
+        _ => return Err(cursor.error(ParseErrorKind::ExpectedCharacter('+'))),
+    };
+    cursor.next_byte();
+    let hours = i64::from(two_digits(cursor, 0..=23, ParseErrorKind::InvalidHour)?);
+    let mut minutes = 0;
+    let mut seconds = 0;
+    let mut nanoseconds = 0;
+
+    let extended = cursor.next_if(b':');
+    if extended || cursor.peek().map_or(false, |byte| byte.is_ascii_digit()) {
+        minutes = i64::from(two_digits(cursor, 0..=59, ParseErrorKind::InvalidMinute)?);
+        let has_seconds = if extended {
+            cursor.next_if(b':')
+        } else {
+            cursor.peek().map_or(false, |byte| byte.is_ascii_digit())
+        };
+        if has_seconds {
+            seconds = i64::from(two_digits(cursor, 0..=59, ParseErrorKind::InvalidSecond)?);
+            if cursor.next_if(b'.') || cursor.next_if(b',') {
+                nanoseconds = i64::from(fraction(cursor, false)?);
+            }
+        }
+    }
+
+    Ok(sign * (((hours * 60 + minutes) * 60 + seconds) * 1_000_000_000 + nanoseconds))
+}
+
+/// Returns `true` if the content of a time zone annotation is a time zone name, made of
+/// components like `Europe` or `GMT+1` separated by `/`, or a UTC offset without seconds.
+fn is_valid_time_zone(content: &str) -> bool {
+    if matches!(content.as_bytes().first(), Some(b'+' | b'-')) {
+        return parse_utc_offset(content).map_or(false, |offset| offset % NS_PER_MINUTE == 0)
+            && content.len() <= 6;
+    }
+    content.split('/').all(|component| {
+        let leading = component.bytes().next().map_or(false, |byte| {
+            byte.is_ascii_alphabetic() || matches!(byte, b'.' | b'_')
+        });
+        leading
+            && component != "."
+            && component != ".."
+            && component.bytes().all(|byte| {
+                byte.is_ascii_alphanumeric() || matches!(byte, b'.' | b'_' | b'-' | b'+')
+            })
+    })
+}
+
+/// Parses the annotations after a date-time, returning the time zone and the calendar.
+///
+/// Unknown annotations are ignored, unless they are marked as critical with a `!`.
+fn annotations(cursor: &mut Cursor<'_>) -> Result<(Option<String>, Option<String>), ParseError> {
+    let mut time_zone = None;
+    let mut calendar = None;
+    let mut calendar_critical = false;
+    let mut calendar_count = 0;
+    let mut first = true;
+
+    while cursor.next_if(b'[') {
+        let position = cursor.position();
+        let critical = cursor.next_if(b'!');
+        let mut content = Vec::new();
+        loop {
+            match cursor.next_byte() {
+                Some(b']') => break,
+                Some(byte) => content.push(byte),
+                None => return Err(cursor.error(ParseErrorKind::ExpectedCharacter(']'))),
+            }
+        }
+        let content = String::from_utf8(content)
+            .map_err(|_| ParseError::new(ParseErrorKind::InvalidAnnotation, position))?;
+
+        match content.split_once('=') {
+            // The time zone annotation can only be the first annotation.
+            None => {
+                if !first {
+                    return Err(ParseError::new(ParseErrorKind::MisplacedTimeZone, position));
+                }
+                if !is_valid_time_zone(&content) {
+                    return Err(ParseError::new(ParseErrorKind::InvalidTimeZone, position));
+                }
+                time_zone = Some(content);
+            }
+            Some((key, value)) => {
+                let valid_key = key.bytes().enumerate().all(|(index, byte)| {
+                    byte.is_ascii_lowercase()
+                        || byte == b'_'
+                        || (index > 0 && (byte.is_ascii_digit() || byte == b'-'))
+                });
+                let valid_value = value.split('-').all(|component| {
+                    !component.is_empty()
+                        && component.bytes().all(|byte| byte.is_ascii_alphanumeric())
+                });
+                if !valid_key || key.is_empty() || !valid_value {
+                    return Err(ParseError::new(ParseErrorKind::InvalidAnnotation, position));
+                }
+                if key == "u-ca" {
+                    calendar_count += 1;
+                    calendar_critical |= critical;
+                    if calendar.is_none() {
+                        calendar = Some(value.to_owned());
+                    }
+                } else if critical {
+                    return Err(ParseError::new(
+                        ParseErrorKind::CriticalAnnotation,
+                        position,
+                    ));
+                }
+            }
+        }
+
+        // Several calendar annotations are only allowed if none of them is critical.
+        if calendar_count > 1 && calendar_critical {
+            return Err(ParseError::new(
+                ParseErrorKind::ConflictingCalendars,
+                position,
+            ));
+        }
+        first = false;
+    }
+
+    Ok((time_zone, calendar))
+}
+
+/// Parses an ISO 8601 duration string, such as `P1Y2M3DT4H5M6.5S`, returning the values of the
+/// years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds and
+/// nanoseconds.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-parsetemporaldurationstring
+pub(crate) fn parse_duration(text: &str) -> Result<[f64; 10], ParseError> {
+    let mut cursor = Cursor::new(text);
+    let sign = match cursor.peek() {
+        Some(b'+') => {
+            cursor.next_byte();
+            1.0
+        }
+        Some(b'-') => {
+            cursor.next_byte();
+            -1.0
+        }
+        _ => 1.0,
+    };
+    if !(cursor.next_if(b'P') || cursor.next_if(b'p')) {
+        return Err(cursor.error(ParseErrorKind::ExpectedCharacter('P')));
+    }
+
+    let mut values = [0.0; 10];
+    let mut in_time = false;
+    // The index of the last unit, as the units must be in decreasing order.
+    let mut last_index = None;
+
+    while !cursor.is_done() {
+        if !in_time && (cursor.next_if(b'T') || cursor.next_if(b't')) {
+            in_time = true;
+            // At least one unit is required after the time designator.
+            if cursor.is_done() {
+                return Err(cursor.error(ParseErrorKind::ExpectedDigit));
+            }
+            continue;
+        }
+
+        let digits = cursor.digit_run();
+        if digits.is_empty() {
+            return Err(cursor.error(ParseErrorKind::ExpectedDigit));
+        }
+        let value: f64 = std::str::from_utf8(digits)
+            .ok()
+            .and_then(|digits| digits.parse().ok())
+            .expect("a run of digits must be a valid number");
+        let fraction_position = cursor.position();
+        let fraction = if cursor.next_if(b'.') || cursor.next_if(b',') {
+            Some(fraction(&mut cursor, false)?)
+        } else {
+            None
+        };
+
+        let unit_position = cursor.position();
+        let unit = match cursor.next_byte() {
+            Some(unit) => unit.to_ascii_uppercase(),
+            None => return Err(cursor.error(ParseErrorKind::UnexpectedEnd)),
+        };
+        let index = match (in_time, unit) {
+            (false, b'Y') => 0,
+            (false, b'M') => 1,
+            (false, b'W') => 2,
+            (false, b'D') => 3,
+            (true, b'H') => 4,
+            (true, b'M') => 5,
+            (true, b'S') => 6,
+            _ => {
+                return Err(ParseError::new(
+                    ParseErrorKind::UnexpectedCharacter,
+                    unit_position,
+                ))
+            }
+        };
+        if last_index.map_or(false, |last| index <= last) {
+            return Err(ParseError::new(
+                ParseErrorKind::DurationUnitOrder,
+                unit_position,
+            ));
+        }
+        last_index = Some(index);
+        values[index] = value;
+
+        if let Some(fraction) = fraction {
+            // Only the time units can have a fraction, and it must be on the last unit.
+            if !in_time || !cursor.is_done() {
+                return Err(ParseError::new(
+                    ParseErrorKind::DurationFraction,
+                    fraction_position,
+                ));
+            }
+
+            // The fraction is balanced into the smaller units.
+            let unit_seconds: i64 = match index {
+                4 => 3600,
+                5 => 60,
+                _ => 1,
+            };
+            let mut nanoseconds = i64::from(fraction) * unit_seconds;
+            for (index, unit_nanoseconds) in [
+                (5, 60_000_000_000),
+                (6, 1_000_000_000),
+                (7, 1_000_000),
+                (8, 1000),
+                (9, 1),
+            ] {
+                if index > last_index.expect("a unit was parsed") {
+                    values[index] = (nanoseconds / unit_nanoseconds) as f64;
+                    nanoseconds %= unit_nanoseconds;
+                }
+            }
+        }
+    }
+
+    if last_index.is_none() {
+        return Err(ParseError::new(
+            ParseErrorKind::EmptyDuration,
+            cursor.position(),
+        ));
+    }
+    // Adding `+0` turns `-0` into `0`.
+    Ok(values.map(|value| value * sign + 0.0))
+}
@@ -0,0 +1,214 @@
+use super::{
+    parse_date_time, parse_date_time_string_format, parse_duration, parse_time_string,
+    parse_utc_offset, ParseError, ParseErrorKind, ParsedDateTime, ParsedTime, UtcOffset,
+};
+
+fn error(kind: ParseErrorKind, position: usize) -> ParseError {
+    ParseError::new(kind, position)
+}
+
+#[test]
+fn date_time_string_format() {
+    assert_eq!(
+        parse_date_time_string_format("2020-02"),
+        Ok(ParsedDateTime {
+            year: 2020,
+            month: 2,
+            day: 1,
+            time: None,
+            offset: None,
+            time_zone: None,
+            calendar: None,
+        })
+    );
+
+    let parsed = parse_date_time_string_format("+002020-02-29T24:00:00.0000000001-01:30").unwrap();
+    assert_eq!(parsed.year, 2020);
+    assert_eq!(
+        parsed.time,
+        Some(ParsedTime {
+            hour: 24,
+            ..ParsedTime::default()
+        })
+    );
+    assert_eq!(parsed.offset, Some(UtcOffset::Offset(-90 * 60_000_000_000)));
+
+    for (text, expected) in [
+        ("", error(ParseErrorKind::UnexpectedEnd, 0)),
+        ("-000000", error(ParseErrorKind::InvalidYear, 1)),
+        ("2020-13", error(ParseErrorKind::InvalidMonth, 5)),
+        ("2021-02-29", error(ParseErrorKind::InvalidDay, 8)),
+        (
+            "2020-01-01T1200",
+            error(ParseErrorKind::ExpectedCharacter(':'), 13),
+        ),
+        ("2020-01-01T24:01", error(ParseErrorKind::InvalidHour, 11)),
+        (
+            "2020-01-01t12:00",
+            error(ParseErrorKind::UnexpectedCharacter, 10),
+        ),
+        (
+            "2020-01-01T12:00+0100",
+            error(ParseErrorKind::ExpectedCharacter(':'), 19),
+        ),
+        ("20200101", error(ParseErrorKind::UnexpectedCharacter, 4)),
+    ] {
+        assert_eq!(parse_date_time_string_format(text), Err(expected), "{text}");
+    }
+}
+
+#[test]
+fn date_time() {
+    assert_eq!(
+        parse_date_time("20200101t120000,5+0100[Europe/Paris][u-ca=hebrew][u-ca=gregory]"),
+        Ok(ParsedDateTime {
+            year: 2020,
+            month: 1,
+            day: 1,
+            time: Some(ParsedTime {
+                hour: 12,
+                minute: 0,
+                second: 0,
+                subsecond_nanoseconds: 500_000_000,
+            }),
+            offset: Some(UtcOffset::Offset(3_600_000_000_000)),
+            time_zone: Some("Europe/Paris".to_owned()),
+            calendar: Some("hebrew".to_owned()),
+        })
+    );
+
+    let parsed = parse_date_time("2016-12-31T23:59:60Z[+05:30][foo=bar-baz]").unwrap();
+    assert_eq!(parsed.time.map(|time| time.second), Some(59));
+    assert_eq!(parsed.offset, Some(UtcOffset::Z));
+    assert_eq!(parsed.time_zone.as_deref(), Some("+05:30"));
+
+    for (text, expected) in [
+        (
+            "2020-0101",
+            error(ParseErrorKind::ExpectedCharacter('-'), 7),
+        ),
+        ("2020-01-01T25:00", error(ParseErrorKind::InvalidHour, 11)),
+        (
+            "2020-01-01T12:00:00.0000000001",
+            error(ParseErrorKind::InvalidFraction, 20),
+        ),
+        (
+            "2020-01-01Z",
+            error(ParseErrorKind::UnexpectedCharacter, 10),
+        ),
+        ("2020-01-01[UTC", error(ParseErrorKind::UnexpectedEnd, 14)),
+        (
+            "2020-01-01[Europe/../Paris]",
+            error(ParseErrorKind::InvalidTimeZone, 11),
+        ),
+        (
+            "2020-01-01[+01:00:00]",
+            error(ParseErrorKind::InvalidTimeZone, 11),
+        ),
+        (
+            "2020-01-01[u-ca=iso8601][UTC]",
+            error(ParseErrorKind::MisplacedTimeZone, 25),
+        ),
+        (
+            "2020-01-01[Foo=bar]",
+            error(ParseErrorKind::InvalidAnnotation, 11),
+        ),
+        (
+            "2020-01-01[u-ca=]",
+            error(ParseErrorKind::InvalidAnnotation, 11),
+        ),
+        (
+            "2020-01-01[!foo=bar]",
+            error(ParseErrorKind::CriticalAnnotation, 11),
+        ),
+        (
+            "2020-01-01[u-ca=iso8601][!u-ca=hebrew]",
+            error(ParseErrorKind::ConflictingCalendars, 25),
+        ),
+    ] {
+        assert_eq!(parse_date_time(text), Err(expected), "{text}");
+    }
+}
+
+#[test]
+fn time() {
+    for (text, expected) in [
+        ("12", (12, 0, 0, 0)),
+        ("T1230", (12, 30, 0, 0)),
+        (
+            "12:30:45.123456789-08:00[America/Los_Angeles]",
+            (12, 30, 45, 123_456_789),
+        ),
+        ("2020-01-01T12:30", (12, 30, 0, 0)),
+    ] {
+        let (hour, minute, second, subsecond_nanoseconds) = expected;
+        assert_eq!(
+            parse_time_string(text),
+            Ok(ParsedTime {
+                hour,
+                minute,
+                second,
+                subsecond_nanoseconds,
+            }),
+            "{text}"
+        );
+    }
+
+    for (text, expected) in [
+        ("1214", error(ParseErrorKind::AmbiguousTime, 0)),
+        ("2021-12", error(ParseErrorKind::AmbiguousTime, 0)),
+        ("12:00Z", error(ParseErrorKind::UtcDesignator, 5)),
+        (
+            "2020-01-01T12:00Z",
+            error(ParseErrorKind::UtcDesignator, 16),
+        ),
+        ("2020-01-01", error(ParseErrorKind::MissingTime, 10)),
+        ("12:60", error(ParseErrorKind::InvalidMinute, 3)),
+    ] {
+        assert_eq!(parse_time_string(text), Err(expected), "{text}");
+    }
+}
+
+#[test]
+fn utc_offset() {
+    assert_eq!(parse_utc_offset("+01"), Ok(3_600_000_000_000));
+    assert_eq!(parse_utc_offset("-0130"), Ok(-5_400_000_000_000));
+    assert_eq!(parse_utc_offset("+00:00:01.5"), Ok(1_500_000_000));
+    assert_eq!(
+        parse_utc_offset("01:00"),
+        Err(error(ParseErrorKind::ExpectedCharacter('+'), 0))
+    );
+    assert_eq!(
+        parse_utc_offset("+24:00"),
+        Err(error(ParseErrorKind::InvalidHour, 1))
+    );
+    assert_eq!(
+        parse_utc_offset("+01:00:00:00"),
+        Err(error(ParseErrorKind::UnexpectedCharacter, 9))
+    );
+}
+
+#[test]
+fn duration() {
+    assert_eq!(
+        parse_duration("-P1Y2M3W4DT5H6M7.008009010S"),
+        Ok([-1.0, -2.0, -3.0, -4.0, -5.0, -6.0, -7.0, -8.0, -9.0, -10.0])
+    );
+    assert_eq!(
+        parse_duration("PT1.5H"),
+        Ok([0.0, 0.0, 0.0, 0.0, 1.0, 30.0, 0.0, 0.0, 0.0, 0.0])
+    );
+
+    for (text, expected) in [
+        ("1Y", error(ParseErrorKind::ExpectedCharacter('P'), 0)),
+        ("P", error(ParseErrorKind::EmptyDuration, 1)),
+        ("PT", error(ParseErrorKind::UnexpectedEnd, 2)),
+        ("P1", error(ParseErrorKind::UnexpectedEnd, 2)),
+        ("P1D2Y", error(ParseErrorKind::DurationUnitOrder, 4)),
+        ("P1H", error(ParseErrorKind::UnexpectedCharacter, 2)),
+        ("P1.5D", error(ParseErrorKind::DurationFraction, 2)),
+        ("PT1.5H2M", error(ParseErrorKind::DurationFraction, 3)),
+    ] {
+        assert_eq!(parse_duration(text), Err(expected), "{text}");
+    }
+}
@@ -1,13 +1,17 @@
+#[cfg(feature = "intl")]
+use crate::builtins::intl::segmenter::SegmentIterator;
 use crate::{
     builtins::{
+        iterator::{iterator_helper::IteratorHelper, IteratorWrapper},
         regexp::regexp_string_iterator::RegExpStringIterator,
-        string::string_iterator::StringIterator, ArrayIterator, ForInIterator, MapIterator,
-        SetIterator,
+        string::string_iterator::StringIterator,
+        ArrayIterator, ForInIterator, MapIterator, Number, SetIterator,
     },
-    object::{JsObject, ObjectInitializer},
+    object::JsObject,
     symbol::WellKnownSymbols,
     Context, JsResult, JsValue,
 };
+use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
 
 #[derive(Debug, Default)]
@@ -26,13 +30,22 @@ pub struct IteratorPrototypes {
     map_iterator: JsObject,
     /// %ForInIteratorPrototype%
     for_in_iterator: JsObject,
+    /// %IteratorHelperPrototype%
+    iterator_helper: JsObject,
+    /// %WrapForValidIteratorPrototype%
+    wrap_for_valid_iterator: JsObject,
+    /// %SegmentIteratorPrototype%
+    #[cfg(feature = "intl")]
+    segment_iterator: JsObject,
 }
 
 impl IteratorPrototypes {
     pub(crate) fn init(context: &mut Context) -> Self {
         let _timer = Profiler::global().start_event("IteratorPrototypes::init", "init");
 
-        let iterator_prototype = create_iterator_prototype(context);
+        // %IteratorPrototype% is the prototype of the `Iterator` constructor, its methods are
+        // added when the `Iterator` builtin is initialized.
+        let iterator_prototype = context.intrinsics().constructors().iterator().prototype();
         Self {
             array_iterator: ArrayIterator::create_prototype(iterator_prototype.clone(), context),
             set_iterator: SetIterator::create_prototype(iterator_prototype.clone(), context),
@@ -43,6 +56,16 @@ impl IteratorPrototypes {
             ),
             map_iterator: MapIterator::create_prototype(iterator_prototype.clone(), context),
             for_in_iterator: ForInIterator::create_prototype(iterator_prototype.clone(), context),
+            iterator_helper: IteratorHelper::create_prototype(iterator_prototype.clone(), context),
+            wrap_for_valid_iterator: IteratorWrapper::create_prototype(
+                iterator_prototype.clone(),
+                context,
+            ),
+            #[cfg(feature = "intl")]
+            segment_iterator: SegmentIterator::create_prototype(
+                iterator_prototype.clone(),
+                context,
+            ),
             iterator_prototype,
         }
     }
@@ -81,6 +104,22 @@ impl IteratorPrototypes {
     pub fn for_in_iterator(&self) -> JsObject {
         self.for_in_iterator.clone()
     }
+
+    #[inline]
+    pub fn iterator_helper(&self) -> JsObject {
+        self.iterator_helper.clone()
+    }
+
+    #[inline]
+    pub fn wrap_for_valid_iterator(&self) -> JsObject {
+        self.wrap_for_valid_iterator.clone()
+    }
+
+    #[cfg(feature = "intl")]
+    #[inline]
+    pub fn segment_iterator(&self) -> JsObject {
+        self.segment_iterator.clone()
+    }
 }
 
 /// `CreateIterResultObject( value, done )`
@@ -175,27 +214,6 @@ impl JsValue {
     }
 }
 
-/// Create the `%IteratorPrototype%` object
-///
-/// More information:
-///  - [ECMA reference][spec]
-///
-/// [spec]: https://tc39.es/ecma262/#sec-%iteratorprototype%-object
-#[inline]
-fn create_iterator_prototype(context: &mut Context) -> JsObject {
-    let _timer = Profiler::global().start_event("Iterator Prototype", "init");
-
-    let symbol_iterator = WellKnownSymbols::iterator();
-    let iterator_prototype = ObjectInitializer::new(context)
-        .function(
-            |v, _, _| Ok(v.clone()),
-            (symbol_iterator, "[Symbol.iterator]"),
-            0,
-        )
-        .build();
-    iterator_prototype
-}
-
 #[derive(Debug)]
 pub struct IteratorResult {
     object: JsObject,
@@ -233,7 +251,7 @@ impl IteratorResult {
 ///  - [ECMA reference][spec]
 ///
 /// [spec]:https://tc39.es/ecma262/#table-iterator-record-fields
-#[derive(Debug)]
+#[derive(Debug, Clone, Trace, Finalize)]
 pub struct IteratorRecord {
     /// `[[Iterator]]`
     ///
@@ -412,6 +430,110 @@ pub(crate) fn iterable_to_list(
     Ok(values)
 }
 
+/// The key coercion used by [`group_by`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum GroupKeyCoercion {
+    /// Keys are converted to property keys, as used by `Object.groupBy`.
+    Property,
+    /// Keys are kept as is, except that `-0` is normalized to `+0`, as used by `Map.groupBy`.
+    Zero,
+}
+
+/// `GroupBy ( items, callbackfn, keyCoercion )`
+///
+/// Groups the values produced by iterating `items` by the key returned from `callback`. Keys are
+/// compared using `SameValue`, and groups are returned in insertion order.
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+///  [spec]: https://tc39.es/ecma262/#sec-groupby
+pub(crate) fn group_by(
+    items: &JsValue,
+    callback: &JsValue,
+    key_coercion: GroupKeyCoercion,
+    context: &mut Context,
+) -> JsResult<Vec<(JsValue, Vec<JsValue>)>> {
+    // 1. Perform ? RequireObjectCoercible(items).
+    items.require_object_coercible(context)?;
+
+    // 2. If IsCallable(callbackfn) is false, throw a TypeError exception.
+    let callback = callback.as_callable().ok_or_else(|| {
+        context.construct_type_error("GroupBy: callback must be a callable object")
+    })?;
+
+    // 3. Let groups be a new empty List.
+    let mut groups: Vec<(JsValue, Vec<JsValue>)> = Vec::new();
+
+    // 4. Let iteratorRecord be ? GetIterator(items, sync).
+    let iterator_record = items.get_iterator(context, Some(IteratorHint::Sync), None)?;
+
+    // 5. Let k be 0.
+    let mut k = 0u64;
+
+    // 6. Repeat,
+    loop {
+        // a. If k ≥ 2^53 - 1, then
+        if k >= Number::MAX_SAFE_INTEGER as u64 {
+            // i. Let error be ThrowCompletion(a newly created TypeError object).
+            let error = context.construct_type_error("GroupBy: too many elements");
+            // ii. Return ? IteratorClose(iteratorRecord, error).
+            iterator_record.close(Err(error.clone()), context)?;
+            return Err(error);
+        }
+
+        // b. Let next be ? IteratorStep(iteratorRecord).
+        // c. If next is false, then
+        //     i. Return groups.
+        let next = match iterator_record.step(context)? {
+            Some(next) => next,
+            None => return Ok(groups),
+        };
+
+        // d. Let value be ? IteratorValue(next).
+        let value = next.value(context)?;
+
+        // e. Let key be Completion(Call(callbackfn, undefined, « value, 𝔽(k) »)).
+        let key = callback.call(&JsValue::undefined(), &[value.clone(), k.into()], context);
+
+        let key = key.and_then(|key| match key_coercion {
+            // g. If keyCoercion is property, then
+            //     i. Set key to Completion(ToPropertyKey(key)).
+            GroupKeyCoercion::Property => key.to_property_key(context).map(JsValue::from),
+            // h. Else,
+            //     i. Assert: keyCoercion is zero.
+            //     ii. If key is -0𝔽, set key to +0𝔽.
+            GroupKeyCoercion::Zero => match key {
+                JsValue::Rational(n) if n == 0.0 => Ok(JsValue::Integer(0)),
+                key => Ok(key),
+            },
+        });
+
+        // f. IfAbruptCloseIterator(key, iteratorRecord).
+        // g. ii. IfAbruptCloseIterator(key, iteratorRecord).
+        let key = match key {
+            Ok(key) => key,
+            Err(err) => {
+                iterator_record.close(Err(err.clone()), context)?;
+                return Err(err);
+            }
+        };
+
+        // i. Perform AddValueToKeyedGroup(groups, key, value).
+        if let Some((_, elements)) = groups
+            .iter_mut()
+            .find(|(group_key, _)| JsValue::same_value(group_key, &key))
+        {
+            elements.push(value);
+        } else {
+            groups.push((key, vec![value]));
+        }
+
+        // j. Set k to k + 1.
+        k += 1;
+    }
+}
+
 /// A shorthand for a sequence of algorithm steps that use an Iterator Record
 ///
 /// More information:
@@ -0,0 +1,436 @@
+//! This module implements the Iterator Helper objects returned by the lazy methods of
+//! `%Iterator.prototype%`, such as `map` and `filter`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-iterator-helper-objects
+
+use super::get_iterator_flattenable;
+use crate::{
+    builtins::{
+        function::make_builtin_fn,
+        iterable::{create_iter_result_object, IteratorRecord},
+    },
+    object::{JsObject, ObjectData},
+    property::PropertyDescriptor,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// The state of an Iterator Helper, mirroring the `[[GeneratorState]]` of the generator the
+/// specification uses to define it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum HelperState {
+    SuspendedStart,
+    SuspendedYield,
+    Executing,
+    Completed,
+}
+
+/// The closure of an Iterator Helper, along with the state captured by it.
+#[derive(Debug, Clone, Finalize, Trace)]
+pub(crate) enum HelperKind {
+    /// `Iterator.prototype.map`
+    Map { mapper: JsObject, counter: usize },
+    /// `Iterator.prototype.filter`
+    Filter { predicate: JsObject, counter: usize },
+    /// `Iterator.prototype.take`
+    Take { remaining: f64 },
+    /// `Iterator.prototype.drop`
+    Drop { remaining: f64 },
+    /// `Iterator.prototype.flatMap`
+    FlatMap {
+        mapper: JsObject,
+        counter: usize,
+        inner: Option<IteratorRecord>,
+    },
+}
+
+/// The internal representation of an Iterator Helper object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-iterator-helper-objects
+#[derive(Debug, Clone, Finalize, Trace)]
+pub struct IteratorHelper {
+    /// The `[[UnderlyingIterator]]` internal slot.
+    underlying: IteratorRecord,
+
+    /// The closure producing the values of the helper.
+    kind: HelperKind,
+
+    /// The `[[GeneratorState]]` internal slot.
+    #[unsafe_ignore_trace]
+    state: HelperState,
+}
+
+impl IteratorHelper {
+    pub(crate) const NAME: &'static str = "Iterator Helper";
+
+    /// Creates a new Iterator Helper over `underlying`.
+    pub(crate) fn create(
+        underlying: IteratorRecord,
+        kind: HelperKind,
+        context: &Context,
+    ) -> JsObject {
+        JsObject::from_proto_and_data(
+            context
+                .intrinsics()
+                .objects()
+                .iterator_prototypes()
+                .iterator_helper(),
+            ObjectData::iterator_helper(Self {
+                underlying,
+                kind,
+                state: HelperState::SuspendedStart,
+            }),
+        )
+    }
+
+    /// `%IteratorHelperPrototype%.next ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%iteratorhelperprototype%.next
+    pub(crate) fn next(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? GeneratorResume(this value, empty, "Iterator Helper").
+        let (underlying, mut kind) = {
+            let mut object = this.as_object().map(JsObject::borrow_mut);
+            let helper = object
+                .as_mut()
+                .and_then(|obj| obj.as_iterator_helper_mut())
+                .ok_or_else(|| context.construct_type_error("`this` is not an Iterator Helper"))?;
+            match helper.state {
+                HelperState::Executing => {
+                    return context.throw_type_error("Iterator Helper is already running")
+                }
+                HelperState::Completed => {
+                    return Ok(create_iter_result_object(
+                        JsValue::undefined(),
+                        true,
+                        context,
+                    ))
+                }
+                HelperState::SuspendedStart | HelperState::SuspendedYield => {}
+            }
+            helper.state = HelperState::Executing;
+            (helper.underlying.clone(), helper.kind.clone())
+        };
+
+        // The closure runs without holding a borrow of the helper, since it calls user code
+        // that may access the helper again.
+        let result = kind.step(&underlying, context);
+
+        let mut object = this.as_object().map(JsObject::borrow_mut);
+        let helper = object
+            .as_mut()
+            .and_then(|obj| obj.as_iterator_helper_mut())
+            .expect("the helper was checked above");
+        match result {
+            Ok(Some(value)) => {
+                helper.kind = kind;
+                helper.state = HelperState::SuspendedYield;
+                drop(object);
+                Ok(create_iter_result_object(value, false, context))
+            }
+            Ok(None) => {
+                helper.state = HelperState::Completed;
+                drop(object);
+                Ok(create_iter_result_object(
+                    JsValue::undefined(),
+                    true,
+                    context,
+                ))
+            }
+            Err(err) => {
+                helper.state = HelperState::Completed;
+                Err(err)
+            }
+        }
+    }
+
+    /// `%IteratorHelperPrototype%.return ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%iteratorhelperprototype%.return
+    pub(crate) fn r#return(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[UnderlyingIterator]]).
+        // 3. Assert: O has a [[GeneratorState]] slot.
+        let (underlying, kind, state) = {
+            let mut object = this.as_object().map(JsObject::borrow_mut);
+            let helper = object
+                .as_mut()
+                .and_then(|obj| obj.as_iterator_helper_mut())
+                .ok_or_else(|| context.construct_type_error("`this` is not an Iterator Helper"))?;
+            let state = helper.state;
+            if state != HelperState::Executing {
+                helper.state = HelperState::Completed;
+            }
+            (helper.underlying.clone(), helper.kind.clone(), state)
+        };
+
+        match state {
+            // 4. If O.[[GeneratorState]] is suspended-start, then
+            HelperState::SuspendedStart => {
+                // a. Set O.[[GeneratorState]] to completed.
+                // b. Perform ? IteratorClose(O.[[UnderlyingIterator]], NormalCompletion(unused)).
+                underlying.close(Ok(JsValue::undefined()), context)?;
+            }
+            // 5. Let C be Completion { [[Type]]: return, [[Value]]: undefined, [[Target]]: empty }.
+            // 6. Return ? GeneratorResumeAbrupt(O, C, "Iterator Helper").
+            HelperState::SuspendedYield => {
+                // The closure is suspended at a yield, so the return completion closes the
+                // iterators it is currently using.
+                if let HelperKind::FlatMap {
+                    inner: Some(inner), ..
+                } = kind
+                {
+                    let completion = inner.close(Ok(JsValue::undefined()), context);
+                    if let Err(err) = completion {
+                        return underlying.close(Err(err), context);
+                    }
+                }
+                underlying.close(Ok(JsValue::undefined()), context)?;
+            }
+            HelperState::Executing => {
+                return context.throw_type_error("Iterator Helper is already running");
+            }
+            HelperState::Completed => {}
+        }
+
+        // c. Return CreateIterResultObject(undefined, true).
+        Ok(create_iter_result_object(
+            JsValue::undefined(),
+            true,
+            context,
+        ))
+    }
+
+    /// Create the `%IteratorHelperPrototype%` object
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%iteratorhelperprototype%-object
+    pub(crate) fn create_prototype(
+        iterator_prototype: JsObject,
+        context: &mut Context,
+    ) -> JsObject {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        // Create prototype
+        let iterator_helper =
+            JsObject::from_proto_and_data(iterator_prototype, ObjectData::ordinary());
+        make_builtin_fn(Self::next, "next", &iterator_helper, 0, context);
+        make_builtin_fn(Self::r#return, "return", &iterator_helper, 0, context);
+
+        let to_string_tag = WellKnownSymbols::to_string_tag();
+        let to_string_tag_property = PropertyDescriptor::builder()
+            .value(Self::NAME)
+            .writable(false)
+            .enumerable(false)
+            .configurable(true);
+        iterator_helper.insert(to_string_tag, to_string_tag_property);
+        iterator_helper
+    }
+}
+
+impl HelperKind {
+    /// Resumes the closure of the helper until it yields a value, returning `None` once the
+    /// underlying iterator is exhausted.
+    fn step(
+        &mut self,
+        underlying: &IteratorRecord,
+        context: &mut Context,
+    ) -> JsResult<Option<JsValue>> {
+        match self {
+            Self::Map { mapper, counter } => {
+                // a. Repeat,
+                //     i. Let value be ? IteratorStepValue(iterated).
+                //     ii. If value is done, return undefined.
+                let value = if let Some(next) = underlying.step(context)? {
+                    next.value(context)?
+                } else {
+                    return Ok(None);
+                };
+
+                // iii. Let mapped be Completion(Call(mapper, undefined, « value, 𝔽(counter) »)).
+                let mapped =
+                    mapper.call(&JsValue::undefined(), &[value, (*counter).into()], context);
+
+                // iv. IfAbruptCloseIterator(mapped, iterated).
+                let mapped = close_if_abrupt(mapped, underlying, context)?;
+
+                // vi. Set counter to counter + 1.
+                *counter += 1;
+
+                // v. Let completion be Completion(Yield(mapped)).
+                Ok(Some(mapped))
+            }
+            Self::Filter { predicate, counter } => {
+                // a. Repeat,
+                loop {
+                    // i. Let value be ? IteratorStepValue(iterated).
+                    // ii. If value is done, return undefined.
+                    let value = if let Some(next) = underlying.step(context)? {
+                        next.value(context)?
+                    } else {
+                        return Ok(None);
+                    };
+
+                    // iii. Let selected be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+                    let selected = predicate.call(
+                        &JsValue::undefined(),
+                        &[value.clone(), (*counter).into()],
+                        context,
+                    );
+
+                    // iv. IfAbruptCloseIterator(selected, iterated).
+                    let selected = close_if_abrupt(selected, underlying, context)?;
+
+                    // vi. Set counter to counter + 1.
+                    *counter += 1;
+
+                    // v. If ToBoolean(selected) is true, then
+                    //     1. Let completion be Completion(Yield(value)).
+                    if selected.to_boolean() {
+                        return Ok(Some(value));
+                    }
+                }
+            }
+            Self::Take { remaining } => {
+                // a. Let remaining be integerLimit.
+                // b. Repeat,
+                //     i. If remaining is 0, then
+                if *remaining == 0.0 {
+                    // 1. Return ? IteratorClose(iterated, ReturnCompletion(undefined)).
+                    underlying.close(Ok(JsValue::undefined()), context)?;
+                    return Ok(None);
+                }
+
+                // ii. If remaining ≠ +∞, then
+                //     1. Set remaining to remaining - 1.
+                if remaining.is_finite() {
+                    *remaining -= 1.0;
+                }
+
+                // iii. Let value be ? IteratorStepValue(iterated).
+                // iv. If value is done, return ReturnCompletion(undefined).
+                // v. Let completion be Completion(Yield(value)).
+                if let Some(next) = underlying.step(context)? {
+                    next.value(context).map(Some)
+                } else {
+                    Ok(None)
+                }
+            }
+            Self::Drop { remaining } => {
+                // a. Let remaining be integerLimit.
+                // b. Repeat, while remaining > 0,
+                while *remaining > 0.0 {
+                    // i. If remaining ≠ +∞, then
+                    //     1. Set remaining to remaining - 1.
+                    if remaining.is_finite() {
+                        *remaining -= 1.0;
+                    }
+
+                    // ii. Let next be ? IteratorStep(iterated).
+                    // iii. If next is done, return ReturnCompletion(undefined).
+                    if underlying.step(context)?.is_none() {
+                        return Ok(None);
+                    }
+                }
+
+                // c. Repeat,
+                //     i. Let value be ? IteratorStepValue(iterated).
+                //     ii. If value is done, return ReturnCompletion(undefined).
+                //     iii. Let completion be Completion(Yield(value)).
+                if let Some(next) = underlying.step(context)? {
+                    next.value(context).map(Some)
+                } else {
+                    Ok(None)
+                }
+            }
+            Self::FlatMap {
+                mapper,
+                counter,
+                inner,
+            } => loop {
+                // viii. Repeat, while innerAlive is true,
+                if let Some(inner_iterator) = inner {
+                    // 1. Let innerValue be Completion(IteratorStepValue(innerIterator)).
+                    // 2. IfAbruptCloseIterator(innerValue, iterated).
+                    let next = close_if_abrupt(inner_iterator.step(context), underlying, context)?;
+
+                    if let Some(next) = next {
+                        // 4. Else,
+                        //     a. Let completion be Completion(Yield(innerValue)).
+                        let value = close_if_abrupt(next.value(context), underlying, context)?;
+                        return Ok(Some(value));
+                    }
+
+                    // 3. If innerValue is done, then
+                    //     a. Set innerAlive to false.
+                    *inner = None;
+                }
+
+                // a. Repeat,
+                //     i. Let value be ? IteratorStepValue(iterated).
+                //     ii. If value is done, return ReturnCompletion(undefined).
+                let value = if let Some(next) = underlying.step(context)? {
+                    next.value(context)?
+                } else {
+                    return Ok(None);
+                };
+
+                // iii. Let mapped be Completion(Call(mapper, undefined, « value, 𝔽(counter) »)).
+                let mapped =
+                    mapper.call(&JsValue::undefined(), &[value, (*counter).into()], context);
+
+                // iv. IfAbruptCloseIterator(mapped, iterated).
+                let mapped = close_if_abrupt(mapped, underlying, context)?;
+
+                // v. Let innerIterator be Completion(GetIteratorFlattenable(mapped, reject-strings)).
+                // vi. IfAbruptCloseIterator(innerIterator, iterated).
+                let inner_iterator = close_if_abrupt(
+                    get_iterator_flattenable(&mapped, false, context),
+                    underlying,
+                    context,
+                )?;
+
+                // vii. Let innerAlive be true.
+                *inner = Some(inner_iterator);
+
+                // ix. Set counter to counter + 1.
+                *counter += 1;
+            },
+        }
+    }
+}
+
+/// Closes `iterated` if `completion` is an abrupt completion, as done by the
+/// `IfAbruptCloseIterator` macro of the specification.
+fn close_if_abrupt<T>(
+    completion: JsResult<T>,
+    iterated: &IteratorRecord,
+    context: &mut Context,
+) -> JsResult<T> {
+    match completion {
+        Ok(value) => Ok(value),
+        Err(err) => {
+            iterated.close(Err(err.clone()), context)?;
+            Err(err)
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! This module implements the wrapper objects returned by `Iterator.from` for iterators that do
+//! not inherit from `%Iterator.prototype%`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-%wrapforvaliditeratorprototype%-object
+
+use crate::{
+    builtins::{
+        function::make_builtin_fn,
+        iterable::{create_iter_result_object, IteratorRecord},
+    },
+    object::{JsObject, ObjectData},
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+/// The internal representation of a `%WrapForValidIteratorPrototype%` object.
+#[derive(Debug, Clone, Finalize, Trace)]
+pub struct IteratorWrapper {
+    /// The `[[Iterated]]` internal slot.
+    iterated: IteratorRecord,
+}
+
+impl IteratorWrapper {
+    pub(crate) const NAME: &'static str = "WrapForValidIterator";
+
+    /// Wraps `iterated` in an object inheriting from `%WrapForValidIteratorPrototype%`.
+    pub(crate) fn create(iterated: IteratorRecord, context: &Context) -> JsObject {
+        JsObject::from_proto_and_data(
+            context
+                .intrinsics()
+                .objects()
+                .iterator_prototypes()
+                .wrap_for_valid_iterator(),
+            ObjectData::iterator_wrapper(Self { iterated }),
+        )
+    }
+
+    /// Returns the `[[Iterated]]` record of `this`.
+    fn iterated(this: &JsValue, context: &mut Context) -> JsResult<IteratorRecord> {
+        // 2. Perform ? RequireInternalSlot(O, [[Iterated]]).
+        this.as_object()
+            .and_then(|obj| {
+                obj.borrow()
+                    .as_iterator_wrapper()
+                    .map(|w| w.iterated.clone())
+            })
+            .ok_or_else(|| context.construct_type_error("`this` is not a wrapped iterator"))
+    }
+
+    /// `%WrapForValidIteratorPrototype%.next ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%wrapforvaliditeratorprototype%.next
+    pub(crate) fn next(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[Iterated]]).
+        // 3. Let iteratorRecord be O.[[Iterated]].
+        let iterated = Self::iterated(this, context)?;
+
+        // 4. Return ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
+        context.call(iterated.next_function(), iterated.iterator_object(), &[])
+    }
+
+    /// `%WrapForValidIteratorPrototype%.return ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%wrapforvaliditeratorprototype%.return
+    pub(crate) fn r#return(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[Iterated]]).
+        // 3. Let iterator be O.[[Iterated]].[[Iterator]].
+        let iterated = Self::iterated(this, context)?;
+        let iterator = iterated.iterator_object();
+
+        // 4. Assert: iterator is an Object.
+        // 5. Let returnMethod be ? GetMethod(iterator, "return").
+        // 6. If returnMethod is undefined, then
+        //     a. Return CreateIterResultObject(undefined, true).
+        // 7. Return ? Call(returnMethod, iterator).
+        match iterator.get_method("return", context)? {
+            Some(return_method) => return_method.call(iterator, &[], context),
+            None => Ok(create_iter_result_object(
+                JsValue::undefined(),
+                true,
+                context,
+            )),
+        }
+    }
+
+    /// Create the `%WrapForValidIteratorPrototype%` object
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%wrapforvaliditeratorprototype%-object
+    pub(crate) fn create_prototype(
+        iterator_prototype: JsObject,
+        context: &mut Context,
+    ) -> JsObject {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let wrapper = JsObject::from_proto_and_data(iterator_prototype, ObjectData::ordinary());
+        make_builtin_fn(Self::next, "next", &wrapper, 0, context);
+        make_builtin_fn(Self::r#return, "return", &wrapper, 0, context);
+        wrapper
+    }
+}
@@ -0,0 +1,681 @@
+//! This module implements the global `Iterator` object and the iterator helpers.
+//!
+//! `Iterator` is an abstract class whose prototype, `%Iterator.prototype%`, is inherited by all
+//! the builtin iterators. It provides lazy helpers such as `map` and `filter`, which return
+//! Iterator Helper objects, and eager helpers such as `reduce` and `toArray`, which consume the
+//! iterator.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-iterator-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator
+
+use self::iterator_helper::{HelperKind, IteratorHelper};
+use crate::{
+    builtins::{
+        iterable::{if_abrupt_close_iterator, IteratorRecord},
+        Array, BuiltIn, JsArgs,
+    },
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_profiler::Profiler;
+use tap::{Conv, Pipe};
+
+pub(crate) use self::iterator_wrapper::IteratorWrapper;
+
+pub mod iterator_helper;
+pub mod iterator_wrapper;
+
+#[cfg(test)]
+mod tests;
+
+/// The `Iterator` builtin.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Iterator;
+
+impl BuiltIn for Iterator {
+    const NAME: &'static str = "Iterator";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().iterator().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .static_method(Self::from, "from", 1)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .method(
+            |this, _, _| Ok(this.clone()),
+            (WellKnownSymbols::iterator(), "[Symbol.iterator]"),
+            0,
+        )
+        .method(Self::map, "map", 1)
+        .method(Self::filter, "filter", 1)
+        .method(Self::take, "take", 1)
+        .method(Self::drop, "drop", 1)
+        .method(Self::flat_map, "flatMap", 1)
+        .method(Self::reduce, "reduce", 1)
+        .method(Self::to_array, "toArray", 0)
+        .method(Self::for_each, "forEach", 1)
+        .method(Self::some, "some", 1)
+        .method(Self::every, "every", 1)
+        .method(Self::find, "find", 1)
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+impl Iterator {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `Iterator ( )`
+    ///
+    /// `Iterator` is abstract, it can only be constructed as the base class of a subclass.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator-constructor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/Iterator
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is either undefined or the active function object, throw a TypeError exception.
+        let iterator = context.intrinsics().constructors().iterator().constructor();
+        match new_target.as_object() {
+            None => {
+                return context.throw_type_error(
+                    "calling a builtin Iterator constructor without new is forbidden",
+                )
+            }
+            Some(new_target) if JsObject::equals(new_target, &iterator) => {
+                return context.throw_type_error("Iterator is an abstract class");
+            }
+            Some(_) => {}
+        }
+
+        // 2. Return ? OrdinaryCreateFromConstructor(NewTarget, "%Iterator.prototype%").
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardConstructors::iterator, context)?;
+        Ok(JsObject::from_proto_and_data(prototype, ObjectData::ordinary()).into())
+    }
+
+    /// `Iterator.from ( O )`
+    ///
+    /// Wraps an iterator or iterable object so that it inherits from `%Iterator.prototype%`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.from
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/from
+    pub(crate) fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let iteratorRecord be ? GetIteratorFlattenable(O, iterate-strings).
+        let iterator_record = get_iterator_flattenable(args.get_or_undefined(0), true, context)?;
+
+        // 2. Let hasInstance be ? OrdinaryHasInstance(%Iterator%, iteratorRecord.[[Iterator]]).
+        let iterator = context.intrinsics().constructors().iterator().constructor();
+        let has_instance = JsValue::ordinary_has_instance(
+            &iterator.into(),
+            iterator_record.iterator_object(),
+            context,
+        )?;
+
+        // 3. If hasInstance is true, then
+        if has_instance {
+            // a. Return iteratorRecord.[[Iterator]].
+            return Ok(iterator_record.iterator_object().clone());
+        }
+
+        // 4. Let wrapper be OrdinaryObjectCreate(%WrapForValidIteratorPrototype%, « [[Iterated]] »).
+        // 5. Set wrapper.[[Iterated]] to iteratorRecord.
+        // 6. Return wrapper.
+        Ok(IteratorWrapper::create(iterator_record, context).into())
+    }
+
+    /// `Iterator.prototype.map ( mapper )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.map
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/map
+    pub(crate) fn map(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        // 3. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(this, context)?;
+
+        // 4. If IsCallable(mapper) is false, throw a TypeError exception.
+        let mapper = args.get_or_undefined(0).as_callable().ok_or_else(|| {
+            context.construct_type_error("Iterator.prototype.map: mapper is not callable")
+        })?;
+
+        // 5-8. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        Ok(IteratorHelper::create(
+            iterated,
+            HelperKind::Map {
+                mapper: mapper.clone(),
+                counter: 0,
+            },
+            context,
+        )
+        .into())
+    }
+
+    /// `Iterator.prototype.filter ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.filter
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/filter
+    pub(crate) fn filter(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        // 3. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(this, context)?;
+
+        // 4. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = args.get_or_undefined(0).as_callable().ok_or_else(|| {
+            context.construct_type_error("Iterator.prototype.filter: predicate is not callable")
+        })?;
+
+        // 5-8. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        Ok(IteratorHelper::create(
+            iterated,
+            HelperKind::Filter {
+                predicate: predicate.clone(),
+                counter: 0,
+            },
+            context,
+        )
+        .into())
+    }
+
+    /// `Iterator.prototype.take ( limit )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.take
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/take
+    pub(crate) fn take(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        // 3. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(this, context)?;
+
+        // 4-7. Let integerLimit be ? ToIntegerOrInfinity(? ToNumber(limit)).
+        let remaining = to_limit(args.get_or_undefined(0), "take", context)?;
+
+        // 8-11. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        Ok(IteratorHelper::create(iterated, HelperKind::Take { remaining }, context).into())
+    }
+
+    /// `Iterator.prototype.drop ( limit )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.drop
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/drop
+    pub(crate) fn drop(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        // 3. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(this, context)?;
+
+        // 4-7. Let integerLimit be ? ToIntegerOrInfinity(? ToNumber(limit)).
+        let remaining = to_limit(args.get_or_undefined(0), "drop", context)?;
+
+        // 8-11. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        Ok(IteratorHelper::create(iterated, HelperKind::Drop { remaining }, context).into())
+    }
+
+    /// `Iterator.prototype.flatMap ( mapper )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.flatmap
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/flatMap
+    pub(crate) fn flat_map(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        // 3. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(this, context)?;
+
+        // 4. If IsCallable(mapper) is false, throw a TypeError exception.
+        let mapper = args.get_or_undefined(0).as_callable().ok_or_else(|| {
+            context.construct_type_error("Iterator.prototype.flatMap: mapper is not callable")
+        })?;
+
+        // 5-8. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        Ok(IteratorHelper::create(
+            iterated,
+            HelperKind::FlatMap {
+                mapper: mapper.clone(),
+                counter: 0,
+                inner: None,
+            },
+            context,
+        )
+        .into())
+    }
+
+    /// `Iterator.prototype.reduce ( reducer [ , initialValue ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.reduce
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/reduce
+    pub(crate) fn reduce(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        // 3. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(this, context)?;
+
+        // 4. If IsCallable(reducer) is false, throw a TypeError exception.
+        let reducer = args.get_or_undefined(0).as_callable().ok_or_else(|| {
+            context.construct_type_error("Iterator.prototype.reduce: reducer is not callable")
+        })?;
+
+        let (mut accumulator, mut counter) = if let Some(initial_value) = args.get(1) {
+            // 6. Else,
+            //     a. Let accumulator be initialValue.
+            //     b. Let counter be 0.
+            (initial_value.clone(), 0_usize)
+        } else {
+            // 5. If initialValue is not present, then
+            //     a. Let next be ? IteratorStep(iterated).
+            //     b. If next is false, throw a TypeError exception.
+            let next = iterated.step(context)?.ok_or_else(|| {
+                context.construct_type_error(
+                    "Iterator.prototype.reduce: cannot reduce an empty iterator without an initial value",
+                )
+            })?;
+
+            //     c. Let accumulator be ? IteratorValue(next).
+            //     d. Let counter be 1.
+            (next.value(context)?, 1)
+        };
+
+        // 7. Repeat,
+        //     a. Let next be ? IteratorStep(iterated).
+        while let Some(next) = iterated.step(context)? {
+            // c. Let value be ? IteratorValue(next).
+            let value = next.value(context)?;
+
+            // d. Let result be Completion(Call(reducer, undefined, « accumulator, value, 𝔽(counter) »)).
+            let result = reducer.call(
+                &JsValue::undefined(),
+                &[accumulator, value, counter.into()],
+                context,
+            );
+
+            // e. IfAbruptCloseIterator(result, iterated).
+            // f. Set accumulator to result.[[Value]].
+            accumulator = if_abrupt_close_iterator!(result, iterated, context);
+
+            // g. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        // b. If next is false, return accumulator.
+        Ok(accumulator)
+    }
+
+    /// `Iterator.prototype.toArray ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.toarray
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/toArray
+    pub(crate) fn to_array(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        // 3. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(this, context)?;
+
+        // 4. Let items be a new empty List.
+        let mut items = Vec::new();
+
+        // 5. Repeat,
+        //     a. Let next be ? IteratorStep(iterated).
+        //     c. Let value be ? IteratorValue(next).
+        //     d. Append value to items.
+        while let Some(next) = iterated.step(context)? {
+            items.push(next.value(context)?);
+        }
+
+        // b. If next is false, return CreateArrayFromList(items).
+        Ok(Array::create_array_from_list(items, context).into())
+    }
+
+    /// `Iterator.prototype.forEach ( fn )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.foreach
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/forEach
+    pub(crate) fn for_each(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        // 3. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(this, context)?;
+
+        // 4. If IsCallable(fn) is false, throw a TypeError exception.
+        let function = args.get_or_undefined(0).as_callable().ok_or_else(|| {
+            context.construct_type_error("Iterator.prototype.forEach: argument is not callable")
+        })?;
+
+        // 5. Let counter be 0.
+        let mut counter = 0_usize;
+
+        // 6. Repeat,
+        //     a. Let next be ? IteratorStep(iterated).
+        while let Some(next) = iterated.step(context)? {
+            // c. Let value be ? IteratorValue(next).
+            let value = next.value(context)?;
+
+            // d. Let result be Completion(Call(fn, undefined, « value, 𝔽(counter) »)).
+            let result = function.call(&JsValue::undefined(), &[value, counter.into()], context);
+
+            // e. IfAbruptCloseIterator(result, iterated).
+            if_abrupt_close_iterator!(result, iterated, context);
+
+            // f. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        // b. If next is false, return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `Iterator.prototype.some ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.some
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/some
+    pub(crate) fn some(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::find_matching(this, args.get_or_undefined(0), "some", context)
+            .map(|found| found.is_some().into())
+    }
+
+    /// `Iterator.prototype.every ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.every
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/every
+    pub(crate) fn every(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        // 3. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(this, context)?;
+
+        // 4. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = args.get_or_undefined(0).as_callable().ok_or_else(|| {
+            context.construct_type_error("Iterator.prototype.every: predicate is not callable")
+        })?;
+
+        // 5. Let counter be 0.
+        let mut counter = 0_usize;
+
+        // 6. Repeat,
+        //     a. Let next be ? IteratorStep(iterated).
+        while let Some(next) = iterated.step(context)? {
+            // c. Let value be ? IteratorValue(next).
+            let value = next.value(context)?;
+
+            // d. Let result be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+            let result = predicate.call(&JsValue::undefined(), &[value, counter.into()], context);
+
+            // e. IfAbruptCloseIterator(result, iterated).
+            let result = if_abrupt_close_iterator!(result, iterated, context);
+
+            // f. If ToBoolean(result) is false, return ? IteratorClose(iterated, NormalCompletion(false)).
+            if !result.to_boolean() {
+                return iterated.close(Ok(false.into()), context);
+            }
+
+            // g. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        // b. If next is false, return true.
+        Ok(true.into())
+    }
+
+    /// `Iterator.prototype.find ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.find
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Iterator/find
+    pub(crate) fn find(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::find_matching(this, args.get_or_undefined(0), "find", context)
+            .map(Option::unwrap_or_default)
+    }
+
+    /// The shared steps of `Iterator.prototype.some` and `Iterator.prototype.find`.
+    ///
+    /// Returns the first value of the iterator for which `predicate` returns a truthy value,
+    /// closing the iterator when found.
+    fn find_matching(
+        this: &JsValue,
+        predicate: &JsValue,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<Option<JsValue>> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        // 3. Let iterated be ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(this, context)?;
+
+        // 4. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = predicate.as_callable().ok_or_else(|| {
+            context.construct_type_error(format!(
+                "Iterator.prototype.{method}: predicate is not callable"
+            ))
+        })?;
+
+        // 5. Let counter be 0.
+        let mut counter = 0_usize;
+
+        // 6. Repeat,
+        //     a. Let next be ? IteratorStep(iterated).
+        while let Some(next) = iterated.step(context)? {
+            // c. Let value be ? IteratorValue(next).
+            let value = next.value(context)?;
+
+            // d. Let result be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+            let result = predicate.call(
+                &JsValue::undefined(),
+                &[value.clone(), counter.into()],
+                context,
+            );
+
+            // e. IfAbruptCloseIterator(result, iterated).
+            let result = match result {
+                Ok(result) => result,
+                Err(err) => return iterated.close(Err(err), context).map(Some),
+            };
+
+            // f. If ToBoolean(result) is true, return ? IteratorClose(iterated, NormalCompletion(value)).
+            if result.to_boolean() {
+                return iterated.close(Ok(value), context).map(Some);
+            }
+
+            // g. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        // b. If next is false, return undefined (or false for `some`).
+        Ok(None)
+    }
+}
+
+/// Converts the `limit` argument of `take` and `drop` to the number of remaining elements.
+fn to_limit(limit: &JsValue, method: &str, context: &mut Context) -> JsResult<f64> {
+    // 4. Let numLimit be ? ToNumber(limit).
+    let num_limit = limit.to_number(context)?;
+
+    // 5. If numLimit is NaN, throw a RangeError exception.
+    if num_limit.is_nan() {
+        return context.throw_range_error(format!("Iterator.prototype.{method}: limit is NaN"));
+    }
+
+    // 6. Let integerLimit be ! ToIntegerOrInfinity(numLimit).
+    let integer_limit = num_limit.trunc() + 0.0;
+
+    // 7. If integerLimit < 0, throw a RangeError exception.
+    if integer_limit < 0.0 {
+        return context
+            .throw_range_error(format!("Iterator.prototype.{method}: limit is negative"));
+    }
+
+    Ok(integer_limit)
+}
+
+/// `GetIteratorDirect ( obj )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getiteratordirect
+pub(crate) fn get_iterator_direct(
+    obj: &JsValue,
+    context: &mut Context,
+) -> JsResult<IteratorRecord> {
+    let obj = obj
+        .as_object()
+        .ok_or_else(|| context.construct_type_error("iterator must be an object"))?;
+
+    // 1. Let nextMethod be ? Get(obj, "next").
+    let next_method = obj.get("next", context)?;
+
+    // 2. Let iteratorRecord be the Iterator Record { [[Iterator]]: obj, [[NextMethod]]: nextMethod, [[Done]]: false }.
+    // 3. Return iteratorRecord.
+    Ok(IteratorRecord::new(obj.clone().into(), next_method))
+}
+
+/// `GetIteratorFlattenable ( obj, stringHandling )`
+///
+/// Strings are only iterated when `iterate_strings` is `true`, otherwise they are rejected.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getiteratorflattenable
+pub(crate) fn get_iterator_flattenable(
+    obj: &JsValue,
+    iterate_strings: bool,
+    context: &mut Context,
+) -> JsResult<IteratorRecord> {
+    // 1. If obj is not an Object, then
+    //     a. If stringHandling is reject-strings or obj is not a String, throw a TypeError exception.
+    if !obj.is_object() && (!iterate_strings || !obj.is_string()) {
+        return context.throw_type_error("value is not an iterator or iterable object");
+    }
+
+    // 2. Let method be ? GetMethod(obj, @@iterator).
+    let method = obj.get_method(WellKnownSymbols::iterator(), context)?;
+
+    let iterator = match method {
+        // 3. If method is undefined, then
+        //     a. Let iterator be obj.
+        None => obj.clone(),
+        // 4. Else,
+        //     a. Let iterator be ? Call(method, obj).
+        Some(method) => method.call(obj, &[], context)?,
+    };
+
+    // 5. If iterator is not an Object, throw a TypeError exception.
+    // 6. Return ? GetIteratorDirect(iterator).
+    get_iterator_direct(&iterator, context)
+}
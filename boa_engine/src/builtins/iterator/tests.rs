@@ -0,0 +1,262 @@
+use crate::{forward, forward_val, Context};
+
+#[test]
+fn iterator_prototype_is_shared() {
+    let mut context = Context::default();
+    let init = r#"
+        var proto = Object.getPrototypeOf(Object.getPrototypeOf([].values()));
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "proto === Iterator.prototype"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Iterator.prototype.constructor === Iterator"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Iterator.prototype[Symbol.toStringTag]"),
+        "\"Iterator\""
+    );
+}
+
+#[test]
+fn abstract_constructor() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(&mut context, "try { new Iterator(); } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { Iterator(); } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    let init = r#"
+        function MyIterator() {
+            return Reflect.construct(Iterator, [], MyIterator);
+        }
+        Object.setPrototypeOf(MyIterator.prototype, Iterator.prototype);
+        Object.setPrototypeOf(MyIterator, Iterator);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "new MyIterator() instanceof Iterator"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(new MyIterator()) === MyIterator.prototype"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn lazy_helpers() {
+    let mut context = Context::default();
+    let init = r#"
+        function* naturals() {
+            let i = 0;
+            while (true) {
+                yield i++;
+            }
+        }
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(
+            &mut context,
+            "naturals().filter(n => n % 2).map(n => n * n).drop(1).take(3).toArray()"
+        ),
+        "[ 9, 25, 49 ]"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[1, 2].values().flatMap(n => [n, n * 10]).toArray()"
+        ),
+        "[ 1, 10, 2, 20 ]"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.prototype.toString.call([].values().map(x => x))"
+        ),
+        "\"[object Iterator Helper]\""
+    );
+}
+
+#[test]
+fn helpers_close_underlying_iterator() {
+    let mut context = Context::default();
+    let init = r#"
+        var closed = 0;
+        function* counted() {
+            try {
+                yield 1;
+                yield 2;
+                yield 3;
+            } finally {
+                closed++;
+            }
+        }
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "counted().take(1).toArray()"),
+        "[ 1 ]"
+    );
+    assert_eq!(forward(&mut context, "closed"), "1");
+    assert_eq!(
+        forward(&mut context, "counted().some(n => n === 2)"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "closed"), "2");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { counted().map(() => { throw 'err'; }).next(); } catch (e) { e }"
+        ),
+        "\"err\""
+    );
+    assert_eq!(forward(&mut context, "closed"), "3");
+    assert_eq!(
+        forward(
+            &mut context,
+            "let helper = counted().map(x => x); helper.next(); helper.return().done"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "closed"), "4");
+}
+
+#[test]
+fn eager_helpers() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(&mut context, "[1, 2, 3].values().reduce((a, b) => a + b)"),
+        "6"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[1, 2, 3].values().reduce((a, b) => a + b, 10)"
+        ),
+        "16"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { [].values().reduce((a, b) => a + b); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "[1, 2, 3].values().find(n => n > 1)"),
+        "2"
+    );
+    assert_eq!(
+        forward(&mut context, "[1, 2, 3].values().every(n => n > 1)"),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "let sum = 0; [1, 2, 3].values().forEach(n => sum += n); sum"
+        ),
+        "6"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { [].values().take(-1); } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn iterator_from() {
+    let mut context = Context::default();
+    let init = r#"
+        var plain = {
+            i: 0,
+            next() {
+                return { value: this.i++, done: this.i > 3 };
+            },
+        };
+        var wrapped = Iterator.from(plain);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "wrapped instanceof Iterator"), "true");
+    assert_eq!(forward(&mut context, "wrapped.toArray()"), "[ 0, 1, 2 ]");
+    assert_eq!(
+        forward(
+            &mut context,
+            "let it = [].values(); Iterator.from(it) === it"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Iterator.from('ab').toArray()"),
+        "[ \"a\", \"b\" ]"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Iterator.from(1); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn js_iterator() {
+    use crate::{object::JsIterator, property::Attribute, JsValue};
+
+    let mut context = Context::default();
+
+    let values = JsIterator::from_rust_iter((0..4).map(JsValue::new), &mut context);
+    context.register_global_property("values", values, Attribute::all());
+    assert_eq!(
+        forward(
+            &mut context,
+            "values.filter((n) => n % 2 === 0).map((n) => n * 10).toArray().join()"
+        ),
+        "\"0,20\""
+    );
+    assert_eq!(forward(&mut context, "[...values].length"), "0");
+
+    let letters = JsIterator::from_rust_iter(vec!["a".into(), "b".into()], &mut context);
+    context.register_global_property("letters", letters, Attribute::all());
+    assert_eq!(
+        forward(&mut context, "Array.from(letters).join()"),
+        "\"a,b\""
+    );
+
+    let generator = forward_val(
+        &mut context,
+        r#"
+            var closed = false;
+            (function* () {
+                try { yield 1; yield 2; yield 3; } finally { closed = true; }
+            })()
+        "#,
+    )
+    .unwrap();
+    let mut iterator = JsIterator::from_iterable(&generator, &mut context).unwrap();
+    assert_eq!(iterator.next(&mut context).unwrap(), Some(JsValue::new(1)));
+    iterator.close(&mut context).unwrap();
+    assert!(iterator.is_done());
+    assert_eq!(iterator.next(&mut context).unwrap(), None);
+    assert_eq!(forward(&mut context, "closed"), "true");
+
+    assert!(JsIterator::from_iterable(&JsValue::new(1), &mut context).is_err());
+    let mut iterator = JsIterator::from_iterable(&"ab".into(), &mut context).unwrap();
+    assert_eq!(iterator.next(&mut context).unwrap(), Some("a".into()));
+    assert_eq!(iterator.next(&mut context).unwrap(), Some("b".into()));
+    assert_eq!(iterator.next(&mut context).unwrap(), None);
+    assert!(iterator.is_done());
+}
@@ -19,7 +19,7 @@ use crate::{
         string::{is_leading_surrogate, is_trailing_surrogate},
         BuiltIn,
     },
-    object::{JsObject, ObjectInitializer, RecursionLimiter},
+    object::{IntegrityLevel, JsObject, ObjectData, ObjectInitializer, RecursionLimiter},
     property::{Attribute, PropertyNameKind},
     symbol::WellKnownSymbols,
     value::IntegerOrInfinity,
@@ -29,6 +29,10 @@ use boa_profiler::Profiler;
 use serde_json::{self, Value as JSONValue};
 use tap::{Conv, Pipe};
 
+use self::parse_record::{reviver_context, JsonNode, JsonParseRecord};
+
+mod parse_record;
+
 #[cfg(test)]
 mod tests;
 
@@ -48,6 +52,8 @@ impl BuiltIn for Json {
         ObjectInitializer::new(context)
             .function(Self::parse, "parse", 2)
             .function(Self::stringify, "stringify", 3)
+            .function(Self::raw_json, "rawJSON", 1)
+            .function(Self::is_raw_json, "isRawJSON", 1)
             .property(to_string_tag, Self::NAME, attribute)
             .build()
             .conv::<JsValue>()
@@ -61,6 +67,7 @@ impl Json {
     /// This `JSON` method parses a JSON string, constructing the JavaScript value or object described by the string.
     ///
     /// An optional `reviver` function can be provided to perform a transformation on the resulting object before it is returned.
+    /// The reviver receives a third `context` argument, holding the `source` text of primitive values.
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
@@ -101,11 +108,16 @@ impl Json {
 
             // b. Let rootName be the empty String.
             // c. Perform ! CreateDataPropertyOrThrow(root, rootName, unfiltered).
-            root.create_data_property_or_throw("", unfiltered, context)
+            root.create_data_property_or_throw("", unfiltered.clone(), context)
                 .expect("CreateDataPropertyOrThrow should never throw here");
 
-            // d. Return ? InternalizeJSONProperty(root, rootName, reviver).
-            Self::internalize_json_property(&root, "".into(), obj, context)
+            // d. Let snapshot be ? CreateJSONParseRecord(script, rootName, unfiltered).
+            let snapshot = JsonNode::parse(json_string.as_str())
+                .map(|node| JsonParseRecord::new(&node, unfiltered, context))
+                .transpose()?;
+
+            // e. Return ? InternalizeJSONProperty(root, rootName, reviver, snapshot).
+            Self::internalize_json_property(&root, "".into(), obj, snapshot.as_ref(), context)
         } else {
             // 12. Else,
             // a. Return unfiltered.
@@ -113,21 +125,35 @@ impl Json {
         }
     }
 
-    /// `25.5.1.1 InternalizeJSONProperty ( holder, name, reviver )`
+    /// `25.5.1.1 InternalizeJSONProperty ( holder, name, reviver, parseRecord )`
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
+    ///  - [Proposal reference][proposal]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-internalizejsonproperty
+    /// [proposal]: https://tc39.es/proposal-json-parse-with-source/#sec-internalizejsonproperty
     fn internalize_json_property(
         holder: &JsObject,
         name: JsString,
         reviver: &JsObject,
+        parse_record: Option<&JsonParseRecord>,
         context: &mut Context,
     ) -> JsResult<JsValue> {
         // 1. Let val be ? Get(holder, name).
         let val = holder.get(name.clone(), context)?;
 
+        // 2. Let context be OrdinaryObjectCreate(%Object.prototype%).
+        // 3. If parseRecord is a JSON Parse Record and SameValue(parseRecord.[[Value]], val) is true, then
+        //     a. If val is not an Object, then ...
+        //     b. Let elementRecords be parseRecord.[[Elements]].
+        //     c. Let entryRecords be parseRecord.[[Entries]].
+        // 4. Else,
+        //     a. Let elementRecords be a new empty List.
+        //     b. Let entryRecords be a new empty List.
+        let parse_record = parse_record.filter(|record| JsValue::same_value(&record.value, &val));
+        let reviver_context = reviver_context(parse_record, context);
+
         // 2. If Type(val) is Object, then
         if let Some(obj) = val.as_object() {
             // a. Let isArray be ? IsArray(val).
@@ -139,11 +165,16 @@ impl Json {
                 let len = obj.length_of_array_like(context)? as i64;
                 for i in 0..len {
                     // 1. Let prop be ! ToString(𝔽(I)).
-                    // 2. Let newElement be ? InternalizeJSONProperty(val, prop, reviver).
+                    // 2. If I < elementRecordsLen, let elementRecord be elementRecords[I]. Otherwise, let elementRecord be empty.
+                    let element_record =
+                        parse_record.and_then(|record| record.elements.get(i as usize));
+
+                    // 3. Let newElement be ? InternalizeJSONProperty(val, prop, reviver, elementRecord).
                     let new_element = Self::internalize_json_property(
                         obj,
                         i.to_string().into(),
                         reviver,
+                        element_record,
                         context,
                     )?;
 
@@ -171,9 +202,17 @@ impl Json {
                         .as_string()
                         .expect("EnumerableOwnPropertyNames only returns strings");
 
-                    // 1. Let newElement be ? InternalizeJSONProperty(val, P, reviver).
-                    let new_element =
-                        Self::internalize_json_property(obj, p.clone(), reviver, context)?;
+                    // 1. Let entryRecord be the element of entryRecords whose [[Key]] field is P. If there is no such element, let entryRecord be empty.
+                    let entry_record = parse_record.and_then(|record| record.entry(p));
+
+                    // 2. Let newElement be ? InternalizeJSONProperty(val, P, reviver, entryRecord).
+                    let new_element = Self::internalize_json_property(
+                        obj,
+                        p.clone(),
+                        reviver,
+                        entry_record,
+                        context,
+                    )?;
 
                     // 2. If newElement is undefined, then
                     if new_element.is_undefined() {
@@ -189,8 +228,84 @@ impl Json {
             }
         }
 
-        // 3. Return ? Call(reviver, holder, « name, val »).
-        reviver.call(&holder.clone().into(), &[name.into(), val], context)
+        // 6. Return ? Call(reviver, holder, « name, val, context »).
+        reviver.call(
+            &holder.clone().into(),
+            &[name.into(), val, reviver_context.into()],
+            context,
+        )
+    }
+
+    /// `JSON.rawJSON ( text )`
+    ///
+    /// Creates a frozen object whose `rawJSON` text is used verbatim by `JSON.stringify`.
+    ///
+    /// More information:
+    ///  - [Proposal reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-json-parse-with-source/#sec-json.rawjson
+    pub(crate) fn raw_json(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let jsonString be ? ToString(text).
+        let json_string = args.get_or_undefined(0).to_string(context)?;
+
+        // 2. Throw a SyntaxError exception if jsonString is the empty String, or if either the first or last code unit of jsonString is any of 0x0009 (CHARACTER TABULATION), 0x000A (LINE FEED), 0x000D (CARRIAGE RETURN), or 0x0020 (SPACE).
+        let is_json_whitespace = |c: char| matches!(c, '\t' | '\n' | '\r' | ' ');
+        match (json_string.chars().next(), json_string.chars().last()) {
+            (Some(first), Some(last))
+                if !is_json_whitespace(first) && !is_json_whitespace(last) => {}
+            _ => {
+                return context.throw_syntax_error(
+                    "JSON.rawJSON: the text must not be empty or start or end with whitespace",
+                )
+            }
+        }
+
+        // 3. Parse StringToCodePoints(jsonString) as a JSON text as specified in ECMA-404. Throw a SyntaxError exception if it is not a valid JSON text as defined in that specification, or if its outermost value is an object or array as defined in that specification.
+        match serde_json::from_str::<JSONValue>(&json_string) {
+            Err(e) => return context.throw_syntax_error(e.to_string()),
+            Ok(JSONValue::Array(_) | JSONValue::Object(_)) => {
+                return context
+                    .throw_syntax_error("JSON.rawJSON: the text must be a primitive JSON value")
+            }
+            Ok(_) => {}
+        }
+
+        // 4. Let internalSlotsList be « [[IsRawJSON]] ».
+        // 5. Let obj be OrdinaryObjectCreate(null, internalSlotsList).
+        let obj = JsObject::from_proto_and_data(None, ObjectData::raw_json());
+
+        // 6. Perform ! CreateDataPropertyOrThrow(obj, "rawJSON", jsonString).
+        obj.create_data_property_or_throw("rawJSON", json_string, context)
+            .expect("CreateDataPropertyOrThrow should never throw here");
+
+        // 7. Perform ! SetIntegrityLevel(obj, frozen).
+        obj.set_integrity_level(IntegrityLevel::Frozen, context)
+            .expect("SetIntegrityLevel should never throw here");
+
+        // 8. Return obj.
+        Ok(obj.into())
+    }
+
+    /// `JSON.isRawJSON ( O )`
+    ///
+    /// Returns `true` if `O` was created by `JSON.rawJSON`.
+    ///
+    /// More information:
+    ///  - [Proposal reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-json-parse-with-source/#sec-json.israwjson
+    pub(crate) fn is_raw_json(_: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. If Type(O) is Object and O has an [[IsRawJSON]] internal slot, return true.
+        // 2. Return false.
+        Ok(args
+            .get_or_undefined(0)
+            .as_object()
+            .map_or(false, |obj| obj.borrow().is_raw_json())
+            .into())
     }
 
     /// `JSON.stringify( value[, replacer[, space]] )`
@@ -409,6 +524,15 @@ impl Json {
                 // i. Set value to value.[[BigIntData]].
                 value = bigint.clone().into();
             }
+            // e. Else if value has an [[IsRawJSON]] internal slot, then
+            else if obj.borrow().is_raw_json() {
+                // i. Return ! Get(value, "rawJSON").
+                return Ok(Some(
+                    obj.get("rawJSON", context)
+                        .expect("Get should never throw on a raw JSON object")
+                        .to_string(context)?,
+                ));
+            }
         }
 
         // 5. If value is null, return "null".
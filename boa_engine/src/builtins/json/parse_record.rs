@@ -0,0 +1,259 @@
+//! The JSON Parse Records of the `JSON.parse` source text access proposal.
+//!
+//! More information:
+//!  - [Proposal reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-json-parse-with-source/#sec-json-parse-record
+
+use crate::{object::JsObject, property::PropertyNameKind, Context, JsResult, JsString, JsValue};
+
+/// A JSON value of a JSON text, with the source text of its primitive values.
+#[derive(Debug)]
+pub(super) enum JsonNode<'a> {
+    Primitive(&'a str),
+    Array(Vec<JsonNode<'a>>),
+    Object(Vec<(String, JsonNode<'a>)>),
+}
+
+impl<'a> JsonNode<'a> {
+    /// Parses a JSON text that was already validated.
+    ///
+    /// Returns `None` if `text` is not a valid JSON text.
+    pub(super) fn parse(text: &'a str) -> Option<Self> {
+        let mut parser = NodeParser { text, position: 0 };
+        let node = parser.parse_value()?;
+        parser.skip_whitespace();
+        if parser.position == text.len() {
+            Some(node)
+        } else {
+            None
+        }
+    }
+}
+
+/// A parser of the source text of JSON values.
+struct NodeParser<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> NodeParser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.position += 1;
+        }
+    }
+
+    /// Consumes `byte` after any whitespace, returning whether it was found.
+    fn eat(&mut self, byte: u8) -> bool {
+        self.skip_whitespace();
+        let found = self.peek() == Some(byte);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn parse_value(&mut self) -> Option<JsonNode<'a>> {
+        self.skip_whitespace();
+        match self.peek()? {
+            b'[' => {
+                self.position += 1;
+                let mut elements = Vec::new();
+                if !self.eat(b']') {
+                    loop {
+                        elements.push(self.parse_value()?);
+                        if self.eat(b']') {
+                            break;
+                        }
+                        if !self.eat(b',') {
+                            return None;
+                        }
+                    }
+                }
+                Some(JsonNode::Array(elements))
+            }
+            b'{' => {
+                self.position += 1;
+                let mut entries = Vec::new();
+                if !self.eat(b'}') {
+                    loop {
+                        self.skip_whitespace();
+                        let key = serde_json::from_str::<String>(self.parse_string()?).ok()?;
+                        if !self.eat(b':') {
+                            return None;
+                        }
+                        entries.push((key, self.parse_value()?));
+                        if self.eat(b'}') {
+                            break;
+                        }
+                        if !self.eat(b',') {
+                            return None;
+                        }
+                    }
+                }
+                Some(JsonNode::Object(entries))
+            }
+            b'"' => self.parse_string().map(JsonNode::Primitive),
+            _ => {
+                let start = self.position;
+                while matches!(
+                    self.peek(),
+                    Some(b'-' | b'+' | b'.' | b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z')
+                ) {
+                    self.position += 1;
+                }
+                (self.position > start)
+                    .then(|| JsonNode::Primitive(&self.text[start..self.position]))
+            }
+        }
+    }
+
+    /// Parses a JSON string, returning its source text including the quotes.
+    fn parse_string(&mut self) -> Option<&'a str> {
+        let start = self.position;
+        if self.peek()? != b'"' {
+            return None;
+        }
+        self.position += 1;
+        loop {
+            match self.peek()? {
+                b'"' => break,
+                b'\\' => self.position += 2,
+                _ => self.position += 1,
+            }
+        }
+        self.position += 1;
+        self.text.get(start..self.position)
+    }
+}
+
+/// A `JSON Parse Record`, associating a value created by `JSON.parse` to its source text.
+///
+/// More information:
+///  - [Proposal reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-json-parse-with-source/#sec-json-parse-record
+#[derive(Debug)]
+pub(super) struct JsonParseRecord {
+    /// The source text of the value, if it is a primitive.
+    pub(super) source: Option<JsString>,
+    pub(super) value: JsValue,
+    pub(super) elements: Vec<JsonParseRecord>,
+    pub(super) entries: Vec<(JsString, JsonParseRecord)>,
+}
+
+impl JsonParseRecord {
+    /// `CreateJSONParseRecord ( parseNode, key, val )`
+    ///
+    /// More information:
+    ///  - [Proposal reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-json-parse-with-source/#sec-createjsonparserecord
+    pub(super) fn new(
+        node: &JsonNode<'_>,
+        value: JsValue,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let object = value.as_object().cloned();
+        let mut record = Self {
+            source: None,
+            value,
+            elements: Vec::new(),
+            entries: Vec::new(),
+        };
+
+        match (node, object) {
+            // 3. If val is an Object, then
+            //     a. Let isArray be ! IsArray(val).
+            //     b. If isArray is true, then
+            (JsonNode::Array(nodes), Some(object)) => {
+                // i. Assert: typedValNode is an ArrayLiteral Parse Node.
+                // ii. Let contentNodes be ArrayLiteralContentNodes of typedValNode.
+                // iii. Let len be the number of elements in contentNodes.
+                // iv. Let valLen be ! LengthOfArrayLike(val).
+                // v. Assert: valLen = len.
+                // vi. Let I be 0.
+                // vii. Repeat, while I < len,
+                for (index, node) in nodes.iter().enumerate() {
+                    // 1. Let propName be ! ToString(𝔽(I)).
+                    // 2. Let elementParseRecord be ? CreateJSONParseRecord(contentNodes[I], propName, ! Get(val, propName)).
+                    // 3. Append elementParseRecord to elements.
+                    let element = object.get(index, context)?;
+                    record.elements.push(Self::new(node, element, context)?);
+                }
+            }
+            //     c. Else,
+            (JsonNode::Object(nodes), Some(object)) => {
+                // i. Assert: typedValNode is an ObjectLiteral Parse Node.
+                // ii. Let propertyNodes be PropertyDefinitionNodes of typedValNode.
+                // iii. NOTE: Because val was produced from JSON text and has not been modified, all of its property keys are Strings and will be exhaustively enumerated in source text order.
+                // iv. Let keys be ! EnumerableOwnProperties(val, key).
+                let keys = object.enumerable_own_property_names(PropertyNameKind::Key, context)?;
+
+                // v. For each String P of keys, do
+                for key in keys {
+                    let key = key
+                        .as_string()
+                        .cloned()
+                        .expect("EnumerableOwnPropertyNames only returns strings");
+
+                    // 1. NOTE: In the case of JSON text specifying a property name more than once, the last such specification wins.
+                    // 2. Let propertyDefinition be the last element of propertyNodes whose PropName is P.
+                    let node = nodes
+                        .iter()
+                        .rev()
+                        .find(|(name, _)| name.as_str() == key.as_str());
+                    if let Some((_, node)) = node {
+                        // 3. Let propertyValueNode be the AssignmentExpression of propertyDefinition.
+                        // 4. Let propertyParseRecord be ? CreateJSONParseRecord(propertyValueNode, P, ! Get(val, P)).
+                        // 5. Append propertyParseRecord to entries.
+                        let property = object.get(key.clone(), context)?;
+                        record
+                            .entries
+                            .push((key, Self::new(node, property, context)?));
+                    }
+                }
+            }
+            // 4. Else,
+            //     a. Assert: typedValNode is not an ArrayLiteral Parse Node and not an ObjectLiteral Parse Node.
+            (JsonNode::Primitive(source), None) => record.source = Some((*source).into()),
+            _ => {}
+        }
+
+        // 5. Return the JSON Parse Record { [[ParseNode]]: typedValNode, [[Key]]: key, [[Value]]: val, [[Elements]]: elements, [[Entries]]: entries }.
+        Ok(record)
+    }
+
+    /// Gets the record of the property `key`, if it exists.
+    pub(super) fn entry(&self, key: &JsString) -> Option<&Self> {
+        self.entries
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, record)| record)
+    }
+}
+
+/// Creates the `context` object passed to the reviver of `JSON.parse`.
+pub(super) fn reviver_context(record: Option<&JsonParseRecord>, context: &mut Context) -> JsObject {
+    // 2. Let context be OrdinaryObjectCreate(%Object.prototype%).
+    let reviver_context = context.construct_object();
+
+    // 3. If parseRecord is a JSON Parse Record and SameValue(parseRecord.[[Value]], val) is true, then
+    //     a. If val is not an Object, then
+    //         i. Let parseNode be parseRecord.[[ParseNode]].
+    //         ii. Assert: parseNode is not an ArrayLiteral Parse Node and not an ObjectLiteral Parse Node.
+    //         iii. Let sourceText be the source text matched by parseNode.
+    //         iv. Perform ! CreateDataPropertyOrThrow(context, "source", CodePointsToString(sourceText)).
+    if let Some(source) = record.and_then(|record| record.source.clone()) {
+        reviver_context
+            .create_data_property_or_throw("source", source, context)
+            .expect("CreateDataPropertyOrThrow should never throw here");
+    }
+
+    reviver_context
+}
@@ -466,3 +466,60 @@ fn json_parse_with_no_args_throws_syntax_error() {
     let result = forward(&mut context, "JSON.parse();");
     assert!(result.contains("SyntaxError"));
 }
+
+#[test]
+fn json_parse_reviver_source_text() {
+    let mut context = Context::default();
+    let actual = forward(
+        &mut context,
+        r#"
+        var sources = [];
+        JSON.parse('{ "big": 12345678901234567890, "list": [1.0, "ab"], "x": 1, "x": -0 }', function (key, value, context) {
+            sources.push(key + "=" + String(context.source).replace(/"/g, "'"));
+            return value;
+        });
+        sources.join(" ");
+        "#,
+    );
+    assert_eq!(
+        actual,
+        r#""big=12345678901234567890 0=1.0 1='ab' list=undefined x=-0 =undefined""#
+    );
+
+    let actual = forward(
+        &mut context,
+        r#"
+        var sources = [];
+        JSON.parse('[1, 2]', function (key, value, context) {
+            if (key === "0") this[1] = 3;
+            sources.push(key + "=" + context.source);
+            return value;
+        });
+        sources.join(" ");
+        "#,
+    );
+    assert_eq!(actual, r#""0=1 1=undefined =undefined""#);
+}
+
+#[test]
+fn json_raw_json() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"JSON.stringify({ n: JSON.rawJSON("12345678901234567890"), s: [JSON.rawJSON('"a"')] })"#
+        ),
+        r#""{"n":12345678901234567890,"s":["a"]}""#
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "var raw = JSON.rawJSON(1e3); [JSON.isRawJSON(raw), JSON.isRawJSON({ rawJSON: '1' }), Object.isFrozen(raw), Object.getPrototypeOf(raw)].join()"
+        ),
+        r#""true,false,true,""#
+    );
+    for text in ["''", "' 1'", "'1\\n'", "'{}'", "'[]'", "'tru'"] {
+        let result = forward(&mut context, &format!("JSON.rawJSON({text})"));
+        assert!(result.contains("SyntaxError"), "{text}: {result}");
+    }
+}
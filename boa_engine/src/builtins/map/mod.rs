@@ -15,7 +15,10 @@
 use self::{map_iterator::MapIterator, ordered_map::OrderedMap};
 use super::JsArgs;
 use crate::{
-    builtins::BuiltIn,
+    builtins::{
+        iterable::{group_by, GroupKeyCoercion},
+        Array, BuiltIn,
+    },
     context::intrinsics::StandardConstructors,
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
@@ -73,6 +76,7 @@ impl BuiltIn for Map {
             None,
             Attribute::CONFIGURABLE,
         )
+        .static_method(Self::group_by, "groupBy", 2)
         .property(
             "entries",
             entries_function.clone(),
@@ -90,6 +94,7 @@ impl BuiltIn for Map {
         )
         .method(Self::clear, "clear", 0)
         .method(Self::delete, "delete", 1)
+        .method(Self::emplace, "emplace", 2)
         .method(Self::for_each, "forEach", 1)
         .method(Self::get, "get", 1)
         .method(Self::has, "has", 1)
@@ -146,6 +151,43 @@ impl Map {
         add_entries_from_iterable(&map, iterable, &adder, context)
     }
 
+    /// `Map.groupBy ( items, callbackfn )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-map.groupby
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/groupBy
+    pub(crate) fn group_by(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let items = args.get_or_undefined(0);
+        let callback = args.get_or_undefined(1);
+
+        // 1. Let groups be ? GroupBy(items, callbackfn, zero).
+        let groups = group_by(items, callback, GroupKeyCoercion::Zero, context)?;
+
+        // 2. Let map be ! Construct(%Map%).
+        let mut map = OrderedMap::new();
+
+        // 3. For each Record { [[Key]], [[Elements]] } g of groups, do
+        for (key, elements) in groups {
+            // a. Let elements be CreateArrayFromList(g.[[Elements]]).
+            let elements = Array::create_array_from_list(elements, context);
+
+            // b. Let entry be the Record { [[Key]]: g.[[Key]], [[Value]]: elements }.
+            // c. Append entry to map.[[MapData]].
+            map.insert(key, elements.into());
+        }
+
+        // 4. Return map.
+        let prototype = context.intrinsics().constructors().map().prototype();
+        Ok(JsObject::from_proto_and_data(prototype, ObjectData::map(map)).into())
+    }
+
     /// `get Map [ @@species ]`
     ///
     /// The `Map [ @@species ]` accessor property returns the Map constructor.
@@ -311,6 +353,90 @@ impl Map {
         context.throw_type_error("'this' is not a Map")
     }
 
+    /// `Map.prototype.emplace( key, handler )`
+    ///
+    /// Updates the value associated with the key using `handler.update`, or inserts the value
+    /// returned by `handler.insert` if there is none. Returns the resulting value.
+    ///
+    /// More information:
+    ///  - [Upsert proposal reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-upsert/#sec-map.prototype.emplace
+    pub(crate) fn emplace(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let key = match args.get_or_undefined(0) {
+            // -0 and +0 are the same key, normalize it so a new entry is always stored as +0.
+            JsValue::Rational(r) if r.is_zero() => JsValue::Rational(0f64),
+            key => key.clone(),
+        };
+        let handler = args.get_or_undefined(1);
+
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[MapData]]).
+        let map = this
+            .as_object()
+            .filter(|obj| obj.is_map())
+            .ok_or_else(|| context.construct_type_error("'this' is not a Map"))?;
+
+        let handler_obj = handler.as_object().ok_or_else(|| {
+            context.construct_type_error("Map.prototype.emplace: handler is not an object")
+        })?;
+
+        // 3. Let entries be the List that is M.[[MapData]].
+        // 4. For each Record { [[Key]], [[Value]] } e that is an element of entries, do
+        //     a. If e.[[Key]] is not empty and SameValueZero(e.[[Key]], key) is true, then
+        let existing = map
+            .borrow()
+            .as_map_ref()
+            .expect("checked that `this` is a Map")
+            .get(&key)
+            .cloned();
+        if let Some(existing) = existing {
+            // i. If HasProperty(handler, "update") is true, then
+            if !handler_obj.has_property("update", context)? {
+                // ii. Return e.[[Value]].
+                return Ok(existing);
+            }
+
+            // 1. Let updateFn be ? Get(handler, "update").
+            let update_fn = handler_obj.get("update", context)?;
+
+            // 2. Let updated be ? Call(updateFn, handler, « e.[[Value]], key, M »).
+            let updated =
+                context.call(&update_fn, handler, &[existing, key.clone(), this.clone()])?;
+
+            // 3. Set e.[[Value]] to updated.
+            // The entry may have been removed by `updateFn`, in which case there is no record
+            // left to update.
+            let mut map = map.borrow_mut();
+            let map = map.as_map_mut().expect("checked that `this` is a Map");
+            if map.contains_key(&key) {
+                map.insert(key, updated.clone());
+            }
+
+            // ii. Return e.[[Value]].
+            return Ok(updated);
+        }
+
+        // 5. Let insertFn be ? Get(handler, "insert").
+        let insert_fn = handler_obj.get("insert", context)?;
+
+        // 6. Let inserted be ? Call(insertFn, handler, « key, M »).
+        let inserted = context.call(&insert_fn, handler, &[key.clone(), this.clone()])?;
+
+        // 7. Set e.[[Value]] to inserted.
+        map.borrow_mut()
+            .as_map_mut()
+            .expect("checked that `this` is a Map")
+            .insert(key, inserted.clone());
+
+        // 8. Return e.[[Value]].
+        Ok(inserted)
+    }
+
     /// `Map.prototype.get( key )`
     ///
     /// Returns the value associated with the key, or undefined if there is none.
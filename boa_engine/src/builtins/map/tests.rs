@@ -1,4 +1,4 @@
-use crate::{forward, Context};
+use crate::{forward, object::JsMap, property::Attribute, Context, JsValue};
 
 #[test]
 fn construct_empty() {
@@ -404,3 +404,97 @@ fn for_of_delete() {
     assert_eq!(forward(&mut context, "result[3][0]"), "3");
     assert_eq!(forward(&mut context, "result[3][1]"), "\"d\"");
 }
+
+#[test]
+fn map_group_by() {
+    let mut context = Context::default();
+    let init = r#"
+        var key = {};
+        var groups = Map.groupBy([-0, 0, 1, NaN, NaN, 2], (n) => n === 1 ? key : n);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "groups.size"), "4");
+    assert_eq!(forward(&mut context, "groups.get(0).length"), "2");
+    assert_eq!(
+        forward(&mut context, "Object.is(groups.keys().next().value, 0)"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "groups.get(NaN).length"), "2");
+    assert_eq!(forward(&mut context, "groups.get(key)[0]"), "1");
+    assert_eq!(forward(&mut context, "groups instanceof Map"), "true");
+}
+
+#[test]
+fn emplace() {
+    let mut context = Context::default();
+    let init = r#"
+        var counter = {
+            update(value, key, map) { return value + 1; },
+            insert(key, map) { return 1; },
+        };
+        var map = new Map([["a", 1]]);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "map.emplace('a', counter)"), "2");
+    assert_eq!(forward(&mut context, "map.emplace('b', counter)"), "1");
+    assert_eq!(forward(&mut context, "map.get('a')"), "2");
+    assert_eq!(forward(&mut context, "map.get('b')"), "1");
+    assert_eq!(
+        forward(&mut context, "map.emplace('a', { insert() { return 0; } })"),
+        "2"
+    );
+    assert_eq!(forward(&mut context, "map.emplace(-0, counter)"), "1");
+    assert_eq!(
+        forward(&mut context, "Object.is([...map.keys()][2], 0)"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "map.emplace('a', { update(v, k, m) { m.delete(k); return 5; } })"
+        ),
+        "5"
+    );
+    assert_eq!(forward(&mut context, "map.has('a')"), "false");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { map.emplace('c', {}); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Map.prototype.emplace.call({}, 'c', counter); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn js_map() {
+    let mut context = Context::default();
+    let map = JsMap::new(&mut context);
+    map.set("a", 1, &mut context).unwrap();
+    map.set(-0.0, "zero", &mut context).unwrap();
+    assert_eq!(map.size(&mut context).unwrap(), 2);
+    assert_eq!(map.get("a", &mut context).unwrap(), JsValue::new(1));
+    assert!(map.has(0, &mut context).unwrap());
+    assert!(map.get("b", &mut context).unwrap().is_undefined());
+
+    let keys = map.keys(&mut context).unwrap();
+    assert_eq!(keys.next(&mut context).unwrap(), Some(JsValue::new("a")));
+    assert!(map.delete(0, &mut context).unwrap());
+    assert_eq!(keys.next(&mut context).unwrap(), None);
+
+    context.register_global_property("map", map.clone(), Attribute::all());
+    assert_eq!(forward(&mut context, "map.get('a')"), "1");
+    forward(&mut context, "map.set('b', 2)");
+    assert_eq!(map.size(&mut context).unwrap(), 2);
+
+    let from_js = JsMap::from_js_iterable(&JsValue::undefined(), &mut context).unwrap();
+    assert!(from_js.is_empty(&mut context).unwrap());
+    let not_a_map = context.construct_object();
+    assert!(JsMap::from_object(not_a_map, &mut context).is_err());
+}
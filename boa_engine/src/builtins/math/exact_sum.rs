@@ -0,0 +1,96 @@
+//! Exact summation of finite numbers, used by `Math.sumPrecise`.
+//!
+//! Every finite `f64` is an integer multiple of the smallest subnormal number `2^-1074`, so the
+//! sum is accumulated exactly as an arbitrary precision integer count of that unit and rounded
+//! only once, when it is converted back to a number.
+
+use num_bigint::{BigInt, Sign};
+use num_traits::{ToPrimitive, Zero};
+
+/// The largest biased exponent of a finite `f64`.
+const MAX_BIASED_EXPONENT: u64 = 2046;
+
+/// The number of significant bits of an `f64`, including the implicit bit.
+const SIGNIFICAND_BITS: u64 = 53;
+
+/// An exact accumulator of finite numbers.
+#[derive(Debug, Default)]
+pub(super) struct ExactSum {
+    /// The sum, in units of `2^-1074`.
+    units: BigInt,
+}
+
+impl ExactSum {
+    /// Adds the finite number `x` to the sum.
+    pub(super) fn add(&mut self, x: f64) {
+        debug_assert!(x.is_finite(), "only finite numbers can be summed exactly");
+
+        let bits = x.to_bits();
+        let biased_exponent = (bits >> 52) & 0x7ff;
+        let fraction = bits & ((1 << 52) - 1);
+
+        // Subnormal numbers have an exponent of `-1022` without the implicit bit.
+        let (significand, shift) = if biased_exponent == 0 {
+            (fraction, 0)
+        } else {
+            (fraction | (1 << 52), biased_exponent - 1)
+        };
+
+        let magnitude = BigInt::from(significand) << shift;
+        if x.is_sign_negative() {
+            self.units -= magnitude;
+        } else {
+            self.units += magnitude;
+        }
+    }
+
+    /// Rounds the sum to the nearest number, breaking ties to even.
+    ///
+    /// A sum of zero is `+0`, and sums too large in magnitude round to infinities.
+    pub(super) fn value(&self) -> f64 {
+        let negative = self.units.sign() == Sign::Minus;
+        let magnitude = self.units.magnitude();
+        let bits = magnitude.bits();
+
+        let value = if magnitude.is_zero() {
+            0.0
+        } else if bits <= SIGNIFICAND_BITS {
+            // The sum is representable as is, so the scaling cannot round.
+            let significand = magnitude.to_u64().expect("significand must fit in 53 bits");
+            significand as f64 * f64::from_bits(1)
+        } else {
+            let mut shift = bits - SIGNIFICAND_BITS;
+            let mut significand = (magnitude >> shift)
+                .to_u64()
+                .expect("significand must fit in 53 bits");
+
+            // Round half to even, looking at the first discarded bit and the bits after it.
+            let half = magnitude.bit(shift - 1);
+            let sticky = magnitude
+                .trailing_zeros()
+                .map_or(false, |zeros| zeros < shift - 1);
+            if half && (sticky || significand & 1 == 1) {
+                significand += 1;
+                if significand == 1 << SIGNIFICAND_BITS {
+                    significand >>= 1;
+                    shift += 1;
+                }
+            }
+
+            // The sum is `significand × 2^(shift - 1074)` with a normalized `significand`, so its
+            // exponent is `shift - 1022`, which is biased by `1023`.
+            let biased_exponent = shift + 1;
+            if biased_exponent > MAX_BIASED_EXPONENT {
+                f64::INFINITY
+            } else {
+                f64::from_bits((biased_exponent << 52) | (significand & ((1 << 52) - 1)))
+            }
+        };
+
+        if negative {
+            -value
+        } else {
+            value
+        }
+    }
+}
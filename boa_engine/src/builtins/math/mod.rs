@@ -13,10 +13,17 @@
 
 use super::JsArgs;
 use crate::{
-    builtins::BuiltIn, object::ObjectInitializer, property::Attribute, symbol::WellKnownSymbols,
+    builtins::{iterable::IteratorHint, BuiltIn, Number},
+    object::ObjectInitializer,
+    property::Attribute,
+    symbol::WellKnownSymbols,
     Context, JsResult, JsValue,
 };
 use boa_profiler::Profiler;
+
+mod exact_sum;
+
+use exact_sum::ExactSum;
 use tap::{Conv, Pipe};
 
 #[cfg(test)]
@@ -75,6 +82,7 @@ impl BuiltIn for Math {
             .function(Self::sin, "sin", 1)
             .function(Self::sinh, "sinh", 1)
             .function(Self::sqrt, "sqrt", 1)
+            .function(Self::sum_precise, "sumPrecise", 1)
             .function(Self::tan, "tan", 1)
             .function(Self::tanh, "tanh", 1)
             .function(Self::trunc, "trunc", 1)
@@ -128,7 +136,7 @@ impl Math {
             // 2. If n is NaN, n > 1𝔽, or n < -1𝔽, return NaN.
             // 3. If n is 1𝔽, return +0𝔽.
             // 4. Return an implementation-approximated value representing the result of the inverse cosine of ℝ(n).
-            .acos()
+            .pipe(libm::acos)
             .into())
     }
 
@@ -149,7 +157,7 @@ impl Math {
             // 2. If n is NaN or n is +∞𝔽, return n.
             // 3. If n is 1𝔽, return +0𝔽.
             // 5. Return an implementation-approximated value representing the result of the inverse hyperbolic cosine of ℝ(n).
-            .acosh()
+            .pipe(libm::acosh)
             .into())
     }
 
@@ -169,7 +177,7 @@ impl Math {
             // 2. If n is NaN, n is +0𝔽, or n is -0𝔽, return n.
             // 3. If n > 1𝔽 or n < -1𝔽, return NaN.
             // 4. Return an implementation-approximated value representing the result of the inverse sine of ℝ(n).
-            .asin()
+            .pipe(libm::asin)
             .into())
     }
 
@@ -188,7 +196,7 @@ impl Math {
             .to_number(context)?
             // 2. If n is NaN, n is +0𝔽, n is -0𝔽, n is +∞𝔽, or n is -∞𝔽, return n.
             // 3. Return an implementation-approximated value representing the result of the inverse hyperbolic sine of ℝ(n).
-            .asinh()
+            .pipe(libm::asinh)
             .into())
     }
 
@@ -209,7 +217,7 @@ impl Math {
             // 3. If n is +∞𝔽, return an implementation-approximated value representing π / 2.
             // 4. If n is -∞𝔽, return an implementation-approximated value representing -π / 2.
             // 5. Return an implementation-approximated value representing the result of the inverse tangent of ℝ(n).
-            .atan()
+            .pipe(libm::atan)
             .into())
     }
 
@@ -231,7 +239,7 @@ impl Math {
             // 4. If n is 1𝔽, return +∞𝔽.
             // 5. If n is -1𝔽, return -∞𝔽.
             // 6. Return an implementation-approximated value representing the result of the inverse hyperbolic tangent of ℝ(n).
-            .atanh()
+            .pipe(libm::atanh)
             .into())
     }
 
@@ -275,7 +283,7 @@ impl Math {
         // c. If nx is +0𝔽 or nx is -0𝔽, return an implementation-approximated value representing -π / 2.
         // 11. Assert: nx is finite and is neither +0𝔽 nor -0𝔽.
         // 12. Return an implementation-approximated value representing the result of the inverse tangent of the quotient ℝ(ny) / ℝ(nx).
-        Ok(y.atan2(x).into())
+        Ok(libm::atan2(y, x).into())
     }
 
     /// Get the cubic root of a number.
@@ -293,7 +301,7 @@ impl Math {
             .to_number(context)?
             // 2. If n is NaN, n is +0𝔽, n is -0𝔽, n is +∞𝔽, or n is -∞𝔽, return n.
             // 3. Return an implementation-approximated value representing the result of the cube root of ℝ(n).
-            .cbrt()
+            .pipe(libm::cbrt)
             .into())
     }
 
@@ -353,7 +361,7 @@ impl Math {
             // 2. If n is NaN, n is +∞𝔽, or n is -∞𝔽, return NaN.
             // 3. If n is +0𝔽 or n is -0𝔽, return 1𝔽.
             // 4. Return an implementation-approximated value representing the result of the cosine of ℝ(n).
-            .cos()
+            .pipe(libm::cos)
             .into())
     }
 
@@ -374,7 +382,7 @@ impl Math {
             // 3. If n is +∞𝔽 or n is -∞𝔽, return +∞𝔽.
             // 4. If n is +0𝔽 or n is -0𝔽, return 1𝔽.
             // 5. Return an implementation-approximated value representing the result of the hyperbolic cosine of ℝ(n).
-            .cosh()
+            .pipe(libm::cosh)
             .into())
     }
 
@@ -395,7 +403,7 @@ impl Math {
             // 3. If n is +0𝔽 or n is -0𝔽, return 1𝔽.
             // 4. If n is -∞𝔽, return +0𝔽.
             // 5. Return an implementation-approximated value representing the result of the exponential function of ℝ(n).
-            .exp()
+            .pipe(libm::exp)
             .into())
     }
 
@@ -417,7 +425,7 @@ impl Math {
             // 2. If n is NaN, n is +0𝔽, n is -0𝔽, or n is +∞𝔽, return n.
             // 3. If n is -∞𝔽, return -1𝔽.
             // 4. Return an implementation-approximated value representing the result of subtracting 1 from the exponential function of ℝ(n).
-            .exp_m1()
+            .pipe(libm::expm1)
             .into())
     }
 
@@ -477,23 +485,33 @@ impl Math {
     pub(crate) fn hypot(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let coerced be a new empty List.
         // 2. For each element arg of args, do
-        // a. Let n be ? ToNumber(arg).
-        // b. Append n to coerced.
+        //     a. Let n be ? ToNumber(arg).
+        //     b. Append n to coerced.
+        let coerced = args
+            .iter()
+            .map(|arg| arg.to_number(context))
+            .collect::<JsResult<Vec<_>>>()?;
+
         // 3. For each element number of coerced, do
+        //     a. If number is +∞𝔽 or number is -∞𝔽, return +∞𝔽.
+        if coerced.iter().any(|number| number.is_infinite()) {
+            return Ok(f64::INFINITY.into());
+        }
+
+        // 4. Let onlyZero be true.
         // 5. For each element number of coerced, do
-        let mut result = 0f64;
-        for arg in args {
-            let num = arg.to_number(context)?;
-            // a. If number is +∞𝔽 or number is -∞𝔽, return +∞𝔽.
-            // 4. Let onlyZero be true.
-            // a. If number is NaN, return NaN.
-            // b. If number is neither +0𝔽 nor -0𝔽, set onlyZero to false.
-            // 6. If onlyZero is true, return +0𝔽.
-            // 7. Return an implementation-approximated value representing the square root of the sum of squares of the mathematical values of the elements of coerced.
-            result = result.hypot(num);
+        //     a. If number is NaN, return NaN.
+        //     b. If number is neither +0𝔽 nor -0𝔽, set onlyZero to false.
+        if coerced.iter().any(|number| number.is_nan()) {
+            return Ok(f64::NAN.into());
         }
 
-        Ok(result.into())
+        // 6. If onlyZero is true, return +0𝔽.
+        // 7. Return an implementation-approximated value representing the square root of the sum of squares of the mathematical values of the elements of coerced.
+        Ok(coerced
+            .into_iter()
+            .fold(0.0, |result, number| libm::hypot(result, number))
+            .into())
     }
 
     /// Get the result of the C-like 32-bit multiplication of the two parameters.
@@ -534,7 +552,7 @@ impl Math {
             // 4. If n is +0𝔽 or n is -0𝔽, return -∞𝔽.
             // 5. If n < +0𝔽, return NaN.
             // 6. Return an implementation-approximated value representing the result of the natural logarithm of ℝ(n).
-            .ln()
+            .pipe(libm::log)
             .into())
     }
 
@@ -555,7 +573,7 @@ impl Math {
             // 3. If n is -1𝔽, return -∞𝔽.
             // 4. If n < -1𝔽, return NaN.
             // 5. Return an implementation-approximated value representing the result of the natural logarithm of 1 + ℝ(n).
-            .ln_1p()
+            .pipe(libm::log1p)
             .into())
     }
 
@@ -577,7 +595,7 @@ impl Math {
             // 4. If n is +0𝔽 or n is -0𝔽, return -∞𝔽.
             // 5. If n < +0𝔽, return NaN.
             // 6. Return an implementation-approximated value representing the result of the base 10 logarithm of ℝ(n).
-            .log10()
+            .pipe(libm::log10)
             .into())
     }
 
@@ -599,7 +617,7 @@ impl Math {
             // 4. If n is +0𝔽 or n is -0𝔽, return -∞𝔽.
             // 5. If n < +0𝔽, return NaN.
             // 6. Return an implementation-approximated value representing the result of the base 2 logarithm of ℝ(n).
-            .log2()
+            .pipe(libm::log2)
             .into())
     }
 
@@ -687,7 +705,6 @@ impl Math {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-math.pow
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/pow
-    #[allow(clippy::float_cmp)]
     pub(crate) fn pow(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Set base to ? ToNumber(base).
         let x = args.get_or_undefined(0).to_number(context)?;
@@ -695,13 +712,8 @@ impl Math {
         // 2. Set exponent to ? ToNumber(exponent).
         let y = args.get_or_undefined(1).to_number(context)?;
 
-        // 3. If |x| = 1 and the exponent is infinite, return NaN.
-        if f64::abs(x) == 1f64 && y.is_infinite() {
-            return Ok(f64::NAN.into());
-        }
-
-        // 4. Return ! Number::exponentiate(base, exponent).
-        Ok(x.powf(y).into())
+        // 3. Return Number::exponentiate(base, exponent).
+        Ok(Number::exponentiate(x, y).into())
     }
 
     /// Generate a random floating-point number between `0` and `1`.
@@ -766,6 +778,131 @@ impl Math {
         Ok(n.signum().into())
     }
 
+    /// Get the correctly rounded sum of the numbers of an iterable.
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-math-sum/#sec-math.sumprecise
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/sumPrecise
+    pub(crate) fn sum_precise(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        /// The state of the summation.
+        #[derive(Clone, Copy, PartialEq)]
+        enum State {
+            MinusZero,
+            Finite,
+            PlusInfinity,
+            MinusInfinity,
+            NotANumber,
+        }
+
+        let items = args.get_or_undefined(0);
+
+        // 1. Perform ? RequireObjectCoercible(items).
+        items.require_object_coercible(context)?;
+
+        // 2. Let iteratorRecord be ? GetIterator(items, sync).
+        let iterator_record = items.get_iterator(context, Some(IteratorHint::Sync), None)?;
+
+        // 3. Let state be minus-zero.
+        let mut state = State::MinusZero;
+
+        // 4. Let sum be 0.
+        let mut sum = ExactSum::default();
+
+        // 5. Let count be 0.
+        let mut count = 0u64;
+
+        // 6. Let next be not-started.
+        // 7. Repeat, while next is not done,
+        //     a. Set next to ? IteratorStepValue(iteratorRecord).
+        while let Some(next) = iterator_record.step(context)? {
+            let next = next.value(context)?;
+
+            // b. If next is not done, then
+            //     i. Set count to count + 1.
+            count += 1;
+
+            //     ii. If count ≥ 2^53, then
+            if count >= 1 << 53 {
+                // 1. Let error be ThrowCompletion(a newly created RangeError object).
+                let error = context.throw_range_error("Math.sumPrecise: too many values");
+
+                // 2. Return ? IteratorClose(iteratorRecord, error).
+                return iterator_record.close(error, context);
+            }
+
+            //     iv. If next is not a Number, then
+            let n = if let Some(n) = next.as_number() {
+                n
+            } else {
+                // 1. Let error be ThrowCompletion(a newly created TypeError object).
+                let error = context.throw_type_error("Math.sumPrecise: value is not a number");
+
+                // 2. Return ? IteratorClose(iteratorRecord, error).
+                return iterator_record.close(error, context);
+            };
+
+            //     v. Let n be next.
+            //     vi. If state is not not-a-number, then
+            if state == State::NotANumber {
+                continue;
+            }
+
+            state = if n.is_nan() {
+                // 1. If n is NaN, set state to not-a-number.
+                State::NotANumber
+            } else if n == f64::INFINITY {
+                // 2. Else if n is +∞𝔽, then
+                //     a. If state is minus-infinity, set state to not-a-number.
+                //     b. Else, set state to plus-infinity.
+                if state == State::MinusInfinity {
+                    State::NotANumber
+                } else {
+                    State::PlusInfinity
+                }
+            } else if n == f64::NEG_INFINITY {
+                // 3. Else if n is -∞𝔽, then
+                //     a. If state is plus-infinity, set state to not-a-number.
+                //     b. Else, set state to minus-infinity.
+                if state == State::PlusInfinity {
+                    State::NotANumber
+                } else {
+                    State::MinusInfinity
+                }
+            } else if !(n == 0.0 && n.is_sign_negative())
+                && matches!(state, State::MinusZero | State::Finite)
+            {
+                // 4. Else if n is not -0𝔽 and state is either minus-zero or finite, then
+                //     a. Set state to finite.
+                //     b. Set sum to sum + ℝ(n).
+                sum.add(n);
+                State::Finite
+            } else {
+                state
+            };
+        }
+
+        Ok(match state {
+            // 8. If state is not-a-number, return NaN.
+            State::NotANumber => f64::NAN,
+            // 9. If state is plus-infinity, return +∞𝔽.
+            State::PlusInfinity => f64::INFINITY,
+            // 10. If state is minus-infinity, return -∞𝔽.
+            State::MinusInfinity => f64::NEG_INFINITY,
+            // 11. If state is minus-zero, return -0𝔽.
+            State::MinusZero => -0.0,
+            // 12. Return 𝔽(sum).
+            State::Finite => sum.value(),
+        }
+        .into())
+    }
+
     /// Get the sine of a number.
     ///
     /// More information:
@@ -782,7 +919,7 @@ impl Math {
             // 2. If n is NaN, n is +0𝔽, or n is -0𝔽, return n.
             // 3. If n is +∞𝔽 or n is -∞𝔽, return NaN.
             // 4. Return an implementation-approximated value representing the result of the sine of ℝ(n).
-            .sin()
+            .pipe(libm::sin)
             .into())
     }
 
@@ -801,7 +938,7 @@ impl Math {
             .to_number(context)?
             // 2. If n is NaN, n is +0𝔽, n is -0𝔽, n is +∞𝔽, or n is -∞𝔽, return n.
             // 3. Return an implementation-approximated value representing the result of the hyperbolic sine of ℝ(n).
-            .sinh()
+            .pipe(libm::sinh)
             .into())
     }
 
@@ -821,7 +958,7 @@ impl Math {
             // 2. If n is NaN, n is +0𝔽, n is -0𝔽, or n is +∞𝔽, return n.
             // 3. If n < +0𝔽, return NaN.
             // 4. Return an implementation-approximated value representing the result of the square root of ℝ(n).
-            .sqrt()
+            .pipe(libm::sqrt)
             .into())
     }
 
@@ -841,7 +978,7 @@ impl Math {
             // 2. If n is NaN, n is +0𝔽, or n is -0𝔽, return n.
             // 3. If n is +∞𝔽, or n is -∞𝔽, return NaN.
             // 4. Return an implementation-approximated value representing the result of the tangent of ℝ(n).
-            .tan()
+            .pipe(libm::tan)
             .into())
     }
 
@@ -862,7 +999,7 @@ impl Math {
             // 3. If n is +∞𝔽, return 1𝔽.
             // 4. If n is -∞𝔽, return -1𝔽.
             // 5. Return an implementation-approximated value representing the result of the hyperbolic tangent of ℝ(n).
-            .tanh()
+            .pipe(libm::tanh)
             .into())
     }
 
@@ -397,6 +397,11 @@ fn hypot() {
     assert_eq!(e.to_number(&mut context).unwrap(), 8.774964387392123);
     assert!(f.to_number(&mut context).unwrap().is_infinite());
     assert_eq!(g.to_number(&mut context).unwrap(), 12f64);
+    assert_eq!(
+        forward(&mut context, "Math.hypot(NaN, -Infinity)"),
+        "Infinity"
+    );
+    assert_eq!(forward(&mut context, "Math.hypot(NaN, 0)"), "NaN");
 }
 
 #[test]
@@ -676,6 +681,60 @@ fn sqrt() {
     assert_eq!(c.to_number(&mut context).unwrap(), 3_f64);
 }
 
+#[test]
+fn sum_precise() {
+    let mut context = Context::default();
+    let init = r#"
+        var a = Math.sumPrecise([1e20, 0.1, -1e20]);
+        var b = Math.sumPrecise([0.1, 0.2, 0.3]);
+        var c = Math.sumPrecise([1e308, 1e308, -1e308]);
+        var d = Math.sumPrecise([Number.MAX_VALUE, Number.MAX_VALUE]);
+        var e = Math.sumPrecise([5e-324, -5e-324, 5e-324]);
+        "#;
+
+    eprintln!("{}", forward(&mut context, init));
+
+    let a = forward_val(&mut context, "a").unwrap();
+    let b = forward_val(&mut context, "b").unwrap();
+    let c = forward_val(&mut context, "c").unwrap();
+    let d = forward(&mut context, "d");
+    let e = forward_val(&mut context, "e").unwrap();
+
+    assert_eq!(a.to_number(&mut context).unwrap(), 0.1);
+    assert_eq!(b.to_number(&mut context).unwrap(), 0.6);
+    assert_eq!(c.to_number(&mut context).unwrap(), 1e308);
+    assert_eq!(d, "Infinity");
+    assert_eq!(e.to_number(&mut context).unwrap(), 5e-324);
+
+    assert_eq!(
+        forward(&mut context, "Object.is(Math.sumPrecise([]), -0)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.is(Math.sumPrecise([-0, -0]), -0)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.is(Math.sumPrecise([-0, 0]), 0)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Math.sumPrecise([Infinity, 1, -Infinity])"),
+        "NaN"
+    );
+    assert_eq!(
+        forward(&mut context, "Math.sumPrecise([-Infinity, 1e308, 1e308])"),
+        "-Infinity"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Math.sumPrecise([1, '2']); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
 #[test]
 fn tan() {
     let mut context = Context::default();
@@ -2,12 +2,14 @@
 
 pub mod array;
 pub mod array_buffer;
+pub mod atomics;
 pub mod bigint;
 pub mod boolean;
 #[cfg(feature = "console")]
 pub mod console;
 pub mod dataview;
 pub mod date;
+pub mod disposable_stack;
 pub mod error;
 pub mod function;
 pub mod generator;
@@ -15,36 +17,47 @@ pub mod generator_function;
 pub mod global_this;
 pub mod infinity;
 pub mod intl;
+pub(crate) mod iso8601;
 pub mod iterable;
+pub mod iterator;
 pub mod json;
 pub mod map;
 pub mod math;
 pub mod nan;
 pub mod number;
 pub mod object;
+pub mod promise;
 pub mod proxy;
 pub mod reflect;
 pub mod regexp;
 pub mod set;
+pub mod shadow_realm;
 pub mod string;
+pub mod structured_clone;
 pub mod symbol;
+#[cfg(feature = "temporal")]
+pub mod temporal;
 pub mod typed_array;
 pub mod undefined;
+pub mod weak;
 
 pub(crate) use self::{
     array::{array_iterator::ArrayIterator, Array},
+    atomics::Atomics,
     bigint::BigInt,
     boolean::Boolean,
     dataview::DataView,
     date::Date,
+    disposable_stack::{AsyncDisposableStack, DisposableStack},
     error::{
-        AggregateError, Error, EvalError, RangeError, ReferenceError, SyntaxError, TypeError,
-        UriError,
+        AggregateError, Error, EvalError, RangeError, ReferenceError, SuppressedError, SyntaxError,
+        TypeError, UriError,
     },
     function::BuiltInFunctionObject,
     global_this::GlobalThis,
     infinity::Infinity,
     intl::Intl,
+    iterator::Iterator,
     json::Json,
     map::map_iterator::MapIterator,
     map::Map,
@@ -53,23 +66,29 @@ pub(crate) use self::{
     number::Number,
     object::for_in_iterator::ForInIterator,
     object::Object as BuiltInObjectObject,
+    promise::Promise,
     proxy::Proxy,
     reflect::Reflect,
     regexp::RegExp,
     set::set_iterator::SetIterator,
     set::Set,
+    shadow_realm::ShadowRealm,
     string::String,
+    structured_clone::StructuredClone,
     symbol::Symbol,
     typed_array::{
         BigInt64Array, BigUint64Array, Float32Array, Float64Array, Int16Array, Int32Array,
         Int8Array, Uint16Array, Uint32Array, Uint8Array, Uint8ClampedArray,
     },
     undefined::Undefined,
+    weak::{FinalizationRegistry, WeakMap, WeakRef},
 };
 
 use crate::{
     builtins::{
-        array_buffer::ArrayBuffer, generator::Generator, generator_function::GeneratorFunction,
+        array_buffer::{shared::SharedArrayBuffer, ArrayBuffer},
+        generator::Generator,
+        generator_function::GeneratorFunction,
         typed_array::TypedArray,
     },
     property::{Attribute, PropertyDescriptor},
@@ -143,9 +162,12 @@ pub fn init(context: &mut Context) {
         Math,
         Intl,
         Json,
+        Iterator,
         Array,
         Proxy,
         ArrayBuffer,
+        SharedArrayBuffer,
+        Atomics,
         BigInt,
         Boolean,
         Date,
@@ -176,13 +198,25 @@ pub fn init(context: &mut Context) {
         EvalError,
         UriError,
         AggregateError,
+        SuppressedError,
         Reflect,
         Generator,
-        GeneratorFunction
+        GeneratorFunction,
+        WeakRef,
+        FinalizationRegistry,
+        WeakMap,
+        Promise,
+        DisposableStack,
+        AsyncDisposableStack,
+        ShadowRealm,
+        StructuredClone
     };
 
     #[cfg(feature = "console")]
     init_builtin::<console::Console>(context);
+
+    #[cfg(feature = "temporal")]
+    init_builtin::<temporal::Temporal>(context);
 }
 
 pub trait JsArgs {
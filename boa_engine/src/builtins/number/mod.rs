@@ -25,18 +25,19 @@ use crate::{
     Context, JsResult,
 };
 use boa_profiler::Profiler;
-use num_traits::{float::FloatCore, Num};
+use num_traits::Num;
 
 mod conversions;
+mod to_string;
 
 pub(crate) use conversions::{f64_to_int32, f64_to_uint32};
 use tap::{Conv, Pipe};
+pub(crate) use to_string::f64_to_fixed;
+use to_string::{f64_to_exponential, f64_to_precision, f64_to_radix_string};
 
 #[cfg(test)]
 mod tests;
 
-const BUF_SIZE: usize = 2200;
-
 /// `Number` implementation.
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Number;
@@ -228,21 +229,18 @@ impl Number {
         if !this_num.is_finite() {
             return Ok(JsValue::new(Self::to_native_string(this_num)));
         }
-        // Get rid of the '-' sign for -0.0
-        let this_num = if this_num == 0. { 0. } else { this_num };
-        let this_str_num = match precision {
-            None => f64_to_exponential(this_num),
-            Some(IntegerOrInfinity::Integer(precision)) if (0..=100).contains(&precision) =>
-            // 5. If f < 0 or f > 100, throw a RangeError exception.
-            {
-                f64_to_exponential_with_precision(this_num, precision as usize)
+        let fraction_digits = match precision {
+            None => None,
+            Some(IntegerOrInfinity::Integer(precision)) if (0..=100).contains(&precision) => {
+                Some(precision as usize)
             }
+            // 5. If f < 0 or f > 100, throw a RangeError exception.
             _ => {
                 return context
                     .throw_range_error("toExponential() argument must be between 0 and 100")
             }
         };
-        Ok(JsValue::new(this_str_num))
+        Ok(JsValue::new(f64_to_exponential(this_num, fraction_digits)))
     }
 
     /// `Number.prototype.toFixed( [digits] )`
@@ -277,16 +275,12 @@ impl Number {
             })? as usize;
 
         // 6. If x is not finite, return ! Number::toString(x).
-        if !this_num.is_finite() {
+        // 10. If x ≥ 10^21, then
+        //     a. Let m be ! ToString(𝔽(x)).
+        if !this_num.is_finite() || this_num.abs() >= 1.0e21 {
             Ok(JsValue::new(Self::to_native_string(this_num)))
-        // 10. If x ≥ 10^21, then let m be ! ToString(𝔽(x)).
-        } else if this_num >= 1.0e21 {
-            Ok(JsValue::new(f64_to_exponential(this_num)))
         } else {
-            // Get rid of the '-' sign for -0.0 because of 9. If x < 0, then set s to "-".
-            let this_num = if this_num == 0_f64 { 0_f64 } else { this_num };
-            let this_fixed_num = format!("{this_num:.precision$}");
-            Ok(JsValue::new(this_fixed_num))
+            Ok(JsValue::new(f64_to_fixed(this_num, precision)))
         }
     }
 
@@ -294,113 +288,52 @@ impl Number {
     ///
     /// The `toLocaleString()` method returns a string with a language-sensitive representation of this number.
     ///
-    /// Note that while this technically conforms to the Ecma standard, it does no actual
-    /// internationalization logic.
-    ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///  - [MDN documentation][mdn]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-number.prototype.tolocalestring
+    /// [spec]: https://tc39.es/ecma402/#sup-number.prototype.tolocalestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toLocaleString
+    #[cfg(feature = "intl")]
     #[allow(clippy::wrong_self_convention)]
     pub(crate) fn to_locale_string(
         this: &JsValue,
-        _: &[JsValue],
+        args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let this_num = Self::this_number_value(this, context)?;
-        let this_str_num = this_num.to_string();
-        Ok(JsValue::new(this_str_num))
+        use crate::builtins::intl::NumberFormat;
+
+        // 1. Let x be ? thisNumberValue(this value).
+        let x = Self::this_number_value(this, context)?;
+
+        // 2. Let numberFormat be ? Construct(%NumberFormat%, « locales, options »).
+        let number_format =
+            NumberFormat::new(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+
+        // 3. Return ? FormatNumeric(numberFormat, x).
+        Ok(number_format.format(x).into())
     }
 
-    /// `flt_str_to_exp` - used in `to_precision`
+    /// `Number.prototype.toLocaleString( [locales [, options]] )`
     ///
-    /// This function traverses a string representing a number,
-    /// returning the floored log10 of this number.
+    /// Without the `intl` feature there is no locale data, so the number is formatted as with
+    /// `toString`, ignoring the arguments.
     ///
-    fn flt_str_to_exp(flt: &str) -> i32 {
-        let mut non_zero_encountered = false;
-        let mut dot_encountered = false;
-        for (i, c) in flt.chars().enumerate() {
-            if c == '.' {
-                if non_zero_encountered {
-                    return (i as i32) - 1;
-                }
-                dot_encountered = true;
-            } else if c != '0' {
-                if dot_encountered {
-                    return 1 - (i as i32);
-                }
-                non_zero_encountered = true;
-            }
-        }
-        (flt.len() as i32) - 1
-    }
-
-    /// `round_to_precision` - used in `to_precision`
-    ///
-    /// This procedure has two roles:
-    /// - If there are enough or more than enough digits in the
-    ///   string to show the required precision, the number
-    ///   represented by these digits is rounded using string
-    ///   manipulation.
-    /// - Else, zeroes are appended to the string.
-    /// - Additionnally, sometimes the exponent was wrongly computed and
-    ///   while up-rounding we find that we need an extra digit. When this
-    ///   happens, we return true so that the calling context can adjust
-    ///   the exponent. The string is kept at an exact length of `precision`.
-    ///
-    /// When this procedure returns, `digits` is exactly `precision` long.
-    ///
-    fn round_to_precision(digits: &mut String, precision: usize) -> bool {
-        if digits.len() > precision {
-            let to_round = digits.split_off(precision);
-            let mut digit = digits
-                .pop()
-                .expect("already checked that lenght is bigger than precision")
-                as u8;
-            if let Some(first) = to_round.chars().next() {
-                if first > '4' {
-                    digit += 1;
-                }
-            }
-
-            if digit as char == ':' {
-                // ':' is '9' + 1
-                // need to propagate the increment backward
-                let mut replacement = String::from("0");
-                let mut propagated = false;
-                for c in digits.chars().rev() {
-                    let d = match (c, propagated) {
-                        ('0'..='8', false) => (c as u8 + 1) as char,
-                        (_, false) => '0',
-                        (_, true) => c,
-                    };
-                    replacement.push(d);
-                    if d != '0' {
-                        propagated = true;
-                    }
-                }
-                digits.clear();
-                let replacement = if propagated {
-                    replacement.as_str()
-                } else {
-                    digits.push('1');
-                    &replacement.as_str()[1..]
-                };
-                for c in replacement.chars().rev() {
-                    digits.push(c);
-                }
-                !propagated
-            } else {
-                digits.push(digit as char);
-                false
-            }
-        } else {
-            digits.push_str(&"0".repeat(precision - digits.len()));
-            false
-        }
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-number.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toLocaleString
+    #[cfg(not(feature = "intl"))]
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn to_locale_string(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let this_num = Self::this_number_value(this, context)?;
+        Ok(JsValue::new(Self::to_native_string(this_num)))
     }
 
     /// `Number.prototype.toPrecision( [precision] )`
@@ -422,7 +355,7 @@ impl Number {
         let precision = args.get_or_undefined(0);
 
         // 1 & 6
-        let mut this_num = Self::this_number_value(this, context)?;
+        let this_num = Self::this_number_value(this, context)?;
         // 2
         if precision.is_undefined() {
             return Self::to_string(this, &[], context);
@@ -445,210 +378,17 @@ impl Number {
                 );
             }
         };
-        let precision_i32 = precision as i32;
-
-        // 7
-        let mut prefix = String::new(); // spec: 's'
-        let mut suffix: String; // spec: 'm'
-        let mut exponent: i32; // spec: 'e'
-
-        // 8
-        if this_num < 0.0 {
-            prefix.push('-');
-            this_num = -this_num;
-        }
-
-        // 9
-        if this_num == 0.0 {
-            suffix = "0".repeat(precision);
-            exponent = 0;
-        // 10
-        } else {
-            // Due to f64 limitations, this part differs a bit from the spec,
-            // but has the same effect. It manipulates the string constructed
-            // by `format`: digits with an optional dot between two of them.
-            suffix = format!("{this_num:.100}");
-
-            // a: getting an exponent
-            exponent = Self::flt_str_to_exp(&suffix);
-            // b: getting relevant digits only
-            if exponent < 0 {
-                suffix = suffix.split_off((1 - exponent) as usize);
-            } else if let Some(n) = suffix.find('.') {
-                suffix.remove(n);
-            }
-            // impl: having exactly `precision` digits in `suffix`
-            if Self::round_to_precision(&mut suffix, precision) {
-                exponent += 1;
-            }
-
-            // c: switching to scientific notation
-            let great_exp = exponent >= precision_i32;
-            if exponent < -6 || great_exp {
-                // ii
-                if precision > 1 {
-                    suffix.insert(1, '.');
-                }
-                // vi
-                suffix.push('e');
-                // iii
-                if great_exp {
-                    suffix.push('+');
-                }
-                // iv, v
-                suffix.push_str(&exponent.to_string());
-
-                return Ok(JsValue::new(prefix + &suffix));
-            }
-        }
-
-        // 11
-        let e_inc = exponent + 1;
-        if e_inc == precision_i32 {
-            return Ok(JsValue::new(prefix + &suffix));
-        }
-
-        // 12
-        if exponent >= 0 {
-            suffix.insert(e_inc as usize, '.');
-        // 13
-        } else {
-            prefix.push('0');
-            prefix.push('.');
-            prefix.push_str(&"0".repeat(-e_inc as usize));
-        }
-
-        // 14
-        Ok(JsValue::new(prefix + &suffix))
-    }
-
-    // https://golang.org/src/math/nextafter.go
-    #[inline]
-    fn next_after(x: f64, y: f64) -> f64 {
-        if x.is_nan() || y.is_nan() {
-            f64::NAN
-        } else if (x - y) == 0. {
-            x
-        } else if x == 0.0 {
-            f64::from_bits(1).copysign(y)
-        } else if y > x || x > 0.0 {
-            f64::from_bits(x.to_bits() + 1)
-        } else {
-            f64::from_bits(x.to_bits() - 1)
-        }
-    }
-
-    // https://chromium.googlesource.com/v8/v8/+/refs/heads/master/src/numbers/conversions.cc#1230
-    #[allow(clippy::wrong_self_convention)]
-    pub(crate) fn to_native_string_radix(mut value: f64, radix: u8) -> String {
-        assert!(radix >= 2);
-        assert!(radix <= 36);
-        assert!(value.is_finite());
-        // assert_ne!(0.0, value);
-
-        // Character array used for conversion.
-        // Temporary buffer for the result. We start with the decimal point in the
-        // middle and write to the left for the integer part and to the right for the
-        // fractional part. 1024 characters for the exponent and 52 for the mantissa
-        // either way, with additional space for sign, decimal point and string
-        // termination should be sufficient.
-        let mut buffer: [u8; BUF_SIZE] = [0; BUF_SIZE];
-        let (int_buf, frac_buf) = buffer.split_at_mut(BUF_SIZE / 2);
-        let mut fraction_cursor = 0;
-        let negative = value.is_sign_negative();
-        if negative {
-            value = -value;
-        }
-        // Split the value into an integer part and a fractional part.
-        // let mut integer = value.trunc();
-        // let mut fraction = value.fract();
-        let mut integer = value.floor();
-        let mut fraction = value - integer;
-
-        // We only compute fractional digits up to the input double's precision.
-        let mut delta = 0.5 * (Self::next_after(value, f64::MAX) - value);
-        delta = Self::next_after(0.0, f64::MAX).max(delta);
-        assert!(delta > 0.0);
-        if fraction >= delta {
-            // Insert decimal point.
-            frac_buf[fraction_cursor] = b'.';
-            fraction_cursor += 1;
-            loop {
-                // Shift up by one digit.
-                fraction *= f64::from(radix);
-                delta *= f64::from(radix);
-                // Write digit.
-                let digit = fraction as u32;
-                frac_buf[fraction_cursor] = std::char::from_digit(digit, u32::from(radix))
-                    .expect("radix already checked")
-                    as u8;
-                fraction_cursor += 1;
-                // Calculate remainder.
-                fraction -= f64::from(digit);
-                // Round to even.
-                if fraction + delta > 1.0
-                    && (fraction > 0.5 || (fraction - 0.5).abs() < f64::EPSILON && digit & 1 != 0)
-                {
-                    loop {
-                        // We need to back trace already written digits in case of carry-over.
-                        fraction_cursor -= 1;
-                        if fraction_cursor == 0 {
-                            //              CHECK_EQ('.', buffer[fraction_cursor]);
-                            // Carry over to the integer part.
-                            integer += 1.;
-                        } else {
-                            let c: u8 = frac_buf[fraction_cursor];
-                            // Reconstruct digit.
-                            let digit_0 = (c as char)
-                                .to_digit(10)
-                                .expect("charactre was not a valid digit");
-                            if digit_0 + 1 >= u32::from(radix) {
-                                continue;
-                            }
-                            frac_buf[fraction_cursor] =
-                                std::char::from_digit(digit_0 + 1, u32::from(radix))
-                                    .expect("digit was not a valid number in the given radix")
-                                    as u8;
-                            fraction_cursor += 1;
-                        }
-                        break;
-                    }
-                    break;
-                }
-                if fraction < delta {
-                    break;
-                }
-            }
-        }
-
-        // Compute integer digits. Fill unrepresented digits with zero.
-        let mut int_iter = int_buf.iter_mut().enumerate().rev();
-        while FloatCore::integer_decode(integer / f64::from(radix)).1 > 0 {
-            integer /= f64::from(radix);
-            *int_iter.next().expect("integer buffer exhausted").1 = b'0';
-        }
-
-        loop {
-            let remainder = integer % f64::from(radix);
-            *int_iter.next().expect("integer buffer exhausted").1 =
-                std::char::from_digit(remainder as u32, u32::from(radix))
-                    .expect("remainder not a digit in the given number") as u8;
-            integer = (integer - remainder) / f64::from(radix);
-            if integer <= 0f64 {
-                break;
-            }
-        }
-        // Add sign and terminate string.
-        if negative {
-            *int_iter.next().expect("integer buffer exhausted").1 = b'-';
-        }
-        assert!(fraction_cursor < BUF_SIZE);
-
-        let integer_cursor = int_iter.next().expect("integer buffer exhausted").0 + 1;
-        let fraction_cursor = fraction_cursor + BUF_SIZE / 2;
-        String::from_utf8_lossy(&buffer[integer_cursor..fraction_cursor]).into()
+        // 6 - 14
+        Ok(JsValue::new(f64_to_precision(this_num, precision)))
     }
 
+    /// `Number::toString ( x )`, converting a number to its shortest round-trip decimal
+    /// representation with the Ryu algorithm.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-numeric-types-number-tostring
     #[allow(clippy::wrong_self_convention)]
     pub(crate) fn to_native_string(x: f64) -> String {
         let mut buffer = ryu_js::Buffer::new();
@@ -716,7 +456,7 @@ impl Number {
         // }
 
         // 6. Return the String representation of this Number value using the radix specified by radixNumber.
-        Ok(JsValue::new(Self::to_native_string_radix(x, radix_number)))
+        Ok(JsValue::new(f64_to_radix_string(x, radix_number)))
     }
 
     /// `Number.prototype.toString()`
@@ -1161,30 +901,32 @@ impl Number {
         (x < y).into()
     }
 
+    /// The abstract operation `Number::exponentiate` takes arguments
+    /// base (a Number) and exponent (a Number). It performs the following steps when called:
+    ///
+    /// <https://tc39.es/ecma262/#sec-numeric-types-number-exponentiate>
+    #[inline]
+    #[allow(clippy::float_cmp)]
+    pub(crate) fn exponentiate(base: f64, exponent: f64) -> f64 {
+        // 1. If exponent is NaN, return NaN.
+        // 2. If exponent is +0𝔽 or exponent is -0𝔽, return 1𝔽.
+        // 3. If base is NaN, return NaN.
+        // 9. If exponent is +∞𝔽, then
+        //     b. If abs(ℝ(base)) = 1, return NaN.
+        // 10. If exponent is -∞𝔽, then
+        //     b. If abs(ℝ(base)) = 1, return NaN.
+        if base.abs() == 1.0 && exponent.is_infinite() {
+            return f64::NAN;
+        }
+
+        // The remaining steps agree with the C `pow` function, so they are delegated to the
+        // platform independent implementation of `libm`.
+        libm::pow(base, exponent)
+    }
+
     #[inline]
     pub(crate) fn not(x: f64) -> i32 {
         let x = f64_to_int32(x);
         !x
     }
 }
-
-/// Helper function that formats a float as a ES6-style exponential number string.
-fn f64_to_exponential(n: f64) -> String {
-    match n.abs() {
-        x if x >= 1.0 || x == 0.0 => format!("{n:e}").replace('e', "e+"),
-        _ => format!("{n:e}"),
-    }
-}
-
-/// Helper function that formats a float as a ES6-style exponential number string with a given precision.
-// We can't use the same approach as in `f64_to_exponential`
-// because in cases like (0.999).toExponential(0) the result will be 1e0.
-// Instead we get the index of 'e', and if the next character is not '-' we insert the plus sign
-fn f64_to_exponential_with_precision(n: f64, prec: usize) -> String {
-    let mut res = format!("{n:.prec$e}");
-    let idx = res.find('e').expect("'e' not found in exponential string");
-    if res.as_bytes()[idx + 1] != b'-' {
-        res.insert(idx + 1, '+');
-    }
-    res
-}
@@ -100,6 +100,90 @@ fn to_fixed() {
     assert_eq!(nan_fixed, "\"NaN\"");
 }
 
+#[test]
+fn to_fixed_rounding() {
+    let mut context = Context::default();
+
+    assert_eq!(forward(&mut context, "(1.25).toFixed(1)"), "\"1.3\"");
+    assert_eq!(forward(&mut context, "(1.005).toFixed(2)"), "\"1.00\"");
+    assert_eq!(forward(&mut context, "(2.5).toFixed(0)"), "\"3\"");
+    assert_eq!(forward(&mut context, "(-1.5).toFixed(0)"), "\"-2\"");
+    assert_eq!(forward(&mut context, "(9.96).toFixed(1)"), "\"10.0\"");
+    assert_eq!(
+        forward(&mut context, "(0.000001).toFixed(7)"),
+        "\"0.0000010\""
+    );
+    assert_eq!(
+        forward(&mut context, "(-0.0000001).toFixed(2)"),
+        "\"-0.00\""
+    );
+    assert_eq!(
+        forward(&mut context, "(1e20).toFixed(2)"),
+        "\"100000000000000000000.00\""
+    );
+    assert_eq!(forward(&mut context, "(-1e21).toFixed(2)"), "\"-1e+21\"");
+}
+
+#[test]
+fn to_exponential_rounding() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(&mut context, "(1.25).toExponential(1)"),
+        "\"1.3e+0\""
+    );
+    assert_eq!(
+        forward(&mut context, "(0.999).toExponential(0)"),
+        "\"1e+0\""
+    );
+    assert_eq!(
+        forward(&mut context, "(123456).toExponential(2)"),
+        "\"1.23e+5\""
+    );
+    assert_eq!(forward(&mut context, "(1e-7).toExponential()"), "\"1e-7\"");
+    assert_eq!(
+        forward(&mut context, "(-0).toExponential(2)"),
+        "\"0.00e+0\""
+    );
+    assert_eq!(
+        forward(&mut context, "(5e-324).toExponential()"),
+        "\"5e-324\""
+    );
+}
+
+#[test]
+fn to_precision_rounding() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(&mut context, "(1e-200).toPrecision(2)"),
+        "\"1.0e-200\""
+    );
+    assert_eq!(
+        forward(&mut context, "(0.00000123).toPrecision(2)"),
+        "\"0.0000012\""
+    );
+    assert_eq!(forward(&mut context, "(1.45).toPrecision(2)"), "\"1.4\"");
+    assert_eq!(forward(&mut context, "(99.99).toPrecision(3)"), "\"100\"");
+    assert_eq!(
+        forward(&mut context, "(99.99).toPrecision(2)"),
+        "\"1.0e+2\""
+    );
+}
+
+#[test]
+fn to_string_radix_digits() {
+    let mut context = Context::default();
+
+    assert_eq!(forward(&mut context, "(35.5).toString(36)"), "\"z.i\"");
+    assert_eq!(forward(&mut context, "(255.5).toString(16)"), "\"ff.8\"");
+    assert_eq!(forward(&mut context, "(-0.5).toString(2)"), "\"-0.1\"");
+    assert_eq!(
+        forward(&mut context, "(2 ** 60).toString(2)"),
+        format!("\"1{}\"", "0".repeat(60))
+    );
+}
+
 #[test]
 fn to_locale_string() {
     let mut context = Context::default();
@@ -110,9 +194,6 @@ fn to_locale_string() {
         var neg_locale = Number(-25).toLocaleString();
         "#;
 
-    // TODO: We don't actually do any locale checking here
-    // To honor the spec we should print numbers according to user locale.
-
     eprintln!("{}", forward(&mut context, init));
     let default_locale = forward(&mut context, "default_locale");
     let small_locale = forward(&mut context, "small_locale");
@@ -121,10 +202,44 @@ fn to_locale_string() {
 
     assert_eq!(default_locale, "\"0\"");
     assert_eq!(small_locale, "\"5\"");
+    #[cfg(feature = "intl")]
+    assert_eq!(big_locale, "\"345,600\"");
+    #[cfg(not(feature = "intl"))]
     assert_eq!(big_locale, "\"345600\"");
     assert_eq!(neg_locale, "\"-25\"");
 }
 
+#[cfg(feature = "intl")]
+#[test]
+fn to_locale_string_options() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(&mut context, "(1234.5678).toLocaleString('en-US')"),
+        "\"1,234.568\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "(0.256).toLocaleString('en', { style: 'percent', minimumFractionDigits: 1 })"
+        ),
+        "\"25.6%\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "(5).toLocaleString(undefined, { minimumIntegerDigits: 3, signDisplay: 'always' })"
+        ),
+        "\"+005\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { (5).toLocaleString('x-') } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
 #[test]
 fn to_precision() {
     let mut context = Context::default();
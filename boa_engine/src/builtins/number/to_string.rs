@@ -0,0 +1,395 @@
+//! Conversions from numbers to their string representations.
+//!
+//! Shortest round-trip conversions are delegated to [`ryu_js`] and to the shortest mode of the
+//! standard library float formatting (Grisu with a Dragon4 fallback), while the fixed precision
+//! conversions of `toFixed`, `toExponential` and `toPrecision` round the exact decimal expansion
+//! of the number, breaking ties upwards as required by the specification.
+
+use num_traits::float::FloatCore;
+
+/// The digits of the numbers in radixes up to 36.
+const RADIX_DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// The number of significant decimal digits needed to represent any finite `f64` exactly.
+const MAX_EXACT_DIGITS: usize = 767;
+
+/// The decimal digits `d0.d1d2... × 10^exponent` of a positive finite number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Digits {
+    /// The ASCII digits, without leading or trailing zeros.
+    digits: Vec<u8>,
+    exponent: i32,
+}
+
+impl Digits {
+    /// Parses the output of the `{:e}` formatting of a positive number.
+    fn from_exponential(formatted: &str) -> Self {
+        let (mantissa, exponent) = formatted
+            .split_once('e')
+            .expect("exponential formatting must contain an exponent");
+        let mut digits: Vec<u8> = mantissa.bytes().filter(u8::is_ascii_digit).collect();
+        while digits.len() > 1 && digits.last() == Some(&b'0') {
+            digits.pop();
+        }
+
+        Self {
+            digits,
+            exponent: exponent
+                .parse()
+                .expect("exponential formatting must have a valid exponent"),
+        }
+    }
+
+    /// The shortest digits that round-trip to `x`.
+    fn shortest(x: f64) -> Self {
+        Self::from_exponential(&format!("{x:e}"))
+    }
+
+    /// All the digits of the exact decimal expansion of `x`.
+    fn exact(x: f64) -> Self {
+        Self::from_exponential(&format!("{x:.MAX_EXACT_DIGITS$e}"))
+    }
+
+    /// Rounds the number to `count` significant digits, breaking ties upwards.
+    ///
+    /// The result has exactly `count` digits, unless `count` is zero and the number rounds down
+    /// to zero, in which case the result has no digits.
+    fn round(&self, count: usize) -> Self {
+        let mut digits: Vec<u8> = self
+            .digits
+            .iter()
+            .copied()
+            .chain(std::iter::repeat(b'0'))
+            .take(count)
+            .collect();
+        let mut exponent = self.exponent;
+
+        // Since the digits are exact, a first dropped digit of 5 or more means that the dropped
+        // part is at least half of the last kept digit.
+        if self.digits.get(count).map_or(false, |digit| *digit >= b'5') {
+            if let Some(index) = digits.iter().rposition(|digit| *digit != b'9') {
+                digits[index] += 1;
+                digits[index + 1..].fill(b'0');
+            } else {
+                digits.fill(b'0');
+                match digits.first_mut() {
+                    Some(first) => *first = b'1',
+                    None => digits.push(b'1'),
+                }
+                exponent += 1;
+            }
+        }
+
+        Self { digits, exponent }
+    }
+}
+
+/// Formats the exponent of the exponential notation, as in `e+21` or `e-7`.
+fn exponent_suffix(exponent: i32) -> String {
+    if exponent < 0 {
+        format!("e{exponent}")
+    } else {
+        format!("e+{exponent}")
+    }
+}
+
+/// Formats the significand of the exponential notation, as in `1.2345`.
+fn significand(digits: &[u8]) -> String {
+    let mut significand = String::with_capacity(digits.len() + 1);
+    significand.push(char::from(digits[0]));
+    if digits.len() > 1 {
+        significand.push('.');
+        significand.extend(digits[1..].iter().copied().map(char::from));
+    }
+    significand
+}
+
+/// Gets the sign prefix of a finite number, and its absolute value.
+fn split_sign(x: f64) -> (&'static str, f64) {
+    if x < 0.0 {
+        ("-", -x)
+    } else {
+        // This also gets rid of the sign of `-0`.
+        ("", x.abs())
+    }
+}
+
+/// The steps of `Number.prototype.toExponential ( fractionDigits )` for a finite `x`, with
+/// `fraction_digits` being `None` if they are undefined.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-number.prototype.toexponential
+pub(super) fn f64_to_exponential(x: f64, fraction_digits: Option<usize>) -> String {
+    // 6. Set x to ℝ(x).
+    // 7. Let s be the empty String.
+    // 8. If x < 0, then
+    //     a. Set s to "-".
+    //     b. Set x to -x.
+    let (sign, x) = split_sign(x);
+
+    // 9. If x = 0, then
+    //     a. Let m be the String value consisting of f + 1 occurrences of the code unit 0x0030 (DIGIT ZERO).
+    //     b. Let e be 0.
+    // 10. Else,
+    //     a. If fractionDigits is not undefined, then
+    //         i. Let e and n be integers such that 10^f ≤ n < 10^(f + 1) and for which n × 10^(e - f) - x is as close to zero as possible. If there are two such sets of e and n, pick the e and n for which n × 10^(e - f) is larger.
+    //     b. Else,
+    //         i. Let e, n, and f be integers such that f ≥ 0, 10^f ≤ n < 10^(f + 1), 𝔽(n × 10^(e - f)) is 𝔽(x), and f is as small as possible. Note that the decimal representation of n has f + 1 digits, n is not divisible by 10, and the least significant digit of n is not necessarily uniquely determined by these criteria.
+    //     c. Let m be the String value consisting of the digits of the decimal representation of n (in order, with no leading zeroes).
+    let Digits { digits, exponent } = match fraction_digits {
+        _ if x == 0.0 => Digits {
+            digits: vec![b'0'; fraction_digits.unwrap_or_default() + 1],
+            exponent: 0,
+        },
+        Some(fraction_digits) => Digits::exact(x).round(fraction_digits + 1),
+        None => Digits::shortest(x),
+    };
+
+    // 11. If f ≠ 0, then
+    //     a. Let a be the first code unit of m.
+    //     b. Let b be the other f code units of m.
+    //     c. Set m to the string-concatenation of a, ".", and b.
+    // 12. If e = 0, then
+    //     a. Let c be "+".
+    //     b. Let d be "0".
+    // 13. Else,
+    //     a. If e > 0, let c be "+".
+    //     b. Else,
+    //         i. Assert: e < 0.
+    //         ii. Let c be "-".
+    //         iii. Set e to -e.
+    //     c. Let d be the String value consisting of the digits of the decimal representation of e (in order, with no leading zeroes).
+    // 14. Set m to the string-concatenation of m, "e", c, and d.
+    // 15. Return the string-concatenation of s and m.
+    format!(
+        "{sign}{}{}",
+        significand(&digits),
+        exponent_suffix(exponent)
+    )
+}
+
+/// The steps of `Number.prototype.toFixed ( fractionDigits )` for a finite `x` smaller than
+/// 10<sup>21</sup> in absolute value.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-number.prototype.tofixed
+pub(crate) fn f64_to_fixed(x: f64, fraction_digits: usize) -> String {
+    // 7. Set x to ℝ(x).
+    // 8. Let s be the empty String.
+    // 9. If x < 0, then
+    //     a. Set s to "-".
+    //     b. Set x to -x.
+    let (sign, x) = split_sign(x);
+
+    // 11. Else,
+    //     a. Let n be an integer for which n / 10^f - x is as close to zero as possible. If there are two such n, pick the larger n.
+    //     b. If n = 0, let m be "0". Otherwise, let m be the String value consisting of the digits of the decimal representation of n (in order, with no leading zeroes).
+    let mut m = if x == 0.0 {
+        vec![b'0']
+    } else {
+        let digits = Digits::exact(x);
+        let integer_digits = digits.exponent + 1 + fraction_digits as i32;
+        if integer_digits < 0 {
+            vec![b'0']
+        } else {
+            let rounded = digits.round(integer_digits as usize);
+            let integer_digits = (rounded.exponent + 1 + fraction_digits as i32).max(1) as usize;
+            let mut m = rounded.digits;
+            if m.is_empty() {
+                m.push(b'0');
+            }
+            m.resize(integer_digits.max(m.len()), b'0');
+            m
+        }
+    };
+
+    //     c. If f ≠ 0, then
+    if fraction_digits != 0 {
+        // i. Let k be the length of m.
+        // ii. If k ≤ f, then
+        if m.len() <= fraction_digits {
+            // 1. Let z be the String value consisting of f + 1 - k occurrences of the code unit 0x0030 (DIGIT ZERO).
+            // 2. Set m to the string-concatenation of z and m.
+            // 3. Set k to f + 1.
+            let zeros = fraction_digits + 1 - m.len();
+            m.splice(0..0, std::iter::repeat(b'0').take(zeros));
+        }
+
+        // iii. Let a be the first k - f code units of m.
+        // iv. Let b be the other f code units of m.
+        // v. Set m to the string-concatenation of a, ".", and b.
+        m.insert(m.len() - fraction_digits, b'.');
+    }
+
+    // 12. Return the string-concatenation of s and m.
+    format!(
+        "{sign}{}",
+        String::from_utf8(m).expect("the digits must be valid UTF-8")
+    )
+}
+
+/// The steps of `Number.prototype.toPrecision ( precision )` for a finite `x`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-number.prototype.toprecision
+pub(super) fn f64_to_precision(x: f64, precision: usize) -> String {
+    // 6. Set x to ℝ(x).
+    // 7. Let s be the empty String.
+    // 8. If x < 0, then
+    //     a. Set s to the code unit 0x002D (HYPHEN-MINUS).
+    //     b. Set x to -x.
+    let (sign, x) = split_sign(x);
+
+    // 9. If x = 0, then
+    //     a. Let m be the String value consisting of p occurrences of the code unit 0x0030 (DIGIT ZERO).
+    //     b. Let e be 0.
+    // 10. Else,
+    //     a. Let e and n be integers such that 10^(p - 1) ≤ n < 10^p and for which n × 10^(e - p + 1) - x is as close to zero as possible. If there are two such sets of e and n, pick the e and n for which n × 10^(e - p + 1) is larger.
+    //     b. Let m be the String value consisting of the digits of the decimal representation of n (in order, with no leading zeroes).
+    let Digits { digits, exponent } = if x == 0.0 {
+        Digits {
+            digits: vec![b'0'; precision],
+            exponent: 0,
+        }
+    } else {
+        Digits::exact(x).round(precision)
+    };
+
+    //     c. If e < -6 or e ≥ p, then
+    if exponent < -6 || exponent >= precision as i32 {
+        // i. Assert: e ≠ 0.
+        // ii. If p ≠ 1, then
+        //     1. Let a be the first code unit of m.
+        //     2. Let b be the other p - 1 code units of m.
+        //     3. Set m to the string-concatenation of a, ".", and b.
+        // iii. If e > 0, then
+        //     1. Let c be the code unit 0x002B (PLUS SIGN).
+        // iv. Else,
+        //     1. Assert: e < 0.
+        //     2. Let c be the code unit 0x002D (HYPHEN-MINUS).
+        //     3. Set e to -e.
+        // v. Let d be the String value consisting of the digits of the decimal representation of e (in order, with no leading zeroes).
+        // vi. Return the string-concatenation of s, m, the code unit 0x0065 (LATIN SMALL LETTER E), c, and d.
+        return format!(
+            "{sign}{}{}",
+            significand(&digits),
+            exponent_suffix(exponent)
+        );
+    }
+
+    let mut m = String::from_utf8(digits).expect("the digits must be valid UTF-8");
+
+    // 11. If e = p - 1, return the string-concatenation of s and m.
+    // 12. If e ≥ 0, then
+    if exponent >= 0 {
+        // a. Set m to the string-concatenation of the first e + 1 code units of m, the code unit 0x002E (FULL STOP), and the remaining p - (e + 1) code units of m.
+        if exponent + 1 < precision as i32 {
+            m.insert(exponent as usize + 1, '.');
+        }
+    // 13. Else,
+    } else {
+        // a. Set m to the string-concatenation of the code unit 0x0030 (DIGIT ZERO), the code unit 0x002E (FULL STOP), -(e + 1) occurrences of the code unit 0x0030 (DIGIT ZERO), and the String m.
+        m.insert_str(0, &"0".repeat(-(exponent + 1) as usize));
+        m.insert_str(0, "0.");
+    }
+
+    // 14. Return the string-concatenation of s and m.
+    format!("{sign}{m}")
+}
+
+/// Gets the smallest number greater than the non-negative finite `x`.
+fn next_up(x: f64) -> f64 {
+    f64::from_bits(x.to_bits() + 1)
+}
+
+/// Converts a finite number to its shortest representation in a radix between 2 and 36, with
+/// the same algorithm as V8's `DoubleToRadixCString`.
+///
+/// <https://chromium.googlesource.com/v8/v8/+/refs/heads/main/src/numbers/conversions.cc>
+#[allow(clippy::float_cmp)]
+pub(super) fn f64_to_radix_string(value: f64, radix: u8) -> String {
+    debug_assert!((2..=36).contains(&radix));
+    debug_assert!(value.is_finite());
+
+    let radix_digit = |digit: f64| char::from(RADIX_DIGITS[digit as usize]);
+    let float_radix = f64::from(radix);
+    let (sign, value) = split_sign(value);
+
+    // Split the value into an integer part and a fractional part.
+    let mut integer = value.floor();
+    let mut fraction = value - integer;
+
+    // We only compute fractional digits up to the input double's precision.
+    let mut delta = (0.5 * (next_up(value) - value)).max(next_up(0.0));
+    let mut fraction_digits = String::new();
+    if fraction >= delta {
+        loop {
+            // Shift up by one digit.
+            fraction *= float_radix;
+            delta *= float_radix;
+
+            // Write digit.
+            let digit = fraction.trunc();
+            fraction_digits.push(radix_digit(digit));
+
+            // Calculate remainder.
+            fraction -= digit;
+
+            // Round to even.
+            if (fraction > 0.5 || (fraction == 0.5 && digit % 2.0 == 1.0)) && fraction + delta > 1.0
+            {
+                // We need to back trace already written digits in case of carry-over.
+                loop {
+                    match fraction_digits.pop() {
+                        // Carry over to the integer part.
+                        None => integer += 1.0,
+                        Some(last) => {
+                            let last = last
+                                .to_digit(u32::from(radix))
+                                .expect("only digits of the radix were written");
+                            if last + 1 == u32::from(radix) {
+                                continue;
+                            }
+                            fraction_digits.push(radix_digit(f64::from(last + 1)));
+                        }
+                    }
+                    break;
+                }
+                break;
+            }
+
+            if fraction < delta {
+                break;
+            }
+        }
+    }
+
+    // Compute integer digits. Fill unrepresented digits with zero.
+    let mut integer_digits = Vec::new();
+    while FloatCore::integer_decode(integer / float_radix).1 > 0 {
+        integer /= float_radix;
+        integer_digits.push('0');
+    }
+    loop {
+        let remainder = integer % float_radix;
+        integer_digits.push(radix_digit(remainder));
+        integer = (integer - remainder) / float_radix;
+        if integer <= 0.0 {
+            break;
+        }
+    }
+
+    let mut result = String::from(sign);
+    result.extend(integer_digits.iter().rev());
+    if !fraction_digits.is_empty() {
+        result.push('.');
+        result.push_str(&fraction_digits);
+    }
+    result
+}
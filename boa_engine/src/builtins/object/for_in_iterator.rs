@@ -9,7 +9,7 @@ use crate::{
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
 use rustc_hash::FxHashSet;
-use std::collections::VecDeque;
+use std::{collections::VecDeque, rc::Rc};
 
 /// The `ForInIterator` object represents an iteration over some specific object.
 /// It implements the iterator protocol.
@@ -24,6 +24,10 @@ pub struct ForInIterator {
     visited_keys: FxHashSet<JsString>,
     remaining_keys: VecDeque<JsString>,
     object_was_visited: bool,
+    /// The cached keys of the object, if it has an enumeration cache.
+    #[unsafe_ignore_trace]
+    enumeration_cache: Option<Rc<EnumerationCache>>,
+    next_cached_key: usize,
 }
 
 impl ForInIterator {
@@ -31,10 +35,12 @@ impl ForInIterator {
 
     fn new(object: JsValue) -> Self {
         Self {
+            enumeration_cache: object.as_object().and_then(EnumerationCache::get),
             object,
             visited_keys: FxHashSet::default(),
             remaining_keys: VecDeque::default(),
             object_was_visited: false,
+            next_cached_key: 0,
         }
     }
 
@@ -73,6 +79,28 @@ impl ForInIterator {
             .and_then(|obj| obj.as_for_in_iterator_mut())
             .ok_or_else(|| context.construct_type_error("`this` is not a ForInIterator"))?;
         let mut object = iterator.object.to_object(context)?;
+
+        if let Some(cache) = iterator.enumeration_cache.clone() {
+            // A property deleted before it is visited must be skipped, so the keys are looked up
+            // again once the object or one of its prototypes has changed.
+            let unchanged = cache.is_valid(&object);
+            while let Some(key) = cache.keys.get(iterator.next_cached_key) {
+                iterator.next_cached_key += 1;
+                if unchanged || Self::is_enumerable(&object, key, context)? {
+                    return Ok(create_iter_result_object(
+                        JsValue::new(key.clone()),
+                        false,
+                        context,
+                    ));
+                }
+            }
+            return Ok(create_iter_result_object(
+                JsValue::undefined(),
+                true,
+                context,
+            ));
+        }
+
         loop {
             if !iterator.object_was_visited {
                 let keys = object.__own_property_keys__(context)?;
@@ -123,6 +151,20 @@ impl ForInIterator {
         }
     }
 
+    /// Returns `true` if the first property named `key` in the prototype chain of `object` is
+    /// enumerable.
+    fn is_enumerable(object: &JsObject, key: &JsString, context: &mut Context) -> JsResult<bool> {
+        let key = PropertyKey::from(key.clone());
+        let mut current = Some(object.clone());
+        while let Some(object) = current {
+            if let Some(desc) = object.__get_own_property__(&key, context)? {
+                return Ok(desc.expect_enumerable());
+            }
+            current = object.prototype().clone();
+        }
+        Ok(false)
+    }
+
     /// Create the `%ArrayIteratorPrototype%` object
     ///
     /// More information:
@@ -150,3 +192,76 @@ impl ForInIterator {
         for_in_iterator
     }
 }
+
+/// The enumerable string keys of an object and its prototypes, in the order in which a `for-in`
+/// loop visits them.
+///
+/// The cache is stored on the object with the layouts of the object and its prototypes, so the
+/// following loops over the object reuse the keys until a property is added, removed or made
+/// (non-)enumerable on one of these objects, or until one of their prototypes is replaced.
+#[derive(Debug)]
+pub(crate) struct EnumerationCache {
+    /// The layouts of the object and its prototypes, in the order of the prototype chain.
+    layouts: Vec<u64>,
+    keys: Vec<JsString>,
+}
+
+impl EnumerationCache {
+    /// Returns the keys of an object, collecting them if its cache is missing or outdated.
+    ///
+    /// Returns `None` if the keys of the object or one of its prototypes can't be cached, either
+    /// because the object is exotic or because it has indexed properties, which would make the
+    /// cache outdated as soon as an element is added.
+    fn get(object: &JsObject) -> Option<Rc<Self>> {
+        let cache = object.borrow().enumeration_cache().cloned();
+        if let Some(cache) = cache.filter(|cache| cache.is_valid(object)) {
+            return Some(cache);
+        }
+
+        let cache = Rc::new(Self::collect(object)?);
+        object.borrow_mut().set_enumeration_cache(cache.clone());
+        Some(cache)
+    }
+
+    /// Collects the keys of an object, or returns `None` if they can't be cached.
+    fn collect(object: &JsObject) -> Option<Self> {
+        let mut layouts = Vec::new();
+        let mut visited_keys = FxHashSet::default();
+        let mut keys = Vec::new();
+
+        let mut current = Some(object.clone());
+        while let Some(object) = current {
+            let object = object.borrow();
+            let properties = object.properties();
+            if properties.index_property_keys().next().is_some() {
+                return None;
+            }
+            layouts.push(object.enumeration_layout()?);
+
+            // A non-enumerable property still shadows the properties of the prototypes.
+            for (key, property) in properties.string_properties() {
+                if visited_keys.insert(key.clone()) && property.expect_enumerable() {
+                    keys.push(key.clone());
+                }
+            }
+            current = object.prototype().clone();
+        }
+
+        Some(Self { layouts, keys })
+    }
+
+    /// Returns `true` if the object and its prototypes still have the layouts of the cache.
+    fn is_valid(&self, object: &JsObject) -> bool {
+        let mut layouts = self.layouts.iter();
+        let mut current = Some(object.clone());
+        while let Some(object) = current {
+            let object = object.borrow();
+            match (object.enumeration_layout(), layouts.next()) {
+                (Some(layout), Some(&cached)) if layout == cached => {}
+                _ => return false,
+            }
+            current = object.prototype().clone();
+        }
+        layouts.next().is_none()
+    }
+}
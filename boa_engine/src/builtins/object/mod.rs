@@ -15,7 +15,10 @@
 
 use super::Array;
 use crate::{
-    builtins::{map, BuiltIn, JsArgs},
+    builtins::{
+        iterable::{group_by, GroupKeyCoercion},
+        map, BuiltIn, JsArgs,
+    },
     context::intrinsics::StandardConstructors,
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
@@ -87,6 +90,7 @@ impl BuiltIn for Object {
         .static_method(Self::get_own_property_symbols, "getOwnPropertySymbols", 1)
         .static_method(Self::has_own, "hasOwn", 2)
         .static_method(Self::from_entries, "fromEntries", 1)
+        .static_method(Self::group_by, "groupBy", 2)
         .build()
         .conv::<JsValue>()
         .pipe(Some)
@@ -504,7 +508,7 @@ impl Object {
                 ObjectKind::Array => "Array",
                 ObjectKind::Arguments(_) => "Arguments",
                 ObjectKind::Function(_) => "Function",
-                ObjectKind::Error => "Error",
+                ObjectKind::Error(_) => "Error",
                 ObjectKind::Boolean(_) => "Boolean",
                 ObjectKind::Number(_) => "Number",
                 ObjectKind::String(_) => "String",
@@ -974,6 +978,38 @@ impl Object {
         // 6. Return ? AddEntriesFromIterable(obj, iterable, adder).
         map::add_entries_from_iterable(&obj, iterable, &adder.into(), context)
     }
+
+    /// `Object.groupBy( items, callbackfn )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.groupby
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/groupBy
+    pub fn group_by(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let items = args.get_or_undefined(0);
+        let callback = args.get_or_undefined(1);
+
+        // 1. Let groups be ? GroupBy(items, callbackfn, property).
+        let groups = group_by(items, callback, GroupKeyCoercion::Property, context)?;
+
+        // 2. Let obj be OrdinaryObjectCreate(null).
+        let obj = JsObject::empty();
+
+        // 3. For each Record { [[Key]], [[Elements]] } g of groups, do
+        for (key, elements) in groups {
+            // a. Let elements be CreateArrayFromList(g.[[Elements]]).
+            let elements = Array::create_array_from_list(elements, context);
+
+            // b. Perform ! CreateDataPropertyOrThrow(obj, g.[[Key]], elements).
+            obj.create_data_property_or_throw(key.to_property_key(context)?, elements, context)
+                .expect("cannot fail for a new object with a null prototype");
+        }
+
+        // 4. Return obj.
+        Ok(obj.into())
+    }
 }
 
 /// The abstract operation `ObjectDefineProperties`
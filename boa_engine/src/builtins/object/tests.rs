@@ -420,3 +420,35 @@ fn object_from_entries() {
         TestAction::TestEq("map[5]", "4"),
     ]);
 }
+
+#[test]
+fn object_group_by() {
+    let mut context = Context::default();
+    let init = r#"
+        var groups = Object.groupBy([1, 2, 3, 4, 5], (n, i) => n % 2 === 0 ? "even" : i);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(groups)"),
+        "null"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.keys(groups).join()"),
+        "\"0,2,4,even\""
+    );
+    assert_eq!(forward(&mut context, "groups.even.join()"), "\"2,4\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Object.groupBy([], 1) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Object.groupBy(null, () => 1) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
@@ -0,0 +1,1914 @@
+//! This module implements the global `Promise` object.
+//!
+//! A `Promise` represents the eventual completion (or failure) of an asynchronous operation and
+//! its resulting value. Promise reactions are run as jobs, through [`Context::run_jobs`].
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-promise-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise
+
+#[cfg(test)]
+mod tests;
+
+mod promise_job;
+
+use self::promise_job::PromiseJob;
+use super::{iterable::IteratorRecord, Array, BuiltIn, JsArgs};
+use crate::{
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::{Attribute, PropertyDescriptorBuilder},
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Cell, Finalize, Gc, Trace};
+use boa_profiler::Profiler;
+use std::{fmt, rc::Rc};
+use tap::{Conv, Pipe};
+
+/// `IfAbruptRejectPromise ( value, capability )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-ifabruptrejectpromise
+macro_rules! if_abrupt_reject_promise {
+    ($value:ident, $capability:expr, $context:expr) => {
+        let $value = match $value {
+            // 1. If value is an abrupt completion, then
+            Err(value) => {
+                // a. Perform ? Call(capability.[[Reject]], undefined, « value.[[Value]] »).
+                $capability
+                    .reject
+                    .call(&JsValue::undefined(), &[value], $context)?;
+
+                // b. Return capability.[[Promise]].
+                return Ok($capability.promise.clone().into());
+            }
+            // 2. Else if value is a Completion Record, set value to value.[[Value]].
+            Ok(value) => value,
+        };
+    };
+}
+
+/// The `[[PromiseState]]` of a promise, together with its `[[PromiseResult]]` once settled.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub enum PromiseState {
+    /// The promise is neither fulfilled nor rejected yet.
+    Pending,
+    /// The promise was fulfilled with the contained value.
+    Fulfilled(JsValue),
+    /// The promise was rejected with the contained reason.
+    Rejected(JsValue),
+}
+
+/// The internal representation of a `Promise` object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-properties-of-promise-instances
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Promise {
+    /// `[[PromiseState]]` and `[[PromiseResult]]`
+    state: PromiseState,
+    /// `[[PromiseFulfillReactions]]`
+    fulfill_reactions: Vec<ReactionRecord>,
+    /// `[[PromiseRejectReactions]]`
+    reject_reactions: Vec<ReactionRecord>,
+    /// `[[PromiseIsHandled]]`
+    handled: bool,
+}
+
+/// The `[[Type]]` of a `PromiseReaction` record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Trace, Finalize)]
+enum ReactionType {
+    Fulfill,
+    Reject,
+}
+
+/// A `PromiseReaction` record, storing how a promise reacts when it is settled.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-promisereaction-records
+#[derive(Debug, Clone, Trace, Finalize)]
+struct ReactionRecord {
+    /// `[[Capability]]`, `None` when undefined.
+    promise_capability: Option<PromiseCapability>,
+    /// `[[Type]]`
+    reaction_type: ReactionType,
+    /// `[[Handler]]`, `None` when empty.
+    handler: Option<JsObject>,
+}
+
+/// A `PromiseCapability` record, encapsulating a promise with the functions that resolve or
+/// reject it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-promisecapability-records
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct PromiseCapability {
+    /// `[[Promise]]`
+    promise: JsObject,
+    /// `[[Resolve]]`
+    resolve: JsObject,
+    /// `[[Reject]]`
+    reject: JsObject,
+}
+
+/// The resolve and reject functions returned by `CreateResolvingFunctions`, which settle a
+/// pending promise.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct ResolvingFunctions {
+    /// The function resolving the promise with its argument.
+    pub resolve: JsObject,
+    /// The function rejecting the promise with its argument.
+    pub reject: JsObject,
+}
+
+/// The operation reported to the host when tracking promise rejections.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-host-promise-rejection-tracker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationType {
+    /// A promise was rejected without any handlers.
+    Reject,
+    /// A handler was added to a rejected promise for the first time.
+    Handle,
+}
+
+/// An event of the lifecycle of a promise, observed with [`HostHooks::promise_hook`].
+///
+/// [`HostHooks::promise_hook`]: crate::context::HostHooks::promise_hook
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromiseEvent {
+    /// A promise was created by the `Promise` constructor.
+    Init,
+    /// A promise was fulfilled or rejected.
+    Resolve,
+    /// A reaction job of a promise, derived from the promise it reacts to, is about to run.
+    Before,
+    /// A reaction job of a promise has finished running.
+    After,
+}
+
+/// A host defined implementation of `HostPromiseRejectionTracker`.
+#[derive(Clone)]
+pub(crate) struct PromiseRejectionTracker(Rc<dyn Fn(&JsObject, OperationType, &mut Context)>);
+
+impl PromiseRejectionTracker {
+    pub(crate) fn new<F>(tracker: F) -> Self
+    where
+        F: Fn(&JsObject, OperationType, &mut Context) + 'static,
+    {
+        Self(Rc::new(tracker))
+    }
+
+    pub(crate) fn call(&self, promise: &JsObject, operation: OperationType, context: &mut Context) {
+        (self.0)(promise, operation, context);
+    }
+}
+
+impl fmt::Debug for PromiseRejectionTracker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PromiseRejectionTracker")
+            .finish_non_exhaustive()
+    }
+}
+
+impl PromiseCapability {
+    /// `NewPromiseCapability ( C )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromisecapability
+    pub(crate) fn new(c: &JsValue, context: &mut Context) -> JsResult<Self> {
+        #[derive(Debug, Clone, Trace, Finalize)]
+        struct RejectResolve {
+            reject: JsValue,
+            resolve: JsValue,
+        }
+
+        // 1. If IsConstructor(C) is false, throw a TypeError exception.
+        let c = c.as_constructor().ok_or_else(|| {
+            context.construct_type_error("NewPromiseCapability: expected a constructor")
+        })?;
+
+        // 2. NOTE: C is assumed to be a constructor function that supports the parameter
+        //    conventions of the Promise constructor (see 27.2.3.1).
+        // 3. Let promiseCapability be the PromiseCapability Record { [[Promise]]: undefined, [[Resolve]]: undefined, [[Reject]]: undefined }.
+        let promise_capability = Gc::new(Cell::new(RejectResolve {
+            reject: JsValue::undefined(),
+            resolve: JsValue::undefined(),
+        }));
+
+        // 4. Let executorClosure be a new Abstract Closure with parameters (resolve, reject) that
+        //    captures promiseCapability and performs the following steps when called:
+        // 5. Let executor be CreateBuiltinFunction(executorClosure, 2, "", « »).
+        let executor = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args: &[JsValue], captures: &mut Gc<Cell<RejectResolve>>, context| {
+                let mut promise_capability = captures.borrow_mut();
+
+                // a. If promiseCapability.[[Resolve]] is not undefined, throw a TypeError exception.
+                if !promise_capability.resolve.is_undefined() {
+                    return context.throw_type_error("promise capability resolve is already set");
+                }
+
+                // b. If promiseCapability.[[Reject]] is not undefined, throw a TypeError exception.
+                if !promise_capability.reject.is_undefined() {
+                    return context.throw_type_error("promise capability reject is already set");
+                }
+
+                // c. Set promiseCapability.[[Resolve]] to resolve.
+                promise_capability.resolve = args.get_or_undefined(0).clone();
+
+                // d. Set promiseCapability.[[Reject]] to reject.
+                promise_capability.reject = args.get_or_undefined(1).clone();
+
+                // e. Return undefined.
+                Ok(JsValue::undefined())
+            },
+            promise_capability.clone(),
+        )
+        .name("")
+        .length(2)
+        .build()
+        .into();
+
+        // 6. Let promise be ? Construct(C, « executor »).
+        let promise = c
+            .construct(&[executor], &c.clone().into(), context)?
+            .as_object()
+            .cloned()
+            .expect("[[Construct]] must always return an object");
+
+        let promise_capability = promise_capability.borrow();
+
+        // 7. If IsCallable(promiseCapability.[[Resolve]]) is false, throw a TypeError exception.
+        let resolve = promise_capability
+            .resolve
+            .as_callable()
+            .cloned()
+            .ok_or_else(|| {
+                context.construct_type_error("promise capability resolve is not callable")
+            })?;
+
+        // 8. If IsCallable(promiseCapability.[[Reject]]) is false, throw a TypeError exception.
+        let reject = promise_capability
+            .reject
+            .as_callable()
+            .cloned()
+            .ok_or_else(|| {
+                context.construct_type_error("promise capability reject is not callable")
+            })?;
+
+        // 9. Set promiseCapability.[[Promise]] to promise.
+        // 10. Return promiseCapability.
+        Ok(Self {
+            promise,
+            resolve,
+            reject,
+        })
+    }
+
+    /// Returns the promise of the capability.
+    #[inline]
+    pub(crate) fn promise(&self) -> &JsObject {
+        &self.promise
+    }
+
+    /// Returns the resolve function of the capability.
+    #[inline]
+    pub(crate) fn resolve(&self) -> &JsObject {
+        &self.resolve
+    }
+
+    /// Returns the reject function of the capability.
+    #[inline]
+    pub(crate) fn reject(&self) -> &JsObject {
+        &self.reject
+    }
+}
+
+impl BuiltIn for Promise {
+    const NAME: &'static str = "Promise";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let get_species = FunctionBuilder::native(context, Self::get_species)
+            .name("get [Symbol.species]")
+            .constructor(false)
+            .build();
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().promise().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .static_method(Self::all, "all", 1)
+        .static_method(Self::all_settled, "allSettled", 1)
+        .static_method(Self::any, "any", 1)
+        .static_method(Self::race, "race", 1)
+        .static_method(Self::reject, "reject", 1)
+        .static_method(Self::resolve, "resolve", 1)
+        .static_method(Self::with_resolvers, "withResolvers", 0)
+        .static_accessor(
+            WellKnownSymbols::species(),
+            Some(get_species),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .method(Self::then, "then", 2)
+        .method(Self::catch, "catch", 1)
+        .method(Self::finally, "finally", 1)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+impl Promise {
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Creates a new, pending `Promise`.
+    pub(crate) fn new() -> Self {
+        Self {
+            state: PromiseState::Pending,
+            fulfill_reactions: Vec::new(),
+            reject_reactions: Vec::new(),
+            handled: false,
+        }
+    }
+
+    /// Gets the current state of the promise.
+    #[inline]
+    pub fn state(&self) -> &PromiseState {
+        &self.state
+    }
+
+    /// `Promise ( executor )`
+    ///
+    /// Creates a new promise, settled through the functions passed to `executor`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-executor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/Promise
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin Promise constructor without new is forbidden",
+            );
+        }
+
+        // 2. If IsCallable(executor) is false, throw a TypeError exception.
+        let executor = args
+            .get_or_undefined(0)
+            .as_callable()
+            .ok_or_else(|| context.construct_type_error("Promise executor is not callable"))?;
+
+        // 3. Let promise be ? OrdinaryCreateFromConstructor(NewTarget, "%Promise.prototype%", « [[PromiseState]], [[PromiseResult]], [[PromiseFulfillReactions]], [[PromiseRejectReactions]], [[PromiseIsHandled]] »).
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardConstructors::promise, context)?;
+
+        // 4. Set promise.[[PromiseState]] to pending.
+        // 5. Set promise.[[PromiseFulfillReactions]] to a new empty List.
+        // 6. Set promise.[[PromiseRejectReactions]] to a new empty List.
+        // 7. Set promise.[[PromiseIsHandled]] to false.
+        let promise = JsObject::from_proto_and_data(prototype, ObjectData::promise(Self::new()));
+        context
+            .host_hooks()
+            .promise_hook(PromiseEvent::Init, &promise, context);
+
+        // 8. Let resolvingFunctions be CreateResolvingFunctions(promise).
+        let ResolvingFunctions { resolve, reject } =
+            Self::create_resolving_functions(&promise, context);
+
+        // 9. Let completion be Completion(Call(executor, undefined, « resolvingFunctions.[[Resolve]], resolvingFunctions.[[Reject]] »)).
+        let completion = executor.call(
+            &JsValue::undefined(),
+            &[resolve.into(), reject.clone().into()],
+            context,
+        );
+
+        // 10. If completion is an abrupt completion, then
+        if let Err(value) = completion {
+            // a. Perform ? Call(resolvingFunctions.[[Reject]], undefined, « completion.[[Value]] »).
+            reject.call(&JsValue::undefined(), &[value], context)?;
+        }
+
+        // 11. Return promise.
+        Ok(promise.into())
+    }
+
+    /// `CreateResolvingFunctions ( promise )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createresolvingfunctions
+    pub(crate) fn create_resolving_functions(
+        promise: &JsObject,
+        context: &mut Context,
+    ) -> ResolvingFunctions {
+        #[derive(Debug, Trace, Finalize)]
+        struct ResolvingFunctionCaptures {
+            promise: JsObject,
+            already_resolved: Gc<Cell<bool>>,
+        }
+
+        // 1. Let alreadyResolved be the Record { [[Value]]: false }.
+        let already_resolved = Gc::new(Cell::new(false));
+
+        // 2. Let stepsResolve be the algorithm steps defined in Promise Resolve Functions.
+        // 3. Let lengthResolve be the number of non-optional parameters of the function definition in Promise Resolve Functions.
+        // 4. Let resolve be CreateBuiltinFunction(stepsResolve, lengthResolve, "", « [[Promise]], [[AlreadyResolved]] »).
+        // 5. Set resolve.[[Promise]] to promise.
+        // 6. Set resolve.[[AlreadyResolved]] to alreadyResolved.
+        let resolve = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args: &[JsValue], captures: &mut ResolvingFunctionCaptures, context| {
+                // https://tc39.es/ecma262/#sec-promise-resolve-functions
+
+                // 1. Let F be the active function object.
+                // 2. Assert: F has a [[Promise]] internal slot whose value is an Object.
+                // 3. Let promise be F.[[Promise]].
+                // 4. Let alreadyResolved be F.[[AlreadyResolved]].
+                let ResolvingFunctionCaptures {
+                    promise,
+                    already_resolved,
+                } = captures;
+
+                // 5. If alreadyResolved.[[Value]] is true, return undefined.
+                if *already_resolved.borrow() {
+                    return Ok(JsValue::undefined());
+                }
+
+                // 6. Set alreadyResolved.[[Value]] to true.
+                *already_resolved.borrow_mut() = true;
+
+                let resolution = args.get_or_undefined(0);
+
+                // 7. If SameValue(resolution, promise) is true, then
+                if resolution
+                    .as_object()
+                    .map_or(false, |resolution| JsObject::equals(resolution, promise))
+                {
+                    // a. Let selfResolutionError be a newly created TypeError object.
+                    let self_resolution_error =
+                        context.construct_type_error("cannot resolve a promise with itself");
+
+                    // b. Perform RejectPromise(promise, selfResolutionError).
+                    Self::reject_promise(promise, self_resolution_error, context);
+
+                    // c. Return undefined.
+                    return Ok(JsValue::undefined());
+                }
+
+                // 8. If resolution is not an Object, then
+                let then = if let Some(resolution) = resolution.as_object() {
+                    // 9. Let then be Completion(Get(resolution, "then")).
+                    resolution.get("then", context)
+                } else {
+                    // a. Perform FulfillPromise(promise, resolution).
+                    Self::fulfill_promise(promise, resolution.clone(), context);
+
+                    // b. Return undefined.
+                    return Ok(JsValue::undefined());
+                };
+
+                let then_action = match then {
+                    // 10. If then is an abrupt completion, then
+                    Err(value) => {
+                        // a. Perform RejectPromise(promise, then.[[Value]]).
+                        Self::reject_promise(promise, value, context);
+
+                        // b. Return undefined.
+                        return Ok(JsValue::undefined());
+                    }
+                    // 11. Let thenAction be then.[[Value]].
+                    Ok(then) => then,
+                };
+
+                // 12. If IsCallable(thenAction) is false, then
+                let then_action = if let Some(then_action) = then_action.as_callable() {
+                    then_action.clone()
+                } else {
+                    // a. Perform FulfillPromise(promise, resolution).
+                    Self::fulfill_promise(promise, resolution.clone(), context);
+
+                    // b. Return undefined.
+                    return Ok(JsValue::undefined());
+                };
+
+                // 13. Let thenJobCallback be HostMakeJobCallback(thenAction).
+                // 14. Let job be NewPromiseResolveThenableJob(promise, resolution, thenJobCallback).
+                let job = PromiseJob::new_promise_resolve_thenable_job(
+                    promise.clone(),
+                    resolution.clone(),
+                    then_action,
+                );
+
+                // 15. Perform HostEnqueuePromiseJob(job.[[Job]], job.[[Realm]]).
+                context.enqueue_job(job);
+
+                // 16. Return undefined.
+                Ok(JsValue::undefined())
+            },
+            ResolvingFunctionCaptures {
+                promise: promise.clone(),
+                already_resolved: already_resolved.clone(),
+            },
+        )
+        .name("")
+        .length(1)
+        .build();
+
+        // 7. Let stepsReject be the algorithm steps defined in Promise Reject Functions.
+        // 8. Let lengthReject be the number of non-optional parameters of the function definition in Promise Reject Functions.
+        // 9. Let reject be CreateBuiltinFunction(stepsReject, lengthReject, "", « [[Promise]], [[AlreadyResolved]] »).
+        // 10. Set reject.[[Promise]] to promise.
+        // 11. Set reject.[[AlreadyResolved]] to alreadyResolved.
+        let reject = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args: &[JsValue], captures: &mut ResolvingFunctionCaptures, context| {
+                // https://tc39.es/ecma262/#sec-promise-reject-functions
+
+                // 1. Let F be the active function object.
+                // 2. Assert: F has a [[Promise]] internal slot whose value is an Object.
+                // 3. Let promise be F.[[Promise]].
+                // 4. Let alreadyResolved be F.[[AlreadyResolved]].
+                let ResolvingFunctionCaptures {
+                    promise,
+                    already_resolved,
+                } = captures;
+
+                // 5. If alreadyResolved.[[Value]] is true, return undefined.
+                if *already_resolved.borrow() {
+                    return Ok(JsValue::undefined());
+                }
+
+                // 6. Set alreadyResolved.[[Value]] to true.
+                *already_resolved.borrow_mut() = true;
+
+                // 7. Perform RejectPromise(promise, reason).
+                Self::reject_promise(promise, args.get_or_undefined(0).clone(), context);
+
+                // 8. Return undefined.
+                Ok(JsValue::undefined())
+            },
+            ResolvingFunctionCaptures {
+                promise: promise.clone(),
+                already_resolved,
+            },
+        )
+        .name("")
+        .length(1)
+        .build();
+
+        // 12. Return the Record { [[Resolve]]: resolve, [[Reject]]: reject }.
+        ResolvingFunctions { resolve, reject }
+    }
+
+    /// `FulfillPromise ( promise, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-fulfillpromise
+    fn fulfill_promise(promise: &JsObject, value: JsValue, context: &mut Context) {
+        let reactions = {
+            let mut promise = promise.borrow_mut();
+            let promise = promise
+                .as_promise_mut()
+                .expect("IsPromise(promise) is false");
+
+            // 1. Assert: The value of promise.[[PromiseState]] is pending.
+            assert!(
+                matches!(promise.state, PromiseState::Pending),
+                "promise was not pending"
+            );
+
+            // 2. Let reactions be promise.[[PromiseFulfillReactions]].
+            let reactions = std::mem::take(&mut promise.fulfill_reactions);
+
+            // 3. Set promise.[[PromiseResult]] to value.
+            // 4. Set promise.[[PromiseFulfillReactions]] to undefined.
+            // 5. Set promise.[[PromiseRejectReactions]] to undefined.
+            // 6. Set promise.[[PromiseState]] to fulfilled.
+            promise.reject_reactions.clear();
+            promise.state = PromiseState::Fulfilled(value.clone());
+
+            reactions
+        };
+        context
+            .host_hooks()
+            .promise_hook(PromiseEvent::Resolve, promise, context);
+
+        // 7. Perform TriggerPromiseReactions(reactions, value).
+        Self::trigger_promise_reactions(reactions, &value, context);
+
+        // 8. Return unused.
+    }
+
+    /// `RejectPromise ( promise, reason )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-rejectpromise
+    fn reject_promise(promise: &JsObject, reason: JsValue, context: &mut Context) {
+        let (reactions, handled) = {
+            let mut promise = promise.borrow_mut();
+            let promise = promise
+                .as_promise_mut()
+                .expect("IsPromise(promise) is false");
+
+            // 1. Assert: The value of promise.[[PromiseState]] is pending.
+            assert!(
+                matches!(promise.state, PromiseState::Pending),
+                "promise was not pending"
+            );
+
+            // 2. Let reactions be promise.[[PromiseRejectReactions]].
+            let reactions = std::mem::take(&mut promise.reject_reactions);
+
+            // 3. Set promise.[[PromiseResult]] to reason.
+            // 4. Set promise.[[PromiseFulfillReactions]] to undefined.
+            // 5. Set promise.[[PromiseRejectReactions]] to undefined.
+            // 6. Set promise.[[PromiseState]] to rejected.
+            promise.fulfill_reactions.clear();
+            promise.state = PromiseState::Rejected(reason.clone());
+
+            (reactions, promise.handled)
+        };
+        context
+            .host_hooks()
+            .promise_hook(PromiseEvent::Resolve, promise, context);
+
+        // 7. If promise.[[PromiseIsHandled]] is false, perform HostPromiseRejectionTracker(promise, "reject").
+        if !handled {
+            context.host_promise_rejection_tracker(promise, OperationType::Reject);
+        }
+
+        // 8. Perform TriggerPromiseReactions(reactions, reason).
+        Self::trigger_promise_reactions(reactions, &reason, context);
+
+        // 9. Return unused.
+    }
+
+    /// `TriggerPromiseReactions ( reactions, argument )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-triggerpromisereactions
+    fn trigger_promise_reactions(
+        reactions: Vec<ReactionRecord>,
+        argument: &JsValue,
+        context: &mut Context,
+    ) {
+        // 1. For each element reaction of reactions, do
+        for reaction in reactions {
+            // a. Let job be NewPromiseReactionJob(reaction, argument).
+            let job = PromiseJob::new_promise_reaction_job(reaction, argument.clone());
+
+            // b. Perform HostEnqueuePromiseJob(job.[[Job]], job.[[Realm]]).
+            context.enqueue_job(job);
+        }
+
+        // 2. Return unused.
+    }
+
+    /// `PromiseResolve ( C, x )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-resolve
+    pub(crate) fn promise_resolve(
+        c: &JsObject,
+        x: JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. If IsPromise(x) is true, then
+        if let Some(x) = x.as_object().filter(|x| x.borrow().is_promise()) {
+            // a. Let xConstructor be ? Get(x, "constructor").
+            let x_constructor = x.get("constructor", context)?;
+
+            // b. If SameValue(xConstructor, C) is true, return x.
+            if x_constructor
+                .as_object()
+                .map_or(false, |x_constructor| JsObject::equals(x_constructor, c))
+            {
+                return Ok(x.clone());
+            }
+        }
+
+        // 2. Let promiseCapability be ? NewPromiseCapability(C).
+        let promise_capability = PromiseCapability::new(&c.clone().into(), context)?;
+
+        // 3. Perform ? Call(promiseCapability.[[Resolve]], undefined, « x »).
+        promise_capability
+            .resolve
+            .call(&JsValue::undefined(), &[x], context)?;
+
+        // 4. Return promiseCapability.[[Promise]].
+        Ok(promise_capability.promise)
+    }
+
+    /// `PerformPromiseThen ( promise, onFulfilled, onRejected [ , resultCapability ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromisethen
+    pub(crate) fn perform_promise_then(
+        promise: &JsObject,
+        on_fulfilled: &JsValue,
+        on_rejected: &JsValue,
+        result_capability: Option<PromiseCapability>,
+        context: &mut Context,
+    ) -> JsValue {
+        // 1. Assert: IsPromise(promise) is true.
+        // 2. If resultCapability is not present, then
+        //     a. Set resultCapability to undefined.
+
+        // 3. If IsCallable(onFulfilled) is false, then
+        //     a. Let onFulfilledJobCallback be empty.
+        // 4. Else,
+        //     a. Let onFulfilledJobCallback be HostMakeJobCallback(onFulfilled).
+        let on_fulfilled_job_callback = on_fulfilled.as_callable().cloned();
+
+        // 5. If IsCallable(onRejected) is false, then
+        //     a. Let onRejectedJobCallback be empty.
+        // 6. Else,
+        //     a. Let onRejectedJobCallback be HostMakeJobCallback(onRejected).
+        let on_rejected_job_callback = on_rejected.as_callable().cloned();
+
+        // 7. Let fulfillReaction be the PromiseReaction { [[Capability]]: resultCapability, [[Type]]: fulfill, [[Handler]]: onFulfilledJobCallback }.
+        let fulfill_reaction = ReactionRecord {
+            promise_capability: result_capability.clone(),
+            reaction_type: ReactionType::Fulfill,
+            handler: on_fulfilled_job_callback,
+        };
+
+        // 8. Let rejectReaction be the PromiseReaction { [[Capability]]: resultCapability, [[Type]]: reject, [[Handler]]: onRejectedJobCallback }.
+        let reject_reaction = ReactionRecord {
+            promise_capability: result_capability.clone(),
+            reaction_type: ReactionType::Reject,
+            handler: on_rejected_job_callback,
+        };
+
+        let (state, handled) = {
+            let promise = promise.borrow();
+            let promise = promise.as_promise().expect("IsPromise(promise) is false");
+            (promise.state.clone(), promise.handled)
+        };
+
+        match state {
+            // 9. If promise.[[PromiseState]] is pending, then
+            PromiseState::Pending => {
+                let mut promise = promise.borrow_mut();
+                let promise = promise
+                    .as_promise_mut()
+                    .expect("IsPromise(promise) is false");
+
+                // a. Append fulfillReaction to promise.[[PromiseFulfillReactions]].
+                promise.fulfill_reactions.push(fulfill_reaction);
+
+                // b. Append rejectReaction to promise.[[PromiseRejectReactions]].
+                promise.reject_reactions.push(reject_reaction);
+            }
+            // 10. Else if promise.[[PromiseState]] is fulfilled, then
+            PromiseState::Fulfilled(value) => {
+                // a. Let value be promise.[[PromiseResult]].
+                // b. Let fulfillJob be NewPromiseReactionJob(fulfillReaction, value).
+                let fulfill_job = PromiseJob::new_promise_reaction_job(fulfill_reaction, value);
+
+                // c. Perform HostEnqueuePromiseJob(fulfillJob.[[Job]], fulfillJob.[[Realm]]).
+                context.enqueue_job(fulfill_job);
+            }
+            // 11. Else,
+            //     a. Assert: The value of promise.[[PromiseState]] is rejected.
+            PromiseState::Rejected(reason) => {
+                // b. Let reason be promise.[[PromiseResult]].
+                // c. If promise.[[PromiseIsHandled]] is false, perform HostPromiseRejectionTracker(promise, "handle").
+                if !handled {
+                    context.host_promise_rejection_tracker(promise, OperationType::Handle);
+                }
+
+                // d. Let rejectJob be NewPromiseReactionJob(rejectReaction, reason).
+                let reject_job = PromiseJob::new_promise_reaction_job(reject_reaction, reason);
+
+                // e. Perform HostEnqueuePromiseJob(rejectJob.[[Job]], rejectJob.[[Realm]]).
+                context.enqueue_job(reject_job);
+            }
+        }
+
+        // 12. Set promise.[[PromiseIsHandled]] to true.
+        promise
+            .borrow_mut()
+            .as_promise_mut()
+            .expect("IsPromise(promise) is false")
+            .handled = true;
+
+        // 13. If resultCapability is undefined, then
+        //     a. Return undefined.
+        // 14. Else,
+        //     a. Return resultCapability.[[Promise]].
+        result_capability.map_or_else(JsValue::undefined, |capability| capability.promise.into())
+    }
+
+    /// `GetPromiseResolve ( promiseConstructor )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getpromiseresolve
+    fn get_promise_resolve(
+        promise_constructor: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Let promiseResolve be ? Get(promiseConstructor, "resolve").
+        let promise_resolve = promise_constructor.get("resolve", context)?;
+
+        // 2. If IsCallable(promiseResolve) is false, throw a TypeError exception.
+        // 3. Return promiseResolve.
+        promise_resolve
+            .as_callable()
+            .cloned()
+            .ok_or_else(|| context.construct_type_error("promise resolve is not callable"))
+    }
+
+    /// Runs `IteratorStep` and `IteratorValue` for the promise combinators, setting
+    /// `iteratorRecord.[[Done]]` when the iterator is exhausted or throws.
+    fn iterator_step_value(
+        iterator_record: &IteratorRecord,
+        done: &mut bool,
+        context: &mut Context,
+    ) -> JsResult<Option<JsValue>> {
+        let next = iterator_record
+            .step(context)
+            .and_then(|next| next.map(|next| next.value(context)).transpose());
+
+        if !matches!(next, Ok(Some(_))) {
+            *done = true;
+        }
+
+        next
+    }
+
+    /// The steps shared by `Promise.all`, `Promise.allSettled`, `Promise.any` and
+    /// `Promise.race`, calling `perform` with the iterator of `iterable`.
+    fn combinator<F>(
+        this: &JsValue,
+        iterable: &JsValue,
+        context: &mut Context,
+        perform: F,
+    ) -> JsResult<JsValue>
+    where
+        F: FnOnce(
+            &IteratorRecord,
+            &mut bool,
+            &JsObject,
+            &PromiseCapability,
+            &JsObject,
+            &mut Context,
+        ) -> JsResult<JsValue>,
+    {
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ? NewPromiseCapability(C).
+        let promise_capability = PromiseCapability::new(this, context)?;
+        let c = this
+            .as_object()
+            .expect("NewPromiseCapability checks that C is a constructor");
+
+        // 3. Let promiseResolve be Completion(GetPromiseResolve(C)).
+        let promise_resolve = Self::get_promise_resolve(c, context);
+
+        // 4. IfAbruptRejectPromise(promiseResolve, promiseCapability).
+        if_abrupt_reject_promise!(promise_resolve, promise_capability, context);
+
+        // 5. Let iteratorRecord be Completion(GetIterator(iterable)).
+        let iterator_record = iterable.get_iterator(context, None, None);
+
+        // 6. IfAbruptRejectPromise(iteratorRecord, promiseCapability).
+        if_abrupt_reject_promise!(iterator_record, promise_capability, context);
+
+        // 7. Let result be Completion(PerformPromiseX(iteratorRecord, C, promiseCapability, promiseResolve)).
+        let mut done = false;
+        let mut result = perform(
+            &iterator_record,
+            &mut done,
+            c,
+            &promise_capability,
+            &promise_resolve,
+            context,
+        );
+
+        // 8. If result is an abrupt completion, then
+        //     a. If iteratorRecord.[[Done]] is false, set result to Completion(IteratorClose(iteratorRecord, result)).
+        if result.is_err() && !done {
+            result = iterator_record.close(result, context);
+        }
+
+        //     b. IfAbruptRejectPromise(result, promiseCapability).
+        if_abrupt_reject_promise!(result, promise_capability, context);
+
+        // 9. Return ? result.
+        Ok(result)
+    }
+
+    /// `Promise.all ( iterable )`
+    ///
+    /// Returns a promise fulfilled with the values of all the promises in `iterable`, or rejected
+    /// with the reason of the first rejected one.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.all
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/all
+    pub(crate) fn all(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::combinator(
+            this,
+            args.get_or_undefined(0),
+            context,
+            Self::perform_promise_all,
+        )
+    }
+
+    /// `PerformPromiseAll ( iteratorRecord, constructor, resultCapability, promiseResolve )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromiseall
+    fn perform_promise_all(
+        iterator_record: &IteratorRecord,
+        done: &mut bool,
+        constructor: &JsObject,
+        result_capability: &PromiseCapability,
+        promise_resolve: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        #[derive(Debug, Trace, Finalize)]
+        struct ResolveElementCaptures {
+            already_called: bool,
+            index: usize,
+            values: Gc<Cell<Vec<JsValue>>>,
+            capability_resolve: JsObject,
+            remaining_elements_count: Gc<Cell<usize>>,
+        }
+
+        // 1. Let values be a new empty List.
+        let values = Gc::new(Cell::new(Vec::new()));
+
+        // 2. Let remainingElementsCount be the Record { [[Value]]: 1 }.
+        let remaining_elements_count = Gc::new(Cell::new(1));
+
+        // 3. Let index be 0.
+        let mut index = 0;
+
+        // 4. Repeat,
+        loop {
+            // a. Let next be Completion(IteratorStep(iteratorRecord)).
+            // b. If next is an abrupt completion, set iteratorRecord.[[Done]] to true.
+            // c. ReturnIfAbrupt(next).
+            // e. Let nextValue be Completion(IteratorValue(next)).
+            // f. If nextValue is an abrupt completion, set iteratorRecord.[[Done]] to true.
+            // g. ReturnIfAbrupt(nextValue).
+            let next_value = if let Some(next_value) =
+                Self::iterator_step_value(iterator_record, done, context)?
+            {
+                next_value
+            } else {
+                // d. If next is false, then
+                //     i. Set iteratorRecord.[[Done]] to true.
+                //     ii. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+                *remaining_elements_count.borrow_mut() -= 1;
+
+                //     iii. If remainingElementsCount.[[Value]] is 0, then
+                if *remaining_elements_count.borrow() == 0 {
+                    // 1. Let valuesArray be CreateArrayFromList(values).
+                    let values_array =
+                        Array::create_array_from_list(values.borrow().iter().cloned(), context);
+
+                    // 2. Perform ? Call(resultCapability.[[Resolve]], undefined, « valuesArray »).
+                    result_capability.resolve.call(
+                        &JsValue::undefined(),
+                        &[values_array.into()],
+                        context,
+                    )?;
+                }
+
+                //     iv. Return resultCapability.[[Promise]].
+                return Ok(result_capability.promise.clone().into());
+            };
+
+            // h. Append undefined to values.
+            values.borrow_mut().push(JsValue::undefined());
+
+            // i. Let nextPromise be ? Call(promiseResolve, constructor, « nextValue »).
+            let next_promise =
+                promise_resolve.call(&constructor.clone().into(), &[next_value], context)?;
+
+            // j. Let steps be the algorithm steps defined in Promise.all Resolve Element Functions.
+            // k. Let length be the number of non-optional parameters of the function definition in Promise.all Resolve Element Functions.
+            // l. Let onFulfilled be CreateBuiltinFunction(steps, length, "", « [[AlreadyCalled]], [[Index]], [[Values]], [[Capability]], [[RemainingElements]] »).
+            // m. Set onFulfilled.[[AlreadyCalled]] to false.
+            // n. Set onFulfilled.[[Index]] to index.
+            // o. Set onFulfilled.[[Values]] to values.
+            // p. Set onFulfilled.[[Capability]] to resultCapability.
+            // q. Set onFulfilled.[[RemainingElements]] to remainingElementsCount.
+            let on_fulfilled = FunctionBuilder::closure_with_captures(
+                context,
+                |_, args: &[JsValue], captures: &mut ResolveElementCaptures, context| {
+                    // https://tc39.es/ecma262/#sec-promise.all-resolve-element-functions
+
+                    // 1. Let F be the active function object.
+                    // 2. If F.[[AlreadyCalled]] is true, return undefined.
+                    if captures.already_called {
+                        return Ok(JsValue::undefined());
+                    }
+
+                    // 3. Set F.[[AlreadyCalled]] to true.
+                    captures.already_called = true;
+
+                    // 4. Let index be F.[[Index]].
+                    // 5. Let values be F.[[Values]].
+                    // 6. Let promiseCapability be F.[[Capability]].
+                    // 7. Let remainingElementsCount be F.[[RemainingElements]].
+                    // 8. Set values[index] to x.
+                    captures.values.borrow_mut()[captures.index] = args.get_or_undefined(0).clone();
+
+                    // 9. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+                    *captures.remaining_elements_count.borrow_mut() -= 1;
+
+                    // 10. If remainingElementsCount.[[Value]] is 0, then
+                    if *captures.remaining_elements_count.borrow() == 0 {
+                        // a. Let valuesArray be CreateArrayFromList(values).
+                        let values_array = Array::create_array_from_list(
+                            captures.values.borrow().iter().cloned(),
+                            context,
+                        );
+
+                        // b. Return ? Call(promiseCapability.[[Resolve]], undefined, « valuesArray »).
+                        return captures.capability_resolve.call(
+                            &JsValue::undefined(),
+                            &[values_array.into()],
+                            context,
+                        );
+                    }
+
+                    // 11. Return undefined.
+                    Ok(JsValue::undefined())
+                },
+                ResolveElementCaptures {
+                    already_called: false,
+                    index,
+                    values: values.clone(),
+                    capability_resolve: result_capability.resolve.clone(),
+                    remaining_elements_count: remaining_elements_count.clone(),
+                },
+            )
+            .name("")
+            .length(1)
+            .build();
+
+            // r. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] + 1.
+            *remaining_elements_count.borrow_mut() += 1;
+
+            // s. Perform ? Invoke(nextPromise, "then", « onFulfilled, resultCapability.[[Reject]] »).
+            next_promise.invoke(
+                "then",
+                &[on_fulfilled.into(), result_capability.reject.clone().into()],
+                context,
+            )?;
+
+            // t. Set index to index + 1.
+            index += 1;
+        }
+    }
+
+    /// `Promise.allSettled ( iterable )`
+    ///
+    /// Returns a promise fulfilled with an array of objects describing the outcome of each of the
+    /// promises in `iterable`, once all of them are settled.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.allsettled
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/allSettled
+    pub(crate) fn all_settled(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::combinator(
+            this,
+            args.get_or_undefined(0),
+            context,
+            Self::perform_promise_all_settled,
+        )
+    }
+
+    /// `PerformPromiseAllSettled ( iteratorRecord, constructor, resultCapability, promiseResolve )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromiseallsettled
+    fn perform_promise_all_settled(
+        iterator_record: &IteratorRecord,
+        done: &mut bool,
+        constructor: &JsObject,
+        result_capability: &PromiseCapability,
+        promise_resolve: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        #[derive(Debug, Clone, Trace, Finalize)]
+        struct SettleElementCaptures {
+            already_called: Gc<Cell<bool>>,
+            index: usize,
+            values: Gc<Cell<Vec<JsValue>>>,
+            capability_resolve: JsObject,
+            remaining_elements_count: Gc<Cell<usize>>,
+        }
+
+        /// The steps shared by the `Promise.allSettled` Resolve and Reject Element Functions,
+        /// storing `{ status, [key]: x }` in the values list.
+        fn settle_element(
+            captures: &SettleElementCaptures,
+            status: &str,
+            key: &str,
+            x: &JsValue,
+            context: &mut Context,
+        ) -> JsResult<JsValue> {
+            // 1. Let F be the active function object.
+            // 2. Let alreadyCalled be F.[[AlreadyCalled]].
+            // 3. If alreadyCalled.[[Value]] is true, return undefined.
+            if *captures.already_called.borrow() {
+                return Ok(JsValue::undefined());
+            }
+
+            // 4. Set alreadyCalled.[[Value]] to true.
+            *captures.already_called.borrow_mut() = true;
+
+            // 5. Let index be F.[[Index]].
+            // 6. Let values be F.[[Values]].
+            // 7. Let promiseCapability be F.[[Capability]].
+            // 8. Let remainingElementsCount be F.[[RemainingElements]].
+            // 9. Let obj be OrdinaryObjectCreate(%Object.prototype%).
+            let obj = context.construct_object();
+
+            // 10. Perform ! CreateDataPropertyOrThrow(obj, "status", status).
+            obj.create_data_property_or_throw("status", status, context)
+                .expect("cannot fail per spec");
+
+            // 11. Perform ! CreateDataPropertyOrThrow(obj, key, x).
+            obj.create_data_property_or_throw(key, x.clone(), context)
+                .expect("cannot fail per spec");
+
+            // 12. Set values[index] to obj.
+            captures.values.borrow_mut()[captures.index] = obj.into();
+
+            // 13. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+            *captures.remaining_elements_count.borrow_mut() -= 1;
+
+            // 14. If remainingElementsCount.[[Value]] is 0, then
+            if *captures.remaining_elements_count.borrow() == 0 {
+                // a. Let valuesArray be CreateArrayFromList(values).
+                let values_array = Array::create_array_from_list(
+                    captures.values.borrow().iter().cloned(),
+                    context,
+                );
+
+                // b. Return ? Call(promiseCapability.[[Resolve]], undefined, « valuesArray »).
+                return captures.capability_resolve.call(
+                    &JsValue::undefined(),
+                    &[values_array.into()],
+                    context,
+                );
+            }
+
+            // 15. Return undefined.
+            Ok(JsValue::undefined())
+        }
+
+        // 1. Let values be a new empty List.
+        let values = Gc::new(Cell::new(Vec::new()));
+
+        // 2. Let remainingElementsCount be the Record { [[Value]]: 1 }.
+        let remaining_elements_count = Gc::new(Cell::new(1));
+
+        // 3. Let index be 0.
+        let mut index = 0;
+
+        // 4. Repeat,
+        loop {
+            // a. Let next be Completion(IteratorStep(iteratorRecord)).
+            // b. If next is an abrupt completion, set iteratorRecord.[[Done]] to true.
+            // c. ReturnIfAbrupt(next).
+            // e. Let nextValue be Completion(IteratorValue(next)).
+            // f. If nextValue is an abrupt completion, set iteratorRecord.[[Done]] to true.
+            // g. ReturnIfAbrupt(nextValue).
+            let next_value = if let Some(next_value) =
+                Self::iterator_step_value(iterator_record, done, context)?
+            {
+                next_value
+            } else {
+                // d. If next is false, then
+                //     i. Set iteratorRecord.[[Done]] to true.
+                //     ii. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+                *remaining_elements_count.borrow_mut() -= 1;
+
+                //     iii. If remainingElementsCount.[[Value]] is 0, then
+                if *remaining_elements_count.borrow() == 0 {
+                    // 1. Let valuesArray be CreateArrayFromList(values).
+                    let values_array =
+                        Array::create_array_from_list(values.borrow().iter().cloned(), context);
+
+                    // 2. Perform ? Call(resultCapability.[[Resolve]], undefined, « valuesArray »).
+                    result_capability.resolve.call(
+                        &JsValue::undefined(),
+                        &[values_array.into()],
+                        context,
+                    )?;
+                }
+
+                //     iv. Return resultCapability.[[Promise]].
+                return Ok(result_capability.promise.clone().into());
+            };
+
+            // h. Append undefined to values.
+            values.borrow_mut().push(JsValue::undefined());
+
+            // i. Let nextPromise be ? Call(promiseResolve, constructor, « nextValue »).
+            let next_promise =
+                promise_resolve.call(&constructor.clone().into(), &[next_value], context)?;
+
+            // j-r. Let onFulfilled be CreateBuiltinFunction(stepsFulfilled, lengthFulfilled, "", « [[AlreadyCalled]], [[Index]], [[Values]], [[Capability]], [[RemainingElements]] »).
+            // s. Let alreadyCalled be the Record { [[Value]]: false }.
+            let captures = SettleElementCaptures {
+                already_called: Gc::new(Cell::new(false)),
+                index,
+                values: values.clone(),
+                capability_resolve: result_capability.resolve.clone(),
+                remaining_elements_count: remaining_elements_count.clone(),
+            };
+            let on_fulfilled = FunctionBuilder::closure_with_captures(
+                context,
+                |_, args: &[JsValue], captures: &mut SettleElementCaptures, context| {
+                    // https://tc39.es/ecma262/#sec-promise.allsettled-resolve-element-functions
+                    settle_element(
+                        captures,
+                        "fulfilled",
+                        "value",
+                        args.get_or_undefined(0),
+                        context,
+                    )
+                },
+                captures.clone(),
+            )
+            .name("")
+            .length(1)
+            .build();
+
+            // t-z. Let onRejected be CreateBuiltinFunction(stepsRejected, lengthRejected, "", « [[AlreadyCalled]], [[Index]], [[Values]], [[Capability]], [[RemainingElements]] »).
+            let on_rejected = FunctionBuilder::closure_with_captures(
+                context,
+                |_, args: &[JsValue], captures: &mut SettleElementCaptures, context| {
+                    // https://tc39.es/ecma262/#sec-promise.allsettled-reject-element-functions
+                    settle_element(
+                        captures,
+                        "rejected",
+                        "reason",
+                        args.get_or_undefined(0),
+                        context,
+                    )
+                },
+                captures,
+            )
+            .name("")
+            .length(1)
+            .build();
+
+            // aa. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] + 1.
+            *remaining_elements_count.borrow_mut() += 1;
+
+            // ab. Perform ? Invoke(nextPromise, "then", « onFulfilled, onRejected »).
+            next_promise.invoke("then", &[on_fulfilled.into(), on_rejected.into()], context)?;
+
+            // ac. Set index to index + 1.
+            index += 1;
+        }
+    }
+
+    /// `Promise.any ( iterable )`
+    ///
+    /// Returns a promise fulfilled with the value of the first fulfilled promise in `iterable`,
+    /// or rejected with an `AggregateError` if all of them are rejected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.any
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/any
+    pub(crate) fn any(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::combinator(
+            this,
+            args.get_or_undefined(0),
+            context,
+            Self::perform_promise_any,
+        )
+    }
+
+    /// Creates a new `AggregateError` object whose `errors` property is an array of `errors`.
+    fn create_aggregate_error(errors: &[JsValue], context: &mut Context) -> JsValue {
+        // 1. Let error be a newly created AggregateError object.
+        let error = JsObject::from_proto_and_data(
+            context
+                .intrinsics()
+                .constructors()
+                .aggregate_error()
+                .prototype(),
+            ObjectData::error(context),
+        );
+
+        // 2. Perform ! DefinePropertyOrThrow(error, "errors", PropertyDescriptor { [[Configurable]]: true, [[Enumerable]]: false, [[Writable]]: true, [[Value]]: CreateArrayFromList(errors) }).
+        let errors = Array::create_array_from_list(errors.iter().cloned(), context);
+        error
+            .define_property_or_throw(
+                "errors",
+                PropertyDescriptorBuilder::new()
+                    .configurable(true)
+                    .enumerable(false)
+                    .writable(true)
+                    .value(errors)
+                    .build(),
+                context,
+            )
+            .expect("cannot fail per spec");
+
+        error.into()
+    }
+
+    /// `PerformPromiseAny ( iteratorRecord, constructor, resultCapability, promiseResolve )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromiseany
+    fn perform_promise_any(
+        iterator_record: &IteratorRecord,
+        done: &mut bool,
+        constructor: &JsObject,
+        result_capability: &PromiseCapability,
+        promise_resolve: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        #[derive(Debug, Trace, Finalize)]
+        struct RejectElementCaptures {
+            already_called: bool,
+            index: usize,
+            errors: Gc<Cell<Vec<JsValue>>>,
+            capability_reject: JsObject,
+            remaining_elements_count: Gc<Cell<usize>>,
+        }
+
+        // 1. Let errors be a new empty List.
+        let errors = Gc::new(Cell::new(Vec::new()));
+
+        // 2. Let remainingElementsCount be the Record { [[Value]]: 1 }.
+        let remaining_elements_count = Gc::new(Cell::new(1));
+
+        // 3. Let index be 0.
+        let mut index = 0;
+
+        // 4. Repeat,
+        loop {
+            // a. Let next be Completion(IteratorStep(iteratorRecord)).
+            // b. If next is an abrupt completion, set iteratorRecord.[[Done]] to true.
+            // c. ReturnIfAbrupt(next).
+            // e. Let nextValue be Completion(IteratorValue(next)).
+            // f. If nextValue is an abrupt completion, set iteratorRecord.[[Done]] to true.
+            // g. ReturnIfAbrupt(nextValue).
+            let next_value = if let Some(next_value) =
+                Self::iterator_step_value(iterator_record, done, context)?
+            {
+                next_value
+            } else {
+                // d. If next is false, then
+                //     i. Set iteratorRecord.[[Done]] to true.
+                //     ii. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+                *remaining_elements_count.borrow_mut() -= 1;
+
+                //     iii. If remainingElementsCount.[[Value]] is 0, then
+                if *remaining_elements_count.borrow() == 0 {
+                    // 1. Let error be a newly created AggregateError object.
+                    // 2. Perform ! DefinePropertyOrThrow(error, "errors", PropertyDescriptor { [[Configurable]]: true, [[Enumerable]]: false, [[Writable]]: true, [[Value]]: CreateArrayFromList(errors) }).
+                    // 3. Return ThrowCompletion(error).
+                    return Err(Self::create_aggregate_error(&errors.borrow(), context));
+                }
+
+                //     iv. Return resultCapability.[[Promise]].
+                return Ok(result_capability.promise.clone().into());
+            };
+
+            // h. Append undefined to errors.
+            errors.borrow_mut().push(JsValue::undefined());
+
+            // i. Let nextPromise be ? Call(promiseResolve, constructor, « nextValue »).
+            let next_promise =
+                promise_resolve.call(&constructor.clone().into(), &[next_value], context)?;
+
+            // j. Let stepsRejected be the algorithm steps defined in Promise.any Reject Element Functions.
+            // k. Let lengthRejected be the number of non-optional parameters of the function definition in Promise.any Reject Element Functions.
+            // l. Let onRejected be CreateBuiltinFunction(stepsRejected, lengthRejected, "", « [[AlreadyCalled]], [[Index]], [[Errors]], [[Capability]], [[RemainingElements]] »).
+            // m. Set onRejected.[[AlreadyCalled]] to false.
+            // n. Set onRejected.[[Index]] to index.
+            // o. Set onRejected.[[Errors]] to errors.
+            // p. Set onRejected.[[Capability]] to resultCapability.
+            // q. Set onRejected.[[RemainingElements]] to remainingElementsCount.
+            let on_rejected = FunctionBuilder::closure_with_captures(
+                context,
+                |_, args: &[JsValue], captures: &mut RejectElementCaptures, context| {
+                    // https://tc39.es/ecma262/#sec-promise.any-reject-element-functions
+
+                    // 1. Let F be the active function object.
+                    // 2. If F.[[AlreadyCalled]] is true, return undefined.
+                    if captures.already_called {
+                        return Ok(JsValue::undefined());
+                    }
+
+                    // 3. Set F.[[AlreadyCalled]] to true.
+                    captures.already_called = true;
+
+                    // 4. Let index be F.[[Index]].
+                    // 5. Let errors be F.[[Errors]].
+                    // 6. Let promiseCapability be F.[[Capability]].
+                    // 7. Let remainingElementsCount be F.[[RemainingElements]].
+                    // 8. Set errors[index] to x.
+                    captures.errors.borrow_mut()[captures.index] = args.get_or_undefined(0).clone();
+
+                    // 9. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
+                    *captures.remaining_elements_count.borrow_mut() -= 1;
+
+                    // 10. If remainingElementsCount.[[Value]] is 0, then
+                    if *captures.remaining_elements_count.borrow() == 0 {
+                        // a. Let error be a newly created AggregateError object.
+                        // b. Perform ! DefinePropertyOrThrow(error, "errors", PropertyDescriptor { [[Configurable]]: true, [[Enumerable]]: false, [[Writable]]: true, [[Value]]: CreateArrayFromList(errors) }).
+                        let error =
+                            Self::create_aggregate_error(&captures.errors.borrow(), context);
+
+                        // c. Return ? Call(promiseCapability.[[Reject]], undefined, « error »).
+                        return captures.capability_reject.call(
+                            &JsValue::undefined(),
+                            &[error],
+                            context,
+                        );
+                    }
+
+                    // 11. Return undefined.
+                    Ok(JsValue::undefined())
+                },
+                RejectElementCaptures {
+                    already_called: false,
+                    index,
+                    errors: errors.clone(),
+                    capability_reject: result_capability.reject.clone(),
+                    remaining_elements_count: remaining_elements_count.clone(),
+                },
+            )
+            .name("")
+            .length(1)
+            .build();
+
+            // r. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] + 1.
+            *remaining_elements_count.borrow_mut() += 1;
+
+            // s. Perform ? Invoke(nextPromise, "then", « resultCapability.[[Resolve]], onRejected »).
+            next_promise.invoke(
+                "then",
+                &[result_capability.resolve.clone().into(), on_rejected.into()],
+                context,
+            )?;
+
+            // t. Set index to index + 1.
+            index += 1;
+        }
+    }
+
+    /// `Promise.race ( iterable )`
+    ///
+    /// Returns a promise settled in the same way as the first promise of `iterable` to settle.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.race
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/race
+    pub(crate) fn race(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::combinator(
+            this,
+            args.get_or_undefined(0),
+            context,
+            Self::perform_promise_race,
+        )
+    }
+
+    /// `PerformPromiseRace ( iteratorRecord, constructor, resultCapability, promiseResolve )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromiserace
+    fn perform_promise_race(
+        iterator_record: &IteratorRecord,
+        done: &mut bool,
+        constructor: &JsObject,
+        result_capability: &PromiseCapability,
+        promise_resolve: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Repeat,
+        //     a. Let next be Completion(IteratorStep(iteratorRecord)).
+        //     b. If next is an abrupt completion, set iteratorRecord.[[Done]] to true.
+        //     c. ReturnIfAbrupt(next).
+        //     d. If next is false, then
+        //         i. Set iteratorRecord.[[Done]] to true.
+        //         ii. Return resultCapability.[[Promise]].
+        //     e. Let nextValue be Completion(IteratorValue(next)).
+        //     f. If nextValue is an abrupt completion, set iteratorRecord.[[Done]] to true.
+        //     g. ReturnIfAbrupt(nextValue).
+        while let Some(next_value) = Self::iterator_step_value(iterator_record, done, context)? {
+            // h. Let nextPromise be ? Call(promiseResolve, constructor, « nextValue »).
+            let next_promise =
+                promise_resolve.call(&constructor.clone().into(), &[next_value], context)?;
+
+            // i. Perform ? Invoke(nextPromise, "then", « resultCapability.[[Resolve]], resultCapability.[[Reject]] »).
+            next_promise.invoke(
+                "then",
+                &[
+                    result_capability.resolve.clone().into(),
+                    result_capability.reject.clone().into(),
+                ],
+                context,
+            )?;
+        }
+
+        Ok(result_capability.promise.clone().into())
+    }
+
+    /// `Promise.reject ( r )`
+    ///
+    /// Returns a new promise rejected with the given reason.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.reject
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/reject
+    pub(crate) fn reject(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ? NewPromiseCapability(C).
+        let promise_capability = PromiseCapability::new(this, context)?;
+
+        // 3. Perform ? Call(promiseCapability.[[Reject]], undefined, « r »).
+        promise_capability.reject.call(
+            &JsValue::undefined(),
+            &[args.get_or_undefined(0).clone()],
+            context,
+        )?;
+
+        // 4. Return promiseCapability.[[Promise]].
+        Ok(promise_capability.promise.into())
+    }
+
+    /// `Promise.resolve ( x )`
+    ///
+    /// Returns a promise resolved with the given value, or the value itself if it is a promise
+    /// created by the same constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.resolve
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/resolve
+    pub(crate) fn resolve(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let C be the this value.
+        // 2. If Type(C) is not Object, throw a TypeError exception.
+        let c = this.as_object().ok_or_else(|| {
+            context.construct_type_error("Promise.resolve() called on a non-object")
+        })?;
+
+        // 3. Return ? PromiseResolve(C, x).
+        Self::promise_resolve(c, args.get_or_undefined(0).clone(), context).map(JsValue::from)
+    }
+
+    /// `Promise.withResolvers ( )`
+    ///
+    /// Returns a new promise together with the functions that resolve and reject it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.withResolvers
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/withResolvers
+    pub(crate) fn with_resolvers(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ? NewPromiseCapability(C).
+        let PromiseCapability {
+            promise,
+            resolve,
+            reject,
+        } = PromiseCapability::new(this, context)?;
+
+        // 3. Let obj be OrdinaryObjectCreate(%Object.prototype%).
+        let obj = context.construct_object();
+
+        // 4. Perform ! CreateDataPropertyOrThrow(obj, "promise", promiseCapability.[[Promise]]).
+        obj.create_data_property_or_throw("promise", promise, context)
+            .expect("cannot fail per spec");
+
+        // 5. Perform ! CreateDataPropertyOrThrow(obj, "resolve", promiseCapability.[[Resolve]]).
+        obj.create_data_property_or_throw("resolve", resolve, context)
+            .expect("cannot fail per spec");
+
+        // 6. Perform ! CreateDataPropertyOrThrow(obj, "reject", promiseCapability.[[Reject]]).
+        obj.create_data_property_or_throw("reject", reject, context)
+            .expect("cannot fail per spec");
+
+        // 7. Return obj.
+        Ok(obj.into())
+    }
+
+    /// `get Promise [ @@species ]`
+    ///
+    /// The `Promise [ @@species ]` accessor property returns the Promise constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-promise-@@species
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/@@species
+    fn get_species(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Return the this value.
+        Ok(this.clone())
+    }
+
+    /// `Promise.prototype.then ( onFulfilled, onRejected )`
+    ///
+    /// Appends fulfillment and rejection handlers to the promise, and returns a new promise
+    /// resolved to the return value of the called handler.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.then
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/then
+    pub(crate) fn then(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let promise be the this value.
+        // 2. If IsPromise(promise) is false, throw a TypeError exception.
+        let promise = this
+            .as_object()
+            .filter(|promise| promise.borrow().is_promise())
+            .ok_or_else(|| {
+                context.construct_type_error("Promise.prototype.then: 'this' is not a Promise")
+            })?;
+
+        // 3. Let C be ? SpeciesConstructor(promise, %Promise%).
+        let c = promise.species_constructor(StandardConstructors::promise, context)?;
+
+        // 4. Let resultCapability be ? NewPromiseCapability(C).
+        let result_capability = PromiseCapability::new(&c.into(), context)?;
+
+        // 5. Return PerformPromiseThen(promise, onFulfilled, onRejected, resultCapability).
+        Ok(Self::perform_promise_then(
+            promise,
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            Some(result_capability),
+            context,
+        ))
+    }
+
+    /// `Promise.prototype.catch ( onRejected )`
+    ///
+    /// Appends a rejection handler to the promise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.catch
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/catch
+    pub(crate) fn catch(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let promise be the this value.
+        // 2. Return ? Invoke(promise, "then", « undefined, onRejected »).
+        this.invoke(
+            "then",
+            &[JsValue::undefined(), args.get_or_undefined(0).clone()],
+            context,
+        )
+    }
+
+    /// `Promise.prototype.finally ( onFinally )`
+    ///
+    /// Appends a handler called when the promise is settled, whatever its outcome.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.finally
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/finally
+    pub(crate) fn finally(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        #[derive(Debug, Trace, Finalize)]
+        struct FinallyCaptures {
+            on_finally: JsObject,
+            c: JsObject,
+        }
+
+        // 1. Let promise be the this value.
+        // 2. If Type(promise) is not Object, throw a TypeError exception.
+        let promise = this.as_object().ok_or_else(|| {
+            context.construct_type_error("Promise.prototype.finally: 'this' is not an object")
+        })?;
+
+        // 3. Let C be ? SpeciesConstructor(promise, %Promise%).
+        // 4. Assert: IsConstructor(C) is true.
+        let c = promise.species_constructor(StandardConstructors::promise, context)?;
+
+        let on_finally = args.get_or_undefined(0);
+
+        // 5. If IsCallable(onFinally) is false, then
+        let (then_finally, catch_finally) = if let Some(on_finally) = on_finally.as_callable() {
+            // 6. Else,
+            //     a. Let thenFinallyClosure be a new Abstract Closure with parameters (value) that
+            //        captures onFinally and C and performs the following steps when called:
+            //     b. Let thenFinally be CreateBuiltinFunction(thenFinallyClosure, 1, "", « »).
+            let then_finally = FunctionBuilder::closure_with_captures(
+                context,
+                |_, args: &[JsValue], captures: &mut FinallyCaptures, context| {
+                    // i. Let result be ? Call(onFinally, undefined).
+                    let result = captures
+                        .on_finally
+                        .call(&JsValue::undefined(), &[], context)?;
+
+                    // ii. Let promise be ? PromiseResolve(C, result).
+                    let promise = Self::promise_resolve(&captures.c, result, context)?;
+
+                    // iii. Let returnValue be a new Abstract Closure with no parameters that
+                    //      captures value and performs the following steps when called:
+                    //     1. Return value.
+                    // iv. Let valueThunk be CreateBuiltinFunction(returnValue, 0, "", « »).
+                    let value_thunk = FunctionBuilder::closure_with_captures(
+                        context,
+                        |_, _, value: &mut JsValue, _| Ok(value.clone()),
+                        args.get_or_undefined(0).clone(),
+                    )
+                    .name("")
+                    .length(0)
+                    .build();
+
+                    // v. Return ? Invoke(promise, "then", « valueThunk »).
+                    JsValue::from(promise).invoke("then", &[value_thunk.into()], context)
+                },
+                FinallyCaptures {
+                    on_finally: on_finally.clone(),
+                    c: c.clone(),
+                },
+            )
+            .name("")
+            .length(1)
+            .build();
+
+            //     c. Let catchFinallyClosure be a new Abstract Closure with parameters (reason)
+            //        that captures onFinally and C and performs the following steps when called:
+            //     d. Let catchFinally be CreateBuiltinFunction(catchFinallyClosure, 1, "", « »).
+            let catch_finally = FunctionBuilder::closure_with_captures(
+                context,
+                |_, args: &[JsValue], captures: &mut FinallyCaptures, context| {
+                    // i. Let result be ? Call(onFinally, undefined).
+                    let result = captures
+                        .on_finally
+                        .call(&JsValue::undefined(), &[], context)?;
+
+                    // ii. Let promise be ? PromiseResolve(C, result).
+                    let promise = Self::promise_resolve(&captures.c, result, context)?;
+
+                    // iii. Let throwReason be a new Abstract Closure with no parameters that
+                    //      captures reason and performs the following steps when called:
+                    //     1. Return ThrowCompletion(reason).
+                    // iv. Let thrower be CreateBuiltinFunction(throwReason, 0, "", « »).
+                    let thrower = FunctionBuilder::closure_with_captures(
+                        context,
+                        |_, _, reason: &mut JsValue, _| Err(reason.clone()),
+                        args.get_or_undefined(0).clone(),
+                    )
+                    .name("")
+                    .length(0)
+                    .build();
+
+                    // v. Return ? Invoke(promise, "then", « thrower »).
+                    JsValue::from(promise).invoke("then", &[thrower.into()], context)
+                },
+                FinallyCaptures {
+                    on_finally: on_finally.clone(),
+                    c,
+                },
+            )
+            .name("")
+            .length(1)
+            .build();
+
+            (then_finally.into(), catch_finally.into())
+        } else {
+            // a. Let thenFinally be onFinally.
+            // b. Let catchFinally be onFinally.
+            (on_finally.clone(), on_finally.clone())
+        };
+
+        // 7. Return ? Invoke(promise, "then", « thenFinally, catchFinally »).
+        this.invoke("then", &[then_finally, catch_finally], context)
+    }
+}
@@ -0,0 +1,145 @@
+use super::{Promise, PromiseEvent, ReactionRecord, ReactionType, ResolvingFunctions};
+use crate::{job::NativeJob, object::JsObject, Context, JsValue};
+
+/// The jobs enqueued by the `Promise` abstract operations.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct PromiseJob;
+
+impl PromiseJob {
+    /// `NewPromiseReactionJob ( reaction, argument )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromisereactionjob
+    pub(super) fn new_promise_reaction_job(
+        reaction: ReactionRecord,
+        argument: JsValue,
+    ) -> NativeJob {
+        // 1. Let job be a new Job Abstract Closure with no parameters that captures reaction and
+        //    argument and performs the following steps when called:
+        let job = move |context: &mut Context| {
+            // a. Let promiseCapability be reaction.[[Capability]].
+            // b. Let type be reaction.[[Type]].
+            // c. Let handler be reaction.[[Handler]].
+            let ReactionRecord {
+                promise_capability,
+                reaction_type,
+                handler,
+            } = reaction;
+
+            // The hooks observe the job through the promise derived from the reaction, so the
+            // reactions without a capability aren't reported.
+            let derived = promise_capability
+                .as_ref()
+                .map(|capability| capability.promise.clone());
+            if let Some(derived) = &derived {
+                context
+                    .host_hooks()
+                    .promise_hook(PromiseEvent::Before, derived, context);
+            }
+
+            let handler_result = match handler {
+                // d. If handler is empty, then
+                None => match reaction_type {
+                    // i. If type is fulfill, let handlerResult be NormalCompletion(argument).
+                    ReactionType::Fulfill => Ok(argument),
+                    // ii. Else,
+                    //     1. Assert: type is reject.
+                    //     2. Let handlerResult be ThrowCompletion(argument).
+                    ReactionType::Reject => Err(argument),
+                },
+                // e. Else, let handlerResult be Completion(HostCallJobCallback(handler, undefined, « argument »)).
+                Some(handler) => handler.call(&JsValue::undefined(), &[argument], context),
+            };
+
+            let result = match promise_capability {
+                // f. If promiseCapability is undefined, then
+                None => {
+                    // i. Assert: handlerResult is not an abrupt completion.
+                    debug_assert!(
+                        handler_result.is_ok(),
+                        "reactions without a capability cannot throw"
+                    );
+
+                    // ii. Return empty.
+                    Ok(JsValue::undefined())
+                }
+                // g. Assert: promiseCapability is a PromiseCapability Record.
+                Some(promise_capability) => match handler_result {
+                    // h. If handlerResult is an abrupt completion, then
+                    //     i. Return ? Call(promiseCapability.[[Reject]], undefined, « handlerResult.[[Value]] »).
+                    Err(value) => {
+                        promise_capability
+                            .reject
+                            .call(&JsValue::undefined(), &[value], context)
+                    }
+                    // i. Else,
+                    //     i. Return ? Call(promiseCapability.[[Resolve]], undefined, « handlerResult.[[Value]] »).
+                    Ok(value) => {
+                        promise_capability
+                            .resolve
+                            .call(&JsValue::undefined(), &[value], context)
+                    }
+                },
+            };
+
+            if let Some(derived) = &derived {
+                context
+                    .host_hooks()
+                    .promise_hook(PromiseEvent::After, derived, context);
+            }
+            result
+        };
+
+        // 2. Let handlerRealm be null.
+        // 3. If reaction.[[Handler]] is not empty, then
+        //     a. Let getHandlerRealmResult be Completion(GetFunctionRealm(reaction.[[Handler]].[[Callback]])).
+        //     b. If getHandlerRealmResult is a normal completion, set handlerRealm to getHandlerRealmResult.[[Value]].
+        //     c. Else, set handlerRealm to the current Realm Record.
+        //     d. NOTE: handlerRealm is never null unless the handler is undefined. When the handler is a
+        //        revoked Proxy and no ECMAScript code runs, handlerRealm is used to create error objects.
+        // 4. Return the Record { [[Job]]: job, [[Realm]]: handlerRealm }.
+        NativeJob::new(job)
+    }
+
+    /// `NewPromiseResolveThenableJob ( promiseToResolve, thenable, then )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromiseresolvethenablejob
+    pub(super) fn new_promise_resolve_thenable_job(
+        promise_to_resolve: JsObject,
+        thenable: JsValue,
+        then: JsObject,
+    ) -> NativeJob {
+        // 1. Let job be a new Job Abstract Closure with no parameters that captures
+        //    promiseToResolve, thenable, and then and performs the following steps when called:
+        let job = move |context: &mut Context| {
+            // a. Let resolvingFunctions be CreateResolvingFunctions(promiseToResolve).
+            let ResolvingFunctions { resolve, reject } =
+                Promise::create_resolving_functions(&promise_to_resolve, context);
+
+            // b. Let thenCallResult be Completion(HostCallJobCallback(then, thenable, « resolvingFunctions.[[Resolve]], resolvingFunctions.[[Reject]] »)).
+            let then_call_result =
+                then.call(&thenable, &[resolve.into(), reject.clone().into()], context);
+
+            match then_call_result {
+                // c. If thenCallResult is an abrupt completion, then
+                //     i. Return ? Call(resolvingFunctions.[[Reject]], undefined, « thenCallResult.[[Value]] »).
+                Err(value) => reject.call(&JsValue::undefined(), &[value], context),
+                // d. Return ? thenCallResult.
+                Ok(value) => Ok(value),
+            }
+        };
+
+        // 2. Let getThenRealmResult be Completion(GetFunctionRealm(then.[[Callback]])).
+        // 3. If getThenRealmResult is a normal completion, let thenRealm be getThenRealmResult.[[Value]].
+        // 4. Else, let thenRealm be the current Realm Record.
+        // 5. NOTE: thenRealm is never null. When then.[[Callback]] is a revoked Proxy and no code
+        //    runs, thenRealm is used to create error objects.
+        // 6. Return the Record { [[Job]]: job, [[Realm]]: thenRealm }.
+        NativeJob::new(job)
+    }
+}
@@ -0,0 +1,269 @@
+use super::OperationType;
+use crate::{forward, Context};
+use std::{cell::RefCell, rc::Rc};
+
+#[test]
+fn reactions_run_as_jobs() {
+    let mut context = Context::default();
+    let init = r#"
+        var log = [];
+        Promise.resolve(1).then((v) => log.push("then " + v));
+        log.push("sync");
+        var synchronous = log.join();
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "synchronous"), "\"sync\"");
+    assert_eq!(forward(&mut context, "log.join()"), "\"sync,then 1\"");
+}
+
+#[test]
+fn then_chaining() {
+    let mut context = Context::default();
+    let init = r#"
+        var log = [];
+        new Promise((resolve) => resolve(1))
+            .then((v) => v + 1)
+            .then((v) => { throw v + 1; })
+            .then(() => log.push("unreachable"))
+            .catch((e) => { log.push("caught " + e); return Promise.resolve(4); })
+            .finally(() => log.push("finally"))
+            .then((v) => log.push("value " + v));
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"caught 3,finally,value 4\""
+    );
+}
+
+#[test]
+fn thenables() {
+    let mut context = Context::default();
+    let init = r#"
+        var result;
+        var thenable = { then(resolve) { resolve("thenable"); } };
+        Promise.resolve(thenable).then((v) => { result = v; });
+
+        var selfResolved = new Promise((resolve) => { Promise.resolve().then(() => resolve(selfResolved)); });
+        var selfError;
+        selfResolved.catch((e) => { selfError = e.name; });
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "\"thenable\"");
+    assert_eq!(forward(&mut context, "selfError"), "\"TypeError\"");
+}
+
+#[test]
+fn combinators() {
+    let mut context = Context::default();
+    let init = r#"
+        var all, allSettled, any, anyError, race;
+        Promise.all([1, Promise.resolve(2), 3]).then((v) => { all = v.join(); });
+        Promise.allSettled([1, Promise.reject(2)]).then((v) => {
+            allSettled = v.map((r) => r.status + ":" + ("value" in r ? r.value : r.reason)).join();
+        });
+        Promise.any([Promise.reject(1), 2]).then((v) => { any = v; });
+        Promise.any([Promise.reject(1), Promise.reject(2)]).catch((e) => {
+            anyError = e.constructor.name + ":" + e.errors.join();
+        });
+        Promise.race([new Promise(() => {}), Promise.resolve("race")]).then((v) => { race = v; });
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "all"), "\"1,2,3\"");
+    assert_eq!(
+        forward(&mut context, "allSettled"),
+        "\"fulfilled:1,rejected:2\""
+    );
+    assert_eq!(forward(&mut context, "any"), "2");
+    assert_eq!(forward(&mut context, "anyError"), "\"AggregateError:1,2\"");
+    assert_eq!(forward(&mut context, "race"), "\"race\"");
+}
+
+#[test]
+fn with_resolvers() {
+    let mut context = Context::default();
+    let init = r#"
+        var { promise, resolve, reject } = Promise.withResolvers();
+        var result;
+        promise.then((v) => { result = v; });
+        resolve("resolved");
+        reject("ignored");
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "\"resolved\"");
+    assert_eq!(forward(&mut context, "promise instanceof Promise"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Promise.withResolvers.call(1); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn rejection_tracker() {
+    let mut context = Context::default();
+    let operations = Rc::new(RefCell::new(Vec::new()));
+    let tracked = operations.clone();
+    context.set_promise_rejection_tracker(move |_, operation, _| {
+        tracked.borrow_mut().push(operation);
+    });
+
+    forward(&mut context, "var rejected = Promise.reject(1);");
+    assert_eq!(*operations.borrow(), [OperationType::Reject]);
+
+    forward(&mut context, "rejected.catch(() => {});");
+    assert_eq!(
+        *operations.borrow(),
+        [OperationType::Reject, OperationType::Handle]
+    );
+
+    forward(
+        &mut context,
+        "var later; new Promise((_, reject) => { later = reject; }).catch(() => {}); later(2);",
+    );
+    assert_eq!(
+        *operations.borrow(),
+        [OperationType::Reject, OperationType::Handle]
+    );
+}
+
+#[test]
+fn custom_job_queue() {
+    use crate::job::{JobQueue, NativeJob, SimpleJobQueue};
+    use crate::JsResult;
+
+    /// A queue that only runs its jobs when the embedder asks for it.
+    #[derive(Debug, Default)]
+    struct DeferredQueue {
+        jobs: SimpleJobQueue,
+        enqueued: RefCell<usize>,
+    }
+
+    impl JobQueue for DeferredQueue {
+        fn enqueue_job(&self, job: NativeJob, context: &mut Context) {
+            *self.enqueued.borrow_mut() += 1;
+            self.jobs.enqueue_job(job, context);
+        }
+
+        fn run_jobs(&self, _: &mut Context) -> JsResult<()> {
+            Ok(())
+        }
+    }
+
+    let queue = Rc::new(DeferredQueue::default());
+    let mut context = Context::builder().job_queue(queue.clone()).build();
+    let init = r#"
+        var log = [];
+        Promise.resolve(1).then((v) => log.push("then " + v)).then(() => log.push("done"));
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "log.length"), "0");
+    assert_eq!(*queue.enqueued.borrow(), 1);
+
+    queue.jobs.run_jobs(&mut context).unwrap();
+    assert!(queue.jobs.is_empty());
+    assert_eq!(*queue.enqueued.borrow(), 2);
+    assert_eq!(forward(&mut context, "log.join()"), "\"then 1,done\"");
+}
+
+#[test]
+fn host_promise_hooks() {
+    use super::PromiseEvent;
+    use crate::{context::HostHooks, object::JsObject};
+
+    /// Records the promise events, with the index of the promise in the order of creation.
+    #[derive(Debug, Default)]
+    struct Recorder {
+        promises: RefCell<Vec<JsObject>>,
+        events: RefCell<Vec<(PromiseEvent, usize)>>,
+    }
+
+    impl HostHooks for Recorder {
+        fn promise_hook(&self, event: PromiseEvent, promise: &JsObject, _: &mut Context) {
+            let mut promises = self.promises.borrow_mut();
+            let index = match promises.iter().position(|p| JsObject::equals(p, promise)) {
+                Some(index) => index,
+                None => {
+                    promises.push(promise.clone());
+                    promises.len() - 1
+                }
+            };
+            self.events.borrow_mut().push((event, index));
+        }
+    }
+
+    let hooks = Rc::new(Recorder::default());
+    let mut context = Context::builder().host_hooks(hooks.clone()).build();
+    forward(
+        &mut context,
+        "new Promise((resolve) => resolve(1)).then(() => {});",
+    );
+    assert_eq!(
+        *hooks.events.borrow(),
+        [
+            (PromiseEvent::Init, 0),
+            (PromiseEvent::Resolve, 0),
+            (PromiseEvent::Init, 1),
+            (PromiseEvent::Before, 1),
+            (PromiseEvent::Resolve, 1),
+            (PromiseEvent::After, 1),
+        ]
+    );
+}
+
+#[test]
+fn js_promise() {
+    use crate::{
+        object::{JsObject, JsPromise},
+        JsValue,
+    };
+    use std::{
+        future::Future,
+        pin::Pin,
+        sync::Arc,
+        task::{Context as TaskContext, Poll, Wake, Waker},
+    };
+
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let mut context = Context::default();
+
+    let rejected = JsPromise::new(
+        |_, context| context.throw_type_error("executor failed"),
+        &mut context,
+    )
+    .unwrap();
+    assert!(rejected.await_blocking(&mut context).is_err());
+
+    let (promise, functions) = JsPromise::new_pending(&mut context);
+    let mut future = promise.clone().into_future(&mut context);
+    let waker = Waker::from(Arc::new(NoopWaker));
+    let mut cx = TaskContext::from_waker(&waker);
+    assert!(Pin::new(&mut future).poll(&mut cx).is_pending());
+
+    functions
+        .resolve
+        .call(&JsValue::undefined(), &[1.into()], &mut context)
+        .unwrap();
+    assert!(Pin::new(&mut future).poll(&mut cx).is_pending());
+    context.run_jobs().unwrap();
+    assert_eq!(
+        Pin::new(&mut future).poll(&mut cx),
+        Poll::Ready(Ok(1.into()))
+    );
+
+    let resolved = JsPromise::resolve(promise.clone(), &mut context).unwrap();
+    assert!(JsObject::equals(&resolved, &promise));
+    assert_eq!(
+        JsPromise::reject("reason", &mut context)
+            .unwrap()
+            .await_blocking(&mut context),
+        Err("reason".into())
+    );
+}
@@ -19,6 +19,9 @@ use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
 use tap::{Conv, Pipe};
 
+#[cfg(test)]
+mod tests;
+
 /// Javascript `Proxy` object.
 #[derive(Debug, Clone, Trace, Finalize)]
 pub struct Proxy {
@@ -50,7 +53,7 @@ impl BuiltIn for Proxy {
 impl Proxy {
     const LENGTH: usize = 2;
 
-    fn new(target: JsObject, handler: JsObject) -> Self {
+    pub(crate) fn new(target: JsObject, handler: JsObject) -> Self {
         Self {
             data: Some((target, handler)),
         }
@@ -82,7 +85,7 @@ impl Proxy {
         }
 
         // 2. Return ? ProxyCreate(target, handler).
-        Self::create(args.get_or_undefined(0), args.get_or_undefined(1), context)
+        Self::create_checked(args.get_or_undefined(0), args.get_or_undefined(1), context)
     }
 
     // `10.5.14 ProxyCreate ( target, handler )`
@@ -91,7 +94,11 @@ impl Proxy {
     //  - [ECMAScript reference][spec]
     //
     // [spec]: https://tc39.es/ecma262/#sec-proxycreate
-    fn create(target: &JsValue, handler: &JsValue, context: &mut Context) -> JsResult<JsValue> {
+    fn create_checked(
+        target: &JsValue,
+        handler: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
         // 1. If Type(target) is not Object, throw a TypeError exception.
         let target = target.as_object().ok_or_else(|| {
             context.construct_type_error("Proxy constructor called with non-object target")
@@ -110,32 +117,31 @@ impl Proxy {
         // i. Set P.[[Construct]] as specified in 10.5.13.
         // 6. Set P.[[ProxyTarget]] to target.
         // 7. Set P.[[ProxyHandler]] to handler.
-        let p = JsObject::from_proto_and_data(
+        // 8. Return P.
+        Ok(Self::create(target, handler, context).into())
+    }
+
+    /// Creates a proxy object from a target and a handler that are known to be objects.
+    ///
+    /// This is the infallible part of [`ProxyCreate`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxycreate
+    pub(crate) fn create(target: &JsObject, handler: &JsObject, context: &Context) -> JsObject {
+        JsObject::from_proto_and_data(
             context.intrinsics().constructors().object().prototype(),
             ObjectData::proxy(
                 Self::new(target.clone(), handler.clone()),
                 target.is_callable(),
                 target.is_constructor(),
             ),
-        );
-
-        // 8. Return P.
-        Ok(p.into())
+        )
     }
 
-    /// `28.2.2.1 Proxy.revocable ( target, handler )`
+    /// Creates the revoker function of a revocable proxy.
     ///
-    /// More information:
-    ///  - [ECMAScript reference][spec]
-    ///
-    /// [spec]: https://tc39.es/ecma262/#sec-proxy.revocable
-    fn revocable(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        // 1. Let p be ? ProxyCreate(target, handler).
-        let p = Self::create(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
-
-        // 3. Let revoker be ! CreateBuiltinFunction(revokerClosure, 0, "", « [[RevocableProxy]] »).
-        // 4. Set revoker.[[RevocableProxy]] to p.
-        let revoker = FunctionBuilder::closure_with_captures(
+    /// Calling the returned function sets the target and handler of `proxy` to null.
+    pub(crate) fn revoker(proxy: JsObject, context: &mut Context) -> JsObject {
+        FunctionBuilder::closure_with_captures(
             context,
             |_, _, revocable_proxy, _| {
                 // a. Let F be the active function object.
@@ -163,9 +169,29 @@ impl Proxy {
                 // h. Return undefined.
                 Ok(JsValue::undefined())
             },
-            p.clone(),
+            JsValue::from(proxy),
         )
-        .build();
+        .build()
+    }
+
+    /// `28.2.2.1 Proxy.revocable ( target, handler )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy.revocable
+    fn revocable(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let p be ? ProxyCreate(target, handler).
+        let p = Self::create_checked(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+
+        // 3. Let revoker be ! CreateBuiltinFunction(revokerClosure, 0, "", « [[RevocableProxy]] »).
+        // 4. Set revoker.[[RevocableProxy]] to p.
+        let revoker = Self::revoker(
+            p.as_object()
+                .cloned()
+                .expect("ProxyCreate must return an object"),
+            context,
+        );
 
         // 5. Let result be ! OrdinaryObjectCreate(%Object.prototype%).
         let result = context.construct_object();
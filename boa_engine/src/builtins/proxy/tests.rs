@@ -0,0 +1,187 @@
+use crate::{
+    forward, forward_val, object::JsProxy, property::Attribute, Context, JsResult, JsValue,
+};
+
+#[test]
+fn set_trap_arguments() {
+    let mut context = Context::default();
+    let init = r#"
+        let log = [];
+        let p = new Proxy({}, {
+            set(target, key, value, receiver) {
+                log.push(key, value, receiver === p);
+                target[key] = value;
+                return true;
+            }
+        });
+        p.a = 1;
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "log.join()"), "\"a,1,true\"");
+    assert_eq!(forward(&mut context, "p.a"), "1");
+}
+
+#[test]
+fn all_traps_are_called() {
+    let mut context = Context::default();
+    let init = r#"
+        let called = [];
+        let handler = {};
+        for (const name of ["getPrototypeOf", "setPrototypeOf", "isExtensible",
+                "preventExtensions", "getOwnPropertyDescriptor", "defineProperty", "has",
+                "get", "set", "deleteProperty", "ownKeys", "apply", "construct"]) {
+            handler[name] = function (...args) {
+                called.push(name);
+                return Reflect[name](...args);
+            };
+        }
+        let p = new Proxy(function () {}, handler);
+        Object.getPrototypeOf(p);
+        Object.setPrototypeOf(p, Function.prototype);
+        Object.isExtensible(p);
+        Object.getOwnPropertyDescriptor(p, "x");
+        Object.defineProperty(p, "x", { value: 1, configurable: true, writable: true });
+        "x" in p;
+        p.x;
+        p.x = 2;
+        delete p.x;
+        Object.keys(p);
+        p();
+        new p();
+        Object.preventExtensions(p);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "new Set(called).size"), "13");
+}
+
+#[test]
+fn own_keys_invariants() {
+    let mut context = Context::default();
+    let init = r#"
+        function check(target, keys) {
+            try {
+                Reflect.ownKeys(new Proxy(target, { ownKeys: () => keys }));
+                return "ok";
+            } catch (e) {
+                return e.constructor.name;
+            }
+        }
+        let frozen = Object.freeze({ a: 1 });
+        let sealed = Object.defineProperty({}, "b", { value: 1 });
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "check({}, ['a', 'a'])"),
+        "\"TypeError\""
+    );
+    assert_eq!(forward(&mut context, "check({}, [1])"), "\"TypeError\"");
+    assert_eq!(forward(&mut context, "check(sealed, [])"), "\"TypeError\"");
+    assert_eq!(forward(&mut context, "check(sealed, ['b', 'c'])"), "\"ok\"");
+    assert_eq!(forward(&mut context, "check(frozen, ['a'])"), "\"ok\"");
+    assert_eq!(
+        forward(&mut context, "check(frozen, ['a', 'b'])"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn non_configurable_invariants() {
+    let mut context = Context::default();
+    let init = r#"
+        let target = Object.defineProperty({}, "fixed", { value: 1 });
+        function throws(f) {
+            try {
+                f();
+                return false;
+            } catch (e) {
+                return e instanceof TypeError;
+            }
+        }
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(
+            &mut context,
+            "throws(() => new Proxy(target, { get: () => 2 }).fixed)"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "throws(() => 'fixed' in new Proxy(target, { has: () => false }))"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "throws(() => Object.getOwnPropertyDescriptor(new Proxy(target, { getOwnPropertyDescriptor: () => undefined }), 'fixed'))"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "throws(() => Reflect.deleteProperty(new Proxy(target, { deleteProperty: () => true }), 'fixed'))"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "throws(() => Object.getOwnPropertyDescriptor(new Proxy({}, { getOwnPropertyDescriptor: () => ({ value: 1, configurable: false }) }), 'x'))"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn revocable() {
+    let mut context = Context::default();
+    let init = r#"
+        let { proxy, revoke } = Proxy.revocable({ a: 1 }, {});
+        let before = proxy.a;
+        revoke();
+        revoke();
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "before"), "1");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { proxy.a } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn js_proxy() {
+    fn get(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let key = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
+        Ok(format!("trapped {key}").into())
+    }
+
+    let mut context = Context::default();
+    let target = context.construct_object();
+    let proxy = JsProxy::builder(target).get(get).build(&mut context);
+    assert_eq!(
+        proxy.get("answer", &mut context).unwrap(),
+        JsValue::new("trapped answer")
+    );
+    assert!(JsProxy::from_object(proxy.clone().into(), &mut context).is_ok());
+    assert!(JsProxy::from_object(context.construct_object(), &mut context).is_err());
+
+    let revocable = JsProxy::builder(context.construct_object())
+        .get(get)
+        .build_revocable(&mut context);
+    context.register_global_property("p", revocable.proxy(), Attribute::all());
+    assert_eq!(forward(&mut context, "p.x"), "\"trapped x\"");
+    revocable.revoke(&mut context).unwrap();
+    assert!(forward_val(&mut context, "p.x").is_err());
+}
@@ -15,12 +15,12 @@ use self::regexp_string_iterator::RegExpStringIterator;
 use super::JsArgs;
 use crate::{
     builtins::{array::Array, string, BuiltIn},
-    context::intrinsics::StandardConstructors,
+    context::{intrinsics::StandardConstructors, Builtins},
     object::{
         internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
         JsObject, ObjectData,
     },
-    property::Attribute,
+    property::{Attribute, PropertyDescriptor, PropertyKey},
     symbol::WellKnownSymbols,
     syntax::lexer::regex::RegExpFlags,
     value::{IntegerOrInfinity, JsValue},
@@ -28,8 +28,8 @@ use crate::{
 };
 use boa_gc::{unsafe_empty_trace, Finalize, Trace};
 use boa_profiler::Profiler;
-use regress::Regex;
-use std::str::FromStr;
+use regress::{NamedGroups, Range, Regex};
+use std::{rc::Rc, str::FromStr};
 use tap::{Conv, Pipe};
 
 #[cfg(test)]
@@ -38,8 +38,8 @@ mod tests;
 /// The internal representation on a `RegExp` object.
 #[derive(Debug, Clone, Finalize)]
 pub struct RegExp {
-    /// Regex matcher.
-    matcher: Regex,
+    /// Regex matcher, shared with the regexp cache of the code block that created it.
+    matcher: Rc<Regex>,
     flags: RegExpFlags,
     original_source: JsString,
     original_flags: JsString,
@@ -63,6 +63,10 @@ impl BuiltIn for RegExp {
 
         let flag_attributes = Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE;
 
+        let get_has_indices = FunctionBuilder::native(context, Self::get_has_indices)
+            .name("get hasIndices")
+            .constructor(false)
+            .build();
         let get_global = FunctionBuilder::native(context, Self::get_global)
             .name("get global")
             .constructor(false)
@@ -83,6 +87,10 @@ impl BuiltIn for RegExp {
             .name("get unicode")
             .constructor(false)
             .build();
+        let get_unicode_sets = FunctionBuilder::native(context, Self::get_unicode_sets)
+            .name("get unicodeSets")
+            .constructor(false)
+            .build();
         let get_sticky = FunctionBuilder::native(context, Self::get_sticky)
             .name("get sticky")
             .constructor(false)
@@ -137,11 +145,13 @@ impl BuiltIn for RegExp {
             (WellKnownSymbols::split(), "[Symbol.split]"),
             2,
         )
+        .accessor("hasIndices", Some(get_has_indices), None, flag_attributes)
         .accessor("global", Some(get_global), None, flag_attributes)
         .accessor("ignoreCase", Some(get_ignore_case), None, flag_attributes)
         .accessor("multiline", Some(get_multiline), None, flag_attributes)
         .accessor("dotAll", Some(get_dot_all), None, flag_attributes)
         .accessor("unicode", Some(get_unicode), None, flag_attributes)
+        .accessor("unicodeSets", Some(get_unicode_sets), None, flag_attributes)
         .accessor("sticky", Some(get_sticky), None, flag_attributes)
         .accessor("flags", Some(get_flags), None, flag_attributes)
         .accessor("source", Some(get_source), None, flag_attributes)
@@ -159,6 +169,16 @@ impl RegExp {
     /// The amount of arguments this function object takes.
     pub(crate) const LENGTH: usize = 2;
 
+    /// Gets the `[[OriginalSource]]` of the regular expression.
+    pub(crate) fn original_source(&self) -> &JsString {
+        &self.original_source
+    }
+
+    /// Gets the `[[OriginalFlags]]` of the regular expression.
+    pub(crate) fn original_flags(&self) -> &JsString {
+        &self.original_flags
+    }
+
     /// `22.2.3.1 RegExp ( pattern, flags )`
     ///
     /// More information:
@@ -263,8 +283,9 @@ impl RegExp {
             flags.to_string(context)?
         };
 
-        // 5. If F contains any code unit other than "g", "i", "m", "s", "u", or "y"
+        // 5. If F contains any code unit other than "d", "g", "i", "m", "s", "u", "v", or "y"
         //    or if it contains the same code unit more than once, throw a SyntaxError exception.
+        // 6. If F contains "u" and "v", throw a SyntaxError exception.
         let flags = match RegExpFlags::from_str(&f) {
             Err(msg) => return context.throw_syntax_error(msg),
             Ok(result) => result,
@@ -273,7 +294,7 @@ impl RegExp {
         // 12. Set obj.[[OriginalSource]] to P.
         // 13. Set obj.[[OriginalFlags]] to F.
         // 14. Set obj.[[RegExpMatcher]] to the Abstract Closure that evaluates parseResult by applying the semantics provided in 22.2.2 using patternCharacters as the pattern's List of SourceCharacter values and F as the flag parameters.
-        let matcher = match Regex::with_flags(&p, f.as_ref()) {
+        let matcher = match Self::compile_matcher(&p, &f, context) {
             Err(error) => {
                 return context
                     .throw_syntax_error(format!("failed to create matcher: {}", error.text));
@@ -294,6 +315,39 @@ impl RegExp {
         Ok(this.clone())
     }
 
+    /// The maximum number of compiled regular expressions cached by a code block.
+    const CODE_BLOCK_CACHE_CAPACITY: usize = 32;
+
+    /// Compiles the matcher of a regular expression, reusing the compiled program if the running
+    /// code block already compiled the same pattern with the same flags.
+    ///
+    /// This makes evaluating a regular expression literal in a loop, or repeatedly calling the
+    /// `String.prototype` methods that create regular expressions from strings, compile the
+    /// pattern only once.
+    fn compile_matcher(
+        pattern: &JsString,
+        flags: &JsString,
+        context: &Context,
+    ) -> Result<Rc<Regex>, regress::Error> {
+        let frame = if let Some(frame) = context.vm.frame.as_ref() {
+            frame
+        } else {
+            return Regex::with_flags(pattern, flags.as_ref()).map(Rc::new);
+        };
+
+        let mut cache = frame.code.regexp_cache.borrow_mut();
+        let key = (pattern.clone(), flags.clone());
+        if let Some(matcher) = cache.get(&key) {
+            return Ok(matcher.clone());
+        }
+
+        let matcher = Rc::new(Regex::with_flags(pattern, flags.as_ref())?);
+        if cache.len() < Self::CODE_BLOCK_CACHE_CAPACITY {
+            cache.insert(key, matcher.clone());
+        }
+        Ok(matcher)
+    }
+
     /// `22.2.3.2.4 RegExpCreate ( P, F )`
     ///
     /// More information:
@@ -301,6 +355,10 @@ impl RegExp {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-regexpcreate
     pub(crate) fn create(p: JsValue, f: JsValue, context: &mut Context) -> JsResult<JsValue> {
+        if context.disabled_builtins().contains(Builtins::REGEXP) {
+            return context.throw_type_error("regular expressions are disabled");
+        }
+
         // 1. Let obj be ? RegExpAlloc(%RegExp%).
         let obj = Self::alloc(
             &context.global_object().clone().get(Self::NAME, context)?,
@@ -333,11 +391,13 @@ impl RegExp {
         if let Some(object) = this.as_object() {
             if let Some(regexp) = object.borrow().as_regexp() {
                 return Ok(JsValue::new(match flag {
+                    b'd' => regexp.flags.contains(RegExpFlags::HAS_INDICES),
                     b'g' => regexp.flags.contains(RegExpFlags::GLOBAL),
                     b'm' => regexp.flags.contains(RegExpFlags::MULTILINE),
                     b's' => regexp.flags.contains(RegExpFlags::DOT_ALL),
                     b'i' => regexp.flags.contains(RegExpFlags::IGNORE_CASE),
                     b'u' => regexp.flags.contains(RegExpFlags::UNICODE),
+                    b'v' => regexp.flags.contains(RegExpFlags::UNICODE_SETS),
                     b'y' => regexp.flags.contains(RegExpFlags::STICKY),
                     _ => unreachable!(),
                 }));
@@ -352,11 +412,13 @@ impl RegExp {
         }
 
         let name = match flag {
+            b'd' => "hasIndices",
             b'g' => "global",
             b'm' => "multiline",
             b's' => "dotAll",
             b'i' => "ignoreCase",
             b'u' => "unicode",
+            b'v' => "unicodeSets",
             b'y' => "sticky",
             _ => unreachable!(),
         };
@@ -366,6 +428,24 @@ impl RegExp {
         ))
     }
 
+    /// `get RegExp.prototype.hasIndices`
+    ///
+    /// The `hasIndices` property indicates whether or not the "`d`" flag is used with the regular expression.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.hasIndices
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/hasIndices
+    pub(crate) fn get_has_indices(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::regexp_has_flag(this, b'd', context)
+    }
+
     /// `get RegExp.prototype.global`
     ///
     /// The `global` property indicates whether or not the "`g`" flag is used with the regular expression.
@@ -457,6 +537,24 @@ impl RegExp {
         Self::regexp_has_flag(this, b'u', context)
     }
 
+    /// `get RegExp.prototype.unicodeSets`
+    ///
+    /// The `unicodeSets` property indicates whether or not the "`v`" flag is used with a regular expression.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-regexp.prototype.unicodesets
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/unicodeSets
+    pub(crate) fn get_unicode_sets(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        Self::regexp_has_flag(this, b'v', context)
+    }
+
     /// `get RegExp.prototype.sticky`
     ///
     /// This flag indicates that it matches only from the index indicated by the `lastIndex` property
@@ -495,43 +593,56 @@ impl RegExp {
         // 1. Let R be the this value.
         // 2. If Type(R) is not Object, throw a TypeError exception.
         if let Some(object) = this.as_object() {
-            // 3. Let result be the empty String.
+            // 3. Let codeUnits be a new empty List.
             let mut result = String::new();
-            // 4. Let global be ! ToBoolean(? Get(R, "global")).
-            // 5. If global is true, append the code unit 0x0067 (LATIN SMALL LETTER G) as the last code unit of result.
+
+            // 4. Let hasIndices be ToBoolean(? Get(R, "hasIndices")).
+            // 5. If hasIndices is true, append the code unit 0x0064 (LATIN SMALL LETTER D) to codeUnits.
+            if object.get("hasIndices", context)?.to_boolean() {
+                result.push('d');
+            }
+
+            // 6. Let global be ! ToBoolean(? Get(R, "global")).
+            // 7. If global is true, append the code unit 0x0067 (LATIN SMALL LETTER G) as the last code unit of result.
             if object.get("global", context)?.to_boolean() {
                 result.push('g');
             }
-            // 6. Let ignoreCase be ! ToBoolean(? Get(R, "ignoreCase")).
-            // 7. If ignoreCase is true, append the code unit 0x0069 (LATIN SMALL LETTER I) as the last code unit of result.
+            // 8. Let ignoreCase be ! ToBoolean(? Get(R, "ignoreCase")).
+            // 9. If ignoreCase is true, append the code unit 0x0069 (LATIN SMALL LETTER I) as the last code unit of result.
             if object.get("ignoreCase", context)?.to_boolean() {
                 result.push('i');
             }
 
-            // 8. Let multiline be ! ToBoolean(? Get(R, "multiline")).
-            // 9. If multiline is true, append the code unit 0x006D (LATIN SMALL LETTER M) as the last code unit of result.
+            // 10. Let multiline be ! ToBoolean(? Get(R, "multiline")).
+            // 11. If multiline is true, append the code unit 0x006D (LATIN SMALL LETTER M) as the last code unit of result.
             if object.get("multiline", context)?.to_boolean() {
                 result.push('m');
             }
 
-            // 10. Let dotAll be ! ToBoolean(? Get(R, "dotAll")).
-            // 11. If dotAll is true, append the code unit 0x0073 (LATIN SMALL LETTER S) as the last code unit of result.
+            // 12. Let dotAll be ! ToBoolean(? Get(R, "dotAll")).
+            // 13. If dotAll is true, append the code unit 0x0073 (LATIN SMALL LETTER S) as the last code unit of result.
             if object.get("dotAll", context)?.to_boolean() {
                 result.push('s');
             }
-            // 12. Let unicode be ! ToBoolean(? Get(R, "unicode")).
-            // 13. If unicode is true, append the code unit 0x0075 (LATIN SMALL LETTER U) as the last code unit of result.
+            // 14. Let unicode be ! ToBoolean(? Get(R, "unicode")).
+            // 15. If unicode is true, append the code unit 0x0075 (LATIN SMALL LETTER U) as the last code unit of result.
             if object.get("unicode", context)?.to_boolean() {
                 result.push('u');
             }
 
-            // 14. Let sticky be ! ToBoolean(? Get(R, "sticky")).
-            // 15. If sticky is true, append the code unit 0x0079 (LATIN SMALL LETTER Y) as the last code unit of result.
+            // 16. Let unicodeSets be ToBoolean(? Get(R, "unicodeSets")).
+            // 17. If unicodeSets is true, append the code unit 0x0076 (LATIN SMALL LETTER V) to codeUnits.
+            if object.get("unicodeSets", context)?.to_boolean() {
+                result.push('v');
+            }
+
+            // 18. Let sticky be ! ToBoolean(? Get(R, "sticky")).
+            // 19. If sticky is true, append the code unit 0x0079 (LATIN SMALL LETTER Y) as the last code unit of result.
             if object.get("sticky", context)?.to_boolean() {
                 result.push('y');
             }
 
-            // 16. Return result.
+            // 20. Return the String value whose code units are the elements of the List codeUnits.
             return Ok(result.into());
         }
 
@@ -695,6 +806,73 @@ impl RegExp {
         }
     }
 
+    /// Collects the functions currently installed on `RegExp.prototype` that are observed by
+    /// `RegExpExec` and by the `String.prototype` methods dispatching through well-known symbols.
+    ///
+    /// This is called once the builtins are initialized, and the result is later used by
+    /// [`RegExp::is_unmodified`] to check whether the prototype is still pristine.
+    pub(crate) fn prototype_snapshot(context: &Context) -> Vec<(PropertyKey, JsObject)> {
+        let prototype = context.intrinsics().constructors().regexp().prototype();
+        let prototype = prototype.borrow();
+
+        [
+            "exec".into(),
+            "flags".into(),
+            "hasIndices".into(),
+            "global".into(),
+            "ignoreCase".into(),
+            "multiline".into(),
+            "dotAll".into(),
+            "unicode".into(),
+            "unicodeSets".into(),
+            "sticky".into(),
+            WellKnownSymbols::r#match().into(),
+            WellKnownSymbols::match_all().into(),
+            WellKnownSymbols::replace().into(),
+            WellKnownSymbols::search().into(),
+            WellKnownSymbols::split().into(),
+        ]
+        .into_iter()
+        .filter_map(|key: PropertyKey| {
+            let function = prototype_function(prototype.properties().get(&key)?)?;
+            Some((key, function))
+        })
+        .collect()
+    }
+
+    /// Returns `true` if `object` is a `RegExp` object for which looking up `exec`, the flag
+    /// accessors and the well-known symbol methods would yield the built-in functions.
+    ///
+    /// In that case the lookups are unobservable, so callers can skip them and call the
+    /// built-in implementations directly.
+    pub(crate) fn is_unmodified(object: &JsObject, context: &Context) -> bool {
+        let object = object.borrow();
+        if !object.is_regexp() {
+            return false;
+        }
+
+        // The only own property of a regexp instance is `lastIndex`.
+        let last_index = PropertyKey::from("lastIndex");
+        if object.properties().keys().any(|key| key != last_index) {
+            return false;
+        }
+
+        let prototype = context.intrinsics().constructors().regexp().prototype();
+        if !matches!(object.prototype(), Some(proto) if JsObject::equals(proto, &prototype)) {
+            return false;
+        }
+
+        let prototype = prototype.borrow();
+        let snapshot = context.intrinsics().objects().regexp_prototype_snapshot();
+        snapshot.iter().all(|(key, function)| {
+            prototype
+                .properties()
+                .get(key)
+                .and_then(prototype_function)
+                .map_or(false, |current| JsObject::equals(&current, function))
+        })
+    }
+
     /// `22.2.5.2.1 RegExpExec ( R, S )`
     ///
     /// More information:
@@ -709,6 +887,11 @@ impl RegExp {
         // 1. Assert: Type(R) is Object.
         // 2. Assert: Type(S) is String.
 
+        // Fast path: if `R` is an unmodified regexp, `exec` is the built-in one.
+        if Self::is_unmodified(this, context) {
+            return Self::abstract_builtin_exec(this, &input, context);
+        }
+
         // 3. Let exec be ? Get(R, "exec").
         let exec = this.get("exec", context)?;
 
@@ -765,27 +948,30 @@ impl RegExp {
         let mut last_index = this.get("lastIndex", context)?.to_length(context)?;
 
         // 5. Let flags be R.[[OriginalFlags]].
-        let flags = &rx.original_flags;
+        let flags = rx.flags;
 
         // 6. If flags contains "g", let global be true; else let global be false.
-        let global = flags.contains('g');
+        let global = flags.contains(RegExpFlags::GLOBAL);
 
         // 7. If flags contains "y", let sticky be true; else let sticky be false.
-        let sticky = flags.contains('y');
+        let sticky = flags.contains(RegExpFlags::STICKY);
 
-        // 8. If global is false and sticky is false, set lastIndex to 0.
+        // 8. If flags contains "d", let hasIndices be true; else let hasIndices be false.
+        let has_indices = flags.contains(RegExpFlags::HAS_INDICES);
+
+        // 9. If global is false and sticky is false, set lastIndex to 0.
         if !global && !sticky {
             last_index = 0;
         }
 
-        // 9. Let matcher be R.[[RegExpMatcher]].
+        // 10. Let matcher be R.[[RegExpMatcher]].
         let matcher = &rx.matcher;
 
-        // 10. If flags contains "u", let fullUnicode be true; else let fullUnicode be false.
-        let unicode = flags.contains('u');
+        // 11. If flags contains "u" or flags contains "v", let fullUnicode be true; else let fullUnicode be false.
+        let unicode = flags.intersects(RegExpFlags::UNICODE | RegExpFlags::UNICODE_SETS);
 
-        // 11. Let matchSucceeded be false.
-        // 12. Repeat, while matchSucceeded is false,
+        // 12. Let matchSucceeded be false.
+        // 13. Repeat, while matchSucceeded is false,
         let match_value = loop {
             // a. If lastIndex > length, then
             if last_index > length {
@@ -831,7 +1017,7 @@ impl RegExp {
                 Some(m) => {
                     // c. If r is failure, then
                     #[allow(clippy::if_not_else)]
-                    if m.start() != last_index {
+                    if m.start() != last_byte_index {
                         // i. If sticky is true, then
                         if sticky {
                             // 1. Perform ? Set(R, "lastIndex", +0𝔽, true).
@@ -853,97 +1039,104 @@ impl RegExp {
             }
         };
 
-        // 13. Let e be r's endIndex value.
-        let mut e = match_value.end();
-
-        // 14. If fullUnicode is true, then
-        if unicode {
-            // e is an index into the Input character list, derived from S, matched by matcher.
-            // Let eUTF be the smallest index into S that corresponds to the character at element e of Input.
-            // If e is greater than or equal to the number of elements in Input, then eUTF is the number of code units in S.
-            // b. Set e to eUTF.
-            e = input.split_at(e).0.encode_utf16().count();
-        }
+        // 14. Let e be r's endIndex value.
+        // 15. If fullUnicode is true, set e to GetStringIndex(S, e).
+        // Regress matches on the UTF-8 representation of S, so every index is converted to a
+        // UTF-16 code unit index, regardless of fullUnicode.
+        let e = utf16_index(input, match_value.end());
 
-        // 15. If global is true or sticky is true, then
+        // 16. If global is true or sticky is true, then
         if global || sticky {
             // a. Perform ? Set(R, "lastIndex", 𝔽(e), true).
             this.set("lastIndex", e, true, context)?;
         }
 
-        // 16. Let n be the number of elements in r's captures List. (This is the same value as 22.2.2.1's NcapturingParens.)
+        // 17. Let n be the number of elements in r's captures List. (This is the same value as 22.2.2.1's NcapturingParens.)
         let n = match_value.captures.len();
-        // 17. Assert: n < 23^2 - 1.
+        // 18. Assert: n = R.[[RegExpRecord]].[[CapturingGroupsCount]].
+        // 19. Assert: n < 2^32 - 1.
         debug_assert!(n < 23usize.pow(2) - 1);
 
-        // 18. Let A be ! ArrayCreate(n + 1).
-        // 19. Assert: The mathematical value of A's "length" property is n + 1.
+        // 20. Let A be ! ArrayCreate(n + 1).
+        // 21. Assert: The mathematical value of A's "length" property is n + 1.
         let a = Array::array_create(n + 1, None, context)?;
 
-        // 20. Perform ! CreateDataPropertyOrThrow(A, "index", 𝔽(lastIndex)).
-        a.create_data_property_or_throw("index", match_value.start(), context)
+        // 22. Perform ! CreateDataPropertyOrThrow(A, "index", 𝔽(lastIndex)).
+        a.create_data_property_or_throw("index", utf16_index(input, match_value.start()), context)
             .expect("this CreateDataPropertyOrThrow call must not fail");
 
-        // 21. Perform ! CreateDataPropertyOrThrow(A, "input", S).
+        // 23. Perform ! CreateDataPropertyOrThrow(A, "input", S).
         a.create_data_property_or_throw("input", input.clone(), context)
             .expect("this CreateDataPropertyOrThrow call must not fail");
 
-        // 22. Let matchedSubstr be the substring of S from lastIndex to e.
+        // 24. Let match be the Match Record { [[StartIndex]]: lastIndex, [[EndIndex]]: e }.
+        // 25. Let indices be a new empty List.
+        // 26. Let groupNames be a new empty List.
+        // 27. Append match to indices.
+        let mut indices = vec![Some(match_value.range())];
+
+        // 28. Let matchedSubstr be GetMatchString(S, match).
         let matched_substr = if let Some(s) = input.get(match_value.range()) {
             s
         } else {
             ""
         };
 
-        // 23. Perform ! CreateDataPropertyOrThrow(A, "0", matchedSubstr).
+        // 29. Perform ! CreateDataPropertyOrThrow(A, "0", matchedSubstr).
         a.create_data_property_or_throw(0, matched_substr, context)
             .expect("this CreateDataPropertyOrThrow call must not fail");
 
-        // 24. If R contains any GroupName, then
-        // 25. Else,
+        // 30. If R contains any GroupName, then
+        // 31. Else,
         let named_groups = match_value.named_groups();
-        let groups = if named_groups.clone().count() > 0 {
-            // a. Let groups be ! OrdinaryObjectCreate(null).
-            let groups = JsValue::from(JsObject::empty());
-
-            // Perform 27.f here
-            // f. If the ith capture of R was defined with a GroupName, then
-            // i. Let s be the CapturingGroupName of the corresponding RegExpIdentifierName.
-            // ii. Perform ! CreateDataPropertyOrThrow(groups, s, capturedValue).
-            for (name, range) in named_groups {
-                if let Some(range) = range {
-                    let value = if let Some(s) = input.get(range.clone()) {
-                        s
-                    } else {
-                        ""
-                    };
-
-                    groups
-                        .to_object(context)?
-                        .create_data_property_or_throw(name, value, context)
-                        .expect("this CreateDataPropertyOrThrow call must not fail");
-                }
+        let has_groups = named_groups.clone().count() > 0;
+        let groups = if has_groups {
+            // a. Let groups be OrdinaryObjectCreate(null).
+            let groups = JsObject::empty();
+
+            // Perform 33.e here
+            // e. If the ith capture of R was defined with a GroupName, then
+            //     i. Let s be the CapturingGroupName of that GroupName.
+            //     ii. Perform ! CreateDataPropertyOrThrow(groups, s, capturedValue).
+            //     iii. Append s to groupNames.
+            for (name, range) in named_groups.clone() {
+                let value = range
+                    .and_then(|range| input.get(range))
+                    .map_or_else(JsValue::undefined, JsValue::new);
+
+                groups
+                    .create_data_property_or_throw(name, value, context)
+                    .expect("this CreateDataPropertyOrThrow call must not fail");
             }
-            groups
+            groups.into()
         } else {
             // a. Let groups be undefined.
             JsValue::undefined()
         };
 
-        // 26. Perform ! CreateDataPropertyOrThrow(A, "groups", groups).
+        // 32. Perform ! CreateDataPropertyOrThrow(A, "groups", groups).
         a.create_data_property_or_throw("groups", groups, context)
             .expect("this CreateDataPropertyOrThrow call must not fail");
 
-        // 27. For each integer i such that i ≥ 1 and i ≤ n, in ascending order, do
+        // 33. For each integer i such that i ≥ 1 and i ≤ n, in ascending order, do
         for i in 1..=n {
             // a. Let captureI be ith element of r's captures List.
             let capture = match_value.group(i);
 
-            let captured_value = match capture {
-                // b. If captureI is undefined, let capturedValue be undefined.
+            let captured_value = match capture.clone() {
+                // b. If captureI is undefined, then
+                //     i. Let capturedValue be undefined.
+                //     ii. Append undefined to indices.
                 None => JsValue::undefined(),
-                // c. Else if fullUnicode is true, then
-                // d. Else,
+                // c. Else,
+                //     i. Let captureStart be captureI's startIndex.
+                //     ii. Let captureEnd be captureI's endIndex.
+                //     iii. If fullUnicode is true, then
+                //         1. Set captureStart to GetStringIndex(S, captureStart).
+                //         2. Set captureEnd to GetStringIndex(S, captureEnd).
+                //     iv. Let capture be the Match Record { [[StartIndex]]: captureStart, [[EndIndex]]: captureEnd }.
+                //     v. Let capturedValue be GetMatchString(S, capture).
+                //     vi. Append capture to indices.
                 Some(range) => {
                     if let Some(s) = input.get(range) {
                         s.into()
@@ -952,13 +1145,29 @@ impl RegExp {
                     }
                 }
             };
+            indices.push(capture);
 
-            // e. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(i)), capturedValue).
+            // d. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(i)), capturedValue).
             a.create_data_property_or_throw(i, captured_value, context)
                 .expect("this CreateDataPropertyOrThrow call must not fail");
         }
 
-        // 28. Return A.
+        // 34. If hasIndices is true, then
+        if has_indices {
+            // a. Let indicesArray be MakeMatchIndicesIndexPairArray(S, indices, groupNames, hasGroups).
+            let indices_array = make_match_indices_index_pair_array(
+                input,
+                &indices,
+                has_groups.then(|| named_groups),
+                context,
+            );
+
+            // b. Perform ! CreateDataPropertyOrThrow(A, "indices", indicesArray).
+            a.create_data_property_or_throw("indices", indices_array, context)
+                .expect("this CreateDataPropertyOrThrow call must not fail");
+        }
+
+        // 35. Return A.
         Ok(Some(a))
     }
 
@@ -993,12 +1202,12 @@ impl RegExp {
             .unwrap_or_default()
             .to_string(context)?;
 
-        // 4. Let global be ! ToBoolean(? Get(rx, "global")).
-        let global = rx.get("global", context)?.to_boolean();
+        // 4. Let flags be ? ToString(? Get(rx, "flags")).
+        let flags = rx.get("flags", context)?.to_string(context)?;
 
-        // 5. If global is false, then
+        // 5. If flags does not contain "g", then
         #[allow(clippy::if_not_else)]
-        if !global {
+        if !flags.contains('g') {
             // a. Return ? RegExpExec(rx, S).
             if let Some(v) = Self::abstract_exec(rx, arg_str, context)? {
                 Ok(v.into())
@@ -1007,59 +1216,58 @@ impl RegExp {
             }
         // 6. Else,
         } else {
-            // a. Assert: global is true.
-
-            // b. Let fullUnicode be ! ToBoolean(? Get(rx, "unicode")).
-            let unicode = rx.get("unicode", context)?.to_boolean();
+            // a. If flags contains "u" or flags contains "v", let fullUnicode be true. Otherwise, let fullUnicode be false.
+            let unicode = flags.contains('u') || flags.contains('v');
 
-            // c. Perform ? Set(rx, "lastIndex", +0𝔽, true).
+            // b. Perform ? Set(rx, "lastIndex", +0𝔽, true).
             rx.set("lastIndex", 0, true, context)?;
 
             // d. Let A be ! ArrayCreate(0).
-            let a =
-                Array::array_create(0, None, context).expect("this ArrayCreate call must not fail");
-
             // e. Let n be 0.
-            let mut n = 0;
-
-            // f. Repeat,
-            loop {
-                // i. Let result be ? RegExpExec(rx, S).
-                let result = Self::abstract_exec(rx, arg_str.clone(), context)?;
-
-                // ii. If result is null, then
-                // iii. Else,
-                if let Some(result) = result {
-                    // 1. Let matchStr be ? ToString(? Get(result, "0")).
-                    let match_str = result.get("0", context)?.to_string(context)?;
-
-                    // 2. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(n)), matchStr).
-                    a.create_data_property_or_throw(n, match_str.clone(), context)
-                        .expect("this CreateDataPropertyOrThrow call must not fail");
-
-                    // 3. If matchStr is the empty String, then
-                    if match_str.is_empty() {
-                        // a. Let thisIndex be ℝ(? ToLength(? Get(rx, "lastIndex"))).
-                        let this_index = rx.get("lastIndex", context)?.to_length(context)?;
-
-                        // b. Let nextIndex be AdvanceStringIndex(S, thisIndex, fullUnicode).
-                        let next_index = advance_string_index(&arg_str, this_index, unicode);
-
-                        // c. Perform ? Set(rx, "lastIndex", 𝔽(nextIndex), true).
-                        rx.set("lastIndex", JsValue::new(next_index), true, context)?;
-                    }
+            //
+            // The matches are kept in a handle scope, and A is only created once they are all
+            // found, since it isn't observable before being returned.
+            context.with_handle_scope(|matches, context| {
+                let mut n = 0;
+
+                // f. Repeat,
+                loop {
+                    // i. Let result be ? RegExpExec(rx, S).
+                    let result = Self::abstract_exec(rx, arg_str.clone(), context)?;
+
+                    // ii. If result is null, then
+                    // iii. Else,
+                    if let Some(result) = result {
+                        // 1. Let matchStr be ? ToString(? Get(result, "0")).
+                        let match_str = result.get("0", context)?.to_string(context)?;
+
+                        // 2. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(n)), matchStr).
+                        matches.push(match_str.clone().into(), context);
+
+                        // 3. If matchStr is the empty String, then
+                        if match_str.is_empty() {
+                            // a. Let thisIndex be ℝ(? ToLength(? Get(rx, "lastIndex"))).
+                            let this_index = rx.get("lastIndex", context)?.to_length(context)?;
+
+                            // b. Let nextIndex be AdvanceStringIndex(S, thisIndex, fullUnicode).
+                            let next_index = advance_string_index(&arg_str, this_index, unicode);
+
+                            // c. Perform ? Set(rx, "lastIndex", 𝔽(nextIndex), true).
+                            rx.set("lastIndex", JsValue::new(next_index), true, context)?;
+                        }
 
-                    // 4. Set n to n + 1.
-                    n += 1;
-                } else {
-                    // 1. If n = 0, return null.
-                    if n == 0 {
-                        return Ok(JsValue::null());
+                        // 4. Set n to n + 1.
+                        n += 1;
+                    } else {
+                        // 1. If n = 0, return null.
+                        if n == 0 {
+                            return Ok(JsValue::null());
+                        }
+                        // 2. Return A.
+                        return Ok(matches.take_array(context).into());
                     }
-                    // 2. Return A.
-                    return Ok(a.into());
                 }
-            }
+            })
         }
     }
 
@@ -1149,9 +1357,9 @@ impl RegExp {
         // 10. Else, let global be false.
         let global = flags.contains('g');
 
-        // 11. If flags contains "u", let fullUnicode be true.
+        // 11. If flags contains "u" or flags contains "v", let fullUnicode be true.
         // 12. Else, let fullUnicode be false.
-        let unicode = flags.contains('u');
+        let unicode = flags.contains('u') || flags.contains('v');
 
         // 13. Return ! CreateRegExpStringIterator(matcher, S, global, fullUnicode).
         Ok(RegExpStringIterator::create_regexp_string_iterator(
@@ -1213,14 +1421,17 @@ impl RegExp {
             replace_value = replace_value.to_string(context)?.into();
         }
 
-        // 7. Let global be ! ToBoolean(? Get(rx, "global")).
-        let global = rx.get("global", context)?.to_boolean();
+        // 7. Let flags be ? ToString(? Get(rx, "flags")).
+        let flags = rx.get("flags", context)?.to_string(context)?;
+
+        // 8. If flags contains "g", let global be true. Otherwise, let global be false.
+        let global = flags.contains('g');
 
-        // 8. If global is true, then
+        // 9. If global is true, then
         let mut unicode = false;
         if global {
-            // a. Let fullUnicode be ! ToBoolean(? Get(rx, "unicode")).
-            unicode = rx.get("unicode", context)?.to_boolean();
+            // a. If flags contains "u" or flags contains "v", let fullUnicode be true. Otherwise, let fullUnicode be false.
+            unicode = flags.contains('u') || flags.contains('v');
 
             // b. Perform ? Set(rx, "lastIndex", +0𝔽, true).
             rx.set("lastIndex", 0, true, context)?;
@@ -1508,9 +1719,9 @@ impl RegExp {
         // 5. Let flags be ? ToString(? Get(rx, "flags")).
         let flags = rx.get("flags", context)?.to_string(context)?;
 
-        // 6. If flags contains "u", let unicodeMatching be true.
+        // 6. If flags contains "u" or flags contains "v", let unicodeMatching be true.
         // 7. Else, let unicodeMatching be false.
-        let unicode = flags.contains('u');
+        let unicode = flags.contains('u') || flags.contains('v');
 
         // 8. If flags contains "y", let newFlags be flags.
         // 9. Else, let newFlags be the string-concatenation of flags and "y".
@@ -1707,3 +1918,94 @@ fn advance_string_index(s: &JsString, index: usize, unicode: bool) -> usize {
 
     index + offset as usize
 }
+
+/// Converts a byte index into the UTF-8 representation of `input` to a UTF-16 code unit index.
+fn utf16_index(input: &str, byte_index: usize) -> usize {
+    input[..byte_index].encode_utf16().count()
+}
+
+/// `MakeMatchIndicesIndexPairArray ( S, indices, groupNames, hasGroups )`
+///
+/// `indices` contains the byte ranges of the whole match and of every capture, and `groups` the
+/// named groups of the match, if the pattern has any.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-makematchindicesindexpairarray
+fn make_match_indices_index_pair_array(
+    input: &str,
+    indices: &[Option<Range<usize>>],
+    groups: Option<NamedGroups<'_>>,
+    context: &mut Context,
+) -> JsObject {
+    // 1. Let n be the number of elements in indices.
+    // 2. Assert: n < 2^32 - 1.
+    // 3. Assert: groupNames has n - 1 elements.
+    // 4. NOTE: The groupNames List contains elements aligned with the indices List starting at indices[1].
+    // 5. Let A be ! ArrayCreate(n).
+    let a = Array::array_create(indices.len(), None, context)
+        .expect("this ArrayCreate call must not fail");
+
+    // GetMatchIndexPair ( S, match )
+    let index_pair = |range: &Option<Range<usize>>, context: &mut Context| -> JsValue {
+        range.as_ref().map_or_else(JsValue::undefined, |range| {
+            Array::create_array_from_list(
+                [
+                    utf16_index(input, range.start).into(),
+                    utf16_index(input, range.end).into(),
+                ],
+                context,
+            )
+            .into()
+        })
+    };
+
+    // 6. If hasGroups is true, then
+    //     a. Let groups be OrdinaryObjectCreate(null).
+    // 7. Else,
+    //     a. Let groups be undefined.
+    // 8. Perform ! CreateDataPropertyOrThrow(A, "groups", groups).
+    let groups = groups.map_or_else(JsValue::undefined, |groups| {
+        let object = JsObject::empty();
+        for (name, range) in groups {
+            let pair = index_pair(&range, context);
+            object
+                .create_data_property_or_throw(name, pair, context)
+                .expect("this CreateDataPropertyOrThrow call must not fail");
+        }
+        object.into()
+    });
+    a.create_data_property_or_throw("groups", groups, context)
+        .expect("this CreateDataPropertyOrThrow call must not fail");
+
+    // 9. For each integer i such that 0 ≤ i < n, in ascending order, do
+    for (i, range) in indices.iter().enumerate() {
+        // a. Let matchIndices be indices[i].
+        // b. If matchIndices is not undefined, then
+        //     i. Let matchIndexPair be GetMatchIndexPair(S, matchIndices).
+        // c. Else,
+        //     i. Let matchIndexPair be undefined.
+        let pair = index_pair(range, context);
+
+        // d. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(i)), matchIndexPair).
+        // e. If i > 0 and groupNames[i - 1] is not undefined, then
+        //     i. Assert: groups is not undefined.
+        //     ii. Perform ! CreateDataPropertyOrThrow(groups, groupNames[i - 1], matchIndexPair).
+        a.create_data_property_or_throw(i, pair, context)
+            .expect("this CreateDataPropertyOrThrow call must not fail");
+    }
+
+    // 10. Return A.
+    a
+}
+
+/// Returns the function stored in a `RegExp.prototype` property: the value of a method, or the
+/// getter of an accessor.
+fn prototype_function(descriptor: &PropertyDescriptor) -> Option<JsObject> {
+    descriptor
+        .value()
+        .or_else(|| descriptor.get())
+        .and_then(JsValue::as_object)
+        .cloned()
+}
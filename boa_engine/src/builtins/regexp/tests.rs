@@ -237,3 +237,176 @@ fn search() {
     assert_eq!(forward(&mut context, "/u/[Symbol.search](null)"), "1");
     assert_eq!(forward(&mut context, "/d/[Symbol.search](undefined)"), "2");
 }
+
+#[test]
+fn modified_regexp_is_observed() {
+    let mut context = Context::default();
+
+    // Unmodified regexps behave as before.
+    assert_eq!(forward(&mut context, "'abc'.replace(/b/, 'x')"), "\"axc\"");
+    assert_eq!(forward(&mut context, "'abc'.search(/c/)"), "2");
+
+    // An own property shadowing a symbol method is honoured.
+    let init = r#"
+        var re = /b/;
+        re[Symbol.replace] = function () { return "own"; };
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "'abc'.replace(re, 'x')"), "\"own\"");
+
+    // So is a user-defined `exec` on the prototype.
+    let init = r#"
+        var exec = RegExp.prototype.exec;
+        RegExp.prototype.exec = function () { return null; };
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "'abc'.search(/c/)"), "-1");
+    forward(&mut context, "RegExp.prototype.exec = exec");
+    assert_eq!(forward(&mut context, "'abc'.search(/c/)"), "2");
+
+    // And a replaced symbol method on the prototype.
+    forward(
+        &mut context,
+        "RegExp.prototype[Symbol.split] = function () { return 'split'; }",
+    );
+    assert_eq!(forward(&mut context, "'a,b'.split(/,/)"), "\"split\"");
+}
+
+#[test]
+fn lookbehind_and_property_escapes() {
+    let mut context = Context::default();
+
+    assert_eq!(
+        forward(&mut context, r#""$10 €20".match(/(?<=\$)\d+/)[0]"#),
+        "\"10\""
+    );
+    assert_eq!(
+        forward(&mut context, r#""$10 €20".match(/(?<!\$)\b\d+/)[0]"#),
+        "\"20\""
+    );
+    assert_eq!(
+        forward(&mut context, r#""abc αβγ".match(/\p{Script=Greek}+/u)[0]"#),
+        "\"αβγ\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"/\p{Lu}/u.test("a") || /\p{Lu}/u.test("A")"#
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn has_indices() {
+    let mut context = Context::default();
+
+    let init = r#"
+        var re = /é(?<word>b+)(x)?/d;
+        var result = re.exec("aébbc");
+        "#;
+    eprintln!("{}", forward(&mut context, init));
+
+    assert_eq!(forward(&mut context, "re.hasIndices"), "true");
+    assert_eq!(forward(&mut context, "re.flags"), "\"d\"");
+    assert_eq!(forward(&mut context, "result.index"), "1");
+    assert_eq!(forward(&mut context, "result.indices[0].join()"), "\"1,4\"");
+    assert_eq!(forward(&mut context, "result.indices[1].join()"), "\"2,4\"");
+    assert_eq!(forward(&mut context, "result.indices[2]"), "undefined");
+    assert_eq!(
+        forward(&mut context, "result.indices.groups.word.join()"),
+        "\"2,4\""
+    );
+    assert_eq!(forward(&mut context, "/a/.exec('a').indices"), "undefined");
+}
+
+#[test]
+fn unicode_sets() {
+    let mut context = Context::default();
+
+    assert_eq!(forward(&mut context, "/a/v.unicodeSets"), "true");
+    assert_eq!(forward(&mut context, "/a/v.unicode"), "false");
+    assert_eq!(
+        forward(&mut context, "new RegExp('a', 'dgv').flags"),
+        "\"dgv\""
+    );
+    assert_eq!(
+        forward(&mut context, r#"/[\p{L}--[a-z]]/v.test("a")"#),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, r#"/[\p{L}--[a-z]]/v.test("A")"#),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new RegExp('a', 'uv'); } catch (e) { e.name }"
+        ),
+        "\"SyntaxError\""
+    );
+}
+
+#[test]
+fn literal_in_loop() {
+    let mut context = Context::default();
+
+    let init = r#"
+        var results = [];
+        var objects = [];
+        for (var i = 0; i < 3; i++) {
+            var re = /a/g;
+            objects.push(re);
+            results.push(re.test("aa"), re.lastIndex);
+        }
+        "#;
+    eprintln!("{}", forward(&mut context, init));
+
+    assert_eq!(
+        forward(&mut context, "results.join()"),
+        "\"true,1,true,1,true,1\""
+    );
+    assert_eq!(forward(&mut context, "objects[0] !== objects[1]"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "'a-b-c'.split('-').length + 'abc'.match('b').index"
+        ),
+        "4"
+    );
+}
+
+#[test]
+fn match_global() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(&mut context, "'a1b22c333'.match(/\\d+/g).join()"),
+        "\"1,22,333\""
+    );
+    assert_eq!(forward(&mut context, "'abc'.match(/\\d/g)"), "null");
+    assert_eq!(forward(&mut context, "'abc'.match(/x*/g).length"), "4");
+
+    let init = r#"
+        var re = /a/g;
+        var calls = 0;
+        re.exec = function (s) {
+            calls++;
+            if (calls === 3) {
+                throw new Error("exec");
+            }
+            return RegExp.prototype.exec.call(this, s);
+        };
+        var caught;
+        try {
+            'aaaa'.match(re);
+        } catch (e) {
+            caught = e.message;
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "caught"), "\"exec\"");
+    assert_eq!(
+        forward(&mut context, "'a-b-a'.match(/a/g).join()"),
+        "\"a,a\""
+    );
+}
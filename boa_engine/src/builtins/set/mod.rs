@@ -418,7 +418,7 @@ impl Set {
     }
 
     /// Helper function to get the size of the set.
-    fn get_size(set: &JsValue, context: &mut Context) -> JsResult<usize> {
+    pub(crate) fn get_size(set: &JsValue, context: &mut Context) -> JsResult<usize> {
         set.as_object()
             .and_then(|obj| obj.borrow().as_set_ref().map(OrderedSet::size))
             .ok_or_else(|| context.construct_type_error("'this' is not a Set"))
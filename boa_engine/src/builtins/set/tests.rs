@@ -1,4 +1,4 @@
-use crate::{forward, Context};
+use crate::{forward, forward_val, object::JsSet, property::Attribute, Context, JsValue};
 
 #[test]
 fn construct_empty() {
@@ -246,3 +246,32 @@ fn not_a_function() {
         "\"TypeError: calling a builtin Set constructor without new is forbidden\""
     );
 }
+
+#[test]
+fn js_set() {
+    let mut context = Context::default();
+    let set = JsSet::new(&mut context);
+    set.add(1, &mut context).unwrap();
+    set.add("a", &mut context).unwrap();
+    set.add(1.0, &mut context).unwrap();
+    assert_eq!(set.size(&mut context).unwrap(), 2);
+    assert!(set.has("a", &mut context).unwrap());
+    assert!(!set.has(2, &mut context).unwrap());
+
+    let values = set.values(&mut context).unwrap();
+    assert_eq!(values.next(&mut context).unwrap(), Some(JsValue::new(1)));
+    assert_eq!(values.next(&mut context).unwrap(), Some(JsValue::new("a")));
+    assert_eq!(values.next(&mut context).unwrap(), None);
+
+    assert!(set.delete(1, &mut context).unwrap());
+    context.register_global_property("set", set.clone(), Attribute::all());
+    assert_eq!(forward(&mut context, "[...set].join()"), "\"a\"");
+    set.clear(&mut context).unwrap();
+    assert!(set.is_empty(&mut context).unwrap());
+
+    let array = forward_val(&mut context, "[1, 2, 2, 3]").unwrap();
+    let from_js = JsSet::from_js_iterable(&array, &mut context).unwrap();
+    assert_eq!(from_js.size(&mut context).unwrap(), 3);
+    let not_a_set = context.construct_object();
+    assert!(JsSet::from_object(not_a_set, &mut context).is_err());
+}
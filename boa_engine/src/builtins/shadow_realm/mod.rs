@@ -0,0 +1,433 @@
+//! This module implements the global `ShadowRealm` object.
+//!
+//! A `ShadowRealm` evaluates code in a new realm of the context, with its own global object and
+//! intrinsics. Only primitives and callables cross the boundary between the realms: functions
+//! are wrapped in functions of the other realm, which wrap their arguments and their result in
+//! turn, and any other object throws a `TypeError`.
+//!
+//! Functions don't record the realm they were created in, so a wrapped function calls its target
+//! in the realm the target was wrapped from.
+//!
+//! More information:
+//!  - [ECMAScript proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-shadowrealm/
+
+use crate::{
+    builtins::{promise::PromiseCapability, BuiltIn, JsArgs},
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
+    },
+    property::{Attribute, PropertyDescriptor},
+    realm::JsRealm,
+    symbol::WellKnownSymbols,
+    value::IntegerOrInfinity,
+    Context, JsResult, JsString, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use tap::{Conv, Pipe};
+
+#[cfg(test)]
+mod tests;
+
+/// The `ShadowRealm` builtin object.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ShadowRealm;
+
+impl BuiltIn for ShadowRealm {
+    const NAME: &'static str = "ShadowRealm";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().shadow_realm().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .method(Self::evaluate, "evaluate", 1)
+        .method(Self::import_value, "importValue", 2)
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+impl ShadowRealm {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `ShadowRealm ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin ShadowRealm constructor without new is forbidden",
+            );
+        }
+
+        // 2. Let O be ? OrdinaryCreateFromConstructor(NewTarget, "%ShadowRealm.prototype%", « [[ShadowRealm]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::shadow_realm,
+            context,
+        )?;
+
+        // 3. Let realmRec be CreateRealm().
+        // 4. Set O.[[ShadowRealm]] to realmRec.
+        // 5. Let context be a new execution context.
+        // ...
+        // 11. Perform ? SetDefaultGlobalBindings(O.[[ShadowRealm]]).
+        // 12. Perform ? HostInitializeShadowRealm(O.[[ShadowRealm]]).
+        let realm = context.create_realm();
+
+        // 13. Return O.
+        Ok(JsObject::from_proto_and_data(prototype, ObjectData::shadow_realm(realm)).into())
+    }
+
+    /// `ShadowRealm.prototype.evaluate ( sourceText )`
+    ///
+    /// Evaluates a script in the realm, and returns its completion value wrapped for the caller.
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm.prototype.evaluate
+    pub(crate) fn evaluate(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateShadowRealmObject(O).
+        let eval_realm = Self::this_realm(this, context)?;
+
+        // 3. If Type(sourceText) is not String, throw a TypeError exception.
+        let source = match args.get_or_undefined(0) {
+            JsValue::String(source) => source.clone(),
+            _ => {
+                return context.throw_type_error(
+                    "ShadowRealm.prototype.evaluate: source text must be a string",
+                )
+            }
+        };
+
+        // 4. Let callerRealm be the current Realm Record.
+        let caller_realm = context.current_realm();
+
+        // 5. Let evalRealm be O.[[ShadowRealm]].
+        // 6. Return ? PerformShadowRealmEval(sourceText, callerRealm, evalRealm).
+        perform_shadow_realm_eval(&source, &caller_realm, &eval_realm, context)
+    }
+
+    /// `ShadowRealm.prototype.importValue ( specifier, exportName )`
+    ///
+    /// The parser doesn't support modules yet, so the returned promise is always rejected with a
+    /// `TypeError`, like the promise of an import that failed.
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm.prototype.importvalue
+    pub(crate) fn import_value(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateShadowRealmObject(O).
+        Self::this_realm(this, context)?;
+
+        // 3. Let specifierString be ? ToString(specifier).
+        let specifier = args.get_or_undefined(0).to_string(context)?;
+
+        // 4. If Type(exportName) is not String, throw a TypeError exception.
+        if !args.get_or_undefined(1).is_string() {
+            return context.throw_type_error(
+                "ShadowRealm.prototype.importValue: export name must be a string",
+            );
+        }
+
+        // 5. Let callerRealm be the current Realm Record.
+        // 6. Let evalRealm be O.[[ShadowRealm]].
+        // 7. Return ShadowRealmImportValue(specifierString, exportName, callerRealm, evalRealm).
+        //     1. Let innerCapability be ! NewPromiseCapability(%Promise%).
+        let capability = PromiseCapability::new(
+            &context
+                .intrinsics()
+                .constructors()
+                .promise()
+                .constructor()
+                .into(),
+            context,
+        )
+        .expect("cannot fail with the %Promise% constructor");
+
+        //     ...
+        //     5. Perform HostLoadImportedModule(...), rejecting the promise with a TypeError if
+        //        the module can't be loaded.
+        let error = context.construct_type_error(format!(
+            "ShadowRealm.prototype.importValue: cannot import `{}`, modules are not supported",
+            specifier
+        ));
+        capability
+            .reject()
+            .call(&JsValue::undefined(), &[error], context)?;
+
+        Ok(capability.promise().clone().into())
+    }
+
+    /// Abstract operation `ValidateShadowRealmObject ( O )`
+    ///
+    /// Returns the realm of a `ShadowRealm` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-validateshadowrealmobject
+    fn this_realm(this: &JsValue, context: &mut Context) -> JsResult<JsRealm> {
+        // 1. Perform ? RequireInternalSlot(O, [[ShadowRealm]]).
+        this.as_object()
+            .and_then(|obj| obj.borrow().as_shadow_realm().cloned())
+            .ok_or_else(|| context.construct_type_error("'this' is not a ShadowRealm"))
+    }
+}
+
+/// A wrapped function exotic object, which calls a function of another realm.
+///
+/// More information:
+///  - [ECMAScript proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrapped-function-exotic-objects
+#[derive(Debug, Trace, Finalize)]
+pub struct WrappedFunction {
+    target: JsObject,
+    target_realm: JsRealm,
+    realm: JsRealm,
+}
+
+impl WrappedFunction {
+    /// Returns the `[[WrappedTargetFunction]]` of the wrapped function.
+    #[inline]
+    pub fn target(&self) -> &JsObject {
+        &self.target
+    }
+
+    /// Returns the realm the target function is called in.
+    #[inline]
+    pub fn target_realm(&self) -> &JsRealm {
+        &self.target_realm
+    }
+
+    /// Returns the `[[Realm]]` of the wrapped function, where its arguments come from.
+    #[inline]
+    pub fn realm(&self) -> &JsRealm {
+        &self.realm
+    }
+}
+
+/// Abstract operation `PerformShadowRealmEval ( sourceText, callerRealm, evalRealm )`
+///
+/// More information:
+///  - [ECMAScript proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-performshadowrealmeval
+fn perform_shadow_realm_eval(
+    source: &JsString,
+    caller_realm: &JsRealm,
+    eval_realm: &JsRealm,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. Perform ? HostEnsureCanCompileStrings(evalRealm).
+    context.with_realm(eval_realm, Context::ensure_can_compile_strings)?;
+
+    // 2. Perform the following substeps in an implementation-defined order, possibly interleaving parsing and error detection:
+    //     a. Let script be ParseText(StringToCodePoints(sourceText), Script).
+    //     b. If script is a List of errors, throw a SyntaxError exception.
+    let statement_list = match context.parse(source.as_str()) {
+        Ok(statement_list) => statement_list,
+        Err(e) => return context.throw_syntax_error(e.to_string()),
+    };
+
+    // 3. Let ecmaCode be script.[[ECMAScriptCode]].
+    // ...
+    // 19. Let result be Completion(GlobalDeclarationInstantiation(ecmaCode, varEnv)).
+    // 20. If result.[[Type]] is normal, then
+    //     a. Set result to the result of evaluating body.
+    let result = context.with_realm(eval_realm, |context| {
+        let code_block = context.compile(&statement_list)?;
+        context.evaluate_script_code(code_block)
+    });
+
+    match result {
+        // 24. Return ? GetWrappedValue(callerRealm, result.[[Value]]).
+        Ok(value) => get_wrapped_value(&value, caller_realm, eval_realm, context),
+        // An uncatchable termination keeps unwinding the stack.
+        Err(error) if context.is_terminating() => Err(error),
+        // 23. If result is an abrupt completion, throw a TypeError exception.
+        Err(_) => {
+            context.throw_type_error("ShadowRealm.prototype.evaluate: the code threw an error")
+        }
+    }
+}
+
+/// Abstract operation `GetWrappedValue ( callerRealm, value )`
+///
+/// Wraps `value`, which comes from the realm `from`, to be used in the realm `into`.
+///
+/// More information:
+///  - [ECMAScript proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-getwrappedvalue
+pub(crate) fn get_wrapped_value(
+    value: &JsValue,
+    into: &JsRealm,
+    from: &JsRealm,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. If Type(value) is Object, then
+    if let Some(object) = value.as_object() {
+        // a. If IsCallable(value) is false, throw a TypeError exception.
+        if !object.is_callable() {
+            return context.throw_type_error("only primitives and callables can cross realms");
+        }
+
+        // b. Return ? WrappedFunctionCreate(callerRealm, value).
+        return wrapped_function_create(object, into, from, context).map(Into::into);
+    }
+
+    // 2. Return value.
+    Ok(value.clone())
+}
+
+/// Abstract operation `WrappedFunctionCreate ( callerRealm, Target )`
+///
+/// More information:
+///  - [ECMAScript proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrappedfunctioncreate
+fn wrapped_function_create(
+    target: &JsObject,
+    into: &JsRealm,
+    from: &JsRealm,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    context.with_realm(into, |context| {
+        // 1. Let internalSlotsList be the internal slots listed in Table 2, plus [[Prototype]] and [[Extensible]].
+        // 2. Let wrapped be MakeBasicObject(internalSlotsList).
+        // 3. Set wrapped.[[Prototype]] to callerRealm.[[Intrinsics]].[[%Function.prototype%]].
+        // 4. Set wrapped.[[Call]] as described in 2.1.
+        // 5. Set wrapped.[[WrappedTargetFunction]] to Target.
+        // 6. Set wrapped.[[Realm]] to callerRealm.
+        let prototype = context.intrinsics().constructors().function().prototype();
+        let wrapped = JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::wrapped_function(WrappedFunction {
+                target: target.clone(),
+                target_realm: from.clone(),
+                realm: into.clone(),
+            }),
+        );
+
+        // 7. Let result be CopyNameAndLength(wrapped, Target).
+        // 8. If result is an Abrupt Completion, throw a TypeError exception.
+        match copy_name_and_length(&wrapped, target, context) {
+            Ok(()) => {}
+            Err(error) if context.is_terminating() => return Err(error),
+            Err(_) => {
+                return context.throw_type_error("cannot copy the name and length of a function")
+            }
+        }
+
+        // 9. Return wrapped.
+        Ok(wrapped)
+    })
+}
+
+/// Abstract operation `CopyNameAndLength ( F, Target )`
+///
+/// More information:
+///  - [ECMAScript proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-copynameandlength
+fn copy_name_and_length(
+    function: &JsObject,
+    target: &JsObject,
+    context: &mut Context,
+) -> JsResult<()> {
+    // 1. If argCount is undefined, then set argCount to 0.
+    // 2. Let L be 0.
+    let mut length = JsValue::new(0);
+
+    // 3. Let targetHasLength be ? HasOwnProperty(Target, "length").
+    // 4. If targetHasLength is true, then
+    if target.has_own_property("length", context)? {
+        // a. Let targetLen be ? Get(Target, "length").
+        let target_len = target.get("length", context)?;
+        // b. If Type(targetLen) is Number, then
+        if target_len.is_number() {
+            match target_len
+                .to_integer_or_infinity(context)
+                .expect("to_integer_or_infinity cannot fail for a number")
+            {
+                // i. If targetLen is +∞𝔽, then set L to +∞.
+                IntegerOrInfinity::PositiveInfinity => length = f64::INFINITY.into(),
+                // ii. Else if targetLen is -∞𝔽, then set L to 0.
+                IntegerOrInfinity::NegativeInfinity => {}
+                // iii. Else,
+                //     1. Let targetLenAsInt be ! ToIntegerOrInfinity(targetLen).
+                //     2. Assert: targetLenAsInt is finite.
+                //     3. Set L to max(targetLenAsInt - argCount, 0).
+                IntegerOrInfinity::Integer(target_len) => length = target_len.max(0).into(),
+            }
+        }
+    }
+
+    // 5. Perform SetFunctionLength(F, L).
+    function.define_property_or_throw(
+        "length",
+        PropertyDescriptor::builder()
+            .value(length)
+            .writable(false)
+            .enumerable(false)
+            .configurable(true),
+        context,
+    )?;
+
+    // 6. Let targetName be ? Get(Target, "name").
+    // 7. If Type(targetName) is not String, set targetName to the empty String.
+    let target_name = target.get("name", context)?;
+    let target_name = target_name
+        .as_string()
+        .map_or_else(|| JsString::new(""), Clone::clone);
+
+    // 8. Perform SetFunctionName(F, targetName, prefix).
+    function.define_property_or_throw(
+        "name",
+        PropertyDescriptor::builder()
+            .value(target_name)
+            .writable(false)
+            .enumerable(false)
+            .configurable(true),
+        context,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use crate::{forward, Context};
+
+#[test]
+fn evaluate() {
+    let mut context = Context::default();
+    let init = r#"
+        var x = 'main';
+        var realm = new ShadowRealm();
+        realm.evaluate("var x = 'shadow';");
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "x"), "\"main\"");
+    assert_eq!(forward(&mut context, "realm.evaluate('x')"), "\"shadow\"");
+    assert_eq!(
+        forward(&mut context, "realm.evaluate('typeof ShadowRealm')"),
+        "\"function\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(realm)"),
+        "\"[object ShadowRealm]\""
+    );
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { realm.evaluate('({})'); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { realm.evaluate('throw 1'); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { realm.evaluate('('); } catch (e) { e.name }"
+        ),
+        "\"SyntaxError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { realm.evaluate(1); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { ShadowRealm(); } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn wrapped_functions() {
+    let mut context = Context::default();
+    let init = r#"
+        var realm = new ShadowRealm();
+        var add = realm.evaluate("(function add(a, b) { return a + b; })");
+        var apply = realm.evaluate("(f, value) => f(value) + '!'");
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "add(1, 2)"), "3");
+    assert_eq!(forward(&mut context, "add.name"), "\"add\"");
+    assert_eq!(forward(&mut context, "add.length"), "2");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(add) === Function.prototype"
+        ),
+        "true"
+    );
+
+    // Callables are wrapped in both directions.
+    assert_eq!(
+        forward(&mut context, "apply(x => x.toUpperCase(), 'hi')"),
+        "\"HI!\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { apply(x => ({}), 'hi'); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { add({}, 1); } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { new add(1, 2); } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn import_value() {
+    let mut context = Context::default();
+    let init = r#"
+        var realm = new ShadowRealm();
+        var error;
+        realm.importValue('./module.js', 'value').catch(e => { error = e; });
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "error instanceof TypeError"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { realm.importValue('./module.js', 1); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
@@ -18,10 +18,13 @@ use crate::{
     builtins::{string::string_iterator::StringIterator, Array, BuiltIn, Number, RegExp},
     context::intrinsics::StandardConstructors,
     object::{
-        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        IntegrityLevel, JsObject, ObjectData,
     },
     property::{Attribute, PropertyDescriptor},
+    string::JsStringBuilder,
     symbol::WellKnownSymbols,
+    syntax::{ast::Position, lexer::template::cook_template_string},
     value::IntegerOrInfinity,
     Context, JsResult, JsString, JsValue,
 };
@@ -32,7 +35,6 @@ use std::{
     string::String as StdString,
 };
 use tap::{Conv, Pipe};
-use unicode_normalization::UnicodeNormalization;
 
 #[derive(Clone, Copy, Eq, PartialEq)]
 pub(crate) enum Placement {
@@ -116,6 +118,7 @@ impl BuiltIn for String {
         .length(Self::LENGTH)
         .property("length", 0, attribute)
         .static_method(Self::raw, "raw", 1)
+        .static_method(Self::dedent, "dedent", 1)
         .static_method(Self::from_char_code, "fromCharCode", 1)
         .static_method(Self::from_code_point, "fromCodePoint", 1)
         .method(Self::char_at, "charAt", 1)
@@ -369,6 +372,92 @@ impl String {
         }
     }
 
+    /// `String.dedent ( templateOrFn, ...substitutions )`
+    ///
+    /// Removes the common leading indentation of a template literal, along with its opening and
+    /// closing lines. When called with a function it returns a new tag that forwards the
+    /// dedented template to that function.
+    ///
+    /// More information:
+    ///  - [Proposal specification][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-string-dedent/#sec-string.dedent
+    pub(crate) fn dedent(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If Type(templateOrFn) is not Object, then
+        //     a. Throw a TypeError exception.
+        let template_or_fn = args
+            .get_or_undefined(0)
+            .as_object()
+            .cloned()
+            .ok_or_else(|| {
+                context.construct_type_error(
+                    "String.dedent must be called with a template or a function",
+                )
+            })?;
+
+        // 2. If IsCallable(templateOrFn) is true, then
+        if template_or_fn.is_callable() {
+            // a. Let tag be templateOrFn.
+            // b. Let closure be a new Abstract Closure with parameters (template, ...substitutions)
+            //    that captures tag and performs the following steps when called:
+            // c. Return CreateBuiltinFunction(closure, 1, "", « »).
+            let function = FunctionBuilder::closure_with_captures(
+                context,
+                |this, args, tag, context| {
+                    // i. Let R be the this value.
+                    // ii. Let dedented be ? DedentTemplateStringsArray(template).
+                    let template = args.get_or_undefined(0).to_object(context)?;
+                    let dedented = dedent_template_strings_array(&template, context)?;
+
+                    // iii. Let args be the list-concatenation of « dedented » and substitutions.
+                    let mut forwarded = Vec::with_capacity(args.len().max(1));
+                    forwarded.push(dedented.into());
+                    forwarded.extend(args.iter().skip(1).cloned());
+
+                    // iv. Return ? Call(tag, R, args).
+                    tag.call(this, &forwarded, context)
+                },
+                template_or_fn,
+            )
+            .length(1)
+            .build();
+
+            return Ok(function.into());
+        }
+
+        // 3. Let template be templateOrFn.
+        // 4. Let dedented be ? DedentTemplateStringsArray(template).
+        let dedented = dedent_template_strings_array(&template_or_fn, context)?;
+
+        // 5. Return ? CookTemplateStringsArray(dedented, substitutions, cooked).
+        let substitutions = args.get(1..).unwrap_or_default();
+        let literal_segments = dedented.length_of_array_like(context)?;
+        let mut result = StdString::new();
+        for index in 0..literal_segments {
+            let segment = dedented
+                .get(index, context)?
+                .as_string()
+                .cloned()
+                .ok_or_else(|| {
+                    context
+                        .construct_type_error("String.dedent template contains an invalid escape")
+                })?;
+            result.push_str(&segment);
+
+            if index + 1 < literal_segments {
+                if let Some(substitution) = substitutions.get(index) {
+                    result.push_str(&substitution.to_string(context)?);
+                }
+            }
+        }
+
+        Ok(result.into())
+    }
+
     /// `String.fromCharCode(...codePoints)`
     ///
     /// Construct a `String` from one or more code points (as numbers).
@@ -972,6 +1061,15 @@ impl String {
 
         // 2. If searchValue is neither undefined nor null, then
         if !search_value.is_null_or_undefined() {
+            // Skip the lookup when it would find the built-in `RegExp.prototype[@@replace]`.
+            if is_unmodified_regexp(search_value, context) {
+                return RegExp::replace(
+                    search_value,
+                    &[this.clone(), replace_value.clone()],
+                    context,
+                );
+            }
+
             // a. Let replacer be ? GetMethod(searchValue, @@replace).
             let replacer = search_value.get_method(WellKnownSymbols::replace(), context)?;
 
@@ -1106,6 +1204,11 @@ impl String {
                 }
             }
 
+            // Skip the lookup when it would find the built-in `RegExp.prototype[@@replace]`.
+            if is_unmodified_regexp(search_value, context) {
+                return RegExp::replace(search_value, &[o.into(), replace_value.clone()], context);
+            }
+
             // c. Let replacer be ? GetMethod(searchValue, @@replace).
             let replacer = search_value.get_method(WellKnownSymbols::replace(), context)?;
 
@@ -1161,19 +1264,15 @@ impl String {
         let mut end_of_last_match = 0;
 
         // 13. Let result be the empty String.
-        let mut result = JsString::new("");
+        let mut result = JsStringBuilder::with_capacity(string.len());
 
         // 14. For each element p of matchPositions, do
         for p in match_positions {
             // a. Let preserved be the substring of string from endOfLastMatch to p.
-            let preserved = StdString::from_utf16_lossy(
-                &string
-                    .clone()
-                    .encode_utf16()
-                    .skip(end_of_last_match)
-                    .take(p - end_of_last_match)
-                    .collect::<Vec<u16>>(),
-            );
+            let preserved = string
+                .encode_utf16()
+                .skip(end_of_last_match)
+                .take(p - end_of_last_match);
 
             // c. Else,
             let replacement = if let Some(ref replace_value) = replace_value_string {
@@ -1208,7 +1307,8 @@ impl String {
             };
 
             // d. Set result to the string-concatenation of result, preserved, and replacement.
-            result = JsString::new(format!("{}{preserved}{replacement}", result.as_str()));
+            result.extend(preserved);
+            result.push_str(&replacement);
 
             // e. Set endOfLastMatch to p + searchLength.
             end_of_last_match = p + search_length;
@@ -1217,20 +1317,11 @@ impl String {
         // 15. If endOfLastMatch < the length of string, then
         if end_of_last_match < string.encode_utf16().count() {
             // a. Set result to the string-concatenation of result and the substring of string from endOfLastMatch.
-            result = JsString::new(format!(
-                "{}{}",
-                result.as_str(),
-                &StdString::from_utf16_lossy(
-                    &string
-                        .encode_utf16()
-                        .skip(end_of_last_match)
-                        .collect::<Vec<u16>>()
-                )
-            ));
+            result.extend(string.encode_utf16().skip(end_of_last_match));
         }
 
         // 16. Return result.
-        Ok(result.into())
+        Ok(result.build().into())
     }
 
     /// `String.prototype.indexOf( searchValue[, fromIndex] )`
@@ -1367,6 +1458,11 @@ impl String {
         // 2. If regexp is neither undefined nor null, then
         let regexp = args.get_or_undefined(0);
         if !regexp.is_null_or_undefined() {
+            // Skip the lookup when it would find the built-in `RegExp.prototype[@@match]`.
+            if is_unmodified_regexp(regexp, context) {
+                return RegExp::r#match(regexp, &[o.clone()], context);
+            }
+
             // a. Let matcher be ? GetMethod(regexp, @@match).
             let matcher = regexp.get_method(WellKnownSymbols::r#match(), context)?;
             // b. If matcher is not undefined, then
@@ -1430,35 +1526,24 @@ impl String {
 
         // 8. Let fillLen be intMaxLength - stringLength.
         let fill_len = int_max_length - string_length;
-        let filler_len = filler.encode_utf16().count();
 
         // 9. Let truncatedStringFiller be the String value consisting of repeated
         // concatenations of filler truncated to length fillLen.
-        let repetitions = {
-            let q = fill_len / filler_len;
-            let r = fill_len % filler_len;
-            if r == 0 {
-                q
-            } else {
-                q + 1
-            }
-        };
+        let truncated_string_filler = filler.encode_utf16().cycle().take(fill_len);
 
-        let truncated_string_filler = filler
-            .repeat(repetitions)
-            .encode_utf16()
-            .take(fill_len)
-            .collect::<Vec<_>>();
-        let truncated_string_filler =
-            std::string::String::from_utf16_lossy(truncated_string_filler.as_slice());
+        let mut result = JsStringBuilder::with_capacity(string.len() + fill_len);
 
         // 10. If placement is start, return the string-concatenation of truncatedStringFiller and S.
         if placement == Placement::Start {
-            Ok(format!("{truncated_string_filler}{string}").into())
+            result.extend(truncated_string_filler);
+            result.push_str(&string);
         } else {
             // 11. Else, return the string-concatenation of S and truncatedStringFiller.
-            Ok(format!("{string}{truncated_string_filler}").into())
+            result.push_str(&string);
+            result.extend(truncated_string_filler);
         }
+
+        Ok(result.build().into())
     }
 
     /// `String.prototype.padEnd( targetLength[, padString] )`
@@ -1793,6 +1878,11 @@ impl String {
 
         // 2. If separator is neither undefined nor null, then
         if !separator.is_null_or_undefined() {
+            // Skip the lookup when it would find the built-in `RegExp.prototype[@@split]`.
+            if is_unmodified_regexp(separator, context) {
+                return RegExp::split(separator, &[this.clone(), limit.clone()], context);
+            }
+
             // a. Let splitter be ? GetMethod(separator, @@split).
             let splitter = separator.get_method(WellKnownSymbols::split(), context)?;
             // b. If splitter is not undefined, then
@@ -1852,73 +1942,92 @@ impl String {
             return Ok(a.into());
         }
 
-        // 12. Let p be 0.
-        // 13. Let q be p.
-        let mut p = 0;
-        let mut q = p;
-
-        // 14. Repeat, while q ≠ s,
-        while q != this_str_length {
-            // a. Let e be SplitMatch(S, q, R).
-            let e = split_match(&this_str, q, &separator_str);
-
-            match e {
-                // b. If e is not-matched, set q to q + 1.
-                None => q += 1,
-                // c. Else,
-                Some(e) => {
-                    // i. Assert: e is a non-negative integer ≤ s.
-                    // ii. If e = p, set q to q + 1.
-                    // iii. Else,
-                    if e == p {
-                        q += 1;
-                    } else {
-                        // 1. Let T be the substring of S from p to q.
-                        let this_str_substring = StdString::from_utf16_lossy(
-                            &this_str
-                                .encode_utf16()
-                                .skip(p)
-                                .take(q - p)
-                                .collect::<Vec<u16>>(),
-                        );
+        // Fast path for separators consisting of a single code unit: since `S` never contains an
+        // unpaired surrogate, splitting on the separator character yields exactly the substrings
+        // of steps 12 to 16, computed in a single scan of `S`.
+        let mut separator_chars = separator_str.chars();
+        if let (Some(separator), None) = (separator_chars.next(), separator_chars.next()) {
+            if separator.len_utf16() == 1 {
+                let substrings = this_str
+                    .split(separator)
+                    .take(lim as usize)
+                    .map(JsValue::new);
+
+                return Ok(Array::create_array_from_list(substrings, context).into());
+            }
+        }
 
-                        // 2. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), T).
-                        a.create_data_property_or_throw(length_a, this_str_substring, context)
-                            .expect("this CreateDataPropertyOrThrow call must not fail");
+        // The substrings are kept in a handle scope, and appended to A at once when they are all
+        // found, since A isn't observable before being returned.
+        context.with_handle_scope(|substrings, context| {
+            // 12. Let p be 0.
+            // 13. Let q be p.
+            let mut p = 0;
+            let mut q = p;
+
+            // 14. Repeat, while q ≠ s,
+            while q != this_str_length {
+                // a. Let e be SplitMatch(S, q, R).
+                let e = split_match(&this_str, q, &separator_str);
+
+                match e {
+                    // b. If e is not-matched, set q to q + 1.
+                    None => q += 1,
+                    // c. Else,
+                    Some(e) => {
+                        // i. Assert: e is a non-negative integer ≤ s.
+                        // ii. If e = p, set q to q + 1.
+                        // iii. Else,
+                        if e == p {
+                            q += 1;
+                        } else {
+                            // 1. Let T be the substring of S from p to q.
+                            let this_str_substring = StdString::from_utf16_lossy(
+                                &this_str
+                                    .encode_utf16()
+                                    .skip(p)
+                                    .take(q - p)
+                                    .collect::<Vec<u16>>(),
+                            );
+
+                            // 2. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), T).
+                            substrings.push(this_str_substring.into(), context);
+
+                            // 3. Set lengthA to lengthA + 1.
+                            length_a += 1;
+
+                            // 4. If lengthA = lim, return A.
+                            if length_a == lim {
+                                substrings.append_to(&a, context);
+                                return Ok(a.into());
+                            }
 
-                        // 3. Set lengthA to lengthA + 1.
-                        length_a += 1;
+                            // 5. Set p to e.
+                            p = e;
 
-                        // 4. If lengthA = lim, return A.
-                        if length_a == lim {
-                            return Ok(a.into());
+                            // 6. Set q to p.
+                            q = p;
                         }
-
-                        // 5. Set p to e.
-                        p = e;
-
-                        // 6. Set q to p.
-                        q = p;
                     }
                 }
             }
-        }
 
-        // 15. Let T be the substring of S from p to s.
-        let this_str_substring = StdString::from_utf16_lossy(
-            &this_str
-                .encode_utf16()
-                .skip(p)
-                .take(this_str_length - p)
-                .collect::<Vec<u16>>(),
-        );
+            // 15. Let T be the substring of S from p to s.
+            let this_str_substring = StdString::from_utf16_lossy(
+                &this_str
+                    .encode_utf16()
+                    .skip(p)
+                    .take(this_str_length - p)
+                    .collect::<Vec<u16>>(),
+            );
 
-        // 16. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), T).
-        a.create_data_property_or_throw(length_a, this_str_substring, context)
-            .expect("this CreateDataPropertyOrThrow call must not fail");
+            // 16. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), T).
+            substrings.push(this_str_substring.into(), context);
+            substrings.append_to(&a, context);
 
-        // 17. Return A.
-        Ok(a.into())
+            // 17. Return A.
+            Ok(a.into())
+        })
     }
 
     /// String.prototype.valueOf()
@@ -1979,6 +2088,11 @@ impl String {
                     );
                 }
             }
+            // Skip the lookup when it would find the built-in `RegExp.prototype[@@matchAll]`.
+            if is_unmodified_regexp(regexp, context) {
+                return RegExp::match_all(regexp, &[o.clone()], context);
+            }
+
             // c. Let matcher be ? GetMethod(regexp, @@matchAll).
             let matcher = regexp.get_method(WellKnownSymbols::match_all(), context)?;
             // d. If matcher is not undefined, then
@@ -2020,30 +2134,27 @@ impl String {
 
         let form = args.get_or_undefined(0);
 
-        let f_str;
-
+        // 3. If form is undefined, let f be "NFC".
+        // 4. Else, let f be ? ToString(form).
         let f = if form.is_undefined() {
-            // 3. If form is undefined, let f be "NFC".
-            "NFC"
+            NormalizationForm::Nfc
         } else {
-            // 4. Else, let f be ? ToString(form).
-            f_str = form.to_string(context)?;
-            f_str.as_str()
+            // 5. If f is not one of "NFC", "NFD", "NFKC", or "NFKD", throw a RangeError exception.
+            NormalizationForm::from_name(&form.to_string(context)?).ok_or_else(|| {
+                context.construct_range_error(
+                    "The normalization form should be one of NFC, NFD, NFKC, NFKD.",
+                )
+            })?
         };
 
         // 6. Let ns be the String value that is the result of normalizing S
         // into the normalization form named by f as specified in
         // https://unicode.org/reports/tr15/.
         // 7. Return ns.
-        match f {
-            "NFC" => Ok(JsValue::new(s.nfc().collect::<StdString>())),
-            "NFD" => Ok(JsValue::new(s.nfd().collect::<StdString>())),
-            "NFKC" => Ok(JsValue::new(s.nfkc().collect::<StdString>())),
-            "NFKD" => Ok(JsValue::new(s.nfkd().collect::<StdString>())),
-            // 5. If f is not one of "NFC", "NFD", "NFKC", or "NFKD", throw a RangeError exception.
-            _ => context
-                .throw_range_error("The normalization form should be one of NFC, NFD, NFKC, NFKD."),
-        }
+        let mut result = JsStringBuilder::with_capacity(s.len());
+        f.normalize_into(&s, &mut result);
+
+        Ok(result.build().into())
     }
 
     /// `String.prototype.search( regexp )`
@@ -2067,6 +2178,11 @@ impl String {
         // 2. If regexp is neither undefined nor null, then
         let regexp = args.get_or_undefined(0);
         if !regexp.is_null_or_undefined() {
+            // Skip the lookup when it would find the built-in `RegExp.prototype[@@search]`.
+            if is_unmodified_regexp(regexp, context) {
+                return RegExp::search(regexp, &[o.clone()], context);
+            }
+
             // a. Let searcher be ? GetMethod(regexp, @@search).
             let searcher = regexp.get_method(WellKnownSymbols::search(), context)?;
             // b. If searcher is not undefined, then
@@ -2281,6 +2397,223 @@ pub(crate) fn get_substitution(
     Ok(result.into())
 }
 
+/// Abstract operation `DedentTemplateStringsArray ( template )`
+///
+/// Returns the frozen dedented template object for `template`, caching it in the realm's
+/// `[[DedentMap]]` so the same template always produces the same object.
+///
+/// More information:
+///  - [Proposal specification][spec]
+///
+/// [spec]: https://tc39.es/proposal-string-dedent/#sec-dedenttemplatestringsarray
+fn dedent_template_strings_array(template: &JsObject, context: &mut Context) -> JsResult<JsObject> {
+    // 1. Let realm be the current Realm Record.
+    // 2. Let dedentMap be realm.[[DedentMap]].
+    // 3. Let rawInput be ? Get(template, "raw").
+    // 4. If Type(rawInput) is not Object, throw a TypeError exception.
+    let raw_input = template
+        .get("raw", context)?
+        .as_object()
+        .cloned()
+        .ok_or_else(|| context.construct_type_error("template raw strings must be an object"))?;
+
+    // 5. For each element e of dedentMap, do
+    //     a. If e.[[Raw]] is not empty and SameValue(e.[[Raw]], rawInput) is true, return e.[[Dedented]].
+    if let Some((_, dedented)) = context
+        .realm
+        .dedent_map
+        .iter()
+        .find(|(raw, _)| JsObject::equals(raw, &raw_input))
+    {
+        return Ok(dedented.clone());
+    }
+
+    // 6. Let raw be ? DedentStringsArray(rawInput).
+    let raw = dedent_strings_array(&raw_input, context)?;
+
+    // 7. Let cookedArr be CreateArrayFromList(CookStrings(raw)).
+    let cooked = raw.iter().map(|raw| {
+        cook_template_string(raw, Position::new(1, 1))
+            .map_or_else(|_| JsValue::undefined(), JsValue::new)
+    });
+    let cooked_arr = Array::create_array_from_list(cooked, context);
+
+    // 8. Let rawArr be CreateArrayFromList(raw).
+    let raw_arr = Array::create_array_from_list(raw.into_iter().map(JsValue::new), context);
+
+    // 9. Perform ! DefinePropertyOrThrow(cookedArr, "raw", PropertyDescriptor { [[Value]]: rawArr,
+    //    [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }).
+    cooked_arr
+        .define_property_or_throw(
+            "raw",
+            PropertyDescriptor::builder()
+                .value(raw_arr.clone())
+                .writable(false)
+                .enumerable(false)
+                .configurable(false),
+            context,
+        )
+        .expect("defining `raw` on a new array must not fail");
+
+    // 10. Perform ! SetIntegrityLevel(rawArr, frozen).
+    raw_arr
+        .set_integrity_level(IntegrityLevel::Frozen, context)
+        .expect("freezing a new array must not fail");
+
+    // 11. Perform ! SetIntegrityLevel(cookedArr, frozen).
+    cooked_arr
+        .set_integrity_level(IntegrityLevel::Frozen, context)
+        .expect("freezing a new array must not fail");
+
+    // 12. Append the Record { [[Raw]]: rawInput, [[Dedented]]: cookedArr } to dedentMap.
+    context
+        .realm
+        .dedent_map
+        .push((raw_input, cooked_arr.clone()));
+
+    // 13. Return cookedArr.
+    Ok(cooked_arr)
+}
+
+/// Abstract operation `DedentStringsArray ( template )`
+///
+/// Removes the opening and closing lines of the raw strings of a template, and strips the common
+/// leading indentation (spaces and tabs) of every line. Lines consisting only of whitespace are
+/// emptied and do not take part in computing the common indentation.
+///
+/// More information:
+///  - [Proposal specification][spec]
+///
+/// [spec]: https://tc39.es/proposal-string-dedent/#sec-dedentstringsarray
+fn dedent_strings_array(template: &JsObject, context: &mut Context) -> JsResult<Vec<StdString>> {
+    fn is_indent(c: char) -> bool {
+        c == ' ' || c == '\t'
+    }
+
+    // 1. Let t be ? LengthOfArrayLike(template).
+    let t = template.length_of_array_like(context)?;
+
+    // 2. If t = 0, throw a TypeError exception.
+    if t == 0 {
+        return context.throw_type_error("String.dedent template must not be empty");
+    }
+
+    let mut strings = Vec::with_capacity(t);
+    for index in 0..t {
+        let string = template
+            .get(index, context)?
+            .as_string()
+            .cloned()
+            .ok_or_else(|| {
+                context.construct_type_error("String.dedent raw strings must be strings")
+            })?;
+        strings.push(string);
+    }
+
+    // 3. Let blocks be ? SplitTemplateIntoBlockLines(template, t).
+    //    Each line is a pair of its contents and the line terminator that follows it.
+    let blocks: Vec<Vec<(&str, &str)>> = strings.iter().map(|s| split_lines(s)).collect();
+
+    // 4. Perform ? RemoveOpeningAndClosingLines(blocks, t).
+    let first = &blocks[0];
+    if first.len() < 2 || !first[0].0.chars().all(is_indent) {
+        return context.throw_type_error("String.dedent template opening line must be empty");
+    }
+    let last = &blocks[t - 1];
+    if last.len() < 2 || !last[last.len() - 1].0.chars().all(is_indent) {
+        return context.throw_type_error("String.dedent template closing line must be empty");
+    }
+
+    // A line start is any line preceded by a line terminator, excluding the opening and closing
+    // lines. It is blank if it only contains whitespace and is not followed by a substitution.
+    let is_opening = |block: usize, line: usize| block == 0 && line == 0;
+    let is_closing = |block: usize, line: usize| block == t - 1 && line == blocks[t - 1].len() - 1;
+    let is_blank = |block: usize, line: usize| {
+        (line + 1 < blocks[block].len() || block == t - 1)
+            && blocks[block][line].0.chars().all(is_indent)
+    };
+
+    // 5. Let common be DetermineCommonLeadingIndentation(blocks).
+    let mut common: Option<&str> = None;
+    for (b, block) in blocks.iter().enumerate() {
+        for (l, (line, _)) in block.iter().enumerate().skip(1) {
+            if is_closing(b, l) || is_blank(b, l) {
+                continue;
+            }
+            let indent_len = line.find(|c| !is_indent(c)).unwrap_or(line.len());
+            let indent = &line[..indent_len];
+            common = Some(match common {
+                None => indent,
+                Some(common) => {
+                    let shared = common
+                        .bytes()
+                        .zip(indent.bytes())
+                        .take_while(|(a, b)| a == b)
+                        .count();
+                    &common[..shared]
+                }
+            });
+        }
+    }
+    let count = common.map_or(0, str::len);
+
+    // 6. Let dedented be a new empty List.
+    // 7. For each element lines of blocks, do
+    let mut dedented = Vec::with_capacity(t);
+    for (b, block) in blocks.iter().enumerate() {
+        let mut result = StdString::new();
+        for (l, (line, terminator)) in block.iter().enumerate() {
+            if is_opening(b, l) || is_closing(b, l) {
+                continue;
+            }
+
+            if l == 0 {
+                // The continuation of a line after a substitution is kept as is.
+                result.push_str(line);
+            } else if !is_blank(b, l) {
+                result.push_str(&line[count..]);
+            }
+
+            // The line terminator that precedes the closing line is removed with it.
+            if !is_closing(b, l + 1) {
+                result.push_str(terminator);
+            }
+        }
+        dedented.push(result);
+    }
+
+    // 8. Return dedented.
+    Ok(dedented)
+}
+
+/// Splits a string into its lines, pairing each line with the line terminator that follows it.
+///
+/// The last line is always paired with the empty string.
+fn split_lines(string: &str) -> Vec<(&str, &str)> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    let mut chars = string.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        let terminator_len = match c {
+            '\r' if chars.peek().map(|&(_, c)| c) == Some('\n') => {
+                chars.next();
+                2
+            }
+            '\n' | '\r' | '\u{2028}' | '\u{2029}' => c.len_utf8(),
+            _ => continue,
+        };
+        lines.push((
+            &string[start..index],
+            &string[index..index + terminator_len],
+        ));
+        start = index + terminator_len;
+    }
+    lines.push((&string[start..], ""));
+
+    lines
+}
+
 /// `22.1.3.21.1 SplitMatch ( S, q, R )`
 ///
 /// More information:
@@ -2316,6 +2649,56 @@ fn split_match(s_str: &str, q: usize, r_str: &str) -> Option<usize> {
     Some(q + r)
 }
 
+/// A Unicode normalization form, as accepted by `String.prototype.normalize`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NormalizationForm {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+impl NormalizationForm {
+    /// Gets the normalization form from its name, if it is one of "NFC", "NFD", "NFKC" or "NFKD".
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "NFC" => Some(Self::Nfc),
+            "NFD" => Some(Self::Nfd),
+            "NFKC" => Some(Self::Nfkc),
+            "NFKD" => Some(Self::Nfkd),
+            _ => None,
+        }
+    }
+
+    /// Normalizes `string`, streaming the resulting code points directly into `result`.
+    #[cfg(not(feature = "intl"))]
+    fn normalize_into(self, string: &str, result: &mut JsStringBuilder) {
+        use unicode_normalization::UnicodeNormalization;
+
+        match self {
+            Self::Nfc => result.extend(string.nfc()),
+            Self::Nfd => result.extend(string.nfd()),
+            Self::Nfkc => result.extend(string.nfkc()),
+            Self::Nfkd => result.extend(string.nfkd()),
+        }
+    }
+
+    /// Normalizes `string` using the ICU4X normalization data, streaming the resulting code
+    /// points directly into `result`.
+    #[cfg(feature = "intl")]
+    fn normalize_into(self, string: &str, result: &mut JsStringBuilder) {
+        use icu_normalizer::{ComposingNormalizer, DecomposingNormalizer};
+
+        let chars = string.chars();
+        match self {
+            Self::Nfc => result.extend(ComposingNormalizer::new_nfc().normalize_iter(chars)),
+            Self::Nfd => result.extend(DecomposingNormalizer::new_nfd().normalize_iter(chars)),
+            Self::Nfkc => result.extend(ComposingNormalizer::new_nfkc().normalize_iter(chars)),
+            Self::Nfkd => result.extend(DecomposingNormalizer::new_nfkd().normalize_iter(chars)),
+        }
+    }
+}
+
 /// Abstract operation `IsRegExp( argument )`
 ///
 /// More information:
@@ -2344,3 +2727,11 @@ fn is_reg_exp_object(argument: &JsObject, context: &mut Context) -> JsResult<boo
     // 5. Return false.
     Ok(argument.is_regexp())
 }
+
+/// Returns `true` if `value` is a `RegExp` object whose symbol methods are the built-in ones, so
+/// that dispatching through `GetMethod` can be skipped.
+fn is_unmodified_regexp(value: &JsValue, context: &Context) -> bool {
+    value
+        .as_object()
+        .map_or(false, |object| RegExp::is_unmodified(object, context))
+}
@@ -654,6 +654,14 @@ fn split() {
         forward(&mut context, "'x1x2x'.split(1)"),
         forward(&mut context, "['x','x2x']")
     );
+    assert_eq!(
+        forward(&mut context, "'a<>b<>c<>d'.split('<>', 3)"),
+        forward(&mut context, "['a','b','c']")
+    );
+    assert_eq!(
+        forward(&mut context, "'<>a<>b<>'.split('<>')"),
+        forward(&mut context, "['','a','b','']")
+    );
 
     assert_eq!(
         forward(&mut context, "'Hello'.split(null, 0)"),
@@ -691,6 +699,35 @@ fn split() {
     );
 }
 
+#[test]
+fn split_single_code_unit() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(&mut context, "'a,b,,c,'.split(',')"),
+        forward(&mut context, "['a','b','','c','']")
+    );
+    assert_eq!(
+        forward(&mut context, "'a,b,,c,'.split(',', 3)"),
+        forward(&mut context, "['a','b','']")
+    );
+    assert_eq!(
+        forward(&mut context, "'中文,长度'.split(',')"),
+        forward(&mut context, "['中文','长度']")
+    );
+    assert_eq!(
+        forward(&mut context, "'中文长度'.split('文')"),
+        forward(&mut context, "['中','长度']")
+    );
+    assert_eq!(
+        forward(&mut context, "'😀x😀'.split('x')"),
+        forward(&mut context, "['😀','😀']")
+    );
+    assert_eq!(
+        forward(&mut context, "'abc'.split('x')"),
+        forward(&mut context, "['abc']")
+    );
+}
+
 #[test]
 fn split_with_symbol_split_method() {
     assert_eq!(
@@ -1150,3 +1187,50 @@ fn search() {
     assert_eq!(forward(&mut context, "'aa'.search(/a/g)"), "0");
     assert_eq!(forward(&mut context, "'ba'.search(/a/)"), "1");
 }
+
+#[test]
+fn dedent() {
+    let mut context = Context::default();
+    let init = r#"
+        var a = String.dedent`
+            hello
+              ${"big"}
+            world
+            `;
+        var b = String.dedent`
+            tab\tbed
+
+            line
+        `;
+        var tag = String.dedent((strings, ...values) => strings.raw.join("|") + values.join());
+        var identity = String.dedent(strings => strings);
+        var raw = { raw: ["\n    x\n"] };
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, r#"a === "hello\n  big\nworld""#),
+        "true"
+    );
+    assert_eq!(forward(&mut context, r#"b === "tab\tbed\n\nline""#), "true");
+    assert_eq!(
+        forward(&mut context, "tag`\n  a${1}\n  b${2}\n  `"),
+        "\"a|\nb|1,2\""
+    );
+    assert_eq!(
+        forward(&mut context, "identity(raw) === identity(raw)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.isFrozen(identity(raw))"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "String.dedent`foo`"),
+        "Uncaught \"TypeError\": \"String.dedent template opening line must be empty\""
+    );
+    assert_eq!(
+        forward(&mut context, "String.dedent`\n  foo`"),
+        "Uncaught \"TypeError\": \"String.dedent template closing line must be empty\""
+    );
+}
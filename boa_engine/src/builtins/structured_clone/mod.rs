@@ -0,0 +1,99 @@
+//! This module implements the global `structuredClone` function.
+//!
+//! `structuredClone` deep-copies a value using the structured serialization algorithm of the
+//! HTML standard, which is exposed to hosts as [`StructuredValue`].
+//!
+//! More information:
+//!  - [HTML reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#dom-structuredclone
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/structuredClone
+
+use crate::{
+    builtins::{iterable::iterable_to_list, BuiltIn, JsArgs},
+    object::FunctionBuilder,
+    Context, JsResult, JsValue,
+};
+use boa_profiler::Profiler;
+use tap::{Conv, Pipe};
+
+mod serialize;
+
+pub use serialize::StructuredValue;
+
+#[cfg(test)]
+mod tests;
+
+/// The global `structuredClone` function.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StructuredClone;
+
+impl BuiltIn for StructuredClone {
+    const NAME: &'static str = "structuredClone";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        FunctionBuilder::native(context, Self::structured_clone)
+            .name(Self::NAME)
+            .length(1)
+            .constructor(false)
+            .build()
+            .conv::<JsValue>()
+            .pipe(Some)
+    }
+}
+
+impl StructuredClone {
+    /// `structuredClone ( value [ , options ] )`
+    ///
+    /// Returns a deep copy of `value`, detaching the `ArrayBuffer`s of `options.transfer` and
+    /// moving their contents into the copy.
+    ///
+    /// More information:
+    ///  - [HTML reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#dom-structuredclone
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/structuredClone
+    pub(crate) fn structured_clone(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let value = args.get_or_undefined(0);
+
+        // StructuredSerializeOptions dictionary conversion.
+        let transfer = match args.get_or_undefined(1) {
+            JsValue::Undefined | JsValue::Null => Vec::new(),
+            JsValue::Object(options) => {
+                let transfer = options.get("transfer", context)?;
+                if transfer.is_undefined() {
+                    Vec::new()
+                } else if transfer.is_object() {
+                    iterable_to_list(context, &transfer, None)?
+                        .into_iter()
+                        .map(|transferable| match transferable {
+                            JsValue::Object(transferable) => Ok(transferable),
+                            _ => context.throw_type_error(
+                                "structuredClone: transfer must only contain objects",
+                            ),
+                        })
+                        .collect::<JsResult<Vec<_>>>()?
+                } else {
+                    return context
+                        .throw_type_error("structuredClone: transfer must be an iterable object");
+                }
+            }
+            _ => return context.throw_type_error("structuredClone: options must be an object"),
+        };
+
+        // 1. Let serialized be ? StructuredSerializeWithTransfer(value, options["transfer"]).
+        let serialized = StructuredValue::serialize_with_transfer(value, &transfer, context)?;
+
+        // 2. Let deserializeRecord be ? StructuredDeserializeWithTransfer(serialized, this's relevant realm).
+        // 3. Return deserializeRecord.[[Deserialized]].
+        serialized.deserialize(context)
+    }
+}
@@ -0,0 +1,856 @@
+//! The structured serialization of JavaScript values.
+//!
+//! A [`StructuredValue`] is a copy of a JavaScript value that doesn't depend on the `Context`
+//! it was created in, and can be turned back into an equivalent JavaScript value in any
+//! `Context`, possibly many times.
+//!
+//! More information:
+//!  - [HTML reference][spec]
+//!
+//! [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#safe-passing-of-structured-data
+
+use crate::{
+    builtins::{
+        array_buffer::{
+            shared::{SharedArrayBuffer, SharedDataBlock},
+            ArrayBuffer, DataBlock,
+        },
+        typed_array::TypedArrayKind,
+        Array, RegExp,
+    },
+    object::{JsMap, JsObject, JsSet, ObjectData, ObjectKind},
+    property::PropertyNameKind,
+    Context, JsBigInt, JsResult, JsString, JsValue,
+};
+use rustc_hash::FxHashMap;
+
+/// A serialized value: either a primitive, or a reference to a serialized object.
+#[derive(Debug, Clone)]
+enum Record {
+    Undefined,
+    Null,
+    Boolean(bool),
+    Number(f64),
+    BigInt(JsBigInt),
+    String(JsString),
+    /// The index of the object in [`StructuredValue::objects`].
+    Object(usize),
+}
+
+/// The name of a serialized error, which selects the prototype of the deserialized error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ErrorName {
+    Error,
+    EvalError,
+    RangeError,
+    ReferenceError,
+    SyntaxError,
+    TypeError,
+    UriError,
+}
+
+/// The kind of a serialized `ArrayBuffer` view.
+#[derive(Debug, Clone, Copy)]
+enum ViewKind {
+    DataView,
+    TypedArray(TypedArrayKind),
+}
+
+/// A serialized object.
+#[derive(Debug, Clone)]
+enum ObjectRecord {
+    Boolean(bool),
+    Number(f64),
+    BigInt(JsBigInt),
+    String(JsString),
+    Date(f64),
+    RegExp {
+        source: JsString,
+        flags: JsString,
+    },
+    ArrayBuffer {
+        data: Vec<u8>,
+        max_byte_length: Option<usize>,
+    },
+    SharedArrayBuffer(SharedDataBlock),
+    View {
+        kind: ViewKind,
+        /// The index of the serialized viewed buffer.
+        buffer: usize,
+        byte_offset: usize,
+        /// The array length of typed arrays, or the byte length of data views. `None` for
+        /// length-tracking views.
+        length: Option<usize>,
+    },
+    Error {
+        name: ErrorName,
+        message: Option<JsString>,
+    },
+    Map(Vec<(Record, Record)>),
+    Set(Vec<Record>),
+    Array {
+        length: usize,
+        properties: Vec<(JsString, Record)>,
+    },
+    Object(Vec<(JsString, Record)>),
+}
+
+/// A JavaScript value serialized with the structured serialization algorithm of the HTML
+/// standard, as used by `structuredClone`.
+///
+/// Serializing a value copies it, together with all the objects reachable through it, keeping
+/// the cycles and the shared references between them. The supported objects are primitive
+/// wrappers, `Date`s, `RegExp`s, `ArrayBuffer`s and their views, `Map`s, `Set`s, errors, arrays
+/// and ordinary objects; trying to serialize any other object, or a symbol, throws a
+/// `TypeError`.
+///
+/// `SharedArrayBuffer`s are not copied: the deserialized buffers share their memory with the
+/// original ones.
+///
+/// # Examples
+///
+/// ```
+/// use boa_engine::{builtins::structured_clone::StructuredValue, property::Attribute, Context};
+///
+/// let mut context = Context::default();
+/// let value = context.eval("var a = { list: [1, 2] }; a.self = a; a").unwrap();
+/// let serialized = StructuredValue::serialize(&value, &mut context).unwrap();
+///
+/// let mut other = Context::default();
+/// let copy = serialized.deserialize(&mut other).unwrap();
+/// other.register_global_property("copy", copy, Attribute::all());
+/// assert_eq!(other.eval("copy.self === copy && copy.list[1]").unwrap(), 2.into());
+/// ```
+#[derive(Debug, Clone)]
+pub struct StructuredValue {
+    root: Record,
+    objects: Vec<ObjectRecord>,
+}
+
+impl StructuredValue {
+    /// Abstract operation `StructuredSerialize ( value )`
+    ///
+    /// More information:
+    ///  - [HTML reference][spec]
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#structuredserialize
+    pub fn serialize(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        Self::serialize_with_transfer(value, &[], context)
+    }
+
+    /// Abstract operation `StructuredSerializeWithTransfer ( value, transferList )`
+    ///
+    /// The `ArrayBuffer`s of `transfer_list` are detached, and their contents are moved into the
+    /// serialized value instead of being copied.
+    ///
+    /// More information:
+    ///  - [HTML reference][spec]
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#structuredserializewithtransfer
+    pub fn serialize_with_transfer(
+        value: &JsValue,
+        transfer_list: &[JsObject],
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 1. Let memory be an empty map.
+        let mut serializer = Serializer::default();
+
+        // 2. For each transferable of transferList:
+        for transferable in transfer_list {
+            // a. If transferable has neither an [[ArrayBufferData]] internal slot nor a [[Detached]] internal slot, then throw a "DataCloneError" DOMException.
+            // b. If transferable has an [[ArrayBufferData]] internal slot and IsSharedArrayBuffer(transferable) is true, then throw a "DataCloneError" DOMException.
+            let is_transferable = transferable
+                .borrow()
+                .as_array_buffer()
+                .map_or(false, |buffer| !buffer.is_shared_array_buffer());
+            if !is_transferable {
+                return context.throw_type_error("only ArrayBuffers can be transferred");
+            }
+
+            // c. If memory[transferable] exists, then throw a "DataCloneError" DOMException.
+            if serializer.lookup(transferable).is_some() {
+                return context.throw_type_error(
+                    "an ArrayBuffer is present more than once in the transfer list",
+                );
+            }
+
+            // d. Set memory[transferable] to { [[Type]]: an uninitialized value }.
+            serializer.insert(
+                transferable,
+                ObjectRecord::ArrayBuffer {
+                    data: Vec::new(),
+                    max_byte_length: None,
+                },
+            );
+        }
+
+        // 3. Let serialized be ? StructuredSerializeInternal(value, false, memory).
+        let root = serializer.serialize(value, context)?;
+
+        // 4. Let transferDataHolders be a new empty List.
+        // 5. For each transferable of transferList:
+        for (index, transferable) in transfer_list.iter().enumerate() {
+            let mut transferable = transferable.borrow_mut();
+            let buffer = transferable
+                .as_array_buffer_mut()
+                .expect("checked that the transferable is an ArrayBuffer");
+
+            // a. If transferable has an [[ArrayBufferData]] internal slot and IsDetachedBuffer(transferable) is true, then throw a "DataCloneError" DOMException.
+            if buffer.is_detached_buffer() {
+                return context.throw_type_error("cannot transfer a detached ArrayBuffer");
+            }
+
+            // b. If transferable has a [[Detached]] internal slot and transferable.[[Detached]] is true, then throw a "DataCloneError" DOMException.
+            // c. Let dataHolder be memory[transferable].
+            // d. If transferable has an [[ArrayBufferData]] internal slot, then
+            //     i. If transferable has an [[ArrayBufferMaxByteLength]] internal slot, then
+            //         1. Set dataHolder.[[Type]] to "ResizableArrayBuffer".
+            //         2. Set dataHolder.[[ArrayBufferData]] to transferable.[[ArrayBufferData]].
+            //         3. Set dataHolder.[[ArrayBufferByteLength]] to transferable.[[ArrayBufferByteLength]].
+            //         4. Set dataHolder.[[ArrayBufferMaxByteLength]] to transferable.[[ArrayBufferMaxByteLength]].
+            //     ii. Otherwise:
+            //         1. Set dataHolder.[[Type]] to "ArrayBuffer".
+            //         2. Set dataHolder.[[ArrayBufferData]] to transferable.[[ArrayBufferData]].
+            //         3. Set dataHolder.[[ArrayBufferByteLength]] to transferable.[[ArrayBufferByteLength]].
+            let byte_length = buffer.array_buffer_byte_length();
+            let max_byte_length = buffer.array_buffer_max_byte_length;
+
+            //     iii. Perform ? DetachArrayBuffer(transferable).
+            let data = match buffer.detach(&JsValue::undefined(), context)? {
+                Some(DataBlock::Owned(mut data)) => {
+                    data.truncate(byte_length);
+                    data
+                }
+                _ => unreachable!("checked that the buffer is neither detached nor shared"),
+            };
+            serializer.objects[index] = ObjectRecord::ArrayBuffer {
+                data,
+                max_byte_length,
+            };
+
+            // f. Append dataHolder to transferDataHolders.
+        }
+
+        // 6. Return { [[Serialized]]: serialized, [[TransferDataHolders]]: transferDataHolders }.
+        Ok(Self {
+            root,
+            objects: serializer.objects,
+        })
+    }
+
+    /// Abstract operation `StructuredDeserialize ( serialized, targetRealm )`
+    ///
+    /// Creates a new JavaScript value equivalent to the serialized one in `context`.
+    ///
+    /// More information:
+    ///  - [HTML reference][spec]
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#structureddeserialize
+    pub fn deserialize(&self, context: &mut Context) -> JsResult<JsValue> {
+        Deserializer {
+            objects: &self.objects,
+            memory: vec![None; self.objects.len()],
+        }
+        .deserialize(&self.root, context)
+    }
+}
+
+/// Gets the address of an object, identifying it in the `memory` of the serialization.
+fn address(object: &JsObject) -> usize {
+    object.as_ref() as *const _ as usize
+}
+
+/// The state of `StructuredSerializeInternal`.
+#[derive(Debug, Default)]
+struct Serializer {
+    objects: Vec<ObjectRecord>,
+    /// The `memory` map, from the address of a serialized object to its index.
+    ///
+    /// The objects are kept alive in the map, so that their addresses can't be reused.
+    memory: FxHashMap<usize, (usize, JsObject)>,
+}
+
+/// How an object is serialized, decided while its internal slots are borrowed.
+enum Serialization {
+    Record(ObjectRecord),
+    View {
+        kind: ViewKind,
+        buffer: JsObject,
+        byte_offset: usize,
+        length: Option<usize>,
+    },
+    Error,
+    Map(Vec<(JsValue, JsValue)>),
+    Set(Vec<JsValue>),
+    Array,
+    Object,
+}
+
+impl Serializer {
+    /// Gets the index of an object that was already serialized.
+    fn lookup(&self, object: &JsObject) -> Option<usize> {
+        self.memory.get(&address(object)).map(|(index, _)| *index)
+    }
+
+    /// Sets `memory[object]` to `record`, returning its index.
+    fn insert(&mut self, object: &JsObject, record: ObjectRecord) -> usize {
+        let index = self.objects.len();
+        self.objects.push(record);
+        self.memory.insert(address(object), (index, object.clone()));
+        index
+    }
+
+    /// Abstract operation `StructuredSerializeInternal ( value, forStorage [ , memory ] )`
+    ///
+    /// More information:
+    ///  - [HTML reference][spec]
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#structuredserializeinternal
+    fn serialize(&mut self, value: &JsValue, context: &mut Context) -> JsResult<Record> {
+        // 3. If Type(value) is Undefined, Null, Boolean, Number, BigInt, or String, then return { [[Type]]: "primitive", [[Value]]: value }.
+        // 4. If Type(value) is Symbol, then throw a "DataCloneError" DOMException.
+        match value {
+            JsValue::Undefined => Ok(Record::Undefined),
+            JsValue::Null => Ok(Record::Null),
+            JsValue::Boolean(boolean) => Ok(Record::Boolean(*boolean)),
+            JsValue::Integer(integer) => Ok(Record::Number(f64::from(*integer))),
+            JsValue::Rational(rational) => Ok(Record::Number(*rational)),
+            JsValue::BigInt(bigint) => Ok(Record::BigInt(bigint.clone())),
+            JsValue::String(string) => Ok(Record::String(string.clone())),
+            JsValue::Symbol(_) => context.throw_type_error("a symbol could not be cloned"),
+            JsValue::Object(object) => self.serialize_object(object, context).map(Record::Object),
+        }
+    }
+
+    /// The steps of `StructuredSerializeInternal` for objects, returning the index of the
+    /// serialized object.
+    fn serialize_object(&mut self, object: &JsObject, context: &mut Context) -> JsResult<usize> {
+        // 1. If memory[value] exists, then return memory[value].
+        if let Some(index) = self.lookup(object) {
+            return Ok(index);
+        }
+
+        // 2. Let deep be false.
+        // 5. Let serialized be an uninitialized value.
+        let serialization = {
+            let obj = object.borrow();
+            match obj.kind() {
+                // 6. If value has a [[BooleanData]] internal slot, then set serialized to { [[Type]]: "Boolean", [[BooleanData]]: value.[[BooleanData]] }.
+                ObjectKind::Boolean(boolean) => {
+                    Serialization::Record(ObjectRecord::Boolean(*boolean))
+                }
+                // 7. Otherwise, if value has a [[NumberData]] internal slot, then set serialized to { [[Type]]: "Number", [[NumberData]]: value.[[NumberData]] }.
+                ObjectKind::Number(number) => Serialization::Record(ObjectRecord::Number(*number)),
+                // 8. Otherwise, if value has a [[BigIntData]] internal slot, then set serialized to { [[Type]]: "BigInt", [[BigIntData]]: value.[[BigIntData]] }.
+                ObjectKind::BigInt(bigint) => {
+                    Serialization::Record(ObjectRecord::BigInt(bigint.clone()))
+                }
+                // 9. Otherwise, if value has a [[StringData]] internal slot, then set serialized to { [[Type]]: "String", [[StringData]]: value.[[StringData]] }.
+                ObjectKind::String(string) => {
+                    Serialization::Record(ObjectRecord::String(string.clone()))
+                }
+                // 10. Otherwise, if value has a [[DateValue]] internal slot, then set serialized to { [[Type]]: "Date", [[DateValue]]: value.[[DateValue]] }.
+                ObjectKind::Date(date) => {
+                    Serialization::Record(ObjectRecord::Date(date.get_time()))
+                }
+                // 11. Otherwise, if value has a [[RegExpMatcher]] internal slot, then set serialized to { [[Type]]: "RegExp", [[RegExpMatcher]]: value.[[RegExpMatcher]], [[OriginalSource]]: value.[[OriginalSource]], [[OriginalFlags]]: value.[[OriginalFlags]] }.
+                ObjectKind::RegExp(regexp) => Serialization::Record(ObjectRecord::RegExp {
+                    source: regexp.original_source().clone(),
+                    flags: regexp.original_flags().clone(),
+                }),
+                // 12. Otherwise, if value has an [[ArrayBufferData]] internal slot, then:
+                ObjectKind::ArrayBuffer(buffer) => match &buffer.array_buffer_data {
+                    // a. If IsSharedArrayBuffer(value) is true, then:
+                    //     ...
+                    //     v. Set serialized to { [[Type]]: "SharedArrayBuffer", [[ArrayBufferData]]: value.[[ArrayBufferData]], [[ArrayBufferByteLength]]: value.[[ArrayBufferByteLength]], [[AgentCluster]]: the surrounding agent's agent cluster }.
+                    Some(DataBlock::Shared(block)) => {
+                        Serialization::Record(ObjectRecord::SharedArrayBuffer(block.clone()))
+                    }
+                    // b. Otherwise:
+                    //     ii. Let size be value.[[ArrayBufferByteLength]].
+                    //     iii. Let dataCopy be ? CreateByteDataBlock(size).
+                    //     iv. Perform CopyDataBlockBytes(dataCopy, 0, value.[[ArrayBufferData]], 0, size).
+                    //     v. If value has an [[ArrayBufferMaxByteLength]] internal slot, then set serialized to { [[Type]]: "ResizableArrayBuffer", [[ArrayBufferData]]: dataCopy, [[ArrayBufferByteLength]]: size, [[ArrayBufferMaxByteLength]]: value.[[ArrayBufferMaxByteLength]] }.
+                    //     vi. Otherwise, set serialized to { [[Type]]: "ArrayBuffer", [[ArrayBufferData]]: dataCopy, [[ArrayBufferByteLength]]: size }.
+                    Some(block) => Serialization::Record(ObjectRecord::ArrayBuffer {
+                        data: block
+                            .bytes(0..buffer.array_buffer_byte_length())
+                            .into_owned(),
+                        max_byte_length: buffer.array_buffer_max_byte_length,
+                    }),
+                    //     i. If IsDetachedBuffer(value) is true, then throw a "DataCloneError" DOMException.
+                    None => {
+                        return context
+                            .throw_type_error("a detached ArrayBuffer could not be cloned")
+                    }
+                },
+                // 13. Otherwise, if value has a [[ViewedArrayBuffer]] internal slot, then:
+                //     a. If IsArrayBufferViewOutOfBounds(value) is true, then throw a "DataCloneError" DOMException.
+                ObjectKind::IntegerIndexed(typed_array) => {
+                    if typed_array.is_out_of_bounds() {
+                        return context
+                            .throw_type_error("an out of bounds typed array could not be cloned");
+                    }
+                    Serialization::View {
+                        kind: ViewKind::TypedArray(typed_array.typed_array_name()),
+                        buffer: typed_array
+                            .viewed_array_buffer()
+                            .cloned()
+                            .expect("typed arrays must have a viewed buffer"),
+                        byte_offset: typed_array.byte_offset(),
+                        length: (!typed_array.is_length_tracking())
+                            .then(|| typed_array.array_length()),
+                    }
+                }
+                ObjectKind::DataView(data_view) => {
+                    if data_view.is_out_of_bounds() {
+                        return context
+                            .throw_type_error("an out of bounds DataView could not be cloned");
+                    }
+                    Serialization::View {
+                        kind: ViewKind::DataView,
+                        buffer: data_view.viewed_array_buffer().clone(),
+                        byte_offset: data_view.byte_offset(),
+                        length: data_view.byte_length(),
+                    }
+                }
+                // 14. Otherwise, if value has a [[MapData]] internal slot, then:
+                //     a. Set serialized to { [[Type]]: "Map", [[MapData]]: a new empty List }.
+                //     b. Set deep to true.
+                ObjectKind::Map(map) => Serialization::Map(
+                    map.iter()
+                        .map(|(key, value)| (key.clone(), value.clone()))
+                        .collect(),
+                ),
+                // 15. Otherwise, if value has a [[SetData]] internal slot, then:
+                //     a. Set serialized to { [[Type]]: "Set", [[SetData]]: a new empty List }.
+                //     b. Set deep to true.
+                ObjectKind::Set(set) => Serialization::Set(set.iter().cloned().collect()),
+                // 16. Otherwise, if value has an [[ErrorData]] internal slot and value is not a platform object, then:
+                ObjectKind::Error(_) => Serialization::Error,
+                // 17. Otherwise, if value is an Array exotic object, then:
+                ObjectKind::Array => Serialization::Array,
+                // 20. Otherwise, if value has any internal slot other than [[Prototype]] and [[Extensible]], then throw a "DataCloneError" DOMException.
+                // 21. Otherwise, if value is an exotic object and value is not the %Object.prototype% intrinsic object associated with any realm, then throw a "DataCloneError" DOMException.
+                // 22. Otherwise:
+                //     a. Set serialized to { [[Type]]: "Object", [[Properties]]: a new empty List }.
+                //     b. Set deep to true.
+                ObjectKind::Ordinary => Serialization::Object,
+                // 18. Otherwise, if value is a platform object that is a serializable object: ...
+                // 19. Otherwise, if value is a platform object, then throw a "DataCloneError" DOMException.
+                // 20. Otherwise, if IsCallable(value) is true, then throw a "DataCloneError" DOMException.
+                _ => {
+                    return context
+                        .throw_type_error(format!("{} object could not be cloned", obj.kind()))
+                }
+            }
+        };
+
+        let record = match serialization {
+            Serialization::Record(record) => record,
+            Serialization::View {
+                kind,
+                buffer,
+                byte_offset,
+                length,
+            } => {
+                // b. Let buffer be the value of value's [[ViewedArrayBuffer]] internal slot.
+                // c. Let bufferSerialized be ? StructuredSerializeInternal(buffer, forStorage, memory).
+                // d. Assert: bufferSerialized.[[Type]] is "ArrayBuffer", "ResizableArrayBuffer", "SharedArrayBuffer", or "GrowableSharedArrayBuffer".
+                let buffer = self.serialize_object(&buffer, context)?;
+
+                // e. If value has a [[DataView]] internal slot, then set serialized to { [[Type]]: "ArrayBufferView", [[Constructor]]: "DataView", [[ArrayBufferSerialized]]: bufferSerialized, [[ByteLength]]: value.[[ByteLength]], [[ByteOffset]]: value.[[ByteOffset]] }.
+                // f. Otherwise:
+                //     i. Assert: value has a [[TypedArrayName]] internal slot.
+                //     ii. Set serialized to { [[Type]]: "ArrayBufferView", [[Constructor]]: value.[[TypedArrayName]], [[ArrayBufferSerialized]]: bufferSerialized, [[ByteLength]]: value.[[ByteLength]], [[ByteOffset]]: value.[[ByteOffset]], [[ArrayLength]]: value.[[ArrayLength]] }.
+                ObjectRecord::View {
+                    kind,
+                    buffer,
+                    byte_offset,
+                    length,
+                }
+            }
+            Serialization::Error => {
+                // a. Let name be ? Get(value, "name").
+                let name = object.get("name", context)?;
+
+                // b. If name is not one of "Error", "EvalError", "RangeError", "ReferenceError", "SyntaxError", "TypeError", or "URIError", then set name to "Error".
+                let name = match name.as_string().map(JsString::as_str) {
+                    Some("EvalError") => ErrorName::EvalError,
+                    Some("RangeError") => ErrorName::RangeError,
+                    Some("ReferenceError") => ErrorName::ReferenceError,
+                    Some("SyntaxError") => ErrorName::SyntaxError,
+                    Some("TypeError") => ErrorName::TypeError,
+                    Some("URIError") => ErrorName::UriError,
+                    _ => ErrorName::Error,
+                };
+
+                // c. Let valueMessageDesc be ? value.[[GetOwnProperty]]("message").
+                let message = object.__get_own_property__(&"message".into(), context)?;
+
+                // d. Let message be undefined if IsDataDescriptor(valueMessageDesc) is false, and ? ToString(valueMessageDesc.[[Value]]) otherwise.
+                let message = match message {
+                    Some(desc) if desc.is_data_descriptor() => Some(
+                        desc.value()
+                            .cloned()
+                            .unwrap_or_default()
+                            .to_string(context)?,
+                    ),
+                    _ => None,
+                };
+
+                // e. Set serialized to { [[Type]]: "Error", [[Name]]: name, [[Message]]: message }.
+                ObjectRecord::Error { name, message }
+            }
+            Serialization::Map(entries) => {
+                // 23. Set memory[value] to serialized.
+                let index = self.insert(object, ObjectRecord::Map(Vec::new()));
+
+                // 24. If deep is true, then:
+                //     a. If value has a [[MapData]] internal slot, then:
+                //         i. Let copiedList be a new empty List.
+                //         ii. For each Record { [[Key]], [[Value]] } entry of value.[[MapData]]:
+                //             1. Let copiedEntry be a new Record { [[Key]]: entry.[[Key]], [[Value]]: entry.[[Value]] }.
+                //             2. If copiedEntry.[[Key]] is not the special value empty, append copiedEntry to copiedList.
+                //         iii. For each Record { [[Key]], [[Value]] } entry of copiedList:
+                let mut serialized = Vec::with_capacity(entries.len());
+                for (key, value) in entries {
+                    // 1. Let serializedKey be ? StructuredSerializeInternal(entry.[[Key]], forStorage, memory).
+                    let key = self.serialize(&key, context)?;
+
+                    // 2. Let serializedValue be ? StructuredSerializeInternal(entry.[[Value]], forStorage, memory).
+                    let value = self.serialize(&value, context)?;
+
+                    // 3. Append { [[Key]]: serializedKey, [[Value]]: serializedValue } to serialized.[[MapData]].
+                    serialized.push((key, value));
+                }
+                self.objects[index] = ObjectRecord::Map(serialized);
+
+                return Ok(index);
+            }
+            Serialization::Set(values) => {
+                // 23. Set memory[value] to serialized.
+                let index = self.insert(object, ObjectRecord::Set(Vec::new()));
+
+                // 24. If deep is true, then:
+                //     b. Otherwise, if value has a [[SetData]] internal slot, then:
+                //         i. Let copiedList be a new empty List.
+                //         ii. For each entry of value.[[SetData]]:
+                //             1. If entry is not the special value empty, append entry to copiedList.
+                //         iii. For each entry of copiedList:
+                //             1. Let serializedEntry be ? StructuredSerializeInternal(entry, forStorage, memory).
+                //             2. Append serializedEntry to serialized.[[SetData]].
+                let serialized = values
+                    .iter()
+                    .map(|value| self.serialize(value, context))
+                    .collect::<JsResult<_>>()?;
+                self.objects[index] = ObjectRecord::Set(serialized);
+
+                return Ok(index);
+            }
+            Serialization::Array => {
+                // a. Let valueLenDescriptor be ? OrdinaryGetOwnProperty(value, "length").
+                // b. Let valueLen be valueLenDescriptor.[[Value]].
+                let length = object.get("length", context)?.to_length(context)?;
+
+                // c. Set serialized to { [[Type]]: "Array", [[Length]]: valueLen, [[Properties]]: a new empty List }.
+                // d. Set deep to true.
+                // 23. Set memory[value] to serialized.
+                let index = self.insert(
+                    object,
+                    ObjectRecord::Array {
+                        length,
+                        properties: Vec::new(),
+                    },
+                );
+
+                // 24. If deep is true, then:
+                //     c. Otherwise, if value is a platform object that is a serializable object, then perform the serialization steps for value's primary interface, given value, serialized, and forStorage.
+                //     d. Otherwise, for each key in ! EnumerableOwnProperties(value, key):
+                let properties = self.serialize_properties(object, context)?;
+                self.objects[index] = ObjectRecord::Array { length, properties };
+
+                return Ok(index);
+            }
+            Serialization::Object => {
+                // 23. Set memory[value] to serialized.
+                let index = self.insert(object, ObjectRecord::Object(Vec::new()));
+
+                // 24. If deep is true, then:
+                //     d. Otherwise, for each key in ! EnumerableOwnProperties(value, key):
+                let properties = self.serialize_properties(object, context)?;
+                self.objects[index] = ObjectRecord::Object(properties);
+
+                return Ok(index);
+            }
+        };
+
+        // 23. Set memory[value] to serialized.
+        Ok(self.insert(object, record))
+    }
+
+    /// Serializes the enumerable own properties of an array or an ordinary object.
+    fn serialize_properties(
+        &mut self,
+        object: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<Vec<(JsString, Record)>> {
+        let mut properties = Vec::new();
+
+        // d. Otherwise, for each key in ! EnumerableOwnProperties(value, key):
+        for key in object.enumerable_own_property_names(PropertyNameKind::Key, context)? {
+            let key = key
+                .as_string()
+                .cloned()
+                .expect("EnumerableOwnProperties(key) must only return strings");
+
+            // i. If ! HasOwnProperty(value, key) is true, then:
+            if object.has_own_property(key.clone(), context)? {
+                // 1. Let inputValue be ? value.[[Get]](key, value).
+                let input_value = object.get(key.clone(), context)?;
+
+                // 2. Let outputValue be ? StructuredSerializeInternal(inputValue, forStorage, memory).
+                let output_value = self.serialize(&input_value, context)?;
+
+                // 3. Append { [[Key]]: key, [[Value]]: outputValue } to serialized.[[Properties]].
+                properties.push((key, output_value));
+            }
+        }
+
+        Ok(properties)
+    }
+}
+
+/// The state of `StructuredDeserialize`.
+struct Deserializer<'a> {
+    objects: &'a [ObjectRecord],
+    /// The `memory` map, from the index of a serialized object to its deserialized value.
+    memory: Vec<Option<JsObject>>,
+}
+
+impl Deserializer<'_> {
+    /// Abstract operation `StructuredDeserialize ( serialized, targetRealm [ , memory ] )`
+    ///
+    /// More information:
+    ///  - [HTML reference][spec]
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#structureddeserialize
+    fn deserialize(&mut self, record: &Record, context: &mut Context) -> JsResult<JsValue> {
+        // 4. If serialized.[[Type]] is "primitive", then set value to serialized.[[Value]].
+        Ok(match record {
+            Record::Undefined => JsValue::undefined(),
+            Record::Null => JsValue::null(),
+            Record::Boolean(boolean) => (*boolean).into(),
+            Record::Number(number) => (*number).into(),
+            Record::BigInt(bigint) => bigint.clone().into(),
+            Record::String(string) => string.clone().into(),
+            Record::Object(index) => self.deserialize_object(*index, context)?.into(),
+        })
+    }
+
+    /// The steps of `StructuredDeserialize` for objects.
+    fn deserialize_object(&mut self, index: usize, context: &mut Context) -> JsResult<JsObject> {
+        // 1. If memory[serialized] exists, then return memory[serialized].
+        if let Some(object) = &self.memory[index] {
+            return Ok(object.clone());
+        }
+
+        let objects = self.objects;
+        let constructors = context.intrinsics().constructors();
+
+        // 2. Let deep be false.
+        // 3. Let value be an uninitialized value.
+        let value = match &objects[index] {
+            // 5. Otherwise, if serialized.[[Type]] is "Boolean", then set value to a new Boolean object in targetRealm whose [[BooleanData]] internal slot value is serialized.[[BooleanData]].
+            ObjectRecord::Boolean(boolean) => JsValue::new(*boolean).to_object(context)?,
+            // 6. Otherwise, if serialized.[[Type]] is "Number", then set value to a new Number object in targetRealm whose [[NumberData]] internal slot value is serialized.[[NumberData]].
+            ObjectRecord::Number(number) => JsValue::new(*number).to_object(context)?,
+            // 7. Otherwise, if serialized.[[Type]] is "BigInt", then set value to a new BigInt object in targetRealm whose [[BigIntData]] internal slot value is serialized.[[BigIntData]].
+            ObjectRecord::BigInt(bigint) => JsValue::new(bigint.clone()).to_object(context)?,
+            // 8. Otherwise, if serialized.[[Type]] is "String", then set value to a new String object in targetRealm whose [[StringData]] internal slot value is serialized.[[StringData]].
+            ObjectRecord::String(string) => JsValue::new(string.clone()).to_object(context)?,
+            // 9. Otherwise, if serialized.[[Type]] is "Date", then set value to a new Date object in targetRealm whose [[DateValue]] internal slot value is serialized.[[DateValue]].
+            ObjectRecord::Date(time) => {
+                let date = constructors.date().constructor();
+                construct(&date, &[(*time).into()], context)?
+            }
+            // 10. Otherwise, if serialized.[[Type]] is "RegExp", then set value to a new RegExp object in targetRealm whose [[RegExpMatcher]] internal slot value is serialized.[[RegExpMatcher]], whose [[OriginalSource]] internal slot value is serialized.[[OriginalSource]], and whose [[OriginalFlags]] internal slot value is serialized.[[OriginalFlags]].
+            ObjectRecord::RegExp { source, flags } => {
+                RegExp::create(source.clone().into(), flags.clone().into(), context)?
+                    .as_object()
+                    .cloned()
+                    .expect("RegExpCreate must return an object")
+            }
+            // 11. Otherwise, if serialized.[[Type]] is "SharedArrayBuffer", then:
+            //     b. Otherwise, set value to a new SharedArrayBuffer object in targetRealm whose [[ArrayBufferData]] internal slot value is serialized.[[ArrayBufferData]] and whose [[ArrayBufferByteLength]] internal slot value is serialized.[[ArrayBufferByteLength]].
+            ObjectRecord::SharedArrayBuffer(block) => {
+                SharedArrayBuffer::create(block.clone(), context)
+            }
+            // 13. Otherwise, if serialized.[[Type]] is "ArrayBuffer", then set value to a new ArrayBuffer object in targetRealm whose [[ArrayBufferData]] internal slot value is serialized.[[ArrayBufferData]], and whose [[ArrayBufferByteLength]] internal slot value is serialized.[[ArrayBufferByteLength]].
+            // 14. Otherwise, if serialized.[[Type]] is "ResizableArrayBuffer", then set value to a new ArrayBuffer object in targetRealm whose [[ArrayBufferData]] internal slot value is serialized.[[ArrayBufferData]], whose [[ArrayBufferByteLength]] internal slot value is serialized.[[ArrayBufferByteLength]], and whose [[ArrayBufferMaxByteLength]] internal slot value is serialized.[[ArrayBufferMaxByteLength]].
+            ObjectRecord::ArrayBuffer {
+                data,
+                max_byte_length,
+            } => JsObject::from_proto_and_data(
+                constructors.array_buffer().prototype(),
+                ObjectData::array_buffer(ArrayBuffer {
+                    array_buffer_data: Some(DataBlock::Owned(data.clone())),
+                    array_buffer_byte_length: data.len(),
+                    array_buffer_max_byte_length: *max_byte_length,
+                    array_buffer_detach_key: JsValue::undefined(),
+                }),
+            ),
+            // 15. Otherwise, if serialized.[[Type]] is "ArrayBufferView", then:
+            ObjectRecord::View {
+                kind,
+                buffer,
+                byte_offset,
+                length,
+            } => {
+                let constructor = match kind {
+                    ViewKind::DataView => constructors.data_view().constructor(),
+                    ViewKind::TypedArray(kind) => {
+                        kind.standard_constructor()(constructors).constructor()
+                    }
+                };
+
+                // a. Let deserializedArrayBuffer be ? StructuredDeserialize(serialized.[[ArrayBufferSerialized]], targetRealm, memory).
+                let buffer = self.deserialize_object(*buffer, context)?;
+
+                // b. If serialized.[[Constructor]] is "DataView", then set value to a new DataView object in targetRealm whose [[ViewedArrayBuffer]] internal slot value is deserializedArrayBuffer, whose [[ByteLength]] internal slot value is serialized.[[ByteLength]], and whose [[ByteOffset]] internal slot value is serialized.[[ByteOffset]].
+                // c. Otherwise, set value to a new typed array object in targetRealm, using the constructor given by serialized.[[Constructor]], whose [[ViewedArrayBuffer]] internal slot value is deserializedArrayBuffer, whose [[TypedArrayName]] internal slot value is serialized.[[Constructor]], whose [[ByteLength]] internal slot value is serialized.[[ByteLength]], whose [[ByteOffset]] internal slot value is serialized.[[ByteOffset]], and whose [[ArrayLength]] internal slot value is serialized.[[ArrayLength]].
+                construct(
+                    &constructor,
+                    &[
+                        buffer.into(),
+                        (*byte_offset).into(),
+                        length.map_or_else(JsValue::undefined, JsValue::from),
+                    ],
+                    context,
+                )?
+            }
+            // 18. Otherwise, if serialized.[[Type]] is "Error", then:
+            ObjectRecord::Error { name, message } => {
+                // a. Let prototype be %Error.prototype%.
+                // b. If serialized.[[Name]] is "EvalError", then set prototype to %EvalError.prototype%.
+                // c. If serialized.[[Name]] is "RangeError", then set prototype to %RangeError.prototype%.
+                // d. If serialized.[[Name]] is "ReferenceError", then set prototype to %ReferenceError.prototype%.
+                // e. If serialized.[[Name]] is "SyntaxError", then set prototype to %SyntaxError.prototype%.
+                // f. If serialized.[[Name]] is "TypeError", then set prototype to %TypeError.prototype%.
+                // g. If serialized.[[Name]] is "URIError", then set prototype to %URIError.prototype%.
+                let prototype = match name {
+                    ErrorName::Error => constructors.error(),
+                    ErrorName::EvalError => constructors.eval_error(),
+                    ErrorName::RangeError => constructors.range_error(),
+                    ErrorName::ReferenceError => constructors.reference_error(),
+                    ErrorName::SyntaxError => constructors.syntax_error(),
+                    ErrorName::TypeError => constructors.type_error(),
+                    ErrorName::UriError => constructors.uri_error(),
+                }
+                .prototype();
+
+                // h. Let message be serialized.[[Message]].
+                // i. Set value to OrdinaryObjectCreate(prototype, « [[ErrorData]] »).
+                let error = JsObject::from_proto_and_data(prototype, ObjectData::error(context));
+
+                // j. Let messageDesc be PropertyDescriptor{ [[Value]]: message, [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true }.
+                // k. If message is not undefined, then perform ! OrdinaryDefineOwnProperty(value, "message", messageDesc).
+                if let Some(message) = message {
+                    error.create_non_enumerable_data_property_or_throw(
+                        "message",
+                        message.clone(),
+                        context,
+                    );
+                }
+
+                error
+            }
+            // 16. Otherwise, if serialized.[[Type]] is "Map", then:
+            //     a. Set value to a new Map object in targetRealm whose [[MapData]] internal slot value is a new empty List.
+            //     b. Set deep to true.
+            ObjectRecord::Map(_) => JsMap::new(context).into(),
+            // 17. Otherwise, if serialized.[[Type]] is "Set", then:
+            //     a. Set value to a new Set object in targetRealm whose [[SetData]] internal slot value is a new empty List.
+            //     b. Set deep to true.
+            ObjectRecord::Set(_) => JsSet::new(context).into(),
+            // 19. Otherwise, if serialized.[[Type]] is "Array", then:
+            //     a. Let outputProto be targetRealm.[[Intrinsics]].[[%Array.prototype%]].
+            //     b. Set value to ! ArrayCreate(serialized.[[Length]], outputProto).
+            //     c. Set deep to true.
+            ObjectRecord::Array { length, .. } => Array::array_create(*length, None, context)?,
+            // 20. Otherwise, if serialized.[[Type]] is "Object", then:
+            //     a. Set value to a new Object in targetRealm.
+            //     b. Set deep to true.
+            ObjectRecord::Object(_) => context.construct_object(),
+        };
+
+        // 22. Set memory[serialized] to value.
+        self.memory[index] = Some(value.clone());
+
+        // 23. If deep is true, then:
+        match &objects[index] {
+            // a. If serialized.[[Type]] is "Map", then:
+            //     i. For each Record { [[Key]], [[Value]] } entry of serialized.[[MapData]]:
+            ObjectRecord::Map(entries) => {
+                for (key, entry_value) in entries {
+                    // 1. Let deserializedKey be ? StructuredDeserialize(entry.[[Key]], targetRealm, memory).
+                    let key = self.deserialize(key, context)?;
+
+                    // 2. Let deserializedValue be ? StructuredDeserialize(entry.[[Value]], targetRealm, memory).
+                    let entry_value = self.deserialize(entry_value, context)?;
+
+                    // 3. Append { [[Key]]: deserializedKey, [[Value]]: deserializedValue } to value.[[MapData]].
+                    value
+                        .borrow_mut()
+                        .as_map_mut()
+                        .expect("value must be a Map")
+                        .insert(key, entry_value);
+                }
+            }
+            // b. Otherwise, if serialized.[[Type]] is "Set", then:
+            //     i. For each entry of serialized.[[SetData]]:
+            ObjectRecord::Set(entries) => {
+                for entry in entries {
+                    // 1. Let deserializedEntry be ? StructuredDeserialize(entry, targetRealm, memory).
+                    let entry = self.deserialize(entry, context)?;
+
+                    // 2. Append deserializedEntry to value.[[SetData]].
+                    value
+                        .borrow_mut()
+                        .as_set_mut()
+                        .expect("value must be a Set")
+                        .add(entry);
+                }
+            }
+            // d. Otherwise:
+            //     i. For each Record { [[Key]], [[Value]] } entry of serialized.[[Properties]]:
+            ObjectRecord::Array { properties, .. } | ObjectRecord::Object(properties) => {
+                for (key, property_value) in properties {
+                    // 1. Let deserializedValue be ? StructuredDeserialize(entry.[[Value]], targetRealm, memory).
+                    let property_value = self.deserialize(property_value, context)?;
+
+                    // 2. Let result be ! CreateDataProperty(value, entry.[[Key]], deserializedValue).
+                    // 3. Assert: result is true.
+                    value.create_data_property_or_throw(key.clone(), property_value, context)?;
+                }
+            }
+            _ => {}
+        }
+
+        // 24. Return value.
+        Ok(value)
+    }
+}
+
+/// Constructs an object with one of the intrinsic `constructor`s.
+fn construct(
+    constructor: &JsObject,
+    args: &[JsValue],
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    Ok(constructor
+        .construct(args, &constructor.clone().into(), context)?
+        .as_object()
+        .cloned()
+        .expect("[[Construct]] must always return an object"))
+}
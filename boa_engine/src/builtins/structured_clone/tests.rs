@@ -0,0 +1,125 @@
+use super::StructuredValue;
+use crate::{forward, property::Attribute, Context};
+
+#[test]
+fn cyclic_objects() {
+    let mut context = Context::default();
+    let init = r#"
+        var original = { list: [1, "two", { three: 3n }] };
+        original.self = original;
+        original.list.push(original.list);
+        var copy = structuredClone(original);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "copy !== original"), "true");
+    assert_eq!(forward(&mut context, "copy.self === copy"), "true");
+    assert_eq!(forward(&mut context, "copy.list[3] === copy.list"), "true");
+    assert_eq!(forward(&mut context, "Array.isArray(copy.list)"), "true");
+    assert_eq!(forward(&mut context, "copy.list[2].three"), "3n");
+}
+
+#[test]
+fn builtin_objects() {
+    let mut context = Context::default();
+    let init = r#"
+        var key = {};
+        var map = structuredClone(new Map([[key, "value"], [1, key]]));
+        var set = structuredClone(new Set([1, "a", 1]));
+        var date = structuredClone(new Date(0));
+        var regexp = structuredClone(/a+b/gi);
+        var error = structuredClone(new RangeError("out of range"));
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "[...map.keys()][0] === map.get(1)"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "[...set].join()"), "\"1,a\"");
+    assert_eq!(
+        forward(&mut context, "date instanceof Date && date.getTime()"),
+        "0"
+    );
+    assert_eq!(forward(&mut context, "regexp.source"), "\"a+b\"");
+    assert_eq!(forward(&mut context, "regexp.flags"), "\"gi\"");
+    assert_eq!(
+        forward(&mut context, "error instanceof RangeError && error.message"),
+        "\"out of range\""
+    );
+}
+
+#[test]
+fn array_buffers() {
+    let mut context = Context::default();
+    let init = r#"
+        var buffer = new ArrayBuffer(8);
+        var bytes = new Uint8Array(buffer, 2, 4);
+        bytes[0] = 42;
+        var original = { view: new DataView(buffer), bytes };
+        var copy = structuredClone(original);
+        bytes[0] = 0;
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "copy.bytes[0]"), "42");
+    assert_eq!(forward(&mut context, "copy.bytes.byteOffset"), "2");
+    assert_eq!(forward(&mut context, "copy.bytes.length"), "4");
+    assert_eq!(
+        forward(&mut context, "copy.bytes.buffer === copy.view.buffer"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "copy.view.getUint8(2)"), "42");
+}
+
+#[test]
+fn transfer() {
+    let mut context = Context::default();
+    let init = r#"
+        var buffer = new ArrayBuffer(4, { maxByteLength: 16 });
+        new Uint8Array(buffer)[3] = 7;
+        var copy = structuredClone({ buffer }, { transfer: [buffer] });
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "buffer.detached"), "true");
+    assert_eq!(forward(&mut context, "copy.buffer.byteLength"), "4");
+    assert_eq!(forward(&mut context, "copy.buffer.maxByteLength"), "16");
+    assert_eq!(forward(&mut context, "new Uint8Array(copy.buffer)[3]"), "7");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { structuredClone(buffer, { transfer: [buffer] }); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn uncloneable_values() {
+    let mut context = Context::default();
+    for value in ["Symbol()", "() => {}", "{ f() {} }", "new Proxy({}, {})"] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ structuredClone({value}); }} catch (e) {{ e.name }}")
+            ),
+            "\"TypeError\"",
+            "{value} should not be cloneable"
+        );
+    }
+}
+
+#[test]
+fn host_serialization() {
+    let mut context = Context::default();
+    let value = context
+        .eval("var a = { list: [1, 2] }; a.self = a; a")
+        .unwrap();
+    let serialized = StructuredValue::serialize(&value, &mut context).unwrap();
+
+    let mut other = Context::default();
+    for name in ["first", "second"] {
+        let copy = serialized.deserialize(&mut other).unwrap();
+        other.register_global_property(name, copy, Attribute::all());
+    }
+    assert_eq!(forward(&mut other, "first.self === first"), "true");
+    assert_eq!(forward(&mut other, "first !== second"), "true");
+    assert_eq!(forward(&mut other, "second.list.join()"), "\"1,2\"");
+}
@@ -78,7 +78,9 @@ impl BuiltIn for Symbol {
     fn init(context: &mut Context) -> Option<JsValue> {
         let _timer = Profiler::global().start_event(Self::NAME, "init");
 
+        let symbol_async_dispose = WellKnownSymbols::async_dispose();
         let symbol_async_iterator = WellKnownSymbols::async_iterator();
+        let symbol_dispose = WellKnownSymbols::dispose();
         let symbol_has_instance = WellKnownSymbols::has_instance();
         let symbol_is_concat_spreadable = WellKnownSymbols::is_concat_spreadable();
         let symbol_iterator = WellKnownSymbols::iterator();
@@ -114,7 +116,9 @@ impl BuiltIn for Symbol {
         .length(Self::LENGTH)
         .static_method(Self::for_, "for", 1)
         .static_method(Self::key_for, "keyFor", 1)
+        .static_property("asyncDispose", symbol_async_dispose, attribute)
         .static_property("asyncIterator", symbol_async_iterator, attribute)
+        .static_property("dispose", symbol_dispose, attribute)
         .static_property("hasInstance", symbol_has_instance, attribute)
         .static_property("isConcatSpreadable", symbol_is_concat_spreadable, attribute)
         .static_property("iterator", symbol_iterator, attribute)
@@ -303,21 +307,31 @@ impl Symbol {
         let sym = args.get_or_undefined(0);
         // 1. If Type(sym) is not Symbol, throw a TypeError exception.
         if let Some(sym) = sym.as_symbol() {
-            // 2. For each element e of the GlobalSymbolRegistry List (see 20.4.2.2), do
-            //     a. If SameValue(e.[[Symbol]], sym) is true, return e.[[Key]].
-            // 3. Assert: GlobalSymbolRegistry does not currently contain an entry for sym.
-            // 4. Return undefined.
-            let symbol = GLOBAL_SYMBOL_REGISTRY.with(move |registry| {
-                let registry = registry.borrow();
-                registry.get_symbol(&sym)
-            });
-
-            Ok(symbol.map(JsValue::from).unwrap_or_default())
+            // 2. Return KeyForSymbol(sym).
+            Ok(Self::key_for_symbol(&sym)
+                .map(JsValue::from)
+                .unwrap_or_default())
         } else {
             context.throw_type_error("Symbol.keyFor: sym is not a symbol")
         }
     }
 
+    /// Abstract operation `KeyForSymbol ( sym )`
+    ///
+    /// Returns the key of `sym` in the global symbol registry, if it was created by `Symbol.for`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-keyforsymbol
+    pub(crate) fn key_for_symbol(sym: &JsSymbol) -> Option<JsString> {
+        // 1. For each element e of the GlobalSymbolRegistry List, do
+        //     a. If SameValue(e.[[Symbol]], sym) is true, return e.[[Key]].
+        // 2. Assert: GlobalSymbolRegistry does not currently contain an entry for sym.
+        // 3. Return undefined.
+        GLOBAL_SYMBOL_REGISTRY.with(|registry| registry.borrow().get_symbol(sym))
+    }
+
     /// `Symbol.prototype [ @@toPrimitive ]`
     ///
     /// This function is called by ECMAScript language operators to convert a Symbol object to a primitive value.
@@ -0,0 +1,1162 @@
+//! The calendars of the Temporal API.
+//!
+//! The ISO 8601 calendar is implemented directly. The other built-in calendars are backed by
+//! the calendrical calculations of ICU4X: a date is converted to the year, month and day of its
+//! calendar to compute its fields, such as its `year` or `monthCode`, and to add durations to it
+//! in the units of the calendar.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-calendars
+
+use super::{
+    epoch_days_from_iso, plain_date::IsoDate, time_zone::TimeZone, to_integer_with_truncation,
+    to_positive_integer_with_truncation, Overflow, TemporalUnit,
+};
+use crate::{
+    builtins::{
+        intl::get_option,
+        iso8601::{iso_days_in_month, parse_date_time, parse_utc_offset},
+    },
+    object::JsObject,
+    value::PreferredType,
+    Context, JsResult, JsValue,
+};
+use icu_calendar::{types::MonthCode, AnyCalendar, AnyCalendarKind, Date, Ref};
+use std::cmp::Ordering;
+
+/// A calendar of the Temporal API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Calendar {
+    /// The ISO 8601 calendar, the proleptic Gregorian calendar with ISO week numbers.
+    Iso8601,
+    /// The proleptic Gregorian calendar, with the `ce` and `bce` eras.
+    Gregorian,
+    /// The Gregorian calendar with the eras of the Japanese emperors since the Meiji era.
+    Japanese,
+    /// The Hebrew calendar, a lunisolar calendar with a leap month in 7 years of every 19.
+    Hebrew,
+    /// The observational Islamic calendar, a lunar calendar.
+    Islamic,
+    /// The tabular Islamic calendar with a Friday epoch.
+    IslamicCivil,
+    /// The tabular Islamic calendar with a Thursday epoch.
+    IslamicTabular,
+    /// The Umm al-Qura Islamic calendar of Saudi Arabia.
+    IslamicUmmAlQura,
+}
+
+/// The identifiers of the calendars, with the aliases of `CanonicalizeCalendar`.
+const CALENDARS: [(&str, Calendar); 9] = [
+    ("iso8601", Calendar::Iso8601),
+    ("gregory", Calendar::Gregorian),
+    ("japanese", Calendar::Japanese),
+    ("hebrew", Calendar::Hebrew),
+    ("islamic", Calendar::Islamic),
+    ("islamic-civil", Calendar::IslamicCivil),
+    ("islamic-tbla", Calendar::IslamicTabular),
+    ("islamic-umalqura", Calendar::IslamicUmmAlQura),
+    ("islamicc", Calendar::IslamicCivil),
+];
+
+/// The eras of the Japanese calendar since the Meiji era, with the ISO year they began in.
+///
+/// The dates before the Meiji era are in the `ce` and `bce` eras of the Gregorian calendar.
+const JAPANESE_ERAS: [(&str, i32); 5] = [
+    ("meiji", 1868),
+    ("taisho", 1912),
+    ("showa", 1926),
+    ("heisei", 1989),
+    ("reiwa", 2019),
+];
+
+/// A date in the units of a calendar: its year, its ordinal month and its day.
+///
+/// The year is the arithmetic year of the calendar, which doesn't depend on the era.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CalendarDate {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Calendar {
+    /// Gets the identifier of the calendar.
+    pub(crate) fn identifier(self) -> &'static str {
+        CALENDARS
+            .iter()
+            .find(|(_, calendar)| *calendar == self)
+            .map_or("iso8601", |&(identifier, _)| identifier)
+    }
+
+    /// Abstract operation `CanonicalizeCalendar ( id )`
+    ///
+    /// Returns `None` if the calendar is not supported.
+    pub(crate) fn from_identifier(identifier: &str) -> Option<Self> {
+        CALENDARS
+            .iter()
+            .find(|(name, _)| identifier.eq_ignore_ascii_case(name))
+            .map(|&(_, calendar)| calendar)
+    }
+
+    /// Gets the calendar of an object with an `[[Calendar]]` internal slot.
+    fn from_temporal_object(object: &JsObject) -> Option<Self> {
+        let object = object.borrow();
+        object
+            .as_plain_date()
+            .map(|date| date.calendar())
+            .or_else(|| {
+                object
+                    .as_plain_date_time()
+                    .map(|date_time| date_time.calendar())
+            })
+            .or_else(|| {
+                object
+                    .as_zoned_date_time()
+                    .map(|date_time| date_time.calendar())
+            })
+    }
+
+    /// Abstract operation `ToTemporalCalendarIdentifier ( temporalCalendarLike )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporalcalendaridentifier
+    pub(crate) fn from_value(calendar_like: &JsValue, context: &mut Context) -> JsResult<Self> {
+        let identifier = match calendar_like {
+            // 1. If temporalCalendarLike is an Object, then
+            //     a. If temporalCalendarLike has an [[InitializedTemporalDate]], ... internal slot, then
+            //         i. Return temporalCalendarLike.[[Calendar]].
+            JsValue::Object(object) => match Self::from_temporal_object(object) {
+                Some(calendar) => return Ok(calendar),
+                None => return context.throw_type_error("a calendar must be a string"),
+            },
+            JsValue::String(identifier) => identifier,
+            // 2. If temporalCalendarLike is not a String, throw a TypeError exception.
+            _ => return context.throw_type_error("a calendar must be a string"),
+        };
+
+        // 3. Let identifier be ? ParseTemporalCalendarString(temporalCalendarLike).
+        // 4. Return ? CanonicalizeCalendar(identifier).
+        let calendar = Self::from_identifier(identifier).or_else(|| {
+            let parsed = parse_date_time(identifier).ok()?;
+            parsed.calendar.map_or(Some(Self::Iso8601), |calendar| {
+                Self::from_identifier(&calendar)
+            })
+        });
+        calendar.map_or_else(
+            || context.throw_range_error(format!("unsupported calendar `{identifier}`")),
+            Ok,
+        )
+    }
+
+    /// Abstract operation `GetTemporalCalendarIdentifierWithISODefault ( item )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-gettemporalcalendarslotvaluewithisodefault
+    pub(crate) fn from_fields_object(item: &JsObject, context: &mut Context) -> JsResult<Self> {
+        // 1. If item has an [[InitializedTemporalDate]], ... internal slot, then
+        //     a. Return item.[[Calendar]].
+        if let Some(calendar) = Self::from_temporal_object(item) {
+            return Ok(calendar);
+        }
+
+        // 2. Let calendarLike be ? Get(item, "calendar").
+        let calendar_like = item.get("calendar", context)?;
+
+        // 3. If calendarLike is undefined, then
+        //     a. Return "iso8601".
+        if calendar_like.is_undefined() {
+            return Ok(Self::Iso8601);
+        }
+
+        // 4. Return ? ToTemporalCalendarIdentifier(calendarLike).
+        Self::from_value(&calendar_like, context)
+    }
+
+    /// Abstract operation `CanonicalizeCalendar ( id )`, for the `calendar` argument of the
+    /// constructors, which must be a calendar identifier.
+    pub(crate) fn from_constructor_argument(
+        calendar: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // a. If calendar is undefined, set calendar to "iso8601".
+        // b. If calendar is not a String, throw a TypeError exception.
+        // c. Set calendar to ? CanonicalizeCalendar(calendar).
+        match calendar {
+            JsValue::Undefined => Ok(Self::Iso8601),
+            JsValue::String(identifier) => Self::from_identifier(identifier).map_or_else(
+                || context.throw_range_error(format!("unsupported calendar `{identifier}`")),
+                Ok,
+            ),
+            _ => context.throw_type_error("a calendar must be a string"),
+        }
+    }
+
+    /// Abstract operation `FormatCalendarAnnotation ( id, showCalendar )`
+    pub(crate) fn annotation(self, show: ShowCalendar) -> String {
+        match show {
+            // 1. If showCalendar is never, return the empty String.
+            ShowCalendar::Never => String::new(),
+            // 2. If showCalendar is auto and id is "iso8601", return the empty String.
+            ShowCalendar::Auto if self == Self::Iso8601 => String::new(),
+            // 3. If showCalendar is critical, let flag be "!"; else, let flag be the empty String.
+            // 4. Return the string-concatenation of "[", flag, "u-ca=", id, and "]".
+            ShowCalendar::Critical => format!("[!u-ca={}]", self.identifier()),
+            ShowCalendar::Auto | ShowCalendar::Always => format!("[u-ca={}]", self.identifier()),
+        }
+    }
+
+    /// Abstract operation `CalendarSupportsEra ( calendar )`
+    fn has_eras(self) -> bool {
+        self != Self::Iso8601
+    }
+
+    /// Returns `true` if the months and days of the calendar are the ones of the ISO 8601
+    /// calendar, so only the eras differ.
+    fn has_iso_months(self) -> bool {
+        matches!(self, Self::Iso8601 | Self::Gregorian | Self::Japanese)
+    }
+
+    /// Gets the ICU4X calendar backing the calendar.
+    fn icu(self) -> AnyCalendar {
+        AnyCalendar::new(match self {
+            Self::Iso8601 => AnyCalendarKind::Iso,
+            Self::Gregorian => AnyCalendarKind::Gregorian,
+            Self::Japanese => AnyCalendarKind::Japanese,
+            Self::Hebrew => AnyCalendarKind::Hebrew,
+            Self::Islamic => AnyCalendarKind::IslamicObservational,
+            Self::IslamicCivil => AnyCalendarKind::IslamicCivil,
+            Self::IslamicTabular => AnyCalendarKind::IslamicTabular,
+            Self::IslamicUmmAlQura => AnyCalendarKind::IslamicUmmAlQura,
+        })
+    }
+
+    /// Converts an ISO date to a date of the ICU4X calendar.
+    fn icu_date(date: IsoDate, calendar: &AnyCalendar) -> Date<Ref<'_, AnyCalendar>> {
+        Date::try_new_iso_date(date.year, date.month, date.day)
+            .expect("an ISO date record must be a valid ICU4X date")
+            .to_calendar(Ref(calendar))
+    }
+
+    /// Converts a date in the units of a calendar without ISO months to a date of the ICU4X
+    /// calendar.
+    ///
+    /// These calendars have a single era, in which the year is the arithmetic year.
+    fn icu_date_from_units(
+        self,
+        date: CalendarDate,
+        calendar: &AnyCalendar,
+    ) -> Date<Ref<'_, AnyCalendar>> {
+        let era = Self::icu_date(IsoDate::from_epoch_days(0), calendar)
+            .year()
+            .era;
+        let month_code = self.month_code_of(date.year, date.month);
+        let month_code = format_month_code(month_code)
+            .parse()
+            .expect("a month code must be a valid ICU4X month code");
+        Date::try_new_from_codes(
+            era,
+            date.year,
+            MonthCode(month_code),
+            date.day,
+            Ref(calendar),
+        )
+        .expect("a date in the units of the calendar must be a valid ICU4X date")
+    }
+
+    /// Abstract operation `CalendarISOToDate ( calendar, isoDate )`, for the year, the month
+    /// and the day.
+    fn to_units(self, date: IsoDate) -> CalendarDate {
+        if self.has_iso_months() {
+            return CalendarDate {
+                year: date.year,
+                month: date.month,
+                day: date.day,
+            };
+        }
+        let calendar = self.icu();
+        let date = Self::icu_date(date, &calendar);
+        CalendarDate {
+            year: date.year().number,
+            month: date.month().ordinal as u8,
+            day: date.day_of_month().0 as u8,
+        }
+    }
+
+    /// Converts a valid date in the units of the calendar to an ISO date.
+    fn iso_date_of(self, date: CalendarDate) -> IsoDate {
+        if self.has_iso_months() {
+            return IsoDate {
+                year: date.year,
+                month: date.month,
+                day: date.day,
+            };
+        }
+        let calendar = self.icu();
+        let date = self.icu_date_from_units(date, &calendar).to_iso();
+        IsoDate {
+            year: date.year().number,
+            month: date.month().ordinal as u8,
+            day: date.day_of_month().0 as u8,
+        }
+    }
+
+    /// Gets the number of months from a fixed origin to the start of `year`, to count the months
+    /// between two years.
+    fn months_before_year(self, year: i64) -> i64 {
+        match self {
+            // The leap years of the Hebrew calendar, with 13 months, are the years 3, 6, 8, 11,
+            // 14, 17 and 19 of every cycle of 19 years.
+            Self::Hebrew => (235 * year - 234).div_euclid(19),
+            _ => 12 * year,
+        }
+    }
+
+    /// Gets the number of months in a year of the calendar.
+    fn months_in_calendar_year(self, year: i32) -> u8 {
+        let year = i64::from(year);
+        (self.months_before_year(year + 1) - self.months_before_year(year)) as u8
+    }
+
+    /// Gets the number of days in a month of a year of the calendar.
+    fn days_in_calendar_month(self, year: i32, month: u8) -> u8 {
+        if self.has_iso_months() {
+            return iso_days_in_month(year, month);
+        }
+        let calendar = self.icu();
+        self.icu_date_from_units(
+            CalendarDate {
+                year,
+                month,
+                day: 1,
+            },
+            &calendar,
+        )
+        .days_in_month()
+    }
+
+    /// Gets the first day of a year of the calendar.
+    fn start_of_year(self, year: i32) -> IsoDate {
+        self.iso_date_of(CalendarDate {
+            year,
+            month: 1,
+            day: 1,
+        })
+    }
+
+    /// Gets the month code of an ordinal month of a year, as the month number and whether it's
+    /// a leap month.
+    fn month_code_of(self, year: i32, month: u8) -> (u8, bool) {
+        // The leap month of the Hebrew calendar, Adar I, is the sixth month of the leap years.
+        if self == Self::Hebrew && self.months_in_calendar_year(year) == 13 && month >= 6 {
+            return if month == 6 {
+                (5, true)
+            } else {
+                (month - 1, false)
+            };
+        }
+        (month, false)
+    }
+
+    /// Returns `true` if the calendar has the month code in any year.
+    fn is_valid_month_code(self, (month, leap): (u8, bool)) -> bool {
+        (1..=12).contains(&month) && (!leap || (self == Self::Hebrew && month == 5))
+    }
+
+    /// Gets the ordinal month of a valid month code in a year.
+    ///
+    /// If the year doesn't have the leap month, returns the following month if `overflow` is
+    /// `Constrain`, or `None` if it's `Reject`.
+    fn ordinal_month_of(self, year: i32, month_code: (u8, bool), overflow: Overflow) -> Option<u8> {
+        let leap_year = self == Self::Hebrew && self.months_in_calendar_year(year) == 13;
+        match month_code {
+            (month, true) if leap_year => Some(month + 1),
+            (month, true) => (overflow == Overflow::Constrain).then(|| month + 1),
+            (month, false) if leap_year && month >= 6 => Some(month + 1),
+            (month, false) => Some(month),
+        }
+    }
+
+    /// Balances a year and a month that may be out of the range of the months of the year.
+    ///
+    /// Returns `None` if the year is too far out of range to be represented.
+    fn balance_year_month(self, year: i64, month: i64) -> Option<(i32, u8)> {
+        let index = self.months_before_year(year) + month - 1;
+        let mut year = match self {
+            Self::Hebrew => (19 * index).div_euclid(235) + 1,
+            _ => index.div_euclid(12),
+        };
+        while self.months_before_year(year) > index {
+            year -= 1;
+        }
+        while self.months_before_year(year + 1) <= index {
+            year += 1;
+        }
+        if year.abs() > 1_000_000 {
+            return None;
+        }
+        let month = index - self.months_before_year(year) + 1;
+        Some((year as i32, month as u8))
+    }
+
+    /// Regulates a possibly invalid month and day of a year of the calendar, as
+    /// `RegulateISODate` does for the ISO 8601 calendar.
+    ///
+    /// Returns `None` if the date is invalid and `overflow` is `Reject`.
+    fn regulate(self, year: f64, month: f64, day: f64, overflow: Overflow) -> Option<CalendarDate> {
+        let year = year.clamp(-1_000_000.0, 1_000_000.0) as i32;
+        let months = f64::from(self.months_in_calendar_year(year));
+        if overflow == Overflow::Reject && !(1.0..=months).contains(&month) {
+            return None;
+        }
+        let month = month.clamp(1.0, months) as u8;
+        let days = f64::from(self.days_in_calendar_month(year, month));
+        if overflow == Overflow::Reject && !(1.0..=days).contains(&day) {
+            return None;
+        }
+        let day = day.clamp(1.0, days) as u8;
+        Some(CalendarDate { year, month, day })
+    }
+
+    /// Gets the arithmetic year of a year of an era.
+    ///
+    /// Returns `None` if the calendar doesn't have the era.
+    fn year_of_era(self, era: &str, era_year: f64) -> Option<f64> {
+        match (self, era) {
+            (Self::Iso8601, _) => None,
+            (Self::Gregorian | Self::Japanese, "ce") => Some(era_year),
+            (Self::Gregorian | Self::Japanese, "bce") => Some(1.0 - era_year),
+            (Self::Gregorian, _) => None,
+            (Self::Japanese, _) => JAPANESE_ERAS
+                .iter()
+                .find(|(name, _)| *name == era)
+                .map(|(_, start)| f64::from(*start) + era_year - 1.0),
+            _ => (self.era(IsoDate::from_epoch_days(0)).as_deref() == Some(era)).then(|| era_year),
+        }
+    }
+
+    /// Abstract operation `CalendarDateFromFields ( calendar, fields, overflow )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-calendardatefromfields
+    pub(crate) fn date_from_fields(
+        self,
+        fields: &Fields,
+        overflow: Overflow,
+        context: &mut Context,
+    ) -> JsResult<IsoDate> {
+        // 1. Perform ? CalendarResolveFields(calendar, fields, date).
+        let year = match (&fields.era, fields.era_year) {
+            (Some(era), Some(era_year)) => match self.year_of_era(era, era_year) {
+                Some(year) if fields.year.map_or(true, |y| y as i64 == year as i64) => Some(year),
+                Some(_) => {
+                    return context.throw_range_error("the `year` and `eraYear` fields differ")
+                }
+                None => return context.throw_range_error(format!("invalid era `{era}`")),
+            },
+            (None, None) => fields.year,
+            _ => {
+                return context
+                    .throw_type_error("the `era` and `eraYear` fields must be given together")
+            }
+        };
+        let (year, day) = match (year, fields.day) {
+            (Some(year), Some(day)) => (year, day),
+            (None, _) => return context.throw_type_error("the `year` field is required"),
+            (_, None) => return context.throw_type_error("the `day` field is required"),
+        };
+        let month = match (fields.month, fields.month_code) {
+            (None, None) => {
+                return context.throw_type_error("the `month` or `monthCode` field is required")
+            }
+            (month, Some(month_code)) => {
+                if !self.is_valid_month_code(month_code) {
+                    return context.throw_range_error(format!(
+                        "invalid `monthCode` for the {} calendar",
+                        self.identifier()
+                    ));
+                }
+                let clamped_year = year.clamp(-1_000_000.0, 1_000_000.0) as i32;
+                let ordinal = match self.ordinal_month_of(clamped_year, month_code, overflow) {
+                    Some(ordinal) => ordinal,
+                    None => {
+                        return context.throw_range_error(format!(
+                            "the year {year} doesn't have the month `{}`",
+                            format_month_code(month_code)
+                        ))
+                    }
+                };
+                if month.map_or(false, |month| month as i64 != i64::from(ordinal)) {
+                    return context.throw_range_error("the `month` and `monthCode` fields differ");
+                }
+                f64::from(ordinal)
+            }
+            (Some(month), None) => month,
+        };
+
+        // 2. Let result be ? CalendarDateToISO(calendar, fields, overflow).
+        // 3. If ISODateWithinLimits(result) is false, throw a RangeError exception.
+        // 4. Return result.
+        let date = if self == Self::Iso8601 {
+            IsoDate::regulate(year, month, day, overflow)
+        } else {
+            self.regulate(year, month, day, overflow)
+                .map(|date| self.iso_date_of(date))
+        };
+        match date {
+            Some(date) if date.is_within_limits() => Ok(date),
+            Some(_) => context.throw_range_error("the date is out of range"),
+            None => context.throw_range_error("the date is invalid"),
+        }
+    }
+
+    /// Abstract operation `CalendarDateAdd ( calendar, isoDate, duration, overflow )`
+    ///
+    /// `duration` is the years, months, weeks and days to add. Returns `None` if the result is
+    /// invalid or out of range.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-calendardateadd
+    pub(crate) fn date_add(
+        self,
+        date: IsoDate,
+        duration: [i64; 4],
+        overflow: Overflow,
+    ) -> Option<IsoDate> {
+        let [years, months, weeks, days] = duration;
+        let result = if self.has_iso_months() {
+            date.add(years, months, weeks, days, overflow)?
+        } else {
+            self.non_iso_date_add(date, duration, overflow)?
+        };
+        result.is_within_limits().then(|| result)
+    }
+
+    /// Abstract operation `NonISODateAdd ( calendar, isoDate, duration, overflow )`
+    ///
+    /// The years are added first, keeping the month code of the date, then the months, then
+    /// the weeks and days.
+    fn non_iso_date_add(
+        self,
+        date: IsoDate,
+        duration: [i64; 4],
+        overflow: Overflow,
+    ) -> Option<IsoDate> {
+        let [years, months, weeks, days] = duration;
+        let date = self.to_units(date);
+
+        let year = i64::from(date.year) + years;
+        if year.abs() > 1_000_000 {
+            return None;
+        }
+        let month_code = self.month_code_of(date.year, date.month);
+        let month = self.ordinal_month_of(year as i32, month_code, overflow)?;
+
+        let (year, month) = self.balance_year_month(year, i64::from(month) + months)?;
+        let intermediate = self.regulate(
+            f64::from(year),
+            f64::from(month),
+            f64::from(date.day),
+            overflow,
+        )?;
+        let intermediate = self.iso_date_of(intermediate);
+
+        let days = i128::from(intermediate.epoch_days()) + i128::from(weeks) * 7 + i128::from(days);
+        if days.abs() > 1_000_000_000 {
+            return None;
+        }
+        Some(IsoDate::from_epoch_days(days as i64))
+    }
+
+    /// Abstract operation `CalendarDateUntil ( calendar, one, two, largestUnit )`
+    ///
+    /// Returns the years, months, weeks and days from `one` to `two`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-calendardateuntil
+    pub(crate) fn date_until(
+        self,
+        one: IsoDate,
+        two: IsoDate,
+        largest_unit: TemporalUnit,
+    ) -> [i64; 4] {
+        if self.has_iso_months() {
+            one.difference(two, largest_unit)
+        } else {
+            self.non_iso_date_until(one, two, largest_unit)
+        }
+    }
+
+    /// Abstract operation `NonISODateUntil ( calendar, one, two, largestUnit )`
+    ///
+    /// As for the ISO 8601 calendar, the years and months are the largest ones that don't go
+    /// past `two` when added to `one`, as computed by `NonISODateSurpasses`.
+    fn non_iso_date_until(
+        self,
+        one: IsoDate,
+        two: IsoDate,
+        largest_unit: TemporalUnit,
+    ) -> [i64; 4] {
+        let sign: i64 = match two.cmp(&one) {
+            Ordering::Less => -1,
+            Ordering::Equal => return [0; 4],
+            Ordering::Greater => 1,
+        };
+        let surpasses = |ordering: Ordering| match ordering {
+            Ordering::Less => sign < 0,
+            Ordering::Equal => false,
+            Ordering::Greater => sign > 0,
+        };
+        let start = self.to_units(one);
+        let end = self.to_units(two);
+        let month_code = self.month_code_of(start.year, start.month);
+        let end_with_code = (
+            i64::from(end.year),
+            self.month_code_of(end.year, end.month),
+            end.day,
+        );
+        let end = (i64::from(end.year), end.month, end.day);
+
+        // The ordinal month of the month code of `one` in a year.
+        let month_in_year = |year: i64| {
+            self.ordinal_month_of(year as i32, month_code, Overflow::Constrain)
+                .expect("constraining a month code must not fail")
+        };
+
+        let mut years = 0;
+        if largest_unit == TemporalUnit::Year {
+            years = end.0 - i64::from(start.year);
+            let surpasses_years = |years: i64| {
+                let year = i64::from(start.year) + years;
+                surpasses((year, month_code, start.day).cmp(&end_with_code))
+                    || surpasses((year, month_in_year(year), start.day).cmp(&end))
+            };
+            while years != 0 && surpasses_years(years) {
+                years -= sign;
+            }
+        }
+
+        let year = i64::from(start.year) + years;
+        let month = i64::from(month_in_year(year));
+        let mut months = 0;
+        if largest_unit <= TemporalUnit::Month {
+            months = (self.months_before_year(end.0) + i64::from(end.1))
+                - (self.months_before_year(year) + month);
+            let surpasses_months = |months: i64| {
+                let (y, m) = self
+                    .balance_year_month(year, month + months)
+                    .expect("the months between two dates must be in range");
+                surpasses((i64::from(y), m, start.day).cmp(&end))
+            };
+            while months != 0 && surpasses_months(months) {
+                months -= sign;
+            }
+        }
+
+        let (y, m) = self
+            .balance_year_month(year, month + months)
+            .expect("the months between two dates must be in range");
+        let constrained = self
+            .regulate(
+                f64::from(y),
+                f64::from(m),
+                f64::from(start.day),
+                Overflow::Constrain,
+            )
+            .expect("constraining a date must not fail");
+        let constrained = self.iso_date_of(constrained);
+
+        let mut days = two.epoch_days() - constrained.epoch_days();
+        if days.signum() == -sign {
+            days = 0;
+        }
+        let mut weeks = 0;
+        if largest_unit == TemporalUnit::Week {
+            weeks = days / 7;
+            days %= 7;
+        }
+
+        [years, months, weeks, days]
+    }
+
+    /// Abstract operation `CalendarMergeFields ( calendar, fields, additionalFields )`
+    pub(crate) fn merge_fields(self, fields: &Fields, additional_fields: &Fields) -> Fields {
+        let mut merged = fields.clone();
+        merged.override_with(additional_fields);
+
+        // The `month` and `monthCode` fields replace each other.
+        match (additional_fields.month, additional_fields.month_code) {
+            (Some(_), None) => merged.month_code = None,
+            (None, Some(_)) => merged.month = None,
+            _ => {}
+        }
+
+        // The `year`, `era` and `eraYear` fields replace each other.
+        if self.has_eras()
+            && (additional_fields.year.is_some()
+                || additional_fields.era.is_some()
+                || additional_fields.era_year.is_some())
+        {
+            merged.year = additional_fields.year;
+            merged.era = additional_fields.era.clone();
+            merged.era_year = additional_fields.era_year;
+        }
+
+        // The era of a Japanese date depends on its month and day.
+        if self == Self::Japanese
+            && (additional_fields.month.is_some()
+                || additional_fields.month_code.is_some()
+                || additional_fields.day.is_some())
+        {
+            merged.era = additional_fields.era.clone();
+            merged.era_year = additional_fields.era_year;
+        }
+        merged
+    }
+
+    /// Gets the fields of a date as a `Fields` record.
+    pub(crate) fn fields_of(self, date: IsoDate) -> Fields {
+        let units = self.to_units(date);
+        Fields {
+            year: Some(f64::from(units.year)),
+            month: Some(f64::from(units.month)),
+            month_code: Some(self.month_code_of(units.year, units.month)),
+            day: Some(f64::from(units.day)),
+            ..Fields::default()
+        }
+    }
+
+    /// Gets the era of a date, if the calendar has eras.
+    pub(crate) fn era(self, date: IsoDate) -> Option<String> {
+        self.has_eras()
+            .then(|| Self::icu_date(date, &self.icu()).year().era.0.to_string())
+    }
+
+    /// Gets the year of a date in its era, if the calendar has eras.
+    pub(crate) fn era_year(self, date: IsoDate) -> Option<i32> {
+        self.has_eras()
+            .then(|| Self::icu_date(date, &self.icu()).year().number)
+    }
+
+    /// Gets the year of a date.
+    pub(crate) fn year(self, date: IsoDate) -> i32 {
+        self.to_units(date).year
+    }
+
+    /// Gets the ordinal month of a date.
+    pub(crate) fn month(self, date: IsoDate) -> u8 {
+        self.to_units(date).month
+    }
+
+    /// Gets the month code of a date, such as `M01` or `M05L`.
+    pub(crate) fn month_code(self, date: IsoDate) -> String {
+        let units = self.to_units(date);
+        format_month_code(self.month_code_of(units.year, units.month))
+    }
+
+    /// Gets the day of the month of a date.
+    pub(crate) fn day(self, date: IsoDate) -> u8 {
+        self.to_units(date).day
+    }
+
+    /// Gets the day of the year of a date, from 1.
+    pub(crate) fn day_of_year(self, date: IsoDate) -> u16 {
+        let start = self.start_of_year(self.year(date));
+        (date.epoch_days() - start.epoch_days()) as u16 + 1
+    }
+
+    /// Gets the ISO week number of a date, and the year of that week, if the calendar has the
+    /// ISO weeks.
+    pub(crate) fn week_of_year(self, date: IsoDate) -> Option<(u8, i32)> {
+        if !self.has_iso_months() {
+            return None;
+        }
+
+        // The week with the first Thursday of the year is the first week of the year, and the
+        // week of a date is the week of its Thursday.
+        let thursday = date.epoch_days() - i64::from(day_of_week(date)) + 4;
+        let year_of_week = IsoDate::from_epoch_days(thursday).year;
+        let week = (thursday - epoch_days_from_iso(year_of_week, 1, 1)) / 7 + 1;
+        Some((week as u8, year_of_week))
+    }
+
+    /// Gets the number of days in the month of a date.
+    pub(crate) fn days_in_month(self, date: IsoDate) -> u8 {
+        let units = self.to_units(date);
+        self.days_in_calendar_month(units.year, units.month)
+    }
+
+    /// Gets the number of days in the year of a date.
+    pub(crate) fn days_in_year(self, date: IsoDate) -> u16 {
+        let year = self.year(date);
+        (self.start_of_year(year + 1).epoch_days() - self.start_of_year(year).epoch_days()) as u16
+    }
+
+    /// Gets the number of months in the year of a date.
+    pub(crate) fn months_in_year(self, date: IsoDate) -> u8 {
+        self.months_in_calendar_year(self.year(date))
+    }
+
+    /// Returns `true` if the year of a date is a leap year.
+    pub(crate) fn in_leap_year(self, date: IsoDate) -> bool {
+        match self {
+            Self::Iso8601 | Self::Gregorian | Self::Japanese => {
+                iso_days_in_month(date.year, 2) == 29
+            }
+            Self::Hebrew => self.months_in_year(date) == 13,
+            Self::Islamic | Self::IslamicCivil | Self::IslamicTabular | Self::IslamicUmmAlQura => {
+                self.days_in_year(date) == 355
+            }
+        }
+    }
+}
+
+/// Gets the day of the week of a date, from 1 for Monday to 7 for Sunday.
+///
+/// All the calendars have the same 7-day weeks.
+fn day_of_week(date: IsoDate) -> u8 {
+    // The epoch was a Thursday.
+    (date.epoch_days() + 3).rem_euclid(7) as u8 + 1
+}
+
+/// Formats a month code, given as the month number and whether it's a leap month.
+fn format_month_code((month, leap): (u8, bool)) -> String {
+    format!("M{month:02}{}", if leap { "L" } else { "" })
+}
+
+/// The getters of the date fields of `Temporal.PlainDate` and `Temporal.PlainDateTime`.
+pub(crate) const DATE_GETTERS: [(&str, fn(Calendar, IsoDate) -> JsValue); 15] = [
+    ("era", |calendar, date| {
+        calendar.era(date).map_or(JsValue::undefined(), Into::into)
+    }),
+    ("eraYear", |calendar, date| {
+        calendar
+            .era_year(date)
+            .map_or(JsValue::undefined(), Into::into)
+    }),
+    ("year", |calendar, date| calendar.year(date).into()),
+    ("month", |calendar, date| calendar.month(date).into()),
+    ("monthCode", |calendar, date| {
+        calendar.month_code(date).into()
+    }),
+    ("day", |calendar, date| calendar.day(date).into()),
+    ("dayOfWeek", |_, date| day_of_week(date).into()),
+    ("dayOfYear", |calendar, date| {
+        calendar.day_of_year(date).into()
+    }),
+    ("weekOfYear", |calendar, date| {
+        calendar
+            .week_of_year(date)
+            .map_or(JsValue::undefined(), |(week, _)| week.into())
+    }),
+    ("yearOfWeek", |calendar, date| {
+        calendar
+            .week_of_year(date)
+            .map_or(JsValue::undefined(), |(_, year)| year.into())
+    }),
+    ("daysInWeek", |_, _| 7.into()),
+    ("daysInMonth", |calendar, date| {
+        calendar.days_in_month(date).into()
+    }),
+    ("daysInYear", |calendar, date| {
+        calendar.days_in_year(date).into()
+    }),
+    ("monthsInYear", |calendar, date| {
+        calendar.months_in_year(date).into()
+    }),
+    ("inLeapYear", |calendar, date| {
+        calendar.in_leap_year(date).into()
+    }),
+];
+
+/// The `calendarName` option of the `toString` methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ShowCalendar {
+    Auto,
+    Always,
+    Never,
+    Critical,
+}
+
+impl ShowCalendar {
+    /// Abstract operation `GetTemporalShowCalendarNameOption ( options )`
+    pub(crate) fn from_options(options: &JsObject, context: &mut Context) -> JsResult<Self> {
+        let show = get_option(
+            options,
+            "calendarName",
+            &["auto", "always", "never", "critical"],
+            context,
+        )?;
+        Ok(match show {
+            Some("always") => Self::Always,
+            Some("never") => Self::Never,
+            Some("critical") => Self::Critical,
+            _ => Self::Auto,
+        })
+    }
+}
+
+/// The date and time fields of a Temporal object, as given by the user.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Fields {
+    pub(crate) era: Option<String>,
+    pub(crate) era_year: Option<f64>,
+    pub(crate) year: Option<f64>,
+    pub(crate) month: Option<f64>,
+    /// The month number and whether it's a leap month.
+    pub(crate) month_code: Option<(u8, bool)>,
+    pub(crate) day: Option<f64>,
+    pub(crate) hour: Option<f64>,
+    pub(crate) minute: Option<f64>,
+    pub(crate) second: Option<f64>,
+    pub(crate) millisecond: Option<f64>,
+    pub(crate) microsecond: Option<f64>,
+    pub(crate) nanosecond: Option<f64>,
+    /// The UTC offset, in nanoseconds.
+    pub(crate) offset: Option<i64>,
+    pub(crate) time_zone: Option<TimeZone>,
+}
+
+/// The kinds of the fields of a Temporal object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldKind {
+    Date,
+    Era,
+    Time,
+    Offset,
+    TimeZone,
+}
+
+impl Fields {
+    /// Abstract operation `PrepareCalendarFields ( calendar, fields, calendarFieldNames, nonCalendarFieldNames, requiredFieldNames )`
+    ///
+    /// Reads the date fields of `calendar` if it's not `None`, and the time fields if `time` is
+    /// `true`, in alphabetical order. Returns `None` if all the fields are undefined.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-preparecalendarfields
+    pub(crate) fn read(
+        object: &JsObject,
+        calendar: Option<Calendar>,
+        time: bool,
+        context: &mut Context,
+    ) -> JsResult<Option<Self>> {
+        Self::read_with(
+            object,
+            |kind| match kind {
+                FieldKind::Date => calendar.is_some(),
+                FieldKind::Era => calendar.map_or(false, Calendar::has_eras),
+                FieldKind::Time => time,
+                FieldKind::Offset | FieldKind::TimeZone => false,
+            },
+            context,
+        )
+    }
+
+    /// Abstract operation `PrepareCalendarFields`, for the fields of a `Temporal.ZonedDateTime`.
+    ///
+    /// Reads the date fields of `calendar`, the time fields and the `offset` field, and the
+    /// `timeZone` field if `time_zone` is `true`, in alphabetical order. Returns `None` if all
+    /// the fields are undefined.
+    pub(crate) fn read_zoned(
+        object: &JsObject,
+        calendar: Calendar,
+        time_zone: bool,
+        context: &mut Context,
+    ) -> JsResult<Option<Self>> {
+        Self::read_with(
+            object,
+            |kind| match kind {
+                FieldKind::Era => calendar.has_eras(),
+                FieldKind::TimeZone => time_zone,
+                FieldKind::Date | FieldKind::Time | FieldKind::Offset => true,
+            },
+            context,
+        )
+    }
+
+    /// Reads the fields of the kinds for which `include` returns `true`, in alphabetical order.
+    fn read_with(
+        object: &JsObject,
+        include: impl Fn(FieldKind) -> bool,
+        context: &mut Context,
+    ) -> JsResult<Option<Self>> {
+        /// The fields, in alphabetical order, with their kind.
+        const FIELDS: [(&str, FieldKind); 14] = [
+            ("day", FieldKind::Date),
+            ("era", FieldKind::Era),
+            ("eraYear", FieldKind::Era),
+            ("hour", FieldKind::Time),
+            ("microsecond", FieldKind::Time),
+            ("millisecond", FieldKind::Time),
+            ("minute", FieldKind::Time),
+            ("month", FieldKind::Date),
+            ("monthCode", FieldKind::Date),
+            ("nanosecond", FieldKind::Time),
+            ("offset", FieldKind::Offset),
+            ("second", FieldKind::Time),
+            ("timeZone", FieldKind::TimeZone),
+            ("year", FieldKind::Date),
+        ];
+
+        let mut fields = Self::default();
+        let mut any = false;
+        for (name, kind) in FIELDS {
+            if !include(kind) {
+                continue;
+            }
+            let value = object.get(name, context)?;
+            if value.is_undefined() {
+                continue;
+            }
+            any = true;
+            match name {
+                "day" => fields.day = Some(to_positive_integer_with_truncation(&value, context)?),
+                "era" => fields.era = Some(value.to_string(context)?.to_string()),
+                "eraYear" => fields.era_year = Some(to_integer_with_truncation(&value, context)?),
+                "month" => {
+                    fields.month = Some(to_positive_integer_with_truncation(&value, context)?);
+                }
+                "monthCode" => fields.month_code = Some(to_month_code(&value, context)?),
+                "year" => fields.year = Some(to_integer_with_truncation(&value, context)?),
+                "hour" => fields.hour = Some(to_integer_with_truncation(&value, context)?),
+                "minute" => fields.minute = Some(to_integer_with_truncation(&value, context)?),
+                "second" => fields.second = Some(to_integer_with_truncation(&value, context)?),
+                "millisecond" => {
+                    fields.millisecond = Some(to_integer_with_truncation(&value, context)?);
+                }
+                "microsecond" => {
+                    fields.microsecond = Some(to_integer_with_truncation(&value, context)?);
+                }
+                "offset" => fields.offset = Some(to_offset(&value, context)?),
+                "timeZone" => fields.time_zone = Some(TimeZone::from_value(&value, context)?),
+                _ => fields.nanosecond = Some(to_integer_with_truncation(&value, context)?),
+            }
+        }
+
+        Ok(any.then(|| fields))
+    }
+
+    /// Replaces the fields with the ones defined in `other`.
+    pub(crate) fn override_with(&mut self, other: &Self) {
+        fn replace<T: Copy>(field: &mut Option<T>, other: Option<T>) {
+            if other.is_some() {
+                *field = other;
+            }
+        }
+
+        if other.era.is_some() {
+            self.era = other.era.clone();
+        }
+        replace(&mut self.era_year, other.era_year);
+        replace(&mut self.year, other.year);
+        replace(&mut self.month, other.month);
+        replace(&mut self.month_code, other.month_code);
+        replace(&mut self.day, other.day);
+        replace(&mut self.hour, other.hour);
+        replace(&mut self.minute, other.minute);
+        replace(&mut self.second, other.second);
+        replace(&mut self.millisecond, other.millisecond);
+        replace(&mut self.microsecond, other.microsecond);
+        replace(&mut self.nanosecond, other.nanosecond);
+        replace(&mut self.offset, other.offset);
+        if other.time_zone.is_some() {
+            self.time_zone = other.time_zone.clone();
+        }
+    }
+
+    /// Gets the time fields, from the hour to the nanosecond, with `0` for the undefined ones.
+    pub(crate) fn time(&self) -> [f64; 6] {
+        [
+            self.hour,
+            self.minute,
+            self.second,
+            self.millisecond,
+            self.microsecond,
+            self.nanosecond,
+        ]
+        .map(Option::unwrap_or_default)
+    }
+}
+
+/// Abstract operation `ToOffsetString ( argument )`
+///
+/// Returns the offset in nanoseconds.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-tooffsetstring
+fn to_offset(argument: &JsValue, context: &mut Context) -> JsResult<i64> {
+    // 1. Let offset be ? ToPrimitive(argument, string).
+    let offset = argument.to_primitive(context, PreferredType::String)?;
+
+    // 2. If offset is not a String, throw a TypeError exception.
+    let offset = match offset {
+        JsValue::String(offset) => offset,
+        _ => return context.throw_type_error("an offset must be a string"),
+    };
+
+    // 3. Perform ? ParseDateTimeUTCOffset(offset).
+    // 4. Return offset.
+    match parse_utc_offset(&offset) {
+        Ok(nanoseconds) => Ok(nanoseconds),
+        Err(error) => context.throw_range_error(format!("invalid offset `{offset}`: {error}")),
+    }
+}
+
+/// Abstract operation `ToMonthCode ( argument )`
+///
+/// Returns the month number and whether it's a leap month.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-tomonthcode
+fn to_month_code(argument: &JsValue, context: &mut Context) -> JsResult<(u8, bool)> {
+    // 1. Let monthCode be ? ToPrimitive(argument, string).
+    let month_code = argument.to_primitive(context, PreferredType::String)?;
+
+    // 2. If monthCode is not a String, throw a TypeError exception.
+    let month_code = match month_code {
+        JsValue::String(month_code) => month_code,
+        _ => return context.throw_type_error("a month code must be a string"),
+    };
+
+    // 3. If the length of monthCode is not 3 or 4, throw a RangeError exception.
+    // 4. If the first code unit of monthCode is not 0x004D (LATIN CAPITAL LETTER M), throw a RangeError exception.
+    // 5. If the second and third code units of monthCode are not ASCII digits, throw a RangeError exception.
+    // 6. If the length of monthCode is 4 and the fourth code unit of monthCode is not 0x004C (LATIN CAPITAL LETTER L), throw a RangeError exception.
+    // 7. Let monthCodeDigits be the substring of monthCode from 1 to 3.
+    // 8. Let monthCodeInteger be ℝ(StringToNumber(monthCodeDigits)).
+    // 9. If monthCodeInteger is 0 and the length of monthCode is not 4, throw a RangeError exception.
+    let parsed = match month_code.as_bytes() {
+        [b'M', tens @ b'0'..=b'9', units @ b'0'..=b'9', rest @ ..]
+            if rest.is_empty() || rest == b"L" =>
+        {
+            let month = (tens - b'0') * 10 + units - b'0';
+            let leap = !rest.is_empty();
+            (month != 0 || leap).then(|| (month, leap))
+        }
+        _ => None,
+    };
+
+    // 10. Return monthCode.
+    parsed.map_or_else(
+        || context.throw_range_error(format!("invalid month code `{month_code}`")),
+        Ok,
+    )
+}
@@ -0,0 +1,1408 @@
+//! This module implements the global `Temporal.Duration` object.
+//!
+//! A `Temporal.Duration` is a length of time, made of years, months, weeks, days, hours, minutes,
+//! seconds, milliseconds, microseconds and nanoseconds. The units are not balanced, so
+//! `PT90M` and `PT1H30M` are distinct durations, until they are balanced by `round`. The
+//! lengths of the years, months and weeks, and of the days in a time zone, depend on a
+//! `relativeTo` date.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-duration-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Duration
+
+#[cfg(test)]
+mod tests;
+
+use super::{
+    calendar::{Calendar, Fields},
+    format_fraction, get_largest_unit, get_round_to_options, get_rounding_increment,
+    get_temporal_unit,
+    plain_date::IsoDate,
+    plain_date_time::{
+        difference_plain_date_time_with_rounding, difference_plain_date_time_with_total,
+        interpret_fields, InternalDuration, IsoDateTime,
+    },
+    plain_time::{ordering_to_value, IsoTime},
+    round_to_increment,
+    time_zone::{Disambiguation, OffsetBehaviour, OffsetOption, TimeZone, NS_PER_MINUTE},
+    total_time_duration, validate_rounding_increment,
+    zoned_date_time::{
+        add_zoned_date_time, difference_zoned_date_time_with_rounding,
+        difference_zoned_date_time_with_total,
+    },
+    DifferenceSettings, Overflow, PlainDate, Precision, RoundingMode, SecondsStringPrecision,
+    TemporalUnit, UnitGroup, ZonedDateTime, NS_PER_DAY,
+};
+use crate::{
+    builtins::{
+        intl::{duration_format::DurationRecord, get_options_object},
+        iso8601::{parse_date_time, parse_duration, UtcOffset},
+        BuiltIn, JsArgs,
+    },
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use tap::{Conv, Pipe};
+
+/// The internal slots of a `Temporal.Duration` object.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Trace, Finalize)]
+pub struct Duration {
+    /// `[[Years]]`, `[[Months]]`, `[[Weeks]]`, `[[Days]]`, `[[Hours]]`, `[[Minutes]]`,
+    /// `[[Seconds]]`, `[[Milliseconds]]`, `[[Microseconds]]` and `[[Nanoseconds]]`.
+    #[unsafe_ignore_trace]
+    values: [f64; 10],
+}
+
+impl BuiltIn for Duration {
+    const NAME: &'static str = "Duration";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let getters = TemporalUnit::ALL.map(|unit| {
+            let getter = FunctionBuilder::closure(context, move |this, _, context| {
+                Ok(Self::this_duration(this, context)?.get(unit).into())
+            })
+            .name(format!("get {}", unit.plural()))
+            .length(0)
+            .constructor(false)
+            .build();
+            (unit.plural(), getter)
+        });
+        let get_sign = FunctionBuilder::native(context, Self::get_sign)
+            .name("get sign")
+            .length(0)
+            .constructor(false)
+            .build();
+        let get_blank = FunctionBuilder::native(context, Self::get_blank)
+            .name("get blank")
+            .length(0)
+            .constructor(false)
+            .build();
+
+        let mut constructor = ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().duration().clone(),
+        );
+        constructor
+            .name(Self::NAME)
+            .length(Self::LENGTH)
+            .static_method(Self::from, "from", 1)
+            .static_method(Self::compare, "compare", 2);
+        for (name, getter) in getters {
+            constructor.accessor(name, Some(getter), None, Attribute::CONFIGURABLE);
+        }
+        constructor
+            .accessor("sign", Some(get_sign), None, Attribute::CONFIGURABLE)
+            .accessor("blank", Some(get_blank), None, Attribute::CONFIGURABLE)
+            .method(Self::with, "with", 1)
+            .method(Self::negated, "negated", 0)
+            .method(Self::abs, "abs", 0)
+            .method(Self::add, "add", 1)
+            .method(Self::subtract, "subtract", 1)
+            .method(Self::round, "round", 1)
+            .method(Self::total, "total", 1)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_json, "toJSON", 0)
+            .method(Self::to_locale_string, "toLocaleString", 0)
+            .method(Self::value_of, "valueOf", 0)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                "Temporal.Duration",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build()
+            .conv::<JsValue>()
+            .pipe(Some)
+    }
+}
+
+impl Duration {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 0;
+
+    /// Creates a duration from the values of its units, from the largest to the smallest.
+    pub(crate) fn new(values: [f64; 10]) -> Self {
+        // Adding `+0` turns `-0` into `0`.
+        Self {
+            values: values.map(|value| value + 0.0),
+        }
+    }
+
+    /// Creates a duration from its years, months, weeks and days, and from its time in
+    /// nanoseconds, balanced up to `largest_unit`, or up to days if it's a calendar unit.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-temporaldurationfrominternal
+    pub(crate) fn from_parts(
+        date: [i64; 4],
+        mut nanoseconds: i128,
+        largest_unit: TemporalUnit,
+    ) -> Self {
+        let mut values = [0.0; 10];
+        for (value, date) in values.iter_mut().zip(date) {
+            *value = date as f64;
+        }
+        for unit in &TemporalUnit::ALL[largest_unit.max(TemporalUnit::Day) as usize..] {
+            let unit_nanoseconds = unit.nanoseconds().expect("must be a day or a unit of time");
+            values[*unit as usize] += (nanoseconds / unit_nanoseconds) as f64;
+            nanoseconds %= unit_nanoseconds;
+        }
+        Self::new(values)
+    }
+
+    /// Gets the years, months, weeks and days of the duration.
+    pub(crate) fn date_part(&self) -> [i64; 4] {
+        [
+            self.values[0] as i64,
+            self.values[1] as i64,
+            self.values[2] as i64,
+            self.values[3] as i64,
+        ]
+    }
+
+    /// Gets the value of a unit of the duration.
+    pub(crate) fn get(&self, unit: TemporalUnit) -> f64 {
+        self.values[unit as usize]
+    }
+
+    /// Abstract operation `DefaultTemporalLargestUnit ( duration )`
+    fn default_largest_unit(&self) -> TemporalUnit {
+        TemporalUnit::ALL
+            .into_iter()
+            .find(|unit| self.get(*unit) != 0.0)
+            .unwrap_or(TemporalUnit::Nanosecond)
+    }
+
+    /// Abstract operation `ToInternalDurationRecord ( duration )`
+    fn internal_record(&self) -> InternalDuration {
+        (self.date_part(), self.time_nanoseconds())
+    }
+
+    /// Abstract operation `ToInternalDurationRecordWith24HourDays ( duration )`
+    fn internal_record_with_24_hour_days(&self) -> InternalDuration {
+        let [years, months, weeks, days] = self.date_part();
+        (
+            [years, months, weeks, 0],
+            self.time_nanoseconds() + i128::from(days) * NS_PER_DAY,
+        )
+    }
+
+    /// Abstract operation `DurationSign ( duration )`
+    pub(crate) fn sign(&self) -> i8 {
+        DurationRecord::new(self.values).sign()
+    }
+
+    /// Abstract operation `IsValidDuration ( years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds )`
+    pub(crate) fn is_valid(&self) -> bool {
+        DurationRecord::new(self.values).is_valid()
+    }
+
+    /// Abstract operation `CreateNegatedTemporalDuration ( duration )`
+    pub(crate) fn negated(&self) -> Self {
+        Self::new(self.values.map(|value| -value))
+    }
+
+    /// Gets the time units of the duration, from hours to nanoseconds, in nanoseconds.
+    pub(crate) fn time_nanoseconds(&self) -> i128 {
+        TemporalUnit::ALL[TemporalUnit::Hour as usize..]
+            .iter()
+            .map(|unit| {
+                self.get(*unit) as i128 * unit.nanoseconds().expect("must be a unit of time")
+            })
+            .sum()
+    }
+
+    /// Abstract operation `CreateTemporalDuration ( years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds [ , newTarget ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-createtemporalduration
+    pub(crate) fn create(
+        self,
+        new_target: Option<&JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. If IsValidDuration(years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds) is false, throw a RangeError exception.
+        if !self.is_valid() {
+            return context.throw_range_error("invalid duration");
+        }
+
+        // 2. If newTarget is not present, set newTarget to %Temporal.Duration%.
+        // 3. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.Duration.prototype%", « ... »).
+        let prototype = match new_target {
+            Some(new_target) => {
+                get_prototype_from_constructor(new_target, StandardConstructors::duration, context)?
+            }
+            None => context.intrinsics().constructors().duration().prototype(),
+        };
+
+        // 4-14. Set object.[[Years]] to ℝ(𝔽(years)), ...
+        // 15. Return object.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::duration(self),
+        ))
+    }
+
+    /// Abstract operation `ToTemporalDuration ( item )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporalduration
+    pub(crate) fn from_value(item: &JsValue, context: &mut Context) -> JsResult<Self> {
+        // 1. If item is an Object and item has an [[InitializedTemporalDuration]] internal slot, then
+        if let Some(duration) = item
+            .as_object()
+            .and_then(|obj| obj.borrow().as_duration().copied())
+        {
+            // a. Return ! CreateTemporalDuration(item.[[Years]], ...).
+            return Ok(duration);
+        }
+
+        let duration = match item {
+            // 2. If item is not an Object, then
+            //     b. Return ? ParseTemporalDurationString(item).
+            JsValue::String(string) => match parse_duration(string) {
+                Ok(values) => Self::new(values),
+                Err(error) => {
+                    return context
+                        .throw_range_error(format!("invalid duration string `{string}`: {error}"))
+                }
+            },
+            // 3. Let result be a new Partial Duration Record with each field set to 0.
+            // 4. Let partial be ? ToTemporalPartialDurationRecord(item).
+            // 5. If partial.[[Years]] is not undefined, set result.[[Years]] to partial.[[Years]].
+            // ...
+            JsValue::Object(item) => {
+                let partial = Self::to_partial(item, context)?;
+                Self::new(partial.map(|value| value.unwrap_or_default()))
+            }
+            //     a. If item is not a String, throw a TypeError exception.
+            _ => return context.throw_type_error("a duration must be a string or an object"),
+        };
+
+        // 15. Return ? CreateTemporalDuration(result.[[Years]], ...).
+        if !duration.is_valid() {
+            return context.throw_range_error("invalid duration");
+        }
+        Ok(duration)
+    }
+
+    /// Abstract operation `ToTemporalPartialDurationRecord ( temporalDurationLike )`
+    ///
+    /// Returns the value of every unit, or `None` if it's undefined.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporalpartialdurationrecord
+    fn to_partial(duration_like: &JsObject, context: &mut Context) -> JsResult<[Option<f64>; 10]> {
+        // 2. Let result be a new partial Duration Record with each field set to undefined.
+        let mut result = [None; 10];
+
+        // 3-22. The properties are read in alphabetical order.
+        let mut units = TemporalUnit::ALL;
+        units.sort_unstable_by_key(|unit| unit.plural());
+        for unit in units {
+            let value = duration_like.get(unit.plural(), context)?;
+            if !value.is_undefined() {
+                result[unit as usize] = Some(to_integer_if_integral(&value, context)?);
+            }
+        }
+
+        // 23. If years is undefined, and months is undefined, ..., throw a TypeError exception.
+        if result.iter().all(Option::is_none) {
+            return context.throw_type_error("a duration must have at least one unit");
+        }
+
+        // 24. Return result.
+        Ok(result)
+    }
+
+    /// Gets the `this` `Temporal.Duration` object.
+    fn this_duration(this: &JsValue, context: &mut Context) -> JsResult<Self> {
+        this.as_object()
+            .and_then(|obj| obj.borrow().as_duration().copied())
+            .ok_or_else(|| context.construct_type_error("'this' is not a Temporal.Duration"))
+    }
+
+    /// `Temporal.Duration ( [ years [ , months [ , weeks [ , days [ , hours [ , minutes [ , seconds [ , milliseconds [ , microseconds [ , nanoseconds ] ] ] ] ] ] ] ] ] ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Duration/Duration
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        if new_target.is_undefined() {
+            // a. Throw a TypeError exception.
+            return context.throw_type_error("Temporal.Duration constructor requires 'new'");
+        }
+
+        // 2. If years is undefined, let y be 0; else let y be ? ToIntegerIfIntegral(years).
+        // ...
+        // 11. If nanoseconds is undefined, let ns be 0; else let ns be ? ToIntegerIfIntegral(nanoseconds).
+        let mut values = [0.0; 10];
+        for (index, value) in values.iter_mut().enumerate() {
+            let arg = args.get_or_undefined(index);
+            if !arg.is_undefined() {
+                *value = to_integer_if_integral(arg, context)?;
+            }
+        }
+
+        // 12. Return ? CreateTemporalDuration(y, mo, w, d, h, m, s, ms, mis, ns, NewTarget).
+        Ok(Self::new(values).create(Some(new_target), context)?.into())
+    }
+
+    /// `Temporal.Duration.from ( item )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.from
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Duration/from
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? ToTemporalDuration(item).
+        Ok(Self::from_value(args.get_or_undefined(0), context)?
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.Duration.compare ( one, two [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.compare
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Duration/compare
+    fn compare(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set one to ? ToTemporalDuration(one).
+        let one = Self::from_value(args.get_or_undefined(0), context)?;
+
+        // 2. Set two to ? ToTemporalDuration(two).
+        let two = Self::from_value(args.get_or_undefined(1), context)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(2), context)?;
+
+        // 4. Let relativeToRecord be ? GetTemporalRelativeToOption(resolvedOptions).
+        let relative_to = RelativeTo::from_options(&options, context)?;
+
+        // 5. If one.[[Years]] = two.[[Years]], and one.[[Months]] = two.[[Months]], ..., then
+        //     a. Return +0𝔽.
+        if one == two {
+            return Ok(0.into());
+        }
+
+        // 6. Let zonedRelativeTo be relativeToRecord.[[ZonedRelativeTo]].
+        // 7. Let plainRelativeTo be relativeToRecord.[[PlainRelativeTo]].
+        // 8. Let largestUnit1 be DefaultTemporalLargestUnit(one).
+        // 9. Let largestUnit2 be DefaultTemporalLargestUnit(two).
+        let largest_unit = one.default_largest_unit().min(two.default_largest_unit());
+
+        // 10. Let duration1 be ToInternalDurationRecord(one).
+        // 11. Let duration2 be ToInternalDurationRecord(two).
+        let one = one.internal_record();
+        let two = two.internal_record();
+
+        // 12. If zonedRelativeTo is not undefined, and either TemporalUnitCategory(largestUnit1) or TemporalUnitCategory(largestUnit2) is date, then
+        if let RelativeTo::Zoned(relative_to) = &relative_to {
+            if !largest_unit.is_time() {
+                // a. Let timeZone be zonedRelativeTo.[[TimeZone]].
+                // b. Let calendar be zonedRelativeTo.[[Calendar]].
+                // c. Let after1 be ? AddZonedDateTime(zonedRelativeTo.[[EpochNanoseconds]], timeZone, calendar, duration1, constrain).
+                // d. Let after2 be ? AddZonedDateTime(zonedRelativeTo.[[EpochNanoseconds]], timeZone, calendar, duration2, constrain).
+                let mut after = |duration| {
+                    add_zoned_date_time(
+                        relative_to.epoch_nanoseconds(),
+                        relative_to.time_zone(),
+                        relative_to.calendar(),
+                        duration,
+                        Overflow::Constrain,
+                        context,
+                    )
+                };
+                let after_one = after(one)?;
+                let after_two = after(two)?;
+
+                // e. If after1 > after2, return 1𝔽.
+                // f. If after1 < after2, return -1𝔽.
+                // g. Return +0𝔽.
+                return Ok(ordering_to_value(after_one.cmp(&after_two)));
+            }
+        }
+
+        // 13. If IsCalendarUnit(largestUnit1) is true or IsCalendarUnit(largestUnit2) is true, then
+        let (days_one, days_two) = if largest_unit < TemporalUnit::Day {
+            // a. If plainRelativeTo is undefined, throw a RangeError exception.
+            let relative_to = match relative_to {
+                RelativeTo::Plain(relative_to) => relative_to,
+                _ => {
+                    return context.throw_range_error(
+                        "a relativeTo date is required to compare years, months or weeks",
+                    )
+                }
+            };
+
+            // b. Let days1 be ? DateDurationDays(duration1.[[Date]], plainRelativeTo).
+            // c. Let days2 be ? DateDurationDays(duration2.[[Date]], plainRelativeTo).
+            (
+                date_duration_days(one.0, relative_to, context)?,
+                date_duration_days(two.0, relative_to, context)?,
+            )
+        } else {
+            // 14. Else,
+            //     a. Let days1 be one.[[Days]].
+            //     b. Let days2 be two.[[Days]].
+            (one.0[3], two.0[3])
+        };
+
+        // 15. Let timeDuration1 be ? Add24HourDaysToTimeDuration(duration1.[[Time]], days1).
+        // 16. Let timeDuration2 be ? Add24HourDaysToTimeDuration(duration2.[[Time]], days2).
+        let time_one = one.1 + i128::from(days_one) * NS_PER_DAY;
+        let time_two = two.1 + i128::from(days_two) * NS_PER_DAY;
+
+        // 17. Return 𝔽(CompareTimeDuration(timeDuration1, timeDuration2)).
+        Ok(ordering_to_value(time_one.cmp(&time_two)))
+    }
+
+    /// `get Temporal.Duration.prototype.sign`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.sign
+    fn get_sign(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        // 3. Return 𝔽(DurationSign(duration)).
+        Ok(Self::this_duration(this, context)?.sign().into())
+    }
+
+    /// `get Temporal.Duration.prototype.blank`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.blank
+    fn get_blank(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        // 3. If DurationSign(duration) = 0, return true.
+        // 4. Return false.
+        Ok((Self::this_duration(this, context)?.sign() == 0).into())
+    }
+
+    /// `Temporal.Duration.prototype.with ( temporalDurationLike )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.with
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Duration/with
+    fn with(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = Self::this_duration(this, context)?;
+
+        // 3. Let temporalDurationLike be ? ToTemporalPartialDurationRecord(temporalDurationLike).
+        let duration_like = match args.get_or_undefined(0) {
+            JsValue::Object(duration_like) => Self::to_partial(duration_like, context)?,
+            _ => return context.throw_type_error("a duration-like value must be an object"),
+        };
+
+        // 4. If temporalDurationLike.[[Years]] is not undefined, then
+        //     a. Let years be temporalDurationLike.[[Years]].
+        // 5. Else,
+        //     a. Let years be duration.[[Years]].
+        // ...
+        let mut values = duration.values;
+        for (value, new_value) in values.iter_mut().zip(duration_like) {
+            if let Some(new_value) = new_value {
+                *value = new_value;
+            }
+        }
+
+        // 24. Return ? CreateTemporalDuration(years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds).
+        Ok(Self::new(values).create(None, context)?.into())
+    }
+
+    /// `Temporal.Duration.prototype.negated ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.negated
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Duration/negated
+    fn negated(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        // 3. Return CreateNegatedTemporalDuration(duration).
+        Ok(Self::this_duration(this, context)?
+            .negated()
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.Duration.prototype.abs ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.abs
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Duration/abs
+    fn abs(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = Self::this_duration(this, context)?;
+
+        // 3. Return ! CreateTemporalDuration(abs(duration.[[Years]]), ...).
+        Ok(Self::new(duration.values.map(f64::abs))
+            .create(None, context)?
+            .into())
+    }
+
+    /// Abstract operation `AddDurations ( operation, duration, other )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-adddurations
+    fn add_durations(
+        this: &JsValue,
+        args: &[JsValue],
+        subtract: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let duration = Self::this_duration(this, context)?;
+
+        // 1. Set other to ? ToTemporalDuration(other).
+        // 2. If operation is subtract, set other to CreateNegatedTemporalDuration(other).
+        let mut other = Self::from_value(args.get_or_undefined(0), context)?;
+        if subtract {
+            other = other.negated();
+        }
+
+        // 3. Let largestUnit1 be DefaultTemporalLargestUnit(duration).
+        // 4. Let largestUnit2 be DefaultTemporalLargestUnit(other).
+        // 5. Let largestUnit be LargerOfTwoTemporalUnits(largestUnit1, largestUnit2).
+        let largest_unit = duration
+            .default_largest_unit()
+            .min(other.default_largest_unit());
+
+        // 6. If IsCalendarUnit(largestUnit) is true, throw a RangeError exception.
+        if largest_unit < TemporalUnit::Day {
+            return context
+                .throw_range_error("durations with years, months or weeks can't be added");
+        }
+
+        // 7. Let d1 be ToInternalDurationRecordWith24HourDays(duration).
+        // 8. Let d2 be ToInternalDurationRecordWith24HourDays(other).
+        // 9. Let timeResult be ? AddTimeDuration(d1.[[Time]], d2.[[Time]]).
+        // 10. Let result be CombineDateAndTimeDuration(ZeroDateDuration(), timeResult).
+        let time = duration.internal_record_with_24_hour_days().1
+            + other.internal_record_with_24_hour_days().1;
+
+        // 11. Return ? TemporalDurationFromInternal(result, largestUnit).
+        Ok(Self::from_parts([0; 4], time, largest_unit)
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.Duration.prototype.add ( other )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.add
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Duration/add
+    fn add(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        // 3. Return ? AddDurations(add, duration, other).
+        Self::add_durations(this, args, false, context)
+    }
+
+    /// `Temporal.Duration.prototype.subtract ( other )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.subtract
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Duration/subtract
+    fn subtract(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        // 3. Return ? AddDurations(subtract, duration, other).
+        Self::add_durations(this, args, true, context)
+    }
+
+    /// `Temporal.Duration.prototype.round ( roundTo )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.round
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Duration/round
+    fn round(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = Self::this_duration(this, context)?;
+
+        // 3-5. Let roundTo be the options object.
+        let round_to = get_round_to_options(args.get_or_undefined(0), context)?;
+
+        // 6. Let smallestUnitPresent be true.
+        // 7. Let largestUnitPresent be true.
+        // 8. NOTE: The following steps read options and perform independent validation in alphabetical order.
+        // 9. Let largestUnit be ? GetTemporalUnitValuedOption(roundTo, "largestUnit", datetime, unset, « auto »).
+        let largest_unit = get_largest_unit(&round_to, UnitGroup::DateTime, context)?;
+
+        // 10. Let relativeToRecord be ? GetTemporalRelativeToOption(roundTo).
+        // 11. Let zonedRelativeTo be relativeToRecord.[[ZonedRelativeTo]].
+        // 12. Let plainRelativeTo be relativeToRecord.[[PlainRelativeTo]].
+        let relative_to = RelativeTo::from_options(&round_to, context)?;
+
+        // 13. Let roundingIncrement be ? GetRoundingIncrementOption(roundTo).
+        let increment = get_rounding_increment(&round_to, context)?;
+
+        // 14. Let roundingMode be ? GetRoundingModeOption(roundTo, half-expand).
+        let rounding_mode =
+            RoundingMode::from_options(&round_to, RoundingMode::HalfExpand, context)?;
+
+        // 15. Let smallestUnit be ? GetTemporalUnitValuedOption(roundTo, "smallestUnit", datetime, unset).
+        // 16. If smallestUnit is unset, then
+        //     a. Set smallestUnitPresent to false.
+        //     b. Set smallestUnit to nanosecond.
+        let smallest_unit =
+            get_temporal_unit(&round_to, "smallestUnit", UnitGroup::DateTime, context)?;
+        let smallest_unit_present = smallest_unit.is_some();
+        let smallest_unit = smallest_unit.unwrap_or(TemporalUnit::Nanosecond);
+
+        // 17. Let existingLargestUnit be DefaultTemporalLargestUnit(duration).
+        // 18. Let defaultLargestUnit be LargerOfTwoTemporalUnits(existingLargestUnit, smallestUnit).
+        let existing_largest_unit = duration.default_largest_unit();
+        let default_largest_unit = existing_largest_unit.min(smallest_unit);
+
+        // 19. If largestUnit is unset, then
+        //     a. Set largestUnitPresent to false.
+        //     b. Set largestUnit to defaultLargestUnit.
+        // 20. Else if largestUnit is auto, then
+        //     a. Set largestUnit to defaultLargestUnit.
+        // 21. If smallestUnitPresent is false and largestUnitPresent is false, throw a RangeError exception.
+        if !smallest_unit_present && largest_unit.is_none() {
+            return context.throw_range_error("either smallestUnit or largestUnit is required");
+        }
+        let largest_unit = largest_unit.flatten().unwrap_or(default_largest_unit);
+
+        // 22. If LargerOfTwoTemporalUnits(largestUnit, smallestUnit) is not largestUnit, throw a RangeError exception.
+        if largest_unit > smallest_unit {
+            return context.throw_range_error("largestUnit must not be smaller than smallestUnit");
+        }
+
+        // 23. Let maximum be MaximumTemporalDurationRoundingIncrement(smallestUnit).
+        // 24. If maximum is not unset, perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, false).
+        if let Some(maximum) = smallest_unit.maximum_rounding_increment() {
+            validate_rounding_increment(increment, maximum, false, context)?;
+        }
+
+        // 25. If roundingIncrement > 1, and largestUnit is not smallestUnit, and TemporalUnitCategory(smallestUnit) is date, throw a RangeError exception.
+        if increment > 1 && largest_unit != smallest_unit && !smallest_unit.is_time() {
+            return context.throw_range_error(
+                "roundingIncrement must be 1 when rounding to a date unit smaller than largestUnit",
+            );
+        }
+
+        let settings = DifferenceSettings {
+            largest_unit,
+            smallest_unit,
+            increment,
+            rounding_mode,
+        };
+        let result = match relative_to {
+            // 26. If zonedRelativeTo is not undefined, then
+            RelativeTo::Zoned(relative_to) => {
+                // a. Let internalDuration be ToInternalDurationRecord(duration).
+                // b. Let timeZone be zonedRelativeTo.[[TimeZone]].
+                // c. Let calendar be zonedRelativeTo.[[Calendar]].
+                // d. Let relativeEpochNs be zonedRelativeTo.[[EpochNanoseconds]].
+                // e. Let targetEpochNs be ? AddZonedDateTime(relativeEpochNs, timeZone, calendar, internalDuration, constrain).
+                let target = add_zoned_date_time(
+                    relative_to.epoch_nanoseconds(),
+                    relative_to.time_zone(),
+                    relative_to.calendar(),
+                    duration.internal_record(),
+                    Overflow::Constrain,
+                    context,
+                )?;
+
+                // f. Set internalDuration to ? DifferenceZonedDateTimeWithRounding(relativeEpochNs, targetEpochNs, timeZone, calendar, largestUnit, roundingIncrement, smallestUnit, roundingMode).
+                let (date, time) = difference_zoned_date_time_with_rounding(
+                    relative_to.epoch_nanoseconds(),
+                    target,
+                    relative_to.time_zone(),
+                    relative_to.calendar(),
+                    settings,
+                    context,
+                )?;
+
+                // g. If TemporalUnitCategory(largestUnit) is date, set largestUnit to hour.
+                // h. Return ? TemporalDurationFromInternal(internalDuration, largestUnit).
+                Self::from_parts(date, time, largest_unit.max(TemporalUnit::Hour))
+            }
+            // 27. If plainRelativeTo is not undefined, then
+            RelativeTo::Plain(relative_to) => {
+                // a-g. Let targetDateTime be the date-time at midnight of plainRelativeTo plus the duration.
+                let (start, target) = duration.add_to_plain_date(relative_to, context)?;
+
+                // h. Set internalDuration to ? DifferencePlainDateTimeWithRounding(isoDateTime, targetDateTime, calendar, largestUnit, roundingIncrement, smallestUnit, roundingMode).
+                let (date, time) = difference_plain_date_time_with_rounding(
+                    start,
+                    target,
+                    relative_to.calendar(),
+                    settings,
+                    context,
+                )?;
+
+                // i. Return ? TemporalDurationFromInternal(internalDuration, largestUnit).
+                Self::from_parts(date, time, largest_unit)
+            }
+            RelativeTo::None => {
+                // 28. If IsCalendarUnit(existingLargestUnit) is true, or IsCalendarUnit(largestUnit) is true, throw a RangeError exception.
+                if existing_largest_unit.min(largest_unit) < TemporalUnit::Day {
+                    return context.throw_range_error(
+                        "a relativeTo date is required to round years, months or weeks",
+                    );
+                }
+
+                // 29. Assert: IsCalendarUnit(smallestUnit) is false.
+                // 30. Let internalDuration be ToInternalDurationRecordWith24HourDays(duration).
+                // 31. If smallestUnit is day, then
+                //     a. Let fractionalDays be TotalTimeDuration(internalDuration.[[Time]], day).
+                //     b. Let days be RoundNumberToIncrement(fractionalDays, roundingIncrement, roundingMode).
+                //     c. Let dateDuration be ? CreateDateDurationRecord(0, 0, 0, days).
+                //     d. Set internalDuration to CombineDateAndTimeDuration(dateDuration, 0).
+                // 32. Else,
+                //     a. Let timeDuration be ? RoundTimeDuration(internalDuration.[[Time]], roundingIncrement, smallestUnit, roundingMode).
+                //     b. Set internalDuration to CombineDateAndTimeDuration(ZeroDateDuration(), timeDuration).
+                //
+                // The days are rounded as a time duration, and balanced back into days below.
+                let unit_nanoseconds = smallest_unit
+                    .nanoseconds()
+                    .expect("the smallest unit must be a day or a unit of time");
+                let time = round_to_increment(
+                    duration.internal_record_with_24_hour_days().1,
+                    unit_nanoseconds * increment,
+                    rounding_mode,
+                );
+
+                // 33. Return ? TemporalDurationFromInternal(internalDuration, largestUnit).
+                Self::from_parts([0; 4], time, largest_unit)
+            }
+        };
+        Ok(result.create(None, context)?.into())
+    }
+
+    /// `Temporal.Duration.prototype.total ( totalOf )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.total
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Duration/total
+    fn total(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = Self::this_duration(this, context)?;
+
+        let total_of = match args.get_or_undefined(0) {
+            // 3. If totalOf is undefined, throw a TypeError exception.
+            JsValue::Undefined => {
+                return context.throw_type_error("the total options must not be undefined")
+            }
+            // 4. If totalOf is a String, then
+            JsValue::String(unit) => {
+                // a. Let paramString be totalOf.
+                // b. Set totalOf to OrdinaryObjectCreate(null).
+                let total_of = JsObject::from_proto_and_data(None, ObjectData::ordinary());
+
+                // c. Perform ! CreateDataPropertyOrThrow(totalOf, "unit", paramString).
+                total_of
+                    .create_data_property_or_throw("unit", unit.clone(), context)
+                    .expect("CreateDataPropertyOrThrow must not fail on an ordinary object");
+                total_of
+            }
+            // 5. Else,
+            //     a. Set totalOf to ? GetOptionsObject(totalOf).
+            total_of => get_options_object(total_of, context)?,
+        };
+
+        // 6. NOTE: The following steps read options and perform independent validation in alphabetical order.
+        // 7. Let relativeToRecord be ? GetTemporalRelativeToOption(totalOf).
+        // 8. Let zonedRelativeTo be relativeToRecord.[[ZonedRelativeTo]].
+        // 9. Let plainRelativeTo be relativeToRecord.[[PlainRelativeTo]].
+        let relative_to = RelativeTo::from_options(&total_of, context)?;
+
+        // 10. Let unit be ? GetTemporalUnitValuedOption(totalOf, "unit", datetime, required).
+        let unit = match get_temporal_unit(&total_of, "unit", UnitGroup::DateTime, context)? {
+            Some(unit) => unit,
+            None => return context.throw_range_error("unit is required"),
+        };
+
+        let total = match relative_to {
+            // 11. If zonedRelativeTo is not undefined, then
+            RelativeTo::Zoned(relative_to) => {
+                // a. Let internalDuration be ToInternalDurationRecord(duration).
+                // b. Let timeZone be zonedRelativeTo.[[TimeZone]].
+                // c. Let calendar be zonedRelativeTo.[[Calendar]].
+                // d. Let relativeEpochNs be zonedRelativeTo.[[EpochNanoseconds]].
+                // e. Let targetEpochNs be ? AddZonedDateTime(relativeEpochNs, timeZone, calendar, internalDuration, constrain).
+                let target = add_zoned_date_time(
+                    relative_to.epoch_nanoseconds(),
+                    relative_to.time_zone(),
+                    relative_to.calendar(),
+                    duration.internal_record(),
+                    Overflow::Constrain,
+                    context,
+                )?;
+
+                // f. Let total be ? DifferenceZonedDateTimeWithTotal(relativeEpochNs, targetEpochNs, timeZone, calendar, unit).
+                difference_zoned_date_time_with_total(
+                    relative_to.epoch_nanoseconds(),
+                    target,
+                    relative_to.time_zone(),
+                    relative_to.calendar(),
+                    unit,
+                    context,
+                )?
+            }
+            // 12. Else if plainRelativeTo is not undefined, then
+            RelativeTo::Plain(relative_to) => {
+                // a-g. Let targetDateTime be the date-time at midnight of plainRelativeTo plus the duration.
+                let (start, target) = duration.add_to_plain_date(relative_to, context)?;
+
+                // h. Let total be ? DifferencePlainDateTimeWithTotal(isoDateTime, targetDateTime, calendar, unit).
+                difference_plain_date_time_with_total(
+                    start,
+                    target,
+                    relative_to.calendar(),
+                    unit,
+                    context,
+                )?
+            }
+            // 13. Else,
+            RelativeTo::None => {
+                // a. Let largestUnit be DefaultTemporalLargestUnit(duration).
+                // b. If IsCalendarUnit(largestUnit) is true, or IsCalendarUnit(unit) is true, throw a RangeError exception.
+                if duration.default_largest_unit().min(unit) < TemporalUnit::Day {
+                    return context.throw_range_error(
+                        "a relativeTo date is required to total years, months or weeks",
+                    );
+                }
+
+                // c. Let internalDuration be ToInternalDurationRecordWith24HourDays(duration).
+                // d. Let total be TotalTimeDuration(internalDuration.[[Time]], unit).
+                total_time_duration(duration.internal_record_with_24_hour_days().1, unit)
+            }
+        };
+
+        // 14. Return 𝔽(total).
+        Ok(total.into())
+    }
+
+    /// Steps of `round` and `total` with a `Temporal.PlainDate` as `relativeTo`.
+    ///
+    /// Returns the date-time at midnight of `relative_to`, and this date-time plus the duration,
+    /// with the days of the duration added as 24 hours.
+    fn add_to_plain_date(
+        &self,
+        relative_to: PlainDate,
+        context: &mut Context,
+    ) -> JsResult<(IsoDateTime, IsoDateTime)> {
+        // a. Let internalDuration be ToInternalDurationRecordWith24HourDays(duration).
+        // b. Let targetTime be AddTime(MidnightTimeRecord(), internalDuration.[[Time]]).
+        // c. Let calendar be plainRelativeTo.[[Calendar]].
+        // d. Let dateDuration be ! AdjustDateDurationRecord(internalDuration.[[Date]], targetTime.[[Days]]).
+        // e. Let targetDate be ? CalendarDateAdd(calendar, plainRelativeTo.[[ISODate]], dateDuration, constrain).
+        // f. Let isoDateTime be CombineISODateAndTimeRecord(plainRelativeTo.[[ISODate]], MidnightTimeRecord()).
+        // g. Let targetDateTime be CombineISODateAndTimeRecord(targetDate, targetTime).
+        let (date, time) = self.internal_record_with_24_hour_days();
+        let start = IsoDateTime::new(relative_to.date(), IsoTime::default());
+        match start.add(date, time, relative_to.calendar(), Overflow::Constrain) {
+            Some(target) => Ok((start, target)),
+            None => context.throw_range_error("the date-time is out of range"),
+        }
+    }
+
+    /// Abstract operation `TemporalDurationToString ( duration, precision )`
+    ///
+    /// The seconds and the smaller units are rounded together with `increment`, in nanoseconds,
+    /// and are not balanced into the larger units.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-temporaldurationtostring
+    pub(crate) fn to_iso_string(&self, precision: Precision, rounded_seconds: i128) -> String {
+        // 1. Let sign be DurationSign(duration).
+        let sign = self.sign();
+
+        // 2. Let datePart be "".
+        // 3-6. If years, months, weeks or days ≠ 0, append the value followed by Y, M, W or D.
+        let mut date_part = String::new();
+        for (unit, designator) in [
+            (TemporalUnit::Year, 'Y'),
+            (TemporalUnit::Month, 'M'),
+            (TemporalUnit::Week, 'W'),
+            (TemporalUnit::Day, 'D'),
+        ] {
+            let value = self.get(unit).abs();
+            if value != 0.0 {
+                date_part.push_str(&format!("{value}{designator}"));
+            }
+        }
+
+        // 7. Let timePart be "".
+        // 8-9. If hours or minutes ≠ 0, append the value followed by H or M.
+        let mut time_part = String::new();
+        for (unit, designator) in [(TemporalUnit::Hour, 'H'), (TemporalUnit::Minute, 'M')] {
+            let value = self.get(unit).abs();
+            if value != 0.0 {
+                time_part.push_str(&format!("{value}{designator}"));
+            }
+        }
+
+        // 10. Let zeroMinutesAndHigher be false.
+        // 11. If DefaultTemporalLargestUnit(duration) is "second", ..., set zeroMinutesAndHigher to true.
+        let zero_minutes_and_higher = date_part.is_empty() && time_part.is_empty();
+
+        // 12-14. If secondsDuration ≠ 0, or zeroMinutesAndHigher is true, or precision is not "auto", then
+        let rounded_seconds = rounded_seconds.unsigned_abs();
+        if rounded_seconds != 0 || zero_minutes_and_higher || precision != Precision::Auto {
+            // a. Let secondsPart be abs(secondsDuration) formatted as a decimal number.
+            // b. Let subSecondsPart be FormatFractionalSeconds(abs(subSecondNanoseconds), precision).
+            // c. Set timePart to the string concatenation of timePart, secondsPart, subSecondsPart, and "S".
+            time_part.push_str(&format!(
+                "{}{}S",
+                rounded_seconds / 1_000_000_000,
+                format_fraction((rounded_seconds % 1_000_000_000) as u32, precision)
+            ));
+        }
+
+        // 15. Let signPart be the code unit 0x002D (HYPHEN-MINUS) if sign < 0, and otherwise the empty String.
+        // 16. Let result be the string concatenation of signPart, the code unit 0x0050 (LATIN CAPITAL LETTER P) and datePart.
+        let mut result = if sign < 0 { "-P" } else { "P" }.to_owned();
+        result.push_str(&date_part);
+
+        // 17. If timePart is not "", then
+        if !time_part.is_empty() {
+            // a. Set result to the string concatenation of result, the code unit 0x0054 (LATIN CAPITAL LETTER T), and timePart.
+            result.push('T');
+            result.push_str(&time_part);
+        }
+
+        // 18. Return result.
+        result
+    }
+
+    /// Gets the seconds and the smaller units of the duration, in nanoseconds.
+    fn seconds_nanoseconds(&self) -> i128 {
+        TemporalUnit::ALL[TemporalUnit::Second as usize..]
+            .iter()
+            .map(|unit| {
+                self.get(*unit) as i128 * unit.nanoseconds().expect("must be a unit of time")
+            })
+            .sum()
+    }
+
+    /// `Temporal.Duration.prototype.toString ( [ options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.tostring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Duration/toString
+    #[allow(clippy::wrong_self_convention)]
+    fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = Self::this_duration(this, context)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(0), context)?;
+
+        // 4-7. Let precision be ToSecondsStringPrecisionRecord(smallestUnit, digits).
+        let (precision, rounding_mode) = SecondsStringPrecision::from_options(&options, context)?;
+
+        // 8. If smallestUnit is "hour" or "minute", throw a RangeError exception.
+        if precision.precision == Precision::Minute {
+            return context.throw_range_error("smallestUnit must not be `minute`");
+        }
+
+        // 9-13. Round the seconds and the smaller units with the precision.
+        let seconds = round_to_increment(
+            duration.seconds_nanoseconds(),
+            precision.nanoseconds(),
+            rounding_mode,
+        );
+
+        // 14. Return TemporalDurationToString(roundedDuration, precision.[[Precision]]).
+        Ok(duration.to_iso_string(precision.precision, seconds).into())
+    }
+
+    /// `Temporal.Duration.prototype.toJSON ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.tojson
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Duration/toJSON
+    #[allow(clippy::wrong_self_convention)]
+    fn to_json(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = Self::this_duration(this, context)?;
+
+        // 3. Return TemporalDurationToString(duration, "auto").
+        Ok(duration
+            .to_iso_string(Precision::Auto, duration.seconds_nanoseconds())
+            .into())
+    }
+
+    /// `Temporal.Duration.prototype.toLocaleString ( [ locales [ , options ] ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Duration/toLocaleString
+    #[allow(clippy::wrong_self_convention)]
+    fn to_locale_string(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = Self::this_duration(this, context)?;
+
+        // 3. Let formatter be ? Construct(%Intl.DurationFormat%, « locales, options »).
+        let constructor = context
+            .intrinsics()
+            .constructors()
+            .duration_format()
+            .constructor();
+        let formatter = constructor.construct(
+            &[
+                args.get_or_undefined(0).clone(),
+                args.get_or_undefined(1).clone(),
+            ],
+            &constructor.clone().into(),
+            context,
+        )?;
+        let formatter = formatter
+            .as_object()
+            .and_then(|obj| obj.borrow().as_duration_format().cloned())
+            .expect("the constructor of Intl.DurationFormat must return an Intl.DurationFormat");
+
+        // 4. Return FormatDurationFormatPattern(formatter, duration).
+        Ok(formatter
+            .format_record(&DurationRecord::new(duration.values))
+            .into())
+    }
+
+    /// `Temporal.Duration.prototype.valueOf ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.valueof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Duration/valueOf
+    fn value_of(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Throw a TypeError exception.
+        context.throw_type_error("a Temporal.Duration can't be converted to a primitive value")
+    }
+}
+
+/// The `relativeTo` option of `compare`, `round` and `total`.
+#[derive(Debug, Clone)]
+enum RelativeTo {
+    None,
+    Plain(PlainDate),
+    Zoned(ZonedDateTime),
+}
+
+impl RelativeTo {
+    /// Abstract operation `GetTemporalRelativeToOption ( options )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-gettemporalrelativetooption
+    fn from_options(options: &JsObject, context: &mut Context) -> JsResult<Self> {
+        // 1. Let value be ? Get(options, "relativeTo").
+        let value = options.get("relativeTo", context)?;
+
+        // 3. Let offsetBehaviour be option.
+        // 4. Let matchBehaviour be match-exactly.
+        let (date, time, time_zone, calendar, offset_behaviour) = match &value {
+            // 2. If value is undefined, return the Record { [[PlainRelativeTo]]: undefined, [[ZonedRelativeTo]]: undefined }.
+            JsValue::Undefined => return Ok(Self::None),
+            // 5. If value is an Object, then
+            JsValue::Object(object) => {
+                // a. If value has an [[InitializedTemporalZonedDateTime]] internal slot, then
+                //     i. Return the Record { [[PlainRelativeTo]]: undefined, [[ZonedRelativeTo]]: value }.
+                // b. If value has an [[InitializedTemporalDate]] internal slot, then
+                //     i. Return the Record { [[PlainRelativeTo]]: value, [[ZonedRelativeTo]]: undefined }.
+                // c. If value has an [[InitializedTemporalDateTime]] internal slot, then
+                //     i. Let plainDate be ! CreateTemporalDate(value.[[ISODateTime]].[[ISODate]], value.[[Calendar]]).
+                //     ii. Return the Record { [[PlainRelativeTo]]: plainDate, [[ZonedRelativeTo]]: undefined }.
+                {
+                    let object = object.borrow();
+                    if let Some(date_time) = object.as_zoned_date_time() {
+                        return Ok(Self::Zoned(date_time.clone()));
+                    }
+                    if let Some(date) = object.as_plain_date() {
+                        return Ok(Self::Plain(*date));
+                    }
+                    if let Some(date_time) = object.as_plain_date_time() {
+                        return Ok(Self::Plain(PlainDate::new(
+                            date_time.date_time().date,
+                            date_time.calendar(),
+                        )));
+                    }
+                }
+
+                // d. Let calendar be ? GetTemporalCalendarIdentifierWithISODefault(value).
+                let calendar = Calendar::from_fields_object(object, context)?;
+
+                // e. Let fields be ? PrepareCalendarFields(calendar, value, « year, month, month-code, day », « hour, minute, second, millisecond, microsecond, nanosecond, offset, time-zone », « »).
+                let fields =
+                    Fields::read_zoned(object, calendar, true, context)?.unwrap_or_default();
+
+                // f. Let result be ? InterpretTemporalDateTimeFields(calendar, fields, constrain).
+                let result = interpret_fields(calendar, &fields, Overflow::Constrain, context)?;
+
+                // g. Let timeZone be fields.[[TimeZone]].
+                // h. Let offsetString be fields.[[OffsetString]].
+                // i. If offsetString is unset, then
+                //     i. Set offsetBehaviour to wall.
+                let offset_behaviour = match fields.offset {
+                    Some(offset) => OffsetBehaviour::Option {
+                        offset,
+                        match_minutes: false,
+                    },
+                    None => OffsetBehaviour::Wall,
+                };
+
+                // j. Let isoDate be result.[[ISODate]].
+                // k. Let time be result.[[Time]].
+                (
+                    result.date,
+                    Some(result.time),
+                    fields.time_zone,
+                    calendar,
+                    offset_behaviour,
+                )
+            }
+            // 6. Else,
+            JsValue::String(string) => {
+                // b. Let result be ? ParseISODateTime(value, « TemporalDateTimeString[+Zoned], TemporalDateTimeString[~Zoned] »).
+                let parsed = match parse_date_time(string) {
+                    Ok(parsed)
+                        if parsed.time_zone.is_some() || parsed.offset != Some(UtcOffset::Z) =>
+                    {
+                        parsed
+                    }
+                    Ok(_) => {
+                        return context.throw_range_error(format!(
+                            "the date-time string `{string}` must not have a `Z` designator"
+                        ))
+                    }
+                    Err(error) => {
+                        return context.throw_range_error(format!(
+                            "invalid date-time string `{string}`: {error}"
+                        ))
+                    }
+                };
+
+                // c. Let offsetString be result.[[TimeZone]].[[OffsetString]].
+                // d. Let annotation be result.[[TimeZone]].[[TimeZoneAnnotation]].
+                // e. If annotation is empty, then
+                //     i. Let timeZone be unset.
+                // f. Else,
+                //     i. Let timeZone be ? ToTemporalTimeZoneIdentifier(annotation).
+                let time_zone = match parsed.time_zone.clone() {
+                    Some(annotation) => Some(TimeZone::from_value(&annotation.into(), context)?),
+                    None => None,
+                };
+
+                //     ii. If result.[[TimeZone]].[[Z]] is true, then
+                //         1. Set offsetBehaviour to exact.
+                //     iii. Else if offsetString is empty, then
+                //         1. Set offsetBehaviour to wall.
+                //     iv. Set matchBehaviour to match-minutes.
+                //     v. If offsetString is not empty, then
+                //         1. Let offsetParseResult be ParseText(StringToCodePoints(offsetString), UTCOffset[+SubMinutePrecision]).
+                //         2. Assert: offsetParseResult is a Parse Node.
+                //         3. If offsetParseResult contains more than one MinuteSecond Parse Node, set matchBehaviour to match-exactly.
+                let offset_behaviour = match parsed.offset {
+                    Some(UtcOffset::Z) => OffsetBehaviour::Exact,
+                    Some(UtcOffset::Offset(offset)) => OffsetBehaviour::Option {
+                        offset,
+                        match_minutes: offset % NS_PER_MINUTE == 0,
+                    },
+                    None => OffsetBehaviour::Wall,
+                };
+
+                // g. Let calendar be result.[[Calendar]].
+                // h. If calendar is empty, set calendar to "iso8601".
+                // i. Set calendar to ? CanonicalizeCalendar(calendar).
+                let calendar = match parsed
+                    .calendar
+                    .as_deref()
+                    .map_or(Some(Calendar::Iso8601), Calendar::from_identifier)
+                {
+                    Some(calendar) => calendar,
+                    None => return context.throw_range_error("unsupported calendar"),
+                };
+
+                // j. Let isoDate be CreateISODateRecord(result.[[Year]], result.[[Month]], result.[[Day]]).
+                // k. Let time be result.[[Time]].
+                let date = IsoDate {
+                    year: parsed.year,
+                    month: parsed.month,
+                    day: parsed.day,
+                };
+                let time = parsed.time.map(IsoTime::from_parsed);
+                (date, time, time_zone, calendar, offset_behaviour)
+            }
+            // a. If value is not a String, throw a TypeError exception.
+            _ => {
+                return context
+                    .throw_type_error("the relativeTo option must be a string or an object")
+            }
+        };
+
+        // 7. If timeZone is unset, then
+        //     a. Let plainDate be ? CreateTemporalDate(isoDate, calendar).
+        //     b. Return the Record { [[PlainRelativeTo]]: plainDate, [[ZonedRelativeTo]]: undefined }.
+        let time_zone = match time_zone {
+            Some(time_zone) => time_zone,
+            None if date.is_within_limits() => {
+                return Ok(Self::Plain(PlainDate::new(date, calendar)))
+            }
+            None => return context.throw_range_error("the relativeTo date is out of range"),
+        };
+
+        // 8. If offsetBehaviour is option, then
+        //     a. Let offsetNs be ! ParseDateTimeUTCOffset(offsetString).
+        // 9. Else,
+        //     a. Let offsetNs be 0.
+        // 10. Let epochNanoseconds be ? InterpretISODateTimeOffset(isoDate, time, offsetBehaviour, offsetNs, timeZone, compatible, reject, matchBehaviour).
+        let epoch_nanoseconds = time_zone.interpret_offset(
+            date,
+            time,
+            offset_behaviour,
+            Disambiguation::Compatible,
+            OffsetOption::Reject,
+            context,
+        )?;
+
+        // 11. Let zonedRelativeTo be ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+        // 12. Return the Record { [[PlainRelativeTo]]: undefined, [[ZonedRelativeTo]]: zonedRelativeTo }.
+        Ok(Self::Zoned(ZonedDateTime::new(
+            epoch_nanoseconds,
+            time_zone,
+            calendar,
+        )))
+    }
+}
+
+/// Abstract operation `DateDurationDays ( dateDuration, plainRelativeTo )`
+///
+/// Gets the years, months, weeks and days of a duration in days, from `relative_to`.
+fn date_duration_days(
+    date_duration: [i64; 4],
+    relative_to: PlainDate,
+    context: &mut Context,
+) -> JsResult<i64> {
+    let [years, months, weeks, days] = date_duration;
+
+    // 1. Let yearsMonthsWeeksDuration be ! AdjustDateDurationRecord(dateDuration, 0).
+    // 2. If DateDurationSign(yearsMonthsWeeksDuration) = 0, return dateDuration.[[Days]].
+    if [years, months, weeks] == [0; 3] {
+        return Ok(days);
+    }
+
+    // 3. Let later be ? CalendarDateAdd(plainRelativeTo.[[Calendar]], plainRelativeTo.[[ISODate]], yearsMonthsWeeksDuration, constrain).
+    let later = match relative_to.calendar().date_add(
+        relative_to.date(),
+        [years, months, weeks, 0],
+        Overflow::Constrain,
+    ) {
+        Some(later) => later,
+        None => return context.throw_range_error("the resulting date is out of range"),
+    };
+
+    // 4. Let epochDays1 be ISODateToEpochDays(plainRelativeTo.[[ISODate]].[[Year]], plainRelativeTo.[[ISODate]].[[Month]] - 1, plainRelativeTo.[[ISODate]].[[Day]]).
+    // 5. Let epochDays2 be ISODateToEpochDays(later.[[Year]], later.[[Month]] - 1, later.[[Day]]).
+    // 6. Let yearsMonthsWeeksInDays be epochDays2 - epochDays1.
+    // 7. Return dateDuration.[[Days]] + yearsMonthsWeeksInDays.
+    Ok(days + later.epoch_days() - relative_to.date().epoch_days())
+}
+
+/// Abstract operation `ToIntegerIfIntegral ( argument )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-tointegerifintegral
+pub(crate) fn to_integer_if_integral(argument: &JsValue, context: &mut Context) -> JsResult<f64> {
+    // 1. Let number be ? ToNumber(argument).
+    let number = argument.to_number(context)?;
+
+    // 2. If IsIntegralNumber(number) is false, throw a RangeError exception.
+    if !number.is_finite() || number.fract() != 0.0 {
+        return context.throw_range_error(format!("{number} is not an integer"));
+    }
+
+    // 3. Return ℝ(number).
+    Ok(number + 0.0)
+}
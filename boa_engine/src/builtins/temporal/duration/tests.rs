@@ -0,0 +1,322 @@
+use crate::{forward, Context};
+
+#[test]
+fn constructor() {
+    let mut context = Context::default();
+    for (code, expected) in [
+        ("new Temporal.Duration().toString()", "PT0S"),
+        ("new Temporal.Duration(1, 2, 3, 4).toString()", "P1Y2M3W4D"),
+        (
+            "new Temporal.Duration(0, 0, 0, 0, 5, 6, 7, 8, 9, 10).toString()",
+            "PT5H6M7.00800901S",
+        ),
+        ("new Temporal.Duration(-1, 0, 0, -2).toString()", "-P1Y2D"),
+        (
+            "new Temporal.Duration(0, 0, 0, 0, 0, 0, 0, 1500).toString()",
+            "PT1.5S",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), format!("\"{expected}\""));
+    }
+
+    for (code, expected) in [
+        ("new Temporal.Duration(1, -1)", "RangeError"),
+        ("new Temporal.Duration(1.5)", "RangeError"),
+        ("new Temporal.Duration(Infinity)", "RangeError"),
+        ("Temporal.Duration()", "TypeError"),
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {code} }} catch (e) {{ e.name }}")
+            ),
+            format!("\"{expected}\"")
+        );
+    }
+}
+
+#[test]
+fn from() {
+    let mut context = Context::default();
+    for (code, expected) in [
+        (
+            "Temporal.Duration.from('P1Y2M3W4DT5H6M7.5S').toString()",
+            "P1Y2M3W4DT5H6M7.5S",
+        ),
+        ("Temporal.Duration.from('-PT1.5H').toString()", "-PT1H30M"),
+        (
+            "Temporal.Duration.from({ hours: 2, minutes: 30 }).toString()",
+            "PT2H30M",
+        ),
+        (
+            "Temporal.Duration.from(new Temporal.Duration(0, 0, 0, 1)).toString()",
+            "P1D",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), format!("\"{expected}\""));
+    }
+
+    for (code, expected) in [
+        ("Temporal.Duration.from('P')", "RangeError"),
+        ("Temporal.Duration.from('P1.5Y')", "RangeError"),
+        ("Temporal.Duration.from('PT1.5H1M')", "RangeError"),
+        ("Temporal.Duration.from({})", "TypeError"),
+        ("Temporal.Duration.from(1)", "TypeError"),
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {code} }} catch (e) {{ e.name }}")
+            ),
+            format!("\"{expected}\"")
+        );
+    }
+}
+
+#[test]
+fn properties() {
+    let mut context = Context::default();
+    forward(&mut context, "var d = Temporal.Duration.from('-P1DT2H');");
+    for (code, expected) in [
+        ("d.days", "-1"),
+        ("d.hours", "-2"),
+        ("d.minutes", "0"),
+        ("d.sign", "-1"),
+        ("d.blank", "false"),
+        ("new Temporal.Duration().blank", "true"),
+        ("d.negated().toString()", "\"P1DT2H\""),
+        ("d.abs().toString()", "\"P1DT2H\""),
+        ("d.with({ days: -3 }).toString()", "\"-P3DT2H\""),
+        ("d.toJSON()", "\"-P1DT2H\""),
+        (
+            "try { d.with({ days: 3 }) } catch (e) { e.name }",
+            "\"RangeError\"",
+        ),
+        ("try { d + 1 } catch (e) { e.name }", "\"TypeError\""),
+        (
+            "Object.prototype.toString.call(d)",
+            "\"[object Temporal.Duration]\"",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+}
+
+#[test]
+fn to_string_options() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        "var d = new Temporal.Duration(0, 0, 0, 0, 1, 2, 3, 456, 789);",
+    );
+    for (code, expected) in [
+        ("d.toString({ fractionalSecondDigits: 2 })", "PT1H2M3.45S"),
+        ("d.toString({ smallestUnit: 'second' })", "PT1H2M3S"),
+        (
+            "d.toString({ smallestUnit: 'millisecond', roundingMode: 'halfExpand' })",
+            "PT1H2M3.457S",
+        ),
+        (
+            "d.toString({ fractionalSecondDigits: 9 })",
+            "PT1H2M3.456789000S",
+        ),
+        (
+            "new Temporal.Duration(1).toString({ fractionalSecondDigits: 0 })",
+            "P1YT0S",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), format!("\"{expected}\""));
+    }
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { d.toString({ smallestUnit: 'minute' }) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn to_locale_string() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.Duration.from('PT1H30M').toLocaleString('en', { style: 'long' })"
+        ),
+        "\"1 hour, 30 minutes\""
+    );
+}
+
+#[test]
+fn add_subtract_compare() {
+    let mut context = Context::default();
+    for (code, expected) in [
+        (
+            "Temporal.Duration.from('PT1H30M').add('PT45M').toString()",
+            "\"PT2H15M\"",
+        ),
+        (
+            "Temporal.Duration.from('P1DT12H').add({ hours: 13 }).toString()",
+            "\"P2DT1H\"",
+        ),
+        (
+            "Temporal.Duration.from('PT1H').subtract('PT90M').toString()",
+            "\"-PT30M\"",
+        ),
+        ("Temporal.Duration.compare('PT1H', 'PT60M')", "0"),
+        ("Temporal.Duration.compare('P1D', 'PT23H')", "1"),
+        (
+            "Temporal.Duration.compare('P1M', 'P30D', { relativeTo: '2020-02-01' })",
+            "-1",
+        ),
+        (
+            "Temporal.Duration.compare('P1M', 'P30D', { relativeTo: '2020-01-01' })",
+            "1",
+        ),
+        (
+            "Temporal.Duration.compare('P1M', 'P29D', { relativeTo: { year: 2020, month: 2, day: 1 } })",
+            "0",
+        ),
+        (
+            "Temporal.Duration.compare('P1D', 'PT24H', { relativeTo: '2020-01-01T00:00[+01:00]' })",
+            "0",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+
+    for (code, expected) in [
+        ("Temporal.Duration.from('P1Y').add('P1D')", "RangeError"),
+        ("Temporal.Duration.compare('P1M', 'P30D')", "RangeError"),
+        (
+            "Temporal.Duration.compare('P1M', 'P30D', { relativeTo: '2020-01-01Z' })",
+            "RangeError",
+        ),
+        (
+            "Temporal.Duration.compare('P1M', 'P30D', { relativeTo: 1 })",
+            "TypeError",
+        ),
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {code} }} catch (e) {{ e.name }}")
+            ),
+            format!("\"{expected}\"")
+        );
+    }
+}
+
+#[test]
+fn round() {
+    let mut context = Context::default();
+    for (code, expected) in [
+        (
+            "Temporal.Duration.from('PT130M').round({ largestUnit: 'hour' })",
+            "PT2H10M",
+        ),
+        ("Temporal.Duration.from('PT90M').round('hour')", "PT2H"),
+        (
+            "Temporal.Duration.from('PT100S').round({ largestUnit: 'auto' })",
+            "PT100S",
+        ),
+        (
+            "Temporal.Duration.from('PT36H').round({ largestUnit: 'day' })",
+            "P1DT12H",
+        ),
+        (
+            "Temporal.Duration.from('P1DT12H').round({ smallestUnit: 'day' })",
+            "P2D",
+        ),
+        (
+            "Temporal.Duration.from('PT1H2M3.5S').round({ smallestUnit: 'second', roundingMode: 'trunc' })",
+            "PT1H2M3S",
+        ),
+        (
+            "Temporal.Duration.from('P1M14D').round({ smallestUnit: 'month', relativeTo: '2020-01-01' })",
+            "P1M",
+        ),
+        (
+            "Temporal.Duration.from('P1M15D').round({ smallestUnit: 'month', relativeTo: '2020-01-01' })",
+            "P2M",
+        ),
+        (
+            "Temporal.Duration.from('P45D').round({ largestUnit: 'month', relativeTo: '2020-01-01' })",
+            "P1M14D",
+        ),
+        (
+            "Temporal.Duration.from('P1Y').round({ largestUnit: 'day', relativeTo: '2020-01-01' })",
+            "P366D",
+        ),
+        (
+            "Temporal.Duration.from('PT36H').round({ largestUnit: 'day', relativeTo: '2020-01-01T00:00+01:00[+01:00]' })",
+            "P1DT12H",
+        ),
+    ] {
+        assert_eq!(
+            forward(&mut context, &format!("{code}.toString()")),
+            format!("\"{expected}\"")
+        );
+    }
+
+    for (code, expected) in [
+        ("Temporal.Duration.from('PT1H').round()", "TypeError"),
+        ("Temporal.Duration.from('PT1H').round({})", "RangeError"),
+        ("Temporal.Duration.from('P1Y').round('day')", "RangeError"),
+        (
+            "Temporal.Duration.from('PT1H').round({ smallestUnit: 'hour', largestUnit: 'minute' })",
+            "RangeError",
+        ),
+        (
+            "Temporal.Duration.from('P1Y').round({ smallestUnit: 'month', roundingIncrement: 2, relativeTo: '2020-01-01' })",
+            "RangeError",
+        ),
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {code} }} catch (e) {{ e.name }}")
+            ),
+            format!("\"{expected}\"")
+        );
+    }
+}
+
+#[test]
+fn total() {
+    let mut context = Context::default();
+    for (code, expected) in [
+        ("Temporal.Duration.from('PT90M').total('hour')", "1.5"),
+        ("Temporal.Duration.from('P1DT6H').total({ unit: 'day' })", "1.25"),
+        (
+            "Temporal.Duration.from('P1M').total({ unit: 'day', relativeTo: '2020-02-01' })",
+            "29",
+        ),
+        (
+            "Temporal.Duration.from('P1M15D').total({ unit: 'month', relativeTo: '2020-03-01' })",
+            "1.5",
+        ),
+        (
+            "Temporal.Duration.from('P1D').total({ unit: 'hour', relativeTo: Temporal.ZonedDateTime.from('2020-01-01T00:00[+01:00]') })",
+            "24",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+
+    for (code, expected) in [
+        ("Temporal.Duration.from('PT1H').total()", "TypeError"),
+        ("Temporal.Duration.from('PT1H').total({})", "RangeError"),
+        ("Temporal.Duration.from('P1M').total('day')", "RangeError"),
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {code} }} catch (e) {{ e.name }}")
+            ),
+            format!("\"{expected}\"")
+        );
+    }
+}
@@ -0,0 +1,846 @@
+//! This module implements the global `Temporal.Instant` object.
+//!
+//! A `Temporal.Instant` is an exact point in time, with a precision of nanoseconds, independent
+//! of any time zone or calendar.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-instant-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Instant
+
+#[cfg(test)]
+mod tests;
+
+use super::{
+    calendar::Calendar, epoch_days_from_iso, format_epoch_nanoseconds, format_offset_rounded,
+    get_round_to_options, get_rounding_increment, get_temporal_unit, round_to_increment,
+    time_zone::TimeZone, validate_rounding_increment, DifferenceSettings, Duration, Precision,
+    RoundingMode, SecondsStringPrecision, TemporalUnit, UnitGroup, ZonedDateTime, NS_MAX_INSTANT,
+    NS_PER_DAY,
+};
+use crate::{
+    bigint::RawBigInt,
+    builtins::{
+        intl::{
+            date_time_format::{DateTimeDefaults, DateTimeRequired},
+            get_options_object, DateTimeFormat,
+        },
+        iso8601::{parse_date_time, UtcOffset},
+        BuiltIn, JsArgs,
+    },
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::PreferredType,
+    Context, JsBigInt, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use num_traits::ToPrimitive;
+use std::cmp::Ordering;
+use tap::{Conv, Pipe};
+
+/// The internal slots of a `Temporal.Instant` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Trace, Finalize)]
+pub struct Instant {
+    /// `[[Nanoseconds]]`
+    #[unsafe_ignore_trace]
+    epoch_nanoseconds: i128,
+}
+
+impl BuiltIn for Instant {
+    const NAME: &'static str = "Instant";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let get_epoch_milliseconds = FunctionBuilder::native(context, Self::get_epoch_milliseconds)
+            .name("get epochMilliseconds")
+            .length(0)
+            .constructor(false)
+            .build();
+        let get_epoch_nanoseconds = FunctionBuilder::native(context, Self::get_epoch_nanoseconds)
+            .name("get epochNanoseconds")
+            .length(0)
+            .constructor(false)
+            .build();
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().instant().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .static_method(Self::from, "from", 1)
+        .static_method(Self::from_epoch_milliseconds, "fromEpochMilliseconds", 1)
+        .static_method(Self::from_epoch_nanoseconds, "fromEpochNanoseconds", 1)
+        .static_method(Self::compare, "compare", 2)
+        .accessor(
+            "epochMilliseconds",
+            Some(get_epoch_milliseconds),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .accessor(
+            "epochNanoseconds",
+            Some(get_epoch_nanoseconds),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .method(Self::add, "add", 1)
+        .method(Self::subtract, "subtract", 1)
+        .method(Self::until, "until", 1)
+        .method(Self::since, "since", 1)
+        .method(Self::round, "round", 1)
+        .method(Self::equals, "equals", 1)
+        .method(Self::to_zoned_date_time_iso, "toZonedDateTimeISO", 1)
+        .method(Self::to_string, "toString", 0)
+        .method(Self::to_json, "toJSON", 0)
+        .method(Self::to_locale_string, "toLocaleString", 0)
+        .method(Self::value_of, "valueOf", 0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            "Temporal.Instant",
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+impl Instant {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Creates an instant from its epoch nanoseconds, which must be valid.
+    pub(crate) fn new(epoch_nanoseconds: i128) -> Self {
+        debug_assert!(is_valid_epoch_nanoseconds(epoch_nanoseconds));
+        Self { epoch_nanoseconds }
+    }
+
+    /// Gets the nanoseconds since the epoch of the instant.
+    pub(crate) fn epoch_nanoseconds(self) -> i128 {
+        self.epoch_nanoseconds
+    }
+
+    /// Abstract operation `CreateTemporalInstant ( epochNanoseconds [ , newTarget ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-createtemporalinstant
+    pub(crate) fn create(
+        self,
+        new_target: Option<&JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Assert: IsValidEpochNanoseconds(epochNanoseconds) is true.
+        // 2. If newTarget is not present, set newTarget to %Temporal.Instant%.
+        // 3. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.Instant.prototype%", « [[InitializedTemporalInstant]], [[Nanoseconds]] »).
+        let prototype = match new_target {
+            Some(new_target) => {
+                get_prototype_from_constructor(new_target, StandardConstructors::instant, context)?
+            }
+            None => context.intrinsics().constructors().instant().prototype(),
+        };
+
+        // 4. Set object.[[Nanoseconds]] to epochNanoseconds.
+        // 5. Return object.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::instant(self),
+        ))
+    }
+
+    /// Abstract operation `ToTemporalInstant ( item )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporalinstant
+    pub(crate) fn from_value(item: &JsValue, context: &mut Context) -> JsResult<Self> {
+        // 1. If item is an Object, then
+        let item = if let JsValue::Object(obj) = item {
+            // a. If item has an [[InitializedTemporalInstant]] or [[InitializedTemporalZonedDateTime]] internal slot, then
+            //     i. Return ! CreateTemporalInstant(item.[[EpochNanoseconds]]).
+            let instant = {
+                let obj = obj.borrow();
+                obj.as_instant().copied().or_else(|| {
+                    obj.as_zoned_date_time()
+                        .map(|date_time| Self::new(date_time.epoch_nanoseconds()))
+                })
+            };
+            if let Some(instant) = instant {
+                return Ok(instant);
+            }
+
+            // c. NOTE: This use of ToPrimitive allows Instant-like objects to be converted.
+            // d. Set item to ? ToPrimitive(item, string).
+            item.to_primitive(context, PreferredType::String)?
+        } else {
+            item.clone()
+        };
+
+        // 2. If item is not a String, throw a TypeError exception.
+        let string = match item {
+            JsValue::String(ref string) => string,
+            _ => return context.throw_type_error("an instant must be a string or an object"),
+        };
+
+        // 3. Let parsed be ? ParseTemporalInstantString(item).
+        let parsed = match parse_date_time(string) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                return context
+                    .throw_range_error(format!("invalid instant string `{string}`: {error}"))
+            }
+        };
+        let (time, offset) = match (parsed.time, parsed.offset) {
+            (Some(time), Some(offset)) => (time, offset),
+            _ => {
+                return context.throw_range_error(format!(
+                    "the instant string `{string}` must have a time and a UTC offset"
+                ))
+            }
+        };
+
+        // 4. If parsed.[[TimeZone]].[[Z]] is true, let offsetNanoseconds be 0; otherwise, let
+        //    offsetNanoseconds be ! ParseDateTimeUTCOffset(parsed.[[TimeZone]].[[OffsetString]]).
+        let offset = match offset {
+            UtcOffset::Z => 0,
+            UtcOffset::Offset(offset) => i128::from(offset),
+        };
+
+        // 5-6. Let epochNanoseconds be GetUTCEpochNanoseconds(...) - offsetNanoseconds.
+        let epoch_nanoseconds =
+            i128::from(epoch_days_from_iso(parsed.year, parsed.month, parsed.day)) * NS_PER_DAY
+                + ((i128::from(time.hour) * 60 + i128::from(time.minute)) * 60
+                    + i128::from(time.second))
+                    * 1_000_000_000
+                + i128::from(time.subsecond_nanoseconds)
+                - offset;
+
+        // 7. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+        if !is_valid_epoch_nanoseconds(epoch_nanoseconds) {
+            return context.throw_range_error("the instant is out of range");
+        }
+
+        // 8. Return ! CreateTemporalInstant(epochNanoseconds).
+        Ok(Self::new(epoch_nanoseconds))
+    }
+
+    /// Gets the `this` `Temporal.Instant` object.
+    fn this_instant(this: &JsValue, context: &mut Context) -> JsResult<Self> {
+        this.as_object()
+            .and_then(|obj| obj.borrow().as_instant().copied())
+            .ok_or_else(|| context.construct_type_error("'this' is not a Temporal.Instant"))
+    }
+
+    /// Creates an instant from a BigInt, if it's in the range of the valid instants.
+    pub(crate) fn from_bigint(
+        epoch_nanoseconds: &JsBigInt,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        match epoch_nanoseconds.as_inner().to_i128() {
+            Some(epoch_nanoseconds) if is_valid_epoch_nanoseconds(epoch_nanoseconds) => {
+                Ok(Self::new(epoch_nanoseconds))
+            }
+            _ => context.throw_range_error("the instant is out of range"),
+        }
+    }
+
+    /// `Temporal.Instant ( epochNanoseconds )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Instant/Instant
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        if new_target.is_undefined() {
+            // a. Throw a TypeError exception.
+            return context.throw_type_error("Temporal.Instant constructor requires 'new'");
+        }
+
+        // 2. Let epochNanoseconds be ? ToBigInt(epochNanoseconds).
+        let epoch_nanoseconds = args.get_or_undefined(0).to_bigint(context)?;
+
+        // 3. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+        let instant = Self::from_bigint(&epoch_nanoseconds, context)?;
+
+        // 4. Return ? CreateTemporalInstant(epochNanoseconds, NewTarget).
+        Ok(instant.create(Some(new_target), context)?.into())
+    }
+
+    /// `Temporal.Instant.from ( item )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.from
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Instant/from
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? ToTemporalInstant(item).
+        Ok(Self::from_value(args.get_or_undefined(0), context)?
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.Instant.fromEpochMilliseconds ( epochMilliseconds )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.fromepochmilliseconds
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Instant/fromEpochMilliseconds
+    fn from_epoch_milliseconds(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Set epochMilliseconds to ? ToNumber(epochMilliseconds).
+        let epoch_milliseconds = args.get_or_undefined(0).to_number(context)?;
+
+        // 2. Set epochMilliseconds to ? NumberToBigInt(epochMilliseconds).
+        if !epoch_milliseconds.is_finite() || epoch_milliseconds.fract() != 0.0 {
+            return context.throw_range_error(format!(
+                "{epoch_milliseconds} can't be converted to a BigInt"
+            ));
+        }
+
+        // 3. Let epochNanoseconds be epochMilliseconds × ℤ(10^6).
+        let epoch_nanoseconds = epoch_milliseconds as i128 * 1_000_000;
+
+        // 4. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+        if !is_valid_epoch_nanoseconds(epoch_nanoseconds) {
+            return context.throw_range_error("the instant is out of range");
+        }
+
+        // 5. Return ! CreateTemporalInstant(epochNanoseconds).
+        Ok(Self::new(epoch_nanoseconds).create(None, context)?.into())
+    }
+
+    /// `Temporal.Instant.fromEpochNanoseconds ( epochNanoseconds )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.fromepochnanoseconds
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Instant/fromEpochNanoseconds
+    fn from_epoch_nanoseconds(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Set epochNanoseconds to ? ToBigInt(epochNanoseconds).
+        let epoch_nanoseconds = args.get_or_undefined(0).to_bigint(context)?;
+
+        // 2. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+        let instant = Self::from_bigint(&epoch_nanoseconds, context)?;
+
+        // 3. Return ! CreateTemporalInstant(epochNanoseconds).
+        Ok(instant.create(None, context)?.into())
+    }
+
+    /// `Temporal.Instant.compare ( one, two )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.compare
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Instant/compare
+    fn compare(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set one to ? ToTemporalInstant(one).
+        let one = Self::from_value(args.get_or_undefined(0), context)?;
+
+        // 2. Set two to ? ToTemporalInstant(two).
+        let two = Self::from_value(args.get_or_undefined(1), context)?;
+
+        // 3. Return 𝔽(CompareEpochNanoseconds(one.[[Nanoseconds]], two.[[Nanoseconds]])).
+        Ok(match one.cmp(&two) {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        }
+        .into())
+    }
+
+    /// `get Temporal.Instant.prototype.epochMilliseconds`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.instant.prototype.epochmilliseconds
+    fn get_epoch_milliseconds(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        let instant = Self::this_instant(this, context)?;
+
+        // 3. Let ns be instant.[[Nanoseconds]].
+        // 4. Let ms be floor(ℝ(ns) / 10^6).
+        // 5. Return 𝔽(ms).
+        Ok((instant.epoch_nanoseconds.div_euclid(1_000_000) as f64).into())
+    }
+
+    /// `get Temporal.Instant.prototype.epochNanoseconds`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.instant.prototype.epochnanoseconds
+    fn get_epoch_nanoseconds(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        let instant = Self::this_instant(this, context)?;
+
+        // 3. Let ns be instant.[[Nanoseconds]].
+        // 4. Return ns.
+        Ok(JsBigInt::from(RawBigInt::from(instant.epoch_nanoseconds)).into())
+    }
+
+    /// Abstract operation `AddDurationToOrSubtractDurationFromInstant ( operation, instant, temporalDurationLike )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-adddurationtoorsubtractdurationfrominstant
+    fn add_duration(
+        this: &JsValue,
+        duration_like: &JsValue,
+        subtract: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let instant = Self::this_instant(this, context)?;
+
+        // 1. If operation is subtract, let sign be -1. Otherwise, let sign be 1.
+        // 2. Let duration be ? ToTemporalDuration(temporalDurationLike).
+        let mut duration = Duration::from_value(duration_like, context)?;
+        if subtract {
+            duration = duration.negated();
+        }
+
+        // 3. If duration.[[Days]] is not 0, throw a RangeError exception.
+        // 4. If duration.[[Months]] is not 0, throw a RangeError exception.
+        // 5. If duration.[[Weeks]] is not 0, throw a RangeError exception.
+        // 6. If duration.[[Years]] is not 0, throw a RangeError exception.
+        if [
+            TemporalUnit::Year,
+            TemporalUnit::Month,
+            TemporalUnit::Week,
+            TemporalUnit::Day,
+        ]
+        .into_iter()
+        .any(|unit| duration.get(unit) != 0.0)
+        {
+            return context.throw_range_error(
+                "a duration added to an instant must not have years, months, weeks or days",
+            );
+        }
+
+        // 7. Let norm be NormalizeTimeDuration(sign × duration.[[Hours]], ...).
+        // 8. Let ns be ? AddInstant(instant.[[Nanoseconds]], norm).
+        let epoch_nanoseconds = instant.epoch_nanoseconds + duration.time_nanoseconds();
+        if !is_valid_epoch_nanoseconds(epoch_nanoseconds) {
+            return context.throw_range_error("the instant is out of range");
+        }
+
+        // 9. Return ! CreateTemporalInstant(ns).
+        Ok(Self::new(epoch_nanoseconds).create(None, context)?.into())
+    }
+
+    /// `Temporal.Instant.prototype.add ( temporalDurationLike )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.add
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Instant/add
+    fn add(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        // 3. Return ? AddDurationToOrSubtractDurationFromInstant(add, instant, temporalDurationLike).
+        Self::add_duration(this, args.get_or_undefined(0), false, context)
+    }
+
+    /// `Temporal.Instant.prototype.subtract ( temporalDurationLike )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.subtract
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Instant/subtract
+    fn subtract(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        // 3. Return ? AddDurationToOrSubtractDurationFromInstant(subtract, instant, temporalDurationLike).
+        Self::add_duration(this, args.get_or_undefined(0), true, context)
+    }
+
+    /// Abstract operation `DifferenceTemporalInstant ( operation, instant, other, options )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalinstant
+    fn difference(
+        this: &JsValue,
+        args: &[JsValue],
+        since: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let instant = Self::this_instant(this, context)?;
+
+        // 1. If operation is since, let sign be -1. Otherwise, let sign be 1.
+        // 2. Set other to ? ToTemporalInstant(other).
+        let other = Self::from_value(args.get_or_undefined(0), context)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        // 4. Let settings be ? GetDifferenceSettings(operation, resolvedOptions, time, « », nanosecond, second).
+        let settings = DifferenceSettings::from_options(
+            args.get_or_undefined(1),
+            since,
+            UnitGroup::Time,
+            TemporalUnit::Nanosecond,
+            TemporalUnit::Second,
+            context,
+        )?;
+
+        // 5. Let internalDuration be DifferenceInstant(instant.[[Nanoseconds]], other.[[Nanoseconds]], settings.[[RoundingIncrement]], settings.[[SmallestUnit]], settings.[[RoundingMode]]).
+        let nanoseconds =
+            difference_instant(instant.epoch_nanoseconds, other.epoch_nanoseconds, settings);
+
+        // 6. Let result be ! TemporalDurationFromInternal(internalDuration, settings.[[LargestUnit]]).
+        let duration = Duration::from_parts([0; 4], nanoseconds, settings.largest_unit);
+
+        // 7. If operation is since, set result to CreateNegatedTemporalDuration(result).
+        let duration = if since { duration.negated() } else { duration };
+
+        // 8. Return result.
+        Ok(duration.create(None, context)?.into())
+    }
+
+    /// `Temporal.Instant.prototype.until ( other [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.until
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Instant/until
+    fn until(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        // 3. Return ? DifferenceTemporalInstant(until, instant, other, options).
+        Self::difference(this, args, false, context)
+    }
+
+    /// `Temporal.Instant.prototype.since ( other [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.since
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Instant/since
+    fn since(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        // 3. Return ? DifferenceTemporalInstant(since, instant, other, options).
+        Self::difference(this, args, true, context)
+    }
+
+    /// `Temporal.Instant.prototype.round ( roundTo )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.round
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Instant/round
+    fn round(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        let instant = Self::this_instant(this, context)?;
+
+        // 3-5. Let roundTo be the options object.
+        let round_to = get_round_to_options(args.get_or_undefined(0), context)?;
+
+        // 6. NOTE: The following steps read options and perform independent validation in alphabetical order.
+        // 7. Let roundingIncrement be ? GetRoundingIncrementOption(roundTo).
+        let increment = get_rounding_increment(&round_to, context)?;
+
+        // 8. Let roundingMode be ? GetRoundingModeOption(roundTo, half-expand).
+        let rounding_mode =
+            RoundingMode::from_options(&round_to, RoundingMode::HalfExpand, context)?;
+
+        // 9. Let smallestUnit be ? GetTemporalUnitValuedOption(roundTo, "smallestUnit", time, required).
+        let smallest_unit =
+            match get_temporal_unit(&round_to, "smallestUnit", UnitGroup::Time, context)? {
+                Some(smallest_unit) => smallest_unit,
+                None => return context.throw_range_error("smallestUnit is required"),
+            };
+
+        // 10-16. Let maximum be the number of the units in a day.
+        let unit_nanoseconds = smallest_unit
+            .nanoseconds()
+            .expect("the smallest unit must be a unit of time");
+
+        // 17. Perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, true).
+        validate_rounding_increment(increment, NS_PER_DAY / unit_nanoseconds, true, context)?;
+
+        // 18. Let roundedNs be RoundTemporalInstant(instant.[[Nanoseconds]], roundingIncrement, smallestUnit, roundingMode).
+        let epoch_nanoseconds = round_to_increment(
+            instant.epoch_nanoseconds,
+            increment * unit_nanoseconds,
+            rounding_mode,
+        );
+        if !is_valid_epoch_nanoseconds(epoch_nanoseconds) {
+            return context.throw_range_error("the instant is out of range");
+        }
+
+        // 19. Return ! CreateTemporalInstant(roundedNs).
+        Ok(Self::new(epoch_nanoseconds).create(None, context)?.into())
+    }
+
+    /// `Temporal.Instant.prototype.equals ( other )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.equals
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Instant/equals
+    fn equals(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        let instant = Self::this_instant(this, context)?;
+
+        // 3. Set other to ? ToTemporalInstant(other).
+        let other = Self::from_value(args.get_or_undefined(0), context)?;
+
+        // 4. If instant.[[Nanoseconds]] ≠ other.[[Nanoseconds]], return false.
+        // 5. Return true.
+        Ok((instant == other).into())
+    }
+
+    /// Abstract operation `TemporalInstantToString ( instant, timeZone, precision )`
+    ///
+    /// `offset` is the offset of the time zone in nanoseconds, or `None` to format the instant
+    /// in UTC with the `Z` designator.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-temporalinstanttostring
+    pub(crate) fn to_iso_string(self, offset: Option<i64>, precision: Precision) -> String {
+        // 1-5. Let dateTimeString be FormatISODateTime(...) of the instant in the time zone.
+        let date_time = format_epoch_nanoseconds(
+            self.epoch_nanoseconds + i128::from(offset.unwrap_or_default()),
+            precision,
+        );
+
+        // 6. If timeZone is undefined, then
+        //     a. Let timeZoneString be "Z".
+        // 7. Else,
+        //     b. Let timeZoneString be FormatDateTimeUTCOffsetRounded(offsetNanoseconds).
+        let time_zone = offset.map_or_else(|| "Z".to_owned(), format_offset_rounded);
+
+        // 8. Return the string-concatenation of dateTimeString and timeZoneString.
+        format!("{date_time}{time_zone}")
+    }
+
+    /// `Temporal.Instant.prototype.toString ( [ options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.tostring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Instant/toString
+    #[allow(clippy::wrong_self_convention)]
+    fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        let instant = Self::this_instant(this, context)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(0), context)?;
+
+        // 4-8. Read the fractionalSecondDigits, roundingMode and smallestUnit options.
+        let (precision, rounding_mode) = SecondsStringPrecision::from_options(&options, context)?;
+
+        // 9. Let timeZone be ? Get(resolvedOptions, "timeZone").
+        let time_zone = options.get("timeZone", context)?;
+
+        // 10. If timeZone is not undefined, then
+        //     a. Set timeZone to ? ToTemporalTimeZoneIdentifier(timeZone).
+        let time_zone = if time_zone.is_undefined() {
+            None
+        } else {
+            Some(TimeZone::from_value(&time_zone, context)?)
+        };
+
+        // 11-12. Let roundedNs be RoundTemporalInstant(instant.[[Nanoseconds]], precision.[[Increment]], precision.[[Unit]], roundingMode).
+        let epoch_nanoseconds = round_to_increment(
+            instant.epoch_nanoseconds,
+            precision.nanoseconds(),
+            rounding_mode,
+        );
+        if !is_valid_epoch_nanoseconds(epoch_nanoseconds) {
+            return context.throw_range_error("the instant is out of range");
+        }
+
+        // 13. Let roundedInstant be ! CreateTemporalInstant(roundedNs).
+        // 14. Return TemporalInstantToString(roundedInstant, timeZone, precision.[[Precision]]).
+        let offset =
+            time_zone.map(|time_zone| time_zone.offset_nanoseconds_for(epoch_nanoseconds, context));
+        Ok(Self::new(epoch_nanoseconds)
+            .to_iso_string(offset, precision.precision)
+            .into())
+    }
+
+    /// `Temporal.Instant.prototype.toZonedDateTimeISO ( timeZone )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.tozoneddatetimeiso
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Instant/toZonedDateTimeISO
+    #[allow(clippy::wrong_self_convention)]
+    fn to_zoned_date_time_iso(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        let instant = Self::this_instant(this, context)?;
+
+        // 3. Set timeZone to ? ToTemporalTimeZoneIdentifier(timeZone).
+        let time_zone = TimeZone::from_value(args.get_or_undefined(0), context)?;
+
+        // 4. Return ! CreateTemporalZonedDateTime(instant.[[EpochNanoseconds]], timeZone, "iso8601").
+        Ok(
+            ZonedDateTime::new(instant.epoch_nanoseconds, time_zone, Calendar::Iso8601)
+                .create(None, context)?
+                .into(),
+        )
+    }
+
+    /// `Temporal.Instant.prototype.toJSON ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.tojson
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Instant/toJSON
+    #[allow(clippy::wrong_self_convention)]
+    fn to_json(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        // 3. Return TemporalInstantToString(instant, undefined, auto).
+        Ok(Self::this_instant(this, context)?
+            .to_iso_string(None, Precision::Auto)
+            .into())
+    }
+
+    /// `Temporal.Instant.prototype.toLocaleString ( [ locales [ , options ] ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Instant/toLocaleString
+    #[allow(clippy::wrong_self_convention)]
+    fn to_locale_string(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
+        let instant = Self::this_instant(this, context)?;
+
+        // 3. Let dateFormat be ? CreateDateTimeFormat(%DateTimeFormat%, locales, options, any, all).
+        let date_format = DateTimeFormat::new(
+            args.get_or_undefined(0),
+            args.get_or_undefined(1),
+            DateTimeRequired::Any,
+            DateTimeDefaults::All,
+            context,
+        )?;
+
+        // 4. Return ? FormatDateTime(dateFormat, instant).
+        Ok(date_format
+            .format(instant.epoch_nanoseconds.div_euclid(1_000_000) as f64)
+            .expect("the epoch milliseconds of an instant must be a valid time value")
+            .into())
+    }
+
+    /// `Temporal.Instant.prototype.valueOf ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.prototype.valueof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Instant/valueOf
+    fn value_of(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Throw a TypeError exception.
+        context.throw_type_error("a Temporal.Instant can't be converted to a primitive value")
+    }
+}
+
+/// Abstract operation `DifferenceInstant ( ns1, ns2, roundingIncrement, smallestUnit, roundingMode )`
+///
+/// Returns the rounded difference from `one` to `two`, in nanoseconds.
+pub(crate) fn difference_instant(one: i128, two: i128, settings: DifferenceSettings) -> i128 {
+    // 1. Let timeDuration be TimeDurationFromEpochNanosecondsDifference(ns2, ns1).
+    // 2. Set timeDuration to ! RoundTimeDuration(timeDuration, roundingIncrement, smallestUnit, roundingMode).
+    // 3. Return CombineDateAndTimeDuration(ZeroDateDuration(), timeDuration).
+    let unit_nanoseconds = settings
+        .smallest_unit
+        .nanoseconds()
+        .expect("the smallest unit must be a unit of time");
+    round_to_increment(
+        two - one,
+        settings.increment * unit_nanoseconds,
+        settings.rounding_mode,
+    )
+}
+
+/// Abstract operation `IsValidEpochNanoseconds ( epochNanoseconds )`
+pub(crate) fn is_valid_epoch_nanoseconds(epoch_nanoseconds: i128) -> bool {
+    (-NS_MAX_INSTANT..=NS_MAX_INSTANT).contains(&epoch_nanoseconds)
+}
@@ -0,0 +1,224 @@
+use crate::{forward, Context};
+
+#[test]
+fn constructor() {
+    let mut context = Context::default();
+    for (code, expected) in [
+        (
+            "new Temporal.Instant(0n).toString()",
+            "\"1970-01-01T00:00:00Z\"",
+        ),
+        ("new Temporal.Instant(-1n).epochNanoseconds", "-1n"),
+        ("new Temporal.Instant(1500000n).epochMilliseconds", "1"),
+        ("new Temporal.Instant(-1500000n).epochMilliseconds", "-2"),
+        (
+            "new Temporal.Instant(8640000000000000000000n).toString()",
+            "\"+275760-09-13T00:00:00Z\"",
+        ),
+        (
+            "new Temporal.Instant(-8640000000000000000000n).toString()",
+            "\"-271821-04-20T00:00:00Z\"",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+
+    for (code, expected) in [
+        (
+            "new Temporal.Instant(8640000000000000000001n)",
+            "RangeError",
+        ),
+        ("new Temporal.Instant(0)", "TypeError"),
+        ("Temporal.Instant(0n)", "TypeError"),
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {code} }} catch (e) {{ e.name }}")
+            ),
+            format!("\"{expected}\"")
+        );
+    }
+}
+
+#[test]
+fn from() {
+    let mut context = Context::default();
+    for (code, expected) in [
+        (
+            "Temporal.Instant.from('2020-01-01T12:30:15.5+01:00').toString()",
+            "2020-01-01T11:30:15.5Z",
+        ),
+        (
+            "Temporal.Instant.from('20200101T1230Z[Europe/Paris]').toString()",
+            "2020-01-01T12:30:00Z",
+        ),
+        (
+            "Temporal.Instant.fromEpochMilliseconds(1e12).toString()",
+            "2001-09-09T01:46:40Z",
+        ),
+        (
+            "Temporal.Instant.fromEpochNanoseconds(1n).toString()",
+            "1970-01-01T00:00:00.000000001Z",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), format!("\"{expected}\""));
+    }
+
+    for (code, expected) in [
+        ("Temporal.Instant.from('2020-01-01T12:30')", "RangeError"),
+        ("Temporal.Instant.from('2020-01-01Z')", "RangeError"),
+        ("Temporal.Instant.from('2020-02-30T00:00Z')", "RangeError"),
+        ("Temporal.Instant.from(0)", "TypeError"),
+        ("Temporal.Instant.fromEpochMilliseconds(0.5)", "RangeError"),
+        (
+            "Temporal.Instant.fromEpochMilliseconds(8.64e15 + 1)",
+            "RangeError",
+        ),
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {code} }} catch (e) {{ e.name }}")
+            ),
+            format!("\"{expected}\"")
+        );
+    }
+}
+
+#[test]
+fn arithmetic() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        r#"
+        var one = Temporal.Instant.from('2020-01-01T00:00Z');
+        var two = Temporal.Instant.from('2020-01-02T01:30:00.25Z');
+        "#,
+    );
+    for (code, expected) in [
+        ("one.add({ hours: 1 }).toString()", "\"2020-01-01T01:00:00Z\""),
+        ("one.subtract('PT1M').toString()", "\"2019-12-31T23:59:00Z\""),
+        ("one.until(two).toString()", "\"PT91800.25S\""),
+        (
+            "one.until(two, { largestUnit: 'hour' }).toString()",
+            "\"PT25H30M0.25S\"",
+        ),
+        (
+            "two.since(one, { smallestUnit: 'minute', roundingMode: 'ceil' }).toString()",
+            "\"PT1531M\"",
+        ),
+        ("two.until(one, { largestUnit: 'minute' }).minutes", "-1530"),
+        ("Temporal.Instant.compare(one, two)", "-1"),
+        ("Temporal.Instant.compare(two, one)", "1"),
+        ("one.equals('2020-01-01T01:00+01:00')", "true"),
+        ("try { one.add({ days: 1 }) } catch (e) { e.name }", "\"RangeError\""),
+        (
+            "try { one.until(two, { largestUnit: 'second', smallestUnit: 'hour' }) } catch (e) { e.name }",
+            "\"RangeError\"",
+        ),
+        (
+            "try { one.until(two, { largestUnit: 'day' }) } catch (e) { e.name }",
+            "\"RangeError\"",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+}
+
+#[test]
+fn round() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        "var instant = Temporal.Instant.from('2020-01-01T12:34:56.789Z');",
+    );
+    for (code, expected) in [
+        ("instant.round('second').toString()", "2020-01-01T12:34:57Z"),
+        (
+            "instant.round({ smallestUnit: 'minute', roundingIncrement: 15 }).toString()",
+            "2020-01-01T12:30:00Z",
+        ),
+        (
+            "instant.round({ smallestUnit: 'hour', roundingMode: 'ceil' }).toString()",
+            "2020-01-01T13:00:00Z",
+        ),
+        (
+            "instant.round({ smallestUnit: 'hour', roundingIncrement: 24 }).toString()",
+            "2020-01-02T00:00:00Z",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), format!("\"{expected}\""));
+    }
+
+    for (code, expected) in [
+        ("instant.round()", "TypeError"),
+        ("instant.round({})", "RangeError"),
+        ("instant.round('day')", "RangeError"),
+        (
+            "instant.round({ smallestUnit: 'minute', roundingIncrement: 7 })",
+            "RangeError",
+        ),
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {code} }} catch (e) {{ e.name }}")
+            ),
+            format!("\"{expected}\"")
+        );
+    }
+}
+
+#[test]
+fn to_string() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        "var instant = Temporal.Instant.from('2020-01-01T12:34:56.789Z');",
+    );
+    for (code, expected) in [
+        ("instant.toString()", "2020-01-01T12:34:56.789Z"),
+        ("instant.toJSON()", "2020-01-01T12:34:56.789Z"),
+        (
+            "instant.toString({ smallestUnit: 'minute' })",
+            "2020-01-01T12:34Z",
+        ),
+        (
+            "instant.toString({ fractionalSecondDigits: 5 })",
+            "2020-01-01T12:34:56.78900Z",
+        ),
+        (
+            "instant.toString({ fractionalSecondDigits: 1, roundingMode: 'halfExpand' })",
+            "2020-01-01T12:34:56.8Z",
+        ),
+        (
+            "instant.toString({ timeZone: 'UTC' })",
+            "2020-01-01T12:34:56.789+00:00",
+        ),
+        (
+            "instant.toString({ timeZone: '-05:30', smallestUnit: 'second' })",
+            "2020-01-01T07:04:56-05:30",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), format!("\"{expected}\""));
+    }
+
+    for (code, expected) in [
+        ("instant.toString({ smallestUnit: 'hour' })", "RangeError"),
+        (
+            "instant.toString({ timeZone: 'Mars/Olympus_Mons' })",
+            "RangeError",
+        ),
+        ("instant.toString({ timeZone: 0 })", "TypeError"),
+        ("instant.valueOf()", "TypeError"),
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {code} }} catch (e) {{ e.name }}")
+            ),
+            format!("\"{expected}\"")
+        );
+    }
+}
@@ -0,0 +1,939 @@
+//! This module implements the global `Temporal` object.
+//!
+//! `Temporal` is a namespace object holding the constructors of the Temporal date and time API,
+//! and the `Temporal.Now` object. It's not a function object.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal
+
+mod calendar;
+pub mod duration;
+pub mod instant;
+mod now;
+pub mod plain_date;
+pub mod plain_date_time;
+pub mod plain_time;
+mod time_zone;
+pub mod zoned_date_time;
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) use duration::Duration;
+pub(crate) use instant::Instant;
+pub(crate) use now::Clock;
+pub(crate) use plain_date::PlainDate;
+pub(crate) use plain_date_time::PlainDateTime;
+pub(crate) use plain_time::PlainTime;
+pub(crate) use zoned_date_time::ZonedDateTime;
+
+use self::now::Now;
+use crate::{
+    builtins::{
+        intl::{get_option, get_options_object},
+        BuiltIn,
+    },
+    object::{JsObject, ObjectData, ObjectInitializer},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_profiler::Profiler;
+use tap::{Conv, Pipe};
+
+/// The number of nanoseconds in a day.
+pub(crate) const NS_PER_DAY: i128 = 86_400_000_000_000;
+
+/// The largest absolute value of the epoch nanoseconds of an instant, 10^8 days.
+pub(crate) const NS_MAX_INSTANT: i128 = NS_PER_DAY * 100_000_000;
+
+/// JavaScript `Temporal` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct Temporal;
+
+impl BuiltIn for Temporal {
+    const NAME: &'static str = "Temporal";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let now = Now::init(context);
+        let instant = Instant::init(context)
+            .expect("initializing `Temporal.Instant` must return a constructor");
+        let duration = Duration::init(context)
+            .expect("initializing `Temporal.Duration` must return a constructor");
+        let plain_date = PlainDate::init(context)
+            .expect("initializing `Temporal.PlainDate` must return a constructor");
+        let plain_time = PlainTime::init(context)
+            .expect("initializing `Temporal.PlainTime` must return a constructor");
+        let plain_date_time = PlainDateTime::init(context)
+            .expect("initializing `Temporal.PlainDateTime` must return a constructor");
+        let zoned_date_time = ZonedDateTime::init(context)
+            .expect("initializing `Temporal.ZonedDateTime` must return a constructor");
+
+        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        ObjectInitializer::new(context)
+            .property(Now::NAME, now, attribute)
+            .property(Instant::NAME, instant, attribute)
+            .property(Duration::NAME, duration, attribute)
+            .property(PlainDate::NAME, plain_date, attribute)
+            .property(PlainTime::NAME, plain_time, attribute)
+            .property(PlainDateTime::NAME, plain_date_time, attribute)
+            .property(ZonedDateTime::NAME, zoned_date_time, attribute)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build()
+            .conv::<JsValue>()
+            .pipe(Some)
+    }
+}
+
+/// A unit of time of the Temporal API, from the largest to the smallest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum TemporalUnit {
+    Year,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    Millisecond,
+    Microsecond,
+    Nanosecond,
+}
+
+impl TemporalUnit {
+    /// The units of time, from the largest to the smallest.
+    pub(crate) const ALL: [Self; 10] = [
+        Self::Year,
+        Self::Month,
+        Self::Week,
+        Self::Day,
+        Self::Hour,
+        Self::Minute,
+        Self::Second,
+        Self::Millisecond,
+        Self::Microsecond,
+        Self::Nanosecond,
+    ];
+
+    /// Gets the singular name of the unit, such as `"hour"`.
+    pub(crate) fn singular(self) -> &'static str {
+        match self {
+            Self::Year => "year",
+            Self::Month => "month",
+            Self::Week => "week",
+            Self::Day => "day",
+            Self::Hour => "hour",
+            Self::Minute => "minute",
+            Self::Second => "second",
+            Self::Millisecond => "millisecond",
+            Self::Microsecond => "microsecond",
+            Self::Nanosecond => "nanosecond",
+        }
+    }
+
+    /// Gets the plural name of the unit, which is also the name of its field in a duration.
+    pub(crate) fn plural(self) -> &'static str {
+        match self {
+            Self::Year => "years",
+            Self::Month => "months",
+            Self::Week => "weeks",
+            Self::Day => "days",
+            Self::Hour => "hours",
+            Self::Minute => "minutes",
+            Self::Second => "seconds",
+            Self::Millisecond => "milliseconds",
+            Self::Microsecond => "microseconds",
+            Self::Nanosecond => "nanoseconds",
+        }
+    }
+
+    /// Gets the unit from its singular or plural name.
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|unit| unit.singular() == name || unit.plural() == name)
+    }
+
+    /// Returns `true` if the unit is a unit of time, from hours to nanoseconds.
+    pub(crate) fn is_time(self) -> bool {
+        self >= Self::Hour
+    }
+
+    /// Gets the length of the unit in nanoseconds, with days of 24 hours, or `None` for the
+    /// calendar units.
+    pub(crate) fn nanoseconds(self) -> Option<i128> {
+        match self {
+            Self::Year | Self::Month | Self::Week => None,
+            Self::Day => Some(NS_PER_DAY),
+            Self::Hour => Some(3_600_000_000_000),
+            Self::Minute => Some(60_000_000_000),
+            Self::Second => Some(1_000_000_000),
+            Self::Millisecond => Some(1_000_000),
+            Self::Microsecond => Some(1000),
+            Self::Nanosecond => Some(1),
+        }
+    }
+
+    /// Gets the maximum rounding increment of the unit in `MaximumTemporalDurationRoundingIncrement`,
+    /// or `None` if the increment of the unit is not limited.
+    pub(crate) fn maximum_rounding_increment(self) -> Option<i128> {
+        match self {
+            Self::Year | Self::Month | Self::Week | Self::Day => None,
+            Self::Hour => Some(24),
+            Self::Minute | Self::Second => Some(60),
+            Self::Millisecond | Self::Microsecond | Self::Nanosecond => Some(1000),
+        }
+    }
+}
+
+/// The `unitGroup` argument of `GetTemporalUnitValuedOption`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UnitGroup {
+    Date,
+    Time,
+    DateTime,
+}
+
+impl UnitGroup {
+    /// Returns `true` if the group contains `unit`.
+    fn contains(self, unit: TemporalUnit) -> bool {
+        match self {
+            Self::Date => !unit.is_time(),
+            Self::Time => unit.is_time(),
+            Self::DateTime => true,
+        }
+    }
+}
+
+/// Abstract operation `GetTemporalUnitValuedOption ( options, key, unitGroup, default, extraValues )`
+///
+/// Returns `None` if the option is undefined, or if it's `"auto"` for the `largestUnit` option.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-gettemporalunitvaluedoption
+pub(crate) fn get_temporal_unit(
+    options: &JsObject,
+    key: &str,
+    unit_group: UnitGroup,
+    context: &mut Context,
+) -> JsResult<Option<TemporalUnit>> {
+    if key == "largestUnit" {
+        return Ok(get_largest_unit(options, unit_group, context)?.flatten());
+    }
+    let value = options.get(key, context)?;
+    if value.is_undefined() {
+        return Ok(None);
+    }
+    let value = value.to_string(context)?;
+    match TemporalUnit::from_name(&value) {
+        Some(unit) if unit_group.contains(unit) => Ok(Some(unit)),
+        _ => context.throw_range_error(format!("invalid value `{value}` for option `{key}`")),
+    }
+}
+
+/// Abstract operation `GetTemporalUnitValuedOption ( options, "largestUnit", unitGroup, unset, « auto » )`
+///
+/// Returns `None` if the option is undefined, and `Some(None)` if it's `"auto"`.
+pub(crate) fn get_largest_unit(
+    options: &JsObject,
+    unit_group: UnitGroup,
+    context: &mut Context,
+) -> JsResult<Option<Option<TemporalUnit>>> {
+    let value = options.get("largestUnit", context)?;
+    if value.is_undefined() {
+        return Ok(None);
+    }
+    let value = value.to_string(context)?;
+    if value.as_str() == "auto" {
+        return Ok(Some(None));
+    }
+    match TemporalUnit::from_name(&value) {
+        Some(unit) if unit_group.contains(unit) => Ok(Some(Some(unit))),
+        _ => context.throw_range_error(format!("invalid value `{value}` for option `largestUnit`")),
+    }
+}
+
+/// The rounding modes of the Temporal API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RoundingMode {
+    Ceil,
+    Floor,
+    Expand,
+    Trunc,
+    HalfCeil,
+    HalfFloor,
+    HalfExpand,
+    HalfTrunc,
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// Abstract operation `GetRoundingModeOption ( options, fallback )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-getroundingmodeoption
+    pub(crate) fn from_options(
+        options: &JsObject,
+        fallback: Self,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let mode = get_option(
+            options,
+            "roundingMode",
+            &[
+                "ceil",
+                "floor",
+                "expand",
+                "trunc",
+                "halfCeil",
+                "halfFloor",
+                "halfExpand",
+                "halfTrunc",
+                "halfEven",
+            ],
+            context,
+        )?;
+        Ok(match mode {
+            None => fallback,
+            Some("ceil") => Self::Ceil,
+            Some("floor") => Self::Floor,
+            Some("expand") => Self::Expand,
+            Some("trunc") => Self::Trunc,
+            Some("halfCeil") => Self::HalfCeil,
+            Some("halfFloor") => Self::HalfFloor,
+            Some("halfExpand") => Self::HalfExpand,
+            Some("halfTrunc") => Self::HalfTrunc,
+            Some(_) => Self::HalfEven,
+        })
+    }
+
+    /// Abstract operation `NegateRoundingMode ( roundingMode )`
+    pub(crate) fn negate(self) -> Self {
+        match self {
+            Self::Ceil => Self::Floor,
+            Self::Floor => Self::Ceil,
+            Self::HalfCeil => Self::HalfFloor,
+            Self::HalfFloor => Self::HalfCeil,
+            mode => mode,
+        }
+    }
+}
+
+/// Abstract operation `RoundNumberToIncrement ( x, increment, roundingMode )` on integers.
+pub(crate) fn round_to_increment(x: i128, increment: i128, mode: RoundingMode) -> i128 {
+    let lower = x.div_euclid(increment) * increment;
+    let remainder = x - lower;
+    if remainder == 0 {
+        return x;
+    }
+    let upper = lower + increment;
+    let negative = x < 0;
+
+    let round_up = match mode {
+        RoundingMode::Ceil => true,
+        RoundingMode::Floor => false,
+        RoundingMode::Expand => !negative,
+        RoundingMode::Trunc => negative,
+        _ if remainder * 2 < increment => false,
+        _ if remainder * 2 > increment => true,
+        RoundingMode::HalfCeil => true,
+        RoundingMode::HalfFloor => false,
+        RoundingMode::HalfExpand => !negative,
+        RoundingMode::HalfTrunc => negative,
+        RoundingMode::HalfEven => (lower / increment) % 2 != 0,
+    };
+
+    if round_up {
+        upper
+    } else {
+        lower
+    }
+}
+
+/// Divides two integers into the nearest number, with the exact integer part of the quotient.
+pub(crate) fn divide_to_number(dividend: i128, divisor: i128) -> f64 {
+    (dividend / divisor) as f64 + (dividend % divisor) as f64 / divisor as f64
+}
+
+/// Abstract operation `TotalTimeDuration ( timeDuration, unit )`
+///
+/// Gets the total of a time duration, in nanoseconds, in `unit`, with days of 24 hours.
+pub(crate) fn total_time_duration(time_duration: i128, unit: TemporalUnit) -> f64 {
+    // 1. Let divisor be the value in the "Length in Nanoseconds" column of the row of Table 21 whose "Value" column contains unit.
+    // 2. Return timeDuration / divisor.
+    let divisor = unit
+        .nanoseconds()
+        .expect("the unit must be a day or a unit of time");
+    divide_to_number(time_duration, divisor)
+}
+
+/// Abstract operation `GetRoundingIncrementOption ( options )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-getroundingincrementoption
+pub(crate) fn get_rounding_increment(options: &JsObject, context: &mut Context) -> JsResult<i128> {
+    // 1. Let value be ? Get(options, "roundingIncrement").
+    let value = options.get("roundingIncrement", context)?;
+
+    // 2. If value is undefined, return 1𝔽.
+    if value.is_undefined() {
+        return Ok(1);
+    }
+
+    // 3. Let integerIncrement be ? ToIntegerWithTruncation(value).
+    let increment = value.to_number(context)?;
+    if !increment.is_finite() {
+        return context.throw_range_error("roundingIncrement must be finite");
+    }
+    let increment = increment.trunc();
+
+    // 4. If integerIncrement < 1 or integerIncrement > 10^9, throw a RangeError exception.
+    if !(1.0..=1e9).contains(&increment) {
+        return context.throw_range_error("roundingIncrement must be between 1 and 10^9");
+    }
+
+    // 5. Return integerIncrement.
+    Ok(increment as i128)
+}
+
+/// Abstract operation `ValidateTemporalRoundingIncrement ( increment, dividend, inclusive )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-validatetemporalroundingincrement
+pub(crate) fn validate_rounding_increment(
+    increment: i128,
+    dividend: i128,
+    inclusive: bool,
+    context: &mut Context,
+) -> JsResult<()> {
+    // 1. If inclusive is true, then
+    //     a. Let maximum be dividend.
+    // 2. Else,
+    //     a. Assert: dividend > 1.
+    //     b. Let maximum be dividend - 1.
+    let maximum = if inclusive { dividend } else { dividend - 1 };
+
+    // 3. If increment > maximum, throw a RangeError exception.
+    // 4. If dividend modulo increment ≠ 0, then
+    //     a. Throw a RangeError exception.
+    if increment > maximum || dividend % increment != 0 {
+        return context.throw_range_error(format!(
+            "roundingIncrement {increment} must be a divisor of {dividend}"
+        ));
+    }
+
+    // 5. Return unused.
+    Ok(())
+}
+
+/// The result of `GetDifferenceSettings`, the options of the `until` and `since` methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct DifferenceSettings {
+    /// `[[LargestUnit]]`
+    pub(crate) largest_unit: TemporalUnit,
+    /// `[[SmallestUnit]]`
+    pub(crate) smallest_unit: TemporalUnit,
+    /// `[[RoundingIncrement]]`
+    pub(crate) increment: i128,
+    /// `[[RoundingMode]]`, already negated for the `since` methods.
+    pub(crate) rounding_mode: RoundingMode,
+}
+
+impl DifferenceSettings {
+    /// Abstract operation `GetDifferenceSettings ( operation, options, unitGroup, disallowedUnits, fallbackSmallestUnit, smallestLargestDefaultUnit )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-getdifferencesettings
+    pub(crate) fn from_options(
+        options: &JsValue,
+        since: bool,
+        unit_group: UnitGroup,
+        fallback_smallest_unit: TemporalUnit,
+        smallest_largest_default_unit: TemporalUnit,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let options = get_options_object(options, context)?;
+
+        // 1. NOTE: The following steps read options and perform independent validation in alphabetical order.
+        // 2. Let largestUnit be ? GetTemporalUnitValuedOption(options, "largestUnit", unitGroup, auto).
+        let largest_unit = get_temporal_unit(&options, "largestUnit", unit_group, context)?;
+
+        // 4. Let roundingIncrement be ? GetRoundingIncrementOption(options).
+        let increment = get_rounding_increment(&options, context)?;
+
+        // 5. Let roundingMode be ? GetRoundingModeOption(options, trunc).
+        let rounding_mode = RoundingMode::from_options(&options, RoundingMode::Trunc, context)?;
+
+        // 6. Let smallestUnit be ? GetTemporalUnitValuedOption(options, "smallestUnit", unitGroup, fallbackSmallestUnit).
+        let smallest_unit = get_temporal_unit(&options, "smallestUnit", unit_group, context)?
+            .unwrap_or(fallback_smallest_unit);
+
+        // 8. Let defaultLargestUnit be LargerOfTwoTemporalUnits(smallestLargestDefaultUnit, smallestUnit).
+        // 9. If largestUnit is auto, set largestUnit to defaultLargestUnit.
+        let largest_unit =
+            largest_unit.unwrap_or_else(|| smallest_largest_default_unit.min(smallest_unit));
+
+        // 10. If LargerOfTwoTemporalUnits(largestUnit, smallestUnit) is not largestUnit, throw a RangeError exception.
+        if largest_unit > smallest_unit {
+            return context.throw_range_error("largestUnit must not be smaller than smallestUnit");
+        }
+
+        // 11. Let maximum be MaximumTemporalDurationRoundingIncrement(smallestUnit).
+        // 12. If maximum is not unset, perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, false).
+        if let Some(maximum) = smallest_unit.maximum_rounding_increment() {
+            validate_rounding_increment(increment, maximum, false, context)?;
+        }
+
+        // 13. If operation is since, then
+        //     a. Set roundingMode to NegateRoundingMode(roundingMode).
+        let rounding_mode = if since {
+            rounding_mode.negate()
+        } else {
+            rounding_mode
+        };
+
+        // 14. Return the Record { [[SmallestUnit]]: smallestUnit, [[LargestUnit]]: largestUnit, [[RoundingMode]]: roundingMode, [[RoundingIncrement]]: roundingIncrement,  }.
+        Ok(Self {
+            largest_unit,
+            smallest_unit,
+            increment,
+            rounding_mode,
+        })
+    }
+
+    /// Returns `true` if the difference doesn't need to be rounded.
+    pub(crate) fn is_exact(self) -> bool {
+        self.smallest_unit == TemporalUnit::Nanosecond && self.increment == 1
+    }
+}
+
+/// Gets the options of the `round` methods, which can be the `smallestUnit` as a string.
+pub(crate) fn get_round_to_options(
+    round_to: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    match round_to {
+        // 3. If roundTo is undefined, then
+        //     a. Throw a TypeError exception.
+        JsValue::Undefined => {
+            context.throw_type_error("the rounding options must not be undefined")
+        }
+        // 4. If roundTo is a String, then
+        JsValue::String(smallest_unit) => {
+            // a. Let paramString be roundTo.
+            // b. Set roundTo to OrdinaryObjectCreate(null).
+            let round_to = JsObject::from_proto_and_data(None, ObjectData::ordinary());
+
+            // c. Perform ! CreateDataPropertyOrThrow(roundTo, "smallestUnit", paramString).
+            round_to
+                .create_data_property_or_throw("smallestUnit", smallest_unit.clone(), context)
+                .expect("CreateDataPropertyOrThrow must not fail on an ordinary object");
+            Ok(round_to)
+        }
+        // 5. Else,
+        //     a. Set roundTo to ? GetOptionsObject(roundTo).
+        round_to => get_options_object(round_to, context),
+    }
+}
+
+/// The `overflow` option, for the fields out of their range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Overflow {
+    Constrain,
+    Reject,
+}
+
+impl Overflow {
+    /// Abstract operation `GetTemporalOverflowOption ( options )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-gettemporaloverflowoption
+    pub(crate) fn from_options(options: &JsValue, context: &mut Context) -> JsResult<Self> {
+        let options = get_options_object(options, context)?;
+        match get_option(&options, "overflow", &["constrain", "reject"], context)? {
+            Some("reject") => Ok(Self::Reject),
+            _ => Ok(Self::Constrain),
+        }
+    }
+}
+
+/// Abstract operation `ToIntegerWithTruncation ( argument )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-tointegerwithtruncation
+pub(crate) fn to_integer_with_truncation(
+    argument: &JsValue,
+    context: &mut Context,
+) -> JsResult<f64> {
+    // 1. Let number be ? ToNumber(argument).
+    let number = argument.to_number(context)?;
+
+    // 2. If number is NaN, +∞𝔽 or -∞𝔽, throw a RangeError exception.
+    if !number.is_finite() {
+        return context.throw_range_error(format!("{number} is not a finite number"));
+    }
+
+    // 3. Return truncate(ℝ(number)).
+    Ok(number.trunc() + 0.0)
+}
+
+/// Abstract operation `ToPositiveIntegerWithTruncation ( argument )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-topositiveintegerwithtruncation
+pub(crate) fn to_positive_integer_with_truncation(
+    argument: &JsValue,
+    context: &mut Context,
+) -> JsResult<f64> {
+    // 1. Let integer be ? ToIntegerWithTruncation(argument).
+    let integer = to_integer_with_truncation(argument, context)?;
+
+    // 2. If integer ≤ 0, throw a RangeError exception.
+    if integer <= 0.0 {
+        return context.throw_range_error(format!("{integer} is not a positive integer"));
+    }
+
+    // 3. Return integer.
+    Ok(integer)
+}
+
+/// Abstract operation `IsPartialTemporalObject ( value )`
+///
+/// Returns the object if it can be used as the argument of the `with` methods, or throws a
+/// `TypeError`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-ispartialtemporalobject
+pub(crate) fn to_partial_temporal_object(
+    value: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    // 1. If value is not an Object, return false.
+    let object = match value {
+        JsValue::Object(object) => object,
+        _ => return context.throw_type_error("the fields to replace must be an object"),
+    };
+
+    // 2. If value has an [[InitializedTemporalDate]], [[InitializedTemporalDateTime]], [[InitializedTemporalMonthDay]], [[InitializedTemporalTime]], [[InitializedTemporalYearMonth]], or [[InitializedTemporalZonedDateTime]] internal slot, return false.
+    let is_temporal = {
+        let object = object.borrow();
+        object.as_plain_date().is_some()
+            || object.as_plain_date_time().is_some()
+            || object.as_plain_time().is_some()
+            || object.as_zoned_date_time().is_some()
+    };
+    if is_temporal {
+        return context.throw_type_error("the fields to replace must not be a Temporal object");
+    }
+
+    // 3. Let calendarProperty be ? Get(value, "calendar").
+    // 4. If calendarProperty is not undefined, return false.
+    // 5. Let timeZoneProperty be ? Get(value, "timeZone").
+    // 6. If timeZoneProperty is not undefined, return false.
+    for key in ["calendar", "timeZone"] {
+        if !object.get(key, context)?.is_undefined() {
+            return context
+                .throw_type_error(format!("the fields to replace must not have a `{key}`"));
+        }
+    }
+
+    // 7. Return true.
+    Ok(object.clone())
+}
+
+/// Abstract operation `GetTemporalFractionalSecondDigitsOption ( options )`
+///
+/// Returns `None` for `auto`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-gettemporalfractionalseconddigitsoption
+pub(crate) fn get_fractional_second_digits(
+    options: &JsObject,
+    context: &mut Context,
+) -> JsResult<Option<u8>> {
+    let digits = options.get("fractionalSecondDigits", context)?;
+    if digits.is_undefined() {
+        return Ok(None);
+    }
+    if let Some(digits) = digits.as_number() {
+        let digits = digits.floor();
+        if !(0.0..=9.0).contains(&digits) {
+            return context.throw_range_error("fractionalSecondDigits must be between 0 and 9");
+        }
+        return Ok(Some(digits as u8));
+    }
+    let digits = digits.to_string(context)?;
+    if digits.as_str() != "auto" {
+        return context.throw_range_error(format!(
+            "invalid value `{digits}` for option `fractionalSecondDigits`"
+        ));
+    }
+    Ok(None)
+}
+
+/// The precision of the seconds of a formatted time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Precision {
+    /// The fraction of the seconds is formatted without trailing zeros.
+    Auto,
+    /// The seconds are omitted.
+    Minute,
+    /// The fraction of the seconds is formatted with this number of digits.
+    Digits(u8),
+}
+
+/// The result of `ToSecondsStringPrecisionRecord`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SecondsStringPrecision {
+    /// `[[Precision]]`
+    pub(crate) precision: Precision,
+    /// `[[Unit]]`
+    pub(crate) unit: TemporalUnit,
+    /// `[[Increment]]`
+    pub(crate) increment: i128,
+}
+
+impl SecondsStringPrecision {
+    /// Reads the `fractionalSecondDigits` and `smallestUnit` options, as in the `toString` methods.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-tosecondsstringprecisionrecord
+    pub(crate) fn from_options(
+        options: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<(Self, RoundingMode)> {
+        let digits = get_fractional_second_digits(options, context)?;
+        let rounding_mode = RoundingMode::from_options(options, RoundingMode::Trunc, context)?;
+        let smallest_unit = get_temporal_unit(options, "smallestUnit", UnitGroup::Time, context)?;
+        let record = Self::new(smallest_unit, digits, context)?;
+        Ok((record, rounding_mode))
+    }
+
+    /// Abstract operation `ToSecondsStringPrecisionRecord ( smallestUnit, fractionalDigitCount )`
+    ///
+    /// `digits` is `None` for `auto`. Throws a `RangeError` if the smallest unit is `hour`.
+    pub(crate) fn new(
+        smallest_unit: Option<TemporalUnit>,
+        digits: Option<u8>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        Ok(match smallest_unit {
+            Some(TemporalUnit::Hour) => {
+                return context.throw_range_error("smallestUnit must not be `hour`")
+            }
+            Some(TemporalUnit::Minute) => Self {
+                precision: Precision::Minute,
+                unit: TemporalUnit::Minute,
+                increment: 1,
+            },
+            Some(TemporalUnit::Second) => Self::digits(0),
+            Some(TemporalUnit::Millisecond) => Self::digits(3),
+            Some(TemporalUnit::Microsecond) => Self::digits(6),
+            Some(_) => Self::digits(9),
+            None => match digits {
+                None => Self {
+                    precision: Precision::Auto,
+                    unit: TemporalUnit::Nanosecond,
+                    increment: 1,
+                },
+                Some(digits) => Self::digits(digits),
+            },
+        })
+    }
+
+    /// Creates the record of a fixed number of fractional digits.
+    fn digits(digits: u8) -> Self {
+        let (unit, exponent) = match digits {
+            0 => (TemporalUnit::Second, 0),
+            1..=3 => (TemporalUnit::Millisecond, 3 - digits),
+            4..=6 => (TemporalUnit::Microsecond, 6 - digits),
+            _ => (TemporalUnit::Nanosecond, 9 - digits),
+        };
+        Self {
+            precision: Precision::Digits(digits),
+            unit,
+            increment: 10i128.pow(u32::from(exponent)),
+        }
+    }
+
+    /// Gets the length of the rounding increment in nanoseconds.
+    pub(crate) fn nanoseconds(self) -> i128 {
+        self.increment
+            * self
+                .unit
+                .nanoseconds()
+                .expect("the unit of a seconds precision must be a unit of time")
+    }
+}
+
+/// Gets the number of days from the epoch to an ISO date, which can be out of the range of
+/// valid dates.
+pub(crate) fn epoch_days_from_iso(year: i32, month: u8, day: u8) -> i64 {
+    // The days from civil algorithm, with years starting in March.
+    let (year, month) = if month <= 2 {
+        (i64::from(year) - 1, i64::from(month) + 9)
+    } else {
+        (i64::from(year), i64::from(month) - 3)
+    };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * month + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Gets the ISO date of a number of days from the epoch.
+pub(crate) fn iso_from_epoch_days(days: i64) -> (i32, u8, u8) {
+    // The civil from days algorithm, with years starting in March.
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year as i32, month as u8, day as u8)
+}
+
+/// Abstract operation `PadISOYear ( y )`
+pub(crate) fn pad_iso_year(year: i32) -> String {
+    if (0..=9999).contains(&year) {
+        format!("{year:04}")
+    } else {
+        let sign = if year < 0 { '-' } else { '+' };
+        format!("{sign}{:06}", year.unsigned_abs())
+    }
+}
+
+/// Abstract operation `FormatTimeString ( hour, minute, second, subSecondNanoseconds, precision )`
+pub(crate) fn format_time(
+    hour: u8,
+    minute: u8,
+    second: u8,
+    subsecond_nanoseconds: u32,
+    precision: Precision,
+) -> String {
+    let mut result = format!("{hour:02}:{minute:02}");
+    if precision == Precision::Minute {
+        return result;
+    }
+    result.push_str(&format!(":{second:02}"));
+    result.push_str(&format_fraction(subsecond_nanoseconds, precision));
+    result
+}
+
+/// Abstract operation `FormatFractionalSeconds ( subSecondNanoseconds, precision )`
+///
+/// Returns the fraction of the seconds with its leading `.`, or an empty string.
+pub(crate) fn format_fraction(subsecond_nanoseconds: u32, precision: Precision) -> String {
+    let fraction = format!("{subsecond_nanoseconds:09}");
+    let fraction = match precision {
+        Precision::Digits(digits) => &fraction[..usize::from(digits)],
+        Precision::Auto => fraction.trim_end_matches('0'),
+        Precision::Minute => "",
+    };
+    if fraction.is_empty() {
+        String::new()
+    } else {
+        format!(".{fraction}")
+    }
+}
+
+/// Abstract operation `FormatOffsetTimeZoneIdentifier ( offsetMinutes )`
+pub(crate) fn format_offset_minutes(offset_minutes: i64) -> String {
+    let sign = if offset_minutes < 0 { '-' } else { '+' };
+    let offset_minutes = offset_minutes.abs();
+    format!(
+        "{sign}{:02}:{:02}",
+        offset_minutes / 60,
+        offset_minutes % 60
+    )
+}
+
+/// Abstract operation `FormatUTCOffsetNanoseconds ( offsetNanoseconds )`
+///
+/// The seconds and their fraction are only formatted if they are not zero.
+pub(crate) fn format_offset_nanoseconds(offset_nanoseconds: i64) -> String {
+    let sign = if offset_nanoseconds < 0 { '-' } else { '+' };
+    let offset = offset_nanoseconds.unsigned_abs();
+    let seconds = offset / 1_000_000_000;
+    let precision = if offset % 60_000_000_000 == 0 {
+        Precision::Minute
+    } else {
+        Precision::Auto
+    };
+    let time = format_time(
+        (seconds / 3600) as u8,
+        (seconds / 60 % 60) as u8,
+        (seconds % 60) as u8,
+        (offset % 1_000_000_000) as u32,
+        precision,
+    );
+    format!("{sign}{time}")
+}
+
+/// Abstract operation `FormatDateTimeUTCOffsetRounded ( offsetNanoseconds )`
+///
+/// Formats the offset rounded to the nearest minute.
+pub(crate) fn format_offset_rounded(offset_nanoseconds: i64) -> String {
+    let offset = round_to_increment(
+        i128::from(offset_nanoseconds),
+        60_000_000_000,
+        RoundingMode::HalfExpand,
+    );
+    format_offset_minutes((offset / 60_000_000_000) as i64)
+}
+
+/// Formats the date and time of an epoch nanoseconds value, as `YYYY-MM-DDTHH:mm:ss.sssssssss`.
+pub(crate) fn format_epoch_nanoseconds(epoch_nanoseconds: i128, precision: Precision) -> String {
+    let days = epoch_nanoseconds.div_euclid(NS_PER_DAY);
+    let nanoseconds = epoch_nanoseconds.rem_euclid(NS_PER_DAY);
+    let (year, month, day) = iso_from_epoch_days(days as i64);
+    let seconds = nanoseconds / 1_000_000_000;
+    format!(
+        "{}-{month:02}-{day:02}T{}",
+        pad_iso_year(year),
+        format_time(
+            (seconds / 3600) as u8,
+            (seconds / 60 % 60) as u8,
+            (seconds % 60) as u8,
+            (nanoseconds % 1_000_000_000) as u32,
+            precision,
+        )
+    )
+}
@@ -0,0 +1,261 @@
+//! This module implements the `Temporal.Now` object.
+//!
+//! `Temporal.Now` gives the current time, from the clock of the host, and the current date and
+//! time in a time zone. Embedders can replace the system clock with
+//! [`Context::set_clock`](crate::Context::set_clock). The time zone of the host is given by the
+//! [`TimeZoneProvider`](crate::context::TimeZoneProvider) of the context, or is its current UTC
+//! offset.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-now-object
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Now
+
+use super::{
+    calendar::Calendar, plain_date_time::IsoDateTime, time_zone::TimeZone, Instant, PlainDate,
+    PlainDateTime, PlainTime, ZonedDateTime, NS_MAX_INSTANT,
+};
+use crate::{
+    builtins::JsArgs, object::ObjectInitializer, property::Attribute, symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_profiler::Profiler;
+use chrono::{Local, Offset};
+use std::{
+    fmt,
+    rc::Rc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// The host defined clock of a context, returning the nanoseconds since the epoch.
+#[derive(Clone)]
+pub(crate) struct Clock(Rc<dyn Fn() -> i128>);
+
+impl Clock {
+    pub(crate) fn new<F>(clock: F) -> Self
+    where
+        F: Fn() -> i128 + 'static,
+    {
+        Self(Rc::new(clock))
+    }
+
+    /// Gets the current time from the clock, in nanoseconds since the epoch.
+    pub(crate) fn now(&self) -> i128 {
+        (self.0)()
+    }
+
+    /// Gets the current time from the system clock, in nanoseconds since the epoch.
+    pub(crate) fn system() -> i128 {
+        match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(duration) => duration.as_nanos() as i128,
+            Err(error) => -(error.duration().as_nanos() as i128),
+        }
+    }
+}
+
+impl fmt::Debug for Clock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Clock").finish_non_exhaustive()
+    }
+}
+
+/// Abstract operation `SystemUTCEpochNanoseconds ( )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-systemutcepochnanoseconds
+pub(super) fn system_utc_epoch_nanoseconds(context: &Context) -> i128 {
+    // 1. Let global be GetGlobalObject().
+    // 2. Let nowNs be HostSystemUTCEpochNanoseconds(global).
+    let now = context.host_system_utc_epoch_nanoseconds();
+
+    // 3. Return ℤ(nowNs), clamped to the range of the valid epoch nanoseconds.
+    now.clamp(-NS_MAX_INSTANT, NS_MAX_INSTANT)
+}
+
+/// Abstract operation `SystemTimeZoneIdentifier ( )`
+///
+/// Returns the time zone of the host given by the time zone provider, or the current UTC offset
+/// of the host.
+fn system_time_zone(context: &Context) -> TimeZone {
+    if let Some(identifier) = context.time_zone_provider().system_time_zone() {
+        return TimeZone::Named(identifier);
+    }
+    match Local::now().offset().fix().local_minus_utc() / 60 {
+        0 => TimeZone::utc(),
+        offset_minutes => TimeZone::Offset(i64::from(offset_minutes)),
+    }
+}
+
+/// Abstract operation `SystemDateTime ( temporalTimeZoneLike )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-systemdatetime
+fn system_date_time(time_zone_like: &JsValue, context: &mut Context) -> JsResult<IsoDateTime> {
+    // 1. If temporalTimeZoneLike is undefined, then
+    //     a. Let timeZone be SystemTimeZoneIdentifier().
+    // 2. Else,
+    //     a. Let timeZone be ? ToTemporalTimeZoneIdentifier(temporalTimeZoneLike).
+    let time_zone = if time_zone_like.is_undefined() {
+        system_time_zone(context)
+    } else {
+        TimeZone::from_value(time_zone_like, context)?
+    };
+
+    // 3. Let epochNs be SystemUTCEpochNanoseconds().
+    let epoch_nanoseconds = system_utc_epoch_nanoseconds(context);
+
+    // 4. Return GetISODateTimeFor(timeZone, epochNs).
+    Ok(time_zone.iso_date_time_for(epoch_nanoseconds, context))
+}
+
+/// JavaScript `Temporal.Now` object.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Now;
+
+impl Now {
+    pub(super) const NAME: &'static str = "Now";
+
+    /// Creates the `Temporal.Now` object.
+    pub(super) fn init(context: &mut Context) -> JsValue {
+        let _timer = Profiler::global().start_event("Temporal.Now", "init");
+
+        ObjectInitializer::new(context)
+            .function(Self::instant, "instant", 0)
+            .function(Self::time_zone_id, "timeZoneId", 0)
+            .function(Self::zoned_date_time_iso, "zonedDateTimeISO", 0)
+            .function(Self::plain_date_time_iso, "plainDateTimeISO", 0)
+            .function(Self::plain_date_iso, "plainDateISO", 0)
+            .function(Self::plain_time_iso, "plainTimeISO", 0)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                "Temporal.Now",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build()
+            .into()
+    }
+
+    /// `Temporal.Now.instant ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.now.instant
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Now/instant
+    fn instant(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let ns be SystemUTCEpochNanoseconds().
+        let nanoseconds = system_utc_epoch_nanoseconds(context);
+
+        // 2. Return ! CreateTemporalInstant(ns).
+        Ok(Instant::new(nanoseconds).create(None, context)?.into())
+    }
+
+    /// `Temporal.Now.timeZoneId ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.now.timezoneid
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Now/timeZoneId
+    fn time_zone_id(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return SystemTimeZoneIdentifier().
+        Ok(system_time_zone(context).identifier().into())
+    }
+
+    /// `Temporal.Now.zonedDateTimeISO ( [ temporalTimeZoneLike ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.now.zoneddatetimeiso
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Now/zonedDateTimeISO
+    fn zoned_date_time_iso(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If temporalTimeZoneLike is undefined, then
+        //     a. Let timeZone be SystemTimeZoneIdentifier().
+        // 2. Else,
+        //     a. Let timeZone be ? ToTemporalTimeZoneIdentifier(temporalTimeZoneLike).
+        let time_zone = match args.get_or_undefined(0) {
+            JsValue::Undefined => system_time_zone(context),
+            time_zone_like => TimeZone::from_value(time_zone_like, context)?,
+        };
+
+        // 3. Let ns be SystemUTCEpochNanoseconds().
+        let nanoseconds = system_utc_epoch_nanoseconds(context);
+
+        // 4. Return ! CreateTemporalZonedDateTime(ns, timeZone, "iso8601").
+        Ok(
+            ZonedDateTime::new(nanoseconds, time_zone, Calendar::Iso8601)
+                .create(None, context)?
+                .into(),
+        )
+    }
+
+    /// `Temporal.Now.plainDateTimeISO ( [ temporalTimeZoneLike ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.now.plaindatetimeiso
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Now/plainDateTimeISO
+    fn plain_date_time_iso(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let isoDateTime be ? SystemDateTime(temporalTimeZoneLike).
+        let date_time = system_date_time(args.get_or_undefined(0), context)?;
+
+        // 2. Return ! CreateTemporalDateTime(isoDateTime, "iso8601").
+        Ok(PlainDateTime::new(date_time, Calendar::Iso8601)
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.Now.plainDateISO ( [ temporalTimeZoneLike ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.now.plaindateiso
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Now/plainDateISO
+    fn plain_date_iso(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let isoDateTime be ? SystemDateTime(temporalTimeZoneLike).
+        let date_time = system_date_time(args.get_or_undefined(0), context)?;
+
+        // 2. Return ! CreateTemporalDate(isoDateTime.[[ISODate]], "iso8601").
+        Ok(PlainDate::new(date_time.date, Calendar::Iso8601)
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.Now.plainTimeISO ( [ temporalTimeZoneLike ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.now.plaintimeiso
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Now/plainTimeISO
+    fn plain_time_iso(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let isoDateTime be ? SystemDateTime(temporalTimeZoneLike).
+        let date_time = system_date_time(args.get_or_undefined(0), context)?;
+
+        // 2. Return ! CreateTemporalTime(isoDateTime.[[Time]]).
+        Ok(PlainTime::new(date_time.time).create(None, context)?.into())
+    }
+}
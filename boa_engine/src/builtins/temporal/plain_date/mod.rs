@@ -0,0 +1,1049 @@
+//! This module implements the global `Temporal.PlainDate` object.
+//!
+//! A `Temporal.PlainDate` is a calendar date, which is not associated with a time or a time
+//! zone.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-plaindate-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDate
+
+#[cfg(test)]
+mod tests;
+
+use super::{
+    calendar::{Calendar, Fields, ShowCalendar, DATE_GETTERS},
+    epoch_days_from_iso, iso_from_epoch_days, pad_iso_year,
+    plain_date_time::{round_relative_duration, IsoDateTime, PlainDateTime, RelativeOrigin},
+    plain_time::{ordering_to_value, IsoTime, PlainTime},
+    time_zone::{Disambiguation, TimeZone},
+    to_integer_with_truncation, to_partial_temporal_object, DifferenceSettings, Duration, Overflow,
+    TemporalUnit, UnitGroup, ZonedDateTime, NS_MAX_INSTANT, NS_PER_DAY,
+};
+use crate::{
+    builtins::{
+        intl::get_options_object,
+        iso8601::{iso_days_in_month, parse_date_time, UtcOffset},
+        BuiltIn, JsArgs,
+    },
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use std::cmp::Ordering;
+use tap::{Conv, Pipe};
+
+/// An ISO date record, a valid date of the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct IsoDate {
+    pub(crate) year: i32,
+    pub(crate) month: u8,
+    pub(crate) day: u8,
+}
+
+impl IsoDate {
+    /// Abstract operation `RegulateISODate ( year, month, day, overflow )`
+    ///
+    /// Returns `None` if the date is invalid and `overflow` is `Reject`. The years far out of
+    /// range are clamped, so the resulting date is out of the limits of `is_within_limits`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-regulateisodate
+    pub(crate) fn regulate(year: f64, month: f64, day: f64, overflow: Overflow) -> Option<Self> {
+        let year = year.clamp(-1_000_000.0, 1_000_000.0) as i32;
+        let (month, day) = match overflow {
+            // 1. If overflow is constrain, then
+            Overflow::Constrain => {
+                // a. Set month to the result of clamping month between 1 and 12.
+                let month = month.clamp(1.0, 12.0) as u8;
+
+                // b. Let daysInMonth be ISODaysInMonth(year, month).
+                // c. Set day to the result of clamping day between 1 and daysInMonth.
+                let day = day.clamp(1.0, f64::from(iso_days_in_month(year, month))) as u8;
+                (month, day)
+            }
+            // 2. Else,
+            //     a. Assert: overflow is reject.
+            //     b. If IsValidISODate(year, month, day) is false, throw a RangeError exception.
+            Overflow::Reject => {
+                if !(1.0..=12.0).contains(&month) {
+                    return None;
+                }
+                let month = month as u8;
+                if !(1.0..=f64::from(iso_days_in_month(year, month))).contains(&day) {
+                    return None;
+                }
+                (month, day as u8)
+            }
+        };
+
+        // 3. Return CreateISODateRecord(year, month, day).
+        Some(Self { year, month, day })
+    }
+
+    /// Gets the number of days from the epoch to the date.
+    pub(crate) fn epoch_days(self) -> i64 {
+        epoch_days_from_iso(self.year, self.month, self.day)
+    }
+
+    /// Gets the date of a number of days from the epoch.
+    pub(crate) fn from_epoch_days(days: i64) -> Self {
+        let (year, month, day) = iso_from_epoch_days(days);
+        Self { year, month, day }
+    }
+
+    /// Abstract operation `ISODateWithinLimits ( isoDate )`
+    ///
+    /// Returns `true` if the noon of the date is within the limits of the date-times.
+    pub(crate) fn is_within_limits(self) -> bool {
+        let epoch_nanoseconds = i128::from(self.epoch_days()) * NS_PER_DAY + NS_PER_DAY / 2;
+        epoch_nanoseconds.abs() < NS_MAX_INSTANT + NS_PER_DAY
+    }
+
+    /// Adds years, months, weeks and days to the date, as in the ISO 8601 calendar.
+    ///
+    /// Returns `None` if the intermediate date is invalid and `overflow` is `Reject`, or if the
+    /// result is too far out of range to be represented.
+    pub(crate) fn add(
+        self,
+        years: i64,
+        months: i64,
+        weeks: i64,
+        days: i64,
+        overflow: Overflow,
+    ) -> Option<Self> {
+        // 1. Let intermediate be BalanceISOYearMonth(date.[[Year]] + duration.[[Years]], date.[[Month]] + duration.[[Months]]).
+        let (year, month) = balance_year_month(
+            i128::from(self.year) + i128::from(years),
+            i128::from(self.month) + i128::from(months),
+        );
+        if year.abs() > 1_000_000 {
+            return None;
+        }
+
+        // 2. Let intermediate be ? RegulateISODate(intermediate.[[Year]], intermediate.[[Month]], date.[[Day]], overflow).
+        let intermediate =
+            Self::regulate(year as f64, month as f64, f64::from(self.day), overflow)?;
+
+        // 3. Let days be duration.[[Days]] + 7 × duration.[[Weeks]].
+        // 4. Return BalanceISODate(intermediate.[[Year]], intermediate.[[Month]], intermediate.[[Day]] + days).
+        let days = i128::from(intermediate.epoch_days()) + i128::from(weeks) * 7 + i128::from(days);
+        if days.abs() > 1_000_000_000 {
+            return None;
+        }
+        Some(Self::from_epoch_days(days as i64))
+    }
+
+    /// Abstract operation `ISODateSurpasses ( sign, y1, m1, d1, isoDate2 )`
+    ///
+    /// Returns `true` if the possibly invalid date `year-month-day` is beyond `other` in the
+    /// direction of `sign`.
+    fn surpasses(sign: i64, year: i128, month: i128, day: i128, other: Self) -> bool {
+        let other = (
+            i128::from(other.year),
+            i128::from(other.month),
+            i128::from(other.day),
+        );
+        match (year, month, day).cmp(&other) {
+            Ordering::Less => sign < 0,
+            Ordering::Equal => false,
+            Ordering::Greater => sign > 0,
+        }
+    }
+
+    /// The ISO 8601 part of `CalendarDateUntil ( calendar, one, two, largestUnit )`
+    ///
+    /// Returns the years, months, weeks and days from the date to `other`. The spec steps count
+    /// the units one by one; the counts are computed directly here, then adjusted by one if they
+    /// went past `other`.
+    pub(crate) fn difference(self, other: Self, largest_unit: TemporalUnit) -> [i64; 4] {
+        // 1. Let sign be -CompareISODate(one, two).
+        // 2. If sign = 0, return ZeroDateDuration().
+        let sign: i64 = match other.cmp(&self) {
+            Ordering::Less => -1,
+            Ordering::Equal => return [0; 4],
+            Ordering::Greater => 1,
+        };
+        let year = i128::from(self.year);
+        let month = i128::from(self.month);
+        let day = i128::from(self.day);
+
+        // 3. Let years be 0.
+        // 4. If largestUnit is year, then
+        //     a. Let candidateYears be sign.
+        //     b. Repeat, while ISODateSurpasses(sign, one.[[Year]] + candidateYears, one.[[Month]], one.[[Day]], two) is false,
+        //         i. Set years to candidateYears.
+        //         ii. Set candidateYears to candidateYears + sign.
+        let mut years = 0;
+        if largest_unit == TemporalUnit::Year {
+            years = i128::from(other.year) - year;
+            while years != 0 && Self::surpasses(sign, year + years, month, day, other) {
+                years -= i128::from(sign);
+            }
+        }
+
+        // 5. Let months be 0.
+        // 6. If largestUnit is year or largestUnit is month, then
+        //     ...
+        let mut months = 0;
+        if largest_unit <= TemporalUnit::Month {
+            months = (i128::from(other.year) * 12 + i128::from(other.month))
+                - ((year + years) * 12 + month);
+            let surpasses = |months| {
+                let (y, m) = balance_year_month(year + years, month + months);
+                Self::surpasses(sign, y, m, day, other)
+            };
+            while months != 0 && surpasses(months) {
+                months -= i128::from(sign);
+            }
+        }
+
+        // 7. Set intermediate to BalanceISOYearMonth(one.[[Year]] + years, one.[[Month]] + months).
+        // 8. Let constrained be ! RegulateISODate(intermediate.[[Year]], intermediate.[[Month]], one.[[Day]], constrain).
+        let (y, m) = balance_year_month(year + years, month + months);
+        let constrained = Self::regulate(y as f64, m as f64, day as f64, Overflow::Constrain)
+            .expect("constraining a date must not fail");
+
+        // 9-14. Count the weeks if largestUnit is week, then the days.
+        let mut days = other.epoch_days() - constrained.epoch_days();
+        if days.signum() == -sign {
+            days = 0;
+        }
+        let mut weeks = 0;
+        if largest_unit == TemporalUnit::Week {
+            weeks = days / 7;
+            days %= 7;
+        }
+
+        // 15. Return ! CreateDateDurationRecord(years, months, weeks, days).
+        [years as i64, months as i64, weeks, days]
+    }
+
+    /// Formats the date as `YYYY-MM-DD`.
+    pub(crate) fn to_iso_string(self) -> String {
+        format!(
+            "{}-{:02}-{:02}",
+            pad_iso_year(self.year),
+            self.month,
+            self.day
+        )
+    }
+}
+
+/// Abstract operation `BalanceISOYearMonth ( year, month )`
+fn balance_year_month(year: i128, month: i128) -> (i128, i128) {
+    // 1. Set year to year + floor((month - 1) / 12).
+    // 2. Set month to ((month - 1) modulo 12) + 1.
+    // 3. Return Year-Month Record { [[Year]]: year, [[Month]]: month }.
+    (
+        year + (month - 1).div_euclid(12),
+        (month - 1).rem_euclid(12) + 1,
+    )
+}
+
+/// The internal slots of a `Temporal.PlainDate` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Trace, Finalize)]
+pub struct PlainDate {
+    /// `[[ISODate]]`
+    #[unsafe_ignore_trace]
+    date: IsoDate,
+    /// `[[Calendar]]`
+    #[unsafe_ignore_trace]
+    calendar: Calendar,
+}
+
+impl BuiltIn for PlainDate {
+    const NAME: &'static str = "PlainDate";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let get_calendar_id = FunctionBuilder::native(context, Self::get_calendar_id)
+            .name("get calendarId")
+            .length(0)
+            .constructor(false)
+            .build();
+        let getters = DATE_GETTERS.map(|(name, getter)| {
+            let function = FunctionBuilder::closure(context, move |this, _, context| {
+                let date = Self::this_plain_date(this, context)?;
+                Ok(getter(date.calendar, date.date))
+            })
+            .name(format!("get {name}"))
+            .length(0)
+            .constructor(false)
+            .build();
+            (name, function)
+        });
+
+        let mut constructor = ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().plain_date().clone(),
+        );
+        constructor
+            .name(Self::NAME)
+            .length(Self::LENGTH)
+            .static_method(Self::from, "from", 1)
+            .static_method(Self::compare, "compare", 2)
+            .accessor(
+                "calendarId",
+                Some(get_calendar_id),
+                None,
+                Attribute::CONFIGURABLE,
+            );
+        for (name, getter) in getters {
+            constructor.accessor(name, Some(getter), None, Attribute::CONFIGURABLE);
+        }
+        constructor
+            .method(Self::add, "add", 1)
+            .method(Self::subtract, "subtract", 1)
+            .method(Self::with, "with", 1)
+            .method(Self::with_calendar, "withCalendar", 1)
+            .method(Self::until, "until", 1)
+            .method(Self::since, "since", 1)
+            .method(Self::equals, "equals", 1)
+            .method(Self::to_plain_date_time, "toPlainDateTime", 0)
+            .method(Self::to_zoned_date_time, "toZonedDateTime", 1)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_json, "toJSON", 0)
+            .method(Self::to_locale_string, "toLocaleString", 0)
+            .method(Self::value_of, "valueOf", 0)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                "Temporal.PlainDate",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build()
+            .conv::<JsValue>()
+            .pipe(Some)
+    }
+}
+
+impl PlainDate {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 3;
+
+    /// Creates a `PlainDate` from its date and calendar.
+    pub(crate) fn new(date: IsoDate, calendar: Calendar) -> Self {
+        Self { date, calendar }
+    }
+
+    /// Gets the date of the `PlainDate`.
+    pub(crate) fn date(self) -> IsoDate {
+        self.date
+    }
+
+    /// Gets the calendar of the `PlainDate`.
+    pub(crate) fn calendar(self) -> Calendar {
+        self.calendar
+    }
+
+    /// Abstract operation `CreateTemporalDate ( isoDate, calendar [ , newTarget ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-createtemporaldate
+    pub(crate) fn create(
+        self,
+        new_target: Option<&JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. If ISODateWithinLimits(isoDate) is false, throw a RangeError exception.
+        if !self.date.is_within_limits() {
+            return context.throw_range_error("the date is out of range");
+        }
+
+        // 2. If newTarget is not present, set newTarget to %Temporal.PlainDate%.
+        // 3. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.PlainDate.prototype%", « [[InitializedTemporalDate]], [[ISODate]], [[Calendar]] »).
+        let prototype = match new_target {
+            Some(new_target) => get_prototype_from_constructor(
+                new_target,
+                StandardConstructors::plain_date,
+                context,
+            )?,
+            None => context.intrinsics().constructors().plain_date().prototype(),
+        };
+
+        // 4. Set object.[[ISODate]] to isoDate.
+        // 5. Set object.[[Calendar]] to calendar.
+        // 6. Return object.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::plain_date(self),
+        ))
+    }
+
+    /// Abstract operation `ToTemporalDate ( item [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporaldate
+    pub(crate) fn from_value(
+        item: &JsValue,
+        options: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        match item {
+            // 2. If item is an Object, then
+            JsValue::Object(object) => {
+                // a. If item has an [[InitializedTemporalDate]] internal slot, then
+                //     i. Let resolvedOptions be ? GetOptionsObject(options).
+                //     ii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+                //     iii. Return ! CreateTemporalDate(item.[[ISODate]], item.[[Calendar]]).
+                // b. If item has an [[InitializedTemporalZonedDateTime]] internal slot, then
+                //     i. Let isoDateTime be GetISODateTimeFor(item.[[TimeZone]], item.[[EpochNanoseconds]]).
+                //     ii. Let resolvedOptions be ? GetOptionsObject(options).
+                //     iii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+                //     iv. Return ! CreateTemporalDate(isoDateTime.[[ISODate]], item.[[Calendar]]).
+                // c. If item has an [[InitializedTemporalDateTime]] internal slot, then
+                //     i. Let resolvedOptions be ? GetOptionsObject(options).
+                //     ii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+                //     iii. Return ! CreateTemporalDate(item.[[ISODateTime]].[[ISODate]], item.[[Calendar]]).
+                let date = {
+                    let object = object.borrow();
+                    object
+                        .as_plain_date()
+                        .copied()
+                        .or_else(|| {
+                            object.as_zoned_date_time().map(|date_time| {
+                                Self::new(date_time.date_time(context).date, date_time.calendar())
+                            })
+                        })
+                        .or_else(|| {
+                            object.as_plain_date_time().map(|date_time| {
+                                Self::new(date_time.date_time().date, date_time.calendar())
+                            })
+                        })
+                };
+                if let Some(date) = date {
+                    Overflow::from_options(options, context)?;
+                    return Ok(date);
+                }
+
+                // d. Let calendar be ? GetTemporalCalendarIdentifierWithISODefault(item).
+                let calendar = Calendar::from_fields_object(object, context)?;
+
+                // e. Let fields be ? PrepareCalendarFields(calendar, item, « year, month, month-code, day », « », « »).
+                let fields =
+                    Fields::read(object, Some(calendar), false, context)?.unwrap_or_default();
+
+                // f. Let resolvedOptions be ? GetOptionsObject(options).
+                // g. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+                let overflow = Overflow::from_options(options, context)?;
+
+                // h. Let isoDate be ? CalendarDateFromFields(calendar, fields, overflow).
+                let date = calendar.date_from_fields(&fields, overflow, context)?;
+
+                // i. Return ! CreateTemporalDate(isoDate, calendar).
+                Ok(Self::new(date, calendar))
+            }
+            // 3. If item is not a String, throw a TypeError exception.
+            JsValue::String(string) => {
+                // 4. Let result be ? ParseISODateTime(item, « TemporalDateTimeString[~Zoned] »).
+                let parsed = match parse_date_time(string) {
+                    Ok(parsed) if parsed.offset != Some(UtcOffset::Z) => parsed,
+                    Ok(_) => {
+                        return context.throw_range_error(format!(
+                            "the date string `{string}` must not have a `Z` designator"
+                        ))
+                    }
+                    Err(error) => {
+                        return context
+                            .throw_range_error(format!("invalid date string `{string}`: {error}"))
+                    }
+                };
+
+                // 5. Let calendar be result.[[Calendar]].
+                // 6. If calendar is empty, set calendar to "iso8601".
+                // 7. Set calendar to ? CanonicalizeCalendar(calendar).
+                let calendar = match parsed
+                    .calendar
+                    .as_deref()
+                    .map_or(Some(Calendar::Iso8601), Calendar::from_identifier)
+                {
+                    Some(calendar) => calendar,
+                    None => return context.throw_range_error("unsupported calendar"),
+                };
+
+                // 8. Let resolvedOptions be ? GetOptionsObject(options).
+                // 9. Perform ? GetTemporalOverflowOption(resolvedOptions).
+                Overflow::from_options(options, context)?;
+
+                // 10. Let isoDate be CreateISODateRecord(result.[[Year]], result.[[Month]], result.[[Day]]).
+                let date = IsoDate {
+                    year: parsed.year,
+                    month: parsed.month,
+                    day: parsed.day,
+                };
+
+                // 11. Return ? CreateTemporalDate(isoDate, calendar).
+                if !date.is_within_limits() {
+                    return context.throw_range_error("the date is out of range");
+                }
+                Ok(Self::new(date, calendar))
+            }
+            _ => context.throw_type_error("a date must be a string or an object"),
+        }
+    }
+
+    /// Gets the `this` `Temporal.PlainDate` object.
+    fn this_plain_date(this: &JsValue, context: &mut Context) -> JsResult<Self> {
+        this.as_object()
+            .and_then(|obj| obj.borrow().as_plain_date().copied())
+            .ok_or_else(|| context.construct_type_error("'this' is not a Temporal.PlainDate"))
+    }
+
+    /// `Temporal.PlainDate ( isoYear, isoMonth, isoDay [ , calendar ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDate/PlainDate
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error("Temporal.PlainDate constructor requires 'new'");
+        }
+
+        // 2. Let y be ? ToIntegerWithTruncation(isoYear).
+        let year = to_integer_with_truncation(args.get_or_undefined(0), context)?;
+
+        // 3. Let m be ? ToIntegerWithTruncation(isoMonth).
+        let month = to_integer_with_truncation(args.get_or_undefined(1), context)?;
+
+        // 4. Let d be ? ToIntegerWithTruncation(isoDay).
+        let day = to_integer_with_truncation(args.get_or_undefined(2), context)?;
+
+        // 5. If calendar is undefined, set calendar to "iso8601".
+        // 6. If calendar is not a String, throw a TypeError exception.
+        // 7. Set calendar to ? CanonicalizeCalendar(calendar).
+        let calendar = Calendar::from_constructor_argument(args.get_or_undefined(3), context)?;
+
+        // 8. If IsValidISODate(y, m, d) is false, throw a RangeError exception.
+        let date = match IsoDate::regulate(year, month, day, Overflow::Reject) {
+            Some(date) => date,
+            None => return context.throw_range_error("the date is invalid"),
+        };
+
+        // 9. Let isoDate be CreateISODateRecord(y, m, d).
+        // 10. Return ? CreateTemporalDate(isoDate, calendar, NewTarget).
+        Ok(Self::new(date, calendar)
+            .create(Some(new_target), context)?
+            .into())
+    }
+
+    /// `Temporal.PlainDate.from ( item [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.from
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDate/from
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? ToTemporalDate(item, options).
+        let date = Self::from_value(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+        Ok(date.create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainDate.compare ( one, two )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.compare
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDate/compare
+    fn compare(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set one to ? ToTemporalDate(one).
+        let one = Self::from_value(args.get_or_undefined(0), &JsValue::undefined(), context)?;
+
+        // 2. Set two to ? ToTemporalDate(two).
+        let two = Self::from_value(args.get_or_undefined(1), &JsValue::undefined(), context)?;
+
+        // 3. Return 𝔽(CompareISODate(one.[[ISODate]], two.[[ISODate]])).
+        Ok(ordering_to_value(one.date.cmp(&two.date)))
+    }
+
+    /// `get Temporal.PlainDate.prototype.calendarId`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.calendarid
+    fn get_calendar_id(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        // 3. Return temporalDate.[[Calendar]].
+        Ok(Self::this_plain_date(this, context)?
+            .calendar
+            .identifier()
+            .into())
+    }
+
+    /// Abstract operation `AddDurationToDate ( operation, temporalDate, temporalDurationLike, options )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-adddurationtodate
+    fn add_duration(
+        this: &JsValue,
+        args: &[JsValue],
+        subtract: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let date = Self::this_plain_date(this, context)?;
+
+        // 1. Let calendar be temporalDate.[[Calendar]].
+        // 2. Let duration be ? ToTemporalDuration(temporalDurationLike).
+        // 3. If operation is subtract, set duration to CreateNegatedTemporalDuration(duration).
+        let mut duration = Duration::from_value(args.get_or_undefined(0), context)?;
+        if subtract {
+            duration = duration.negated();
+        }
+
+        // 4. Let dateDuration be ToDateDurationRecordWithoutTime(duration).
+        let mut date_duration = duration.date_part();
+        date_duration[3] += (duration.time_nanoseconds() / NS_PER_DAY) as i64;
+
+        // 5. Let resolvedOptions be ? GetOptionsObject(options).
+        // 6. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow = Overflow::from_options(args.get_or_undefined(1), context)?;
+
+        // 7. Let result be ? CalendarDateAdd(calendar, temporalDate.[[ISODate]], dateDuration, overflow).
+        let result = match date.calendar.date_add(date.date, date_duration, overflow) {
+            Some(result) => result,
+            None => return context.throw_range_error("the resulting date is out of range"),
+        };
+
+        // 8. Return ! CreateTemporalDate(result, calendar).
+        Ok(Self::new(result, date.calendar)
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.PlainDate.prototype.add ( temporalDurationLike [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.add
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDate/add
+    fn add(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        // 3. Return ? AddDurationToDate(add, temporalDate, temporalDurationLike, options).
+        Self::add_duration(this, args, false, context)
+    }
+
+    /// `Temporal.PlainDate.prototype.subtract ( temporalDurationLike [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.subtract
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDate/subtract
+    fn subtract(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        // 3. Return ? AddDurationToDate(subtract, temporalDate, temporalDurationLike, options).
+        Self::add_duration(this, args, true, context)
+    }
+
+    /// `Temporal.PlainDate.prototype.with ( temporalDateLike [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.with
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDate/with
+    fn with(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let date = Self::this_plain_date(this, context)?;
+
+        // 3. If ? IsPartialTemporalObject(temporalDateLike) is false, throw a TypeError exception.
+        let date_like = to_partial_temporal_object(args.get_or_undefined(0), context)?;
+
+        // 4. Let calendar be temporalDate.[[Calendar]].
+        let calendar = date.calendar;
+
+        // 5. Let fields be ISODateToFields(calendar, temporalDate.[[ISODate]], date).
+        let fields = calendar.fields_of(date.date);
+
+        // 6. Let partialDate be ? PrepareCalendarFields(calendar, temporalDateLike, « year, month, month-code, day », « », partial).
+        let partial = match Fields::read(&date_like, Some(calendar), false, context)? {
+            Some(partial) => partial,
+            None => return context.throw_type_error("at least one date field is required"),
+        };
+
+        // 7. Set fields to CalendarMergeFields(calendar, fields, partialDate).
+        let fields = calendar.merge_fields(&fields, &partial);
+
+        // 8. Let resolvedOptions be ? GetOptionsObject(options).
+        // 9. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow = Overflow::from_options(args.get_or_undefined(1), context)?;
+
+        // 10. Let isoDate be ? CalendarDateFromFields(calendar, fields, overflow).
+        let result = calendar.date_from_fields(&fields, overflow, context)?;
+
+        // 11. Return ! CreateTemporalDate(isoDate, calendar).
+        Ok(Self::new(result, calendar).create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainDate.prototype.withCalendar ( calendarLike )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.withcalendar
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDate/withCalendar
+    fn with_calendar(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let date = Self::this_plain_date(this, context)?;
+
+        // 3. Let calendar be ? ToTemporalCalendarIdentifier(calendarLike).
+        let calendar = Calendar::from_value(args.get_or_undefined(0), context)?;
+
+        // 4. Return ! CreateTemporalDate(temporalDate.[[ISODate]], calendar).
+        Ok(Self::new(date.date, calendar).create(None, context)?.into())
+    }
+
+    /// Abstract operation `DifferenceTemporalPlainDate ( operation, temporalDate, other, options )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalplaindate
+    fn difference(
+        this: &JsValue,
+        args: &[JsValue],
+        since: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let date = Self::this_plain_date(this, context)?;
+
+        // 1. If operation is since, let sign be -1. Otherwise, let sign be 1.
+        // 2. Set other to ? ToTemporalDate(other).
+        let other = Self::from_value(args.get_or_undefined(0), &JsValue::undefined(), context)?;
+
+        // 3. If CalendarEquals(temporalDate.[[Calendar]], other.[[Calendar]]) is false, throw a RangeError exception.
+        if date.calendar != other.calendar {
+            return context.throw_range_error("the dates must have the same calendar");
+        }
+
+        // 4. Let resolvedOptions be ? GetOptionsObject(options).
+        // 5. Let settings be ? GetDifferenceSettings(operation, resolvedOptions, date, « », day, day).
+        let settings = DifferenceSettings::from_options(
+            args.get_or_undefined(1),
+            since,
+            UnitGroup::Date,
+            TemporalUnit::Day,
+            TemporalUnit::Day,
+            context,
+        )?;
+
+        // 6. If CompareISODate(temporalDate.[[ISODate]], other.[[ISODate]]) = 0, then
+        //     a. Return ! CreateTemporalDuration(0, 0, 0, 0, 0, 0, 0, 0, 0, 0).
+        if date.date == other.date {
+            return Ok(Duration::new([0.0; 10]).create(None, context)?.into());
+        }
+
+        // 7. Let dateDifference be CalendarDateUntil(calendar, temporalDate.[[ISODate]], other.[[ISODate]], settings.[[LargestUnit]]).
+        // 8. Let duration be CombineDateAndTimeDuration(dateDifference, 0).
+        let mut duration = (
+            date.calendar
+                .date_until(date.date, other.date, settings.largest_unit),
+            0,
+        );
+
+        // 9. If settings.[[SmallestUnit]] is not day or settings.[[RoundingIncrement]] ≠ 1, then
+        if settings.smallest_unit != TemporalUnit::Day || settings.increment != 1 {
+            // a. Let isoDateTime be CombineISODateAndTimeRecord(temporalDate.[[ISODate]], MidnightTimeRecord()).
+            let origin = RelativeOrigin {
+                date_time: IsoDateTime::new(date.date, IsoTime::default()),
+                time_zone: None,
+                calendar: date.calendar,
+            };
+
+            // b. Let isoDateTimeOther be CombineISODateAndTimeRecord(other.[[ISODate]], MidnightTimeRecord()).
+            // c. Let destEpochNs be GetUTCEpochNanoseconds(isoDateTimeOther).
+            let dest = IsoDateTime::new(other.date, IsoTime::default()).epoch_nanoseconds();
+
+            // d. Set duration to ? RoundRelativeDuration(duration, destEpochNs, isoDateTime, unset, temporalDate.[[Calendar]], settings.[[LargestUnit]], settings.[[RoundingIncrement]], settings.[[SmallestUnit]], settings.[[RoundingMode]]).
+            duration = round_relative_duration(duration, dest, origin, settings, context)?;
+        }
+
+        // 10. Let result be ! TemporalDurationFromInternal(duration, day).
+        let result = Duration::from_parts(duration.0, duration.1, TemporalUnit::Day);
+
+        // 11. If operation is since, set result to CreateNegatedTemporalDuration(result).
+        let result = if since { result.negated() } else { result };
+
+        // 12. Return result.
+        Ok(result.create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainDate.prototype.until ( other [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.until
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDate/until
+    fn until(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        // 3. Return ? DifferenceTemporalPlainDate(until, temporalDate, other, options).
+        Self::difference(this, args, false, context)
+    }
+
+    /// `Temporal.PlainDate.prototype.since ( other [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.since
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDate/since
+    fn since(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        // 3. Return ? DifferenceTemporalPlainDate(since, temporalDate, other, options).
+        Self::difference(this, args, true, context)
+    }
+
+    /// `Temporal.PlainDate.prototype.equals ( other )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.equals
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDate/equals
+    fn equals(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let date = Self::this_plain_date(this, context)?;
+
+        // 3. Set other to ? ToTemporalDate(other).
+        let other = Self::from_value(args.get_or_undefined(0), &JsValue::undefined(), context)?;
+
+        // 4. If CompareISODate(temporalDate.[[ISODate]], other.[[ISODate]]) ≠ 0, return false.
+        // 5. Return CalendarEquals(temporalDate.[[Calendar]], other.[[Calendar]]).
+        Ok((date == other).into())
+    }
+
+    /// `Temporal.PlainDate.prototype.toPlainDateTime ( [ temporalTime ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.toplaindatetime
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDate/toPlainDateTime
+    #[allow(clippy::wrong_self_convention)]
+    fn to_plain_date_time(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let date = Self::this_plain_date(this, context)?;
+
+        // 3. Let time be ? ToTimeRecordOrMidnight(temporalTime).
+        let time = match args.get_or_undefined(0) {
+            JsValue::Undefined => IsoTime::default(),
+            time => PlainTime::from_value(time, &JsValue::undefined(), context)?,
+        };
+
+        // 4. Let isoDateTime be CombineISODateAndTimeRecord(temporalDate.[[ISODate]], time).
+        // 5. Return ? CreateTemporalDateTime(isoDateTime, temporalDate.[[Calendar]]).
+        Ok(
+            PlainDateTime::new(IsoDateTime::new(date.date, time), date.calendar)
+                .create(None, context)?
+                .into(),
+        )
+    }
+
+    /// `Temporal.PlainDate.prototype.toZonedDateTime ( item )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.tozoneddatetime
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDate/toZonedDateTime
+    #[allow(clippy::wrong_self_convention)]
+    fn to_zoned_date_time(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let date = Self::this_plain_date(this, context)?;
+
+        let item = args.get_or_undefined(0);
+        let (time_zone, temporal_time) = match item {
+            // 3. If item is an Object, then
+            JsValue::Object(object) => {
+                // a. Let timeZoneLike be ? Get(item, "timeZone").
+                let time_zone_like = object.get("timeZone", context)?;
+                if time_zone_like.is_undefined() {
+                    // b. If timeZoneLike is undefined, then
+                    //     i. Let timeZone be ? ToTemporalTimeZoneIdentifier(item).
+                    //     ii. Let temporalTime be undefined.
+                    (TimeZone::from_value(item, context)?, JsValue::undefined())
+                } else {
+                    // c. Else,
+                    //     i. Let timeZone be ? ToTemporalTimeZoneIdentifier(timeZoneLike).
+                    //     ii. Let temporalTime be ? Get(item, "plainTime").
+                    let time_zone = TimeZone::from_value(&time_zone_like, context)?;
+                    (time_zone, object.get("plainTime", context)?)
+                }
+            }
+            // 4. Else,
+            //     a. Let timeZone be ? ToTemporalTimeZoneIdentifier(item).
+            //     b. Let temporalTime be undefined.
+            _ => (TimeZone::from_value(item, context)?, JsValue::undefined()),
+        };
+
+        let epoch_nanoseconds = if temporal_time.is_undefined() {
+            // 5. If temporalTime is undefined, then
+            //     a. Let epochNs be ? GetStartOfDay(timeZone, temporalDate.[[ISODate]]).
+            time_zone.start_of_day(date.date, context)?
+        } else {
+            // 6. Else,
+            //     a. Set temporalTime to ? ToTemporalTime(temporalTime).
+            //     b. Let isoDateTime be CombineISODateAndTimeRecord(temporalDate.[[ISODate]], temporalTime.[[Time]]).
+            //     c. If ISODateTimeWithinLimits(isoDateTime) is false, throw a RangeError exception.
+            //     d. Let epochNs be ? GetEpochNanosecondsFor(timeZone, isoDateTime, compatible).
+            let time = PlainTime::from_value(&temporal_time, &JsValue::undefined(), context)?;
+            let date_time = IsoDateTime::new(date.date, time);
+            if !date_time.is_within_limits() {
+                return context.throw_range_error("the date-time is out of range");
+            }
+            time_zone.epoch_nanoseconds_for(date_time, Disambiguation::Compatible, context)?
+        };
+
+        // 7. Return ! CreateTemporalZonedDateTime(epochNs, timeZone, temporalDate.[[Calendar]]).
+        Ok(
+            ZonedDateTime::new(epoch_nanoseconds, time_zone, date.calendar)
+                .create(None, context)?
+                .into(),
+        )
+    }
+
+    /// `Temporal.PlainDate.prototype.toString ( [ options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.tostring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDate/toString
+    #[allow(clippy::wrong_self_convention)]
+    fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let date = Self::this_plain_date(this, context)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(0), context)?;
+
+        // 4. Let showCalendar be ? GetTemporalShowCalendarNameOption(resolvedOptions).
+        let show_calendar = ShowCalendar::from_options(&options, context)?;
+
+        // 5. Return TemporalDateToString(temporalDate, showCalendar).
+        Ok(date.to_iso_string(show_calendar).into())
+    }
+
+    /// Abstract operation `TemporalDateToString ( temporalDate, showCalendar )`
+    fn to_iso_string(self, show_calendar: ShowCalendar) -> String {
+        format!(
+            "{}{}",
+            self.date.to_iso_string(),
+            self.calendar.annotation(show_calendar)
+        )
+    }
+
+    /// `Temporal.PlainDate.prototype.toJSON ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.tojson
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDate/toJSON
+    #[allow(clippy::wrong_self_convention)]
+    fn to_json(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        // 3. Return TemporalDateToString(temporalDate, auto).
+        Ok(Self::this_plain_date(this, context)?
+            .to_iso_string(ShowCalendar::Auto)
+            .into())
+    }
+
+    /// `Temporal.PlainDate.prototype.toLocaleString ( [ locales [ , options ] ] )`
+    ///
+    /// The plain dates are not supported by `Intl.DateTimeFormat` yet, so this returns the same
+    /// string as `toJSON`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDate/toLocaleString
+    #[allow(clippy::wrong_self_convention)]
+    fn to_locale_string(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        // 3. Return TemporalDateToString(temporalDate, auto).
+        Ok(Self::this_plain_date(this, context)?
+            .to_iso_string(ShowCalendar::Auto)
+            .into())
+    }
+
+    /// `Temporal.PlainDate.prototype.valueOf ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.valueof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDate/valueOf
+    fn value_of(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Throw a TypeError exception.
+        context.throw_type_error("a Temporal.PlainDate can't be converted to a primitive value")
+    }
+}
@@ -0,0 +1,329 @@
+use crate::{forward, Context};
+
+#[test]
+fn constructor() {
+    let mut context = Context::default();
+    for (code, expected) in [
+        (
+            "new Temporal.PlainDate(2020, 2, 29).toString()",
+            "\"2020-02-29\"",
+        ),
+        (
+            "new Temporal.PlainDate(2020, 2, 29, 'ISO8601').calendarId",
+            "\"iso8601\"",
+        ),
+        (
+            "new Temporal.PlainDate(-10000, 1, 1).toString()",
+            "\"-010000-01-01\"",
+        ),
+        (
+            "new Temporal.PlainDate(275760, 9, 13).toString()",
+            "\"+275760-09-13\"",
+        ),
+        (
+            "new Temporal.PlainDate(-271821, 4, 19).toString()",
+            "\"-271821-04-19\"",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+
+    for (code, expected) in [
+        ("new Temporal.PlainDate(2021, 2, 29)", "RangeError"),
+        ("new Temporal.PlainDate(2020, 1, 1, 'coptic')", "RangeError"),
+        ("new Temporal.PlainDate(2020, 1, 1, 1)", "TypeError"),
+        ("new Temporal.PlainDate(275760, 9, 14)", "RangeError"),
+        ("new Temporal.PlainDate(-271821, 4, 18)", "RangeError"),
+        ("new Temporal.PlainDate(2020, 1)", "RangeError"),
+        ("Temporal.PlainDate(2020, 1, 1)", "TypeError"),
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {code} }} catch (e) {{ e.name }}")
+            ),
+            format!("\"{expected}\"")
+        );
+    }
+}
+
+#[test]
+fn getters() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        "var date = new Temporal.PlainDate(2021, 1, 3);",
+    );
+    for (code, expected) in [
+        ("date.year", "2021"),
+        ("date.month", "1"),
+        ("date.monthCode", "\"M01\""),
+        ("date.day", "3"),
+        ("date.era", "undefined"),
+        ("date.dayOfWeek", "7"),
+        ("date.dayOfYear", "3"),
+        ("date.weekOfYear", "53"),
+        ("date.yearOfWeek", "2020"),
+        ("date.daysInWeek", "7"),
+        ("date.daysInMonth", "31"),
+        ("date.daysInYear", "365"),
+        ("date.monthsInYear", "12"),
+        ("date.inLeapYear", "false"),
+        ("new Temporal.PlainDate(2024, 12, 30).weekOfYear", "1"),
+        ("new Temporal.PlainDate(2024, 12, 30).yearOfWeek", "2025"),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+}
+
+#[test]
+fn from() {
+    let mut context = Context::default();
+    for (code, expected) in [
+        (
+            "Temporal.PlainDate.from('2020-01-01T23:00').toString()",
+            "2020-01-01",
+        ),
+        (
+            "Temporal.PlainDate.from('2020-01-01[u-ca=iso8601]').toString()",
+            "2020-01-01",
+        ),
+        (
+            "Temporal.PlainDate.from({ year: 2021, month: 2, day: 31 }).toString()",
+            "2021-02-28",
+        ),
+        (
+            "Temporal.PlainDate.from({ year: 2021, monthCode: 'M03', day: 1 }).toString()",
+            "2021-03-01",
+        ),
+        (
+            "Temporal.PlainDate.from(new Temporal.PlainDateTime(2020, 5, 6, 7)).toString()",
+            "2020-05-06",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), format!("\"{expected}\""));
+    }
+
+    for (code, expected) in [
+        (
+            "Temporal.PlainDate.from({ year: 2021, month: 2, day: 31 }, { overflow: 'reject' })",
+            "RangeError",
+        ),
+        (
+            "Temporal.PlainDate.from({ year: 2021, day: 1 })",
+            "TypeError",
+        ),
+        (
+            "Temporal.PlainDate.from({ year: 2021, month: 1, monthCode: 'M02', day: 1 })",
+            "RangeError",
+        ),
+        (
+            "Temporal.PlainDate.from({ year: 2021, monthCode: 'M13', day: 1 })",
+            "RangeError",
+        ),
+        ("Temporal.PlainDate.from('2020-01-01T00:00Z')", "RangeError"),
+        (
+            "Temporal.PlainDate.from('2020-01-01[u-ca=coptic]')",
+            "RangeError",
+        ),
+        ("Temporal.PlainDate.from(20200101)", "TypeError"),
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {code} }} catch (e) {{ e.name }}")
+            ),
+            format!("\"{expected}\"")
+        );
+    }
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Temporal.PlainDate.from('2020-13-01') } catch (e) { e.message }"
+        ),
+        "\"invalid date string `2020-13-01`: month out of range at position 5\""
+    );
+}
+
+#[test]
+fn arithmetic() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        "var date = Temporal.PlainDate.from('2020-01-31');",
+    );
+    for (code, expected) in [
+        ("date.add({ months: 1 }).toString()", "2020-02-29"),
+        ("date.add({ years: 1, days: 1 }).toString()", "2021-02-01"),
+        ("date.subtract({ weeks: 5 }).toString()", "2019-12-27"),
+        ("date.add({ hours: 47 }).toString()", "2020-02-01"),
+        ("date.with({ month: 4 }).toString()", "2020-04-30"),
+        ("date.with({ monthCode: 'M02' }).toString()", "2020-02-29"),
+        ("date.until('2020-03-01').toString()", "P30D"),
+        (
+            "date.until('2020-03-01', { largestUnit: 'month' }).toString()",
+            "P1M1D",
+        ),
+        (
+            "date.since('2020-03-01', { largestUnit: 'month' }).toString()",
+            "-P1M1D",
+        ),
+        (
+            "date.until('2021-03-01', { largestUnit: 'year' }).toString()",
+            "P1Y1M1D",
+        ),
+        (
+            "date.until('2020-03-15', { smallestUnit: 'month' }).toString()",
+            "P1M",
+        ),
+        (
+            "date.until('2020-03-15', { smallestUnit: 'month', roundingMode: 'ceil' }).toString()",
+            "P2M",
+        ),
+        (
+            "date.until('2020-02-10', { smallestUnit: 'week', roundingMode: 'halfExpand' }).toString()",
+            "P1W",
+        ),
+        (
+            "Temporal.PlainDate.from('2020-01-01').until('2020-12-20', { largestUnit: 'year', smallestUnit: 'month', roundingMode: 'ceil' }).toString()",
+            "P1Y",
+        ),
+        ("date.toPlainDateTime('12:30').toString()", "2020-01-31T12:30:00"),
+        ("date.toPlainDateTime().toString()", "2020-01-31T00:00:00"),
+    ] {
+        assert_eq!(forward(&mut context, code), format!("\"{expected}\""));
+    }
+
+    for (code, expected) in [
+        (
+            "Temporal.PlainDate.compare('2020-01-01', '2019-12-31')",
+            "1",
+        ),
+        ("date.equals('2020-01-31')", "true"),
+        ("date.equals('2020-02-01')", "false"),
+        (
+            "try { date.add({ months: 1 }, { overflow: 'reject' }) } catch (e) { e.name }",
+            "\"RangeError\"",
+        ),
+        (
+            "try { date.add({ years: 300000 }) } catch (e) { e.name }",
+            "\"RangeError\"",
+        ),
+        (
+            "try { date.until('2020-03-01', { largestUnit: 'hour' }) } catch (e) { e.name }",
+            "\"RangeError\"",
+        ),
+        (
+            "try { date.with({ year: 2020, timeZone: 'UTC' }) } catch (e) { e.name }",
+            "\"TypeError\"",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+}
+
+#[test]
+fn to_string() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        "var date = Temporal.PlainDate.from('2020-01-31');",
+    );
+    for (code, expected) in [
+        ("date.toString()", "\"2020-01-31\""),
+        ("date.toJSON()", "\"2020-01-31\""),
+        (
+            "date.toString({ calendarName: 'always' })",
+            "\"2020-01-31[u-ca=iso8601]\"",
+        ),
+        (
+            "date.toString({ calendarName: 'critical' })",
+            "\"2020-01-31[!u-ca=iso8601]\"",
+        ),
+        ("date.toString({ calendarName: 'never' })", "\"2020-01-31\""),
+        (
+            "try { date.toString({ calendarName: 'sometimes' }) } catch (e) { e.name }",
+            "\"RangeError\"",
+        ),
+        (
+            "Object.prototype.toString.call(date)",
+            "\"[object Temporal.PlainDate]\"",
+        ),
+        (
+            "try { date.valueOf() } catch (e) { e.name }",
+            "\"TypeError\"",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+}
+
+#[test]
+fn calendars() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        "var hebrew = Temporal.PlainDate.from('2023-09-16').withCalendar('hebrew');
+        var adar = Temporal.PlainDate.from({ year: 5784, monthCode: 'M05L', day: 1, calendar: 'hebrew' });",
+    );
+    for (code, expected) in [
+        ("hebrew.calendarId", "\"hebrew\""),
+        ("hebrew.year", "5784"),
+        ("hebrew.monthCode", "\"M01\""),
+        ("hebrew.daysInYear", "383"),
+        ("hebrew.monthsInYear", "13"),
+        ("hebrew.inLeapYear", "true"),
+        ("hebrew.weekOfYear", "undefined"),
+        ("hebrew.toString()", "\"2023-09-16[u-ca=hebrew]\""),
+        ("adar.toString()", "\"2024-02-10[u-ca=hebrew]\""),
+        ("adar.month", "6"),
+        ("adar.add({ months: 1 }).monthCode", "\"M06\""),
+        ("adar.add({ years: 1 }).toString()", "\"2025-03-01[u-ca=hebrew]\""),
+        (
+            "try { adar.add({ years: 1 }, { overflow: 'reject' }) } catch (e) { e.name }",
+            "\"RangeError\"",
+        ),
+        ("hebrew.add({ months: 6 }).toString()", "\"2024-03-11[u-ca=hebrew]\""),
+        (
+            "hebrew.until(adar.add({ months: 1 }), { largestUnit: 'months' }).toString()",
+            "\"P6M\"",
+        ),
+        (
+            "hebrew.until(Temporal.PlainDate.from('2024-10-03[u-ca=hebrew]'), { largestUnit: 'years' }).toString()",
+            "\"P1Y\"",
+        ),
+        (
+            "Temporal.PlainDate.from('2019-05-01[u-ca=japanese]').era",
+            "\"reiwa\"",
+        ),
+        (
+            "Temporal.PlainDate.from({ era: 'heisei', eraYear: 31, month: 4, day: 30, calendar: 'japanese' }).toString()",
+            "\"2019-04-30[u-ca=japanese]\"",
+        ),
+        ("new Temporal.PlainDate(-5, 1, 1, 'gregory').era", "\"bce\""),
+        ("new Temporal.PlainDate(-5, 1, 1, 'gregory').eraYear", "6"),
+        (
+            "Temporal.PlainDate.from('2023-07-19').withCalendar('islamic-civil').monthCode",
+            "\"M01\"",
+        ),
+        (
+            "Temporal.PlainDate.from('2023-07-19').withCalendar('islamicc').year",
+            "1445",
+        ),
+        (
+            "Temporal.PlainDate.from('2023-07-19').withCalendar('islamic-civil').daysInYear",
+            "355",
+        ),
+        (
+            "try { hebrew.withCalendar({}) } catch (e) { e.name }",
+            "\"TypeError\"",
+        ),
+        (
+            "try { hebrew.until(Temporal.PlainDate.from('2024-01-01')) } catch (e) { e.name }",
+            "\"RangeError\"",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+}
@@ -0,0 +1,1727 @@
+//! This module implements the global `Temporal.PlainDateTime` object.
+//!
+//! A `Temporal.PlainDateTime` is a calendar date and a wall-clock time, which are not
+//! associated with a time zone.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-plaindatetime-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime
+
+#[cfg(test)]
+mod tests;
+
+use super::{
+    calendar::{Calendar, Fields, ShowCalendar, DATE_GETTERS},
+    divide_to_number, get_round_to_options, get_rounding_increment, get_temporal_unit,
+    plain_date::{IsoDate, PlainDate},
+    plain_time::{ordering_to_value, time_fields, IsoTime, PlainTime, TIME_GETTERS},
+    round_to_increment,
+    time_zone::{Disambiguation, TimeZone},
+    to_integer_with_truncation, to_partial_temporal_object, total_time_duration,
+    validate_rounding_increment, DifferenceSettings, Duration, Overflow, Precision, RoundingMode,
+    SecondsStringPrecision, TemporalUnit, UnitGroup, ZonedDateTime, NS_MAX_INSTANT, NS_PER_DAY,
+};
+use crate::{
+    builtins::{
+        intl::get_options_object,
+        iso8601::{parse_date_time, UtcOffset},
+        BuiltIn, JsArgs,
+    },
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use tap::{Conv, Pipe};
+
+/// An ISO date-time record, a date and a wall-clock time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct IsoDateTime {
+    pub(crate) date: IsoDate,
+    pub(crate) time: IsoTime,
+}
+
+impl IsoDateTime {
+    /// Abstract operation `CombineISODateAndTimeRecord ( isoDate, time )`
+    pub(crate) fn new(date: IsoDate, time: IsoTime) -> Self {
+        Self { date, time }
+    }
+
+    /// Gets the date-time in UTC of an epoch nanoseconds value.
+    pub(crate) fn from_epoch_nanoseconds(epoch_nanoseconds: i128) -> Self {
+        let (days, time) = IsoTime::from_nanoseconds(epoch_nanoseconds);
+        Self::new(IsoDate::from_epoch_days(days), time)
+    }
+
+    /// Abstract operation `GetUTCEpochNanoseconds ( isoDateTime )`
+    pub(crate) fn epoch_nanoseconds(self) -> i128 {
+        i128::from(self.date.epoch_days()) * NS_PER_DAY + i128::from(self.time.nanoseconds())
+    }
+
+    /// Abstract operation `ISODateTimeWithinLimits ( isoDateTime )`
+    ///
+    /// Returns `true` if the date-time is less than a day away from the range of the instants.
+    pub(crate) fn is_within_limits(self) -> bool {
+        self.epoch_nanoseconds().abs() < NS_MAX_INSTANT + NS_PER_DAY
+    }
+
+    /// Abstract operation `AddDateTime`, the last steps of `AddDurationToDateTime`.
+    ///
+    /// Adds the time duration to the time, then the years, months, weeks and days, with the
+    /// days carried from the time, to the date. Returns `None` if the result is invalid or out
+    /// of range.
+    pub(crate) fn add(
+        self,
+        date_duration: [i64; 4],
+        time_duration: i128,
+        calendar: Calendar,
+        overflow: Overflow,
+    ) -> Option<Self> {
+        // 7. Let timeResult be AddTime(isoDateTime.[[Time]], internalDuration.[[Time]]).
+        let (days, time) = self.time.add(time_duration);
+
+        // 8. Let dateDuration be ? AdjustDateDurationRecord(internalDuration.[[Date]], timeResult.[[Days]]).
+        let [years, months, weeks, duration_days] = date_duration;
+        let days = duration_days.checked_add(days)?;
+
+        // 9. Let addedDate be ? CalendarDateAdd(calendar, isoDateTime.[[ISODate]], dateDuration, overflow).
+        let date = calendar.date_add(self.date, [years, months, weeks, days], overflow)?;
+
+        // 10. Let result be CombineISODateAndTimeRecord(addedDate, timeResult).
+        let result = Self::new(date, time);
+        result.is_within_limits().then(|| result)
+    }
+
+    /// Abstract operation `RoundISODateTime ( isoDateTime, increment, unit, roundingMode )`
+    ///
+    /// `increment` is the length of the rounding increment in nanoseconds.
+    pub(crate) fn round(self, increment: i128, rounding_mode: RoundingMode) -> Self {
+        // 2. Let roundedTime be RoundTime(isoDateTime.[[Time]], increment, unit, roundingMode).
+        let (days, time) = self.time.round(increment, rounding_mode);
+
+        // 3. Let balanceResult be BalanceISODate(isoDateTime.[[Year]], isoDateTime.[[Month]], isoDateTime.[[Day]] + roundedTime.[[Days]]).
+        // 4. Return CombineISODateAndTimeRecord(balanceResult, roundedTime).
+        Self::new(
+            IsoDate::from_epoch_days(self.date.epoch_days() + days),
+            time,
+        )
+    }
+
+    /// Abstract operation `ISODateTimeToString ( isoDateTime, calendar, precision, showCalendar )`,
+    /// without the calendar annotation.
+    pub(crate) fn to_iso_string(self, precision: Precision) -> String {
+        format!(
+            "{}T{}",
+            self.date.to_iso_string(),
+            self.time.to_iso_string(precision)
+        )
+    }
+}
+
+/// An internal duration record, with the years, months, weeks and days of a duration, and its
+/// time in nanoseconds.
+pub(crate) type InternalDuration = ([i64; 4], i128);
+
+/// Abstract operation `InternalDurationSign ( internalDuration )`
+pub(crate) fn internal_duration_sign(duration: InternalDuration) -> i64 {
+    match duration.0.iter().find(|value| **value != 0) {
+        Some(value) => value.signum(),
+        None => duration.1.signum() as i64,
+    }
+}
+
+/// Abstract operation `DifferenceISODateTime ( isoDateTime1, isoDateTime2, calendar, largestUnit )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differenceisodatetime
+pub(crate) fn difference_iso_date_time(
+    one: IsoDateTime,
+    two: IsoDateTime,
+    calendar: Calendar,
+    largest_unit: TemporalUnit,
+) -> InternalDuration {
+    // 2. Let timeDuration be DifferenceTime(isoDateTime1.[[Time]], isoDateTime2.[[Time]]).
+    let mut time = i128::from(two.time.nanoseconds() - one.time.nanoseconds());
+
+    // 3. Let timeSign be TimeDurationSign(timeDuration).
+    let time_sign = time.signum();
+
+    // 4. Let dateSign be CompareISODate(isoDateTime2.[[ISODate]], isoDateTime1.[[ISODate]]).
+    let date_sign = two.date.cmp(&one.date) as i128;
+
+    // 5. Let adjustedDate be isoDateTime2.[[ISODate]].
+    // 6. If timeSign = -dateSign, then
+    //     a. Set adjustedDate to BalanceISODate(adjustedDate.[[Year]], adjustedDate.[[Month]], adjustedDate.[[Day]] + timeSign).
+    //     b. Set timeDuration to ! Add24HourDaysToTimeDuration(timeDuration, -timeSign).
+    let mut adjusted_date = two.date;
+    if time_sign == -date_sign {
+        adjusted_date = IsoDate::from_epoch_days(adjusted_date.epoch_days() + time_sign as i64);
+        time -= time_sign * NS_PER_DAY;
+    }
+
+    // 7. Let dateLargestUnit be LargerOfTwoTemporalUnits(day, largestUnit).
+    // 8. Let dateDifference be CalendarDateUntil(calendar, isoDateTime1.[[ISODate]], adjustedDate, dateLargestUnit).
+    let date_largest_unit = largest_unit.min(TemporalUnit::Day);
+    let mut date = calendar.date_until(one.date, adjusted_date, date_largest_unit);
+
+    // 9. If largestUnit is not dateLargestUnit, then
+    //     a. Set timeDuration to ! Add24HourDaysToTimeDuration(timeDuration, dateDifference.[[Days]]).
+    //     b. Set dateDifference.[[Days]] to 0.
+    if largest_unit != date_largest_unit {
+        time += i128::from(date[3]) * NS_PER_DAY;
+        date[3] = 0;
+    }
+
+    // 10. Return CombineDateAndTimeDuration(dateDifference, timeDuration).
+    (date, time)
+}
+
+/// Abstract operation `DifferencePlainDateTimeWithRounding ( isoDateTime1, isoDateTime2, calendar, largestUnit, roundingIncrement, smallestUnit, roundingMode )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differenceplaindatetimewithrounding
+pub(crate) fn difference_plain_date_time_with_rounding(
+    one: IsoDateTime,
+    two: IsoDateTime,
+    calendar: Calendar,
+    settings: DifferenceSettings,
+    context: &mut Context,
+) -> JsResult<InternalDuration> {
+    // 1. If CompareISODateTime(isoDateTime1, isoDateTime2) = 0, then
+    //     a. Return CombineDateAndTimeDuration(ZeroDateDuration(), 0).
+    if one == two {
+        return Ok(([0; 4], 0));
+    }
+
+    // 2. If ISODateTimeWithinLimits(isoDateTime1) is false or ISODateTimeWithinLimits(isoDateTime2) is false, throw a RangeError exception.
+    if !one.is_within_limits() || !two.is_within_limits() {
+        return context.throw_range_error("the date-time is out of range");
+    }
+
+    // 3. Let diff be DifferenceISODateTime(isoDateTime1, isoDateTime2, calendar, largestUnit).
+    let difference = difference_iso_date_time(one, two, calendar, settings.largest_unit);
+
+    // 4. If smallestUnit is nanosecond and roundingIncrement = 1, return diff.
+    if settings.is_exact() {
+        return Ok(difference);
+    }
+
+    // 5. Let destEpochNs be GetUTCEpochNanoseconds(isoDateTime2).
+    // 6. Return ? RoundRelativeDuration(diff, destEpochNs, isoDateTime1, unset, calendar, largestUnit, roundingIncrement, smallestUnit, roundingMode).
+    let origin = RelativeOrigin {
+        date_time: one,
+        time_zone: None,
+        calendar,
+    };
+    round_relative_duration(
+        difference,
+        two.epoch_nanoseconds(),
+        origin,
+        settings,
+        context,
+    )
+}
+
+/// Abstract operation `DifferencePlainDateTimeWithTotal ( isoDateTime1, isoDateTime2, calendar, unit )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differenceplaindatetimewithtotal
+pub(crate) fn difference_plain_date_time_with_total(
+    one: IsoDateTime,
+    two: IsoDateTime,
+    calendar: Calendar,
+    unit: TemporalUnit,
+    context: &mut Context,
+) -> JsResult<f64> {
+    // 1. If CompareISODateTime(isoDateTime1, isoDateTime2) = 0, then
+    //     a. Return 0.
+    if one == two {
+        return Ok(0.0);
+    }
+
+    // 2. If ISODateTimeWithinLimits(isoDateTime1) is false or ISODateTimeWithinLimits(isoDateTime2) is false, throw a RangeError exception.
+    if !one.is_within_limits() || !two.is_within_limits() {
+        return context.throw_range_error("the date-time is out of range");
+    }
+
+    // 3. Let diff be DifferenceISODateTime(isoDateTime1, isoDateTime2, calendar, unit).
+    let difference = difference_iso_date_time(one, two, calendar, unit);
+
+    // 4. If unit is nanosecond, return diff.[[Time]].
+    if unit == TemporalUnit::Nanosecond {
+        return Ok(difference.1 as f64);
+    }
+
+    // 5. Let destEpochNs be GetUTCEpochNanoseconds(isoDateTime2).
+    // 6. Return ? TotalRelativeDuration(diff, destEpochNs, isoDateTime1, unset, calendar, unit).
+    let origin = RelativeOrigin {
+        date_time: one,
+        time_zone: None,
+        calendar,
+    };
+    total_relative_duration(difference, two.epoch_nanoseconds(), origin, unit, context)
+}
+
+/// The origin of a relative duration rounding: a date-time, with the time zone of a
+/// `Temporal.ZonedDateTime`, if any, and a calendar.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RelativeOrigin<'a> {
+    pub(crate) date_time: IsoDateTime,
+    pub(crate) time_zone: Option<&'a TimeZone>,
+    pub(crate) calendar: Calendar,
+}
+
+impl RelativeOrigin<'_> {
+    /// Gets the epoch nanoseconds of the time of the origin on another date, in UTC or in the
+    /// time zone of the origin.
+    fn epoch_nanoseconds_on(self, date: IsoDate, context: &mut Context) -> JsResult<i128> {
+        let date_time = IsoDateTime::new(date, self.date_time.time);
+        match self.time_zone {
+            Some(time_zone) => {
+                time_zone.epoch_nanoseconds_for(date_time, Disambiguation::Compatible, context)
+            }
+            None => Ok(date_time.epoch_nanoseconds()),
+        }
+    }
+
+    /// Adds a date duration to the date of the origin, with the `constrain` overflow.
+    fn date_add(self, duration: [i64; 4], context: &mut Context) -> JsResult<IsoDate> {
+        self.calendar
+            .date_add(self.date_time.date, duration, Overflow::Constrain)
+            .map_or_else(
+                || context.throw_range_error("the rounded duration is out of range"),
+                Ok,
+            )
+    }
+}
+
+/// A `Duration Nudge Result Record`, the result of the nudge operations of
+/// `RoundRelativeDuration`.
+#[derive(Debug, Clone, Copy)]
+struct DurationNudge {
+    /// `[[Duration]]`
+    duration: InternalDuration,
+    /// `[[NudgedEpochNs]]`
+    nudged_epoch_nanoseconds: i128,
+    /// `[[DidExpandCalendarUnit]]`
+    did_expand_calendar_unit: bool,
+}
+
+/// Abstract operation `RoundRelativeDuration ( duration, destEpochNs, isoDateTime, timeZone, calendar, largestUnit, increment, smallestUnit, roundingMode )`
+///
+/// Rounds a duration relative to `origin`, where `destination` is the epoch nanoseconds of
+/// `origin` plus the duration.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-roundrelativeduration
+pub(crate) fn round_relative_duration(
+    duration: InternalDuration,
+    destination: i128,
+    origin: RelativeOrigin<'_>,
+    settings: DifferenceSettings,
+    context: &mut Context,
+) -> JsResult<InternalDuration> {
+    // 1. Let irregularLengthUnit be false.
+    // 2. If IsCalendarUnit(smallestUnit) is true, set irregularLengthUnit to true.
+    // 3. If timeZone is not unset and smallestUnit is day, set irregularLengthUnit to true.
+    let irregular_length_unit = settings.smallest_unit < TemporalUnit::Day
+        || (origin.time_zone.is_some() && settings.smallest_unit == TemporalUnit::Day);
+
+    // 4. Let sign be InternalDurationSign(duration).
+    let sign = internal_duration_sign(duration);
+
+    // 5. If irregularLengthUnit is true, then
+    //     a. Let nudgeResult be ? NudgeToCalendarUnit(sign, duration, destEpochNs, isoDateTime, timeZone, calendar, increment, smallestUnit, roundingMode).
+    // 6. Else if timeZone is not unset, then
+    //     a. Let nudgeResult be ? NudgeToZonedTime(sign, duration, isoDateTime, timeZone, calendar, increment, smallestUnit, roundingMode).
+    // 7. Else,
+    //     a. Let nudgeResult be ? NudgeToDayOrTime(duration, destEpochNs, largestUnit, increment, smallestUnit, roundingMode).
+    let nudge = if irregular_length_unit {
+        nudge_to_calendar_unit(sign, duration, destination, origin, settings, context)?.0
+    } else if let Some(time_zone) = origin.time_zone {
+        nudge_to_zoned_time(sign, duration, origin, time_zone, settings, context)?
+    } else {
+        nudge_to_day_or_time(duration, destination, settings)
+    };
+
+    // 8. Set duration to nudgeResult.[[Duration]].
+    // 9. If nudgeResult.[[DidExpandCalendarUnit]] is true and smallestUnit is not week, then
+    if nudge.did_expand_calendar_unit && settings.smallest_unit != TemporalUnit::Week {
+        // a. Let startUnit be LargerOfTwoTemporalUnits(smallestUnit, day).
+        // b. Set duration to ? BubbleRelativeDuration(sign, duration, nudgeResult.[[NudgedEpochNs]], isoDateTime, timeZone, calendar, largestUnit, startUnit).
+        return bubble_relative_duration(
+            sign,
+            nudge.duration,
+            nudge.nudged_epoch_nanoseconds,
+            origin,
+            settings.largest_unit,
+            settings.smallest_unit.min(TemporalUnit::Day),
+            context,
+        );
+    }
+
+    // 10. Return duration.
+    Ok(nudge.duration)
+}
+
+/// Abstract operation `TotalRelativeDuration ( duration, destEpochNs, isoDateTime, timeZone, calendar, unit )`
+///
+/// Gets the total of a duration in `unit`, relative to `origin`, where `destination` is the
+/// epoch nanoseconds of `origin` plus the duration.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totalrelativeduration
+pub(crate) fn total_relative_duration(
+    duration: InternalDuration,
+    destination: i128,
+    origin: RelativeOrigin<'_>,
+    unit: TemporalUnit,
+    context: &mut Context,
+) -> JsResult<f64> {
+    // 1. If IsCalendarUnit(unit) is true, or timeZone is not unset and unit is day, then
+    if unit < TemporalUnit::Day || (origin.time_zone.is_some() && unit == TemporalUnit::Day) {
+        // a. Let sign be InternalDurationSign(duration).
+        let sign = internal_duration_sign(duration);
+
+        // b. Let record be ? NudgeToCalendarUnit(sign, duration, destEpochNs, isoDateTime, timeZone, calendar, 1, unit, trunc).
+        // c. Return record.[[Total]].
+        let settings = DifferenceSettings {
+            largest_unit: unit,
+            smallest_unit: unit,
+            increment: 1,
+            rounding_mode: RoundingMode::Trunc,
+        };
+        let (_, total) =
+            nudge_to_calendar_unit(sign, duration, destination, origin, settings, context)?;
+        return Ok(total);
+    }
+
+    // 2. Let timeDuration be ! Add24HourDaysToTimeDuration(duration.[[Time]], duration.[[Date]].[[Days]]).
+    let time = duration.1 + i128::from(duration.0[3]) * NS_PER_DAY;
+
+    // 3. Return TotalTimeDuration(timeDuration, unit).
+    Ok(total_time_duration(time, unit))
+}
+
+/// Abstract operation `NudgeToCalendarUnit ( sign, duration, destEpochNs, isoDateTime, timeZone, calendar, increment, unit, roundingMode )`
+///
+/// Returns the nudge result, and the total of the duration in the unit, `[[Total]]`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-nudgetocalendarunit
+fn nudge_to_calendar_unit(
+    sign: i64,
+    duration: InternalDuration,
+    destination: i128,
+    origin: RelativeOrigin<'_>,
+    settings: DifferenceSettings,
+    context: &mut Context,
+) -> JsResult<(DurationNudge, f64)> {
+    let [years, months, weeks, days] = duration.0;
+    let increment = settings.increment as i64;
+    let truncate = |value: i64| value / increment * increment;
+
+    // 2-5. Let r1 be the value of the unit rounded down to the increment, and r2 be r1 plus
+    //      increment × sign, with the start and end durations of these values.
+    let (r1, start_duration, end_duration) = match settings.smallest_unit {
+        TemporalUnit::Year => {
+            let r1 = truncate(years);
+            (r1, [r1, 0, 0, 0], [r1 + increment * sign, 0, 0, 0])
+        }
+        TemporalUnit::Month => {
+            let r1 = truncate(months);
+            (r1, [years, r1, 0, 0], [years, r1 + increment * sign, 0, 0])
+        }
+        TemporalUnit::Week => {
+            // a. Let yearsMonths be AdjustDateDurationRecord(duration.[[Date]], 0, 0).
+            // b. Let weeksStart be ? CalendarDateAdd(calendar, isoDateTime.[[ISODate]], yearsMonths, constrain).
+            // c. Let weeksEnd be BalanceISODate(weeksStart.[[Year]], weeksStart.[[Month]], weeksStart.[[Day]] + duration.[[Date]].[[Days]]).
+            // d. Let untilResult be CalendarDateUntil(calendar, weeksStart, weeksEnd, week).
+            let weeks_start = origin.date_add([years, months, 0, 0], context)?;
+            let weeks_end = IsoDate::from_epoch_days(weeks_start.epoch_days() + days);
+            let until = origin
+                .calendar
+                .date_until(weeks_start, weeks_end, TemporalUnit::Week);
+
+            // e. Let weeks be RoundNumberToIncrement(duration.[[Date]].[[Weeks]] + untilResult.[[Weeks]], increment, trunc).
+            let r1 = truncate(weeks + until[2]);
+            (
+                r1,
+                [years, months, r1, 0],
+                [years, months, r1 + increment * sign, 0],
+            )
+        }
+        _ => {
+            // a. Let days be RoundNumberToIncrement(duration.[[Date]].[[Days]], increment, trunc).
+            let r1 = truncate(days);
+            (
+                r1,
+                [years, months, weeks, r1],
+                [years, months, weeks, r1 + increment * sign],
+            )
+        }
+    };
+
+    // 7. Let start be ? CalendarDateAdd(calendar, isoDateTime.[[ISODate]], startDuration, constrain).
+    // 8. Let end be ? CalendarDateAdd(calendar, isoDateTime.[[ISODate]], endDuration, constrain).
+    let start = origin.date_add(start_duration, context)?;
+    let end = origin.date_add(end_duration, context)?;
+
+    // 9. Let startDateTime be CombineISODateAndTimeRecord(start, isoDateTime.[[Time]]).
+    // 10. Let endDateTime be CombineISODateAndTimeRecord(end, isoDateTime.[[Time]]).
+    // 11. If timeZone is unset, then
+    //     a. Let startEpochNs be GetUTCEpochNanoseconds(startDateTime).
+    //     b. Let endEpochNs be GetUTCEpochNanoseconds(endDateTime).
+    // 12. Else,
+    //     a. Let startEpochNs be ? GetEpochNanosecondsFor(timeZone, startDateTime, compatible).
+    //     b. Let endEpochNs be ? GetEpochNanosecondsFor(timeZone, endDateTime, compatible).
+    let start_epoch_nanoseconds = origin.epoch_nanoseconds_on(start, context)?;
+    let end_epoch_nanoseconds = origin.epoch_nanoseconds_on(end, context)?;
+
+    // 13. If endEpochNs = startEpochNs, throw a RangeError exception.
+    if end_epoch_nanoseconds == start_epoch_nanoseconds {
+        return context.throw_range_error("the rounded duration is out of range");
+    }
+
+    // 14. Let numerator be TimeDurationFromEpochNanosecondsDifference(destEpochNs, startEpochNs).
+    // 15. Let denominator be TimeDurationFromEpochNanosecondsDifference(endEpochNs, startEpochNs).
+    // 16. Let total be r1 + numerator / denominator × increment × sign.
+    // 17-18. Let roundedUnit be the rounding of total between r1 and r2 with roundingMode.
+    //
+    // The total is scaled by the absolute value of the denominator to round it as an integer.
+    let numerator = (destination - start_epoch_nanoseconds).abs();
+    let denominator = (end_epoch_nanoseconds - start_epoch_nanoseconds).abs();
+    let increment = settings.increment;
+    let total = i128::from(r1) * denominator + i128::from(sign) * increment * numerator;
+    let rounded = round_to_increment(total, increment * denominator, settings.rounding_mode);
+    let total_number = divide_to_number(total, denominator);
+
+    // 19. If roundedUnit = abs(r2), then
+    //     a. Let didExpandCalendarUnit be true.
+    //     b. Let resultDuration be endDuration.
+    //     c. Let nudgedEpochNs be endEpochNs.
+    // 20. Else,
+    //     a. Let didExpandCalendarUnit be false.
+    //     b. Let resultDuration be startDuration.
+    //     c. Let nudgedEpochNs be startEpochNs.
+    // 21. Set resultDuration to CombineDateAndTimeDuration(resultDuration, 0).
+    // 22. Let nudgeResult be Duration Nudge Result Record { [[Duration]]: resultDuration, [[NudgedEpochNs]]: nudgedEpochNs, [[DidExpandCalendarUnit]]: didExpandCalendarUnit }.
+    // 23. Return the Record { [[NudgeResult]]: nudgeResult, [[Total]]: total }.
+    let r2 = i128::from(r1) + i128::from(sign) * increment;
+    let nudge = if rounded == r2 * denominator {
+        DurationNudge {
+            duration: (end_duration, 0),
+            nudged_epoch_nanoseconds: end_epoch_nanoseconds,
+            did_expand_calendar_unit: true,
+        }
+    } else {
+        DurationNudge {
+            duration: (start_duration, 0),
+            nudged_epoch_nanoseconds: start_epoch_nanoseconds,
+            did_expand_calendar_unit: false,
+        }
+    };
+    Ok((nudge, total_number))
+}
+
+/// Abstract operation `NudgeToZonedTime ( sign, duration, isoDateTime, timeZone, calendar, increment, unit, roundingMode )`
+///
+/// Rounds the time of a duration relative to a date-time in a time zone, where the days may
+/// not be 24 hours long.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-nudgetozonedtime
+fn nudge_to_zoned_time(
+    sign: i64,
+    duration: InternalDuration,
+    origin: RelativeOrigin<'_>,
+    time_zone: &TimeZone,
+    settings: DifferenceSettings,
+    context: &mut Context,
+) -> JsResult<DurationNudge> {
+    // 1. Let start be ? CalendarDateAdd(calendar, isoDateTime.[[ISODate]], duration.[[Date]], constrain).
+    let start = origin.date_add(duration.0, context)?;
+
+    // 2. Let startDateTime be CombineISODateAndTimeRecord(start, isoDateTime.[[Time]]).
+    // 3. Let endDate be BalanceISODate(start.[[Year]], start.[[Month]], start.[[Day]] + sign).
+    // 4. Let endDateTime be CombineISODateAndTimeRecord(endDate, isoDateTime.[[Time]]).
+    // 5. Let startEpochNs be ? GetEpochNanosecondsFor(timeZone, startDateTime, compatible).
+    // 6. Let endEpochNs be ? GetEpochNanosecondsFor(timeZone, endDateTime, compatible).
+    let end = IsoDate::from_epoch_days(start.epoch_days() + sign);
+    let start_epoch_nanoseconds = time_zone.epoch_nanoseconds_for(
+        IsoDateTime::new(start, origin.date_time.time),
+        Disambiguation::Compatible,
+        context,
+    )?;
+    let end_epoch_nanoseconds = time_zone.epoch_nanoseconds_for(
+        IsoDateTime::new(end, origin.date_time.time),
+        Disambiguation::Compatible,
+        context,
+    )?;
+
+    // 7. Let daySpan be TimeDurationFromEpochNanosecondsDifference(endEpochNs, startEpochNs).
+    // 8. If TimeDurationSign(daySpan) ≠ sign, throw a RangeError exception.
+    let day_span = end_epoch_nanoseconds - start_epoch_nanoseconds;
+    if day_span.signum() as i64 != sign {
+        return context.throw_range_error("the rounded duration is out of range");
+    }
+
+    // 9. Let unitLength be the value in the "Length in Nanoseconds" column of the row of Table 21 whose "Value" column contains unit.
+    // 10. Let roundedTimeDuration be ? RoundTimeDurationToIncrement(duration.[[Time]], increment × unitLength, roundingMode).
+    let increment = settings
+        .smallest_unit
+        .nanoseconds()
+        .expect("the smallest unit must be a unit of time")
+        * settings.increment;
+    let rounded = round_to_increment(duration.1, increment, settings.rounding_mode);
+
+    // 11. Let beyondDaySpan be ! AddTimeDuration(roundedTimeDuration, -daySpan).
+    let beyond_day_span = rounded - day_span;
+
+    // 12. If TimeDurationSign(beyondDaySpan) ≠ -sign, then
+    //     a. Let didRoundBeyondDay be true.
+    //     b. Let dayDelta be sign.
+    //     c. Set roundedTimeDuration to ? RoundTimeDurationToIncrement(beyondDaySpan, increment × unitLength, roundingMode).
+    //     d. Let nudgedEpochNs be AddTimeDurationToEpochNanoseconds(roundedTimeDuration, endEpochNs).
+    // 13. Else,
+    //     a. Let didRoundBeyondDay be false.
+    //     b. Let dayDelta be 0.
+    //     c. Let nudgedEpochNs be AddTimeDurationToEpochNanoseconds(roundedTimeDuration, startEpochNs).
+    let (did_round_beyond_day, day_delta, rounded, nudged_epoch_nanoseconds) =
+        if beyond_day_span.signum() as i64 == -sign {
+            (false, 0, rounded, start_epoch_nanoseconds + rounded)
+        } else {
+            let rounded = round_to_increment(beyond_day_span, increment, settings.rounding_mode);
+            (true, sign, rounded, end_epoch_nanoseconds + rounded)
+        };
+
+    // 14. Let dateDuration be ! AdjustDateDurationRecord(duration.[[Date]], duration.[[Date]].[[Days]] + dayDelta).
+    // 15. Let resultDuration be CombineDateAndTimeDuration(dateDuration, roundedTimeDuration).
+    // 16. Return Duration Nudge Result Record { [[Duration]]: resultDuration, [[NudgedEpochNs]]: nudgedEpochNs, [[DidExpandCalendarUnit]]: didRoundBeyondDay }.
+    let [years, months, weeks, days] = duration.0;
+    Ok(DurationNudge {
+        duration: ([years, months, weeks, days + day_delta], rounded),
+        nudged_epoch_nanoseconds,
+        did_expand_calendar_unit: did_round_beyond_day,
+    })
+}
+
+/// Abstract operation `NudgeToDayOrTime ( duration, destEpochNs, largestUnit, increment, smallestUnit, roundingMode )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-nudgetodayortime
+fn nudge_to_day_or_time(
+    duration: InternalDuration,
+    destination: i128,
+    settings: DifferenceSettings,
+) -> DurationNudge {
+    let [years, months, weeks, days] = duration.0;
+
+    // 2. Let timeDuration be ! Add24HourDaysToTimeDuration(duration.[[Time]], duration.[[Date]].[[Days]]).
+    let time = duration.1 + i128::from(days) * NS_PER_DAY;
+
+    // 3. Let unitLength be the value in the "Length in Nanoseconds" column of the row of Table 21 whose "Value" column contains smallestUnit.
+    // 4. Let roundedTime be ? RoundTimeDurationToIncrement(timeDuration, unitLength × increment, roundingMode).
+    let unit_length = settings
+        .smallest_unit
+        .nanoseconds()
+        .expect("the smallest unit must be a day or a unit of time");
+    let rounded = round_to_increment(
+        time,
+        unit_length * settings.increment,
+        settings.rounding_mode,
+    );
+
+    // 5. Let diffTime be ! AddTimeDuration(roundedTime, -timeDuration).
+    // 6. Let wholeDays be truncate(TotalTimeDuration(timeDuration, day)).
+    // 7. Let roundedWholeDays be truncate(TotalTimeDuration(roundedTime, day)).
+    // 8. Let dayDelta be roundedWholeDays - wholeDays.
+    let whole_days = time / NS_PER_DAY;
+    let rounded_whole_days = rounded / NS_PER_DAY;
+    let day_delta = rounded_whole_days - whole_days;
+
+    // 9. If dayDelta < 0, let dayDeltaSign be -1; else if dayDelta > 0, let dayDeltaSign be 1; else let dayDeltaSign be 0.
+    // 10. If dayDeltaSign = TimeDurationSign(timeDuration), let didExpandDays be true; else let didExpandDays be false.
+    let did_expand_days = day_delta.signum() == time.signum();
+
+    // 11. Let nudgedEpochNs be AddTimeDurationToEpochNanoseconds(diffTime, destEpochNs).
+    let nudged_epoch_nanoseconds = destination + rounded - time;
+
+    // 12. Let days be 0.
+    // 13. Let remainder be roundedTime.
+    // 14. If TemporalUnitCategory(largestUnit) is date, then
+    //     a. Set days to roundedWholeDays.
+    //     b. Set remainder to ! AddTimeDuration(roundedTime, TimeDurationFromComponents(-roundedWholeDays × HoursPerDay, 0, 0, 0, 0, 0)).
+    let (days, remainder) = if settings.largest_unit.is_time() {
+        (0, rounded)
+    } else {
+        (
+            rounded_whole_days as i64,
+            rounded - rounded_whole_days * NS_PER_DAY,
+        )
+    };
+
+    // 15. Let dateDuration be ! AdjustDateDurationRecord(duration.[[Date]], days).
+    // 16. Let resultDuration be ! CombineDateAndTimeDuration(dateDuration, remainder).
+    // 17. Return Duration Nudge Result Record { [[Duration]]: resultDuration, [[NudgedEpochNs]]: nudgedEpochNs, [[DidExpandCalendarUnit]]: didExpandDays }.
+    DurationNudge {
+        duration: ([years, months, weeks, days], remainder),
+        nudged_epoch_nanoseconds,
+        did_expand_calendar_unit: did_expand_days,
+    }
+}
+
+/// Abstract operation `BubbleRelativeDuration ( sign, duration, nudgedEpochNs, isoDateTime, timeZone, calendar, largestUnit, smallestUnit )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-bubblerelativeduration
+fn bubble_relative_duration(
+    sign: i64,
+    duration: InternalDuration,
+    nudged_epoch_nanoseconds: i128,
+    origin: RelativeOrigin<'_>,
+    largest_unit: TemporalUnit,
+    smallest_unit: TemporalUnit,
+    context: &mut Context,
+) -> JsResult<InternalDuration> {
+    // 1. If smallestUnit is largestUnit, return duration.
+    if largest_unit >= smallest_unit {
+        return Ok(duration);
+    }
+
+    // 2-5. Repeat, for each unit larger than smallestUnit and not larger than largestUnit,
+    //      from the smallest to the largest,
+    let mut duration = duration;
+    for unit in TemporalUnit::ALL[largest_unit as usize..smallest_unit as usize]
+        .iter()
+        .rev()
+    {
+        // b. If unit is not week, or largestUnit is week, then
+        if *unit == TemporalUnit::Week && largest_unit != TemporalUnit::Week {
+            continue;
+        }
+
+        // i-iii. Let endDuration be the duration with one more of unit in the direction of sign,
+        //        and with the smaller date units set to zero.
+        let [years, months, weeks, _] = duration.0;
+        let end_duration = match unit {
+            TemporalUnit::Year => [years + sign, 0, 0, 0],
+            TemporalUnit::Month => [years, months + sign, 0, 0],
+            _ => [years, months, weeks + sign, 0],
+        };
+
+        // iv. Let end be ? CalendarDateAdd(calendar, isoDateTime.[[ISODate]], endDuration, constrain).
+        // v. Let endDateTime be CombineISODateAndTimeRecord(end, isoDateTime.[[Time]]).
+        // vi. If timeZone is unset, let endEpochNs be GetUTCEpochNanoseconds(endDateTime).
+        // vii. Else, let endEpochNs be ? GetEpochNanosecondsFor(timeZone, endDateTime, compatible).
+        let end = origin.date_add(end_duration, context)?;
+        let end_epoch_nanoseconds = origin.epoch_nanoseconds_on(end, context)?;
+
+        // viii. Let beyondEnd be nudgedEpochNs - endEpochNs.
+        // ix. If beyondEnd < 0, let beyondEndSign be -1; else if beyondEnd > 0, let beyondEndSign be 1; else let beyondEndSign be 0.
+        // x. If beyondEndSign ≠ -sign, then
+        //     1. Set duration to CombineDateAndTimeDuration(endDuration, 0).
+        // xi. Else,
+        //     1. Set done to true.
+        let beyond_end_sign = (nudged_epoch_nanoseconds - end_epoch_nanoseconds).signum() as i64;
+        if beyond_end_sign == -sign {
+            break;
+        }
+        duration = (end_duration, 0);
+    }
+
+    // 6. Return duration.
+    Ok(duration)
+}
+
+/// The internal slots of a `Temporal.PlainDateTime` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Trace, Finalize)]
+pub struct PlainDateTime {
+    /// `[[ISODateTime]]`
+    #[unsafe_ignore_trace]
+    date_time: IsoDateTime,
+    /// `[[Calendar]]`
+    #[unsafe_ignore_trace]
+    calendar: Calendar,
+}
+
+impl BuiltIn for PlainDateTime {
+    const NAME: &'static str = "PlainDateTime";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let get_calendar_id = FunctionBuilder::native(context, Self::get_calendar_id)
+            .name("get calendarId")
+            .length(0)
+            .constructor(false)
+            .build();
+        let date_getters = DATE_GETTERS.map(|(name, getter)| {
+            let function = FunctionBuilder::closure(context, move |this, _, context| {
+                let date_time = Self::this_plain_date_time(this, context)?;
+                Ok(getter(date_time.calendar, date_time.date_time.date))
+            })
+            .name(format!("get {name}"))
+            .length(0)
+            .constructor(false)
+            .build();
+            (name, function)
+        });
+        let time_getters = TIME_GETTERS.map(|(name, getter)| {
+            let function = FunctionBuilder::closure(context, move |this, _, context| {
+                Ok(getter(
+                    Self::this_plain_date_time(this, context)?.date_time.time,
+                ))
+            })
+            .name(format!("get {name}"))
+            .length(0)
+            .constructor(false)
+            .build();
+            (name, function)
+        });
+
+        let mut constructor = ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context
+                .intrinsics()
+                .constructors()
+                .plain_date_time()
+                .clone(),
+        );
+        constructor
+            .name(Self::NAME)
+            .length(Self::LENGTH)
+            .static_method(Self::from, "from", 1)
+            .static_method(Self::compare, "compare", 2)
+            .accessor(
+                "calendarId",
+                Some(get_calendar_id),
+                None,
+                Attribute::CONFIGURABLE,
+            );
+        for (name, getter) in date_getters.into_iter().chain(time_getters) {
+            constructor.accessor(name, Some(getter), None, Attribute::CONFIGURABLE);
+        }
+        constructor
+            .method(Self::add, "add", 1)
+            .method(Self::subtract, "subtract", 1)
+            .method(Self::with, "with", 1)
+            .method(Self::with_plain_time, "withPlainTime", 0)
+            .method(Self::with_calendar, "withCalendar", 1)
+            .method(Self::until, "until", 1)
+            .method(Self::since, "since", 1)
+            .method(Self::round, "round", 1)
+            .method(Self::equals, "equals", 1)
+            .method(Self::to_plain_date, "toPlainDate", 0)
+            .method(Self::to_plain_time, "toPlainTime", 0)
+            .method(Self::to_zoned_date_time, "toZonedDateTime", 1)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_json, "toJSON", 0)
+            .method(Self::to_locale_string, "toLocaleString", 0)
+            .method(Self::value_of, "valueOf", 0)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                "Temporal.PlainDateTime",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build()
+            .conv::<JsValue>()
+            .pipe(Some)
+    }
+}
+
+impl PlainDateTime {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 3;
+
+    /// Creates a `PlainDateTime` from its date-time and calendar.
+    pub(crate) fn new(date_time: IsoDateTime, calendar: Calendar) -> Self {
+        Self {
+            date_time,
+            calendar,
+        }
+    }
+
+    /// Gets the date-time of the `PlainDateTime`.
+    pub(crate) fn date_time(self) -> IsoDateTime {
+        self.date_time
+    }
+
+    /// Gets the calendar of the `PlainDateTime`.
+    pub(crate) fn calendar(self) -> Calendar {
+        self.calendar
+    }
+
+    /// Abstract operation `CreateTemporalDateTime ( isoDateTime, calendar [ , newTarget ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-createtemporaldatetime
+    pub(crate) fn create(
+        self,
+        new_target: Option<&JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. If ISODateTimeWithinLimits(isoDateTime) is false, then
+        //     a. Throw a RangeError exception.
+        if !self.date_time.is_within_limits() {
+            return context.throw_range_error("the date-time is out of range");
+        }
+
+        // 2. If newTarget is not present, set newTarget to %Temporal.PlainDateTime%.
+        // 3. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.PlainDateTime.prototype%", « [[InitializedTemporalDateTime]], [[ISODateTime]], [[Calendar]] »).
+        let prototype = match new_target {
+            Some(new_target) => get_prototype_from_constructor(
+                new_target,
+                StandardConstructors::plain_date_time,
+                context,
+            )?,
+            None => context
+                .intrinsics()
+                .constructors()
+                .plain_date_time()
+                .prototype(),
+        };
+
+        // 4. Set object.[[ISODateTime]] to isoDateTime.
+        // 5. Set object.[[Calendar]] to calendar.
+        // 6. Return object.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::plain_date_time(self),
+        ))
+    }
+
+    /// Abstract operation `ToTemporalDateTime ( item [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporaldatetime
+    pub(crate) fn from_value(
+        item: &JsValue,
+        options: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let date_time = match item {
+            // 2. If item is an Object, then
+            JsValue::Object(object) => {
+                // a. If item has an [[InitializedTemporalDateTime]] internal slot, then
+                //     i. Let resolvedOptions be ? GetOptionsObject(options).
+                //     ii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+                //     iii. Return ! CreateTemporalDateTime(item.[[ISODateTime]], item.[[Calendar]]).
+                // b. If item has an [[InitializedTemporalZonedDateTime]] internal slot, then
+                //     i. Let isoDateTime be GetISODateTimeFor(item.[[TimeZone]], item.[[EpochNanoseconds]]).
+                //     ii. Let resolvedOptions be ? GetOptionsObject(options).
+                //     iii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+                //     iv. Return ! CreateTemporalDateTime(isoDateTime, item.[[Calendar]]).
+                // c. If item has an [[InitializedTemporalDate]] internal slot, then
+                //     i. Let resolvedOptions be ? GetOptionsObject(options).
+                //     ii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+                //     iii. Let isoDateTime be CombineISODateAndTimeRecord(item.[[ISODate]], MidnightTimeRecord()).
+                //     iv. Return ? CreateTemporalDateTime(isoDateTime, item.[[Calendar]]).
+                let date_time = {
+                    let object = object.borrow();
+                    object
+                        .as_plain_date_time()
+                        .copied()
+                        .or_else(|| {
+                            object.as_zoned_date_time().map(|date_time| {
+                                Self::new(date_time.date_time(context), date_time.calendar())
+                            })
+                        })
+                        .or_else(|| {
+                            object.as_plain_date().map(|date| {
+                                Self::new(
+                                    IsoDateTime::new(date.date(), IsoTime::default()),
+                                    date.calendar(),
+                                )
+                            })
+                        })
+                };
+                if let Some(date_time) = date_time {
+                    Overflow::from_options(options, context)?;
+                    date_time
+                } else {
+                    // d. Let calendar be ? GetTemporalCalendarIdentifierWithISODefault(item).
+                    let calendar = Calendar::from_fields_object(object, context)?;
+
+                    // e. Let fields be ? PrepareCalendarFields(calendar, item, « year, month, month-code, day », « hour, minute, second, millisecond, microsecond, nanosecond », « »).
+                    let fields =
+                        Fields::read(object, Some(calendar), true, context)?.unwrap_or_default();
+
+                    // f. Let resolvedOptions be ? GetOptionsObject(options).
+                    // g. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+                    let overflow = Overflow::from_options(options, context)?;
+
+                    // h. Let result be ? InterpretTemporalDateTimeFields(calendar, fields, overflow).
+                    Self::new(
+                        interpret_fields(calendar, &fields, overflow, context)?,
+                        calendar,
+                    )
+                }
+            }
+            // 3. Else,
+            JsValue::String(string) => {
+                // b. Let result be ? ParseISODateTime(item, « TemporalDateTimeString[~Zoned] »).
+                let parsed = match parse_date_time(string) {
+                    Ok(parsed) if parsed.offset != Some(UtcOffset::Z) => parsed,
+                    Ok(_) => {
+                        return context.throw_range_error(format!(
+                            "the date-time string `{string}` must not have a `Z` designator"
+                        ))
+                    }
+                    Err(error) => {
+                        return context.throw_range_error(format!(
+                            "invalid date-time string `{string}`: {error}"
+                        ))
+                    }
+                };
+
+                // c. Let time be result.[[Time]].
+                // d. If time is start-of-day, set time to MidnightTimeRecord().
+                let time = parsed
+                    .time
+                    .map_or_else(IsoTime::default, IsoTime::from_parsed);
+
+                // e. Let calendar be result.[[Calendar]].
+                // f. If calendar is empty, set calendar to "iso8601".
+                // g. Set calendar to ? CanonicalizeCalendar(calendar).
+                let calendar = match parsed
+                    .calendar
+                    .as_deref()
+                    .map_or(Some(Calendar::Iso8601), Calendar::from_identifier)
+                {
+                    Some(calendar) => calendar,
+                    None => return context.throw_range_error("unsupported calendar"),
+                };
+
+                // h. Let resolvedOptions be ? GetOptionsObject(options).
+                // i. Perform ? GetTemporalOverflowOption(resolvedOptions).
+                Overflow::from_options(options, context)?;
+
+                // j. Let isoDate be CreateISODateRecord(result.[[Year]], result.[[Month]], result.[[Day]]).
+                // k. Let dateTime be CombineISODateAndTimeRecord(isoDate, time).
+                let date = IsoDate {
+                    year: parsed.year,
+                    month: parsed.month,
+                    day: parsed.day,
+                };
+                Self::new(IsoDateTime::new(date, time), calendar)
+            }
+            // a. If item is not a String, throw a TypeError exception.
+            _ => return context.throw_type_error("a date-time must be a string or an object"),
+        };
+
+        // 4. Return ? CreateTemporalDateTime(dateTime, calendar).
+        if !date_time.date_time.is_within_limits() {
+            return context.throw_range_error("the date-time is out of range");
+        }
+        Ok(date_time)
+    }
+
+    /// Gets the `this` `Temporal.PlainDateTime` object.
+    fn this_plain_date_time(this: &JsValue, context: &mut Context) -> JsResult<Self> {
+        this.as_object()
+            .and_then(|obj| obj.borrow().as_plain_date_time().copied())
+            .ok_or_else(|| context.construct_type_error("'this' is not a Temporal.PlainDateTime"))
+    }
+
+    /// `Temporal.PlainDateTime ( isoYear, isoMonth, isoDay [ , hour [ , minute [ , second [ , millisecond [ , microsecond [ , nanosecond [ , calendar ] ] ] ] ] ] ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime/PlainDateTime
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error("Temporal.PlainDateTime constructor requires 'new'");
+        }
+
+        // 2. Set isoYear to ? ToIntegerWithTruncation(isoYear).
+        // 3. Set isoMonth to ? ToIntegerWithTruncation(isoMonth).
+        // 4. Set isoDay to ? ToIntegerWithTruncation(isoDay).
+        let year = to_integer_with_truncation(args.get_or_undefined(0), context)?;
+        let month = to_integer_with_truncation(args.get_or_undefined(1), context)?;
+        let day = to_integer_with_truncation(args.get_or_undefined(2), context)?;
+
+        // 5. If hour is undefined, set hour to 0; else set hour to ? ToIntegerWithTruncation(hour).
+        // ...
+        // 10. If nanosecond is undefined, set nanosecond to 0; else set nanosecond to ? ToIntegerWithTruncation(nanosecond).
+        let mut time = [0.0; 6];
+        for (index, value) in time.iter_mut().enumerate() {
+            let arg = args.get_or_undefined(index + 3);
+            if !arg.is_undefined() {
+                *value = to_integer_with_truncation(arg, context)?;
+            }
+        }
+
+        // 11. If calendar is undefined, set calendar to "iso8601".
+        // 12. If calendar is not a String, throw a TypeError exception.
+        // 13. Set calendar to ? CanonicalizeCalendar(calendar).
+        let calendar = Calendar::from_constructor_argument(args.get_or_undefined(9), context)?;
+
+        // 14. If IsValidISODate(isoYear, isoMonth, isoDay) is false, throw a RangeError exception.
+        // 15. Let isoDate be CreateISODateRecord(isoYear, isoMonth, isoDay).
+        let date = match IsoDate::regulate(year, month, day, Overflow::Reject) {
+            Some(date) => date,
+            None => return context.throw_range_error("the date is invalid"),
+        };
+
+        // 16. If IsValidTime(hour, minute, second, millisecond, microsecond, nanosecond) is false, throw a RangeError exception.
+        // 17. Let time be CreateTimeRecord(hour, minute, second, millisecond, microsecond, nanosecond).
+        let time = match IsoTime::regulate(time, Overflow::Reject) {
+            Some(time) => time,
+            None => return context.throw_range_error("the time is invalid"),
+        };
+
+        // 18. Let isoDateTime be CombineISODateAndTimeRecord(isoDate, time).
+        // 19. Return ? CreateTemporalDateTime(isoDateTime, calendar, NewTarget).
+        Ok(Self::new(IsoDateTime::new(date, time), calendar)
+            .create(Some(new_target), context)?
+            .into())
+    }
+
+    /// `Temporal.PlainDateTime.from ( item [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.from
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime/from
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? ToTemporalDateTime(item, options).
+        let date_time =
+            Self::from_value(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+        Ok(date_time.create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainDateTime.compare ( one, two )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.compare
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime/compare
+    fn compare(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set one to ? ToTemporalDateTime(one).
+        let one = Self::from_value(args.get_or_undefined(0), &JsValue::undefined(), context)?;
+
+        // 2. Set two to ? ToTemporalDateTime(two).
+        let two = Self::from_value(args.get_or_undefined(1), &JsValue::undefined(), context)?;
+
+        // 3. Return 𝔽(CompareISODateTime(one.[[ISODateTime]], two.[[ISODateTime]])).
+        Ok(ordering_to_value(one.date_time.cmp(&two.date_time)))
+    }
+
+    /// `get Temporal.PlainDateTime.prototype.calendarId`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.calendarid
+    fn get_calendar_id(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        // 3. Return dateTime.[[Calendar]].
+        Ok(Self::this_plain_date_time(this, context)?
+            .calendar
+            .identifier()
+            .into())
+    }
+
+    /// Abstract operation `AddDurationToDateTime ( operation, dateTime, temporalDurationLike, options )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-adddurationtodatetime
+    fn add_duration(
+        this: &JsValue,
+        args: &[JsValue],
+        subtract: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let date_time = Self::this_plain_date_time(this, context)?;
+
+        // 1. Let duration be ? ToTemporalDuration(temporalDurationLike).
+        // 2. If operation is subtract, set duration to CreateNegatedTemporalDuration(duration).
+        let mut duration = Duration::from_value(args.get_or_undefined(0), context)?;
+        if subtract {
+            duration = duration.negated();
+        }
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        // 4. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow = Overflow::from_options(args.get_or_undefined(1), context)?;
+
+        // 5. Let internalDuration be ToInternalDurationRecordWith24HourDays(duration).
+        let mut date_duration = duration.date_part();
+        let time_duration = duration.time_nanoseconds() + i128::from(date_duration[3]) * NS_PER_DAY;
+        date_duration[3] = 0;
+
+        // 6-11. Let result be the date-time plus the duration.
+        let result = match date_time.date_time.add(
+            date_duration,
+            time_duration,
+            date_time.calendar,
+            overflow,
+        ) {
+            Some(result) => result,
+            None => return context.throw_range_error("the resulting date-time is out of range"),
+        };
+
+        // 12. Return ? CreateTemporalDateTime(result, dateTime.[[Calendar]]).
+        Ok(Self::new(result, date_time.calendar)
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.add ( temporalDurationLike [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.add
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime/add
+    fn add(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        // 3. Return ? AddDurationToDateTime(add, dateTime, temporalDurationLike, options).
+        Self::add_duration(this, args, false, context)
+    }
+
+    /// `Temporal.PlainDateTime.prototype.subtract ( temporalDurationLike [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.subtract
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime/subtract
+    fn subtract(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        // 3. Return ? AddDurationToDateTime(subtract, dateTime, temporalDurationLike, options).
+        Self::add_duration(this, args, true, context)
+    }
+
+    /// `Temporal.PlainDateTime.prototype.with ( temporalDateTimeLike [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.with
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime/with
+    fn with(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let date_time = Self::this_plain_date_time(this, context)?;
+
+        // 3. If ? IsPartialTemporalObject(temporalDateTimeLike) is false, throw a TypeError exception.
+        let date_time_like = to_partial_temporal_object(args.get_or_undefined(0), context)?;
+
+        // 4. Let calendar be dateTime.[[Calendar]].
+        let calendar = date_time.calendar;
+
+        // 5. Let fields be ISODateToFields(calendar, dateTime.[[ISODateTime]].[[ISODate]], date).
+        // 6-11. Set fields.[[Hour]] to dateTime.[[ISODateTime]].[[Time]].[[Hour]].
+        //       ...
+        let mut fields = calendar.fields_of(date_time.date_time.date);
+        fields.override_with(&time_fields(date_time.date_time.time));
+
+        // 12. Let partialDateTime be ? PrepareCalendarFields(calendar, temporalDateTimeLike, « year, month, month-code, day », « hour, minute, second, millisecond, microsecond, nanosecond », partial).
+        let partial = match Fields::read(&date_time_like, Some(calendar), true, context)? {
+            Some(partial) => partial,
+            None => return context.throw_type_error("at least one date or time field is required"),
+        };
+
+        // 13. Set fields to CalendarMergeFields(calendar, fields, partialDateTime).
+        let fields = calendar.merge_fields(&fields, &partial);
+
+        // 14. Let resolvedOptions be ? GetOptionsObject(options).
+        // 15. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow = Overflow::from_options(args.get_or_undefined(1), context)?;
+
+        // 16. Let result be ? InterpretTemporalDateTimeFields(calendar, fields, overflow).
+        let result = interpret_fields(calendar, &fields, overflow, context)?;
+
+        // 17. Return ? CreateTemporalDateTime(result, calendar).
+        Ok(Self::new(result, calendar).create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.withPlainTime ( [ plainTimeLike ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.withplaintime
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime/withPlainTime
+    fn with_plain_time(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let date_time = Self::this_plain_date_time(this, context)?;
+
+        // 3. Let time be ? ToTimeRecordOrMidnight(plainTimeLike).
+        let time = match args.get_or_undefined(0) {
+            JsValue::Undefined => IsoTime::default(),
+            time => PlainTime::from_value(time, &JsValue::undefined(), context)?,
+        };
+
+        // 4. Let isoDateTime be CombineISODateAndTimeRecord(dateTime.[[ISODateTime]].[[ISODate]], time).
+        // 5. Return ? CreateTemporalDateTime(isoDateTime, dateTime.[[Calendar]]).
+        Ok(Self::new(
+            IsoDateTime::new(date_time.date_time.date, time),
+            date_time.calendar,
+        )
+        .create(None, context)?
+        .into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.withCalendar ( calendarLike )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.withcalendar
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime/withCalendar
+    fn with_calendar(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let date_time = Self::this_plain_date_time(this, context)?;
+
+        // 3. Let calendar be ? ToTemporalCalendarIdentifier(calendarLike).
+        let calendar = Calendar::from_value(args.get_or_undefined(0), context)?;
+
+        // 4. Return ! CreateTemporalDateTime(dateTime.[[ISODateTime]], calendar).
+        Ok(Self::new(date_time.date_time, calendar)
+            .create(None, context)?
+            .into())
+    }
+
+    /// Abstract operation `DifferenceTemporalPlainDateTime ( operation, dateTime, other, options )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalplaindatetime
+    fn difference(
+        this: &JsValue,
+        args: &[JsValue],
+        since: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let date_time = Self::this_plain_date_time(this, context)?;
+
+        // 1. If operation is since, let sign be -1. Otherwise, let sign be 1.
+        // 2. Set other to ? ToTemporalDateTime(other).
+        let other = Self::from_value(args.get_or_undefined(0), &JsValue::undefined(), context)?;
+
+        // 3. If CalendarEquals(dateTime.[[Calendar]], other.[[Calendar]]) is false, throw a RangeError exception.
+        if date_time.calendar != other.calendar {
+            return context.throw_range_error("the date-times must have the same calendar");
+        }
+
+        // 4. Let resolvedOptions be ? GetOptionsObject(options).
+        // 5. Let settings be ? GetDifferenceSettings(operation, resolvedOptions, datetime, « », nanosecond, day).
+        let settings = DifferenceSettings::from_options(
+            args.get_or_undefined(1),
+            since,
+            UnitGroup::DateTime,
+            TemporalUnit::Nanosecond,
+            TemporalUnit::Day,
+            context,
+        )?;
+
+        // 6. If CompareISODateTime(dateTime.[[ISODateTime]], other.[[ISODateTime]]) = 0, then
+        //     a. Return ! CreateTemporalDuration(0, 0, 0, 0, 0, 0, 0, 0, 0, 0).
+        if date_time.date_time == other.date_time {
+            return Ok(Duration::new([0.0; 10]).create(None, context)?.into());
+        }
+
+        // 7. Let internalDuration be ? DifferencePlainDateTimeWithRounding(dateTime.[[ISODateTime]], other.[[ISODateTime]], dateTime.[[Calendar]], settings.[[LargestUnit]], settings.[[RoundingIncrement]], settings.[[SmallestUnit]], settings.[[RoundingMode]]).
+        let duration = difference_plain_date_time_with_rounding(
+            date_time.date_time,
+            other.date_time,
+            date_time.calendar,
+            settings,
+            context,
+        )?;
+
+        // 8. Let result be ! TemporalDurationFromInternal(internalDuration, settings.[[LargestUnit]]).
+        let result = Duration::from_parts(duration.0, duration.1, settings.largest_unit);
+
+        // 9. If operation is since, set result to CreateNegatedTemporalDuration(result).
+        let result = if since { result.negated() } else { result };
+
+        // 10. Return result.
+        Ok(result.create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.until ( other [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.until
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime/until
+    fn until(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        // 3. Return ? DifferenceTemporalPlainDateTime(until, dateTime, other, options).
+        Self::difference(this, args, false, context)
+    }
+
+    /// `Temporal.PlainDateTime.prototype.since ( other [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.since
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime/since
+    fn since(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        // 3. Return ? DifferenceTemporalPlainDateTime(since, dateTime, other, options).
+        Self::difference(this, args, true, context)
+    }
+
+    /// `Temporal.PlainDateTime.prototype.round ( roundTo )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.round
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime/round
+    fn round(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let date_time = Self::this_plain_date_time(this, context)?;
+
+        // 3-5. Let roundTo be the options object.
+        let round_to = get_round_to_options(args.get_or_undefined(0), context)?;
+
+        // 6. NOTE: The following steps read options and perform independent validation in alphabetical order.
+        // 7. Let roundingIncrement be ? GetRoundingIncrementOption(roundTo).
+        let increment = get_rounding_increment(&round_to, context)?;
+
+        // 8. Let roundingMode be ? GetRoundingModeOption(roundTo, half-expand).
+        let rounding_mode =
+            RoundingMode::from_options(&round_to, RoundingMode::HalfExpand, context)?;
+
+        // 9. Let smallestUnit be ? GetTemporalUnitValuedOption(roundTo, "smallestUnit", time, required, « day »).
+        let smallest_unit =
+            match get_temporal_unit(&round_to, "smallestUnit", UnitGroup::DateTime, context)? {
+                Some(smallest_unit) if smallest_unit >= TemporalUnit::Day => smallest_unit,
+                Some(smallest_unit) => {
+                    return context.throw_range_error(format!(
+                        "invalid value `{}` for option `smallestUnit`",
+                        smallest_unit.singular()
+                    ))
+                }
+                None => return context.throw_range_error("smallestUnit is required"),
+            };
+
+        // 10. If smallestUnit is day, then
+        //     a. Let maximum be 1.
+        //     b. Let inclusive be true.
+        // 11. Else,
+        //     a. Let maximum be MaximumTemporalDurationRoundingIncrement(smallestUnit).
+        //     b. Assert: maximum is not unset.
+        //     c. Let inclusive be false.
+        // 12. Perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, inclusive).
+        match smallest_unit.maximum_rounding_increment() {
+            Some(maximum) => validate_rounding_increment(increment, maximum, false, context)?,
+            None => validate_rounding_increment(increment, 1, true, context)?,
+        }
+
+        // 13. If smallestUnit is nanosecond and roundingIncrement = 1, then
+        //     a. Return ! CreateTemporalDateTime(dateTime.[[ISODateTime]], dateTime.[[Calendar]]).
+        // 14. Let result be RoundISODateTime(dateTime.[[ISODateTime]], roundingIncrement, smallestUnit, roundingMode).
+        let unit_nanoseconds = smallest_unit
+            .nanoseconds()
+            .expect("the smallest unit must be a day or a unit of time");
+        let result = date_time
+            .date_time
+            .round(increment * unit_nanoseconds, rounding_mode);
+
+        // 15. Return ? CreateTemporalDateTime(result, dateTime.[[Calendar]]).
+        Ok(Self::new(result, date_time.calendar)
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.equals ( other )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.equals
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime/equals
+    fn equals(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let date_time = Self::this_plain_date_time(this, context)?;
+
+        // 3. Set other to ? ToTemporalDateTime(other).
+        let other = Self::from_value(args.get_or_undefined(0), &JsValue::undefined(), context)?;
+
+        // 4. If CompareISODateTime(dateTime.[[ISODateTime]], other.[[ISODateTime]]) ≠ 0, return false.
+        // 5. Return CalendarEquals(dateTime.[[Calendar]], other.[[Calendar]]).
+        Ok((date_time == other).into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.toPlainDate ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.toplaindate
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime/toPlainDate
+    #[allow(clippy::wrong_self_convention)]
+    fn to_plain_date(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let date_time = Self::this_plain_date_time(this, context)?;
+
+        // 3. Return ! CreateTemporalDate(dateTime.[[ISODateTime]].[[ISODate]], dateTime.[[Calendar]]).
+        Ok(PlainDate::new(date_time.date_time.date, date_time.calendar)
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.toPlainTime ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.toplaintime
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime/toPlainTime
+    #[allow(clippy::wrong_self_convention)]
+    fn to_plain_time(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let date_time = Self::this_plain_date_time(this, context)?;
+
+        // 3. Return ! CreateTemporalTime(dateTime.[[ISODateTime]].[[Time]]).
+        Ok(PlainTime::new(date_time.date_time.time)
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.toZonedDateTime ( temporalTimeZoneLike [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.tozoneddatetime
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime/toZonedDateTime
+    #[allow(clippy::wrong_self_convention)]
+    fn to_zoned_date_time(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let date_time = Self::this_plain_date_time(this, context)?;
+
+        // 3. Let timeZone be ? ToTemporalTimeZoneIdentifier(temporalTimeZoneLike).
+        let time_zone = TimeZone::from_value(args.get_or_undefined(0), context)?;
+
+        // 4. Let resolvedOptions be ? GetOptionsObject(options).
+        // 5. Let disambiguation be ? GetTemporalDisambiguationOption(resolvedOptions).
+        let options = get_options_object(args.get_or_undefined(1), context)?;
+        let disambiguation = Disambiguation::from_options(&options, context)?;
+
+        // 6. Let epochNs be ? GetEpochNanosecondsFor(timeZone, dateTime.[[ISODateTime]], disambiguation).
+        let epoch_nanoseconds =
+            time_zone.epoch_nanoseconds_for(date_time.date_time, disambiguation, context)?;
+
+        // 7. Return ! CreateTemporalZonedDateTime(epochNs, timeZone, dateTime.[[Calendar]]).
+        Ok(
+            ZonedDateTime::new(epoch_nanoseconds, time_zone, date_time.calendar)
+                .create(None, context)?
+                .into(),
+        )
+    }
+
+    /// `Temporal.PlainDateTime.prototype.toString ( [ options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.tostring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime/toString
+    #[allow(clippy::wrong_self_convention)]
+    fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        let date_time = Self::this_plain_date_time(this, context)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(0), context)?;
+
+        // 4. NOTE: The following steps read options and perform independent validation in alphabetical order.
+        // 5. Let showCalendar be ? GetTemporalShowCalendarNameOption(resolvedOptions).
+        let show_calendar = ShowCalendar::from_options(&options, context)?;
+
+        // 6-10. Read the fractionalSecondDigits, roundingMode and smallestUnit options.
+        let (precision, rounding_mode) = SecondsStringPrecision::from_options(&options, context)?;
+
+        // 11. Let result be RoundISODateTime(dateTime.[[ISODateTime]], precision.[[Increment]], precision.[[Unit]], roundingMode).
+        let result = date_time
+            .date_time
+            .round(precision.nanoseconds(), rounding_mode);
+
+        // 12. If ISODateTimeWithinLimits(result) is false, throw a RangeError exception.
+        if !result.is_within_limits() {
+            return context.throw_range_error("the rounded date-time is out of range");
+        }
+
+        // 13. Return ISODateTimeToString(result, dateTime.[[Calendar]], precision.[[Precision]], showCalendar).
+        Ok(Self::new(result, date_time.calendar)
+            .to_iso_string(precision.precision, show_calendar)
+            .into())
+    }
+
+    /// Abstract operation `ISODateTimeToString ( isoDateTime, calendar, precision, showCalendar )`
+    fn to_iso_string(self, precision: Precision, show_calendar: ShowCalendar) -> String {
+        format!(
+            "{}{}",
+            self.date_time.to_iso_string(precision),
+            self.calendar.annotation(show_calendar)
+        )
+    }
+
+    /// `Temporal.PlainDateTime.prototype.toJSON ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.tojson
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime/toJSON
+    #[allow(clippy::wrong_self_convention)]
+    fn to_json(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        // 3. Return ISODateTimeToString(dateTime.[[ISODateTime]], dateTime.[[Calendar]], auto, auto).
+        Ok(Self::this_plain_date_time(this, context)?
+            .to_iso_string(Precision::Auto, ShowCalendar::Auto)
+            .into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.toLocaleString ( [ locales [ , options ] ] )`
+    ///
+    /// The plain date-times are not supported by `Intl.DateTimeFormat` yet, so this returns the
+    /// same string as `toJSON`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime/toLocaleString
+    #[allow(clippy::wrong_self_convention)]
+    fn to_locale_string(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
+        // 3. Return ISODateTimeToString(dateTime.[[ISODateTime]], dateTime.[[Calendar]], auto, auto).
+        Ok(Self::this_plain_date_time(this, context)?
+            .to_iso_string(Precision::Auto, ShowCalendar::Auto)
+            .into())
+    }
+
+    /// `Temporal.PlainDateTime.prototype.valueOf ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.valueof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime/valueOf
+    fn value_of(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Throw a TypeError exception.
+        context.throw_type_error("a Temporal.PlainDateTime can't be converted to a primitive value")
+    }
+}
+
+/// Abstract operation `InterpretTemporalDateTimeFields ( calendar, fields, overflow )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-interprettemporaldatetimefields
+pub(crate) fn interpret_fields(
+    calendar: Calendar,
+    fields: &Fields,
+    overflow: Overflow,
+    context: &mut Context,
+) -> JsResult<IsoDateTime> {
+    // 1. Let isoDate be ? CalendarDateFromFields(calendar, fields, overflow).
+    let date = calendar.date_from_fields(fields, overflow, context)?;
+
+    // 2. Let time be ? RegulateTime(fields.[[Hour]], fields.[[Minute]], fields.[[Second]], fields.[[Millisecond]], fields.[[Microsecond]], fields.[[Nanosecond]], overflow).
+    let time = match IsoTime::regulate(fields.time(), overflow) {
+        Some(time) => time,
+        None => return context.throw_range_error("the time is invalid"),
+    };
+
+    // 3. Return CombineISODateAndTimeRecord(isoDate, time).
+    Ok(IsoDateTime::new(date, time))
+}
@@ -0,0 +1,269 @@
+use crate::{forward, Context};
+
+#[test]
+fn constructor() {
+    let mut context = Context::default();
+    for (code, expected) in [
+        (
+            "new Temporal.PlainDateTime(2020, 1, 2, 3, 4, 5, 6, 7, 8).toString()",
+            "\"2020-01-02T03:04:05.006007008\"",
+        ),
+        (
+            "new Temporal.PlainDateTime(2020, 1, 2).toString()",
+            "\"2020-01-02T00:00:00\"",
+        ),
+        (
+            "new Temporal.PlainDateTime(-271821, 4, 19, 0, 0, 0, 0, 0, 1).toString()",
+            "\"-271821-04-19T00:00:00.000000001\"",
+        ),
+        (
+            "new Temporal.PlainDateTime(275760, 9, 13, 23, 59).toString()",
+            "\"+275760-09-13T23:59:00\"",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+
+    for (code, expected) in [
+        ("new Temporal.PlainDateTime(-271821, 4, 19)", "RangeError"),
+        ("new Temporal.PlainDateTime(2020, 1, 1, 24)", "RangeError"),
+        ("new Temporal.PlainDateTime(2020, 13, 1)", "RangeError"),
+        (
+            "new Temporal.PlainDateTime(2020, 1, 1, 0, 0, 0, 0, 0, 0, 'coptic')",
+            "RangeError",
+        ),
+        ("Temporal.PlainDateTime(2020, 1, 1)", "TypeError"),
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {code} }} catch (e) {{ e.name }}")
+            ),
+            format!("\"{expected}\"")
+        );
+    }
+}
+
+#[test]
+fn getters() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        "var dt = new Temporal.PlainDateTime(2020, 3, 1, 12, 30, 45, 123, 456, 789);",
+    );
+    for (code, expected) in [
+        ("dt.calendarId", "\"iso8601\""),
+        ("dt.year", "2020"),
+        ("dt.month", "3"),
+        ("dt.dayOfWeek", "7"),
+        ("dt.inLeapYear", "true"),
+        ("dt.hour", "12"),
+        ("dt.second", "45"),
+        ("dt.nanosecond", "789"),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+}
+
+#[test]
+fn from() {
+    let mut context = Context::default();
+    for (code, expected) in [
+        (
+            "Temporal.PlainDateTime.from('2020-01-01T12:30+01:00[Europe/Paris]').toString()",
+            "2020-01-01T12:30:00",
+        ),
+        (
+            "Temporal.PlainDateTime.from('2020-01-01').toString()",
+            "2020-01-01T00:00:00",
+        ),
+        (
+            "Temporal.PlainDateTime.from({ year: 2020, month: 2, day: 30, hour: 25 }).toString()",
+            "2020-02-29T23:00:00",
+        ),
+        (
+            "Temporal.PlainDateTime.from(Temporal.PlainDate.from('2020-01-01')).toString()",
+            "2020-01-01T00:00:00",
+        ),
+        (
+            "Temporal.PlainDateTime.from('2020-01-01T12:00').withCalendar('gregory').toString()",
+            "2020-01-01T12:00:00[u-ca=gregory]",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), format!("\"{expected}\""));
+    }
+
+    for (code, expected) in [
+        (
+            "Temporal.PlainDateTime.from({ year: 2020, month: 1, day: 1, hour: 25 }, { overflow: 'reject' })",
+            "RangeError",
+        ),
+        (
+            "Temporal.PlainDateTime.from('2020-01-01T00:00Z')",
+            "RangeError",
+        ),
+        ("Temporal.PlainDateTime.from({ hour: 1 })", "TypeError"),
+        ("Temporal.PlainDateTime.from(1)", "TypeError"),
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {code} }} catch (e) {{ e.name }}")
+            ),
+            format!("\"{expected}\"")
+        );
+    }
+}
+
+#[test]
+fn arithmetic() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        "var dt = Temporal.PlainDateTime.from('2020-01-31T23:30');",
+    );
+    for (code, expected) in [
+        ("dt.add({ hours: 1 }).toString()", "2020-02-01T00:30:00"),
+        (
+            "dt.add({ months: 1, minutes: 45 }).toString()",
+            "2020-03-01T00:15:00",
+        ),
+        (
+            "dt.subtract({ days: 1, hours: 24 }).toString()",
+            "2020-01-29T23:30:00",
+        ),
+        ("dt.with({ day: 1, hour: 8 }).toString()", "2020-01-01T08:30:00"),
+        ("dt.withPlainTime('12:00').toString()", "2020-01-31T12:00:00"),
+        ("dt.withPlainTime().toString()", "2020-01-31T00:00:00"),
+        ("dt.until('2020-02-02T00:00').toString()", "P1DT30M"),
+        (
+            "dt.until('2020-02-02T00:00', { largestUnit: 'hour' }).toString()",
+            "PT24H30M",
+        ),
+        (
+            "dt.since('2020-02-02T00:00', { largestUnit: 'hour' }).toString()",
+            "-PT24H30M",
+        ),
+        (
+            "dt.until('2020-03-01T00:00', { largestUnit: 'month' }).toString()",
+            "P29DT30M",
+        ),
+        (
+            "dt.until('2020-02-02T00:00', { smallestUnit: 'day', roundingMode: 'halfExpand' }).toString()",
+            "P1D",
+        ),
+        (
+            "dt.until('2020-02-02T12:00', { smallestUnit: 'day', roundingMode: 'halfExpand' }).toString()",
+            "P2D",
+        ),
+        ("dt.toPlainDate().toString()", "2020-01-31"),
+        ("dt.toPlainTime().toString()", "23:30:00"),
+        (
+            "Temporal.PlainDateTime.from('+275760-09-13T00:00').add({ hours: 23 }).toString()",
+            "+275760-09-13T23:00:00",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), format!("\"{expected}\""));
+    }
+
+    for (code, expected) in [
+        (
+            "Temporal.PlainDateTime.compare(dt, '2020-01-31T23:30:00.000000001')",
+            "-1",
+        ),
+        ("dt.equals('2020-01-31T23:30')", "true"),
+        (
+            "try { Temporal.PlainDateTime.from('+275760-09-13T00:00').add({ days: 1 }) } catch (e) { e.name }",
+            "\"RangeError\"",
+        ),
+        (
+            "try { dt.with({ hour: 1, calendar: 'iso8601' }) } catch (e) { e.name }",
+            "\"TypeError\"",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+}
+
+#[test]
+fn round() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        "var dt = Temporal.PlainDateTime.from('2020-01-31T23:30');",
+    );
+    for (code, expected) in [
+        (
+            "dt.round({ smallestUnit: 'hour' }).toString()",
+            "2020-02-01T00:00:00",
+        ),
+        ("dt.round('day').toString()", "2020-02-01T00:00:00"),
+        (
+            "dt.round({ smallestUnit: 'hour', roundingMode: 'floor' }).toString()",
+            "2020-01-31T23:00:00",
+        ),
+        (
+            "dt.round({ smallestUnit: 'minute', roundingIncrement: 45 }).toString()",
+            "2020-01-31T23:15:00",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), format!("\"{expected}\""));
+    }
+
+    for (code, expected) in [
+        ("dt.round()", "TypeError"),
+        ("dt.round('month')", "RangeError"),
+        (
+            "dt.round({ smallestUnit: 'day', roundingIncrement: 2 })",
+            "RangeError",
+        ),
+        (
+            "dt.round({ smallestUnit: 'minute', roundingIncrement: 60 })",
+            "RangeError",
+        ),
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {code} }} catch (e) {{ e.name }}")
+            ),
+            format!("\"{expected}\"")
+        );
+    }
+}
+
+#[test]
+fn to_string() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        "var dt = Temporal.PlainDateTime.from('2020-01-31T23:59:59.999');",
+    );
+    for (code, expected) in [
+        ("dt.toString()", "\"2020-01-31T23:59:59.999\""),
+        ("dt.toJSON()", "\"2020-01-31T23:59:59.999\""),
+        (
+            "dt.toString({ smallestUnit: 'second', roundingMode: 'halfExpand' })",
+            "\"2020-02-01T00:00:00\"",
+        ),
+        (
+            "dt.toString({ fractionalSecondDigits: 0 })",
+            "\"2020-01-31T23:59:59\"",
+        ),
+        (
+            "dt.toString({ smallestUnit: 'minute', calendarName: 'always' })",
+            "\"2020-01-31T23:59[u-ca=iso8601]\"",
+        ),
+        (
+            "try { dt.toString({ smallestUnit: 'hour' }) } catch (e) { e.name }",
+            "\"RangeError\"",
+        ),
+        (
+            "Object.prototype.toString.call(dt)",
+            "\"[object Temporal.PlainDateTime]\"",
+        ),
+        ("try { dt.valueOf() } catch (e) { e.name }", "\"TypeError\""),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+}
@@ -0,0 +1,815 @@
+//! This module implements the global `Temporal.PlainTime` object.
+//!
+//! A `Temporal.PlainTime` is a wall-clock time, with a precision of nanoseconds, which is not
+//! associated with a date or a time zone.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-plaintime-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainTime
+
+#[cfg(test)]
+mod tests;
+
+use super::{
+    calendar::Fields, format_time, get_round_to_options, get_rounding_increment, get_temporal_unit,
+    round_to_increment, to_integer_with_truncation, to_partial_temporal_object,
+    validate_rounding_increment, DifferenceSettings, Duration, Overflow, Precision, RoundingMode,
+    SecondsStringPrecision, TemporalUnit, UnitGroup, NS_PER_DAY,
+};
+use crate::{
+    builtins::{
+        intl::get_options_object,
+        iso8601::{parse_time_string, ParsedTime},
+        BuiltIn, JsArgs,
+    },
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use std::cmp::Ordering;
+use tap::{Conv, Pipe};
+
+/// An ISO time record, a valid wall-clock time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct IsoTime {
+    pub(crate) hour: u8,
+    pub(crate) minute: u8,
+    pub(crate) second: u8,
+    pub(crate) millisecond: u16,
+    pub(crate) microsecond: u16,
+    pub(crate) nanosecond: u16,
+}
+
+impl IsoTime {
+    /// Abstract operation `RegulateTime ( hour, minute, second, millisecond, microsecond, nanosecond, overflow )`
+    ///
+    /// Returns `None` if the time is invalid and `overflow` is `Reject`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-regulatetime
+    pub(crate) fn regulate(values: [f64; 6], overflow: Overflow) -> Option<Self> {
+        const MAXIMUMS: [f64; 6] = [23.0, 59.0, 59.0, 999.0, 999.0, 999.0];
+
+        let values = match overflow {
+            // 1. If overflow is constrain, then
+            //     a. Set hour to the result of clamping hour between 0 and 23.
+            //     ...
+            Overflow::Constrain => {
+                let mut values = values;
+                for (value, maximum) in values.iter_mut().zip(MAXIMUMS) {
+                    *value = value.clamp(0.0, maximum);
+                }
+                values
+            }
+            // 2. Else,
+            //     a. Assert: overflow is reject.
+            //     b. If IsValidTime(hour, minute, second, millisecond, microsecond, nanosecond) is false, throw a RangeError exception.
+            Overflow::Reject => {
+                if values
+                    .iter()
+                    .zip(MAXIMUMS)
+                    .any(|(value, maximum)| !(0.0..=maximum).contains(value))
+                {
+                    return None;
+                }
+                values
+            }
+        };
+
+        // 3. Return CreateTimeRecord(hour, minute, second, millisecond, microsecond, nanosecond).
+        Some(Self {
+            hour: values[0] as u8,
+            minute: values[1] as u8,
+            second: values[2] as u8,
+            millisecond: values[3] as u16,
+            microsecond: values[4] as u16,
+            nanosecond: values[5] as u16,
+        })
+    }
+
+    /// Creates a time record from a parsed time.
+    pub(crate) fn from_parsed(time: ParsedTime) -> Self {
+        let subsecond = time.subsecond_nanoseconds;
+        Self {
+            hour: time.hour,
+            minute: time.minute,
+            second: time.second,
+            millisecond: (subsecond / 1_000_000) as u16,
+            microsecond: (subsecond / 1000 % 1000) as u16,
+            nanosecond: (subsecond % 1000) as u16,
+        }
+    }
+
+    /// Gets the number of nanoseconds since midnight.
+    pub(crate) fn nanoseconds(self) -> i64 {
+        ((i64::from(self.hour) * 60 + i64::from(self.minute)) * 60 + i64::from(self.second))
+            * 1_000_000_000
+            + i64::from(self.subsecond_nanoseconds())
+    }
+
+    /// Gets the fraction of the second, in nanoseconds.
+    pub(crate) fn subsecond_nanoseconds(self) -> u32 {
+        u32::from(self.millisecond) * 1_000_000
+            + u32::from(self.microsecond) * 1000
+            + u32::from(self.nanosecond)
+    }
+
+    /// Abstract operation `BalanceTime ( hour, minute, second, millisecond, microsecond, nanosecond )`
+    ///
+    /// Returns the number of days of `nanoseconds`, and the time of the remaining nanoseconds.
+    pub(crate) fn from_nanoseconds(nanoseconds: i128) -> (i64, Self) {
+        let days = nanoseconds.div_euclid(NS_PER_DAY) as i64;
+        let nanoseconds = nanoseconds.rem_euclid(NS_PER_DAY) as i64;
+        let seconds = nanoseconds / 1_000_000_000;
+        let subsecond = nanoseconds % 1_000_000_000;
+        let time = Self {
+            hour: (seconds / 3600) as u8,
+            minute: (seconds / 60 % 60) as u8,
+            second: (seconds % 60) as u8,
+            millisecond: (subsecond / 1_000_000) as u16,
+            microsecond: (subsecond / 1000 % 1000) as u16,
+            nanosecond: (subsecond % 1000) as u16,
+        };
+        (days, time)
+    }
+
+    /// Abstract operation `AddTime ( time, timeDuration )`
+    ///
+    /// Returns the number of days after the time, and the resulting time.
+    pub(crate) fn add(self, nanoseconds: i128) -> (i64, Self) {
+        Self::from_nanoseconds(i128::from(self.nanoseconds()) + nanoseconds)
+    }
+
+    /// Abstract operation `RoundTime ( time, increment, unit, roundingMode )`
+    ///
+    /// `increment` is the length of the rounding increment in nanoseconds. Returns the number
+    /// of days after the time, and the rounded time.
+    pub(crate) fn round(self, increment: i128, rounding_mode: RoundingMode) -> (i64, Self) {
+        Self::from_nanoseconds(round_to_increment(
+            i128::from(self.nanoseconds()),
+            increment,
+            rounding_mode,
+        ))
+    }
+
+    /// Abstract operation `TimeRecordToString ( time, precision )`
+    pub(crate) fn to_iso_string(self, precision: Precision) -> String {
+        format_time(
+            self.hour,
+            self.minute,
+            self.second,
+            self.subsecond_nanoseconds(),
+            precision,
+        )
+    }
+}
+
+/// The getters of the time fields of `Temporal.PlainTime` and `Temporal.PlainDateTime`.
+pub(crate) const TIME_GETTERS: [(&str, fn(IsoTime) -> JsValue); 6] = [
+    ("hour", |time| time.hour.into()),
+    ("minute", |time| time.minute.into()),
+    ("second", |time| time.second.into()),
+    ("millisecond", |time| time.millisecond.into()),
+    ("microsecond", |time| time.microsecond.into()),
+    ("nanosecond", |time| time.nanosecond.into()),
+];
+
+/// The internal slots of a `Temporal.PlainTime` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Trace, Finalize)]
+pub struct PlainTime {
+    /// `[[Time]]`
+    #[unsafe_ignore_trace]
+    time: IsoTime,
+}
+
+impl BuiltIn for PlainTime {
+    const NAME: &'static str = "PlainTime";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let getters = TIME_GETTERS.map(|(name, getter)| {
+            let function = FunctionBuilder::closure(context, move |this, _, context| {
+                Ok(getter(Self::this_time(this, context)?))
+            })
+            .name(format!("get {name}"))
+            .length(0)
+            .constructor(false)
+            .build();
+            (name, function)
+        });
+
+        let mut constructor = ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().plain_time().clone(),
+        );
+        constructor
+            .name(Self::NAME)
+            .length(Self::LENGTH)
+            .static_method(Self::from, "from", 1)
+            .static_method(Self::compare, "compare", 2);
+        for (name, getter) in getters {
+            constructor.accessor(name, Some(getter), None, Attribute::CONFIGURABLE);
+        }
+        constructor
+            .method(Self::add, "add", 1)
+            .method(Self::subtract, "subtract", 1)
+            .method(Self::with, "with", 1)
+            .method(Self::until, "until", 1)
+            .method(Self::since, "since", 1)
+            .method(Self::round, "round", 1)
+            .method(Self::equals, "equals", 1)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_json, "toJSON", 0)
+            .method(Self::to_locale_string, "toLocaleString", 0)
+            .method(Self::value_of, "valueOf", 0)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                "Temporal.PlainTime",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build()
+            .conv::<JsValue>()
+            .pipe(Some)
+    }
+}
+
+impl PlainTime {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 0;
+
+    /// Creates a `PlainTime` from its time.
+    pub(crate) fn new(time: IsoTime) -> Self {
+        Self { time }
+    }
+
+    /// Gets the time of the `PlainTime`.
+    pub(crate) fn time(self) -> IsoTime {
+        self.time
+    }
+
+    /// Abstract operation `CreateTemporalTime ( time [ , newTarget ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-createtemporaltime
+    pub(crate) fn create(
+        self,
+        new_target: Option<&JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. If newTarget is not present, set newTarget to %Temporal.PlainTime%.
+        // 2. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.PlainTime.prototype%", « [[InitializedTemporalTime]], [[Time]] »).
+        let prototype = match new_target {
+            Some(new_target) => get_prototype_from_constructor(
+                new_target,
+                StandardConstructors::plain_time,
+                context,
+            )?,
+            None => context.intrinsics().constructors().plain_time().prototype(),
+        };
+
+        // 3. Set object.[[Time]] to time.
+        // 4. Return object.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::plain_time(self),
+        ))
+    }
+
+    /// Abstract operation `ToTemporalTime ( item [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporaltime
+    pub(crate) fn from_value(
+        item: &JsValue,
+        options: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<IsoTime> {
+        match item {
+            // 2. If item is an Object, then
+            JsValue::Object(object) => {
+                // a. If item has an [[InitializedTemporalTime]] internal slot, then
+                //     i. Let resolvedOptions be ? GetOptionsObject(options).
+                //     ii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+                //     iii. Return ! CreateTemporalTime(item.[[Time]]).
+                // b. If item has an [[InitializedTemporalZonedDateTime]] internal slot, then
+                //     i. Let isoDateTime be GetISODateTimeFor(item.[[TimeZone]], item.[[EpochNanoseconds]]).
+                //     ii. Let resolvedOptions be ? GetOptionsObject(options).
+                //     iii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+                //     iv. Return ! CreateTemporalTime(isoDateTime.[[Time]]).
+                // c. If item has an [[InitializedTemporalDateTime]] internal slot, then
+                //     i. Let resolvedOptions be ? GetOptionsObject(options).
+                //     ii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+                //     iii. Return ! CreateTemporalTime(item.[[ISODateTime]].[[Time]]).
+                let time = {
+                    let object = object.borrow();
+                    object
+                        .as_plain_time()
+                        .map(|time| time.time)
+                        .or_else(|| {
+                            object
+                                .as_zoned_date_time()
+                                .map(|date_time| date_time.date_time(context).time)
+                        })
+                        .or_else(|| {
+                            object
+                                .as_plain_date_time()
+                                .map(|date_time| date_time.date_time().time)
+                        })
+                };
+                if let Some(time) = time {
+                    Overflow::from_options(options, context)?;
+                    return Ok(time);
+                }
+
+                // d. Let result be ? ToTemporalTimeRecord(item).
+                let fields = match Fields::read(object, None, true, context)? {
+                    Some(fields) => fields,
+                    None => {
+                        return context.throw_type_error("a time must have at least one time field")
+                    }
+                };
+
+                // e. Let resolvedOptions be ? GetOptionsObject(options).
+                // f. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+                let overflow = Overflow::from_options(options, context)?;
+
+                // g. Set result to ? RegulateTime(result.[[Hour]], ..., overflow).
+                IsoTime::regulate(fields.time(), overflow)
+                    .map_or_else(|| context.throw_range_error("the time is invalid"), Ok)
+            }
+            // 3. Else,
+            JsValue::String(string) => {
+                // b. Let parseResult be ? ParseTemporalTimeString(item).
+                let time = match parse_time_string(string) {
+                    Ok(time) => IsoTime::from_parsed(time),
+                    Err(error) => {
+                        return context
+                            .throw_range_error(format!("invalid time string `{string}`: {error}"))
+                    }
+                };
+
+                // c. Assert: IsValidTime(...) is true.
+                // e. Let resolvedOptions be ? GetOptionsObject(options).
+                // f. Perform ? GetTemporalOverflowOption(resolvedOptions).
+                Overflow::from_options(options, context)?;
+                Ok(time)
+            }
+            // a. If item is not a String, throw a TypeError exception.
+            _ => context.throw_type_error("a time must be a string or an object"),
+        }
+    }
+
+    /// Gets the time of the `this` `Temporal.PlainTime` object.
+    fn this_time(this: &JsValue, context: &mut Context) -> JsResult<IsoTime> {
+        this.as_object()
+            .and_then(|obj| obj.borrow().as_plain_time().map(|time| time.time))
+            .ok_or_else(|| context.construct_type_error("'this' is not a Temporal.PlainTime"))
+    }
+
+    /// `Temporal.PlainTime ( [ hour [ , minute [ , second [ , millisecond [ , microsecond [ , nanosecond ] ] ] ] ] ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainTime/PlainTime
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        if new_target.is_undefined() {
+            // a. Throw a TypeError exception.
+            return context.throw_type_error("Temporal.PlainTime constructor requires 'new'");
+        }
+
+        // 2. If hour is undefined, set hour to 0; else set hour to ? ToIntegerWithTruncation(hour).
+        // ...
+        // 7. If nanosecond is undefined, set nanosecond to 0; else set nanosecond to ? ToIntegerWithTruncation(nanosecond).
+        let mut values = [0.0; 6];
+        for (index, value) in values.iter_mut().enumerate() {
+            let arg = args.get_or_undefined(index);
+            if !arg.is_undefined() {
+                *value = to_integer_with_truncation(arg, context)?;
+            }
+        }
+
+        // 8. If IsValidTime(hour, minute, second, millisecond, microsecond, nanosecond) is false, throw a RangeError exception.
+        let time = match IsoTime::regulate(values, Overflow::Reject) {
+            Some(time) => time,
+            None => return context.throw_range_error("the time is invalid"),
+        };
+
+        // 9. Let time be CreateTimeRecord(hour, minute, second, millisecond, microsecond, nanosecond).
+        // 10. Return ? CreateTemporalTime(time, NewTarget).
+        Ok(Self::new(time).create(Some(new_target), context)?.into())
+    }
+
+    /// `Temporal.PlainTime.from ( item [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.from
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainTime/from
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? ToTemporalTime(item, options).
+        let time = Self::from_value(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+        Ok(Self::new(time).create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainTime.compare ( one, two )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.compare
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainTime/compare
+    fn compare(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set one to ? ToTemporalTime(one).
+        let one = Self::from_value(args.get_or_undefined(0), &JsValue::undefined(), context)?;
+
+        // 2. Set two to ? ToTemporalTime(two).
+        let two = Self::from_value(args.get_or_undefined(1), &JsValue::undefined(), context)?;
+
+        // 3. Return 𝔽(CompareTimeRecord(one.[[Time]], two.[[Time]])).
+        Ok(ordering_to_value(one.cmp(&two)))
+    }
+
+    /// Abstract operation `AddDurationToTime ( operation, temporalTime, temporalDurationLike )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-adddurationtotime
+    fn add_duration(
+        this: &JsValue,
+        duration_like: &JsValue,
+        subtract: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let time = Self::this_time(this, context)?;
+
+        // 1. Let duration be ? ToTemporalDuration(temporalDurationLike).
+        // 2. If operation is subtract, set duration to CreateNegatedTemporalDuration(duration).
+        let mut duration = Duration::from_value(duration_like, context)?;
+        if subtract {
+            duration = duration.negated();
+        }
+
+        // 3. Let internalDuration be ToInternalDurationRecord(duration).
+        // 4. Let result be AddTime(temporalTime.[[Time]], internalDuration.[[Time]]).
+        let (_, time) = time.add(duration.time_nanoseconds());
+
+        // 5. Return ! CreateTemporalTime(result).
+        Ok(Self::new(time).create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainTime.prototype.add ( temporalDurationLike )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.add
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainTime/add
+    fn add(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalTime be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        // 3. Return ? AddDurationToTime(add, temporalTime, temporalDurationLike).
+        Self::add_duration(this, args.get_or_undefined(0), false, context)
+    }
+
+    /// `Temporal.PlainTime.prototype.subtract ( temporalDurationLike )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.subtract
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainTime/subtract
+    fn subtract(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalTime be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        // 3. Return ? AddDurationToTime(subtract, temporalTime, temporalDurationLike).
+        Self::add_duration(this, args.get_or_undefined(0), true, context)
+    }
+
+    /// `Temporal.PlainTime.prototype.with ( temporalTimeLike [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.with
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainTime/with
+    fn with(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalTime be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        let time = Self::this_time(this, context)?;
+
+        // 3. If ? IsPartialTemporalObject(temporalTimeLike) is false, throw a TypeError exception.
+        let time_like = to_partial_temporal_object(args.get_or_undefined(0), context)?;
+
+        // 4. Let partialTime be ? ToTemporalTimeRecord(temporalTimeLike, partial).
+        let partial = match Fields::read(&time_like, None, true, context)? {
+            Some(partial) => partial,
+            None => return context.throw_type_error("at least one time field is required"),
+        };
+
+        // 5-16. If partialTime.[[Hour]] is not undefined, let hour be partialTime.[[Hour]];
+        //       else let hour be temporalTime.[[Time]].[[Hour]].
+        //       ...
+        let mut fields = time_fields(time);
+        fields.override_with(&partial);
+
+        // 17. Let resolvedOptions be ? GetOptionsObject(options).
+        // 18. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow = Overflow::from_options(args.get_or_undefined(1), context)?;
+
+        // 19. Let result be ? RegulateTime(hour, minute, second, millisecond, microsecond, nanosecond, overflow).
+        let time = match IsoTime::regulate(fields.time(), overflow) {
+            Some(time) => time,
+            None => return context.throw_range_error("the time is invalid"),
+        };
+
+        // 20. Return ! CreateTemporalTime(result).
+        Ok(Self::new(time).create(None, context)?.into())
+    }
+
+    /// Abstract operation `DifferenceTemporalPlainTime ( operation, temporalTime, other, options )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalplaintime
+    fn difference(
+        this: &JsValue,
+        args: &[JsValue],
+        since: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let time = Self::this_time(this, context)?;
+
+        // 1. If operation is since, let sign be -1. Otherwise, let sign be 1.
+        // 2. Set other to ? ToTemporalTime(other).
+        let other = Self::from_value(args.get_or_undefined(0), &JsValue::undefined(), context)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        // 4. Let settings be ? GetDifferenceSettings(operation, resolvedOptions, time, « », nanosecond, hour).
+        let settings = DifferenceSettings::from_options(
+            args.get_or_undefined(1),
+            since,
+            UnitGroup::Time,
+            TemporalUnit::Nanosecond,
+            TemporalUnit::Hour,
+            context,
+        )?;
+
+        // 5. Let timeDuration be DifferenceTime(temporalTime.[[Time]], other.[[Time]]).
+        // 6. Set timeDuration to ! RoundTimeDuration(timeDuration, settings.[[RoundingIncrement]], settings.[[SmallestUnit]], settings.[[RoundingMode]]).
+        let unit_nanoseconds = settings
+            .smallest_unit
+            .nanoseconds()
+            .expect("the smallest unit must be a unit of time");
+        let nanoseconds = round_to_increment(
+            i128::from(other.nanoseconds() - time.nanoseconds()),
+            settings.increment * unit_nanoseconds,
+            settings.rounding_mode,
+        );
+
+        // 7. Let duration be CombineDateAndTimeDuration(ZeroDateDuration(), timeDuration).
+        // 8. Let result be ! TemporalDurationFromInternal(duration, settings.[[LargestUnit]]).
+        let duration = Duration::from_parts([0; 4], nanoseconds, settings.largest_unit);
+
+        // 9. If operation is since, set result to CreateNegatedTemporalDuration(result).
+        let duration = if since { duration.negated() } else { duration };
+
+        // 10. Return result.
+        Ok(duration.create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainTime.prototype.until ( other [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.until
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainTime/until
+    fn until(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalTime be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        // 3. Return ? DifferenceTemporalPlainTime(until, temporalTime, other, options).
+        Self::difference(this, args, false, context)
+    }
+
+    /// `Temporal.PlainTime.prototype.since ( other [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.since
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainTime/since
+    fn since(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalTime be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        // 3. Return ? DifferenceTemporalPlainTime(since, temporalTime, other, options).
+        Self::difference(this, args, true, context)
+    }
+
+    /// `Temporal.PlainTime.prototype.round ( roundTo )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.round
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainTime/round
+    fn round(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalTime be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        let time = Self::this_time(this, context)?;
+
+        // 3-5. Let roundTo be the options object.
+        let round_to = get_round_to_options(args.get_or_undefined(0), context)?;
+
+        // 6. NOTE: The following steps read options and perform independent validation in alphabetical order.
+        // 7. Let roundingIncrement be ? GetRoundingIncrementOption(roundTo).
+        let increment = get_rounding_increment(&round_to, context)?;
+
+        // 8. Let roundingMode be ? GetRoundingModeOption(roundTo, half-expand).
+        let rounding_mode =
+            RoundingMode::from_options(&round_to, RoundingMode::HalfExpand, context)?;
+
+        // 9. Let smallestUnit be ? GetTemporalUnitValuedOption(roundTo, "smallestUnit", time, required).
+        let smallest_unit =
+            match get_temporal_unit(&round_to, "smallestUnit", UnitGroup::Time, context)? {
+                Some(smallest_unit) => smallest_unit,
+                None => return context.throw_range_error("smallestUnit is required"),
+            };
+
+        // 10. Let maximum be MaximumTemporalDurationRoundingIncrement(smallestUnit).
+        // 11. Assert: maximum is not unset.
+        // 12. Perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, false).
+        let maximum = smallest_unit
+            .maximum_rounding_increment()
+            .expect("the smallest unit must be a unit of time");
+        validate_rounding_increment(increment, maximum, false, context)?;
+
+        // 13. Let result be RoundTime(temporalTime.[[Time]], roundingIncrement, smallestUnit, roundingMode).
+        let unit_nanoseconds = smallest_unit
+            .nanoseconds()
+            .expect("the smallest unit must be a unit of time");
+        let (_, time) = time.round(increment * unit_nanoseconds, rounding_mode);
+
+        // 14. Return ! CreateTemporalTime(result).
+        Ok(Self::new(time).create(None, context)?.into())
+    }
+
+    /// `Temporal.PlainTime.prototype.equals ( other )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.equals
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainTime/equals
+    fn equals(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalTime be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        let time = Self::this_time(this, context)?;
+
+        // 3. Set other to ? ToTemporalTime(other).
+        let other = Self::from_value(args.get_or_undefined(0), &JsValue::undefined(), context)?;
+
+        // 4. If CompareTimeRecord(temporalTime.[[Time]], other.[[Time]]) = 0, return true.
+        // 5. Return false.
+        Ok((time == other).into())
+    }
+
+    /// `Temporal.PlainTime.prototype.toString ( [ options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.tostring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainTime/toString
+    #[allow(clippy::wrong_self_convention)]
+    fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalTime be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        let time = Self::this_time(this, context)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(0), context)?;
+
+        // 4-8. Read the fractionalSecondDigits, roundingMode and smallestUnit options.
+        let (precision, rounding_mode) = SecondsStringPrecision::from_options(&options, context)?;
+
+        // 9. Let roundResult be RoundTime(temporalTime.[[Time]], precision.[[Increment]], precision.[[Unit]], roundingMode).
+        let (_, time) = time.round(precision.nanoseconds(), rounding_mode);
+
+        // 10. Return TimeRecordToString(roundResult, precision.[[Precision]]).
+        Ok(time.to_iso_string(precision.precision).into())
+    }
+
+    /// `Temporal.PlainTime.prototype.toJSON ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.tojson
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainTime/toJSON
+    #[allow(clippy::wrong_self_convention)]
+    fn to_json(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalTime be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        // 3. Return TimeRecordToString(temporalTime.[[Time]], auto).
+        Ok(Self::this_time(this, context)?
+            .to_iso_string(Precision::Auto)
+            .into())
+    }
+
+    /// `Temporal.PlainTime.prototype.toLocaleString ( [ locales [ , options ] ] )`
+    ///
+    /// The plain times are not supported by `Intl.DateTimeFormat` yet, so this returns the same
+    /// string as `toJSON`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainTime/toLocaleString
+    #[allow(clippy::wrong_self_convention)]
+    fn to_locale_string(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let temporalTime be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
+        // 3. Return TimeRecordToString(temporalTime.[[Time]], auto).
+        Ok(Self::this_time(this, context)?
+            .to_iso_string(Precision::Auto)
+            .into())
+    }
+
+    /// `Temporal.PlainTime.prototype.valueOf ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.valueof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainTime/valueOf
+    fn value_of(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Throw a TypeError exception.
+        context.throw_type_error("a Temporal.PlainTime can't be converted to a primitive value")
+    }
+}
+
+/// Gets the time fields of a time as a `Fields` record.
+pub(crate) fn time_fields(time: IsoTime) -> Fields {
+    Fields {
+        hour: Some(time.hour.into()),
+        minute: Some(time.minute.into()),
+        second: Some(time.second.into()),
+        millisecond: Some(time.millisecond.into()),
+        microsecond: Some(time.microsecond.into()),
+        nanosecond: Some(time.nanosecond.into()),
+        ..Fields::default()
+    }
+}
+
+/// Converts the result of a comparison to `-1`, `0` or `1`.
+pub(crate) fn ordering_to_value(ordering: Ordering) -> JsValue {
+    match ordering {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    }
+    .into()
+}
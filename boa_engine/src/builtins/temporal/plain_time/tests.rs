@@ -0,0 +1,153 @@
+use crate::{forward, Context};
+
+#[test]
+fn constructor() {
+    let mut context = Context::default();
+    for (code, expected) in [
+        ("new Temporal.PlainTime().toString()", "\"00:00:00\""),
+        ("new Temporal.PlainTime(13, 37).toString()", "\"13:37:00\""),
+        (
+            "new Temporal.PlainTime(1, 2, 3, 4, 5, 6).toString()",
+            "\"01:02:03.004005006\"",
+        ),
+        ("new Temporal.PlainTime(1.9, 2.5).minute", "2"),
+        ("new Temporal.PlainTime(1, 2, 3, 4, 5, 6).microsecond", "5"),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+
+    for (code, expected) in [
+        ("new Temporal.PlainTime(24)", "RangeError"),
+        ("new Temporal.PlainTime(0, 0, 60)", "RangeError"),
+        ("new Temporal.PlainTime(Infinity)", "RangeError"),
+        ("Temporal.PlainTime()", "TypeError"),
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {code} }} catch (e) {{ e.name }}")
+            ),
+            format!("\"{expected}\"")
+        );
+    }
+}
+
+#[test]
+fn from() {
+    let mut context = Context::default();
+    for (code, expected) in [
+        (
+            "Temporal.PlainTime.from('12:34:56.5').toString()",
+            "12:34:56.5",
+        ),
+        ("Temporal.PlainTime.from('T1234').toString()", "12:34:00"),
+        (
+            "Temporal.PlainTime.from('2020-01-01T08:30+01:00[Europe/Paris]').toString()",
+            "08:30:00",
+        ),
+        (
+            "Temporal.PlainTime.from({ hour: 25, minute: 70 }).toString()",
+            "23:59:00",
+        ),
+        (
+            "Temporal.PlainTime.from(new Temporal.PlainTime(1, 2)).toString()",
+            "01:02:00",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), format!("\"{expected}\""));
+    }
+
+    for (code, expected) in [
+        (
+            "Temporal.PlainTime.from({ hour: 25 }, { overflow: 'reject' })",
+            "RangeError",
+        ),
+        ("Temporal.PlainTime.from('2020-01-01T08:30Z')", "RangeError"),
+        ("Temporal.PlainTime.from('2020-01-01')", "RangeError"),
+        ("Temporal.PlainTime.from('1214')", "RangeError"),
+        ("Temporal.PlainTime.from({})", "TypeError"),
+        ("Temporal.PlainTime.from(12)", "TypeError"),
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!("try {{ {code} }} catch (e) {{ e.name }}")
+            ),
+            format!("\"{expected}\"")
+        );
+    }
+}
+
+#[test]
+fn arithmetic() {
+    let mut context = Context::default();
+    forward(&mut context, "var time = Temporal.PlainTime.from('10:30');");
+    for (code, expected) in [
+        ("time.add({ hours: 15 }).toString()", "\"01:30:00\""),
+        ("time.subtract({ minutes: 45 }).toString()", "\"09:45:00\""),
+        ("time.with({ minute: 5 }).toString()", "\"10:05:00\""),
+        ("time.until('12:45:30').toString()", "\"PT2H15M30S\""),
+        (
+            "time.since('12:45:30', { largestUnit: 'minute' }).toString()",
+            "\"-PT135M30S\"",
+        ),
+        (
+            "time.until('12:45:30', { smallestUnit: 'hour' }).toString()",
+            "\"PT2H\"",
+        ),
+        ("time.round({ smallestUnit: 'hour' }).toString()", "\"11:00:00\""),
+        (
+            "time.round({ smallestUnit: 'minute', roundingIncrement: 20 }).toString()",
+            "\"10:40:00\"",
+        ),
+        ("Temporal.PlainTime.compare('10:30', time)", "0"),
+        ("Temporal.PlainTime.compare('09:00', time)", "-1"),
+        ("time.equals('10:30:00')", "true"),
+        ("time.hour", "10"),
+        ("time.minute", "30"),
+        ("try { time.with({}) } catch (e) { e.name }", "\"TypeError\""),
+        (
+            "try { time.with({ hour: 1, calendar: 'iso8601' }) } catch (e) { e.name }",
+            "\"TypeError\"",
+        ),
+        ("try { time.round('day') } catch (e) { e.name }", "\"RangeError\""),
+        (
+            "try { time.round({ smallestUnit: 'hour', roundingIncrement: 24 }) } catch (e) { e.name }",
+            "\"RangeError\"",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+}
+
+#[test]
+fn to_string() {
+    let mut context = Context::default();
+    forward(
+        &mut context,
+        "var time = Temporal.PlainTime.from('10:30:15.1234');",
+    );
+    for (code, expected) in [
+        ("time.toString()", "\"10:30:15.1234\""),
+        ("time.toJSON()", "\"10:30:15.1234\""),
+        ("time.toString({ smallestUnit: 'minute' })", "\"10:30\""),
+        (
+            "time.toString({ fractionalSecondDigits: 2 })",
+            "\"10:30:15.12\"",
+        ),
+        (
+            "time.toString({ smallestUnit: 'second', roundingMode: 'ceil' })",
+            "\"10:30:16\"",
+        ),
+        (
+            "Object.prototype.toString.call(time)",
+            "\"[object Temporal.PlainTime]\"",
+        ),
+        (
+            "try { time.valueOf() } catch (e) { e.name }",
+            "\"TypeError\"",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{forward, Context};
+
+#[test]
+fn temporal_object() {
+    let mut context = Context::default();
+    for (code, expected) in [
+        ("typeof Temporal", "\"object\""),
+        (
+            "Object.prototype.toString.call(Temporal)",
+            "\"[object Temporal]\"",
+        ),
+        (
+            "Object.prototype.toString.call(Temporal.Now)",
+            "\"[object Temporal.Now]\"",
+        ),
+        ("Temporal.Instant.name", "\"Instant\""),
+        ("Temporal.Duration.length", "0"),
+        ("Object.keys(Temporal).length", "0"),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+}
+
+#[test]
+fn now() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.Now.instant() instanceof Temporal.Instant"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "typeof Temporal.Now.timeZoneId()"),
+        "\"string\""
+    );
+}
+
+#[test]
+fn host_clock() {
+    let mut context = Context::default();
+    context.set_clock(|| 1_000_000_000_000_000_123);
+    assert_eq!(
+        forward(&mut context, "Temporal.Now.instant().toString()"),
+        "\"2001-09-09T01:46:40.000000123Z\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.Now.zonedDateTimeISO('+01:00').toString()"
+        ),
+        "\"2001-09-09T02:46:40.000000123+01:00[+01:00]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temporal.Now.plainDateISO('-02:00').toString()"
+        ),
+        "\"2001-09-08\""
+    );
+
+    // The time of the clock is clamped to the range of the valid instants.
+    context.set_clock(|| i128::MAX);
+    assert_eq!(
+        forward(&mut context, "Temporal.Now.instant().epochNanoseconds"),
+        "8640000000000000000000n"
+    );
+}
@@ -0,0 +1,568 @@
+//! The time zones of the Temporal API.
+//!
+//! A time zone is either a UTC offset with a precision of minutes, such as `+01:00`, or a named
+//! time zone, such as `Europe/Paris`, whose rules are given by the [`TimeZoneProvider`] of the
+//! context. The `UTC` time zone is always available.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-time-zones
+//!
+//! [`TimeZoneProvider`]: crate::context::TimeZoneProvider
+
+use super::{
+    format_offset_minutes, instant::is_valid_epoch_nanoseconds, plain_date::IsoDate,
+    plain_date_time::IsoDateTime, plain_time::IsoTime, round_to_increment, RoundingMode,
+    NS_PER_DAY,
+};
+use crate::{
+    builtins::{
+        intl::get_option,
+        iso8601::{parse_date_time, parse_utc_offset, UtcOffset},
+    },
+    object::JsObject,
+    Context, JsResult, JsValue,
+};
+
+/// The number of nanoseconds in a minute.
+pub(crate) const NS_PER_MINUTE: i64 = 60_000_000_000;
+
+/// A time zone of the Temporal API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum TimeZone {
+    /// A UTC offset time zone, with its offset in minutes.
+    Offset(i64),
+    /// A named time zone, with the identifier of the time zone database, or `UTC`.
+    Named(String),
+}
+
+impl TimeZone {
+    /// The `UTC` time zone.
+    pub(crate) fn utc() -> Self {
+        Self::Named("UTC".to_owned())
+    }
+
+    /// Abstract operations `ParseTimeZoneIdentifier ( identifier )` and
+    /// `GetAvailableNamedTimeZoneIdentifier ( timeZoneIdentifier )`
+    ///
+    /// Returns `None` if the identifier is neither an offset with a precision of minutes nor an
+    /// available named time zone.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-parsetimezoneidentifier
+    pub(crate) fn from_identifier(identifier: &str, context: &Context) -> Option<Self> {
+        if identifier.eq_ignore_ascii_case("UTC") {
+            return Some(Self::utc());
+        }
+        if matches!(identifier.as_bytes().first(), Some(b'+' | b'-')) {
+            let offset = parse_utc_offset(identifier).ok()?;
+            return (offset % NS_PER_MINUTE == 0).then(|| Self::Offset(offset / NS_PER_MINUTE));
+        }
+        context
+            .time_zone_provider()
+            .identifier(identifier)
+            .map(Self::Named)
+    }
+
+    /// Abstract operation `ToTemporalTimeZoneIdentifier ( temporalTimeZoneLike )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporaltimezoneidentifier
+    pub(crate) fn from_value(time_zone_like: &JsValue, context: &mut Context) -> JsResult<Self> {
+        let identifier = match time_zone_like {
+            // 1. If temporalTimeZoneLike is an Object, then
+            //     a. If temporalTimeZoneLike has an [[InitializedTemporalZonedDateTime]] internal slot, then
+            //         i. Return temporalTimeZoneLike.[[TimeZone]].
+            JsValue::Object(object) => {
+                let time_zone = object
+                    .borrow()
+                    .as_zoned_date_time()
+                    .map(|date_time| date_time.time_zone().clone());
+                return time_zone.map_or_else(
+                    || context.throw_type_error("a time zone must be a string"),
+                    Ok,
+                );
+            }
+            JsValue::String(identifier) => identifier,
+            // 2. If temporalTimeZoneLike is not a String, throw a TypeError exception.
+            _ => return context.throw_type_error("a time zone must be a string"),
+        };
+
+        // 3. Let parseResult be ? ParseTemporalTimeZoneString(temporalTimeZoneLike).
+        // 4. Let offsetMinutes be parseResult.[[OffsetMinutes]].
+        // 5. If offsetMinutes is not empty, return FormatOffsetTimeZoneIdentifier(offsetMinutes).
+        // 6. Let name be parseResult.[[Name]].
+        // 7. Let timeZoneIdentifierRecord be GetAvailableNamedTimeZoneIdentifier(name).
+        // 8. If timeZoneIdentifierRecord is empty, throw a RangeError exception.
+        let time_zone = Self::from_identifier(identifier, context).or_else(|| {
+            let parsed = parse_date_time(identifier).ok()?;
+            match (parsed.time_zone, parsed.offset) {
+                (Some(time_zone), _) => Self::from_identifier(&time_zone, context),
+                (None, Some(UtcOffset::Z)) => Some(Self::utc()),
+                (None, Some(UtcOffset::Offset(offset))) if offset % NS_PER_MINUTE == 0 => {
+                    Some(Self::Offset(offset / NS_PER_MINUTE))
+                }
+                _ => None,
+            }
+        });
+
+        // 9. Return timeZoneIdentifierRecord.[[PrimaryIdentifier]].
+        time_zone.map_or_else(
+            || context.throw_range_error(format!("unsupported time zone `{identifier}`")),
+            Ok,
+        )
+    }
+
+    /// Gets the identifier of the time zone.
+    pub(crate) fn identifier(&self) -> String {
+        match self {
+            Self::Offset(minutes) => format_offset_minutes(*minutes),
+            Self::Named(identifier) => identifier.clone(),
+        }
+    }
+
+    /// Abstract operation `GetOffsetNanosecondsFor ( timeZone, epochNs )`
+    pub(crate) fn offset_nanoseconds_for(&self, epoch_nanoseconds: i128, context: &Context) -> i64 {
+        match self {
+            Self::Offset(minutes) => minutes * NS_PER_MINUTE,
+            Self::Named(identifier) if identifier == "UTC" => 0,
+            Self::Named(identifier) => context
+                .time_zone_provider()
+                .offset_nanoseconds(identifier, epoch_nanoseconds),
+        }
+    }
+
+    /// Abstract operation `GetISODateTimeFor ( timeZone, epochNs )`
+    pub(crate) fn iso_date_time_for(
+        &self,
+        epoch_nanoseconds: i128,
+        context: &Context,
+    ) -> IsoDateTime {
+        let offset = self.offset_nanoseconds_for(epoch_nanoseconds, context);
+        IsoDateTime::from_epoch_nanoseconds(epoch_nanoseconds + i128::from(offset))
+    }
+
+    /// Abstract operation `GetPossibleEpochNanoseconds ( timeZone, isoDateTime )`
+    ///
+    /// Returns the exact times of a wall-clock time in the time zone, in ascending order.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-getpossibleepochnanoseconds
+    pub(crate) fn possible_epoch_nanoseconds(
+        &self,
+        date_time: IsoDateTime,
+        context: &mut Context,
+    ) -> JsResult<Vec<i128>> {
+        // 1-2. Perform ? CheckISODaysRange(isoDateTime.[[ISODate]]).
+        check_iso_days_range(date_time.date, context)?;
+
+        let local_nanoseconds = date_time.epoch_nanoseconds();
+        let possible = match self {
+            // 1. If timeZone is an offset time zone identifier, then
+            //     ...
+            //     d. Let epochNanoseconds be GetUTCEpochNanoseconds(balanced).
+            //     e. Let possibleEpochNanoseconds be « epochNanoseconds ».
+            Self::Offset(minutes) => vec![local_nanoseconds - i128::from(minutes * NS_PER_MINUTE)],
+            Self::Named(identifier) if identifier == "UTC" => vec![local_nanoseconds],
+            // 2. Else,
+            //     b. Let possibleEpochNanoseconds be GetNamedTimeZoneEpochNanoseconds(timeZone, isoDateTime).
+            Self::Named(identifier) => context
+                .time_zone_provider()
+                .possible_epoch_nanoseconds(identifier, local_nanoseconds),
+        };
+
+        // 3. For each value epochNanoseconds in possibleEpochNanoseconds, do
+        //     a. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+        if !possible.iter().copied().all(is_valid_epoch_nanoseconds) {
+            return context.throw_range_error("the date-time is out of range");
+        }
+
+        // 4. Return possibleEpochNanoseconds.
+        Ok(possible)
+    }
+
+    /// Abstract operation `GetEpochNanosecondsFor ( timeZone, isoDateTime, disambiguation )`
+    pub(crate) fn epoch_nanoseconds_for(
+        &self,
+        date_time: IsoDateTime,
+        disambiguation: Disambiguation,
+        context: &mut Context,
+    ) -> JsResult<i128> {
+        // 1. Let possibleEpochNs be ? GetPossibleEpochNanoseconds(timeZone, isoDateTime).
+        let possible = self.possible_epoch_nanoseconds(date_time, context)?;
+
+        // 2. Return ? DisambiguatePossibleEpochNanoseconds(possibleEpochNs, timeZone, isoDateTime, disambiguation).
+        self.disambiguate(&possible, date_time, disambiguation, context)
+    }
+
+    /// Abstract operation `DisambiguatePossibleEpochNanoseconds ( possibleEpochNs, timeZone, isoDateTime, disambiguation )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-disambiguatepossibleepochnanoseconds
+    fn disambiguate(
+        &self,
+        possible: &[i128],
+        date_time: IsoDateTime,
+        disambiguation: Disambiguation,
+        context: &mut Context,
+    ) -> JsResult<i128> {
+        match (possible, disambiguation) {
+            // 2. If n = 1, then
+            //     a. Return possibleEpochNs[0].
+            ([epoch_nanoseconds], _) => return Ok(*epoch_nanoseconds),
+            // 3. If n ≠ 0, then
+            //     a. If disambiguation is earlier or compatible, then
+            //         i. Return possibleEpochNs[0].
+            //     b. If disambiguation is later, then
+            //         i. Return possibleEpochNs[n - 1].
+            //     c. Assert: disambiguation is reject.
+            //     d. Throw a RangeError exception.
+            // 5. If disambiguation is reject, throw a RangeError exception.
+            (_, Disambiguation::Reject) => {
+                return context.throw_range_error("the date-time is ambiguous in its time zone")
+            }
+            ([epoch_nanoseconds, ..], Disambiguation::Earlier | Disambiguation::Compatible)
+            | ([.., epoch_nanoseconds], Disambiguation::Later) => return Ok(*epoch_nanoseconds),
+            // 4. Assert: n = 0.
+            ([], _) => {}
+        }
+
+        // 6. Let before be the latest possible ISO Date-Time Record for which CompareISODateTime(before, isoDateTime) = -1 and ! GetPossibleEpochNanoseconds(timeZone, before) is not empty.
+        // 7. Let after be the earliest possible ISO Date-Time Record for which CompareISODateTime(after, isoDateTime) = 1 and ! GetPossibleEpochNanoseconds(timeZone, after) is not empty.
+        // 8. Let beforePossible be ! GetPossibleEpochNanoseconds(timeZone, before).
+        // 9. Assert: beforePossible's length is 1.
+        // 10. Let afterPossible be ! GetPossibleEpochNanoseconds(timeZone, after).
+        // 11. Assert: afterPossible's length is 1.
+        // 12. Let offsetBefore be GetOffsetNanosecondsFor(timeZone, beforePossible[0]).
+        // 13. Let offsetAfter be GetOffsetNanosecondsFor(timeZone, afterPossible[0]).
+        //
+        // The offsets before and after the gap are the ones a day before and after it.
+        let epoch_nanoseconds = date_time.epoch_nanoseconds();
+        let day_before = epoch_nanoseconds - NS_PER_DAY;
+        let day_after = epoch_nanoseconds + NS_PER_DAY;
+        if !is_valid_epoch_nanoseconds(day_before) || !is_valid_epoch_nanoseconds(day_after) {
+            return context.throw_range_error("the date-time is out of range");
+        }
+        let offset_before = self.offset_nanoseconds_for(day_before, context);
+        let offset_after = self.offset_nanoseconds_for(day_after, context);
+
+        // 14. Let nanoseconds be offsetAfter - offsetBefore.
+        // 15. Assert: abs(nanoseconds) ≤ nsPerDay.
+        let nanoseconds = i128::from(offset_after - offset_before);
+
+        // 16. If disambiguation is earlier, then
+        //     a. Let timeDuration be TimeDurationFromComponents(0, 0, 0, 0, 0, -nanoseconds).
+        //     b. Let earlierTime be AddTime(isoDateTime.[[Time]], timeDuration).
+        //     c. Let earlierDate be BalanceISODate(isoDateTime.[[ISODate]].[[Year]], isoDateTime.[[ISODate]].[[Month]], isoDateTime.[[ISODate]].[[Day]] + earlierTime.[[Days]]).
+        //     d. Let earlierDateTime be CombineISODateAndTimeRecord(earlierDate, earlierTime).
+        //     e. Set possibleEpochNs to ? GetPossibleEpochNanoseconds(timeZone, earlierDateTime).
+        //     f. Assert: possibleEpochNs is not empty.
+        //     g. Return possibleEpochNs[0].
+        // 17. Assert: disambiguation is compatible or later.
+        // 18. Let timeDuration be TimeDurationFromComponents(0, 0, 0, 0, 0, nanoseconds).
+        // 19. Let laterTime be AddTime(isoDateTime.[[Time]], timeDuration).
+        // 20. Let laterDate be BalanceISODate(isoDateTime.[[ISODate]].[[Year]], isoDateTime.[[ISODate]].[[Month]], isoDateTime.[[ISODate]].[[Day]] + laterTime.[[Days]]).
+        // 21. Let laterDateTime be CombineISODateAndTimeRecord(laterDate, laterTime).
+        // 22. Set possibleEpochNs to ? GetPossibleEpochNanoseconds(timeZone, laterDateTime).
+        // 23. Let n be possibleEpochNs's length.
+        // 24. Assert: n ≠ 0.
+        // 25. Return possibleEpochNs[n - 1].
+        let earlier = disambiguation == Disambiguation::Earlier;
+        let shifted = IsoDateTime::from_epoch_nanoseconds(if earlier {
+            epoch_nanoseconds - nanoseconds
+        } else {
+            epoch_nanoseconds + nanoseconds
+        });
+        let possible = self.possible_epoch_nanoseconds(shifted, context)?;
+        let epoch_nanoseconds = if earlier {
+            possible.first()
+        } else {
+            possible.last()
+        };
+        epoch_nanoseconds.copied().map_or_else(
+            || context.throw_range_error("the date-time is skipped by its time zone"),
+            Ok,
+        )
+    }
+
+    /// Abstract operation `GetStartOfDay ( timeZone, isoDate )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-getstartofday
+    pub(crate) fn start_of_day(&self, date: IsoDate, context: &mut Context) -> JsResult<i128> {
+        // 1. Let isoDateTime be CombineISODateAndTimeRecord(isoDate, MidnightTimeRecord()).
+        let date_time = IsoDateTime::new(date, IsoTime::default());
+
+        // 2. Let possibleEpochNs be ? GetPossibleEpochNanoseconds(timeZone, isoDateTime).
+        // 3. If possibleEpochNs is not empty, return possibleEpochNs[0].
+        if let Some(epoch_nanoseconds) =
+            self.possible_epoch_nanoseconds(date_time, context)?.first()
+        {
+            return Ok(*epoch_nanoseconds);
+        }
+
+        // 4. Assert: IsOffsetTimeZoneIdentifier(timeZone) is false.
+        // 5. Let possibleEpochNsAfter be GetNamedTimeZoneEpochNanoseconds(timeZone, isoDateTime + 1 day).
+        // 6. Let dayBefore be the epoch nanoseconds a day before the midnight in UTC.
+        // 7. Return the first transition after dayBefore, the start of the day skipped by it.
+        let day_before = date_time.epoch_nanoseconds() - NS_PER_DAY;
+        self.transition(day_before, TransitionDirection::Next, context)
+            .map_or_else(
+                || context.throw_range_error("the start of the day is out of range"),
+                Ok,
+            )
+    }
+
+    /// Abstract operations `GetNamedTimeZoneNextTransition ( timeZoneIdentifier, epochNanoseconds )`
+    /// and `GetNamedTimeZonePreviousTransition ( timeZoneIdentifier, epochNanoseconds )`
+    ///
+    /// Returns `None` if there is no transition in the range of the instants, and for the time
+    /// zones with a fixed offset.
+    pub(crate) fn transition(
+        &self,
+        epoch_nanoseconds: i128,
+        direction: TransitionDirection,
+        context: &Context,
+    ) -> Option<i128> {
+        let identifier = match self {
+            Self::Named(identifier) if identifier != "UTC" => identifier,
+            _ => return None,
+        };
+        let provider = context.time_zone_provider();
+        let transition = match direction {
+            TransitionDirection::Next => provider.next_transition(identifier, epoch_nanoseconds),
+            TransitionDirection::Previous => {
+                provider.previous_transition(identifier, epoch_nanoseconds)
+            }
+        }?;
+        is_valid_epoch_nanoseconds(transition).then(|| transition)
+    }
+
+    /// Abstract operation `InterpretISODateTimeOffset ( isoDate, time, offsetBehaviour, offsetNanoseconds, timeZone, disambiguation, offsetOption, matchBehaviour )`
+    ///
+    /// `time` is `None` for the start of the day.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-interpretisodatetimeoffset
+    pub(crate) fn interpret_offset(
+        &self,
+        date: IsoDate,
+        time: Option<IsoTime>,
+        offset_behaviour: OffsetBehaviour,
+        disambiguation: Disambiguation,
+        offset_option: OffsetOption,
+        context: &mut Context,
+    ) -> JsResult<i128> {
+        // 1. If time is start-of-day, then
+        //     a. Assert: offsetBehaviour is wall.
+        //     b. Assert: offsetNanoseconds is 0.
+        //     c. Return ? GetStartOfDay(timeZone, isoDate).
+        let time = match time {
+            Some(time) => time,
+            None => return self.start_of_day(date, context),
+        };
+
+        // 2. Let isoDateTime be CombineISODateAndTimeRecord(isoDate, time).
+        let date_time = IsoDateTime::new(date, time);
+
+        let (offset, match_minutes) = match (offset_behaviour, offset_option) {
+            // 3. If offsetBehaviour is wall, or offsetBehaviour is option and offsetOption is ignore, then
+            //     a. Return ? GetEpochNanosecondsFor(timeZone, isoDateTime, disambiguation).
+            (OffsetBehaviour::Wall, _) | (OffsetBehaviour::Option { .. }, OffsetOption::Ignore) => {
+                return self.epoch_nanoseconds_for(date_time, disambiguation, context);
+            }
+            // 4. If offsetBehaviour is exact, or offsetBehaviour is option and offsetOption is use, then
+            //     a. Let balanced be BalanceISODateTime(isoDate.[[Year]], isoDate.[[Month]], isoDate.[[Day]], time.[[Hour]], time.[[Minute]], time.[[Second]], time.[[Millisecond]], time.[[Microsecond]], time.[[Nanosecond]] - offsetNanoseconds).
+            //     b. Perform ? CheckISODaysRange(balanced.[[ISODate]]).
+            //     c. Let epochNanoseconds be GetUTCEpochNanoseconds(balanced).
+            //     d. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+            //     e. Return epochNanoseconds.
+            (OffsetBehaviour::Exact, _) => {
+                return epoch_nanoseconds_with_offset(date_time, 0, context)
+            }
+            (OffsetBehaviour::Option { offset, .. }, OffsetOption::Use) => {
+                return epoch_nanoseconds_with_offset(date_time, offset, context);
+            }
+            // 5. Assert: offsetBehaviour is option.
+            // 6. Assert: offsetOption is prefer or reject.
+            (
+                OffsetBehaviour::Option {
+                    offset,
+                    match_minutes,
+                },
+                _,
+            ) => (offset, match_minutes),
+        };
+
+        // 7. Perform ? CheckISODaysRange(isoDate).
+        check_iso_days_range(date, context)?;
+
+        // 8. Let utcEpochNanoseconds be GetUTCEpochNanoseconds(isoDateTime).
+        let utc_epoch_nanoseconds = date_time.epoch_nanoseconds();
+
+        // 9. Let possibleEpochNs be ? GetPossibleEpochNanoseconds(timeZone, isoDateTime).
+        let possible = self.possible_epoch_nanoseconds(date_time, context)?;
+
+        // 10. For each element candidate of possibleEpochNs, do
+        for candidate in &possible {
+            // a. Let candidateOffset be utcEpochNanoseconds - candidate.
+            let candidate_offset = utc_epoch_nanoseconds - candidate;
+
+            // b. If candidateOffset = offsetNanoseconds, then
+            //     i. Return candidate.
+            // c. If matchBehaviour is match-minutes, then
+            //     i. Let roundedCandidateNanoseconds be RoundNumberToIncrement(candidateOffset, 60 × 10^9, half-expand).
+            //     ii. If roundedCandidateNanoseconds = offsetNanoseconds, then
+            //         1. Return candidate.
+            if candidate_offset == i128::from(offset)
+                || (match_minutes
+                    && round_to_increment(
+                        candidate_offset,
+                        i128::from(NS_PER_MINUTE),
+                        RoundingMode::HalfExpand,
+                    ) == i128::from(offset))
+            {
+                return Ok(*candidate);
+            }
+        }
+
+        // 11. If offsetOption is reject, throw a RangeError exception.
+        if offset_option == OffsetOption::Reject {
+            return context.throw_range_error("the offset is invalid for the time zone");
+        }
+
+        // 12. Return ? DisambiguatePossibleEpochNanoseconds(possibleEpochNs, timeZone, isoDateTime, disambiguation).
+        self.disambiguate(&possible, date_time, disambiguation, context)
+    }
+}
+
+/// Gets the epoch nanoseconds of a date-time with a UTC offset, in nanoseconds.
+fn epoch_nanoseconds_with_offset(
+    date_time: IsoDateTime,
+    offset: i64,
+    context: &mut Context,
+) -> JsResult<i128> {
+    let epoch_nanoseconds = date_time.epoch_nanoseconds() - i128::from(offset);
+    check_iso_days_range(
+        IsoDateTime::from_epoch_nanoseconds(epoch_nanoseconds).date,
+        context,
+    )?;
+    if !is_valid_epoch_nanoseconds(epoch_nanoseconds) {
+        return context.throw_range_error("the date-time is out of range");
+    }
+    Ok(epoch_nanoseconds)
+}
+
+/// Abstract operation `CheckISODaysRange ( isoDate )`
+fn check_iso_days_range(date: IsoDate, context: &mut Context) -> JsResult<()> {
+    // 1. If abs(ISODateToEpochDays(isoDate.[[Year]], isoDate.[[Month]] - 1, isoDate.[[Day]])) > 10^8, then
+    //     a. Throw a RangeError exception.
+    if date.epoch_days().abs() > 100_000_000 {
+        return context.throw_range_error("the date is out of range");
+    }
+
+    // 2. Return unused.
+    Ok(())
+}
+
+/// The direction of the time zone transition looked up by `getTimeZoneTransition`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TransitionDirection {
+    Next,
+    Previous,
+}
+
+/// The `disambiguation` option, for the wall-clock times which are skipped or repeated by a
+/// time zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Disambiguation {
+    Compatible,
+    Earlier,
+    Later,
+    Reject,
+}
+
+impl Disambiguation {
+    /// Abstract operation `GetTemporalDisambiguationOption ( options )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-gettemporaldisambiguationoption
+    pub(crate) fn from_options(options: &JsObject, context: &mut Context) -> JsResult<Self> {
+        let disambiguation = get_option(
+            options,
+            "disambiguation",
+            &["compatible", "earlier", "later", "reject"],
+            context,
+        )?;
+        Ok(match disambiguation {
+            Some("earlier") => Self::Earlier,
+            Some("later") => Self::Later,
+            Some("reject") => Self::Reject,
+            _ => Self::Compatible,
+        })
+    }
+}
+
+/// The `offset` option, for the date-times whose UTC offset is not valid in their time zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OffsetOption {
+    Prefer,
+    Use,
+    Ignore,
+    Reject,
+}
+
+impl OffsetOption {
+    /// Abstract operation `GetTemporalOffsetOption ( options, fallback )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-gettemporaloffsetoption
+    pub(crate) fn from_options(
+        options: &JsObject,
+        fallback: Self,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let offset = get_option(
+            options,
+            "offset",
+            &["prefer", "use", "ignore", "reject"],
+            context,
+        )?;
+        Ok(match offset {
+            Some("prefer") => Self::Prefer,
+            Some("use") => Self::Use,
+            Some("ignore") => Self::Ignore,
+            Some("reject") => Self::Reject,
+            _ => fallback,
+        })
+    }
+}
+
+/// How the UTC offset of a date-time is used to find its exact time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OffsetBehaviour {
+    /// The offset, in nanoseconds, is used as requested by the `offset` option. With
+    /// `match_minutes`, it also matches the offsets of the time zone rounded to the minute.
+    Option { offset: i64, match_minutes: bool },
+    /// The date-time is in UTC, as given by the `Z` designator.
+    Exact,
+    /// The date-time is a wall-clock time, without offset.
+    Wall,
+}
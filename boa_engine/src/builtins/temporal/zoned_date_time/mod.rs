@@ -0,0 +1,1776 @@
+//! This module implements the global `Temporal.ZonedDateTime` object.
+//!
+//! A `Temporal.ZonedDateTime` is an exact point in time in a time zone and a calendar. Its
+//! arithmetic follows the wall-clock time of the time zone, so that adding a day across a
+//! daylight saving time transition keeps the same wall-clock time.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-temporal/#sec-temporal-zoneddatetime-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime
+
+#[cfg(test)]
+mod tests;
+
+use super::{
+    calendar::{Calendar, Fields, ShowCalendar, DATE_GETTERS},
+    format_offset_nanoseconds, format_offset_rounded, get_fractional_second_digits,
+    get_round_to_options, get_rounding_increment, get_temporal_unit,
+    instant::{difference_instant, is_valid_epoch_nanoseconds},
+    plain_date::IsoDate,
+    plain_date_time::{
+        interpret_fields, round_relative_duration, total_relative_duration, InternalDuration,
+        IsoDateTime, RelativeOrigin,
+    },
+    plain_time::{ordering_to_value, time_fields, IsoTime, TIME_GETTERS},
+    round_to_increment,
+    time_zone::{Disambiguation, OffsetBehaviour, OffsetOption, TimeZone, TransitionDirection},
+    to_partial_temporal_object, total_time_duration, validate_rounding_increment,
+    DifferenceSettings, Duration, Instant, Overflow, PlainDate, PlainDateTime, PlainTime,
+    Precision, RoundingMode, SecondsStringPrecision, TemporalUnit, UnitGroup,
+};
+use crate::{
+    bigint::RawBigInt,
+    builtins::{
+        function::NativeFunctionSignature,
+        intl::{get_option, get_options_object},
+        iso8601::{parse_date_time, UtcOffset},
+        BuiltIn, JsArgs,
+    },
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
+        JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsBigInt, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use tap::{Conv, Pipe};
+
+/// The nanoseconds in an hour.
+const NS_PER_HOUR: i128 = 3_600_000_000_000;
+
+/// The nanoseconds in a minute.
+const NS_PER_MINUTE: i64 = 60_000_000_000;
+
+/// The internal slots of a `Temporal.ZonedDateTime` object.
+#[derive(Debug, Clone, PartialEq, Eq, Trace, Finalize)]
+pub struct ZonedDateTime {
+    /// `[[EpochNanoseconds]]`
+    #[unsafe_ignore_trace]
+    epoch_nanoseconds: i128,
+    /// `[[TimeZone]]`
+    #[unsafe_ignore_trace]
+    time_zone: TimeZone,
+    /// `[[Calendar]]`
+    #[unsafe_ignore_trace]
+    calendar: Calendar,
+}
+
+impl BuiltIn for ZonedDateTime {
+    const NAME: &'static str = "ZonedDateTime";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        let getters: [(&str, NativeFunctionSignature); 7] = [
+            ("calendarId", Self::get_calendar_id),
+            ("timeZoneId", Self::get_time_zone_id),
+            ("epochMilliseconds", Self::get_epoch_milliseconds),
+            ("epochNanoseconds", Self::get_epoch_nanoseconds),
+            ("hoursInDay", Self::get_hours_in_day),
+            ("offsetNanoseconds", Self::get_offset_nanoseconds),
+            ("offset", Self::get_offset),
+        ];
+        let getters = getters.map(|(name, getter)| {
+            let function = FunctionBuilder::native(context, getter)
+                .name(format!("get {name}"))
+                .length(0)
+                .constructor(false)
+                .build();
+            (name, function)
+        });
+        let date_getters = DATE_GETTERS.map(|(name, getter)| {
+            let function = FunctionBuilder::closure(context, move |this, _, context| {
+                let date_time = Self::this_zoned_date_time(this, context)?;
+                Ok(getter(
+                    date_time.calendar,
+                    date_time.date_time(context).date,
+                ))
+            })
+            .name(format!("get {name}"))
+            .length(0)
+            .constructor(false)
+            .build();
+            (name, function)
+        });
+        let time_getters = TIME_GETTERS.map(|(name, getter)| {
+            let function = FunctionBuilder::closure(context, move |this, _, context| {
+                let date_time = Self::this_zoned_date_time(this, context)?;
+                Ok(getter(date_time.date_time(context).time))
+            })
+            .name(format!("get {name}"))
+            .length(0)
+            .constructor(false)
+            .build();
+            (name, function)
+        });
+
+        let mut constructor = ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context
+                .intrinsics()
+                .constructors()
+                .zoned_date_time()
+                .clone(),
+        );
+        constructor
+            .name(Self::NAME)
+            .length(Self::LENGTH)
+            .static_method(Self::from, "from", 1)
+            .static_method(Self::compare, "compare", 2);
+        for (name, getter) in getters.into_iter().chain(date_getters).chain(time_getters) {
+            constructor.accessor(name, Some(getter), None, Attribute::CONFIGURABLE);
+        }
+        constructor
+            .method(Self::with, "with", 1)
+            .method(Self::with_plain_time, "withPlainTime", 0)
+            .method(Self::with_time_zone, "withTimeZone", 1)
+            .method(Self::with_calendar, "withCalendar", 1)
+            .method(Self::add, "add", 1)
+            .method(Self::subtract, "subtract", 1)
+            .method(Self::until, "until", 1)
+            .method(Self::since, "since", 1)
+            .method(Self::round, "round", 1)
+            .method(Self::equals, "equals", 1)
+            .method(Self::start_of_day, "startOfDay", 0)
+            .method(Self::get_time_zone_transition, "getTimeZoneTransition", 1)
+            .method(Self::to_instant, "toInstant", 0)
+            .method(Self::to_plain_date, "toPlainDate", 0)
+            .method(Self::to_plain_time, "toPlainTime", 0)
+            .method(Self::to_plain_date_time, "toPlainDateTime", 0)
+            .method(Self::to_string, "toString", 0)
+            .method(Self::to_json, "toJSON", 0)
+            .method(Self::to_locale_string, "toLocaleString", 0)
+            .method(Self::value_of, "valueOf", 0)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                "Temporal.ZonedDateTime",
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build()
+            .conv::<JsValue>()
+            .pipe(Some)
+    }
+}
+
+impl ZonedDateTime {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 2;
+
+    /// Creates a `ZonedDateTime` from its epoch nanoseconds, time zone and calendar.
+    pub(crate) fn new(epoch_nanoseconds: i128, time_zone: TimeZone, calendar: Calendar) -> Self {
+        Self {
+            epoch_nanoseconds,
+            time_zone,
+            calendar,
+        }
+    }
+
+    /// Gets the epoch nanoseconds of the `ZonedDateTime`.
+    pub(crate) fn epoch_nanoseconds(&self) -> i128 {
+        self.epoch_nanoseconds
+    }
+
+    /// Gets the time zone of the `ZonedDateTime`.
+    pub(crate) fn time_zone(&self) -> &TimeZone {
+        &self.time_zone
+    }
+
+    /// Gets the calendar of the `ZonedDateTime`.
+    pub(crate) fn calendar(&self) -> Calendar {
+        self.calendar
+    }
+
+    /// Gets the wall-clock date and time of the `ZonedDateTime` in its time zone.
+    pub(crate) fn date_time(&self, context: &Context) -> IsoDateTime {
+        self.time_zone
+            .iso_date_time_for(self.epoch_nanoseconds, context)
+    }
+
+    /// Gets the UTC offset of the `ZonedDateTime` in its time zone, in nanoseconds.
+    fn offset_nanoseconds(&self, context: &Context) -> i64 {
+        self.time_zone
+            .offset_nanoseconds_for(self.epoch_nanoseconds, context)
+    }
+
+    /// Abstract operation `CreateTemporalZonedDateTime ( epochNanoseconds, timeZone, calendar [ , newTarget ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-createtemporalzoneddatetime
+    pub(crate) fn create(
+        self,
+        new_target: Option<&JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Assert: IsValidEpochNanoseconds(epochNanoseconds) is true.
+        // 2. If newTarget is not present, set newTarget to %Temporal.ZonedDateTime%.
+        // 3. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.ZonedDateTime.prototype%", « [[InitializedTemporalZonedDateTime]], [[EpochNanoseconds]], [[TimeZone]], [[Calendar]] »).
+        let prototype = match new_target {
+            Some(new_target) => get_prototype_from_constructor(
+                new_target,
+                StandardConstructors::zoned_date_time,
+                context,
+            )?,
+            None => context
+                .intrinsics()
+                .constructors()
+                .zoned_date_time()
+                .prototype(),
+        };
+
+        // 4. Set object.[[EpochNanoseconds]] to epochNanoseconds.
+        // 5. Set object.[[TimeZone]] to timeZone.
+        // 6. Set object.[[Calendar]] to calendar.
+        // 7. Return object.
+        Ok(JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::zoned_date_time(self),
+        ))
+    }
+
+    /// Abstract operation `ToTemporalZonedDateTime ( item [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-totemporalzoneddatetime
+    pub(crate) fn from_value(
+        item: &JsValue,
+        options: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 2. Let offsetBehaviour be option.
+        // 3. Let matchBehaviour be match-exactly.
+        let (date, time, time_zone, calendar, offset_behaviour, options) = match item {
+            // 4. If item is an Object, then
+            JsValue::Object(object) => {
+                // a. If item has an [[InitializedTemporalZonedDateTime]] internal slot, then
+                let date_time = object.borrow().as_zoned_date_time().cloned();
+                if let Some(date_time) = date_time {
+                    // i. NOTE: The following steps, and similar ones below, read options and perform independent validation in alphabetical order.
+                    // ii. Let resolvedOptions be ? GetOptionsObject(options).
+                    // iii. Perform ? GetTemporalDisambiguationOption(resolvedOptions).
+                    // iv. Perform ? GetTemporalOffsetOption(resolvedOptions, reject).
+                    // v. Perform ? GetTemporalOverflowOption(resolvedOptions).
+                    // vi. Return ! CreateTemporalZonedDateTime(item.[[EpochNanoseconds]], item.[[TimeZone]], item.[[Calendar]]).
+                    let options = get_options_object(options, context)?;
+                    Disambiguation::from_options(&options, context)?;
+                    OffsetOption::from_options(&options, OffsetOption::Reject, context)?;
+                    Overflow::from_options(&options.into(), context)?;
+                    return Ok(date_time);
+                }
+
+                // b. Let calendar be ? GetTemporalCalendarIdentifierWithISODefault(item).
+                let calendar = Calendar::from_fields_object(object, context)?;
+
+                // c. Let fields be ? PrepareCalendarFields(calendar, item, « year, month, month-code, day », « hour, minute, second, millisecond, microsecond, nanosecond, offset, time-zone », « time-zone »).
+                // d. Let timeZone be fields.[[TimeZone]].
+                let fields =
+                    Fields::read_zoned(object, calendar, true, context)?.unwrap_or_default();
+                let time_zone = match fields.time_zone.clone() {
+                    Some(time_zone) => time_zone,
+                    None => return context.throw_type_error("the timeZone field is required"),
+                };
+
+                // e. Let offsetString be fields.[[OffsetString]].
+                // f. If offsetString is unset, then
+                //     i. Set offsetBehaviour to wall.
+                let offset_behaviour = match fields.offset {
+                    Some(offset) => OffsetBehaviour::Option {
+                        offset,
+                        match_minutes: false,
+                    },
+                    None => OffsetBehaviour::Wall,
+                };
+
+                // g. Let resolvedOptions be ? GetOptionsObject(options).
+                // h. Let disambiguation be ? GetTemporalDisambiguationOption(resolvedOptions).
+                // i. Let offsetOption be ? GetTemporalOffsetOption(resolvedOptions, reject).
+                // j. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+                let options = ZonedOptions::from_options(options, context)?;
+
+                // k. Let result be ? InterpretTemporalDateTimeFields(calendar, fields, overflow).
+                // l. Let isoDate be result.[[ISODate]].
+                // m. Let time be result.[[Time]].
+                let result = interpret_fields(calendar, &fields, options.overflow, context)?;
+                (
+                    result.date,
+                    Some(result.time),
+                    time_zone,
+                    calendar,
+                    offset_behaviour,
+                    options,
+                )
+            }
+            // 5. Else,
+            JsValue::String(string) => {
+                // b. Let result be ? ParseISODateTime(item, « TemporalDateTimeString[+Zoned] »).
+                // c. Let annotation be result.[[TimeZone]].[[TimeZoneAnnotation]].
+                // d. Assert: annotation is not empty.
+                let (parsed, annotation) = match parse_date_time(string) {
+                    Ok(parsed) => match parsed.time_zone.clone() {
+                        Some(annotation) => (parsed, annotation),
+                        None => {
+                            return context.throw_range_error(format!(
+                                "the date-time string `{string}` has no time zone annotation"
+                            ))
+                        }
+                    },
+                    Err(error) => {
+                        return context.throw_range_error(format!(
+                            "invalid date-time string `{string}`: {error}"
+                        ))
+                    }
+                };
+
+                // e. Let timeZone be ? ToTemporalTimeZoneIdentifier(annotation).
+                let time_zone = TimeZone::from_value(&annotation.into(), context)?;
+
+                // f. Let offsetString be result.[[TimeZone]].[[OffsetString]].
+                // g. If result.[[TimeZone]].[[Z]] is true, then
+                //     i. Set offsetBehaviour to exact.
+                // h. Else if offsetString is empty, then
+                //     i. Set offsetBehaviour to wall.
+                // l. Set matchBehaviour to match-minutes.
+                // m. If offsetString is not empty, then
+                //     i. Let offsetParseResult be ParseText(StringToCodePoints(offsetString), UTCOffset[+SubMinutePrecision]).
+                //     ii. Assert: offsetParseResult is a Parse Node.
+                //     iii. If offsetParseResult contains more than one MinuteSecond Parse Node, set matchBehaviour to match-exactly.
+                let offset_behaviour = match parsed.offset {
+                    Some(UtcOffset::Z) => OffsetBehaviour::Exact,
+                    Some(UtcOffset::Offset(offset)) => OffsetBehaviour::Option {
+                        offset,
+                        match_minutes: offset % NS_PER_MINUTE == 0,
+                    },
+                    None => OffsetBehaviour::Wall,
+                };
+
+                // i. Let calendar be result.[[Calendar]].
+                // j. If calendar is empty, set calendar to "iso8601".
+                // k. Set calendar to ? CanonicalizeCalendar(calendar).
+                let calendar = match parsed
+                    .calendar
+                    .as_deref()
+                    .map_or(Some(Calendar::Iso8601), Calendar::from_identifier)
+                {
+                    Some(calendar) => calendar,
+                    None => return context.throw_range_error("unsupported calendar"),
+                };
+
+                // n. Let resolvedOptions be ? GetOptionsObject(options).
+                // o. Let disambiguation be ? GetTemporalDisambiguationOption(resolvedOptions).
+                // p. Let offsetOption be ? GetTemporalOffsetOption(resolvedOptions, reject).
+                // q. Perform ? GetTemporalOverflowOption(resolvedOptions).
+                let options = ZonedOptions::from_options(options, context)?;
+
+                // r. Let isoDate be CreateISODateRecord(result.[[Year]], result.[[Month]], result.[[Day]]).
+                // s. Let time be result.[[Time]].
+                let date = IsoDate {
+                    year: parsed.year,
+                    month: parsed.month,
+                    day: parsed.day,
+                };
+                let time = parsed.time.map(IsoTime::from_parsed);
+                (date, time, time_zone, calendar, offset_behaviour, options)
+            }
+            // a. If item is not a String, throw a TypeError exception.
+            _ => {
+                return context.throw_type_error("a zoned date-time must be a string or an object")
+            }
+        };
+
+        // 6. If offsetBehaviour is option, then
+        //     a. Let offsetNanoseconds be ! ParseDateTimeUTCOffset(offsetString).
+        // 7. Else,
+        //     a. Let offsetNanoseconds be 0.
+        // 8. Let epochNanoseconds be ? InterpretISODateTimeOffset(isoDate, time, offsetBehaviour, offsetNanoseconds, timeZone, disambiguation, offsetOption, matchBehaviour).
+        let epoch_nanoseconds = time_zone.interpret_offset(
+            date,
+            time,
+            offset_behaviour,
+            options.disambiguation,
+            options.offset,
+            context,
+        )?;
+
+        // 9. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+        Ok(Self::new(epoch_nanoseconds, time_zone, calendar))
+    }
+
+    /// Gets the `this` `Temporal.ZonedDateTime` object.
+    fn this_zoned_date_time(this: &JsValue, context: &mut Context) -> JsResult<Self> {
+        this.as_object()
+            .and_then(|obj| obj.borrow().as_zoned_date_time().cloned())
+            .ok_or_else(|| context.construct_type_error("'this' is not a Temporal.ZonedDateTime"))
+    }
+
+    /// `Temporal.ZonedDateTime ( epochNanoseconds, timeZone [ , calendar ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime/ZonedDateTime
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, then
+        if new_target.is_undefined() {
+            // a. Throw a TypeError exception.
+            return context.throw_type_error("Temporal.ZonedDateTime constructor requires 'new'");
+        }
+
+        // 2. Set epochNanoseconds to ? ToBigInt(epochNanoseconds).
+        let epoch_nanoseconds = args.get_or_undefined(0).to_bigint(context)?;
+
+        // 3. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+        let epoch_nanoseconds =
+            Instant::from_bigint(&epoch_nanoseconds, context)?.epoch_nanoseconds();
+
+        // 4. If timeZone is not a String, throw a TypeError exception.
+        // 5. Let timeZoneParse be ? ParseTimeZoneIdentifier(timeZone).
+        // 6. If timeZoneParse.[[OffsetMinutes]] is empty, then
+        //     a. Let identifierRecord be GetAvailableNamedTimeZoneIdentifier(timeZoneParse.[[Name]]).
+        //     b. If identifierRecord is empty, throw a RangeError exception.
+        //     c. Set timeZone to identifierRecord.[[Identifier]].
+        // 7. Else,
+        //     a. Set timeZone to FormatOffsetTimeZoneIdentifier(timeZoneParse.[[OffsetMinutes]]).
+        let time_zone = match args.get_or_undefined(1) {
+            JsValue::String(identifier) => match TimeZone::from_identifier(identifier, context) {
+                Some(time_zone) => time_zone,
+                None => {
+                    return context
+                        .throw_range_error(format!("unsupported time zone `{identifier}`"))
+                }
+            },
+            _ => return context.throw_type_error("a time zone must be a string"),
+        };
+
+        // 8. If calendar is undefined, set calendar to "iso8601".
+        // 9. If calendar is not a String, throw a TypeError exception.
+        // 10. Set calendar to ? CanonicalizeCalendar(calendar).
+        let calendar = Calendar::from_constructor_argument(args.get_or_undefined(2), context)?;
+
+        // 11. Return ? CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar, NewTarget).
+        Ok(Self::new(epoch_nanoseconds, time_zone, calendar)
+            .create(Some(new_target), context)?
+            .into())
+    }
+
+    /// `Temporal.ZonedDateTime.from ( item [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.from
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime/from
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? ToTemporalZonedDateTime(item, options).
+        let date_time =
+            Self::from_value(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+        Ok(date_time.create(None, context)?.into())
+    }
+
+    /// `Temporal.ZonedDateTime.compare ( one, two )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.compare
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime/compare
+    fn compare(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Set one to ? ToTemporalZonedDateTime(one).
+        let one = Self::from_value(args.get_or_undefined(0), &JsValue::undefined(), context)?;
+
+        // 2. Set two to ? ToTemporalZonedDateTime(two).
+        let two = Self::from_value(args.get_or_undefined(1), &JsValue::undefined(), context)?;
+
+        // 3. Return 𝔽(CompareEpochNanoseconds(one.[[EpochNanoseconds]], two.[[EpochNanoseconds]])).
+        Ok(ordering_to_value(
+            one.epoch_nanoseconds.cmp(&two.epoch_nanoseconds),
+        ))
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.calendarId`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.calendarid
+    fn get_calendar_id(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        // 3. Return zonedDateTime.[[Calendar]].
+        Ok(Self::this_zoned_date_time(this, context)?
+            .calendar
+            .identifier()
+            .into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.timeZoneId`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.timezoneid
+    fn get_time_zone_id(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        // 3. Return zonedDateTime.[[TimeZone]].
+        Ok(Self::this_zoned_date_time(this, context)?
+            .time_zone
+            .identifier()
+            .into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.epochMilliseconds`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.epochmilliseconds
+    fn get_epoch_milliseconds(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let date_time = Self::this_zoned_date_time(this, context)?;
+
+        // 3. Let ns be zonedDateTime.[[EpochNanoseconds]].
+        // 4. Let ms be floor(ℝ(ns) / 10^6).
+        // 5. Return 𝔽(ms).
+        Ok((date_time.epoch_nanoseconds.div_euclid(1_000_000) as f64).into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.epochNanoseconds`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.epochnanoseconds
+    fn get_epoch_nanoseconds(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        // 3. Return zonedDateTime.[[EpochNanoseconds]].
+        let date_time = Self::this_zoned_date_time(this, context)?;
+        Ok(JsBigInt::from(RawBigInt::from(date_time.epoch_nanoseconds)).into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.hoursInDay`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.hoursinday
+    fn get_hours_in_day(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let date_time = Self::this_zoned_date_time(this, context)?;
+
+        // 3. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 4. Let isoDateTime be GetISODateTimeFor(timeZone, zonedDateTime.[[EpochNanoseconds]]).
+        // 5. Let today be isoDateTime.[[ISODate]].
+        // 6. Let tomorrow be BalanceISODate(today.[[Year]], today.[[Month]], today.[[Day]] + 1).
+        // 7. Let todayNs be ? GetStartOfDay(timeZone, today).
+        // 8. Let tomorrowNs be ? GetStartOfDay(timeZone, tomorrow).
+        let (today, tomorrow) = date_time.day_bounds(context)?;
+
+        // 9. Let diff be TimeDurationFromEpochNanosecondsDifference(tomorrowNs, todayNs).
+        // 10. Return 𝔽(TotalTimeDuration(diff, hour)).
+        Ok(((tomorrow - today) as f64 / NS_PER_HOUR as f64).into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.offsetNanoseconds`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.offsetnanoseconds
+    fn get_offset_nanoseconds(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        // 3. Return 𝔽(GetOffsetNanosecondsFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]])).
+        let date_time = Self::this_zoned_date_time(this, context)?;
+        Ok((date_time.offset_nanoseconds(context) as f64).into())
+    }
+
+    /// `get Temporal.ZonedDateTime.prototype.offset`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.offset
+    fn get_offset(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        // 3. Let offsetNanoseconds be GetOffsetNanosecondsFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return FormatUTCOffsetNanoseconds(offsetNanoseconds).
+        let date_time = Self::this_zoned_date_time(this, context)?;
+        Ok(format_offset_nanoseconds(date_time.offset_nanoseconds(context)).into())
+    }
+
+    /// Gets the epoch nanoseconds of the start of the day of the `ZonedDateTime` and of the
+    /// next day, in its time zone.
+    fn day_bounds(&self, context: &mut Context) -> JsResult<(i128, i128)> {
+        let today = self.date_time(context).date;
+        let tomorrow = IsoDate::from_epoch_days(today.epoch_days() + 1);
+        let today = self.time_zone.start_of_day(today, context)?;
+        let tomorrow = self.time_zone.start_of_day(tomorrow, context)?;
+        Ok((today, tomorrow))
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.with ( temporalZonedDateTimeLike [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.with
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime/with
+    fn with(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let date_time = Self::this_zoned_date_time(this, context)?;
+
+        // 3. If ? IsPartialTemporalObject(temporalZonedDateTimeLike) is false, throw a TypeError exception.
+        let date_time_like = to_partial_temporal_object(args.get_or_undefined(0), context)?;
+
+        // 4. Let epochNs be zonedDateTime.[[EpochNanoseconds]].
+        // 5. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 6. Let calendar be zonedDateTime.[[Calendar]].
+        // 7. Let offsetNanoseconds be GetOffsetNanosecondsFor(timeZone, epochNs).
+        // 8. Let isoDateTime be GetISODateTimeFor(timeZone, epochNs).
+        let calendar = date_time.calendar;
+        let offset = date_time.offset_nanoseconds(context);
+        let iso_date_time = date_time.date_time(context);
+
+        // 9. Let fields be ISODateToFields(calendar, isoDateTime.[[ISODate]], date).
+        // 10-15. Set fields.[[Hour]] to isoDateTime.[[Time]].[[Hour]].
+        //        ...
+        // 16. Set fields.[[OffsetString]] to FormatUTCOffsetNanoseconds(offsetNanoseconds).
+        let mut fields = calendar.fields_of(iso_date_time.date);
+        fields.override_with(&time_fields(iso_date_time.time));
+        fields.offset = Some(offset);
+
+        // 17. Let partialZonedDateTime be ? PrepareCalendarFields(calendar, temporalZonedDateTimeLike, « year, month, month-code, day », « hour, minute, second, millisecond, microsecond, nanosecond, offset », partial).
+        let partial = match Fields::read_zoned(&date_time_like, calendar, false, context)? {
+            Some(partial) => partial,
+            None => return context.throw_type_error("at least one date or time field is required"),
+        };
+
+        // 18. Set fields to CalendarMergeFields(calendar, fields, partialZonedDateTime).
+        let fields = calendar.merge_fields(&fields, &partial);
+
+        // 19. Let resolvedOptions be ? GetOptionsObject(options).
+        // 20. Let disambiguation be ? GetTemporalDisambiguationOption(resolvedOptions).
+        // 21. Let offset be ? GetTemporalOffsetOption(resolvedOptions, prefer).
+        // 22. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let options = get_options_object(args.get_or_undefined(1), context)?;
+        let disambiguation = Disambiguation::from_options(&options, context)?;
+        let offset_option = OffsetOption::from_options(&options, OffsetOption::Prefer, context)?;
+        let overflow = Overflow::from_options(&options.into(), context)?;
+
+        // 23. Let dateTimeResult be ? InterpretTemporalDateTimeFields(calendar, fields, overflow).
+        let result = interpret_fields(calendar, &fields, overflow, context)?;
+
+        // 24. Let newOffsetNanoseconds be ! ParseDateTimeUTCOffset(fields.[[OffsetString]]).
+        // 25. Let epochNanoseconds be ? InterpretISODateTimeOffset(dateTimeResult.[[ISODate]], dateTimeResult.[[Time]], option, newOffsetNanoseconds, timeZone, disambiguation, offset, match-exactly).
+        let epoch_nanoseconds = date_time.time_zone.interpret_offset(
+            result.date,
+            Some(result.time),
+            OffsetBehaviour::Option {
+                offset: fields.offset.unwrap_or(offset),
+                match_minutes: false,
+            },
+            disambiguation,
+            offset_option,
+            context,
+        )?;
+
+        // 26. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+        Ok(Self::new(epoch_nanoseconds, date_time.time_zone, calendar)
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.withPlainTime ( [ plainTimeLike ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.withplaintime
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime/withPlainTime
+    fn with_plain_time(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let date_time = Self::this_zoned_date_time(this, context)?;
+
+        // 3. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 4. Let calendar be zonedDateTime.[[Calendar]].
+        // 5. Let isoDateTime be GetISODateTimeFor(timeZone, zonedDateTime.[[EpochNanoseconds]]).
+        let date = date_time.date_time(context).date;
+
+        let epoch_nanoseconds = match args.get_or_undefined(0) {
+            // 6. If plainTimeLike is undefined, then
+            //     a. Let epochNs be ? GetStartOfDay(timeZone, isoDateTime.[[ISODate]]).
+            JsValue::Undefined => date_time.time_zone.start_of_day(date, context)?,
+            // 7. Else,
+            //     a. Let plainTime be ? ToTemporalTime(plainTimeLike).
+            //     b. Let resultISODateTime be CombineISODateAndTimeRecord(isoDateTime.[[ISODate]], plainTime.[[Time]]).
+            //     c. Let epochNs be ? GetEpochNanosecondsFor(timeZone, resultISODateTime, compatible).
+            time => {
+                let time = PlainTime::from_value(time, &JsValue::undefined(), context)?;
+                date_time.time_zone.epoch_nanoseconds_for(
+                    IsoDateTime::new(date, time),
+                    Disambiguation::Compatible,
+                    context,
+                )?
+            }
+        };
+
+        // 8. Return ! CreateTemporalZonedDateTime(epochNs, timeZone, calendar).
+        Ok(
+            Self::new(epoch_nanoseconds, date_time.time_zone, date_time.calendar)
+                .create(None, context)?
+                .into(),
+        )
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.withTimeZone ( timeZoneLike )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.withtimezone
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime/withTimeZone
+    fn with_time_zone(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let date_time = Self::this_zoned_date_time(this, context)?;
+
+        // 3. Let timeZone be ? ToTemporalTimeZoneIdentifier(timeZoneLike).
+        let time_zone = TimeZone::from_value(args.get_or_undefined(0), context)?;
+
+        // 4. Return ! CreateTemporalZonedDateTime(zonedDateTime.[[EpochNanoseconds]], timeZone, zonedDateTime.[[Calendar]]).
+        Ok(
+            Self::new(date_time.epoch_nanoseconds, time_zone, date_time.calendar)
+                .create(None, context)?
+                .into(),
+        )
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.withCalendar ( calendarLike )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.withcalendar
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime/withCalendar
+    fn with_calendar(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let date_time = Self::this_zoned_date_time(this, context)?;
+
+        // 3. Let calendar be ? ToTemporalCalendarIdentifier(calendarLike).
+        let calendar = Calendar::from_value(args.get_or_undefined(0), context)?;
+
+        // 4. Return ! CreateTemporalZonedDateTime(zonedDateTime.[[EpochNanoseconds]], zonedDateTime.[[TimeZone]], calendar).
+        Ok(
+            Self::new(date_time.epoch_nanoseconds, date_time.time_zone, calendar)
+                .create(None, context)?
+                .into(),
+        )
+    }
+
+    /// Abstract operation `AddDurationToZonedDateTime ( operation, zonedDateTime, temporalDurationLike, options )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-adddurationtozoneddatetime
+    fn add_duration(
+        this: &JsValue,
+        args: &[JsValue],
+        subtract: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let date_time = Self::this_zoned_date_time(this, context)?;
+
+        // 1. Let duration be ? ToTemporalDuration(temporalDurationLike).
+        // 2. If operation is subtract, set duration to CreateNegatedTemporalDuration(duration).
+        let mut duration = Duration::from_value(args.get_or_undefined(0), context)?;
+        if subtract {
+            duration = duration.negated();
+        }
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        // 4. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow = Overflow::from_options(args.get_or_undefined(1), context)?;
+
+        // 5. Let calendar be zonedDateTime.[[Calendar]].
+        // 6. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 7. Let internalDuration be ToInternalDurationRecord(duration).
+        // 8. Let epochNanoseconds be ? AddZonedDateTime(zonedDateTime.[[EpochNanoseconds]], timeZone, calendar, internalDuration, overflow).
+        let epoch_nanoseconds = add_zoned_date_time(
+            date_time.epoch_nanoseconds,
+            &date_time.time_zone,
+            date_time.calendar,
+            (duration.date_part(), duration.time_nanoseconds()),
+            overflow,
+            context,
+        )?;
+
+        // 9. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+        Ok(
+            Self::new(epoch_nanoseconds, date_time.time_zone, date_time.calendar)
+                .create(None, context)?
+                .into(),
+        )
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.add ( temporalDurationLike [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.add
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime/add
+    fn add(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        // 3. Return ? AddDurationToZonedDateTime(add, zonedDateTime, temporalDurationLike, options).
+        Self::add_duration(this, args, false, context)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.subtract ( temporalDurationLike [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.subtract
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime/subtract
+    fn subtract(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        // 3. Return ? AddDurationToZonedDateTime(subtract, zonedDateTime, temporalDurationLike, options).
+        Self::add_duration(this, args, true, context)
+    }
+
+    /// Abstract operation `DifferenceTemporalZonedDateTime ( operation, zonedDateTime, other, options )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalzoneddatetime
+    fn difference(
+        this: &JsValue,
+        args: &[JsValue],
+        since: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let date_time = Self::this_zoned_date_time(this, context)?;
+
+        // 1. If operation is since, let sign be -1. Otherwise, let sign be 1.
+        // 2. Set other to ? ToTemporalZonedDateTime(other).
+        let other = Self::from_value(args.get_or_undefined(0), &JsValue::undefined(), context)?;
+
+        // 3. If CalendarEquals(zonedDateTime.[[Calendar]], other.[[Calendar]]) is false, then
+        //     a. Throw a RangeError exception.
+        if date_time.calendar != other.calendar {
+            return context.throw_range_error("the zoned date-times must have the same calendar");
+        }
+
+        // 4. Let resolvedOptions be ? GetOptionsObject(options).
+        // 5. Let settings be ? GetDifferenceSettings(operation, resolvedOptions, datetime, « », nanosecond, hour).
+        let settings = DifferenceSettings::from_options(
+            args.get_or_undefined(1),
+            since,
+            UnitGroup::DateTime,
+            TemporalUnit::Nanosecond,
+            TemporalUnit::Hour,
+            context,
+        )?;
+
+        let result = if settings.largest_unit.is_time() {
+            // 6. If TemporalUnitCategory(settings.[[LargestUnit]]) is time, then
+            //     a. Let internalDuration be DifferenceInstant(zonedDateTime.[[EpochNanoseconds]], other.[[EpochNanoseconds]], settings.[[RoundingIncrement]], settings.[[SmallestUnit]], settings.[[RoundingMode]]).
+            //     b. Let result be ! TemporalDurationFromInternal(internalDuration, settings.[[LargestUnit]]).
+            let nanoseconds = difference_instant(
+                date_time.epoch_nanoseconds,
+                other.epoch_nanoseconds,
+                settings,
+            );
+            Duration::from_parts([0; 4], nanoseconds, settings.largest_unit)
+        } else {
+            // 7. NOTE: To calculate differences in two different time zones, settings.[[LargestUnit]] must be a time unit, because day lengths can vary between time zones due to DST and other UTC offset shifts.
+            // 8. If TimeZoneEquals(zonedDateTime.[[TimeZone]], other.[[TimeZone]]) is false, then
+            //     a. Throw a RangeError exception.
+            if date_time.time_zone != other.time_zone {
+                return context.throw_range_error(
+                    "the zoned date-times must have the same time zone to be compared in days",
+                );
+            }
+
+            // 9. If zonedDateTime.[[EpochNanoseconds]] = other.[[EpochNanoseconds]], then
+            //     a. Return ! CreateTemporalDuration(0, 0, 0, 0, 0, 0, 0, 0, 0, 0).
+            if date_time.epoch_nanoseconds == other.epoch_nanoseconds {
+                return Ok(Duration::new([0.0; 10]).create(None, context)?.into());
+            }
+
+            // 10. Let internalDuration be ? DifferenceZonedDateTimeWithRounding(zonedDateTime.[[EpochNanoseconds]], other.[[EpochNanoseconds]], zonedDateTime.[[TimeZone]], zonedDateTime.[[Calendar]], settings.[[LargestUnit]], settings.[[RoundingIncrement]], settings.[[SmallestUnit]], settings.[[RoundingMode]]).
+            let duration = difference_zoned_date_time_with_rounding(
+                date_time.epoch_nanoseconds,
+                other.epoch_nanoseconds,
+                &date_time.time_zone,
+                date_time.calendar,
+                settings,
+                context,
+            )?;
+
+            // 11. Let result be ! TemporalDurationFromInternal(internalDuration, hour).
+            Duration::from_parts(duration.0, duration.1, TemporalUnit::Hour)
+        };
+
+        // 12. If operation is since, set result to CreateNegatedTemporalDuration(result).
+        let result = if since { result.negated() } else { result };
+
+        // 13. Return result.
+        Ok(result.create(None, context)?.into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.until ( other [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.until
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime/until
+    fn until(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        // 3. Return ? DifferenceTemporalZonedDateTime(until, zonedDateTime, other, options).
+        Self::difference(this, args, false, context)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.since ( other [ , options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.since
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime/since
+    fn since(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        // 3. Return ? DifferenceTemporalZonedDateTime(since, zonedDateTime, other, options).
+        Self::difference(this, args, true, context)
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.round ( roundTo )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.round
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime/round
+    fn round(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let date_time = Self::this_zoned_date_time(this, context)?;
+
+        // 3-5. Let roundTo be the options object.
+        let round_to = get_round_to_options(args.get_or_undefined(0), context)?;
+
+        // 6. NOTE: The following steps read options and perform independent validation in alphabetical order.
+        // 7. Let roundingIncrement be ? GetRoundingIncrementOption(roundTo).
+        let increment = get_rounding_increment(&round_to, context)?;
+
+        // 8. Let roundingMode be ? GetRoundingModeOption(roundTo, half-expand).
+        let rounding_mode =
+            RoundingMode::from_options(&round_to, RoundingMode::HalfExpand, context)?;
+
+        // 9. Let smallestUnit be ? GetTemporalUnitValuedOption(roundTo, "smallestUnit", time, required, « day »).
+        let smallest_unit =
+            match get_temporal_unit(&round_to, "smallestUnit", UnitGroup::DateTime, context)? {
+                Some(smallest_unit) if smallest_unit >= TemporalUnit::Day => smallest_unit,
+                Some(smallest_unit) => {
+                    return context.throw_range_error(format!(
+                        "invalid value `{}` for option `smallestUnit`",
+                        smallest_unit.singular()
+                    ))
+                }
+                None => return context.throw_range_error("smallestUnit is required"),
+            };
+
+        // 10. If smallestUnit is day, then
+        //     a. Let maximum be 1.
+        //     b. Let inclusive be true.
+        // 11. Else,
+        //     a. Let maximum be MaximumTemporalDurationRoundingIncrement(smallestUnit).
+        //     b. Assert: maximum is not unset.
+        //     c. Let inclusive be false.
+        // 12. Perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, inclusive).
+        match smallest_unit.maximum_rounding_increment() {
+            Some(maximum) => validate_rounding_increment(increment, maximum, false, context)?,
+            None => validate_rounding_increment(increment, 1, true, context)?,
+        }
+
+        // 13. If smallestUnit is nanosecond and roundingIncrement = 1, then
+        //     a. Return ! CreateTemporalZonedDateTime(zonedDateTime.[[EpochNanoseconds]], zonedDateTime.[[TimeZone]], zonedDateTime.[[Calendar]]).
+        // 14. Let thisNs be zonedDateTime.[[EpochNanoseconds]].
+        // 15. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 16. Let calendar be zonedDateTime.[[Calendar]].
+        // 17. Let isoDateTime be GetISODateTimeFor(timeZone, thisNs).
+        let epoch_nanoseconds = if smallest_unit == TemporalUnit::Day {
+            // 18. If smallestUnit is day, then
+            //     a. Let dateStart be isoDateTime.[[ISODate]].
+            //     b. Let dateEnd be BalanceISODate(dateStart.[[Year]], dateStart.[[Month]], dateStart.[[Day]] + 1).
+            //     c. Let startNs be ? GetStartOfDay(timeZone, dateStart).
+            //     d. Assert: thisNs ≥ startNs.
+            //     e. Let endNs be ? GetStartOfDay(timeZone, dateEnd).
+            //     f. Assert: thisNs < endNs.
+            let (start, end) = date_time.day_bounds(context)?;
+
+            // g. Let dayLengthNs be ℝ(endNs - startNs).
+            // h. Let dayProgressNs be TimeDurationFromEpochNanosecondsDifference(thisNs, startNs).
+            // i. Let roundedDayNs be ! RoundTimeDurationToIncrement(dayProgressNs, dayLengthNs, roundingMode).
+            // j. Let epochNanoseconds be AddTimeDurationToEpochNanoseconds(roundedDayNs, startNs).
+            start
+                + round_to_increment(
+                    date_time.epoch_nanoseconds - start,
+                    end - start,
+                    rounding_mode,
+                )
+        } else {
+            // 19. Else,
+            //     a. Let roundResult be RoundISODateTime(isoDateTime, roundingIncrement, smallestUnit, roundingMode).
+            //     b. Let offsetNanoseconds be GetOffsetNanosecondsFor(timeZone, thisNs).
+            //     c. Let epochNanoseconds be ? InterpretISODateTimeOffset(roundResult.[[ISODate]], roundResult.[[Time]], option, offsetNanoseconds, timeZone, compatible, prefer, match-exactly).
+            let unit_nanoseconds = smallest_unit
+                .nanoseconds()
+                .expect("the smallest unit must be a unit of time");
+            let result = date_time
+                .date_time(context)
+                .round(increment * unit_nanoseconds, rounding_mode);
+            let offset = date_time.offset_nanoseconds(context);
+            date_time.time_zone.interpret_offset(
+                result.date,
+                Some(result.time),
+                OffsetBehaviour::Option {
+                    offset,
+                    match_minutes: false,
+                },
+                Disambiguation::Compatible,
+                OffsetOption::Prefer,
+                context,
+            )?
+        };
+
+        // 20. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+        Ok(
+            Self::new(epoch_nanoseconds, date_time.time_zone, date_time.calendar)
+                .create(None, context)?
+                .into(),
+        )
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.equals ( other )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.equals
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime/equals
+    fn equals(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let date_time = Self::this_zoned_date_time(this, context)?;
+
+        // 3. Set other to ? ToTemporalZonedDateTime(other).
+        let other = Self::from_value(args.get_or_undefined(0), &JsValue::undefined(), context)?;
+
+        // 4. If zonedDateTime.[[EpochNanoseconds]] ≠ other.[[EpochNanoseconds]], return false.
+        // 5. If TimeZoneEquals(zonedDateTime.[[TimeZone]], other.[[TimeZone]]) is false, return false.
+        // 6. Return CalendarEquals(zonedDateTime.[[Calendar]], other.[[Calendar]]).
+        Ok((date_time == other).into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.startOfDay ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.startofday
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime/startOfDay
+    fn start_of_day(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let date_time = Self::this_zoned_date_time(this, context)?;
+
+        // 3. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 4. Let calendar be zonedDateTime.[[Calendar]].
+        // 5. Let isoDateTime be GetISODateTimeFor(timeZone, zonedDateTime.[[EpochNanoseconds]]).
+        // 6. Let epochNanoseconds be ? GetStartOfDay(timeZone, isoDateTime.[[ISODate]]).
+        let date = date_time.date_time(context).date;
+        let epoch_nanoseconds = date_time.time_zone.start_of_day(date, context)?;
+
+        // 7. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+        Ok(
+            Self::new(epoch_nanoseconds, date_time.time_zone, date_time.calendar)
+                .create(None, context)?
+                .into(),
+        )
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.getTimeZoneTransition ( directionParam )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.gettimezonetransition
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime/getTimeZoneTransition
+    fn get_time_zone_transition(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let date_time = Self::this_zoned_date_time(this, context)?;
+
+        let options = match args.get_or_undefined(0) {
+            // 4. If directionParam is undefined, throw a TypeError exception.
+            JsValue::Undefined => {
+                return context.throw_type_error("the transition direction must not be undefined")
+            }
+            // 5. If directionParam is a String, then
+            JsValue::String(direction) => {
+                // a. Let paramString be directionParam.
+                // b. Set directionParam to OrdinaryObjectCreate(null).
+                let options = JsObject::from_proto_and_data(None, ObjectData::ordinary());
+
+                // c. Perform ! CreateDataPropertyOrThrow(directionParam, "direction", paramString).
+                options
+                    .create_data_property_or_throw("direction", direction.clone(), context)
+                    .expect("CreateDataPropertyOrThrow must not fail on an ordinary object");
+                options
+            }
+            // 6. Else,
+            //     a. Set directionParam to ? GetOptionsObject(directionParam).
+            options => get_options_object(options, context)?,
+        };
+
+        // 7. Let direction be ? GetDirectionOption(directionParam).
+        let direction = match get_option(&options, "direction", &["next", "previous"], context)? {
+            Some("next") => TransitionDirection::Next,
+            Some(_) => TransitionDirection::Previous,
+            None => return context.throw_range_error("direction is required"),
+        };
+
+        // 3. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 8. If IsOffsetTimeZoneIdentifier(timeZone) is true, return null.
+        // 9. If direction is next, then
+        //     a. Let transition be GetNamedTimeZoneNextTransition(timeZone, zonedDateTime.[[EpochNanoseconds]]).
+        // 10. Else,
+        //     a. Let transition be GetNamedTimeZonePreviousTransition(timeZone, zonedDateTime.[[EpochNanoseconds]]).
+        // 11. If transition is null, return null.
+        let transition =
+            match date_time
+                .time_zone
+                .transition(date_time.epoch_nanoseconds, direction, context)
+            {
+                Some(transition) => transition,
+                None => return Ok(JsValue::null()),
+            };
+
+        // 12. Return ! CreateTemporalZonedDateTime(transition, timeZone, zonedDateTime.[[Calendar]]).
+        Ok(
+            Self::new(transition, date_time.time_zone, date_time.calendar)
+                .create(None, context)?
+                .into(),
+        )
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toInstant ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.toinstant
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime/toInstant
+    #[allow(clippy::wrong_self_convention)]
+    fn to_instant(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let date_time = Self::this_zoned_date_time(this, context)?;
+
+        // 3. Return ! CreateTemporalInstant(zonedDateTime.[[EpochNanoseconds]]).
+        Ok(Instant::new(date_time.epoch_nanoseconds)
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toPlainDate ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.toplaindate
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime/toPlainDate
+    #[allow(clippy::wrong_self_convention)]
+    fn to_plain_date(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let date_time = Self::this_zoned_date_time(this, context)?;
+
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return ! CreateTemporalDate(isoDateTime.[[ISODate]], zonedDateTime.[[Calendar]]).
+        let date = date_time.date_time(context).date;
+        Ok(PlainDate::new(date, date_time.calendar)
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toPlainTime ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.toplaintime
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime/toPlainTime
+    #[allow(clippy::wrong_self_convention)]
+    fn to_plain_time(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let date_time = Self::this_zoned_date_time(this, context)?;
+
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return ! CreateTemporalTime(isoDateTime.[[Time]]).
+        let time = date_time.date_time(context).time;
+        Ok(PlainTime::new(time).create(None, context)?.into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toPlainDateTime ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.toplaindatetime
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime/toPlainDateTime
+    #[allow(clippy::wrong_self_convention)]
+    fn to_plain_date_time(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let date_time = Self::this_zoned_date_time(this, context)?;
+
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return ! CreateTemporalDateTime(isoDateTime, zonedDateTime.[[Calendar]]).
+        let iso_date_time = date_time.date_time(context);
+        Ok(PlainDateTime::new(iso_date_time, date_time.calendar)
+            .create(None, context)?
+            .into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toString ( [ options ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.tostring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime/toString
+    #[allow(clippy::wrong_self_convention)]
+    fn to_string(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let date_time = Self::this_zoned_date_time(this, context)?;
+
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(0), context)?;
+
+        // 4. NOTE: The following steps read options and perform independent validation in alphabetical order.
+        // 5. Let showCalendar be ? GetTemporalShowCalendarNameOption(resolvedOptions).
+        let show_calendar = ShowCalendar::from_options(&options, context)?;
+
+        // 6. Let digits be ? GetTemporalFractionalSecondDigitsOption(resolvedOptions).
+        let digits = get_fractional_second_digits(&options, context)?;
+
+        // 7. Let showOffset be ? GetTemporalShowOffsetOption(resolvedOptions).
+        let show_offset =
+            get_option(&options, "offset", &["auto", "never"], context)? != Some("never");
+
+        // 8. Let roundingMode be ? GetRoundingModeOption(resolvedOptions, trunc).
+        let rounding_mode = RoundingMode::from_options(&options, RoundingMode::Trunc, context)?;
+
+        // 9. Let smallestUnit be ? GetTemporalUnitValuedOption(resolvedOptions, "smallestUnit", time, unset).
+        let smallest_unit = get_temporal_unit(&options, "smallestUnit", UnitGroup::Time, context)?;
+
+        // 10. If smallestUnit is hour, throw a RangeError exception.
+        // 12. Let precision be ToSecondsStringPrecisionRecord(smallestUnit, digits).
+        let precision = SecondsStringPrecision::new(smallest_unit, digits, context)?;
+
+        // 11. Let showTimeZone be ? GetTemporalShowTimeZoneNameOption(resolvedOptions).
+        let show_time_zone = ShowTimeZone::from_options(&options, context)?;
+
+        // 13. Return TemporalZonedDateTimeToString(zonedDateTime, precision.[[Precision]], showCalendar, showTimeZone, showOffset, precision.[[Increment]], precision.[[Unit]], roundingMode).
+        Ok(date_time
+            .to_iso_string(
+                precision,
+                rounding_mode,
+                show_calendar,
+                show_time_zone,
+                show_offset,
+                context,
+            )
+            .into())
+    }
+
+    /// Abstract operation `TemporalZonedDateTimeToString ( zonedDateTime, precision, showCalendar, showTimeZone, showOffset [ , increment, unit, roundingMode ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-temporalzoneddatetimetostring
+    fn to_iso_string(
+        &self,
+        precision: SecondsStringPrecision,
+        rounding_mode: RoundingMode,
+        show_calendar: ShowCalendar,
+        show_time_zone: ShowTimeZone,
+        show_offset: bool,
+        context: &Context,
+    ) -> String {
+        // 4. Let epochNs be RoundTemporalInstant(zonedDateTime.[[EpochNanoseconds]], increment, unit, roundingMode).
+        let epoch_nanoseconds = round_to_increment(
+            self.epoch_nanoseconds,
+            precision.nanoseconds(),
+            rounding_mode,
+        );
+
+        // 5. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 6. Let offsetNanoseconds be GetOffsetNanosecondsFor(timeZone, epochNs).
+        // 7. Let isoDateTime be GetISODateTimeFor(timeZone, epochNs).
+        // 8. Let dateTimeString be ISODateTimeToString(isoDateTime, "iso8601", precision, never).
+        let offset = self
+            .time_zone
+            .offset_nanoseconds_for(epoch_nanoseconds, context);
+        let date_time = self
+            .time_zone
+            .iso_date_time_for(epoch_nanoseconds, context)
+            .to_iso_string(precision.precision);
+
+        // 9. If showOffset is never, then
+        //     a. Let offsetString be the empty String.
+        // 10. Else,
+        //     a. Let offsetString be FormatDateTimeUTCOffsetRounded(offsetNanoseconds).
+        let offset = if show_offset {
+            format_offset_rounded(offset)
+        } else {
+            String::new()
+        };
+
+        // 11. If showTimeZone is never, then
+        //     a. Let timeZoneString be the empty String.
+        // 12. Else,
+        //     a. If showTimeZone is critical, let flag be "!"; else let flag be the empty String.
+        //     b. Let timeZoneString be the string-concatenation of the code unit 0x005B (LEFT SQUARE BRACKET), flag, timeZone, and the code unit 0x005D (RIGHT SQUARE BRACKET).
+        let time_zone = match show_time_zone {
+            ShowTimeZone::Never => String::new(),
+            ShowTimeZone::Auto => format!("[{}]", self.time_zone.identifier()),
+            ShowTimeZone::Critical => format!("[!{}]", self.time_zone.identifier()),
+        };
+
+        // 13. Let calendarString be FormatCalendarAnnotation(zonedDateTime.[[Calendar]], showCalendar).
+        // 14. Return the string-concatenation of dateTimeString, offsetString, timeZoneString, and calendarString.
+        format!(
+            "{date_time}{offset}{time_zone}{}",
+            self.calendar.annotation(show_calendar)
+        )
+    }
+
+    /// Formats the `ZonedDateTime` with the default options.
+    fn to_default_string(&self, context: &Context) -> String {
+        self.to_iso_string(
+            SecondsStringPrecision {
+                precision: Precision::Auto,
+                unit: TemporalUnit::Nanosecond,
+                increment: 1,
+            },
+            RoundingMode::Trunc,
+            ShowCalendar::Auto,
+            ShowTimeZone::Auto,
+            true,
+            context,
+        )
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toJSON ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.tojson
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime/toJSON
+    #[allow(clippy::wrong_self_convention)]
+    fn to_json(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        // 3. Return TemporalZonedDateTimeToString(zonedDateTime, auto, auto, auto, auto).
+        Ok(Self::this_zoned_date_time(this, context)?
+            .to_default_string(context)
+            .into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.toLocaleString ( [ locales [ , options ] ] )`
+    ///
+    /// The time zones of the time zone provider are not supported by `Intl.DateTimeFormat` yet,
+    /// so this returns the same string as `toJSON`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime/toLocaleString
+    #[allow(clippy::wrong_self_convention)]
+    fn to_locale_string(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        // 3. Return TemporalZonedDateTimeToString(zonedDateTime, auto, auto, auto, auto).
+        Ok(Self::this_zoned_date_time(this, context)?
+            .to_default_string(context)
+            .into())
+    }
+
+    /// `Temporal.ZonedDateTime.prototype.valueOf ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.valueof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/ZonedDateTime/valueOf
+    fn value_of(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Throw a TypeError exception.
+        context.throw_type_error("a Temporal.ZonedDateTime can't be converted to a primitive value")
+    }
+}
+
+/// The `disambiguation`, `offset` and `overflow` options of `ToTemporalZonedDateTime`.
+#[derive(Debug, Clone, Copy)]
+struct ZonedOptions {
+    disambiguation: Disambiguation,
+    offset: OffsetOption,
+    overflow: Overflow,
+}
+
+impl ZonedOptions {
+    /// Reads the options in alphabetical order, with the `reject` fallback for `offset`.
+    fn from_options(options: &JsValue, context: &mut Context) -> JsResult<Self> {
+        let options = get_options_object(options, context)?;
+        let disambiguation = Disambiguation::from_options(&options, context)?;
+        let offset = OffsetOption::from_options(&options, OffsetOption::Reject, context)?;
+        let overflow = Overflow::from_options(&options.into(), context)?;
+        Ok(Self {
+            disambiguation,
+            offset,
+            overflow,
+        })
+    }
+}
+
+/// The `timeZoneName` option of `toString`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ShowTimeZone {
+    Auto,
+    Never,
+    Critical,
+}
+
+impl ShowTimeZone {
+    /// Abstract operation `GetTemporalShowTimeZoneNameOption ( options )`
+    fn from_options(options: &JsObject, context: &mut Context) -> JsResult<Self> {
+        let show = get_option(
+            options,
+            "timeZoneName",
+            &["auto", "never", "critical"],
+            context,
+        )?;
+        Ok(match show {
+            Some("never") => Self::Never,
+            Some("critical") => Self::Critical,
+            _ => Self::Auto,
+        })
+    }
+}
+
+/// Abstract operation `AddZonedDateTime ( epochNanoseconds, timeZone, calendar, duration, overflow )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-addzoneddatetime
+pub(crate) fn add_zoned_date_time(
+    epoch_nanoseconds: i128,
+    time_zone: &TimeZone,
+    calendar: Calendar,
+    duration: InternalDuration,
+    overflow: Overflow,
+    context: &mut Context,
+) -> JsResult<i128> {
+    let (date_duration, time_duration) = duration;
+
+    // 1. If DateDurationSign(duration.[[Date]]) = 0, then
+    //     a. Return ? AddInstant(epochNanoseconds, duration.[[Time]]).
+    let intermediate = if date_duration == [0; 4] {
+        epoch_nanoseconds
+    } else {
+        // 2. Let isoDateTime be GetISODateTimeFor(timeZone, epochNanoseconds).
+        let date_time = time_zone.iso_date_time_for(epoch_nanoseconds, context);
+
+        // 3. Let addedDate be ? CalendarDateAdd(calendar, isoDateTime.[[ISODate]], duration.[[Date]], overflow).
+        let date = match calendar.date_add(date_time.date, date_duration, overflow) {
+            Some(date) => date,
+            None => return context.throw_range_error("the resulting date is out of range"),
+        };
+
+        // 4. Let intermediateDateTime be CombineISODateAndTimeRecord(addedDate, isoDateTime.[[Time]]).
+        // 5. If ISODateTimeWithinLimits(intermediateDateTime) is false, throw a RangeError exception.
+        let intermediate = IsoDateTime::new(date, date_time.time);
+        if !intermediate.is_within_limits() {
+            return context.throw_range_error("the resulting date-time is out of range");
+        }
+
+        // 6. Let intermediateNs be ! GetEpochNanosecondsFor(timeZone, intermediateDateTime, compatible).
+        time_zone.epoch_nanoseconds_for(intermediate, Disambiguation::Compatible, context)?
+    };
+
+    // 7. Return ? AddInstant(intermediateNs, duration.[[Time]]).
+    let result = intermediate + time_duration;
+    if !is_valid_epoch_nanoseconds(result) {
+        return context.throw_range_error("the resulting instant is out of range");
+    }
+    Ok(result)
+}
+
+/// Abstract operation `DifferenceZonedDateTime ( ns1, ns2, timeZone, calendar, largestUnit )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differencezoneddatetime
+fn difference_zoned_date_time(
+    one: i128,
+    two: i128,
+    time_zone: &TimeZone,
+    calendar: Calendar,
+    largest_unit: TemporalUnit,
+    context: &mut Context,
+) -> JsResult<InternalDuration> {
+    // 1. If ns1 = ns2, return CombineDateAndTimeDuration(ZeroDateDuration(), 0).
+    if one == two {
+        return Ok(([0; 4], 0));
+    }
+
+    // 2. Let startDateTime be GetISODateTimeFor(timeZone, ns1).
+    // 3. Let endDateTime be GetISODateTimeFor(timeZone, ns2).
+    let start = time_zone.iso_date_time_for(one, context);
+    let end = time_zone.iso_date_time_for(two, context);
+
+    // 4. If CompareISODate(startDateTime.[[ISODate]], endDateTime.[[ISODate]]) = 0, then
+    //     a. Let timeDuration be TimeDurationFromEpochNanosecondsDifference(ns2, ns1).
+    //     b. Return CombineDateAndTimeDuration(ZeroDateDuration(), timeDuration).
+    if start.date == end.date {
+        return Ok(([0; 4], two - one));
+    }
+
+    // 5. If ns2 - ns1 < 0, let sign be -1; else let sign be 1.
+    // 6. If sign = 1, let maxDayCorrection be 2; else let maxDayCorrection be 1.
+    let sign: i64 = if two < one { -1 } else { 1 };
+    let max_day_correction = if sign == 1 { 2 } else { 1 };
+
+    // 7. Let dayCorrection be 0.
+    // 8. Let timeDuration be DifferenceTime(startDateTime.[[Time]], endDateTime.[[Time]]).
+    // 9. If TimeDurationSign(timeDuration) = -sign, set dayCorrection to dayCorrection + 1.
+    let mut day_correction =
+        i64::from((end.time.nanoseconds() - start.time.nanoseconds()).signum() == -sign);
+
+    // 10. Let success be false.
+    // 11. Repeat, while dayCorrection ≤ maxDayCorrection and success is false,
+    while day_correction <= max_day_correction {
+        // a. Let intermediateDate be BalanceISODate(endDateTime.[[ISODate]].[[Year]], endDateTime.[[ISODate]].[[Month]], endDateTime.[[ISODate]].[[Day]] - dayCorrection × sign).
+        // b. Let intermediateDateTime be CombineISODateAndTimeRecord(intermediateDate, startDateTime.[[Time]]).
+        // c. Let intermediateNs be ? GetEpochNanosecondsFor(timeZone, intermediateDateTime, compatible).
+        let intermediate_date =
+            IsoDate::from_epoch_days(end.date.epoch_days() - day_correction * sign);
+        let intermediate = time_zone.epoch_nanoseconds_for(
+            IsoDateTime::new(intermediate_date, start.time),
+            Disambiguation::Compatible,
+            context,
+        )?;
+
+        // d. Set timeDuration to TimeDurationFromEpochNanosecondsDifference(ns2, intermediateNs).
+        // e. Let timeSign be TimeDurationSign(timeDuration).
+        // f. If sign ≠ -timeSign, then
+        //     i. Set success to true.
+        let time = two - intermediate;
+        if time.signum() as i64 != -sign {
+            // 12. Assert: success is true.
+            // 13. Let dateLargestUnit be LargerOfTwoTemporalUnits(largestUnit, day).
+            // 14. Let dateDifference be CalendarDateUntil(calendar, startDateTime.[[ISODate]], intermediateDate, dateLargestUnit).
+            // 15. Return CombineDateAndTimeDuration(dateDifference, timeDuration).
+            let date = calendar.date_until(
+                start.date,
+                intermediate_date,
+                largest_unit.min(TemporalUnit::Day),
+            );
+            return Ok((date, time));
+        }
+
+        // g. Set dayCorrection to dayCorrection + 1.
+        day_correction += 1;
+    }
+
+    context.throw_range_error("the difference of the zoned date-times is out of range")
+}
+
+/// Abstract operation `DifferenceZonedDateTimeWithRounding ( ns1, ns2, timeZone, calendar, largestUnit, roundingIncrement, smallestUnit, roundingMode )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differencezoneddatetimewithrounding
+pub(crate) fn difference_zoned_date_time_with_rounding(
+    one: i128,
+    two: i128,
+    time_zone: &TimeZone,
+    calendar: Calendar,
+    settings: DifferenceSettings,
+    context: &mut Context,
+) -> JsResult<InternalDuration> {
+    // 1. If TemporalUnitCategory(largestUnit) is time, then
+    //     a. Return DifferenceInstant(ns1, ns2, roundingIncrement, smallestUnit, roundingMode).
+    if settings.largest_unit.is_time() {
+        return Ok(([0; 4], difference_instant(one, two, settings)));
+    }
+
+    // 2. Let difference be ? DifferenceZonedDateTime(ns1, ns2, timeZone, calendar, largestUnit).
+    let difference = difference_zoned_date_time(
+        one,
+        two,
+        time_zone,
+        calendar,
+        settings.largest_unit,
+        context,
+    )?;
+
+    // 3. If smallestUnit is nanosecond and roundingIncrement = 1, return difference.
+    if settings.is_exact() {
+        return Ok(difference);
+    }
+
+    // 4. Let dateTime be GetISODateTimeFor(timeZone, ns1).
+    // 5. Return ? RoundRelativeDuration(difference, ns2, dateTime, timeZone, calendar, largestUnit, roundingIncrement, smallestUnit, roundingMode).
+    let origin = RelativeOrigin {
+        date_time: time_zone.iso_date_time_for(one, context),
+        time_zone: Some(time_zone),
+        calendar,
+    };
+    round_relative_duration(difference, two, origin, settings, context)
+}
+
+/// Abstract operation `DifferenceZonedDateTimeWithTotal ( ns1, ns2, timeZone, calendar, unit )`
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-differencezoneddatetimewithtotal
+pub(crate) fn difference_zoned_date_time_with_total(
+    one: i128,
+    two: i128,
+    time_zone: &TimeZone,
+    calendar: Calendar,
+    unit: TemporalUnit,
+    context: &mut Context,
+) -> JsResult<f64> {
+    // 1. If TemporalUnitCategory(unit) is time, then
+    //     a. Let difference be TimeDurationFromEpochNanosecondsDifference(ns2, ns1).
+    //     b. Return TotalTimeDuration(difference, unit).
+    if unit.is_time() {
+        return Ok(total_time_duration(two - one, unit));
+    }
+
+    // 2. Let difference be ? DifferenceZonedDateTime(ns1, ns2, timeZone, calendar, unit).
+    let difference = difference_zoned_date_time(one, two, time_zone, calendar, unit, context)?;
+
+    // 3. Let dateTime be GetISODateTimeFor(timeZone, ns1).
+    // 4. Return ? TotalRelativeDuration(difference, ns2, dateTime, timeZone, calendar, unit).
+    let origin = RelativeOrigin {
+        date_time: time_zone.iso_date_time_for(one, context),
+        time_zone: Some(time_zone),
+        calendar,
+    };
+    total_relative_duration(difference, two, origin, unit, context)
+}
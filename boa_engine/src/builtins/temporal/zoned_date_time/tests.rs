@@ -0,0 +1,486 @@
+use crate::{context::TimeZoneProvider, forward, Context};
+
+/// The start of the daylight saving time of 2020 in Paris, at 2020-03-29T01:00Z.
+const DST_START: i128 = 1_585_443_600_000_000_000;
+
+/// The end of the daylight saving time of 2020 in Paris, at 2020-10-25T01:00Z.
+const DST_END: i128 = 1_603_587_600_000_000_000;
+
+/// A time zone provider with the rules of `Europe/Paris` in 2020.
+#[derive(Debug)]
+struct Paris;
+
+impl TimeZoneProvider for Paris {
+    fn identifier(&self, identifier: &str) -> Option<String> {
+        identifier
+            .eq_ignore_ascii_case("Europe/Paris")
+            .then(|| "Europe/Paris".to_owned())
+    }
+
+    fn offset_nanoseconds(&self, _: &str, epoch_nanoseconds: i128) -> i64 {
+        if (DST_START..DST_END).contains(&epoch_nanoseconds) {
+            7_200_000_000_000
+        } else {
+            3_600_000_000_000
+        }
+    }
+
+    fn next_transition(&self, _: &str, epoch_nanoseconds: i128) -> Option<i128> {
+        [DST_START, DST_END]
+            .into_iter()
+            .find(|transition| *transition > epoch_nanoseconds)
+    }
+
+    fn previous_transition(&self, _: &str, epoch_nanoseconds: i128) -> Option<i128> {
+        [DST_START, DST_END]
+            .into_iter()
+            .rev()
+            .find(|transition| *transition < epoch_nanoseconds)
+    }
+}
+
+fn context() -> Context {
+    Context::builder().time_zone_provider(Paris).build()
+}
+
+fn error_name(context: &mut Context, code: &str) -> String {
+    forward(context, &format!("try {{ {code} }} catch (e) {{ e.name }}"))
+}
+
+#[test]
+fn constructor() {
+    let mut context = context();
+    for (code, expected) in [
+        (
+            "new Temporal.ZonedDateTime(1585440000000000000n, 'europe/paris').toString()",
+            "\"2020-03-29T01:00:00+01:00[Europe/Paris]\"",
+        ),
+        (
+            "new Temporal.ZonedDateTime(0n, '+05:30').toString()",
+            "\"1970-01-01T05:30:00+05:30[+05:30]\"",
+        ),
+        (
+            "new Temporal.ZonedDateTime(0n, 'UTC', 'iso8601').toString()",
+            "\"1970-01-01T00:00:00+00:00[UTC]\"",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+
+    for (code, expected) in [
+        ("Temporal.ZonedDateTime(0n, 'UTC')", "TypeError"),
+        ("new Temporal.ZonedDateTime(0n, 1)", "TypeError"),
+        (
+            "new Temporal.ZonedDateTime(0n, 'Mars/Olympus')",
+            "RangeError",
+        ),
+        ("new Temporal.ZonedDateTime(0n, '+01:00:30')", "RangeError"),
+        (
+            "new Temporal.ZonedDateTime(8640000000000000000001n, 'UTC')",
+            "RangeError",
+        ),
+    ] {
+        assert_eq!(error_name(&mut context, code), format!("\"{expected}\""));
+    }
+}
+
+#[test]
+fn getters() {
+    let mut context = context();
+    forward(
+        &mut context,
+        "var zdt = Temporal.ZonedDateTime.from('2020-06-01T12:30:45.123[Europe/Paris]');",
+    );
+    for (code, expected) in [
+        ("zdt.year", "2020"),
+        ("zdt.month", "6"),
+        ("zdt.day", "1"),
+        ("zdt.hour", "12"),
+        ("zdt.minute", "30"),
+        ("zdt.millisecond", "123"),
+        ("zdt.offset", "\"+02:00\""),
+        ("zdt.offsetNanoseconds", "7200000000000"),
+        ("zdt.timeZoneId", "\"Europe/Paris\""),
+        ("zdt.calendarId", "\"iso8601\""),
+        ("zdt.epochMilliseconds", "1591007445123"),
+        ("zdt.epochNanoseconds", "1591007445123000000n"),
+        ("zdt.hoursInDay", "24"),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+}
+
+#[test]
+fn hours_in_day() {
+    let mut context = context();
+    for (code, expected) in [
+        (
+            "Temporal.ZonedDateTime.from('2020-03-29T12:00[Europe/Paris]').hoursInDay",
+            "23",
+        ),
+        (
+            "Temporal.ZonedDateTime.from('2020-10-25T12:00[Europe/Paris]').hoursInDay",
+            "25",
+        ),
+        (
+            "Temporal.ZonedDateTime.from('2020-03-29T12:00[+01:00]').hoursInDay",
+            "24",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+}
+
+#[test]
+fn disambiguation() {
+    let mut context = context();
+    for (code, expected) in [
+        // 02:30 is skipped on 2020-03-29.
+        (
+            "Temporal.ZonedDateTime.from('2020-03-29T02:30[Europe/Paris]').toString()",
+            "\"2020-03-29T03:30:00+02:00[Europe/Paris]\"",
+        ),
+        (
+            "Temporal.ZonedDateTime.from('2020-03-29T02:30[Europe/Paris]', { disambiguation: 'earlier' }).toString()",
+            "\"2020-03-29T01:30:00+01:00[Europe/Paris]\"",
+        ),
+        (
+            "Temporal.ZonedDateTime.from('2020-03-29T02:30[Europe/Paris]', { disambiguation: 'later' }).toString()",
+            "\"2020-03-29T03:30:00+02:00[Europe/Paris]\"",
+        ),
+        // 02:30 is repeated on 2020-10-25.
+        (
+            "Temporal.ZonedDateTime.from('2020-10-25T02:30[Europe/Paris]').toString()",
+            "\"2020-10-25T02:30:00+02:00[Europe/Paris]\"",
+        ),
+        (
+            "Temporal.ZonedDateTime.from('2020-10-25T02:30[Europe/Paris]', { disambiguation: 'later' }).toString()",
+            "\"2020-10-25T02:30:00+01:00[Europe/Paris]\"",
+        ),
+        (
+            "Temporal.PlainDateTime.from('2020-10-25T02:30').toZonedDateTime('Europe/Paris', { disambiguation: 'later' }).offset",
+            "\"+01:00\"",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+
+    for code in [
+        "Temporal.ZonedDateTime.from('2020-03-29T02:30[Europe/Paris]', { disambiguation: 'reject' })",
+        "Temporal.ZonedDateTime.from('2020-10-25T02:30[Europe/Paris]', { disambiguation: 'reject' })",
+    ] {
+        assert_eq!(error_name(&mut context, code), "\"RangeError\"");
+    }
+}
+
+#[test]
+fn offset_option() {
+    let mut context = context();
+    for (code, expected) in [
+        // The offset selects one of the repeated wall-clock times.
+        (
+            "Temporal.ZonedDateTime.from('2020-10-25T02:30+01:00[Europe/Paris]').toString()",
+            "\"2020-10-25T02:30:00+01:00[Europe/Paris]\"",
+        ),
+        (
+            "Temporal.ZonedDateTime.from('2020-06-01T10:00Z[Europe/Paris]').toString()",
+            "\"2020-06-01T12:00:00+02:00[Europe/Paris]\"",
+        ),
+        (
+            "Temporal.ZonedDateTime.from('2020-06-01T12:00+01:00[Europe/Paris]', { offset: 'prefer' }).toString()",
+            "\"2020-06-01T12:00:00+02:00[Europe/Paris]\"",
+        ),
+        (
+            "Temporal.ZonedDateTime.from('2020-06-01T12:00+01:00[Europe/Paris]', { offset: 'use' }).toString()",
+            "\"2020-06-01T13:00:00+02:00[Europe/Paris]\"",
+        ),
+        (
+            "Temporal.ZonedDateTime.from('2020-06-01T12:00+01:00[Europe/Paris]', { offset: 'ignore' }).toString()",
+            "\"2020-06-01T12:00:00+02:00[Europe/Paris]\"",
+        ),
+        (
+            "Temporal.ZonedDateTime.from({ year: 2020, month: 10, day: 25, hour: 2, minute: 30, offset: '+01:00', timeZone: 'Europe/Paris' }).toString()",
+            "\"2020-10-25T02:30:00+01:00[Europe/Paris]\"",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+
+    for (code, expected) in [
+        (
+            "Temporal.ZonedDateTime.from('2020-06-01T12:00+01:00[Europe/Paris]')",
+            "RangeError",
+        ),
+        (
+            "Temporal.ZonedDateTime.from('2020-06-01T12:00')",
+            "RangeError",
+        ),
+        (
+            "Temporal.ZonedDateTime.from({ year: 2020, month: 6, day: 1 })",
+            "TypeError",
+        ),
+    ] {
+        assert_eq!(error_name(&mut context, code), format!("\"{expected}\""));
+    }
+}
+
+#[test]
+fn with() {
+    let mut context = context();
+    forward(
+        &mut context,
+        "var zdt = Temporal.ZonedDateTime.from('2020-10-25T01:30+02:00[Europe/Paris]');",
+    );
+    for (code, expected) in [
+        // The current offset is kept when it's still valid.
+        (
+            "zdt.with({ hour: 2 }).toString()",
+            "\"2020-10-25T02:30:00+02:00[Europe/Paris]\"",
+        ),
+        (
+            "zdt.with({ hour: 2, offset: '+01:00' }).toString()",
+            "\"2020-10-25T02:30:00+01:00[Europe/Paris]\"",
+        ),
+        (
+            "zdt.with({ day: 26 }).toString()",
+            "\"2020-10-26T01:30:00+01:00[Europe/Paris]\"",
+        ),
+        (
+            "zdt.withPlainTime('12:00').toString()",
+            "\"2020-10-25T12:00:00+01:00[Europe/Paris]\"",
+        ),
+        (
+            "zdt.withPlainTime().toString()",
+            "\"2020-10-25T00:00:00+02:00[Europe/Paris]\"",
+        ),
+        (
+            "zdt.startOfDay().toString()",
+            "\"2020-10-25T00:00:00+02:00[Europe/Paris]\"",
+        ),
+        (
+            "zdt.withTimeZone('UTC').toString()",
+            "\"2020-10-24T23:30:00+00:00[UTC]\"",
+        ),
+        (
+            "zdt.withCalendar('japanese').toString()",
+            "\"2020-10-25T01:30:00+02:00[Europe/Paris][u-ca=japanese]\"",
+        ),
+        ("zdt.withCalendar('japanese').era", "\"reiwa\""),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+
+    for (code, expected) in [
+        ("zdt.with({})", "TypeError"),
+        ("zdt.with({ timeZone: 'UTC' })", "TypeError"),
+        (
+            "zdt.with({ day: 26, offset: '+02:00' }, { offset: 'reject' })",
+            "RangeError",
+        ),
+    ] {
+        assert_eq!(error_name(&mut context, code), format!("\"{expected}\""));
+    }
+}
+
+#[test]
+fn arithmetic() {
+    let mut context = context();
+    forward(
+        &mut context,
+        "var zdt = Temporal.ZonedDateTime.from('2020-03-28T12:00[Europe/Paris]');",
+    );
+    for (code, expected) in [
+        // A day keeps the wall-clock time across the transition, 24 hours don't.
+        (
+            "zdt.add('P1D').toString()",
+            "\"2020-03-29T12:00:00+02:00[Europe/Paris]\"",
+        ),
+        (
+            "zdt.add('PT24H').toString()",
+            "\"2020-03-29T13:00:00+02:00[Europe/Paris]\"",
+        ),
+        (
+            "zdt.add('P1DT12H').toString()",
+            "\"2020-03-30T00:00:00+02:00[Europe/Paris]\"",
+        ),
+        ("zdt.add('P1D').subtract('P1D').equals(zdt)", "true"),
+        (
+            "zdt.add({ hours: 14, minutes: 30 }).toString()",
+            "\"2020-03-29T03:30:00+02:00[Europe/Paris]\"",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+}
+
+#[test]
+fn difference() {
+    let mut context = context();
+    forward(
+        &mut context,
+        "
+        var one = Temporal.ZonedDateTime.from('2020-03-28T12:00[Europe/Paris]');
+        var two = Temporal.ZonedDateTime.from('2020-03-29T12:00[Europe/Paris]');
+        ",
+    );
+    for (code, expected) in [
+        ("one.until(two).toString()", "\"PT23H\""),
+        ("one.until(two, { largestUnit: 'days' }).toString()", "\"P1D\""),
+        ("two.since(one, { largestUnit: 'days' }).toString()", "\"P1D\""),
+        ("two.until(one, { largestUnit: 'days' }).toString()", "\"-P1D\""),
+        (
+            "one.until(two.add('PT12H30M'), { largestUnit: 'days', smallestUnit: 'days' }).toString()",
+            "\"P1D\"",
+        ),
+        (
+            "one.until(two.add('PT12H30M'), { largestUnit: 'days', smallestUnit: 'days', roundingMode: 'halfExpand' }).toString()",
+            "\"P2D\"",
+        ),
+        (
+            "one.until(two.withTimeZone('UTC'), { largestUnit: 'hours' }).toString()",
+            "\"PT23H\"",
+        ),
+        ("one.until(one).toString()", "\"PT0S\""),
+        (
+            "Temporal.ZonedDateTime.compare(one, two)",
+            "-1",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+
+    assert_eq!(
+        error_name(
+            &mut context,
+            "one.until(two.withTimeZone('UTC'), { largestUnit: 'days' })"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn round() {
+    let mut context = context();
+    for (code, expected) in [
+        // The 23 hours of 2020-03-29 are rounded to the nearest start of day.
+        (
+            "Temporal.ZonedDateTime.from('2020-03-29T12:00[Europe/Paris]').round('day').toString()",
+            "\"2020-03-29T00:00:00+01:00[Europe/Paris]\"",
+        ),
+        (
+            "Temporal.ZonedDateTime.from('2020-03-29T13:00[Europe/Paris]').round('day').toString()",
+            "\"2020-03-30T00:00:00+02:00[Europe/Paris]\"",
+        ),
+        (
+            "Temporal.ZonedDateTime.from('2020-10-25T02:40+01:00[Europe/Paris]').round({ smallestUnit: 'hour' }).toString()",
+            "\"2020-10-25T03:00:00+01:00[Europe/Paris]\"",
+        ),
+        (
+            "Temporal.ZonedDateTime.from('2020-10-25T02:20+01:00[Europe/Paris]').round('hour').toString()",
+            "\"2020-10-25T02:00:00+01:00[Europe/Paris]\"",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+
+    for (code, expected) in [
+        (
+            "Temporal.ZonedDateTime.from('2020-03-29T12:00[Europe/Paris]').round('month')",
+            "RangeError",
+        ),
+        (
+            "Temporal.ZonedDateTime.from('2020-03-29T12:00[Europe/Paris]').round()",
+            "TypeError",
+        ),
+    ] {
+        assert_eq!(error_name(&mut context, code), format!("\"{expected}\""));
+    }
+}
+
+#[test]
+fn time_zone_transition() {
+    let mut context = context();
+    forward(
+        &mut context,
+        "var zdt = Temporal.ZonedDateTime.from('2020-06-01T00:00[Europe/Paris]');",
+    );
+    for (code, expected) in [
+        (
+            "zdt.getTimeZoneTransition('next').toString()",
+            "\"2020-10-25T02:00:00+01:00[Europe/Paris]\"",
+        ),
+        (
+            "zdt.getTimeZoneTransition({ direction: 'previous' }).toString()",
+            "\"2020-03-29T03:00:00+02:00[Europe/Paris]\"",
+        ),
+        (
+            "zdt.getTimeZoneTransition('previous').getTimeZoneTransition('previous')",
+            "null",
+        ),
+        (
+            "zdt.withTimeZone('+01:00').getTimeZoneTransition('next')",
+            "null",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+
+    for (code, expected) in [
+        ("zdt.getTimeZoneTransition()", "TypeError"),
+        ("zdt.getTimeZoneTransition({})", "RangeError"),
+        ("zdt.getTimeZoneTransition('up')", "RangeError"),
+    ] {
+        assert_eq!(error_name(&mut context, code), format!("\"{expected}\""));
+    }
+}
+
+#[test]
+fn to_string() {
+    let mut context = context();
+    forward(
+        &mut context,
+        "var zdt = Temporal.ZonedDateTime.from('2020-06-01T12:30:45.123456789[Europe/Paris]');",
+    );
+    for (code, expected) in [
+        (
+            "zdt.toString()",
+            "\"2020-06-01T12:30:45.123456789+02:00[Europe/Paris]\"",
+        ),
+        (
+            "zdt.toString({ smallestUnit: 'minute', offset: 'never' })",
+            "\"2020-06-01T12:30[Europe/Paris]\"",
+        ),
+        (
+            "zdt.toString({ fractionalSecondDigits: 2, roundingMode: 'halfExpand', timeZoneName: 'critical' })",
+            "\"2020-06-01T12:30:45.12+02:00[!Europe/Paris]\"",
+        ),
+        (
+            "zdt.toString({ timeZoneName: 'never', calendarName: 'always' })",
+            "\"2020-06-01T12:30:45.123456789+02:00[u-ca=iso8601]\"",
+        ),
+        (
+            "zdt.toJSON()",
+            "\"2020-06-01T12:30:45.123456789+02:00[Europe/Paris]\"",
+        ),
+        (
+            "zdt.toPlainDateTime().toString()",
+            "\"2020-06-01T12:30:45.123456789\"",
+        ),
+        ("zdt.toPlainDate().toString()", "\"2020-06-01\""),
+        ("zdt.toPlainTime().toString()", "\"12:30:45.123456789\""),
+        (
+            "zdt.toInstant().toString()",
+            "\"2020-06-01T10:30:45.123456789Z\"",
+        ),
+    ] {
+        assert_eq!(forward(&mut context, code), expected);
+    }
+
+    for (code, expected) in [
+        ("zdt.toString({ smallestUnit: 'hour' })", "RangeError"),
+        ("zdt.toString({ offset: 'always' })", "RangeError"),
+        ("zdt.valueOf()", "TypeError"),
+    ] {
+        assert_eq!(error_name(&mut context, code), format!("\"{expected}\""));
+    }
+}
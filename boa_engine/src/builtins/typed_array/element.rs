@@ -0,0 +1,90 @@
+//! Native operations on the elements of a typed array.
+//!
+//! These work directly on the bytes of the viewed `ArrayBuffer`, without converting every element
+//! to a `JsValue`. They must only be used when the operation cannot be observed from JavaScript,
+//! e.g. when sorting without a comparator.
+
+use super::TypedArrayKind;
+use std::cmp::Ordering;
+
+/// Compares two numbers the way `%TypedArray%.prototype.sort` does without a comparator.
+///
+/// `NaN` is sorted after every other number, and `-0` is sorted before `+0`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-comparetypedarrayelements
+fn compare_numbers(x: f64, y: f64) -> Ordering {
+    match (x.is_nan(), y.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => x
+            .partial_cmp(&y)
+            .expect("numbers that are not NaN are ordered")
+            .then_with(|| x.is_sign_positive().cmp(&y.is_sign_positive())),
+    }
+}
+
+/// Sorts the elements of type `kind` stored in `bytes` in ascending numeric order.
+///
+/// Elements are stored in little endian order, as done by `SetValueInBuffer`.
+pub(crate) fn sort_elements(kind: TypedArrayKind, bytes: &mut [u8]) {
+    macro_rules! sort_as {
+        ($ty:ty, |$values:ident| $sort:expr) => {{
+            let size = std::mem::size_of::<$ty>();
+            let mut $values: Vec<$ty> = bytes
+                .chunks_exact(size)
+                .map(|chunk| {
+                    <$ty>::from_le_bytes(chunk.try_into().expect("chunk has the element size"))
+                })
+                .collect();
+            $sort;
+            for (chunk, value) in bytes.chunks_exact_mut(size).zip($values) {
+                chunk.copy_from_slice(&value.to_le_bytes());
+            }
+        }};
+    }
+
+    match kind {
+        TypedArrayKind::Int8 => sort_as!(i8, |values| values.sort_unstable()),
+        TypedArrayKind::Uint8 | TypedArrayKind::Uint8Clamped => bytes.sort_unstable(),
+        TypedArrayKind::Int16 => sort_as!(i16, |values| values.sort_unstable()),
+        TypedArrayKind::Uint16 => sort_as!(u16, |values| values.sort_unstable()),
+        TypedArrayKind::Int32 => sort_as!(i32, |values| values.sort_unstable()),
+        TypedArrayKind::Uint32 => sort_as!(u32, |values| values.sort_unstable()),
+        TypedArrayKind::BigInt64 => sort_as!(i64, |values| values.sort_unstable()),
+        TypedArrayKind::BigUint64 => sort_as!(u64, |values| values.sort_unstable()),
+        // Floats that compare as equal are either the same value or NaNs with possibly different
+        // payloads, which cannot be told apart, so an unstable sort is not observable.
+        TypedArrayKind::Float32 => {
+            sort_as!(f32, |values| values.sort_unstable_by(|x, y| {
+                compare_numbers(f64::from(*x), f64::from(*y))
+            }));
+        }
+        TypedArrayKind::Float64 => {
+            sort_as!(f64, |values| values
+                .sort_unstable_by(|x, y| compare_numbers(*x, *y)));
+        }
+    }
+}
+
+/// Reverses the order of the elements of type `kind` stored in `bytes`.
+pub(crate) fn reverse_elements(kind: TypedArrayKind, bytes: &mut [u8]) {
+    let size = kind.element_size();
+    let len = bytes.len() / size;
+    for lower in 0..len / 2 {
+        let upper = len - lower - 1;
+        for byte in 0..size {
+            bytes.swap(lower * size + byte, upper * size + byte);
+        }
+    }
+}
+
+/// Sets every element stored in `bytes` to the element whose raw bytes are `raw_value`.
+pub(crate) fn fill_elements(bytes: &mut [u8], raw_value: &[u8]) {
+    for chunk in bytes.chunks_exact_mut(raw_value.len()) {
+        chunk.copy_from_slice(raw_value);
+    }
+}
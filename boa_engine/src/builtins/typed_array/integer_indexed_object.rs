@@ -35,6 +35,7 @@ pub struct IntegerIndexed {
     byte_offset: usize,
     byte_length: usize,
     array_length: usize,
+    length_tracking: bool,
 }
 
 impl IntegerIndexed {
@@ -51,6 +52,7 @@ impl IntegerIndexed {
             byte_offset,
             byte_length,
             array_length,
+            length_tracking: false,
         }
     }
 
@@ -85,25 +87,6 @@ impl IntegerIndexed {
         a
     }
 
-    /// Abstract operation `IsDetachedBuffer ( arrayBuffer )`.
-    ///
-    /// Check if `[[ArrayBufferData]]` is null.
-    ///
-    /// More information:
-    ///  - [ECMAScript reference][spec]
-    ///
-    /// [spec]: https://tc39.es/ecma262/#sec-isdetachedbuffer
-    pub(crate) fn is_detached(&self) -> bool {
-        if let Some(obj) = &self.viewed_array_buffer {
-            obj.borrow()
-                .as_array_buffer()
-                .expect("Typed array must have internal array buffer object")
-                .is_detached_buffer()
-        } else {
-            false
-        }
-    }
-
     /// Get the integer indexed object's byte offset.
     pub(crate) fn byte_offset(&self) -> usize {
         self.byte_offset
@@ -129,9 +112,67 @@ impl IntegerIndexed {
         self.viewed_array_buffer = viewed_array_buffer;
     }
 
-    /// Get the integer indexed object's byte length.
+    /// Abstract operation `IsTypedArrayOutOfBounds ( taRecord )`.
+    ///
+    /// Check if the typed array views bytes outside of its buffer, which can happen when the buffer
+    /// is detached or shrunk. Detached buffers are always out of bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-istypedarrayoutofbounds
+    pub(crate) fn is_out_of_bounds(&self) -> bool {
+        self.buffer_byte_length()
+            .map_or(true, |buffer_byte_length| {
+                self.is_out_of_bounds_of(buffer_byte_length)
+            })
+    }
+
+    /// Returns the current byte length of the viewed buffer, or `None` if it is detached.
+    fn buffer_byte_length(&self) -> Option<usize> {
+        let obj = self.viewed_array_buffer.as_ref()?.borrow();
+        let buffer = obj
+            .as_array_buffer()
+            .expect("Typed array must have internal array buffer object");
+        buffer
+            .array_buffer_data
+            .as_ref()
+            .map(|_| buffer.array_buffer_byte_length())
+    }
+
+    fn is_out_of_bounds_of(&self, buffer_byte_length: usize) -> bool {
+        // 4. Let byteOffsetStart be O.[[ByteOffset]].
+        // 5. If taRecord.[[TypedArray]].[[ArrayLength]] is auto, then
+        //     a. Let byteOffsetEnd be bufferByteLength.
+        // 6. Else,
+        //     a. Let byteOffsetEnd be byteOffsetStart + O.[[ArrayLength]] × elementSize.
+        // 7. If byteOffsetStart > bufferByteLength or byteOffsetEnd > bufferByteLength, return true.
+        // 8. Return false.
+        self.byte_offset > buffer_byte_length
+            || (!self.length_tracking && self.byte_offset + self.byte_length > buffer_byte_length)
+    }
+
+    /// Returns `true` if the length of the typed array follows the length of its resizable buffer.
+    pub(crate) fn is_length_tracking(&self) -> bool {
+        self.length_tracking
+    }
+
+    /// Makes the length of the typed array follow the length of its resizable buffer, as done when
+    /// `[[ArrayLength]]` is set to auto.
+    pub(crate) fn set_length_tracking(&mut self, length_tracking: bool) {
+        self.length_tracking = length_tracking;
+    }
+
+    /// Abstract operation `TypedArrayByteLength ( taRecord )`.
+    ///
+    /// Returns 0 if the typed array is out of bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarraybytelength
     pub fn byte_length(&self) -> usize {
-        self.byte_length
+        self.array_length() * self.typed_array_name.element_size()
     }
 
     /// Set the integer indexed object's byte length.
@@ -139,9 +180,32 @@ impl IntegerIndexed {
         self.byte_length = byte_length;
     }
 
-    /// Get the integer indexed object's array length.
+    /// Abstract operation `TypedArrayLength ( taRecord )`.
+    ///
+    /// Returns 0 if the typed array is out of bounds.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarraylength
     pub fn array_length(&self) -> usize {
-        self.array_length
+        let buffer_byte_length = match self.buffer_byte_length() {
+            Some(buffer_byte_length) if !self.is_out_of_bounds_of(buffer_byte_length) => {
+                buffer_byte_length
+            }
+            _ => return 0,
+        };
+
+        // 3. If taRecord.[[TypedArray]].[[ArrayLength]] is not auto, return O.[[ArrayLength]].
+        if !self.length_tracking {
+            return self.array_length;
+        }
+
+        // 4. Let byteOffset be O.[[ByteOffset]].
+        // 5. Let elementSize be TypedArrayElementSize(O).
+        // 6. Let byteLength be taRecord.[[CachedBufferByteLength]].
+        // 7. Return floor((byteLength - byteOffset) / elementSize).
+        (buffer_byte_length - self.byte_offset) / self.typed_array_name.element_size()
     }
 
     /// Set the integer indexed object's array length.
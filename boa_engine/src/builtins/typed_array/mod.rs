@@ -14,15 +14,18 @@
 
 use crate::{
     builtins::{
-        array_buffer::{ArrayBuffer, SharedMemoryOrder},
+        array::sort,
+        array_buffer::{shared::SharedArrayBuffer, ArrayBuffer, SharedMemoryOrder},
         iterable::iterable_to_list,
         typed_array::integer_indexed_object::{ContentType, IntegerIndexed},
         Array, ArrayIterator, BuiltIn, JsArgs,
     },
     context::intrinsics::{StandardConstructor, StandardConstructors},
     object::{
-        internal_methods::get_prototype_from_constructor, ConstructorBuilder, FunctionBuilder,
-        JsObject, ObjectData,
+        internal_methods::{
+            get_prototype_from_constructor, integer_indexed::is_valid_integer_index,
+        },
+        ConstructorBuilder, FunctionBuilder, JsObject, ObjectData,
     },
     property::{Attribute, PropertyNameKind},
     symbol::WellKnownSymbols,
@@ -36,7 +39,10 @@ use std::cmp::Ordering;
 
 use tap::{Conv, Pipe};
 
+mod element;
 pub mod integer_indexed_object;
+#[cfg(test)]
+mod tests;
 
 macro_rules! typed_array {
     ($ty:ident, $variant:ident, $name:literal, $global_object_name:ident) => {
@@ -346,6 +352,8 @@ impl BuiltIn for TypedArray {
         .method(Self::filter, "filter", 1)
         .method(Self::find, "find", 1)
         .method(Self::findindex, "findIndex", 1)
+        .method(Self::find_last, "findLast", 1)
+        .method(Self::find_last_index, "findLastIndex", 1)
         .method(Self::foreach, "forEach", 1)
         .method(Self::includes, "includes", 1)
         .method(Self::index_of, "indexOf", 1)
@@ -361,7 +369,10 @@ impl BuiltIn for TypedArray {
         .method(Self::some, "some", 1)
         .method(Self::sort, "sort", 1)
         .method(Self::subarray, "subarray", 2)
+        .method(Self::to_reversed, "toReversed", 0)
+        .method(Self::to_sorted, "toSorted", 1)
         .method(Self::values, "values", 0)
+        .method(Self::with, "with", 2)
         // 23.2.3.29 %TypedArray%.prototype.toString ( )
         // The initial value of the %TypedArray%.prototype.toString data property is the same
         // built-in function object as the Array.prototype.toString method defined in 23.1.3.30.
@@ -559,8 +570,9 @@ impl TypedArray {
         let o = obj_borrow
             .as_typed_array()
             .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if o.is_out_of_bounds() {
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -634,15 +646,10 @@ impl TypedArray {
             .as_typed_array()
             .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
 
-        // 4. Let buffer be O.[[ViewedArrayBuffer]].
-        // 5. If IsDetachedBuffer(buffer) is true, return +0𝔽.
-        // 6. Let size be O.[[ByteLength]].
-        // 7. Return 𝔽(size).
-        if typed_array.is_detached() {
-            Ok(0.into())
-        } else {
-            Ok(typed_array.byte_length().into())
-        }
+        // 4. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+        // 5. Let size be TypedArrayByteLength(taRecord).
+        // 6. Return 𝔽(size).
+        Ok(typed_array.byte_length().into())
     }
 
     /// `23.2.3.4 get %TypedArray%.prototype.byteOffset`
@@ -663,11 +670,11 @@ impl TypedArray {
             .as_typed_array()
             .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
 
-        // 4. Let buffer be O.[[ViewedArrayBuffer]].
-        // 5. If IsDetachedBuffer(buffer) is true, return +0𝔽.
+        // 4. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+        // 5. If IsTypedArrayOutOfBounds(taRecord) is true, return +0𝔽.
         // 6. Let offset be O.[[ByteOffset]].
         // 7. Return 𝔽(offset).
-        if typed_array.is_detached() {
+        if typed_array.is_out_of_bounds() {
             Ok(0.into())
         } else {
             Ok(typed_array.byte_offset().into())
@@ -693,8 +700,9 @@ impl TypedArray {
                 .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
 
             // 2. Perform ? ValidateTypedArray(O).
-            if o.is_detached() {
-                return context.throw_type_error("Buffer of the typed array is detached");
+            if o.is_out_of_bounds() {
+                return context
+                    .throw_type_error("Buffer of the typed array is detached or out of bounds");
             }
 
             // 3. Let len be O.[[ArrayLength]].
@@ -757,9 +765,10 @@ impl TypedArray {
         if count > 0 {
             // a. NOTE: The copying must be performed in a manner that preserves the bit-level encoding of the source data.
             // b. Let buffer be O.[[ViewedArrayBuffer]].
-            // c. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
-            if o.is_detached() {
-                return context.throw_type_error("Buffer of the typed array is detached");
+            // c. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+            if o.is_out_of_bounds() {
+                return context
+                    .throw_type_error("Buffer of the typed array is detached or out of bounds");
             }
 
             // d. Let typedArrayName be the String value of O.[[TypedArrayName]].
@@ -771,6 +780,9 @@ impl TypedArray {
             // f. Let byteOffset be O.[[ByteOffset]].
             let byte_offset = o.byte_offset() as i64;
 
+            // Let bufferByteLimit be (TypedArrayLength(taRecord) × elementSize) + byteOffset.
+            let buffer_byte_limit = o.array_length() as i64 * element_size + byte_offset;
+
             // g. Let toByteIndex be to × elementSize + byteOffset.
             let mut to_byte_index = to * element_size + byte_offset;
 
@@ -809,6 +821,12 @@ impl TypedArray {
 
             // l. Repeat, while countBytes > 0,
             while count_bytes > 0 {
+                // The buffer may have been shrunk by the conversions above, in which case the
+                // copy stops at the first byte out of bounds.
+                if from_byte_index >= buffer_byte_limit || to_byte_index >= buffer_byte_limit {
+                    break;
+                }
+
                 // i. Let value be GetValueFromBuffer(buffer, fromByteIndex, Uint8, true, Unordered).
                 let value = buffer.get_value_from_buffer(
                     from_byte_index as usize,
@@ -858,9 +876,10 @@ impl TypedArray {
         if o.borrow()
             .as_typed_array()
             .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?
-            .is_detached()
+            .is_out_of_bounds()
         {
-            return context.throw_type_error("Buffer of the typed array is detached");
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
 
         // 3. Return CreateArrayIterator(O, key+value).
@@ -887,8 +906,9 @@ impl TypedArray {
         let o = obj_borrow
             .as_typed_array()
             .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if o.is_out_of_bounds() {
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -946,8 +966,9 @@ impl TypedArray {
         let o = obj_borrow
             .as_typed_array()
             .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if o.is_out_of_bounds() {
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -962,7 +983,7 @@ impl TypedArray {
         };
 
         // 6. Let relativeStart be ? ToIntegerOrInfinity(start).
-        let mut k = match args.get_or_undefined(1).to_integer_or_infinity(context)? {
+        let k = match args.get_or_undefined(1).to_integer_or_infinity(context)? {
             // 7. If relativeStart is -∞, let k be 0.
             IntegerOrInfinity::NegativeInfinity => 0,
             // 8. Else if relativeStart < 0, let k be max(len + relativeStart, 0).
@@ -990,20 +1011,39 @@ impl TypedArray {
             IntegerOrInfinity::PositiveInfinity => len,
         };
 
-        // 14. If IsDetachedBuffer(O.[[ViewedArrayBuffer]]) is true, throw a TypeError exception.
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        // 14. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+        if o.is_out_of_bounds() {
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
 
-        // 15. Repeat, while k < final,
-        while k < r#final {
-            // a. Let Pk be ! ToString(𝔽(k)).
-            // b. Perform ! Set(O, Pk, value, true).
-            obj.set(k, value.clone(), true, context)
-                .expect("Set cannot fail here");
+        // Set len to TypedArrayLength(taRecord), and set final to min(final, len), as the buffer
+        // could have been shrunk.
+        let r#final = std::cmp::min(r#final, o.array_length() as i64);
 
-            // c. Set k to k + 1.
-            k += 1;
+        // 15. Repeat, while k < final,
+        //     a. Let Pk be ! ToString(𝔽(k)).
+        //     b. Perform ! Set(O, Pk, value, true).
+        //     c. Set k to k + 1.
+        // Every element gets the same value, so it is only converted to raw bytes once, and then
+        // copied directly into the viewed buffer.
+        if k < r#final {
+            let kind = o.typed_array_name();
+            let raw_value = ArrayBuffer::numeric_to_raw_bytes(kind, &value, true, context)
+                .expect("value was already converted to the element type");
+            let start = o.byte_offset() + k as usize * kind.element_size();
+            let end = o.byte_offset() + r#final as usize * kind.element_size();
+            let mut buffer = o
+                .viewed_array_buffer()
+                .expect("Already checked for detached buffer")
+                .borrow_mut();
+            let data = buffer
+                .as_array_buffer_mut()
+                .and_then(|buffer| buffer.array_buffer_data.as_mut())
+                .expect("Already checked for detached buffer");
+            data.with_bytes_mut(start..end, |bytes| {
+                element::fill_elements(bytes, &raw_value)
+            });
         }
 
         // 16. Return O.
@@ -1026,8 +1066,9 @@ impl TypedArray {
         let o = obj_borrow
             .as_typed_array()
             .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if o.is_out_of_bounds() {
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -1107,8 +1148,9 @@ impl TypedArray {
         let o = obj_borrow
             .as_typed_array()
             .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if o.is_out_of_bounds() {
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -1165,8 +1207,9 @@ impl TypedArray {
         let o = obj_borrow
             .as_typed_array()
             .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if o.is_out_of_bounds() {
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -1206,6 +1249,130 @@ impl TypedArray {
         Ok((-1).into())
     }
 
+    /// `%TypedArray%.prototype.findLast ( predicate [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.findlast
+    fn find_last(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let obj = this
+            .as_object()
+            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj_borrow = obj.borrow();
+        let o = obj_borrow
+            .as_typed_array()
+            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        if o.is_out_of_bounds() {
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
+        }
+
+        // 3. Let len be O.[[ArrayLength]].
+        let len = o.array_length();
+
+        // 4. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = match args.get_or_undefined(0).as_object() {
+            Some(obj) if obj.is_callable() => obj,
+            _ => {
+                return context.throw_type_error(
+                    "TypedArray.prototype.findLast called with non-callable predicate function",
+                )
+            }
+        };
+
+        // 5. Let k be len - 1.
+        // 6. Repeat, while k ≥ 0,
+        for k in (0..len).rev() {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Let kValue be ! Get(O, Pk).
+            let k_value = obj.get(k, context).expect("Get cannot fail here");
+
+            // c. Let testResult be ! ToBoolean(? Call(predicate, thisArg, « kValue, 𝔽(k), O »)).
+            // d. If testResult is true, return kValue.
+            if predicate
+                .call(
+                    args.get_or_undefined(1),
+                    &[k_value.clone(), k.into(), this.clone()],
+                    context,
+                )?
+                .to_boolean()
+            {
+                return Ok(k_value);
+            }
+
+            // e. Set k to k - 1.
+        }
+
+        // 7. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `%TypedArray%.prototype.findLastIndex ( predicate [ , thisArg ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.findlastindex
+    fn find_last_index(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let obj = this
+            .as_object()
+            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let obj_borrow = obj.borrow();
+        let o = obj_borrow
+            .as_typed_array()
+            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        if o.is_out_of_bounds() {
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
+        }
+
+        // 3. Let len be O.[[ArrayLength]].
+        let len = o.array_length();
+
+        // 4. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = match args.get_or_undefined(0).as_object() {
+            Some(obj) if obj.is_callable() => obj,
+            _ => return context.throw_type_error(
+                "TypedArray.prototype.findLastIndex called with non-callable predicate function",
+            ),
+        };
+
+        // 5. Let k be len - 1.
+        // 6. Repeat, while k ≥ 0,
+        for k in (0..len).rev() {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Let kValue be ! Get(O, Pk).
+            let k_value = obj.get(k, context).expect("Get cannot fail here");
+
+            // c. Let testResult be ! ToBoolean(? Call(predicate, thisArg, « kValue, 𝔽(k), O »)).
+            // d. If testResult is true, return 𝔽(k).
+            if predicate
+                .call(
+                    args.get_or_undefined(1),
+                    &[k_value, k.into(), this.clone()],
+                    context,
+                )?
+                .to_boolean()
+            {
+                return Ok(k.into());
+            }
+
+            // e. Set k to k - 1.
+        }
+
+        // 7. Return -1𝔽.
+        Ok((-1).into())
+    }
+
     /// `23.2.3.13 %TypedArray%.prototype.forEach ( callbackfn [ , thisArg ] )`
     ///
     /// More information:
@@ -1222,8 +1389,9 @@ impl TypedArray {
         let o = obj_borrow
             .as_typed_array()
             .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if o.is_out_of_bounds() {
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -1274,8 +1442,9 @@ impl TypedArray {
         let o = obj_borrow
             .as_typed_array()
             .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if o.is_out_of_bounds() {
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -1347,8 +1516,9 @@ impl TypedArray {
         let o = obj_borrow
             .as_typed_array()
             .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if o.is_out_of_bounds() {
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -1429,8 +1599,9 @@ impl TypedArray {
         let o = obj_borrow
             .as_typed_array()
             .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if o.is_out_of_bounds() {
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -1485,9 +1656,10 @@ impl TypedArray {
         if o.borrow()
             .as_typed_array()
             .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?
-            .is_detached()
+            .is_out_of_bounds()
         {
-            return context.throw_type_error("Buffer of the typed array is detached");
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
 
         // 3. Return CreateArrayIterator(O, key).
@@ -1514,8 +1686,9 @@ impl TypedArray {
         let o = obj_borrow
             .as_typed_array()
             .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if o.is_out_of_bounds() {
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -1590,15 +1763,11 @@ impl TypedArray {
             .as_typed_array()
             .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
 
-        // 4. Let buffer be O.[[ViewedArrayBuffer]].
-        // 5. If IsDetachedBuffer(buffer) is true, return +0𝔽.
-        // 6. Let length be O.[[ArrayLength]].
+        // 4. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+        // 5. If IsTypedArrayOutOfBounds(taRecord) is true, return +0𝔽.
+        // 6. Let length be TypedArrayLength(taRecord).
         // 7. Return 𝔽(length).
-        if typed_array.is_detached() {
-            Ok(0.into())
-        } else {
-            Ok(typed_array.array_length().into())
-        }
+        Ok(typed_array.array_length().into())
     }
 
     /// `23.2.3.20 %TypedArray%.prototype.map ( callbackfn [ , thisArg ] )`
@@ -1617,8 +1786,9 @@ impl TypedArray {
         let o = obj_borrow
             .as_typed_array()
             .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if o.is_out_of_bounds() {
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -1675,8 +1845,9 @@ impl TypedArray {
         let o = obj_borrow
             .as_typed_array()
             .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if o.is_out_of_bounds() {
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -1752,8 +1923,9 @@ impl TypedArray {
         let o = obj_borrow
             .as_typed_array()
             .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if o.is_out_of_bounds() {
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -1821,7 +1993,6 @@ impl TypedArray {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.reverse
-    #[allow(clippy::float_cmp)]
     fn reverse(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
         // 1. Let O be the this value.
         // 2. Perform ? ValidateTypedArray(O).
@@ -1832,45 +2003,88 @@ impl TypedArray {
         let o = obj_borrow
             .as_typed_array()
             .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if o.is_out_of_bounds() {
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
 
         // 3. Let len be O.[[ArrayLength]].
-        let len = o.array_length() as f64;
-
         // 4. Let middle be floor(len / 2).
-        let middle = (len / 2.0).floor();
-
         // 5. Let lower be 0.
-        let mut lower = 0.0;
         // 6. Repeat, while lower ≠ middle,
-        while lower != middle {
-            // a. Let upper be len - lower - 1.
-            let upper = len - lower - 1.0;
-
-            // b. Let upperP be ! ToString(𝔽(upper)).
-            // c. Let lowerP be ! ToString(𝔽(lower)).
-            // d. Let lowerValue be ! Get(O, lowerP).
-            let lower_value = obj.get(lower, context).expect("Get cannot fail here");
-            // e. Let upperValue be ! Get(O, upperP).
-            let upper_value = obj.get(upper, context).expect("Get cannot fail here");
-
-            // f. Perform ! Set(O, lowerP, upperValue, true).
-            obj.set(lower, upper_value, true, context)
-                .expect("Set cannot fail here");
-            // g. Perform ! Set(O, upperP, lowerValue, true).
-            obj.set(upper, lower_value, true, context)
-                .expect("Set cannot fail here");
-
-            // h. Set lower to lower + 1.
-            lower += 1.0;
-        }
+        //     a. Let upper be len - lower - 1.
+        //     b. Let upperP be ! ToString(𝔽(upper)).
+        //     c. Let lowerP be ! ToString(𝔽(lower)).
+        //     d. Let lowerValue be ! Get(O, lowerP).
+        //     e. Let upperValue be ! Get(O, upperP).
+        //     f. Perform ! Set(O, lowerP, upperValue, true).
+        //     g. Perform ! Set(O, upperP, lowerValue, true).
+        //     h. Set lower to lower + 1.
+        // None of the steps are observable, so the elements are swapped directly in the viewed buffer.
+        let start = o.byte_offset();
+        let end = start + o.byte_length();
+        let mut buffer = o
+            .viewed_array_buffer()
+            .expect("Already checked for detached buffer")
+            .borrow_mut();
+        let data = buffer
+            .as_array_buffer_mut()
+            .and_then(|buffer| buffer.array_buffer_data.as_mut())
+            .expect("Already checked for detached buffer");
+        data.with_bytes_mut(start..end, |bytes| {
+            element::reverse_elements(o.typed_array_name(), bytes);
+        });
 
         // 7. Return O.
         Ok(this.clone())
     }
 
+    /// `%TypedArray%.prototype.toReversed ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.toreversed
+    fn to_reversed(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let obj = this
+            .as_object()
+            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let (kind, len) = {
+            let obj_borrow = obj.borrow();
+            let o = obj_borrow
+                .as_typed_array()
+                .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+            if o.is_out_of_bounds() {
+                return context
+                    .throw_type_error("Buffer of the typed array is detached or out of bounds");
+            }
+
+            // 3. Let len be O.[[ArrayLength]].
+            (o.typed_array_name(), o.array_length())
+        };
+
+        // 4. Let A be ? TypedArrayCreateSameType(O, « 𝔽(length) »).
+        let a = Self::create_same_type(kind, &[len.into()], context)?;
+
+        // 5. Let k be 0.
+        // 6. Repeat, while k < length,
+        for k in 0..len {
+            // a. Let from be ! ToString(𝔽(length - k - 1)).
+            // b. Let Pk be ! ToString(𝔽(k)).
+            // c. Let fromValue be ! Get(O, from).
+            let from_value = obj.get(len - k - 1, context).expect("Get cannot fail here");
+            // d. Perform ! Set(A, Pk, fromValue, true).
+            a.set(k, from_value, true, context)
+                .expect("Set cannot fail here");
+            // e. Set k to k + 1.
+        }
+
+        // 7. Return A.
+        Ok(a.into())
+    }
+
     /// `23.2.3.24 %TypedArray%.prototype.set ( source [ , offset ] )`
     ///
     /// More information:
@@ -1944,8 +2158,9 @@ impl TypedArray {
 
         // 1. Let targetBuffer be target.[[ViewedArrayBuffer]].
         // 2. If IsDetachedBuffer(targetBuffer) is true, throw a TypeError exception.
-        if target_array.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if target_array.is_out_of_bounds() {
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
         let target_buffer_obj = target_array
             .viewed_array_buffer()
@@ -1956,8 +2171,9 @@ impl TypedArray {
 
         // 4. Let srcBuffer be source.[[ViewedArrayBuffer]].
         // 5. If IsDetachedBuffer(srcBuffer) is true, throw a TypeError exception.
-        if source_array.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if source_array.is_out_of_bounds() {
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
         let mut src_buffer_obj = source_array
             .viewed_array_buffer()
@@ -2010,13 +2226,16 @@ impl TypedArray {
             );
         }
 
-        // TODO: Shared Array Buffer
         // 18. If both IsSharedArrayBuffer(srcBuffer) and IsSharedArrayBuffer(targetBuffer) are true, then
-
-        // a. If srcBuffer.[[ArrayBufferData]] and targetBuffer.[[ArrayBufferData]] are the same Shared Data Block values, let same be true; else let same be false.
-
+        //     a. If srcBuffer.[[ArrayBufferData]] and targetBuffer.[[ArrayBufferData]] are the same Shared Data Block values, let same be true; else let same be false.
         // 19. Else, let same be SameValue(srcBuffer, targetBuffer).
-        let same = JsObject::equals(&src_buffer_obj, target_buffer_obj);
+        let same = match (
+            SharedArrayBuffer::data_block(&src_buffer_obj),
+            SharedArrayBuffer::data_block(target_buffer_obj),
+        ) {
+            (Some(src_data), Some(target_data)) => src_data.ptr_eq(&target_data),
+            _ => JsObject::equals(&src_buffer_obj, target_buffer_obj),
+        };
 
         // 20. If same is true, then
         let mut src_byte_index = if same {
@@ -2066,36 +2285,26 @@ impl TypedArray {
         if src_name == target_name {
             // a. NOTE: If srcType and targetType are the same, the transfer must be performed in a manner that preserves the bit-level encoding of the source data.
             // b. Repeat, while targetByteIndex < limit,
-            while target_byte_index < limit {
-                // i. Let value be GetValueFromBuffer(srcBuffer, srcByteIndex, Uint8, true, Unordered).
-                let value = src_buffer.get_value_from_buffer(
-                    src_byte_index,
-                    TypedArrayKind::Uint8,
-                    true,
-                    SharedMemoryOrder::Unordered,
-                    None,
-                );
-
-                // ii. Perform SetValueInBuffer(targetBuffer, targetByteIndex, Uint8, value, true, Unordered).
-                target_buffer_obj
-                    .borrow_mut()
-                    .as_array_buffer_mut()
-                    .expect("Must be an array buffer")
-                    .set_value_in_buffer(
-                        target_byte_index,
-                        TypedArrayKind::Uint8,
-                        &value,
-                        SharedMemoryOrder::Unordered,
-                        None,
-                        context,
-                    )?;
-
-                // iii. Set srcByteIndex to srcByteIndex + 1.
-                src_byte_index += 1;
-
-                // iv. Set targetByteIndex to targetByteIndex + 1.
-                target_byte_index += 1;
-            }
+            //     i. Let value be GetValueFromBuffer(srcBuffer, srcByteIndex, Uint8, true, Unordered).
+            //     ii. Perform SetValueInBuffer(targetBuffer, targetByteIndex, Uint8, value, true, Unordered).
+            //     iii. Set srcByteIndex to srcByteIndex + 1.
+            //     iv. Set targetByteIndex to targetByteIndex + 1.
+            // srcBuffer was cloned above if it was the same as targetBuffer, so the bytes can be
+            // copied directly between the two buffers.
+            let byte_count = limit - target_byte_index;
+            let src_data = src_buffer
+                .array_buffer_data
+                .as_ref()
+                .expect("Already checked for detached buffer");
+            let mut target_buffer = target_buffer_obj.borrow_mut();
+            let target_data = target_buffer
+                .as_array_buffer_mut()
+                .and_then(|buffer| buffer.array_buffer_data.as_mut())
+                .expect("Already checked for detached buffer");
+            target_data.write(
+                target_byte_index,
+                &src_data.bytes(src_byte_index..src_byte_index + byte_count),
+            );
         }
         // 25. Else,
         else {
@@ -2154,8 +2363,9 @@ impl TypedArray {
 
         // 1. Let targetBuffer be target.[[ViewedArrayBuffer]].
         // 2. If IsDetachedBuffer(targetBuffer) is true, throw a TypeError exception.
-        if target_array.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if target_array.is_out_of_bounds() {
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
 
         // 3. Let targetLength be target.[[ArrayLength]].
@@ -2230,14 +2440,17 @@ impl TypedArray {
             }
 
             // f. Perform SetValueInBuffer(targetBuffer, targetByteIndex, targetType, value, true, Unordered).
-            target_buffer.set_value_in_buffer(
-                target_byte_index,
-                target_name,
-                &value,
-                SharedMemoryOrder::Unordered,
-                None,
-                context,
-            )?;
+            // Values are ignored if the conversions above have shrunk the buffer past the element.
+            if target_byte_index + target_element_size <= target_buffer.array_buffer_byte_length() {
+                target_buffer.set_value_in_buffer(
+                    target_byte_index,
+                    target_name,
+                    &value,
+                    SharedMemoryOrder::Unordered,
+                    None,
+                    context,
+                )?;
+            }
 
             // g. Set k to k + 1.
             k += 1;
@@ -2265,8 +2478,9 @@ impl TypedArray {
         let o = obj_borrow
             .as_typed_array()
             .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if o.is_out_of_bounds() {
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -2313,11 +2527,16 @@ impl TypedArray {
 
         // 14. If count > 0, then
         if count > 0 {
-            // a. If IsDetachedBuffer(O.[[ViewedArrayBuffer]]) is true, throw a TypeError exception.
-            if o.is_detached() {
-                return context.throw_type_error("Buffer of the typed array is detached");
+            // a. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+            if o.is_out_of_bounds() {
+                return context
+                    .throw_type_error("Buffer of the typed array is detached or out of bounds");
             }
 
+            // Set final to min(final, TypedArrayLength(taRecord)), as the buffer could have been
+            // shrunk.
+            let r#final = std::cmp::min(r#final, o.array_length() as i64);
+
             // b. Let srcName be the String value of O.[[TypedArrayName]].
             // c. Let srcType be the Element Type value in Table 73 for srcName.
             // d. Let targetName be the String value of A.[[TypedArrayName]].
@@ -2375,8 +2594,9 @@ impl TypedArray {
                 // vii. Let srcByteIndex be (k × elementSize) + srcByteOffset.
                 let mut src_byte_index = k as usize * element_size + src_byte_offset;
 
-                // viii. Let limit be targetByteIndex + count × elementSize.
-                let limit = target_byte_index + count * element_size;
+                // viii. Let limit be targetByteIndex + min(count, final - k) × elementSize.
+                let limit =
+                    target_byte_index + std::cmp::max(r#final - k, 0) as usize * element_size;
 
                 // ix. Repeat, while targetByteIndex < limit,
                 while target_byte_index < limit {
@@ -2430,8 +2650,9 @@ impl TypedArray {
         let o = obj_borrow
             .as_typed_array()
             .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if o.is_out_of_bounds() {
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
 
         // 3. Let len be O.[[ArrayLength]].
@@ -2496,26 +2717,42 @@ impl TypedArray {
 
         // 4. Let buffer be obj.[[ViewedArrayBuffer]].
         // 5. Let len be obj.[[ArrayLength]].
-        let (buffer, len) = {
+        let (kind, buffer, byte_offset, len) = {
             // 3. Perform ? ValidateTypedArray(obj).
             let obj_borrow = obj.borrow();
             let o = obj_borrow.as_typed_array().ok_or_else(|| {
                 context.construct_type_error("TypedArray.sort must be called on typed array object")
             })?;
-            if o.is_detached() {
+            if o.is_out_of_bounds() {
                 return context.throw_type_error(
                     "TypedArray.sort called on typed array object with detached array buffer",
                 );
             }
 
             (
+                o.typed_array_name(),
                 o.viewed_array_buffer()
                     .expect("Already checked for detached buffer")
                     .clone(),
+                o.byte_offset(),
                 o.array_length(),
             )
         };
 
+        // Without a comparator the sort is not observable, so the elements are sorted directly
+        // in the viewed buffer.
+        if compare_fn.is_none() {
+            let start = byte_offset;
+            let end = start + len * kind.element_size();
+            let mut buffer = buffer.borrow_mut();
+            let data = buffer
+                .as_array_buffer_mut()
+                .and_then(|buffer| buffer.array_buffer_data.as_mut())
+                .expect("Already checked for detached buffer");
+            data.with_bytes_mut(start..end, |bytes| element::sort_elements(kind, bytes));
+            return Ok(obj.clone().into());
+        }
+
         // 4. Let items be a new empty List.
         let mut items = Vec::with_capacity(len);
 
@@ -2537,129 +2774,12 @@ impl TypedArray {
         // 7. Let itemCount be the number of elements in items.
         let item_count = items.len();
 
-        let sort_compare = |x: &JsValue,
-                            y: &JsValue,
-                            compare_fn: Option<&JsObject>,
-                            context: &mut Context|
-         -> JsResult<Ordering> {
-            // 1. Assert: Both Type(x) and Type(y) are Number or both are BigInt.
-            // 2. If comparefn is not undefined, then
-            if let Some(obj) = compare_fn {
-                // a. Let v be ? ToNumber(? Call(comparefn, undefined, « x, y »)).
-                let v = obj
-                    .call(&JsValue::undefined(), &[x.clone(), y.clone()], context)?
-                    .to_number(context)?;
-
-                // b. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
-                if buffer
-                    .borrow()
-                    .as_array_buffer()
-                    .expect("Must be array buffer")
-                    .is_detached_buffer()
-                {
-                    return context
-                        .throw_type_error("Cannot sort typed array with detached buffer");
-                }
-
-                // c. If v is NaN, return +0𝔽.
-                // d. Return v.
-                return Ok(v.partial_cmp(&0.0).unwrap_or(Ordering::Equal));
-            }
-
-            if let (JsValue::BigInt(x), JsValue::BigInt(y)) = (x, y) {
-                // 6. If x < y, return -1𝔽.
-                if x < y {
-                    return Ok(Ordering::Less);
-                }
-
-                // 7. If x > y, return 1𝔽.
-                if x > y {
-                    return Ok(Ordering::Greater);
-                }
-
-                // 8. If x is -0𝔽 and y is +0𝔽, return -1𝔽.
-                if x.is_zero()
-                    && y.is_zero()
-                    && x.as_inner().is_negative()
-                    && y.as_inner().is_positive()
-                {
-                    return Ok(Ordering::Less);
-                }
-
-                // 9. If x is +0𝔽 and y is -0𝔽, return 1𝔽.
-                if x.is_zero()
-                    && y.is_zero()
-                    && x.as_inner().is_positive()
-                    && y.as_inner().is_negative()
-                {
-                    return Ok(Ordering::Greater);
-                }
-
-                // 10. Return +0𝔽.
-                Ok(Ordering::Equal)
-            } else {
-                let x = x
-                    .as_number()
-                    .expect("Typed array can only contain number or bigint");
-                let y = y
-                    .as_number()
-                    .expect("Typed array can only contain number or bigint");
-
-                // 3. If x and y are both NaN, return +0𝔽.
-                if x.is_nan() && y.is_nan() {
-                    return Ok(Ordering::Equal);
-                }
-
-                // 4. If x is NaN, return 1𝔽.
-                if x.is_nan() {
-                    return Ok(Ordering::Greater);
-                }
-
-                // 5. If y is NaN, return -1𝔽.
-                if y.is_nan() {
-                    return Ok(Ordering::Less);
-                }
-
-                // 6. If x < y, return -1𝔽.
-                if x < y {
-                    return Ok(Ordering::Less);
-                }
-
-                // 7. If x > y, return 1𝔽.
-                if x > y {
-                    return Ok(Ordering::Greater);
-                }
-
-                // 8. If x is -0𝔽 and y is +0𝔽, return -1𝔽.
-                if x.is_zero() && y.is_zero() && x.is_sign_negative() && y.is_sign_positive() {
-                    return Ok(Ordering::Less);
-                }
-
-                // 9. If x is +0𝔽 and y is -0𝔽, return 1𝔽.
-                if x.is_zero() && y.is_zero() && x.is_sign_positive() && y.is_sign_negative() {
-                    return Ok(Ordering::Greater);
-                }
-
-                // 10. Return +0𝔽.
-                Ok(Ordering::Equal)
-            }
-        };
-
         // 8. Sort items using an implementation-defined sequence of calls to SortCompare.
         // If any such call returns an abrupt completion, stop before performing any further
         // calls to SortCompare or steps in this algorithm and return that completion.
-        let mut sort_err = Ok(());
-        items.sort_by(|x, y| {
-            if sort_err.is_ok() {
-                sort_compare(x, y, compare_fn, context).unwrap_or_else(|err| {
-                    sort_err = Err(err);
-                    Ordering::Equal
-                })
-            } else {
-                Ordering::Equal
-            }
-        });
-        sort_err?;
+        sort::timsort_by(&mut items, |x, y| {
+            Self::compare_typed_array_elements(x, y, compare_fn, &buffer, context)
+        })?;
 
         // 9. Let j be 0.
         // 10. Repeat, while j < itemCount,
@@ -2680,6 +2800,231 @@ impl TypedArray {
         Ok(obj.clone().into())
     }
 
+    /// `%TypedArray%.prototype.toSorted ( comparefn )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.tosorted
+    fn to_sorted(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. If comparefn is not undefined and IsCallable(comparefn) is false, throw a TypeError exception.
+        let compare_fn = match args.get(0) {
+            None | Some(JsValue::Undefined) => None,
+            Some(JsValue::Object(obj)) if obj.is_callable() => Some(obj),
+            _ => {
+                return context
+                    .throw_type_error("TypedArray.toSorted called with non-callable comparefn")
+            }
+        };
+
+        // 2. Let O be the this value.
+        let obj = this.as_object().ok_or_else(|| {
+            context.construct_type_error("TypedArray.toSorted must be called on typed array object")
+        })?;
+
+        let (kind, buffer, byte_offset, len) = {
+            // 3. Perform ? ValidateTypedArray(O).
+            let obj_borrow = obj.borrow();
+            let o = obj_borrow.as_typed_array().ok_or_else(|| {
+                context.construct_type_error(
+                    "TypedArray.toSorted must be called on typed array object",
+                )
+            })?;
+            if o.is_out_of_bounds() {
+                return context.throw_type_error(
+                    "TypedArray.toSorted called on typed array object with detached array buffer",
+                );
+            }
+
+            // 4. Let len be O.[[ArrayLength]].
+            (
+                o.typed_array_name(),
+                o.viewed_array_buffer()
+                    .expect("Already checked for detached buffer")
+                    .clone(),
+                o.byte_offset(),
+                o.array_length(),
+            )
+        };
+
+        // 5. Let A be ? TypedArrayCreateSameType(O, « 𝔽(len) »).
+        let a = Self::create_same_type(kind, &[len.into()], context)?;
+
+        // Without a comparator the sort is not observable, so the elements are copied to the
+        // buffer of A and sorted there directly.
+        if compare_fn.is_none() {
+            {
+                let byte_length = len * kind.element_size();
+                let a_borrow = a.borrow();
+                let a_array = a_borrow
+                    .as_typed_array()
+                    .expect("TypedArrayCreateSameType must return a typed array");
+                let mut target = a_array
+                    .viewed_array_buffer()
+                    .expect("A new typed array cannot be detached")
+                    .borrow_mut();
+                let target_data = target
+                    .as_array_buffer_mut()
+                    .and_then(|buffer| buffer.array_buffer_data.as_mut())
+                    .expect("A new typed array cannot be detached");
+
+                let source = buffer.borrow();
+                let source_data = source
+                    .as_array_buffer()
+                    .and_then(|buffer| buffer.array_buffer_data.as_ref())
+                    .expect("Already checked for detached buffer");
+                let mut bytes = source_data
+                    .bytes(byte_offset..byte_offset + byte_length)
+                    .into_owned();
+
+                element::sort_elements(kind, &mut bytes);
+                target_data.write(a_array.byte_offset(), &bytes);
+            }
+            return Ok(a.into());
+        }
+
+        // 6. NOTE: The following closure performs a numeric comparison rather than the string comparison used in 23.1.3.30.
+        // 7. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparefn and performs the following steps when called:
+        // 8. Let sortedList be ? SortIndexedProperties(O, len, SortCompare, read-through-holes).
+        let mut items = Vec::with_capacity(len);
+        for k in 0..len {
+            items.push(obj.get(k, context).expect("Get cannot fail here"));
+        }
+
+        sort::timsort_by(&mut items, |x, y| {
+            Self::compare_typed_array_elements(x, y, compare_fn, &buffer, context)
+        })?;
+
+        // 9. Let j be 0.
+        // 10. Repeat, while j < len,
+        for (j, item) in items.into_iter().enumerate() {
+            // a. Perform ! Set(A, ! ToString(𝔽(j)), sortedList[j], true).
+            a.set(j, item, true, context).expect("Set cannot fail here");
+            // b. Set j to j + 1.
+        }
+
+        // 11. Return A.
+        Ok(a.into())
+    }
+
+    /// Abstract operation `CompareTypedArrayElements ( x, y, comparefn )`
+    ///
+    /// `buffer` is the viewed array buffer of the typed array being sorted, which is checked for
+    /// detachment after calling `comparefn`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-comparetypedarrayelements
+    fn compare_typed_array_elements(
+        x: &JsValue,
+        y: &JsValue,
+        comparefn: Option<&JsObject>,
+        buffer: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<Ordering> {
+        // 1. Assert: Both Type(x) and Type(y) are Number or both are BigInt.
+        // 2. If comparefn is not undefined, then
+        if let Some(obj) = comparefn {
+            // a. Let v be ? ToNumber(? Call(comparefn, undefined, « x, y »)).
+            let v = obj
+                .call(&JsValue::undefined(), &[x.clone(), y.clone()], context)?
+                .to_number(context)?;
+
+            // b. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
+            if buffer
+                .borrow()
+                .as_array_buffer()
+                .expect("Must be array buffer")
+                .is_detached_buffer()
+            {
+                return context.throw_type_error("Cannot sort typed array with detached buffer");
+            }
+
+            // c. If v is NaN, return +0𝔽.
+            // d. Return v.
+            return Ok(v.partial_cmp(&0.0).unwrap_or(Ordering::Equal));
+        }
+
+        if let (JsValue::BigInt(x), JsValue::BigInt(y)) = (x, y) {
+            // 6. If x < y, return -1𝔽.
+            if x < y {
+                return Ok(Ordering::Less);
+            }
+
+            // 7. If x > y, return 1𝔽.
+            if x > y {
+                return Ok(Ordering::Greater);
+            }
+
+            // 8. If x is -0𝔽 and y is +0𝔽, return -1𝔽.
+            if x.is_zero()
+                && y.is_zero()
+                && x.as_inner().is_negative()
+                && y.as_inner().is_positive()
+            {
+                return Ok(Ordering::Less);
+            }
+
+            // 9. If x is +0𝔽 and y is -0𝔽, return 1𝔽.
+            if x.is_zero()
+                && y.is_zero()
+                && x.as_inner().is_positive()
+                && y.as_inner().is_negative()
+            {
+                return Ok(Ordering::Greater);
+            }
+
+            // 10. Return +0𝔽.
+            Ok(Ordering::Equal)
+        } else {
+            let x = x
+                .as_number()
+                .expect("Typed array can only contain number or bigint");
+            let y = y
+                .as_number()
+                .expect("Typed array can only contain number or bigint");
+
+            // 3. If x and y are both NaN, return +0𝔽.
+            if x.is_nan() && y.is_nan() {
+                return Ok(Ordering::Equal);
+            }
+
+            // 4. If x is NaN, return 1𝔽.
+            if x.is_nan() {
+                return Ok(Ordering::Greater);
+            }
+
+            // 5. If y is NaN, return -1𝔽.
+            if y.is_nan() {
+                return Ok(Ordering::Less);
+            }
+
+            // 6. If x < y, return -1𝔽.
+            if x < y {
+                return Ok(Ordering::Less);
+            }
+
+            // 7. If x > y, return 1𝔽.
+            if x > y {
+                return Ok(Ordering::Greater);
+            }
+
+            // 8. If x is -0𝔽 and y is +0𝔽, return -1𝔽.
+            if x.is_zero() && y.is_zero() && x.is_sign_negative() && y.is_sign_positive() {
+                return Ok(Ordering::Less);
+            }
+
+            // 9. If x is +0𝔽 and y is -0𝔽, return 1𝔽.
+            if x.is_zero() && y.is_zero() && x.is_sign_positive() && y.is_sign_negative() {
+                return Ok(Ordering::Greater);
+            }
+
+            // 10. Return +0𝔽.
+            Ok(Ordering::Equal)
+        }
+    }
+
     /// `23.2.3.28 %TypedArray%.prototype.subarray ( begin, end )`
     ///
     /// More information:
@@ -2703,7 +3048,11 @@ impl TypedArray {
             .viewed_array_buffer()
             .expect("Buffer cannot be detached here");
 
-        // 5. Let srcLength be O.[[ArrayLength]].
+        // 5. Let srcRecord be MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+        // 6. If IsTypedArrayOutOfBounds(srcRecord) is true, then
+        //     a. Let srcLength be 0.
+        // 7. Else,
+        //     a. Let srcLength be TypedArrayLength(srcRecord).
         let src_length = o.array_length() as i64;
 
         // 6. Let relativeBegin be ? ToIntegerOrInfinity(begin).
@@ -2735,9 +3084,6 @@ impl TypedArray {
             IntegerOrInfinity::PositiveInfinity => src_length,
         };
 
-        // 14. Let newLength be max(endIndex - beginIndex, 0).
-        let new_length = std::cmp::max(end_index - begin_index, 0);
-
         // 15. Let constructorName be the String value of O.[[TypedArrayName]].
         // 16. Let elementSize be the Element Size value specified in Table 73 for constructorName.
         let element_size = o.typed_array_name().element_size();
@@ -2748,19 +3094,26 @@ impl TypedArray {
         // 18. Let beginByteOffset be srcByteOffset + beginIndex × elementSize.
         let begin_byte_offset = src_byte_offset + begin_index as usize * element_size;
 
-        // 19. Let argumentsList be « buffer, 𝔽(beginByteOffset), 𝔽(newLength) ».
-        // 20. Return ? TypedArraySpeciesCreate(O, argumentsList).
-        Ok(Self::species_create(
-            obj,
-            o.typed_array_name(),
-            &[
+        // 19. If O.[[ArrayLength]] is auto and end is undefined, then
+        let arguments_list = if o.is_length_tracking() && end.is_undefined() {
+            // a. Let argumentsList be « buffer, 𝔽(beginByteOffset) ».
+            vec![buffer.clone().into(), begin_byte_offset.into()]
+        }
+        // 20. Else,
+        else {
+            // a. Let newLength be max(endIndex - beginIndex, 0).
+            let new_length = std::cmp::max(end_index - begin_index, 0);
+
+            // b. Let argumentsList be « buffer, 𝔽(beginByteOffset), 𝔽(newLength) ».
+            vec![
                 buffer.clone().into(),
                 begin_byte_offset.into(),
                 new_length.into(),
-            ],
-            context,
-        )?
-        .into())
+            ]
+        };
+
+        // 21. Return ? TypedArraySpeciesCreate(O, argumentsList).
+        Ok(Self::species_create(obj, o.typed_array_name(), &arguments_list, context)?.into())
     }
 
     // TODO: 23.2.3.29 %TypedArray%.prototype.toLocaleString ( [ reserved1 [ , reserved2 ] ] )
@@ -2780,9 +3133,10 @@ impl TypedArray {
         if o.borrow()
             .as_typed_array()
             .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?
-            .is_detached()
+            .is_out_of_bounds()
         {
-            return context.throw_type_error("Buffer of the typed array is detached");
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
 
         // 3. Return CreateArrayIterator(O, value).
@@ -2793,6 +3147,81 @@ impl TypedArray {
         ))
     }
 
+    /// `%TypedArray%.prototype.with ( index, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.with
+    fn with(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateTypedArray(O).
+        let obj = this
+            .as_object()
+            .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+        let (kind, len) = {
+            let obj_borrow = obj.borrow();
+            let o = obj_borrow
+                .as_typed_array()
+                .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
+            if o.is_out_of_bounds() {
+                return context
+                    .throw_type_error("Buffer of the typed array is detached or out of bounds");
+            }
+
+            // 3. Let len be O.[[ArrayLength]].
+            (o.typed_array_name(), o.array_length())
+        };
+
+        // 4. Let relativeIndex be ? ToIntegerOrInfinity(index).
+        let relative_index = args.get_or_undefined(0).to_integer_or_infinity(context)?;
+
+        let actual_index = match relative_index {
+            // 5. If relativeIndex ≥ 0, let actualIndex be relativeIndex.
+            IntegerOrInfinity::Integer(i) if i >= 0 => Some(i as u64),
+            // 6. Else, let actualIndex be len + relativeIndex.
+            IntegerOrInfinity::Integer(i) => (len as u64).checked_sub(i.unsigned_abs()),
+            IntegerOrInfinity::PositiveInfinity | IntegerOrInfinity::NegativeInfinity => None,
+        };
+
+        // 7. If O.[[ContentType]] is BigInt, let numericValue be ? ToBigInt(value).
+        let numeric_value: JsValue = if kind.content_type() == ContentType::BigInt {
+            args.get_or_undefined(1).to_bigint(context)?.into()
+        // 8. Else, let numericValue be ? ToNumber(value).
+        } else {
+            args.get_or_undefined(1).to_number(context)?.into()
+        };
+
+        // 9. If IsValidIntegerIndex(O, 𝔽(actualIndex)) is false, throw a RangeError exception.
+        let actual_index = match actual_index {
+            Some(index) if is_valid_integer_index(obj, index as usize) => index as usize,
+            _ => return context.throw_range_error("TypedArray.with index out of range"),
+        };
+
+        // 10. Let A be ? TypedArrayCreateSameType(O, « 𝔽(len) »).
+        let a = Self::create_same_type(kind, &[len.into()], context)?;
+
+        // 11. Let k be 0.
+        // 12. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. If k is actualIndex, let fromValue be numericValue.
+            let from_value = if k == actual_index {
+                numeric_value.clone()
+            // c. Else, let fromValue be ! Get(O, Pk).
+            } else {
+                obj.get(k, context).expect("Get cannot fail here")
+            };
+            // d. Perform ! Set(A, Pk, fromValue, true).
+            a.set(k, from_value, true, context)
+                .expect("Set cannot fail here");
+            // e. Set k to k + 1.
+        }
+
+        // 13. Return A.
+        Ok(a.into())
+    }
+
     /// `23.2.3.33 get %TypedArray%.prototype [ @@toStringTag ]`
     ///
     /// More information:
@@ -2830,19 +3259,7 @@ impl TypedArray {
         context: &mut Context,
     ) -> JsResult<JsObject> {
         // 1. Let defaultConstructor be the intrinsic object listed in column one of Table 73 for exemplar.[[TypedArrayName]].
-        let default_constructor = match typed_array_name {
-            TypedArrayKind::Int8 => StandardConstructors::typed_int8_array,
-            TypedArrayKind::Uint8 => StandardConstructors::typed_uint8_array,
-            TypedArrayKind::Uint8Clamped => StandardConstructors::typed_uint8clamped_array,
-            TypedArrayKind::Int16 => StandardConstructors::typed_int16_array,
-            TypedArrayKind::Uint16 => StandardConstructors::typed_uint16_array,
-            TypedArrayKind::Int32 => StandardConstructors::typed_int32_array,
-            TypedArrayKind::Uint32 => StandardConstructors::typed_uint32_array,
-            TypedArrayKind::BigInt64 => StandardConstructors::typed_bigint64_array,
-            TypedArrayKind::BigUint64 => StandardConstructors::typed_biguint64_array,
-            TypedArrayKind::Float32 => StandardConstructors::typed_float32_array,
-            TypedArrayKind::Float64 => StandardConstructors::typed_float64_array,
-        };
+        let default_constructor = typed_array_name.standard_constructor();
 
         // 2. Let constructor be ? SpeciesConstructor(exemplar, defaultConstructor).
         let constructor = exemplar.species_constructor(default_constructor, context)?;
@@ -2868,6 +3285,29 @@ impl TypedArray {
         Ok(result)
     }
 
+    /// `TypedArrayCreateSameType ( exemplar, argumentList )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarray-create-same-type
+    fn create_same_type(
+        typed_array_name: TypedArrayKind,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Let constructor be the intrinsic object listed in column one of Table 71 for exemplar.[[TypedArrayName]].
+        let constructor =
+            typed_array_name.standard_constructor()(context.intrinsics().constructors())
+                .constructor();
+
+        // 2. Let result be ? TypedArrayCreate(constructor, argumentList).
+        // 3. Assert: result has [[TypedArrayName]] and [[ContentType]] internal slots.
+        // 4. Assert: result.[[ContentType]] is exemplar.[[ContentType]].
+        // 5. Return result.
+        Self::create(&constructor, args, context)
+    }
+
     /// `23.2.4.2 TypedArrayCreate ( constructor, argumentList )`
     ///
     /// More information:
@@ -2890,8 +3330,9 @@ impl TypedArray {
         let o = obj_borrow
             .as_typed_array()
             .ok_or_else(|| context.construct_type_error("Value is not a typed array object"))?;
-        if o.is_detached() {
-            return context.throw_type_error("Buffer of the typed array is detached");
+        if o.is_out_of_bounds() {
+            return context
+                .throw_type_error("Buffer of the typed array is detached or out of bounds");
         }
 
         // 3. If argumentList is a List of a single Number, then
@@ -2934,6 +3375,7 @@ impl TypedArray {
                 .constructor()
                 .into(),
             byte_length,
+            None,
             context,
         )?;
 
@@ -3047,7 +3489,7 @@ impl TypedArray {
 
         // 1. Let srcData be srcArray.[[ViewedArrayBuffer]].
         // 2. If IsDetachedBuffer(srcData) is true, throw a TypeError exception.
-        if src_array.is_detached() {
+        if src_array.is_out_of_bounds() {
             return context.throw_type_error("Cannot initialize typed array from detached buffer");
         }
         let src_data_obj = src_array
@@ -3074,12 +3516,19 @@ impl TypedArray {
         let byte_length = constructor_name.element_size() * element_length;
 
         // 12. If IsSharedArrayBuffer(srcData) is false, then
-        // a. Let bufferConstructor be ? SpeciesConstructor(srcData, %ArrayBuffer%).
-        // TODO: Shared Array Buffer
+        let buffer_constructor = if SharedArrayBuffer::data_block(src_data_obj).is_none() {
+            // a. Let bufferConstructor be ? SpeciesConstructor(srcData, %ArrayBuffer%).
+            src_data_obj.species_constructor(StandardConstructors::array_buffer, context)?
+        }
         // 13. Else,
-        // a. Let bufferConstructor be %ArrayBuffer%.
-        let buffer_constructor =
-            src_data_obj.species_constructor(StandardConstructors::array_buffer, context)?;
+        else {
+            // a. Let bufferConstructor be %ArrayBuffer%.
+            context
+                .intrinsics()
+                .constructors()
+                .array_buffer()
+                .constructor()
+        };
 
         let src_data_obj_b = src_data_obj.borrow();
         let src_data = src_data_obj_b
@@ -3098,7 +3547,8 @@ impl TypedArray {
         // 15. Else,
         } else {
             // a. Let data be ? AllocateArrayBuffer(bufferConstructor, byteLength).
-            let data_obj = ArrayBuffer::allocate(&buffer_constructor.into(), byte_length, context)?;
+            let data_obj =
+                ArrayBuffer::allocate(&buffer_constructor.into(), byte_length, None, context)?;
             let mut data_obj_b = data_obj.borrow_mut();
             let data = data_obj_b
                 .as_array_buffer_mut()
@@ -3201,52 +3651,76 @@ impl TypedArray {
             return context.throw_range_error("Invalid length for typed array");
         }
 
+        // 5. Let bufferIsFixedLength be IsFixedLengthArrayBuffer(buffer).
+        let buffer_is_fixed_length = buffer
+            .borrow()
+            .as_array_buffer()
+            .expect("This must be an ArrayBuffer")
+            .is_fixed_length();
+
+        // 6. If length is not undefined, then
+        //     a. Let newLength be ? ToIndex(length).
+        let new_length = if length.is_undefined() {
+            None
+        } else {
+            Some(length.to_index(context)?)
+        };
+
         let buffer_byte_length = {
             let buffer_obj_b = buffer.borrow();
             let buffer_array = buffer_obj_b
                 .as_array_buffer()
                 .expect("This must be an ArrayBuffer");
 
-            // 6. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
+            // 7. If IsDetachedBuffer(buffer) is true, throw a TypeError exception.
             if buffer_array.is_detached_buffer() {
                 return context
                     .throw_type_error("Cannot construct typed array from detached buffer");
             }
 
-            // 7. Let bufferByteLength be buffer.[[ArrayBufferByteLength]].
+            // 8. Let bufferByteLength be ArrayBufferByteLength(buffer, seq-cst).
             buffer_array.array_buffer_byte_length()
         };
 
-        // 8. If length is undefined, then
-        let new_byte_length = if length.is_undefined() {
-            // a. If bufferByteLength modulo elementSize ≠ 0, throw a RangeError exception.
-            if buffer_byte_length % constructor_name.element_size() != 0 {
-                return context.throw_range_error("Invalid length for typed array");
+        let length_tracking = new_length.is_none() && !buffer_is_fixed_length;
+
+        // 9. If length is undefined and bufferIsFixedLength is false, then
+        let new_byte_length = if length_tracking {
+            // a. If offset > bufferByteLength, throw a RangeError exception.
+            if offset > buffer_byte_length {
+                return context.throw_range_error("Invalid offset for typed array");
             }
 
-            // b. Let newByteLength be bufferByteLength - offset.
-            let new_byte_length = buffer_byte_length as isize - offset as isize;
+            // b. Set O.[[ByteLength]] to auto.
+            // c. Set O.[[ArrayLength]] to auto.
+            buffer_byte_length - offset
+        }
+        // 10. Else,
+        else if let Some(new_length) = new_length {
+            // b. Else,
+            //     i. Let newByteLength be newLength × elementSize.
+            let new_byte_length = new_length * constructor_name.element_size();
 
-            // c. If newByteLength < 0, throw a RangeError exception.
-            if new_byte_length < 0 {
+            //     ii. If offset + newByteLength > bufferByteLength, throw a RangeError exception.
+            if offset + new_byte_length > buffer_byte_length {
                 return context.throw_range_error("Invalid length for typed array");
             }
 
-            new_byte_length as usize
-        // 9. Else,
+            new_byte_length
         } else {
-            // 5. If length is not undefined, then
-            // a. Let newLength be ? ToIndex(length).
-
-            // a. Let newByteLength be newLength × elementSize.
-            let new_byte_length = length.to_index(context)? * constructor_name.element_size();
+            // a. If length is undefined, then
+            //     i. If bufferByteLength modulo elementSize ≠ 0, throw a RangeError exception.
+            if buffer_byte_length % constructor_name.element_size() != 0 {
+                return context.throw_range_error("Invalid length for typed array");
+            }
 
-            // b. If offset + newByteLength > bufferByteLength, throw a RangeError exception.
-            if offset + new_byte_length > buffer_byte_length {
+            //     ii. Let newByteLength be bufferByteLength - offset.
+            //     iii. If newByteLength < 0, throw a RangeError exception.
+            if offset > buffer_byte_length {
                 return context.throw_range_error("Invalid length for typed array");
             }
 
-            new_byte_length
+            buffer_byte_length - offset
         };
 
         let mut o_obj_borrow = o.borrow_mut();
@@ -3254,14 +3728,15 @@ impl TypedArray {
             .as_typed_array_mut()
             .expect("This must be an ArrayBuffer");
 
-        // 10. Set O.[[ViewedArrayBuffer]] to buffer.
-        o.set_viewed_array_buffer(Some(buffer));
-        // 11. Set O.[[ByteLength]] to newByteLength.
+        // 10. c. Set O.[[ByteLength]] to newByteLength.
+        //     d. Set O.[[ArrayLength]] to newByteLength / elementSize.
+        o.set_length_tracking(length_tracking);
         o.set_byte_length(new_byte_length);
+        o.set_array_length(new_byte_length / constructor_name.element_size());
+        // 11. Set O.[[ViewedArrayBuffer]] to buffer.
+        o.set_viewed_array_buffer(Some(buffer));
         // 12. Set O.[[ByteOffset]] to offset.
         o.set_byte_offset(offset);
-        // 13. Set O.[[ArrayLength]] to newByteLength / elementSize.
-        o.set_array_length(new_byte_length / constructor_name.element_size());
 
         Ok(())
     }
@@ -3367,6 +3842,27 @@ impl TypedArrayKind {
     pub(crate) fn is_big_int_element_type(self) -> bool {
         matches!(self, TypedArrayKind::BigUint64 | TypedArrayKind::BigInt64)
     }
+
+    /// Gets the accessor for the intrinsic constructor of this typed array name, as per the
+    /// [spec].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#table-the-typedarray-constructors
+    #[inline]
+    pub(crate) fn standard_constructor(self) -> fn(&StandardConstructors) -> &StandardConstructor {
+        match self {
+            Self::Int8 => StandardConstructors::typed_int8_array,
+            Self::Uint8 => StandardConstructors::typed_uint8_array,
+            Self::Uint8Clamped => StandardConstructors::typed_uint8clamped_array,
+            Self::Int16 => StandardConstructors::typed_int16_array,
+            Self::Uint16 => StandardConstructors::typed_uint16_array,
+            Self::Int32 => StandardConstructors::typed_int32_array,
+            Self::Uint32 => StandardConstructors::typed_uint32_array,
+            Self::BigInt64 => StandardConstructors::typed_bigint64_array,
+            Self::BigUint64 => StandardConstructors::typed_biguint64_array,
+            Self::Float32 => StandardConstructors::typed_float32_array,
+            Self::Float64 => StandardConstructors::typed_float64_array,
+        }
+    }
 }
 
 typed_array!(Int8Array, Int8, "Int8Array", typed_int8_array);
@@ -0,0 +1,146 @@
+use crate::{forward, Context};
+
+#[test]
+fn find_last() {
+    let mut context = Context::default();
+    let init = r#"
+        var ta = new Int8Array([1, 2, 3, 4]);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "ta.findLast(x => x % 2)"), "3");
+    assert_eq!(forward(&mut context, "ta.findLastIndex(x => x % 2)"), "2");
+    assert_eq!(
+        forward(&mut context, "ta.findLast(x => x > 4)"),
+        "undefined"
+    );
+    assert_eq!(forward(&mut context, "ta.findLastIndex(x => x > 4)"), "-1");
+    assert_eq!(
+        forward(
+            &mut context,
+            "let seen = []; ta.findLastIndex((x, i) => { seen.push(i); return false; }); seen"
+        ),
+        "[ 3, 2, 1, 0 ]"
+    );
+}
+
+#[test]
+fn sort_without_comparator() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.from(new Float64Array([3, NaN, -0, 0, -Infinity, 1]).sort()).map(x => Object.is(x, -0) ? '-0' : String(x)).join()"
+        ),
+        "\"-Infinity,-0,0,1,3,NaN\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Int16Array([300, -2, 7, -300]).sort().join()"
+        ),
+        "\"-300,-2,7,300\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new BigInt64Array([5n, -1n, 3n]).sort().join()"
+        ),
+        "\"-1,3,5\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "let buf = new Uint8Array([9, 3, 2, 1, 0]); new Uint8Array(buf.buffer, 1, 3).sort(); buf.join()"
+        ),
+        "\"9,1,2,3,0\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "let source = new Uint32Array([3, 1, 2]); source.toSorted().join() + ' ' + source.join()"
+        ),
+        "\"1,2,3 3,1,2\""
+    );
+}
+
+#[test]
+fn reverse_and_fill() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(&mut context, "new Int32Array([1, 2, 3]).reverse().join()"),
+        "\"3,2,1\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Float32Array([1.5, 2.5]).reverse().join()"
+        ),
+        "\"2.5,1.5\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Uint16Array(5).fill(258, 1, -1).join()"),
+        "\"0,258,258,258,0\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Uint8ClampedArray(2).fill(300).join()"),
+        "\"255,255\""
+    );
+}
+
+#[test]
+fn set_from_typed_array() {
+    let mut context = Context::default();
+    assert_eq!(
+        forward(
+            &mut context,
+            "let target = new Int16Array(4); target.set(new Int16Array([1, -2]), 1); target.join()"
+        ),
+        "\"0,1,-2,0\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "let overlap = new Uint8Array([1, 2, 3, 4]); overlap.set(overlap.subarray(0, 3), 1); overlap.join()"
+        ),
+        "\"1,1,2,3\""
+    );
+}
+
+#[test]
+fn length_tracking() {
+    let mut context = Context::default();
+    let init = r#"
+        var buffer = new ArrayBuffer(4, { maxByteLength: 16 });
+        var tracking = new Uint16Array(buffer);
+        var offset = new Uint16Array(buffer, 2);
+        var fixed = new Uint16Array(buffer, 0, 2);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "tracking.length"), "2");
+    assert_eq!(
+        forward(
+            &mut context,
+            "buffer.resize(8); tracking.fill(3); [tracking.length, offset.length, tracking.byteLength]"
+        ),
+        "[ 4, 3, 8 ]"
+    );
+    assert_eq!(forward(&mut context, "offset.join()"), "\"3,3,3\"");
+    assert_eq!(forward(&mut context, "tracking.subarray(1).length"), "3");
+    assert_eq!(
+        forward(
+            &mut context,
+            "buffer.resize(2); [tracking.length, offset.length, fixed.length, fixed.byteOffset]"
+        ),
+        "[ 1, 0, 0, 0 ]"
+    );
+    assert_eq!(
+        forward(&mut context, "try { fixed.join(); } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(forward(&mut context, "fixed[0]"), "undefined");
+    assert_eq!(forward(&mut context, "Object.keys(tracking).length"), "1");
+    assert_eq!(
+        forward(&mut context, "buffer.resize(4); fixed.join()"),
+        "\"3,0\""
+    );
+}
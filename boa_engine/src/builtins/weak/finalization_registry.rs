@@ -0,0 +1,372 @@
+use super::{can_be_held_weakly, WeakTarget};
+use crate::{
+    builtins::{BuiltIn, JsArgs},
+    context::intrinsics::StandardConstructors,
+    job::NativeJob,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
+        WeakJsObject,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use tap::{Conv, Pipe};
+
+/// A record of a target registered in a `FinalizationRegistry`.
+#[derive(Debug, Trace, Finalize)]
+struct Cell {
+    /// `[[WeakRefTarget]]`
+    target: WeakTarget,
+    /// `[[HeldValue]]`
+    held_value: JsValue,
+    /// `[[UnregisterToken]]`, `None` when empty.
+    unregister_token: Option<WeakTarget>,
+}
+
+/// The internal representation of a `FinalizationRegistry` object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-finalization-registry-objects
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry
+#[derive(Debug, Trace, Finalize)]
+pub struct FinalizationRegistry {
+    /// `[[CleanupCallback]]`
+    cleanup_callback: JsObject,
+    /// `[[Cells]]`
+    cells: Vec<Cell>,
+}
+
+impl BuiltIn for FinalizationRegistry {
+    const NAME: &'static str = "FinalizationRegistry";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context
+                .intrinsics()
+                .constructors()
+                .finalization_registry()
+                .clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .method(Self::cleanup_some, "cleanupSome", 0)
+        .method(Self::register, "register", 2)
+        .method(Self::unregister, "unregister", 1)
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+impl FinalizationRegistry {
+    pub(crate) const LENGTH: usize = 1;
+
+    /// `FinalizationRegistry ( cleanupCallback )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry-cleanup-callback
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/FinalizationRegistry
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin FinalizationRegistry constructor without new is forbidden",
+            );
+        }
+
+        // 2. If IsCallable(cleanupCallback) is false, throw a TypeError exception.
+        let cleanup_callback =
+            args.get_or_undefined(0)
+                .as_callable()
+                .cloned()
+                .ok_or_else(|| {
+                    context.construct_type_error(
+                        "FinalizationRegistry: cleanup callback is not callable",
+                    )
+                })?;
+
+        // 3. Let finalizationRegistry be ? OrdinaryCreateFromConstructor(NewTarget, "%FinalizationRegistry.prototype%", « [[Realm]], [[CleanupCallback]], [[Cells]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::finalization_registry,
+            context,
+        )?;
+
+        // 4. Let fn be the active function object.
+        // 5. Set finalizationRegistry.[[Realm]] to fn.[[Realm]].
+        // 6. Set finalizationRegistry.[[CleanupCallback]] to HostMakeJobCallback(cleanupCallback).
+        // 7. Set finalizationRegistry.[[Cells]] to a new empty List.
+        let registry = JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::finalization_registry(Self {
+                cleanup_callback,
+                cells: Vec::new(),
+            }),
+        );
+
+        // The context polls its registries after collections, without keeping them alive.
+        context.finalization_registries.push(registry.downgrade());
+
+        // 8. Return finalizationRegistry.
+        Ok(registry.into())
+    }
+
+    /// `FinalizationRegistry.prototype.register ( target, heldValue [ , unregisterToken ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.register
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/register
+    pub(crate) fn register(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let held_value = args.get_or_undefined(1);
+        let unregister_token = args.get_or_undefined(2);
+
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let registry = this_finalization_registry(this, context)?;
+
+        // 3. If CanBeHeldWeakly(target) is false, throw a TypeError exception.
+        let target = args.get_or_undefined(0);
+        let weak_target = WeakTarget::new(target).ok_or_else(|| {
+            context.construct_type_error("FinalizationRegistry.register: invalid target")
+        })?;
+
+        // 4. If SameValue(target, heldValue) is true, throw a TypeError exception.
+        if JsValue::same_value(target, held_value) {
+            return context.throw_type_error(
+                "FinalizationRegistry.register: target and held value must not be the same",
+            );
+        }
+
+        // 5. If CanBeHeldWeakly(unregisterToken) is false, then
+        //     a. If unregisterToken is not undefined, throw a TypeError exception.
+        //     b. Set unregisterToken to empty.
+        let unregister_token = match WeakTarget::new(unregister_token) {
+            Some(token) => Some(token),
+            None if unregister_token.is_undefined() => None,
+            None => {
+                return context
+                    .throw_type_error("FinalizationRegistry.register: invalid unregister token")
+            }
+        };
+
+        // 6. Let cell be the Record { [[WeakRefTarget]]: target, [[HeldValue]]: heldValue, [[UnregisterToken]]: unregisterToken }.
+        let cell = Cell {
+            target: weak_target,
+            held_value: held_value.clone(),
+            unregister_token,
+        };
+
+        // 7. Append cell to finalizationRegistry.[[Cells]].
+        registry
+            .borrow_mut()
+            .as_finalization_registry_mut()
+            .expect("checked that `this` is a FinalizationRegistry")
+            .cells
+            .push(cell);
+
+        // 8. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `FinalizationRegistry.prototype.unregister ( unregisterToken )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.unregister
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/FinalizationRegistry/unregister
+    pub(crate) fn unregister(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let registry = this_finalization_registry(this, context)?;
+
+        // 3. If CanBeHeldWeakly(unregisterToken) is false, throw a TypeError exception.
+        let token = args.get_or_undefined(0);
+        if !can_be_held_weakly(token) {
+            return context
+                .throw_type_error("FinalizationRegistry.unregister: invalid unregister token");
+        }
+
+        // 4. Let removed be false.
+        // 5. For each Record { [[WeakRefTarget]], [[HeldValue]], [[UnregisterToken]] } cell of finalizationRegistry.[[Cells]], do
+        //     a. If cell.[[UnregisterToken]] is not empty and SameValue(cell.[[UnregisterToken]], unregisterToken) is true, then
+        //         i. Remove cell from finalizationRegistry.[[Cells]].
+        //         ii. Set removed to true.
+        let mut registry = registry.borrow_mut();
+        let cells = &mut registry
+            .as_finalization_registry_mut()
+            .expect("checked that `this` is a FinalizationRegistry")
+            .cells;
+        let len = cells.len();
+        cells.retain(|cell| {
+            !cell
+                .unregister_token
+                .as_ref()
+                .map_or(false, |cell_token| cell_token.is(token))
+        });
+
+        // 6. Return removed.
+        Ok((cells.len() != len).into())
+    }
+
+    /// `FinalizationRegistry.prototype.cleanupSome ( [ callback ] )`
+    ///
+    /// Synchronously calls the cleanup callback, or `callback` if given, for the targets of the
+    /// registry that were garbage collected.
+    ///
+    /// More information:
+    ///  - [WeakRefs proposal reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-cleanup-some/#sec-finalization-registry.prototype.cleanupSome
+    pub(crate) fn cleanup_some(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let registry = this_finalization_registry(this, context)?;
+
+        // 3. If callback is present and IsCallable(callback) is false, throw a TypeError exception.
+        let callback = args.get_or_undefined(0);
+        let callback = if callback.is_undefined() {
+            None
+        } else {
+            Some(callback.as_callable().cloned().ok_or_else(|| {
+                context.construct_type_error("FinalizationRegistry.cleanupSome: invalid callback")
+            })?)
+        };
+
+        // 4. Perform ? CleanupFinalizationRegistry(finalizationRegistry, callback).
+        Self::cleanup(&registry, callback, context)?;
+
+        // 5. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// Abstract operation `CleanupFinalizationRegistry ( finalizationRegistry )`
+    ///
+    /// Calls the cleanup callback, or `callback` if given, with the held value of every cell
+    /// whose target was garbage collected, removing the cell first.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-cleanup-finalization-registry
+    fn cleanup(
+        registry: &JsObject,
+        callback: Option<JsObject>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // 1. Assert: finalizationRegistry has [[Cells]] and [[CleanupCallback]] internal slots.
+        // 2. Let callback be finalizationRegistry.[[CleanupCallback]].
+        // 3. While finalizationRegistry.[[Cells]] contains a Record cell such that cell.[[WeakRefTarget]] is empty, an implementation may perform the following steps:
+        loop {
+            let (callback, held_value) = {
+                let mut registry = registry.borrow_mut();
+                let registry = registry
+                    .as_finalization_registry_mut()
+                    .expect("registry must be a FinalizationRegistry");
+                let index = match registry
+                    .cells
+                    .iter()
+                    .position(|cell| cell.target.is_collected())
+                {
+                    Some(index) => index,
+                    None => return Ok(()),
+                };
+
+                // a. Choose any such cell.
+                // b. Remove cell from finalizationRegistry.[[Cells]].
+                let cell = registry.cells.remove(index);
+                let callback = callback
+                    .clone()
+                    .unwrap_or_else(|| registry.cleanup_callback.clone());
+                (callback, cell.held_value)
+            };
+
+            // c. Perform ? HostCallJobCallback(callback, undefined, « cell.[[HeldValue]] »).
+            callback.call(&JsValue::undefined(), &[held_value], context)?;
+        }
+
+        // 4. Return unused.
+    }
+
+    /// Enqueues a cleanup job for every live registry with a garbage collected target.
+    ///
+    /// This is the engine side of `HostEnqueueFinalizationRegistryCleanupJob`, called when
+    /// `boa_gc` reports that a collection cleared weak references.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-cleanup-finalization-registry
+    pub(crate) fn schedule_cleanup(context: &mut Context) {
+        // Registries that were collected themselves won't call their callbacks anymore.
+        context
+            .finalization_registries
+            .retain(|registry| !registry.is_collected());
+
+        let registries: Vec<_> = context
+            .finalization_registries
+            .iter()
+            .filter_map(WeakJsObject::upgrade)
+            .filter(|registry| {
+                registry
+                    .borrow()
+                    .as_finalization_registry()
+                    .map_or(false, |registry| {
+                        registry.cells.iter().any(|cell| cell.target.is_collected())
+                    })
+            })
+            .collect();
+
+        for registry in registries {
+            context.enqueue_job(NativeJob::new(move |context| {
+                Self::cleanup(&registry, None, context).map(|_| JsValue::undefined())
+            }));
+        }
+    }
+}
+
+/// Returns the `this` object if it is a `FinalizationRegistry`, or throws a `TypeError`.
+fn this_finalization_registry(this: &JsValue, context: &mut Context) -> JsResult<JsObject> {
+    this.as_object()
+        .filter(|obj| obj.borrow().is_finalization_registry())
+        .cloned()
+        .ok_or_else(|| context.construct_type_error("'this' is not a FinalizationRegistry"))
+}
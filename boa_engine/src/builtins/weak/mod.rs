@@ -0,0 +1,111 @@
+//! This module implements the builtins that hold values weakly: `WeakRef`,
+//! `FinalizationRegistry` and `WeakMap`.
+//!
+//! `WeakRef` and `FinalizationRegistry` are built on the weak references of `boa_gc`, which are
+//! cleared when their target is collected. Symbols aren't allocated by the collector but
+//! reference counted, and notify `boa_gc` when they are dropped while weakly referenced. Cleanup
+//! callbacks can't run during a collection, so the context polls for cleared references when
+//! running its jobs and enqueues the cleanup of the affected registries.
+//!
+//! `WeakMap` stores the values of its object keys in the ephemeron tables of the keys, so a value
+//! is only kept alive by its key, even if the value references the key. Symbols have no ephemeron
+//! table, so the values of the symbol keys are kept alive by the map, and a value that references
+//! its symbol key keeps the key alive as long as the entry is in the map.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-managing-memory
+
+mod finalization_registry;
+mod weak_map;
+mod weak_ref;
+
+#[cfg(test)]
+mod tests;
+
+pub use finalization_registry::FinalizationRegistry;
+pub use weak_map::WeakMap;
+pub(crate) use weak_ref::WeakRef;
+
+use crate::{
+    builtins::Symbol,
+    object::{JsObject, WeakJsObject},
+    symbol::WeakJsSymbol,
+    JsValue,
+};
+use boa_gc::{Finalize, Trace};
+
+/// Abstract operation `CanBeHeldWeakly ( v )`
+///
+/// Returns `true` if the value can be used as the target of a weak reference: objects, and
+/// symbols that are not in the global symbol registry.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-canbeheldweakly
+pub(crate) fn can_be_held_weakly(value: &JsValue) -> bool {
+    match value {
+        // 1. If v is an Object, return true.
+        JsValue::Object(_) => true,
+        // 2. If v is a Symbol and KeyForSymbol(v) is undefined, return true.
+        JsValue::Symbol(symbol) => Symbol::key_for_symbol(symbol).is_none(),
+        // 3. Return false.
+        _ => false,
+    }
+}
+
+/// A weak reference to a value that can be held weakly.
+#[derive(Debug, Trace, Finalize)]
+pub enum WeakTarget {
+    /// A weakly referenced object.
+    Object(WeakJsObject),
+    /// A weakly referenced symbol that is not in the global symbol registry.
+    Symbol(WeakJsSymbol),
+}
+
+impl WeakTarget {
+    /// Creates a weak reference to `value`, or returns `None` if `CanBeHeldWeakly(value)` is
+    /// `false`.
+    pub(crate) fn new(value: &JsValue) -> Option<Self> {
+        if !can_be_held_weakly(value) {
+            return None;
+        }
+
+        match value {
+            JsValue::Object(object) => Some(Self::Object(object.downgrade())),
+            JsValue::Symbol(symbol) => Some(Self::Symbol(symbol.downgrade())),
+            _ => None,
+        }
+    }
+
+    /// Returns the target, or `None` if it was garbage collected.
+    pub fn upgrade(&self) -> Option<JsValue> {
+        match self {
+            Self::Object(object) => object.upgrade().map(JsValue::from),
+            Self::Symbol(symbol) => symbol.upgrade().map(JsValue::from),
+        }
+    }
+
+    /// Returns `true` if the target was garbage collected.
+    pub fn is_collected(&self) -> bool {
+        match self {
+            Self::Object(object) => object.is_collected(),
+            Self::Symbol(symbol) => symbol.is_collected(),
+        }
+    }
+
+    /// Returns `true` if the target is alive and is the same value as `value`.
+    pub(crate) fn is(&self, value: &JsValue) -> bool {
+        match (self, value) {
+            (Self::Object(target), JsValue::Object(value)) => target
+                .upgrade()
+                .map_or(false, |target| JsObject::equals(&target, value)),
+            (Self::Symbol(target), JsValue::Symbol(value)) => {
+                target.upgrade().map_or(false, |target| target == *value)
+            }
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use crate::{forward, Context};
+
+#[test]
+fn weak_ref() {
+    let mut context = Context::default();
+    let init = r#"
+        var target = {};
+        var strong = new WeakRef(target);
+        var weak = new WeakRef({});
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "strong.deref() === target"), "true");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(strong)"),
+        "\"[object WeakRef]\""
+    );
+
+    boa_gc::force_collect();
+    assert_eq!(forward(&mut context, "strong.deref() === target"), "true");
+    assert_eq!(forward(&mut context, "weak.deref()"), "undefined");
+
+    assert_eq!(
+        forward(&mut context, "try { new WeakRef(1); } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { WeakRef({}); } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn weak_ref_kept_alive_during_job() {
+    let mut context = Context::default();
+    let script = r#"
+        var weak = new WeakRef({});
+        var same = weak.deref() !== undefined;
+    "#;
+    forward(&mut context, script);
+    assert_eq!(forward(&mut context, "same"), "true");
+}
+
+#[test]
+fn finalization_registry_cleanup() {
+    let mut context = Context::default();
+    let init = r#"
+        var cleaned = [];
+        var registry = new FinalizationRegistry((held) => cleaned.push(held));
+        var kept = {};
+        registry.register({}, "collected");
+        registry.register(kept, "kept");
+    "#;
+    forward(&mut context, init);
+
+    boa_gc::force_collect();
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "cleaned.join()"), "\"collected\"");
+
+    boa_gc::force_collect();
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "cleaned.length"), "1");
+}
+
+#[test]
+fn finalization_registry_cleanup_some() {
+    let mut context = Context::default();
+    let init = r#"
+        var fromCallback = [];
+        var registry = new FinalizationRegistry(() => { throw new Error("unreachable"); });
+        registry.register({}, 1);
+        registry.register({}, 2);
+    "#;
+    forward(&mut context, init);
+
+    // Collect without running the queued jobs, so the cells are still pending.
+    boa_gc::force_collect();
+    assert_eq!(
+        forward(
+            &mut context,
+            "registry.cleanupSome((held) => fromCallback.push(held)); fromCallback.sort().join()"
+        ),
+        "\"1,2\""
+    );
+}
+
+#[test]
+fn finalization_registry_unregister() {
+    let mut context = Context::default();
+    let init = r#"
+        var registry = new FinalizationRegistry(() => {});
+        var target = {};
+        var token = {};
+        registry.register(target, "a", token);
+        registry.register({}, "b", token);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "registry.unregister(token)"), "true");
+    assert_eq!(forward(&mut context, "registry.unregister(token)"), "false");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { registry.register(target, target); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { registry.register(target, 1, 1); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { registry.unregister(1); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn symbols_as_weak_targets() {
+    let mut context = Context::default();
+    let init = r#"
+        var sym = Symbol("kept");
+        var strong = new WeakRef(sym);
+        var weak = new WeakRef(Symbol("dropped"));
+        var wellKnown = new WeakRef(Symbol.iterator);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "strong.deref() === sym"), "true");
+    assert_eq!(
+        forward(&mut context, "wellKnown.deref() === Symbol.iterator"),
+        "true"
+    );
+
+    boa_gc::force_collect();
+    assert_eq!(forward(&mut context, "strong.deref() === sym"), "true");
+    assert_eq!(forward(&mut context, "weak.deref()"), "undefined");
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new WeakRef(Symbol.for('registered')); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn finalization_registry_symbols() {
+    let mut context = Context::default();
+    let init = r#"
+        var cleaned = [];
+        var registry = new FinalizationRegistry((held) => cleaned.push(held));
+        var token = Symbol("token");
+        var target = Symbol("target");
+        registry.register(Symbol("collected"), "collected");
+        registry.register(target, "kept", token);
+    "#;
+    forward(&mut context, init);
+
+    boa_gc::force_collect();
+    context.run_jobs().unwrap();
+    assert_eq!(forward(&mut context, "cleaned.join()"), "\"collected\"");
+
+    assert_eq!(forward(&mut context, "registry.unregister(token)"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { registry.register(target, target); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { registry.register(Symbol.for('registered'), 1); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { registry.unregister(Symbol.for('registered')); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn weak_map() {
+    let mut context = Context::default();
+    let init = r#"
+        var key = {};
+        var sym = Symbol("key");
+        var map = new WeakMap([[key, 1]]);
+        map.set(sym, 2);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "map.get(key)"), "1");
+    assert_eq!(forward(&mut context, "map.get(sym)"), "2");
+    assert_eq!(forward(&mut context, "map.has({})"), "false");
+    assert_eq!(forward(&mut context, "map.get(1)"), "undefined");
+    assert_eq!(forward(&mut context, "map.set(key, 3) === map"), "true");
+    assert_eq!(forward(&mut context, "map.get(key)"), "3");
+    assert_eq!(forward(&mut context, "map.delete(key)"), "true");
+    assert_eq!(forward(&mut context, "map.has(key)"), "false");
+    assert_eq!(forward(&mut context, "map.delete(sym)"), "true");
+    assert_eq!(forward(&mut context, "map.delete(sym)"), "false");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(map)"),
+        "\"[object WeakMap]\""
+    );
+
+    assert_eq!(
+        forward(&mut context, "try { map.set(1, 1); } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { map.set(Symbol.for('registered'), 1); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { WeakMap(); } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn weak_map_ephemerons() {
+    let mut context = Context::default();
+    let init = r#"
+        var map = new WeakMap();
+        var kept = {};
+        var dropped = {};
+        map.set(kept, { kept });
+        map.set(dropped, { dropped });
+        map.set(map, map);
+        var weak = new WeakRef(dropped);
+        dropped = undefined;
+    "#;
+    forward(&mut context, init);
+
+    boa_gc::force_collect();
+    assert_eq!(forward(&mut context, "map.get(kept).kept === kept"), "true");
+    assert_eq!(forward(&mut context, "map.get(map) === map"), "true");
+    assert_eq!(forward(&mut context, "weak.deref()"), "undefined");
+}
+
+#[test]
+fn weak_map_symbol_keys() {
+    let mut context = Context::default();
+    let init = r#"
+        var map = new WeakMap();
+        var kept = Symbol("kept");
+        var dropped = Symbol("dropped");
+        var cyclic = Symbol("cyclic");
+        map.set(kept, 1);
+        map.set(dropped, 2);
+        map.set(cyclic, { cyclic });
+        var droppedRef = new WeakRef(dropped);
+        var cyclicRef = new WeakRef(cyclic);
+        dropped = undefined;
+        cyclic = undefined;
+    "#;
+    forward(&mut context, init);
+
+    boa_gc::force_collect();
+    assert_eq!(forward(&mut context, "droppedRef.deref()"), "undefined");
+    // The value of a symbol key is kept alive by the map, so it keeps alive the key it
+    // references, unlike the values of object keys.
+    assert_eq!(
+        forward(&mut context, "typeof cyclicRef.deref()"),
+        "\"symbol\""
+    );
+
+    forward(
+        &mut context,
+        "for (var i = 0; i < 20; i++) { map.set(Symbol(), i); }",
+    );
+    assert_eq!(forward(&mut context, "map.get(kept)"), "1");
+    assert_eq!(forward(&mut context, "map.set(kept, 3).get(kept)"), "3");
+
+    forward(&mut context, "map = undefined;");
+    boa_gc::force_collect();
+    assert_eq!(forward(&mut context, "cyclicRef.deref()"), "undefined");
+}
@@ -0,0 +1,303 @@
+use super::can_be_held_weakly;
+use crate::{
+    builtins::{map::add_entries_from_iterable, BuiltIn, JsArgs},
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::{JsSymbol, WeakJsSymbol, WellKnownSymbols},
+    Context, JsResult, JsValue,
+};
+use boa_gc::{custom_trace, Finalize, Trace};
+use boa_profiler::Profiler;
+use rustc_hash::FxHashMap;
+use tap::{Conv, Pipe};
+
+/// The internal representation of a `WeakMap` object.
+///
+/// The values of the object keys are stored in the ephemeron tables of the keys, under the weak
+/// reference to the map, so they don't keep their keys alive.
+///
+/// Symbols are reference counted instead of garbage collected, so they have no ephemeron table
+/// and their values can't be traced with them. The values of the symbol keys are stored in the
+/// map, by the hash of their keys, and the map keeps them alive: a value that references its
+/// symbol key keeps the key alive until the key is deleted from the map or the map is collected.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-weakmap-objects
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap
+#[derive(Debug, Default, Finalize)]
+pub struct WeakMap {
+    /// The entries of the symbol keys, by the hash of the symbols, which is never reused.
+    symbols: FxHashMap<u64, (WeakJsSymbol, JsValue)>,
+    /// The number of entries of the symbol keys after the entries of the dropped symbols were
+    /// last removed.
+    swept: usize,
+}
+
+// SAFETY: The values are traced, and the weak references to the symbols contain no garbage
+// collected pointers.
+unsafe impl Trace for WeakMap {
+    custom_trace!(this, {
+        for (_, value) in this.symbols.values() {
+            mark(value);
+        }
+    });
+}
+
+impl BuiltIn for WeakMap {
+    const NAME: &'static str = "WeakMap";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().weak_map().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .method(Self::delete, "delete", 1)
+        .method(Self::get, "get", 1)
+        .method(Self::has, "has", 1)
+        .method(Self::set, "set", 2)
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+impl WeakMap {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `WeakMap ( [ iterable ] )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap-iterable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/WeakMap
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin WeakMap constructor without new is forbidden",
+            );
+        }
+
+        // 2. Let map be ? OrdinaryCreateFromConstructor(NewTarget, "%WeakMap.prototype%", « [[WeakMapData]] »).
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardConstructors::weak_map, context)?;
+
+        // 3. Set map.[[WeakMapData]] to a new empty List.
+        let map = JsObject::from_proto_and_data(prototype, ObjectData::weak_map(Self::default()));
+
+        // 4. If iterable is either undefined or null, return map.
+        let iterable = args.get_or_undefined(0);
+        if iterable.is_null_or_undefined() {
+            return Ok(map.into());
+        }
+
+        // 5. Let adder be ? Get(map, "set").
+        let adder = map.get("set", context)?;
+
+        // 6. Return ? AddEntriesFromIterable(map, iterable, adder).
+        add_entries_from_iterable(&map, iterable, &adder, context)
+    }
+
+    /// `WeakMap.prototype.delete ( key )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/delete
+    pub(crate) fn delete(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        let map = this_weak_map(this, context)?;
+
+        // 3. If CanBeHeldWeakly(key) is false, return false.
+        // 4. For each Record { [[Key]], [[Value]] } p of M.[[WeakMapData]], do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, then
+        //         i. Set p.[[Key]] to empty.
+        //         ii. Set p.[[Value]] to empty.
+        //         iii. Return true.
+        // 5. Return false.
+        let removed = match args.get_or_undefined(0) {
+            JsValue::Object(key) => key
+                .borrow_mut()
+                .ephemerons_mut()
+                .remove(map.downgrade().as_weak_gc())
+                .is_some(),
+            JsValue::Symbol(key) => map
+                .borrow_mut()
+                .as_weak_map_mut()
+                .expect("checked that `this` is a WeakMap")
+                .symbols
+                .remove(&key.hash())
+                .is_some(),
+            _ => false,
+        };
+        Ok(removed.into())
+    }
+
+    /// `WeakMap.prototype.get ( key )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.get
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/get
+    pub(crate) fn get(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        let map = this_weak_map(this, context)?;
+
+        // 3. If CanBeHeldWeakly(key) is false, return undefined.
+        // 4. For each Record { [[Key]], [[Value]] } p of M.[[WeakMapData]], do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, return p.[[Value]].
+        // 5. Return undefined.
+        Ok(Self::value(&map, args.get_or_undefined(0)).unwrap_or_default())
+    }
+
+    /// `WeakMap.prototype.has ( key )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/has
+    pub(crate) fn has(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        let map = this_weak_map(this, context)?;
+
+        // 3. If CanBeHeldWeakly(key) is false, return false.
+        // 4. For each Record { [[Key]], [[Value]] } p of M.[[WeakMapData]], do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, return true.
+        // 5. Return false.
+        Ok(Self::value(&map, args.get_or_undefined(0)).is_some().into())
+    }
+
+    /// `WeakMap.prototype.set ( key, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.set
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/set
+    pub(crate) fn set(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        let map = this_weak_map(this, context)?;
+
+        // 3. If CanBeHeldWeakly(key) is false, throw a TypeError exception.
+        let key = args.get_or_undefined(0);
+        if !can_be_held_weakly(key) {
+            return context.throw_type_error("WeakMap.set: invalid key");
+        }
+
+        // 4. For each Record { [[Key]], [[Value]] } p of M.[[WeakMapData]], do
+        //     a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, then
+        //         i. Set p.[[Value]] to value.
+        //         ii. Return M.
+        // 5. Let p be the Record { [[Key]]: key, [[Value]]: value }.
+        // 6. Append p to M.[[WeakMapData]].
+        let value = args.get_or_undefined(1).clone();
+        match key {
+            JsValue::Object(key) => {
+                let collection = map.downgrade();
+                key.borrow_mut()
+                    .ephemerons_mut()
+                    .insert(collection.as_weak_gc(), value);
+            }
+            JsValue::Symbol(key) => map
+                .borrow_mut()
+                .as_weak_map_mut()
+                .expect("checked that `this` is a WeakMap")
+                .insert_symbol(key, value),
+            _ => unreachable!("CanBeHeldWeakly returned true"),
+        }
+
+        // 7. Return M.
+        Ok(map.into())
+    }
+
+    /// Returns the value of `key` in the map, or `None` if it is not a key of the map.
+    fn value(map: &JsObject, key: &JsValue) -> Option<JsValue> {
+        match key {
+            JsValue::Object(key) => key
+                .borrow()
+                .ephemerons()
+                .get(map.downgrade().as_weak_gc())
+                .cloned(),
+            JsValue::Symbol(key) => Self::symbol_value(map, key),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the symbol `key` in the map.
+    fn symbol_value(map: &JsObject, key: &JsSymbol) -> Option<JsValue> {
+        map.borrow()
+            .as_weak_map()
+            .expect("checked that `this` is a WeakMap")
+            .symbols
+            .get(&key.hash())
+            .map(|(_, value)| value.clone())
+    }
+
+    /// Sets the value of the symbol `key` in the map.
+    fn insert_symbol(&mut self, key: &JsSymbol, value: JsValue) {
+        // The entries of the dropped symbols are removed when the number of entries doubled since
+        // they were last removed, so that they take a constant time per insertion on average.
+        if self.symbols.len() >= (2 * self.swept).max(8) {
+            self.symbols.retain(|_, (symbol, _)| !symbol.is_collected());
+            self.swept = self.symbols.len();
+        }
+        self.symbols.insert(key.hash(), (key.downgrade(), value));
+    }
+}
+
+/// Returns the `this` object if it is a `WeakMap`, or throws a `TypeError`.
+fn this_weak_map(this: &JsValue, context: &mut Context) -> JsResult<JsObject> {
+    this.as_object()
+        .filter(|obj| obj.borrow().is_weak_map())
+        .cloned()
+        .ok_or_else(|| context.construct_type_error("'this' is not a WeakMap"))
+}
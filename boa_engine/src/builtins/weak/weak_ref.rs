@@ -0,0 +1,124 @@
+use super::WeakTarget;
+use crate::{
+    builtins::{BuiltIn, JsArgs},
+    context::intrinsics::StandardConstructors,
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsObject, ObjectData,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    Context, JsResult, JsValue,
+};
+use boa_profiler::Profiler;
+use tap::{Conv, Pipe};
+
+/// The `WeakRef` builtin object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-weak-ref-objects
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakRef
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakRef;
+
+impl BuiltIn for WeakRef {
+    const NAME: &'static str = "WeakRef";
+
+    fn init(context: &mut Context) -> Option<JsValue> {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+
+        ConstructorBuilder::with_standard_constructor(
+            context,
+            Self::constructor,
+            context.intrinsics().constructors().weak_ref().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .method(Self::deref, "deref", 0)
+        .build()
+        .conv::<JsValue>()
+        .pipe(Some)
+    }
+}
+
+impl WeakRef {
+    pub(crate) const LENGTH: usize = 1;
+
+    /// `WeakRef ( target )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weak-ref-target
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakRef/WeakRef
+    pub(crate) fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin WeakRef constructor without new is forbidden",
+            );
+        }
+
+        // 2. If CanBeHeldWeakly(target) is false, throw a TypeError exception.
+        let target = args.get_or_undefined(0);
+        let weak_target = WeakTarget::new(target).ok_or_else(|| {
+            context.construct_type_error(
+                "WeakRef: target must be an object or a non-registered symbol",
+            )
+        })?;
+
+        // 3. Let weakRef be ? OrdinaryCreateFromConstructor(NewTarget, "%WeakRef.prototype%", « [[WeakRefTarget]] »).
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardConstructors::weak_ref, context)?;
+
+        // 4. Perform AddToKeptObjects(target).
+        // 5. Set weakRef.[[WeakRefTarget]] to target.
+        let weak_ref = JsObject::from_proto_and_data(prototype, ObjectData::weak_ref(weak_target));
+        context.add_to_kept_objects(target.clone());
+
+        // 6. Return weakRef.
+        Ok(weak_ref.into())
+    }
+
+    /// `WeakRef.prototype.deref ( )`
+    ///
+    /// Returns the target of the `WeakRef`, or `undefined` if it was garbage collected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weak-ref.prototype.deref
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakRef/deref
+    pub(crate) fn deref(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let weakRef be the this value.
+        // 2. Perform ? RequireInternalSlot(weakRef, [[WeakRefTarget]]).
+        let target = this
+            .as_object()
+            .and_then(|obj| obj.borrow().as_weak_ref().map(WeakTarget::upgrade))
+            .ok_or_else(|| context.construct_type_error("'this' is not a WeakRef"))?;
+
+        // 3. Return WeakRefDeref(weakRef).
+        //     1. Let target be weakRef.[[WeakRefTarget]].
+        //     2. If target is not empty, then
+        //         a. Perform AddToKeptObjects(target).
+        //         b. Return target.
+        //     3. Return undefined.
+        Ok(target.map_or_else(JsValue::undefined, |target| {
+            context.add_to_kept_objects(target.clone());
+            target
+        }))
+    }
+}
@@ -0,0 +1,360 @@
+//! Static analysis of the bindings captured by nested functions.
+//!
+//! The bindings of a function that are never referenced by one of its nested functions can't
+//! outlive a call of the function, so they are stored in the call frame instead of a heap
+//! allocated environment. Since the language has no `with` statement and no direct `eval`, the
+//! references to a binding are all known at compile time.
+//!
+//! The analysis only works on names: a name used anywhere in a nested function captures the
+//! bindings of that name in the enclosing function, even if the nested function declares its own
+//! binding of the same name. This over-approximates the captured bindings, but doesn't depend on
+//! the scoping rules of the compiler.
+
+use crate::syntax::ast::node::{
+    declaration::{BindingPatternTypeArray, BindingPatternTypeObject, DeclarationPattern},
+    iteration::IterableLoopInitializer,
+    object::{MethodDefinition, PropertyDefinition, PropertyName},
+    operator::assign::AssignTarget,
+    template::TemplateElement,
+    Declaration, FormalParameter, FormalParameterList, Node,
+};
+use boa_interner::Sym;
+use rustc_hash::FxHashSet;
+
+/// Returns the names of the bindings of a function that must be stored in environments.
+pub(super) fn captured_names(parameters: &FormalParameterList, body: &[Node]) -> FxHashSet<Sym> {
+    let mut captures = Captures {
+        names: FxHashSet::default(),
+        depth: 0,
+        uses_arguments: false,
+    };
+    captures.parameters(parameters);
+    captures.nodes(body);
+
+    // The `arguments` binding is initialized in the function environment when the function is
+    // called, and a mapped arguments object refers to the parameters in the function environment.
+    captures.names.insert(Sym::ARGUMENTS);
+    if captures.uses_arguments {
+        captures.names.extend(
+            parameters
+                .parameters
+                .iter()
+                .flat_map(FormalParameter::names),
+        );
+    }
+
+    captures.names
+}
+
+/// The names referenced by the nested functions of a function.
+#[derive(Debug)]
+struct Captures {
+    names: FxHashSet<Sym>,
+    /// The number of functions nested in the analysed function that enclose the current node.
+    depth: usize,
+    uses_arguments: bool,
+}
+
+impl Captures {
+    fn name(&mut self, name: Sym) {
+        if name == Sym::ARGUMENTS {
+            self.uses_arguments = true;
+        }
+        if self.depth > 0 {
+            self.names.insert(name);
+        }
+    }
+
+    fn nodes(&mut self, nodes: &[Node]) {
+        for node in nodes {
+            self.node(node);
+        }
+    }
+
+    fn optional(&mut self, node: Option<&Node>) {
+        if let Some(node) = node {
+            self.node(node);
+        }
+    }
+
+    fn function(&mut self, parameters: &FormalParameterList, body: &[Node]) {
+        self.depth += 1;
+        self.parameters(parameters);
+        self.nodes(body);
+        self.depth -= 1;
+    }
+
+    fn parameters(&mut self, parameters: &FormalParameterList) {
+        for parameter in parameters.parameters.iter() {
+            self.declaration(parameter.declaration());
+        }
+    }
+
+    fn declaration(&mut self, declaration: &Declaration) {
+        match declaration {
+            Declaration::Identifier { ident, init } => {
+                self.name(ident.sym());
+                self.optional(init.as_ref());
+            }
+            Declaration::Pattern(pattern) => self.pattern(pattern),
+        }
+    }
+
+    fn pattern(&mut self, pattern: &DeclarationPattern) {
+        for name in pattern.idents() {
+            self.name(name);
+        }
+        self.optional(pattern.init());
+
+        match pattern {
+            DeclarationPattern::Object(pattern) => {
+                for binding in pattern.bindings() {
+                    match binding {
+                        BindingPatternTypeObject::SingleName { default_init, .. } => {
+                            self.optional(default_init.as_ref());
+                        }
+                        BindingPatternTypeObject::RestGetConstField {
+                            get_const_field, ..
+                        } => self.node(get_const_field.obj()),
+                        BindingPatternTypeObject::BindingPattern {
+                            pattern,
+                            default_init,
+                            ..
+                        } => {
+                            self.pattern(pattern);
+                            self.optional(default_init.as_ref());
+                        }
+                        BindingPatternTypeObject::Empty
+                        | BindingPatternTypeObject::RestProperty { .. } => {}
+                    }
+                }
+            }
+            DeclarationPattern::Array(pattern) => {
+                for binding in pattern.bindings() {
+                    match binding {
+                        BindingPatternTypeArray::SingleName { default_init, .. } => {
+                            self.optional(default_init.as_ref());
+                        }
+                        BindingPatternTypeArray::GetField { get_field }
+                        | BindingPatternTypeArray::GetFieldRest { get_field } => {
+                            self.node(get_field.obj());
+                            self.node(get_field.field());
+                        }
+                        BindingPatternTypeArray::GetConstField { get_const_field }
+                        | BindingPatternTypeArray::GetConstFieldRest { get_const_field } => {
+                            self.node(get_const_field.obj());
+                        }
+                        BindingPatternTypeArray::BindingPattern { pattern }
+                        | BindingPatternTypeArray::BindingPatternRest { pattern } => {
+                            self.pattern(pattern);
+                        }
+                        BindingPatternTypeArray::Empty
+                        | BindingPatternTypeArray::Elision
+                        | BindingPatternTypeArray::SingleNameRest { .. } => {}
+                    }
+                }
+            }
+        }
+    }
+
+    fn property_name(&mut self, name: &PropertyName) {
+        if let PropertyName::Computed(node) = name {
+            self.node(node);
+        }
+    }
+
+    fn node(&mut self, node: &Node) {
+        match node {
+            Node::ArrayDecl(array) => self.nodes(array.as_ref()),
+            Node::ArrowFunctionDecl(function) => {
+                self.function(function.params(), function.body().items());
+            }
+            Node::Assign(assign) => {
+                match assign.lhs() {
+                    AssignTarget::Identifier(ident) => self.name(ident.sym()),
+                    AssignTarget::GetConstField(field) => self.node(field.obj()),
+                    AssignTarget::GetField(field) => {
+                        self.node(field.obj());
+                        self.node(field.field());
+                    }
+                    AssignTarget::DeclarationPattern(pattern) => self.pattern(pattern),
+                }
+                self.node(assign.rhs());
+            }
+            Node::AsyncFunctionDecl(function) => {
+                self.function(function.parameters(), function.body().items());
+            }
+            Node::AsyncFunctionExpr(function) => {
+                self.function(function.parameters(), function.body().items());
+            }
+            Node::AsyncGeneratorDecl(function) => {
+                self.function(function.parameters(), function.body().items());
+            }
+            Node::AsyncGeneratorExpr(function) => {
+                self.function(function.parameters(), function.body().items());
+            }
+            Node::FunctionDecl(function) => {
+                self.name(function.name());
+                self.function(function.parameters(), function.body().items());
+            }
+            Node::FunctionExpr(function) => {
+                self.function(function.parameters(), function.body().items());
+            }
+            Node::GeneratorDecl(function) => {
+                self.name(function.name());
+                self.function(function.parameters(), function.body().items());
+            }
+            Node::GeneratorExpr(function) => {
+                self.function(function.parameters(), function.body().items());
+            }
+            Node::BinOp(op) => {
+                self.node(op.lhs());
+                self.node(op.rhs());
+            }
+            Node::Block(block) => self.nodes(block.items()),
+            Node::Call(call) => {
+                self.node(call.expr());
+                self.nodes(call.args());
+            }
+            Node::New(new) => {
+                self.node(new.expr());
+                self.nodes(new.args());
+            }
+            Node::ConditionalOp(op) => {
+                self.node(op.cond());
+                self.node(op.if_true());
+                self.node(op.if_false());
+            }
+            Node::ConstDeclList(list)
+            | Node::LetDeclList(list)
+            | Node::UsingDeclList(list)
+            | Node::VarDeclList(list) => {
+                for declaration in list.as_ref() {
+                    self.declaration(declaration);
+                }
+            }
+            Node::DoWhileLoop(do_while) => {
+                self.node(do_while.body());
+                self.node(do_while.cond());
+            }
+            Node::WhileLoop(while_loop) => {
+                self.node(while_loop.cond());
+                self.node(while_loop.body());
+            }
+            Node::ForLoop(for_loop) => {
+                self.optional(for_loop.init());
+                self.optional(for_loop.condition());
+                self.optional(for_loop.final_expr());
+                self.node(for_loop.body());
+            }
+            Node::ForInLoop(for_in_loop) => {
+                self.loop_initializer(for_in_loop.init());
+                self.node(for_in_loop.expr());
+                self.node(for_in_loop.body());
+            }
+            Node::ForOfLoop(for_of_loop) => {
+                self.loop_initializer(for_of_loop.init());
+                self.node(for_of_loop.iterable());
+                self.node(for_of_loop.body());
+            }
+            Node::GetConstField(field) => self.node(field.obj()),
+            Node::GetField(field) => {
+                self.node(field.obj());
+                self.node(field.field());
+            }
+            Node::Identifier(ident) => self.name(ident.sym()),
+            Node::If(if_node) => {
+                self.node(if_node.cond());
+                self.node(if_node.body());
+                self.optional(if_node.else_node());
+            }
+            Node::Object(object) => {
+                for property in object.properties() {
+                    match property {
+                        PropertyDefinition::IdentifierReference(name) => self.name(*name),
+                        PropertyDefinition::Property(name, value) => {
+                            self.property_name(name);
+                            self.node(value);
+                        }
+                        PropertyDefinition::MethodDefinition(method, name) => {
+                            self.property_name(name);
+                            match method {
+                                MethodDefinition::Get(function)
+                                | MethodDefinition::Set(function)
+                                | MethodDefinition::Ordinary(function) => {
+                                    self.function(function.parameters(), function.body().items());
+                                }
+                                MethodDefinition::Generator(function) => {
+                                    self.function(function.parameters(), function.body().items());
+                                }
+                                MethodDefinition::AsyncGenerator(function) => {
+                                    self.function(function.parameters(), function.body().items());
+                                }
+                                MethodDefinition::Async(function) => {
+                                    self.function(function.parameters(), function.body().items());
+                                }
+                            }
+                        }
+                        PropertyDefinition::SpreadObject(node) => self.node(node),
+                    }
+                }
+            }
+            Node::Return(ret) => self.optional(ret.expr()),
+            Node::Switch(switch) => {
+                self.node(switch.val());
+                for case in switch.cases() {
+                    self.node(case.condition());
+                    self.nodes(case.body().items());
+                }
+                if let Some(default) = switch.default() {
+                    self.nodes(default);
+                }
+            }
+            Node::Spread(spread) => self.node(spread.val()),
+            Node::TaggedTemplate(template) => {
+                self.node(template.tag());
+                self.nodes(template.exprs());
+            }
+            Node::TemplateLit(template) => {
+                for element in template.elements() {
+                    if let TemplateElement::Expr(node) = element {
+                        self.node(node);
+                    }
+                }
+            }
+            Node::Throw(throw) => self.node(throw.expr()),
+            Node::Try(try_node) => {
+                self.nodes(try_node.block().items());
+                if let Some(catch) = try_node.catch() {
+                    if let Some(parameter) = catch.parameter() {
+                        self.declaration(parameter);
+                    }
+                    self.nodes(catch.block().items());
+                }
+                if let Some(finally) = try_node.finally() {
+                    self.nodes(finally.items());
+                }
+            }
+            Node::UnaryOp(op) => self.node(op.target()),
+            Node::Yield(r#yield) => self.optional(r#yield.expr()),
+            // Await expressions are only valid in async functions, which are not compiled.
+            Node::AwaitExpr(_)
+            | Node::Break(_)
+            | Node::Continue(_)
+            | Node::Const(_)
+            | Node::This
+            | Node::Empty
+            | Node::Error => {}
+        }
+    }
+
+    fn loop_initializer(&mut self, initializer: &IterableLoopInitializer) {
+        match initializer {
+            IterableLoopInitializer::Identifier(ident) => self.name(ident.sym()),
+            IterableLoopInitializer::Var(declaration)
+            | IterableLoopInitializer::Let(declaration)
+            | IterableLoopInitializer::Const(declaration) => self.declaration(declaration),
+            IterableLoopInitializer::DeclarationPattern(pattern) => self.pattern(pattern),
+        }
+    }
+}
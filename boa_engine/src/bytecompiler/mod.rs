@@ -1,6 +1,6 @@
 use crate::{
     builtins::function::ThisMode,
-    environments::BindingLocator,
+    environments::{BindingLocator, CompileTimeEnvironment},
     syntax::ast::{
         node::{
             declaration::{BindingPatternTypeArray, BindingPatternTypeObject, DeclarationPattern},
@@ -8,18 +8,22 @@ use crate::{
             object::{MethodDefinition, PropertyDefinition, PropertyName},
             operator::assign::AssignTarget,
             template::TemplateElement,
-            Declaration, GetConstField, GetField,
+            Call, Case, Declaration, DeclarationList, FormalParameterList, GetConstField, GetField,
+            StatementList,
         },
         op::{AssignOp, BinOp, BitOp, CompOp, LogOp, NumOp, UnaryOp},
-        Const, Node,
+        Const, Node, Span,
     },
-    vm::{BindingOpcode, CodeBlock, Opcode},
+    vm::{BindingOpcode, CodeBlock, JumpTable, JumpTableKey, LazyFunction, Opcode},
     Context, JsBigInt, JsResult, JsString, JsValue,
 };
 use boa_gc::Gc;
 use boa_interner::{Interner, Sym};
+use captures::captured_names;
 use rustc_hash::FxHashMap;
-use std::mem::size_of;
+use std::{mem::size_of, rc::Rc};
+
+mod captures;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 enum Literal {
@@ -61,6 +65,93 @@ enum Access<'a> {
     This,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CallKind {
+    Call,
+    TailCall,
+    New,
+}
+
+/// The kind of a function, which determines how it is bound by the code defining it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FunctionKind {
+    Declaration,
+    Expression,
+    Arrow,
+}
+
+/// Returns the kind, the name, the parameters and the body of a function, and if it is a
+/// generator.
+fn function_parts(
+    function: &Node,
+) -> (
+    FunctionKind,
+    Option<Sym>,
+    &FormalParameterList,
+    &StatementList,
+    bool,
+) {
+    match function {
+        Node::FunctionDecl(function) => (
+            FunctionKind::Declaration,
+            Some(function.name()),
+            function.parameters(),
+            function.body(),
+            false,
+        ),
+        Node::GeneratorDecl(generator) => (
+            FunctionKind::Declaration,
+            Some(generator.name()),
+            generator.parameters(),
+            generator.body(),
+            true,
+        ),
+        Node::FunctionExpr(function) => (
+            FunctionKind::Expression,
+            function.name(),
+            function.parameters(),
+            function.body(),
+            false,
+        ),
+        Node::GeneratorExpr(generator) => (
+            FunctionKind::Expression,
+            generator.name(),
+            generator.parameters(),
+            generator.body(),
+            true,
+        ),
+        Node::ArrowFunctionDecl(function) => (
+            FunctionKind::Arrow,
+            function.name(),
+            function.params(),
+            function.body(),
+            false,
+        ),
+        _ => unreachable!(),
+    }
+}
+
+/// Creates the code block of a function with its attributes and without bytecode, for a function
+/// defined in strict mode code if `strict` is set.
+fn function_code_block(function: &Node, strict: bool) -> CodeBlock {
+    let (kind, name, parameters, body, generator) = function_parts(function);
+
+    let strict = body.strict() || strict;
+    let length = parameters.parameters.len() as u32;
+    let mut code = CodeBlock::new(name.unwrap_or(Sym::EMPTY_STRING), length, strict, true);
+
+    if let FunctionKind::Arrow = kind {
+        code.constructor = false;
+        code.this_mode = ThisMode::Lexical;
+    }
+
+    if generator {
+        code.constructor = false;
+    }
+
+    code
+}
+
 #[derive(Debug)]
 pub struct ByteCompiler<'b> {
     code_block: CodeBlock,
@@ -68,6 +159,11 @@ pub struct ByteCompiler<'b> {
     names_map: FxHashMap<Sym, u32>,
     bindings_map: FxHashMap<BindingLocator, u32>,
     jump_info: Vec<JumpControlInfo>,
+    /// Whether calls in tail position are compiled as tail calls, which is only the case in the
+    /// body of a strict mode function that is not a generator.
+    tail_calls: bool,
+    /// The span of the source code being compiled, if it is known.
+    current_span: Option<Span>,
     context: &'b mut Context,
 }
 
@@ -75,6 +171,9 @@ impl<'b> ByteCompiler<'b> {
     /// Represents a placeholder address that will be patched later.
     const DUMMY_ADDRESS: u32 = u32::MAX;
 
+    /// The smallest number of cases of a `switch` statement that is compiled to a jump table.
+    const MIN_JUMP_TABLE_CASES: usize = 4;
+
     #[inline]
     pub fn new(name: Sym, strict: bool, context: &'b mut Context) -> Self {
         Self {
@@ -83,6 +182,8 @@ impl<'b> ByteCompiler<'b> {
             names_map: FxHashMap::default(),
             bindings_map: FxHashMap::default(),
             jump_info: Vec::new(),
+            tail_calls: false,
+            current_span: None,
             context,
         }
     }
@@ -133,18 +234,31 @@ impl<'b> ByteCompiler<'b> {
         index
     }
 
+    /// Emits an instruction with a binding operand, which is `local` if the binding is stored in
+    /// the call frame.
+    #[inline]
+    fn emit_binding_access(&mut self, opcode: Opcode, local: Opcode, binding: BindingLocator) {
+        let index = self.get_or_insert_binding(binding);
+        if binding.is_local() {
+            self.emit(local, &[index]);
+        } else {
+            self.emit(opcode, &[index]);
+        }
+    }
+
     #[inline]
     fn emit_binding(&mut self, opcode: BindingOpcode, name: Sym) {
         match opcode {
             BindingOpcode::Var => {
                 let binding = self.context.initialize_mutable_binding(name, true);
-                let index = self.get_or_insert_binding(binding);
-                self.emit(Opcode::DefVar, &[index]);
+                self.emit_binding_access(Opcode::DefVar, Opcode::DefVarLocal, binding);
             }
             BindingOpcode::Let => {
                 let binding = self.context.initialize_mutable_binding(name, false);
-                let index = self.get_or_insert_binding(binding);
-                self.emit(Opcode::DefLet, &[index]);
+                if binding.is_local() {
+                    self.emit_opcode(Opcode::PushUndefined);
+                }
+                self.emit_binding_access(Opcode::DefLet, Opcode::DefInitLocal, binding);
             }
             BindingOpcode::InitVar => {
                 let binding = if self.context.has_binding(name) {
@@ -152,32 +266,46 @@ impl<'b> ByteCompiler<'b> {
                 } else {
                     self.context.initialize_mutable_binding(name, true)
                 };
-                let index = self.get_or_insert_binding(binding);
-                self.emit(Opcode::DefInitVar, &[index]);
+                self.emit_binding_access(Opcode::DefInitVar, Opcode::DefInitLocal, binding);
             }
             BindingOpcode::InitLet => {
                 let binding = self.context.initialize_mutable_binding(name, false);
-                let index = self.get_or_insert_binding(binding);
-                self.emit(Opcode::DefInitLet, &[index]);
+                self.emit_binding_access(Opcode::DefInitLet, Opcode::DefInitLocal, binding);
             }
             BindingOpcode::InitArg => {
                 let binding = self.context.initialize_mutable_binding(name, false);
-                let index = self.get_or_insert_binding(binding);
-                self.emit(Opcode::DefInitArg, &[index]);
+                self.emit_binding_access(Opcode::DefInitArg, Opcode::DefInitLocal, binding);
             }
             BindingOpcode::InitConst => {
                 let binding = self.context.initialize_immutable_binding(name);
-                let index = self.get_or_insert_binding(binding);
-                self.emit(Opcode::DefInitConst, &[index]);
+                self.emit_binding_access(Opcode::DefInitConst, Opcode::DefInitLocal, binding);
             }
             BindingOpcode::SetName => {
                 let binding = self.context.set_mutable_binding(name);
-                let index = self.get_or_insert_binding(binding);
-                self.emit(Opcode::SetName, &[index]);
+                self.emit_binding_access(Opcode::SetName, Opcode::SetLocal, binding);
             }
         }
     }
 
+    /// Records in the line table that the next instructions are compiled from the source code at
+    /// `span`.
+    ///
+    /// Does nothing if the span is not known, so that the instructions are attributed to the
+    /// enclosing code.
+    fn set_span(&mut self, span: Option<Span>) {
+        let span = match span {
+            Some(span) if self.current_span != Some(span) => span,
+            _ => return,
+        };
+        self.current_span = Some(span);
+
+        let pc = self.next_opcode_location();
+        match self.code_block.spans.last_mut() {
+            Some(last) if last.0 == pc => last.1 = span,
+            _ => self.code_block.spans.push((pc, span)),
+        }
+    }
+
     #[inline]
     fn next_opcode_location(&mut self) -> u32 {
         assert!(self.code_block.code.len() < u32::MAX as usize);
@@ -298,6 +426,35 @@ impl<'b> ByteCompiler<'b> {
         self.code_block.code[index + 4] = bytes[3];
     }
 
+    /// Pushes a declarative environment, whose sizes are patched when it is popped.
+    #[inline]
+    fn push_declarative_environment(&mut self) -> Label {
+        self.context.push_compile_time_environment(false);
+        let index = self.next_opcode_location();
+        self.emit(
+            Opcode::PushDeclarativeEnvironment,
+            &[
+                Self::DUMMY_ADDRESS,
+                Self::DUMMY_ADDRESS,
+                Self::DUMMY_ADDRESS,
+            ],
+        );
+        Label { index }
+    }
+
+    /// Pops the declarative environment pushed at `label`.
+    #[inline]
+    fn pop_declarative_environment(&mut self, label: Label) {
+        let env = self.context.pop_compile_time_environment();
+        let operands = [env.num_bindings(), env.first_local(), env.num_locals()];
+        let mut index = label.index as usize + size_of::<Opcode>();
+        for operand in operands {
+            let bytes = (operand as u32).to_ne_bytes();
+            self.code_block.code[index..index + bytes.len()].copy_from_slice(&bytes);
+            index += bytes.len();
+        }
+    }
+
     #[inline]
     fn patch_jump(&mut self, label: Label) {
         let target = self.next_opcode_location();
@@ -477,8 +634,7 @@ impl<'b> ByteCompiler<'b> {
         match access {
             Access::Variable { name } => {
                 let binding = self.context.get_binding_value(name);
-                let index = self.get_or_insert_binding(binding);
-                self.emit(Opcode::GetName, &[index]);
+                self.emit_binding_access(Opcode::GetName, Opcode::GetLocal, binding);
             }
             Access::ByName { node } => {
                 let index = self.get_or_insert_name(node.field());
@@ -519,8 +675,7 @@ impl<'b> ByteCompiler<'b> {
         match access {
             Access::Variable { name } => {
                 let binding = self.context.set_mutable_binding(name);
-                let index = self.get_or_insert_binding(binding);
-                self.emit(Opcode::SetName, &[index]);
+                self.emit_binding_access(Opcode::SetName, Opcode::SetLocal, binding);
             }
             Access::ByName { node } => {
                 self.compile_expr(node.obj(), true)?;
@@ -538,13 +693,68 @@ impl<'b> ByteCompiler<'b> {
     }
 
     #[inline]
-    pub fn compile_statement_list(&mut self, list: &[Node], use_expr: bool) -> JsResult<()> {
-        if let Some((last, items)) = list.split_last() {
-            for node in items {
-                self.compile_stmt(node, false)?;
-            }
-            self.compile_stmt(last, use_expr)?;
+    pub fn compile_statement_list(&mut self, list: &StatementList, use_expr: bool) -> JsResult<()> {
+        if list
+            .items()
+            .iter()
+            .any(|node| matches!(node, Node::UsingDeclList(_)))
+        {
+            return self.compile_disposable_statement_list(list, use_expr);
         }
+
+        self.compile_statement_list_items(list, use_expr)
+    }
+
+    /// Compiles the items of a statement list, recording their spans in the line table.
+    fn compile_statement_list_items(
+        &mut self,
+        list: &StatementList,
+        use_expr: bool,
+    ) -> JsResult<()> {
+        let last = list.items().len().saturating_sub(1);
+        for (index, node) in list.items().iter().enumerate() {
+            self.set_span(list.span(index));
+            self.compile_stmt(node, use_expr && index == last)?;
+        }
+        Ok(())
+    }
+
+    /// Compiles a statement list that contains `using` declarations.
+    ///
+    /// The statements are compiled in a new dispose scope, as the block of a `try` statement
+    /// whose `finally` block disposes the declared resources.
+    fn compile_disposable_statement_list(
+        &mut self,
+        list: &StatementList,
+        use_expr: bool,
+    ) -> JsResult<()> {
+        self.emit_opcode(Opcode::PushDisposeScope);
+        self.push_try_control_info(true);
+        let try_start = self.next_opcode_location();
+        self.emit(Opcode::TryStart, &[Self::DUMMY_ADDRESS, 0]);
+
+        self.compile_statement_list_items(list, use_expr)?;
+
+        self.emit_opcode(Opcode::TryEnd);
+        let finally = self.jump();
+        self.patch_jump(Label { index: try_start });
+        self.patch_jump(finally);
+
+        self.emit_opcode(Opcode::FinallyStart);
+        let finally_start_address = self.next_opcode_location();
+        self.push_try_control_info_finally_start(Label {
+            index: finally_start_address,
+        });
+        self.patch_jump_with_target(
+            Label {
+                index: try_start + 4,
+            },
+            finally_start_address,
+        );
+        self.emit_opcode(Opcode::DisposeResources);
+        self.emit_opcode(Opcode::FinallyEnd);
+        self.pop_try_control_info(Some(finally_start_address));
+
         Ok(())
     }
 
@@ -648,8 +858,11 @@ impl<'b> ByteCompiler<'b> {
                         match &unary.target() {
                             Node::Identifier(identifier) => {
                                 let binding = self.context.get_binding_value(identifier.sym());
-                                let index = self.get_or_insert_binding(binding);
-                                self.emit(Opcode::GetNameOrUndefined, &[index]);
+                                self.emit_binding_access(
+                                    Opcode::GetNameOrUndefined,
+                                    Opcode::GetLocalOrUndefined,
+                                    binding,
+                                );
                             }
                             expr => self.compile_expr(expr, true)?,
                         }
@@ -1077,8 +1290,13 @@ impl<'b> ByteCompiler<'b> {
                     }
                 }
 
-                self.emit_opcode(Opcode::PushNewArray);
-                for cooked in template.cookeds() {
+                let site = self.context.realm.next_template_site();
+                let count = template.cookeds().len() as u32;
+
+                let jump_label = self.jump_with_custom_opcode(Opcode::TemplateLookup);
+                self.emit_u64(site);
+
+                for (cooked, raw) in template.cookeds().iter().zip(template.raws()) {
                     if let Some(cooked) = cooked {
                         self.emit_push_literal(Literal::String(
                             self.interner().resolve_expect(*cooked).into(),
@@ -1086,21 +1304,15 @@ impl<'b> ByteCompiler<'b> {
                     } else {
                         self.emit_opcode(Opcode::PushUndefined);
                     }
-                    self.emit_opcode(Opcode::PushValueToArray);
-                }
-                self.emit_opcode(Opcode::Dup);
-
-                self.emit_opcode(Opcode::PushNewArray);
-                for raw in template.raws() {
                     self.emit_push_literal(Literal::String(
                         self.interner().resolve_expect(*raw).into(),
                     ));
-                    self.emit_opcode(Opcode::PushValueToArray);
                 }
 
-                self.emit_opcode(Opcode::Swap);
-                let index = self.get_or_insert_name(Sym::RAW);
-                self.emit(Opcode::SetPropertyByName, &[index]);
+                self.emit(Opcode::TemplateCreate, &[count]);
+                self.emit_u64(site);
+
+                self.patch_jump(jump_label);
 
                 for expr in template.exprs() {
                     self.compile_expr(expr, true)?;
@@ -1208,6 +1420,27 @@ impl<'b> ByteCompiler<'b> {
                     }
                 }
             }
+            Node::UsingDeclList(list) => {
+                let hint = u32::from(matches!(list, DeclarationList::AwaitUsing(_)));
+                for decl in list.as_ref() {
+                    match decl {
+                        Declaration::Identifier { ident, .. } => {
+                            if ident.sym() == Sym::ARGUMENTS {
+                                self.code_block.lexical_name_argument = true;
+                            }
+                            let init = decl
+                                .init()
+                                .expect("using declaration must have initializer");
+                            self.compile_expr(init, true)?;
+                            self.emit(Opcode::AddDisposableResource, &[hint]);
+                            self.emit_binding(BindingOpcode::InitConst, ident.sym());
+                        }
+                        Declaration::Pattern(_) => {
+                            unreachable!("using declarations cannot have binding patterns")
+                        }
+                    }
+                }
+            }
             Node::If(node) => {
                 self.compile_expr(node.cond(), true)?;
                 let jelse = self.jump_if_false();
@@ -1234,8 +1467,7 @@ impl<'b> ByteCompiler<'b> {
                 }
             }
             Node::ForLoop(for_loop) => {
-                self.context.push_compile_time_environment(false);
-                let push_env = self.jump_with_custom_opcode(Opcode::PushDeclarativeEnvironment);
+                let push_env = self.push_declarative_environment();
 
                 if let Some(init) = for_loop.init() {
                     self.create_decls_from_stmt(init)?;
@@ -1273,8 +1505,7 @@ impl<'b> ByteCompiler<'b> {
                 self.pop_loop_control_info();
                 self.emit_opcode(Opcode::LoopEnd);
 
-                let num_bindings = self.context.pop_compile_time_environment().num_bindings();
-                self.patch_jump_with_target(push_env, num_bindings as u32);
+                self.pop_declarative_environment(push_env);
                 self.emit_opcode(Opcode::PopEnvironment);
             }
             Node::ForInLoop(for_in_loop) => {
@@ -1286,8 +1517,7 @@ impl<'b> ByteCompiler<'b> {
                 self.push_loop_control_info_for_of_in_loop(for_in_loop.label(), start_address);
                 self.emit_opcode(Opcode::LoopContinue);
 
-                self.context.push_compile_time_environment(false);
-                let push_env = self.jump_with_custom_opcode(Opcode::PushDeclarativeEnvironment);
+                let push_env = self.push_declarative_environment();
                 let exit = self.jump_with_custom_opcode(Opcode::ForInLoopNext);
 
                 match for_in_loop.init() {
@@ -1295,8 +1525,7 @@ impl<'b> ByteCompiler<'b> {
                         self.context
                             .create_mutable_binding(ident.sym(), true, true)?;
                         let binding = self.context.set_mutable_binding(ident.sym());
-                        let index = self.get_or_insert_binding(binding);
-                        self.emit(Opcode::DefInitVar, &[index]);
+                        self.emit_binding_access(Opcode::DefInitVar, Opcode::DefInitLocal, binding);
                     }
                     IterableLoopInitializer::Var(declaration) => match declaration {
                         Declaration::Identifier { ident, .. } => {
@@ -1346,8 +1575,7 @@ impl<'b> ByteCompiler<'b> {
 
                 self.compile_stmt(for_in_loop.body(), false)?;
 
-                let num_bindings = self.context.pop_compile_time_environment().num_bindings();
-                self.patch_jump_with_target(push_env, num_bindings as u32);
+                self.pop_declarative_environment(push_env);
                 self.emit_opcode(Opcode::PopEnvironment);
 
                 self.emit(Opcode::Jump, &[start_address]);
@@ -1369,8 +1597,7 @@ impl<'b> ByteCompiler<'b> {
                 self.push_loop_control_info_for_of_in_loop(for_of_loop.label(), start_address);
                 self.emit_opcode(Opcode::LoopContinue);
 
-                self.context.push_compile_time_environment(false);
-                let push_env = self.jump_with_custom_opcode(Opcode::PushDeclarativeEnvironment);
+                let push_env = self.push_declarative_environment();
                 let exit = self.jump_with_custom_opcode(Opcode::ForInLoopNext);
 
                 match for_of_loop.init() {
@@ -1378,8 +1605,7 @@ impl<'b> ByteCompiler<'b> {
                         self.context
                             .create_mutable_binding(ident.sym(), true, true)?;
                         let binding = self.context.set_mutable_binding(ident.sym());
-                        let index = self.get_or_insert_binding(binding);
-                        self.emit(Opcode::DefInitVar, &[index]);
+                        self.emit_binding_access(Opcode::DefInitVar, Opcode::DefInitLocal, binding);
                     }
                     IterableLoopInitializer::Var(declaration) => match declaration {
                         Declaration::Identifier { ident, .. } => {
@@ -1429,8 +1655,7 @@ impl<'b> ByteCompiler<'b> {
 
                 self.compile_stmt(for_of_loop.body(), false)?;
 
-                let num_bindings = self.context.pop_compile_time_environment().num_bindings();
-                self.patch_jump_with_target(push_env, num_bindings as u32);
+                self.pop_declarative_environment(push_env);
                 self.emit_opcode(Opcode::PopEnvironment);
 
                 self.emit(Opcode::Jump, &[start_address]);
@@ -1603,12 +1828,10 @@ impl<'b> ByteCompiler<'b> {
                 }
             }
             Node::Block(block) => {
-                self.context.push_compile_time_environment(false);
-                let push_env = self.jump_with_custom_opcode(Opcode::PushDeclarativeEnvironment);
+                let push_env = self.push_declarative_environment();
                 self.create_declarations(block.items())?;
-                self.compile_statement_list(block.items(), use_expr)?;
-                let num_bindings = self.context.pop_compile_time_environment().num_bindings();
-                self.patch_jump_with_target(push_env, num_bindings as u32);
+                self.compile_statement_list(block.statement_list(), use_expr)?;
+                self.pop_declarative_environment(push_env);
                 self.emit_opcode(Opcode::PopEnvironment);
             }
             Node::Throw(throw) => {
@@ -1616,8 +1839,7 @@ impl<'b> ByteCompiler<'b> {
                 self.emit(Opcode::Throw, &[]);
             }
             Node::Switch(switch) => {
-                self.context.push_compile_time_environment(false);
-                let push_env = self.jump_with_custom_opcode(Opcode::PushDeclarativeEnvironment);
+                let push_env = self.push_declarative_environment();
                 for case in switch.cases() {
                     self.create_declarations(case.body().items())?;
                 }
@@ -1627,35 +1849,52 @@ impl<'b> ByteCompiler<'b> {
                 self.push_switch_control_info(None, start_address);
 
                 self.compile_expr(switch.val(), true)?;
-                let mut labels = Vec::with_capacity(switch.cases().len());
-                for case in switch.cases() {
-                    self.compile_expr(case.condition(), true)?;
-                    labels.push(self.jump_with_custom_opcode(Opcode::Case));
-                }
+                let exit = if let Some(keys) = self.jump_table_keys(switch.cases()) {
+                    let index = self.code_block.jump_tables.len();
+                    self.code_block.jump_tables.push(JumpTable::default());
+                    self.emit(Opcode::JumpTable, &[index as u32]);
+                    let exit = self.jump_with_custom_opcode(Opcode::Default);
+
+                    let mut cases = Vec::with_capacity(keys.len());
+                    for (key, case) in keys.into_iter().zip(switch.cases()) {
+                        cases.push((key, self.next_opcode_location()));
+                        self.compile_statement_list(case.body(), false)?;
+                    }
+                    self.code_block.jump_tables[index] = JumpTable::new(cases);
+                    exit
+                } else {
+                    let mut labels = Vec::with_capacity(switch.cases().len());
+                    for case in switch.cases() {
+                        self.compile_expr(case.condition(), true)?;
+                        labels.push(self.jump_with_custom_opcode(Opcode::Case));
+                    }
 
-                let exit = self.jump_with_custom_opcode(Opcode::Default);
+                    let exit = self.jump_with_custom_opcode(Opcode::Default);
 
-                for (label, case) in labels.into_iter().zip(switch.cases()) {
-                    self.patch_jump(label);
-                    self.compile_statement_list(case.body().items(), false)?;
-                }
+                    for (label, case) in labels.into_iter().zip(switch.cases()) {
+                        self.patch_jump(label);
+                        self.compile_statement_list(case.body(), false)?;
+                    }
+                    exit
+                };
 
                 self.patch_jump(exit);
-                if let Some(body) = switch.default() {
-                    self.create_declarations(body)?;
+                if let Some(body) = switch.default_statement_list() {
+                    self.create_declarations(body.items())?;
                     self.compile_statement_list(body, false)?;
                 }
 
                 self.pop_switch_control_info();
 
                 self.emit_opcode(Opcode::LoopEnd);
-                let num_bindings = self.context.pop_compile_time_environment().num_bindings();
-                self.patch_jump_with_target(push_env, num_bindings as u32);
+                self.pop_declarative_environment(push_env);
                 self.emit_opcode(Opcode::PopEnvironment);
             }
             Node::FunctionDecl(_function) => self.function(node, false)?,
             Node::Return(ret) => {
-                if let Some(expr) = ret.expr() {
+                if let Some(Node::Call(call)) = ret.expr().filter(|_| self.in_tail_position()) {
+                    self.compile_call(call, CallKind::TailCall)?;
+                } else if let Some(expr) = ret.expr() {
                     self.compile_expr(expr, true)?;
                 } else {
                     self.emit(Opcode::PushUndefined, &[]);
@@ -1666,14 +1905,12 @@ impl<'b> ByteCompiler<'b> {
                 self.push_try_control_info(t.finally().is_some());
                 let try_start = self.next_opcode_location();
                 self.emit(Opcode::TryStart, &[Self::DUMMY_ADDRESS, 0]);
-                self.context.push_compile_time_environment(false);
-                let push_env = self.jump_with_custom_opcode(Opcode::PushDeclarativeEnvironment);
+                let push_env = self.push_declarative_environment();
 
                 self.create_declarations(t.block().items())?;
-                self.compile_statement_list(t.block().items(), use_expr)?;
+                self.compile_statement_list(t.block().statement_list(), use_expr)?;
 
-                let num_bindings = self.context.pop_compile_time_environment().num_bindings();
-                self.patch_jump_with_target(push_env, num_bindings as u32);
+                self.pop_declarative_environment(push_env);
                 self.emit_opcode(Opcode::PopEnvironment);
                 self.emit_opcode(Opcode::TryEnd);
 
@@ -1687,8 +1924,7 @@ impl<'b> ByteCompiler<'b> {
                     } else {
                         None
                     };
-                    self.context.push_compile_time_environment(false);
-                    let push_env = self.jump_with_custom_opcode(Opcode::PushDeclarativeEnvironment);
+                    let push_env = self.push_declarative_environment();
                     if let Some(decl) = catch.parameter() {
                         match decl {
                             Declaration::Identifier { ident, .. } => {
@@ -1708,10 +1944,9 @@ impl<'b> ByteCompiler<'b> {
                     }
 
                     self.create_declarations(catch.block().items())?;
-                    self.compile_statement_list(catch.block().items(), use_expr)?;
+                    self.compile_statement_list(catch.block().statement_list(), use_expr)?;
 
-                    let num_bindings = self.context.pop_compile_time_environment().num_bindings();
-                    self.patch_jump_with_target(push_env, num_bindings as u32);
+                    self.pop_declarative_environment(push_env);
                     self.emit_opcode(Opcode::PopEnvironment);
                     if let Some(catch_start) = catch_start {
                         self.emit_opcode(Opcode::CatchEnd);
@@ -1736,14 +1971,12 @@ impl<'b> ByteCompiler<'b> {
                         finally_start_address,
                     );
 
-                    self.context.push_compile_time_environment(false);
-                    let push_env = self.jump_with_custom_opcode(Opcode::PushDeclarativeEnvironment);
+                    let push_env = self.push_declarative_environment();
 
                     self.create_declarations(finally.items())?;
-                    self.compile_statement_list(finally.items(), false)?;
+                    self.compile_statement_list(finally.statement_list(), false)?;
 
-                    let num_bindings = self.context.pop_compile_time_environment().num_bindings();
-                    self.patch_jump_with_target(push_env, num_bindings as u32);
+                    self.pop_declarative_environment(push_env);
                     self.emit_opcode(Opcode::PopEnvironment);
 
                     self.emit_opcode(Opcode::FinallyEnd);
@@ -1759,80 +1992,81 @@ impl<'b> ByteCompiler<'b> {
                 self.emit_opcode(Opcode::PushUndefined);
             }
             Node::Empty => {}
+            Node::Error => {
+                return Err(self
+                    .context
+                    .construct_syntax_error("cannot compile a statement that failed to parse"))
+            }
             expr => self.compile_expr(expr, use_expr)?,
         }
         Ok(())
     }
 
     pub(crate) fn function(&mut self, function: &Node, use_expr: bool) -> JsResult<()> {
-        #[derive(Debug, Clone, Copy, PartialEq)]
-        enum FunctionKind {
-            Declaration,
-            Expression,
-            Arrow,
-        }
-
-        let (kind, name, parameters, body, generator) = match function {
-            Node::FunctionDecl(function) => (
-                FunctionKind::Declaration,
-                Some(function.name()),
-                function.parameters(),
-                function.body(),
-                false,
-            ),
-            Node::GeneratorDecl(generator) => (
-                FunctionKind::Declaration,
-                Some(generator.name()),
-                generator.parameters(),
-                generator.body(),
-                true,
-            ),
-            Node::FunctionExpr(function) => (
-                FunctionKind::Expression,
-                function.name(),
-                function.parameters(),
-                function.body(),
-                false,
-            ),
-            Node::GeneratorExpr(generator) => (
-                FunctionKind::Expression,
-                generator.name(),
-                generator.parameters(),
-                generator.body(),
-                true,
-            ),
-            Node::ArrowFunctionDecl(function) => (
-                FunctionKind::Arrow,
-                function.name(),
-                function.params(),
-                function.body(),
-                false,
-            ),
-            _ => unreachable!(),
+        let (kind, name, _, _, generator) = function_parts(function);
+
+        let code = if self.context.lazy_compilation() {
+            let mut code = function_code_block(function, self.code_block.strict);
+            code.lazy = Some(Gc::new(LazyFunction::new(
+                function.clone(),
+                self.context.compile_time_environments(),
+            )));
+            Gc::new(code)
+        } else {
+            Self::compile_function(function, self.code_block.strict, self.context)?
         };
 
-        let strict = body.strict() || self.code_block.strict;
-        let length = parameters.parameters.len() as u32;
-        let mut code = CodeBlock::new(name.unwrap_or(Sym::EMPTY_STRING), length, strict, true);
+        let index = self.code_block.functions.len() as u32;
+        self.code_block.functions.push(code);
 
-        if let FunctionKind::Arrow = kind {
-            code.constructor = false;
-            code.this_mode = ThisMode::Lexical;
+        if generator {
+            self.emit(Opcode::GetGenerator, &[index]);
+        } else {
+            self.emit(Opcode::GetFunction, &[index]);
         }
 
-        if generator {
-            code.constructor = false;
+        match kind {
+            FunctionKind::Declaration => {
+                self.emit_binding(
+                    BindingOpcode::InitVar,
+                    name.expect("function declaration must have a name"),
+                );
+            }
+            FunctionKind::Expression | FunctionKind::Arrow => {
+                if !use_expr {
+                    self.emit(Opcode::Pop, &[]);
+                }
+            }
         }
 
+        Ok(())
+    }
+
+    /// Compiles a function, that is defined in strict mode code if `strict` is set.
+    fn compile_function(
+        function: &Node,
+        strict: bool,
+        context: &mut Context,
+    ) -> JsResult<Gc<CodeBlock>> {
+        let (kind, _, parameters, body, generator) = function_parts(function);
+
+        let code = function_code_block(function, strict);
+        let tail_calls = code.strict && !generator;
+
         let mut compiler = ByteCompiler {
             code_block: code,
             literals_map: FxHashMap::default(),
             names_map: FxHashMap::default(),
             bindings_map: FxHashMap::default(),
             jump_info: Vec::new(),
-            context: self.context,
+            tail_calls,
+            current_span: None,
+            context,
         };
 
+        compiler
+            .context
+            .push_compile_time_frame(captured_names(parameters, body.items()));
         compiler.context.push_compile_time_environment(true);
 
         // An arguments object is added when all of the following conditions are met
@@ -1898,7 +2132,7 @@ impl<'b> ByteCompiler<'b> {
         }
 
         compiler.create_declarations(body.items())?;
-        compiler.compile_statement_list(body.items(), false)?;
+        compiler.compile_statement_list(body, false)?;
 
         if let Some(env_label) = env_label {
             let num_bindings = compiler
@@ -1914,57 +2148,90 @@ impl<'b> ByteCompiler<'b> {
                 .num_bindings();
         }
 
+        let frame = compiler.context.pop_compile_time_frame();
+        compiler.code_block.num_locals = frame.num_locals();
+        for binding in &mut compiler.code_block.bindings {
+            *binding = frame.resolve(*binding);
+        }
+
         compiler.code_block.params = parameters.clone();
 
         // TODO These are redundant if a function returns so may need to check if a function returns and adding these if it doesn't
         compiler.emit(Opcode::PushUndefined, &[]);
         compiler.emit(Opcode::Return, &[]);
 
-        let code = Gc::new(compiler.finish());
+        Ok(Gc::new(compiler.finish()))
+    }
 
-        let index = self.code_block.functions.len() as u32;
-        self.code_block.functions.push(code);
+    /// Compiles a function that is compiled lazily, in the compile time environments that
+    /// enclosed its definition.
+    pub(crate) fn compile_lazy_function(
+        function: &Node,
+        strict: bool,
+        environments: Vec<Rc<CompileTimeEnvironment>>,
+        context: &mut Context,
+    ) -> JsResult<Gc<CodeBlock>> {
+        let environments = context.replace_compile_time_environments(environments);
+        let code = Self::compile_function(function, strict, context);
+        context.replace_compile_time_environments(environments);
+        code
+    }
 
-        if generator {
-            self.emit(Opcode::GetGenerator, &[index]);
-        } else {
-            self.emit(Opcode::GetFunction, &[index]);
+    pub(crate) fn call(&mut self, node: &Node, use_expr: bool) -> JsResult<()> {
+        match node {
+            Node::Call(call) => self.compile_call(call, CallKind::Call)?,
+            Node::New(new) => self.compile_call(new.call(), CallKind::New)?,
+            _ => unreachable!(),
         }
 
-        match kind {
-            FunctionKind::Declaration => {
-                self.emit_binding(
-                    BindingOpcode::InitVar,
-                    name.expect("function declaration must have a name"),
-                );
-            }
-            FunctionKind::Expression | FunctionKind::Arrow => {
-                if !use_expr {
-                    self.emit(Opcode::Pop, &[]);
-                }
-            }
+        if !use_expr {
+            self.emit(Opcode::Pop, &[]);
         }
-
         Ok(())
     }
 
-    pub(crate) fn call(&mut self, node: &Node, use_expr: bool) -> JsResult<()> {
-        #[derive(PartialEq)]
-        enum CallKind {
-            Call,
-            New,
+    /// Returns the keys of a jump table for the cases of a `switch` statement, if there are enough
+    /// cases and they are all integer or string constants.
+    ///
+    /// The conditions of these cases have no side effects, so the cases can be looked up in a
+    /// table instead of being evaluated and compared in order.
+    fn jump_table_keys(&self, cases: &[Case]) -> Option<Vec<JumpTableKey>> {
+        if cases.len() < Self::MIN_JUMP_TABLE_CASES {
+            return None;
         }
+        cases
+            .iter()
+            .map(|case| match case.condition() {
+                Node::Const(Const::Int(integer)) => Some(JumpTableKey::Integer(*integer)),
+                #[allow(clippy::float_cmp)]
+                Node::Const(Const::Num(number)) if f64::from(*number as i32) == *number => {
+                    Some(JumpTableKey::Integer(*number as i32))
+                }
+                Node::Const(Const::String(string)) => Some(JumpTableKey::String(
+                    self.interner().resolve_expect(*string).into(),
+                )),
+                _ => None,
+            })
+            .collect()
+    }
 
-        let (call, kind) = match node {
-            Node::Call(call) => (call, CallKind::Call),
-            Node::New(new) => (new.call(), CallKind::New),
-            _ => unreachable!(),
-        };
+    /// Returns `true` if a `return` statement compiled now is in tail position.
+    ///
+    /// A call in a `try` statement is not in tail position, and neither is a call in a `for-in`
+    /// or `for-of` loop, since the iterator of the loop has to be closed after it returns.
+    fn in_tail_position(&self) -> bool {
+        self.tail_calls
+            && !self
+                .jump_info
+                .iter()
+                .any(|info| info.for_of_in_loop || info.kind == JumpControlInfoKind::Try)
+    }
 
+    fn compile_call(&mut self, call: &Call, kind: CallKind) -> JsResult<()> {
         match call.expr() {
             Node::GetConstField(field) => {
                 self.compile_expr(field.obj(), true)?;
-                if kind == CallKind::Call {
+                if kind != CallKind::New {
                     self.emit(Opcode::Dup, &[]);
                 }
                 let index = self.get_or_insert_name(field.field());
@@ -1972,7 +2239,7 @@ impl<'b> ByteCompiler<'b> {
             }
             Node::GetField(field) => {
                 self.compile_expr(field.obj(), true)?;
-                if kind == CallKind::Call {
+                if kind != CallKind::New {
                     self.emit(Opcode::Dup, &[]);
                 }
                 self.compile_expr(field.field(), true)?;
@@ -1981,7 +2248,7 @@ impl<'b> ByteCompiler<'b> {
             }
             expr => {
                 self.compile_expr(expr, true)?;
-                if kind == CallKind::Call {
+                if kind != CallKind::New {
                     self.emit_opcode(Opcode::This);
                     self.emit_opcode(Opcode::Swap);
                 }
@@ -1992,22 +2259,24 @@ impl<'b> ByteCompiler<'b> {
             self.compile_expr(arg, true)?;
         }
 
+        let enclosing_span = self.current_span;
+        self.set_span(call.span());
+
         let last_is_rest_parameter = matches!(call.args().last(), Some(Node::Spread(_)));
 
         match kind {
-            CallKind::Call if last_is_rest_parameter => {
+            CallKind::Call | CallKind::TailCall if last_is_rest_parameter => {
                 self.emit(Opcode::CallWithRest, &[call.args().len() as u32]);
             }
             CallKind::Call => self.emit(Opcode::Call, &[call.args().len() as u32]),
+            CallKind::TailCall => self.emit(Opcode::TailCall, &[call.args().len() as u32]),
             CallKind::New if last_is_rest_parameter => {
                 self.emit(Opcode::NewWithRest, &[call.args().len() as u32]);
             }
             CallKind::New => self.emit(Opcode::New, &[call.args().len() as u32]),
         }
 
-        if !use_expr {
-            self.emit(Opcode::Pop, &[]);
-        }
+        self.set_span(enclosing_span);
         Ok(())
     }
 
@@ -2299,6 +2568,17 @@ impl<'b> ByteCompiler<'b> {
                     }
                 }
             }
+            Node::UsingDeclList(list) => {
+                for decl in list.as_ref() {
+                    if let Declaration::Identifier { ident, .. } = decl {
+                        let ident = ident.sym();
+                        if ident == Sym::ARGUMENTS {
+                            has_identifier_argument = true;
+                        }
+                        self.context.create_immutable_binding(ident)?;
+                    }
+                }
+            }
             Node::FunctionDecl(decl) => {
                 let ident = decl.name();
                 if ident == Sym::ARGUMENTS {
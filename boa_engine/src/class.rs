@@ -54,6 +54,18 @@
 //!             Ok(JsValue::undefined())
 //!         });
 //!
+//!         // `animal.isPet` is a getter reading the native data, which throws a `TypeError` if
+//!         // `this` is not an `Animal`.
+//!         class.native_accessor(
+//!             "isPet",
+//!             Some(|this, _args, context| {
+//!                 let animal = Self::from_this(this, context)?;
+//!                 Ok(matches!(*animal, Self::Cat | Self::Dog).into())
+//!             }),
+//!             None,
+//!             Attribute::CONFIGURABLE,
+//!         );
+//!
 //!         Ok(())
 //!     }
 //! }
@@ -63,9 +75,12 @@
 
 use crate::{
     builtins::function::NativeFunctionSignature,
-    object::{ConstructorBuilder, JsObject, NativeObject, ObjectData, PROTOTYPE},
+    object::{
+        ConstructorBuilder, FunctionBinding, FunctionBuilder, JsObject, NativeObject, Object,
+        ObjectData, Ref, RefMut, PROTOTYPE,
+    },
     property::{Attribute, PropertyDescriptor, PropertyKey},
-    Context, JsResult, JsValue,
+    Context, JsResult, JsString, JsValue,
 };
 
 /// Native class.
@@ -82,6 +97,34 @@ pub trait Class: NativeObject + Sized {
 
     /// Initializes the internals and the methods of the class.
     fn init(class: &mut ClassBuilder<'_>) -> JsResult<()>;
+
+    /// Returns the native data of `this`, or throws a `TypeError` if `this` is not an instance of
+    /// the class.
+    ///
+    /// The native data isn't visible from JavaScript, so its fields act as the private internal
+    /// slots of the instances.
+    fn from_this<'a>(this: &'a JsValue, context: &mut Context) -> JsResult<Ref<'a, Self>> {
+        match this.as_object().and_then(JsObject::downcast_ref::<Self>) {
+            Some(data) => Ok(data),
+            None => context.throw_type_error(format!("'this' is not a {}", Self::NAME)),
+        }
+    }
+
+    /// Returns the native data of `this` mutably, or throws a `TypeError` if `this` is not an
+    /// instance of the class.
+    fn from_this_mut<'a>(
+        this: &'a JsValue,
+        context: &mut Context,
+    ) -> JsResult<RefMut<'a, Object, Self>> {
+        match this.as_object().filter(|object| object.is::<Self>()) {
+            Some(object) => Ok(RefMut::map(object.borrow_mut(), |object| {
+                object
+                    .downcast_mut::<Self>()
+                    .expect("the type of the native data was checked")
+            })),
+            None => context.throw_type_error(format!("'this' is not a {}", Self::NAME)),
+        }
+    }
 }
 
 /// This is a wrapper around `Class::constructor` that sets the internal data of a class.
@@ -174,18 +217,19 @@ impl<'context> ClassBuilder<'context> {
 
     /// Add a method to the class.
     ///
-    /// It is added to `prototype`.
+    /// It is added to `prototype`. Protocol methods like `[Symbol.iterator]` are added with a
+    /// `(key, name)` binding, like `(WellKnownSymbols::iterator(), "[Symbol.iterator]")`.
     #[inline]
-    pub fn method<N>(
+    pub fn method<B>(
         &mut self,
-        name: N,
+        binding: B,
         length: usize,
         function: NativeFunctionSignature,
     ) -> &mut Self
     where
-        N: AsRef<str>,
+        B: Into<FunctionBinding>,
     {
-        self.builder.method(function, name.as_ref(), length);
+        self.builder.method(function, binding, length);
         self
     }
 
@@ -193,16 +237,16 @@ impl<'context> ClassBuilder<'context> {
     ///
     /// It is added to class object itself.
     #[inline]
-    pub fn static_method<N>(
+    pub fn static_method<B>(
         &mut self,
-        name: N,
+        binding: B,
         length: usize,
         function: NativeFunctionSignature,
     ) -> &mut Self
     where
-        N: AsRef<str>,
+        B: Into<FunctionBinding>,
     {
-        self.builder.static_method(function, name.as_ref(), length);
+        self.builder.static_method(function, binding, length);
         self
     }
 
@@ -268,6 +312,104 @@ impl<'context> ClassBuilder<'context> {
         self
     }
 
+    /// Add an accessor property to the class, with native functions as its getter and setter.
+    ///
+    /// It is added to `prototype`. The functions are named `get <name>` and `set <name>`.
+    #[inline]
+    pub fn native_accessor<B>(
+        &mut self,
+        binding: B,
+        get: Option<NativeFunctionSignature>,
+        set: Option<NativeFunctionSignature>,
+        attribute: Attribute,
+    ) -> &mut Self
+    where
+        B: Into<FunctionBinding>,
+    {
+        let binding = binding.into();
+        let (get, set) = self.accessor_functions(&binding.name, get, set);
+        self.builder.accessor(binding.binding, get, set, attribute);
+        self
+    }
+
+    /// Add a static accessor property to the class, with native functions as its getter and
+    /// setter.
+    ///
+    /// It is added to class object itself. The functions are named `get <name>` and
+    /// `set <name>`.
+    #[inline]
+    pub fn static_native_accessor<B>(
+        &mut self,
+        binding: B,
+        get: Option<NativeFunctionSignature>,
+        set: Option<NativeFunctionSignature>,
+        attribute: Attribute,
+    ) -> &mut Self
+    where
+        B: Into<FunctionBinding>,
+    {
+        let binding = binding.into();
+        let (get, set) = self.accessor_functions(&binding.name, get, set);
+        self.builder
+            .static_accessor(binding.binding, get, set, attribute);
+        self
+    }
+
+    /// Creates the getter and the setter function objects of an accessor.
+    fn accessor_functions(
+        &mut self,
+        name: &JsString,
+        get: Option<NativeFunctionSignature>,
+        set: Option<NativeFunctionSignature>,
+    ) -> (Option<JsObject>, Option<JsObject>) {
+        let context = self.builder.context();
+        let get = get.map(|get| {
+            FunctionBuilder::native(context, get)
+                .name(format!("get {name}"))
+                .length(0)
+                .constructor(false)
+                .build()
+        });
+        let set = set.map(|set| {
+            FunctionBuilder::native(context, set)
+                .name(format!("set {name}"))
+                .length(1)
+                .constructor(false)
+                .build()
+        });
+        (get, set)
+    }
+
+    /// Make the class extend `superclass`, the constructor of a class visible from JavaScript.
+    ///
+    /// The prototype of the class inherits from `superclass.prototype`, and the class object
+    /// inherits the static members of `superclass`. The instances of the class only hold the
+    /// native data of the class, so the native methods of a native superclass don't accept them,
+    /// but its JavaScript methods and its accessors of inherited data do.
+    ///
+    /// # Errors
+    ///
+    /// Throws a `TypeError` if `superclass` is not a constructor, or if its `prototype` property
+    /// is neither an object nor `null`.
+    pub fn superclass(&mut self, superclass: &JsObject) -> JsResult<&mut Self> {
+        let context = self.builder.context();
+        if !superclass.is_constructor() {
+            return context.throw_type_error("the superclass of a class must be a constructor");
+        }
+        let prototype = match superclass.get(PROTOTYPE, context)? {
+            JsValue::Object(prototype) => Some(prototype),
+            JsValue::Null => None,
+            _ => {
+                return context
+                    .throw_type_error("the prototype of a superclass must be an object or null")
+            }
+        };
+        self.builder
+            .inherit(prototype)
+            .custom_prototype(superclass.clone());
+        Ok(self)
+    }
+
     /// Add a property descriptor to the class, with the specified attribute.
     ///
     /// It is added to `prototype`.
@@ -0,0 +1,89 @@
+//! The instruction budget of a [`Context`], which bounds the work of the scripts it runs.
+//!
+//! Embedders set the budget with [`ContextBuilder::instruction_budget`]. Each instruction run by
+//! the VM consumes one unit of fuel, and the budget is refilled when the context starts
+//! evaluating a script and when it starts running the pending jobs. When the fuel runs out, the
+//! callback set with [`ContextBuilder::fuel_exhausted_callback`] can add more fuel, otherwise the
+//! script is terminated with a `RangeError`, which can't be caught, like an uncatchable
+//! [interruption][crate::context::ContextInterrupter].
+//!
+//! Unlike a timer, the budget stops a script at the same instruction on every run.
+//!
+//! [`ContextBuilder::instruction_budget`]: crate::context::ContextBuilder::instruction_budget
+//! [`ContextBuilder::fuel_exhausted_callback`]: crate::context::ContextBuilder::fuel_exhausted_callback
+
+use crate::{Context, JsResult};
+use std::fmt;
+
+/// The instruction budget of a [`Context`], and the callback of the embedder.
+pub(crate) struct Fuel {
+    budget: u64,
+    /// The instructions that can still run before the callback is called.
+    remaining: u64,
+    /// The fuel given since the budget was refilled, including the fuel added by the callback.
+    granted: u64,
+    callback: Option<Box<dyn FnMut(u64) -> Option<u64>>>,
+}
+
+impl Fuel {
+    pub(crate) fn new(budget: u64, callback: Option<Box<dyn FnMut(u64) -> Option<u64>>>) -> Self {
+        Self {
+            budget,
+            remaining: budget,
+            granted: budget,
+            callback,
+        }
+    }
+}
+
+impl fmt::Debug for Fuel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Fuel")
+            .field("budget", &self.budget)
+            .field("remaining", &self.remaining)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Context {
+    /// Returns the fuel left in the instruction budget, or `None` if the context has no budget.
+    #[inline]
+    pub fn remaining_fuel(&self) -> Option<u64> {
+        self.fuel.as_ref().map(|fuel| fuel.remaining)
+    }
+
+    /// Refills the instruction budget.
+    #[inline]
+    pub(crate) fn refuel(&mut self) {
+        if let Some(fuel) = &mut self.fuel {
+            fuel.remaining = fuel.budget;
+            fuel.granted = fuel.budget;
+        }
+    }
+
+    /// Consumes the fuel of an instruction, and terminates the script if the fuel ran out and
+    /// the callback didn't add more.
+    #[inline]
+    pub(crate) fn consume_fuel(&mut self) -> JsResult<()> {
+        let fuel = match &mut self.fuel {
+            Some(fuel) => fuel,
+            None => return Ok(()),
+        };
+        if fuel.remaining == 0 {
+            let granted = fuel.granted;
+            let added = fuel
+                .callback
+                .as_mut()
+                .and_then(|callback| callback(granted))
+                .unwrap_or(0);
+            if added == 0 {
+                self.terminate();
+                return self.throw_range_error("instruction budget exhausted");
+            }
+            fuel.remaining = added;
+            fuel.granted = fuel.granted.saturating_add(added);
+        }
+        fuel.remaining -= 1;
+        Ok(())
+    }
+}
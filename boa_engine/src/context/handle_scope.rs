@@ -0,0 +1,55 @@
+//! Handle scopes, which keep the temporary values of a builtin alive together.
+//!
+//! A builtin that creates many temporary values, like the matches of a global regular
+//! expression, can push them onto the handle stack of the context instead of collecting them in
+//! its own vector. The values are rooted as long as they are on the stack, and all the values of a
+//! scope are released in one operation when the scope ends, even if the builtin returns early
+//! with an error.
+
+use crate::{builtins::Array, object::JsObject, Context, JsValue};
+
+/// A scope of the handle stack of a [`Context`], opened by [`Context::with_handle_scope`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct HandleScope {
+    /// The length of the handle stack when the scope was opened.
+    base: usize,
+}
+
+impl HandleScope {
+    /// Pushes a temporary value onto the scope.
+    #[inline]
+    pub(crate) fn push(self, value: JsValue, context: &mut Context) {
+        debug_assert!(context.handles.len() >= self.base);
+        context.handles.push(value);
+    }
+
+    /// Moves the values of the scope to a new array, in the order they were pushed.
+    pub(crate) fn take_array(self, context: &mut Context) -> JsObject {
+        let array = Array::array_create(0, None, context)
+            .expect("creating an empty array with the default prototype must not fail");
+        self.append_to(&array, context);
+        array
+    }
+
+    /// Moves the values of the scope to `array`, which must be an empty array created by
+    /// `ArrayCreate(0)`.
+    pub(crate) fn append_to(self, array: &JsObject, context: &mut Context) {
+        Array::extend_new_array(array, context.handles.drain(self.base..));
+    }
+}
+
+impl Context {
+    /// Runs `f` with a new handle scope, and releases the values of the scope when `f` returns.
+    ///
+    /// Scopes can be nested, but values must only be pushed onto the innermost scope, since the
+    /// values above the base of a scope are released when it ends.
+    pub(crate) fn with_handle_scope<F, T>(&mut self, f: F) -> T
+    where
+        F: FnOnce(HandleScope, &mut Self) -> T,
+    {
+        let base = self.handles.len();
+        let result = f(HandleScope { base }, self);
+        self.handles.truncate(base);
+        result
+    }
+}
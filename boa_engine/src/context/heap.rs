@@ -0,0 +1,139 @@
+//! The garbage collected heap of a [`Context`]: its size limit and the collection hooks.
+//!
+//! The heap is checked when a loop starts a new iteration and when a function is called, since a
+//! script can't allocate without bounds otherwise.
+//!
+//! Embedders set the limit with [`ContextBuilder::heap_limit`]. If the heap is still larger than
+//! the limit after a collection, the callback set with [`ContextBuilder::heap_limit_callback`]
+//! can raise the limit, otherwise a `RangeError` is thrown, which aborts the script unless it
+//! catches the error. Only the objects managed by the garbage collector are counted, not the
+//! memory allocated for the contents of strings or array buffers.
+//!
+//! The hooks set with [`Context::set_before_gc_hook`] and [`Context::set_after_gc_hook`] receive
+//! the [`GcStats`] of the collector. The collector also runs automatically during allocations,
+//! when no code can run, so the automatic collections are only reported to the after hook, at the
+//! next check of the heap.
+//!
+//! [`ContextBuilder::heap_limit`]: crate::context::ContextBuilder::heap_limit
+//! [`ContextBuilder::heap_limit_callback`]: crate::context::ContextBuilder::heap_limit_callback
+
+use crate::{Context, JsResult};
+use boa_gc::GcStats;
+use std::{fmt, time::Duration};
+
+/// The heap limit of a [`Context`], in bytes, and the callback of the embedder.
+pub(crate) struct HeapLimit {
+    limit: usize,
+    callback: Option<Box<dyn FnMut(usize, usize) -> usize>>,
+}
+
+impl HeapLimit {
+    pub(crate) fn new(
+        limit: usize,
+        callback: Option<Box<dyn FnMut(usize, usize) -> usize>>,
+    ) -> Self {
+        Self { limit, callback }
+    }
+}
+
+impl fmt::Debug for HeapLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HeapLimit")
+            .field("limit", &self.limit)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The hooks of the embedder notified of the garbage collections.
+#[derive(Default)]
+pub(crate) struct GcHooks {
+    before: Option<Box<dyn FnMut(&GcStats)>>,
+    after: Option<Box<dyn FnMut(&GcStats)>>,
+    /// The number of collections that were reported to the after hook.
+    reported: usize,
+}
+
+impl fmt::Debug for GcHooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GcHooks")
+            .field("reported", &self.reported)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Context {
+    /// Sets the hook called before each collection started by the context, with the statistics
+    /// of the collector.
+    #[inline]
+    pub fn set_before_gc_hook<F>(&mut self, hook: F)
+    where
+        F: FnMut(&GcStats) + 'static,
+    {
+        self.gc_hooks.before = Some(Box::new(hook));
+    }
+
+    /// Sets the hook called after the collections, with the statistics of the collector.
+    ///
+    /// The collections started by the context are reported when they end, and the automatic
+    /// collections are reported the next time a loop iterates or a function is called.
+    #[inline]
+    pub fn set_after_gc_hook<F>(&mut self, hook: F)
+    where
+        F: FnMut(&GcStats) + 'static,
+    {
+        self.gc_hooks.reported = boa_gc::stats().collections;
+        self.gc_hooks.after = Some(Box::new(hook));
+    }
+
+    /// Runs a full garbage collection, calling the collection hooks, and returns its duration.
+    pub fn collect_garbage(&mut self) -> Duration {
+        if let Some(before) = &mut self.gc_hooks.before {
+            before(&boa_gc::stats());
+        }
+        let pause = boa_gc::collect();
+        self.report_collections();
+        pause
+    }
+
+    /// Calls the after hook if collections happened since the last call.
+    fn report_collections(&mut self) {
+        if let Some(after) = &mut self.gc_hooks.after {
+            let stats = boa_gc::stats();
+            if stats.collections != self.gc_hooks.reported {
+                self.gc_hooks.reported = stats.collections;
+                after(&stats);
+            }
+        }
+    }
+
+    /// Reports the automatic collections to the after hook, and throws a `RangeError` if the heap
+    /// is larger than the heap limit of the context, even after a collection and after calling
+    /// the callback of the embedder.
+    pub(crate) fn check_heap(&mut self) -> JsResult<()> {
+        self.report_collections();
+
+        match &self.heap_limit {
+            Some(heap_limit) if boa_gc::allocated_bytes() > heap_limit.limit => {}
+            _ => return Ok(()),
+        }
+        self.collect_garbage();
+
+        let size = boa_gc::allocated_bytes();
+        let heap_limit = self
+            .heap_limit
+            .as_mut()
+            .expect("the heap limit was checked above");
+        if size <= heap_limit.limit {
+            return Ok(());
+        }
+        if let Some(callback) = &mut heap_limit.callback {
+            heap_limit.limit = callback(size, heap_limit.limit);
+            if size <= heap_limit.limit {
+                return Ok(());
+            }
+        }
+
+        let limit = heap_limit.limit;
+        self.throw_range_error(format!("Heap limit of {limit} bytes exceeded"))
+    }
+}
@@ -0,0 +1,66 @@
+//! The host hooks of a context, which let the embedder customize the host defined behavior of
+//! the specification.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-host-hooks-summary
+
+use crate::{
+    builtins::promise::{OperationType, PromiseEvent},
+    object::JsObject,
+    Context, JsResult,
+};
+use std::fmt;
+
+/// The host defined hooks of a [`Context`], set with [`ContextBuilder::host_hooks`].
+///
+/// Every hook has a default implementation following the recommendations of the specification,
+/// so an embedder only implements the hooks it needs.
+///
+/// The time zone and the locale of the host are supplied with
+/// [`ContextBuilder::time_zone_provider`] and [`Context::set_default_locale`].
+///
+/// [`ContextBuilder::host_hooks`]: crate::context::ContextBuilder::host_hooks
+/// [`ContextBuilder::time_zone_provider`]: crate::context::ContextBuilder::time_zone_provider
+pub trait HostHooks: fmt::Debug {
+    /// Abstract operation `HostEnsureCanCompileStrings ( calleeRealm )`
+    ///
+    /// Called before the `Function` constructor compiles its arguments, and throws to forbid
+    /// compiling strings as code. By default, compiling strings is allowed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostensurecancompilestrings
+    fn ensure_can_compile_strings(&self, _context: &mut Context) -> JsResult<()> {
+        Ok(())
+    }
+
+    /// Abstract operation `HostPromiseRejectionTracker ( promise, operation )`
+    ///
+    /// Called when a promise is rejected without any handler, and when a handler is added to
+    /// such a promise afterwards. By default, rejections are not tracked.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-promise-rejection-tracker
+    fn promise_rejection_tracker(
+        &self,
+        _promise: &JsObject,
+        _operation: OperationType,
+        _context: &mut Context,
+    ) {
+    }
+
+    /// Called on the events of the lifecycle of a promise, listed by [`PromiseEvent`]. By
+    /// default, the events are ignored.
+    fn promise_hook(&self, _event: PromiseEvent, _promise: &JsObject, _context: &mut Context) {}
+}
+
+/// The default host hooks, which implement the defaults of every hook.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefaultHooks;
+
+impl HostHooks for DefaultHooks {}
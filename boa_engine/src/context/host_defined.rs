@@ -0,0 +1,110 @@
+//! Storage for the state of the embedder.
+//!
+//! Native functions only receive the [`Context`](super::Context) they run in, so the state of
+//! the application they need, like a database connection or the configuration of the current
+//! request, would otherwise have to be kept in thread-locals or statics. A [`HostDefined`] stores
+//! one value of each type, and is available on the context with
+//! [`Context::host_defined`](super::Context::host_defined) and on each realm with
+//! [`Context::realm_host_defined`](super::Context::realm_host_defined).
+
+use rustc_hash::FxHashMap;
+use std::{
+    any::{Any, TypeId},
+    fmt,
+};
+
+/// A map storing at most one value of each type, defined by the host.
+///
+/// The values are not traced by the garbage collector, so the JavaScript values they hold stay
+/// alive until they are removed from the map.
+///
+/// # Examples
+///
+/// ```
+/// use boa_engine::Context;
+///
+/// struct RequestId(u32);
+///
+/// let mut context = Context::default();
+/// context.host_defined_mut().insert(RequestId(7));
+///
+/// let id = context
+///     .host_defined()
+///     .get::<RequestId>()
+///     .map(|id| id.0);
+/// assert_eq!(id, Some(7));
+/// ```
+#[derive(Default)]
+pub struct HostDefined {
+    values: FxHashMap<TypeId, Box<dyn Any>>,
+}
+
+impl HostDefined {
+    /// Stores `value`, returning the previous value of the same type, if any.
+    pub fn insert<T: 'static>(&mut self, value: T) -> Option<T> {
+        self.values
+            .insert(TypeId::of::<T>(), Box::new(value))
+            .map(Self::downcast)
+    }
+
+    /// Returns `true` if a value of type `T` is stored.
+    #[inline]
+    pub fn has<T: 'static>(&self) -> bool {
+        self.values.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Returns a reference to the stored value of type `T`, if any.
+    #[inline]
+    pub fn get<T: 'static>(&self) -> Option<&T> {
+        self.values
+            .get(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_ref())
+    }
+
+    /// Returns a mutable reference to the stored value of type `T`, if any.
+    #[inline]
+    pub fn get_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.values
+            .get_mut(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_mut())
+    }
+
+    /// Returns a mutable reference to the stored value of type `T`, storing the value returned
+    /// by `default` first if there is none.
+    pub fn get_or_insert_with<T, F>(&mut self, default: F) -> &mut T
+    where
+        T: 'static,
+        F: FnOnce() -> T,
+    {
+        self.values
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(default()))
+            .downcast_mut()
+            .expect("the values are stored under the identifier of their type")
+    }
+
+    /// Removes the stored value of type `T` and returns it, if any.
+    pub fn remove<T: 'static>(&mut self) -> Option<T> {
+        self.values.remove(&TypeId::of::<T>()).map(Self::downcast)
+    }
+
+    /// Removes all the stored values.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+
+    fn downcast<T: 'static>(value: Box<dyn Any>) -> T {
+        *value
+            .downcast()
+            .expect("the values are stored under the identifier of their type")
+    }
+}
+
+impl fmt::Debug for HostDefined {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HostDefined")
+            .field("len", &self.values.len())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,116 @@
+//! The ICU4X data used by the `Intl` services of a [`Context`].
+//!
+//! By default, the engine uses the data compiled into the ICU4X crates. Embedders can replace it
+//! with their own [`BoaProvider`] using [`ContextBuilder::icu_provider`], to tune the binary size
+//! and the locale coverage of their application.
+//!
+//! The provider supplies the data of `Intl.DisplayNames`, `Intl.Locale`, `Intl.PluralRules`,
+//! `Intl.Segmenter` and the number formatting of `toLocaleString`, and the likely subtags and
+//! alias data used to negotiate and canonicalize locales. `Intl.DateTimeFormat` and
+//! `Intl.DurationFormat` don't read locale data: they only support English, with built-in data.
+//!
+//! [`Context`]: crate::Context
+//! [`ContextBuilder::icu_provider`]: crate::context::ContextBuilder::icu_provider
+
+use icu_locid_transform::{LocaleCanonicalizer, LocaleExpander};
+use icu_provider::{
+    AnyProvider, AsDeserializingBufferProvider, AsDowncastingAnyProvider, BufferProvider,
+    DataError, DataProvider, DataRequest, DataResponse, KeyedDataMarker, MaybeSendSync,
+};
+use serde::Deserialize;
+use std::fmt;
+use yoke::{trait_hack::YokeTraitHack, Yokeable};
+use zerofrom::ZeroFrom;
+
+/// An ICU4X data provider supplied by the embedder.
+pub enum BoaProvider {
+    /// A provider of serialized data, such as a `BlobDataProvider` or an `FsDataProvider`.
+    Buffer(Box<dyn BufferProvider>),
+    /// A provider of deserialized data, such as baked data exported with `impl_any_provider!`.
+    Any(Box<dyn AnyProvider>),
+}
+
+impl fmt::Debug for BoaProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Buffer(_) => f.write_str("BoaProvider::Buffer(..)"),
+            Self::Any(_) => f.write_str("BoaProvider::Any(..)"),
+        }
+    }
+}
+
+impl<M> DataProvider<M> for BoaProvider
+where
+    M: KeyedDataMarker + 'static,
+    for<'de> YokeTraitHack<<M::Yokeable as Yokeable<'de>>::Output>: Deserialize<'de>,
+    for<'a> YokeTraitHack<<M::Yokeable as Yokeable<'a>>::Output>: Clone,
+    M::Yokeable: ZeroFrom<'static, M::Yokeable> + MaybeSendSync,
+{
+    fn load(&self, req: DataRequest<'_>) -> Result<DataResponse<M>, DataError> {
+        match self {
+            Self::Buffer(provider) => provider.as_ref().as_deserializing().load(req),
+            Self::Any(provider) => provider.as_ref().as_downcasting().load(req),
+        }
+    }
+}
+
+/// The ICU4X data of a [`Context`](crate::Context).
+pub(crate) struct Icu {
+    /// The provider of the embedder, or `None` to use the compiled data.
+    provider: Option<BoaProvider>,
+    /// The canonicalizer of `CanonicalizeUnicodeLocaleId`.
+    locale_canonicalizer: LocaleCanonicalizer,
+    /// The expander of the Add and Remove Likely Subtags algorithms.
+    locale_expander: LocaleExpander,
+}
+
+impl Icu {
+    /// Creates the ICU4X data from the provider of the embedder.
+    ///
+    /// Returns an error if the provider doesn't have the data needed to canonicalize locales.
+    pub(crate) fn new(provider: BoaProvider) -> Result<Self, DataError> {
+        let locale_canonicalizer = LocaleCanonicalizer::try_new_unstable(&provider)?;
+        let locale_expander = LocaleExpander::try_new_extended_unstable(&provider)?;
+        Ok(Self {
+            provider: Some(provider),
+            locale_canonicalizer,
+            locale_expander,
+        })
+    }
+
+    /// Gets the provider of the embedder, or `None` if the compiled data must be used.
+    #[inline]
+    pub(crate) fn provider(&self) -> Option<&BoaProvider> {
+        self.provider.as_ref()
+    }
+
+    /// Gets the locale canonicalizer.
+    #[inline]
+    pub(crate) fn locale_canonicalizer(&self) -> &LocaleCanonicalizer {
+        &self.locale_canonicalizer
+    }
+
+    /// Gets the locale expander.
+    #[inline]
+    pub(crate) fn locale_expander(&self) -> &LocaleExpander {
+        &self.locale_expander
+    }
+}
+
+impl Default for Icu {
+    fn default() -> Self {
+        Self {
+            provider: None,
+            locale_canonicalizer: LocaleCanonicalizer::new(),
+            locale_expander: LocaleExpander::new_extended(),
+        }
+    }
+}
+
+impl fmt::Debug for Icu {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Icu")
+            .field("provider", &self.provider)
+            .finish_non_exhaustive()
+    }
+}
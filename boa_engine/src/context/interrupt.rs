@@ -0,0 +1,103 @@
+//! Interruption of running scripts, requested from any thread.
+//!
+//! A [`ContextInterrupter`] sets a flag shared with its [`Context`], which the VM checks before
+//! each instruction. When it sees the flag, the VM clears it and throws an `Error` at the current
+//! instruction. By default, the error terminates the script: it can't be caught, `finally`
+//! blocks don't run, and no other instruction runs until the error reaches the code that called
+//! into the engine. With
+//! [`ContextBuilder::catchable_interrupts`], the error can be caught like any other.
+//!
+//! [`ContextBuilder::catchable_interrupts`]: crate::context::ContextBuilder::catchable_interrupts
+
+use crate::{Context, JsResult};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+/// A handle that interrupts the scripts running in a [`Context`], created with
+/// [`Context::interrupter`].
+///
+/// The handle can be sent to other threads, for example to a watchdog that stops the scripts
+/// running for too long.
+#[derive(Debug, Clone)]
+pub struct ContextInterrupter(Arc<AtomicBool>);
+
+impl ContextInterrupter {
+    /// Interrupts the script running in the context.
+    ///
+    /// If no script is running, the next script run by the context is interrupted as soon as it
+    /// starts.
+    #[inline]
+    pub fn interrupt(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if an interruption was requested, and the context didn't handle it yet.
+    #[inline]
+    pub fn is_interrupted(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// The interruption state of a context.
+#[derive(Debug, Default)]
+pub(crate) struct Interrupt {
+    /// The flag set by the interrupters of the context.
+    requested: Arc<AtomicBool>,
+    /// Whether scripts can catch the errors thrown by interruptions.
+    catchable: bool,
+    /// Whether an uncatchable interruption is unwinding the running script.
+    terminating: bool,
+}
+
+impl Interrupt {
+    /// Creates the interruption state of a context.
+    pub(crate) fn new(catchable: bool) -> Self {
+        Self {
+            catchable,
+            ..Self::default()
+        }
+    }
+}
+
+impl Context {
+    /// Creates a handle that interrupts the scripts running in the context, from any thread.
+    #[inline]
+    pub fn interrupter(&self) -> ContextInterrupter {
+        ContextInterrupter(self.interrupt.requested.clone())
+    }
+
+    /// Throws the interruption error if an interruption was requested, or if an uncatchable
+    /// interruption is unwinding the running script, since a builtin might have swallowed the
+    /// error.
+    #[inline]
+    pub(crate) fn check_interrupt(&mut self) -> JsResult<()> {
+        if !self.interrupt.terminating && !self.interrupt.requested.swap(false, Ordering::Relaxed) {
+            return Ok(());
+        }
+        if !self.interrupt.catchable {
+            self.terminate();
+        }
+        self.throw_error("script execution was interrupted")
+    }
+
+    /// Makes the error being thrown uncatchable, so it terminates the running script.
+    #[inline]
+    pub(crate) fn terminate(&mut self) {
+        self.interrupt.terminating = true;
+    }
+
+    /// Returns `true` if an uncatchable interruption is unwinding the running script, so the
+    /// error must not be caught.
+    #[inline]
+    pub(crate) fn is_terminating(&self) -> bool {
+        self.interrupt.terminating
+    }
+
+    /// Ends the termination of the running script, once the error reaches the host.
+    #[inline]
+    pub(crate) fn finish_termination(&mut self) {
+        self.interrupt.terminating = false;
+    }
+}
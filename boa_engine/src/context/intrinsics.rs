@@ -1,6 +1,9 @@
+#[cfg(feature = "intl")]
+use crate::builtins::intl::segmenter::Segments;
 use crate::{
     builtins::{error::r#type::create_throw_type_error, iterable::IteratorPrototypes},
     object::{JsObject, ObjectData},
+    property::PropertyKey,
     Context,
 };
 
@@ -76,6 +79,7 @@ pub struct StandardConstructors {
     function: StandardConstructor,
     generator: StandardConstructor,
     generator_function: StandardConstructor,
+    iterator: StandardConstructor,
     array: StandardConstructor,
     bigint: StandardConstructor,
     number: StandardConstructor,
@@ -91,6 +95,7 @@ pub struct StandardConstructors {
     eval_error: StandardConstructor,
     uri_error: StandardConstructor,
     aggregate_error: StandardConstructor,
+    suppressed_error: StandardConstructor,
     map: StandardConstructor,
     set: StandardConstructor,
     typed_array: StandardConstructor,
@@ -106,7 +111,28 @@ pub struct StandardConstructors {
     typed_float32_array: StandardConstructor,
     typed_float64_array: StandardConstructor,
     array_buffer: StandardConstructor,
+    shared_array_buffer: StandardConstructor,
     data_view: StandardConstructor,
+    date: StandardConstructor,
+    date_time_format: StandardConstructor,
+    display_names: StandardConstructor,
+    duration_format: StandardConstructor,
+    weak_ref: StandardConstructor,
+    finalization_registry: StandardConstructor,
+    weak_map: StandardConstructor,
+    promise: StandardConstructor,
+    shadow_realm: StandardConstructor,
+    locale: StandardConstructor,
+    plural_rules: StandardConstructor,
+    segmenter: StandardConstructor,
+    disposable_stack: StandardConstructor,
+    async_disposable_stack: StandardConstructor,
+    instant: StandardConstructor,
+    duration: StandardConstructor,
+    plain_date: StandardConstructor,
+    plain_time: StandardConstructor,
+    plain_date_time: StandardConstructor,
+    zoned_date_time: StandardConstructor,
 }
 
 impl Default for StandardConstructors {
@@ -117,6 +143,7 @@ impl Default for StandardConstructors {
             function: StandardConstructor::default(),
             generator: StandardConstructor::default(),
             generator_function: StandardConstructor::default(),
+            iterator: StandardConstructor::default(),
             array: StandardConstructor::default(),
             bigint: StandardConstructor::default(),
             number: StandardConstructor::with_prototype(JsObject::from_proto_and_data(
@@ -141,6 +168,7 @@ impl Default for StandardConstructors {
             eval_error: StandardConstructor::default(),
             uri_error: StandardConstructor::default(),
             aggregate_error: StandardConstructor::default(),
+            suppressed_error: StandardConstructor::default(),
             map: StandardConstructor::default(),
             set: StandardConstructor::default(),
             typed_array: StandardConstructor::default(),
@@ -156,7 +184,28 @@ impl Default for StandardConstructors {
             typed_float32_array: StandardConstructor::default(),
             typed_float64_array: StandardConstructor::default(),
             array_buffer: StandardConstructor::default(),
+            shared_array_buffer: StandardConstructor::default(),
             data_view: StandardConstructor::default(),
+            date: StandardConstructor::default(),
+            date_time_format: StandardConstructor::default(),
+            display_names: StandardConstructor::default(),
+            duration_format: StandardConstructor::default(),
+            weak_ref: StandardConstructor::default(),
+            finalization_registry: StandardConstructor::default(),
+            weak_map: StandardConstructor::default(),
+            promise: StandardConstructor::default(),
+            shadow_realm: StandardConstructor::default(),
+            locale: StandardConstructor::default(),
+            plural_rules: StandardConstructor::default(),
+            segmenter: StandardConstructor::default(),
+            disposable_stack: StandardConstructor::default(),
+            async_disposable_stack: StandardConstructor::default(),
+            instant: StandardConstructor::default(),
+            duration: StandardConstructor::default(),
+            plain_date: StandardConstructor::default(),
+            plain_time: StandardConstructor::default(),
+            plain_date_time: StandardConstructor::default(),
+            zoned_date_time: StandardConstructor::default(),
         }
     }
 }
@@ -182,6 +231,11 @@ impl StandardConstructors {
         &self.generator
     }
 
+    #[inline]
+    pub fn iterator(&self) -> &StandardConstructor {
+        &self.iterator
+    }
+
     #[inline]
     pub fn generator_function(&self) -> &StandardConstructor {
         &self.generator_function
@@ -262,6 +316,11 @@ impl StandardConstructors {
         &self.aggregate_error
     }
 
+    #[inline]
+    pub fn suppressed_error(&self) -> &StandardConstructor {
+        &self.suppressed_error
+    }
+
     #[inline]
     pub fn map(&self) -> &StandardConstructor {
         &self.map
@@ -337,10 +396,115 @@ impl StandardConstructors {
         &self.array_buffer
     }
 
+    #[inline]
+    pub fn shared_array_buffer(&self) -> &StandardConstructor {
+        &self.shared_array_buffer
+    }
+
     #[inline]
     pub fn data_view(&self) -> &StandardConstructor {
         &self.data_view
     }
+
+    #[inline]
+    pub fn date(&self) -> &StandardConstructor {
+        &self.date
+    }
+
+    #[inline]
+    pub fn date_time_format(&self) -> &StandardConstructor {
+        &self.date_time_format
+    }
+
+    #[inline]
+    pub fn display_names(&self) -> &StandardConstructor {
+        &self.display_names
+    }
+
+    #[inline]
+    pub fn duration_format(&self) -> &StandardConstructor {
+        &self.duration_format
+    }
+
+    #[inline]
+    pub fn weak_ref(&self) -> &StandardConstructor {
+        &self.weak_ref
+    }
+
+    #[inline]
+    pub fn finalization_registry(&self) -> &StandardConstructor {
+        &self.finalization_registry
+    }
+
+    #[inline]
+    pub fn weak_map(&self) -> &StandardConstructor {
+        &self.weak_map
+    }
+
+    #[inline]
+    pub fn promise(&self) -> &StandardConstructor {
+        &self.promise
+    }
+
+    #[inline]
+    pub fn shadow_realm(&self) -> &StandardConstructor {
+        &self.shadow_realm
+    }
+
+    #[inline]
+    pub fn locale(&self) -> &StandardConstructor {
+        &self.locale
+    }
+
+    #[inline]
+    pub fn plural_rules(&self) -> &StandardConstructor {
+        &self.plural_rules
+    }
+
+    #[inline]
+    pub fn segmenter(&self) -> &StandardConstructor {
+        &self.segmenter
+    }
+
+    #[inline]
+    pub fn disposable_stack(&self) -> &StandardConstructor {
+        &self.disposable_stack
+    }
+
+    #[inline]
+    pub fn async_disposable_stack(&self) -> &StandardConstructor {
+        &self.async_disposable_stack
+    }
+
+    #[inline]
+    pub fn instant(&self) -> &StandardConstructor {
+        &self.instant
+    }
+
+    #[inline]
+    pub fn duration(&self) -> &StandardConstructor {
+        &self.duration
+    }
+
+    #[inline]
+    pub fn plain_date(&self) -> &StandardConstructor {
+        &self.plain_date
+    }
+
+    #[inline]
+    pub fn plain_time(&self) -> &StandardConstructor {
+        &self.plain_time
+    }
+
+    #[inline]
+    pub fn plain_date_time(&self) -> &StandardConstructor {
+        &self.plain_date_time
+    }
+
+    #[inline]
+    pub fn zoned_date_time(&self) -> &StandardConstructor {
+        &self.zoned_date_time
+    }
 }
 
 /// Cached intrinsic objects
@@ -350,6 +514,11 @@ pub struct IntrinsicObjects {
     throw_type_error: JsObject,
     /// Cached iterator prototypes.
     iterator_prototypes: IteratorPrototypes,
+    /// The initial functions of `%RegExp.prototype%`, used to detect unmodified regexps.
+    pub(super) regexp_prototype_snapshot: Vec<(PropertyKey, JsObject)>,
+    /// %SegmentsPrototype% intrinsic object
+    #[cfg(feature = "intl")]
+    segments_prototype: JsObject,
 }
 
 impl IntrinsicObjects {
@@ -358,6 +527,9 @@ impl IntrinsicObjects {
         Self {
             throw_type_error: create_throw_type_error(context),
             iterator_prototypes: IteratorPrototypes::init(context),
+            regexp_prototype_snapshot: Vec::new(),
+            #[cfg(feature = "intl")]
+            segments_prototype: Segments::create_prototype(context),
         }
     }
 
@@ -372,4 +544,17 @@ impl IntrinsicObjects {
     pub fn iterator_prototypes(&self) -> &IteratorPrototypes {
         &self.iterator_prototypes
     }
+
+    /// Get the initial functions of `%RegExp.prototype%`.
+    #[inline]
+    pub(crate) fn regexp_prototype_snapshot(&self) -> &[(PropertyKey, JsObject)] {
+        &self.regexp_prototype_snapshot
+    }
+
+    /// Get the `%SegmentsPrototype%` intrinsic object
+    #[cfg(feature = "intl")]
+    #[inline]
+    pub fn segments_prototype(&self) -> JsObject {
+        self.segments_prototype.clone()
+    }
 }
@@ -1,16 +1,56 @@
 //! Javascript context.
 
+mod fuel;
+mod handle_scope;
+mod heap;
+mod hooks;
+mod host_defined;
+#[cfg(feature = "intl")]
+pub mod icu;
+mod interrupt;
 pub mod intrinsics;
+mod pool;
+mod roots;
+mod sandbox;
+#[cfg(feature = "temporal")]
+pub mod time_zone;
 
+pub use boa_gc::GcStats;
+pub use hooks::{DefaultHooks, HostHooks};
+pub use host_defined::HostDefined;
+#[cfg(feature = "intl")]
+pub use icu::BoaProvider;
+pub use interrupt::ContextInterrupter;
+pub use pool::{ContextPool, PoolTask};
+pub use roots::{PersistentHandle, PersistentRoot};
+pub use sandbox::Builtins;
+#[cfg(feature = "temporal")]
+pub use time_zone::{TimeZoneProvider, TzifProvider};
+
+use fuel::Fuel;
+use heap::{GcHooks, HeapLimit};
+#[cfg(feature = "intl")]
+use icu::Icu;
+use interrupt::Interrupt;
 use intrinsics::{IntrinsicObjects, Intrinsics};
+use roots::RootSet;
 
 use crate::{
-    builtins::{self, function::NativeFunctionSignature},
+    builtins::{
+        self,
+        function::NativeFunctionSignature,
+        intl::negotiation::{canonicalize_locale, LocaleSettings},
+        promise::{OperationType, PromiseRejectionTracker},
+        FinalizationRegistry, RegExp,
+    },
     bytecompiler::ByteCompiler,
     class::{Class, ClassBuilder},
-    object::{FunctionBuilder, GlobalPropertyMap, JsObject, ObjectData},
+    job::{JobQueue, NativeJob, SimpleJobQueue},
+    object::{
+        FunctionBuilder, GlobalPropertyMap, IntoJsFunction, JsObject, ObjectData, WeakJsObject,
+    },
     property::{Attribute, PropertyDescriptor, PropertyKey},
-    realm::Realm,
+    realm::{JsRealm, Realm},
     syntax::{ast::node::StatementList, parser::ParseError, Parser},
     vm::{CallFrame, CodeBlock, FinallyReturn, GeneratorResumeKind, Vm},
     JsResult, JsValue,
@@ -18,16 +58,26 @@ use crate::{
 use boa_gc::Gc;
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
+use std::rc::Rc;
 
 #[cfg(feature = "console")]
 use crate::builtins::console::Console;
 
+#[cfg(feature = "temporal")]
+use crate::builtins::temporal::Clock;
+
+/// The default maximum number of nested function calls.
+const DEFAULT_RECURSION_LIMIT: usize = 400;
+
 /// Javascript context. It is the primary way to interact with the runtime.
 ///
 /// `Context`s constructed in a thread share the same runtime, therefore it
 /// is possible to share objects from one context to another context, but they
 /// have to be in the same thread.
 ///
+/// A `Context` is not `Send`, since its objects live in the heap of its thread. A
+/// [`ContextPool`] runs tasks on contexts owned by worker threads instead.
+///
 /// # Examples
 ///
 /// ## Execute Function of Script File
@@ -72,6 +122,9 @@ pub struct Context {
     /// realm holds both the global object and the environment
     pub(crate) realm: Realm,
 
+    /// The handle of the running realm, whose record is held by `realm` and `intrinsics`.
+    running_realm: JsRealm,
+
     /// String interner in the context.
     interner: Interner,
 
@@ -85,32 +138,73 @@ pub struct Context {
     /// Whether or not global strict mode is active.
     strict: bool,
 
+    /// Whether the functions defined by compiled code are compiled on their first call.
+    lazy_compilation: bool,
+
     pub(crate) vm: Vm,
+
+    /// The queue of the jobs waiting to run, shared with the embedder.
+    job_queue: Rc<dyn JobQueue>,
+
+    /// The host defined hooks, shared with the embedder.
+    host_hooks: Rc<dyn HostHooks>,
+
+    /// The state of the embedder stored in the context.
+    host_defined: HostDefined,
+
+    /// The interruption requested by the interrupters of the context.
+    interrupt: Interrupt,
+
+    /// The instruction budget, if the embedder set one.
+    fuel: Option<Fuel>,
+
+    /// The maximum nesting depth of the parsed scripts, or `None` for the default of the parser.
+    parser_recursion_limit: Option<usize>,
+
+    /// The groups of builtins left out of the realms of the context.
+    disabled_builtins: Builtins,
+
+    /// Values kept alive until the current synchronous execution finishes, so that a `WeakRef`
+    /// target can't be collected between two observations in the same job.
+    kept_alive: Vec<JsValue>,
+
+    /// The temporary values of the open handle scopes.
+    handles: Vec<JsValue>,
+
+    /// The live `FinalizationRegistry` objects, checked for cleanup work after collections.
+    pub(crate) finalization_registries: Vec<WeakJsObject>,
+
+    /// The host hook notified of rejected promises without handlers.
+    promise_rejection_tracker: Option<PromiseRejectionTracker>,
+
+    /// The limit on the size of the heap, if the embedder set one.
+    heap_limit: Option<HeapLimit>,
+
+    /// The hooks of the embedder notified of the garbage collections.
+    gc_hooks: GcHooks,
+
+    /// The values rooted by the persistent handles of the embedder.
+    roots: RootSet,
+
+    /// The default locale and the available locales of the `Intl` services.
+    locale_settings: LocaleSettings,
+
+    /// The ICU4X data of the `Intl` services.
+    #[cfg(feature = "intl")]
+    icu: Icu,
+
+    /// The host hook giving the current time of `Temporal.Now`, or `None` to use the system clock.
+    #[cfg(feature = "temporal")]
+    clock: Option<Clock>,
+
+    /// The rules of the named time zones of `Temporal`.
+    #[cfg(feature = "temporal")]
+    time_zone_provider: Box<dyn TimeZoneProvider>,
 }
 
 impl Default for Context {
     fn default() -> Self {
-        let mut context = Self {
-            realm: Realm::create(),
-            interner: Interner::default(),
-            #[cfg(feature = "console")]
-            console: Console::default(),
-            intrinsics: Intrinsics::default(),
-            strict: false,
-            vm: Vm {
-                frame: None,
-                stack: Vec::with_capacity(1024),
-                trace: false,
-                stack_size_limit: 1024,
-            },
-        };
-
-        // Add new builtIns to Context Realm
-        // At a later date this can be removed from here and called explicitly,
-        // but for now we almost always want these default builtins
-        context.intrinsics.objects = IntrinsicObjects::init(&mut context);
-        context.create_intrinsics();
-        context
+        ContextBuilder::default().build()
     }
 }
 
@@ -118,10 +212,13 @@ impl Context {
     /// Create a new `Context`.
     #[inline]
     pub fn new(interner: Interner) -> Self {
-        Self {
-            interner,
-            ..Self::default()
-        }
+        Self::builder().interner(interner).build()
+    }
+
+    /// Creates a [`ContextBuilder`], to configure a new `Context`.
+    #[inline]
+    pub fn builder() -> ContextBuilder {
+        ContextBuilder::default()
     }
 
     /// Gets the string interner.
@@ -161,12 +258,43 @@ impl Context {
         self.strict = strict;
     }
 
+    /// Returns if the functions defined by compiled code are compiled on their first call.
+    #[inline]
+    pub fn lazy_compilation(&self) -> bool {
+        self.lazy_compilation
+    }
+
+    /// Sets whether the functions defined by compiled code are compiled on their first call,
+    /// instead of together with the code defining them.
+    ///
+    /// Functions that are never called are then never compiled, which reduces the time needed
+    /// to load large scripts. Until it is compiled, a function keeps its syntax tree alive. The
+    /// source code is still parsed eagerly, so syntax errors are reported before running it.
+    ///
+    /// Functions are compiled eagerly by default, and always by
+    /// [`Script::compile`](crate::script::Script::compile), since the compiled script must
+    /// contain the bytecode of all of its functions.
+    #[inline]
+    pub fn set_lazy_compilation(&mut self, lazy: bool) {
+        self.lazy_compilation = lazy;
+    }
+
     /// Sets up the default global objects within Global
     #[inline]
-    fn create_intrinsics(&mut self) {
+    pub(crate) fn create_intrinsics(&mut self) {
         let _timer = Profiler::global().start_event("create_intrinsics", "interpreter");
+        self.intrinsics.objects = IntrinsicObjects::init(self);
         // Create intrinsics, add global objects here
         builtins::init(self);
+        self.remove_disabled_builtins();
+        self.intrinsics.objects.regexp_prototype_snapshot = RegExp::prototype_snapshot(self);
+    }
+
+    /// Swaps the running realm and its intrinsics with `realm` and `intrinsics`.
+    #[inline]
+    pub(crate) fn swap_realm(&mut self, realm: &mut Realm, intrinsics: &mut Intrinsics) {
+        std::mem::swap(&mut self.realm, realm);
+        std::mem::swap(&mut self.intrinsics, intrinsics);
     }
 
     /// Constructs an object with the `%Object.prototype%` prototype.
@@ -182,7 +310,17 @@ impl Context {
     where
         S: AsRef<[u8]>,
     {
-        Parser::new(src.as_ref(), self.strict).parse_all(&mut self.interner)
+        self.parser(src.as_ref(), self.strict)
+            .parse_all(&mut self.interner)
+    }
+
+    /// Creates a parser for `src`, with the recursion limit of the context.
+    pub(crate) fn parser<'a>(&self, src: &'a [u8], strict: bool) -> Parser<&'a [u8]> {
+        let mut parser = Parser::new(src, strict);
+        if let Some(limit) = self.parser_recursion_limit {
+            parser.set_recursion_limit(limit);
+        }
+        parser
     }
 
     /// <https://tc39.es/ecma262/#sec-call>
@@ -532,6 +670,31 @@ impl Context {
         Ok(())
     }
 
+    /// Register a global function built from a closure with typed arguments.
+    ///
+    /// The function is not a constructor, and is bound to the global object with `writable`,
+    /// `non-enumerable` and `configurable` attributes. See
+    /// [`FunctionBuilder::typed`](crate::object::FunctionBuilder::typed) for the conversions of
+    /// its arguments and result.
+    #[inline]
+    pub fn register_global_typed_function<F, Args>(&mut self, name: &str, body: F)
+    where
+        F: IntoJsFunction<Args>,
+        Args: 'static,
+    {
+        let function = FunctionBuilder::typed(self, body).name(name).build();
+
+        self.global_bindings_mut().insert(
+            name.into(),
+            PropertyDescriptor::builder()
+                .value(function)
+                .writable(true)
+                .enumerable(false)
+                .configurable(true)
+                .build(),
+        );
+    }
+
     /// <https://tc39.es/ecma262/#sec-hasproperty>
     #[inline]
     pub(crate) fn has_property(&mut self, obj: &JsValue, key: &PropertyKey) -> JsResult<bool> {
@@ -647,7 +810,8 @@ impl Context {
     {
         let main_timer = Profiler::global().start_event("Evaluation", "Main");
 
-        let parsing_result = Parser::new(src.as_ref(), false)
+        let parsing_result = self
+            .parser(src.as_ref(), false)
             .parse_all(&mut self.interner)
             .map_err(|e| e.to_string());
 
@@ -657,7 +821,7 @@ impl Context {
         };
 
         let code_block = self.compile(&statement_list)?;
-        let result = self.execute(code_block);
+        let result = self.evaluate_script_code(code_block);
 
         // The main_timer needs to be dropped before the Profiler is.
         drop(main_timer);
@@ -666,13 +830,250 @@ impl Context {
         result
     }
 
+    /// Executes the code block of a script, then runs the pending jobs if no other code is
+    /// running.
+    pub(crate) fn evaluate_script_code(&mut self, code_block: Gc<CodeBlock>) -> JsResult<JsValue> {
+        if self.vm.frame.is_none() {
+            self.refuel();
+        }
+        let mut result = self.execute(code_block);
+
+        // Jobs only run once the outermost script finishes, not when a builtin evaluates code.
+        if self.vm.frame.is_none() {
+            self.clear_kept_objects();
+            if result.is_ok() {
+                if let Err(e) = self.run_jobs() {
+                    result = Err(e);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Enqueues a job in the [`JobQueue`] of the context, to be run after the currently
+    /// executing code finishes.
+    #[inline]
+    pub fn enqueue_job(&mut self, job: NativeJob) {
+        self.job_queue().enqueue_job(job, self);
+    }
+
+    /// Runs the pending jobs with the [`JobQueue`] of the context.
+    ///
+    /// This first schedules the cleanup of the `FinalizationRegistry` objects whose targets were
+    /// garbage collected. With the default [`SimpleJobQueue`], the jobs run in FIFO order,
+    /// including the jobs enqueued while running them, and if a job throws, the error is returned
+    /// and the remaining jobs stay queued.
+    pub fn run_jobs(&mut self) -> JsResult<()> {
+        self.refuel();
+        if boa_gc::take_cleared() {
+            FinalizationRegistry::schedule_cleanup(self);
+        }
+        self.job_queue().run_jobs(self)
+    }
+
+    /// Returns the [`JobQueue`] of the context.
+    #[inline]
+    pub fn job_queue(&self) -> Rc<dyn JobQueue> {
+        self.job_queue.clone()
+    }
+
+    /// Returns the [`HostHooks`] of the context.
+    #[inline]
+    pub fn host_hooks(&self) -> Rc<dyn HostHooks> {
+        self.host_hooks.clone()
+    }
+
+    /// Returns the state of the embedder stored in the context, shared by all its realms.
+    #[inline]
+    pub fn host_defined(&self) -> &HostDefined {
+        &self.host_defined
+    }
+
+    /// Returns the state of the embedder stored in the context, mutably.
+    #[inline]
+    pub fn host_defined_mut(&mut self) -> &mut HostDefined {
+        &mut self.host_defined
+    }
+
+    /// Returns the state of the embedder stored in the running realm.
+    ///
+    /// The state of another realm is reached by running it with [`Context::with_realm`].
+    #[inline]
+    pub fn realm_host_defined(&self) -> &HostDefined {
+        &self.realm.host_defined
+    }
+
+    /// Returns the state of the embedder stored in the running realm, mutably.
+    #[inline]
+    pub fn realm_host_defined_mut(&mut self) -> &mut HostDefined {
+        &mut self.realm.host_defined
+    }
+
+    /// Sets the callback notified when a promise is rejected without any handler, and when a
+    /// handler is added to such a promise afterwards.
+    ///
+    /// This is a host defined implementation of `HostPromiseRejectionTracker`, which can be
+    /// used to report unhandled rejections, called after [`HostHooks::promise_rejection_tracker`].
+    /// By default, rejections are not tracked.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-promise-rejection-tracker
+    #[inline]
+    pub fn set_promise_rejection_tracker<F>(&mut self, tracker: F)
+    where
+        F: Fn(&JsObject, OperationType, &mut Self) + 'static,
+    {
+        self.promise_rejection_tracker = Some(PromiseRejectionTracker::new(tracker));
+    }
+
+    /// Abstract operation `HostPromiseRejectionTracker ( promise, operation )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-promise-rejection-tracker
+    pub(crate) fn host_promise_rejection_tracker(
+        &mut self,
+        promise: &JsObject,
+        operation: OperationType,
+    ) {
+        self.host_hooks()
+            .promise_rejection_tracker(promise, operation, self);
+        if let Some(tracker) = self.promise_rejection_tracker.clone() {
+            tracker.call(promise, operation, self);
+        }
+    }
+
+    /// Sets the clock giving the current time of `Temporal.Now`, in nanoseconds since the epoch.
+    ///
+    /// This is the host defined implementation of `HostSystemUTCEpochNanoseconds`, which can be
+    /// used to run scripts with a deterministic or a simulated time. By default, the system clock
+    /// is used.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-hostsystemutcepochnanoseconds
+    #[cfg(feature = "temporal")]
+    #[inline]
+    pub fn set_clock<F>(&mut self, clock: F)
+    where
+        F: Fn() -> i128 + 'static,
+    {
+        self.clock = Some(Clock::new(clock));
+    }
+
+    /// Abstract operation `HostSystemUTCEpochNanoseconds ( global )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-hostsystemutcepochnanoseconds
+    #[cfg(feature = "temporal")]
+    pub(crate) fn host_system_utc_epoch_nanoseconds(&self) -> i128 {
+        self.clock.as_ref().map_or_else(Clock::system, Clock::now)
+    }
+
+    /// Gets the provider of the rules of the named time zones of `Temporal`.
+    #[cfg(feature = "temporal")]
+    #[inline]
+    pub(crate) fn time_zone_provider(&self) -> &dyn TimeZoneProvider {
+        self.time_zone_provider.as_ref()
+    }
+
+    /// Abstract operation `AddToKeptObjects ( value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-addtokeptobjects
+    #[inline]
+    pub(crate) fn add_to_kept_objects(&mut self, value: JsValue) {
+        self.kept_alive.push(value);
+    }
+
+    /// Abstract operation `ClearKeptObjects ( )`
+    ///
+    /// Called when a synchronous sequence of executions completes.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-clear-kept-objects
+    #[inline]
+    pub(crate) fn clear_kept_objects(&mut self) {
+        self.kept_alive.clear();
+    }
+
+    /// Returns the default locale of the `Intl` services, `en-US` unless set with
+    /// [`Self::set_default_locale()`].
+    #[inline]
+    pub fn default_locale(&self) -> &str {
+        &self.locale_settings.default_locale
+    }
+
+    /// Sets the locale used by the `Intl` services when none of the requested locales is
+    /// available.
+    ///
+    /// This is the host defined result of `DefaultLocale ( )`. The tag is canonicalized, and a
+    /// `RangeError` is returned if it is not a structurally valid language tag.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-defaultlocale
+    pub fn set_default_locale(&mut self, locale: &str) -> JsResult<()> {
+        let locale = canonicalize_locale(locale, self).ok_or_else(|| {
+            self.construct_range_error(format!("`{locale}` is not a valid language tag"))
+        })?;
+        self.locale_settings.default_locale = locale.to_string();
+        Ok(())
+    }
+
+    /// Restricts the `[[AvailableLocales]]` of the `Intl` services to `locales`.
+    ///
+    /// By default, every locale a service has data for is available. The tags are canonicalized,
+    /// and a `RangeError` is returned if one of them is not a structurally valid language tag.
+    pub fn set_available_locales<I>(&mut self, locales: I) -> JsResult<()>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut available = Vec::new();
+        for locale in locales {
+            let locale = locale.as_ref();
+            let canonical = canonicalize_locale(locale, self).ok_or_else(|| {
+                self.construct_range_error(format!("`{locale}` is not a valid language tag"))
+            })?;
+            available.push(canonical.to_string());
+        }
+        self.locale_settings.available_locales = Some(available);
+        Ok(())
+    }
+
+    /// Gets the locale negotiation settings of the `Intl` services.
+    #[inline]
+    pub(crate) fn locale_settings(&self) -> &LocaleSettings {
+        &self.locale_settings
+    }
+
+    /// Gets the ICU4X data of the `Intl` services.
+    #[cfg(feature = "intl")]
+    #[inline]
+    pub(crate) fn icu(&self) -> &Icu {
+        &self.icu
+    }
+
     /// Compile the AST into a `CodeBlock` ready to be executed by the VM.
     #[inline]
     pub fn compile(&mut self, statement_list: &StatementList) -> JsResult<Gc<CodeBlock>> {
         let _timer = Profiler::global().start_event("Compilation", "Main");
         let mut compiler = ByteCompiler::new(Sym::MAIN, statement_list.strict(), self);
         compiler.create_declarations(statement_list.items())?;
-        compiler.compile_statement_list(statement_list.items(), true)?;
+        compiler.compile_statement_list(statement_list, true)?;
         Ok(Gc::new(compiler.finish()))
     }
 
@@ -689,6 +1090,7 @@ impl Context {
 
         self.vm.push_frame(CallFrame {
             prev: None,
+            locals: vec![None; code_block.num_locals],
             code: code_block,
             this: global_object,
             pc: 0,
@@ -704,6 +1106,7 @@ impl Context {
             param_count: 0,
             arg_count: 0,
             generator_resume_kind: GeneratorResumeKind::Normal,
+            dispose_stack: Vec::new(),
         });
 
         self.realm.set_global_binding_number();
@@ -723,4 +1126,280 @@ impl Context {
     pub fn set_trace(&mut self, trace: bool) {
         self.vm.trace = trace;
     }
+
+    /// Sets the number of invocations after which a function is compiled to native code by the
+    /// experimental JIT compiler, or disables the JIT compiler with `None`.
+    ///
+    /// Instructions are not traced while native code runs.
+    #[cfg(feature = "jit")]
+    pub fn set_jit_threshold(&mut self, threshold: Option<u32>) {
+        self.vm.jit.threshold = threshold;
+    }
+}
+
+/// A builder to configure a new [`Context`].
+///
+/// # Examples
+///
+/// ```
+/// use boa_engine::Context;
+///
+/// let mut context = Context::builder().build();
+///
+/// let value = context.eval("1 + 1").unwrap();
+///
+/// assert_eq!(value.as_number(), Some(2.0));
+/// ```
+#[derive(Default)]
+pub struct ContextBuilder {
+    interner: Option<Interner>,
+    heap_limit: Option<usize>,
+    heap_limit_callback: Option<Box<dyn FnMut(usize, usize) -> usize>>,
+    job_queue: Option<Rc<dyn JobQueue>>,
+    host_hooks: Option<Rc<dyn HostHooks>>,
+    catchable_interrupts: bool,
+    recursion_limit: Option<usize>,
+    parser_recursion_limit: Option<usize>,
+    instruction_budget: Option<u64>,
+    fuel_exhausted_callback: Option<Box<dyn FnMut(u64) -> Option<u64>>>,
+    disabled_builtins: Builtins,
+    #[cfg(feature = "intl")]
+    icu: Option<Icu>,
+    #[cfg(feature = "temporal")]
+    time_zone_provider: Option<Box<dyn TimeZoneProvider>>,
+}
+
+impl std::fmt::Debug for ContextBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ContextBuilder")
+            .field("interner", &self.interner)
+            .field("heap_limit", &self.heap_limit)
+            .field("job_queue", &self.job_queue)
+            .field("host_hooks", &self.host_hooks)
+            .field("catchable_interrupts", &self.catchable_interrupts)
+            .field("recursion_limit", &self.recursion_limit)
+            .field("parser_recursion_limit", &self.parser_recursion_limit)
+            .field("instruction_budget", &self.instruction_budget)
+            .field("disabled_builtins", &self.disabled_builtins)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ContextBuilder {
+    /// Sets the string interner of the context.
+    #[inline]
+    #[must_use]
+    pub fn interner(mut self, interner: Interner) -> Self {
+        self.interner = Some(interner);
+        self
+    }
+
+    /// Sets the maximum size of the garbage collected heap, in bytes.
+    ///
+    /// When a script makes the heap grow past the limit, a `RangeError` is thrown, which aborts
+    /// the script unless it catches the error. By default, the heap is not limited.
+    #[inline]
+    #[must_use]
+    pub fn heap_limit(mut self, bytes: usize) -> Self {
+        self.heap_limit = Some(bytes);
+        self
+    }
+
+    /// Sets the callback invoked when the heap grows past the [heap limit][Self::heap_limit].
+    ///
+    /// The callback receives the size of the heap and the current limit, in bytes, and returns
+    /// the new limit. Returning a limit larger than the size of the heap lets the script continue,
+    /// otherwise a `RangeError` is thrown.
+    #[inline]
+    #[must_use]
+    pub fn heap_limit_callback<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(usize, usize) -> usize + 'static,
+    {
+        self.heap_limit_callback = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the [`JobQueue`] of the context, instead of a [`SimpleJobQueue`].
+    ///
+    /// The embedder can keep a clone of the queue to run its jobs itself, for example from the
+    /// tasks of an async executor.
+    #[inline]
+    #[must_use]
+    pub fn job_queue(mut self, queue: Rc<dyn JobQueue>) -> Self {
+        self.job_queue = Some(queue);
+        self
+    }
+
+    /// Sets the [`HostHooks`] of the context, instead of the [`DefaultHooks`].
+    #[inline]
+    #[must_use]
+    pub fn host_hooks(mut self, hooks: Rc<dyn HostHooks>) -> Self {
+        self.host_hooks = Some(hooks);
+        self
+    }
+
+    /// Sets whether scripts can catch the errors thrown when a [`ContextInterrupter`] interrupts
+    /// them. By default, interruptions terminate the scripts.
+    #[inline]
+    #[must_use]
+    pub fn catchable_interrupts(mut self, catchable: bool) -> Self {
+        self.catchable_interrupts = catchable;
+        self
+    }
+
+    /// Sets the maximum number of nested function calls.
+    ///
+    /// A call beyond the limit throws a `RangeError`, instead of overflowing the native stack of
+    /// the thread running the script. The default limit is 400 calls, which fits in the stack of
+    /// the threads spawned by the standard library.
+    #[inline]
+    #[must_use]
+    pub fn recursion_limit(mut self, calls: usize) -> Self {
+        self.recursion_limit = Some(calls);
+        self
+    }
+
+    /// Sets the maximum nesting depth of the expressions and statements of the parsed scripts.
+    ///
+    /// A script nested more deeply fails to parse with a `SyntaxError`, instead of overflowing
+    /// the native stack. The default limit is 256 levels.
+    #[inline]
+    #[must_use]
+    pub fn parser_recursion_limit(mut self, levels: usize) -> Self {
+        self.parser_recursion_limit = Some(levels);
+        self
+    }
+
+    /// Leaves the `builtins` out of the context, to reduce the surface exposed to untrusted code.
+    ///
+    /// The builtins are removed from every realm of the context, including the realms created
+    /// later, and the calls to disable builtins add up. By default, all the builtins are
+    /// available.
+    #[inline]
+    #[must_use]
+    pub fn disable_builtins(mut self, builtins: Builtins) -> Self {
+        self.disabled_builtins |= builtins;
+        self
+    }
+
+    /// Sets the number of instructions the VM can run each time the context evaluates a script
+    /// or runs the pending jobs.
+    ///
+    /// When the budget is exhausted, a `RangeError` that can't be caught terminates the script,
+    /// unless the [callback][Self::fuel_exhausted_callback] adds more fuel. By default, the
+    /// instructions are not counted.
+    #[inline]
+    #[must_use]
+    pub fn instruction_budget(mut self, instructions: u64) -> Self {
+        self.instruction_budget = Some(instructions);
+        self
+    }
+
+    /// Sets the callback invoked when the [instruction budget][Self::instruction_budget] is
+    /// exhausted.
+    ///
+    /// The callback receives the number of instructions run since the budget was refilled, and
+    /// returns the number of instructions the script can still run, or `None` to terminate it.
+    #[inline]
+    #[must_use]
+    pub fn fuel_exhausted_callback<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(u64) -> Option<u64> + 'static,
+    {
+        self.fuel_exhausted_callback = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets the ICU4X data provider of the `Intl` services, instead of the data compiled into
+    /// the engine.
+    ///
+    /// The provider can be a `BlobDataProvider` loaded at runtime, an `FsDataProvider` reading a
+    /// data directory, or baked data exported for the locales of the application. See the
+    /// [`icu`](crate::context::icu) module for the services reading their data from it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the provider doesn't have the data needed to canonicalize locales.
+    #[cfg(feature = "intl")]
+    pub fn icu_provider(mut self, provider: BoaProvider) -> Result<Self, icu_provider::DataError> {
+        self.icu = Some(Icu::new(provider)?);
+        Ok(self)
+    }
+
+    /// Sets the provider of the rules of the named time zones of `Temporal`, instead of the
+    /// time zone database of the operating system.
+    ///
+    /// A [`TzifProvider`] with the time zones inserted by the embedder makes the results
+    /// independent of the host, which is useful on systems without a time zone database.
+    #[cfg(feature = "temporal")]
+    #[inline]
+    #[must_use]
+    pub fn time_zone_provider<P>(mut self, provider: P) -> Self
+    where
+        P: TimeZoneProvider + 'static,
+    {
+        self.time_zone_provider = Some(Box::new(provider));
+        self
+    }
+
+    /// Builds the [`Context`], with the default built-in objects.
+    pub fn build(self) -> Context {
+        let realm = Realm::create();
+        let mut context = Context {
+            running_realm: JsRealm::running(realm.global_object().clone()),
+            realm,
+            interner: self.interner.unwrap_or_default(),
+            #[cfg(feature = "console")]
+            console: Console::default(),
+            intrinsics: Intrinsics::default(),
+            strict: false,
+            lazy_compilation: false,
+            vm: Vm {
+                frame: None,
+                stack: Vec::with_capacity(1024),
+                trace: false,
+                stack_size_limit: 1024,
+                call_depth: 0,
+                recursion_limit: self.recursion_limit.unwrap_or(DEFAULT_RECURSION_LIMIT),
+                #[cfg(feature = "jit")]
+                jit: crate::vm::Jit::default(),
+            },
+            job_queue: self
+                .job_queue
+                .unwrap_or_else(|| Rc::new(SimpleJobQueue::new())),
+            host_hooks: self.host_hooks.unwrap_or_else(|| Rc::new(DefaultHooks)),
+            host_defined: HostDefined::default(),
+            interrupt: Interrupt::new(self.catchable_interrupts),
+            fuel: self
+                .instruction_budget
+                .map(|budget| Fuel::new(budget, self.fuel_exhausted_callback)),
+            parser_recursion_limit: self.parser_recursion_limit,
+            disabled_builtins: self.disabled_builtins,
+            kept_alive: Vec::new(),
+            handles: Vec::new(),
+            finalization_registries: Vec::new(),
+            promise_rejection_tracker: None,
+            heap_limit: self
+                .heap_limit
+                .map(|limit| HeapLimit::new(limit, self.heap_limit_callback)),
+            gc_hooks: GcHooks::default(),
+            roots: RootSet::default(),
+            locale_settings: LocaleSettings::default(),
+            #[cfg(feature = "intl")]
+            icu: self.icu.unwrap_or_default(),
+            #[cfg(feature = "temporal")]
+            clock: None,
+            #[cfg(feature = "temporal")]
+            time_zone_provider: self
+                .time_zone_provider
+                .unwrap_or_else(|| Box::new(TzifProvider::system())),
+        };
+
+        // Add new builtIns to Context Realm
+        // At a later date this can be removed from here and called explicitly,
+        // but for now we almost always want these default builtins
+        context.create_intrinsics();
+        context
+    }
 }
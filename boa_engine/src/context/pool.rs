@@ -0,0 +1,156 @@
+//! Pools of contexts running on their own threads.
+//!
+//! A [`Context`] can't be sent to another thread: its objects live in the garbage collected heap
+//! of the thread that created it, which is not synchronized. A [`ContextPool`] gives work-per-thread
+//! architectures a safe alternative: each of its worker threads creates its own context once and
+//! keeps it for the lifetime of the pool, and the tasks sent to the pool are run on the context
+//! of the worker that picks them up. Tasks and their results cross threads, so they must be
+//! `Send`: a task converts the values it needs from and to Rust types, and no JavaScript value
+//! leaves its worker.
+
+use super::Context;
+use std::{
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Arc, Mutex},
+    thread,
+};
+
+/// A task of a [`ContextPool`].
+type Task = Box<dyn FnOnce(&mut Context) + Send>;
+
+/// A pool of worker threads, each owning a [`Context`].
+///
+/// # Examples
+///
+/// ```
+/// use boa_engine::{context::ContextPool, Context};
+///
+/// let pool = ContextPool::new(2, Context::default);
+/// let tasks: Vec<_> = (0..4)
+///     .map(|i| {
+///         pool.execute(move |context| {
+///             let value = context.eval(format!("{i} * 2")).unwrap();
+///             value.as_number().unwrap()
+///         })
+///     })
+///     .collect();
+///
+/// let results: Vec<_> = tasks.into_iter().map(|task| task.join().unwrap()).collect();
+/// assert_eq!(results, [0.0, 2.0, 4.0, 6.0]);
+/// ```
+pub struct ContextPool {
+    sender: Option<mpsc::Sender<Task>>,
+    workers: Vec<thread::JoinHandle<()>>,
+}
+
+impl ContextPool {
+    /// Creates a pool of `threads` worker threads, whose contexts are created by `init` on the
+    /// threads themselves.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threads` is zero, or if a thread can't be spawned.
+    pub fn new<F>(threads: usize, init: F) -> Self
+    where
+        F: Fn() -> Context + Send + Sync + 'static,
+    {
+        assert!(threads > 0, "a context pool needs at least one thread");
+
+        let (sender, receiver) = mpsc::channel::<Task>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let init = Arc::new(init);
+
+        let workers = (0..threads)
+            .map(|index| {
+                let receiver = receiver.clone();
+                let init = init.clone();
+                thread::Builder::new()
+                    .name(format!("boa-context-{index}"))
+                    .spawn(move || worker(&receiver, &*init))
+                    .expect("failed to spawn a context pool thread")
+            })
+            .collect();
+
+        Self {
+            sender: Some(sender),
+            workers,
+        }
+    }
+
+    /// Returns the number of worker threads of the pool.
+    #[inline]
+    pub fn threads(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Runs `task` on the context of the first idle worker, and returns a handle to its result.
+    pub fn execute<F, R>(&self, task: F) -> PoolTask<R>
+    where
+        F: FnOnce(&mut Context) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel();
+        let task: Task = Box::new(move |context| {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| task(context)));
+            // The handle may have been dropped, in which case nobody waits for the result.
+            let _ = sender.send(result);
+        });
+        self.sender
+            .as_ref()
+            .expect("the sender is only taken when the pool is dropped")
+            .send(task)
+            .expect("the workers live as long as the pool");
+        PoolTask(receiver)
+    }
+}
+
+impl Drop for ContextPool {
+    /// Waits for the queued tasks to finish, and stops the worker threads.
+    fn drop(&mut self) {
+        drop(self.sender.take());
+        for worker in self.workers.drain(..) {
+            // The tasks catch their panics, so the workers can't panic.
+            let _ = worker.join();
+        }
+    }
+}
+
+impl fmt::Debug for ContextPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ContextPool")
+            .field("threads", &self.workers.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Runs the tasks of a pool until the pool is dropped.
+fn worker(receiver: &Mutex<mpsc::Receiver<Task>>, init: &dyn Fn() -> Context) {
+    let mut context = init();
+    loop {
+        let task = receiver
+            .lock()
+            .expect("receiving a task can't poison the lock")
+            .recv();
+        match task {
+            Ok(task) => task(&mut context),
+            Err(_) => return,
+        }
+    }
+}
+
+/// A handle to the result of a task of a [`ContextPool`].
+#[derive(Debug)]
+pub struct PoolTask<R>(mpsc::Receiver<thread::Result<R>>);
+
+impl<R> PoolTask<R> {
+    /// Waits for the task to finish, and returns its result.
+    ///
+    /// If the task panicked, the panic payload is returned as an error. The worker keeps its
+    /// context, which may be left in an inconsistent state by the panic.
+    pub fn join(self) -> thread::Result<R> {
+        self.0
+            .recv()
+            .expect("a task sends its result before it is dropped")
+    }
+}
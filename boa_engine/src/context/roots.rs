@@ -0,0 +1,169 @@
+//! Persistent handles, which keep values alive for the embedder across calls into the engine.
+//!
+//! A [`PersistentHandle`] owns its value, so the value isn't collected while the handle exists.
+//! The handles are registered in the root set of the [`Context`] that created them until they are
+//! dropped, so the embedder can list the values it keeps alive with [`Context::roots`].
+//!
+//! Handles are not traced by the collector: a handle stored in a garbage collected value, like
+//! the native data of an object, keeps its value alive for as long as the handle exists, even if
+//! the value references the object back.
+
+use crate::{Context, JsValue};
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    fmt,
+    ops::Deref,
+    rc::{Rc, Weak},
+};
+
+/// The values rooted by the persistent handles of a context.
+#[derive(Debug, Default)]
+pub(crate) struct RootSet(Rc<RefCell<RootSetInner>>);
+
+#[derive(Debug, Default)]
+struct RootSetInner {
+    next_id: u64,
+    roots: BTreeMap<u64, PersistentRoot>,
+}
+
+impl RootSet {
+    /// Registers a root, returning its id.
+    fn insert(&self, name: Option<String>, value: JsValue) -> u64 {
+        let mut inner = self.0.borrow_mut();
+        let id = inner.next_id;
+        inner.next_id += 1;
+        inner.roots.insert(id, PersistentRoot { id, name, value });
+        id
+    }
+}
+
+/// A root registered by a [`PersistentHandle`], as listed by [`Context::roots`].
+#[derive(Debug, Clone)]
+pub struct PersistentRoot {
+    id: u64,
+    name: Option<String>,
+    value: JsValue,
+}
+
+impl PersistentRoot {
+    /// Returns the id of the root, unique among the roots of its context.
+    #[inline]
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns the name given to the root when it was created, if it has one.
+    #[inline]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the rooted value.
+    #[inline]
+    pub fn value(&self) -> &JsValue {
+        &self.value
+    }
+}
+
+/// A handle that keeps a value alive until it is dropped, even across garbage collections.
+///
+/// Handles are created with [`Context::root`] and [`Context::root_named`], and dereference to
+/// their value. Cloning a handle registers a new root with the same name.
+pub struct PersistentHandle<T> {
+    value: T,
+    id: u64,
+    roots: Weak<RefCell<RootSetInner>>,
+}
+
+impl<T> PersistentHandle<T> {
+    /// Returns the id of the root of the handle in [`Context::roots`].
+    #[inline]
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+}
+
+impl<T> Deref for PersistentHandle<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: Clone + Into<JsValue>> Clone for PersistentHandle<T> {
+    fn clone(&self) -> Self {
+        let id = self.roots.upgrade().map_or(self.id, |roots| {
+            let name = roots
+                .borrow()
+                .roots
+                .get(&self.id)
+                .and_then(|root| root.name.clone());
+            RootSet(roots).insert(name, self.value.clone().into())
+        });
+        Self {
+            value: self.value.clone(),
+            id,
+            roots: self.roots.clone(),
+        }
+    }
+}
+
+impl<T> Drop for PersistentHandle<T> {
+    fn drop(&mut self) {
+        if let Some(roots) = self.roots.upgrade() {
+            roots.borrow_mut().roots.remove(&self.id);
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for PersistentHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PersistentHandle")
+            .field("id", &self.id)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl Context {
+    /// Creates a handle that keeps `value` alive until the handle is dropped.
+    #[inline]
+    pub fn root<T>(&mut self, value: T) -> PersistentHandle<T>
+    where
+        T: Clone + Into<JsValue>,
+    {
+        self.root_with(None, value)
+    }
+
+    /// Creates a handle that keeps `value` alive until the handle is dropped, with a name
+    /// identifying the root in [`Context::roots`].
+    #[inline]
+    pub fn root_named<T, N>(&mut self, name: N, value: T) -> PersistentHandle<T>
+    where
+        T: Clone + Into<JsValue>,
+        N: Into<String>,
+    {
+        self.root_with(Some(name.into()), value)
+    }
+
+    fn root_with<T>(&mut self, name: Option<String>, value: T) -> PersistentHandle<T>
+    where
+        T: Clone + Into<JsValue>,
+    {
+        let id = self.roots.insert(name, value.clone().into());
+        PersistentHandle {
+            value,
+            id,
+            roots: Rc::downgrade(&self.roots.0),
+        }
+    }
+
+    /// Returns the roots of the live persistent handles of the context, in the order they were
+    /// created.
+    pub fn roots(&self) -> Vec<PersistentRoot> {
+        self.roots.0.borrow().roots.values().cloned().collect()
+    }
+}
@@ -0,0 +1,109 @@
+//! Builtins that can be left out of a context.
+//!
+//! A context running untrusted code often needs only a fraction of the builtins, and every
+//! builtin it exposes is attack surface to audit: compiling strings as code defeats static review
+//! of the scripts, regular expressions can backtrack for exponential time, `Date`, `Atomics` and
+//! weak references expose timers and the garbage collector, and the legacy Annex B functions are
+//! rarely needed. [`ContextBuilder::disable_builtins`](super::ContextBuilder::disable_builtins)
+//! removes the selected groups of builtins from all the realms of a context.
+
+use super::Context;
+use crate::JsResult;
+use bitflags::bitflags;
+
+bitflags! {
+    /// Groups of builtins that can be disabled with
+    /// [`ContextBuilder::disable_builtins`](super::ContextBuilder::disable_builtins).
+    #[derive(Default)]
+    pub struct Builtins: u16 {
+        /// Compiling strings as code: the `Function` constructor and
+        /// `ShadowRealm.prototype.evaluate` throw an `EvalError`.
+        const STRING_COMPILATION = 0b0000_0000_0001;
+
+        /// The `RegExp` global. Regular expression literals and the `String.prototype` methods
+        /// creating regular expressions from strings throw.
+        const REGEXP = 0b0000_0000_0010;
+
+        /// The `Reflect` global.
+        const REFLECT = 0b0000_0000_0100;
+
+        /// The `Proxy` global.
+        const PROXY = 0b0000_0000_1000;
+
+        /// The `Date` global.
+        const DATE = 0b0000_0001_0000;
+
+        /// The `Temporal` global, when the `temporal` feature is enabled.
+        const TEMPORAL = 0b0000_0010_0000;
+
+        /// The `SharedArrayBuffer` and `Atomics` globals.
+        const SHARED_MEMORY = 0b0000_0100_0000;
+
+        /// The `WeakRef` and `FinalizationRegistry` globals, which observe garbage collections.
+        const WEAK_REFS = 0b0000_1000_0000;
+
+        /// The `console` global, when the `console` feature is enabled.
+        const CONSOLE = 0b0001_0000_0000;
+
+        /// The legacy functions of Annex B: `String.prototype.substr`, `Date.prototype.getYear`,
+        /// `Date.prototype.setYear` and `Date.prototype.toGMTString`.
+        const ANNEX_B = 0b0010_0000_0000;
+    }
+}
+
+/// The globals removed by each group of builtins.
+const GLOBALS: [(Builtins, &[&str]); 8] = [
+    (Builtins::REGEXP, &["RegExp"]),
+    (Builtins::REFLECT, &["Reflect"]),
+    (Builtins::PROXY, &["Proxy"]),
+    (Builtins::DATE, &["Date"]),
+    (Builtins::TEMPORAL, &["Temporal"]),
+    (Builtins::SHARED_MEMORY, &["SharedArrayBuffer", "Atomics"]),
+    (Builtins::WEAK_REFS, &["WeakRef", "FinalizationRegistry"]),
+    (Builtins::CONSOLE, &["console"]),
+];
+
+impl Context {
+    /// Returns the groups of builtins disabled in the context.
+    #[inline]
+    pub fn disabled_builtins(&self) -> Builtins {
+        self.disabled_builtins
+    }
+
+    /// Abstract operation `HostEnsureCanCompileStrings ( calleeRealm )`, which throws an
+    /// `EvalError` if [`Builtins::STRING_COMPILATION`] is disabled and then asks the
+    /// [`HostHooks`](super::HostHooks).
+    pub(crate) fn ensure_can_compile_strings(&mut self) -> JsResult<()> {
+        if self
+            .disabled_builtins
+            .contains(Builtins::STRING_COMPILATION)
+        {
+            return self.throw_eval_error("compiling strings as code is disabled");
+        }
+        self.host_hooks().ensure_can_compile_strings(self)
+    }
+
+    /// Removes the disabled builtins from the running realm, once its builtins are created.
+    pub(crate) fn remove_disabled_builtins(&mut self) {
+        for (group, names) in GLOBALS {
+            if self.disabled_builtins.contains(group) {
+                for name in names {
+                    self.global_bindings_mut().shift_remove(*name);
+                }
+            }
+        }
+
+        if self.disabled_builtins.contains(Builtins::ANNEX_B) {
+            let string_prototype = self.intrinsics().constructors().string().prototype();
+            let date_prototype = self.intrinsics().constructors().date().prototype();
+            for (prototype, name) in [
+                (&string_prototype, "substr"),
+                (&date_prototype, "getYear"),
+                (&date_prototype, "setYear"),
+                (&date_prototype, "toGMTString"),
+            ] {
+                prototype.borrow_mut().remove(&name.into());
+            }
+        }
+    }
+}
@@ -0,0 +1,307 @@
+//! The time zone database used by the `Temporal` builtins of a [`Context`].
+//!
+//! The rules of the named time zones, such as `Europe/Paris`, are given by a
+//! [`TimeZoneProvider`]. By default, the engine reads the TZif files of the operating system
+//! with a [`TzifProvider`]. Embedders can ship their own copy of the IANA time zone database
+//! with [`TzifProvider::insert`], or implement the trait on top of another database, and set the
+//! provider with [`ContextBuilder::time_zone_provider`].
+//!
+//! [`Context`]: crate::Context
+//! [`ContextBuilder::time_zone_provider`]: crate::context::ContextBuilder::time_zone_provider
+
+mod tzif;
+
+#[cfg(test)]
+mod tests;
+
+use rustc_hash::FxHashMap;
+use std::{
+    cell::{Cell, RefCell},
+    env, fmt, fs,
+    io::Read,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+use tzif::Tzif;
+
+/// The nanoseconds in a day.
+const NS_PER_DAY: i128 = 86_400_000_000_000;
+
+/// The nanoseconds in a second.
+const NS_PER_SECOND: i128 = 1_000_000_000;
+
+/// A provider of the rules of the named time zones.
+///
+/// The times are given in nanoseconds since the epoch, and the UTC offsets in nanoseconds. The
+/// identifiers given to the methods other than [`identifier`](Self::identifier) are always the
+/// ones returned by it.
+///
+/// The `UTC` time zone and the offset time zones, such as `+01:00`, are handled by the engine,
+/// and don't need to be supported by the provider.
+pub trait TimeZoneProvider: fmt::Debug {
+    /// Finds a time zone by its case-insensitive identifier, and returns the identifier with
+    /// the case used by the database, or `None` if the time zone is not supported.
+    ///
+    /// This is the host defined operation `GetAvailableNamedTimeZoneIdentifier`.
+    fn identifier(&self, identifier: &str) -> Option<String>;
+
+    /// Gets the UTC offset of a time zone at an exact time.
+    ///
+    /// This is the host defined operation `GetNamedTimeZoneOffsetNanoseconds`.
+    fn offset_nanoseconds(&self, identifier: &str, epoch_nanoseconds: i128) -> i64;
+
+    /// Gets the exact times at which a time zone has a wall-clock time, given as the
+    /// nanoseconds since the epoch of the same wall-clock time in UTC, in ascending order.
+    ///
+    /// The result is empty if the wall-clock time is skipped by a transition, and has two
+    /// values if it's repeated.
+    ///
+    /// This is the host defined operation `GetNamedTimeZoneEpochNanoseconds`. The default
+    /// implementation supposes that the offsets change at most once in two days.
+    fn possible_epoch_nanoseconds(&self, identifier: &str, local_nanoseconds: i128) -> Vec<i128> {
+        let mut result = Vec::with_capacity(2);
+        for offset in [
+            self.offset_nanoseconds(identifier, local_nanoseconds - NS_PER_DAY),
+            self.offset_nanoseconds(identifier, local_nanoseconds + NS_PER_DAY),
+        ] {
+            let epoch_nanoseconds = local_nanoseconds - i128::from(offset);
+            if self.offset_nanoseconds(identifier, epoch_nanoseconds) == offset
+                && !result.contains(&epoch_nanoseconds)
+            {
+                result.push(epoch_nanoseconds);
+            }
+        }
+        result.sort_unstable();
+        result
+    }
+
+    /// Gets the first time after `epoch_nanoseconds` at which the UTC offset of a time zone
+    /// changes, if any.
+    ///
+    /// This is the host defined operation `GetNamedTimeZoneNextTransition`.
+    fn next_transition(&self, identifier: &str, epoch_nanoseconds: i128) -> Option<i128>;
+
+    /// Gets the last time before `epoch_nanoseconds` at which the UTC offset of a time zone
+    /// changed, if any.
+    ///
+    /// This is the host defined operation `GetNamedTimeZonePreviousTransition`.
+    fn previous_transition(&self, identifier: &str, epoch_nanoseconds: i128) -> Option<i128>;
+
+    /// Gets the identifier of the time zone of the host, or `None` to use its current UTC
+    /// offset.
+    ///
+    /// This is the host defined operation `SystemTimeZoneIdentifier`.
+    fn system_time_zone(&self) -> Option<String> {
+        None
+    }
+}
+
+/// The error of invalid TZif data given to [`TzifProvider::insert`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidTzif;
+
+impl fmt::Display for InvalidTzif {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid TZif data")
+    }
+}
+
+impl std::error::Error for InvalidTzif {}
+
+/// A [`TimeZoneProvider`] reading the TZif files of the IANA time zone database.
+///
+/// The files are either read from a directory, such as the `/usr/share/zoneinfo` directory of
+/// the operating system, when a time zone is first used, or given by the embedder.
+#[derive(Debug, Default)]
+pub struct TzifProvider {
+    /// The directory of the TZif files, or `None` to use the inserted time zones only.
+    directory: Option<PathBuf>,
+    /// Whether the time zones of the directory have been listed.
+    listed: Cell<bool>,
+    /// The time zones by their lowercase identifier, with their identifier and their rules,
+    /// if they have been read.
+    zones: RefCell<FxHashMap<String, (String, Option<Rc<Tzif>>)>>,
+}
+
+impl TzifProvider {
+    /// Creates a provider without any time zone, to [`insert`](Self::insert) them.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a provider reading the TZif files of a directory, named after the identifiers of
+    /// their time zone, such as `America/New_York`.
+    #[inline]
+    pub fn from_directory<P>(directory: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self {
+            directory: Some(directory.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a provider reading the time zone database of the operating system, from the
+    /// `TZDIR` environment variable or `/usr/share/zoneinfo`.
+    ///
+    /// This is the default provider of a context. On systems without this directory, only the
+    /// `UTC` and the offset time zones are available.
+    pub fn system() -> Self {
+        Self::from_directory(
+            env::var_os("TZDIR")
+                .map_or_else(|| PathBuf::from("/usr/share/zoneinfo"), PathBuf::from),
+        )
+    }
+
+    /// Adds a time zone from the content of its TZif file, replacing the time zone with the
+    /// same identifier, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data is not a valid TZif file.
+    pub fn insert(&mut self, identifier: &str, data: &[u8]) -> Result<(), InvalidTzif> {
+        let rules = Tzif::parse(data).ok_or(InvalidTzif)?;
+        self.zones.get_mut().insert(
+            identifier.to_ascii_lowercase(),
+            (identifier.to_owned(), Some(Rc::new(rules))),
+        );
+        Ok(())
+    }
+
+    /// Lists the TZif files of the directory, the first time a time zone is looked up.
+    fn list(&self) {
+        if self.listed.replace(true) {
+            return;
+        }
+        if let Some(directory) = &self.directory {
+            let mut zones = self.zones.borrow_mut();
+            list_directory(directory, "", &mut |identifier| {
+                zones
+                    .entry(identifier.to_ascii_lowercase())
+                    .or_insert_with(|| (identifier, None));
+            });
+        }
+    }
+
+    /// Gets the identifier and the rules of a time zone, reading them if needed.
+    fn zone(&self, identifier: &str) -> Option<(String, Rc<Tzif>)> {
+        self.list();
+        let key = identifier.to_ascii_lowercase();
+        let mut zones = self.zones.borrow_mut();
+        let (identifier, rules) = zones.get_mut(&key)?;
+        if let Some(rules) = rules {
+            return Some((identifier.clone(), rules.clone()));
+        }
+
+        let parsed = self
+            .directory
+            .as_ref()
+            .and_then(|directory| fs::read(directory.join(identifier.as_str())).ok())
+            .and_then(|data| Tzif::parse(&data));
+        match parsed {
+            Some(parsed) => {
+                let parsed = Rc::new(parsed);
+                *rules = Some(parsed.clone());
+                Some((identifier.clone(), parsed))
+            }
+            None => {
+                zones.remove(&key);
+                None
+            }
+        }
+    }
+
+    /// Gets the rules of a time zone.
+    fn rules(&self, identifier: &str) -> Option<Rc<Tzif>> {
+        self.zone(identifier).map(|(_, rules)| rules)
+    }
+}
+
+impl TimeZoneProvider for TzifProvider {
+    fn identifier(&self, identifier: &str) -> Option<String> {
+        self.zone(identifier).map(|(identifier, _)| identifier)
+    }
+
+    fn offset_nanoseconds(&self, identifier: &str, epoch_nanoseconds: i128) -> i64 {
+        self.rules(identifier).map_or(0, |rules| {
+            let epoch_seconds = to_epoch_seconds(epoch_nanoseconds.div_euclid(NS_PER_SECOND));
+            i64::from(rules.offset_seconds(epoch_seconds)) * 1_000_000_000
+        })
+    }
+
+    fn next_transition(&self, identifier: &str, epoch_nanoseconds: i128) -> Option<i128> {
+        let epoch_seconds = to_epoch_seconds(epoch_nanoseconds.div_euclid(NS_PER_SECOND));
+        let transition = self.rules(identifier)?.next_transition(epoch_seconds)?;
+        Some(i128::from(transition) * NS_PER_SECOND)
+    }
+
+    fn previous_transition(&self, identifier: &str, epoch_nanoseconds: i128) -> Option<i128> {
+        // The transitions are at whole seconds, so the ones before a time are the ones before
+        // the next second.
+        let epoch_seconds = to_epoch_seconds(-(-epoch_nanoseconds).div_euclid(NS_PER_SECOND));
+        let transition = self.rules(identifier)?.previous_transition(epoch_seconds)?;
+        Some(i128::from(transition) * NS_PER_SECOND)
+    }
+
+    fn system_time_zone(&self) -> Option<String> {
+        // The `TZ` environment variable can name a time zone, with an optional `:` prefix.
+        if let Some(identifier) = env::var("TZ")
+            .ok()
+            .and_then(|tz| self.identifier(tz.trim_start_matches(':')))
+        {
+            return Some(identifier);
+        }
+
+        // Otherwise, `/etc/localtime` is usually a link to a file of the database.
+        let target = fs::read_link("/etc/localtime").ok()?;
+        let target = target.to_str()?;
+        let (_, identifier) = target.split_once("zoneinfo/")?;
+        self.identifier(identifier)
+    }
+}
+
+/// Converts seconds since the epoch to an `i64`, saturating at its bounds.
+fn to_epoch_seconds(epoch_seconds: i128) -> i64 {
+    epoch_seconds.clamp(i128::from(i64::MIN), i128::from(i64::MAX)) as i64
+}
+
+/// Lists the TZif files of a directory and its subdirectories, calling `add` with their
+/// identifier.
+///
+/// The `posix` and `right` directories, which duplicate the database with other conventions,
+/// and the files which are not time zones, are skipped.
+fn list_directory(directory: &Path, prefix: &str, add: &mut dyn FnMut(String)) {
+    let entries = match fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        let name = match entry.file_name().into_string() {
+            Ok(name) => name,
+            Err(_) => continue,
+        };
+        if matches!(
+            name.as_str(),
+            "posix" | "right" | "posixrules" | "localtime" | "Factory"
+        ) {
+            continue;
+        }
+        let identifier = format!("{prefix}{name}");
+        let path = entry.path();
+        if path.is_dir() {
+            list_directory(&path, &format!("{identifier}/"), add);
+        } else if is_tzif_file(&path) {
+            add(identifier);
+        }
+    }
+}
+
+/// Checks if a file starts with the magic number of the TZif files.
+fn is_tzif_file(path: &Path) -> bool {
+    let mut magic = [0; 4];
+    fs::File::open(path)
+        .and_then(|mut file| file.read_exact(&mut magic))
+        .map_or(false, |_| &magic == b"TZif")
+}
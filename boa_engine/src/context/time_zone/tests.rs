@@ -0,0 +1,154 @@
+use super::{InvalidTzif, TimeZoneProvider, TzifProvider};
+
+const NS_PER_SECOND: i128 = 1_000_000_000;
+
+/// Builds a version 2 TZif file, with the initial offset, the transitions to new offsets and
+/// the footer.
+fn tzif(initial_offset: i32, transitions: &[(i64, i32)], footer: &str) -> Vec<u8> {
+    fn header(time_count: usize, type_count: usize) -> Vec<u8> {
+        let mut header = b"TZif2".to_vec();
+        header.extend([0; 15]);
+        for count in [0, 0, 0, time_count, type_count, 4] {
+            header.extend((count as u32).to_be_bytes());
+        }
+        header
+    }
+
+    let mut types = vec![initial_offset];
+    for (_, offset) in transitions {
+        if !types.contains(offset) {
+            types.push(*offset);
+        }
+    }
+
+    // The version 1 data block, which is skipped.
+    let mut data = header(0, 1);
+    data.extend(initial_offset.to_be_bytes());
+    data.extend([0, 0]);
+    data.extend(b"LMT\0");
+
+    data.extend(header(transitions.len(), types.len()));
+    for (time, _) in transitions {
+        data.extend(time.to_be_bytes());
+    }
+    for (_, offset) in transitions {
+        data.push(types.iter().position(|t| t == offset).unwrap() as u8);
+    }
+    for offset in &types {
+        data.extend(offset.to_be_bytes());
+        data.extend([0, 0]);
+    }
+    data.extend(b"LMT\0");
+    data.extend(format!("\n{footer}\n").bytes());
+    data
+}
+
+fn paris() -> TzifProvider {
+    let mut provider = TzifProvider::new();
+    provider
+        .insert(
+            "Europe/Paris",
+            &tzif(
+                3600,
+                &[(1_585_443_600, 7200), (1_603_587_600, 3600)],
+                "CET-1CEST,M3.5.0,M10.5.0/3",
+            ),
+        )
+        .unwrap();
+    provider
+}
+
+#[test]
+fn identifier() {
+    let provider = paris();
+    assert_eq!(
+        provider.identifier("europe/PARIS").as_deref(),
+        Some("Europe/Paris")
+    );
+    assert_eq!(provider.identifier("Europe/London"), None);
+    assert_eq!(
+        TzifProvider::new().insert("Europe/Paris", b"TZif"),
+        Err(InvalidTzif)
+    );
+}
+
+#[test]
+fn offsets() {
+    let provider = paris();
+    for (epoch_seconds, offset) in [
+        // The transitions of the table.
+        (1_585_443_599, 3600),
+        (1_585_443_600, 7200),
+        (1_603_587_599, 7200),
+        (1_603_587_600, 3600),
+        // The transitions of the footer, in 2040.
+        (2_216_249_999, 3600),
+        (2_216_250_000, 7200),
+        (2_234_998_800, 3600),
+    ] {
+        assert_eq!(
+            provider.offset_nanoseconds("Europe/Paris", epoch_seconds * NS_PER_SECOND),
+            offset * 1_000_000_000,
+            "offset at {epoch_seconds}"
+        );
+    }
+
+    // The daylight saving time of the southern hemisphere spans the new year.
+    let mut provider = TzifProvider::new();
+    provider
+        .insert(
+            "Australia/Sydney",
+            &tzif(36000, &[], "AEST-10AEDT,M10.1.0,M4.1.0/3"),
+        )
+        .unwrap();
+    assert_eq!(
+        provider.offset_nanoseconds("Australia/Sydney", 1_894_665_600 * NS_PER_SECOND),
+        39_600_000_000_000
+    );
+    assert_eq!(
+        provider.offset_nanoseconds("Australia/Sydney", 1_910_304_000 * NS_PER_SECOND),
+        36_000_000_000_000
+    );
+}
+
+#[test]
+fn transitions() {
+    let provider = paris();
+    assert_eq!(
+        provider.next_transition("Europe/Paris", 0),
+        Some(1_585_443_600 * NS_PER_SECOND)
+    );
+    assert_eq!(
+        provider.next_transition("Europe/Paris", 1_603_587_600 * NS_PER_SECOND),
+        Some(1_616_893_200 * NS_PER_SECOND)
+    );
+    assert_eq!(
+        provider.previous_transition("Europe/Paris", 2_216_250_000 * NS_PER_SECOND),
+        Some(2_203_549_200 * NS_PER_SECOND)
+    );
+    assert_eq!(
+        provider.previous_transition("Europe/Paris", 1_585_443_600 * NS_PER_SECOND + 1),
+        Some(1_585_443_600 * NS_PER_SECOND)
+    );
+    assert_eq!(
+        provider.previous_transition("Europe/Paris", 1_585_443_600 * NS_PER_SECOND),
+        None
+    );
+}
+
+#[test]
+fn possible_epoch_nanoseconds() {
+    let provider = paris();
+
+    // 2020-03-29T02:30 is skipped.
+    assert_eq!(
+        provider.possible_epoch_nanoseconds("Europe/Paris", 1_585_449_000 * NS_PER_SECOND),
+        Vec::<i128>::new()
+    );
+
+    // 2020-10-25T02:30 is repeated.
+    assert_eq!(
+        provider.possible_epoch_nanoseconds("Europe/Paris", 1_603_593_000 * NS_PER_SECOND),
+        vec![1_603_585_800 * NS_PER_SECOND, 1_603_589_400 * NS_PER_SECOND]
+    );
+}
@@ -0,0 +1,459 @@
+//! Parsing of the Time Zone Information Format (TZif) files of the time zone database, and of
+//! the POSIX TZ strings in their footer.
+//!
+//! More information:
+//!  - [RFC 8536][rfc]
+//!
+//! [rfc]: https://datatracker.ietf.org/doc/html/rfc8536
+
+use crate::builtins::temporal::{epoch_days_from_iso, iso_days_in_month, iso_from_epoch_days};
+
+/// The seconds in a day.
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// The rules of a time zone, read from a TZif file.
+///
+/// The times are in seconds since the epoch, and the offsets are in seconds east of UTC.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Tzif {
+    /// The times of the transitions that change the UTC offset, in ascending order.
+    transitions: Vec<i64>,
+    /// The UTC offset after each transition.
+    offsets: Vec<i32>,
+    /// The UTC offset before the first transition.
+    initial_offset: i32,
+    /// The rule of the times after the last transition.
+    footer: Option<PosixTimeZone>,
+}
+
+impl Tzif {
+    /// Parses a TZif file, of any version.
+    ///
+    /// Returns `None` if the data is not a valid TZif file.
+    pub(crate) fn parse(data: &[u8]) -> Option<Self> {
+        let mut reader = Reader(data);
+        let header = Header::read(&mut reader)?;
+
+        // The version 1 data block uses 32-bit times. From version 2, it's followed by a second
+        // header and data block with 64-bit times, and by a footer.
+        let (header, time_size) = if header.version >= b'2' {
+            reader.skip(header.data_length(4))?;
+            (Header::read(&mut reader)?, 8)
+        } else {
+            (header, 4)
+        };
+
+        let times = (0..header.time_count)
+            .map(|_| reader.time(time_size))
+            .collect::<Option<Vec<_>>>()?;
+        let indices = reader.take(header.time_count)?;
+        let types = (0..header.type_count)
+            .map(|_| {
+                let offset = reader.time(4)? as i32;
+                reader.skip(2)?;
+                Some(offset)
+            })
+            .collect::<Option<Vec<_>>>()?;
+        reader.skip(header.char_count + header.leap_count * (time_size + 4))?;
+        reader.skip(header.std_count + header.ut_count)?;
+
+        // The local time type 0 is used before the first transition.
+        let initial_offset = *types.first()?;
+
+        // Keep only the transitions that change the offset.
+        let mut transitions = Vec::with_capacity(times.len());
+        let mut offsets = Vec::with_capacity(times.len());
+        let mut previous_offset = initial_offset;
+        for (time, index) in times.into_iter().zip(indices) {
+            let offset = *types.get(usize::from(*index))?;
+            if offset != previous_offset {
+                transitions.push(time);
+                offsets.push(offset);
+                previous_offset = offset;
+            }
+        }
+
+        let footer = if time_size == 8 {
+            let footer = reader.0.strip_prefix(b"\n")?;
+            let end = footer.iter().position(|byte| *byte == b'\n')?;
+            let footer = std::str::from_utf8(&footer[..end]).ok()?;
+            if footer.is_empty() {
+                None
+            } else {
+                Some(PosixTimeZone::parse(footer)?)
+            }
+        } else {
+            None
+        };
+
+        Some(Self {
+            transitions,
+            offsets,
+            initial_offset,
+            footer,
+        })
+    }
+
+    /// Gets the UTC offset at a time.
+    pub(crate) fn offset_seconds(&self, epoch_seconds: i64) -> i32 {
+        let index = self
+            .transitions
+            .partition_point(|transition| *transition <= epoch_seconds);
+        match (&self.footer, index) {
+            (Some(footer), index) if index == self.transitions.len() => {
+                footer.offset_seconds(epoch_seconds)
+            }
+            (_, 0) => self.initial_offset,
+            (_, index) => self.offsets[index - 1],
+        }
+    }
+
+    /// Gets the time of the first transition after `epoch_seconds`, if any.
+    pub(crate) fn next_transition(&self, epoch_seconds: i64) -> Option<i64> {
+        let index = self
+            .transitions
+            .partition_point(|transition| *transition <= epoch_seconds);
+        match self.transitions.get(index) {
+            Some(transition) => Some(*transition),
+            None => self.footer.as_ref()?.next_transition(epoch_seconds),
+        }
+    }
+
+    /// Gets the time of the last transition before `epoch_seconds`, if any.
+    pub(crate) fn previous_transition(&self, epoch_seconds: i64) -> Option<i64> {
+        let last = self.transitions.last().copied();
+        if let Some(footer) = &self.footer {
+            if let Some(transition) = footer.previous_transition(epoch_seconds) {
+                if last.map_or(true, |last| transition > last) {
+                    return Some(transition);
+                }
+            }
+        }
+
+        let index = self
+            .transitions
+            .partition_point(|transition| *transition < epoch_seconds);
+        index.checked_sub(1).map(|index| self.transitions[index])
+    }
+}
+
+/// The header of a TZif data block.
+struct Header {
+    version: u8,
+    ut_count: usize,
+    std_count: usize,
+    leap_count: usize,
+    time_count: usize,
+    type_count: usize,
+    char_count: usize,
+}
+
+impl Header {
+    /// Reads a header, checking its magic number.
+    fn read(reader: &mut Reader<'_>) -> Option<Self> {
+        if reader.take(4)? != b"TZif" {
+            return None;
+        }
+        let version = reader.take(1)?[0];
+        reader.skip(15)?;
+        let mut count = || usize::try_from(reader.time(4)?).ok();
+        Some(Self {
+            version,
+            ut_count: count()?,
+            std_count: count()?,
+            leap_count: count()?,
+            time_count: count()?,
+            type_count: count()?,
+            char_count: count()?,
+        })
+    }
+
+    /// Gets the length of the data block following the header.
+    fn data_length(&self, time_size: usize) -> usize {
+        self.time_count * (time_size + 1)
+            + self.type_count * 6
+            + self.char_count
+            + self.leap_count * (time_size + 4)
+            + self.std_count
+            + self.ut_count
+    }
+}
+
+/// A reader of big-endian values.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, length: usize) -> Option<&'a [u8]> {
+        if self.0.len() < length {
+            return None;
+        }
+        let (taken, rest) = self.0.split_at(length);
+        self.0 = rest;
+        Some(taken)
+    }
+
+    fn skip(&mut self, length: usize) -> Option<()> {
+        self.take(length).map(|_| ())
+    }
+
+    /// Reads a signed integer of 4 or 8 bytes.
+    fn time(&mut self, size: usize) -> Option<i64> {
+        let bytes = self.take(size)?;
+        Some(if size == 4 {
+            i64::from(i32::from_be_bytes(bytes.try_into().ok()?))
+        } else {
+            i64::from_be_bytes(bytes.try_into().ok()?)
+        })
+    }
+}
+
+/// A time zone rule given by a POSIX TZ string, such as `CET-1CEST,M3.5.0,M10.5.0/3`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PosixTimeZone {
+    /// The UTC offset of the standard time.
+    std_offset: i32,
+    /// The UTC offset of the daylight saving time, with its start and end rules.
+    dst: Option<(i32, Rule, Rule)>,
+}
+
+/// The rule of a daylight saving time transition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Rule {
+    date: RuleDate,
+    /// The local time of the transition, in seconds from the start of the day.
+    time: i32,
+}
+
+/// The day of a daylight saving time transition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RuleDate {
+    /// `Jn`: the day of the year from 1 to 365, without February 29.
+    Julian(u16),
+    /// `n`: the day of the year from 0 to 365, counting February 29.
+    Ordinal(u16),
+    /// `Mm.w.d`: the day `d` (0 is Sunday) of the week `w` (5 is the last one) of the month `m`.
+    MonthWeekDay(u8, u8, u8),
+}
+
+impl PosixTimeZone {
+    /// Parses a POSIX TZ string, with the extensions of RFC 8536.
+    pub(crate) fn parse(text: &str) -> Option<Self> {
+        let mut text = text.as_bytes();
+        skip_name(&mut text)?;
+
+        // The POSIX offsets are west of UTC.
+        let std_offset = -posix_time(&mut text)?;
+        if text.is_empty() {
+            return Some(Self {
+                std_offset,
+                dst: None,
+            });
+        }
+
+        skip_name(&mut text)?;
+        let dst_offset = if text.first() == Some(&b',') {
+            std_offset + 3600
+        } else {
+            -posix_time(&mut text)?
+        };
+        text = text.strip_prefix(b",")?;
+        let start = Rule::parse(&mut text)?;
+        text = text.strip_prefix(b",")?;
+        let end = Rule::parse(&mut text)?;
+        if !text.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            std_offset,
+            dst: (dst_offset != std_offset).then(|| (dst_offset, start, end)),
+        })
+    }
+
+    /// Gets the start and end of the daylight saving time of a year.
+    fn dst_period(year: i32, std_offset: i32, dst: (i32, Rule, Rule)) -> (i64, i64) {
+        let (dst_offset, start, end) = dst;
+        (
+            start.epoch_seconds(year) - i64::from(std_offset),
+            end.epoch_seconds(year) - i64::from(dst_offset),
+        )
+    }
+
+    /// Gets the year of a time, in the standard time.
+    fn year(&self, epoch_seconds: i64) -> i32 {
+        let local = epoch_seconds + i64::from(self.std_offset);
+        iso_from_epoch_days(local.div_euclid(SECONDS_PER_DAY)).0
+    }
+
+    /// Gets the UTC offset at a time.
+    fn offset_seconds(&self, epoch_seconds: i64) -> i32 {
+        let dst = match self.dst {
+            Some(dst) => dst,
+            None => return self.std_offset,
+        };
+        let (start, end) = Self::dst_period(self.year(epoch_seconds), self.std_offset, dst);
+        let in_dst = if start < end {
+            start <= epoch_seconds && epoch_seconds < end
+        } else {
+            // The daylight saving time of the southern hemisphere spans the new year.
+            !(end <= epoch_seconds && epoch_seconds < start)
+        };
+        if in_dst {
+            dst.0
+        } else {
+            self.std_offset
+        }
+    }
+
+    /// Gets the transitions of the years around a time, in ascending order.
+    fn transitions_around(&self, epoch_seconds: i64) -> Vec<i64> {
+        let dst = match self.dst {
+            Some(dst) => dst,
+            None => return Vec::new(),
+        };
+        let year = self.year(epoch_seconds);
+        let mut transitions = (year - 1..=year + 1)
+            .flat_map(|year| {
+                let (start, end) = Self::dst_period(year, self.std_offset, dst);
+                [start, end]
+            })
+            .collect::<Vec<_>>();
+        transitions.sort_unstable();
+        transitions
+    }
+
+    /// Gets the time of the first transition after `epoch_seconds`, if any.
+    fn next_transition(&self, epoch_seconds: i64) -> Option<i64> {
+        self.transitions_around(epoch_seconds)
+            .into_iter()
+            .find(|transition| *transition > epoch_seconds)
+    }
+
+    /// Gets the time of the last transition before `epoch_seconds`, if any.
+    fn previous_transition(&self, epoch_seconds: i64) -> Option<i64> {
+        self.transitions_around(epoch_seconds)
+            .into_iter()
+            .rev()
+            .find(|transition| *transition < epoch_seconds)
+    }
+}
+
+impl Rule {
+    /// Parses a rule, as a date optionally followed by `/` and a time.
+    fn parse(text: &mut &[u8]) -> Option<Self> {
+        let date = match text.first()? {
+            b'J' => {
+                *text = &text[1..];
+                let day = number(text)?;
+                (1..=365)
+                    .contains(&day)
+                    .then(|| RuleDate::Julian(day as u16))?
+            }
+            b'M' => {
+                *text = &text[1..];
+                let month = number(text)?;
+                *text = text.strip_prefix(b".")?;
+                let week = number(text)?;
+                *text = text.strip_prefix(b".")?;
+                let day = number(text)?;
+                if !(1..=12).contains(&month) || !(1..=5).contains(&week) || day > 6 {
+                    return None;
+                }
+                RuleDate::MonthWeekDay(month as u8, week as u8, day as u8)
+            }
+            _ => {
+                let day = number(text)?;
+                (day <= 365).then(|| RuleDate::Ordinal(day as u16))?
+            }
+        };
+
+        // The default time of a transition is 02:00.
+        let time = if let Some(rest) = text.strip_prefix(b"/") {
+            *text = rest;
+            posix_time(text)?
+        } else {
+            7200
+        };
+        Some(Self { date, time })
+    }
+
+    /// Gets the time of the transition in a year, as the seconds since the epoch of the local
+    /// time.
+    fn epoch_seconds(self, year: i32) -> i64 {
+        let leap = iso_days_in_month(year, 2) == 29;
+        let days = match self.date {
+            RuleDate::Julian(day) => {
+                let day = i64::from(day) - 1;
+                epoch_days_from_iso(year, 1, 1) + day + i64::from(leap && day >= 59)
+            }
+            RuleDate::Ordinal(day) => epoch_days_from_iso(year, 1, 1) + i64::from(day),
+            RuleDate::MonthWeekDay(month, week, weekday) => {
+                let first = epoch_days_from_iso(year, month, 1);
+                // The epoch was a Thursday.
+                let first_weekday = (first + 4).rem_euclid(7);
+                let mut day = first
+                    + (i64::from(weekday) - first_weekday).rem_euclid(7)
+                    + 7 * i64::from(week - 1);
+                while day >= first + i64::from(iso_days_in_month(year, month)) {
+                    day -= 7;
+                }
+                day
+            }
+        };
+        days * SECONDS_PER_DAY + i64::from(self.time)
+    }
+}
+
+/// Skips the name of a time, either alphabetic or quoted with `<` and `>`.
+fn skip_name(text: &mut &[u8]) -> Option<()> {
+    let length = if text.first() == Some(&b'<') {
+        text.iter().position(|byte| *byte == b'>')? + 1
+    } else {
+        text.iter()
+            .position(|byte| !byte.is_ascii_alphabetic())
+            .unwrap_or(text.len())
+    };
+    if length < 3 {
+        return None;
+    }
+    *text = &text[length..];
+    Some(())
+}
+
+/// Parses a decimal number.
+fn number(text: &mut &[u8]) -> Option<i32> {
+    let length = text
+        .iter()
+        .position(|byte| !byte.is_ascii_digit())
+        .unwrap_or(text.len());
+    if length == 0 || length > 6 {
+        return None;
+    }
+    let (digits, rest) = text.split_at(length);
+    *text = rest;
+    std::str::from_utf8(digits).ok()?.parse().ok()
+}
+
+/// Parses a time or an offset, as `[+|-]hh[:mm[:ss]]`, returning it in seconds.
+fn posix_time(text: &mut &[u8]) -> Option<i32> {
+    let sign = match text.first() {
+        Some(b'-') => -1,
+        Some(b'+') => 1,
+        _ => 0,
+    };
+    if sign != 0 {
+        *text = &text[1..];
+    }
+    let hours = number(text)?;
+    let mut seconds = hours * 3600;
+    for multiplier in [60, 1] {
+        match text.strip_prefix(b":") {
+            Some(rest) => {
+                *text = rest;
+                seconds += number(text)? * multiplier;
+            }
+            None => break,
+        }
+    }
+    (hours <= 167).then(|| if sign < 0 { -seconds } else { seconds })
+}
@@ -3,32 +3,160 @@ use crate::{
     JsString, JsValue,
 };
 use boa_interner::Sym;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::rc::Rc;
 
 /// A compile time binding represents a binding at bytecode compile time in a [`CompileTimeEnvironment`].
 ///
 /// It contains the binding index and a flag to indicate if this is a mutable binding or not.
-#[derive(Debug)]
+///
+/// A local binding is stored in the call frame instead of the environment. Its index is the
+/// order in which it was created in its function, until its environment is popped.
+#[derive(Debug, Clone)]
 struct CompileTimeBinding {
     index: usize,
     mutable: bool,
+    local: bool,
 }
 
 /// A compile time environment maps bound identifiers to their binding positions.
 ///
 /// A compile time environment also indicates, if it is a function environment.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct CompileTimeEnvironment {
     bindings: FxHashMap<Sym, CompileTimeBinding>,
     function_scope: bool,
+    num_bindings: usize,
+    first_local: usize,
+    num_locals: usize,
 }
 
 impl CompileTimeEnvironment {
-    /// Returns the number of bindings in this environment.
+    /// Creates a new compile time environment.
+    #[inline]
+    fn new(function_scope: bool) -> Self {
+        Self {
+            bindings: FxHashMap::default(),
+            function_scope,
+            num_bindings: 0,
+            first_local: 0,
+            num_locals: 0,
+        }
+    }
+
+    /// Returns the number of bindings in this environment, without its local bindings.
     #[inline]
     pub(crate) fn num_bindings(&self) -> usize {
-        self.bindings.len()
+        self.num_bindings
+    }
+
+    /// Returns the index of the first local binding of this environment in the call frame.
+    ///
+    /// The local bindings of an environment have consecutive indices, that are known once the
+    /// environment has been popped.
+    #[inline]
+    pub(crate) fn first_local(&self) -> usize {
+        self.first_local
+    }
+
+    /// Returns the number of local bindings of this environment.
+    #[inline]
+    pub(crate) fn num_locals(&self) -> usize {
+        self.num_locals
+    }
+
+    /// Creates a binding in this environment.
+    ///
+    /// The binding is local if the environment belongs to `frame` and the binding is not
+    /// captured by a nested function.
+    #[inline]
+    fn create_binding(&mut self, name: Sym, mutable: bool, frame: Option<&mut CompileTimeFrame>) {
+        let binding = match frame {
+            Some(frame) if !frame.captured.contains(&name) => {
+                frame.locals.push(None);
+                CompileTimeBinding {
+                    index: frame.locals.len() - 1,
+                    mutable,
+                    local: true,
+                }
+            }
+            _ => {
+                self.num_bindings += 1;
+                CompileTimeBinding {
+                    index: self.num_bindings - 1,
+                    mutable,
+                    local: false,
+                }
+            }
+        };
+        self.bindings.insert(name, binding);
+    }
+}
+
+/// A compile time frame tracks the local bindings of the function being compiled.
+///
+/// The local bindings of a function are the bindings that are not captured by its nested
+/// functions, which are stored in the call frame instead of the environments.
+#[derive(Debug)]
+pub(crate) struct CompileTimeFrame {
+    /// The index of the function environment of the function on the environment stack.
+    environment_index: usize,
+    /// The names of the bindings that must be stored in environments.
+    captured: FxHashSet<Sym>,
+    /// The indices of the local bindings in the call frame, in the order they were created.
+    locals: Vec<Option<usize>>,
+    num_locals: usize,
+}
+
+impl CompileTimeFrame {
+    /// Returns the number of local bindings of the function.
+    #[inline]
+    pub(crate) fn num_locals(&self) -> usize {
+        self.num_locals
     }
+
+    /// Returns the binding locator with the index of the binding in the call frame, if the
+    /// binding is local.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the environment of a local binding was not popped.
+    #[inline]
+    pub(crate) fn resolve(&self, binding: BindingLocator) -> BindingLocator {
+        if binding.is_local() {
+            let index = self.locals[binding.binding_index()]
+                .expect("the environments of a function must be popped");
+            BindingLocator::local(binding.name(), index)
+        } else {
+            binding
+        }
+    }
+}
+
+/// A declaration made on the global environment at bytecode compile time.
+///
+/// Compiling a script declares its top level bindings, so these declarations are recorded to be
+/// made again when a compiled script is loaded in another realm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum GlobalDeclaration {
+    /// A `var` or function declaration, which is a property of the global object.
+    Var(Sym),
+    /// A `let` declaration.
+    Let(Sym),
+    /// A `const` or `using` declaration.
+    Const(Sym),
+}
+
+/// Returns the frame of the function being compiled, if the environment at `environment_index`
+/// belongs to it.
+#[inline]
+fn frame_of(
+    frames: &mut [CompileTimeFrame],
+    environment_index: usize,
+) -> Option<&mut CompileTimeFrame> {
+    frames
+        .last_mut()
+        .filter(|frame| environment_index >= frame.environment_index)
 }
 
 /// The compile time environment stack contains a stack of all environments at bytecode compile time.
@@ -36,9 +164,15 @@ impl CompileTimeEnvironment {
 /// The first environment on the stack represents the global environment.
 /// This is never being deleted and is tied to the existence of the realm.
 /// All other environments are being dropped once they are not needed anymore.
+///
+/// The environments are shared with the functions that are compiled lazily, which keep the
+/// environments enclosing them as they were when they were defined. An environment is copied
+/// when it is changed while it is shared.
 #[derive(Debug)]
 pub(crate) struct CompileTimeEnvironmentStack {
-    stack: Vec<CompileTimeEnvironment>,
+    stack: Vec<Rc<CompileTimeEnvironment>>,
+    frames: Vec<CompileTimeFrame>,
+    recorded_declarations: Option<Vec<GlobalDeclaration>>,
 }
 
 impl CompileTimeEnvironmentStack {
@@ -48,10 +182,9 @@ impl CompileTimeEnvironmentStack {
     #[inline]
     pub(crate) fn new() -> Self {
         Self {
-            stack: vec![CompileTimeEnvironment {
-                bindings: FxHashMap::default(),
-                function_scope: true,
-            }],
+            stack: vec![Rc::new(CompileTimeEnvironment::new(true))],
+            frames: Vec::new(),
+            recorded_declarations: None,
         }
     }
 
@@ -67,6 +200,60 @@ impl CompileTimeEnvironmentStack {
             .expect("global environment must always exist")
             .num_bindings()
     }
+
+    /// Starts recording the declarations made on the global environment.
+    #[inline]
+    pub(crate) fn start_recording_declarations(&mut self) {
+        self.recorded_declarations = Some(Vec::new());
+    }
+
+    /// Stops recording the declarations made on the global environment, returning them in the
+    /// order they were made.
+    #[inline]
+    pub(crate) fn finish_recording_declarations(&mut self) -> Vec<GlobalDeclaration> {
+        self.recorded_declarations.take().unwrap_or_default()
+    }
+
+    /// Records a declaration made on the global environment, if recording.
+    #[inline]
+    fn record_declaration(&mut self, declaration: GlobalDeclaration) {
+        if let Some(declarations) = &mut self.recorded_declarations {
+            declarations.push(declaration);
+        }
+    }
+
+    /// Returns the index of the binding with the given name on the global environment, and if it
+    /// is mutable.
+    #[inline]
+    pub(crate) fn global_binding(&self, name: Sym) -> Option<(usize, bool)> {
+        self.stack
+            .first()
+            .expect("global environment must always exist")
+            .bindings
+            .get(&name)
+            .map(|binding| (binding.index, binding.mutable))
+    }
+
+    /// Returns the binding locator of a binding of the environment at `environment_index`.
+    #[inline]
+    fn locator(
+        &self,
+        name: Sym,
+        environment_index: usize,
+        binding: &CompileTimeBinding,
+    ) -> BindingLocator {
+        if binding.local {
+            debug_assert!(
+                self.frames
+                    .last()
+                    .map_or(false, |frame| environment_index >= frame.environment_index),
+                "local bindings cannot be captured"
+            );
+            BindingLocator::local(name, binding.index)
+        } else {
+            BindingLocator::declarative(name, environment_index, binding.index)
+        }
+    }
 }
 
 impl Context {
@@ -75,12 +262,69 @@ impl Context {
     /// Note: This function only works at bytecode compile time!
     #[inline]
     pub(crate) fn push_compile_time_environment(&mut self, function_scope: bool) {
-        self.realm.compile_env.stack.push(CompileTimeEnvironment {
-            bindings: FxHashMap::default(),
-            function_scope,
+        self.realm
+            .compile_env
+            .stack
+            .push(Rc::new(CompileTimeEnvironment::new(function_scope)));
+    }
+
+    /// Returns the compile time environments enclosing the code being compiled, in which a
+    /// function defined by this code can be compiled later.
+    ///
+    /// Note: This function only works at bytecode compile time!
+    #[inline]
+    pub(crate) fn compile_time_environments(&self) -> Vec<Rc<CompileTimeEnvironment>> {
+        self.realm.compile_env.stack.clone()
+    }
+
+    /// Replaces the compile time environment stack with `environments`, returning the replaced
+    /// environments.
+    ///
+    /// This is used to compile a function in the environments that enclosed its definition, and
+    /// to restore the environments afterwards. The frames left by a compilation that failed are
+    /// discarded.
+    #[inline]
+    pub(crate) fn replace_compile_time_environments(
+        &mut self,
+        environments: Vec<Rc<CompileTimeEnvironment>>,
+    ) -> Vec<Rc<CompileTimeEnvironment>> {
+        self.realm.compile_env.frames.clear();
+        std::mem::replace(&mut self.realm.compile_env.stack, environments)
+    }
+
+    /// Push a new frame for a function, whose bindings are local unless their names are
+    /// `captured`, on the compile time environment stack.
+    ///
+    /// The frame must be pushed before the function environment.
+    ///
+    /// Note: This function only works at bytecode compile time!
+    #[inline]
+    pub(crate) fn push_compile_time_frame(&mut self, captured: FxHashSet<Sym>) {
+        let environment_index = self.realm.compile_env.stack.len();
+        self.realm.compile_env.frames.push(CompileTimeFrame {
+            environment_index,
+            captured,
+            locals: Vec::new(),
+            num_locals: 0,
         });
     }
 
+    /// Pop the last compile time frame, after all of the environments of its function.
+    ///
+    /// Note: This function only works at bytecode compile time!
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no frame on the stack.
+    #[inline]
+    pub(crate) fn pop_compile_time_frame(&mut self) -> CompileTimeFrame {
+        self.realm
+            .compile_env
+            .frames
+            .pop()
+            .expect("a frame must have been pushed")
+    }
+
     /// Pop the last compile time environment from the stack.
     ///
     /// Note: This function only works at bytecode compile time!
@@ -94,11 +338,25 @@ impl Context {
             self.realm.compile_env.stack.len() > 1,
             "cannot pop global environment"
         );
-        self.realm
+        let env = self
+            .realm
             .compile_env
             .stack
             .pop()
-            .expect("len > 1 already checked")
+            .expect("len > 1 already checked");
+        let mut env = Rc::try_unwrap(env).unwrap_or_else(|env| (*env).clone());
+
+        // The local bindings are given consecutive indices, so that the instruction pushing the
+        // environment can uninitialize them when the environment is entered again.
+        if let Some(frame) = self.realm.compile_env.frames.last_mut() {
+            env.first_local = frame.num_locals;
+            for binding in env.bindings.values().filter(|binding| binding.local) {
+                frame.locals[binding.index] = Some(frame.num_locals);
+                frame.num_locals += 1;
+            }
+            env.num_locals = frame.num_locals - env.first_local;
+        }
+        env
     }
 
     /// Get the number of bindings for the current compile time environment.
@@ -125,7 +383,7 @@ impl Context {
     pub(crate) fn get_binding_value(&self, name: Sym) -> BindingLocator {
         for (i, env) in self.realm.compile_env.stack.iter().enumerate().rev() {
             if let Some(binding) = env.bindings.get(&name) {
-                return BindingLocator::declarative(name, i, binding.index);
+                return self.realm.compile_env.locator(name, i, binding);
             }
         }
         BindingLocator::global(name)
@@ -166,6 +424,11 @@ impl Context {
             if !function_scope || env.function_scope {
                 if env.bindings.contains_key(&name) {
                     if allow_name_reuse {
+                        if i == 0 && function_scope {
+                            self.realm
+                                .compile_env
+                                .record_declaration(GlobalDeclaration::Var(name));
+                        }
                         return Ok(());
                     }
                     return self
@@ -192,8 +455,14 @@ impl Context {
                                 .configurable(true)
                                 .build(),
                         );
+                        self.realm
+                            .compile_env
+                            .record_declaration(GlobalDeclaration::Var(name));
                         return Ok(());
                     } else if function_scope {
+                        self.realm
+                            .compile_env
+                            .record_declaration(GlobalDeclaration::Var(name));
                         return Ok(());
                     } else if !function_scope
                         && !allow_name_reuse
@@ -204,14 +473,19 @@ impl Context {
                     }
                 }
 
-                let binding_index = env.bindings.len();
-                env.bindings.insert(
+                Rc::make_mut(env).create_binding(
                     name,
-                    CompileTimeBinding {
-                        index: binding_index,
-                        mutable: true,
-                    },
+                    true,
+                    frame_of(&mut self.realm.compile_env.frames, i),
                 );
+                if i == 0 {
+                    let declaration = if function_scope {
+                        GlobalDeclaration::Var(name)
+                    } else {
+                        GlobalDeclaration::Let(name)
+                    };
+                    self.realm.compile_env.record_declaration(declaration);
+                }
                 return Ok(());
             }
             continue;
@@ -233,7 +507,7 @@ impl Context {
                 continue;
             }
             if let Some(binding) = env.bindings.get(&name) {
-                return BindingLocator::declarative(name, i, binding.index);
+                return self.realm.compile_env.locator(name, i, binding);
             }
             return BindingLocator::global(name);
         }
@@ -251,9 +525,10 @@ impl Context {
     #[inline]
     pub(crate) fn create_immutable_binding(&mut self, name: Sym) -> JsResult<()> {
         let name_str = JsString::from(self.interner().resolve_expect(name));
-        let exists_global = self.realm.compile_env.stack.len() == 1
-            && self.global_bindings().contains_key(&name_str);
+        let is_global = self.realm.compile_env.stack.len() == 1;
+        let exists_global = is_global && self.global_bindings().contains_key(&name_str);
 
+        let environment_index = self.realm.compile_env.stack.len() - 1;
         let env = self
             .realm
             .compile_env
@@ -264,14 +539,16 @@ impl Context {
         if env.bindings.contains_key(&name) || exists_global {
             self.throw_syntax_error(format!("Redeclaration of variable {}", name_str))
         } else {
-            let binding_index = env.bindings.len();
-            env.bindings.insert(
+            Rc::make_mut(env).create_binding(
                 name,
-                CompileTimeBinding {
-                    index: binding_index,
-                    mutable: false,
-                },
+                false,
+                frame_of(&mut self.realm.compile_env.frames, environment_index),
             );
+            if is_global {
+                self.realm
+                    .compile_env
+                    .record_declaration(GlobalDeclaration::Const(name));
+            }
             Ok(())
         }
     }
@@ -294,7 +571,9 @@ impl Context {
             .expect("global environment must always exist");
 
         let binding = env.bindings.get(&name).expect("binding must exist");
-        BindingLocator::declarative(name, environment_index, binding.index)
+        self.realm
+            .compile_env
+            .locator(name, environment_index, binding)
     }
 
     /// Return the binding locator for a set operation on an existing binding.
@@ -305,7 +584,7 @@ impl Context {
         for (i, env) in self.realm.compile_env.stack.iter().enumerate().rev() {
             if let Some(binding) = env.bindings.get(&name) {
                 if binding.mutable {
-                    return BindingLocator::declarative(name, i, binding.index);
+                    return self.realm.compile_env.locator(name, i, binding);
                 }
                 return BindingLocator::mutate_immutable(name);
             }
@@ -19,6 +19,10 @@
 //!  - An environment index, that identifies the environment in which the binding exists
 //!  - A binding index, that identifies the binding in the environment
 //!
+//! The bindings of a function that are not captured by its nested functions don't need to
+//! outlive the call of the function. These local bindings are not stored in environments, but
+//! in the call frame, where they are identified by their index alone.
+//!
 //! More information:
 //!  - [ECMAScript reference][spec]
 //!
@@ -28,7 +32,7 @@ mod compile;
 mod runtime;
 
 pub(crate) use {
-    compile::CompileTimeEnvironmentStack,
+    compile::{CompileTimeEnvironment, CompileTimeEnvironmentStack, GlobalDeclaration},
     runtime::{BindingLocator, DeclarativeEnvironment, DeclarativeEnvironmentStack},
 };
 
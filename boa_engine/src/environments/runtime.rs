@@ -246,47 +246,60 @@ pub(crate) struct BindingLocator {
     binding_index: usize,
     global: bool,
     mutate_immutable: bool,
+    local: bool,
 }
 
 impl BindingLocator {
     /// Creates a new declarative binding locator that has knows indices.
     #[inline]
-    pub(in crate::environments) fn declarative(
-        name: Sym,
-        environment_index: usize,
-        binding_index: usize,
-    ) -> Self {
+    pub(crate) fn declarative(name: Sym, environment_index: usize, binding_index: usize) -> Self {
         Self {
             name,
             environment_index,
             binding_index,
             global: false,
             mutate_immutable: false,
+            local: false,
+        }
+    }
+
+    /// Creates a binding locator for a binding stored in the call frame at the given index.
+    #[inline]
+    pub(crate) fn local(name: Sym, binding_index: usize) -> Self {
+        Self {
+            name,
+            environment_index: 0,
+            binding_index,
+            global: false,
+            mutate_immutable: false,
+            local: true,
         }
     }
 
     /// Creates a binding locator that indicates that the binding is on the global object.
     #[inline]
-    pub(in crate::environments) fn global(name: Sym) -> Self {
+    pub(crate) fn global(name: Sym) -> Self {
         Self {
             name,
             environment_index: 0,
             binding_index: 0,
             global: true,
             mutate_immutable: false,
+            local: false,
         }
     }
 
     /// Creates a binding locator that indicates that it was attempted to mutate an immutable binding.
     /// At runtime this should always produce a type error.
     #[inline]
-    pub(in crate::environments) fn mutate_immutable(name: Sym) -> Self {
+    pub(crate) fn mutate_immutable(name: Sym) -> Self {
         Self {
             name,
             environment_index: 0,
             binding_index: 0,
             global: false,
             mutate_immutable: true,
+            local: false,
         }
     }
 
@@ -302,13 +315,25 @@ impl BindingLocator {
         self.global
     }
 
+    /// Returns if the binding is stored in the call frame.
+    #[inline]
+    pub(crate) fn is_local(&self) -> bool {
+        self.local
+    }
+
+    /// Returns if the binding locator indicates that it was attempted to mutate an immutable binding.
+    #[inline]
+    pub(crate) fn is_mutate_immutable(&self) -> bool {
+        self.mutate_immutable
+    }
+
     /// Returns the environment index of the binding.
     #[inline]
     pub(crate) fn environment_index(&self) -> usize {
         self.environment_index
     }
 
-    /// Returns the binding index of the binding.
+    /// Returns the binding index of the binding, or its index in the call frame if it is local.
     #[inline]
     pub(crate) fn binding_index(&self) -> usize {
         self.binding_index
@@ -90,3 +90,116 @@ fn set_outer_let_in_block_scope() {
 
     assert_eq!(&exec(scenario), "true");
 }
+
+#[test]
+fn local_and_captured_bindings() {
+    let scenario = r#"
+          function f() {
+            let a = 1;
+            let b = 2;
+            const g = () => b;
+            b = 3;
+            a = a + g();
+            return a;
+          }
+          f();
+        "#;
+
+    assert_eq!(&exec(scenario), "4");
+}
+
+#[test]
+fn local_let_in_loop_is_uninitialized_on_reentry() {
+    let scenario = r#"
+          function f() {
+            let result = "";
+            for (let i = 0; i < 2; i++) {
+              try {
+                result += x;
+              } catch (err) {
+                result += err.message;
+              }
+              let x = i;
+            }
+            return result;
+          }
+          f();
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"x is not initializedx is not initialized\""
+    );
+}
+
+#[test]
+fn local_let_in_switch_is_uninitialized() {
+    let scenario = r#"
+          function f(value) {
+            switch (value) {
+              case 0:
+                let x = "foo";
+                return x;
+              case 1:
+                try {
+                  x = "bar";
+                } catch (err) {
+                  return err.message;
+                }
+            }
+          }
+          f(0) + f(1);
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"foocannot access 'x' before initialization\""
+    );
+}
+
+#[test]
+fn assign_local_const() {
+    let scenario = r#"
+          function f() {
+            const a = 1;
+            try {
+              a = 2;
+            } catch (err) {
+              return err instanceof TypeError;
+            }
+          }
+          f();
+        "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn local_bindings_in_generator() {
+    let scenario = r#"
+          function* g() {
+            let a = 1;
+            yield a;
+            a += 1;
+            yield a;
+          }
+          const it = g();
+          it.next().value + it.next().value;
+        "#;
+
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn mapped_arguments_with_local_bindings() {
+    let scenario = r#"
+          function f(a, b) {
+            let c = 3;
+            arguments[0] = 10;
+            return a + b + c;
+          }
+          f(1, 2);
+        "#;
+
+    assert_eq!(&exec(scenario), "15");
+}
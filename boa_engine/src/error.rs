@@ -0,0 +1,409 @@
+//! Rust-side representation of thrown JavaScript values and their call stacks.
+//!
+//! Every error object created by the engine records the call stack that was active when it was
+//! constructed. The [`JsError`] wrapper gives embedders structured access to those frames, while
+//! scripts can read them in text form through `Error.prototype.stack`.
+//!
+//! [`JsError::capture`] copies a thrown value into a [`CapturedError`], which implements
+//! [`std::error::Error`] and can outlive the context, and [`JsError::from_std_error`] converts a
+//! Rust error back to an error object whose `cause` chain follows the sources of the error. This
+//! makes thrown values interoperate with error libraries like `anyhow`.
+
+use crate::{
+    object::{JsObject, ObjectData},
+    syntax::ast::Span,
+    Context, JsString, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_interner::Sym;
+use std::{error, fmt};
+
+/// The maximum number of causes followed by [`JsError::capture`], which stops at cycles.
+const MAX_CAUSE_DEPTH: usize = 32;
+
+/// A single frame of a captured call stack.
+#[derive(Debug, Clone, PartialEq, Eq, Trace, Finalize)]
+pub struct StackFrame {
+    function_name: JsString,
+    bytecode_offset: usize,
+    #[unsafe_ignore_trace]
+    span: Option<Span>,
+}
+
+impl StackFrame {
+    /// The name of the function executing in this frame.
+    ///
+    /// The top-level code of a script is named `<main>`, and anonymous functions have an empty
+    /// name.
+    #[inline]
+    pub fn function_name(&self) -> &JsString {
+        &self.function_name
+    }
+
+    /// The offset of the next instruction to be executed in the bytecode of the function.
+    #[inline]
+    pub fn bytecode_offset(&self) -> usize {
+        self.bytecode_offset
+    }
+
+    /// The span of the source code being executed in this frame, if it is known.
+    ///
+    /// This is the span of the call made by the frame for every frame but the innermost one, and
+    /// of the call, `new` expression or statement that was executing when the stack was captured
+    /// for the innermost frame.
+    #[inline]
+    pub fn span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// Captures the frames of the currently executing code, innermost first.
+    pub(crate) fn capture(context: &Context) -> Vec<Self> {
+        let mut frames = Vec::new();
+        let mut frame = context.vm.frame.as_deref();
+        while let Some(current) = frame {
+            let name = if current.code.name == Sym::EMPTY_STRING {
+                JsString::empty()
+            } else {
+                context.interner().resolve_expect(current.code.name).into()
+            };
+            // The program counter is past the instruction being executed.
+            let span = current
+                .pc
+                .checked_sub(1)
+                .and_then(|pc| current.code.span_at(pc));
+            frames.push(Self {
+                function_name: name,
+                bytecode_offset: current.pc,
+                span,
+            });
+            frame = current.prev.as_deref();
+        }
+        frames
+    }
+}
+
+impl fmt::Display for StackFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.function_name.is_empty() {
+            f.write_str("<anonymous>")?;
+        } else {
+            f.write_str(&self.function_name)?;
+        }
+        if let Some(span) = self.span {
+            write!(f, " ({})", span.start())?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats a captured call stack the way `Error.prototype.stack` presents it.
+pub(crate) fn format_stack(header: &str, frames: &[StackFrame]) -> String {
+    let mut stack = header.to_owned();
+    for frame in frames {
+        stack.push_str(&format!("\n    at {frame}"));
+    }
+    stack
+}
+
+/// A JavaScript value thrown by the engine, as seen from Rust.
+///
+/// The error variant of [`JsResult`](crate::JsResult) is a plain [`JsValue`], since any value
+/// can be thrown. `JsError` wraps such a value to give access to the information recorded when
+/// the value is an error object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsError {
+    inner: JsValue,
+}
+
+impl JsError {
+    /// Wraps a thrown value.
+    #[inline]
+    pub fn from_value(value: JsValue) -> Self {
+        Self { inner: value }
+    }
+
+    /// Gets the thrown value.
+    #[inline]
+    pub fn as_value(&self) -> &JsValue {
+        &self.inner
+    }
+
+    /// Unwraps the thrown value.
+    #[inline]
+    pub fn into_value(self) -> JsValue {
+        self.inner
+    }
+
+    /// Gets the thrown value if it is an error object.
+    #[inline]
+    pub fn as_error_object(&self) -> Option<&JsObject> {
+        self.inner.as_object().filter(|obj| obj.is_error())
+    }
+
+    /// The call stack captured when the thrown error object was created, innermost frame first.
+    ///
+    /// Returns an empty list when the thrown value is not an error object.
+    pub fn stack_frames(&self) -> Vec<StackFrame> {
+        self.as_error_object()
+            .and_then(|obj| obj.borrow().as_error_stack().map(<[_]>::to_vec))
+            .unwrap_or_default()
+    }
+
+    /// Copies the kind, message, stack and causes of the thrown value into a [`CapturedError`].
+    ///
+    /// Reading the `message` and `cause` of an error object can run getters; a getter that throws
+    /// is treated as a missing property.
+    pub fn capture(&self, context: &mut Context) -> CapturedError {
+        CapturedError::new(&self.inner, MAX_CAUSE_DEPTH, context)
+    }
+
+    /// Converts a Rust error to an `Error` object, whose `cause` property holds the conversion of
+    /// the source of the error, if any.
+    ///
+    /// A [`CapturedError`] found in the chain is converted back to an error object of its kind.
+    pub fn from_std_error(error: &(dyn error::Error + 'static), context: &mut Context) -> Self {
+        let (kind, message) = match error.downcast_ref::<CapturedError>() {
+            Some(captured) if captured.kind == JsErrorKind::Value => {
+                return Self::from_value(captured.message.clone().into());
+            }
+            Some(captured) => (captured.kind, captured.message.clone()),
+            None => (JsErrorKind::Error, error.to_string()),
+        };
+        let cause = error
+            .source()
+            .map(|source| Self::from_std_error(source, context).into_value());
+
+        let prototype = kind.prototype(context);
+        let object = JsObject::from_proto_and_data(prototype, ObjectData::error(context));
+        object.create_non_enumerable_data_property_or_throw("message", message, context);
+        if let Some(cause) = cause {
+            object.create_non_enumerable_data_property_or_throw("cause", cause, context);
+        }
+        Self::from_value(object.into())
+    }
+}
+
+impl From<JsValue> for JsError {
+    #[inline]
+    fn from(value: JsValue) -> Self {
+        Self::from_value(value)
+    }
+}
+
+impl From<JsError> for JsValue {
+    #[inline]
+    fn from(error: JsError) -> Self {
+        error.into_value()
+    }
+}
+
+/// The kind of a thrown value, given by the builtin error constructor that created it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsErrorKind {
+    /// An `Error`, or an error object whose kind isn't recognized.
+    Error,
+    /// An `AggregateError`.
+    Aggregate,
+    /// An `EvalError`.
+    Eval,
+    /// A `RangeError`.
+    Range,
+    /// A `ReferenceError`.
+    Reference,
+    /// A `SuppressedError`.
+    Suppressed,
+    /// A `SyntaxError`.
+    Syntax,
+    /// A `TypeError`.
+    Type,
+    /// A `URIError`.
+    Uri,
+    /// A thrown value that isn't an error object.
+    Value,
+}
+
+impl JsErrorKind {
+    /// The kinds of error objects, with the most specific kinds first.
+    const ERRORS: [Self; 9] = [
+        Self::Aggregate,
+        Self::Eval,
+        Self::Range,
+        Self::Reference,
+        Self::Suppressed,
+        Self::Syntax,
+        Self::Type,
+        Self::Uri,
+        Self::Error,
+    ];
+
+    /// The name of the constructor of the kind, or `"Value"` for [`JsErrorKind::Value`].
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Error => "Error",
+            Self::Aggregate => "AggregateError",
+            Self::Eval => "EvalError",
+            Self::Range => "RangeError",
+            Self::Reference => "ReferenceError",
+            Self::Suppressed => "SuppressedError",
+            Self::Syntax => "SyntaxError",
+            Self::Type => "TypeError",
+            Self::Uri => "URIError",
+            Self::Value => "Value",
+        }
+    }
+
+    /// Returns the kind of the error object `object`, from the first builtin error prototype in
+    /// its prototype chain.
+    fn of(object: &JsObject, context: &Context) -> Self {
+        let prototypes = Self::ERRORS.map(|kind| (kind, kind.prototype(context)));
+        let mut prototype = object.prototype().clone();
+        while let Some(current) = prototype {
+            if let Some((kind, _)) = prototypes
+                .iter()
+                .find(|(_, candidate)| JsObject::equals(candidate, &current))
+            {
+                return *kind;
+            }
+            prototype = current.prototype().clone();
+        }
+        Self::Error
+    }
+
+    /// The prototype of the error objects of the kind, `%Error.prototype%` for
+    /// [`JsErrorKind::Value`].
+    fn prototype(self, context: &Context) -> JsObject {
+        let constructors = context.intrinsics().constructors();
+        match self {
+            Self::Error | Self::Value => constructors.error(),
+            Self::Aggregate => constructors.aggregate_error(),
+            Self::Eval => constructors.eval_error(),
+            Self::Range => constructors.range_error(),
+            Self::Reference => constructors.reference_error(),
+            Self::Suppressed => constructors.suppressed_error(),
+            Self::Syntax => constructors.syntax_error(),
+            Self::Type => constructors.type_error(),
+            Self::Uri => constructors.uri_error(),
+        }
+        .prototype()
+    }
+}
+
+impl fmt::Display for JsErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A copy of a thrown value, made with [`JsError::capture`].
+///
+/// Unlike a [`JsError`], it doesn't hold any JavaScript value, so it can outlive its context and
+/// be sent to other threads. It implements [`std::error::Error`], with the captured cause of the
+/// error as its source.
+///
+/// # Examples
+///
+/// ```
+/// use boa_engine::{error::JsErrorKind, Context, JsError};
+///
+/// let mut context = Context::default();
+/// let thrown = context
+///     .eval("throw new TypeError('bad input', { cause: new RangeError('too long') })")
+///     .unwrap_err();
+///
+/// let error = JsError::from(thrown).capture(&mut context);
+/// assert_eq!(error.kind(), JsErrorKind::Type);
+/// assert_eq!(error.to_string(), "TypeError: bad input");
+///
+/// let cause = std::error::Error::source(&error).unwrap();
+/// assert_eq!(cause.to_string(), "RangeError: too long");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapturedError {
+    kind: JsErrorKind,
+    message: String,
+    stack: Option<String>,
+    cause: Option<Box<CapturedError>>,
+}
+
+impl CapturedError {
+    fn new(value: &JsValue, depth: usize, context: &mut Context) -> Self {
+        let object = match value.as_object() {
+            Some(object) if object.is_error() => object.clone(),
+            _ => {
+                return Self {
+                    kind: JsErrorKind::Value,
+                    message: value.display().to_string(),
+                    stack: None,
+                    cause: None,
+                }
+            }
+        };
+
+        let message = match object.get("message", context) {
+            Ok(JsValue::String(message)) => message.to_string(),
+            Ok(message) if !message.is_undefined() => message.display().to_string(),
+            _ => String::new(),
+        };
+        let cause = match object.has_own_property("cause", context) {
+            Ok(true) if depth > 0 => object
+                .get("cause", context)
+                .ok()
+                .map(|cause| Box::new(Self::new(&cause, depth - 1, context))),
+            _ => None,
+        };
+
+        let mut error = Self {
+            kind: JsErrorKind::of(&object, context),
+            message,
+            stack: None,
+            cause,
+        };
+        if let Some(frames) = object.borrow().as_error_stack() {
+            error.stack = Some(format_stack(&error.to_string(), frames));
+        }
+        error
+    }
+
+    /// The kind of the thrown value.
+    #[inline]
+    pub fn kind(&self) -> JsErrorKind {
+        self.kind
+    }
+
+    /// The `message` of the error object, or the thrown value converted to a string if it isn't
+    /// an error object.
+    #[inline]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The call stack of the error object, formatted like `Error.prototype.stack`.
+    #[inline]
+    pub fn stack(&self) -> Option<&str> {
+        self.stack.as_deref()
+    }
+
+    /// The captured `cause` of the error object, if it has one.
+    #[inline]
+    pub fn cause(&self) -> Option<&Self> {
+        self.cause.as_deref()
+    }
+}
+
+impl fmt::Display for CapturedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            JsErrorKind::Value => write!(f, "uncaught {}", self.message),
+            kind if self.message.is_empty() => write!(f, "{kind}"),
+            kind => write!(f, "{kind}: {}", self.message),
+        }
+    }
+}
+
+impl error::Error for CapturedError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        self.cause
+            .as_deref()
+            .map(|cause| cause as &(dyn error::Error + 'static))
+    }
+}
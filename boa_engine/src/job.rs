@@ -0,0 +1,115 @@
+//! Jobs run by the engine once the currently executing code has finished.
+//!
+//! The jobs enqueued by the engine, like promise reactions, are handed to the [`JobQueue`] of the
+//! [`Context`], which decides when and in which order they run. The default [`SimpleJobQueue`]
+//! runs them in FIFO order from [`Context::run_jobs`], but embedders can provide their own queue
+//! with [`ContextBuilder::job_queue`], for example to run the jobs from the tasks of an async
+//! executor, to prioritize some jobs, or to observe the jobs that are scheduled.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-jobs
+//! [`ContextBuilder::job_queue`]: crate::context::ContextBuilder::job_queue
+
+use crate::{builtins::FinalizationRegistry, Context, JsResult, JsValue};
+use std::{cell::RefCell, collections::VecDeque, fmt};
+
+/// A job implemented as a Rust closure.
+///
+/// Jobs are enqueued with [`Context::enqueue_job`], and run by the [`JobQueue`] of the context.
+pub struct NativeJob {
+    f: Box<dyn FnOnce(&mut Context) -> JsResult<JsValue>>,
+}
+
+impl NativeJob {
+    /// Creates a new job from a closure.
+    #[inline]
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnOnce(&mut Context) -> JsResult<JsValue> + 'static,
+    {
+        Self { f: Box::new(f) }
+    }
+
+    /// Runs the job, consuming it.
+    ///
+    /// Once the job finishes, the objects kept alive by `WeakRef`s during the job are released,
+    /// and the cleanup of the `FinalizationRegistry` objects whose targets were collected is
+    /// enqueued.
+    pub fn call(self, context: &mut Context) -> JsResult<JsValue> {
+        let result = (self.f)(context);
+        context.clear_kept_objects();
+        if boa_gc::take_cleared() {
+            FinalizationRegistry::schedule_cleanup(context);
+        }
+        result
+    }
+}
+
+impl fmt::Debug for NativeJob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NativeJob").finish_non_exhaustive()
+    }
+}
+
+/// The queue of the jobs of a [`Context`].
+///
+/// This is the host defined implementation of `HostEnqueuePromiseJob`, and of the scheduling of
+/// the other jobs of the engine. The context shares the queue with the embedder, so the methods
+/// take `&self` and the queue must use interior mutability. A job can enqueue other jobs while
+/// it runs, so the queue must not stay borrowed while it calls [`NativeJob::call`].
+pub trait JobQueue: fmt::Debug {
+    /// Enqueues a job, which must run after the currently executing code finishes.
+    fn enqueue_job(&self, job: NativeJob, context: &mut Context);
+
+    /// Runs the pending jobs, including the jobs enqueued while running them.
+    ///
+    /// This is called by [`Context::run_jobs`], after each script evaluated by the context. A
+    /// queue that runs its jobs elsewhere, like from an async executor, can leave them queued.
+    fn run_jobs(&self, context: &mut Context) -> JsResult<()>;
+}
+
+/// The default job queue, which runs the jobs in FIFO order.
+///
+/// If a job throws, [`JobQueue::run_jobs`] returns the error and the remaining jobs stay queued.
+#[derive(Debug, Default)]
+pub struct SimpleJobQueue(RefCell<VecDeque<NativeJob>>);
+
+impl SimpleJobQueue {
+    /// Creates an empty queue.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of pending jobs.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.borrow().len()
+    }
+
+    /// Returns `true` if there are no pending jobs.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.borrow().is_empty()
+    }
+}
+
+impl JobQueue for SimpleJobQueue {
+    #[inline]
+    fn enqueue_job(&self, job: NativeJob, _: &mut Context) {
+        self.0.borrow_mut().push_back(job);
+    }
+
+    fn run_jobs(&self, context: &mut Context) -> JsResult<()> {
+        loop {
+            // The borrow must end before the job runs, since it can enqueue other jobs.
+            let job = self.0.borrow_mut().pop_front();
+            match job {
+                Some(job) => job.call(context)?,
+                None => return Ok(()),
+            };
+        }
+    }
+}
@@ -5,6 +5,12 @@
 //!  - **serde** - Enables serialization and deserialization of the AST (Abstract Syntax Tree).
 //!  - **console** - Enables `boa`s WHATWG `console` object implementation.
 //!  - **profiler** - Enables profiling with measureme (this is mostly internal).
+//!
+//! # WebAssembly
+//!
+//! The engine builds for `wasm32-unknown-unknown`, where it takes the current time and the
+//! randomness of `Math.random` from the JavaScript host. The `boa_wasm` crate wraps it with
+//! wasm-bindgen, and lets the host expose its own functions to the scripts.
 
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/boa-dev/boa/main/assets/logo.svg",
@@ -73,9 +79,13 @@ pub mod bytecompiler;
 pub mod class;
 pub mod context;
 pub mod environments;
+pub mod error;
+pub mod job;
 pub mod object;
 pub mod property;
 pub mod realm;
+pub mod script;
+pub mod serde;
 pub mod string;
 pub mod symbol;
 pub mod syntax;
@@ -85,6 +95,10 @@ pub mod vm;
 #[cfg(test)]
 mod tests;
 
+// The derives of `boa_macros` refer to the items of this crate through `::boa_engine`.
+#[cfg(test)]
+extern crate self as boa_engine;
+
 /// A convenience module that re-exports the most commonly-used Boa APIs
 pub mod prelude {
     pub use crate::{object::JsObject, Context, JsBigInt, JsResult, JsString, JsValue};
@@ -95,7 +109,8 @@ use std::result::Result as StdResult;
 // Export things to root level
 #[doc(inline)]
 pub use crate::{
-    bigint::JsBigInt, context::Context, string::JsString, symbol::JsSymbol, value::JsValue,
+    bigint::JsBigInt, context::Context, error::JsError, string::JsString, symbol::JsSymbol,
+    value::JsValue,
 };
 
 /// The result of a Javascript expression is represented like this so it can succeed (`Ok`) or fail (`Err`)
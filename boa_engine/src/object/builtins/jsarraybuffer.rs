@@ -0,0 +1,232 @@
+use crate::{
+    builtins::array_buffer::{external::ExternalDataBlock, ArrayBuffer, DataBlock},
+    object::{JsObject, JsObjectType, ObjectData},
+    Context, JsResult, JsSymbol, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use std::ops::Deref;
+
+/// JavaScript `ArrayBuffer` rust object.
+///
+/// A `JsArrayBuffer` can be created over memory owned by Rust without copying it, with
+/// [`JsArrayBuffer::from_byte_block`] for a `Vec<u8>` or [`JsArrayBuffer::from_external`] for
+/// any other byte container, and the memory is handed back by detaching the buffer with
+/// [`JsArrayBuffer::take`] or [`JsArrayBuffer::take_external`].
+///
+/// # Examples
+///
+/// ```
+/// use boa_engine::{object::JsArrayBuffer, property::Attribute, Context};
+///
+/// let mut context = Context::default();
+/// let buffer = JsArrayBuffer::from_external(vec![1u8, 2, 3].into_boxed_slice(), &mut context);
+/// context.register_global_property("buffer", buffer.clone(), Attribute::all());
+/// context.eval("new Uint8Array(buffer)[0] = 42").unwrap();
+///
+/// let bytes: Box<[u8]> = buffer.take_external(&mut context).unwrap();
+/// assert_eq!(&*bytes, &[42, 2, 3]);
+/// ```
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsArrayBuffer {
+    inner: JsObject,
+}
+
+impl JsArrayBuffer {
+    /// Create a new `ArrayBuffer` of `byte_length` zeroed bytes.
+    ///
+    /// Same as `new ArrayBuffer(byteLength)` in JavaScript.
+    #[inline]
+    pub fn new(byte_length: usize, context: &mut Context) -> JsResult<Self> {
+        let constructor = context
+            .intrinsics()
+            .constructors()
+            .array_buffer()
+            .constructor();
+        let inner = ArrayBuffer::allocate(&constructor.into(), byte_length, None, context)?;
+
+        Ok(Self { inner })
+    }
+
+    /// Create a new `ArrayBuffer` owning the bytes of `block`, without copying them.
+    ///
+    /// Scripts can detach the buffer, for example by transferring it, in which case
+    /// [`JsArrayBuffer::take`] fails.
+    #[inline]
+    pub fn from_byte_block(block: Vec<u8>, context: &mut Context) -> Self {
+        let byte_length = block.len();
+        Self::from_data_block(
+            DataBlock::Owned(block),
+            byte_length,
+            JsValue::undefined(),
+            context,
+        )
+    }
+
+    /// Create a new `ArrayBuffer` over the bytes of `memory`, without copying them.
+    ///
+    /// The buffer has the length of `memory` when it is created. Scripts can read and write its
+    /// bytes but can't detach it, so the memory stays in the buffer until it is taken back with
+    /// [`JsArrayBuffer::take_external`].
+    #[inline]
+    pub fn from_external<T>(memory: T, context: &mut Context) -> Self
+    where
+        T: AsRef<[u8]> + AsMut<[u8]> + 'static,
+    {
+        let block = ExternalDataBlock::new(memory);
+        let byte_length = block.bytes().len();
+
+        // The key is only known to the buffer, which keeps scripts from detaching it.
+        let detach_key = JsSymbol::new(Some("external memory".into()));
+        Self::from_data_block(
+            DataBlock::External(block),
+            byte_length,
+            detach_key.into(),
+            context,
+        )
+    }
+
+    /// Create a `JsArrayBuffer` from a `JsObject`, if the object is not an `ArrayBuffer` throw a
+    /// `TypeError`.
+    ///
+    /// A `SharedArrayBuffer` is not an `ArrayBuffer`.
+    #[inline]
+    pub fn from_object(object: JsObject, context: &mut Context) -> JsResult<Self> {
+        let is_array_buffer = object
+            .borrow()
+            .as_array_buffer()
+            .map_or(false, |buffer| !buffer.is_shared_array_buffer());
+        if is_array_buffer {
+            Ok(Self { inner: object })
+        } else {
+            context.throw_type_error("object is not an ArrayBuffer")
+        }
+    }
+
+    /// Returns the length of the buffer in bytes, which is `0` once it is detached.
+    #[inline]
+    pub fn byte_length(&self) -> usize {
+        self.inner
+            .borrow()
+            .as_array_buffer()
+            .expect("JsArrayBuffer must wrap an ArrayBuffer")
+            .array_buffer_byte_length()
+    }
+
+    /// Returns `true` if the buffer is detached.
+    #[inline]
+    pub fn is_detached(&self) -> bool {
+        self.inner
+            .borrow()
+            .as_array_buffer()
+            .expect("JsArrayBuffer must wrap an ArrayBuffer")
+            .is_detached_buffer()
+    }
+
+    /// Detach the buffer and return its bytes, without copying them if the buffer owns them.
+    ///
+    /// Throws a `TypeError` if the buffer is already detached, or if it was created over external
+    /// memory, which is taken with [`JsArrayBuffer::take_external`].
+    pub fn take(&self, context: &mut Context) -> JsResult<Vec<u8>> {
+        let mut object = self.inner.borrow_mut();
+        let buffer = object
+            .as_array_buffer_mut()
+            .expect("JsArrayBuffer must wrap an ArrayBuffer");
+        let byte_length = buffer.array_buffer_byte_length();
+
+        match buffer.array_buffer_data {
+            Some(DataBlock::Owned(_)) => {}
+            Some(_) => {
+                return context.throw_type_error("cannot take the bytes of an external ArrayBuffer")
+            }
+            None => return context.throw_type_error("cannot take a detached ArrayBuffer"),
+        }
+
+        match buffer.detach(&JsValue::undefined(), context)? {
+            Some(DataBlock::Owned(mut block)) => {
+                block.truncate(byte_length);
+                Ok(block)
+            }
+            _ => unreachable!("checked that the buffer owns its bytes"),
+        }
+    }
+
+    /// Detach a buffer created by [`JsArrayBuffer::from_external`] and return its memory.
+    ///
+    /// Throws a `TypeError` if the buffer is already detached, or if its memory is not a `T`, in
+    /// which case the buffer is left untouched.
+    pub fn take_external<T: 'static>(&self, context: &mut Context) -> JsResult<T> {
+        let mut object = self.inner.borrow_mut();
+        let buffer = object
+            .as_array_buffer_mut()
+            .expect("JsArrayBuffer must wrap an ArrayBuffer");
+
+        match &buffer.array_buffer_data {
+            Some(DataBlock::External(block)) if block.is::<T>() => {}
+            None => return context.throw_type_error("cannot take a detached ArrayBuffer"),
+            _ => {
+                return context.throw_type_error(format!(
+                    "the ArrayBuffer is not backed by external memory of type `{}`",
+                    std::any::type_name::<T>()
+                ))
+            }
+        }
+
+        let key = buffer.array_buffer_detach_key.clone();
+        match buffer.detach(&key, context)? {
+            Some(DataBlock::External(block)) => Ok(block
+                .into_inner()
+                .unwrap_or_else(|_| unreachable!("checked the type of the memory"))),
+            _ => unreachable!("checked that the buffer is backed by external memory"),
+        }
+    }
+
+    /// Wraps a new `ArrayBuffer` holding `block`.
+    fn from_data_block(
+        block: DataBlock,
+        byte_length: usize,
+        detach_key: JsValue,
+        context: &mut Context,
+    ) -> Self {
+        let prototype = context
+            .intrinsics()
+            .constructors()
+            .array_buffer()
+            .prototype();
+        let inner = JsObject::from_proto_and_data(
+            prototype,
+            ObjectData::array_buffer(ArrayBuffer {
+                array_buffer_data: Some(block),
+                array_buffer_byte_length: byte_length,
+                array_buffer_max_byte_length: None,
+                array_buffer_detach_key: detach_key,
+            }),
+        );
+
+        Self { inner }
+    }
+}
+
+impl From<JsArrayBuffer> for JsObject {
+    #[inline]
+    fn from(o: JsArrayBuffer) -> Self {
+        o.inner.clone()
+    }
+}
+
+impl From<JsArrayBuffer> for JsValue {
+    #[inline]
+    fn from(o: JsArrayBuffer) -> Self {
+        o.inner.clone().into()
+    }
+}
+
+impl Deref for JsArrayBuffer {
+    type Target = JsObject;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl JsObjectType for JsArrayBuffer {}
@@ -0,0 +1,418 @@
+use crate::{
+    builtins::DataView,
+    object::{JsObject, JsObjectType},
+    Context, JsBigInt, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use num_traits::ToPrimitive;
+use std::ops::Deref;
+
+/// JavaScript `DataView` rust object.
+///
+/// Reading and writing through a `JsDataView` performs the same conversions and bounds checks
+/// as the `DataView.prototype` methods, so it can be used to read binary data produced by
+/// scripts regardless of the endianness of the host.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsDataView {
+    inner: JsObject,
+}
+
+/// Generates the methods reading a number of type `$ty` from the view.
+macro_rules! get_number {
+    ($(#[$attr:meta])* $name:ident, $native:path, $ty:ty) => {
+        $(#[$attr])*
+        #[inline]
+        pub fn $name(
+            &self,
+            byte_offset: usize,
+            is_little_endian: bool,
+            context: &mut Context,
+        ) -> JsResult<$ty> {
+            let value = $native(
+                &self.inner.clone().into(),
+                &[byte_offset.into(), is_little_endian.into()],
+                context,
+            )?;
+            Ok(value.as_number().expect("DataView getters must return a number") as $ty)
+        }
+    };
+}
+
+/// Generates the methods writing a value of type `$ty` to the view.
+macro_rules! set_value {
+    ($(#[$attr:meta])* $name:ident, $native:path, $ty:ty) => {
+        $(#[$attr])*
+        #[inline]
+        pub fn $name(
+            &self,
+            byte_offset: usize,
+            value: $ty,
+            is_little_endian: bool,
+            context: &mut Context,
+        ) -> JsResult<()> {
+            $native(
+                &self.inner.clone().into(),
+                &[byte_offset.into(), f64::from(value).into(), is_little_endian.into()],
+                context,
+            )
+            .map(|_| ())
+        }
+    };
+}
+
+impl JsDataView {
+    /// Create a new `DataView` viewing `array_buffer`, which must be an `ArrayBuffer` or a
+    /// `SharedArrayBuffer`.
+    ///
+    /// If `byte_length` is `None`, the view extends to the end of the buffer.
+    ///
+    /// Same as `new DataView(arrayBuffer, byteOffset, byteLength)` in JavaScript.
+    #[inline]
+    pub fn from_js_array_buffer(
+        array_buffer: &JsObject,
+        byte_offset: Option<usize>,
+        byte_length: Option<usize>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let constructor = context
+            .intrinsics()
+            .constructors()
+            .data_view()
+            .constructor()
+            .into();
+        let inner = DataView::constructor(
+            &constructor,
+            &[
+                array_buffer.clone().into(),
+                byte_offset.map_or_else(JsValue::undefined, Into::into),
+                byte_length.map_or_else(JsValue::undefined, Into::into),
+            ],
+            context,
+        )?
+        .as_object()
+        .cloned()
+        .expect("DataView constructor must return an object");
+
+        Ok(Self { inner })
+    }
+
+    /// Create a `JsDataView` from a `JsObject`, if the object is not a `DataView` throw a
+    /// `TypeError`.
+    #[inline]
+    pub fn from_object(object: JsObject, context: &mut Context) -> JsResult<Self> {
+        if object.is_data_view() {
+            Ok(Self { inner: object })
+        } else {
+            context.throw_type_error("object is not a DataView")
+        }
+    }
+
+    /// Get the `ArrayBuffer` or `SharedArrayBuffer` viewed by the `DataView`.
+    ///
+    /// Same as `dataView.buffer` in JavaScript.
+    #[inline]
+    pub fn buffer(&self, context: &mut Context) -> JsResult<JsObject> {
+        DataView::get_buffer(&self.inner.clone().into(), &[], context).map(|buffer| {
+            buffer
+                .as_object()
+                .cloned()
+                .expect("the buffer of a DataView must be an object")
+        })
+    }
+
+    /// Get the length in bytes of the view.
+    ///
+    /// Same as `dataView.byteLength` in JavaScript.
+    #[inline]
+    pub fn byte_length(&self, context: &mut Context) -> JsResult<usize> {
+        DataView::get_byte_length(&self.inner.clone().into(), &[], context)
+            .map(|length| length.as_number().expect("byteLength must be a number") as usize)
+    }
+
+    /// Get the offset in bytes of the view from the start of its buffer.
+    ///
+    /// Same as `dataView.byteOffset` in JavaScript.
+    #[inline]
+    pub fn byte_offset(&self, context: &mut Context) -> JsResult<usize> {
+        DataView::get_byte_offset(&self.inner.clone().into(), &[], context)
+            .map(|offset| offset.as_number().expect("byteOffset must be a number") as usize)
+    }
+
+    /// Reads a signed 64-bit integer at `byte_offset`.
+    ///
+    /// Same as `dataView.getBigInt64(byteOffset, littleEndian)` in JavaScript.
+    #[inline]
+    pub fn get_big_int64(
+        &self,
+        byte_offset: usize,
+        is_little_endian: bool,
+        context: &mut Context,
+    ) -> JsResult<i64> {
+        let value = DataView::get_big_int64(
+            &self.inner.clone().into(),
+            &[byte_offset.into(), is_little_endian.into()],
+            context,
+        )?;
+        Ok(value
+            .as_bigint()
+            .and_then(|value| value.as_inner().to_i64())
+            .expect("getBigInt64 must return a BigInt in the range of i64"))
+    }
+
+    /// Reads an unsigned 64-bit integer at `byte_offset`.
+    ///
+    /// Same as `dataView.getBigUint64(byteOffset, littleEndian)` in JavaScript.
+    #[inline]
+    pub fn get_big_uint64(
+        &self,
+        byte_offset: usize,
+        is_little_endian: bool,
+        context: &mut Context,
+    ) -> JsResult<u64> {
+        let value = DataView::get_big_uint64(
+            &self.inner.clone().into(),
+            &[byte_offset.into(), is_little_endian.into()],
+            context,
+        )?;
+        Ok(value
+            .as_bigint()
+            .and_then(|value| value.as_inner().to_u64())
+            .expect("getBigUint64 must return a BigInt in the range of u64"))
+    }
+
+    get_number!(
+        /// Reads a 32-bit float at `byte_offset`.
+        ///
+        /// Same as `dataView.getFloat32(byteOffset, littleEndian)` in JavaScript.
+        get_float32,
+        DataView::get_float32,
+        f32
+    );
+
+    /// Reads a 64-bit float at `byte_offset`.
+    ///
+    /// Same as `dataView.getFloat64(byteOffset, littleEndian)` in JavaScript.
+    #[inline]
+    pub fn get_float64(
+        &self,
+        byte_offset: usize,
+        is_little_endian: bool,
+        context: &mut Context,
+    ) -> JsResult<f64> {
+        let value = DataView::get_float64(
+            &self.inner.clone().into(),
+            &[byte_offset.into(), is_little_endian.into()],
+            context,
+        )?;
+        Ok(value
+            .as_number()
+            .expect("DataView getters must return a number"))
+    }
+
+    get_number!(
+        /// Reads a signed 8-bit integer at `byte_offset`.
+        ///
+        /// Same as `dataView.getInt8(byteOffset)` in JavaScript.
+        get_int8,
+        DataView::get_int8,
+        i8
+    );
+
+    get_number!(
+        /// Reads a signed 16-bit integer at `byte_offset`.
+        ///
+        /// Same as `dataView.getInt16(byteOffset, littleEndian)` in JavaScript.
+        get_int16,
+        DataView::get_int16,
+        i16
+    );
+
+    get_number!(
+        /// Reads a signed 32-bit integer at `byte_offset`.
+        ///
+        /// Same as `dataView.getInt32(byteOffset, littleEndian)` in JavaScript.
+        get_int32,
+        DataView::get_int32,
+        i32
+    );
+
+    get_number!(
+        /// Reads an unsigned 8-bit integer at `byte_offset`.
+        ///
+        /// Same as `dataView.getUint8(byteOffset)` in JavaScript.
+        get_uint8,
+        DataView::get_uint8,
+        u8
+    );
+
+    get_number!(
+        /// Reads an unsigned 16-bit integer at `byte_offset`.
+        ///
+        /// Same as `dataView.getUint16(byteOffset, littleEndian)` in JavaScript.
+        get_uint16,
+        DataView::get_uint16,
+        u16
+    );
+
+    get_number!(
+        /// Reads an unsigned 32-bit integer at `byte_offset`.
+        ///
+        /// Same as `dataView.getUint32(byteOffset, littleEndian)` in JavaScript.
+        get_uint32,
+        DataView::get_uint32,
+        u32
+    );
+
+    /// Writes a signed 64-bit integer at `byte_offset`.
+    ///
+    /// Same as `dataView.setBigInt64(byteOffset, value, littleEndian)` in JavaScript.
+    #[inline]
+    pub fn set_big_int64(
+        &self,
+        byte_offset: usize,
+        value: i64,
+        is_little_endian: bool,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        DataView::set_big_int64(
+            &self.inner.clone().into(),
+            &[
+                byte_offset.into(),
+                JsBigInt::from(value).into(),
+                is_little_endian.into(),
+            ],
+            context,
+        )
+        .map(|_| ())
+    }
+
+    /// Writes an unsigned 64-bit integer at `byte_offset`.
+    ///
+    /// Same as `dataView.setBigUint64(byteOffset, value, littleEndian)` in JavaScript.
+    #[inline]
+    pub fn set_big_uint64(
+        &self,
+        byte_offset: usize,
+        value: u64,
+        is_little_endian: bool,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        DataView::set_big_uint64(
+            &self.inner.clone().into(),
+            &[
+                byte_offset.into(),
+                JsBigInt::from(value).into(),
+                is_little_endian.into(),
+            ],
+            context,
+        )
+        .map(|_| ())
+    }
+
+    set_value!(
+        /// Writes a 32-bit float at `byte_offset`.
+        ///
+        /// Same as `dataView.setFloat32(byteOffset, value, littleEndian)` in JavaScript.
+        set_float32,
+        DataView::set_float32,
+        f32
+    );
+
+    /// Writes a 64-bit float at `byte_offset`.
+    ///
+    /// Same as `dataView.setFloat64(byteOffset, value, littleEndian)` in JavaScript.
+    #[inline]
+    pub fn set_float64(
+        &self,
+        byte_offset: usize,
+        value: f64,
+        is_little_endian: bool,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        DataView::set_float64(
+            &self.inner.clone().into(),
+            &[byte_offset.into(), value.into(), is_little_endian.into()],
+            context,
+        )
+        .map(|_| ())
+    }
+
+    set_value!(
+        /// Writes a signed 8-bit integer at `byte_offset`.
+        ///
+        /// Same as `dataView.setInt8(byteOffset, value)` in JavaScript.
+        set_int8,
+        DataView::set_int8,
+        i8
+    );
+
+    set_value!(
+        /// Writes a signed 16-bit integer at `byte_offset`.
+        ///
+        /// Same as `dataView.setInt16(byteOffset, value, littleEndian)` in JavaScript.
+        set_int16,
+        DataView::set_int16,
+        i16
+    );
+
+    set_value!(
+        /// Writes a signed 32-bit integer at `byte_offset`.
+        ///
+        /// Same as `dataView.setInt32(byteOffset, value, littleEndian)` in JavaScript.
+        set_int32,
+        DataView::set_int32,
+        i32
+    );
+
+    set_value!(
+        /// Writes an unsigned 8-bit integer at `byte_offset`.
+        ///
+        /// Same as `dataView.setUint8(byteOffset, value)` in JavaScript.
+        set_uint8,
+        DataView::set_uint8,
+        u8
+    );
+
+    set_value!(
+        /// Writes an unsigned 16-bit integer at `byte_offset`.
+        ///
+        /// Same as `dataView.setUint16(byteOffset, value, littleEndian)` in JavaScript.
+        set_uint16,
+        DataView::set_uint16,
+        u16
+    );
+
+    set_value!(
+        /// Writes an unsigned 32-bit integer at `byte_offset`.
+        ///
+        /// Same as `dataView.setUint32(byteOffset, value, littleEndian)` in JavaScript.
+        set_uint32,
+        DataView::set_uint32,
+        u32
+    );
+}
+
+impl From<JsDataView> for JsObject {
+    #[inline]
+    fn from(o: JsDataView) -> Self {
+        o.inner.clone()
+    }
+}
+
+impl From<JsDataView> for JsValue {
+    #[inline]
+    fn from(o: JsDataView) -> Self {
+        o.inner.clone().into()
+    }
+}
+
+impl Deref for JsDataView {
+    type Target = JsObject;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl JsObjectType for JsDataView {}
@@ -0,0 +1,167 @@
+use crate::{
+    builtins::iterable::{create_iter_result_object, IteratorHint, IteratorRecord},
+    object::{FunctionBuilder, JsObject, ObjectData},
+    property::PropertyDescriptor,
+    Context, JsResult, JsValue,
+};
+use boa_gc::{unsafe_empty_trace, Finalize, Trace};
+use std::fmt;
+
+/// JavaScript iterator rust object.
+///
+/// A `JsIterator` drives the iterator of a script iterable step by step from Rust, and wraps Rust
+/// iterators into iterator objects for scripts.
+///
+/// # Examples
+///
+/// ```
+/// use boa_engine::{object::JsIterator, property::Attribute, Context, JsValue};
+///
+/// let mut context = Context::default();
+/// let set = context.eval("new Set([1, 2, 3])").unwrap();
+/// let mut iterator = JsIterator::from_iterable(&set, &mut context).unwrap();
+///
+/// let mut sum = 0;
+/// while let Some(value) = iterator.next(&mut context).unwrap() {
+///     sum += value.to_i32(&mut context).unwrap();
+/// }
+/// assert_eq!(sum, 6);
+///
+/// let squares = JsIterator::from_rust_iter((1..=3).map(|n| JsValue::new(n * n)), &mut context);
+/// context.register_global_property("squares", squares, Attribute::all());
+/// let spread = context.eval("[...squares].join()").unwrap();
+/// assert_eq!(spread.as_string().map(|s| s.as_str()), Some("1,4,9"));
+/// ```
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsIterator {
+    record: IteratorRecord,
+    done: bool,
+}
+
+impl JsIterator {
+    /// Gets the iterator of `iterable`, like a `for..of` loop does.
+    ///
+    /// Throws a `TypeError` if `iterable` is not iterable.
+    #[inline]
+    pub fn from_iterable(iterable: &JsValue, context: &mut Context) -> JsResult<Self> {
+        let record = iterable.get_iterator(context, Some(IteratorHint::Sync), None)?;
+        Ok(Self {
+            record,
+            done: false,
+        })
+    }
+
+    /// Creates an iterator object yielding the values of a Rust iterator.
+    ///
+    /// The object inherits from `%Iterator.prototype%`, so scripts can use it in `for..of` loops,
+    /// spread it, pass it to `Array.from` and call the iterator helpers on it. The values held by
+    /// the Rust iterator are not traced by the garbage collector, so they stay alive until the
+    /// iterator drops them.
+    pub fn from_rust_iter<I>(iter: I, context: &mut Context) -> Self
+    where
+        I: IntoIterator<Item = JsValue>,
+        I::IntoIter: 'static,
+    {
+        let next = FunctionBuilder::closure_with_captures(
+            context,
+            |_, _, iter: &mut RustIterator, context| {
+                Ok(match iter.0.next() {
+                    Some(value) => create_iter_result_object(value, false, context),
+                    None => create_iter_result_object(JsValue::undefined(), true, context),
+                })
+            },
+            RustIterator(Box::new(iter.into_iter().fuse())),
+        )
+        .name("next")
+        .build();
+
+        let object = JsObject::from_proto_and_data(
+            context
+                .intrinsics()
+                .objects()
+                .iterator_prototypes()
+                .iterator_prototype(),
+            ObjectData::ordinary(),
+        );
+        object.insert_property(
+            "next",
+            PropertyDescriptor::builder()
+                .value(next.clone())
+                .writable(true)
+                .enumerable(false)
+                .configurable(true),
+        );
+
+        Self {
+            record: IteratorRecord::new(object.into(), next.into()),
+            done: false,
+        }
+    }
+
+    /// Returns the next value of the iterator, or `None` once it is done.
+    ///
+    /// The iterator is done once it completes or throws, and is not advanced anymore.
+    pub fn next(&mut self, context: &mut Context) -> JsResult<Option<JsValue>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let value = match self.record.step(context) {
+            Ok(Some(result)) => result.value(context).map(Some),
+            Ok(None) => Ok(None),
+            Err(error) => Err(error),
+        };
+        self.done = !matches!(value, Ok(Some(_)));
+        value
+    }
+
+    /// Closes the iterator before it is done, calling its `return` method like a `break` out of
+    /// a `for..of` loop.
+    ///
+    /// Does nothing if the iterator is already done.
+    pub fn close(&mut self, context: &mut Context) -> JsResult<()> {
+        if self.done {
+            return Ok(());
+        }
+        self.done = true;
+        self.record
+            .close(Ok(JsValue::undefined()), context)
+            .map(|_| ())
+    }
+
+    /// Returns `true` if the iterator completed, threw or was closed.
+    #[inline]
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// Returns the iterator object.
+    #[inline]
+    pub fn iterator_object(&self) -> &JsValue {
+        self.record.iterator_object()
+    }
+}
+
+impl From<JsIterator> for JsValue {
+    #[inline]
+    fn from(o: JsIterator) -> Self {
+        o.record.iterator_object().clone()
+    }
+}
+
+/// The captures of the `next` method of the iterator objects created by
+/// [`JsIterator::from_rust_iter`].
+#[derive(Finalize)]
+struct RustIterator(Box<dyn Iterator<Item = JsValue>>);
+
+impl fmt::Debug for RustIterator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RustIterator").finish_non_exhaustive()
+    }
+}
+
+// SAFETY: The values held by the Rust iterator aren't traced: they are kept alive until the
+// iterator drops them.
+unsafe impl Trace for RustIterator {
+    unsafe_empty_trace!();
+}
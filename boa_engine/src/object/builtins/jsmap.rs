@@ -0,0 +1,280 @@
+use crate::{
+    builtins::{
+        map::{map_iterator::MapIterator, ordered_map::OrderedMap},
+        Map,
+    },
+    object::{JsObject, JsObjectType, ObjectData},
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use std::ops::Deref;
+
+/// JavaScript `Map` rust object.
+///
+/// Keys are compared with `SameValueZero`, exactly like `Map.prototype` does, so `-0` and `+0`
+/// refer to the same entry.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsMap {
+    inner: JsObject,
+}
+
+impl JsMap {
+    /// Create a new empty map.
+    ///
+    /// Same as `new Map()` in JavaScript.
+    #[inline]
+    pub fn new(context: &mut Context) -> Self {
+        let prototype = context.intrinsics().constructors().map().prototype();
+        let inner = JsObject::from_proto_and_data(prototype, ObjectData::map(OrderedMap::new()));
+
+        Self { inner }
+    }
+
+    /// Create a new map from an iterable of `[key, value]` entries.
+    ///
+    /// Same as `new Map(iterable)` in JavaScript.
+    #[inline]
+    pub fn from_js_iterable(iterable: &JsValue, context: &mut Context) -> JsResult<Self> {
+        let constructor = context
+            .intrinsics()
+            .constructors()
+            .map()
+            .constructor()
+            .into();
+        let inner = Map::constructor(&constructor, &[iterable.clone()], context)?
+            .as_object()
+            .cloned()
+            .expect("Map constructor must return an object");
+
+        Ok(Self { inner })
+    }
+
+    /// Create a `JsMap` from a `JsObject`, if the object is not a `Map` throw a `TypeError`.
+    ///
+    /// This does not copy the entries of the map, it only does a shallow copy.
+    #[inline]
+    pub fn from_object(object: JsObject, context: &mut Context) -> JsResult<Self> {
+        if object.is_map() {
+            Ok(Self { inner: object })
+        } else {
+            context.throw_type_error("object is not a Map")
+        }
+    }
+
+    /// Get the number of entries of the map.
+    ///
+    /// Same as `map.size` in JavaScript.
+    #[inline]
+    pub fn size(&self, context: &mut Context) -> JsResult<usize> {
+        Map::get_size(&self.inner.clone().into(), &[], context)
+            .map(|size| size.as_number().expect("Map size must be a number") as usize)
+    }
+
+    /// Check if the map has no entries.
+    #[inline]
+    pub fn is_empty(&self, context: &mut Context) -> JsResult<bool> {
+        self.size(context).map(|size| size == 0)
+    }
+
+    /// Insert or replace the value associated with `key`.
+    ///
+    /// Same as `map.set(key, value)` in JavaScript.
+    #[inline]
+    pub fn set<K, V>(&self, key: K, value: V, context: &mut Context) -> JsResult<()>
+    where
+        K: Into<JsValue>,
+        V: Into<JsValue>,
+    {
+        Map::set(
+            &self.inner.clone().into(),
+            &[key.into(), value.into()],
+            context,
+        )
+        .map(|_| ())
+    }
+
+    /// Get the value associated with `key`, or `undefined` if there is none.
+    ///
+    /// Same as `map.get(key)` in JavaScript.
+    #[inline]
+    pub fn get<K>(&self, key: K, context: &mut Context) -> JsResult<JsValue>
+    where
+        K: Into<JsValue>,
+    {
+        Map::get(&self.inner.clone().into(), &[key.into()], context)
+    }
+
+    /// Check if the map has an entry for `key`.
+    ///
+    /// Same as `map.has(key)` in JavaScript.
+    #[inline]
+    pub fn has<K>(&self, key: K, context: &mut Context) -> JsResult<bool>
+    where
+        K: Into<JsValue>,
+    {
+        Map::has(&self.inner.clone().into(), &[key.into()], context)
+            .map(|has| has.as_boolean().expect("Map has must return a boolean"))
+    }
+
+    /// Remove the entry for `key`, returning `true` if there was one.
+    ///
+    /// Same as `map.delete(key)` in JavaScript.
+    #[inline]
+    pub fn delete<K>(&self, key: K, context: &mut Context) -> JsResult<bool>
+    where
+        K: Into<JsValue>,
+    {
+        Map::delete(&self.inner.clone().into(), &[key.into()], context).map(|deleted| {
+            deleted
+                .as_boolean()
+                .expect("Map delete must return a boolean")
+        })
+    }
+
+    /// Remove all the entries of the map.
+    ///
+    /// Same as `map.clear()` in JavaScript.
+    #[inline]
+    pub fn clear(&self, context: &mut Context) -> JsResult<()> {
+        Map::clear(&self.inner.clone().into(), &[], context).map(|_| ())
+    }
+
+    /// Call `callback` once for every entry of the map, in insertion order.
+    ///
+    /// Same as `map.forEach(callback, thisArg)` in JavaScript.
+    #[inline]
+    pub fn for_each(
+        &self,
+        callback: JsObject,
+        this_arg: JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        Map::for_each(
+            &self.inner.clone().into(),
+            &[callback.into(), this_arg],
+            context,
+        )
+        .map(|_| ())
+    }
+
+    /// Get an iterator over the `[key, value]` entries of the map.
+    ///
+    /// Same as `map.entries()` in JavaScript.
+    #[inline]
+    pub fn entries(&self, context: &mut Context) -> JsResult<JsMapIterator> {
+        Map::entries(&self.inner.clone().into(), &[], context).map(JsMapIterator::from_value)
+    }
+
+    /// Get an iterator over the keys of the map.
+    ///
+    /// Same as `map.keys()` in JavaScript.
+    #[inline]
+    pub fn keys(&self, context: &mut Context) -> JsResult<JsMapIterator> {
+        Map::keys(&self.inner.clone().into(), &[], context).map(JsMapIterator::from_value)
+    }
+
+    /// Get an iterator over the values of the map.
+    ///
+    /// Same as `map.values()` in JavaScript.
+    #[inline]
+    pub fn values(&self, context: &mut Context) -> JsResult<JsMapIterator> {
+        Map::values(&self.inner.clone().into(), &[], context).map(JsMapIterator::from_value)
+    }
+}
+
+impl From<JsMap> for JsObject {
+    #[inline]
+    fn from(o: JsMap) -> Self {
+        o.inner.clone()
+    }
+}
+
+impl From<JsMap> for JsValue {
+    #[inline]
+    fn from(o: JsMap) -> Self {
+        o.inner.clone().into()
+    }
+}
+
+impl Deref for JsMap {
+    type Target = JsObject;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl JsObjectType for JsMap {}
+
+/// JavaScript `MapIterator` rust object.
+///
+/// Entries added to the map while iterating are visited, and removed entries are skipped.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsMapIterator {
+    inner: JsObject,
+}
+
+impl JsMapIterator {
+    /// Wraps the value returned by `CreateMapIterator`.
+    fn from_value(value: JsValue) -> Self {
+        Self {
+            inner: value
+                .as_object()
+                .cloned()
+                .expect("CreateMapIterator must return an object"),
+        }
+    }
+
+    /// Create a `JsMapIterator` from a `JsObject`, if the object is not a `MapIterator` throw a
+    /// `TypeError`.
+    #[inline]
+    pub fn from_object(object: JsObject, context: &mut Context) -> JsResult<Self> {
+        if object.borrow().is_map_iterator() {
+            Ok(Self { inner: object })
+        } else {
+            context.throw_type_error("object is not a MapIterator")
+        }
+    }
+
+    /// Advance the iterator, returning `None` once every entry has been visited.
+    ///
+    /// The returned value is the key, the value or a `[key, value]` array, depending on the method
+    /// that created the iterator.
+    #[inline]
+    pub fn next(&self, context: &mut Context) -> JsResult<Option<JsValue>> {
+        let result = MapIterator::next(&self.inner.clone().into(), &[], context)?;
+        let result = result
+            .as_object()
+            .expect("MapIterator next must return an object");
+        if result.get("done", context)?.to_boolean() {
+            return Ok(None);
+        }
+        result.get("value", context).map(Some)
+    }
+}
+
+impl From<JsMapIterator> for JsObject {
+    #[inline]
+    fn from(o: JsMapIterator) -> Self {
+        o.inner.clone()
+    }
+}
+
+impl From<JsMapIterator> for JsValue {
+    #[inline]
+    fn from(o: JsMapIterator) -> Self {
+        o.inner.clone().into()
+    }
+}
+
+impl Deref for JsMapIterator {
+    type Target = JsObject;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl JsObjectType for JsMapIterator {}
@@ -0,0 +1,310 @@
+use crate::{
+    builtins::{
+        promise::{PromiseEvent, PromiseState, ResolvingFunctions},
+        Promise,
+    },
+    object::{FunctionBuilder, JsObject, JsObjectType, ObjectData},
+    Context, JsResult, JsValue,
+};
+use boa_gc::{unsafe_empty_trace, Finalize, Trace};
+use std::{
+    cell::RefCell,
+    future::Future,
+    ops::Deref,
+    pin::Pin,
+    rc::Rc,
+    task::{Context as TaskContext, Poll, Waker},
+};
+
+/// JavaScript `Promise` rust object.
+///
+/// # Examples
+///
+/// ```
+/// use boa_engine::{object::JsPromise, Context, JsValue};
+///
+/// let mut context = Context::default();
+/// let (promise, resolvers) = JsPromise::new_pending(&mut context);
+/// resolvers
+///     .resolve
+///     .call(&JsValue::undefined(), &[5.into()], &mut context)
+///     .unwrap();
+///
+/// let doubled = context
+///     .eval("(value) => value * 2")
+///     .unwrap()
+///     .as_object()
+///     .cloned()
+///     .unwrap();
+/// let promise = promise.then(Some(doubled), None, &mut context).unwrap();
+/// assert_eq!(promise.await_blocking(&mut context), Ok(10.into()));
+/// ```
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsPromise {
+    inner: JsObject,
+}
+
+impl JsPromise {
+    /// Create a new pending promise, returned with the functions that settle it.
+    #[inline]
+    pub fn new_pending(context: &mut Context) -> (Self, ResolvingFunctions) {
+        let prototype = context.intrinsics().constructors().promise().prototype();
+        let inner = JsObject::from_proto_and_data(prototype, ObjectData::promise(Promise::new()));
+        context
+            .host_hooks()
+            .promise_hook(PromiseEvent::Init, &inner, context);
+        let functions = Promise::create_resolving_functions(&inner, context);
+
+        (Self { inner }, functions)
+    }
+
+    /// Create a new promise settled by `executor`, which is called with the functions that
+    /// settle it. The promise is rejected if `executor` throws.
+    ///
+    /// Same as `new Promise(executor)` in JavaScript.
+    #[inline]
+    pub fn new<F>(executor: F, context: &mut Context) -> JsResult<Self>
+    where
+        F: FnOnce(&ResolvingFunctions, &mut Context) -> JsResult<()>,
+    {
+        let (promise, functions) = Self::new_pending(context);
+        if let Err(e) = executor(&functions, context) {
+            functions
+                .reject
+                .call(&JsValue::undefined(), &[e], context)?;
+        }
+
+        Ok(promise)
+    }
+
+    /// Create a promise resolved with `value`, or return `value` if it is already a promise.
+    ///
+    /// Same as `Promise.resolve(value)` in JavaScript.
+    #[inline]
+    pub fn resolve<V>(value: V, context: &mut Context) -> JsResult<Self>
+    where
+        V: Into<JsValue>,
+    {
+        let constructor = context.intrinsics().constructors().promise().constructor();
+        Promise::promise_resolve(&constructor, value.into(), context)
+            .and_then(|promise| Self::from_object(promise, context))
+    }
+
+    /// Create a promise rejected with `reason`.
+    ///
+    /// Same as `Promise.reject(reason)` in JavaScript.
+    #[inline]
+    pub fn reject<V>(reason: V, context: &mut Context) -> JsResult<Self>
+    where
+        V: Into<JsValue>,
+    {
+        let (promise, functions) = Self::new_pending(context);
+        functions
+            .reject
+            .call(&JsValue::undefined(), &[reason.into()], context)?;
+
+        Ok(promise)
+    }
+
+    /// Create a `JsPromise` from a `JsObject`, if the object is not a `Promise` throw a
+    /// `TypeError`.
+    #[inline]
+    pub fn from_object(object: JsObject, context: &mut Context) -> JsResult<Self> {
+        if object.borrow().is_promise() {
+            Ok(Self { inner: object })
+        } else {
+            context.throw_type_error("object is not a Promise")
+        }
+    }
+
+    /// Get the current state of the promise.
+    #[inline]
+    pub fn state(&self) -> PromiseState {
+        self.inner
+            .borrow()
+            .as_promise()
+            .expect("JsPromise must wrap a promise")
+            .state()
+            .clone()
+    }
+
+    /// Register the functions called when the promise is fulfilled or rejected, returning the
+    /// promise of their result.
+    ///
+    /// Same as `promise.then(onFulfilled, onRejected)` in JavaScript.
+    #[inline]
+    pub fn then(
+        &self,
+        on_fulfilled: Option<JsObject>,
+        on_rejected: Option<JsObject>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let result = Promise::then(
+            &self.inner.clone().into(),
+            &[
+                on_fulfilled.map_or_else(JsValue::undefined, JsValue::from),
+                on_rejected.map_or_else(JsValue::undefined, JsValue::from),
+            ],
+            context,
+        )?;
+        Self::from_value(result, context)
+    }
+
+    /// Register the function called when the promise is rejected, returning the promise of its
+    /// result.
+    ///
+    /// Same as `promise.catch(onRejected)` in JavaScript.
+    #[inline]
+    pub fn catch(&self, on_rejected: JsObject, context: &mut Context) -> JsResult<Self> {
+        self.then(None, Some(on_rejected), context)
+    }
+
+    /// Register the function called when the promise is settled, returning a promise settled
+    /// like this one once it returns.
+    ///
+    /// Same as `promise.finally(onFinally)` in JavaScript.
+    #[inline]
+    pub fn finally(&self, on_finally: JsObject, context: &mut Context) -> JsResult<Self> {
+        let result = Promise::finally(&self.inner.clone().into(), &[on_finally.into()], context)?;
+        Self::from_value(result, context)
+    }
+
+    /// Convert the promise to a Rust future, which completes with the value of the promise when
+    /// it is fulfilled, or with its reason when it is rejected.
+    ///
+    /// The promise settles while the jobs of the context run, so the future only makes progress
+    /// if the embedder runs them, with [`Context::run_jobs`] or its own
+    /// [`JobQueue`](crate::job::JobQueue). [`JsPromise::await_blocking`] runs the jobs itself.
+    pub fn into_future(self, context: &mut Context) -> JsFuture {
+        #[derive(Debug, Clone, Finalize)]
+        struct Captures(Rc<RefCell<FutureState>>);
+
+        // SAFETY: The state isn't traced: the settled value it holds is kept alive until the
+        // future completes.
+        unsafe impl Trace for Captures {
+            unsafe_empty_trace!();
+        }
+
+        fn settle(
+            value: &[JsValue],
+            captures: &mut Captures,
+            fulfilled: bool,
+        ) -> JsResult<JsValue> {
+            let value = value.first().cloned().unwrap_or_default();
+            let mut state = captures.0.borrow_mut();
+            state.result = Some(if fulfilled { Ok(value) } else { Err(value) });
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+            Ok(JsValue::undefined())
+        }
+
+        let state = Rc::new(RefCell::new(FutureState {
+            result: None,
+            waker: None,
+        }));
+        let on_fulfilled = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args, captures, _| settle(args, captures, true),
+            Captures(state.clone()),
+        )
+        .build();
+        let on_rejected = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args, captures, _| settle(args, captures, false),
+            Captures(state.clone()),
+        )
+        .build();
+
+        Promise::perform_promise_then(
+            &self.inner,
+            &on_fulfilled.into(),
+            &on_rejected.into(),
+            None,
+            context,
+        );
+
+        JsFuture { state }
+    }
+
+    /// Run the pending jobs of the context until the promise is settled, and return its value if
+    /// it is fulfilled, or its reason if it is rejected.
+    ///
+    /// Throws a `TypeError` if the promise is still pending once all the jobs have run, and
+    /// returns the error of a job that throws.
+    pub fn await_blocking(&self, context: &mut Context) -> JsResult<JsValue> {
+        if let PromiseState::Pending = self.state() {
+            context.run_jobs()?;
+        }
+
+        match self.state() {
+            PromiseState::Fulfilled(value) => Ok(value),
+            PromiseState::Rejected(reason) => Err(reason),
+            PromiseState::Pending => {
+                context.throw_type_error("the promise is still pending after running the jobs")
+            }
+        }
+    }
+
+    /// Wraps the value returned by a method of `Promise.prototype`.
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value {
+            JsValue::Object(object) => Self::from_object(object, context),
+            _ => context.throw_type_error("value is not a Promise"),
+        }
+    }
+}
+
+impl From<JsPromise> for JsObject {
+    #[inline]
+    fn from(o: JsPromise) -> Self {
+        o.inner.clone()
+    }
+}
+
+impl From<JsPromise> for JsValue {
+    #[inline]
+    fn from(o: JsPromise) -> Self {
+        o.inner.clone().into()
+    }
+}
+
+impl Deref for JsPromise {
+    type Target = JsObject;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl JsObjectType for JsPromise {}
+
+/// The state shared by a [`JsFuture`] and the reactions to its promise.
+#[derive(Debug)]
+struct FutureState {
+    result: Option<JsResult<JsValue>>,
+    waker: Option<Waker>,
+}
+
+/// A Rust future completed by the settlement of a promise, created with
+/// [`JsPromise::into_future`].
+#[derive(Debug)]
+pub struct JsFuture {
+    state: Rc<RefCell<FutureState>>,
+}
+
+impl Future for JsFuture {
+    type Output = JsResult<JsValue>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.borrow_mut();
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
@@ -0,0 +1,302 @@
+use crate::{
+    builtins::{function::NativeFunctionSignature, Proxy},
+    object::{FunctionBuilder, JsObject, JsObjectType},
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use std::ops::Deref;
+
+/// JavaScript `Proxy` rust object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsProxy {
+    inner: JsObject,
+}
+
+impl JsProxy {
+    /// Create a [`JsProxyBuilder`] for a proxy of `target`.
+    #[inline]
+    pub fn builder(target: JsObject) -> JsProxyBuilder {
+        JsProxyBuilder::new(target)
+    }
+
+    /// Create a `JsProxy` from a `JsObject`, if the object is not a `Proxy` throw a `TypeError`.
+    #[inline]
+    pub fn from_object(object: JsObject, context: &mut Context) -> JsResult<Self> {
+        if object.borrow().is_proxy() {
+            Ok(Self { inner: object })
+        } else {
+            context.throw_type_error("object is not a Proxy")
+        }
+    }
+}
+
+impl From<JsProxy> for JsObject {
+    #[inline]
+    fn from(o: JsProxy) -> Self {
+        o.inner.clone()
+    }
+}
+
+impl From<JsProxy> for JsValue {
+    #[inline]
+    fn from(o: JsProxy) -> Self {
+        o.inner.clone().into()
+    }
+}
+
+impl Deref for JsProxy {
+    type Target = JsObject;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl JsObjectType for JsProxy {}
+
+/// A [`JsProxy`] that can be revoked from Rust.
+///
+/// Same as the result of `Proxy.revocable(target, handler)` in JavaScript.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsRevocableProxy {
+    proxy: JsProxy,
+    revoker: JsObject,
+}
+
+impl JsRevocableProxy {
+    /// Get the revocable proxy.
+    #[inline]
+    pub fn proxy(&self) -> JsProxy {
+        self.proxy.clone()
+    }
+
+    /// Revoke the proxy, making every operation on it throw a `TypeError`.
+    ///
+    /// Same as calling the `revoke` function returned by `Proxy.revocable` in JavaScript.
+    #[inline]
+    pub fn revoke(self, context: &mut Context) -> JsResult<()> {
+        self.revoker
+            .call(&JsValue::undefined(), &[], context)
+            .map(|_| ())
+    }
+}
+
+/// Utility to build a [`JsProxy`] whose traps are native functions.
+///
+/// Every trap is called with the handler object as its `this` value and with the same arguments
+/// as the corresponding trap of a JavaScript handler. Traps that are not set forward the
+/// operation to the target, and the results of the set traps are checked against the invariants
+/// of the target like any other proxy.
+#[derive(Debug, Clone)]
+pub struct JsProxyBuilder {
+    target: JsObject,
+    apply: Option<NativeFunctionSignature>,
+    construct: Option<NativeFunctionSignature>,
+    define_property: Option<NativeFunctionSignature>,
+    delete_property: Option<NativeFunctionSignature>,
+    get: Option<NativeFunctionSignature>,
+    get_own_property_descriptor: Option<NativeFunctionSignature>,
+    get_prototype_of: Option<NativeFunctionSignature>,
+    has: Option<NativeFunctionSignature>,
+    is_extensible: Option<NativeFunctionSignature>,
+    own_keys: Option<NativeFunctionSignature>,
+    prevent_extensions: Option<NativeFunctionSignature>,
+    set: Option<NativeFunctionSignature>,
+    set_prototype_of: Option<NativeFunctionSignature>,
+}
+
+impl JsProxyBuilder {
+    /// Create a new builder for a proxy of `target`, without any traps.
+    #[inline]
+    pub fn new(target: JsObject) -> Self {
+        Self {
+            target,
+            apply: None,
+            construct: None,
+            define_property: None,
+            delete_property: None,
+            get: None,
+            get_own_property_descriptor: None,
+            get_prototype_of: None,
+            has: None,
+            is_extensible: None,
+            own_keys: None,
+            prevent_extensions: None,
+            set: None,
+            set_prototype_of: None,
+        }
+    }
+
+    /// Set the `apply` trap, called with `(target, thisArgument, argumentsArray)`.
+    ///
+    /// Only used if the target is callable.
+    #[inline]
+    #[must_use]
+    pub fn apply(mut self, apply: NativeFunctionSignature) -> Self {
+        self.apply = Some(apply);
+        self
+    }
+
+    /// Set the `construct` trap, called with `(target, argumentsArray, newTarget)`.
+    ///
+    /// Only used if the target is a constructor.
+    #[inline]
+    #[must_use]
+    pub fn construct(mut self, construct: NativeFunctionSignature) -> Self {
+        self.construct = Some(construct);
+        self
+    }
+
+    /// Set the `defineProperty` trap, called with `(target, key, descriptor)`.
+    #[inline]
+    #[must_use]
+    pub fn define_property(mut self, define_property: NativeFunctionSignature) -> Self {
+        self.define_property = Some(define_property);
+        self
+    }
+
+    /// Set the `deleteProperty` trap, called with `(target, key)`.
+    #[inline]
+    #[must_use]
+    pub fn delete_property(mut self, delete_property: NativeFunctionSignature) -> Self {
+        self.delete_property = Some(delete_property);
+        self
+    }
+
+    /// Set the `get` trap, called with `(target, key, receiver)`.
+    #[inline]
+    #[must_use]
+    pub fn get(mut self, get: NativeFunctionSignature) -> Self {
+        self.get = Some(get);
+        self
+    }
+
+    /// Set the `getOwnPropertyDescriptor` trap, called with `(target, key)`.
+    #[inline]
+    #[must_use]
+    pub fn get_own_property_descriptor(
+        mut self,
+        get_own_property_descriptor: NativeFunctionSignature,
+    ) -> Self {
+        self.get_own_property_descriptor = Some(get_own_property_descriptor);
+        self
+    }
+
+    /// Set the `getPrototypeOf` trap, called with `(target)`.
+    #[inline]
+    #[must_use]
+    pub fn get_prototype_of(mut self, get_prototype_of: NativeFunctionSignature) -> Self {
+        self.get_prototype_of = Some(get_prototype_of);
+        self
+    }
+
+    /// Set the `has` trap, called with `(target, key)`.
+    #[inline]
+    #[must_use]
+    pub fn has(mut self, has: NativeFunctionSignature) -> Self {
+        self.has = Some(has);
+        self
+    }
+
+    /// Set the `isExtensible` trap, called with `(target)`.
+    #[inline]
+    #[must_use]
+    pub fn is_extensible(mut self, is_extensible: NativeFunctionSignature) -> Self {
+        self.is_extensible = Some(is_extensible);
+        self
+    }
+
+    /// Set the `ownKeys` trap, called with `(target)`.
+    #[inline]
+    #[must_use]
+    pub fn own_keys(mut self, own_keys: NativeFunctionSignature) -> Self {
+        self.own_keys = Some(own_keys);
+        self
+    }
+
+    /// Set the `preventExtensions` trap, called with `(target)`.
+    #[inline]
+    #[must_use]
+    pub fn prevent_extensions(mut self, prevent_extensions: NativeFunctionSignature) -> Self {
+        self.prevent_extensions = Some(prevent_extensions);
+        self
+    }
+
+    /// Set the `set` trap, called with `(target, key, value, receiver)`.
+    #[inline]
+    #[must_use]
+    pub fn set(mut self, set: NativeFunctionSignature) -> Self {
+        self.set = Some(set);
+        self
+    }
+
+    /// Set the `setPrototypeOf` trap, called with `(target, prototype)`.
+    #[inline]
+    #[must_use]
+    pub fn set_prototype_of(mut self, set_prototype_of: NativeFunctionSignature) -> Self {
+        self.set_prototype_of = Some(set_prototype_of);
+        self
+    }
+
+    /// Build the proxy.
+    ///
+    /// Same as `new Proxy(target, handler)` in JavaScript.
+    #[inline]
+    pub fn build(self, context: &mut Context) -> JsProxy {
+        let handler = self.handler(context);
+
+        JsProxy {
+            inner: Proxy::create(&self.target, &handler, context),
+        }
+    }
+
+    /// Build a proxy that can be revoked.
+    ///
+    /// Same as `Proxy.revocable(target, handler)` in JavaScript.
+    #[inline]
+    pub fn build_revocable(self, context: &mut Context) -> JsRevocableProxy {
+        let proxy = self.build(context);
+        let revoker = Proxy::revoker(proxy.inner.clone(), context);
+
+        JsRevocableProxy { proxy, revoker }
+    }
+
+    /// Create the handler object, with a function property for every set trap.
+    fn handler(&self, context: &mut Context) -> JsObject {
+        let traps = [
+            ("apply", self.apply, 3),
+            ("construct", self.construct, 3),
+            ("defineProperty", self.define_property, 3),
+            ("deleteProperty", self.delete_property, 2),
+            ("get", self.get, 3),
+            (
+                "getOwnPropertyDescriptor",
+                self.get_own_property_descriptor,
+                2,
+            ),
+            ("getPrototypeOf", self.get_prototype_of, 1),
+            ("has", self.has, 2),
+            ("isExtensible", self.is_extensible, 1),
+            ("ownKeys", self.own_keys, 1),
+            ("preventExtensions", self.prevent_extensions, 1),
+            ("set", self.set, 4),
+            ("setPrototypeOf", self.set_prototype_of, 2),
+        ];
+
+        let handler = context.construct_object();
+        for (name, trap, length) in traps {
+            if let Some(trap) = trap {
+                let trap = FunctionBuilder::native(context, trap)
+                    .name(name)
+                    .length(length)
+                    .build();
+                handler
+                    .create_data_property_or_throw(name, trap, context)
+                    .expect("creating a property of a new object cannot fail");
+            }
+        }
+
+        handler
+    }
+}
@@ -0,0 +1,247 @@
+use crate::{
+    builtins::{
+        set::{ordered_set::OrderedSet, set_iterator::SetIterator},
+        Set,
+    },
+    object::{JsObject, JsObjectType, ObjectData},
+    Context, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use std::ops::Deref;
+
+/// JavaScript `Set` rust object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsSet {
+    inner: JsObject,
+}
+
+impl JsSet {
+    /// Create a new empty set.
+    ///
+    /// Same as `new Set()` in JavaScript.
+    #[inline]
+    pub fn new(context: &mut Context) -> Self {
+        let prototype = context.intrinsics().constructors().set().prototype();
+        let inner =
+            JsObject::from_proto_and_data(prototype, ObjectData::set(OrderedSet::default()));
+
+        Self { inner }
+    }
+
+    /// Create a new set from the values of an iterable.
+    ///
+    /// Same as `new Set(iterable)` in JavaScript.
+    #[inline]
+    pub fn from_js_iterable(iterable: &JsValue, context: &mut Context) -> JsResult<Self> {
+        let constructor = context
+            .intrinsics()
+            .constructors()
+            .set()
+            .constructor()
+            .into();
+        let inner = Set::constructor(&constructor, &[iterable.clone()], context)?
+            .as_object()
+            .cloned()
+            .expect("Set constructor must return an object");
+
+        Ok(Self { inner })
+    }
+
+    /// Create a `JsSet` from a `JsObject`, if the object is not a `Set` throw a `TypeError`.
+    ///
+    /// This does not copy the values of the set, it only does a shallow copy.
+    #[inline]
+    pub fn from_object(object: JsObject, context: &mut Context) -> JsResult<Self> {
+        if object.borrow().is_set() {
+            Ok(Self { inner: object })
+        } else {
+            context.throw_type_error("object is not a Set")
+        }
+    }
+
+    /// Get the number of values of the set.
+    ///
+    /// Same as `set.size` in JavaScript.
+    #[inline]
+    pub fn size(&self, context: &mut Context) -> JsResult<usize> {
+        Set::get_size(&self.inner.clone().into(), context)
+    }
+
+    /// Check if the set has no values.
+    #[inline]
+    pub fn is_empty(&self, context: &mut Context) -> JsResult<bool> {
+        self.size(context).map(|size| size == 0)
+    }
+
+    /// Add `value` to the set, if it is not already present.
+    ///
+    /// Same as `set.add(value)` in JavaScript.
+    #[inline]
+    pub fn add<T>(&self, value: T, context: &mut Context) -> JsResult<()>
+    where
+        T: Into<JsValue>,
+    {
+        Set::add(&self.inner.clone().into(), &[value.into()], context).map(|_| ())
+    }
+
+    /// Check if `value` is in the set.
+    ///
+    /// Same as `set.has(value)` in JavaScript.
+    #[inline]
+    pub fn has<T>(&self, value: T, context: &mut Context) -> JsResult<bool>
+    where
+        T: Into<JsValue>,
+    {
+        Set::has(&self.inner.clone().into(), &[value.into()], context)
+            .map(|has| has.as_boolean().expect("Set has must return a boolean"))
+    }
+
+    /// Remove `value` from the set, returning `true` if it was present.
+    ///
+    /// Same as `set.delete(value)` in JavaScript.
+    #[inline]
+    pub fn delete<T>(&self, value: T, context: &mut Context) -> JsResult<bool>
+    where
+        T: Into<JsValue>,
+    {
+        Set::delete(&self.inner.clone().into(), &[value.into()], context).map(|deleted| {
+            deleted
+                .as_boolean()
+                .expect("Set delete must return a boolean")
+        })
+    }
+
+    /// Remove all the values of the set.
+    ///
+    /// Same as `set.clear()` in JavaScript.
+    #[inline]
+    pub fn clear(&self, context: &mut Context) -> JsResult<()> {
+        Set::clear(&self.inner.clone().into(), &[], context).map(|_| ())
+    }
+
+    /// Call `callback` once for every value of the set, in insertion order.
+    ///
+    /// Same as `set.forEach(callback, thisArg)` in JavaScript.
+    #[inline]
+    pub fn for_each(
+        &self,
+        callback: JsObject,
+        this_arg: JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        Set::for_each(
+            &self.inner.clone().into(),
+            &[callback.into(), this_arg],
+            context,
+        )
+        .map(|_| ())
+    }
+
+    /// Get an iterator over the `[value, value]` entries of the set.
+    ///
+    /// Same as `set.entries()` in JavaScript.
+    #[inline]
+    pub fn entries(&self, context: &mut Context) -> JsResult<JsSetIterator> {
+        Set::entries(&self.inner.clone().into(), &[], context).map(JsSetIterator::from_value)
+    }
+
+    /// Get an iterator over the values of the set.
+    ///
+    /// Same as `set.values()` in JavaScript.
+    #[inline]
+    pub fn values(&self, context: &mut Context) -> JsResult<JsSetIterator> {
+        Set::values(&self.inner.clone().into(), &[], context).map(JsSetIterator::from_value)
+    }
+}
+
+impl From<JsSet> for JsObject {
+    #[inline]
+    fn from(o: JsSet) -> Self {
+        o.inner.clone()
+    }
+}
+
+impl From<JsSet> for JsValue {
+    #[inline]
+    fn from(o: JsSet) -> Self {
+        o.inner.clone().into()
+    }
+}
+
+impl Deref for JsSet {
+    type Target = JsObject;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl JsObjectType for JsSet {}
+
+/// JavaScript `SetIterator` rust object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct JsSetIterator {
+    inner: JsObject,
+}
+
+impl JsSetIterator {
+    /// Wraps the value returned by `CreateSetIterator`.
+    fn from_value(value: JsValue) -> Self {
+        Self {
+            inner: value
+                .as_object()
+                .cloned()
+                .expect("CreateSetIterator must return an object"),
+        }
+    }
+
+    /// Create a `JsSetIterator` from a `JsObject`, if the object is not a `SetIterator` throw a
+    /// `TypeError`.
+    #[inline]
+    pub fn from_object(object: JsObject, context: &mut Context) -> JsResult<Self> {
+        if object.borrow().is_set_iterator() {
+            Ok(Self { inner: object })
+        } else {
+            context.throw_type_error("object is not a SetIterator")
+        }
+    }
+
+    /// Advance the iterator, returning `None` once every value has been visited.
+    #[inline]
+    pub fn next(&self, context: &mut Context) -> JsResult<Option<JsValue>> {
+        let result = SetIterator::next(&self.inner.clone().into(), &[], context)?;
+        let result = result
+            .as_object()
+            .expect("SetIterator next must return an object");
+        if result.get("done", context)?.to_boolean() {
+            return Ok(None);
+        }
+        result.get("value", context).map(Some)
+    }
+}
+
+impl From<JsSetIterator> for JsObject {
+    #[inline]
+    fn from(o: JsSetIterator) -> Self {
+        o.inner.clone()
+    }
+}
+
+impl From<JsSetIterator> for JsValue {
+    #[inline]
+    fn from(o: JsSetIterator) -> Self {
+        o.inner.clone().into()
+    }
+}
+
+impl Deref for JsSetIterator {
+    type Target = JsObject;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl JsObjectType for JsSetIterator {}
@@ -0,0 +1,22 @@
+//! Rust API wrappers for the builtin ECMAScript objects.
+//!
+//! Each wrapper holds a [`JsObject`][super::JsObject] that is guaranteed to be of the wrapped
+//! type, and exposes the methods of its prototype as typed Rust methods.
+
+mod jsarray;
+mod jsarraybuffer;
+mod jsdataview;
+mod jsiterator;
+mod jsmap;
+mod jspromise;
+mod jsproxy;
+mod jsset;
+
+pub use jsarray::*;
+pub use jsarraybuffer::*;
+pub use jsdataview::*;
+pub use jsiterator::*;
+pub use jsmap::*;
+pub use jspromise::*;
+pub use jsproxy::*;
+pub use jsset::*;
@@ -0,0 +1,54 @@
+//! The finalizers of objects, which release the resources of native data when the object is
+//! collected.
+
+use super::Object;
+use std::{cell::RefCell, fmt, mem};
+
+/// A callback run when an object is collected.
+///
+/// The garbage collector finalizes an object before freeing it, in this order:
+///
+/// 1. The finalizers registered with [`Object::add_finalizer`] or
+///    [`ObjectInitializer::finalizer`], in the order they were registered.
+/// 2. The [`Finalize::finalize`] method of the native data of the object, if it has one.
+/// 3. The `Drop` implementation of the native data.
+///
+/// All the unreachable objects of a collection are finalized before any of them is dropped, in no
+/// particular order. A finalizer can read the objects referenced by its object, but they might
+/// already be finalized. The collector can't allocate while it collects, so finalizers must not
+/// create garbage collected values or run JavaScript code.
+///
+/// Finalizers only run when the object is collected, and each one runs at most once. The objects
+/// that are still alive when the thread exits might never be finalized.
+///
+/// [`ObjectInitializer::finalizer`]: crate::object::ObjectInitializer::finalizer
+/// [`Finalize::finalize`]: boa_gc::Finalize::finalize
+pub type NativeFinalizer = Box<dyn FnOnce(&Object)>;
+
+/// The finalizers registered on an object.
+#[derive(Default)]
+pub(crate) struct Finalizers(RefCell<Vec<NativeFinalizer>>);
+
+impl Finalizers {
+    /// Registers a finalizer, which runs after the ones already registered.
+    #[inline]
+    pub(crate) fn push(&mut self, finalizer: NativeFinalizer) {
+        self.0.get_mut().push(finalizer);
+    }
+
+    /// Runs and removes the finalizers of `object`.
+    pub(crate) fn run(&self, object: &Object) {
+        let finalizers = mem::take(&mut *self.0.borrow_mut());
+        for finalizer in finalizers {
+            finalizer(object);
+        }
+    }
+}
+
+impl fmt::Debug for Finalizers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Finalizers")
+            .field("len", &self.0.borrow().len())
+            .finish()
+    }
+}
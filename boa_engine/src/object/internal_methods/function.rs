@@ -39,7 +39,7 @@ fn function_call(
     args: &[JsValue],
     context: &mut Context,
 ) -> JsResult<JsValue> {
-    obj.call_internal(this, args, context)
+    context.with_call_depth(|context| obj.call_internal(this, args, context))
 }
 
 /// Construct an instance of this object with the specified arguments.
@@ -56,5 +56,5 @@ fn function_construct(
     new_target: &JsValue,
     context: &mut Context,
 ) -> JsResult<JsValue> {
-    obj.construct_internal(args, new_target, context)
+    context.with_call_depth(|context| obj.construct_internal(args, new_target, context))
 }
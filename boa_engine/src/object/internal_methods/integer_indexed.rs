@@ -223,11 +223,12 @@ pub(crate) fn integer_indexed_exotic_own_property_keys(
     );
 
     // 1. Let keys be a new empty List.
-    let mut keys = if inner.is_detached() {
+    let mut keys = if inner.is_out_of_bounds() {
         vec![]
     } else {
-        // 2. If IsDetachedBuffer(O.[[ViewedArrayBuffer]]) is false, then
-        // a. For each integer i starting with 0 such that i < O.[[ArrayLength]], in ascending order, do
+        // 2. If IsTypedArrayOutOfBounds(taRecord) is false, then
+        // a. Let length be TypedArrayLength(taRecord).
+        // b. For each integer i starting with 0 such that i < length, in ascending order, do
         // i. Add ! ToString(𝔽(i)) as the last element of keys.
         (0..inner.array_length())
             .into_iter()
@@ -271,9 +272,13 @@ pub(crate) fn is_valid_integer_index(obj: &JsObject, index: usize) -> bool {
     // 1. If IsDetachedBuffer(O.[[ViewedArrayBuffer]]) is true, return false.
     // 2. If ! IsIntegralNumber(index) is false, return false.
     // 3. If index is -0𝔽, return false.
-    // 4. If ℝ(index) < 0 or ℝ(index) ≥ O.[[ArrayLength]], return false.
-    // 5. Return true.
-    !inner.is_detached() && index < inner.array_length()
+    // 4. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(O, unordered).
+    // 5. NOTE: Bounds checking is not a synchronizing operation when O's backing buffer is a growable SharedArrayBuffer.
+    // 6. If IsTypedArrayOutOfBounds(taRecord) is true, return false.
+    // 7. Let length be TypedArrayLength(taRecord).
+    // 8. If ℝ(index) < 0 or ℝ(index) ≥ length, return false.
+    // 9. Return true.
+    index < inner.array_length()
 }
 
 /// Abstract operation `IntegerIndexedElementGet ( O, index )`.
@@ -20,9 +20,10 @@ pub(super) mod array;
 pub(super) mod bound_function;
 pub(super) mod function;
 pub(crate) mod global;
-pub(super) mod integer_indexed;
+pub(crate) mod integer_indexed;
 pub(super) mod proxy;
 pub(super) mod string;
+pub(super) mod wrapped_function;
 
 impl JsObject {
     /// Internal method `[[GetPrototypeOf]]`
@@ -725,12 +726,7 @@ pub(crate) fn ordinary_own_property_keys(
     let mut keys = Vec::new();
 
     let ordered_indexes = {
-        let mut indexes: Vec<_> = obj
-            .borrow()
-            .properties
-            .index_property_keys()
-            .copied()
-            .collect();
+        let mut indexes: Vec<_> = obj.borrow().properties.index_property_keys().collect();
         indexes.sort_unstable();
         indexes
     };
@@ -328,7 +328,7 @@ pub(crate) fn proxy_exotic_get_own_property(
             // b. If targetDesc.[[Configurable]] is false, throw a TypeError exception.
             if !desc.expect_configurable() {
                 return context.throw_type_error(
-                    "Proxy trap result is undefined adn target result is not configurable",
+                    "Proxy trap result is undefined and target result is not configurable",
                 );
             }
 
@@ -674,7 +674,12 @@ pub(crate) fn proxy_exotic_set(
     if !trap
         .call(
             &handler.into(),
-            &[target.clone().into(), value.clone(), receiver],
+            &[
+                target.clone().into(),
+                key.clone().into(),
+                value.clone(),
+                receiver,
+            ],
             context,
         )?
         .to_boolean()
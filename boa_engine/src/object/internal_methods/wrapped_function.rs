@@ -0,0 +1,77 @@
+use crate::{
+    builtins::shadow_realm::get_wrapped_value, object::JsObject, Context, JsResult, JsValue,
+};
+
+use super::{InternalObjectMethods, ORDINARY_INTERNAL_METHODS};
+
+/// Definitions of the internal object methods for wrapped function exotic objects.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrapped-function-exotic-objects
+pub(crate) static WRAPPED_FUNCTION_INTERNAL_METHODS: InternalObjectMethods =
+    InternalObjectMethods {
+        __call__: Some(wrapped_function_call),
+        __construct__: None,
+        ..ORDINARY_INTERNAL_METHODS
+    };
+
+/// Internal method `[[Call]]` for wrapped function exotic objects.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrapped-function-exotic-objects-call-thisargument-argumentslist
+#[track_caller]
+fn wrapped_function_call(
+    obj: &JsObject,
+    this: &JsValue,
+    args: &[JsValue],
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. Let target be F.[[WrappedTargetFunction]].
+    // 2. Assert: IsCallable(target) is true.
+    // 3. Let callerRealm be F.[[Realm]].
+    // 4. NOTE: Any exception objects produced after this point are associated with callerRealm.
+    // 5. Let targetRealm be ? GetFunctionRealm(target).
+    let (target, caller_realm, target_realm) = {
+        let obj = obj.borrow();
+        let wrapped = obj.as_wrapped_function().expect(
+            "wrapped function exotic method should only be callable from wrapped functions",
+        );
+        (
+            wrapped.target().clone(),
+            wrapped.realm().clone(),
+            wrapped.target_realm().clone(),
+        )
+    };
+
+    // 6. Let wrappedArgs be a new empty List.
+    // 7. For each element arg of argumentsList, do
+    //     a. Let wrappedValue be ? GetWrappedValue(targetRealm, arg).
+    //     b. Append wrappedValue to wrappedArgs.
+    let wrapped_args = args
+        .iter()
+        .map(|arg| get_wrapped_value(arg, &target_realm, &caller_realm, context))
+        .collect::<JsResult<Vec<_>>>()?;
+
+    // 8. Let wrappedThisArgument to ? GetWrappedValue(targetRealm, thisArgument).
+    let wrapped_this = get_wrapped_value(this, &target_realm, &caller_realm, context)?;
+
+    // 9. Let result be the Completion Record of Call(target, wrappedThisArgument, wrappedArgs).
+    let result = context.with_realm(&target_realm, |context| {
+        target.call(&wrapped_this, &wrapped_args, context)
+    });
+
+    match result {
+        // 10. If result.[[Type]] is normal or result.[[Type]] is return, then
+        //     a. Return ? GetWrappedValue(callerRealm, result.[[Value]]).
+        Ok(result) => get_wrapped_value(&result, &caller_realm, &target_realm, context),
+        // An uncatchable termination keeps unwinding the stack.
+        Err(error) if context.is_terminating() => Err(error),
+        // 11. Else,
+        //     a. Throw a TypeError exception.
+        Err(_) => context.throw_type_error("the wrapped function threw an error"),
+    }
+}
@@ -2,14 +2,14 @@
 //!
 //! The `JsObject` is a garbage collected Object.
 
-use super::{JsPrototype, NativeObject, Object, PropertyMap};
+use super::{finalizer::Finalizers, JsPrototype, NativeObject, Object, PropertyMap};
 use crate::{
     object::{ObjectData, ObjectKind},
     property::{PropertyDescriptor, PropertyKey},
     value::PreferredType,
     Context, JsResult, JsValue,
 };
-use boa_gc::{self, Finalize, Gc, Trace};
+use boa_gc::{self, EphemeronTable, Finalize, Gc, LiveCounter, Trace, WeakGc, WeakSlot};
 use std::{
     cell::RefCell,
     collections::HashMap,
@@ -56,6 +56,11 @@ impl JsObject {
             prototype: prototype.into(),
             extensible: true,
             properties: PropertyMap::default(),
+            weak_slot: None,
+            enumeration_cache: None,
+            live_counter: LiveCounter::new(),
+            ephemerons: EphemeronTable::new(),
+            finalizers: Finalizers::default(),
         })
     }
 
@@ -114,6 +119,23 @@ impl JsObject {
         std::ptr::eq(lhs.as_ref(), rhs.as_ref())
     }
 
+    /// Creates a weak reference to the object, which doesn't keep it alive.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently borrowed.
+    #[inline]
+    #[track_caller]
+    pub fn downgrade(&self) -> WeakJsObject {
+        let mut object = self.borrow_mut();
+        let slot = object.weak_slot.get_or_insert_with(WeakSlot::new);
+
+        // SAFETY: The slot is owned by the object, so it is cleared when the object is finalized.
+        let inner = unsafe { WeakGc::new(&self.inner, slot) };
+
+        WeakJsObject { inner }
+    }
+
     /// Converts an object to a primitive.
     ///
     /// Diverges from the spec to prevent a stack overflow when the object is recursive.
@@ -311,6 +333,17 @@ impl JsObject {
         self.borrow().is_array_buffer()
     }
 
+    /// Checks if it's a `DataView` object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub fn is_data_view(&self) -> bool {
+        self.borrow().is_data_view()
+    }
+
     /// Checks if it is a `Map` object.pub
     ///
     /// # Panics
@@ -683,6 +716,32 @@ impl PartialEq for JsObject {
     }
 }
 
+/// A weak reference to a [`JsObject`], created with [`JsObject::downgrade`].
+#[derive(Debug, Trace, Finalize)]
+pub struct WeakJsObject {
+    inner: WeakGc<boa_gc::Cell<Object>>,
+}
+
+impl WeakJsObject {
+    /// Returns the object, or `None` if it was garbage collected.
+    #[inline]
+    pub fn upgrade(&self) -> Option<JsObject> {
+        self.inner.upgrade().map(|inner| JsObject { inner })
+    }
+
+    /// Returns `true` if the object was garbage collected.
+    #[inline]
+    pub fn is_collected(&self) -> bool {
+        self.inner.is_cleared()
+    }
+
+    /// Returns the weak reference of the collector to the object.
+    #[inline]
+    pub(crate) fn as_weak_gc(&self) -> &WeakGc<boa_gc::Cell<Object>> {
+        &self.inner
+    }
+}
+
 /// An error returned by [`JsObject::try_borrow`](struct.JsObject.html#method.try_borrow).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct BorrowError;
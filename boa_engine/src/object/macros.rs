@@ -0,0 +1,80 @@
+//! Macros building objects and arrays with a literal syntax from Rust.
+
+/// Creates an ordinary object from a list of properties, like an object literal in JavaScript.
+///
+/// The first argument is the [`Context`](crate::Context), followed by the properties in braces.
+/// A key is an identifier or a string or number literal, used as is, or an expression in
+/// brackets that converts into a [`PropertyKey`](crate::property::PropertyKey), like a symbol.
+/// The values are any expressions that convert into a [`JsValue`](crate::JsValue), and can use
+/// the context, for example to nest other objects and arrays.
+///
+/// The properties are writable, enumerable and configurable, like the properties of an object
+/// literal. An [`ObjectInitializer`](crate::object::ObjectInitializer) builds objects with other
+/// properties, like accessors or methods.
+///
+/// # Examples
+///
+/// ```
+/// use boa_engine::{js_array, js_object, Context, JsSymbol};
+///
+/// let mut context = Context::default();
+/// let tag = JsSymbol::new(Some("tag".into()));
+/// let object = js_object!(&mut context, {
+///     name: "boa",
+///     "version": 1,
+///     [tag]: true,
+///     tags: js_array!(&mut context, ["engine", "javascript"]),
+///     nested: js_object!(&mut context, { empty: js_object!(&mut context, {}) }),
+/// });
+///
+/// let name = object.get("name", &mut context).unwrap();
+/// assert_eq!(name.as_string().map(|s| s.as_str()), Some("boa"));
+/// ```
+#[macro_export]
+macro_rules! js_object {
+    (@key [$key:expr]) => {
+        $crate::property::PropertyKey::from($key)
+    };
+    (@key $key:ident) => {
+        $crate::property::PropertyKey::from(::std::stringify!($key))
+    };
+    (@key $key:literal) => {
+        $crate::property::PropertyKey::from($key)
+    };
+    ($context:expr, { $($key:tt : $value:expr),* $(,)? }) => {{
+        // The keys and values are evaluated before borrowing the context, so they can use it.
+        let properties: ::std::vec::Vec<($crate::property::PropertyKey, $crate::JsValue)> =
+            ::std::vec![$(($crate::js_object!(@key $key), $crate::JsValue::from($value))),*];
+        let context: &mut $crate::Context = $context;
+        let mut initializer = $crate::object::ObjectInitializer::new(context);
+        for (key, value) in properties {
+            initializer.property(key, value, $crate::property::Attribute::all());
+        }
+        initializer.build()
+    }};
+}
+
+/// Creates an array from a list of elements, like an array literal in JavaScript.
+///
+/// The first argument is the [`Context`](crate::Context), followed by the elements in brackets.
+/// The elements are any expressions that convert into a [`JsValue`](crate::JsValue), and can use
+/// the context, for example to nest other objects and arrays.
+///
+/// # Examples
+///
+/// ```
+/// use boa_engine::{js_array, Context};
+///
+/// let mut context = Context::default();
+/// let array = js_array!(&mut context, [1, "two", js_array!(&mut context, [3.0])]);
+/// assert_eq!(array.length(&mut context).unwrap(), 3);
+/// ```
+#[macro_export]
+macro_rules! js_array {
+    ($context:expr, [ $($element:expr),* $(,)? ]) => {{
+        // The elements are evaluated before borrowing the context, so they can use it.
+        let elements: ::std::vec::Vec<$crate::JsValue> =
+            ::std::vec![$($crate::JsValue::from($element)),*];
+        $crate::object::JsArray::from_iter(elements, $context)
+    }};
+}
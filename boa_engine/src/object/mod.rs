@@ -1,9 +1,12 @@
 //! This module implements the Rust representation of a JavaScript object.
 
-pub use jsobject::{JsObject, RecursionLimiter, Ref, RefMut};
+pub use finalizer::NativeFinalizer;
+pub use jsobject::{JsObject, RecursionLimiter, Ref, RefMut, WeakJsObject};
 pub use operations::IntegrityLevel;
 pub use property_map::*;
+pub use typed_function::{IntoJsFunction, IntoJsResult};
 
+use self::finalizer::Finalizers;
 use self::internal_methods::{
     arguments::ARGUMENTS_EXOTIC_INTERNAL_METHODS,
     array::ARRAY_EXOTIC_INTERNAL_METHODS,
@@ -18,49 +21,71 @@ use self::internal_methods::{
         PROXY_EXOTIC_INTERNAL_METHODS_WITH_CALL,
     },
     string::STRING_EXOTIC_INTERNAL_METHODS,
+    wrapped_function::WRAPPED_FUNCTION_INTERNAL_METHODS,
     InternalObjectMethods, ORDINARY_INTERNAL_METHODS,
 };
+#[cfg(feature = "intl")]
+use crate::builtins::intl::{
+    segmenter::{SegmentIterator, Segments},
+    DisplayNames, Locale, PluralRules, Segmenter,
+};
+#[cfg(feature = "temporal")]
+use crate::builtins::temporal::{
+    Duration, Instant, PlainDate, PlainDateTime, PlainTime, ZonedDateTime,
+};
 use crate::{
     builtins::{
         array::array_iterator::ArrayIterator,
         array_buffer::ArrayBuffer,
+        disposable_stack::DisposableStack,
         function::arguments::Arguments,
         function::{
             arguments::ParameterMap, BoundFunction, Captures, Function, NativeFunctionSignature,
         },
         generator::Generator,
+        intl::{DateTimeFormat, DurationFormat},
+        iterator::{iterator_helper::IteratorHelper, IteratorWrapper},
         map::map_iterator::MapIterator,
         map::ordered_map::OrderedMap,
-        object::for_in_iterator::ForInIterator,
+        object::for_in_iterator::{EnumerationCache, ForInIterator},
+        promise::Promise,
         proxy::Proxy,
         regexp::regexp_string_iterator::RegExpStringIterator,
         set::ordered_set::OrderedSet,
         set::set_iterator::SetIterator,
+        shadow_realm::WrappedFunction,
         string::string_iterator::StringIterator,
         typed_array::integer_indexed_object::IntegerIndexed,
+        weak::{FinalizationRegistry, WeakMap, WeakTarget},
         DataView, Date, RegExp,
     },
     context::intrinsics::StandardConstructor,
+    error::StackFrame,
     property::{Attribute, PropertyDescriptor, PropertyKey},
+    realm::JsRealm,
     Context, JsBigInt, JsResult, JsString, JsSymbol, JsValue,
 };
-use boa_gc::{Finalize, Trace};
+use boa_gc::{EphemeronTable, Finalize, LiveCounter, Trace, WeakSlot};
 use std::{
     any::Any,
     fmt::{self, Debug, Display},
     ops::{Deref, DerefMut},
+    rc::Rc,
 };
 
 #[cfg(test)]
 mod tests;
 
+pub mod builtins;
+mod finalizer;
 pub(crate) mod internal_methods;
-mod jsarray;
 mod jsobject;
+mod macros;
 mod operations;
 mod property_map;
+mod typed_function;
 
-pub use jsarray::*;
+pub use builtins::*;
 
 pub(crate) trait JsObjectType:
     Into<JsValue> + Into<JsObject> + Deref<Target = JsObject>
@@ -96,7 +121,7 @@ impl<T: Any + Debug + Trace> NativeObject for T {
 }
 
 /// The internal representation of a JavaScript object.
-#[derive(Debug, Trace, Finalize)]
+#[derive(Debug, Trace)]
 pub struct Object {
     /// The type of the object.
     pub data: ObjectData,
@@ -106,6 +131,24 @@ pub struct Object {
     prototype: JsPrototype,
     /// Whether it can have new properties added to it.
     extensible: bool,
+    /// Liveness flag shared with the weak references to this object, created on demand.
+    weak_slot: Option<WeakSlot>,
+    /// The keys of the last `for-in` loop over the object.
+    #[unsafe_ignore_trace]
+    enumeration_cache: Option<Rc<EnumerationCache>>,
+    /// Counts the object in the live objects of the statistics of the collector.
+    live_counter: LiveCounter,
+    /// The values associated with the object by the `WeakMap` objects it is a key of.
+    ephemerons: EphemeronTable<JsValue>,
+    /// The callbacks run when the object is collected.
+    #[unsafe_ignore_trace]
+    finalizers: Finalizers,
+}
+
+impl Finalize for Object {
+    fn finalize(&self) {
+        self.finalizers.run(self);
+    }
 }
 
 /// Defines the kind of an object and its internal methods
@@ -128,25 +171,62 @@ pub enum ObjectKind {
     BigInt(JsBigInt),
     Boolean(bool),
     DataView(DataView),
+    DisposableStack(DisposableStack),
+    AsyncDisposableStack(DisposableStack),
     ForInIterator(ForInIterator),
     Function(Function),
     BoundFunction(BoundFunction),
+    WrappedFunction(WrappedFunction),
     Generator(Generator),
     GeneratorFunction(Function),
+    IteratorHelper(IteratorHelper),
+    IteratorWrapper(IteratorWrapper),
     Set(OrderedSet<JsValue>),
     SetIterator(SetIterator),
     String(JsString),
     StringIterator(StringIterator),
     Number(f64),
     Symbol(JsSymbol),
-    Error,
+    Error(Vec<StackFrame>),
     Ordinary,
     Proxy(Proxy),
     Date(Date),
+    DateTimeFormat(DateTimeFormat),
+    #[cfg(feature = "intl")]
+    DisplayNames(DisplayNames),
+    DurationFormat(DurationFormat),
+    #[cfg(feature = "intl")]
+    Locale(Locale),
+    #[cfg(feature = "intl")]
+    PluralRules(PluralRules),
+    #[cfg(feature = "intl")]
+    Segmenter(Segmenter),
+    #[cfg(feature = "intl")]
+    Segments(Segments),
+    #[cfg(feature = "intl")]
+    SegmentIterator(SegmentIterator),
+    #[cfg(feature = "temporal")]
+    Instant(Instant),
+    #[cfg(feature = "temporal")]
+    Duration(Duration),
+    #[cfg(feature = "temporal")]
+    PlainDate(PlainDate),
+    #[cfg(feature = "temporal")]
+    PlainTime(PlainTime),
+    #[cfg(feature = "temporal")]
+    PlainDateTime(PlainDateTime),
+    #[cfg(feature = "temporal")]
+    ZonedDateTime(ZonedDateTime),
     Global,
     Arguments(Arguments),
     NativeObject(Box<dyn NativeObject>),
     IntegerIndexed(IntegerIndexed),
+    WeakRef(WeakTarget),
+    FinalizationRegistry(FinalizationRegistry),
+    WeakMap(WeakMap),
+    Promise(Promise),
+    ShadowRealm(JsRealm),
+    RawJson,
 }
 
 impl ObjectData {
@@ -230,6 +310,62 @@ impl ObjectData {
         }
     }
 
+    /// Create the `DisposableStack` object data
+    pub fn disposable_stack(stack: DisposableStack) -> Self {
+        Self {
+            kind: ObjectKind::DisposableStack(stack),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `AsyncDisposableStack` object data
+    pub fn async_disposable_stack(stack: DisposableStack) -> Self {
+        Self {
+            kind: ObjectKind::AsyncDisposableStack(stack),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `WeakRef` object data
+    pub fn weak_ref(target: WeakTarget) -> Self {
+        Self {
+            kind: ObjectKind::WeakRef(target),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `FinalizationRegistry` object data
+    pub fn finalization_registry(registry: FinalizationRegistry) -> Self {
+        Self {
+            kind: ObjectKind::FinalizationRegistry(registry),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `WeakMap` object data
+    pub fn weak_map(map: WeakMap) -> Self {
+        Self {
+            kind: ObjectKind::WeakMap(map),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Promise` object data
+    pub fn promise(promise: Promise) -> Self {
+        Self {
+            kind: ObjectKind::Promise(promise),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `ShadowRealm` object data
+    pub fn shadow_realm(realm: JsRealm) -> Self {
+        Self {
+            kind: ObjectKind::ShadowRealm(realm),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `ForInIterator` object data
     pub fn for_in_iterator(for_in_iterator: ForInIterator) -> Self {
         Self {
@@ -238,6 +374,22 @@ impl ObjectData {
         }
     }
 
+    /// Create the `IteratorHelper` object data
+    pub fn iterator_helper(iterator_helper: IteratorHelper) -> Self {
+        Self {
+            kind: ObjectKind::IteratorHelper(iterator_helper),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `IteratorWrapper` object data
+    pub fn iterator_wrapper(iterator_wrapper: IteratorWrapper) -> Self {
+        Self {
+            kind: ObjectKind::IteratorWrapper(iterator_wrapper),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `Function` object data
     pub fn function(function: Function) -> Self {
         Self {
@@ -262,6 +414,14 @@ impl ObjectData {
         }
     }
 
+    /// Create the wrapped function exotic object data
+    pub fn wrapped_function(wrapped_function: WrappedFunction) -> Self {
+        Self {
+            kind: ObjectKind::WrappedFunction(wrapped_function),
+            internal_methods: &WRAPPED_FUNCTION_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `Generator` object data
     pub fn generator(generator: Generator) -> Self {
         Self {
@@ -330,10 +490,18 @@ impl ObjectData {
         }
     }
 
-    /// Create the `Error` object data
-    pub fn error() -> Self {
+    /// Create the `Error` object data, recording the call stack currently active in `context`
+    pub fn error(context: &Context) -> Self {
+        Self {
+            kind: ObjectKind::Error(StackFrame::capture(context)),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the raw JSON object data, for the objects returned by `JSON.rawJSON`
+    pub fn raw_json() -> Self {
         Self {
-            kind: ObjectKind::Error,
+            kind: ObjectKind::RawJson,
             internal_methods: &ORDINARY_INTERNAL_METHODS,
         }
     }
@@ -368,6 +536,130 @@ impl ObjectData {
         }
     }
 
+    /// Create the `DateTimeFormat` object data
+    pub fn date_time_format(date_time_format: DateTimeFormat) -> Self {
+        Self {
+            kind: ObjectKind::DateTimeFormat(date_time_format),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `DisplayNames` object data
+    #[cfg(feature = "intl")]
+    pub fn display_names(display_names: DisplayNames) -> Self {
+        Self {
+            kind: ObjectKind::DisplayNames(display_names),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `DurationFormat` object data
+    pub fn duration_format(duration_format: DurationFormat) -> Self {
+        Self {
+            kind: ObjectKind::DurationFormat(duration_format),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Instant` object data
+    #[cfg(feature = "temporal")]
+    pub fn instant(instant: Instant) -> Self {
+        Self {
+            kind: ObjectKind::Instant(instant),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Duration` object data
+    #[cfg(feature = "temporal")]
+    pub fn duration(duration: Duration) -> Self {
+        Self {
+            kind: ObjectKind::Duration(duration),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `PlainDate` object data
+    #[cfg(feature = "temporal")]
+    pub fn plain_date(plain_date: PlainDate) -> Self {
+        Self {
+            kind: ObjectKind::PlainDate(plain_date),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `PlainTime` object data
+    #[cfg(feature = "temporal")]
+    pub fn plain_time(plain_time: PlainTime) -> Self {
+        Self {
+            kind: ObjectKind::PlainTime(plain_time),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `PlainDateTime` object data
+    #[cfg(feature = "temporal")]
+    pub fn plain_date_time(plain_date_time: PlainDateTime) -> Self {
+        Self {
+            kind: ObjectKind::PlainDateTime(plain_date_time),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `ZonedDateTime` object data
+    #[cfg(feature = "temporal")]
+    pub fn zoned_date_time(zoned_date_time: ZonedDateTime) -> Self {
+        Self {
+            kind: ObjectKind::ZonedDateTime(zoned_date_time),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Locale` object data
+    #[cfg(feature = "intl")]
+    pub fn locale(locale: Locale) -> Self {
+        Self {
+            kind: ObjectKind::Locale(locale),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `PluralRules` object data
+    #[cfg(feature = "intl")]
+    pub fn plural_rules(plural_rules: PluralRules) -> Self {
+        Self {
+            kind: ObjectKind::PluralRules(plural_rules),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Segmenter` object data
+    #[cfg(feature = "intl")]
+    pub fn segmenter(segmenter: Segmenter) -> Self {
+        Self {
+            kind: ObjectKind::Segmenter(segmenter),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `Segments` object data
+    #[cfg(feature = "intl")]
+    pub fn segments(segments: Segments) -> Self {
+        Self {
+            kind: ObjectKind::Segments(segments),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
+    /// Create the `SegmentIterator` object data
+    #[cfg(feature = "intl")]
+    pub fn segment_iterator(segment_iterator: SegmentIterator) -> Self {
+        Self {
+            kind: ObjectKind::SegmentIterator(segment_iterator),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `Global` object data
     pub fn global() -> Self {
         Self {
@@ -410,12 +702,16 @@ impl Display for ObjectKind {
         f.write_str(match self {
             Self::Array => "Array",
             Self::ArrayIterator(_) => "ArrayIterator",
+            Self::ArrayBuffer(buffer) if buffer.is_shared_array_buffer() => "SharedArrayBuffer",
             Self::ArrayBuffer(_) => "ArrayBuffer",
             Self::ForInIterator(_) => "ForInIterator",
             Self::Function(_) => "Function",
             Self::BoundFunction(_) => "BoundFunction",
+            Self::WrappedFunction(_) => "WrappedFunction",
             Self::Generator(_) => "Generator",
             Self::GeneratorFunction(_) => "GeneratorFunction",
+            Self::IteratorHelper(_) => "IteratorHelper",
+            Self::IteratorWrapper(_) => "IteratorWrapper",
             Self::RegExp(_) => "RegExp",
             Self::RegExpStringIterator(_) => "RegExpStringIterator",
             Self::Map(_) => "Map",
@@ -425,18 +721,52 @@ impl Display for ObjectKind {
             Self::String(_) => "String",
             Self::StringIterator(_) => "StringIterator",
             Self::Symbol(_) => "Symbol",
-            Self::Error => "Error",
+            Self::Error(_) => "Error",
             Self::Ordinary => "Ordinary",
             Self::Proxy(_) => "Proxy",
             Self::Boolean(_) => "Boolean",
             Self::Number(_) => "Number",
             Self::BigInt(_) => "BigInt",
             Self::Date(_) => "Date",
+            Self::DateTimeFormat(_) => "DateTimeFormat",
+            #[cfg(feature = "intl")]
+            Self::DisplayNames(_) => "DisplayNames",
+            Self::DurationFormat(_) => "DurationFormat",
+            #[cfg(feature = "intl")]
+            Self::Locale(_) => "Locale",
+            #[cfg(feature = "intl")]
+            Self::PluralRules(_) => "PluralRules",
+            #[cfg(feature = "intl")]
+            Self::Segmenter(_) => "Segmenter",
+            #[cfg(feature = "intl")]
+            Self::Segments(_) => "Segments",
+            #[cfg(feature = "intl")]
+            Self::SegmentIterator(_) => "SegmentIterator",
+            #[cfg(feature = "temporal")]
+            Self::Instant(_) => "Instant",
+            #[cfg(feature = "temporal")]
+            Self::Duration(_) => "Duration",
+            #[cfg(feature = "temporal")]
+            Self::PlainDate(_) => "PlainDate",
+            #[cfg(feature = "temporal")]
+            Self::PlainTime(_) => "PlainTime",
+            #[cfg(feature = "temporal")]
+            Self::PlainDateTime(_) => "PlainDateTime",
+            #[cfg(feature = "temporal")]
+            Self::ZonedDateTime(_) => "ZonedDateTime",
             Self::Global => "Global",
             Self::Arguments(_) => "Arguments",
             Self::NativeObject(_) => "NativeObject",
             Self::IntegerIndexed(_) => "TypedArray",
             Self::DataView(_) => "DataView",
+            Self::DisposableStack(_) => "DisposableStack",
+            Self::AsyncDisposableStack(_) => "AsyncDisposableStack",
+            Self::WeakRef(_) => "WeakRef",
+            Self::FinalizationRegistry(_) => "FinalizationRegistry",
+            Self::WeakMap(_) => "WeakMap",
+            Self::Promise(_) => "Promise",
+            Self::ShadowRealm(_) => "ShadowRealm",
+            Self::RawJson => "RawJSON",
         })
     }
 }
@@ -459,6 +789,11 @@ impl Default for Object {
             properties: PropertyMap::default(),
             prototype: None,
             extensible: true,
+            weak_slot: None,
+            enumeration_cache: None,
+            live_counter: LiveCounter::new(),
+            ephemerons: EphemeronTable::new(),
+            finalizers: Finalizers::default(),
         }
     }
 }
@@ -593,6 +928,28 @@ impl Object {
         }
     }
 
+    #[inline]
+    pub fn as_iterator_helper_mut(&mut self) -> Option<&mut IteratorHelper> {
+        match &mut self.data {
+            ObjectData {
+                kind: ObjectKind::IteratorHelper(iter),
+                ..
+            } => Some(iter),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_iterator_wrapper(&self) -> Option<&IteratorWrapper> {
+        match &self.data {
+            ObjectData {
+                kind: ObjectKind::IteratorWrapper(iter),
+                ..
+            } => Some(iter),
+            _ => None,
+        }
+    }
+
     /// Checks if it is a `Map` object.pub
     #[inline]
     pub fn is_map(&self) -> bool {
@@ -693,6 +1050,17 @@ impl Object {
         }
     }
 
+    #[inline]
+    pub fn is_set_iterator(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::SetIterator(_),
+                ..
+            }
+        )
+    }
+
     #[inline]
     pub fn as_set_iterator_mut(&mut self) -> Option<&mut SetIterator> {
         match &mut self.data {
@@ -775,6 +1143,17 @@ impl Object {
         }
     }
 
+    #[inline]
+    pub fn as_wrapped_function(&self) -> Option<&WrappedFunction> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::WrappedFunction(ref wrapped_function),
+                ..
+            } => Some(wrapped_function),
+            _ => None,
+        }
+    }
+
     /// Checks if it's a `Generator` object.
     #[inline]
     pub fn is_generator(&self) -> bool {
@@ -840,12 +1219,24 @@ impl Object {
         matches!(
             self.data,
             ObjectData {
-                kind: ObjectKind::Error,
+                kind: ObjectKind::Error(_),
                 ..
             }
         )
     }
 
+    /// Gets the call stack captured when the error object was created, if it is an Error object.
+    #[inline]
+    pub fn as_error_stack(&self) -> Option<&[StackFrame]> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Error(ref stack),
+                ..
+            } => Some(stack),
+            _ => None,
+        }
+    }
+
     /// Checks if it a Boolean object.
     #[inline]
     pub fn is_boolean(&self) -> bool {
@@ -937,153 +1328,534 @@ impl Object {
         }
     }
 
-    /// Checks if it a `RegExp` object.
+    /// Gets the `DateTimeFormat` data if the object is an `Intl.DateTimeFormat`.
     #[inline]
-    pub fn is_regexp(&self) -> bool {
-        matches!(
-            self.data,
+    pub fn as_date_time_format(&self) -> Option<&DateTimeFormat> {
+        match self.data {
             ObjectData {
-                kind: ObjectKind::RegExp(_),
+                kind: ObjectKind::DateTimeFormat(ref date_time_format),
                 ..
-            }
-        )
+            } => Some(date_time_format),
+            _ => None,
+        }
     }
 
-    /// Gets the regexp data if the object is a regexp.
+    /// Gets the mutable `DateTimeFormat` data if the object is an `Intl.DateTimeFormat`.
     #[inline]
-    pub fn as_regexp(&self) -> Option<&RegExp> {
+    pub fn as_date_time_format_mut(&mut self) -> Option<&mut DateTimeFormat> {
         match self.data {
             ObjectData {
-                kind: ObjectKind::RegExp(ref regexp),
+                kind: ObjectKind::DateTimeFormat(ref mut date_time_format),
                 ..
-            } => Some(regexp),
+            } => Some(date_time_format),
             _ => None,
         }
     }
 
-    /// Checks if it a `TypedArray` object.
+    /// Gets the `DisplayNames` data if the object is an `Intl.DisplayNames`.
+    #[cfg(feature = "intl")]
     #[inline]
-    pub fn is_typed_array(&self) -> bool {
-        matches!(
-            self.data,
+    pub fn as_display_names(&self) -> Option<&DisplayNames> {
+        match self.data {
             ObjectData {
-                kind: ObjectKind::IntegerIndexed(_),
+                kind: ObjectKind::DisplayNames(ref display_names),
                 ..
-            }
-        )
+            } => Some(display_names),
+            _ => None,
+        }
     }
 
+    /// Gets the `DurationFormat` data if the object is an `Intl.DurationFormat`.
     #[inline]
-    pub fn as_data_view(&self) -> Option<&DataView> {
-        match &self.data {
+    pub fn as_duration_format(&self) -> Option<&DurationFormat> {
+        match self.data {
             ObjectData {
-                kind: ObjectKind::DataView(data_view),
+                kind: ObjectKind::DurationFormat(ref duration_format),
                 ..
-            } => Some(data_view),
+            } => Some(duration_format),
             _ => None,
         }
     }
 
+    /// Gets the `Instant` data if the object is a `Temporal.Instant`.
+    #[cfg(feature = "temporal")]
     #[inline]
-    pub fn as_data_view_mut(&mut self) -> Option<&mut DataView> {
-        match &mut self.data {
+    pub fn as_instant(&self) -> Option<&Instant> {
+        match self.data {
             ObjectData {
-                kind: ObjectKind::DataView(data_view),
+                kind: ObjectKind::Instant(ref instant),
                 ..
-            } => Some(data_view),
+            } => Some(instant),
             _ => None,
         }
     }
 
-    /// Checks if it is an `Arguments` object.
+    /// Gets the `Duration` data if the object is a `Temporal.Duration`.
+    #[cfg(feature = "temporal")]
     #[inline]
-    pub fn is_arguments(&self) -> bool {
-        matches!(
-            self.data,
+    pub fn as_duration(&self) -> Option<&Duration> {
+        match self.data {
             ObjectData {
-                kind: ObjectKind::Arguments(_),
+                kind: ObjectKind::Duration(ref duration),
                 ..
-            }
-        )
+            } => Some(duration),
+            _ => None,
+        }
     }
 
-    /// Gets the mapped arguments data if this is a mapped arguments object.
+    /// Gets the `PlainDate` data if the object is a `Temporal.PlainDate`.
+    #[cfg(feature = "temporal")]
     #[inline]
-    pub fn as_mapped_arguments(&self) -> Option<&ParameterMap> {
+    pub fn as_plain_date(&self) -> Option<&PlainDate> {
         match self.data {
             ObjectData {
-                kind: ObjectKind::Arguments(Arguments::Mapped(ref args)),
+                kind: ObjectKind::PlainDate(ref date),
                 ..
-            } => Some(args),
+            } => Some(date),
             _ => None,
         }
     }
 
-    /// Gets the mutable mapped arguments data if this is a mapped arguments object.
+    /// Gets the `PlainTime` data if the object is a `Temporal.PlainTime`.
+    #[cfg(feature = "temporal")]
     #[inline]
-    pub fn as_mapped_arguments_mut(&mut self) -> Option<&mut ParameterMap> {
+    pub fn as_plain_time(&self) -> Option<&PlainTime> {
         match self.data {
             ObjectData {
-                kind: ObjectKind::Arguments(Arguments::Mapped(ref mut args)),
+                kind: ObjectKind::PlainTime(ref time),
                 ..
-            } => Some(args),
+            } => Some(time),
             _ => None,
         }
     }
 
-    /// Gets the typed array data (integer indexed object) if this is a typed array.
+    /// Gets the `PlainDateTime` data if the object is a `Temporal.PlainDateTime`.
+    #[cfg(feature = "temporal")]
     #[inline]
-    pub fn as_typed_array(&self) -> Option<&IntegerIndexed> {
+    pub fn as_plain_date_time(&self) -> Option<&PlainDateTime> {
         match self.data {
             ObjectData {
-                kind: ObjectKind::IntegerIndexed(ref integer_indexed_object),
+                kind: ObjectKind::PlainDateTime(ref date_time),
                 ..
-            } => Some(integer_indexed_object),
+            } => Some(date_time),
             _ => None,
         }
     }
 
-    /// Gets the typed array data (integer indexed object) if this is a typed array.
+    /// Gets the `ZonedDateTime` data if the object is a `Temporal.ZonedDateTime`.
+    #[cfg(feature = "temporal")]
     #[inline]
-    pub fn as_typed_array_mut(&mut self) -> Option<&mut IntegerIndexed> {
+    pub fn as_zoned_date_time(&self) -> Option<&ZonedDateTime> {
         match self.data {
             ObjectData {
-                kind: ObjectKind::IntegerIndexed(ref mut integer_indexed_object),
+                kind: ObjectKind::ZonedDateTime(ref date_time),
                 ..
-            } => Some(integer_indexed_object),
+            } => Some(date_time),
             _ => None,
         }
     }
 
-    /// Checks if it an ordinary object.
+    /// Gets the `Locale` data if the object is an `Intl.Locale`.
+    #[cfg(feature = "intl")]
     #[inline]
-    pub fn is_ordinary(&self) -> bool {
-        matches!(
-            self.data,
+    pub fn as_locale(&self) -> Option<&Locale> {
+        match self.data {
             ObjectData {
-                kind: ObjectKind::Ordinary,
+                kind: ObjectKind::Locale(ref locale),
                 ..
-            }
-        )
+            } => Some(locale),
+            _ => None,
+        }
     }
 
-    /// Checks if it's an proxy object.
+    /// Gets the `PluralRules` data if the object is an `Intl.PluralRules`.
+    #[cfg(feature = "intl")]
     #[inline]
-    pub fn is_proxy(&self) -> bool {
-        matches!(
-            self.data,
+    pub fn as_plural_rules(&self) -> Option<&PluralRules> {
+        match self.data {
             ObjectData {
-                kind: ObjectKind::Proxy(_),
+                kind: ObjectKind::PluralRules(ref plural_rules),
                 ..
-            }
-        )
+            } => Some(plural_rules),
+            _ => None,
+        }
     }
 
+    /// Gets the `Segmenter` data if the object is an `Intl.Segmenter`.
+    #[cfg(feature = "intl")]
     #[inline]
-    pub fn as_proxy(&self) -> Option<&Proxy> {
+    pub fn as_segmenter(&self) -> Option<&Segmenter> {
         match self.data {
             ObjectData {
-                kind: ObjectKind::Proxy(ref proxy),
+                kind: ObjectKind::Segmenter(ref segmenter),
+                ..
+            } => Some(segmenter),
+            _ => None,
+        }
+    }
+
+    /// Gets the `Segments` data if the object is a `Segments` object.
+    #[cfg(feature = "intl")]
+    #[inline]
+    pub fn as_segments(&self) -> Option<&Segments> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Segments(ref segments),
+                ..
+            } => Some(segments),
+            _ => None,
+        }
+    }
+
+    /// Gets the mutable `SegmentIterator` data if the object is a `%SegmentIterator%`.
+    #[cfg(feature = "intl")]
+    #[inline]
+    pub fn as_segment_iterator_mut(&mut self) -> Option<&mut SegmentIterator> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::SegmentIterator(ref mut iter),
+                ..
+            } => Some(iter),
+            _ => None,
+        }
+    }
+
+    /// Checks if it a `RegExp` object.
+    #[inline]
+    pub fn is_regexp(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::RegExp(_),
+                ..
+            }
+        )
+    }
+
+    /// Gets the regexp data if the object is a regexp.
+    #[inline]
+    pub fn as_regexp(&self) -> Option<&RegExp> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::RegExp(ref regexp),
+                ..
+            } => Some(regexp),
+            _ => None,
+        }
+    }
+
+    /// Checks if it a `TypedArray` object.
+    #[inline]
+    pub fn is_typed_array(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::IntegerIndexed(_),
+                ..
+            }
+        )
+    }
+
+    /// Checks if it is a `DataView` object.
+    #[inline]
+    pub fn is_data_view(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::DataView(_),
+                ..
+            }
+        )
+    }
+
+    #[inline]
+    pub fn as_data_view(&self) -> Option<&DataView> {
+        match &self.data {
+            ObjectData {
+                kind: ObjectKind::DataView(data_view),
+                ..
+            } => Some(data_view),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_data_view_mut(&mut self) -> Option<&mut DataView> {
+        match &mut self.data {
+            ObjectData {
+                kind: ObjectKind::DataView(data_view),
+                ..
+            } => Some(data_view),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `DisposableStack` object.
+    #[inline]
+    pub fn is_disposable_stack(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::DisposableStack(_),
+                ..
+            }
+        )
+    }
+
+    /// Checks if it is an `AsyncDisposableStack` object.
+    #[inline]
+    pub fn is_async_disposable_stack(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::AsyncDisposableStack(_),
+                ..
+            }
+        )
+    }
+
+    /// Gets a mutable reference to the internal slots of a `DisposableStack` or an
+    /// `AsyncDisposableStack` object.
+    #[inline]
+    pub fn as_disposable_stack_mut(&mut self) -> Option<&mut DisposableStack> {
+        match &mut self.data {
+            ObjectData {
+                kind: ObjectKind::DisposableStack(stack) | ObjectKind::AsyncDisposableStack(stack),
+                ..
+            } => Some(stack),
+            _ => None,
+        }
+    }
+
+    /// Gets the weak reference to the target, if it is a `WeakRef` object.
+    #[inline]
+    pub fn as_weak_ref(&self) -> Option<&WeakTarget> {
+        match &self.data {
+            ObjectData {
+                kind: ObjectKind::WeakRef(target),
+                ..
+            } => Some(target),
+            _ => None,
+        }
+    }
+
+    /// Gets the realm of a `ShadowRealm` object.
+    #[inline]
+    pub fn as_shadow_realm(&self) -> Option<&JsRealm> {
+        match &self.data {
+            ObjectData {
+                kind: ObjectKind::ShadowRealm(realm),
+                ..
+            } => Some(realm),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `FinalizationRegistry` object.
+    #[inline]
+    pub fn is_finalization_registry(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::FinalizationRegistry(_),
+                ..
+            }
+        )
+    }
+
+    #[inline]
+    pub fn as_finalization_registry(&self) -> Option<&FinalizationRegistry> {
+        match &self.data {
+            ObjectData {
+                kind: ObjectKind::FinalizationRegistry(registry),
+                ..
+            } => Some(registry),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_finalization_registry_mut(&mut self) -> Option<&mut FinalizationRegistry> {
+        match &mut self.data {
+            ObjectData {
+                kind: ObjectKind::FinalizationRegistry(registry),
+                ..
+            } => Some(registry),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `WeakMap` object.
+    #[inline]
+    pub fn is_weak_map(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::WeakMap(_),
+                ..
+            }
+        )
+    }
+
+    #[inline]
+    pub fn as_weak_map(&self) -> Option<&WeakMap> {
+        match &self.data {
+            ObjectData {
+                kind: ObjectKind::WeakMap(map),
+                ..
+            } => Some(map),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_weak_map_mut(&mut self) -> Option<&mut WeakMap> {
+        match &mut self.data {
+            ObjectData {
+                kind: ObjectKind::WeakMap(map),
+                ..
+            } => Some(map),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `Promise` object.
+    #[inline]
+    pub fn is_promise(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::Promise(_),
+                ..
+            }
+        )
+    }
+
+    /// Gets the promise data if the object is a `Promise`.
+    #[inline]
+    pub fn as_promise(&self) -> Option<&Promise> {
+        match &self.data {
+            ObjectData {
+                kind: ObjectKind::Promise(promise),
+                ..
+            } => Some(promise),
+            _ => None,
+        }
+    }
+
+    /// Gets the mutable promise data if the object is a `Promise`.
+    #[inline]
+    pub fn as_promise_mut(&mut self) -> Option<&mut Promise> {
+        match &mut self.data {
+            ObjectData {
+                kind: ObjectKind::Promise(promise),
+                ..
+            } => Some(promise),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a raw JSON object, created by `JSON.rawJSON`.
+    #[inline]
+    pub fn is_raw_json(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::RawJson,
+                ..
+            }
+        )
+    }
+
+    /// Checks if it is an `Arguments` object.
+    #[inline]
+    pub fn is_arguments(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::Arguments(_),
+                ..
+            }
+        )
+    }
+
+    /// Gets the mapped arguments data if this is a mapped arguments object.
+    #[inline]
+    pub fn as_mapped_arguments(&self) -> Option<&ParameterMap> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Arguments(Arguments::Mapped(ref args)),
+                ..
+            } => Some(args),
+            _ => None,
+        }
+    }
+
+    /// Gets the mutable mapped arguments data if this is a mapped arguments object.
+    #[inline]
+    pub fn as_mapped_arguments_mut(&mut self) -> Option<&mut ParameterMap> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Arguments(Arguments::Mapped(ref mut args)),
+                ..
+            } => Some(args),
+            _ => None,
+        }
+    }
+
+    /// Gets the typed array data (integer indexed object) if this is a typed array.
+    #[inline]
+    pub fn as_typed_array(&self) -> Option<&IntegerIndexed> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::IntegerIndexed(ref integer_indexed_object),
+                ..
+            } => Some(integer_indexed_object),
+            _ => None,
+        }
+    }
+
+    /// Gets the typed array data (integer indexed object) if this is a typed array.
+    #[inline]
+    pub fn as_typed_array_mut(&mut self) -> Option<&mut IntegerIndexed> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::IntegerIndexed(ref mut integer_indexed_object),
+                ..
+            } => Some(integer_indexed_object),
+            _ => None,
+        }
+    }
+
+    /// Checks if it an ordinary object.
+    #[inline]
+    pub fn is_ordinary(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::Ordinary,
+                ..
+            }
+        )
+    }
+
+    /// Checks if it's an proxy object.
+    #[inline]
+    pub fn is_proxy(&self) -> bool {
+        matches!(
+            self.data,
+            ObjectData {
+                kind: ObjectKind::Proxy(_),
+                ..
+            }
+        )
+    }
+
+    #[inline]
+    pub fn as_proxy(&self) -> Option<&Proxy> {
+        match self.data {
+            ObjectData {
+                kind: ObjectKind::Proxy(ref proxy),
                 ..
             } => Some(proxy),
             _ => None,
@@ -1118,6 +1890,7 @@ impl Object {
         let prototype = prototype.into();
         if self.extensible {
             self.prototype = prototype;
+            self.properties.invalidate_layout();
             true
         } else {
             // If target is non-extensible, [[SetPrototypeOf]] must return false
@@ -1201,6 +1974,72 @@ impl Object {
         &self.properties
     }
 
+    #[inline]
+    pub(crate) fn properties_mut(&mut self) -> &mut PropertyMap {
+        &mut self.properties
+    }
+
+    /// Returns `true` if new properties can be added to the object.
+    #[inline]
+    pub(crate) fn extensible(&self) -> bool {
+        self.extensible
+    }
+
+    /// Returns the identifier of the layout of the properties and the prototype of the object, or
+    /// `None` if its keys can't be cached because `[[OwnPropertyKeys]]` or `[[GetOwnProperty]]`
+    /// are not the ordinary ones.
+    #[inline]
+    pub(crate) fn enumeration_layout(&self) -> Option<u64> {
+        let methods = self.data.internal_methods;
+        [
+            &ORDINARY_INTERNAL_METHODS,
+            &ARRAY_EXOTIC_INTERNAL_METHODS,
+            &FUNCTION_INTERNAL_METHODS,
+            &CONSTRUCTOR_INTERNAL_METHODS,
+        ]
+        .into_iter()
+        .any(|ordinary| std::ptr::eq(methods, ordinary))
+        .then(|| self.properties.layout())
+    }
+
+    /// Registers a callback that runs when the object is collected, after the finalizers already
+    /// registered on the object.
+    ///
+    /// The callback receives the object, so it can release the resources of its native data. See
+    /// [`NativeFinalizer`] for the order of the finalization.
+    #[inline]
+    pub fn add_finalizer<F>(&mut self, finalizer: F)
+    where
+        F: FnOnce(&Self) + 'static,
+    {
+        self.finalizers.push(Box::new(finalizer));
+    }
+
+    /// Returns the values associated with the object by the `WeakMap` objects it is a key of.
+    #[inline]
+    pub(crate) fn ephemerons(&self) -> &EphemeronTable<JsValue> {
+        &self.ephemerons
+    }
+
+    /// Returns the values associated with the object by the `WeakMap` objects it is a key of,
+    /// mutably.
+    #[inline]
+    pub(crate) fn ephemerons_mut(&mut self) -> &mut EphemeronTable<JsValue> {
+        &mut self.ephemerons
+    }
+
+    /// Returns the keys of the last `for-in` loop over the object.
+    #[inline]
+    pub(crate) fn enumeration_cache(&self) -> Option<&Rc<EnumerationCache>> {
+        self.enumeration_cache.as_ref()
+    }
+
+    /// Sets the keys of the last `for-in` loop over the object.
+    #[inline]
+    pub(crate) fn set_enumeration_cache(&mut self, cache: Rc<EnumerationCache>) {
+        self.enumeration_cache = Some(cache);
+    }
+
     /// Inserts a field in the object `properties` without checking if it's writable.
     ///
     /// If a field was already in the object with the same name, then a `Some` is returned
@@ -1236,8 +2075,8 @@ impl Object {
 /// and the `N` is the function name.
 #[derive(Debug, Clone)]
 pub struct FunctionBinding {
-    binding: PropertyKey,
-    name: JsString,
+    pub(crate) binding: PropertyKey,
+    pub(crate) name: JsString,
 }
 
 impl From<&str> for FunctionBinding {
@@ -1364,6 +2203,35 @@ impl<'context> FunctionBuilder<'context> {
         }
     }
 
+    /// Create a new function from a closure with typed arguments, which are converted from the
+    /// arguments of the call with [`TryFromJs`](crate::value::TryFromJs).
+    ///
+    /// Unlike [`FunctionBuilder::closure`], the closure doesn't need to be `Copy`. Its length is
+    /// the number of its arguments. See [`IntoJsFunction`] for the conversions.
+    #[inline]
+    pub fn typed<F, Args>(context: &'context mut Context, function: F) -> Self
+    where
+        F: IntoJsFunction<Args>,
+        Args: 'static,
+    {
+        Self {
+            context,
+            function: Function::Closure {
+                function: Box::new(|_, args, captures: Captures, context| {
+                    let captures = captures.as_any();
+                    let function = captures
+                        .downcast_ref::<typed_function::TypedFunction<F>>()
+                        .expect("the captures of a typed function hold the function");
+                    function.0.call_with_args(args, context)
+                }),
+                constructor: false,
+                captures: Captures::new(typed_function::TypedFunction(function)),
+            },
+            name: JsString::default(),
+            length: F::LENGTH,
+        }
+    }
+
     /// Specify the name property of object function object.
     ///
     /// The default is `""` (empty string).
@@ -1499,6 +2367,33 @@ impl<'context> ObjectInitializer<'context> {
         Self { context, object }
     }
 
+    /// Create a new `ObjectBuilder` for an object holding the native data `data`, with
+    /// `Object.prototype` as its prototype.
+    #[inline]
+    pub fn with_native<T>(data: T, context: &'context mut Context) -> Self
+    where
+        T: NativeObject,
+    {
+        let object = JsObject::from_proto_and_data(
+            context.intrinsics().constructors().object().prototype(),
+            ObjectData::native_object(Box::new(data)),
+        );
+        Self { context, object }
+    }
+
+    /// Register a callback that runs when the object is collected.
+    ///
+    /// The finalizers run in the order they were registered, before the native data of the object
+    /// is finalized and dropped.
+    #[inline]
+    pub fn finalizer<F>(&mut self, finalizer: F) -> &mut Self
+    where
+        F: FnOnce(&Object) + 'static,
+    {
+        self.object.borrow_mut().add_finalizer(finalizer);
+        self
+    }
+
     /// Add a function to the object.
     #[inline]
     pub fn function<B>(
@@ -1544,6 +2439,67 @@ impl<'context> ObjectInitializer<'context> {
         self
     }
 
+    /// Add an accessor property to the object.
+    #[inline]
+    pub fn accessor<K>(
+        &mut self,
+        key: K,
+        get: Option<JsObject>,
+        set: Option<JsObject>,
+        attribute: Attribute,
+    ) -> &mut Self
+    where
+        K: Into<PropertyKey>,
+    {
+        let property = PropertyDescriptor::builder()
+            .maybe_get(get)
+            .maybe_set(set)
+            .enumerable(attribute.enumerable())
+            .configurable(attribute.configurable());
+        self.object.borrow_mut().insert(key, property);
+        self
+    }
+
+    /// Add a property holding a new object, whose properties are added by `init`.
+    ///
+    /// ```
+    /// # use boa_engine::{Context, object::ObjectInitializer, property::Attribute};
+    /// let mut context = Context::default();
+    /// let object = ObjectInitializer::new(&mut context)
+    ///     .object(
+    ///         "point",
+    ///         |point| {
+    ///             point
+    ///                 .property("x", 1, Attribute::all())
+    ///                 .property("y", 2, Attribute::all());
+    ///         },
+    ///         Attribute::all(),
+    ///     )
+    ///     .build();
+    /// ```
+    #[inline]
+    pub fn object<K, F>(&mut self, key: K, init: F, attribute: Attribute) -> &mut Self
+    where
+        K: Into<PropertyKey>,
+        F: FnOnce(&mut ObjectInitializer<'_>),
+    {
+        let mut initializer = ObjectInitializer::new(self.context);
+        init(&mut initializer);
+        let object = initializer.build();
+        self.property(key, object, attribute)
+    }
+
+    /// Add a property holding a new array of `elements`.
+    #[inline]
+    pub fn array<K, I>(&mut self, key: K, elements: I, attribute: Attribute) -> &mut Self
+    where
+        K: Into<PropertyKey>,
+        I: IntoIterator<Item = JsValue>,
+    {
+        let array = JsArray::from_iter(elements, self.context);
+        self.property(key, array, attribute)
+    }
+
     /// Build the object.
     #[inline]
     pub fn build(&mut self) -> JsObject {
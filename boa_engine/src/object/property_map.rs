@@ -3,7 +3,18 @@ use crate::{JsString, JsSymbol};
 use boa_gc::{custom_trace, Finalize, Trace};
 use indexmap::IndexMap;
 use rustc_hash::{FxHashMap, FxHasher};
-use std::{collections::hash_map, hash::BuildHasherDefault, iter::FusedIterator};
+use std::{
+    cell::Cell,
+    cmp::Ordering,
+    collections::hash_map,
+    hash::BuildHasherDefault,
+    iter::FusedIterator,
+    slice,
+    sync::atomic::{AtomicU64, Ordering as AtomicOrdering},
+};
+
+/// The next identifier of a property layout.
+static NEXT_LAYOUT: AtomicU64 = AtomicU64::new(1);
 
 /// Type alias to make it easier to work with the string properties on the global object.
 pub(crate) type GlobalPropertyMap =
@@ -28,13 +39,113 @@ unsafe impl<K: Trace> Trace for OrderedHashMap<K> {
     });
 }
 
+/// The storage of the indexed properties of an object.
+///
+/// Objects start with packed storage, which holds the properties `0..len` without any holes in a
+/// vector. Creating a hole, either by adding a property past the end or by removing a property
+/// that is not the last one, transitions the storage to the dictionary mode, where the properties
+/// are kept in a hash map.
+#[derive(Debug, Trace, Finalize)]
+enum IndexedProperties {
+    /// Properties `0..len`, without holes.
+    Packed(Vec<PropertyDescriptor>),
+
+    /// Arbitrary indexed properties.
+    Dictionary(FxHashMap<u32, PropertyDescriptor>),
+}
+
+impl Default for IndexedProperties {
+    fn default() -> Self {
+        Self::Packed(Vec::new())
+    }
+}
+
+impl IndexedProperties {
+    fn get(&self, index: u32) -> Option<&PropertyDescriptor> {
+        match self {
+            Self::Packed(vec) => vec.get(index as usize),
+            Self::Dictionary(map) => map.get(&index),
+        }
+    }
+
+    fn insert(&mut self, index: u32, property: PropertyDescriptor) -> Option<PropertyDescriptor> {
+        if let Self::Packed(vec) = self {
+            let len = vec.len();
+            match (index as usize).cmp(&len) {
+                Ordering::Less => {
+                    return Some(std::mem::replace(&mut vec[index as usize], property))
+                }
+                Ordering::Equal => {
+                    vec.push(property);
+                    return None;
+                }
+                // Inserting past the end creates a hole.
+                Ordering::Greater => self.convert_to_dictionary(),
+            }
+        }
+
+        match self {
+            Self::Dictionary(map) => map.insert(index, property),
+            Self::Packed(_) => unreachable!("the storage was converted to a dictionary"),
+        }
+    }
+
+    fn remove(&mut self, index: u32) -> Option<PropertyDescriptor> {
+        if let Self::Packed(vec) = self {
+            let index = index as usize;
+            if index + 1 == vec.len() {
+                return vec.pop();
+            } else if index >= vec.len() {
+                return None;
+            }
+
+            // Removing any other property creates a hole.
+            self.convert_to_dictionary();
+        }
+
+        match self {
+            Self::Dictionary(map) => map.remove(&index),
+            Self::Packed(_) => unreachable!("the storage was converted to a dictionary"),
+        }
+    }
+
+    fn contains_key(&self, index: u32) -> bool {
+        match self {
+            Self::Packed(vec) => (index as usize) < vec.len(),
+            Self::Dictionary(map) => map.contains_key(&index),
+        }
+    }
+
+    fn iter(&self) -> IndexProperties<'_> {
+        match self {
+            Self::Packed(vec) => IndexProperties::Packed(vec.iter().enumerate()),
+            Self::Dictionary(map) => IndexProperties::Dictionary(map.iter()),
+        }
+    }
+
+    /// Moves the packed properties to a hash map.
+    fn convert_to_dictionary(&mut self) {
+        if let Self::Packed(vec) = self {
+            let map = std::mem::take(vec)
+                .into_iter()
+                .enumerate()
+                .map(|(index, property)| (index as u32, property))
+                .collect();
+            *self = Self::Dictionary(map);
+        }
+    }
+}
+
 #[derive(Default, Debug, Trace, Finalize)]
 pub struct PropertyMap {
-    indexed_properties: FxHashMap<u32, PropertyDescriptor>,
+    indexed_properties: IndexedProperties,
     /// Properties
     string_properties: OrderedHashMap<JsString>,
     /// Symbol Properties
     symbol_properties: OrderedHashMap<JsSymbol>,
+    /// The identifier of the current layout, or `0` if it wasn't assigned yet.
+    #[unsafe_ignore_trace]
+    layout: Cell<u64>,
 }
 
 impl PropertyMap {
@@ -43,7 +154,7 @@ impl PropertyMap {
     }
     pub fn get(&self, key: &PropertyKey) -> Option<&PropertyDescriptor> {
         match key {
-            PropertyKey::Index(index) => self.indexed_properties.get(index),
+            PropertyKey::Index(index) => self.indexed_properties.get(*index),
             PropertyKey::String(string) => self.string_properties.0.get(string),
             PropertyKey::Symbol(symbol) => self.symbol_properties.0.get(symbol),
         }
@@ -54,7 +165,8 @@ impl PropertyMap {
         key: &PropertyKey,
         property: PropertyDescriptor,
     ) -> Option<PropertyDescriptor> {
-        match &key {
+        let enumerable = property.enumerable();
+        let old = match &key {
             PropertyKey::Index(index) => self.indexed_properties.insert(*index, property),
             PropertyKey::String(string) => {
                 self.string_properties.0.insert(string.clone(), property)
@@ -62,15 +174,66 @@ impl PropertyMap {
             PropertyKey::Symbol(symbol) => {
                 self.symbol_properties.0.insert(symbol.clone(), property)
             }
+        };
+        if old
+            .as_ref()
+            .map_or(true, |old| old.enumerable() != enumerable)
+        {
+            self.invalidate_layout();
+        }
+        old
+    }
+
+    /// Returns an identifier of the keys of the map and of their enumerability.
+    ///
+    /// The identifier is unique among all the maps, and changes when a property is added or
+    /// removed, or when a property becomes enumerable or non-enumerable.
+    #[inline]
+    pub(crate) fn layout(&self) -> u64 {
+        if self.layout.get() == 0 {
+            self.layout
+                .set(NEXT_LAYOUT.fetch_add(1, AtomicOrdering::Relaxed));
+        }
+        self.layout.get()
+    }
+
+    /// Gives the map a new layout identifier.
+    #[inline]
+    pub(crate) fn invalidate_layout(&mut self) {
+        self.layout.set(0);
+    }
+
+    /// Returns the packed indexed properties `0..len` of the object, or `None` if the indexed
+    /// properties are stored in dictionary mode.
+    #[inline]
+    pub(crate) fn packed_index_properties(&self) -> Option<&[PropertyDescriptor]> {
+        match &self.indexed_properties {
+            IndexedProperties::Packed(vec) => Some(vec),
+            IndexedProperties::Dictionary(_) => None,
+        }
+    }
+
+    /// Returns a mutable reference to the packed indexed properties `0..len` of the object, or
+    /// `None` if the indexed properties are stored in dictionary mode.
+    #[inline]
+    pub(crate) fn packed_index_properties_mut(&mut self) -> Option<&mut Vec<PropertyDescriptor>> {
+        self.invalidate_layout();
+        match &mut self.indexed_properties {
+            IndexedProperties::Packed(vec) => Some(vec),
+            IndexedProperties::Dictionary(_) => None,
         }
     }
 
     pub fn remove(&mut self, key: &PropertyKey) -> Option<PropertyDescriptor> {
-        match key {
-            PropertyKey::Index(index) => self.indexed_properties.remove(index),
+        let old = match key {
+            PropertyKey::Index(index) => self.indexed_properties.remove(*index),
             PropertyKey::String(string) => self.string_properties.0.shift_remove(string),
             PropertyKey::Symbol(symbol) => self.symbol_properties.0.shift_remove(symbol),
+        };
+        if old.is_some() {
+            self.invalidate_layout();
         }
+        old
     }
 
     /// An iterator visiting all key-value pairs in arbitrary order. The iterator element type is `(PropertyKey, &'a Property)`.
@@ -126,20 +289,20 @@ impl PropertyMap {
         SymbolPropertyValues(self.symbol_properties.0.values())
     }
 
-    /// An iterator visiting all indexed key-value pairs in arbitrary order. The iterator element type is `(&'a u32, &'a Property)`.
+    /// An iterator visiting all indexed key-value pairs in arbitrary order. The iterator element type is `(u32, &'a Property)`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
     pub fn index_properties(&self) -> IndexProperties<'_> {
-        IndexProperties(self.indexed_properties.iter())
+        self.indexed_properties.iter()
     }
 
-    /// An iterator visiting all index keys in arbitrary order. The iterator element type is `&'a u32`.
+    /// An iterator visiting all index keys in arbitrary order. The iterator element type is `u32`.
     ///
     /// This iterator does not recurse down the prototype chain.
     #[inline]
     pub fn index_property_keys(&self) -> IndexPropertyKeys<'_> {
-        IndexPropertyKeys(self.indexed_properties.keys())
+        IndexPropertyKeys(self.indexed_properties.iter())
     }
 
     /// An iterator visiting all index values in arbitrary order. The iterator element type is `&'a Property`.
@@ -147,7 +310,7 @@ impl PropertyMap {
     /// This iterator does not recurse down the prototype chain.
     #[inline]
     pub fn index_property_values(&self) -> IndexPropertyValues<'_> {
-        IndexPropertyValues(self.indexed_properties.values())
+        IndexPropertyValues(self.indexed_properties.iter())
     }
 
     /// An iterator visiting all string key-value pairs in arbitrary order. The iterator element type is `(&'a RcString, &'a Property)`.
@@ -177,7 +340,7 @@ impl PropertyMap {
     #[inline]
     pub fn contains_key(&self, key: &PropertyKey) -> bool {
         match key {
-            PropertyKey::Index(index) => self.indexed_properties.contains_key(index),
+            PropertyKey::Index(index) => self.indexed_properties.contains_key(*index),
             PropertyKey::String(string) => self.string_properties.0.contains_key(string),
             PropertyKey::Symbol(symbol) => self.symbol_properties.0.contains_key(symbol),
         }
@@ -190,6 +353,7 @@ impl PropertyMap {
 
     #[inline]
     pub(crate) fn string_property_map_mut(&mut self) -> &mut GlobalPropertyMap {
+        self.invalidate_layout();
         &mut self.string_properties.0
     }
 }
@@ -197,7 +361,7 @@ impl PropertyMap {
 /// An iterator over the property entries of an `Object`
 #[derive(Debug, Clone)]
 pub struct Iter<'a> {
-    indexed_properties: hash_map::Iter<'a, u32, PropertyDescriptor>,
+    indexed_properties: IndexProperties<'a>,
     string_properties: indexmap::map::Iter<'a, JsString, PropertyDescriptor>,
     symbol_properties: indexmap::map::Iter<'a, JsSymbol, PropertyDescriptor>,
 }
@@ -206,7 +370,7 @@ impl<'a> Iterator for Iter<'a> {
     type Item = (PropertyKey, &'a PropertyDescriptor);
     fn next(&mut self) -> Option<Self::Item> {
         if let Some((key, value)) = self.indexed_properties.next() {
-            Some(((*key).into(), value))
+            Some((key.into(), value))
         } else if let Some((key, value)) = self.string_properties.next() {
             Some((key.clone().into(), value))
         } else {
@@ -350,26 +514,38 @@ impl FusedIterator for SymbolPropertyValues<'_> {}
 
 /// An iterator over the indexed property entries of an `Object`
 #[derive(Debug, Clone)]
-pub struct IndexProperties<'a>(hash_map::Iter<'a, u32, PropertyDescriptor>);
+pub enum IndexProperties<'a> {
+    Packed(std::iter::Enumerate<slice::Iter<'a, PropertyDescriptor>>),
+    Dictionary(hash_map::Iter<'a, u32, PropertyDescriptor>),
+}
 
 impl<'a> Iterator for IndexProperties<'a> {
-    type Item = (&'a u32, &'a PropertyDescriptor);
+    type Item = (u32, &'a PropertyDescriptor);
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.next()
+        match self {
+            Self::Packed(iter) => iter.next().map(|(index, value)| (index as u32, value)),
+            Self::Dictionary(iter) => iter.next().map(|(index, value)| (*index, value)),
+        }
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.0.size_hint()
+        match self {
+            Self::Packed(iter) => iter.size_hint(),
+            Self::Dictionary(iter) => iter.size_hint(),
+        }
     }
 }
 
 impl ExactSizeIterator for IndexProperties<'_> {
     #[inline]
     fn len(&self) -> usize {
-        self.0.len()
+        match self {
+            Self::Packed(iter) => iter.len(),
+            Self::Dictionary(iter) => iter.len(),
+        }
     }
 }
 
@@ -377,14 +553,14 @@ impl FusedIterator for IndexProperties<'_> {}
 
 /// An iterator over the index keys (`u32`) of an `Object`.
 #[derive(Debug, Clone)]
-pub struct IndexPropertyKeys<'a>(hash_map::Keys<'a, u32, PropertyDescriptor>);
+pub struct IndexPropertyKeys<'a>(IndexProperties<'a>);
 
 impl<'a> Iterator for IndexPropertyKeys<'a> {
-    type Item = &'a u32;
+    type Item = u32;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.next()
+        self.0.next().map(|(key, _)| key)
     }
 
     #[inline]
@@ -404,14 +580,14 @@ impl FusedIterator for IndexPropertyKeys<'_> {}
 
 /// An iterator over the index values (`Property`) of an `Object`.
 #[derive(Debug, Clone)]
-pub struct IndexPropertyValues<'a>(hash_map::Values<'a, u32, PropertyDescriptor>);
+pub struct IndexPropertyValues<'a>(IndexProperties<'a>);
 
 impl<'a> Iterator for IndexPropertyValues<'a> {
     type Item = &'a PropertyDescriptor;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        self.0.next()
+        self.0.next().map(|(_, value)| value)
     }
 
     #[inline]
@@ -44,3 +44,116 @@ fn object_properties_return_order() {
         ),
     ]);
 }
+
+#[test]
+fn native_object_finalizers() {
+    use crate::{
+        object::{JsObject, ObjectInitializer},
+        Context,
+    };
+    use boa_gc::{Finalize, Trace};
+    use std::{cell::RefCell, rc::Rc};
+
+    #[derive(Debug)]
+    struct Handle(Rc<RefCell<Vec<&'static str>>>);
+
+    impl Drop for Handle {
+        fn drop(&mut self) {
+            self.0.borrow_mut().push("drop");
+        }
+    }
+
+    #[derive(Debug, Trace)]
+    struct Resource {
+        #[unsafe_ignore_trace]
+        handle: Handle,
+    }
+
+    impl Finalize for Resource {
+        fn finalize(&self) {
+            self.handle.0.borrow_mut().push("finalize");
+        }
+    }
+
+    let mut context = Context::default();
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let first = log.clone();
+    let second = log.clone();
+    let object: JsObject = ObjectInitializer::with_native(
+        Resource {
+            handle: Handle(log.clone()),
+        },
+        &mut context,
+    )
+    .finalizer(move |object| {
+        assert!(object.is::<Resource>());
+        first.borrow_mut().push("first");
+    })
+    .finalizer(move |_| second.borrow_mut().push("second"))
+    .build();
+
+    boa_gc::force_collect();
+    assert!(log.borrow().is_empty());
+
+    drop(object);
+    boa_gc::force_collect();
+    assert_eq!(*log.borrow(), ["first", "second", "finalize", "drop"]);
+}
+
+#[test]
+fn literal_macros() {
+    use crate::{forward, js_array, js_object, property::Attribute, Context, JsSymbol};
+
+    let mut context = Context::default();
+    let symbol = JsSymbol::new(Some("key".into()));
+    let object = js_object!(&mut context, {
+        name: "boa",
+        "with space": 1,
+        2: true,
+        [symbol.clone()]: "symbol",
+        list: js_array!(&mut context, [1, js_object!(&mut context, { x: 1.5 })]),
+    });
+    context.register_global_property("object", object, Attribute::all());
+    context.register_global_property("symbol", symbol, Attribute::all());
+    assert_eq!(
+        forward(
+            &mut context,
+            "[Object.keys(object).join(), object[symbol], object.list[1].x]"
+        ),
+        "[ \"2,name,with space,list\", \"symbol\", 1.5 ]"
+    );
+}
+
+#[test]
+fn object_initializer_nested() {
+    use crate::{
+        forward,
+        object::{FunctionBuilder, ObjectInitializer},
+        property::Attribute,
+        Context,
+    };
+
+    let mut context = Context::default();
+    let getter = FunctionBuilder::native(&mut context, |_, _, _| Ok(42.into()))
+        .name("get answer")
+        .build();
+    let object = ObjectInitializer::new(&mut context)
+        .accessor("answer", Some(getter), None, Attribute::all())
+        .object(
+            "point",
+            |point| {
+                point.property("x", 1, Attribute::all());
+            },
+            Attribute::all(),
+        )
+        .array("list", [1.into(), 2.into()], Attribute::all())
+        .build();
+    context.register_global_property("object", object, Attribute::all());
+    assert_eq!(
+        forward(
+            &mut context,
+            "[object.answer, object.point.x, object.list.length]"
+        ),
+        "[ 42, 1, 2 ]"
+    );
+}
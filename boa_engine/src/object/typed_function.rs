@@ -0,0 +1,143 @@
+//! Native functions built from Rust closures with typed signatures.
+//!
+//! A closure like `Fn(String, f64) -> Result<Vec<u8>, MyError>` can be turned into a function
+//! object with [`FunctionBuilder::typed`](super::FunctionBuilder::typed) or registered as a global
+//! with [`Context::register_global_typed_function`]. The arguments are converted with
+//! [`TryFromJs`], a missing argument being `undefined`, and a conversion that fails throws a
+//! `TypeError` before the closure runs. The return value is converted with [`TryIntoJs`], and the
+//! error of a returned `Err` is thrown as an `Error` object converted with
+//! [`JsError::from_std_error`], so the sources of the error become its `cause` chain.
+//!
+//! ```
+//! use boa_engine::{Context, JsValue};
+//!
+//! let mut context = Context::default();
+//! context.register_global_typed_function("repeat", |text: String, count: Option<u32>| {
+//!     match count {
+//!         Some(count) if count > 100 => Err(format!("cannot repeat {count} times")),
+//!         Some(count) => Ok(text.repeat(count as usize)),
+//!         None => Ok(text),
+//!     }
+//! });
+//!
+//! let value = context.eval("repeat('ab', 2)").unwrap();
+//! assert_eq!(value.as_string().map(|s| s.as_str()), Some("abab"));
+//! assert!(context.eval("repeat('ab', 1000)").is_err());
+//! assert!(context.eval("repeat({})").is_err());
+//! ```
+
+use crate::{
+    value::{TryFromJs, TryIntoJs},
+    Context, JsError, JsResult, JsValue,
+};
+use boa_gc::{unsafe_empty_trace, Finalize, Trace};
+use std::{error::Error, fmt};
+
+/// A Rust function whose arguments, given by the tuple `Args`, can be converted from JavaScript
+/// values.
+///
+/// It is implemented for the closures of up to eight arguments implementing [`TryFromJs`], which
+/// return a value implementing [`IntoJsResult`].
+pub trait IntoJsFunction<Args>: 'static {
+    /// The number of arguments of the function, used as the `length` of the function object.
+    const LENGTH: usize;
+
+    /// Converts `args` to the arguments of the function, calls it and converts its result.
+    fn call_with_args(&self, args: &[JsValue], context: &mut Context) -> JsResult<JsValue>;
+}
+
+/// The return value of a typed native function.
+pub trait IntoJsResult {
+    /// Converts the value to a JavaScript value, or throws.
+    fn into_js_result(self, context: &mut Context) -> JsResult<JsValue>;
+}
+
+impl<T: TryIntoJs> IntoJsResult for T {
+    #[inline]
+    fn into_js_result(self, context: &mut Context) -> JsResult<JsValue> {
+        self.try_into_js(context)
+    }
+}
+
+/// The error is thrown as an `Error` object, converted with [`JsError::from_std_error`].
+///
+/// This accepts the errors that convert to a boxed [`Error`], like strings, the types
+/// implementing `Error` and `anyhow::Error`.
+impl<T, E> IntoJsResult for Result<T, E>
+where
+    T: TryIntoJs,
+    E: Into<Box<dyn Error + Send + Sync>>,
+{
+    fn into_js_result(self, context: &mut Context) -> JsResult<JsValue> {
+        match self {
+            Ok(value) => value.try_into_js(context),
+            Err(error) => {
+                let error: Box<dyn Error + Send + Sync> = error.into();
+                Err(JsError::from_std_error(&*error, context).into_value())
+            }
+        }
+    }
+}
+
+/// The error is thrown as is.
+impl<T: TryIntoJs> IntoJsResult for JsResult<T> {
+    #[inline]
+    fn into_js_result(self, context: &mut Context) -> JsResult<JsValue> {
+        self?.try_into_js(context)
+    }
+}
+
+/// Implements `IntoJsFunction` for the closures of the given arguments.
+macro_rules! impl_into_js_function {
+    (@one $ty:ident) => {
+        1
+    };
+    ($($arg:ident : $ty:ident),*) => {
+        impl<F, R, $($ty),*> IntoJsFunction<($($ty,)*)> for F
+        where
+            F: Fn($($ty),*) -> R + 'static,
+            R: IntoJsResult,
+            $($ty: TryFromJs,)*
+        {
+            const LENGTH: usize = 0 $(+ impl_into_js_function!(@one $ty))*;
+
+            #[allow(unused_variables, unused_mut)]
+            fn call_with_args(&self, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+                let mut args = args.iter();
+                $(
+                    let $arg = $ty::try_from_js(
+                        args.next().unwrap_or(&JsValue::Undefined),
+                        context,
+                    )?;
+                )*
+                self($($arg),*).into_js_result(context)
+            }
+        }
+    };
+}
+
+impl_into_js_function!();
+impl_into_js_function!(a: A);
+impl_into_js_function!(a: A, b: B);
+impl_into_js_function!(a: A, b: B, c: C);
+impl_into_js_function!(a: A, b: B, c: C, d: D);
+impl_into_js_function!(a: A, b: B, c: C, d: D, e: E);
+impl_into_js_function!(a: A, b: B, c: C, d: D, e: E, f: G);
+impl_into_js_function!(a: A, b: B, c: C, d: D, e: E, f: G, g: H);
+impl_into_js_function!(a: A, b: B, c: C, d: D, e: E, f: G, g: H, h: I);
+
+/// The captures of a typed native function, holding the Rust closure.
+#[derive(Finalize)]
+pub(crate) struct TypedFunction<F>(pub(crate) F);
+
+impl<F> fmt::Debug for TypedFunction<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypedFunction").finish_non_exhaustive()
+    }
+}
+
+// SAFETY: The values captured by the closure aren't traced: they are kept alive until the
+// function object is collected.
+unsafe impl<F: 'static> Trace for TypedFunction<F> {
+    unsafe_empty_trace!();
+}
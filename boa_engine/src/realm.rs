@@ -5,10 +5,15 @@
 //! A realm is represented in this implementation as a Realm struct with the fields specified from the spec.
 
 use crate::{
+    context::{intrinsics::Intrinsics, HostDefined},
     environments::{CompileTimeEnvironmentStack, DeclarativeEnvironmentStack},
     object::{GlobalPropertyMap, JsObject, ObjectData, PropertyMap},
+    Context, JsResult, JsValue,
 };
+use boa_gc::{unsafe_empty_trace, Finalize, Trace};
 use boa_profiler::Profiler;
+use rustc_hash::FxHashMap;
+use std::{cell::RefCell, fmt, rc::Rc};
 
 /// Representation of a Realm.
 ///
@@ -20,6 +25,17 @@ pub struct Realm {
     pub(crate) global_property_map: PropertyMap,
     pub(crate) environments: DeclarativeEnvironmentStack,
     pub(crate) compile_env: CompileTimeEnvironmentStack,
+    /// The `[[DedentMap]]` of the realm, mapping a template's raw strings object to its dedented
+    /// template object, used by `String.dedent`.
+    pub(crate) dedent_map: Vec<(JsObject, JsObject)>,
+    /// The `[[TemplateMap]]` of the realm, mapping each tagged template call site to its
+    /// template object.
+    pub(crate) template_map: FxHashMap<u64, JsObject>,
+    /// The number of tagged template call sites compiled in this realm, used to assign them
+    /// unique identifiers.
+    template_sites: u64,
+    /// The state of the embedder stored in this realm.
+    pub(crate) host_defined: HostDefined,
 }
 
 impl Realm {
@@ -37,6 +53,10 @@ impl Realm {
             global_property_map: PropertyMap::default(),
             environments: DeclarativeEnvironmentStack::new(),
             compile_env: CompileTimeEnvironmentStack::new(),
+            dedent_map: Vec::new(),
+            template_map: FxHashMap::default(),
+            template_sites: 0,
+            host_defined: HostDefined::default(),
         }
     }
 
@@ -55,6 +75,14 @@ impl Realm {
         self.global_property_map.string_property_map_mut()
     }
 
+    /// Returns a new unique identifier for a tagged template call site.
+    #[inline]
+    pub(crate) fn next_template_site(&mut self) -> u64 {
+        let site = self.template_sites;
+        self.template_sites += 1;
+        site
+    }
+
     /// Set the number of bindings on the global environment.
     #[inline]
     pub(crate) fn set_global_binding_number(&mut self) {
@@ -62,3 +90,134 @@ impl Realm {
         self.environments.set_global_binding_number(binding_number);
     }
 }
+
+/// A handle to a realm of a [`Context`], either its initial realm, returned by
+/// [`Context::current_realm`], or one created with [`Context::create_realm`].
+///
+/// A realm has its own global object and its own intrinsics, like `Array` or `Object.prototype`,
+/// but shares the heap, the job queue and the host hooks of its context. Code runs in a realm
+/// with [`Context::with_realm`] or [`Context::eval_in_realm`], which makes it the running realm
+/// of the context until they return.
+///
+/// The functions of a realm keep the environments they were created in, but the global object
+/// and the intrinsics they use are the ones of the running realm. A function of another realm
+/// should be called with [`Context::with_realm`] to run with its own.
+#[derive(Clone)]
+pub struct JsRealm {
+    global_object: JsObject,
+    /// The realm and its intrinsics, or `None` while it is the running realm.
+    record: Rc<RefCell<Option<(Realm, Intrinsics)>>>,
+}
+
+impl JsRealm {
+    /// Creates the handle of the running realm of a context, whose global object is
+    /// `global_object`.
+    #[inline]
+    pub(crate) fn running(global_object: JsObject) -> Self {
+        Self {
+            global_object,
+            record: Rc::new(RefCell::new(None)),
+        }
+    }
+
+    /// Returns the global object of the realm.
+    #[inline]
+    pub fn global_object(&self) -> &JsObject {
+        &self.global_object
+    }
+}
+
+impl PartialEq for JsRealm {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.record, &other.record)
+    }
+}
+
+impl Eq for JsRealm {}
+
+impl fmt::Debug for JsRealm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JsRealm")
+            .field("global_object", &self.global_object)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Finalize for JsRealm {}
+
+// SAFETY: The record of a realm isn't traced, like the record of the running realm isn't. A
+// handle stored in a garbage collected value keeps the values of its realm alive for as long as
+// the handle exists.
+unsafe impl Trace for JsRealm {
+    unsafe_empty_trace!();
+}
+
+impl Context {
+    /// Returns the running realm of the context.
+    #[inline]
+    pub fn current_realm(&self) -> JsRealm {
+        self.running_realm.clone()
+    }
+
+    /// Creates a new realm with its own global object and intrinsics, sharing the heap and the
+    /// jobs of the context.
+    pub fn create_realm(&mut self) -> JsRealm {
+        let _timer = Profiler::global().start_event("Context::create_realm", "realm");
+        let mut realm = Realm::create();
+        let mut intrinsics = Intrinsics::default();
+        self.swap_realm(&mut realm, &mut intrinsics);
+        self.create_intrinsics();
+        self.swap_realm(&mut realm, &mut intrinsics);
+
+        JsRealm {
+            global_object: realm.global_object().clone(),
+            record: Rc::new(RefCell::new(Some((realm, intrinsics)))),
+        }
+    }
+
+    /// Runs `f` with `realm` as the running realm, and restores the previous realm when `f`
+    /// returns.
+    ///
+    /// Calls can be nested, and entering the running realm again just calls `f`.
+    pub fn with_realm<F, T>(&mut self, realm: &JsRealm, f: F) -> T
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        if *realm == self.running_realm {
+            return f(self);
+        }
+
+        let (mut record, mut intrinsics) = realm
+            .record
+            .borrow_mut()
+            .take()
+            .expect("only the running realm has no record");
+        self.swap_realm(&mut record, &mut intrinsics);
+        let previous = std::mem::replace(&mut self.running_realm, realm.clone());
+        *previous.record.borrow_mut() = Some((record, intrinsics));
+
+        let result = f(self);
+
+        let (mut record, mut intrinsics) = previous
+            .record
+            .borrow_mut()
+            .take()
+            .expect("the previous realm was parked by this call");
+        self.swap_realm(&mut record, &mut intrinsics);
+        self.running_realm = previous;
+        *realm.record.borrow_mut() = Some((record, intrinsics));
+        result
+    }
+
+    /// Evaluates the source code of a script in `realm`.
+    ///
+    /// The global declarations of the script are made in the global environment of `realm`, and
+    /// the pending jobs run once the script finishes, like with [`Context::eval`].
+    pub fn eval_in_realm<S>(&mut self, realm: &JsRealm, src: S) -> JsResult<JsValue>
+    where
+        S: AsRef<[u8]>,
+    {
+        self.with_realm(realm, |context| context.eval(src))
+    }
+}
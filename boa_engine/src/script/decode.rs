@@ -0,0 +1,312 @@
+//! Decoding of serialized scripts.
+//!
+//! Decoding only checks that the bytes are well formed, the bytecode of the decoded code blocks
+//! is checked by the verifier.
+
+use super::{ScriptError, FORMAT_VERSION, MAGIC};
+use crate::{
+    builtins::function::ThisMode,
+    environments::{BindingLocator, GlobalDeclaration},
+    syntax::ast::{
+        node::{FormalParameter, FormalParameterList, FormalParameterListFlags},
+        Position, Span,
+    },
+    vm::{CodeBlock, JumpTable, JumpTableKey},
+    JsBigInt, JsString, JsValue,
+};
+use boa_interner::{Interner, Sym};
+
+/// The maximum nesting of the functions of a script, which bounds the recursion of decoding and
+/// verifying it.
+const MAX_NESTING: usize = 512;
+
+/// A code block decoded from a serialized script, whose bindings are not yet linked to a realm.
+#[derive(Debug)]
+pub(super) struct DecodedCodeBlock {
+    /// The code block, without its bindings and functions.
+    pub(super) code: CodeBlock,
+    pub(super) bindings: Vec<DecodedBinding>,
+    pub(super) arguments_binding: Option<DecodedBinding>,
+    pub(super) functions: Vec<DecodedCodeBlock>,
+}
+
+impl DecodedCodeBlock {
+    /// Returns the name and mutability of the global lexical bindings used by this code block.
+    pub(super) fn global_bindings(&self) -> Vec<(Sym, bool)> {
+        self.bindings
+            .iter()
+            .chain(&self.arguments_binding)
+            .filter_map(|binding| match *binding {
+                DecodedBinding::GlobalLexical { name, mutable } => Some((name, mutable)),
+                DecodedBinding::Locator(_) => None,
+            })
+            .collect()
+    }
+}
+
+/// A decoded binding locator.
+#[derive(Debug, Clone, Copy)]
+pub(super) enum DecodedBinding {
+    /// A locator that does not depend on the realm.
+    Locator(BindingLocator),
+    /// A binding of the global declarative environment, which is resolved by name since its
+    /// index depends on the bindings declared in the realm.
+    GlobalLexical { name: Sym, mutable: bool },
+}
+
+/// Decodes a serialized script, interning its strings in `interner`.
+pub(super) fn decode(
+    bytes: &[u8],
+    interner: &mut Interner,
+) -> Result<(Vec<GlobalDeclaration>, DecodedCodeBlock), ScriptError> {
+    let mut reader = Reader {
+        bytes,
+        position: 0,
+        strings: Vec::new(),
+    };
+
+    if reader.bytes(MAGIC.len())? != MAGIC {
+        return Err(ScriptError::InvalidMagic);
+    }
+    if reader.u32()? != FORMAT_VERSION
+        || reader.str()? != env!("CARGO_PKG_VERSION")
+        || reader.u8()? != u8::from(cfg!(target_endian = "little"))
+    {
+        return Err(ScriptError::UnsupportedVersion);
+    }
+
+    for _ in 0..reader.u32()? {
+        let string = reader.str()?;
+        reader.strings.push(interner.get_or_intern(string));
+    }
+
+    let mut declarations = Vec::new();
+    for _ in 0..reader.u32()? {
+        let declaration = match reader.u8()? {
+            0 => GlobalDeclaration::Var(reader.sym()?),
+            1 => GlobalDeclaration::Let(reader.sym()?),
+            2 => GlobalDeclaration::Const(reader.sym()?),
+            _ => return Err(ScriptError::InvalidTag),
+        };
+        declarations.push(declaration);
+    }
+
+    let code = reader.code_block(0)?;
+
+    if reader.position != bytes.len() {
+        return Err(ScriptError::TrailingBytes);
+    }
+
+    Ok((declarations, code))
+}
+
+/// A reader of the values of a serialized script.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    /// The symbols of the string table.
+    strings: Vec<Sym>,
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], ScriptError> {
+        let end = self
+            .position
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or(ScriptError::UnexpectedEnd)?;
+        let bytes = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8, ScriptError> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, ScriptError> {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(self.bytes(4)?);
+        Ok(u32::from_le_bytes(bytes))
+    }
+
+    fn bool(&mut self) -> Result<bool, ScriptError> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(ScriptError::InvalidTag),
+        }
+    }
+
+    fn position(&mut self) -> Result<Position, ScriptError> {
+        let (line, column, offset) = (self.u32()?, self.u32()?, self.u32()?);
+        if line == 0 || column == 0 {
+            return Err(ScriptError::InvalidSpan);
+        }
+        Ok(Position::with_offset(line, column, offset))
+    }
+
+    fn str(&mut self) -> Result<&'a str, ScriptError> {
+        let len = self.u32()? as usize;
+        std::str::from_utf8(self.bytes(len)?).map_err(|_| ScriptError::InvalidString)
+    }
+
+    fn sym(&mut self) -> Result<Sym, ScriptError> {
+        let index = self.u32()? as usize;
+        self.strings
+            .get(index)
+            .copied()
+            .ok_or(ScriptError::InvalidStringIndex)
+    }
+
+    fn syms(&mut self) -> Result<Vec<Sym>, ScriptError> {
+        let mut syms = Vec::new();
+        for _ in 0..self.u32()? {
+            syms.push(self.sym()?);
+        }
+        Ok(syms)
+    }
+
+    fn binding(&mut self) -> Result<DecodedBinding, ScriptError> {
+        let binding = match self.u8()? {
+            0 => {
+                let name = self.sym()?;
+                let environment_index = self.u32()? as usize;
+                let binding_index = self.u32()? as usize;
+                if environment_index == 0 {
+                    return Err(ScriptError::InvalidBinding);
+                }
+                DecodedBinding::Locator(BindingLocator::declarative(
+                    name,
+                    environment_index,
+                    binding_index,
+                ))
+            }
+            1 => DecodedBinding::Locator(BindingLocator::global(self.sym()?)),
+            2 => DecodedBinding::Locator(BindingLocator::mutate_immutable(self.sym()?)),
+            3 => DecodedBinding::GlobalLexical {
+                name: self.sym()?,
+                mutable: self.bool()?,
+            },
+            4 => DecodedBinding::Locator(BindingLocator::local(self.sym()?, self.u32()? as usize)),
+            _ => return Err(ScriptError::InvalidTag),
+        };
+        Ok(binding)
+    }
+
+    fn parameters(&mut self) -> Result<FormalParameterList, ScriptError> {
+        let flags = FormalParameterListFlags::from_bits(self.u8()?)
+            .ok_or(ScriptError::InvalidParameters)?;
+
+        let mut parameters = Vec::new();
+        for _ in 0..self.u32()? {
+            let kind = self.u8()?;
+            if kind & !0b111 != 0 {
+                return Err(ScriptError::InvalidParameters);
+            }
+            let is_identifier = kind & 0b001 != 0;
+            let names = self.syms()?;
+            if is_identifier && names.len() != 1 {
+                return Err(ScriptError::InvalidParameters);
+            }
+            parameters.push(FormalParameter::from_bound_names(
+                &names,
+                is_identifier,
+                kind & 0b010 != 0,
+                kind & 0b100 != 0,
+            ));
+        }
+
+        Ok(FormalParameterList::new(
+            parameters.into_boxed_slice(),
+            flags,
+        ))
+    }
+
+    fn code_block(&mut self, depth: usize) -> Result<DecodedCodeBlock, ScriptError> {
+        if depth > MAX_NESTING {
+            return Err(ScriptError::NestingTooDeep);
+        }
+
+        let name = self.sym()?;
+        let length = self.u32()?;
+        let flags = self.u8()?;
+        if flags & !0b111 != 0 {
+            return Err(ScriptError::InvalidTag);
+        }
+        let mut code = CodeBlock::new(name, length, flags & 0b001 != 0, flags & 0b010 != 0);
+        code.lexical_name_argument = flags & 0b100 != 0;
+        code.this_mode = match self.u8()? {
+            0 => ThisMode::Lexical,
+            1 => ThisMode::Strict,
+            2 => ThisMode::Global,
+            _ => return Err(ScriptError::InvalidTag),
+        };
+        code.params = self.parameters()?;
+        code.num_bindings = self.u32()? as usize;
+        code.num_locals = self.u32()? as usize;
+
+        let len = self.u32()? as usize;
+        code.code = self.bytes(len)?.to_vec();
+
+        for _ in 0..self.u32()? {
+            let address = self.u32()?;
+            let (start, end) = (self.position()?, self.position()?);
+            let follows = code.spans.last().map_or(true, |(last, _)| *last < address);
+            if start > end || !follows || address as usize > code.code.len() {
+                return Err(ScriptError::InvalidSpan);
+            }
+            code.spans.push((address, Span::new(start, end)));
+        }
+
+        for _ in 0..self.u32()? {
+            let literal = match self.u8()? {
+                0 => JsValue::new(JsString::new(self.str()?)),
+                1 => JsValue::new(
+                    JsBigInt::from_string_radix(self.str()?, 10)
+                        .ok_or(ScriptError::InvalidBigInt)?,
+                ),
+                _ => return Err(ScriptError::InvalidTag),
+            };
+            code.literals.push(literal);
+        }
+
+        code.names = self.syms()?;
+
+        for _ in 0..self.u32()? {
+            let mut cases = Vec::new();
+            for _ in 0..self.u32()? {
+                let key = match self.u8()? {
+                    0 => JumpTableKey::Integer(self.u32()? as i32),
+                    1 => JumpTableKey::String(JsString::new(self.str()?)),
+                    _ => return Err(ScriptError::InvalidTag),
+                };
+                cases.push((key, self.u32()?));
+            }
+            code.jump_tables.push(JumpTable::new(cases));
+        }
+
+        let mut bindings = Vec::new();
+        for _ in 0..self.u32()? {
+            bindings.push(self.binding()?);
+        }
+
+        let arguments_binding = if self.bool()? {
+            Some(self.binding()?)
+        } else {
+            None
+        };
+
+        let mut functions = Vec::new();
+        for _ in 0..self.u32()? {
+            functions.push(self.code_block(depth + 1)?);
+        }
+
+        Ok(DecodedCodeBlock {
+            code,
+            bindings,
+            arguments_binding,
+            functions,
+        })
+    }
+}
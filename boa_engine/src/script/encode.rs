@@ -0,0 +1,252 @@
+//! Encoding of compiled scripts.
+//!
+//! All the values of the format are little endian, except the operands of the bytecode which are
+//! kept in the byte order of the target, that is recorded in the header.
+
+use super::{Script, FORMAT_VERSION, MAGIC};
+use crate::{
+    builtins::function::ThisMode,
+    environments::{BindingLocator, GlobalDeclaration},
+    syntax::ast::{node::FormalParameterList, Position},
+    vm::{CodeBlock, JumpTableKey},
+    Context, JsValue,
+};
+use boa_interner::Sym;
+use rustc_hash::FxHashMap;
+
+/// Encodes a compiled script, whose symbols and global bindings are those of `context`.
+pub(super) fn encode(script: &Script, context: &Context) -> Vec<u8> {
+    let mut writer = Writer {
+        bytes: Vec::new(),
+        strings: Vec::new(),
+        string_indices: FxHashMap::default(),
+        context,
+    };
+
+    writer.len(script.declarations.len());
+    for declaration in script.declarations.iter() {
+        let (tag, name) = match *declaration {
+            GlobalDeclaration::Var(name) => (0, name),
+            GlobalDeclaration::Let(name) => (1, name),
+            GlobalDeclaration::Const(name) => (2, name),
+        };
+        writer.bytes.push(tag);
+        writer.sym(name);
+    }
+    writer.code_block(&script.code);
+
+    let mut bytes = Vec::with_capacity(writer.bytes.len());
+    bytes.extend_from_slice(MAGIC);
+    put_u32(&mut bytes, FORMAT_VERSION);
+    put_str(&mut bytes, env!("CARGO_PKG_VERSION"));
+    bytes.push(u8::from(cfg!(target_endian = "little")));
+    put_len(&mut bytes, writer.strings.len());
+    for string in &writer.strings {
+        put_str(&mut bytes, context.interner().resolve_expect(*string));
+    }
+
+    bytes.extend_from_slice(&writer.bytes);
+    bytes
+}
+
+fn put_u32(bytes: &mut Vec<u8>, value: u32) {
+    bytes.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_len(bytes: &mut Vec<u8>, len: usize) {
+    put_u32(
+        bytes,
+        u32::try_from(len).expect("lengths of a script must fit in a u32"),
+    );
+}
+
+fn put_str(bytes: &mut Vec<u8>, string: &str) {
+    put_len(bytes, string.len());
+    bytes.extend_from_slice(string.as_bytes());
+}
+
+/// A writer of the values of a serialized script.
+struct Writer<'a> {
+    bytes: Vec<u8>,
+    /// The symbols of the string table, in order.
+    strings: Vec<Sym>,
+    string_indices: FxHashMap<Sym, u32>,
+    context: &'a Context,
+}
+
+impl Writer<'_> {
+    fn u32(&mut self, value: u32) {
+        put_u32(&mut self.bytes, value);
+    }
+
+    fn len(&mut self, len: usize) {
+        put_len(&mut self.bytes, len);
+    }
+
+    fn str(&mut self, string: &str) {
+        put_str(&mut self.bytes, string);
+    }
+
+    fn position(&mut self, position: Position) {
+        self.u32(position.line_number());
+        self.u32(position.column_number());
+        self.u32(position.offset());
+    }
+
+    fn sym(&mut self, sym: Sym) {
+        let index = if let Some(index) = self.string_indices.get(&sym) {
+            *index
+        } else {
+            let index = self.strings.len() as u32;
+            self.strings.push(sym);
+            self.string_indices.insert(sym, index);
+            index
+        };
+        self.u32(index);
+    }
+
+    fn syms(&mut self, syms: &[Sym]) {
+        self.len(syms.len());
+        for sym in syms {
+            self.sym(*sym);
+        }
+    }
+
+    fn binding(&mut self, binding: &BindingLocator) {
+        if binding.is_global() {
+            self.bytes.push(1);
+            self.sym(binding.name());
+        } else if binding.is_mutate_immutable() {
+            self.bytes.push(2);
+            self.sym(binding.name());
+        } else if binding.is_local() {
+            self.bytes.push(4);
+            self.sym(binding.name());
+            self.len(binding.binding_index());
+        } else if binding.environment_index() == 0 {
+            let (_, mutable) = self
+                .context
+                .realm
+                .compile_env
+                .global_binding(binding.name())
+                .expect("the global bindings of a script must be declared");
+            self.bytes.push(3);
+            self.sym(binding.name());
+            self.bytes.push(u8::from(mutable));
+        } else {
+            self.bytes.push(0);
+            self.sym(binding.name());
+            self.len(binding.environment_index());
+            self.len(binding.binding_index());
+        }
+    }
+
+    fn parameters(&mut self, parameters: &FormalParameterList) {
+        self.bytes.push(parameters.flags.bits());
+        self.len(parameters.parameters.len());
+        for parameter in parameters.parameters.iter() {
+            let mut kind = 0;
+            if parameter.is_identifier() {
+                kind |= 0b001;
+            }
+            if parameter.init().is_some() {
+                kind |= 0b010;
+            }
+            if parameter.is_rest_param() {
+                kind |= 0b100;
+            }
+            self.bytes.push(kind);
+            self.syms(&parameter.names());
+        }
+    }
+
+    fn code_block(&mut self, code: &CodeBlock) {
+        debug_assert!(
+            code.lazy.is_none(),
+            "the functions of a script must be compiled eagerly"
+        );
+        self.sym(code.name);
+        self.u32(code.length);
+        let mut flags = 0;
+        if code.strict {
+            flags |= 0b001;
+        }
+        if code.constructor {
+            flags |= 0b010;
+        }
+        if code.lexical_name_argument {
+            flags |= 0b100;
+        }
+        self.bytes.push(flags);
+        self.bytes.push(match code.this_mode {
+            ThisMode::Lexical => 0,
+            ThisMode::Strict => 1,
+            ThisMode::Global => 2,
+        });
+        self.parameters(&code.params);
+        self.len(code.num_bindings);
+        self.len(code.num_locals);
+
+        self.len(code.code.len());
+        self.bytes.extend_from_slice(&code.code);
+
+        self.len(code.spans.len());
+        for (address, span) in &code.spans {
+            self.u32(*address);
+            self.position(span.start());
+            self.position(span.end());
+        }
+
+        self.len(code.literals.len());
+        for literal in &code.literals {
+            match literal {
+                JsValue::String(string) => {
+                    self.bytes.push(0);
+                    self.str(string);
+                }
+                JsValue::BigInt(bigint) => {
+                    self.bytes.push(1);
+                    self.str(&bigint.to_string_radix(10));
+                }
+                _ => unreachable!("the literals of a code block are strings and BigInts"),
+            }
+        }
+
+        self.syms(&code.names);
+
+        self.len(code.jump_tables.len());
+        for table in &code.jump_tables {
+            self.len(table.entries().len());
+            for (key, address) in table.entries() {
+                match key {
+                    JumpTableKey::Integer(integer) => {
+                        self.bytes.push(0);
+                        self.u32(*integer as u32);
+                    }
+                    JumpTableKey::String(string) => {
+                        self.bytes.push(1);
+                        self.str(string);
+                    }
+                }
+                self.u32(*address);
+            }
+        }
+
+        self.len(code.bindings.len());
+        for binding in &code.bindings {
+            self.binding(binding);
+        }
+
+        if let Some(binding) = &code.arguments_binding {
+            self.bytes.push(1);
+            self.binding(binding);
+        } else {
+            self.bytes.push(0);
+        }
+
+        self.len(code.functions.len());
+        for function in &code.functions {
+            self.code_block(function);
+        }
+    }
+}
@@ -0,0 +1,323 @@
+//! This module implements the `Script` type, a compiled ECMAScript script.
+//!
+//! A script is parsed and compiled once, and can then be evaluated any number of times. The
+//! bytecode of a script can also be serialized with [`Script::to_bytes`], so embedders can keep a
+//! cache of compiled scripts and skip parsing and compiling them on subsequent startups.
+//!
+//! The serialized form contains the compiled `CodeBlock`s, their constants, the strings of the
+//! interner they refer to, and the declarations the script makes on the global environment.
+//! It is tied to the version of the engine that wrote it, and [`Script::from_bytes`] verifies it
+//! before use, to reject corrupted bytes. The VM still trusts the compiler for parts of the
+//! bytecode that the verifier can't check, so scripts must not be loaded from untrusted storage.
+
+mod decode;
+mod encode;
+mod verify;
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    environments::{BindingLocator, GlobalDeclaration},
+    vm::CodeBlock,
+    Context, JsResult, JsValue,
+};
+use boa_gc::Gc;
+use decode::{DecodedBinding, DecodedCodeBlock};
+use std::fmt;
+
+/// The magic number at the start of a serialized script.
+const MAGIC: &[u8; 4] = b"BOAS";
+
+/// The version of the serialization format, incremented on every change to it.
+const FORMAT_VERSION: u32 = 4;
+
+/// A compiled ECMAScript script.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_engine::{script::Script, Context};
+/// let mut context = Context::default();
+/// let script = Script::compile("let x = 40; x + 2", &mut context).unwrap();
+/// let bytes = script.to_bytes(&context);
+///
+/// let mut context = Context::default();
+/// let script = Script::from_bytes(&bytes, &mut context).unwrap();
+/// assert_eq!(script.evaluate(&mut context).unwrap().as_number(), Some(42.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Script {
+    code: Gc<CodeBlock>,
+    declarations: Box<[GlobalDeclaration]>,
+}
+
+impl Script {
+    /// Parses and compiles the given source code as a script.
+    ///
+    /// Like compiling with [`Context::compile`], this declares the top level bindings of the
+    /// script on the global environment of the context.
+    pub fn compile<S>(src: S, context: &mut Context) -> JsResult<Self>
+    where
+        S: AsRef<[u8]>,
+    {
+        let statement_list = match context
+            .parser(src.as_ref(), false)
+            .parse_all(context.interner_mut())
+        {
+            Ok(statement_list) => statement_list,
+            Err(e) => return context.throw_syntax_error(e.to_string()),
+        };
+
+        // The functions of the script are compiled eagerly, since their bytecode is serialized.
+        let lazy_compilation = context.lazy_compilation();
+        context.set_lazy_compilation(false);
+        context.realm.compile_env.start_recording_declarations();
+        let code = context.compile(&statement_list);
+        let declarations = context.realm.compile_env.finish_recording_declarations();
+        context.set_lazy_compilation(lazy_compilation);
+
+        Ok(Self {
+            code: code?,
+            declarations: declarations.into_boxed_slice(),
+        })
+    }
+
+    /// Evaluates the script, returning the value of its last statement.
+    ///
+    /// The pending jobs are run after the script, unless it is evaluated by running code.
+    pub fn evaluate(&self, context: &mut Context) -> JsResult<JsValue> {
+        context.evaluate_script_code(self.code.clone())
+    }
+
+    /// Returns the code block of the script.
+    #[inline]
+    pub fn code_block(&self) -> Gc<CodeBlock> {
+        self.code.clone()
+    }
+
+    /// Serializes the compiled script, to be loaded with [`Script::from_bytes`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the script was not compiled or loaded in `context`.
+    pub fn to_bytes(&self, context: &Context) -> Vec<u8> {
+        encode::encode(self, context)
+    }
+
+    /// Loads a script serialized with [`Script::to_bytes`].
+    ///
+    /// The bytes are verified before use: they must have been written by this version of the
+    /// engine, every instruction of the bytecode and every index into the tables of its code
+    /// blocks must be valid, and no instruction can pop more values than the stack holds. A
+    /// `TypeError` is thrown if they are rejected.
+    ///
+    /// Like compiling the script, this declares the top level bindings of the script on the
+    /// global environment of the context. The script may only use the global lexical bindings
+    /// that it declares, or that exist in the context with the same mutability.
+    pub fn from_bytes(bytes: &[u8], context: &mut Context) -> JsResult<Self> {
+        let (declarations, code) = match decode::decode(bytes, context.interner_mut()) {
+            Ok(decoded) => decoded,
+            Err(e) => return context.throw_type_error(format!("invalid compiled script: {e}")),
+        };
+        if let Err(e) = verify::verify(&code) {
+            return context.throw_type_error(format!("invalid compiled script: {e}"));
+        }
+        if let Err(e) = check_global_bindings(&code, &declarations, context) {
+            return context.throw_type_error(format!("invalid compiled script: {e}"));
+        }
+
+        for declaration in declarations.iter().copied() {
+            match declaration {
+                GlobalDeclaration::Var(name) => context.create_mutable_binding(name, true, true)?,
+                GlobalDeclaration::Let(name) => {
+                    context.create_mutable_binding(name, false, false)?;
+                }
+                GlobalDeclaration::Const(name) => context.create_immutable_binding(name)?,
+            }
+        }
+
+        Ok(Self {
+            code: link(code, context),
+            declarations: declarations.into_boxed_slice(),
+        })
+    }
+}
+
+/// The reason why a serialized script was rejected by [`Script::from_bytes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ScriptError {
+    /// The bytes end in the middle of a value.
+    UnexpectedEnd,
+    /// There are bytes after the end of the script.
+    TrailingBytes,
+    /// The bytes don't start with the magic number of a serialized script.
+    InvalidMagic,
+    /// The script was serialized by a different version of the engine, or on a target with a
+    /// different byte order.
+    UnsupportedVersion,
+    /// A string is not valid UTF-8.
+    InvalidString,
+    /// An index into the string table is out of range.
+    InvalidStringIndex,
+    /// A tag does not identify a value of its type.
+    InvalidTag,
+    /// A BigInt literal is not a decimal integer.
+    InvalidBigInt,
+    /// The line table of a code block is not ordered, or has an invalid span.
+    InvalidSpan,
+    /// The code blocks are nested too deeply.
+    NestingTooDeep,
+    /// The parameters of a function are inconsistent.
+    InvalidParameters,
+    /// A byte of the bytecode is not an opcode.
+    InvalidOpcode { pc: usize },
+    /// An instruction ends after the end of the bytecode.
+    TruncatedInstruction { pc: usize },
+    /// An operand of an instruction is an index out of range, or a count that is too large.
+    InvalidOperand { pc: usize },
+    /// An instruction jumps to an address that is not the start of an instruction.
+    InvalidJump { pc: usize },
+    /// An instruction pops more values than the stack can hold when it runs.
+    StackUnderflow { pc: usize },
+    /// A binding locator refers to an environment that can't exist when it is used.
+    InvalidBinding,
+    /// An environment has more bindings than the script declares.
+    TooManyBindings,
+    /// A global lexical binding used by the script is not declared.
+    UndeclaredBinding(String),
+    /// A global lexical binding used by the script doesn't have the expected mutability.
+    BindingMutability(String),
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd => f.write_str("unexpected end of input"),
+            Self::TrailingBytes => f.write_str("unexpected bytes after the end of the script"),
+            Self::InvalidMagic => f.write_str("not a compiled script"),
+            Self::UnsupportedVersion => f.write_str(
+                "the script was compiled by a different version of the engine or for another target",
+            ),
+            Self::InvalidString => f.write_str("invalid UTF-8 string"),
+            Self::InvalidStringIndex => f.write_str("string index out of range"),
+            Self::InvalidTag => f.write_str("invalid tag"),
+            Self::InvalidBigInt => f.write_str("invalid BigInt literal"),
+            Self::InvalidSpan => f.write_str("invalid line table"),
+            Self::NestingTooDeep => f.write_str("functions are nested too deeply"),
+            Self::InvalidParameters => f.write_str("invalid function parameters"),
+            Self::InvalidOpcode { pc } => write!(f, "invalid opcode at {pc}"),
+            Self::TruncatedInstruction { pc } => write!(f, "truncated instruction at {pc}"),
+            Self::InvalidOperand { pc } => write!(f, "invalid operand at {pc}"),
+            Self::InvalidJump { pc } => write!(f, "invalid jump target at {pc}"),
+            Self::StackUnderflow { pc } => write!(f, "stack underflow at {pc}"),
+            Self::InvalidBinding => f.write_str("invalid binding locator"),
+            Self::TooManyBindings => f.write_str("too many bindings in an environment"),
+            Self::UndeclaredBinding(name) => write!(f, "undeclared global binding `{name}`"),
+            Self::BindingMutability(name) => {
+                write!(f, "global binding `{name}` has a different mutability")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+/// Checks that the global lexical bindings used by the code blocks will be declared once the
+/// declarations of the script are made, with the mutability they were compiled with.
+fn check_global_bindings(
+    code: &DecodedCodeBlock,
+    declarations: &[GlobalDeclaration],
+    context: &Context,
+) -> Result<(), ScriptError> {
+    for (name, mutable) in code.global_bindings() {
+        let declared = declarations
+            .iter()
+            .find_map(|declaration| match *declaration {
+                GlobalDeclaration::Let(declared) if declared == name => Some(true),
+                GlobalDeclaration::Const(declared) if declared == name => Some(false),
+                _ => None,
+            });
+        let declared = declared.or_else(|| {
+            context
+                .realm
+                .compile_env
+                .global_binding(name)
+                .map(|(_, mutable)| mutable)
+        });
+        match declared {
+            Some(declared) if declared == mutable => {}
+            Some(_) => {
+                return Err(ScriptError::BindingMutability(
+                    context.interner().resolve_expect(name).to_owned(),
+                ))
+            }
+            None => {
+                return Err(ScriptError::UndeclaredBinding(
+                    context.interner().resolve_expect(name).to_owned(),
+                ))
+            }
+        }
+    }
+
+    for function in &code.functions {
+        check_global_bindings(function, declarations, context)?;
+    }
+
+    Ok(())
+}
+
+/// Links the decoded code blocks to the realm of the context, resolving their global lexical
+/// bindings and giving their tagged templates new call sites.
+fn link(code: DecodedCodeBlock, context: &mut Context) -> Gc<CodeBlock> {
+    let mut sites = Vec::new();
+    link_code_block(code, &mut sites, context)
+}
+
+fn link_code_block(
+    decoded: DecodedCodeBlock,
+    sites: &mut Vec<(u64, u64)>,
+    context: &mut Context,
+) -> Gc<CodeBlock> {
+    let DecodedCodeBlock {
+        mut code,
+        bindings,
+        arguments_binding,
+        functions,
+    } = decoded;
+
+    let resolve = |binding: DecodedBinding| match binding {
+        DecodedBinding::Locator(locator) => locator,
+        DecodedBinding::GlobalLexical { name, .. } => {
+            let (index, _) = context
+                .realm
+                .compile_env
+                .global_binding(name)
+                .expect("global bindings must have been checked");
+            BindingLocator::declarative(name, 0, index)
+        }
+    };
+    code.bindings = bindings.into_iter().map(&resolve).collect();
+    code.arguments_binding = arguments_binding.map(&resolve);
+
+    for offset in verify::template_sites(&code.code) {
+        let mut site = [0; 8];
+        site.copy_from_slice(&code.code[offset..offset + 8]);
+        let site = u64::from_ne_bytes(site);
+        let linked = if let Some(&(_, linked)) = sites.iter().find(|(old, _)| *old == site) {
+            linked
+        } else {
+            let linked = context.realm.next_template_site();
+            sites.push((site, linked));
+            linked
+        };
+        code.code[offset..offset + 8].copy_from_slice(&linked.to_ne_bytes());
+    }
+
+    code.functions = functions
+        .into_iter()
+        .map(|function| link_code_block(function, sites, context))
+        .collect();
+
+    Gc::new(code)
+}
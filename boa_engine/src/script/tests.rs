@@ -0,0 +1,443 @@
+use super::{
+    decode::{DecodedBinding, DecodedCodeBlock},
+    verify::verify,
+    Script, ScriptError,
+};
+use crate::{
+    environments::BindingLocator,
+    forward,
+    vm::{CodeBlock, JumpTable, JumpTableKey, Opcode},
+    Context, JsString,
+};
+use boa_interner::Sym;
+
+/// Compiles a script in a new context and loads its bytes in another new context.
+fn round_trip(src: &str) -> (Script, Context) {
+    let mut context = Context::default();
+    let bytes = Script::compile(src, &mut context)
+        .expect("the script must compile")
+        .to_bytes(&context);
+
+    let mut context = Context::default();
+    let script = Script::from_bytes(&bytes, &mut context).expect("the script must load");
+    (script, context)
+}
+
+fn load_error(bytes: &[u8], context: &mut Context) -> String {
+    let error = Script::from_bytes(bytes, context).expect_err("the script must be rejected");
+    error
+        .to_string(context)
+        .expect("errors must convert to strings")
+        .to_string()
+}
+
+#[test]
+fn evaluate_loaded_script() {
+    let (script, mut context) = round_trip(
+        r#"
+        'use strict';
+        var log = [];
+        let counter = 0;
+        const prefix = 'value';
+        function tag(strings, ...values) { return strings.raw.join('|') + values.length; }
+        function* range(n) { for (let i = 0; i < n; i++) { yield i; } }
+        function Point(x, y = x * 2) { this.x = x; this.y = y; }
+        Point.prototype.sum = function () { return this.x + this.y; };
+        const add = ({ a, b }, [c] = [10]) => a + b + c;
+        for (const i of range(3)) { counter += i; }
+        try { undefinedFunction(); } catch (e) { log.push(e.name); }
+        log.push(`${prefix}: ${counter}`, tag`a${1}b\n`, 10n ** 20n);
+        log.push(new Point(3).sum(), add({ a: 1, b: 2 }), add({ a: 1, b: 2 }, [3]));
+        log.join()
+        "#,
+    );
+
+    assert_eq!(
+        script
+            .evaluate(&mut context)
+            .map(|value| value.display().to_string()),
+        Ok("\"ReferenceError,value: 3,a|b\\n1,100000000000000000000,9,13,6\"".to_owned())
+    );
+    assert_eq!(
+        forward(&mut context, "counter + prefix.length + log.length"),
+        "15"
+    );
+}
+
+#[test]
+fn declarations() {
+    let mut context = Context::default();
+    let bytes = Script::compile("var a = 1; let b = 2; const c = 3; a + b + c", &mut context)
+        .expect("the script must compile")
+        .to_bytes(&context);
+
+    let mut context = Context::default();
+    let script = Script::from_bytes(&bytes, &mut context).expect("the script must load");
+    assert_eq!(forward(&mut context, "typeof a"), "\"undefined\"");
+    assert_eq!(
+        script
+            .evaluate(&mut context)
+            .map(|value| value.display().to_string()),
+        Ok("6".to_owned())
+    );
+    assert_eq!(
+        forward(&mut context, "try { c = 4 } catch (e) { e.name }"),
+        "\"TypeError\""
+    );
+
+    let mut context = Context::default();
+    context
+        .eval("let b = 0;")
+        .expect("the binding must be declared");
+    let error = Script::from_bytes(&bytes, &mut context).expect_err("`b` must be redeclared");
+    assert_eq!(
+        error
+            .to_string(&mut context)
+            .map(|message| message.to_string()),
+        Ok("SyntaxError: Redeclaration of variable b".to_owned())
+    );
+}
+
+#[test]
+fn global_lexical_bindings() {
+    let mut context = Context::default();
+    context
+        .eval("let count = 1; const name = 'boa';")
+        .expect("the bindings must be declared");
+    let bytes = Script::compile("count += 1; name + count", &mut context)
+        .expect("the script must compile")
+        .to_bytes(&context);
+
+    let mut context = Context::default();
+    context
+        .eval("const unrelated = 0; let count = 41; const name = 'engine';")
+        .expect("the bindings must be declared");
+    let script = Script::from_bytes(&bytes, &mut context).expect("the script must load");
+    assert_eq!(
+        script
+            .evaluate(&mut context)
+            .map(|value| value.display().to_string()),
+        Ok("\"engine42\"".to_owned())
+    );
+
+    let mut context = Context::default();
+    assert_eq!(
+        load_error(&bytes, &mut context),
+        "TypeError: invalid compiled script: undeclared global binding `count`"
+    );
+
+    let mut context = Context::default();
+    context
+        .eval("const count = 0; const name = '';")
+        .expect("the bindings must be declared");
+    assert_eq!(
+        load_error(&bytes, &mut context),
+        "TypeError: invalid compiled script: global binding `count` has a different mutability"
+    );
+}
+
+#[test]
+fn invalid_bytes() {
+    let mut context = Context::default();
+    let bytes = Script::compile("function f(x) { return x * 2; } f(21)", &mut context)
+        .expect("the script must compile")
+        .to_bytes(&context);
+
+    let mut context = Context::default();
+    let mut invalid = bytes.clone();
+    invalid[0] = b'X';
+    assert_eq!(
+        load_error(&invalid, &mut context),
+        "TypeError: invalid compiled script: not a compiled script"
+    );
+
+    let mut invalid = bytes.clone();
+    invalid[4] += 1;
+    assert_eq!(
+        load_error(&invalid, &mut context),
+        "TypeError: invalid compiled script: the script was compiled by a different version of \
+         the engine or for another target"
+    );
+
+    assert_eq!(
+        load_error(&bytes[..bytes.len() - 1], &mut context),
+        "TypeError: invalid compiled script: unexpected end of input"
+    );
+
+    let mut invalid = bytes.clone();
+    invalid.push(0);
+    assert_eq!(
+        load_error(&invalid, &mut context),
+        "TypeError: invalid compiled script: unexpected bytes after the end of the script"
+    );
+
+    assert_eq!(forward(&mut context, "typeof f"), "\"undefined\"");
+}
+
+fn code_block(code: Vec<u8>) -> DecodedCodeBlock {
+    let mut block = CodeBlock::new(Sym::MAIN, 0, false, false);
+    block.code = code;
+    DecodedCodeBlock {
+        code: block,
+        bindings: Vec::new(),
+        arguments_binding: None,
+        functions: Vec::new(),
+    }
+}
+
+fn instruction(opcode: Opcode, operand: u32) -> Vec<u8> {
+    let mut code = vec![opcode as u8];
+    code.extend_from_slice(&operand.to_ne_bytes());
+    code
+}
+
+fn push_environment(num_bindings: u32, first_local: u32, num_locals: u32) -> Vec<u8> {
+    let mut code = instruction(Opcode::PushDeclarativeEnvironment, num_bindings);
+    code.extend_from_slice(&first_local.to_ne_bytes());
+    code.extend_from_slice(&num_locals.to_ne_bytes());
+    code
+}
+
+#[test]
+fn verify_bytecode() {
+    let valid = [
+        Vec::new(),
+        vec![Opcode::PushInt8 as u8, 0, Opcode::Pop as u8],
+        instruction(Opcode::Jump, 5),
+        instruction(Opcode::Jump, 0),
+    ];
+    for code in valid {
+        assert_eq!(verify(&code_block(code.clone())), Ok(()), "{code:?}");
+    }
+
+    let mut jump_into_operand = vec![Opcode::PushInt8 as u8, 0];
+    jump_into_operand.extend(instruction(Opcode::Jump, 1));
+
+    for (code, expected) in [
+        (vec![u8::MAX], ScriptError::InvalidOpcode { pc: 0 }),
+        (
+            vec![Opcode::Pop as u8, Opcode::PushInt32 as u8, 1, 2],
+            ScriptError::TruncatedInstruction { pc: 1 },
+        ),
+        (jump_into_operand, ScriptError::InvalidJump { pc: 2 }),
+        (
+            instruction(Opcode::Jump, 6),
+            ScriptError::InvalidJump { pc: 0 },
+        ),
+        (
+            instruction(Opcode::PushLiteral, 0),
+            ScriptError::InvalidOperand { pc: 0 },
+        ),
+        (
+            instruction(Opcode::GetName, 0),
+            ScriptError::InvalidOperand { pc: 0 },
+        ),
+        (
+            instruction(Opcode::GetFunction, 0),
+            ScriptError::InvalidOperand { pc: 0 },
+        ),
+        (
+            push_environment(u32::MAX, 0, 0),
+            ScriptError::InvalidOperand { pc: 0 },
+        ),
+        (
+            push_environment(0, 1, 0),
+            ScriptError::InvalidOperand { pc: 0 },
+        ),
+        (
+            instruction(Opcode::GetLocal, 0),
+            ScriptError::InvalidOperand { pc: 0 },
+        ),
+        (
+            instruction(Opcode::JumpTable, 0),
+            ScriptError::InvalidOperand { pc: 0 },
+        ),
+        (
+            instruction(Opcode::Call, u32::MAX),
+            ScriptError::InvalidOperand { pc: 0 },
+        ),
+        (
+            instruction(Opcode::CallWithRest, 0),
+            ScriptError::InvalidOperand { pc: 0 },
+        ),
+    ] {
+        assert_eq!(verify(&code_block(code.clone())), Err(expected), "{code:?}");
+    }
+
+    let mut block = code_block(instruction(Opcode::GetName, 0));
+    block
+        .bindings
+        .push(DecodedBinding::Locator(BindingLocator::declarative(
+            Sym::EMPTY_STRING,
+            1,
+            0,
+        )));
+    assert_eq!(verify(&block), Err(ScriptError::InvalidBinding));
+
+    let mut code = push_environment(1, 0, 0);
+    code.extend(instruction(Opcode::GetName, 0));
+    block.code.code = code;
+    assert_eq!(verify(&block), Ok(()));
+
+    block.code.num_bindings = 100;
+    assert_eq!(verify(&block), Err(ScriptError::TooManyBindings));
+
+    let mut block = code_block(push_environment(0, 0, 2));
+    block.code.num_locals = 1;
+    assert_eq!(verify(&block), Err(ScriptError::InvalidOperand { pc: 0 }));
+
+    let mut code = push_environment(0, 0, 1);
+    code.extend(instruction(Opcode::GetLocal, 0));
+    block.code.code = code;
+    block
+        .bindings
+        .push(DecodedBinding::Locator(BindingLocator::local(
+            Sym::EMPTY_STRING,
+            1,
+        )));
+    assert_eq!(verify(&block), Err(ScriptError::InvalidBinding));
+
+    block.code.num_locals = 2;
+    assert_eq!(verify(&block), Ok(()));
+
+    block.code.code = instruction(Opcode::GetName, 0);
+    assert_eq!(verify(&block), Err(ScriptError::InvalidOperand { pc: 0 }));
+
+    let mut code = vec![Opcode::PushZero as u8];
+    code.extend(instruction(Opcode::JumpTable, 0));
+    let mut block = code_block(code);
+    block
+        .code
+        .jump_tables
+        .push(JumpTable::new([(JumpTableKey::Integer(1), 6)]));
+    assert_eq!(verify(&block), Ok(()));
+
+    block.code.jump_tables[0] = JumpTable::new([
+        (JumpTableKey::Integer(1), 6),
+        (JumpTableKey::String(JsString::from("a")), 3),
+    ]);
+    assert_eq!(verify(&block), Err(ScriptError::InvalidJump { pc: 1 }));
+}
+
+/// Returns a `try` block that catches an error, and pops `pops` values in the `catch` block.
+fn try_catch(pops: usize) -> Vec<u8> {
+    let mut code = instruction(Opcode::TryStart, 15);
+    code.extend_from_slice(&0u32.to_ne_bytes());
+    code.push(Opcode::TryEnd as u8);
+    code.extend(instruction(Opcode::Jump, (15 + pops) as u32));
+    code.extend(vec![Opcode::Pop as u8; pops]);
+    code
+}
+
+#[test]
+fn verify_stack_depth() {
+    let mut call = vec![Opcode::PushUndefined as u8, Opcode::PushUndefined as u8];
+    call.extend(instruction(Opcode::Call, 0));
+
+    for code in [call, try_catch(1)] {
+        assert_eq!(verify(&code_block(code.clone())), Ok(()), "{code:?}");
+    }
+
+    let mut call_with_arguments = vec![Opcode::PushUndefined as u8, Opcode::PushUndefined as u8];
+    call_with_arguments.extend(instruction(Opcode::Call, 1));
+
+    let mut jump_with_fewer_values = vec![Opcode::PushTrue as u8];
+    jump_with_fewer_values.extend(instruction(Opcode::JumpIfFalse, 7));
+    jump_with_fewer_values.extend([Opcode::PushZero as u8, Opcode::Pop as u8]);
+
+    let mut popping_loop = vec![
+        Opcode::PushZero as u8,
+        Opcode::PushZero as u8,
+        Opcode::Pop as u8,
+    ];
+    popping_loop.extend(instruction(Opcode::Jump, 2));
+
+    for (code, pc) in [
+        (vec![Opcode::Pop as u8], 0),
+        (call_with_arguments, 2),
+        (jump_with_fewer_values, 7),
+        (popping_loop, 2),
+        (try_catch(2), 16),
+        (vec![Opcode::Return as u8], 0),
+    ] {
+        assert_eq!(
+            verify(&code_block(code.clone())),
+            Err(ScriptError::StackUnderflow { pc }),
+            "{code:?}"
+        );
+    }
+
+    let mut context = Context::default();
+    let mut bytes = Script::compile("true; false", &mut context)
+        .expect("the script must compile")
+        .to_bytes(&context);
+    let pc = bytes
+        .windows(3)
+        .position(|code| {
+            code == [
+                Opcode::PushTrue as u8,
+                Opcode::Pop as u8,
+                Opcode::PushFalse as u8,
+            ]
+        })
+        .expect("the bytecode must be in the bytes");
+    bytes[pc] = Opcode::Nop as u8;
+    assert!(load_error(&bytes, &mut context)
+        .starts_with("TypeError: invalid compiled script: stack underflow at "));
+}
+
+#[test]
+fn evaluate_loaded_jump_tables() {
+    let (script, mut context) = round_trip(
+        r#"
+        function token(c) {
+            switch (c) {
+                case 'a': return 1;
+                case 'b': return 2;
+                case 'c': return 3;
+                case 'd': return 4;
+                default: return 0;
+            }
+        }
+        function digit(n) {
+            switch (n) {
+                case 0:
+                case 1: return 'low';
+                case 2:
+                case 3: return 'high';
+            }
+            return 'none';
+        }
+        [token('c'), token('z'), digit(1), digit(3), digit(4)].join()
+        "#,
+    );
+
+    assert_eq!(
+        script
+            .evaluate(&mut context)
+            .map(|value| value.display().to_string()),
+        Ok("\"3,0,low,high,none\"".to_owned())
+    );
+}
+
+#[test]
+fn compile_functions_eagerly() {
+    let mut context = Context::default();
+    context.set_lazy_compilation(true);
+    let bytes = Script::compile(
+        "function f(x) { return () => x * 2; } f(21)()",
+        &mut context,
+    )
+    .expect("the script must compile")
+    .to_bytes(&context);
+    assert!(context.lazy_compilation());
+
+    let mut context = Context::default();
+    let script = Script::from_bytes(&bytes, &mut context).expect("the script must load");
+    assert_eq!(
+        script
+            .evaluate(&mut context)
+            .map(|value| value.display().to_string()),
+        Ok("42".to_owned())
+    );
+}
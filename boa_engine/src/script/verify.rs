@@ -0,0 +1,475 @@
+//! Verification of the code blocks of decoded scripts.
+//!
+//! The verifier checks that the bytecode can be read by the VM: every byte of the code is part of
+//! an instruction, every operand that indexes a table of the code block or the local bindings of
+//! the call frame is in range, and every jump, including the jumps of the jump tables, targets
+//! the start of an instruction. The binding locators must refer to environments that the code
+//! block can have, and the environments can't hold more bindings than the script declares, so a
+//! serialized script can't make the VM allocate more than its own size warrants.
+//!
+//! The verifier also checks the stack usage of the bytecode: along every path of its control
+//! flow, including the jumps to the `catch` and `finally` blocks, no instruction pops more values
+//! than the code block pushed, or than the parameters of its function. The values that
+//! `FinallyEnd` pops to complete a `return` or a `throw` are not checked, and the VM still relies
+//! on the compiler for the types of the values on the stack and for the nesting of the `try`,
+//! loop and environment instructions, so the verifier rejects corrupted bytecode but doesn't make
+//! bytecode from an untrusted source safe to run.
+
+use super::{
+    decode::{DecodedBinding, DecodedCodeBlock},
+    ScriptError,
+};
+use crate::vm::{CodeBlock, Opcode, Operand};
+use std::{collections::BTreeSet, convert::TryFrom, mem::size_of};
+
+/// Verifies a decoded script.
+pub(super) fn verify(code: &DecodedCodeBlock) -> Result<(), ScriptError> {
+    // Every binding of an environment is declared by an instruction that refers to it through a
+    // binding locator, except for the `arguments` binding of a function.
+    let max_bindings = count_bindings(code);
+
+    // The main code block starts with the global environment.
+    verify_code_block(code, 1, max_bindings)
+}
+
+/// Returns the number of binding locators and code blocks of a code block and its functions.
+fn count_bindings(code: &DecodedCodeBlock) -> usize {
+    code.functions
+        .iter()
+        .map(count_bindings)
+        .fold(code.bindings.len() + 1, usize::saturating_add)
+}
+
+/// Verifies a code block, which starts with at most `environments` environments.
+fn verify_code_block(
+    decoded: &DecodedCodeBlock,
+    environments: usize,
+    max_bindings: usize,
+) -> Result<(), ScriptError> {
+    let code = &decoded.code;
+    let bytes = &code.code;
+
+    if code.num_bindings > max_bindings || code.num_locals > max_bindings {
+        return Err(ScriptError::TooManyBindings);
+    }
+
+    let is_local = |index: usize| {
+        matches!(
+            decoded.bindings.get(index),
+            Some(DecodedBinding::Locator(locator)) if locator.is_local()
+        )
+    };
+
+    let mut starts = vec![false; bytes.len() + 1];
+    let mut jumps = Vec::new();
+    let mut pushed_environments = 0;
+    let mut first_local = 0;
+
+    let mut pc = 0;
+    while pc < bytes.len() {
+        starts[pc] = true;
+        let opcode = Opcode::try_from(bytes[pc]).map_err(|_| ScriptError::InvalidOpcode { pc })?;
+        let mut offset = pc + size_of::<Opcode>();
+
+        for operand in opcode.operands() {
+            let end = offset + operand.size();
+            if end > bytes.len() {
+                return Err(ScriptError::TruncatedInstruction { pc });
+            }
+            let value = if operand.size() == size_of::<u32>() {
+                let mut value = [0; 4];
+                value.copy_from_slice(&bytes[offset..end]);
+                u32::from_ne_bytes(value) as usize
+            } else {
+                0
+            };
+            offset = end;
+
+            let valid = match operand {
+                Operand::Literal => value < code.literals.len(),
+                Operand::Function => value < decoded.functions.len(),
+                Operand::JumpTable => {
+                    if let Some(table) = code.jump_tables.get(value) {
+                        jumps.extend(
+                            table
+                                .entries()
+                                .iter()
+                                .map(|(_, address)| (pc, *address as usize)),
+                        );
+                        true
+                    } else {
+                        false
+                    }
+                }
+                Operand::Binding => value < decoded.bindings.len() && !is_local(value),
+                Operand::LocalBinding => is_local(value),
+                Operand::Name => value < code.names.len(),
+                Operand::Address => {
+                    jumps.push((pc, value));
+                    true
+                }
+                // A value is pushed by at least one byte of code, and the last argument of a
+                // call with a rest argument is the value to spread.
+                Operand::Count => {
+                    value <= bytes.len()
+                        && (value > 0
+                            || !matches!(opcode, Opcode::CallWithRest | Opcode::NewWithRest))
+                }
+                Operand::EnvironmentSize => {
+                    pushed_environments += 1;
+                    value <= max_bindings
+                }
+                Operand::Local => {
+                    first_local = value;
+                    value <= code.num_locals
+                }
+                Operand::LocalCount => value <= code.num_locals - first_local,
+                Operand::I8 | Operand::I16 | Operand::I32 | Operand::F64 | Operand::Site => true,
+            };
+            if !valid {
+                return Err(ScriptError::InvalidOperand { pc });
+            }
+        }
+
+        pc = offset;
+    }
+    starts[bytes.len()] = true;
+
+    for (pc, target) in jumps {
+        if !starts.get(target).copied().unwrap_or(false) {
+            return Err(ScriptError::InvalidJump { pc });
+        }
+    }
+
+    // The `arguments` object is stored in the function environment when the function is called.
+    if let Some(DecodedBinding::Locator(locator)) = decoded.arguments_binding {
+        if locator.is_local() {
+            return Err(ScriptError::InvalidBinding);
+        }
+    }
+
+    let environments = environments + pushed_environments;
+    for binding in decoded.bindings.iter().chain(&decoded.arguments_binding) {
+        if let DecodedBinding::Locator(locator) = binding {
+            if locator.is_local() && locator.binding_index() >= code.num_locals {
+                return Err(ScriptError::InvalidBinding);
+            }
+            if !locator.is_global()
+                && !locator.is_local()
+                && !locator.is_mutate_immutable()
+                && locator.environment_index() >= environments
+            {
+                return Err(ScriptError::InvalidBinding);
+            }
+        }
+    }
+
+    // The arguments of a function are pushed to the stack before it starts, with `undefined` for
+    // the missing ones.
+    verify_stack(code, code.params.parameters.len())?;
+
+    // The environments of a function are the environments it captured, and its function
+    // environment.
+    for function in &decoded.functions {
+        verify_code_block(function, environments + 1, max_bindings)?;
+    }
+
+    Ok(())
+}
+
+/// Verifies that no instruction of a code block pops more values than the stack holds, if it
+/// starts with `depth` values.
+///
+/// The verifier computes a lower bound of the depth of the stack at every reachable instruction.
+/// The bound of an instruction decreases when it is reached by a path with fewer values on the
+/// stack. For an instruction that was already checked, that path jumps back to it, like a loop
+/// that pops more values than it pushes: its bound is then lowered to zero, so that every
+/// instruction is checked at most twice.
+fn verify_stack(code: &CodeBlock, depth: usize) -> Result<(), ScriptError> {
+    let bytes = &code.code;
+    if bytes.is_empty() {
+        return Ok(());
+    }
+
+    let mut bounds = vec![None; bytes.len()];
+    let mut checked = vec![false; bytes.len()];
+    let mut pending = BTreeSet::new();
+    bounds[0] = Some(depth);
+    pending.insert(0);
+
+    // The instructions are checked in the order of the bytecode, so that the bound of an
+    // instruction is known from all its predecessors before it is checked, except for the jumps
+    // back to the start of a loop.
+    while let Some(pc) = pending.pop_first() {
+        let depth = bounds[pc].expect("pending instructions must have a bound");
+        checked[pc] = true;
+
+        let (opcode, operands, next) = read_instruction(bytes, pc);
+        let effect = stack_effect(opcode, operands[0]);
+        let rest = depth
+            .checked_sub(effect.pops)
+            .ok_or(ScriptError::StackUnderflow { pc })?;
+
+        let mut enter = |target: usize, depth: usize| {
+            // The code block returns at the end of its bytecode.
+            if target == bytes.len() {
+                return;
+            }
+            match bounds[target] {
+                Some(bound) if bound <= depth => return,
+                Some(_) if checked[target] => bounds[target] = Some(0),
+                _ => bounds[target] = Some(depth),
+            }
+            pending.insert(target);
+        };
+
+        match opcode {
+            // A caught error is pushed before jumping to the `catch` block, and a `return`
+            // leaves its value on the stack when it jumps to the `finally` block.
+            Opcode::TryStart => {
+                enter(operands[0], depth + 1);
+                if operands[1] != 0 {
+                    enter(operands[1], depth + 1);
+                }
+            }
+            // An error thrown by a `catch` block replaces the caught error on the stack before
+            // jumping to the `finally` block.
+            Opcode::CatchStart => enter(operands[0], depth),
+            // `FinallyEnd` jumps to the address of a `break` or a `continue` that left a `try`
+            // block, with the stack of the `try` block.
+            Opcode::FinallySetJump => enter(operands[0], depth),
+            Opcode::JumpTable => {
+                for (_, address) in code.jump_tables[operands[0]].entries() {
+                    enter(*address as usize, rest + effect.jump);
+                }
+            }
+            _ if opcode.operands().first() == Some(&Operand::Address) => {
+                enter(operands[0], rest + effect.jump);
+            }
+            _ => {}
+        }
+        if let Some(pushes) = effect.next {
+            enter(next, rest + pushes);
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads the instruction at `pc` of verified bytecode.
+///
+/// Returns the opcode, the values of the 32-bit operands of the instruction, and the offset of
+/// the next instruction.
+fn read_instruction(bytes: &[u8], pc: usize) -> (Opcode, [usize; 3], usize) {
+    let opcode = Opcode::try_from(bytes[pc]).expect("bytecode must have been verified");
+    let mut operands = [0; 3];
+    let mut offset = pc + size_of::<Opcode>();
+    for (operand, value) in opcode.operands().iter().zip(&mut operands) {
+        if operand.size() == size_of::<u32>() {
+            let mut bytes_of_value = [0; 4];
+            bytes_of_value.copy_from_slice(&bytes[offset..offset + size_of::<u32>()]);
+            *value = u32::from_ne_bytes(bytes_of_value) as usize;
+        }
+        offset += operand.size();
+    }
+    (opcode, operands, offset)
+}
+
+/// The effect of an instruction on the stack of its call frame.
+struct StackEffect {
+    /// The number of values popped by the instruction.
+    pops: usize,
+    /// The number of values pushed before continuing with the next instruction, or `None` if the
+    /// instruction never continues with the next one.
+    next: Option<usize>,
+    /// The number of values pushed before jumping to the address of the instruction.
+    jump: usize,
+}
+
+impl StackEffect {
+    /// The effect of an instruction that pops `pops` values and pushes `pushes` values.
+    const fn new(pops: usize, pushes: usize) -> Self {
+        Self {
+            pops,
+            next: Some(pushes),
+            jump: pushes,
+        }
+    }
+
+    /// The effect of a conditional jump that pops `pops` values, and pushes `next` values if it
+    /// continues with the next instruction or `jump` values if it jumps.
+    const fn branch(pops: usize, next: usize, jump: usize) -> Self {
+        Self {
+            pops,
+            next: Some(next),
+            jump,
+        }
+    }
+
+    /// The effect of an instruction that pops `pops` values and never continues with the next
+    /// instruction.
+    const fn exit(pops: usize) -> Self {
+        Self {
+            pops,
+            next: None,
+            jump: 0,
+        }
+    }
+}
+
+/// Returns the effect of an instruction on the stack, given the value of its first operand.
+///
+/// A generator that is resumed pushes the value it receives, which is taken by the
+/// `GeneratorNext` that follows the `Yield` that suspended it.
+fn stack_effect(opcode: Opcode, count: usize) -> StackEffect {
+    match opcode {
+        Opcode::Nop
+        | Opcode::DefVar
+        | Opcode::DefLet
+        | Opcode::DefVarLocal
+        | Opcode::TryStart
+        | Opcode::TryEnd
+        | Opcode::CatchStart
+        | Opcode::CatchEnd
+        | Opcode::CatchEnd2
+        | Opcode::FinallyStart
+        | Opcode::FinallyEnd
+        | Opcode::FinallySetJump
+        | Opcode::PushDisposeScope
+        | Opcode::DisposeResources
+        | Opcode::PushDeclarativeEnvironment
+        | Opcode::PushFunctionEnvironment
+        | Opcode::PopEnvironment
+        | Opcode::LoopStart
+        | Opcode::LoopContinue
+        | Opcode::LoopEnd
+        | Opcode::RestParameterPop
+        | Opcode::PopOnReturnAdd
+        | Opcode::PopOnReturnSub => StackEffect::new(0, 0),
+        Opcode::PushZero
+        | Opcode::PushOne
+        | Opcode::PushInt8
+        | Opcode::PushInt16
+        | Opcode::PushInt32
+        | Opcode::PushRational
+        | Opcode::PushNaN
+        | Opcode::PushPositiveInfinity
+        | Opcode::PushNegativeInfinity
+        | Opcode::PushNull
+        | Opcode::PushTrue
+        | Opcode::PushFalse
+        | Opcode::PushUndefined
+        | Opcode::PushLiteral
+        | Opcode::PushEmptyObject
+        | Opcode::PushNewArray
+        | Opcode::GetName
+        | Opcode::GetNameOrUndefined
+        | Opcode::GetLocal
+        | Opcode::GetLocalOrUndefined
+        | Opcode::This
+        | Opcode::GetFunction
+        | Opcode::GetGenerator
+        | Opcode::GeneratorNext => StackEffect::new(0, 1),
+        Opcode::Pop
+        | Opcode::DefInitArg
+        | Opcode::DefInitVar
+        | Opcode::DefInitLet
+        | Opcode::DefInitConst
+        | Opcode::SetName
+        | Opcode::SetLocal
+        | Opcode::DefInitLocal
+        | Opcode::JumpIfFalse
+        | Opcode::Yield => StackEffect::new(1, 0),
+        Opcode::PushElisionToArray
+        | Opcode::BitNot
+        | Opcode::TypeOf
+        | Opcode::Void
+        | Opcode::LogicalNot
+        | Opcode::Pos
+        | Opcode::Neg
+        | Opcode::Inc
+        | Opcode::Dec
+        | Opcode::GetPropertyByName
+        | Opcode::DeletePropertyByName
+        | Opcode::AddDisposableResource
+        | Opcode::ToBoolean
+        | Opcode::RequireObjectCoercible
+        | Opcode::ValueNotNullOrUndefined
+        | Opcode::RestParameterInit => StackEffect::new(1, 1),
+        Opcode::Dup | Opcode::IncPost | Opcode::DecPost | Opcode::InitIterator => {
+            StackEffect::new(1, 2)
+        }
+        Opcode::PushValueToArray
+        | Opcode::Add
+        | Opcode::Sub
+        | Opcode::Div
+        | Opcode::Mul
+        | Opcode::Mod
+        | Opcode::Pow
+        | Opcode::ShiftRight
+        | Opcode::ShiftLeft
+        | Opcode::UnsignedShiftRight
+        | Opcode::BitOr
+        | Opcode::BitAnd
+        | Opcode::BitXor
+        | Opcode::In
+        | Opcode::Eq
+        | Opcode::StrictEq
+        | Opcode::NotEq
+        | Opcode::StrictNotEq
+        | Opcode::GreaterThan
+        | Opcode::GreaterThanOrEq
+        | Opcode::LessThan
+        | Opcode::LessThanOrEq
+        | Opcode::InstanceOf
+        | Opcode::GetPropertyByValue
+        | Opcode::DeletePropertyByValue => StackEffect::new(2, 1),
+        Opcode::SetPropertyByName
+        | Opcode::DefineOwnPropertyByName
+        | Opcode::SetPropertyGetterByName
+        | Opcode::SetPropertySetterByName => StackEffect::new(2, 0),
+        Opcode::Swap => StackEffect::new(2, 2),
+        Opcode::IteratorNext | Opcode::IteratorToArray => StackEffect::new(2, 3),
+        Opcode::IteratorNextFull => StackEffect::new(2, 4),
+        Opcode::SetPropertyByValue
+        | Opcode::DefineOwnPropertyByValue
+        | Opcode::SetPropertyGetterByValue
+        | Opcode::SetPropertySetterByValue
+        | Opcode::IteratorClose => StackEffect::new(3, 0),
+        Opcode::PushIteratorToArray => StackEffect::new(3, 1),
+        Opcode::ConcatToString => StackEffect::new(count, 1),
+        Opcode::TemplateCreate => StackEffect::new(count * 2, 1),
+        Opcode::New | Opcode::NewWithRest => StackEffect::new(count + 1, 1),
+        Opcode::Call | Opcode::CallWithRest | Opcode::TailCall | Opcode::CopyDataProperties => {
+            StackEffect::new(count + 2, 1)
+        }
+        Opcode::JumpIfNotUndefined | Opcode::LogicalAnd | Opcode::LogicalOr | Opcode::Coalesce => {
+            StackEffect::branch(1, 0, 1)
+        }
+        Opcode::Case => StackEffect::branch(2, 1, 0),
+        Opcode::JumpTable => StackEffect::branch(1, 1, 0),
+        Opcode::ForInLoopInitIterator => StackEffect::branch(1, 2, 0),
+        Opcode::ForInLoopNext => StackEffect::branch(2, 3, 2),
+        Opcode::GeneratorNextDelegate => StackEffect::branch(3, 3, 1),
+        Opcode::TemplateLookup => StackEffect::branch(0, 0, 1),
+        Opcode::Jump => StackEffect::exit(0),
+        Opcode::Default | Opcode::Throw | Opcode::Return => StackEffect::exit(1),
+    }
+}
+
+/// Returns the offsets of the tagged template call sites in verified bytecode.
+pub(super) fn template_sites(bytes: &[u8]) -> Vec<usize> {
+    let mut sites = Vec::new();
+    let mut pc = 0;
+    while pc < bytes.len() {
+        let opcode = Opcode::try_from(bytes[pc]).expect("bytecode must have been verified");
+        pc += size_of::<Opcode>();
+        for operand in opcode.operands() {
+            if *operand == Operand::Site {
+                sites.push(pc);
+            }
+            pc += operand.size();
+        }
+    }
+    sites
+}
@@ -0,0 +1,334 @@
+//! The deserializer of Rust values from JavaScript values.
+
+use super::Error;
+use crate::{object::JsObject, property::PropertyNameKind, Context, JsString, JsValue};
+use serde::{
+    de::{self, DeserializeSeed, IntoDeserializer, Visitor},
+    forward_to_deserialize_any,
+};
+
+/// The largest integer exactly representable by a JavaScript number, `Number.MAX_SAFE_INTEGER`.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+/// A [`serde::Deserializer`] reading a [`JsValue`] in a [`Context`].
+#[derive(Debug)]
+pub struct Deserializer<'context> {
+    value: JsValue,
+    context: &'context mut Context,
+}
+
+impl<'context> Deserializer<'context> {
+    /// Creates a deserializer reading `value` in `context`.
+    #[inline]
+    pub fn new(value: JsValue, context: &'context mut Context) -> Self {
+        Self { value, context }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer<'_> {
+    type Error = Error;
+
+    #[allow(clippy::float_cmp)]
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.value {
+            JsValue::Null | JsValue::Undefined => visitor.visit_unit(),
+            JsValue::Boolean(boolean) => visitor.visit_bool(boolean),
+            JsValue::Integer(integer) => visitor.visit_i32(integer),
+            JsValue::Rational(number)
+                if number.fract() == 0.0 && number.abs() <= MAX_SAFE_INTEGER =>
+            {
+                visitor.visit_i64(number as i64)
+            }
+            JsValue::Rational(number) => visitor.visit_f64(number),
+            JsValue::String(ref string) => visitor.visit_str(string.as_str()),
+            JsValue::BigInt(ref bigint) => {
+                if let Some(integer) = bigint.to_i64() {
+                    visitor.visit_i64(integer)
+                } else if let Some(integer) = bigint.to_u64() {
+                    visitor.visit_u64(integer)
+                } else if let Some(integer) = bigint.to_i128() {
+                    visitor.visit_i128(integer)
+                } else if let Some(integer) = bigint.to_u128() {
+                    visitor.visit_u128(integer)
+                } else {
+                    Err(Error::Message(format!(
+                        "the BigInt {bigint}n is too large to be deserialized"
+                    )))
+                }
+            }
+            JsValue::Symbol(_) => Err(Error::Message("cannot deserialize a Symbol".to_owned())),
+            JsValue::Object(ref object) if object.is_callable() => {
+                Err(Error::Message("cannot deserialize a function".to_owned()))
+            }
+            JsValue::Object(object) if object.is_array() => {
+                let length = object.length_of_array_like(self.context)?;
+                visitor.visit_seq(SeqDeserializer {
+                    object,
+                    index: 0,
+                    length,
+                    context: self.context,
+                })
+            }
+            JsValue::Object(object) => {
+                let keys =
+                    object.enumerable_own_property_names(PropertyNameKind::Key, self.context)?;
+                visitor.visit_map(MapDeserializer {
+                    object,
+                    keys: keys.into_iter(),
+                    value: None,
+                    context: self.context,
+                })
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.value.is_null_or_undefined() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.value {
+            JsValue::String(variant) => visitor.visit_enum(EnumDeserializer {
+                variant,
+                value: None,
+                context: self.context,
+            }),
+            JsValue::Object(ref object) if !object.is_array() && !object.is_callable() => {
+                let mut keys =
+                    object.enumerable_own_property_names(PropertyNameKind::Key, self.context)?;
+                let variant = match (keys.pop(), keys.is_empty()) {
+                    (Some(JsValue::String(variant)), true) => variant,
+                    _ => {
+                        return Err(Error::Message(
+                            "expected an object with a single property naming the variant"
+                                .to_owned(),
+                        ))
+                    }
+                };
+                let value = object.get(variant.clone(), self.context)?;
+                visitor.visit_enum(EnumDeserializer {
+                    variant,
+                    value: Some(value),
+                    context: self.context,
+                })
+            }
+            _ => Err(Error::Message(
+                "expected a string or an object naming the variant".to_owned(),
+            )),
+        }
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit
+        unit_struct seq tuple tuple_struct map struct identifier
+    }
+}
+
+/// Deserializes the elements of an array.
+struct SeqDeserializer<'context> {
+    object: JsObject,
+    index: usize,
+    length: usize,
+    context: &'context mut Context,
+}
+
+impl<'de> de::SeqAccess<'de> for SeqDeserializer<'_> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        if self.index >= self.length {
+            return Ok(None);
+        }
+        let value = self.object.get(self.index, self.context)?;
+        self.index += 1;
+        seed.deserialize(Deserializer::new(value, self.context))
+            .map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.length - self.index)
+    }
+}
+
+/// Deserializes the enumerable own string properties of an object.
+struct MapDeserializer<'context> {
+    object: JsObject,
+    keys: std::vec::IntoIter<JsValue>,
+    /// The value of the property whose key was deserialized last.
+    value: Option<JsValue>,
+    context: &'context mut Context,
+}
+
+impl<'de> de::MapAccess<'de> for MapDeserializer<'_> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        let name = match self.keys.next() {
+            Some(JsValue::String(name)) => name,
+            Some(_) => unreachable!("the enumerable own property names are strings"),
+            None => return Ok(None),
+        };
+        self.value = Some(self.object.get(name.clone(), self.context)?);
+        seed.deserialize(PropertyNameDeserializer { name })
+            .map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let value = self
+            .value
+            .take()
+            .expect("next_value_seed is called after next_key_seed");
+        seed.deserialize(Deserializer::new(value, self.context))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.keys.len())
+    }
+}
+
+/// Deserializes a property name, parsing it for the map keys of integer types.
+struct PropertyNameDeserializer {
+    name: JsString,
+}
+
+/// Implements the deserialization of integers by parsing the property name.
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                match self.name.as_str().parse() {
+                    Ok(integer) => visitor.$visit(integer),
+                    Err(_) => Err(Error::Message(format!(
+                        "expected an integer property name, got `{}`",
+                        self.name.as_str()
+                    ))),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for PropertyNameDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_str(self.name.as_str())
+    }
+
+    deserialize_parsed! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+    }
+
+    forward_to_deserialize_any! {
+        bool f32 f64 char str string bytes byte_buf option unit unit_struct newtype_struct seq
+        tuple tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// Deserializes an enum from the name of its variant and the value of the variant, if any.
+struct EnumDeserializer<'context> {
+    variant: JsString,
+    value: Option<JsValue>,
+    context: &'context mut Context,
+}
+
+impl<'de, 'context> de::EnumAccess<'de> for EnumDeserializer<'context> {
+    type Error = Error;
+    type Variant = VariantDeserializer<'context>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, VariantDeserializer<'context>), Error> {
+        let variant = seed.deserialize(self.variant.as_str().into_deserializer())?;
+        Ok((
+            variant,
+            VariantDeserializer {
+                value: self.value,
+                context: self.context,
+            },
+        ))
+    }
+}
+
+/// Deserializes the value of an enum variant.
+struct VariantDeserializer<'context> {
+    value: Option<JsValue>,
+    context: &'context mut Context,
+}
+
+impl<'context> VariantDeserializer<'context> {
+    /// Returns the value of the variant, or an error for unit variants.
+    fn value(self, expected: &str) -> Result<Deserializer<'context>, Error> {
+        match self.value {
+            Some(value) => Ok(Deserializer::new(value, self.context)),
+            None => Err(Error::Message(format!(
+                "expected {expected}, got a unit variant"
+            ))),
+        }
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for VariantDeserializer<'_> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        match self.value {
+            None => Ok(()),
+            Some(value) if value.is_null_or_undefined() => Ok(()),
+            Some(_) => Err(Error::Message("expected a unit variant".to_owned())),
+        }
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        seed.deserialize(self.value("a newtype variant")?)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_seq(self.value("a tuple variant")?, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_map(self.value("a struct variant")?, visitor)
+    }
+}
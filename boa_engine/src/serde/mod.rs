@@ -0,0 +1,129 @@
+//! Conversions between Rust values and JavaScript values with [`serde`](::serde).
+//!
+//! The [`Serializer`] builds a [`JsValue`] from any type implementing `Serialize`, and the
+//! [`Deserializer`] reads any type implementing `Deserialize` from a [`JsValue`], without going
+//! through an intermediate format like [`serde_json::Value`](::serde_json::Value).
+//!
+//! The data model of serde is mapped to JavaScript values like JSON does:
+//!  - Booleans and strings are converted to their JavaScript equivalents, and characters to
+//!    strings.
+//!  - Numbers are converted to numbers, except for the integers outside of the safe integer
+//!    range, which are converted to `BigInt`s. Integral numbers can be deserialized as integers.
+//!  - `None` and the unit types are converted to `null`, and `null` or `undefined` deserialize to
+//!    `None` and the unit types.
+//!  - Sequences, tuples and byte arrays are converted to arrays.
+//!  - Maps and structs are converted to ordinary objects, whose keys are the map keys converted
+//!    to property keys. Objects are deserialized from their enumerable own string properties.
+//!  - Enum variants are converted like `serde_json` does by default: a unit variant to its name,
+//!    and the other variants to an object with a single property named after the variant.
+//!
+//! # Examples
+//!
+//! ```
+//! use boa_engine::{Context, JsValue};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Point {
+//!     x: i32,
+//!     y: i32,
+//! }
+//!
+//! let mut context = Context::default();
+//! let value = boa_engine::serde::to_value(&Point { x: 1, y: 2 }, &mut context).unwrap();
+//! let moved = context
+//!     .eval("(point) => ({ x: point.x + 1, y: point.y * 2 })")
+//!     .unwrap()
+//!     .as_callable()
+//!     .unwrap()
+//!     .call(&JsValue::undefined(), &[value], &mut context)
+//!     .unwrap();
+//!
+//! let point: Point = boa_engine::serde::from_value(moved, &mut context).unwrap();
+//! assert_eq!(point, Point { x: 2, y: 4 });
+//! ```
+
+mod de;
+mod ser;
+
+#[cfg(test)]
+mod tests;
+
+pub use de::Deserializer;
+pub use ser::{SerializeArray, SerializeObject, Serializer};
+
+use crate::{Context, JsResult, JsValue};
+use std::fmt;
+
+/// Converts `value` to a `JsValue`.
+///
+/// Throws a `TypeError` if `value` can't be serialized.
+pub fn to_value<T>(value: &T, context: &mut Context) -> JsResult<JsValue>
+where
+    T: ::serde::Serialize + ?Sized,
+{
+    value
+        .serialize(Serializer::new(context))
+        .map_err(|e| e.into_value(context))
+}
+
+/// Converts the JavaScript `value` to a `T`.
+///
+/// Throws the errors thrown by the getters of the objects of `value`, and a `TypeError` if
+/// `value` doesn't have the shape of a `T`.
+pub fn from_value<T>(value: JsValue, context: &mut Context) -> JsResult<T>
+where
+    T: ::serde::de::DeserializeOwned,
+{
+    T::deserialize(Deserializer::new(value, context)).map_err(|e| e.into_value(context))
+}
+
+/// An error of the [`Serializer`] or the [`Deserializer`].
+#[derive(Debug, Clone)]
+pub enum Error {
+    /// A JavaScript value thrown while converting, for example by a getter.
+    Thrown(JsValue),
+    /// A value that doesn't fit the data model of JavaScript, or a JavaScript value that doesn't
+    /// have the expected shape.
+    Message(String),
+}
+
+impl Error {
+    /// Converts the error to a JavaScript value, a `TypeError` for [`Error::Message`].
+    pub fn into_value(self, context: &mut Context) -> JsValue {
+        match self {
+            Self::Thrown(value) => value,
+            Self::Message(message) => context.construct_type_error(message),
+        }
+    }
+}
+
+impl From<JsValue> for Error {
+    #[inline]
+    fn from(value: JsValue) -> Self {
+        Self::Thrown(value)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Thrown(value) => write!(f, "uncaught {}", value.display()),
+            Self::Message(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ::serde::ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::Message(msg.to_string())
+    }
+}
+
+impl ::serde::de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::Message(msg.to_string())
+    }
+}
@@ -0,0 +1,397 @@
+//! The serializer of Rust values to JavaScript values.
+
+use super::Error;
+use crate::{builtins::Array, object::JsObject, Context, JsBigInt, JsValue};
+use serde::ser::{self, Serialize};
+
+/// The largest integer exactly representable by a JavaScript number, `Number.MAX_SAFE_INTEGER`.
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+/// A [`serde::Serializer`] building [`JsValue`]s in a [`Context`].
+#[derive(Debug)]
+pub struct Serializer<'context> {
+    context: &'context mut Context,
+}
+
+impl<'context> Serializer<'context> {
+    /// Creates a serializer building values in `context`.
+    #[inline]
+    pub fn new(context: &'context mut Context) -> Self {
+        Self { context }
+    }
+
+    /// Creates an object with the single property `variant`, holding `value`.
+    fn variant_object(&mut self, variant: &'static str, value: JsValue) -> Result<JsValue, Error> {
+        let object = self.context.construct_object();
+        object.create_data_property_or_throw(variant, value, self.context)?;
+        Ok(object.into())
+    }
+}
+
+impl<'context> ser::Serializer for Serializer<'context> {
+    type Ok = JsValue;
+    type Error = Error;
+    type SerializeSeq = SerializeArray<'context>;
+    type SerializeTuple = SerializeArray<'context>;
+    type SerializeTupleStruct = SerializeArray<'context>;
+    type SerializeTupleVariant = SerializeArray<'context>;
+    type SerializeMap = SerializeObject<'context>;
+    type SerializeStruct = SerializeObject<'context>;
+    type SerializeStructVariant = SerializeObject<'context>;
+
+    fn serialize_bool(self, v: bool) -> Result<JsValue, Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<JsValue, Error> {
+        Ok(i32::from(v).into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<JsValue, Error> {
+        Ok(i32::from(v).into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<JsValue, Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<JsValue, Error> {
+        Ok(if let Ok(v) = i32::try_from(v) {
+            v.into()
+        } else if v.unsigned_abs() <= MAX_SAFE_INTEGER {
+            JsValue::Rational(v as f64)
+        } else {
+            JsBigInt::from(v).into()
+        })
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<JsValue, Error> {
+        match i64::try_from(v) {
+            Ok(v) => self.serialize_i64(v),
+            Err(_) => Ok(JsBigInt::from(v).into()),
+        }
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<JsValue, Error> {
+        Ok(i32::from(v).into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<JsValue, Error> {
+        Ok(i32::from(v).into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<JsValue, Error> {
+        self.serialize_u64(u64::from(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<JsValue, Error> {
+        Ok(if let Ok(v) = i32::try_from(v) {
+            v.into()
+        } else if v <= MAX_SAFE_INTEGER {
+            JsValue::Rational(v as f64)
+        } else {
+            JsBigInt::from(v).into()
+        })
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<JsValue, Error> {
+        match u64::try_from(v) {
+            Ok(v) => self.serialize_u64(v),
+            Err(_) => Ok(JsBigInt::from(v).into()),
+        }
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<JsValue, Error> {
+        Ok(JsValue::Rational(f64::from(v)))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<JsValue, Error> {
+        Ok(JsValue::Rational(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<JsValue, Error> {
+        Ok(v.to_string().into())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<JsValue, Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<JsValue, Error> {
+        let elements = v.iter().map(|byte| i32::from(*byte).into());
+        Ok(Array::create_array_from_list(elements, self.context).into())
+    }
+
+    fn serialize_none(self) -> Result<JsValue, Error> {
+        Ok(JsValue::null())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<JsValue, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<JsValue, Error> {
+        Ok(JsValue::null())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<JsValue, Error> {
+        Ok(JsValue::null())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<JsValue, Error> {
+        Ok(variant.into())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<JsValue, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        mut self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<JsValue, Error> {
+        let value = value.serialize(Serializer::new(self.context))?;
+        self.variant_object(variant, value)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeArray<'context>, Error> {
+        Ok(SerializeArray {
+            context: self.context,
+            elements: Vec::with_capacity(len.unwrap_or_default()),
+            variant: None,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeArray<'context>, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeArray<'context>, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeArray<'context>, Error> {
+        Ok(SerializeArray {
+            context: self.context,
+            elements: Vec::with_capacity(len),
+            variant: Some(variant),
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<SerializeObject<'context>, Error> {
+        Ok(SerializeObject {
+            object: self.context.construct_object(),
+            context: self.context,
+            key: None,
+            variant: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeObject<'context>, Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<SerializeObject<'context>, Error> {
+        Ok(SerializeObject {
+            object: self.context.construct_object(),
+            context: self.context,
+            key: None,
+            variant: Some(variant),
+        })
+    }
+}
+
+/// Serializes sequences, tuples and tuple variants to arrays.
+#[derive(Debug)]
+pub struct SerializeArray<'context> {
+    context: &'context mut Context,
+    elements: Vec<JsValue>,
+    /// The variant wrapping the array, for tuple variants.
+    variant: Option<&'static str>,
+}
+
+impl SerializeArray<'_> {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let value = value.serialize(Serializer::new(self.context))?;
+        self.elements.push(value);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<JsValue, Error> {
+        let array = Array::create_array_from_list(self.elements, self.context).into();
+        match self.variant {
+            Some(variant) => Serializer::new(self.context).variant_object(variant, array),
+            None => Ok(array),
+        }
+    }
+}
+
+impl ser::SerializeSeq for SerializeArray<'_> {
+    type Ok = JsValue;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<JsValue, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for SerializeArray<'_> {
+    type Ok = JsValue;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<JsValue, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeArray<'_> {
+    type Ok = JsValue;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<JsValue, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for SerializeArray<'_> {
+    type Ok = JsValue;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<JsValue, Error> {
+        self.finish()
+    }
+}
+
+/// Serializes maps, structs and struct variants to objects.
+#[derive(Debug)]
+pub struct SerializeObject<'context> {
+    context: &'context mut Context,
+    object: JsObject,
+    /// The key of the map entry whose value is serialized next.
+    key: Option<JsValue>,
+    /// The variant wrapping the object, for struct variants.
+    variant: Option<&'static str>,
+}
+
+impl SerializeObject<'_> {
+    fn insert<T: Serialize + ?Sized>(&mut self, key: JsValue, value: &T) -> Result<(), Error> {
+        let key = key.to_property_key(self.context)?;
+        let value = value.serialize(Serializer::new(self.context))?;
+        self.object
+            .create_data_property_or_throw(key, value, self.context)?;
+        Ok(())
+    }
+
+    fn finish(self) -> Result<JsValue, Error> {
+        match self.variant {
+            Some(variant) => {
+                Serializer::new(self.context).variant_object(variant, self.object.into())
+            }
+            None => Ok(self.object.into()),
+        }
+    }
+}
+
+impl ser::SerializeMap for SerializeObject<'_> {
+    type Ok = JsValue;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        self.key = Some(key.serialize(Serializer::new(self.context))?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .key
+            .take()
+            .expect("serialize_value is called after serialize_key");
+        self.insert(key, value)
+    }
+
+    fn end(self) -> Result<JsValue, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for SerializeObject<'_> {
+    type Ok = JsValue;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.insert(key.into(), value)
+    }
+
+    fn end(self) -> Result<JsValue, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeObject<'_> {
+    type Ok = JsValue;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.insert(key.into(), value)
+    }
+
+    fn end(self) -> Result<JsValue, Error> {
+        self.finish()
+    }
+}
@@ -0,0 +1,142 @@
+use super::{from_value, to_value};
+use crate::{Context, JsBigInt, JsValue};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    name: String,
+    retries: u8,
+    ratio: f64,
+    tags: Vec<String>,
+    limit: Option<u32>,
+    mode: Mode,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Mode {
+    Off,
+    Fixed(u32),
+    Range(i32, i32),
+    Custom { label: String },
+}
+
+#[test]
+fn round_trip() {
+    let mut context = Context::default();
+
+    let config = Config {
+        name: "boa".to_owned(),
+        retries: 3,
+        ratio: 0.5,
+        tags: vec!["fast".to_owned(), "safe".to_owned()],
+        limit: None,
+        mode: Mode::Custom {
+            label: "custom".to_owned(),
+        },
+    };
+    let value = to_value(&config, &mut context).unwrap();
+    assert_eq!(
+        value.to_json(&mut context).unwrap(),
+        serde_json::json!({
+            "name": "boa",
+            "retries": 3,
+            "ratio": 0.5,
+            "tags": ["fast", "safe"],
+            "limit": null,
+            "mode": { "Custom": { "label": "custom" } },
+        })
+    );
+    assert_eq!(from_value::<Config>(value, &mut context).unwrap(), config);
+
+    for mode in [Mode::Off, Mode::Fixed(7), Mode::Range(-1, 1)] {
+        let value = to_value(&mode, &mut context).unwrap();
+        assert_eq!(from_value::<Mode>(value, &mut context).unwrap(), mode);
+    }
+
+    let map: HashMap<u32, bool> = [(1, true), (2, false)].into_iter().collect();
+    let value = to_value(&map, &mut context).unwrap();
+    assert_eq!(
+        from_value::<HashMap<u32, bool>>(value, &mut context).unwrap(),
+        map
+    );
+}
+
+#[test]
+fn from_script_values() {
+    let mut context = Context::default();
+
+    let value = context
+        .eval(
+            r#"({
+                name: "script",
+                retries: 2,
+                ratio: 1,
+                tags: ["a"],
+                mode: "Off",
+                ignored() { return 1; },
+            })"#,
+        )
+        .unwrap();
+    let config: Config = from_value(value, &mut context).unwrap();
+    assert_eq!(
+        config,
+        Config {
+            name: "script".to_owned(),
+            retries: 2,
+            ratio: 1.0,
+            tags: vec!["a".to_owned()],
+            limit: None,
+            mode: Mode::Off,
+        }
+    );
+
+    let value = context.eval("({ Range: [2, 4] })").unwrap();
+    assert_eq!(
+        from_value::<Mode>(value, &mut context).unwrap(),
+        Mode::Range(2, 4)
+    );
+}
+
+#[test]
+fn big_integers() {
+    let mut context = Context::default();
+
+    let value = to_value(&u64::MAX, &mut context).unwrap();
+    assert_eq!(value, JsValue::from(JsBigInt::from(u64::MAX)));
+    assert_eq!(from_value::<u64>(value, &mut context).unwrap(), u64::MAX);
+
+    let value = to_value(&(1_i64 << 40), &mut context).unwrap();
+    assert_eq!(value, JsValue::Rational((1_i64 << 40) as f64));
+    assert_eq!(from_value::<i64>(value, &mut context).unwrap(), 1 << 40);
+
+    let value = to_value(&i128::MIN, &mut context).unwrap();
+    assert_eq!(from_value::<i128>(value, &mut context).unwrap(), i128::MIN);
+}
+
+#[test]
+fn errors() {
+    let mut context = Context::default();
+
+    let value = context.eval("({ name: 1 })").unwrap();
+    let error = from_value::<Config>(value, &mut context).unwrap_err();
+    assert!(error.is_object());
+    let message = error
+        .as_object()
+        .unwrap()
+        .get("message", &mut context)
+        .unwrap();
+    assert!(!message.is_undefined());
+
+    let value = context.eval("1.5").unwrap();
+    assert!(from_value::<u32>(value, &mut context).is_err());
+
+    let value = context.eval("Symbol()").unwrap();
+    assert!(from_value::<String>(value, &mut context).is_err());
+
+    let value = context.eval("({ get name() { throw 'getter' } })").unwrap();
+    assert_eq!(
+        from_value::<Config>(value, &mut context).unwrap_err(),
+        JsValue::from("getter")
+    );
+}
@@ -3,7 +3,7 @@ use boa_gc::{unsafe_empty_trace, Finalize, Trace};
 use rustc_hash::FxHashSet;
 use std::{
     alloc::{alloc, dealloc, handle_alloc_error, Layout},
-    borrow::Borrow,
+    borrow::{Borrow, Cow},
     cell::Cell,
     hash::{Hash, Hasher},
     marker::PhantomData,
@@ -405,6 +405,37 @@ impl JsString {
         }
     }
 
+    /// Returns the JavaScript string as a rust `&str` if it only contains ASCII characters.
+    ///
+    /// ASCII-only strings have the same length in UTF-8 and UTF-16 code units, so byte offsets
+    /// into the returned slice are also valid ECMAScript string indices.
+    #[inline]
+    pub fn as_ascii(&self) -> Option<&str> {
+        let s = self.as_str();
+        if s.is_ascii() {
+            Some(s)
+        } else {
+            None
+        }
+    }
+
+    /// Return the JavaScript string as a `Cow<str>`, borrowing from the string data.
+    ///
+    /// This never allocates, since the string is already stored as UTF-8.
+    #[inline]
+    pub fn to_cow_str(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.as_str())
+    }
+
+    /// Converts the JavaScript string into an owned rust `String`.
+    ///
+    /// The conversion is lossless, since the string is already stored as valid UTF-8; it is
+    /// provided to mirror [`String::from_utf16_lossy`] for code that works on UTF-16 data.
+    #[inline]
+    pub fn to_std_string_lossy(&self) -> String {
+        self.as_str().to_owned()
+    }
+
     /// Gets the number of `JsString`s which point to this allocation.
     #[inline]
     pub fn refcount(this: &Self) -> usize {
@@ -519,6 +550,173 @@ impl JsString {
     }
 }
 
+/// A mutable buffer used to build a [`JsString`] piece by piece.
+///
+/// Besides Rust strings and characters, the builder accepts UTF-16 code units and arbitrary code
+/// points, which is what most of the string builtins operate on. A leading surrogate followed by
+/// a trailing surrogate is combined into a single character; any unpaired surrogate is replaced
+/// with `U+FFFD REPLACEMENT CHARACTER`.
+///
+/// # Examples
+///
+/// ```
+/// use boa_engine::string::JsStringBuilder;
+///
+/// let mut builder = JsStringBuilder::with_capacity(8);
+/// builder.push_str("Hello");
+/// builder.push_code_unit(0xD83D);
+/// builder.push_code_unit(0xDE00);
+/// builder.push_code_point(u32::from('!'));
+///
+/// assert_eq!(builder.build(), "Hello😀!");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct JsStringBuilder {
+    buffer: String,
+    leading_surrogate: Option<u16>,
+}
+
+impl JsStringBuilder {
+    /// Create a new, empty builder.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new, empty builder with space for at least `capacity` bytes of UTF-8.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: String::with_capacity(capacity),
+            leading_surrogate: None,
+        }
+    }
+
+    /// Returns the number of bytes the builder can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buffer.capacity()
+    }
+
+    /// Reserves space for at least `additional` more bytes of UTF-8.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.buffer.reserve(additional);
+    }
+
+    /// Returns the length of the contents of the builder, in bytes of UTF-8.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.buffer.len() + self.leading_surrogate.map_or(0, |_| '\u{FFFD}'.len_utf8())
+    }
+
+    /// Returns `true` if nothing has been pushed to the builder.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty() && self.leading_surrogate.is_none()
+    }
+
+    /// Appends a string slice to the builder.
+    #[inline]
+    pub fn push_str(&mut self, string: &str) {
+        self.flush_surrogate();
+        self.buffer.push_str(string);
+    }
+
+    /// Appends a character to the builder.
+    #[inline]
+    pub fn push(&mut self, c: char) {
+        self.flush_surrogate();
+        self.buffer.push(c);
+    }
+
+    /// Appends a code point to the builder.
+    ///
+    /// Surrogate code points are handled like the equivalent UTF-16 code unit, and values
+    /// above `0x10FFFF` are replaced with `U+FFFD REPLACEMENT CHARACTER`.
+    #[inline]
+    pub fn push_code_point(&mut self, code_point: u32) {
+        if let Some(c) = char::from_u32(code_point) {
+            self.push(c);
+        } else if let Ok(code_unit) = u16::try_from(code_point) {
+            self.push_code_unit(code_unit);
+        } else {
+            self.push(char::REPLACEMENT_CHARACTER);
+        }
+    }
+
+    /// Appends a UTF-16 code unit to the builder.
+    pub fn push_code_unit(&mut self, code_unit: u16) {
+        match code_unit {
+            0xD800..=0xDBFF => {
+                self.flush_surrogate();
+                self.leading_surrogate = Some(code_unit);
+            }
+            0xDC00..=0xDFFF => {
+                if let Some(leading) = self.leading_surrogate.take() {
+                    let code_point = (u32::from(leading) - 0xD800) * 0x400
+                        + (u32::from(code_unit) - 0xDC00)
+                        + 0x10000;
+                    self.buffer.push(
+                        char::from_u32(code_point)
+                            .expect("a surrogate pair always forms a valid code point"),
+                    );
+                } else {
+                    self.buffer.push(char::REPLACEMENT_CHARACTER);
+                }
+            }
+            _ => self.push(char::from_u32(u32::from(code_unit)).expect("not a surrogate")),
+        }
+    }
+
+    /// Consumes the builder, returning the built [`JsString`].
+    #[inline]
+    pub fn build(mut self) -> JsString {
+        self.flush_surrogate();
+        JsString::new(self.buffer)
+    }
+
+    /// Replaces a pending unpaired leading surrogate with `U+FFFD REPLACEMENT CHARACTER`.
+    #[inline]
+    fn flush_surrogate(&mut self) {
+        if self.leading_surrogate.take().is_some() {
+            self.buffer.push(char::REPLACEMENT_CHARACTER);
+        }
+    }
+}
+
+impl Extend<char> for JsStringBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        self.flush_surrogate();
+        self.buffer.extend(iter);
+    }
+}
+
+impl<'a> Extend<&'a str> for JsStringBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        self.flush_surrogate();
+        self.buffer.extend(iter);
+    }
+}
+
+impl Extend<u16> for JsStringBuilder {
+    #[inline]
+    fn extend<I: IntoIterator<Item = u16>>(&mut self, iter: I) {
+        for code_unit in iter {
+            self.push_code_unit(code_unit);
+        }
+    }
+}
+
+impl From<JsStringBuilder> for JsString {
+    #[inline]
+    fn from(builder: JsStringBuilder) -> Self {
+        builder.build()
+    }
+}
+
 // Safety: [`JsString`] does not contain any objects which recquire trace,
 // so this is safe.
 unsafe impl Trace for JsString {
@@ -589,6 +787,27 @@ impl From<String> for JsString {
     }
 }
 
+impl From<Cow<'_, str>> for JsString {
+    #[inline]
+    fn from(s: Cow<'_, str>) -> Self {
+        Self::new(s)
+    }
+}
+
+impl<'a> From<&'a JsString> for Cow<'a, str> {
+    #[inline]
+    fn from(s: &'a JsString) -> Self {
+        s.to_cow_str()
+    }
+}
+
+impl From<JsString> for String {
+    #[inline]
+    fn from(s: JsString) -> Self {
+        s.to_std_string_lossy()
+    }
+}
+
 impl AsRef<str> for JsString {
     #[inline]
     fn as_ref(&self) -> &str {
@@ -677,8 +896,8 @@ impl PartialEq<JsString> for &str {
 
 #[cfg(test)]
 mod tests {
-    use super::JsString;
-    use std::mem::size_of;
+    use super::{JsString, JsStringBuilder};
+    use std::{borrow::Cow, mem::size_of};
 
     #[test]
     fn empty() {
@@ -774,4 +993,49 @@ mod tests {
         assert_eq!(xyzw, "hello, world!");
         assert_eq!(JsString::refcount(&xyzw), 1);
     }
+
+    #[test]
+    fn std_string_views() {
+        let ascii = JsString::new("hello");
+        assert_eq!(ascii.as_ascii(), Some("hello"));
+        assert!(matches!(ascii.to_cow_str(), Cow::Borrowed("hello")));
+        assert_eq!(ascii.to_std_string_lossy(), "hello");
+
+        let non_ascii = JsString::new("héllo");
+        assert_eq!(non_ascii.as_ascii(), None);
+        assert_eq!(Cow::from(&non_ascii), "héllo");
+        assert_eq!(String::from(non_ascii.clone()), "héllo");
+        assert_eq!(JsString::from(Cow::Borrowed("héllo")), non_ascii);
+    }
+
+    #[test]
+    fn builder() {
+        let mut builder = JsStringBuilder::new();
+        assert!(builder.is_empty());
+
+        builder.push_str("hello");
+        builder.push(',');
+        builder.extend(" world".chars());
+        assert_eq!(builder.len(), 12);
+        assert_eq!(builder.build(), "hello, world");
+    }
+
+    #[test]
+    fn builder_surrogates() {
+        let mut builder = JsStringBuilder::with_capacity(16);
+        assert!(builder.capacity() >= 16);
+
+        // A valid surrogate pair, pushed both as code units and as code points.
+        builder.extend([0xD83D_u16, 0xDE00]);
+        builder.push_code_point(0xD83D);
+        builder.push_code_point(0xDE00);
+
+        // Unpaired surrogates.
+        builder.push_code_unit(0xDE00);
+        builder.push_code_unit(0xD83D);
+        builder.push('a');
+        builder.push_code_unit(0xD83D);
+
+        assert_eq!(builder.build(), "😀😀\u{FFFD}\u{FFFD}a\u{FFFD}");
+    }
 }
@@ -21,7 +21,7 @@ use std::{
     cell::Cell,
     fmt::{self, Display},
     hash::{Hash, Hasher},
-    rc::Rc,
+    rc::{self, Rc},
 };
 
 /// A structure that contains the JavaScript well known symbols.
@@ -36,7 +36,9 @@ use std::{
 /// This is equivalent to `let iterator = Symbol.iterator` in JavaScript.
 #[derive(Debug, Clone)]
 pub struct WellKnownSymbols {
+    async_dispose: JsSymbol,
     async_iterator: JsSymbol,
+    dispose: JsSymbol,
     has_instance: JsSymbol,
     is_concat_spreadable: JsSymbol,
     iterator: JsSymbol,
@@ -72,8 +74,12 @@ impl WellKnownSymbols {
     fn new() -> Self {
         let mut count = 0;
 
+        let async_dispose = JsSymbol::with_hash(count, Some("Symbol.asyncDispose".into()));
+        count += 1;
         let async_iterator = JsSymbol::with_hash(count, Some("Symbol.asyncIterator".into()));
         count += 1;
+        let dispose = JsSymbol::with_hash(count, Some("Symbol.dispose".into()));
+        count += 1;
         let has_instance = JsSymbol::with_hash(count, Some("Symbol.hasInstance".into()));
         count += 1;
         let is_concat_spreadable =
@@ -100,7 +106,9 @@ impl WellKnownSymbols {
         let unscopables = JsSymbol::with_hash(count, Some("Symbol.unscopables".into()));
 
         Self {
+            async_dispose,
             async_iterator,
+            dispose,
             has_instance,
             is_concat_spreadable,
             iterator,
@@ -116,6 +124,15 @@ impl WellKnownSymbols {
         }
     }
 
+    /// The `Symbol.asyncDispose` well known symbol.
+    ///
+    /// A method that asynchronously releases the resources held by an object.
+    /// Called by the semantics of `await using` declarations and `AsyncDisposableStack`.
+    #[inline]
+    pub fn async_dispose() -> JsSymbol {
+        WELL_KNOW_SYMBOLS.with(|symbols| symbols.async_dispose.clone())
+    }
+
     /// The `Symbol.asyncIterator` well known symbol.
     ///
     /// A method that returns the default `AsyncIterator` for an object.
@@ -125,6 +142,15 @@ impl WellKnownSymbols {
         WELL_KNOW_SYMBOLS.with(|symbols| symbols.async_iterator.clone())
     }
 
+    /// The `Symbol.dispose` well known symbol.
+    ///
+    /// A method that releases the resources held by an object.
+    /// Called by the semantics of `using` declarations and `DisposableStack`.
+    #[inline]
+    pub fn dispose() -> JsSymbol {
+        WELL_KNOW_SYMBOLS.with(|symbols| symbols.dispose.clone())
+    }
+
     /// The `Symbol.hasInstance` well known symbol.
     ///
     /// A method that determines if a `constructor` object
@@ -299,6 +325,26 @@ impl JsSymbol {
     pub fn descriptive_string(&self) -> JsString {
         self.to_string().into()
     }
+
+    /// Creates a weak reference to the symbol, which doesn't keep it alive.
+    #[inline]
+    pub fn downgrade(&self) -> WeakJsSymbol {
+        WeakJsSymbol {
+            inner: Rc::downgrade(&self.inner),
+        }
+    }
+}
+
+impl Drop for JsSymbol {
+    #[inline]
+    fn drop(&mut self) {
+        // Symbols are reference counted, so they are freed when the last object holding them is
+        // collected. Report it like a collected object, so the `FinalizationRegistry` objects
+        // targeting the symbol get their cleanup scheduled.
+        if Rc::strong_count(&self.inner) == 1 && Rc::weak_count(&self.inner) > 0 {
+            boa_gc::notify_cleared();
+        }
+    }
 }
 
 impl Finalize for JsSymbol {}
@@ -309,6 +355,34 @@ unsafe impl Trace for JsSymbol {
     unsafe_empty_trace!();
 }
 
+/// A weak reference to a [`JsSymbol`], created with [`JsSymbol::downgrade`].
+#[derive(Debug, Clone)]
+pub struct WeakJsSymbol {
+    inner: rc::Weak<Inner>,
+}
+
+impl WeakJsSymbol {
+    /// Returns the symbol, or `None` if it was dropped.
+    #[inline]
+    pub fn upgrade(&self) -> Option<JsSymbol> {
+        self.inner.upgrade().map(|inner| JsSymbol { inner })
+    }
+
+    /// Returns `true` if the symbol was dropped.
+    #[inline]
+    pub fn is_collected(&self) -> bool {
+        self.inner.strong_count() == 0
+    }
+}
+
+impl Finalize for WeakJsSymbol {}
+
+// Safety: `WeakJsSymbol` does not contain any object that require trace,
+// so this is safe.
+unsafe impl Trace for WeakJsSymbol {
+    unsafe_empty_trace!();
+}
+
 impl Display for JsSymbol {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
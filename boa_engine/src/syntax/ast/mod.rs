@@ -6,6 +6,7 @@ pub mod node;
 pub mod op;
 pub mod position;
 pub mod punctuator;
+pub mod visitor;
 
 pub use self::{
     constant::Const,
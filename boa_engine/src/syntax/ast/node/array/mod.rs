@@ -58,6 +58,12 @@ impl AsRef<[Node]> for ArrayDecl {
     }
 }
 
+impl AsMut<[Node]> for ArrayDecl {
+    fn as_mut(&mut self) -> &mut [Node] {
+        &mut self.arr
+    }
+}
+
 impl<T> From<T> for ArrayDecl
 where
     T: Into<Box<[Node]>>,
@@ -25,6 +25,20 @@ pub struct AwaitExpr {
     expr: Box<Node>,
 }
 
+impl AwaitExpr {
+    /// Gets the expression of the await expression.
+    #[inline]
+    pub fn expr(&self) -> &Node {
+        &self.expr
+    }
+
+    /// Gets a mutable reference to the expression of the await expression.
+    #[inline]
+    pub fn expr_mut(&mut self) -> &mut Node {
+        &mut self.expr
+    }
+}
+
 impl<T> From<T> for AwaitExpr
 where
     T: Into<Box<Node>>,
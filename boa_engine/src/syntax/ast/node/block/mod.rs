@@ -40,6 +40,16 @@ impl Block {
         self.statements.items()
     }
 
+    /// Gets the statement list of this block.
+    pub(crate) fn statement_list(&self) -> &StatementList {
+        &self.statements
+    }
+
+    /// Gets a mutable reference to the statement list of this block.
+    pub(crate) fn statement_list_mut(&mut self) -> &mut StatementList {
+        &mut self.statements
+    }
+
     pub(crate) fn lexically_declared_names(&self, interner: &Interner) -> FxHashSet<Sym> {
         self.statements.lexically_declared_names(interner)
     }
@@ -1,4 +1,7 @@
-use crate::syntax::ast::node::{join_nodes, Node};
+use crate::syntax::ast::{
+    node::{join_nodes, Node},
+    Span,
+};
 use boa_gc::{Finalize, Trace};
 use boa_interner::{Interner, ToInternedString};
 
@@ -16,6 +19,9 @@ mod tests;
 /// function in which it is declared (or the entire program, if it is declared at the top
 /// level).
 ///
+/// Calls made by the parser hold their span in the source code, which is ignored when comparing
+/// calls.
+///
 /// More information:
 ///  - [ECMAScript reference][spec]
 ///  - [MDN documentation][mdn]
@@ -23,10 +29,13 @@ mod tests;
 /// [spec]: https://tc39.es/ecma262/#prod-CallExpression
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Functions#Calling_functions
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+#[derive(Clone, Debug, Trace, Finalize)]
 pub struct Call {
     expr: Box<Node>,
     args: Box<[Node]>,
+    #[unsafe_ignore_trace]
+    #[cfg_attr(feature = "deser", serde(default))]
+    span: Option<Span>,
 }
 
 impl Call {
@@ -39,6 +48,7 @@ impl Call {
         Self {
             expr: Box::new(expr.into()),
             args: args.into(),
+            span: None,
         }
     }
 
@@ -47,10 +57,36 @@ impl Call {
         &self.expr
     }
 
+    /// Gets a mutable reference to the name of the function call.
+    pub fn expr_mut(&mut self) -> &mut Node {
+        &mut self.expr
+    }
+
     /// Retrieves the arguments passed to the function.
     pub fn args(&self) -> &[Node] {
         &self.args
     }
+
+    /// Gets a mutable reference to the arguments passed to the function.
+    pub fn args_mut(&mut self) -> &mut [Node] {
+        &mut self.args
+    }
+
+    /// Gets the span of the call in the source code, if it is known.
+    pub fn span(&self) -> Option<Span> {
+        self.span
+    }
+
+    /// Sets the span of the call in the source code.
+    pub fn set_span(&mut self, span: Span) {
+        self.span = Some(span);
+    }
+}
+
+impl PartialEq for Call {
+    fn eq(&self, other: &Self) -> bool {
+        self.expr == other.expr && self.args == other.args
+    }
 }
 
 impl ToInternedString for Call {
@@ -32,14 +32,26 @@ impl ConditionalOp {
         &self.condition
     }
 
+    pub fn cond_mut(&mut self) -> &mut Node {
+        &mut self.condition
+    }
+
     pub fn if_true(&self) -> &Node {
         &self.if_true
     }
 
+    pub fn if_true_mut(&mut self) -> &mut Node {
+        &mut self.if_true
+    }
+
     pub fn if_false(&self) -> &Node {
         &self.if_false
     }
 
+    pub fn if_false_mut(&mut self) -> &mut Node {
+        &mut self.if_false
+    }
+
     /// Creates a `ConditionalOp` AST node.
     pub fn new<C, T, F>(condition: C, if_true: T, if_false: F) -> Self
     where
@@ -34,14 +34,26 @@ impl If {
         &self.cond
     }
 
+    pub fn cond_mut(&mut self) -> &mut Node {
+        &mut self.cond
+    }
+
     pub fn body(&self) -> &Node {
         &self.body
     }
 
+    pub fn body_mut(&mut self) -> &mut Node {
+        &mut self.body
+    }
+
     pub fn else_node(&self) -> Option<&Node> {
         self.else_node.as_ref().map(Box::as_ref)
     }
 
+    pub fn else_node_mut(&mut self) -> Option<&mut Node> {
+        self.else_node.as_deref_mut()
+    }
+
     /// Creates an `If` AST node.
     pub fn new<C, B, E, OE>(condition: C, body: B, else_node: OE) -> Self
     where
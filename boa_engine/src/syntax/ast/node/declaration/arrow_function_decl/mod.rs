@@ -56,11 +56,21 @@ impl ArrowFunctionDecl {
         &self.params
     }
 
+    /// Gets a mutable reference to the list of parameters of the arrow function.
+    pub(crate) fn params_mut(&mut self) -> &mut FormalParameterList {
+        &mut self.params
+    }
+
     /// Gets the body of the arrow function.
     pub(crate) fn body(&self) -> &StatementList {
         &self.body
     }
 
+    /// Gets a mutable reference to the body of the arrow function.
+    pub(crate) fn body_mut(&mut self) -> &mut StatementList {
+        &mut self.body
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn to_indented_string(
         &self,
@@ -47,9 +47,19 @@ impl AsyncFunctionDecl {
         &self.parameters
     }
 
+    /// Gets a mutable reference to the list of parameters of the async function declaration.
+    pub fn parameters_mut(&mut self) -> &mut FormalParameterList {
+        &mut self.parameters
+    }
+
     /// Gets the body of the async function declaration.
-    pub fn body(&self) -> &[Node] {
-        self.body.items()
+    pub fn body(&self) -> &StatementList {
+        &self.body
+    }
+
+    /// Gets a mutable reference to the body of the async function declaration.
+    pub fn body_mut(&mut self) -> &mut StatementList {
+        &mut self.body
     }
 
     /// Implements the display formatting with indentation.
@@ -63,7 +73,7 @@ impl AsyncFunctionDecl {
             interner.resolve_expect(self.name),
             join_nodes(interner, &self.parameters.parameters)
         );
-        if self.body().is_empty() {
+        if self.body.items().is_empty() {
             buf.push_str(") {}");
         } else {
             buf.push_str(&format!(
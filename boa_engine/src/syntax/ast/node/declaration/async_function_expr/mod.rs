@@ -49,11 +49,21 @@ impl AsyncFunctionExpr {
         &self.parameters
     }
 
+    /// Gets a mutable reference to the list of parameters of the function declaration.
+    pub fn parameters_mut(&mut self) -> &mut FormalParameterList {
+        &mut self.parameters
+    }
+
     /// Gets the body of the function declaration.
     pub fn body(&self) -> &StatementList {
         &self.body
     }
 
+    /// Gets a mutable reference to the body of the function declaration.
+    pub fn body_mut(&mut self) -> &mut StatementList {
+        &mut self.body
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn to_indented_string(
         &self,
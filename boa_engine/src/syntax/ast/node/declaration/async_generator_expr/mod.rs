@@ -48,11 +48,21 @@ impl AsyncGeneratorExpr {
         &self.parameters
     }
 
+    /// Gets a mutable reference to the list of parameters of the async generator expression
+    pub fn parameters_mut(&mut self) -> &mut FormalParameterList {
+        &mut self.parameters
+    }
+
     /// Gets the body of the async generator expression
     pub fn body(&self) -> &StatementList {
         &self.body
     }
 
+    /// Gets a mutable reference to the body of the async generator expression
+    pub fn body_mut(&mut self) -> &mut StatementList {
+        &mut self.body
+    }
+
     pub(in crate::syntax::ast::node) fn to_indented_string(
         &self,
         interner: &Interner,
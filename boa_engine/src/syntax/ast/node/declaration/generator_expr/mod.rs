@@ -48,11 +48,21 @@ impl GeneratorExpr {
         &self.parameters
     }
 
+    /// Gets a mutable reference to the list of parameters of the generator declaration.
+    pub fn parameters_mut(&mut self) -> &mut FormalParameterList {
+        &mut self.parameters
+    }
+
     /// Gets the body of the generator declaration.
     pub fn body(&self) -> &StatementList {
         &self.body
     }
 
+    /// Gets a mutable reference to the body of the generator declaration.
+    pub fn body_mut(&mut self) -> &mut StatementList {
+        &mut self.body
+    }
+
     /// Converts the generator expresion node to a string with indentation.
     pub(in crate::syntax::ast::node) fn to_indented_string(
         &self,
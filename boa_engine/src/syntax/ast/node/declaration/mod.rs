@@ -92,13 +92,50 @@ pub enum DeclarationList {
     /// [spec]: https://tc39.es/ecma262/#prod-VariableStatement
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/var
     Var(Box<[Declaration]>),
+
+    /// The `using` declaration declares block-scoped constants holding resources, which are
+    /// disposed by calling their `Symbol.dispose` method when the enclosing scope is exited.
+    ///
+    /// Resources are disposed in the reverse order of their declaration, whether the scope is
+    /// exited normally or by an exception. `null` and `undefined` can be declared, and are
+    /// skipped on disposal.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#prod-UsingDeclaration
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/using
+    Using(Box<[Declaration]>),
+
+    /// The `await using` declaration is the asynchronous version of `using`, disposing its
+    /// resources by calling and awaiting their `Symbol.asyncDispose` method.
+    ///
+    /// It can only be used where `await` is allowed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#prod-AwaitUsingDeclaration
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/await_using
+    AwaitUsing(Box<[Declaration]>),
 }
 
 impl AsRef<[Declaration]> for DeclarationList {
     fn as_ref(&self) -> &[Declaration] {
-        use DeclarationList::{Const, Let, Var};
+        use DeclarationList::{AwaitUsing, Const, Let, Using, Var};
         match self {
-            Var(list) | Const(list) | Let(list) => list,
+            Var(list) | Const(list) | Let(list) | Using(list) | AwaitUsing(list) => list,
+        }
+    }
+}
+
+impl AsMut<[Declaration]> for DeclarationList {
+    fn as_mut(&mut self) -> &mut [Declaration] {
+        use DeclarationList::{AwaitUsing, Const, Let, Using, Var};
+        match self {
+            Var(list) | Const(list) | Let(list) | Using(list) | AwaitUsing(list) => list,
         }
     }
 }
@@ -108,13 +145,15 @@ impl ToInternedString for DeclarationList {
         if self.as_ref().is_empty() {
             String::new()
         } else {
-            use DeclarationList::{Const, Let, Var};
+            use DeclarationList::{AwaitUsing, Const, Let, Using, Var};
             format!(
                 "{} {}",
                 match &self {
                     Let(_) => "let",
                     Const(_) => "const",
                     Var(_) => "var",
+                    Using(_) => "using",
+                    AwaitUsing(_) => "await using",
                 },
                 join_nodes(interner, self.as_ref())
             )
@@ -124,11 +163,12 @@ impl ToInternedString for DeclarationList {
 
 impl From<DeclarationList> for Node {
     fn from(list: DeclarationList) -> Self {
-        use DeclarationList::{Const, Let, Var};
+        use DeclarationList::{AwaitUsing, Const, Let, Using, Var};
         match &list {
             Let(_) => Self::LetDeclList(list),
             Const(_) => Self::ConstDeclList(list),
             Var(_) => Self::VarDeclList(list),
+            Using(_) | AwaitUsing(_) => Self::UsingDeclList(list),
         }
     }
 }
@@ -328,12 +368,24 @@ impl DeclarationPatternObject {
         self.init.as_ref()
     }
 
+    /// Gets a mutable reference to the initialization node for the object binding pattern, if any.
+    #[inline]
+    pub(crate) fn init_mut(&mut self) -> Option<&mut Node> {
+        self.init.as_mut()
+    }
+
     /// Gets the bindings for the object binding pattern.
     #[inline]
     pub(crate) fn bindings(&self) -> &Vec<BindingPatternTypeObject> {
         &self.bindings
     }
 
+    /// Gets a mutable reference to the bindings for the object binding pattern.
+    #[inline]
+    pub(crate) fn bindings_mut(&mut self) -> &mut Vec<BindingPatternTypeObject> {
+        &mut self.bindings
+    }
+
     /// Gets the list of identifiers declared by the object binding pattern.
     #[inline]
     pub(crate) fn idents(&self) -> Vec<Sym> {
@@ -429,12 +481,24 @@ impl DeclarationPatternArray {
         self.init.as_ref()
     }
 
+    /// Gets a mutable reference to the initialization node for the array binding pattern, if any.
+    #[inline]
+    pub(crate) fn init_mut(&mut self) -> Option<&mut Node> {
+        self.init.as_mut()
+    }
+
     /// Gets the bindings for the array binding pattern.
     #[inline]
     pub(crate) fn bindings(&self) -> &Vec<BindingPatternTypeArray> {
         &self.bindings
     }
 
+    /// Gets a mutable reference to the bindings for the array binding pattern.
+    #[inline]
+    pub(crate) fn bindings_mut(&mut self) -> &mut Vec<BindingPatternTypeArray> {
+        &mut self.bindings
+    }
+
     /// Gets the list of identifiers declared by the array binding pattern.
     #[inline]
     pub(crate) fn idents(&self) -> Vec<Sym> {
@@ -39,10 +39,18 @@ impl GetField {
         &self.obj
     }
 
+    pub fn obj_mut(&mut self) -> &mut Node {
+        &mut self.obj
+    }
+
     pub fn field(&self) -> &Node {
         &self.field
     }
 
+    pub fn field_mut(&mut self) -> &mut Node {
+        &mut self.field
+    }
+
     /// Creates a `GetField` AST node.
     pub fn new<V, F>(value: V, field: F) -> Self
     where
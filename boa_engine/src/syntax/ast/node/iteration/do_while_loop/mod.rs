@@ -30,10 +30,18 @@ impl DoWhileLoop {
         &self.body
     }
 
+    pub fn body_mut(&mut self) -> &mut Node {
+        &mut self.body
+    }
+
     pub fn cond(&self) -> &Node {
         &self.cond
     }
 
+    pub fn cond_mut(&mut self) -> &mut Node {
+        &mut self.cond
+    }
+
     pub fn label(&self) -> Option<Sym> {
         self.label
     }
@@ -32,14 +32,26 @@ impl ForInLoop {
         &self.init
     }
 
+    pub fn init_mut(&mut self) -> &mut IterableLoopInitializer {
+        &mut self.init
+    }
+
     pub fn expr(&self) -> &Node {
         &self.expr
     }
 
+    pub fn expr_mut(&mut self) -> &mut Node {
+        &mut self.expr
+    }
+
     pub fn body(&self) -> &Node {
         &self.body
     }
 
+    pub fn body_mut(&mut self) -> &mut Node {
+        &mut self.body
+    }
+
     pub fn label(&self) -> Option<Sym> {
         self.label
     }
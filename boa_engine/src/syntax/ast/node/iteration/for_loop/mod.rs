@@ -44,21 +44,41 @@ impl ForLoop {
         self.inner.init()
     }
 
+    /// Gets a mutable reference to the initialization node.
+    pub fn init_mut(&mut self) -> Option<&mut Node> {
+        self.inner.init.as_mut()
+    }
+
     /// Gets the loop condition node.
     pub fn condition(&self) -> Option<&Node> {
         self.inner.condition()
     }
 
+    /// Gets a mutable reference to the loop condition node.
+    pub fn condition_mut(&mut self) -> Option<&mut Node> {
+        self.inner.condition.as_mut()
+    }
+
     /// Gets the final expression node.
     pub fn final_expr(&self) -> Option<&Node> {
         self.inner.final_expr()
     }
 
+    /// Gets a mutable reference to the final expression node.
+    pub fn final_expr_mut(&mut self) -> Option<&mut Node> {
+        self.inner.final_expr.as_mut()
+    }
+
     /// Gets the body of the for loop.
     pub fn body(&self) -> &Node {
         self.inner.body()
     }
 
+    /// Gets a mutable reference to the body of the for loop.
+    pub fn body_mut(&mut self) -> &mut Node {
+        &mut self.inner.body
+    }
+
     /// Converts the for loop to a string with the given indentation.
     pub(in crate::syntax::ast::node) fn to_indented_string(
         &self,
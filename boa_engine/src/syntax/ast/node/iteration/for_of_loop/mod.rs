@@ -33,14 +33,26 @@ impl ForOfLoop {
         &self.init
     }
 
+    pub fn init_mut(&mut self) -> &mut IterableLoopInitializer {
+        &mut self.init
+    }
+
     pub fn iterable(&self) -> &Node {
         &self.iterable
     }
 
+    pub fn iterable_mut(&mut self) -> &mut Node {
+        &mut self.iterable
+    }
+
     pub fn body(&self) -> &Node {
         &self.body
     }
 
+    pub fn body_mut(&mut self) -> &mut Node {
+        &mut self.body
+    }
+
     pub fn label(&self) -> Option<Sym> {
         self.label
     }
@@ -29,10 +29,18 @@ impl WhileLoop {
         &self.cond
     }
 
+    pub fn cond_mut(&mut self) -> &mut Node {
+        &mut self.cond
+    }
+
     pub fn body(&self) -> &Node {
         &self.body
     }
 
+    pub fn body_mut(&mut self) -> &mut Node {
+        &mut self.body
+    }
+
     pub fn label(&self) -> Option<Sym> {
         self.label
     }
@@ -200,6 +200,9 @@ pub enum Node {
     /// Unary operation node. [More information](./operator/struct.UnaryOp.html)
     UnaryOp(UnaryOp),
 
+    /// A `using` or `await using` declaration list. [More information](./declaration/enum.DeclarationList.html#variant.Using).
+    UsingDeclList(DeclarationList),
+
     /// Array declaration node. [More information](./declaration/enum.DeclarationList.html#variant.Var).
     VarDeclList(DeclarationList),
 
@@ -218,6 +221,11 @@ pub enum Node {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/Empty
     Empty,
 
+    /// A statement that failed to parse, left in the tree by a parser recovering from errors.
+    ///
+    /// Such a tree can't be compiled.
+    Error,
+
     /// A `yield` node. [More information](./yield/struct.Yield.html).
     Yield(Yield),
 
@@ -314,13 +322,13 @@ impl Node {
             Self::TemplateLit(ref template) => template.to_interned_string(interner),
             Self::Throw(ref throw) => throw.to_interned_string(interner),
             Self::Assign(ref op) => op.to_interned_string(interner),
-            Self::LetDeclList(ref decl) | Self::ConstDeclList(ref decl) => {
-                decl.to_interned_string(interner)
-            }
+            Self::LetDeclList(ref decl)
+            | Self::ConstDeclList(ref decl)
+            | Self::UsingDeclList(ref decl) => decl.to_interned_string(interner),
             Self::AsyncFunctionDecl(ref decl) => decl.to_indented_string(interner, indentation),
             Self::AsyncFunctionExpr(ref expr) => expr.to_indented_string(interner, indentation),
             Self::AwaitExpr(ref expr) => expr.to_interned_string(interner),
-            Self::Empty => ";".to_owned(),
+            Self::Empty | Self::Error => ";".to_owned(),
             Self::Yield(ref y) => y.to_interned_string(interner),
             Self::GeneratorDecl(ref decl) => decl.to_interned_string(interner),
             Self::GeneratorExpr(ref expr) => expr.to_indented_string(interner, indentation),
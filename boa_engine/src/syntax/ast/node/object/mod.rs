@@ -44,6 +44,10 @@ impl Object {
         &self.properties
     }
 
+    pub fn properties_mut(&mut self) -> &mut [PropertyDefinition] {
+        &mut self.properties
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn to_indented_string(
         &self,
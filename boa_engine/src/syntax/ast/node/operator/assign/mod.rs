@@ -48,10 +48,20 @@ impl Assign {
         &self.lhs
     }
 
+    /// Gets a mutable reference to the left hand side of the assignment operation.
+    pub fn lhs_mut(&mut self) -> &mut AssignTarget {
+        &mut self.lhs
+    }
+
     /// Gets the right hand side of the assignment operation.
     pub fn rhs(&self) -> &Node {
         &self.rhs
     }
+
+    /// Gets a mutable reference to the right hand side of the assignment operation.
+    pub fn rhs_mut(&mut self) -> &mut Node {
+        &mut self.rhs
+    }
 }
 
 impl ToInternedString for Assign {
@@ -44,10 +44,20 @@ impl BinOp {
         &self.lhs
     }
 
+    /// Gets a mutable reference to the left hand side of the binary operation.
+    pub fn lhs_mut(&mut self) -> &mut Node {
+        &mut self.lhs
+    }
+
     /// Gets the right hand side of the binary operation.
     pub fn rhs(&self) -> &Node {
         &self.rhs
     }
+
+    /// Gets a mutable reference to the right hand side of the binary operation.
+    pub fn rhs_mut(&mut self) -> &mut Node {
+        &mut self.rhs
+    }
 }
 
 impl ToInternedString for BinOp {
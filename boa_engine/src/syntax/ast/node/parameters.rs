@@ -1,4 +1,4 @@
-use super::{Declaration, DeclarationPattern, Node};
+use super::{declaration::BindingPatternTypeArray, Declaration, DeclarationPattern, Node};
 use bitflags::bitflags;
 use boa_gc::{Finalize, Trace};
 use boa_interner::{Interner, Sym, ToInternedString};
@@ -120,11 +120,48 @@ impl FormalParameter {
         }
     }
 
+    /// Creates a formal parameter binding the given names, for a function restored from compiled
+    /// code.
+    ///
+    /// The bytecode of a function binds its parameters, so the parameter only keeps what the VM
+    /// reads from it: whether it is an identifier, has an initializer or is a rest parameter, and
+    /// the names it binds.
+    pub(crate) fn from_bound_names(
+        names: &[Sym],
+        is_identifier: bool,
+        has_init: bool,
+        is_rest_param: bool,
+    ) -> Self {
+        let init = if has_init { Some(Node::Empty) } else { None };
+        let declaration = match names {
+            [name] if is_identifier => Declaration::new_with_identifier(*name, init),
+            _ => Declaration::new_with_array_pattern(
+                names
+                    .iter()
+                    .map(|&ident| BindingPatternTypeArray::SingleName {
+                        ident,
+                        default_init: None,
+                    })
+                    .collect(),
+                init,
+            ),
+        };
+        Self {
+            declaration,
+            is_rest_param,
+        }
+    }
+
     /// Get the declaration of the formal parameter
     pub fn declaration(&self) -> &Declaration {
         &self.declaration
     }
 
+    /// Gets a mutable reference to the declaration of the formal parameter
+    pub fn declaration_mut(&mut self) -> &mut Declaration {
+        &mut self.declaration
+    }
+
     /// Gets the initialization node of the formal parameter, if any.
     pub fn init(&self) -> Option<&Node> {
         self.declaration.init()
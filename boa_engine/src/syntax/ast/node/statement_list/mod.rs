@@ -1,6 +1,9 @@
 //! Statement list node.
 
-use crate::syntax::ast::node::{Declaration, Node};
+use crate::syntax::ast::{
+    node::{Declaration, Node},
+    Span,
+};
 use boa_gc::{unsafe_empty_trace, Finalize, Trace};
 use boa_interner::{Interner, Sym, ToInternedString};
 use std::{ops::Deref, rc::Rc};
@@ -16,24 +19,65 @@ mod tests;
 ///
 /// Similar to `Node::Block` but without the braces.
 ///
+/// Statement lists made by the parser also hold the span of each of their items, which the
+/// compiler records in the line table of the bytecode. The spans are not part of the structure of
+/// the list, and are ignored when comparing lists.
+///
 /// More information:
 ///  - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-StatementList
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+#[derive(Clone, Debug, Trace, Finalize)]
 pub struct StatementList {
     items: Box<[Node]>,
+    #[unsafe_ignore_trace]
+    #[cfg_attr(feature = "deser", serde(default))]
+    spans: Box<[Span]>,
     strict: bool,
 }
 
 impl StatementList {
+    /// Creates a list of statements from its items and their spans in the source code.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there isn't one span per item.
+    #[inline]
+    #[track_caller]
+    pub fn with_spans<I, S>(items: I, spans: S) -> Self
+    where
+        I: Into<Box<[Node]>>,
+        S: Into<Box<[Span]>>,
+    {
+        let items = items.into();
+        let spans = spans.into();
+        assert_eq!(items.len(), spans.len(), "every item must have a span");
+        Self {
+            items,
+            spans,
+            strict: false,
+        }
+    }
+
     /// Gets the list of items.
     #[inline]
     pub fn items(&self) -> &[Node] {
         &self.items
     }
 
+    /// Gets a mutable reference to the list of items.
+    #[inline]
+    pub fn items_mut(&mut self) -> &mut [Node] {
+        &mut self.items
+    }
+
+    /// Gets the span of the item at `index` in the source code, if it is known.
+    #[inline]
+    pub fn span(&self, index: usize) -> Option<Span> {
+        self.spans.get(index).copied()
+    }
+
     /// Get the strict mode.
     #[inline]
     pub fn strict(&self) -> bool {
@@ -71,7 +115,10 @@ impl StatementList {
     pub fn lexically_declared_names(&self, interner: &Interner) -> FxHashSet<Sym> {
         let mut set = FxHashSet::default();
         for stmt in self.items() {
-            if let Node::LetDeclList(decl_list) | Node::ConstDeclList(decl_list) = stmt {
+            if let Node::LetDeclList(decl_list)
+            | Node::ConstDeclList(decl_list)
+            | Node::UsingDeclList(decl_list) = stmt
+            {
                 for decl in decl_list.as_ref() {
                     // It is a Syntax Error if the LexicallyDeclaredNames of StatementList contains any duplicate entries.
                     // https://tc39.es/ecma262/#sec-block-static-semantics-early-errors
@@ -136,11 +183,18 @@ where
     fn from(stm: T) -> Self {
         Self {
             items: stm.into(),
+            spans: Box::default(),
             strict: false,
         }
     }
 }
 
+impl PartialEq for StatementList {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items && self.strict == other.strict
+    }
+}
+
 impl ToInternedString for StatementList {
     fn to_interned_string(&self, interner: &Interner) -> String {
         self.to_indented_string(interner, 0)
@@ -37,10 +37,20 @@ impl Case {
         &self.condition
     }
 
+    /// Gets a mutable reference to the condition of the case.
+    pub fn condition_mut(&mut self) -> &mut Node {
+        &mut self.condition
+    }
+
     /// Gets the statement listin the body of the case.
     pub fn body(&self) -> &StatementList {
         &self.body
     }
+
+    /// Gets a mutable reference to the statement list in the body of the case.
+    pub fn body_mut(&mut self) -> &mut StatementList {
+        &mut self.body
+    }
 }
 
 /// The `switch` statement evaluates an expression, matching the expression's value to a case
@@ -87,16 +97,36 @@ impl Switch {
         &self.val
     }
 
+    /// Gets a mutable reference to the value to switch.
+    pub fn val_mut(&mut self) -> &mut Node {
+        &mut self.val
+    }
+
     /// Gets the list of cases for the switch statement.
     pub fn cases(&self) -> &[Case] {
         &self.cases
     }
 
+    /// Gets a mutable reference to the list of cases for the switch statement.
+    pub fn cases_mut(&mut self) -> &mut [Case] {
+        &mut self.cases
+    }
+
     /// Gets the default statement list, if any.
     pub fn default(&self) -> Option<&[Node]> {
         self.default.as_ref().map(StatementList::items)
     }
 
+    /// Gets the statement list of the default case, if any.
+    pub(crate) fn default_statement_list(&self) -> Option<&StatementList> {
+        self.default.as_ref()
+    }
+
+    /// Gets a mutable reference to the statement list of the default case, if any.
+    pub(crate) fn default_statement_list_mut(&mut self) -> Option<&mut StatementList> {
+        self.default.as_mut()
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn to_indented_string(
         &self,
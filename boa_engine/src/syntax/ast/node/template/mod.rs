@@ -37,6 +37,10 @@ impl TemplateLit {
     pub(crate) fn elements(&self) -> &[TemplateElement] {
         &self.elements
     }
+
+    pub(crate) fn elements_mut(&mut self) -> &mut [TemplateElement] {
+        &mut self.elements
+    }
 }
 
 impl ToInternedString for TemplateLit {
@@ -86,6 +90,10 @@ impl TaggedTemplate {
         &self.tag
     }
 
+    pub(crate) fn tag_mut(&mut self) -> &mut Node {
+        &mut self.tag
+    }
+
     pub(crate) fn raws(&self) -> &[Sym] {
         &self.raws
     }
@@ -97,6 +105,10 @@ impl TaggedTemplate {
     pub(crate) fn exprs(&self) -> &[Node] {
         &self.exprs
     }
+
+    pub(crate) fn exprs_mut(&mut self) -> &mut [Node] {
+        &mut self.exprs
+    }
 }
 
 impl ToInternedString for TaggedTemplate {
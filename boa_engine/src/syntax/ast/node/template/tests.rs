@@ -30,6 +30,26 @@ fn tagged_template() {
     );
 }
 
+#[test]
+fn tagged_template_object_is_cached() {
+    let scenario = r#"
+        function tag(t) { return t; }
+        function site() { return tag`a${1}b`; }
+        let first = site();
+        let second = site();
+        let other = tag`a${1}b`;
+        [
+            first === second,
+            first === other,
+            Object.isFrozen(first),
+            Object.isFrozen(first.raw),
+            Object.getOwnPropertyDescriptor(first, "raw").enumerable,
+        ]
+        "#;
+
+    assert_eq!(&exec(scenario), "[ true, false, true, true, false ]");
+}
+
 #[test]
 fn fmt() {
     super::super::test_formatting(
@@ -56,16 +56,31 @@ impl Try {
         &self.block
     }
 
+    /// Gets a mutable reference to the `try` block.
+    pub fn block_mut(&mut self) -> &mut Block {
+        &mut self.block
+    }
+
     /// Gets the `catch` block, if any.
     pub fn catch(&self) -> Option<&Catch> {
         self.catch.as_ref()
     }
 
+    /// Gets a mutable reference to the `catch` block, if any.
+    pub fn catch_mut(&mut self) -> Option<&mut Catch> {
+        self.catch.as_mut()
+    }
+
     /// Gets the `finally` block, if any.
     pub fn finally(&self) -> Option<&Block> {
         self.finally.as_ref().map(Finally::block)
     }
 
+    /// Gets a mutable reference to the `finally` block, if any.
+    pub fn finally_mut(&mut self) -> Option<&mut Block> {
+        self.finally.as_mut().map(Finally::block_mut)
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn to_indented_string(
         &self,
@@ -128,11 +143,21 @@ impl Catch {
         self.parameter.as_deref()
     }
 
+    /// Gets a mutable reference to the parameter of the catch block.
+    pub fn parameter_mut(&mut self) -> Option<&mut Declaration> {
+        self.parameter.as_deref_mut()
+    }
+
     /// Retrieves the catch execution block.
     pub fn block(&self) -> &Block {
         &self.block
     }
 
+    /// Gets a mutable reference to the catch execution block.
+    pub fn block_mut(&mut self) -> &mut Block {
+        &mut self.block
+    }
+
     /// Implements the display formatting with indentation.
     pub(super) fn to_indented_string(&self, interner: &Interner, indentation: usize) -> String {
         let mut buf = " catch".to_owned();
@@ -167,6 +192,11 @@ impl Finally {
         &self.block
     }
 
+    /// Gets a mutable reference to the finally block.
+    pub fn block_mut(&mut self) -> &mut Block {
+        &mut self.block
+    }
+
     /// Implements the display formatting with indentation.
     pub(super) fn to_indented_string(&self, interner: &Interner, indentation: usize) -> String {
         format!(
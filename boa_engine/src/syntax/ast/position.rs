@@ -7,21 +7,27 @@ use serde::{Deserialize, Serialize};
 
 /// A position in the JavaScript source code.
 ///
-/// Stores both the column number and the line number.
+/// Stores the line number, the column number and the byte offset of the position.
 ///
 /// Note that spans are of the form [begining, end) i.e. that the begining position is inclusive
 /// and the end position is exclusive. See test `check_positions` from `syntax/lexer/tests.rs` for
 /// an example.
 ///
+/// Positions are compared by line and column only: the byte offset follows from them for a given
+/// source, and is `0` for positions created with [`Position::new`].
+///
 /// ## Similar Implementations
 /// [V8: Location](https://cs.chromium.org/chromium/src/v8/src/parsing/scanner.h?type=cs&q=isValid+Location&g=0&l=216)
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy)]
 pub struct Position {
     /// Line number.
     line_number: NonZeroU32,
     /// Column number.
     column_number: NonZeroU32,
+    /// Offset in bytes from the start of the source.
+    #[cfg_attr(feature = "deser", serde(default))]
+    offset: u32,
 }
 
 impl Position {
@@ -29,9 +35,17 @@ impl Position {
     #[inline]
     #[track_caller]
     pub fn new(line_number: u32, column_number: u32) -> Self {
+        Self::with_offset(line_number, column_number, 0)
+    }
+
+    /// Creates a new `Position` with the byte offset of the position in the source.
+    #[inline]
+    #[track_caller]
+    pub fn with_offset(line_number: u32, column_number: u32, offset: u32) -> Self {
         Self {
             line_number: NonZeroU32::new(line_number).expect("line number cannot be 0"),
             column_number: NonZeroU32::new(column_number).expect("column number cannot be 0"),
+            offset,
         }
     }
 
@@ -46,6 +60,32 @@ impl Position {
     pub fn column_number(self) -> u32 {
         self.column_number.get()
     }
+
+    /// Gets the offset in bytes of the position from the start of the source.
+    #[inline]
+    pub fn offset(self) -> u32 {
+        self.offset
+    }
+}
+
+impl PartialEq for Position {
+    fn eq(&self, other: &Self) -> bool {
+        self.line_number == other.line_number && self.column_number == other.column_number
+    }
+}
+
+impl Eq for Position {}
+
+impl PartialOrd for Position {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Position {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.line_number, self.column_number).cmp(&(other.line_number, other.column_number))
+    }
 }
 
 impl fmt::Display for Position {
@@ -174,6 +214,11 @@ mod tests {
         let pos = Position::new(10, 50);
         assert_eq!(pos.line_number(), 10);
         assert_eq!(pos.column_number(), 50);
+        assert_eq!(pos.offset(), 0);
+
+        let pos = Position::with_offset(10, 50, 420);
+        assert_eq!(pos.offset(), 420);
+        assert_eq!(pos, Position::new(10, 50));
     }
 
     /// Checks that the string representation of a position is correct.
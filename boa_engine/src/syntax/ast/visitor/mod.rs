@@ -0,0 +1,147 @@
+//! Traversal of the abstract syntax tree.
+//!
+//! The [`Visitor`] and [`VisitorMut`] traits have a method for each type of the syntax tree,
+//! called when the traversal reaches a value of that type. By default, a method visits the
+//! children of the value in source order by calling the `walk_*` function of the same name, so an
+//! implementation only overrides the methods of the types it is interested in. An overriding
+//! method calls the `walk_*` function itself to keep traversing the children, or doesn't to skip
+//! them.
+//!
+//! ```
+//! use boa_engine::syntax::{
+//!     ast::{
+//!         node::Call,
+//!         visitor::{walk_call, Visitor},
+//!     },
+//!     Parser,
+//! };
+//! use boa_interner::Interner;
+//!
+//! #[derive(Default)]
+//! struct CountCalls(usize);
+//!
+//! impl<'ast> Visitor<'ast> for CountCalls {
+//!     fn visit_call(&mut self, call: &'ast Call) {
+//!         self.0 += 1;
+//!         walk_call(self, call);
+//!     }
+//! }
+//!
+//! let mut interner = Interner::default();
+//! let script = Parser::new(&b"f(g(), h())"[..], false)
+//!     .parse_all(&mut interner)
+//!     .unwrap();
+//! let mut count = CountCalls::default();
+//! count.visit_statement_list(&script);
+//! assert_eq!(count.0, 3);
+//! ```
+
+/// Calls `$macro` with the types of the syntax tree, and the names of their visit and walk
+/// functions in [`Visitor`] and [`VisitorMut`].
+macro_rules! node_types {
+    ($macro:ident) => {
+        $macro! {
+            Node: visit_node, walk_node, visit_node_mut, walk_node_mut;
+            StatementList: visit_statement_list, walk_statement_list,
+                visit_statement_list_mut, walk_statement_list_mut;
+            ArrayDecl: visit_array_decl, walk_array_decl, visit_array_decl_mut,
+                walk_array_decl_mut;
+            ArrowFunctionDecl: visit_arrow_function_decl, walk_arrow_function_decl,
+                visit_arrow_function_decl_mut, walk_arrow_function_decl_mut;
+            Assign: visit_assign, walk_assign, visit_assign_mut, walk_assign_mut;
+            AssignTarget: visit_assign_target, walk_assign_target, visit_assign_target_mut,
+                walk_assign_target_mut;
+            AsyncFunctionDecl: visit_async_function_decl, walk_async_function_decl,
+                visit_async_function_decl_mut, walk_async_function_decl_mut;
+            AsyncFunctionExpr: visit_async_function_expr, walk_async_function_expr,
+                visit_async_function_expr_mut, walk_async_function_expr_mut;
+            AsyncGeneratorDecl: visit_async_generator_decl, walk_async_generator_decl,
+                visit_async_generator_decl_mut, walk_async_generator_decl_mut;
+            AsyncGeneratorExpr: visit_async_generator_expr, walk_async_generator_expr,
+                visit_async_generator_expr_mut, walk_async_generator_expr_mut;
+            AwaitExpr: visit_await_expr, walk_await_expr, visit_await_expr_mut,
+                walk_await_expr_mut;
+            BinOp: visit_bin_op, walk_bin_op, visit_bin_op_mut, walk_bin_op_mut;
+            Block: visit_block, walk_block, visit_block_mut, walk_block_mut;
+            Break: visit_break, walk_break, visit_break_mut, walk_break_mut;
+            Call: visit_call, walk_call, visit_call_mut, walk_call_mut;
+            ConditionalOp: visit_conditional_op, walk_conditional_op, visit_conditional_op_mut,
+                walk_conditional_op_mut;
+            Const: visit_const, walk_const, visit_const_mut, walk_const_mut;
+            Continue: visit_continue, walk_continue, visit_continue_mut, walk_continue_mut;
+            DeclarationList: visit_declaration_list, walk_declaration_list,
+                visit_declaration_list_mut, walk_declaration_list_mut;
+            Declaration: visit_declaration, walk_declaration, visit_declaration_mut,
+                walk_declaration_mut;
+            DeclarationPattern: visit_declaration_pattern, walk_declaration_pattern,
+                visit_declaration_pattern_mut, walk_declaration_pattern_mut;
+            BindingPatternTypeObject: visit_binding_pattern_type_object,
+                walk_binding_pattern_type_object, visit_binding_pattern_type_object_mut,
+                walk_binding_pattern_type_object_mut;
+            BindingPatternTypeArray: visit_binding_pattern_type_array,
+                walk_binding_pattern_type_array, visit_binding_pattern_type_array_mut,
+                walk_binding_pattern_type_array_mut;
+            DoWhileLoop: visit_do_while_loop, walk_do_while_loop, visit_do_while_loop_mut,
+                walk_do_while_loop_mut;
+            FormalParameterList: visit_formal_parameter_list, walk_formal_parameter_list,
+                visit_formal_parameter_list_mut, walk_formal_parameter_list_mut;
+            FormalParameter: visit_formal_parameter, walk_formal_parameter,
+                visit_formal_parameter_mut, walk_formal_parameter_mut;
+            ForInLoop: visit_for_in_loop, walk_for_in_loop, visit_for_in_loop_mut,
+                walk_for_in_loop_mut;
+            ForLoop: visit_for_loop, walk_for_loop, visit_for_loop_mut, walk_for_loop_mut;
+            ForOfLoop: visit_for_of_loop, walk_for_of_loop, visit_for_of_loop_mut,
+                walk_for_of_loop_mut;
+            FunctionDecl: visit_function_decl, walk_function_decl, visit_function_decl_mut,
+                walk_function_decl_mut;
+            FunctionExpr: visit_function_expr, walk_function_expr, visit_function_expr_mut,
+                walk_function_expr_mut;
+            GeneratorDecl: visit_generator_decl, walk_generator_decl, visit_generator_decl_mut,
+                walk_generator_decl_mut;
+            GeneratorExpr: visit_generator_expr, walk_generator_expr, visit_generator_expr_mut,
+                walk_generator_expr_mut;
+            GetConstField: visit_get_const_field, walk_get_const_field,
+                visit_get_const_field_mut, walk_get_const_field_mut;
+            GetField: visit_get_field, walk_get_field, visit_get_field_mut, walk_get_field_mut;
+            Identifier: visit_identifier, walk_identifier, visit_identifier_mut,
+                walk_identifier_mut;
+            If: visit_if, walk_if, visit_if_mut, walk_if_mut;
+            IterableLoopInitializer: visit_iterable_loop_initializer,
+                walk_iterable_loop_initializer, visit_iterable_loop_initializer_mut,
+                walk_iterable_loop_initializer_mut;
+            MethodDefinition: visit_method_definition, walk_method_definition,
+                visit_method_definition_mut, walk_method_definition_mut;
+            New: visit_new, walk_new, visit_new_mut, walk_new_mut;
+            Object: visit_object, walk_object, visit_object_mut, walk_object_mut;
+            PropertyDefinition: visit_property_definition, walk_property_definition,
+                visit_property_definition_mut, walk_property_definition_mut;
+            PropertyName: visit_property_name, walk_property_name, visit_property_name_mut,
+                walk_property_name_mut;
+            Return: visit_return, walk_return, visit_return_mut, walk_return_mut;
+            Spread: visit_spread, walk_spread, visit_spread_mut, walk_spread_mut;
+            Switch: visit_switch, walk_switch, visit_switch_mut, walk_switch_mut;
+            Case: visit_case, walk_case, visit_case_mut, walk_case_mut;
+            TaggedTemplate: visit_tagged_template, walk_tagged_template,
+                visit_tagged_template_mut, walk_tagged_template_mut;
+            TemplateLit: visit_template_lit, walk_template_lit, visit_template_lit_mut,
+                walk_template_lit_mut;
+            TemplateElement: visit_template_element, walk_template_element,
+                visit_template_element_mut, walk_template_element_mut;
+            Throw: visit_throw, walk_throw, visit_throw_mut, walk_throw_mut;
+            Try: visit_try, walk_try, visit_try_mut, walk_try_mut;
+            Catch: visit_catch, walk_catch, visit_catch_mut, walk_catch_mut;
+            UnaryOp: visit_unary_op, walk_unary_op, visit_unary_op_mut, walk_unary_op_mut;
+            WhileLoop: visit_while_loop, walk_while_loop, visit_while_loop_mut,
+                walk_while_loop_mut;
+            Yield: visit_yield, walk_yield, visit_yield_mut, walk_yield_mut;
+        }
+    };
+}
+
+mod visit;
+mod visit_mut;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::{visit::*, visit_mut::*};
@@ -0,0 +1,88 @@
+use super::{walk_call, Visitor, VisitorMut};
+use crate::syntax::{
+    ast::node::{Call, Identifier, Node, StatementList},
+    Parser,
+};
+use boa_interner::{Interner, Sym};
+
+fn parse(source: &str, interner: &mut Interner) -> StatementList {
+    Parser::new(source.as_bytes(), false)
+        .parse_all(interner)
+        .expect("parsing failed")
+}
+
+/// Collects the names of the functions called by identifier.
+struct Callees<'a> {
+    interner: &'a Interner,
+    names: Vec<&'a str>,
+}
+
+impl<'ast> Visitor<'ast> for Callees<'_> {
+    fn visit_call(&mut self, call: &'ast Call) {
+        if let Node::Identifier(ident) = call.expr() {
+            self.names.push(self.interner.resolve_expect(ident.sym()));
+        }
+        walk_call(self, call);
+    }
+}
+
+#[test]
+fn visit_in_source_order() {
+    let mut interner = Interner::default();
+    let script = parse(
+        r#"
+        function f(a, b = g()) {
+            return h(a, [i()], { k: j(), [l()]: 1 });
+        }
+        for (const x of y()) {
+            try { z(`${v()}`) } catch (e) { w(x) }
+        }
+        "#,
+        &mut interner,
+    );
+
+    let mut callees = Callees {
+        interner: &interner,
+        names: Vec::new(),
+    };
+    callees.visit_statement_list(&script);
+    assert_eq!(callees.names, ["g", "h", "i", "j", "l", "y", "z", "v", "w"]);
+}
+
+/// Renames the identifiers named `from` to `to`.
+struct Rename {
+    from: Sym,
+    to: Sym,
+    count: usize,
+}
+
+impl VisitorMut for Rename {
+    fn visit_identifier_mut(&mut self, ident: &mut Identifier) {
+        if ident.sym() == self.from {
+            *ident = Identifier::new(self.to);
+            self.count += 1;
+        }
+    }
+}
+
+#[test]
+fn visit_mut_rewrites_in_place() {
+    let mut interner = Interner::default();
+    let mut script = parse(
+        "let a = 1; a = a + f(a); for (let c of [a]) { (() => a)(); }",
+        &mut interner,
+    );
+    let expected = parse(
+        "let b = 1; b = b + f(b); for (let c of [b]) { (() => b)(); }",
+        &mut interner,
+    );
+
+    let mut rename = Rename {
+        from: interner.get_or_intern_static("a"),
+        to: interner.get_or_intern_static("b"),
+        count: 0,
+    };
+    rename.visit_statement_list_mut(&mut script);
+    assert_eq!(rename.count, 6);
+    assert_eq!(script, expected);
+}
@@ -0,0 +1,717 @@
+//! The [`Visitor`] trait, traversing a syntax tree by shared reference.
+
+use crate::syntax::ast::{
+    node::{
+        declaration::{BindingPatternTypeArray, BindingPatternTypeObject},
+        iteration::IterableLoopInitializer,
+        object::{MethodDefinition, PropertyDefinition, PropertyName},
+        operator::assign::AssignTarget,
+        template::TemplateElement,
+        ArrayDecl, ArrowFunctionDecl, Assign, AsyncFunctionDecl, AsyncFunctionExpr,
+        AsyncGeneratorDecl, AsyncGeneratorExpr, AwaitExpr, BinOp, Block, Break, Call, Case, Catch,
+        ConditionalOp, Continue, Declaration, DeclarationList, DeclarationPattern, DoWhileLoop,
+        ForInLoop, ForLoop, ForOfLoop, FormalParameter, FormalParameterList, FunctionDecl,
+        FunctionExpr, GeneratorDecl, GeneratorExpr, GetConstField, GetField, Identifier, If, New,
+        Node, Object, Return, Spread, StatementList, Switch, TaggedTemplate, TemplateLit, Throw,
+        Try, UnaryOp, WhileLoop, Yield,
+    },
+    Const,
+};
+
+macro_rules! visitor {
+    ($($ty:ident: $visit:ident, $walk:ident, $visit_mut:ident, $walk_mut:ident;)*) => {
+        /// A traversal of a syntax tree by shared reference.
+        ///
+        /// The lifetime `'ast` is the lifetime of the tree, so a visitor can keep references to
+        /// the nodes it visits. See the [module documentation](super) for the traversal order.
+        pub trait Visitor<'ast> {
+            $(
+                #[doc = concat!(
+                    "Called for each [`", stringify!($ty), "`] of the tree. Visits its children ",
+                    "with [`", stringify!($walk), "`] by default."
+                )]
+                fn $visit(&mut self, node: &'ast $ty) {
+                    $walk(self, node);
+                }
+            )*
+        }
+    };
+}
+
+node_types!(visitor);
+
+/// Visits a node, dispatching on its kind.
+///
+/// `this` and empty statements have no children and no method of their own.
+pub fn walk_node<'ast, V>(visitor: &mut V, node: &'ast Node)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    match node {
+        Node::ArrayDecl(node) => visitor.visit_array_decl(node),
+        Node::ArrowFunctionDecl(node) => visitor.visit_arrow_function_decl(node),
+        Node::Assign(node) => visitor.visit_assign(node),
+        Node::AsyncFunctionDecl(node) => visitor.visit_async_function_decl(node),
+        Node::AsyncFunctionExpr(node) => visitor.visit_async_function_expr(node),
+        Node::AsyncGeneratorExpr(node) => visitor.visit_async_generator_expr(node),
+        Node::AsyncGeneratorDecl(node) => visitor.visit_async_generator_decl(node),
+        Node::AwaitExpr(node) => visitor.visit_await_expr(node),
+        Node::BinOp(node) => visitor.visit_bin_op(node),
+        Node::Block(node) => visitor.visit_block(node),
+        Node::Break(node) => visitor.visit_break(node),
+        Node::Call(node) => visitor.visit_call(node),
+        Node::ConditionalOp(node) => visitor.visit_conditional_op(node),
+        Node::Const(node) => visitor.visit_const(node),
+        Node::ConstDeclList(node)
+        | Node::LetDeclList(node)
+        | Node::UsingDeclList(node)
+        | Node::VarDeclList(node) => visitor.visit_declaration_list(node),
+        Node::Continue(node) => visitor.visit_continue(node),
+        Node::DoWhileLoop(node) => visitor.visit_do_while_loop(node),
+        Node::FunctionDecl(node) => visitor.visit_function_decl(node),
+        Node::FunctionExpr(node) => visitor.visit_function_expr(node),
+        Node::GetConstField(node) => visitor.visit_get_const_field(node),
+        Node::GetField(node) => visitor.visit_get_field(node),
+        Node::ForLoop(node) => visitor.visit_for_loop(node),
+        Node::ForInLoop(node) => visitor.visit_for_in_loop(node),
+        Node::ForOfLoop(node) => visitor.visit_for_of_loop(node),
+        Node::If(node) => visitor.visit_if(node),
+        Node::Identifier(node) => visitor.visit_identifier(node),
+        Node::New(node) => visitor.visit_new(node),
+        Node::Object(node) => visitor.visit_object(node),
+        Node::Return(node) => visitor.visit_return(node),
+        Node::Switch(node) => visitor.visit_switch(node),
+        Node::Spread(node) => visitor.visit_spread(node),
+        Node::TaggedTemplate(node) => visitor.visit_tagged_template(node),
+        Node::TemplateLit(node) => visitor.visit_template_lit(node),
+        Node::Throw(node) => visitor.visit_throw(node),
+        Node::Try(node) => visitor.visit_try(node),
+        Node::UnaryOp(node) => visitor.visit_unary_op(node),
+        Node::WhileLoop(node) => visitor.visit_while_loop(node),
+        Node::Yield(node) => visitor.visit_yield(node),
+        Node::GeneratorDecl(node) => visitor.visit_generator_decl(node),
+        Node::GeneratorExpr(node) => visitor.visit_generator_expr(node),
+        Node::This | Node::Empty | Node::Error => {}
+    }
+}
+
+/// Visits the statements of a statement list.
+pub fn walk_statement_list<'ast, V>(visitor: &mut V, list: &'ast StatementList)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    for node in list.items() {
+        visitor.visit_node(node);
+    }
+}
+
+/// Visits the elements of an array literal.
+pub fn walk_array_decl<'ast, V>(visitor: &mut V, array: &'ast ArrayDecl)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    for node in array.as_ref() {
+        visitor.visit_node(node);
+    }
+}
+
+/// Visits the parameters and the body of an arrow function.
+pub fn walk_arrow_function_decl<'ast, V>(visitor: &mut V, function: &'ast ArrowFunctionDecl)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_formal_parameter_list(function.params());
+    visitor.visit_statement_list(function.body());
+}
+
+/// Visits the target and the value of an assignment.
+pub fn walk_assign<'ast, V>(visitor: &mut V, assign: &'ast Assign)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_assign_target(assign.lhs());
+    visitor.visit_node(assign.rhs());
+}
+
+/// Visits the target of an assignment.
+pub fn walk_assign_target<'ast, V>(visitor: &mut V, target: &'ast AssignTarget)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    match target {
+        AssignTarget::Identifier(ident) => visitor.visit_identifier(ident),
+        AssignTarget::GetConstField(field) => visitor.visit_get_const_field(field),
+        AssignTarget::GetField(field) => visitor.visit_get_field(field),
+        AssignTarget::DeclarationPattern(pattern) => visitor.visit_declaration_pattern(pattern),
+    }
+}
+
+/// Visits the parameters and the body of an async function declaration.
+pub fn walk_async_function_decl<'ast, V>(visitor: &mut V, function: &'ast AsyncFunctionDecl)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_formal_parameter_list(function.parameters());
+    visitor.visit_statement_list(function.body());
+}
+
+/// Visits the parameters and the body of an async function expression.
+pub fn walk_async_function_expr<'ast, V>(visitor: &mut V, function: &'ast AsyncFunctionExpr)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_formal_parameter_list(function.parameters());
+    visitor.visit_statement_list(function.body());
+}
+
+/// Visits the parameters and the body of an async generator declaration.
+pub fn walk_async_generator_decl<'ast, V>(visitor: &mut V, function: &'ast AsyncGeneratorDecl)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_formal_parameter_list(function.parameters());
+    visitor.visit_statement_list(function.body());
+}
+
+/// Visits the parameters and the body of an async generator expression.
+pub fn walk_async_generator_expr<'ast, V>(visitor: &mut V, function: &'ast AsyncGeneratorExpr)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_formal_parameter_list(function.parameters());
+    visitor.visit_statement_list(function.body());
+}
+
+/// Visits the operand of an `await` expression.
+pub fn walk_await_expr<'ast, V>(visitor: &mut V, expr: &'ast AwaitExpr)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(expr.expr());
+}
+
+/// Visits the operands of a binary operation.
+pub fn walk_bin_op<'ast, V>(visitor: &mut V, op: &'ast BinOp)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(op.lhs());
+    visitor.visit_node(op.rhs());
+}
+
+/// Visits the statements of a block.
+pub fn walk_block<'ast, V>(visitor: &mut V, block: &'ast Block)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_statement_list(block.statement_list());
+}
+
+/// Does nothing, a `break` statement has no children.
+pub fn walk_break<'ast, V>(_visitor: &mut V, _node: &'ast Break)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+}
+
+/// Visits the callee and the arguments of a call.
+pub fn walk_call<'ast, V>(visitor: &mut V, call: &'ast Call)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(call.expr());
+    for arg in call.args() {
+        visitor.visit_node(arg);
+    }
+}
+
+/// Visits the condition and the branches of a conditional operator.
+pub fn walk_conditional_op<'ast, V>(visitor: &mut V, op: &'ast ConditionalOp)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(op.cond());
+    visitor.visit_node(op.if_true());
+    visitor.visit_node(op.if_false());
+}
+
+/// Does nothing, a literal has no children.
+pub fn walk_const<'ast, V>(_visitor: &mut V, _node: &'ast Const)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+}
+
+/// Does nothing, a `continue` statement has no children.
+pub fn walk_continue<'ast, V>(_visitor: &mut V, _node: &'ast Continue)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+}
+
+/// Visits the declarations of a `var`, `let`, `const` or `using` declaration list.
+pub fn walk_declaration_list<'ast, V>(visitor: &mut V, list: &'ast DeclarationList)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    for declaration in list.as_ref() {
+        visitor.visit_declaration(declaration);
+    }
+}
+
+/// Visits the binding and the initializer of a declaration.
+pub fn walk_declaration<'ast, V>(visitor: &mut V, declaration: &'ast Declaration)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    match declaration {
+        Declaration::Identifier { ident, init } => {
+            visitor.visit_identifier(ident);
+            if let Some(init) = init {
+                visitor.visit_node(init);
+            }
+        }
+        Declaration::Pattern(pattern) => visitor.visit_declaration_pattern(pattern),
+    }
+}
+
+/// Visits the bindings and the initializer of a binding pattern.
+pub fn walk_declaration_pattern<'ast, V>(visitor: &mut V, pattern: &'ast DeclarationPattern)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    match pattern {
+        DeclarationPattern::Object(pattern) => {
+            for binding in pattern.bindings() {
+                visitor.visit_binding_pattern_type_object(binding);
+            }
+            if let Some(init) = pattern.init() {
+                visitor.visit_node(init);
+            }
+        }
+        DeclarationPattern::Array(pattern) => {
+            for binding in pattern.bindings() {
+                visitor.visit_binding_pattern_type_array(binding);
+            }
+            if let Some(init) = pattern.init() {
+                visitor.visit_node(init);
+            }
+        }
+    }
+}
+
+/// Visits the target and the default value of a binding of an object binding pattern.
+pub fn walk_binding_pattern_type_object<'ast, V>(
+    visitor: &mut V,
+    binding: &'ast BindingPatternTypeObject,
+) where
+    V: Visitor<'ast> + ?Sized,
+{
+    match binding {
+        BindingPatternTypeObject::SingleName { default_init, .. } => {
+            if let Some(init) = default_init {
+                visitor.visit_node(init);
+            }
+        }
+        BindingPatternTypeObject::RestGetConstField {
+            get_const_field, ..
+        } => visitor.visit_get_const_field(get_const_field),
+        BindingPatternTypeObject::BindingPattern {
+            pattern,
+            default_init,
+            ..
+        } => {
+            visitor.visit_declaration_pattern(pattern);
+            if let Some(init) = default_init {
+                visitor.visit_node(init);
+            }
+        }
+        BindingPatternTypeObject::Empty | BindingPatternTypeObject::RestProperty { .. } => {}
+    }
+}
+
+/// Visits the target and the default value of a binding of an array binding pattern.
+pub fn walk_binding_pattern_type_array<'ast, V>(
+    visitor: &mut V,
+    binding: &'ast BindingPatternTypeArray,
+) where
+    V: Visitor<'ast> + ?Sized,
+{
+    match binding {
+        BindingPatternTypeArray::SingleName { default_init, .. } => {
+            if let Some(init) = default_init {
+                visitor.visit_node(init);
+            }
+        }
+        BindingPatternTypeArray::GetField { get_field }
+        | BindingPatternTypeArray::GetFieldRest { get_field } => {
+            visitor.visit_get_field(get_field);
+        }
+        BindingPatternTypeArray::GetConstField { get_const_field }
+        | BindingPatternTypeArray::GetConstFieldRest { get_const_field } => {
+            visitor.visit_get_const_field(get_const_field);
+        }
+        BindingPatternTypeArray::BindingPattern { pattern }
+        | BindingPatternTypeArray::BindingPatternRest { pattern } => {
+            visitor.visit_declaration_pattern(pattern);
+        }
+        BindingPatternTypeArray::Empty
+        | BindingPatternTypeArray::Elision
+        | BindingPatternTypeArray::SingleNameRest { .. } => {}
+    }
+}
+
+/// Visits the body and the condition of a `do...while` loop.
+pub fn walk_do_while_loop<'ast, V>(visitor: &mut V, do_while: &'ast DoWhileLoop)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(do_while.body());
+    visitor.visit_node(do_while.cond());
+}
+
+/// Visits the parameters of a function.
+pub fn walk_formal_parameter_list<'ast, V>(visitor: &mut V, parameters: &'ast FormalParameterList)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    for parameter in parameters.parameters.iter() {
+        visitor.visit_formal_parameter(parameter);
+    }
+}
+
+/// Visits the declaration of a parameter.
+pub fn walk_formal_parameter<'ast, V>(visitor: &mut V, parameter: &'ast FormalParameter)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_declaration(parameter.declaration());
+}
+
+/// Visits the initializer, the object and the body of a `for...in` loop.
+pub fn walk_for_in_loop<'ast, V>(visitor: &mut V, for_in_loop: &'ast ForInLoop)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_iterable_loop_initializer(for_in_loop.init());
+    visitor.visit_node(for_in_loop.expr());
+    visitor.visit_node(for_in_loop.body());
+}
+
+/// Visits the initializer, the condition, the final expression and the body of a `for` loop.
+pub fn walk_for_loop<'ast, V>(visitor: &mut V, for_loop: &'ast ForLoop)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    if let Some(init) = for_loop.init() {
+        visitor.visit_node(init);
+    }
+    if let Some(condition) = for_loop.condition() {
+        visitor.visit_node(condition);
+    }
+    if let Some(final_expr) = for_loop.final_expr() {
+        visitor.visit_node(final_expr);
+    }
+    visitor.visit_node(for_loop.body());
+}
+
+/// Visits the initializer, the iterable and the body of a `for...of` loop.
+pub fn walk_for_of_loop<'ast, V>(visitor: &mut V, for_of_loop: &'ast ForOfLoop)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_iterable_loop_initializer(for_of_loop.init());
+    visitor.visit_node(for_of_loop.iterable());
+    visitor.visit_node(for_of_loop.body());
+}
+
+/// Visits the parameters and the body of a function declaration.
+pub fn walk_function_decl<'ast, V>(visitor: &mut V, function: &'ast FunctionDecl)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_formal_parameter_list(function.parameters());
+    visitor.visit_statement_list(function.body());
+}
+
+/// Visits the parameters and the body of a function expression.
+pub fn walk_function_expr<'ast, V>(visitor: &mut V, function: &'ast FunctionExpr)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_formal_parameter_list(function.parameters());
+    visitor.visit_statement_list(function.body());
+}
+
+/// Visits the parameters and the body of a generator declaration.
+pub fn walk_generator_decl<'ast, V>(visitor: &mut V, function: &'ast GeneratorDecl)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_formal_parameter_list(function.parameters());
+    visitor.visit_statement_list(function.body());
+}
+
+/// Visits the parameters and the body of a generator expression.
+pub fn walk_generator_expr<'ast, V>(visitor: &mut V, function: &'ast GeneratorExpr)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_formal_parameter_list(function.parameters());
+    visitor.visit_statement_list(function.body());
+}
+
+/// Visits the object of a property access with a constant name.
+pub fn walk_get_const_field<'ast, V>(visitor: &mut V, field: &'ast GetConstField)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(field.obj());
+}
+
+/// Visits the object and the key of a computed property access.
+pub fn walk_get_field<'ast, V>(visitor: &mut V, field: &'ast GetField)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(field.obj());
+    visitor.visit_node(field.field());
+}
+
+/// Does nothing, an identifier has no children.
+pub fn walk_identifier<'ast, V>(_visitor: &mut V, _node: &'ast Identifier)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+}
+
+/// Visits the condition and the branches of an `if` statement.
+pub fn walk_if<'ast, V>(visitor: &mut V, if_node: &'ast If)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(if_node.cond());
+    visitor.visit_node(if_node.body());
+    if let Some(else_node) = if_node.else_node() {
+        visitor.visit_node(else_node);
+    }
+}
+
+/// Visits the binding of a `for...in` or `for...of` loop.
+pub fn walk_iterable_loop_initializer<'ast, V>(
+    visitor: &mut V,
+    initializer: &'ast IterableLoopInitializer,
+) where
+    V: Visitor<'ast> + ?Sized,
+{
+    match initializer {
+        IterableLoopInitializer::Identifier(ident) => visitor.visit_identifier(ident),
+        IterableLoopInitializer::Var(declaration)
+        | IterableLoopInitializer::Let(declaration)
+        | IterableLoopInitializer::Const(declaration) => visitor.visit_declaration(declaration),
+        IterableLoopInitializer::DeclarationPattern(pattern) => {
+            visitor.visit_declaration_pattern(pattern);
+        }
+    }
+}
+
+/// Visits the function of a method definition.
+pub fn walk_method_definition<'ast, V>(visitor: &mut V, method: &'ast MethodDefinition)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    match method {
+        MethodDefinition::Get(function)
+        | MethodDefinition::Set(function)
+        | MethodDefinition::Ordinary(function) => visitor.visit_function_expr(function),
+        MethodDefinition::Generator(function) => visitor.visit_generator_expr(function),
+        MethodDefinition::AsyncGenerator(function) => {
+            visitor.visit_async_generator_expr(function);
+        }
+        MethodDefinition::Async(function) => visitor.visit_async_function_expr(function),
+    }
+}
+
+/// Visits the constructor and the arguments of a `new` expression.
+pub fn walk_new<'ast, V>(visitor: &mut V, new: &'ast New)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(new.expr());
+    for arg in new.args() {
+        visitor.visit_node(arg);
+    }
+}
+
+/// Visits the properties of an object literal.
+pub fn walk_object<'ast, V>(visitor: &mut V, object: &'ast Object)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    for property in object.properties() {
+        visitor.visit_property_definition(property);
+    }
+}
+
+/// Visits the name and the value of a property of an object literal.
+pub fn walk_property_definition<'ast, V>(visitor: &mut V, property: &'ast PropertyDefinition)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    match property {
+        PropertyDefinition::IdentifierReference(_) => {}
+        PropertyDefinition::Property(name, value) => {
+            visitor.visit_property_name(name);
+            visitor.visit_node(value);
+        }
+        PropertyDefinition::MethodDefinition(method, name) => {
+            visitor.visit_property_name(name);
+            visitor.visit_method_definition(method);
+        }
+        PropertyDefinition::SpreadObject(node) => visitor.visit_node(node),
+    }
+}
+
+/// Visits the expression of a computed property name.
+pub fn walk_property_name<'ast, V>(visitor: &mut V, name: &'ast PropertyName)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    match name {
+        PropertyName::Literal(_) => {}
+        PropertyName::Computed(node) => visitor.visit_node(node),
+    }
+}
+
+/// Visits the value of a `return` statement.
+pub fn walk_return<'ast, V>(visitor: &mut V, ret: &'ast Return)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    if let Some(expr) = ret.expr() {
+        visitor.visit_node(expr);
+    }
+}
+
+/// Visits the operand of a spread element.
+pub fn walk_spread<'ast, V>(visitor: &mut V, spread: &'ast Spread)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(spread.val());
+}
+
+/// Visits the value, the cases and the default case of a `switch` statement.
+pub fn walk_switch<'ast, V>(visitor: &mut V, switch: &'ast Switch)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(switch.val());
+    for case in switch.cases() {
+        visitor.visit_case(case);
+    }
+    if let Some(default) = switch.default_statement_list() {
+        visitor.visit_statement_list(default);
+    }
+}
+
+/// Visits the condition and the body of a case of a `switch` statement.
+pub fn walk_case<'ast, V>(visitor: &mut V, case: &'ast Case)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(case.condition());
+    visitor.visit_statement_list(case.body());
+}
+
+/// Visits the tag and the substitutions of a tagged template.
+pub fn walk_tagged_template<'ast, V>(visitor: &mut V, template: &'ast TaggedTemplate)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(template.tag());
+    for expr in template.exprs() {
+        visitor.visit_node(expr);
+    }
+}
+
+/// Visits the elements of a template literal.
+pub fn walk_template_lit<'ast, V>(visitor: &mut V, template: &'ast TemplateLit)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    for element in template.elements() {
+        visitor.visit_template_element(element);
+    }
+}
+
+/// Visits the substitution of an element of a template literal.
+pub fn walk_template_element<'ast, V>(visitor: &mut V, element: &'ast TemplateElement)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    match element {
+        TemplateElement::String(_) => {}
+        TemplateElement::Expr(node) => visitor.visit_node(node),
+    }
+}
+
+/// Visits the value of a `throw` statement.
+pub fn walk_throw<'ast, V>(visitor: &mut V, throw: &'ast Throw)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(throw.expr());
+}
+
+/// Visits the blocks of a `try` statement.
+pub fn walk_try<'ast, V>(visitor: &mut V, try_node: &'ast Try)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_block(try_node.block());
+    if let Some(catch) = try_node.catch() {
+        visitor.visit_catch(catch);
+    }
+    if let Some(finally) = try_node.finally() {
+        visitor.visit_block(finally);
+    }
+}
+
+/// Visits the parameter and the block of a `catch` clause.
+pub fn walk_catch<'ast, V>(visitor: &mut V, catch: &'ast Catch)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    if let Some(parameter) = catch.parameter() {
+        visitor.visit_declaration(parameter);
+    }
+    visitor.visit_block(catch.block());
+}
+
+/// Visits the operand of a unary operation.
+pub fn walk_unary_op<'ast, V>(visitor: &mut V, op: &'ast UnaryOp)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(op.target());
+}
+
+/// Visits the condition and the body of a `while` loop.
+pub fn walk_while_loop<'ast, V>(visitor: &mut V, while_loop: &'ast WhileLoop)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(while_loop.cond());
+    visitor.visit_node(while_loop.body());
+}
+
+/// Visits the operand of a `yield` expression.
+pub fn walk_yield<'ast, V>(visitor: &mut V, r#yield: &'ast Yield)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    if let Some(expr) = r#yield.expr() {
+        visitor.visit_node(expr);
+    }
+}
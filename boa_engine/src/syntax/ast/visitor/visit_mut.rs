@@ -0,0 +1,719 @@
+//! The [`VisitorMut`] trait, traversing a syntax tree by mutable reference.
+
+use crate::syntax::ast::{
+    node::{
+        declaration::{BindingPatternTypeArray, BindingPatternTypeObject},
+        iteration::IterableLoopInitializer,
+        object::{MethodDefinition, PropertyDefinition, PropertyName},
+        operator::assign::AssignTarget,
+        template::TemplateElement,
+        ArrayDecl, ArrowFunctionDecl, Assign, AsyncFunctionDecl, AsyncFunctionExpr,
+        AsyncGeneratorDecl, AsyncGeneratorExpr, AwaitExpr, BinOp, Block, Break, Call, Case, Catch,
+        ConditionalOp, Continue, Declaration, DeclarationList, DeclarationPattern, DoWhileLoop,
+        ForInLoop, ForLoop, ForOfLoop, FormalParameter, FormalParameterList, FunctionDecl,
+        FunctionExpr, GeneratorDecl, GeneratorExpr, GetConstField, GetField, Identifier, If, New,
+        Node, Object, Return, Spread, StatementList, Switch, TaggedTemplate, TemplateLit, Throw,
+        Try, UnaryOp, WhileLoop, Yield,
+    },
+    Const,
+};
+
+macro_rules! visitor_mut {
+    ($($ty:ident: $visit:ident, $walk:ident, $visit_mut:ident, $walk_mut:ident;)*) => {
+        /// A traversal of a syntax tree by mutable reference, to rewrite it in place.
+        ///
+        /// The visitor can change a node before or after visiting its children, or replace it.
+        /// The parser computes a few properties of the functions from their parameters, like
+        /// whether they are simple, which are not updated when the parameters are changed. See the
+        /// [module documentation](super) for the traversal order.
+        pub trait VisitorMut {
+            $(
+                #[doc = concat!(
+                    "Called for each [`", stringify!($ty), "`] of the tree. Visits its children ",
+                    "with [`", stringify!($walk_mut), "`] by default."
+                )]
+                fn $visit_mut(&mut self, node: &mut $ty) {
+                    $walk_mut(self, node);
+                }
+            )*
+        }
+    };
+}
+
+node_types!(visitor_mut);
+
+/// Visits a node, dispatching on its kind.
+///
+/// `this` and empty statements have no children and no method of their own.
+pub fn walk_node_mut<V>(visitor: &mut V, node: &mut Node)
+where
+    V: VisitorMut + ?Sized,
+{
+    match node {
+        Node::ArrayDecl(node) => visitor.visit_array_decl_mut(node),
+        Node::ArrowFunctionDecl(node) => visitor.visit_arrow_function_decl_mut(node),
+        Node::Assign(node) => visitor.visit_assign_mut(node),
+        Node::AsyncFunctionDecl(node) => visitor.visit_async_function_decl_mut(node),
+        Node::AsyncFunctionExpr(node) => visitor.visit_async_function_expr_mut(node),
+        Node::AsyncGeneratorExpr(node) => visitor.visit_async_generator_expr_mut(node),
+        Node::AsyncGeneratorDecl(node) => visitor.visit_async_generator_decl_mut(node),
+        Node::AwaitExpr(node) => visitor.visit_await_expr_mut(node),
+        Node::BinOp(node) => visitor.visit_bin_op_mut(node),
+        Node::Block(node) => visitor.visit_block_mut(node),
+        Node::Break(node) => visitor.visit_break_mut(node),
+        Node::Call(node) => visitor.visit_call_mut(node),
+        Node::ConditionalOp(node) => visitor.visit_conditional_op_mut(node),
+        Node::Const(node) => visitor.visit_const_mut(node),
+        Node::ConstDeclList(node)
+        | Node::LetDeclList(node)
+        | Node::UsingDeclList(node)
+        | Node::VarDeclList(node) => visitor.visit_declaration_list_mut(node),
+        Node::Continue(node) => visitor.visit_continue_mut(node),
+        Node::DoWhileLoop(node) => visitor.visit_do_while_loop_mut(node),
+        Node::FunctionDecl(node) => visitor.visit_function_decl_mut(node),
+        Node::FunctionExpr(node) => visitor.visit_function_expr_mut(node),
+        Node::GetConstField(node) => visitor.visit_get_const_field_mut(node),
+        Node::GetField(node) => visitor.visit_get_field_mut(node),
+        Node::ForLoop(node) => visitor.visit_for_loop_mut(node),
+        Node::ForInLoop(node) => visitor.visit_for_in_loop_mut(node),
+        Node::ForOfLoop(node) => visitor.visit_for_of_loop_mut(node),
+        Node::If(node) => visitor.visit_if_mut(node),
+        Node::Identifier(node) => visitor.visit_identifier_mut(node),
+        Node::New(node) => visitor.visit_new_mut(node),
+        Node::Object(node) => visitor.visit_object_mut(node),
+        Node::Return(node) => visitor.visit_return_mut(node),
+        Node::Switch(node) => visitor.visit_switch_mut(node),
+        Node::Spread(node) => visitor.visit_spread_mut(node),
+        Node::TaggedTemplate(node) => visitor.visit_tagged_template_mut(node),
+        Node::TemplateLit(node) => visitor.visit_template_lit_mut(node),
+        Node::Throw(node) => visitor.visit_throw_mut(node),
+        Node::Try(node) => visitor.visit_try_mut(node),
+        Node::UnaryOp(node) => visitor.visit_unary_op_mut(node),
+        Node::WhileLoop(node) => visitor.visit_while_loop_mut(node),
+        Node::Yield(node) => visitor.visit_yield_mut(node),
+        Node::GeneratorDecl(node) => visitor.visit_generator_decl_mut(node),
+        Node::GeneratorExpr(node) => visitor.visit_generator_expr_mut(node),
+        Node::This | Node::Empty | Node::Error => {}
+    }
+}
+
+/// Visits the statements of a statement list.
+pub fn walk_statement_list_mut<V>(visitor: &mut V, list: &mut StatementList)
+where
+    V: VisitorMut + ?Sized,
+{
+    for node in list.items_mut() {
+        visitor.visit_node_mut(node);
+    }
+}
+
+/// Visits the elements of an array literal.
+pub fn walk_array_decl_mut<V>(visitor: &mut V, array: &mut ArrayDecl)
+where
+    V: VisitorMut + ?Sized,
+{
+    for node in array.as_mut() {
+        visitor.visit_node_mut(node);
+    }
+}
+
+/// Visits the parameters and the body of an arrow function.
+pub fn walk_arrow_function_decl_mut<V>(visitor: &mut V, function: &mut ArrowFunctionDecl)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_formal_parameter_list_mut(function.params_mut());
+    visitor.visit_statement_list_mut(function.body_mut());
+}
+
+/// Visits the target and the value of an assignment.
+pub fn walk_assign_mut<V>(visitor: &mut V, assign: &mut Assign)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_assign_target_mut(assign.lhs_mut());
+    visitor.visit_node_mut(assign.rhs_mut());
+}
+
+/// Visits the target of an assignment.
+pub fn walk_assign_target_mut<V>(visitor: &mut V, target: &mut AssignTarget)
+where
+    V: VisitorMut + ?Sized,
+{
+    match target {
+        AssignTarget::Identifier(ident) => visitor.visit_identifier_mut(ident),
+        AssignTarget::GetConstField(field) => visitor.visit_get_const_field_mut(field),
+        AssignTarget::GetField(field) => visitor.visit_get_field_mut(field),
+        AssignTarget::DeclarationPattern(pattern) => visitor.visit_declaration_pattern_mut(pattern),
+    }
+}
+
+/// Visits the parameters and the body of an async function declaration.
+pub fn walk_async_function_decl_mut<V>(visitor: &mut V, function: &mut AsyncFunctionDecl)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_formal_parameter_list_mut(function.parameters_mut());
+    visitor.visit_statement_list_mut(function.body_mut());
+}
+
+/// Visits the parameters and the body of an async function expression.
+pub fn walk_async_function_expr_mut<V>(visitor: &mut V, function: &mut AsyncFunctionExpr)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_formal_parameter_list_mut(function.parameters_mut());
+    visitor.visit_statement_list_mut(function.body_mut());
+}
+
+/// Visits the parameters and the body of an async generator declaration.
+pub fn walk_async_generator_decl_mut<V>(visitor: &mut V, function: &mut AsyncGeneratorDecl)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_formal_parameter_list_mut(function.parameters_mut());
+    visitor.visit_statement_list_mut(function.body_mut());
+}
+
+/// Visits the parameters and the body of an async generator expression.
+pub fn walk_async_generator_expr_mut<V>(visitor: &mut V, function: &mut AsyncGeneratorExpr)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_formal_parameter_list_mut(function.parameters_mut());
+    visitor.visit_statement_list_mut(function.body_mut());
+}
+
+/// Visits the operand of an `await` expression.
+pub fn walk_await_expr_mut<V>(visitor: &mut V, expr: &mut AwaitExpr)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_node_mut(expr.expr_mut());
+}
+
+/// Visits the operands of a binary operation.
+pub fn walk_bin_op_mut<V>(visitor: &mut V, op: &mut BinOp)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_node_mut(op.lhs_mut());
+    visitor.visit_node_mut(op.rhs_mut());
+}
+
+/// Visits the statements of a block.
+pub fn walk_block_mut<V>(visitor: &mut V, block: &mut Block)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_statement_list_mut(block.statement_list_mut());
+}
+
+/// Does nothing, a `break` statement has no children.
+pub fn walk_break_mut<V>(_visitor: &mut V, _node: &mut Break)
+where
+    V: VisitorMut + ?Sized,
+{
+}
+
+/// Visits the callee and the arguments of a call.
+pub fn walk_call_mut<V>(visitor: &mut V, call: &mut Call)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_node_mut(call.expr_mut());
+    for arg in call.args_mut() {
+        visitor.visit_node_mut(arg);
+    }
+}
+
+/// Visits the condition and the branches of a conditional operator.
+pub fn walk_conditional_op_mut<V>(visitor: &mut V, op: &mut ConditionalOp)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_node_mut(op.cond_mut());
+    visitor.visit_node_mut(op.if_true_mut());
+    visitor.visit_node_mut(op.if_false_mut());
+}
+
+/// Does nothing, a literal has no children.
+pub fn walk_const_mut<V>(_visitor: &mut V, _node: &mut Const)
+where
+    V: VisitorMut + ?Sized,
+{
+}
+
+/// Does nothing, a `continue` statement has no children.
+pub fn walk_continue_mut<V>(_visitor: &mut V, _node: &mut Continue)
+where
+    V: VisitorMut + ?Sized,
+{
+}
+
+/// Visits the declarations of a `var`, `let`, `const` or `using` declaration list.
+pub fn walk_declaration_list_mut<V>(visitor: &mut V, list: &mut DeclarationList)
+where
+    V: VisitorMut + ?Sized,
+{
+    for declaration in list.as_mut() {
+        visitor.visit_declaration_mut(declaration);
+    }
+}
+
+/// Visits the binding and the initializer of a declaration.
+pub fn walk_declaration_mut<V>(visitor: &mut V, declaration: &mut Declaration)
+where
+    V: VisitorMut + ?Sized,
+{
+    match declaration {
+        Declaration::Identifier { ident, init } => {
+            visitor.visit_identifier_mut(ident);
+            if let Some(init) = init {
+                visitor.visit_node_mut(init);
+            }
+        }
+        Declaration::Pattern(pattern) => visitor.visit_declaration_pattern_mut(pattern),
+    }
+}
+
+/// Visits the bindings and the initializer of a binding pattern.
+pub fn walk_declaration_pattern_mut<V>(visitor: &mut V, pattern: &mut DeclarationPattern)
+where
+    V: VisitorMut + ?Sized,
+{
+    match pattern {
+        DeclarationPattern::Object(pattern) => {
+            for binding in pattern.bindings_mut() {
+                visitor.visit_binding_pattern_type_object_mut(binding);
+            }
+            if let Some(init) = pattern.init_mut() {
+                visitor.visit_node_mut(init);
+            }
+        }
+        DeclarationPattern::Array(pattern) => {
+            for binding in pattern.bindings_mut() {
+                visitor.visit_binding_pattern_type_array_mut(binding);
+            }
+            if let Some(init) = pattern.init_mut() {
+                visitor.visit_node_mut(init);
+            }
+        }
+    }
+}
+
+/// Visits the target and the default value of a binding of an object binding pattern.
+pub fn walk_binding_pattern_type_object_mut<V>(
+    visitor: &mut V,
+    binding: &mut BindingPatternTypeObject,
+) where
+    V: VisitorMut + ?Sized,
+{
+    match binding {
+        BindingPatternTypeObject::SingleName { default_init, .. } => {
+            if let Some(init) = default_init {
+                visitor.visit_node_mut(init);
+            }
+        }
+        BindingPatternTypeObject::RestGetConstField {
+            get_const_field, ..
+        } => visitor.visit_get_const_field_mut(get_const_field),
+        BindingPatternTypeObject::BindingPattern {
+            pattern,
+            default_init,
+            ..
+        } => {
+            visitor.visit_declaration_pattern_mut(pattern);
+            if let Some(init) = default_init {
+                visitor.visit_node_mut(init);
+            }
+        }
+        BindingPatternTypeObject::Empty | BindingPatternTypeObject::RestProperty { .. } => {}
+    }
+}
+
+/// Visits the target and the default value of a binding of an array binding pattern.
+pub fn walk_binding_pattern_type_array_mut<V>(
+    visitor: &mut V,
+    binding: &mut BindingPatternTypeArray,
+) where
+    V: VisitorMut + ?Sized,
+{
+    match binding {
+        BindingPatternTypeArray::SingleName { default_init, .. } => {
+            if let Some(init) = default_init {
+                visitor.visit_node_mut(init);
+            }
+        }
+        BindingPatternTypeArray::GetField { get_field }
+        | BindingPatternTypeArray::GetFieldRest { get_field } => {
+            visitor.visit_get_field_mut(get_field);
+        }
+        BindingPatternTypeArray::GetConstField { get_const_field }
+        | BindingPatternTypeArray::GetConstFieldRest { get_const_field } => {
+            visitor.visit_get_const_field_mut(get_const_field);
+        }
+        BindingPatternTypeArray::BindingPattern { pattern }
+        | BindingPatternTypeArray::BindingPatternRest { pattern } => {
+            visitor.visit_declaration_pattern_mut(pattern);
+        }
+        BindingPatternTypeArray::Empty
+        | BindingPatternTypeArray::Elision
+        | BindingPatternTypeArray::SingleNameRest { .. } => {}
+    }
+}
+
+/// Visits the body and the condition of a `do...while` loop.
+pub fn walk_do_while_loop_mut<V>(visitor: &mut V, do_while: &mut DoWhileLoop)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_node_mut(do_while.body_mut());
+    visitor.visit_node_mut(do_while.cond_mut());
+}
+
+/// Visits the parameters of a function.
+pub fn walk_formal_parameter_list_mut<V>(visitor: &mut V, parameters: &mut FormalParameterList)
+where
+    V: VisitorMut + ?Sized,
+{
+    for parameter in parameters.parameters.iter_mut() {
+        visitor.visit_formal_parameter_mut(parameter);
+    }
+}
+
+/// Visits the declaration of a parameter.
+pub fn walk_formal_parameter_mut<V>(visitor: &mut V, parameter: &mut FormalParameter)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_declaration_mut(parameter.declaration_mut());
+}
+
+/// Visits the initializer, the object and the body of a `for...in` loop.
+pub fn walk_for_in_loop_mut<V>(visitor: &mut V, for_in_loop: &mut ForInLoop)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_iterable_loop_initializer_mut(for_in_loop.init_mut());
+    visitor.visit_node_mut(for_in_loop.expr_mut());
+    visitor.visit_node_mut(for_in_loop.body_mut());
+}
+
+/// Visits the initializer, the condition, the final expression and the body of a `for` loop.
+pub fn walk_for_loop_mut<V>(visitor: &mut V, for_loop: &mut ForLoop)
+where
+    V: VisitorMut + ?Sized,
+{
+    if let Some(init) = for_loop.init_mut() {
+        visitor.visit_node_mut(init);
+    }
+    if let Some(condition) = for_loop.condition_mut() {
+        visitor.visit_node_mut(condition);
+    }
+    if let Some(final_expr) = for_loop.final_expr_mut() {
+        visitor.visit_node_mut(final_expr);
+    }
+    visitor.visit_node_mut(for_loop.body_mut());
+}
+
+/// Visits the initializer, the iterable and the body of a `for...of` loop.
+pub fn walk_for_of_loop_mut<V>(visitor: &mut V, for_of_loop: &mut ForOfLoop)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_iterable_loop_initializer_mut(for_of_loop.init_mut());
+    visitor.visit_node_mut(for_of_loop.iterable_mut());
+    visitor.visit_node_mut(for_of_loop.body_mut());
+}
+
+/// Visits the parameters and the body of a function declaration.
+pub fn walk_function_decl_mut<V>(visitor: &mut V, function: &mut FunctionDecl)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_formal_parameter_list_mut(function.parameters_mut());
+    visitor.visit_statement_list_mut(function.body_mut());
+}
+
+/// Visits the parameters and the body of a function expression.
+pub fn walk_function_expr_mut<V>(visitor: &mut V, function: &mut FunctionExpr)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_formal_parameter_list_mut(function.parameters_mut());
+    visitor.visit_statement_list_mut(function.body_mut());
+}
+
+/// Visits the parameters and the body of a generator declaration.
+pub fn walk_generator_decl_mut<V>(visitor: &mut V, function: &mut GeneratorDecl)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_formal_parameter_list_mut(function.parameters_mut());
+    visitor.visit_statement_list_mut(function.body_mut());
+}
+
+/// Visits the parameters and the body of a generator expression.
+pub fn walk_generator_expr_mut<V>(visitor: &mut V, function: &mut GeneratorExpr)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_formal_parameter_list_mut(function.parameters_mut());
+    visitor.visit_statement_list_mut(function.body_mut());
+}
+
+/// Visits the object of a property access with a constant name.
+pub fn walk_get_const_field_mut<V>(visitor: &mut V, field: &mut GetConstField)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_node_mut(field.obj_mut());
+}
+
+/// Visits the object and the key of a computed property access.
+pub fn walk_get_field_mut<V>(visitor: &mut V, field: &mut GetField)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_node_mut(field.obj_mut());
+    visitor.visit_node_mut(field.field_mut());
+}
+
+/// Does nothing, an identifier has no children.
+pub fn walk_identifier_mut<V>(_visitor: &mut V, _node: &mut Identifier)
+where
+    V: VisitorMut + ?Sized,
+{
+}
+
+/// Visits the condition and the branches of an `if` statement.
+pub fn walk_if_mut<V>(visitor: &mut V, if_node: &mut If)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_node_mut(if_node.cond_mut());
+    visitor.visit_node_mut(if_node.body_mut());
+    if let Some(else_node) = if_node.else_node_mut() {
+        visitor.visit_node_mut(else_node);
+    }
+}
+
+/// Visits the binding of a `for...in` or `for...of` loop.
+pub fn walk_iterable_loop_initializer_mut<V>(
+    visitor: &mut V,
+    initializer: &mut IterableLoopInitializer,
+) where
+    V: VisitorMut + ?Sized,
+{
+    match initializer {
+        IterableLoopInitializer::Identifier(ident) => visitor.visit_identifier_mut(ident),
+        IterableLoopInitializer::Var(declaration)
+        | IterableLoopInitializer::Let(declaration)
+        | IterableLoopInitializer::Const(declaration) => visitor.visit_declaration_mut(declaration),
+        IterableLoopInitializer::DeclarationPattern(pattern) => {
+            visitor.visit_declaration_pattern_mut(pattern);
+        }
+    }
+}
+
+/// Visits the function of a method definition.
+pub fn walk_method_definition_mut<V>(visitor: &mut V, method: &mut MethodDefinition)
+where
+    V: VisitorMut + ?Sized,
+{
+    match method {
+        MethodDefinition::Get(function)
+        | MethodDefinition::Set(function)
+        | MethodDefinition::Ordinary(function) => visitor.visit_function_expr_mut(function),
+        MethodDefinition::Generator(function) => visitor.visit_generator_expr_mut(function),
+        MethodDefinition::AsyncGenerator(function) => {
+            visitor.visit_async_generator_expr_mut(function);
+        }
+        MethodDefinition::Async(function) => visitor.visit_async_function_expr_mut(function),
+    }
+}
+
+/// Visits the constructor and the arguments of a `new` expression.
+pub fn walk_new_mut<V>(visitor: &mut V, new: &mut New)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_node_mut(new.expr_mut());
+    for arg in new.args_mut() {
+        visitor.visit_node_mut(arg);
+    }
+}
+
+/// Visits the properties of an object literal.
+pub fn walk_object_mut<V>(visitor: &mut V, object: &mut Object)
+where
+    V: VisitorMut + ?Sized,
+{
+    for property in object.properties_mut() {
+        visitor.visit_property_definition_mut(property);
+    }
+}
+
+/// Visits the name and the value of a property of an object literal.
+pub fn walk_property_definition_mut<V>(visitor: &mut V, property: &mut PropertyDefinition)
+where
+    V: VisitorMut + ?Sized,
+{
+    match property {
+        PropertyDefinition::IdentifierReference(_) => {}
+        PropertyDefinition::Property(name, value) => {
+            visitor.visit_property_name_mut(name);
+            visitor.visit_node_mut(value);
+        }
+        PropertyDefinition::MethodDefinition(method, name) => {
+            visitor.visit_property_name_mut(name);
+            visitor.visit_method_definition_mut(method);
+        }
+        PropertyDefinition::SpreadObject(node) => visitor.visit_node_mut(node),
+    }
+}
+
+/// Visits the expression of a computed property name.
+pub fn walk_property_name_mut<V>(visitor: &mut V, name: &mut PropertyName)
+where
+    V: VisitorMut + ?Sized,
+{
+    match name {
+        PropertyName::Literal(_) => {}
+        PropertyName::Computed(node) => visitor.visit_node_mut(node),
+    }
+}
+
+/// Visits the value of a `return` statement.
+pub fn walk_return_mut<V>(visitor: &mut V, ret: &mut Return)
+where
+    V: VisitorMut + ?Sized,
+{
+    if let Some(expr) = ret.expr_mut() {
+        visitor.visit_node_mut(expr);
+    }
+}
+
+/// Visits the operand of a spread element.
+pub fn walk_spread_mut<V>(visitor: &mut V, spread: &mut Spread)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_node_mut(spread.val_mut());
+}
+
+/// Visits the value, the cases and the default case of a `switch` statement.
+pub fn walk_switch_mut<V>(visitor: &mut V, switch: &mut Switch)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_node_mut(switch.val_mut());
+    for case in switch.cases_mut() {
+        visitor.visit_case_mut(case);
+    }
+    if let Some(default) = switch.default_statement_list_mut() {
+        visitor.visit_statement_list_mut(default);
+    }
+}
+
+/// Visits the condition and the body of a case of a `switch` statement.
+pub fn walk_case_mut<V>(visitor: &mut V, case: &mut Case)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_node_mut(case.condition_mut());
+    visitor.visit_statement_list_mut(case.body_mut());
+}
+
+/// Visits the tag and the substitutions of a tagged template.
+pub fn walk_tagged_template_mut<V>(visitor: &mut V, template: &mut TaggedTemplate)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_node_mut(template.tag_mut());
+    for expr in template.exprs_mut() {
+        visitor.visit_node_mut(expr);
+    }
+}
+
+/// Visits the elements of a template literal.
+pub fn walk_template_lit_mut<V>(visitor: &mut V, template: &mut TemplateLit)
+where
+    V: VisitorMut + ?Sized,
+{
+    for element in template.elements_mut() {
+        visitor.visit_template_element_mut(element);
+    }
+}
+
+/// Visits the substitution of an element of a template literal.
+pub fn walk_template_element_mut<V>(visitor: &mut V, element: &mut TemplateElement)
+where
+    V: VisitorMut + ?Sized,
+{
+    match element {
+        TemplateElement::String(_) => {}
+        TemplateElement::Expr(node) => visitor.visit_node_mut(node),
+    }
+}
+
+/// Visits the value of a `throw` statement.
+pub fn walk_throw_mut<V>(visitor: &mut V, throw: &mut Throw)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_node_mut(throw.expr_mut());
+}
+
+/// Visits the blocks of a `try` statement.
+pub fn walk_try_mut<V>(visitor: &mut V, try_node: &mut Try)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_block_mut(try_node.block_mut());
+    if let Some(catch) = try_node.catch_mut() {
+        visitor.visit_catch_mut(catch);
+    }
+    if let Some(finally) = try_node.finally_mut() {
+        visitor.visit_block_mut(finally);
+    }
+}
+
+/// Visits the parameter and the block of a `catch` clause.
+pub fn walk_catch_mut<V>(visitor: &mut V, catch: &mut Catch)
+where
+    V: VisitorMut + ?Sized,
+{
+    if let Some(parameter) = catch.parameter_mut() {
+        visitor.visit_declaration_mut(parameter);
+    }
+    visitor.visit_block_mut(catch.block_mut());
+}
+
+/// Visits the operand of a unary operation.
+pub fn walk_unary_op_mut<V>(visitor: &mut V, op: &mut UnaryOp)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_node_mut(op.target_mut());
+}
+
+/// Visits the condition and the body of a `while` loop.
+pub fn walk_while_loop_mut<V>(visitor: &mut V, while_loop: &mut WhileLoop)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_node_mut(while_loop.cond_mut());
+    visitor.visit_node_mut(while_loop.body_mut());
+}
+
+/// Visits the operand of a `yield` expression.
+pub fn walk_yield_mut<V>(visitor: &mut V, r#yield: &mut Yield)
+where
+    V: VisitorMut + ?Sized,
+{
+    if let Some(expr) = r#yield.expr_mut() {
+        visitor.visit_node_mut(expr);
+    }
+}
@@ -0,0 +1,1315 @@
+//! Generation of source text from the syntax tree.
+//!
+//! The [`ToSource`] trait prints a [`StatementList`] or a [`Node`] back to JavaScript, either
+//! [readable](Formatting::readable) or [minified](Formatting::minified). Unlike the
+//! [`ToInternedString`](boa_interner::ToInternedString) implementations of the nodes, which are
+//! meant for display, the generated text parses back to the same tree: parentheses are added
+//! where the precedence of the operators requires them, and strings and template literals are
+//! escaped.
+//!
+//! Comments and the original formatting are not part of the tree, and are not restored.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    builtins::Number,
+    syntax::ast::{
+        node::{
+            declaration::{BindingPatternTypeArray, BindingPatternTypeObject},
+            iteration::IterableLoopInitializer,
+            object::{MethodDefinition, PropertyDefinition, PropertyName},
+            operator::assign::AssignTarget,
+            template::TemplateElement,
+            ArrayDecl, BinOp, Declaration, DeclarationList, DeclarationPattern,
+            FormalParameterList, GetConstField, GetField, Node, Object, StatementList,
+            TaggedTemplate, TemplateLit, UnaryOp,
+        },
+        op::{self, CompOp, LogOp, NumOp},
+        visitor::{walk_bin_op, Visitor},
+        Const,
+    },
+};
+use boa_interner::{Interner, Sym};
+use boa_unicode::UnicodeProperties;
+
+/// The formatting of generated source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Formatting {
+    minified: bool,
+    indent_width: usize,
+}
+
+impl Formatting {
+    /// One statement per line, indented by four spaces per level, with spaces around operators.
+    #[inline]
+    pub const fn readable() -> Self {
+        Self {
+            minified: false,
+            indent_width: 4,
+        }
+    }
+
+    /// No whitespace besides the spaces separating words.
+    #[inline]
+    pub const fn minified() -> Self {
+        Self {
+            minified: true,
+            indent_width: 0,
+        }
+    }
+
+    /// Sets the number of spaces of an indentation level of readable text.
+    #[inline]
+    pub const fn with_indent_width(mut self, indent_width: usize) -> Self {
+        self.indent_width = indent_width;
+        self
+    }
+
+    /// Returns `true` if the text is minified.
+    #[inline]
+    pub const fn is_minified(self) -> bool {
+        self.minified
+    }
+}
+
+impl Default for Formatting {
+    #[inline]
+    fn default() -> Self {
+        Self::readable()
+    }
+}
+
+/// Conversion of a syntax tree to source text.
+pub trait ToSource {
+    /// Generates the source text of the tree, resolving names with `interner`.
+    fn to_source(&self, interner: &Interner, formatting: Formatting) -> String;
+}
+
+impl ToSource for StatementList {
+    fn to_source(&self, interner: &Interner, formatting: Formatting) -> String {
+        let mut generator = Generator::new(interner, formatting);
+        generator.statements(self.items());
+        generator.buf
+    }
+}
+
+impl ToSource for Node {
+    /// Generates a statement, or an expression without the trailing semicolon.
+    fn to_source(&self, interner: &Interner, formatting: Formatting) -> String {
+        let mut generator = Generator::new(interner, formatting);
+        if is_statement(self) {
+            generator.statement(self);
+        } else {
+            generator.expr(self, Precedence::Comma);
+        }
+        generator.buf
+    }
+}
+
+/// The precedence of an expression, from the loosest to the tightest binding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Precedence {
+    Comma,
+    Assign,
+    Conditional,
+    Coalesce,
+    LogicalOr,
+    LogicalAnd,
+    BitOr,
+    BitXor,
+    BitAnd,
+    Equality,
+    Relational,
+    Shift,
+    Additive,
+    Multiplicative,
+    Exponent,
+    Unary,
+    Update,
+    Call,
+    Member,
+    Primary,
+}
+
+impl Precedence {
+    /// The precedence of the right operand of a left-associative binary operator.
+    fn next(self) -> Self {
+        match self {
+            Self::Comma => Self::Assign,
+            Self::Assign => Self::Conditional,
+            Self::Conditional => Self::Coalesce,
+            Self::Coalesce => Self::LogicalOr,
+            Self::LogicalOr => Self::LogicalAnd,
+            Self::LogicalAnd => Self::BitOr,
+            Self::BitOr => Self::BitXor,
+            Self::BitXor => Self::BitAnd,
+            Self::BitAnd => Self::Equality,
+            Self::Equality => Self::Relational,
+            Self::Relational => Self::Shift,
+            Self::Shift => Self::Additive,
+            Self::Additive => Self::Multiplicative,
+            Self::Multiplicative => Self::Exponent,
+            Self::Exponent => Self::Unary,
+            Self::Unary => Self::Update,
+            Self::Update => Self::Call,
+            Self::Call => Self::Member,
+            Self::Member | Self::Primary => Self::Primary,
+        }
+    }
+
+    /// The precedence of a binary operator.
+    fn of_bin_op(op: op::BinOp) -> Self {
+        match op {
+            op::BinOp::Num(NumOp::Add | NumOp::Sub) => Self::Additive,
+            op::BinOp::Num(NumOp::Mul | NumOp::Div | NumOp::Mod) => Self::Multiplicative,
+            op::BinOp::Num(NumOp::Exp) => Self::Exponent,
+            op::BinOp::Bit(op::BitOp::And) => Self::BitAnd,
+            op::BinOp::Bit(op::BitOp::Or) => Self::BitOr,
+            op::BinOp::Bit(op::BitOp::Xor) => Self::BitXor,
+            op::BinOp::Bit(op::BitOp::Shl | op::BitOp::Shr | op::BitOp::UShr) => Self::Shift,
+            op::BinOp::Comp(
+                CompOp::Equal | CompOp::NotEqual | CompOp::StrictEqual | CompOp::StrictNotEqual,
+            ) => Self::Equality,
+            op::BinOp::Comp(_) => Self::Relational,
+            op::BinOp::Log(LogOp::And) => Self::LogicalAnd,
+            op::BinOp::Log(LogOp::Or) => Self::LogicalOr,
+            op::BinOp::Log(LogOp::Coalesce) => Self::Coalesce,
+            op::BinOp::Assign(_) => Self::Assign,
+            op::BinOp::Comma => Self::Comma,
+        }
+    }
+
+    /// The precedence of an expression node.
+    fn of(node: &Node) -> Self {
+        match node {
+            Node::Const(Const::Int(int)) if *int < 0 => Self::Unary,
+            Node::Const(Const::Num(num)) if num.is_sign_negative() && !num.is_nan() => Self::Unary,
+            Node::BinOp(op) => Self::of_bin_op(op.op()),
+            Node::UnaryOp(op) => match op.op() {
+                op::UnaryOp::IncrementPost | op::UnaryOp::DecrementPost => Self::Update,
+                _ => Self::Unary,
+            },
+            Node::AwaitExpr(_) => Self::Unary,
+            Node::Assign(_) | Node::ArrowFunctionDecl(_) | Node::Yield(_) | Node::Spread(_) => {
+                Self::Assign
+            }
+            Node::ConditionalOp(_) => Self::Conditional,
+            Node::Call(_) => Self::Call,
+            Node::GetConstField(_) | Node::GetField(_) | Node::New(_) | Node::TaggedTemplate(_) => {
+                Self::Member
+            }
+            _ => Self::Primary,
+        }
+    }
+}
+
+/// Returns `true` if the node is a statement or a declaration, rather than an expression.
+pub(super) fn is_statement(node: &Node) -> bool {
+    matches!(
+        node,
+        Node::Block(_)
+            | Node::Break(_)
+            | Node::Continue(_)
+            | Node::ConstDeclList(_)
+            | Node::LetDeclList(_)
+            | Node::UsingDeclList(_)
+            | Node::VarDeclList(_)
+            | Node::DoWhileLoop(_)
+            | Node::ForLoop(_)
+            | Node::ForInLoop(_)
+            | Node::ForOfLoop(_)
+            | Node::WhileLoop(_)
+            | Node::If(_)
+            | Node::Return(_)
+            | Node::Switch(_)
+            | Node::Throw(_)
+            | Node::Try(_)
+            | Node::FunctionDecl(_)
+            | Node::GeneratorDecl(_)
+            | Node::AsyncFunctionDecl(_)
+            | Node::AsyncGeneratorDecl(_)
+            | Node::Empty
+            | Node::Error
+    )
+}
+
+/// Returns `true` if the expression contains a call outside of parentheses, which would become
+/// the callee of a `new` expression.
+fn contains_call(node: &Node) -> bool {
+    match node {
+        Node::Call(_) => true,
+        Node::GetConstField(field) => contains_call(field.obj()),
+        Node::GetField(field) => contains_call(field.obj()),
+        Node::TaggedTemplate(template) => contains_call(template.tag()),
+        _ => false,
+    }
+}
+
+/// Returns `true` if a statement ending with `node` would take the `else` branch of an enclosing
+/// `if` statement.
+fn ends_with_open_if(node: &Node) -> bool {
+    match node {
+        Node::If(if_node) => if_node.else_node().map_or(true, ends_with_open_if),
+        Node::WhileLoop(while_loop) => ends_with_open_if(while_loop.body()),
+        Node::ForLoop(for_loop) => ends_with_open_if(for_loop.body()),
+        Node::ForInLoop(for_in_loop) => ends_with_open_if(for_in_loop.body()),
+        Node::ForOfLoop(for_of_loop) => ends_with_open_if(for_of_loop.body()),
+        _ => false,
+    }
+}
+
+/// Returns `true` if the expression contains an `in` operator, which can't appear unparenthesized
+/// in the initializer of a `for` loop.
+fn contains_in(node: &Node) -> bool {
+    struct ContainsIn(bool);
+
+    impl<'ast> Visitor<'ast> for ContainsIn {
+        fn visit_bin_op(&mut self, op: &'ast BinOp) {
+            if op.op() == op::BinOp::Comp(CompOp::In) {
+                self.0 = true;
+            } else {
+                walk_bin_op(self, op);
+            }
+        }
+    }
+
+    let mut visitor = ContainsIn(false);
+    visitor.visit_node(node);
+    visitor.0
+}
+
+/// Returns `true` if `name` can be written as an identifier.
+pub(super) fn is_identifier_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map_or(false, |c| matches!(c, '$' | '_') || c.is_id_start())
+        && chars.all(|c| matches!(c, '$' | '_' | '\u{200C}' | '\u{200D}') || c.is_id_continue())
+}
+
+/// Returns `true` if two tokens ending and starting with these characters merge without a space.
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '$' | '_' | '\\') || !c.is_ascii()
+}
+
+/// Writes `text` as a string literal between double quotes.
+fn escape_string(text: &str, buf: &mut String) {
+    buf.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            '\u{8}' => buf.push_str("\\b"),
+            '\u{b}' => buf.push_str("\\v"),
+            '\u{c}' => buf.push_str("\\f"),
+            '\0'..='\u{1f}' | '\u{7f}' | '\u{2028}' | '\u{2029}' => {
+                buf.push_str(&format!("\\u{:04X}", u32::from(c)));
+            }
+            c => buf.push(c),
+        }
+    }
+    buf.push('"');
+}
+
+/// Writes the cooked text of a template literal, escaping what would end it or start a
+/// substitution.
+pub(super) fn escape_template(text: &str, buf: &mut String) {
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '`' => buf.push_str("\\`"),
+            '\\' => buf.push_str("\\\\"),
+            '$' if chars.peek() == Some(&'{') => buf.push_str("\\$"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            '\0'..='\u{1f}' | '\u{7f}' | '\u{2028}' | '\u{2029}' => {
+                buf.push_str(&format!("\\u{:04X}", u32::from(c)));
+            }
+            c => buf.push(c),
+        }
+    }
+}
+
+/// The state of the generation of the source text.
+struct Generator<'a> {
+    interner: &'a Interner,
+    formatting: Formatting,
+    buf: String,
+    indentation: usize,
+}
+
+impl<'a> Generator<'a> {
+    fn new(interner: &'a Interner, formatting: Formatting) -> Self {
+        Self {
+            interner,
+            formatting,
+            buf: String::new(),
+            indentation: 0,
+        }
+    }
+
+    /// Writes a token, separated from the previous one by a space if they would merge.
+    fn push(&mut self, text: &str) {
+        if let (Some(last), Some(first)) = (self.buf.chars().next_back(), text.chars().next()) {
+            if (is_word_char(last) && is_word_char(first))
+                || (matches!(last, '+' | '-') && last == first)
+            {
+                self.buf.push(' ');
+            }
+        }
+        self.buf.push_str(text);
+    }
+
+    /// Writes an optional space.
+    fn space(&mut self) {
+        if !self.formatting.minified {
+            self.buf.push(' ');
+        }
+    }
+
+    /// Writes an optional line break.
+    fn newline(&mut self) {
+        if !self.formatting.minified {
+            self.buf.push('\n');
+        }
+    }
+
+    /// Writes the indentation of the current line.
+    fn indent(&mut self) {
+        if !self.formatting.minified {
+            let width = self.indentation * self.formatting.indent_width;
+            self.buf.extend(std::iter::repeat(' ').take(width));
+        }
+    }
+
+    /// Writes a comma separating two items of a list.
+    fn comma(&mut self) {
+        self.push(",");
+        self.space();
+    }
+
+    fn name(&mut self, sym: Sym) {
+        let interner = self.interner;
+        self.push(interner.resolve_expect(sym));
+    }
+
+    fn string(&mut self, sym: Sym) {
+        let mut literal = String::new();
+        escape_string(self.interner.resolve_expect(sym), &mut literal);
+        self.push(&literal);
+    }
+
+    /// Writes the statements of a script or a block, one per line.
+    fn statements(&mut self, nodes: &[Node]) {
+        for node in nodes {
+            self.indent();
+            self.statement(node);
+            self.newline();
+        }
+    }
+
+    /// Writes a block of statements.
+    fn block(&mut self, nodes: &[Node]) {
+        if nodes.is_empty() {
+            self.push("{}");
+            return;
+        }
+        self.push("{");
+        self.newline();
+        self.indentation += 1;
+        self.statements(nodes);
+        self.indentation -= 1;
+        self.indent();
+        self.push("}");
+    }
+
+    fn statement(&mut self, node: &Node) {
+        match node {
+            Node::Block(block) => self.block(block.statement_list().items()),
+            Node::ConstDeclList(list)
+            | Node::LetDeclList(list)
+            | Node::UsingDeclList(list)
+            | Node::VarDeclList(list) => {
+                self.declaration_list(list, false);
+                self.push(";");
+            }
+            Node::If(if_node) => {
+                self.push("if");
+                self.space();
+                self.push("(");
+                self.expr(if_node.cond(), Precedence::Comma);
+                self.push(")");
+                self.space();
+                match if_node.else_node() {
+                    Some(else_node) => {
+                        if ends_with_open_if(if_node.body()) {
+                            self.block(std::slice::from_ref(if_node.body()));
+                        } else {
+                            self.statement(if_node.body());
+                        }
+                        self.space();
+                        self.push("else");
+                        self.space();
+                        self.statement(else_node);
+                    }
+                    None => self.statement(if_node.body()),
+                }
+            }
+            Node::WhileLoop(while_loop) => {
+                self.label(while_loop.label());
+                self.push("while");
+                self.space();
+                self.push("(");
+                self.expr(while_loop.cond(), Precedence::Comma);
+                self.push(")");
+                self.space();
+                self.statement(while_loop.body());
+            }
+            Node::DoWhileLoop(do_while) => {
+                self.label(do_while.label());
+                self.push("do");
+                self.space();
+                self.statement(do_while.body());
+                self.space();
+                self.push("while");
+                self.space();
+                self.push("(");
+                self.expr(do_while.cond(), Precedence::Comma);
+                self.push(");");
+            }
+            Node::ForLoop(for_loop) => {
+                self.label(for_loop.label());
+                self.push("for");
+                self.space();
+                self.push("(");
+                if let Some(init) = for_loop.init() {
+                    match init {
+                        Node::ConstDeclList(list)
+                        | Node::LetDeclList(list)
+                        | Node::UsingDeclList(list)
+                        | Node::VarDeclList(list) => self.declaration_list(list, true),
+                        init if contains_in(init) => {
+                            self.push("(");
+                            self.expr(init, Precedence::Comma);
+                            self.push(")");
+                        }
+                        init => self.expr(init, Precedence::Comma),
+                    }
+                }
+                self.push(";");
+                if let Some(condition) = for_loop.condition() {
+                    self.space();
+                    self.expr(condition, Precedence::Comma);
+                }
+                self.push(";");
+                if let Some(final_expr) = for_loop.final_expr() {
+                    self.space();
+                    self.expr(final_expr, Precedence::Comma);
+                }
+                self.push(")");
+                self.space();
+                self.statement(for_loop.body());
+            }
+            Node::ForInLoop(for_in_loop) => {
+                self.label(for_in_loop.label());
+                self.push("for");
+                self.space();
+                self.push("(");
+                self.iterable_loop_initializer(for_in_loop.init());
+                self.space();
+                self.push("in");
+                self.space();
+                self.expr(for_in_loop.expr(), Precedence::Comma);
+                self.push(")");
+                self.space();
+                self.statement(for_in_loop.body());
+            }
+            Node::ForOfLoop(for_of_loop) => {
+                self.label(for_of_loop.label());
+                self.push("for");
+                self.space();
+                self.push("(");
+                self.iterable_loop_initializer(for_of_loop.init());
+                self.space();
+                self.push("of");
+                self.space();
+                self.expr(for_of_loop.iterable(), Precedence::Assign);
+                self.push(")");
+                self.space();
+                self.statement(for_of_loop.body());
+            }
+            Node::Switch(switch) => {
+                self.push("switch");
+                self.space();
+                self.push("(");
+                self.expr(switch.val(), Precedence::Comma);
+                self.push(")");
+                self.space();
+                self.push("{");
+                self.newline();
+                self.indentation += 1;
+                for case in switch.cases() {
+                    self.indent();
+                    self.push("case");
+                    self.space();
+                    self.expr(case.condition(), Precedence::Comma);
+                    self.push(":");
+                    self.newline();
+                    self.indentation += 1;
+                    self.statements(case.body().items());
+                    self.indentation -= 1;
+                }
+                if let Some(default) = switch.default() {
+                    self.indent();
+                    self.push("default:");
+                    self.newline();
+                    self.indentation += 1;
+                    self.statements(default);
+                    self.indentation -= 1;
+                }
+                self.indentation -= 1;
+                self.indent();
+                self.push("}");
+            }
+            Node::Try(try_node) => {
+                self.push("try");
+                self.space();
+                self.block(try_node.block().statement_list().items());
+                if let Some(catch) = try_node.catch() {
+                    self.space();
+                    self.push("catch");
+                    self.space();
+                    if let Some(parameter) = catch.parameter() {
+                        self.push("(");
+                        self.declaration(parameter, false);
+                        self.push(")");
+                        self.space();
+                    }
+                    self.block(catch.block().statement_list().items());
+                }
+                if let Some(finally) = try_node.finally() {
+                    self.space();
+                    self.push("finally");
+                    self.space();
+                    self.block(finally.statement_list().items());
+                }
+            }
+            Node::Throw(throw) => {
+                self.push("throw");
+                self.space();
+                self.expr(throw.expr(), Precedence::Comma);
+                self.push(";");
+            }
+            Node::Return(ret) => {
+                self.push("return");
+                if let Some(expr) = ret.expr() {
+                    self.space();
+                    self.expr(expr, Precedence::Comma);
+                }
+                self.push(";");
+            }
+            Node::Break(node) => {
+                self.push("break");
+                if let Some(label) = node.label() {
+                    self.space();
+                    self.name(label);
+                }
+                self.push(";");
+            }
+            Node::Continue(node) => {
+                self.push("continue");
+                if let Some(label) = node.label() {
+                    self.space();
+                    self.name(label);
+                }
+                self.push(";");
+            }
+            Node::FunctionDecl(function) => self.function(
+                "function",
+                Some(function.name()),
+                function.parameters(),
+                function.body(),
+            ),
+            Node::GeneratorDecl(function) => self.function(
+                "function*",
+                Some(function.name()),
+                function.parameters(),
+                function.body(),
+            ),
+            Node::AsyncFunctionDecl(function) => self.function(
+                "async function",
+                Some(function.name()),
+                function.parameters(),
+                function.body(),
+            ),
+            Node::AsyncGeneratorDecl(function) => self.function(
+                "async function*",
+                Some(function.name()),
+                function.parameters(),
+                function.body(),
+            ),
+            // The source of statements that failed to parse is lost.
+            Node::Empty | Node::Error => self.push(";"),
+            expr => {
+                if self.starts_with_block_or_function(expr, false) {
+                    self.push("(");
+                    self.expr(expr, Precedence::Comma);
+                    self.push(")");
+                } else {
+                    self.expr(expr, Precedence::Comma);
+                }
+                self.push(";");
+            }
+        }
+    }
+
+    /// Returns `true` if the expression starts with `{` or `function`, which would be read as a
+    /// block or a declaration at the start of a statement, or with `let [`. In the body of an
+    /// arrow function, only `{` is ambiguous.
+    fn starts_with_block_or_function(&self, node: &Node, arrow_body: bool) -> bool {
+        match node {
+            Node::Object(_) => true,
+            Node::FunctionExpr(_)
+            | Node::GeneratorExpr(_)
+            | Node::AsyncFunctionExpr(_)
+            | Node::AsyncGeneratorExpr(_) => !arrow_body,
+            Node::Assign(assign) => match assign.lhs() {
+                AssignTarget::DeclarationPattern(DeclarationPattern::Object(_)) => true,
+                AssignTarget::GetConstField(field) => {
+                    self.starts_with_block_or_function(field.obj(), arrow_body)
+                }
+                AssignTarget::GetField(field) => self.starts_with_member_of_let(field, arrow_body),
+                AssignTarget::Identifier(_) | AssignTarget::DeclarationPattern(_) => false,
+            },
+            Node::BinOp(op) if Precedence::of(op.lhs()) >= Precedence::of(node) => {
+                self.starts_with_block_or_function(op.lhs(), arrow_body)
+            }
+            Node::ConditionalOp(op) => self.starts_with_block_or_function(op.cond(), arrow_body),
+            Node::Call(call) => self.starts_with_block_or_function(call.expr(), arrow_body),
+            Node::GetConstField(field) => {
+                self.starts_with_block_or_function(field.obj(), arrow_body)
+            }
+            Node::GetField(field) => self.starts_with_member_of_let(field, arrow_body),
+            Node::TaggedTemplate(template) => {
+                self.starts_with_block_or_function(template.tag(), arrow_body)
+            }
+            Node::UnaryOp(op)
+                if matches!(
+                    op.op(),
+                    op::UnaryOp::IncrementPost | op::UnaryOp::DecrementPost
+                ) =>
+            {
+                self.starts_with_block_or_function(op.target(), arrow_body)
+            }
+            _ => false,
+        }
+    }
+
+    fn starts_with_member_of_let(&self, field: &GetField, arrow_body: bool) -> bool {
+        match field.obj() {
+            Node::Identifier(ident) => {
+                !arrow_body && self.interner.resolve_expect(ident.sym()) == "let"
+            }
+            obj => self.starts_with_block_or_function(obj, arrow_body),
+        }
+    }
+
+    fn label(&mut self, label: Option<Sym>) {
+        if let Some(label) = label {
+            self.name(label);
+            self.push(":");
+            self.space();
+        }
+    }
+
+    /// Writes a declaration list. In the initializer of a `for` loop, `no_in` wraps the
+    /// initializers containing the `in` operator in parentheses.
+    fn declaration_list(&mut self, list: &DeclarationList, no_in: bool) {
+        self.push(match list {
+            DeclarationList::Const(_) => "const",
+            DeclarationList::Let(_) => "let",
+            DeclarationList::Var(_) => "var",
+            DeclarationList::Using(_) => "using",
+            DeclarationList::AwaitUsing(_) => "await using",
+        });
+        self.space();
+        for (i, declaration) in list.as_ref().iter().enumerate() {
+            if i > 0 {
+                self.comma();
+            }
+            self.declaration(declaration, no_in);
+        }
+    }
+
+    fn declaration(&mut self, declaration: &Declaration, no_in: bool) {
+        match declaration {
+            Declaration::Identifier { ident, init } => {
+                self.name(ident.sym());
+                if let Some(init) = init {
+                    self.initializer(init, no_in);
+                }
+            }
+            Declaration::Pattern(pattern) => self.pattern(pattern, no_in),
+        }
+    }
+
+    /// Writes ` = init`.
+    fn initializer(&mut self, init: &Node, no_in: bool) {
+        self.space();
+        self.push("=");
+        self.space();
+        if no_in && contains_in(init) {
+            self.push("(");
+            self.expr(init, Precedence::Comma);
+            self.push(")");
+        } else {
+            self.expr(init, Precedence::Assign);
+        }
+    }
+
+    fn pattern(&mut self, pattern: &DeclarationPattern, no_in: bool) {
+        match pattern {
+            DeclarationPattern::Object(object) => {
+                let bindings: Vec<_> = object
+                    .bindings()
+                    .iter()
+                    .filter(|binding| !matches!(binding, BindingPatternTypeObject::Empty))
+                    .collect();
+                if bindings.is_empty() {
+                    self.push("{}");
+                } else {
+                    self.push("{");
+                    self.space();
+                    for (i, binding) in bindings.into_iter().enumerate() {
+                        if i > 0 {
+                            self.comma();
+                        }
+                        self.object_binding(binding);
+                    }
+                    self.space();
+                    self.push("}");
+                }
+                if let Some(init) = object.init() {
+                    self.initializer(init, no_in);
+                }
+            }
+            DeclarationPattern::Array(array) => {
+                let bindings: Vec<_> = array
+                    .bindings()
+                    .iter()
+                    .filter(|binding| !matches!(binding, BindingPatternTypeArray::Empty))
+                    .collect();
+                self.push("[");
+                for (i, binding) in bindings.iter().enumerate() {
+                    if i > 0 {
+                        self.comma();
+                    }
+                    self.array_binding(binding);
+                }
+                if matches!(bindings.last(), Some(BindingPatternTypeArray::Elision)) {
+                    self.push(",");
+                }
+                self.push("]");
+                if let Some(init) = array.init() {
+                    self.initializer(init, no_in);
+                }
+            }
+        }
+    }
+
+    fn object_binding(&mut self, binding: &BindingPatternTypeObject) {
+        match binding {
+            BindingPatternTypeObject::Empty => {}
+            BindingPatternTypeObject::SingleName {
+                ident,
+                property_name,
+                default_init,
+            } => {
+                if ident != property_name {
+                    self.property_key(*property_name);
+                    self.push(":");
+                    self.space();
+                }
+                self.name(*ident);
+                if let Some(init) = default_init {
+                    self.initializer(init, false);
+                }
+            }
+            BindingPatternTypeObject::RestProperty { ident, .. } => {
+                self.push("...");
+                self.name(*ident);
+            }
+            BindingPatternTypeObject::RestGetConstField {
+                get_const_field, ..
+            } => {
+                self.push("...");
+                self.get_const_field(get_const_field);
+            }
+            BindingPatternTypeObject::BindingPattern {
+                ident,
+                pattern,
+                default_init,
+            } => {
+                self.property_key(*ident);
+                self.push(":");
+                self.space();
+                self.pattern(pattern, false);
+                if let Some(init) = default_init {
+                    self.initializer(init, false);
+                }
+            }
+        }
+    }
+
+    fn array_binding(&mut self, binding: &BindingPatternTypeArray) {
+        match binding {
+            BindingPatternTypeArray::Empty | BindingPatternTypeArray::Elision => {}
+            BindingPatternTypeArray::SingleName {
+                ident,
+                default_init,
+            } => {
+                self.name(*ident);
+                if let Some(init) = default_init {
+                    self.initializer(init, false);
+                }
+            }
+            BindingPatternTypeArray::GetField { get_field } => self.get_field(get_field),
+            BindingPatternTypeArray::GetConstField { get_const_field } => {
+                self.get_const_field(get_const_field);
+            }
+            BindingPatternTypeArray::BindingPattern { pattern } => self.pattern(pattern, false),
+            BindingPatternTypeArray::SingleNameRest { ident } => {
+                self.push("...");
+                self.name(*ident);
+            }
+            BindingPatternTypeArray::GetFieldRest { get_field } => {
+                self.push("...");
+                self.get_field(get_field);
+            }
+            BindingPatternTypeArray::GetConstFieldRest { get_const_field } => {
+                self.push("...");
+                self.get_const_field(get_const_field);
+            }
+            BindingPatternTypeArray::BindingPatternRest { pattern } => {
+                self.push("...");
+                self.pattern(pattern, false);
+            }
+        }
+    }
+
+    fn iterable_loop_initializer(&mut self, initializer: &IterableLoopInitializer) {
+        match initializer {
+            IterableLoopInitializer::Identifier(ident) => self.name(ident.sym()),
+            IterableLoopInitializer::Var(declaration) => {
+                self.push("var");
+                self.space();
+                self.declaration(declaration, false);
+            }
+            IterableLoopInitializer::Let(declaration) => {
+                self.push("let");
+                self.space();
+                self.declaration(declaration, false);
+            }
+            IterableLoopInitializer::Const(declaration) => {
+                self.push("const");
+                self.space();
+                self.declaration(declaration, false);
+            }
+            IterableLoopInitializer::DeclarationPattern(pattern) => self.pattern(pattern, false),
+        }
+    }
+
+    /// Writes a function, `keyword` being `function` and its `async` and generator variants.
+    fn function(
+        &mut self,
+        keyword: &str,
+        name: Option<Sym>,
+        parameters: &FormalParameterList,
+        body: &StatementList,
+    ) {
+        self.push(keyword);
+        if let Some(name) = name {
+            self.space();
+            self.name(name);
+        }
+        self.parameters(parameters);
+        self.space();
+        self.block(body.items());
+    }
+
+    fn parameters(&mut self, parameters: &FormalParameterList) {
+        self.push("(");
+        for (i, parameter) in parameters.parameters.iter().enumerate() {
+            if i > 0 {
+                self.comma();
+            }
+            if parameter.is_rest_param() {
+                self.push("...");
+            }
+            self.declaration(parameter.declaration(), false);
+        }
+        self.push(")");
+    }
+
+    /// Writes an expression, in parentheses if it binds looser than `min`.
+    fn expr(&mut self, node: &Node, min: Precedence) {
+        let parenthesized = Precedence::of(node) < min;
+        if parenthesized {
+            self.push("(");
+        }
+        match node {
+            Node::This => self.push("this"),
+            Node::Identifier(ident) => self.name(ident.sym()),
+            Node::Const(constant) => self.constant(constant),
+            Node::ArrayDecl(array) => self.array(array),
+            Node::Object(object) => self.object(object),
+            Node::TemplateLit(template) => self.template(template),
+            Node::TaggedTemplate(template) => self.tagged_template(template),
+            Node::FunctionExpr(function) => self.function(
+                "function",
+                function.name(),
+                function.parameters(),
+                function.body(),
+            ),
+            Node::GeneratorExpr(function) => self.function(
+                "function*",
+                function.name(),
+                function.parameters(),
+                function.body(),
+            ),
+            Node::AsyncFunctionExpr(function) => self.function(
+                "async function",
+                function.name(),
+                function.parameters(),
+                function.body(),
+            ),
+            Node::AsyncGeneratorExpr(function) => self.function(
+                "async function*",
+                function.name(),
+                function.parameters(),
+                function.body(),
+            ),
+            Node::ArrowFunctionDecl(function) => {
+                self.parameters(function.params());
+                self.space();
+                self.push("=>");
+                self.space();
+                match function.body().items() {
+                    [Node::Return(ret)] if ret.expr().is_some() && ret.label().is_none() => {
+                        let expr = ret.expr().expect("checked above");
+                        if self.starts_with_block_or_function(expr, true) {
+                            self.push("(");
+                            self.expr(expr, Precedence::Comma);
+                            self.push(")");
+                        } else {
+                            self.expr(expr, Precedence::Assign);
+                        }
+                    }
+                    items => self.block(items),
+                }
+            }
+            Node::GetConstField(field) => self.get_const_field(field),
+            Node::GetField(field) => self.get_field(field),
+            Node::Call(call) => {
+                self.expr(call.expr(), Precedence::Call);
+                self.arguments(call.args());
+            }
+            Node::New(new) => {
+                self.push("new");
+                self.space();
+                if contains_call(new.expr()) {
+                    self.push("(");
+                    self.expr(new.expr(), Precedence::Comma);
+                    self.push(")");
+                } else {
+                    self.expr(new.expr(), Precedence::Member);
+                }
+                self.arguments(new.args());
+            }
+            Node::Spread(spread) => {
+                self.push("...");
+                self.expr(spread.val(), Precedence::Assign);
+            }
+            Node::UnaryOp(op) => self.unary_op(op),
+            Node::AwaitExpr(expr) => {
+                self.push("await");
+                self.space();
+                self.expr(expr.expr(), Precedence::Unary);
+            }
+            Node::BinOp(op) => self.bin_op(op),
+            Node::Assign(assign) => {
+                match assign.lhs() {
+                    AssignTarget::Identifier(ident) => self.name(ident.sym()),
+                    AssignTarget::GetConstField(field) => self.get_const_field(field),
+                    AssignTarget::GetField(field) => self.get_field(field),
+                    AssignTarget::DeclarationPattern(pattern) => self.pattern(pattern, false),
+                }
+                self.initializer(assign.rhs(), false);
+            }
+            Node::ConditionalOp(op) => {
+                self.expr(op.cond(), Precedence::Coalesce);
+                self.space();
+                self.push("?");
+                self.space();
+                self.expr(op.if_true(), Precedence::Assign);
+                self.space();
+                self.push(":");
+                self.space();
+                self.expr(op.if_false(), Precedence::Assign);
+            }
+            Node::Yield(node) => {
+                self.push(if node.delegate() { "yield*" } else { "yield" });
+                if let Some(expr) = node.expr() {
+                    self.space();
+                    self.expr(expr, Precedence::Assign);
+                }
+            }
+            statement => self.statement(statement),
+        }
+        if parenthesized {
+            self.push(")");
+        }
+    }
+
+    fn constant(&mut self, constant: &Const) {
+        match constant {
+            Const::String(string) => self.string(*string),
+            Const::Num(num) if *num == 0.0 && num.is_sign_negative() => self.push("-0"),
+            Const::Num(num) => self.push(&Number::to_native_string(*num)),
+            Const::Int(int) => self.push(&int.to_string()),
+            Const::BigInt(bigint) => self.push(&format!("{bigint}n")),
+            Const::Bool(true) => self.push("true"),
+            Const::Bool(false) => self.push("false"),
+            Const::Null => self.push("null"),
+            Const::Undefined => self.push("undefined"),
+        }
+    }
+
+    /// Writes the object of a property access, in parentheses if it is a number, whose decimal
+    /// point would take the dot of the property access.
+    fn member_object(&mut self, obj: &Node) {
+        if matches!(obj, Node::Const(Const::Int(_) | Const::Num(_))) {
+            self.push("(");
+            self.expr(obj, Precedence::Comma);
+            self.push(")");
+        } else {
+            self.expr(obj, Precedence::Call);
+        }
+    }
+
+    fn get_const_field(&mut self, field: &GetConstField) {
+        self.member_object(field.obj());
+        let interner = self.interner;
+        let name = interner.resolve_expect(field.field());
+        if is_identifier_name(name) {
+            self.push(".");
+            self.push(name);
+        } else {
+            self.push("[");
+            self.string(field.field());
+            self.push("]");
+        }
+    }
+
+    fn get_field(&mut self, field: &GetField) {
+        self.member_object(field.obj());
+        self.push("[");
+        self.expr(field.field(), Precedence::Comma);
+        self.push("]");
+    }
+
+    fn arguments(&mut self, args: &[Node]) {
+        self.push("(");
+        for (i, arg) in args.iter().enumerate() {
+            if i > 0 {
+                self.comma();
+            }
+            self.expr(arg, Precedence::Assign);
+        }
+        self.push(")");
+    }
+
+    fn array(&mut self, array: &ArrayDecl) {
+        self.push("[");
+        let elements = array.as_ref();
+        for (i, element) in elements.iter().enumerate() {
+            if i > 0 {
+                self.comma();
+            }
+            if !matches!(element, Node::Empty) {
+                self.expr(element, Precedence::Assign);
+            }
+        }
+        if matches!(elements.last(), Some(Node::Empty)) || array.has_trailing_comma_spread() {
+            self.push(",");
+        }
+        self.push("]");
+    }
+
+    fn object(&mut self, object: &Object) {
+        if object.properties().is_empty() {
+            self.push("{}");
+            return;
+        }
+        self.push("{");
+        self.space();
+        for (i, property) in object.properties().iter().enumerate() {
+            if i > 0 {
+                self.comma();
+            }
+            match property {
+                PropertyDefinition::IdentifierReference(ident) => self.name(*ident),
+                PropertyDefinition::Property(name, value) => {
+                    self.property_name(name);
+                    self.push(":");
+                    self.space();
+                    self.expr(value, Precedence::Assign);
+                }
+                PropertyDefinition::MethodDefinition(method, name) => self.method(method, name),
+                PropertyDefinition::SpreadObject(node) => {
+                    self.push("...");
+                    self.expr(node, Precedence::Assign);
+                }
+            }
+        }
+        self.space();
+        self.push("}");
+    }
+
+    fn method(&mut self, method: &MethodDefinition, name: &PropertyName) {
+        let (prefix, parameters, body) = match method {
+            MethodDefinition::Get(function) => ("get ", function.parameters(), function.body()),
+            MethodDefinition::Set(function) => ("set ", function.parameters(), function.body()),
+            MethodDefinition::Ordinary(function) => ("", function.parameters(), function.body()),
+            MethodDefinition::Generator(function) => ("*", function.parameters(), function.body()),
+            MethodDefinition::Async(function) => ("async ", function.parameters(), function.body()),
+            MethodDefinition::AsyncGenerator(function) => {
+                ("async *", function.parameters(), function.body())
+            }
+        };
+        if let Some(word) = prefix.strip_suffix(' ') {
+            self.push(word);
+            self.space();
+        } else if let Some(word) = prefix.strip_suffix(" *") {
+            self.push(word);
+            self.space();
+            self.push("*");
+        } else {
+            self.push(prefix);
+        }
+        self.property_name(name);
+        self.parameters(parameters);
+        self.space();
+        self.block(body.items());
+    }
+
+    fn property_name(&mut self, name: &PropertyName) {
+        match name {
+            PropertyName::Literal(name) => self.property_key(*name),
+            PropertyName::Computed(node) => {
+                self.push("[");
+                self.expr(node, Precedence::Assign);
+                self.push("]");
+            }
+        }
+    }
+
+    /// Writes a property name as an identifier, or as a string if it isn't a valid one.
+    fn property_key(&mut self, name: Sym) {
+        let interner = self.interner;
+        let text = interner.resolve_expect(name);
+        if is_identifier_name(text) {
+            self.push(text);
+        } else {
+            self.string(name);
+        }
+    }
+
+    fn template(&mut self, template: &TemplateLit) {
+        let mut text = String::from("`");
+        for element in template.elements() {
+            match element {
+                TemplateElement::String(string) => {
+                    escape_template(self.interner.resolve_expect(*string), &mut text);
+                }
+                TemplateElement::Expr(node) => {
+                    text.push_str("${");
+                    self.push(&text);
+                    self.expr(node, Precedence::Comma);
+                    text = String::from("}");
+                }
+            }
+        }
+        text.push('`');
+        self.push(&text);
+    }
+
+    /// Writes a tagged template with its raw strings, which are the source text of the template.
+    fn tagged_template(&mut self, template: &TaggedTemplate) {
+        self.member_object(template.tag());
+        let mut text = String::from("`");
+        for (i, raw) in template.raws().iter().enumerate() {
+            text.push_str(self.interner.resolve_expect(*raw));
+            if let Some(expr) = template.exprs().get(i) {
+                text.push_str("${");
+                self.push(&text);
+                self.expr(expr, Precedence::Comma);
+                text = String::from("}");
+            }
+        }
+        text.push('`');
+        self.push(&text);
+    }
+
+    fn unary_op(&mut self, op: &UnaryOp) {
+        match op.op() {
+            op::UnaryOp::IncrementPost | op::UnaryOp::DecrementPost => {
+                self.expr(op.target(), Precedence::Call);
+                self.push(&op.op().to_string());
+            }
+            op::UnaryOp::TypeOf | op::UnaryOp::Delete | op::UnaryOp::Void => {
+                self.push(&op.op().to_string());
+                self.space();
+                self.expr(op.target(), Precedence::Unary);
+            }
+            _ => {
+                self.push(&op.op().to_string());
+                self.expr(op.target(), Precedence::Unary);
+            }
+        }
+    }
+
+    fn bin_op(&mut self, node: &BinOp) {
+        let op = node.op();
+        let precedence = Precedence::of_bin_op(op);
+        let (left, right) = match op {
+            // `**` is right-associative, and its left operand can't be a unary expression.
+            op::BinOp::Num(NumOp::Exp) => (Precedence::Update, Precedence::Exponent),
+            op::BinOp::Assign(_) => (Precedence::Call, Precedence::Assign),
+            // `??` can't be mixed with `||` and `&&` without parentheses.
+            op::BinOp::Log(LogOp::Coalesce) => {
+                let left = if Precedence::of(node.lhs()) == Precedence::Coalesce {
+                    Precedence::Coalesce
+                } else {
+                    Precedence::BitOr
+                };
+                (left, Precedence::BitOr)
+            }
+            _ => (precedence, precedence.next()),
+        };
+        self.expr(node.lhs(), left);
+        if op == op::BinOp::Comma {
+            self.comma();
+        } else {
+            self.space();
+            self.push(&op.to_string());
+            self.space();
+        }
+        self.expr(node.rhs(), right);
+    }
+}
@@ -0,0 +1,149 @@
+use super::{Formatting, ToSource};
+use crate::syntax::{
+    ast::{
+        node::{BinOp, Identifier, Node, StatementList, UnaryOp},
+        op, Const,
+    },
+    Parser,
+};
+use boa_interner::Interner;
+
+fn parse(source: &str, interner: &mut Interner) -> StatementList {
+    Parser::new(source.as_bytes(), false)
+        .parse_all(interner)
+        .expect("parsing failed")
+}
+
+/// Checks that the source generated from `source` parses to the same tree in both formattings.
+fn check_round_trip(source: &str) {
+    let mut interner = Interner::default();
+    let script = parse(source, &mut interner);
+    for formatting in [Formatting::readable(), Formatting::minified()] {
+        let generated = script.to_source(&interner, formatting);
+        let reparsed = parse(&generated, &mut interner);
+        assert_eq!(script, reparsed, "generated source:\n{generated}");
+    }
+}
+
+/// Checks the source generated from `source` in both formattings.
+fn check_output(source: &str, readable: &str, minified: &str) {
+    let mut interner = Interner::default();
+    let script = parse(source, &mut interner);
+    assert_eq!(
+        script.to_source(&interner, Formatting::readable()),
+        readable
+    );
+    assert_eq!(
+        script.to_source(&interner, Formatting::minified()),
+        minified
+    );
+}
+
+#[test]
+fn statements() {
+    check_output(
+        "if (a) { b(); } else if (c) d(); else { }",
+        "if (a) {\n    b();\n} else if (c) d(); else {}\n",
+        "if(a){b();}else if(c)d();else{}",
+    );
+    check_output(
+        "function f(a, ...b) { return typeof a; }",
+        "function f(a, ...b) {\n    return typeof a;\n}\n",
+        "function f(a,...b){return typeof a;}",
+    );
+    check_output(
+        "outer: for (let i = 0; i < 3; i++) { continue outer; }",
+        "outer: for (let i = 0; i < 3; i++) {\n    continue outer;\n}\n",
+        "outer:for(let i=0;i<3;i++){continue outer;}",
+    );
+    check_output(
+        "let o = { a: 1, b, 'c-d': [, 1, , ] };",
+        "let o = { a: 1, b, \"c-d\": [, 1, ,] };\n",
+        "let o={a:1,b,\"c-d\":[,1,,]};",
+    );
+}
+
+#[test]
+fn indent_width() {
+    let mut interner = Interner::default();
+    let script = parse("while (a) { switch (b) { case 1: c(); } }", &mut interner);
+    assert_eq!(
+        script.to_source(&interner, Formatting::readable().with_indent_width(2)),
+        "while (a) {\n  switch (b) {\n    case 1:\n      c();\n  }\n}\n"
+    );
+}
+
+#[test]
+fn precedence() {
+    let mut interner = Interner::default();
+    let [a, b, c] = ["a", "b", "c"]
+        .map(|name| Node::from(Identifier::new(interner.get_or_intern_static(name))));
+
+    let sum = BinOp::new(op::NumOp::Add, a.clone(), b.clone());
+    let product = Node::from(BinOp::new(op::NumOp::Mul, sum, c.clone()));
+    assert_eq!(
+        product.to_source(&interner, Formatting::readable()),
+        "(a + b) * c"
+    );
+
+    let difference = BinOp::new(op::NumOp::Sub, a.clone(), BinOp::new(op::NumOp::Sub, b, c));
+    assert_eq!(
+        Node::from(difference).to_source(&interner, Formatting::minified()),
+        "a-(b-c)"
+    );
+
+    let negation = UnaryOp::new(op::UnaryOp::Minus, a);
+    let power = Node::from(BinOp::new(op::NumOp::Exp, negation, Const::from(2)));
+    assert_eq!(
+        power.to_source(&interner, Formatting::minified()),
+        "(-a)**2"
+    );
+
+    let negative = UnaryOp::new(op::UnaryOp::Minus, Const::from(-1));
+    assert_eq!(
+        Node::from(negative).to_source(&interner, Formatting::minified()),
+        "- -1"
+    );
+}
+
+#[test]
+fn string_escapes() {
+    check_output(
+        r#"f('it\'s', "\"\\\n\t\u2028\0", `a${b}\`\${c}`);"#,
+        "f(\"it's\", \"\\\"\\\\\\n\\t\\u2028\\u0000\", `a${b}\\`\\${c}`);\n",
+        "f(\"it's\",\"\\\"\\\\\\n\\t\\u2028\\u0000\",`a${b}\\`\\${c}`);",
+    );
+}
+
+#[test]
+fn round_trip() {
+    check_round_trip(
+        r#"
+        "use strict";
+        var x = 1, y;
+        const { a, b: [c, , ...d], ...e } = f;
+        let g = (h, i = 2) => ({ h, i });
+        ({ a } = b);
+        (function () {})();
+        new (f())();
+        new f.g();
+        (1).toString();
+        a = b ? c : d ? e : f;
+        a ?? (b || c);
+        (a, b) + c;
+        -(-a) + +(+b) - (a - -b);
+        2 ** -a;
+        for (var k in o) if (k) if (o[k]) g(k); else h();
+        for (let [v, w] of pairs) label: while (v) break label;
+        for (var z = ("x" in o); z; ) z = !z;
+        do x++; while (--y > 0);
+        try { throw new Error("e"); } catch ({ message }) { log(message); } finally { done(); }
+        switch (a) { case 1: case 2: b(); break; default: c(); }
+        async function* gen() { yield* await a; yield; }
+        var obj = { get x() { return 1; }, set x(v) {}, *g() {}, async m() {}, [k]: 1, "a b": 2 };
+        f`a${b}c\n`;
+        `x${y}\${z}é`;
+        a.b["c"].d;
+        "#,
+    );
+}
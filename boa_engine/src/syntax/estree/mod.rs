@@ -0,0 +1,855 @@
+//! Conversion of the syntax tree to [ESTree] JSON.
+//!
+//! The [`ToEstree`] trait converts a [`StatementList`] to an ESTree `Program`, or a [`Node`] to
+//! the ESTree statement or expression it represents, so that tools consuming the ESTree format
+//! can read the output of the parser.
+//!
+//! Some of the source isn't part of the tree, and can't be restored: regular expression literals
+//! are `new RegExp(..)` expressions, negative number literals are folded, the raw text of
+//! literals is lost and the directives of a script aren't marked. The statements of statement
+//! lists made by the parser have a `loc` with their source location.
+//!
+//! [ESTree]: https://github.com/estree/estree
+
+#[cfg(test)]
+mod tests;
+
+use crate::syntax::{
+    ast::{
+        node::{
+            declaration::{BindingPatternTypeArray, BindingPatternTypeObject},
+            iteration::IterableLoopInitializer,
+            object::{MethodDefinition, PropertyDefinition, PropertyName},
+            operator::assign::AssignTarget,
+            template::TemplateElement,
+            ArrayDecl, BinOp, Declaration, DeclarationList, DeclarationPattern,
+            FormalParameterList, GetConstField, GetField, Node, Object, StatementList,
+            TaggedTemplate, TemplateLit, UnaryOp,
+        },
+        op, Const, Position, Span,
+    },
+    codegen::{escape_template, is_identifier_name, is_statement},
+};
+use boa_interner::{Interner, Sym};
+use serde_json::{json, Value};
+
+/// Conversion of a syntax tree to ESTree JSON.
+pub trait ToEstree {
+    /// Converts the tree to ESTree JSON, resolving names with `interner`.
+    fn to_estree(&self, interner: &Interner) -> Value;
+}
+
+impl ToEstree for StatementList {
+    /// Converts a script to a `Program`.
+    fn to_estree(&self, interner: &Interner) -> Value {
+        let converter = Converter { interner };
+        json!({
+            "type": "Program",
+            "sourceType": "script",
+            "body": converter.statement_list(self),
+        })
+    }
+}
+
+impl ToEstree for Node {
+    /// Converts a statement, or an expression not wrapped in an `ExpressionStatement`.
+    fn to_estree(&self, interner: &Interner) -> Value {
+        let converter = Converter { interner };
+        if is_statement(self) {
+            converter.statement(self)
+        } else {
+            converter.expr(self)
+        }
+    }
+}
+
+/// A source position, with the zero-based column of ESTree.
+fn position(position: Position) -> Value {
+    json!({
+        "line": position.line_number(),
+        "column": position.column_number() - 1,
+    })
+}
+
+/// A source location.
+fn location(span: Span) -> Value {
+    json!({
+        "start": position(span.start()),
+        "end": position(span.end()),
+    })
+}
+
+fn literal(value: Value) -> Value {
+    json!({ "type": "Literal", "value": value })
+}
+
+/// Negates an expression, for the negative numbers folded by the parser.
+fn negate(argument: Value) -> Value {
+    json!({
+        "type": "UnaryExpression",
+        "operator": "-",
+        "prefix": true,
+        "argument": argument,
+    })
+}
+
+/// Wraps an initialized binding target in an `AssignmentPattern`.
+fn with_default(target: Value, init: Option<Value>) -> Value {
+    match init {
+        Some(init) => json!({ "type": "AssignmentPattern", "left": target, "right": init }),
+        None => target,
+    }
+}
+
+fn rest(argument: Value) -> Value {
+    json!({ "type": "RestElement", "argument": argument })
+}
+
+/// The conversion of a tree, resolving names with the interner.
+struct Converter<'a> {
+    interner: &'a Interner,
+}
+
+impl Converter<'_> {
+    fn identifier(&self, sym: Sym) -> Value {
+        json!({ "type": "Identifier", "name": self.interner.resolve_expect(sym) })
+    }
+
+    fn string(&self, sym: Sym) -> Value {
+        literal(self.interner.resolve_expect(sym).into())
+    }
+
+    /// A property name, as an identifier if it is a valid one.
+    fn property_key(&self, sym: Sym) -> Value {
+        if is_identifier_name(self.interner.resolve_expect(sym)) {
+            self.identifier(sym)
+        } else {
+            self.string(sym)
+        }
+    }
+
+    /// The statements of a statement list, with their location if it is known.
+    fn statement_list(&self, list: &StatementList) -> Vec<Value> {
+        list.items()
+            .iter()
+            .enumerate()
+            .map(|(index, node)| {
+                let mut statement = self.statement(node);
+                if let (Some(span), Value::Object(object)) = (list.span(index), &mut statement) {
+                    object.insert("loc".to_owned(), location(span));
+                }
+                statement
+            })
+            .collect()
+    }
+
+    fn statements(&self, nodes: &[Node]) -> Vec<Value> {
+        nodes.iter().map(|node| self.statement(node)).collect()
+    }
+
+    fn block(&self, list: &StatementList) -> Value {
+        json!({ "type": "BlockStatement", "body": self.statement_list(list) })
+    }
+
+    /// Wraps a loop in a `LabeledStatement` if it has a label.
+    fn labeled(&self, label: Option<Sym>, body: Value) -> Value {
+        match label {
+            Some(label) => json!({
+                "type": "LabeledStatement",
+                "label": self.identifier(label),
+                "body": body,
+            }),
+            None => body,
+        }
+    }
+
+    fn statement(&self, node: &Node) -> Value {
+        match node {
+            Node::Block(block) => self.block(block.statement_list()),
+            Node::ConstDeclList(list)
+            | Node::LetDeclList(list)
+            | Node::UsingDeclList(list)
+            | Node::VarDeclList(list) => self.declaration_list(list),
+            Node::If(if_node) => json!({
+                "type": "IfStatement",
+                "test": self.expr(if_node.cond()),
+                "consequent": self.statement(if_node.body()),
+                "alternate": if_node.else_node().map(|node| self.statement(node)),
+            }),
+            Node::WhileLoop(while_loop) => self.labeled(
+                while_loop.label(),
+                json!({
+                    "type": "WhileStatement",
+                    "test": self.expr(while_loop.cond()),
+                    "body": self.statement(while_loop.body()),
+                }),
+            ),
+            Node::DoWhileLoop(do_while) => self.labeled(
+                do_while.label(),
+                json!({
+                    "type": "DoWhileStatement",
+                    "body": self.statement(do_while.body()),
+                    "test": self.expr(do_while.cond()),
+                }),
+            ),
+            Node::ForLoop(for_loop) => self.labeled(
+                for_loop.label(),
+                json!({
+                    "type": "ForStatement",
+                    "init": for_loop.init().map(|init| match init {
+                        Node::ConstDeclList(list)
+                        | Node::LetDeclList(list)
+                        | Node::UsingDeclList(list)
+                        | Node::VarDeclList(list) => self.declaration_list(list),
+                        init => self.expr(init),
+                    }),
+                    "test": for_loop.condition().map(|condition| self.expr(condition)),
+                    "update": for_loop.final_expr().map(|final_expr| self.expr(final_expr)),
+                    "body": self.statement(for_loop.body()),
+                }),
+            ),
+            Node::ForInLoop(for_in_loop) => self.labeled(
+                for_in_loop.label(),
+                json!({
+                    "type": "ForInStatement",
+                    "left": self.iterable_loop_initializer(for_in_loop.init()),
+                    "right": self.expr(for_in_loop.expr()),
+                    "body": self.statement(for_in_loop.body()),
+                }),
+            ),
+            Node::ForOfLoop(for_of_loop) => self.labeled(
+                for_of_loop.label(),
+                json!({
+                    "type": "ForOfStatement",
+                    "await": false,
+                    "left": self.iterable_loop_initializer(for_of_loop.init()),
+                    "right": self.expr(for_of_loop.iterable()),
+                    "body": self.statement(for_of_loop.body()),
+                }),
+            ),
+            Node::Switch(switch) => {
+                let mut cases: Vec<_> = switch
+                    .cases()
+                    .iter()
+                    .map(|case| {
+                        json!({
+                            "type": "SwitchCase",
+                            "test": self.expr(case.condition()),
+                            "consequent": self.statement_list(case.body()),
+                        })
+                    })
+                    .collect();
+                if let Some(default) = switch.default() {
+                    cases.push(json!({
+                        "type": "SwitchCase",
+                        "test": null,
+                        "consequent": self.statements(default),
+                    }));
+                }
+                json!({
+                    "type": "SwitchStatement",
+                    "discriminant": self.expr(switch.val()),
+                    "cases": cases,
+                })
+            }
+            Node::Try(try_node) => json!({
+                "type": "TryStatement",
+                "block": self.block(try_node.block().statement_list()),
+                "handler": try_node.catch().map(|catch| json!({
+                    "type": "CatchClause",
+                    "param": catch.parameter().map(|parameter| self.binding(parameter)),
+                    "body": self.block(catch.block().statement_list()),
+                })),
+                "finalizer": try_node
+                    .finally()
+                    .map(|finally| self.block(finally.statement_list())),
+            }),
+            Node::Throw(throw) => json!({
+                "type": "ThrowStatement",
+                "argument": self.expr(throw.expr()),
+            }),
+            Node::Return(ret) => json!({
+                "type": "ReturnStatement",
+                "argument": ret.expr().map(|expr| self.expr(expr)),
+            }),
+            Node::Break(node) => json!({
+                "type": "BreakStatement",
+                "label": node.label().map(|label| self.identifier(label)),
+            }),
+            Node::Continue(node) => json!({
+                "type": "ContinueStatement",
+                "label": node.label().map(|label| self.identifier(label)),
+            }),
+            Node::FunctionDecl(function) => self.function(
+                "FunctionDeclaration",
+                Some(function.name()),
+                function.parameters(),
+                function.body(),
+                (false, false),
+            ),
+            Node::GeneratorDecl(function) => self.function(
+                "FunctionDeclaration",
+                Some(function.name()),
+                function.parameters(),
+                function.body(),
+                (true, false),
+            ),
+            Node::AsyncFunctionDecl(function) => self.function(
+                "FunctionDeclaration",
+                Some(function.name()),
+                function.parameters(),
+                function.body(),
+                (false, true),
+            ),
+            Node::AsyncGeneratorDecl(function) => self.function(
+                "FunctionDeclaration",
+                Some(function.name()),
+                function.parameters(),
+                function.body(),
+                (true, true),
+            ),
+            // ESTree has no node for statements that failed to parse.
+            Node::Empty | Node::Error => json!({ "type": "EmptyStatement" }),
+            expr => json!({ "type": "ExpressionStatement", "expression": self.expr(expr) }),
+        }
+    }
+
+    fn declaration_list(&self, list: &DeclarationList) -> Value {
+        let kind = match list {
+            DeclarationList::Const(_) => "const",
+            DeclarationList::Let(_) => "let",
+            DeclarationList::Var(_) => "var",
+            DeclarationList::Using(_) => "using",
+            DeclarationList::AwaitUsing(_) => "await using",
+        };
+        let declarations: Vec<_> = list
+            .as_ref()
+            .iter()
+            .map(|declaration| self.declarator(declaration))
+            .collect();
+        json!({
+            "type": "VariableDeclaration",
+            "declarations": declarations,
+            "kind": kind,
+        })
+    }
+
+    fn declarator(&self, declaration: &Declaration) -> Value {
+        json!({
+            "type": "VariableDeclarator",
+            "id": self.binding_target(declaration),
+            "init": declaration.init().map(|init| self.expr(init)),
+        })
+    }
+
+    /// The target of a declaration, without its initializer.
+    fn binding_target(&self, declaration: &Declaration) -> Value {
+        match declaration {
+            Declaration::Identifier { ident, .. } => self.identifier(ident.sym()),
+            Declaration::Pattern(pattern) => self.pattern(pattern),
+        }
+    }
+
+    /// A parameter or a catch parameter, with its initializer as a default value.
+    fn binding(&self, declaration: &Declaration) -> Value {
+        with_default(
+            self.binding_target(declaration),
+            declaration.init().map(|init| self.expr(init)),
+        )
+    }
+
+    /// A binding pattern, without its initializer.
+    fn pattern(&self, pattern: &DeclarationPattern) -> Value {
+        match pattern {
+            DeclarationPattern::Object(object) => {
+                let properties: Vec<_> = object
+                    .bindings()
+                    .iter()
+                    .filter_map(|binding| self.object_binding(binding))
+                    .collect();
+                json!({ "type": "ObjectPattern", "properties": properties })
+            }
+            DeclarationPattern::Array(array) => {
+                let elements: Vec<_> = array
+                    .bindings()
+                    .iter()
+                    .filter(|binding| !matches!(binding, BindingPatternTypeArray::Empty))
+                    .map(|binding| self.array_binding(binding))
+                    .collect();
+                json!({ "type": "ArrayPattern", "elements": elements })
+            }
+        }
+    }
+
+    fn object_binding(&self, binding: &BindingPatternTypeObject) -> Option<Value> {
+        let property = |key: Sym, value: Value, shorthand: bool| {
+            json!({
+                "type": "Property",
+                "key": self.property_key(key),
+                "value": value,
+                "kind": "init",
+                "method": false,
+                "shorthand": shorthand,
+                "computed": false,
+            })
+        };
+        let default = |init: Option<&Node>| init.map(|init| self.expr(init));
+
+        let value = match binding {
+            BindingPatternTypeObject::Empty => return None,
+            BindingPatternTypeObject::SingleName {
+                ident,
+                property_name,
+                default_init,
+            } => property(
+                *property_name,
+                with_default(self.identifier(*ident), default(default_init.as_ref())),
+                ident == property_name,
+            ),
+            BindingPatternTypeObject::RestProperty { ident, .. } => rest(self.identifier(*ident)),
+            BindingPatternTypeObject::RestGetConstField {
+                get_const_field, ..
+            } => rest(self.get_const_field(get_const_field)),
+            BindingPatternTypeObject::BindingPattern {
+                ident,
+                pattern,
+                default_init,
+            } => property(
+                *ident,
+                with_default(self.pattern(pattern), default(default_init.as_ref())),
+                false,
+            ),
+        };
+        Some(value)
+    }
+
+    /// An element of an array pattern, `null` for an elision.
+    fn array_binding(&self, binding: &BindingPatternTypeArray) -> Value {
+        match binding {
+            BindingPatternTypeArray::Empty | BindingPatternTypeArray::Elision => Value::Null,
+            BindingPatternTypeArray::SingleName {
+                ident,
+                default_init,
+            } => with_default(
+                self.identifier(*ident),
+                default_init.as_ref().map(|init| self.expr(init)),
+            ),
+            BindingPatternTypeArray::GetField { get_field } => self.get_field(get_field),
+            BindingPatternTypeArray::GetConstField { get_const_field } => {
+                self.get_const_field(get_const_field)
+            }
+            BindingPatternTypeArray::BindingPattern { pattern } => self.pattern(pattern),
+            BindingPatternTypeArray::SingleNameRest { ident } => rest(self.identifier(*ident)),
+            BindingPatternTypeArray::GetFieldRest { get_field } => rest(self.get_field(get_field)),
+            BindingPatternTypeArray::GetConstFieldRest { get_const_field } => {
+                rest(self.get_const_field(get_const_field))
+            }
+            BindingPatternTypeArray::BindingPatternRest { pattern } => rest(self.pattern(pattern)),
+        }
+    }
+
+    fn iterable_loop_initializer(&self, initializer: &IterableLoopInitializer) -> Value {
+        let declaration = |kind: &str, declaration: &Declaration| {
+            json!({
+                "type": "VariableDeclaration",
+                "declarations": [self.declarator(declaration)],
+                "kind": kind,
+            })
+        };
+        match initializer {
+            IterableLoopInitializer::Identifier(ident) => self.identifier(ident.sym()),
+            IterableLoopInitializer::Var(var) => declaration("var", var),
+            IterableLoopInitializer::Let(var) => declaration("let", var),
+            IterableLoopInitializer::Const(var) => declaration("const", var),
+            IterableLoopInitializer::DeclarationPattern(pattern) => self.pattern(pattern),
+        }
+    }
+
+    /// A function, with its `(generator, async)` flags.
+    fn function(
+        &self,
+        node_type: &str,
+        name: Option<Sym>,
+        parameters: &FormalParameterList,
+        body: &StatementList,
+        (generator, is_async): (bool, bool),
+    ) -> Value {
+        json!({
+            "type": node_type,
+            "id": name.map(|name| self.identifier(name)),
+            "params": self.parameters(parameters),
+            "body": self.block(body),
+            "generator": generator,
+            "async": is_async,
+            "expression": false,
+        })
+    }
+
+    fn parameters(&self, parameters: &FormalParameterList) -> Vec<Value> {
+        parameters
+            .parameters
+            .iter()
+            .map(|parameter| {
+                if parameter.is_rest_param() {
+                    rest(self.binding(parameter.declaration()))
+                } else {
+                    self.binding(parameter.declaration())
+                }
+            })
+            .collect()
+    }
+
+    fn exprs(&self, nodes: &[Node]) -> Vec<Value> {
+        nodes.iter().map(|node| self.expr(node)).collect()
+    }
+
+    fn expr(&self, node: &Node) -> Value {
+        match node {
+            Node::This => json!({ "type": "ThisExpression" }),
+            Node::Identifier(ident) => self.identifier(ident.sym()),
+            Node::Const(constant) => self.constant(constant),
+            Node::ArrayDecl(array) => self.array(array),
+            Node::Object(object) => self.object(object),
+            Node::TemplateLit(template) => self.template(template),
+            Node::TaggedTemplate(template) => self.tagged_template(template),
+            Node::FunctionExpr(function) => self.function(
+                "FunctionExpression",
+                function.name(),
+                function.parameters(),
+                function.body(),
+                (false, false),
+            ),
+            Node::GeneratorExpr(function) => self.function(
+                "FunctionExpression",
+                function.name(),
+                function.parameters(),
+                function.body(),
+                (true, false),
+            ),
+            Node::AsyncFunctionExpr(function) => self.function(
+                "FunctionExpression",
+                function.name(),
+                function.parameters(),
+                function.body(),
+                (false, true),
+            ),
+            Node::AsyncGeneratorExpr(function) => self.function(
+                "FunctionExpression",
+                function.name(),
+                function.parameters(),
+                function.body(),
+                (true, true),
+            ),
+            Node::ArrowFunctionDecl(function) => {
+                // The parser makes the expression body of an arrow function a return statement.
+                let (body, expression) = match function.body().items() {
+                    [Node::Return(ret)] if ret.label().is_none() => match ret.expr() {
+                        Some(expr) => (self.expr(expr), true),
+                        None => (self.block(function.body()), false),
+                    },
+                    _ => (self.block(function.body()), false),
+                };
+                json!({
+                    "type": "ArrowFunctionExpression",
+                    "id": null,
+                    "params": self.parameters(function.params()),
+                    "body": body,
+                    "generator": false,
+                    "async": false,
+                    "expression": expression,
+                })
+            }
+            Node::GetConstField(field) => self.get_const_field(field),
+            Node::GetField(field) => self.get_field(field),
+            Node::Call(call) => json!({
+                "type": "CallExpression",
+                "callee": self.expr(call.expr()),
+                "arguments": self.exprs(call.args()),
+                "optional": false,
+            }),
+            Node::New(new) => json!({
+                "type": "NewExpression",
+                "callee": self.expr(new.expr()),
+                "arguments": self.exprs(new.args()),
+            }),
+            Node::Spread(spread) => json!({
+                "type": "SpreadElement",
+                "argument": self.expr(spread.val()),
+            }),
+            Node::UnaryOp(op) => self.unary_op(op),
+            Node::AwaitExpr(expr) => json!({
+                "type": "AwaitExpression",
+                "argument": self.expr(expr.expr()),
+            }),
+            Node::BinOp(op) => self.bin_op(op),
+            Node::Assign(assign) => json!({
+                "type": "AssignmentExpression",
+                "operator": "=",
+                "left": match assign.lhs() {
+                    AssignTarget::Identifier(ident) => self.identifier(ident.sym()),
+                    AssignTarget::GetConstField(field) => self.get_const_field(field),
+                    AssignTarget::GetField(field) => self.get_field(field),
+                    AssignTarget::DeclarationPattern(pattern) => self.pattern(pattern),
+                },
+                "right": self.expr(assign.rhs()),
+            }),
+            Node::ConditionalOp(op) => json!({
+                "type": "ConditionalExpression",
+                "test": self.expr(op.cond()),
+                "consequent": self.expr(op.if_true()),
+                "alternate": self.expr(op.if_false()),
+            }),
+            Node::Yield(node) => json!({
+                "type": "YieldExpression",
+                "argument": node.expr().map(|expr| self.expr(expr)),
+                "delegate": node.delegate(),
+            }),
+            statement => self.statement(statement),
+        }
+    }
+
+    fn constant(&self, constant: &Const) -> Value {
+        match constant {
+            Const::String(string) => self.string(*string),
+            Const::Num(num) if num.is_nan() => json!({ "type": "Identifier", "name": "NaN" }),
+            Const::Num(num) if num.is_sign_negative() => negate(self.constant(&Const::Num(-*num))),
+            Const::Num(num) if num.is_infinite() => {
+                json!({ "type": "Identifier", "name": "Infinity" })
+            }
+            Const::Num(num) => literal((*num).into()),
+            Const::Int(int) if *int < 0 => negate(literal((-i64::from(*int)).into())),
+            Const::Int(int) => literal((*int).into()),
+            Const::BigInt(bigint) => json!({
+                "type": "Literal",
+                "value": null,
+                "bigint": bigint.to_string(),
+            }),
+            Const::Bool(boolean) => literal((*boolean).into()),
+            Const::Null => literal(Value::Null),
+            Const::Undefined => json!({ "type": "Identifier", "name": "undefined" }),
+        }
+    }
+
+    fn get_const_field(&self, field: &GetConstField) -> Value {
+        let computed = !is_identifier_name(self.interner.resolve_expect(field.field()));
+        json!({
+            "type": "MemberExpression",
+            "object": self.expr(field.obj()),
+            "property": self.property_key(field.field()),
+            "computed": computed,
+            "optional": false,
+        })
+    }
+
+    fn get_field(&self, field: &GetField) -> Value {
+        json!({
+            "type": "MemberExpression",
+            "object": self.expr(field.obj()),
+            "property": self.expr(field.field()),
+            "computed": true,
+            "optional": false,
+        })
+    }
+
+    fn array(&self, array: &ArrayDecl) -> Value {
+        let elements: Vec<_> = array
+            .as_ref()
+            .iter()
+            .map(|element| match element {
+                Node::Empty => Value::Null,
+                element => self.expr(element),
+            })
+            .collect();
+        json!({ "type": "ArrayExpression", "elements": elements })
+    }
+
+    fn object(&self, object: &Object) -> Value {
+        let properties: Vec<_> = object
+            .properties()
+            .iter()
+            .map(|property| match property {
+                PropertyDefinition::IdentifierReference(ident) => json!({
+                    "type": "Property",
+                    "key": self.identifier(*ident),
+                    "value": self.identifier(*ident),
+                    "kind": "init",
+                    "method": false,
+                    "shorthand": true,
+                    "computed": false,
+                }),
+                PropertyDefinition::Property(name, value) => {
+                    self.property(name, self.expr(value), "init", false)
+                }
+                PropertyDefinition::MethodDefinition(method, name) => self.method(method, name),
+                PropertyDefinition::SpreadObject(node) => json!({
+                    "type": "SpreadElement",
+                    "argument": self.expr(node),
+                }),
+            })
+            .collect();
+        json!({ "type": "ObjectExpression", "properties": properties })
+    }
+
+    fn property(&self, name: &PropertyName, value: Value, kind: &str, method: bool) -> Value {
+        let (key, computed) = match name {
+            PropertyName::Literal(name) => (self.property_key(*name), false),
+            PropertyName::Computed(node) => (self.expr(node), true),
+        };
+        json!({
+            "type": "Property",
+            "key": key,
+            "value": value,
+            "kind": kind,
+            "method": method,
+            "shorthand": false,
+            "computed": computed,
+        })
+    }
+
+    fn method(&self, method: &MethodDefinition, name: &PropertyName) -> Value {
+        let (kind, parameters, body, flags) = match method {
+            MethodDefinition::Get(function) => (
+                "get",
+                function.parameters(),
+                function.body(),
+                (false, false),
+            ),
+            MethodDefinition::Set(function) => (
+                "set",
+                function.parameters(),
+                function.body(),
+                (false, false),
+            ),
+            MethodDefinition::Ordinary(function) => (
+                "init",
+                function.parameters(),
+                function.body(),
+                (false, false),
+            ),
+            MethodDefinition::Generator(function) => (
+                "init",
+                function.parameters(),
+                function.body(),
+                (true, false),
+            ),
+            MethodDefinition::Async(function) => (
+                "init",
+                function.parameters(),
+                function.body(),
+                (false, true),
+            ),
+            MethodDefinition::AsyncGenerator(function) => {
+                ("init", function.parameters(), function.body(), (true, true))
+            }
+        };
+        let value = self.function("FunctionExpression", None, parameters, body, flags);
+        self.property(name, value, kind, kind == "init")
+    }
+
+    fn template_element(raw: String, cooked: Option<&str>, tail: bool) -> Value {
+        json!({
+            "type": "TemplateElement",
+            "value": { "raw": raw, "cooked": cooked },
+            "tail": tail,
+        })
+    }
+
+    fn template(&self, template: &TemplateLit) -> Value {
+        // The elements alternate between strings and expressions, but an expression can come
+        // first, last or after another one, separated by an empty string.
+        let mut quasis = Vec::new();
+        let mut expressions = Vec::new();
+        let mut cooked = String::new();
+        for element in template.elements() {
+            match element {
+                TemplateElement::String(string) => {
+                    cooked.push_str(self.interner.resolve_expect(*string));
+                }
+                TemplateElement::Expr(node) => {
+                    let mut raw = String::new();
+                    escape_template(&cooked, &mut raw);
+                    quasis.push(Self::template_element(raw, Some(&cooked), false));
+                    expressions.push(self.expr(node));
+                    cooked.clear();
+                }
+            }
+        }
+        let mut raw = String::new();
+        escape_template(&cooked, &mut raw);
+        quasis.push(Self::template_element(raw, Some(&cooked), true));
+
+        json!({
+            "type": "TemplateLiteral",
+            "quasis": quasis,
+            "expressions": expressions,
+        })
+    }
+
+    fn tagged_template(&self, template: &TaggedTemplate) -> Value {
+        let raws = template.raws();
+        let quasis: Vec<_> = raws
+            .iter()
+            .zip(template.cookeds())
+            .enumerate()
+            .map(|(i, (raw, &cooked))| {
+                Self::template_element(
+                    self.interner.resolve_expect(*raw).to_owned(),
+                    cooked.map(|cooked| self.interner.resolve_expect(cooked)),
+                    i + 1 == raws.len(),
+                )
+            })
+            .collect();
+        json!({
+            "type": "TaggedTemplateExpression",
+            "tag": self.expr(template.tag()),
+            "quasi": {
+                "type": "TemplateLiteral",
+                "quasis": quasis,
+                "expressions": self.exprs(template.exprs()),
+            },
+        })
+    }
+
+    fn unary_op(&self, op: &UnaryOp) -> Value {
+        let (node_type, prefix) = match op.op() {
+            op::UnaryOp::IncrementPost | op::UnaryOp::DecrementPost => ("UpdateExpression", false),
+            op::UnaryOp::IncrementPre | op::UnaryOp::DecrementPre => ("UpdateExpression", true),
+            _ => ("UnaryExpression", true),
+        };
+        json!({
+            "type": node_type,
+            "operator": op.op().to_string(),
+            "prefix": prefix,
+            "argument": self.expr(op.target()),
+        })
+    }
+
+    fn bin_op(&self, node: &BinOp) -> Value {
+        let node_type = match node.op() {
+            op::BinOp::Comma => {
+                // Nested comma operators are a single sequence.
+                let mut expressions = vec![self.expr(node.rhs())];
+                let mut lhs = node.lhs();
+                while let Node::BinOp(op) = lhs {
+                    if op.op() != op::BinOp::Comma {
+                        break;
+                    }
+                    expressions.push(self.expr(op.rhs()));
+                    lhs = op.lhs();
+                }
+                expressions.push(self.expr(lhs));
+                expressions.reverse();
+                return json!({ "type": "SequenceExpression", "expressions": expressions });
+            }
+            op::BinOp::Assign(_) => "AssignmentExpression",
+            op::BinOp::Log(_) => "LogicalExpression",
+            _ => "BinaryExpression",
+        };
+        json!({
+            "type": node_type,
+            "operator": node.op().to_string(),
+            "left": self.expr(node.lhs()),
+            "right": self.expr(node.rhs()),
+        })
+    }
+}
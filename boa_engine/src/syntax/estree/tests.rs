@@ -0,0 +1,203 @@
+use super::ToEstree;
+use crate::syntax::{ast::node::StatementList, Parser};
+use boa_interner::Interner;
+use serde_json::{json, Value};
+
+fn parse(source: &str, interner: &mut Interner) -> StatementList {
+    Parser::new(source.as_bytes(), false)
+        .parse_all(interner)
+        .expect("parsing failed")
+}
+
+/// Converts the single statement of `source`, or its expression if it is an expression statement.
+fn convert_single(source: &str) -> Value {
+    let mut interner = Interner::default();
+    let script = parse(source, &mut interner);
+    match script.items() {
+        [node] => node.to_estree(&interner),
+        items => panic!("expected a single statement, got {items:?}"),
+    }
+}
+
+fn identifier(name: &str) -> Value {
+    json!({ "type": "Identifier", "name": name })
+}
+
+#[test]
+fn program() {
+    let mut interner = Interner::default();
+    let script = parse("let a = 1;\nf(a);", &mut interner);
+    let mut program = script.to_estree(&interner);
+
+    let body = program["body"]
+        .as_array_mut()
+        .expect("body is not an array");
+    let starts: Vec<_> = body
+        .iter_mut()
+        .map(|statement| {
+            let loc = statement
+                .as_object_mut()
+                .and_then(|statement| statement.remove("loc"))
+                .expect("missing location");
+            loc["start"].clone()
+        })
+        .collect();
+    assert_eq!(
+        starts,
+        [
+            json!({ "line": 1, "column": 0 }),
+            json!({ "line": 2, "column": 0 }),
+        ]
+    );
+
+    assert_eq!(
+        program,
+        json!({
+            "type": "Program",
+            "sourceType": "script",
+            "body": [
+                {
+                    "type": "VariableDeclaration",
+                    "declarations": [{
+                        "type": "VariableDeclarator",
+                        "id": identifier("a"),
+                        "init": { "type": "Literal", "value": 1 },
+                    }],
+                    "kind": "let",
+                },
+                {
+                    "type": "ExpressionStatement",
+                    "expression": {
+                        "type": "CallExpression",
+                        "callee": identifier("f"),
+                        "arguments": [identifier("a")],
+                        "optional": false,
+                    },
+                },
+            ],
+        })
+    );
+}
+
+#[test]
+fn expressions() {
+    assert_eq!(
+        convert_single("-1"),
+        json!({
+            "type": "UnaryExpression",
+            "operator": "-",
+            "prefix": true,
+            "argument": { "type": "Literal", "value": 1 },
+        })
+    );
+    assert_eq!(
+        convert_single("i++"),
+        json!({
+            "type": "UpdateExpression",
+            "operator": "++",
+            "prefix": false,
+            "argument": identifier("i"),
+        })
+    );
+    assert_eq!(
+        convert_single("a ?? b"),
+        json!({
+            "type": "LogicalExpression",
+            "operator": "??",
+            "left": identifier("a"),
+            "right": identifier("b"),
+        })
+    );
+    assert_eq!(
+        convert_single("a, b, c"),
+        json!({
+            "type": "SequenceExpression",
+            "expressions": [identifier("a"), identifier("b"), identifier("c")],
+        })
+    );
+    assert_eq!(
+        convert_single("o['c-d'].e"),
+        json!({
+            "type": "MemberExpression",
+            "object": {
+                "type": "MemberExpression",
+                "object": identifier("o"),
+                "property": { "type": "Literal", "value": "c-d" },
+                "computed": true,
+                "optional": false,
+            },
+            "property": identifier("e"),
+            "computed": false,
+            "optional": false,
+        })
+    );
+    assert_eq!(
+        convert_single("`a${b}`"),
+        json!({
+            "type": "TemplateLiteral",
+            "quasis": [
+                {
+                    "type": "TemplateElement",
+                    "value": { "raw": "a", "cooked": "a" },
+                    "tail": false,
+                },
+                {
+                    "type": "TemplateElement",
+                    "value": { "raw": "", "cooked": "" },
+                    "tail": true,
+                },
+            ],
+            "expressions": [identifier("b")],
+        })
+    );
+}
+
+#[test]
+fn patterns() {
+    let function = convert_single("function f({ a, b: [c, , ...d] } = {}, e = 1, ...g) {}");
+    assert_eq!(
+        function["params"],
+        json!([
+            {
+                "type": "AssignmentPattern",
+                "left": {
+                    "type": "ObjectPattern",
+                    "properties": [
+                        {
+                            "type": "Property",
+                            "key": identifier("a"),
+                            "value": identifier("a"),
+                            "kind": "init",
+                            "method": false,
+                            "shorthand": true,
+                            "computed": false,
+                        },
+                        {
+                            "type": "Property",
+                            "key": identifier("b"),
+                            "value": {
+                                "type": "ArrayPattern",
+                                "elements": [
+                                    identifier("c"),
+                                    null,
+                                    { "type": "RestElement", "argument": identifier("d") },
+                                ],
+                            },
+                            "kind": "init",
+                            "method": false,
+                            "shorthand": false,
+                            "computed": false,
+                        },
+                    ],
+                },
+                "right": { "type": "ObjectExpression", "properties": [] },
+            },
+            {
+                "type": "AssignmentPattern",
+                "left": identifier("e"),
+                "right": { "type": "Literal", "value": 1 },
+            },
+            { "type": "RestElement", "argument": identifier("g") },
+        ])
+    );
+}
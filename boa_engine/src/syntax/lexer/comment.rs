@@ -9,6 +9,91 @@ use boa_interner::Interner;
 use boa_profiler::Profiler;
 use std::io::Read;
 
+/// A comment of the source code, collected by the lexer.
+///
+/// Comments are only collected if requested with [`Lexer::set_collect_comments`], or with the
+/// method of the same name of the parser, which also attaches them to the statements they
+/// document.
+///
+/// [`Lexer::set_collect_comments`]: super::Lexer::set_collect_comments
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comment {
+    kind: CommentKind,
+    text: Box<str>,
+    span: Span,
+    attachment: Option<CommentAttachment>,
+}
+
+impl Comment {
+    /// Creates a new comment, attached to nothing.
+    #[inline]
+    pub(crate) fn new(kind: CommentKind, text: Box<str>, span: Span) -> Self {
+        Self {
+            kind,
+            text,
+            span,
+            attachment: None,
+        }
+    }
+
+    /// Gets the kind of the comment.
+    #[inline]
+    pub fn kind(&self) -> CommentKind {
+        self.kind
+    }
+
+    /// Gets the text of the comment, without its delimiters.
+    #[inline]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Gets the span of the comment in the source code, including its delimiters.
+    #[inline]
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Gets the statement the comment is attached to, if any.
+    #[inline]
+    pub fn attachment(&self) -> Option<CommentAttachment> {
+        self.attachment
+    }
+
+    /// Attaches the comment to a statement.
+    #[inline]
+    pub(crate) fn set_attachment(&mut self, attachment: CommentAttachment) {
+        self.attachment = Some(attachment);
+    }
+}
+
+/// The kind of a [`Comment`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentKind {
+    /// A single line comment, starting with `//`.
+    SingleLine,
+
+    /// A multi-line comment, between `/*` and `*/`.
+    MultiLine,
+
+    /// The hashbang comment at the start of a script, starting with `#!`.
+    Hashbang,
+}
+
+/// The statement a [`Comment`] documents.
+///
+/// Statements are identified by their span, which is given by
+/// [`StatementList::span`](crate::syntax::ast::node::StatementList::span) for the items of the
+/// statement lists made by the parser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentAttachment {
+    /// The comment comes before the statement with this span, with no other statement in between.
+    Leading(Span),
+
+    /// The comment follows the statement with this span on the line where it ends.
+    Trailing(Span),
+}
+
 /// Lexes a single line comment.
 ///
 /// Assumes that the initial '//' is already consumed.
@@ -19,7 +104,24 @@ use std::io::Read;
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-comments
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Lexical_grammar
-pub(super) struct SingleLineComment;
+pub(super) struct SingleLineComment {
+    text: Option<Vec<u8>>,
+}
+
+impl SingleLineComment {
+    /// Creates a new single line comment lexer, collecting the text of the comment if `collect`
+    /// is `true`.
+    pub(super) fn new(collect: bool) -> Self {
+        Self {
+            text: if collect { Some(Vec::new()) } else { None },
+        }
+    }
+
+    /// Gets the collected text of the comment.
+    pub(super) fn into_text(self) -> Option<Box<str>> {
+        self.text.map(|text| String::from_utf8_lossy(&text).into())
+    }
+}
 
 impl<R> Tokenizer<R> for SingleLineComment {
     fn lex(
@@ -39,7 +141,10 @@ impl<R> Tokenizer<R> for SingleLineComment {
                 break;
             }
             // Consume char.
-            cursor.next_byte()?.expect("Comment character vanished");
+            let byte = cursor.next_byte()?.expect("Comment character vanished");
+            if let Some(text) = &mut self.text {
+                text.push(byte);
+            }
         }
         Ok(Token::new(
             TokenKind::Comment,
@@ -58,7 +163,24 @@ impl<R> Tokenizer<R> for SingleLineComment {
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-comments
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Lexical_grammar
-pub(super) struct MultiLineComment;
+pub(super) struct MultiLineComment {
+    text: Option<String>,
+}
+
+impl MultiLineComment {
+    /// Creates a new multi-line comment lexer, collecting the text of the comment if `collect`
+    /// is `true`.
+    pub(super) fn new(collect: bool) -> Self {
+        Self {
+            text: if collect { Some(String::new()) } else { None },
+        }
+    }
+
+    /// Gets the collected text of the comment.
+    pub(super) fn into_text(self) -> Option<Box<str>> {
+        self.text.map(String::into_boxed_str)
+    }
+}
 
 impl<R> Tokenizer<R> for MultiLineComment {
     fn lex(
@@ -91,6 +213,9 @@ impl<R> Tokenizer<R> for MultiLineComment {
                 }
                 _ => {}
             };
+            if let (Some(text), Ok(c)) = (&mut self.text, tried_ch) {
+                text.push(c);
+            }
         }
 
         Err(Error::syntax(
@@ -100,14 +225,33 @@ impl<R> Tokenizer<R> for MultiLineComment {
     }
 }
 
-///Lexes a first line Hashbang comment
+/// Lexes a first line Hashbang comment.
+///
+/// Assumes that the initial '#!' is already consumed. The line terminator ending the comment is
+/// not part of it.
 ///
 /// More information:
 ///  - [ECMAScript reference][spec]
 ///
-/// [spec]: https://tc39.es/ecma262/#sec-ecmascript-language-lexical-grammar
+/// [spec]: https://tc39.es/ecma262/#sec-hashbang
+pub(super) struct HashbangComment {
+    text: Option<String>,
+}
 
-pub(super) struct HashbangComment;
+impl HashbangComment {
+    /// Creates a new hashbang comment lexer, collecting the text of the comment if `collect` is
+    /// `true`.
+    pub(super) fn new(collect: bool) -> Self {
+        Self {
+            text: if collect { Some(String::new()) } else { None },
+        }
+    }
+
+    /// Gets the collected text of the comment.
+    pub(super) fn into_text(self) -> Option<Box<str>> {
+        self.text.map(String::into_boxed_str)
+    }
+}
 
 impl<R> Tokenizer<R> for HashbangComment {
     fn lex(
@@ -121,12 +265,17 @@ impl<R> Tokenizer<R> for HashbangComment {
     {
         let _timer = Profiler::global().start_event("Hashbang", "Lexing");
 
-        while let Some(ch) = cursor.next_char()? {
-            let tried_ch = char::try_from(ch);
-            match tried_ch {
-                Ok(c) if c == '\r' || c == '\n' || c == '\u{2028}' || c == '\u{2029}' => break,
-                _ => {}
-            };
+        while let Some(ch) = cursor.peek_char()? {
+            if let Ok(c) = char::try_from(ch) {
+                if matches!(c, '\r' | '\n' | '\u{2028}' | '\u{2029}') {
+                    break;
+                }
+                if let Some(text) = &mut self.text {
+                    text.push(c);
+                }
+            }
+            // Consume char.
+            cursor.next_char()?.expect("Comment character vanished");
         }
 
         Ok(Token::new(
@@ -15,7 +15,11 @@ impl<R> Cursor<R> {
     /// Gets the current position of the cursor in the source code.
     #[inline]
     pub(super) fn pos(&self) -> Position {
-        self.pos
+        Position::with_offset(
+            self.pos.line_number(),
+            self.pos.column_number(),
+            self.iter.offset,
+        )
     }
     /// Advances the position to the next column.
     #[inline]
@@ -51,7 +55,7 @@ where
     #[inline]
     pub(super) fn new(inner: R) -> Self {
         Self {
-            iter: InnerIter::new(inner.bytes()),
+            iter: InnerIter::new(inner.bytes(), 0),
             pos: Position::new(1, 1),
             strict_mode: false,
         }
@@ -61,7 +65,7 @@ where
     #[inline]
     pub(super) fn with_position(inner: R, pos: Position) -> Self {
         Self {
-            iter: InnerIter::new(inner.bytes()),
+            iter: InnerIter::new(inner.bytes(), pos.offset()),
             pos,
             strict_mode: false,
         }
@@ -296,17 +300,20 @@ struct InnerIter<R> {
     num_peeked_bytes: u8,
     peeked_bytes: u32,
     peeked_char: Option<Option<u32>>,
+    /// The number of bytes consumed since the start of the source.
+    offset: u32,
 }
 
 impl<R> InnerIter<R> {
-    /// Creates a new inner iterator.
+    /// Creates a new inner iterator, starting at the byte offset `offset` of the source.
     #[inline]
-    fn new(iter: Bytes<R>) -> Self {
+    fn new(iter: Bytes<R>, offset: u32) -> Self {
         Self {
             iter,
             num_peeked_bytes: 0,
             peeked_bytes: 0,
             peeked_char: None,
+            offset,
         }
     }
 }
@@ -431,14 +438,18 @@ where
     #[inline]
     fn next_byte(&mut self) -> io::Result<Option<u8>> {
         self.peeked_char = None;
-        if self.num_peeked_bytes > 0 {
+        let byte = if self.num_peeked_bytes > 0 {
             let byte = (self.peeked_bytes & 0xFF) as u8;
             self.num_peeked_bytes -= 1;
             self.peeked_bytes >>= 8;
-            Ok(Some(byte))
+            Some(byte)
         } else {
-            self.iter.next().transpose()
+            self.iter.next().transpose()?
+        };
+        if byte.is_some() {
+            self.offset += 1;
         }
+        Ok(byte)
     }
 
     /// Retrieves the next unchecked char in u32 code point.
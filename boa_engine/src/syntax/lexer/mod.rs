@@ -23,7 +23,7 @@ mod operator;
 pub mod regex;
 mod spread;
 mod string;
-mod template;
+pub(crate) mod template;
 pub mod token;
 
 #[cfg(test)]
@@ -46,6 +46,7 @@ use boa_profiler::Profiler;
 use std::io::Read;
 
 pub use self::{
+    comment::{Comment, CommentAttachment, CommentKind},
     error::Error,
     token::{Token, TokenKind},
 };
@@ -67,6 +68,7 @@ trait Tokenizer<R> {
 pub struct Lexer<R> {
     cursor: Cursor<R>,
     goal_symbol: InputElement,
+    comments: Option<Vec<Comment>>,
 }
 
 impl<R> Lexer<R> {
@@ -109,6 +111,35 @@ impl<R> Lexer<R> {
         self.cursor.set_strict_mode(strict_mode);
     }
 
+    /// Sets whether the lexer collects the comments it skips, instead of discarding them.
+    #[inline]
+    pub fn set_collect_comments(&mut self, collect: bool) {
+        if !collect {
+            self.comments = None;
+        } else if self.comments.is_none() {
+            self.comments = Some(Vec::new());
+        }
+    }
+
+    /// Gets the comments collected so far, in source order.
+    #[inline]
+    pub fn comments(&self) -> &[Comment] {
+        self.comments.as_deref().unwrap_or_default()
+    }
+
+    /// Gets a mutable reference to the comments collected so far.
+    #[inline]
+    pub(crate) fn comments_mut(&mut self) -> &mut [Comment] {
+        self.comments.as_deref_mut().unwrap_or_default()
+    }
+
+    /// Records a comment, if comments are collected.
+    fn push_comment(&mut self, kind: CommentKind, text: Option<Box<str>>, span: Span) {
+        if let (Some(comments), Some(text)) = (&mut self.comments, text) {
+            comments.push(Comment::new(kind, text, span));
+        }
+    }
+
     /// Creates a new lexer.
     #[inline]
     pub fn new(reader: R) -> Self
@@ -118,6 +149,7 @@ impl<R> Lexer<R> {
         Self {
             cursor: Cursor::new(reader),
             goal_symbol: InputElement::default(),
+            comments: None,
         }
     }
 
@@ -142,11 +174,17 @@ impl<R> Lexer<R> {
             match c {
                 b'/' => {
                     self.cursor.next_byte()?.expect("/ token vanished"); // Consume the '/'
-                    SingleLineComment.lex(&mut self.cursor, start, interner)
+                    let mut comment = SingleLineComment::new(self.comments.is_some());
+                    let token = comment.lex(&mut self.cursor, start, interner)?;
+                    self.push_comment(CommentKind::SingleLine, comment.into_text(), token.span());
+                    Ok(token)
                 }
                 b'*' => {
                     self.cursor.next_byte()?.expect("* token vanished"); // Consume the '*'
-                    MultiLineComment.lex(&mut self.cursor, start, interner)
+                    let mut comment = MultiLineComment::new(self.comments.is_some());
+                    let token = comment.lex(&mut self.cursor, start, interner)?;
+                    self.push_comment(CommentKind::MultiLine, comment.into_text(), token.span());
+                    Ok(token)
                 }
                 ch => {
                     match self.get_goal() {
@@ -208,7 +246,10 @@ impl<R> Lexer<R> {
         if start.column_number() == 1 && start.line_number() == 1 && next_ch == 0x23 {
             if let Some(hashbang_peek) = self.cursor.peek()? {
                 if hashbang_peek == 0x21 {
-                    let _token = HashbangComment.lex(&mut self.cursor, start, interner);
+                    self.cursor.next_byte()?.expect("! token vanished"); // Consume the '!'
+                    let mut comment = HashbangComment::new(self.comments.is_some());
+                    let token = comment.lex(&mut self.cursor, start, interner)?;
+                    self.push_comment(CommentKind::Hashbang, comment.into_text(), token.span());
                     return self.next(interner);
                 }
             }
@@ -8,6 +8,7 @@ use crate::syntax::{
 use bitflags::bitflags;
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
+use regress::Regex;
 use std::{
     io::{self, ErrorKind, Read},
     str::{self, FromStr},
@@ -112,11 +113,19 @@ impl<R> Tokenizer<R> for RegexLiteral {
 
         let flags_str = unsafe { str::from_utf8_unchecked(flags.as_slice()) };
         if let Ok(body_str) = str::from_utf8(body.as_slice()) {
+            let flags_sym = parse_regex_flags(flags_str, flags_start, interner)?;
+
+            // An invalid pattern is an early error, so it is reported even if the literal is
+            // never evaluated.
+            if let Err(error) = Regex::with_flags(body_str, flags_str) {
+                return Err(Error::syntax(
+                    format!("invalid regular expression literal: {}", error.text),
+                    start_pos,
+                ));
+            }
+
             Ok(Token::new(
-                TokenKind::regular_expression_literal(
-                    interner.get_or_intern(body_str),
-                    parse_regex_flags(flags_str, flags_start, interner)?,
-                ),
+                TokenKind::regular_expression_literal(interner.get_or_intern(body_str), flags_sym),
                 Span::new(start_pos, cursor.pos()),
             ))
         } else {
@@ -138,6 +147,8 @@ bitflags! {
         const DOT_ALL = 0b0000_1000;
         const UNICODE = 0b0001_0000;
         const STICKY = 0b0010_0000;
+        const HAS_INDICES = 0b0100_0000;
+        const UNICODE_SETS = 0b1000_0000;
     }
 }
 
@@ -148,11 +159,13 @@ impl FromStr for RegExpFlags {
         let mut flags = Self::default();
         for c in s.bytes() {
             let new_flag = match c {
+                b'd' => Self::HAS_INDICES,
                 b'g' => Self::GLOBAL,
                 b'i' => Self::IGNORE_CASE,
                 b'm' => Self::MULTILINE,
                 b's' => Self::DOT_ALL,
                 b'u' => Self::UNICODE,
+                b'v' => Self::UNICODE_SETS,
                 b'y' => Self::STICKY,
                 _ => return Err(format!("invalid regular expression flag {}", char::from(c))),
             };
@@ -166,6 +179,10 @@ impl FromStr for RegExpFlags {
             flags.insert(new_flag);
         }
 
+        if flags.contains(Self::UNICODE | Self::UNICODE_SETS) {
+            return Err("regular expression flags u and v are mutually exclusive".to_owned());
+        }
+
         Ok(flags)
     }
 }
@@ -180,6 +197,9 @@ fn parse_regex_flags(s: &str, start: Position, interner: &mut Interner) -> Resul
 impl ToString for RegExpFlags {
     fn to_string(&self) -> String {
         let mut s = String::new();
+        if self.contains(Self::HAS_INDICES) {
+            s.push('d');
+        }
         if self.contains(Self::GLOBAL) {
             s.push('g');
         }
@@ -195,6 +215,9 @@ impl ToString for RegExpFlags {
         if self.contains(Self::UNICODE) {
             s.push('u');
         }
+        if self.contains(Self::UNICODE_SETS) {
+            s.push('v');
+        }
         if self.contains(Self::STICKY) {
             s.push('y');
         }
@@ -49,41 +49,51 @@ impl TemplateString {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-templatestrings
     pub fn to_owned_cooked(self, interner: &mut Interner) -> Result<Sym, Error> {
-        let mut cursor =
-            Cursor::with_position(interner.resolve_expect(self.raw).as_bytes(), self.start_pos);
-        let mut buf: Vec<u16> = Vec::new();
+        let str = cook_template_string(interner.resolve_expect(self.raw), self.start_pos)?;
 
-        loop {
-            let ch_start_pos = cursor.pos();
-            let ch = cursor.next_char()?;
+        Ok(interner.get_or_intern(&str))
+    }
+}
 
-            match ch {
-                Some(0x005C /* \ */) => {
-                    let escape_value = StringLiteral::take_escape_sequence_or_line_continuation(
-                        &mut cursor,
-                        ch_start_pos,
-                        true,
-                        true,
-                    )?;
-
-                    if let Some(escape_value) = escape_value {
-                        buf.push_code_point(escape_value);
-                    }
-                }
-                Some(ch) => {
-                    // The caller guarantees that sequences '`' and '${' never appear
-                    // LineTerminatorSequence <CR> <LF> is consumed by `cursor.next_char()` and
-                    // returns <LF>, which matches the TV of <CR> <LF>
-                    buf.push_code_point(ch);
+/// Cooks a raw template string, interpreting its escape sequences and line continuations.
+/// Returns a lexer error if the raw string contains an invalid escape sequence.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-static-semantics-tv
+pub(crate) fn cook_template_string(raw: &str, start_pos: Position) -> Result<String, Error> {
+    let mut cursor = Cursor::with_position(raw.as_bytes(), start_pos);
+    let mut buf: Vec<u16> = Vec::new();
+
+    loop {
+        let ch_start_pos = cursor.pos();
+        let ch = cursor.next_char()?;
+
+        match ch {
+            Some(0x005C /* \ */) => {
+                let escape_value = StringLiteral::take_escape_sequence_or_line_continuation(
+                    &mut cursor,
+                    ch_start_pos,
+                    true,
+                    true,
+                )?;
+
+                if let Some(escape_value) = escape_value {
+                    buf.push_code_point(escape_value);
                 }
-                None => break,
             }
+            Some(ch) => {
+                // The caller guarantees that sequences '`' and '${' never appear
+                // LineTerminatorSequence <CR> <LF> is consumed by `cursor.next_char()` and
+                // returns <LF>, which matches the TV of <CR> <LF>
+                buf.push_code_point(ch);
+            }
+            None => break,
         }
-
-        let str = buf.to_string_lossy();
-
-        Ok(interner.get_or_intern(&str))
     }
+
+    Ok(buf.to_string_lossy())
 }
 
 /// Template literal lexing.
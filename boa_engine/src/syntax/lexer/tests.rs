@@ -61,6 +61,36 @@ fn check_single_line_comment_with_crlf_ending() {
     expect_tokens(&mut lexer, &expected, &mut interner);
 }
 
+#[test]
+fn check_hashbang_comment() {
+    let s = "#!/usr/bin/env boa\r\nvar";
+    let mut lexer = Lexer::new(s.as_bytes());
+    let mut interner = Interner::default();
+
+    let expected = [TokenKind::LineTerminator, TokenKind::Keyword(Keyword::Var)];
+
+    expect_tokens(&mut lexer, &expected, &mut interner);
+}
+
+#[test]
+fn check_misplaced_hashbang_comment() {
+    for s in [
+        " #!comment",
+        "\n#!comment",
+        "var #!comment",
+        "#\\u0021comment",
+    ] {
+        let mut lexer = Lexer::new(s.as_bytes());
+        let mut interner = Interner::default();
+
+        let mut result = lexer.next(&mut interner);
+        while let Ok(Some(_)) = result {
+            result = lexer.next(&mut interner);
+        }
+        assert!(result.is_err(), "hashbang accepted in {s:?}");
+    }
+}
+
 #[test]
 fn check_multi_line_comment() {
     let s = "var /* await \n break \n*/ x";
@@ -434,6 +464,22 @@ fn check_line_numbers() {
     );
 }
 
+#[test]
+fn check_byte_offsets() {
+    let s = "let \u{e9} =\r\n'\u{2764}';";
+
+    let mut lexer = Lexer::new(s.as_bytes());
+    let mut interner = Interner::default();
+
+    let offsets: Vec<_> = std::iter::from_fn(|| lexer.next(&mut interner).unwrap())
+        .map(|token| (token.span().start().offset(), token.span().end().offset()))
+        .collect();
+    assert_eq!(
+        offsets,
+        [(0, 3), (4, 6), (7, 8), (8, 10), (10, 15), (15, 16)]
+    );
+}
+
 // Increment/Decrement
 #[test]
 fn check_decrement_advances_lexer_2_places() {
@@ -664,6 +710,22 @@ fn regex_literal_flags_err() {
         .expect_err("Lexer did not handle regex literal with error");
 }
 
+#[test]
+fn regex_literal_pattern_err() {
+    for source in ["/(/", "/[b-a]/g"] {
+        let mut lexer = Lexer::new(source.as_bytes());
+        let mut interner = Interner::default();
+
+        let error = lexer
+            .next(&mut interner)
+            .expect_err("Lexer did not handle invalid regex pattern with error");
+        assert!(
+            matches!(error, Error::Syntax(_, position) if position == Position::new(1, 1)),
+            "unexpected error {error:?}"
+        );
+    }
+}
+
 #[test]
 fn addition_no_spaces() {
     let mut lexer = Lexer::new(&b"1+1"[..]);
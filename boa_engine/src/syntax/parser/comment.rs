@@ -0,0 +1,72 @@
+//! Attachment of the collected comments to the statements they document.
+
+use crate::syntax::{
+    ast::{
+        node::StatementList,
+        visitor::{walk_statement_list, Visitor},
+        Span,
+    },
+    lexer::{Comment, CommentAttachment},
+};
+
+/// Attaches each comment to the statement it documents, if any.
+///
+/// A comment following a statement on the line where the statement ends trails that statement.
+/// Otherwise, it leads the next statement. In both cases, the statement must be in the same
+/// block as the comment, so a comment at the end of a block is attached to nothing.
+pub(super) fn attach_comments(comments: &mut [Comment], statement_list: &StatementList) {
+    if comments.is_empty() {
+        return;
+    }
+
+    let mut spans = StatementSpans(Vec::new());
+    spans.visit_statement_list(statement_list);
+
+    for comment in comments {
+        if let Some(attachment) = attachment(comment.span(), &spans.0) {
+            comment.set_attachment(attachment);
+        }
+    }
+}
+
+/// Collects the spans of the items of every statement list of a tree.
+struct StatementSpans(Vec<Span>);
+
+impl<'ast> Visitor<'ast> for StatementSpans {
+    fn visit_statement_list(&mut self, list: &'ast StatementList) {
+        self.0
+            .extend((0..list.items().len()).filter_map(|index| list.span(index)));
+        walk_statement_list(self, list);
+    }
+}
+
+/// Finds the statement a comment with the given span is attached to.
+fn attachment(comment: Span, spans: &[Span]) -> Option<CommentAttachment> {
+    // A statement is in the same block as the comment if every statement containing the comment
+    // also contains it.
+    let in_same_block = |span: &&Span| {
+        spans
+            .iter()
+            .filter(|outer| outer.contains(comment))
+            .all(|outer| outer.contains(**span))
+    };
+
+    let trailed = spans
+        .iter()
+        .filter(|span| {
+            span.end() <= comment.start()
+                && span.end().line_number() == comment.start().line_number()
+        })
+        .filter(in_same_block)
+        .max_by_key(|span| span.end());
+    if let Some(span) = trailed {
+        return Some(CommentAttachment::Trailing(*span));
+    }
+
+    spans
+        .iter()
+        .filter(|span| span.start() >= comment.end())
+        .min_by_key(|span| span.start())
+        .filter(in_same_block)
+        .map(|span| CommentAttachment::Leading(*span))
+}
@@ -1,6 +1,6 @@
 use crate::syntax::{
     ast::Position,
-    lexer::{InputElement, Lexer, Token, TokenKind},
+    lexer::{Comment, InputElement, Lexer, Token, TokenKind},
     parser::error::ParseError,
 };
 use boa_interner::Interner;
@@ -29,6 +29,8 @@ pub(super) struct BufferedLexer<R> {
     peeked: [Option<Token>; PEEK_BUF_SIZE],
     read_index: usize,
     write_index: usize,
+    /// The end of the last token returned by [`BufferedLexer::next`].
+    last_end: Position,
 }
 
 impl<R> From<Lexer<R>> for BufferedLexer<R>
@@ -52,6 +54,7 @@ where
             ],
             read_index: 0,
             write_index: 0,
+            last_end: Position::new(1, 1),
         }
     }
 }
@@ -86,9 +89,9 @@ where
     ) -> Result<Token, ParseError> {
         let _timer = Profiler::global().start_event("cursor::lex_regex()", "Parsing");
         self.set_goal(InputElement::RegExp);
-        self.lexer
-            .lex_slash_token(start, interner)
-            .map_err(Into::into)
+        let token = self.lexer.lex_slash_token(start, interner)?;
+        self.last_end = token.span().end();
+        Ok(token)
     }
 
     /// Lexes the next tokens as template middle or template tail assuming that the starting
@@ -98,9 +101,16 @@ where
         start: Position,
         interner: &mut Interner,
     ) -> Result<Token, ParseError> {
-        self.lexer
-            .lex_template(start, interner)
-            .map_err(ParseError::from)
+        let token = self.lexer.lex_template(start, interner)?;
+        self.last_end = token.span().end();
+        Ok(token)
+    }
+
+    /// Gets the end of the last token consumed, or the start of the source if no token was
+    /// consumed.
+    #[inline]
+    pub(super) fn last_end(&self) -> Position {
+        self.last_end
     }
 
     #[inline]
@@ -113,6 +123,21 @@ where
         self.lexer.set_strict_mode(strict_mode);
     }
 
+    #[inline]
+    pub(super) fn set_collect_comments(&mut self, collect: bool) {
+        self.lexer.set_collect_comments(collect);
+    }
+
+    #[inline]
+    pub(super) fn comments(&self) -> &[Comment] {
+        self.lexer.comments()
+    }
+
+    #[inline]
+    pub(super) fn comments_mut(&mut self) -> &mut [Comment] {
+        self.lexer.comments_mut()
+    }
+
     /// Fills the peeking buffer with the next token.
     ///
     /// It will not fill two line terminators one after the other.
@@ -188,6 +213,11 @@ where
             let tok = self.peeked[self.read_index].take();
             self.read_index = (self.read_index + 1) % PEEK_BUF_SIZE;
 
+            if let Some(ref token) = tok {
+                if token.kind() != &TokenKind::LineTerminator {
+                    self.last_end = token.span().end();
+                }
+            }
             Ok(tok)
         } else {
             // We do not update the read index, since we should always return `None` from now on.
@@ -1,14 +1,17 @@
 //! Cursor implementation for the parser.
 mod buffered_lexer;
 
-use super::ParseError;
+use super::{Diagnostic, ParseError};
 use crate::syntax::{
-    ast::{Position, Punctuator},
-    lexer::{InputElement, Lexer, Token, TokenKind},
+    ast::{Position, Punctuator, Span},
+    lexer::{Comment, Error as LexError, InputElement, Lexer, Token, TokenKind},
 };
 use boa_interner::Interner;
 use buffered_lexer::BufferedLexer;
-use std::io::Read;
+use std::{cell::Cell, io::Read, rc::Rc};
+
+/// The default maximum nesting depth of the expressions and statements of a script.
+const DEFAULT_RECURSION_LIMIT: usize = 256;
 
 /// The result of a peek for a semicolon.
 #[derive(Debug)]
@@ -23,6 +26,23 @@ pub(super) enum SemicolonResult<'s> {
 #[derive(Debug)]
 pub(super) struct Cursor<R> {
     buffered_lexer: BufferedLexer<R>,
+    /// The nesting depth of the expressions and statements being parsed.
+    depth: Rc<Cell<usize>>,
+    /// The maximum nesting depth, which keeps the parser from overflowing the native stack.
+    recursion_limit: usize,
+    /// The errors the parser recovered from, if it recovers from errors.
+    diagnostics: Option<Vec<Diagnostic>>,
+}
+
+/// A guard for one level of nesting of the parser, which leaves it when dropped, even if parsing
+/// failed.
+#[derive(Debug)]
+pub(super) struct NestingGuard(Rc<Cell<usize>>);
+
+impl Drop for NestingGuard {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() - 1);
+    }
 }
 
 impl<R> Cursor<R>
@@ -34,7 +54,154 @@ where
     pub(super) fn new(reader: R) -> Self {
         Self {
             buffered_lexer: Lexer::new(reader).into(),
+            depth: Rc::default(),
+            recursion_limit: DEFAULT_RECURSION_LIMIT,
+            diagnostics: None,
+        }
+    }
+
+    /// Sets the maximum nesting depth of the expressions and statements.
+    #[inline]
+    pub(super) fn set_recursion_limit(&mut self, limit: usize) {
+        self.recursion_limit = limit;
+    }
+
+    /// Makes the parser recover from syntax errors, collecting them instead of failing.
+    #[inline]
+    pub(super) fn set_error_recovery(&mut self, recover: bool) {
+        if !recover {
+            self.diagnostics = None;
+        } else if self.diagnostics.is_none() {
+            self.diagnostics = Some(Vec::new());
+        }
+    }
+
+    /// Takes the errors the parser recovered from so far.
+    #[inline]
+    pub(super) fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        self.diagnostics
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
+    /// Records an error that doesn't prevent the parser from continuing, if the parser recovers
+    /// from errors, or returns it otherwise.
+    pub(super) fn report(&mut self, error: ParseError, span: Span) -> Result<(), ParseError> {
+        match &mut self.diagnostics {
+            Some(diagnostics) => {
+                diagnostics.push(Diagnostic::new(error, span));
+                Ok(())
+            }
+            None => Err(error),
+        }
+    }
+
+    /// Recovers from an error in a statement starting at `start`, if the parser recovers from
+    /// errors, or returns the error otherwise.
+    ///
+    /// The tokens up to the end of the statement are skipped. The statement ends with a `;`, or
+    /// before one of the `break_nodes` ending the enclosing statement list, or before a keyword
+    /// or an identifier starting a new line. Returns the span of the skipped statement.
+    pub(super) fn recover(
+        &mut self,
+        error: ParseError,
+        start: Position,
+        break_nodes: &[TokenKind],
+        interner: &mut Interner,
+    ) -> Result<Span, ParseError> {
+        if self.diagnostics.is_none() {
+            return Err(error);
+        }
+
+        // The brackets opened while skipping, `true` standing for template substitutions.
+        let mut brackets = Vec::new();
+        // Whether a part of the statement was consumed, either by the parser or by the lexer
+        // skipping invalid input.
+        let mut skipped = self.last_end() > start || matches!(error, ParseError::Lex { .. });
+        let mut new_line = false;
+        loop {
+            let (kind, token_start) = match self.buffered_lexer.peek(0, false, interner) {
+                Ok(Some(token)) => (token.kind().clone(), token.span().start()),
+                // The lexer moves past invalid input, so the tokens after it can be read.
+                Err(ParseError::Lex {
+                    err: LexError::Syntax(..),
+                }) => {
+                    skipped = true;
+                    continue;
+                }
+                Ok(None) | Err(_) => break,
+            };
+            let nested = !brackets.is_empty();
+            match kind {
+                TokenKind::LineTerminator => {
+                    new_line = true;
+                    self.buffered_lexer.next(false, interner)?;
+                    continue;
+                }
+                TokenKind::Punctuator(Punctuator::Semicolon) if !nested => {
+                    self.buffered_lexer.next(false, interner)?;
+                    break;
+                }
+                _ if !nested && break_nodes.contains(&kind) => break,
+                TokenKind::Keyword(_) | TokenKind::Identifier(_)
+                    if !nested && new_line && skipped =>
+                {
+                    break
+                }
+                TokenKind::Punctuator(
+                    Punctuator::OpenBlock | Punctuator::OpenParen | Punctuator::OpenBracket,
+                ) => brackets.push(false),
+                TokenKind::TemplateMiddle(_) => brackets.push(true),
+                TokenKind::Punctuator(
+                    Punctuator::CloseBlock | Punctuator::CloseParen | Punctuator::CloseBracket,
+                ) => {
+                    if brackets.pop() == Some(true) {
+                        // The `}` ends a template substitution, and the template continues after
+                        // it.
+                        self.buffered_lexer.next(false, interner)?;
+                        if let Ok(token) = self.buffered_lexer.lex_template(token_start, interner) {
+                            if matches!(token.kind(), TokenKind::TemplateMiddle(_)) {
+                                brackets.push(true);
+                            }
+                        }
+                        skipped = true;
+                        new_line = false;
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+            self.buffered_lexer.next(false, interner)?;
+            skipped = true;
+            new_line = false;
+        }
+
+        let span = Span::new(start, self.last_end().max(start));
+        self.report(error, span)?;
+        Ok(span)
+    }
+
+    /// Enters a nested expression or statement, or returns an error if the source is nested too
+    /// deeply.
+    pub(super) fn enter_nested(
+        &mut self,
+        interner: &mut Interner,
+    ) -> Result<NestingGuard, ParseError> {
+        let depth = self.depth.get();
+        if depth >= self.recursion_limit {
+            let position = self
+                .peek(0, interner)?
+                .ok_or(ParseError::AbruptEnd)?
+                .span()
+                .start();
+            return Err(ParseError::general(
+                "maximum nesting depth exceeded",
+                position,
+            ));
         }
+        self.depth.set(depth + 1);
+        Ok(NestingGuard(self.depth.clone()))
     }
 
     #[inline]
@@ -60,6 +227,12 @@ where
         self.buffered_lexer.lex_template(start, interner)
     }
 
+    /// Gets the end of the last token consumed by the cursor.
+    #[inline]
+    pub(super) fn last_end(&self) -> Position {
+        self.buffered_lexer.last_end()
+    }
+
     #[inline]
     pub(super) fn next(&mut self, interner: &mut Interner) -> Result<Option<Token>, ParseError> {
         self.buffered_lexer.next(true, interner)
@@ -84,6 +257,21 @@ where
         self.buffered_lexer.set_strict_mode(strict_mode);
     }
 
+    #[inline]
+    pub(super) fn set_collect_comments(&mut self, collect: bool) {
+        self.buffered_lexer.set_collect_comments(collect);
+    }
+
+    #[inline]
+    pub(super) fn comments(&self) -> &[Comment] {
+        self.buffered_lexer.comments()
+    }
+
+    #[inline]
+    pub(super) fn comments_mut(&mut self) -> &mut [Comment] {
+        self.buffered_lexer.comments_mut()
+    }
+
     /// Returns an error if the next token is not of kind `kind`.
     #[inline]
     pub(super) fn expect<K>(
@@ -187,6 +375,32 @@ where
         }
     }
 
+    /// Checks that the next `skip_n + 1` tokens are all on the same line.
+    ///
+    /// Line terminators before the next token are ignored, so this returns `true` when no line
+    /// terminator separates the peeked token from the `skip_n` tokens after it.
+    #[inline]
+    pub(super) fn peek_is_same_line(
+        &mut self,
+        skip_n: usize,
+        interner: &mut Interner,
+    ) -> Result<bool, ParseError> {
+        let offset = match self.buffered_lexer.peek(0, false, interner)? {
+            Some(t) if t.kind() == &TokenKind::LineTerminator => 1,
+            Some(_) => 0,
+            None => return Ok(false),
+        };
+
+        for n in 1..=skip_n {
+            match self.buffered_lexer.peek(offset + n, false, interner)? {
+                Some(t) if t.kind() != &TokenKind::LineTerminator => {}
+                _ => return Ok(false),
+            }
+        }
+
+        Ok(true)
+    }
+
     /// Advance the cursor to the next token and retrieve it, only if it's of `kind` type.
     ///
     /// When the next token is a `kind` token, get the token, otherwise return `None`.
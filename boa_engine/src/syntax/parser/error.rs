@@ -122,6 +122,47 @@ impl ParseError {
     }
 }
 
+/// A syntax error the parser recovered from.
+///
+/// Diagnostics are returned by [`Parser::parse_all_with_recovery`](super::Parser::parse_all_with_recovery).
+#[derive(Debug)]
+pub struct Diagnostic {
+    error: ParseError,
+    span: Span,
+}
+
+impl Diagnostic {
+    /// Creates a new diagnostic for an error in the given span of the source.
+    #[inline]
+    pub(super) fn new(error: ParseError, span: Span) -> Self {
+        Self { error, span }
+    }
+
+    /// Gets the error.
+    #[inline]
+    pub fn error(&self) -> &ParseError {
+        &self.error
+    }
+
+    /// Gets the span of the source the parser skipped to recover from the error.
+    #[inline]
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Converts the diagnostic into its error.
+    #[inline]
+    pub fn into_error(self) -> ParseError {
+        self.error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -14,7 +14,7 @@ use crate::syntax::{
             declaration::Declaration, ArrowFunctionDecl, FormalParameter, FormalParameterList,
             FormalParameterListFlags, Node, Return, StatementList,
         },
-        Position, Punctuator,
+        Position, Punctuator, Span,
     },
     lexer::{Error as LexError, TokenKind},
     parser::{
@@ -198,22 +198,22 @@ where
         cursor: &mut Cursor<R>,
         interner: &mut Interner,
     ) -> Result<Self::Output, ParseError> {
-        match cursor
-            .peek(0, interner)?
-            .ok_or(ParseError::AbruptEnd)?
-            .kind()
-        {
+        let token = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+        match token.kind() {
             TokenKind::Punctuator(Punctuator::OpenBlock) => {
                 let _next = cursor.next(interner)?;
                 let body = FunctionBody::new(false, false).parse(cursor, interner)?;
                 cursor.expect(Punctuator::CloseBlock, "arrow function", interner)?;
                 Ok(body)
             }
-            _ => Ok(StatementList::from(vec![Return::new(
-                ExpressionBody::new(self.allow_in, false).parse(cursor, interner)?,
-                None,
-            )
-            .into()])),
+            _ => {
+                let start = token.span().start();
+                let expr = ExpressionBody::new(self.allow_in, false).parse(cursor, interner)?;
+                Ok(StatementList::with_spans(
+                    vec![Return::new(expr, None).into()],
+                    vec![Span::new(start, cursor.last_end())],
+                ))
+            }
         }
     }
 }
@@ -89,6 +89,7 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult {
         let _timer = Profiler::global().start_event("AssignmentExpression", "Parsing");
+        let _nesting = cursor.enter_nested(interner)?;
         cursor.set_goal(InputElement::Div);
 
         match cursor
@@ -14,7 +14,7 @@ use crate::syntax::{
             field::{GetConstField, GetField},
             Call, Node,
         },
-        Punctuator,
+        Position, Punctuator, Span,
     },
     lexer::TokenKind,
     parser::{
@@ -36,12 +36,18 @@ use std::io::Read;
 pub(super) struct CallExpression {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
+    start: Position,
     first_member_expr: Node,
 }
 
 impl CallExpression {
-    /// Creates a new `CallExpression` parser.
-    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A, first_member_expr: Node) -> Self
+    /// Creates a new `CallExpression` parser, for the call expression starting at `start`.
+    pub(super) fn new<Y, A>(
+        allow_yield: Y,
+        allow_await: A,
+        start: Position,
+        first_member_expr: Node,
+    ) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -49,6 +55,7 @@ impl CallExpression {
         Self {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
+            start,
             first_member_expr,
         }
     }
@@ -68,7 +75,9 @@ where
         let mut lhs = if token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
             let args =
                 Arguments::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
-            Node::from(Call::new(self.first_member_expr, args))
+            let mut call = Call::new(self.first_member_expr, args);
+            call.set_span(Span::new(self.start, cursor.last_end()));
+            Node::from(call)
         } else {
             let next_token = cursor.next(interner)?.expect("token vanished");
             return Err(ParseError::expected(
@@ -85,7 +94,9 @@ where
                 TokenKind::Punctuator(Punctuator::OpenParen) => {
                     let args = Arguments::new(self.allow_yield, self.allow_await)
                         .parse(cursor, interner)?;
-                    lhs = Node::from(Call::new(lhs, args));
+                    let mut call = Call::new(lhs, args);
+                    call.set_span(Span::new(self.start, cursor.last_end()));
+                    lhs = Node::from(call);
                 }
                 TokenKind::Punctuator(Punctuator::Dot) => {
                     cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?; // We move the parser forward.
@@ -12,7 +12,7 @@ use crate::syntax::{
             field::{GetConstField, GetField},
             Call, New, Node,
         },
-        Keyword, Punctuator,
+        Keyword, Punctuator, Span,
     },
     lexer::TokenKind,
     parser::{
@@ -64,12 +64,9 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult {
         let _timer = Profiler::global().start_event("MemberExpression", "Parsing");
 
-        let mut lhs = if cursor
-            .peek(0, interner)?
-            .ok_or(ParseError::AbruptEnd)?
-            .kind()
-            == &TokenKind::Keyword(Keyword::New)
-        {
+        let token = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+        let mut lhs = if token.kind() == &TokenKind::Keyword(Keyword::New) {
+            let start = token.span().start();
             let _next = cursor.next(interner).expect("new keyword disappeared");
             let lhs = self.parse(cursor, interner)?;
             let args = match cursor.peek(0, interner)? {
@@ -78,7 +75,8 @@ where
                 }
                 _ => Box::new([]),
             };
-            let call_node = Call::new(lhs, args);
+            let mut call_node = Call::new(lhs, args);
+            call_node.set_span(Span::new(start, cursor.last_end()));
 
             Node::from(New::from(call_node))
         } else {
@@ -16,7 +16,7 @@ use self::{call::CallExpression, member::MemberExpression};
 use crate::syntax::{
     ast::{Node, Punctuator},
     lexer::{InputElement, TokenKind},
-    parser::{AllowAwait, AllowYield, Cursor, ParseResult, TokenParser},
+    parser::{AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser},
 };
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
@@ -64,12 +64,17 @@ where
 
         cursor.set_goal(InputElement::TemplateTail);
 
+        let start = cursor
+            .peek(0, interner)?
+            .ok_or(ParseError::AbruptEnd)?
+            .span()
+            .start();
         // TODO: Implement NewExpression: new MemberExpression
         let lhs = MemberExpression::new(self.name, self.allow_yield, self.allow_await)
             .parse(cursor, interner)?;
         if let Some(tok) = cursor.peek(0, interner)? {
             if tok.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
-                return CallExpression::new(self.allow_yield, self.allow_await, lhs)
+                return CallExpression::new(self.allow_yield, self.allow_await, start, lhs)
                     .parse(cursor, interner);
             }
         }
@@ -1,5 +1,6 @@
 //! Boa parser implementation.
 
+mod comment;
 mod cursor;
 pub mod error;
 mod expression;
@@ -8,10 +9,13 @@ mod statement;
 #[cfg(test)]
 mod tests;
 
-pub use self::error::{ParseError, ParseResult};
+pub use self::error::{Diagnostic, ParseError, ParseResult};
 
-use self::cursor::Cursor;
-use crate::syntax::{ast::node::StatementList, lexer::TokenKind};
+use self::{comment::attach_comments, cursor::Cursor};
+use crate::syntax::{
+    ast::{node::StatementList, Position, Span},
+    lexer::{Comment, TokenKind},
+};
 use boa_interner::Interner;
 use std::io::Read;
 
@@ -102,11 +106,78 @@ impl<R> Parser<R> {
         Self { cursor }
     }
 
+    /// Sets the maximum nesting depth of the expressions and statements of the source, beyond
+    /// which parsing fails instead of overflowing the native stack.
+    #[inline]
+    pub fn set_recursion_limit(&mut self, limit: usize)
+    where
+        R: Read,
+    {
+        self.cursor.set_recursion_limit(limit);
+    }
+
+    /// Sets whether the parser collects the comments of the source, instead of discarding them.
+    ///
+    /// After parsing, the collected comments are available from [`Parser::comments`], attached
+    /// to the statements they document.
+    #[inline]
+    pub fn set_collect_comments(&mut self, collect: bool)
+    where
+        R: Read,
+    {
+        self.cursor.set_collect_comments(collect);
+    }
+
+    /// Gets the comments collected while parsing, in source order.
+    ///
+    /// This is empty unless comments are collected with [`Parser::set_collect_comments`].
+    #[inline]
+    pub fn comments(&self) -> &[Comment]
+    where
+        R: Read,
+    {
+        self.cursor.comments()
+    }
+
     pub fn parse_all(&mut self, interner: &mut Interner) -> Result<StatementList, ParseError>
     where
         R: Read,
     {
-        Script.parse(&mut self.cursor, interner)
+        let statement_list = Script.parse(&mut self.cursor, interner)?;
+        attach_comments(self.cursor.comments_mut(), &statement_list);
+        Ok(statement_list)
+    }
+
+    /// Parses the full script, recovering from syntax errors instead of stopping at the first
+    /// one.
+    ///
+    /// A statement that fails to parse is skipped up to its end, which is the next `;`, the end
+    /// of the enclosing block, or a line starting with a keyword or an identifier. It is replaced
+    /// with [`Node::Error`](crate::syntax::ast::Node::Error) in the returned tree, and its error
+    /// is returned with the span of the skipped source, in source order.
+    pub fn parse_all_with_recovery(
+        &mut self,
+        interner: &mut Interner,
+    ) -> (StatementList, Vec<Diagnostic>)
+    where
+        R: Read,
+    {
+        self.cursor.set_error_recovery(true);
+        let result = Script.parse(&mut self.cursor, interner);
+        let mut diagnostics = self.cursor.take_diagnostics();
+        self.cursor.set_error_recovery(false);
+
+        let statement_list = match result {
+            Ok(statement_list) => statement_list,
+            Err(error) => {
+                let span = Span::new(Position::new(1, 1), self.cursor.last_end());
+                diagnostics.push(Diagnostic::new(error, span));
+                StatementList::from(Vec::new())
+            }
+        };
+        diagnostics.sort_by_key(|diagnostic| diagnostic.span().start());
+        attach_comments(self.cursor.comments_mut(), &statement_list);
+        (statement_list, diagnostics)
     }
 }
 
@@ -1,11 +1,13 @@
 //! Lexical declaration parsing.
 //!
-//! This parses `let` and `const` declarations.
+//! This parses `let`, `const`, `using` and `await using` declarations.
 //!
 //! More information:
 //!  - [ECMAScript specification][spec]
+//!  - [Explicit resource management proposal][using]
 //!
 //! [spec]: https://tc39.es/ecma262/#sec-let-and-const-declarations
+//! [using]: https://tc39.es/proposal-explicit-resource-management/#sec-let-const-using-and-await-using-declarations
 
 use crate::syntax::{
     ast::{
@@ -15,7 +17,7 @@ use crate::syntax::{
         },
         Keyword, Punctuator,
     },
-    lexer::TokenKind,
+    lexer::{Token, TokenKind},
     parser::{
         cursor::{Cursor, SemicolonResult},
         expression::Initializer,
@@ -95,6 +97,134 @@ where
     }
 }
 
+/// Checks if the token peeked after skipping `skip_n` tokens starts a `using` declaration.
+///
+/// `using` is not a reserved word, so it only starts a declaration when it is followed by a
+/// binding identifier on the same line.
+pub(in crate::syntax::parser) fn is_using_declaration<R>(
+    cursor: &mut Cursor<R>,
+    skip_n: usize,
+    interner: &mut Interner,
+) -> Result<bool, ParseError>
+where
+    R: Read,
+{
+    match cursor.peek(skip_n, interner)?.map(Token::kind) {
+        Some(TokenKind::Identifier(sym)) if interner.resolve_expect(*sym) == "using" => {}
+        _ => return Ok(false),
+    }
+
+    if !cursor.peek_is_same_line(skip_n + 1, interner)? {
+        return Ok(false);
+    }
+
+    Ok(matches!(
+        cursor.peek(skip_n + 1, interner)?.map(Token::kind),
+        Some(TokenKind::Identifier(_))
+    ))
+}
+
+/// Parses a `using` or an `await using` declaration.
+///
+/// Every binding must be an identifier with an initializer.
+///
+/// More information:
+///  - [Explicit resource management proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#prod-UsingDeclaration
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct UsingDeclaration {
+    allow_in: AllowIn,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    is_await: bool,
+}
+
+impl UsingDeclaration {
+    /// Creates a new `UsingDeclaration` parser.
+    pub(in crate::syntax::parser) fn new<I, Y, A>(
+        allow_in: I,
+        allow_yield: Y,
+        allow_await: A,
+        is_await: bool,
+    ) -> Self
+    where
+        I: Into<AllowIn>,
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_in: allow_in.into(),
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            is_await,
+        }
+    }
+}
+
+impl<R> TokenParser<R> for UsingDeclaration
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult {
+        let _timer = Profiler::global().start_event("UsingDeclaration", "Parsing");
+
+        if self.is_await {
+            cursor.expect(Keyword::Await, "await using declaration", interner)?;
+        }
+        let _using = cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?;
+
+        let mut decls = Vec::new();
+        loop {
+            let ident = BindingIdentifier::new(self.allow_yield, self.allow_await)
+                .parse(cursor, interner)?;
+
+            let next = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+            if *next.kind() != TokenKind::Punctuator(Punctuator::Assign) {
+                return Err(ParseError::general(
+                    "Expected initializer for using declaration",
+                    next.span().start(),
+                ));
+            }
+            let init = Initializer::new(
+                Some(ident),
+                self.allow_in,
+                self.allow_yield,
+                self.allow_await,
+            )
+            .parse(cursor, interner)?;
+            decls.push(Declaration::new_with_identifier(ident, Some(init)));
+
+            match cursor.peek_semicolon(interner)? {
+                SemicolonResult::Found(_) => break,
+                SemicolonResult::NotFound(tk)
+                    if tk.kind() == &TokenKind::Punctuator(Punctuator::Comma) =>
+                {
+                    // We discard the comma
+                    let _comma = cursor.next(interner)?;
+                }
+                SemicolonResult::NotFound(_) => {
+                    let next = cursor.next(interner)?.ok_or(ParseError::AbruptEnd)?;
+                    return Err(ParseError::expected(
+                        [";".to_owned(), "line terminator".to_owned()],
+                        next.to_string(interner),
+                        next.span(),
+                        "using declaration binding list",
+                    ));
+                }
+            }
+        }
+
+        if self.is_await {
+            Ok(DeclarationList::AwaitUsing(decls.into()).into())
+        } else {
+            Ok(DeclarationList::Using(decls.into()).into())
+        }
+    }
+}
+
 /// Parses a binding list.
 ///
 /// It will return an error if a `const` declaration is being parsed and there is no
@@ -12,6 +12,8 @@ mod lexical;
 #[cfg(test)]
 mod tests;
 
+pub(super) use self::lexical::{is_using_declaration, UsingDeclaration};
+
 use self::{hoistable::HoistableDeclaration, lexical::LexicalDeclaration};
 use crate::syntax::{
     ast::{Keyword, Node},
@@ -1,6 +1,6 @@
 use crate::syntax::{
     ast::{
-        node::{Declaration, DeclarationList, Node},
+        node::{Block, Declaration, DeclarationList, Identifier, Node},
         Const,
     },
     parser::tests::{check_invalid, check_parser},
@@ -326,3 +326,53 @@ fn multiple_const_declaration() {
         &mut interner,
     );
 }
+
+/// Checks `using` declaration parsing inside a block.
+#[test]
+fn using_declaration() {
+    let mut interner = Interner::default();
+    check_parser(
+        "{ using a = b, c = d; }",
+        vec![Block::from(vec![DeclarationList::Using(
+            vec![
+                Declaration::new_with_identifier(
+                    interner.get_or_intern_static("a"),
+                    Node::from(Identifier::new(interner.get_or_intern_static("b"))),
+                ),
+                Declaration::new_with_identifier(
+                    interner.get_or_intern_static("c"),
+                    Node::from(Identifier::new(interner.get_or_intern_static("d"))),
+                ),
+            ]
+            .into(),
+        )
+        .into()])
+        .into()],
+        &mut interner,
+    );
+}
+
+/// Checks that `using` declarations are not allowed outside blocks or without an initializer.
+#[test]
+fn invalid_using_declaration() {
+    check_invalid("using a = b;");
+    check_invalid("{ using a; }");
+}
+
+/// Checks that `using` is still a valid identifier where it does not start a declaration.
+#[test]
+fn using_as_identifier() {
+    let mut interner = Interner::default();
+    check_parser(
+        "let using = 5;",
+        vec![DeclarationList::Let(
+            vec![Declaration::new_with_identifier(
+                interner.get_or_intern_static("using"),
+                Some(Const::from(5).into()),
+            )]
+            .into(),
+        )
+        .into()],
+        &mut interner,
+    );
+}
@@ -25,7 +25,7 @@ use self::{
     block::BlockStatement,
     break_stm::BreakStatement,
     continue_stm::ContinueStatement,
-    declaration::Declaration,
+    declaration::{is_using_declaration, Declaration, UsingDeclaration},
     expression::ExpressionStatement,
     if_stm::IfStatement,
     iteration::{DoWhileStatement, ForStatement, WhileStatement},
@@ -46,9 +46,9 @@ use crate::syntax::{
                 DeclarationPatternArray, DeclarationPatternObject,
             },
         },
-        Keyword, Node, Position, Punctuator,
+        Keyword, Node, Position, Punctuator, Span,
     },
-    lexer::{Error as LexError, InputElement, TokenKind},
+    lexer::{Error as LexError, InputElement, Token, TokenKind},
     parser::expression::{await_expr::AwaitExpression, Initializer},
 };
 use boa_interner::{Interner, Sym};
@@ -116,6 +116,7 @@ where
         interner: &mut Interner,
     ) -> Result<Self::Output, ParseError> {
         let _timer = Profiler::global().start_event("Statement", "Parsing");
+        let _nesting = cursor.enter_nested(interner)?;
         // TODO: add BreakableStatement and divide Whiles, fors and so on to another place.
         let tok = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
 
@@ -260,6 +261,41 @@ impl StatementList {
             break_nodes,
         }
     }
+
+    /// Parses the next item of the list, after skipping the semicolons following the previous
+    /// item, if any. `start` is set to the start of the item once it is known.
+    ///
+    /// Returns the item and its span, or `None` at the end of the list.
+    fn parse_item<R>(
+        &self,
+        after_item: bool,
+        start: &mut Position,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> Result<Option<(Node, Span)>, ParseError>
+    where
+        R: Read,
+    {
+        if after_item {
+            // move the cursor forward for any consecutive semicolon.
+            while cursor.next_if(Punctuator::Semicolon, interner)?.is_some() {}
+        }
+
+        *start = match cursor.peek(0, interner)? {
+            Some(token) if self.break_nodes.contains(token.kind()) => return Ok(None),
+            None => return Ok(None),
+            Some(token) => token.span().start(),
+        };
+
+        let item = StatementListItem::new(
+            self.allow_yield,
+            self.allow_await,
+            self.allow_return,
+            self.in_block,
+        )
+        .parse(cursor, interner)?;
+        Ok(Some((item, Span::new(*start, cursor.last_end()))))
+    }
 }
 
 impl<R> TokenParser<R> for StatementList
@@ -278,6 +314,9 @@ where
     ///
     /// Note that the last token which causes the parse to finish is not
     /// consumed.
+    ///
+    /// If the parser recovers from errors, the items that fail to parse are replaced with
+    /// `Node::Error` instead.
     fn parse(
         self,
         cursor: &mut Cursor<R>,
@@ -285,25 +324,29 @@ where
     ) -> Result<Self::Output, ParseError> {
         let _timer = Profiler::global().start_event("StatementList", "Parsing");
         let mut items = Vec::new();
+        let mut spans = Vec::new();
 
         loop {
-            match cursor.peek(0, interner)? {
-                Some(token) if self.break_nodes.contains(token.kind()) => break,
-                None => break,
-                _ => {}
+            // The start of the item, until the item is peeked.
+            let mut start = cursor.last_end();
+            match self.parse_item(!items.is_empty(), &mut start, cursor, interner) {
+                Ok(Some((item, span))) => {
+                    items.push(item);
+                    spans.push(span);
+                }
+                Ok(None) => break,
+                // The source can't be read further.
+                Err(
+                    error @ ParseError::Lex {
+                        err: LexError::IO(_),
+                    },
+                ) => return Err(error),
+                Err(error) => {
+                    let span = cursor.recover(error, start, self.break_nodes, interner)?;
+                    items.push(Node::Error);
+                    spans.push(span);
+                }
             }
-
-            let item = StatementListItem::new(
-                self.allow_yield,
-                self.allow_await,
-                self.allow_return,
-                self.in_block,
-            )
-            .parse(cursor, interner)?;
-            items.push(item);
-
-            // move the cursor forward for any consecutive semicolon.
-            while cursor.next_if(Punctuator::Semicolon, interner)?.is_some() {}
         }
 
         // Handle any redeclarations
@@ -313,9 +356,11 @@ where
             let mut var_declared_names: HashSet<Sym> = HashSet::new();
 
             // TODO: Use more helpful positions in errors when spans are added to Nodes
-            for item in &items {
+            for (item, span) in items.iter().zip(&spans) {
                 match item {
-                    Node::LetDeclList(decl_list) | Node::ConstDeclList(decl_list) => {
+                    Node::LetDeclList(decl_list)
+                    | Node::ConstDeclList(decl_list)
+                    | Node::UsingDeclList(decl_list) => {
                         for decl in decl_list.as_ref() {
                             // if name in VarDeclaredNames or can't be added to
                             // LexicallyDeclaredNames, raise an error
@@ -324,7 +369,7 @@ where
                                     if var_declared_names.contains(&ident.sym())
                                         || !lexically_declared_names.insert(ident.sym())
                                     {
-                                        return Err(ParseError::lex(LexError::Syntax(
+                                        let error = ParseError::lex(LexError::Syntax(
                                             format!(
                                                 "Redeclaration of variable `{}`",
                                                 interner.resolve_expect(ident.sym())
@@ -334,7 +379,8 @@ where
                                                 Some(token) => token.span().end(),
                                                 None => Position::new(1, 1),
                                             },
-                                        )));
+                                        ));
+                                        cursor.report(error, *span)?;
                                     }
                                 }
                                 node::Declaration::Pattern(p) => {
@@ -342,7 +388,7 @@ where
                                         if var_declared_names.contains(&ident)
                                             || !lexically_declared_names.insert(ident)
                                         {
-                                            return Err(ParseError::lex(LexError::Syntax(
+                                            let error = ParseError::lex(LexError::Syntax(
                                                 format!(
                                                     "Redeclaration of variable `{}`",
                                                     interner.resolve_expect(ident)
@@ -352,7 +398,8 @@ where
                                                     Some(token) => token.span().end(),
                                                     None => Position::new(1, 1),
                                                 },
-                                            )));
+                                            ));
+                                            cursor.report(error, *span)?;
                                         }
                                     }
                                 }
@@ -365,7 +412,7 @@ where
                                 node::Declaration::Identifier { ident, .. } => {
                                     // if name in LexicallyDeclaredNames, raise an error
                                     if lexically_declared_names.contains(&ident.sym()) {
-                                        return Err(ParseError::lex(LexError::Syntax(
+                                        let error = ParseError::lex(LexError::Syntax(
                                             format!(
                                                 "Redeclaration of variable `{}`",
                                                 interner.resolve_expect(ident.sym())
@@ -375,7 +422,8 @@ where
                                                 Some(token) => token.span().end(),
                                                 None => Position::new(1, 1),
                                             },
-                                        )));
+                                        ));
+                                        cursor.report(error, *span)?;
                                     }
                                     // otherwise, add to VarDeclaredNames
                                     var_declared_names.insert(ident.sym());
@@ -384,7 +432,7 @@ where
                                     for ident in p.idents() {
                                         // if name in LexicallyDeclaredNames, raise an error
                                         if lexically_declared_names.contains(&ident) {
-                                            return Err(ParseError::lex(LexError::Syntax(
+                                            let error = ParseError::lex(LexError::Syntax(
                                                 format!(
                                                     "Redeclaration of variable `{}`",
                                                     interner.resolve_expect(ident)
@@ -394,7 +442,8 @@ where
                                                     Some(token) => token.span().end(),
                                                     None => Position::new(1, 1),
                                                 },
-                                            )));
+                                            ));
+                                            cursor.report(error, *span)?;
                                         }
                                         // otherwise, add to VarDeclaredNames
                                         var_declared_names.insert(ident);
@@ -408,9 +457,11 @@ where
             }
         }
 
-        items.sort_by(Node::hoistable_order);
+        let mut spanned: Vec<_> = items.into_iter().zip(spans).collect();
+        spanned.sort_by(|(a, _), (b, _)| Node::hoistable_order(a, b));
+        let (items, spans): (Vec<_>, Vec<_>) = spanned.into_iter().unzip();
 
-        Ok(items.into())
+        Ok(node::StatementList::with_spans(items, spans))
     }
 }
 
@@ -462,6 +513,26 @@ where
     ) -> Result<Self::Output, ParseError> {
         let _timer = Profiler::global().start_event("StatementListItem", "Parsing");
         let strict_mode = cursor.strict_mode();
+
+        let is_await_using = self.allow_await.0
+            && matches!(
+                cursor.peek(0, interner)?.map(Token::kind),
+                Some(TokenKind::Keyword(Keyword::Await))
+            )
+            && cursor.peek_is_same_line(1, interner)?
+            && is_using_declaration(cursor, 1, interner)?;
+        if is_await_using || is_using_declaration(cursor, 0, interner)? {
+            if !self.in_block {
+                let tok = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
+                return Err(ParseError::general(
+                    "using declarations are only allowed in blocks and function bodies",
+                    tok.span().start(),
+                ));
+            }
+            return UsingDeclaration::new(true, self.allow_yield, self.allow_await, is_await_using)
+                .parse(cursor, interner);
+        }
+
         let tok = cursor.peek(0, interner)?.ok_or(ParseError::AbruptEnd)?;
 
         match *tok.kind() {
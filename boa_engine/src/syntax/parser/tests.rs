@@ -1,15 +1,18 @@
 //! Tests for the parser.
 
 use super::Parser;
-use crate::syntax::ast::{
-    node::{
-        field::GetConstField, object::PropertyDefinition, ArrowFunctionDecl, Assign, BinOp, Call,
-        Declaration, DeclarationList, FormalParameter, FormalParameterList,
-        FormalParameterListFlags, FunctionDecl, Identifier, If, New, Node, Object, Return,
-        StatementList, UnaryOp,
+use crate::syntax::{
+    ast::{
+        node::{
+            field::GetConstField, object::PropertyDefinition, ArrowFunctionDecl, Assign, BinOp,
+            Call, Declaration, DeclarationList, FormalParameter, FormalParameterList,
+            FormalParameterListFlags, FunctionDecl, Identifier, If, New, Node, Object, Return,
+            StatementList, UnaryOp,
+        },
+        op::{self, CompOp, LogOp, NumOp},
+        Const, Position, Span,
     },
-    op::{self, CompOp, LogOp, NumOp},
-    Const,
+    lexer::{CommentAttachment, CommentKind},
 };
 use boa_interner::Interner;
 
@@ -481,3 +484,109 @@ fn hashbang_comment() {
     let mut interner = Interner::default();
     check_parser(r"#!Comment Here", vec![], &mut interner);
 }
+
+#[test]
+fn collect_comments() {
+    let source = "#!/usr/bin/env boa\n\
+        // Adds one.\n\
+        function inc(x) {\n\
+            return x + 1; /* trailing */\n\
+        }\n\
+        /* leading\n   the call */\n\
+        inc(1); // after the call\n\
+        {\n\
+            a;\n\
+            // dangling\n\
+        }\n";
+
+    let mut interner = Interner::default();
+    let mut parser = Parser::new(source.as_bytes(), false);
+    parser.set_collect_comments(true);
+    let script = parser.parse_all(&mut interner).expect("failed to parse");
+
+    let function = script.span(0).expect("missing span");
+    let call = script.span(1).expect("missing span");
+    let ret = match &script.items()[0] {
+        Node::FunctionDecl(function) => function.body().span(0).expect("missing span"),
+        node => panic!("expected a function declaration, got {node:?}"),
+    };
+    let comments: Vec<_> = parser
+        .comments()
+        .iter()
+        .map(|comment| (comment.kind(), comment.text(), comment.attachment()))
+        .collect();
+    assert_eq!(
+        comments,
+        [
+            (
+                CommentKind::Hashbang,
+                "/usr/bin/env boa",
+                Some(CommentAttachment::Leading(function))
+            ),
+            (
+                CommentKind::SingleLine,
+                " Adds one.",
+                Some(CommentAttachment::Leading(function))
+            ),
+            (
+                CommentKind::MultiLine,
+                " trailing ",
+                Some(CommentAttachment::Trailing(ret))
+            ),
+            (
+                CommentKind::MultiLine,
+                " leading\n   the call ",
+                Some(CommentAttachment::Leading(call))
+            ),
+            (
+                CommentKind::SingleLine,
+                " after the call",
+                Some(CommentAttachment::Trailing(call))
+            ),
+            (CommentKind::SingleLine, " dangling", None),
+        ]
+    );
+
+    let mut parser = Parser::new(source.as_bytes(), false);
+    parser.parse_all(&mut interner).expect("failed to parse");
+    assert!(parser.comments().is_empty());
+}
+
+#[test]
+fn recover_from_errors() {
+    let source = "let a = ;\n\
+        function f() {\n\
+            return 1 +;\n\
+            g();\n\
+        }\n\
+        let b = 2 @;\n\
+        c();\n";
+
+    let mut interner = Interner::default();
+    assert!(Parser::new(source.as_bytes(), false)
+        .parse_all(&mut interner)
+        .is_err());
+
+    let (script, diagnostics) =
+        Parser::new(source.as_bytes(), false).parse_all_with_recovery(&mut interner);
+    let lines: Vec<_> = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.span().start().line_number())
+        .collect();
+    assert_eq!(lines, [1, 3, 6]);
+    assert_eq!(
+        diagnostics[0].span(),
+        Span::new(Position::new(1, 1), Position::new(1, 10))
+    );
+
+    // The function declaration is hoisted.
+    match script.items() {
+        [Node::FunctionDecl(function), Node::Error, Node::Error, Node::Call(_)] => {
+            assert!(matches!(
+                function.body().items(),
+                [Node::Error, Node::Call(_)]
+            ));
+        }
+        items => panic!("unexpected items {items:?}"),
+    }
+}
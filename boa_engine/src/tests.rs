@@ -1650,3 +1650,241 @@ fn test_empty_statement() {
     "#;
     assert_eq!(&exec(src), "10");
 }
+
+#[test]
+fn native_class_members() {
+    use crate::{
+        builtins::{Array, JsArgs},
+        class::{Class, ClassBuilder},
+        property::Attribute,
+        symbol::WellKnownSymbols,
+        JsResult,
+    };
+    use boa_gc::{Finalize, Trace};
+
+    #[derive(Debug, Trace, Finalize)]
+    struct Counter {
+        count: i32,
+    }
+
+    impl Class for Counter {
+        const NAME: &'static str = "Counter";
+        const LENGTH: usize = 1;
+
+        fn constructor(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<Self> {
+            let count = args.get_or_undefined(0).to_i32(context)?;
+            Ok(Self { count })
+        }
+
+        fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+            let base = class.context().eval(
+                r#"
+                    function Base() {}
+                    Base.prototype.describe = function () {
+                        return `count: ${this.count}`;
+                    };
+                    Base;
+                "#,
+            )?;
+            let base = base.as_object().expect("a function is an object").clone();
+            class.superclass(&base)?;
+
+            class.native_accessor(
+                "count",
+                Some(|this, _, context| Ok(Counter::from_this(this, context)?.count.into())),
+                Some(|this, args, context| {
+                    let count = args.get_or_undefined(0).to_i32(context)?;
+                    Counter::from_this_mut(this, context)?.count = count;
+                    Ok(JsValue::undefined())
+                }),
+                Attribute::CONFIGURABLE,
+            );
+            class.static_native_accessor(
+                "kind",
+                Some(|_, _, _| Ok("counter".into())),
+                None,
+                Attribute::CONFIGURABLE,
+            );
+            class.method(
+                (WellKnownSymbols::iterator(), "[Symbol.iterator]"),
+                0,
+                |this, _, context| {
+                    let count = Counter::from_this(this, context)?.count;
+                    let array =
+                        Array::create_array_from_list((0..count).map(JsValue::new), context);
+                    Array::values(&array.into(), &[], context)
+                },
+            );
+            Ok(())
+        }
+    }
+
+    let mut context = Context::default();
+    context.register_global_class::<Counter>().unwrap();
+
+    assert_eq!(
+        forward(&mut context, "var c = new Counter(2); c.count"),
+        "2"
+    );
+    assert_eq!(forward(&mut context, "c.count = 3; c.count"), "3");
+    assert_eq!(forward(&mut context, "c.describe()"), "\"count: 3\"");
+    assert_eq!(forward(&mut context, "Counter.kind"), "\"counter\"");
+    assert_eq!(forward(&mut context, "[...c].join()"), "\"0,1,2\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(Counter.prototype, 'count').get.name"
+        ),
+        "\"get count\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(Counter).name + ',' + (c instanceof Object.getPrototypeOf(Counter))"
+        ),
+        "\"Base,true\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Counter.prototype.count; } catch (e) { e.message }"
+        ),
+        "\"'this' is not a Counter\""
+    );
+}
+
+#[test]
+fn realms() {
+    let mut context = Context::default();
+    let realm = context.create_realm();
+    forward(&mut context, "var x = 'main'; var mainArray = [];");
+
+    assert_eq!(
+        context.eval_in_realm(&realm, "var x = 'plugin'; x"),
+        Ok(JsValue::new("plugin"))
+    );
+    assert_eq!(forward(&mut context, "x"), "\"main\"");
+    assert_ne!(realm.global_object(), context.global_object());
+
+    // Each realm has its own intrinsics.
+    let array = context.eval_in_realm(&realm, "[]").unwrap();
+    context.register_global_property("pluginArray", array, crate::property::Attribute::all());
+    assert_eq!(
+        forward(&mut context, "pluginArray instanceof Array"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "Array.isArray(pluginArray)"), "true");
+
+    // The realms share the job queue.
+    assert_eq!(
+        context.eval_in_realm(
+            &realm,
+            "var log = []; Promise.resolve().then(() => log.push('job')); log.length"
+        ),
+        Ok(JsValue::new(0))
+    );
+    assert_eq!(
+        context.eval_in_realm(&realm, "log.join()"),
+        Ok(JsValue::new("job"))
+    );
+
+    // Realms can be entered again while they are running.
+    let main = context.current_realm();
+    let nested = context.with_realm(&realm, |context| {
+        context.with_realm(&main, |context| {
+            context.with_realm(&realm, |context| forward(context, "x"))
+        })
+    });
+    assert_eq!(nested, "\"plugin\"");
+    assert_eq!(context.current_realm(), main);
+    assert_eq!(forward(&mut context, "x"), "\"main\"");
+}
+
+#[test]
+fn context_pool() {
+    use crate::context::ContextPool;
+
+    let pool = ContextPool::new(1, Context::default);
+    assert_eq!(pool.threads(), 1);
+
+    // The worker keeps its context between tasks.
+    pool.execute(|context| forward(context, "var counter = 1;"))
+        .join()
+        .unwrap();
+    let counter = pool
+        .execute(|context| forward(context, "++counter"))
+        .join()
+        .unwrap();
+    assert_eq!(counter, "2");
+
+    // A panicking task doesn't stop the worker.
+    assert!(pool.execute(|_| panic!("task failure")).join().is_err());
+    let counter = pool
+        .execute(|context| forward(context, "counter"))
+        .join()
+        .unwrap();
+    assert_eq!(counter, "2");
+}
+
+#[test]
+fn disabled_builtins() {
+    use crate::context::Builtins;
+
+    let mut context = Context::builder()
+        .disable_builtins(Builtins::STRING_COMPILATION | Builtins::REGEXP)
+        .disable_builtins(Builtins::DATE | Builtins::ANNEX_B)
+        .build();
+    assert_eq!(
+        forward(
+            &mut context,
+            "[typeof RegExp, typeof Date, typeof Reflect, typeof ''.substr]"
+        ),
+        "[ \"undefined\", \"undefined\", \"object\", \"undefined\" ]"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Function('return 1'); } catch (e) { e.name }"
+        ),
+        "\"EvalError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { 'abc'.match('b'); } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+
+    // The builtins are also left out of new realms.
+    let realm = context.create_realm();
+    let has_date = context.with_realm(&realm, |context| forward(context, "typeof Date"));
+    assert_eq!(has_date, "\"undefined\"");
+}
+
+#[test]
+fn host_defined() {
+    #[derive(Debug, PartialEq)]
+    struct Counter(u32);
+
+    fn increment(_: &JsValue, _: &[JsValue], context: &mut Context) -> crate::JsResult<JsValue> {
+        let counter = context.host_defined_mut().get_or_insert_with(|| Counter(0));
+        counter.0 += 1;
+        Ok(counter.0.into())
+    }
+
+    let mut context = Context::default();
+    context.register_global_function("increment", 0, increment);
+    assert_eq!(forward(&mut context, "increment(); increment()"), "2");
+    assert_eq!(context.host_defined_mut().remove(), Some(Counter(2)));
+    assert!(!context.host_defined().has::<Counter>());
+
+    // Each realm has its own state.
+    context.realm_host_defined_mut().insert(Counter(1));
+    let realm = context.create_realm();
+    let in_realm = context.with_realm(&realm, |context| {
+        context.realm_host_defined().has::<Counter>()
+    });
+    assert!(!in_realm);
+    assert_eq!(context.realm_host_defined().get(), Some(&Counter(1)));
+}
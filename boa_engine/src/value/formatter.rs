@@ -0,0 +1,646 @@
+//! A configurable pretty-printer of values, producing the output of `console.log`.
+
+use super::JsValue;
+use crate::{
+    object::{JsObject, ObjectKind},
+    property::{PropertyDescriptor, PropertyKey},
+    Context, JsError,
+};
+
+/// How the [`ValueFormatter`] prints the properties defined by accessors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GetterPolicy {
+    /// Prints `[Getter]`, `[Setter]` or `[Getter/Setter]` without calling the getter.
+    Hide,
+    /// Calls the getter and prints its result, like `[Getter: 1]`.
+    ///
+    /// A getter that throws is printed as `[Getter: <Inspection threw>]`.
+    Evaluate,
+}
+
+impl Default for GetterPolicy {
+    #[inline]
+    fn default() -> Self {
+        Self::Hide
+    }
+}
+
+/// A pretty-printer of values, formatting them the way `console.log` shows them.
+///
+/// Objects print their enumerable own properties, arrays their elements, maps and sets their
+/// entries, and functions, regular expressions, dates and errors a short description. Nested
+/// objects deeper than [`depth`](Self::depth) are abbreviated, and objects containing themselves
+/// print `[Circular]` instead of recursing.
+///
+/// # Examples
+///
+/// ```
+/// use boa_engine::{value::ValueFormatter, Context};
+///
+/// let mut context = Context::default();
+/// let value = context
+///     .eval("({ name: 'boa', tags: ['fast', 'safe'], nested: { a: { b: { c: 1 } } } })")
+///     .unwrap();
+///
+/// assert_eq!(
+///     ValueFormatter::default().format(&value, &mut context),
+///     "{ name: 'boa', tags: [ 'fast', 'safe' ], nested: { a: { b: [Object] } } }"
+/// );
+/// assert_eq!(
+///     ValueFormatter::default().depth(0).format(&value, &mut context),
+///     "{ name: 'boa', tags: [Array], nested: [Object] }"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ValueFormatter {
+    depth: usize,
+    max_array_length: usize,
+    max_string_length: usize,
+    break_length: usize,
+    colors: bool,
+    getters: GetterPolicy,
+}
+
+impl Default for ValueFormatter {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            depth: 2,
+            max_array_length: 100,
+            max_string_length: 10_000,
+            break_length: 80,
+            colors: false,
+            getters: GetterPolicy::default(),
+        }
+    }
+}
+
+impl ValueFormatter {
+    /// Sets how many levels of nested objects are printed before abbreviating them as
+    /// `[Object]`, `[Array]`, ...
+    ///
+    /// By default this is `2`. Use `usize::MAX` to print every level.
+    #[inline]
+    #[must_use]
+    pub fn depth(mut self, depth: usize) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Sets how many elements of arrays, maps and sets are printed, the others being summarized
+    /// as `... 3 more items`.
+    ///
+    /// By default this is `100`. Use `usize::MAX` to print every element.
+    #[inline]
+    #[must_use]
+    pub fn max_array_length(mut self, length: usize) -> Self {
+        self.max_array_length = length;
+        self
+    }
+
+    /// Sets how many characters of strings are printed, the others being summarized as
+    /// `... 3 more characters`.
+    ///
+    /// By default this is `10_000`. Use `usize::MAX` to print whole strings.
+    #[inline]
+    #[must_use]
+    pub fn max_string_length(mut self, length: usize) -> Self {
+        self.max_string_length = length;
+        self
+    }
+
+    /// Sets the length above which the entries of an object are printed on separate lines.
+    ///
+    /// By default this is `80`. Use `usize::MAX` to print every object on a single line.
+    #[inline]
+    #[must_use]
+    pub fn break_length(mut self, length: usize) -> Self {
+        self.break_length = length;
+        self
+    }
+
+    /// Colors the output with ANSI escape codes, for terminals.
+    ///
+    /// By default this is `false`.
+    #[inline]
+    #[must_use]
+    pub fn colors(mut self, colors: bool) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Sets how the properties defined by accessors are printed.
+    ///
+    /// By default this is [`GetterPolicy::Hide`].
+    #[inline]
+    #[must_use]
+    pub fn getters(mut self, getters: GetterPolicy) -> Self {
+        self.getters = getters;
+        self
+    }
+
+    /// Formats `value`.
+    ///
+    /// Strings are quoted, like in the properties of objects. Formatting does not run any code,
+    /// except the getters when they are [evaluated](GetterPolicy::Evaluate) and the getters of
+    /// the `message` of errors.
+    pub fn format(&self, value: &JsValue, context: &mut Context) -> String {
+        self.format_value(value, 0, &mut Vec::new(), context)
+    }
+
+    fn format_value(
+        &self,
+        value: &JsValue,
+        level: usize,
+        seen: &mut Vec<JsObject>,
+        context: &mut Context,
+    ) -> String {
+        match value {
+            JsValue::Object(object) => self.format_object(object, level, seen, context),
+            primitive => self.format_primitive(primitive),
+        }
+    }
+
+    fn format_primitive(&self, value: &JsValue) -> String {
+        match value {
+            JsValue::String(string) => self.format_string(string.as_str()),
+            JsValue::Undefined => self.style("undefined", Style::Undefined),
+            JsValue::Null => self.style("null", Style::Null),
+            JsValue::Symbol(_) => self.style(&value.display().to_string(), Style::Symbol),
+            JsValue::Boolean(_)
+            | JsValue::Integer(_)
+            | JsValue::Rational(_)
+            | JsValue::BigInt(_) => self.style(&value.display().to_string(), Style::Number),
+            JsValue::Object(_) => unreachable!("objects are not primitives"),
+        }
+    }
+
+    /// Quotes and escapes a string, eliding the characters after `max_string_length`.
+    fn format_string(&self, string: &str) -> String {
+        let mut quoted = String::from('\'');
+        let mut chars = string.chars();
+        for c in chars.by_ref().take(self.max_string_length) {
+            match c {
+                '\'' => quoted.push_str("\\'"),
+                '\\' => quoted.push_str("\\\\"),
+                '\n' => quoted.push_str("\\n"),
+                '\r' => quoted.push_str("\\r"),
+                '\t' => quoted.push_str("\\t"),
+                c if c.is_control() => quoted.push_str(&format!("\\u{:04X}", c as u32)),
+                c => quoted.push(c),
+            }
+        }
+        quoted.push('\'');
+
+        let mut formatted = self.style(&quoted, Style::String);
+        let remaining = chars.count();
+        if remaining > 0 {
+            formatted.push_str(&format!(
+                "... {remaining} more character{}",
+                plural(remaining)
+            ));
+        }
+        formatted
+    }
+
+    fn format_object(
+        &self,
+        object: &JsObject,
+        level: usize,
+        seen: &mut Vec<JsObject>,
+        context: &mut Context,
+    ) -> String {
+        if seen.iter().any(|o| JsObject::equals(o, object)) {
+            return self.style("[Circular]", Style::Special);
+        }
+
+        let (prefix, shape) = match self.shape_of(object, context) {
+            Shape::Error(stack) => return stack,
+            Shape::Plain(prefix, base) => (prefix, base),
+        };
+        let mut properties = own_enumerable_properties(object);
+        if matches!(shape, Base::Text(_)) && properties.is_empty() {
+            return prefix;
+        }
+
+        if level > self.depth {
+            let name = constructor_name(object).unwrap_or_else(|| "Object".to_owned());
+            return match shape {
+                Base::Text(_) => prefix,
+                _ => self.style(&format!("[{name}]"), Style::Special),
+            };
+        }
+
+        seen.push(object.clone());
+        let mut entries = Vec::new();
+        let (open, close) = match &shape {
+            Base::Array(length) => {
+                properties = self.push_elements(
+                    &mut entries,
+                    object,
+                    properties,
+                    *length,
+                    level,
+                    seen,
+                    context,
+                );
+                ('[', ']')
+            }
+            Base::Map(map) => {
+                let length = map.len();
+                for (key, value) in map.iter().take(self.max_array_length) {
+                    let key = self.format_value(key, level + 1, seen, context);
+                    let value = self.format_value(value, level + 1, seen, context);
+                    entries.push(format!("{key} => {value}"));
+                }
+                self.push_more_items(&mut entries, length);
+                ('{', '}')
+            }
+            Base::Set(set) => {
+                let length = set.len();
+                for value in set.iter().take(self.max_array_length) {
+                    entries.push(self.format_value(value, level + 1, seen, context));
+                }
+                self.push_more_items(&mut entries, length);
+                ('{', '}')
+            }
+            Base::Object | Base::Text(_) => ('{', '}'),
+        };
+        for (key, descriptor) in properties {
+            let value = self.format_property(object, &descriptor, level, seen, context);
+            entries.push(format!("{}: {value}", self.format_key(&key)));
+        }
+        seen.pop();
+
+        let prefix = match shape {
+            Base::Text(text) => format!("{text} "),
+            _ => prefix,
+        };
+        if entries.is_empty() {
+            return format!("{prefix}{open}{close}");
+        }
+        let length = visible_length(&prefix)
+            + entries
+                .iter()
+                .map(|entry| visible_length(entry) + 2)
+                .sum::<usize>()
+            + 2;
+        if length <= self.break_length && entries.iter().all(|entry| !entry.contains('\n')) {
+            format!("{prefix}{open} {} {close}", entries.join(", "))
+        } else {
+            let entries = entries
+                .iter()
+                .map(|entry| format!("  {}", entry.replace('\n', "\n  ")))
+                .collect::<Vec<_>>();
+            format!("{prefix}{open}\n{}\n{close}", entries.join(",\n"))
+        }
+    }
+
+    /// Pushes the elements of an array, returning its properties that are not elements.
+    #[allow(clippy::too_many_arguments)]
+    fn push_elements(
+        &self,
+        entries: &mut Vec<String>,
+        array: &JsObject,
+        properties: Vec<(PropertyKey, PropertyDescriptor)>,
+        length: u64,
+        level: usize,
+        seen: &mut Vec<JsObject>,
+        context: &mut Context,
+    ) -> Vec<(PropertyKey, PropertyDescriptor)> {
+        let (elements, others): (Vec<_>, Vec<_>) = properties.into_iter().partition(
+            |(key, _)| matches!(key, PropertyKey::Index(index) if u64::from(*index) < length),
+        );
+
+        let mut next = 0;
+        for (key, descriptor) in elements {
+            let index = match key {
+                PropertyKey::Index(index) => u64::from(index),
+                _ => unreachable!("the elements have index keys"),
+            };
+            if entries.len() >= self.max_array_length {
+                break;
+            }
+            if index > next {
+                entries.push(self.format_holes(index - next));
+                next = index;
+                if entries.len() >= self.max_array_length {
+                    break;
+                }
+            }
+            entries.push(self.format_property(array, &descriptor, level, seen, context));
+            next = index + 1;
+        }
+        if next < length {
+            if entries.len() < self.max_array_length {
+                entries.push(self.format_holes(length - next));
+            } else {
+                let remaining = length - next;
+                entries.push(format!("... {remaining} more item{}", plural(remaining)));
+            }
+        }
+        others
+    }
+
+    fn format_holes(&self, count: u64) -> String {
+        self.style(
+            &format!("<{count} empty item{}>", plural(count)),
+            Style::Undefined,
+        )
+    }
+
+    /// Pushes the summary of the entries that are not printed, if any.
+    fn push_more_items(&self, entries: &mut Vec<String>, length: usize) {
+        if length > self.max_array_length {
+            let remaining = length - self.max_array_length;
+            entries.push(format!("... {remaining} more item{}", plural(remaining)));
+        }
+    }
+
+    fn format_property(
+        &self,
+        object: &JsObject,
+        descriptor: &PropertyDescriptor,
+        level: usize,
+        seen: &mut Vec<JsObject>,
+        context: &mut Context,
+    ) -> String {
+        if let Some(value) = descriptor.value() {
+            return self.format_value(value, level + 1, seen, context);
+        }
+
+        let getter = descriptor
+            .get()
+            .and_then(JsValue::as_object)
+            .filter(|getter| getter.is_callable());
+        let has_setter = descriptor.set().map_or(false, JsValue::is_object);
+        let label = match (getter.is_some(), has_setter) {
+            (true, true) => "Getter/Setter",
+            (true, false) => "Getter",
+            (false, true) => "Setter",
+            (false, false) => return self.style("undefined", Style::Undefined),
+        };
+        match (getter, self.getters) {
+            (Some(getter), GetterPolicy::Evaluate) => {
+                let value = match getter.call(&object.clone().into(), &[], context) {
+                    Ok(value) => self.format_value(&value, level + 1, seen, context),
+                    Err(_) => "<Inspection threw>".to_owned(),
+                };
+                self.style(&format!("[{label}: "), Style::Special)
+                    + &value
+                    + &self.style("]", Style::Special)
+            }
+            _ => self.style(&format!("[{label}]"), Style::Special),
+        }
+    }
+
+    fn format_key(&self, key: &PropertyKey) -> String {
+        match key {
+            PropertyKey::Index(index) => index.to_string(),
+            PropertyKey::String(name) if is_identifier(name.as_str()) => name.to_string(),
+            PropertyKey::String(name) => self.format_string(name.as_str()),
+            PropertyKey::Symbol(symbol) => format!(
+                "[{}]",
+                self.style(
+                    &JsValue::from(symbol.clone()).display().to_string(),
+                    Style::Symbol
+                )
+            ),
+        }
+    }
+
+    /// Finds how an object is printed.
+    fn shape_of(&self, object: &JsObject, context: &mut Context) -> Shape {
+        if object.is_error() {
+            let error = JsError::from_value(object.clone().into()).capture(context);
+            return Shape::Error(
+                error
+                    .stack()
+                    .map_or_else(|| error.to_string(), str::to_owned),
+            );
+        }
+
+        let constructor = constructor_name(object);
+        let callable = object.is_callable();
+        let borrowed = object.borrow();
+        let text = match borrowed.kind {
+            ObjectKind::Array => {
+                let length = borrowed
+                    .properties()
+                    .get(&"length".into())
+                    .as_ref()
+                    .and_then(PropertyDescriptor::value)
+                    .and_then(JsValue::as_number)
+                    .unwrap_or_default() as u64;
+                let prefix = match constructor.as_deref() {
+                    Some("Array") => String::new(),
+                    Some(name) => format!("{name}({length}) "),
+                    None => format!("[Array({length}): null prototype] "),
+                };
+                return Shape::Plain(prefix, Base::Array(length));
+            }
+            ObjectKind::Map(ref map) => {
+                let entries = map
+                    .iter()
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect::<Vec<_>>();
+                let prefix = format!(
+                    "{}({}) ",
+                    constructor.as_deref().unwrap_or("Map"),
+                    entries.len()
+                );
+                return Shape::Plain(prefix, Base::Map(entries));
+            }
+            ObjectKind::Set(ref set) => {
+                let values = set.iter().cloned().collect::<Vec<_>>();
+                let prefix = format!(
+                    "{}({}) ",
+                    constructor.as_deref().unwrap_or("Set"),
+                    values.len()
+                );
+                return Shape::Plain(prefix, Base::Set(values));
+            }
+            ObjectKind::String(ref string) => {
+                let inner = self.format_string(string.as_str());
+                self.style("[String: ", Style::String) + &inner + &self.style("]", Style::String)
+            }
+            ObjectKind::Number(number) => self.style(
+                &format!("[Number: {}]", JsValue::new(number).display()),
+                Style::Number,
+            ),
+            ObjectKind::Boolean(boolean) => {
+                self.style(&format!("[Boolean: {boolean}]"), Style::Number)
+            }
+            ObjectKind::BigInt(ref bigint) => {
+                self.style(&format!("[BigInt: {bigint}n]"), Style::Number)
+            }
+            ObjectKind::Symbol(ref symbol) => self.style(
+                &format!("[Symbol: {}]", JsValue::from(symbol.clone()).display()),
+                Style::Symbol,
+            ),
+            ObjectKind::RegExp(ref regexp) => self.style(
+                &format!("/{}/{}", regexp.original_source(), regexp.original_flags()),
+                Style::RegExp,
+            ),
+            ObjectKind::Date(date) => match date.to_utc() {
+                Some(date) => self.style(
+                    &date.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(),
+                    Style::Date,
+                ),
+                None => self.style("Invalid Date", Style::Date),
+            },
+            _ if callable => {
+                let name = borrowed
+                    .properties()
+                    .get(&"name".into())
+                    .as_ref()
+                    .and_then(PropertyDescriptor::value)
+                    .and_then(JsValue::as_string)
+                    .filter(|name| !name.is_empty())
+                    .map_or_else(|| " (anonymous)".to_owned(), |name| format!(": {name}"));
+                self.style(&format!("[Function{name}]"), Style::Special)
+            }
+            _ => {
+                let prefix = match constructor.as_deref() {
+                    Some("Object") => String::new(),
+                    Some(name) => format!("{name} "),
+                    None => "[Object: null prototype] ".to_owned(),
+                };
+                return Shape::Plain(prefix, Base::Object);
+            }
+        };
+        Shape::Plain(text.clone(), Base::Text(text))
+    }
+
+    /// Wraps `text` in the ANSI escape codes of `style`, if colors are enabled.
+    fn style(&self, text: &str, style: Style) -> String {
+        if !self.colors {
+            return text.to_owned();
+        }
+        let (open, close) = match style {
+            Style::Number => (33, 39),
+            Style::String | Style::Symbol => (32, 39),
+            Style::Undefined => (90, 39),
+            Style::Null => (1, 22),
+            Style::Special => (36, 39),
+            Style::Date => (35, 39),
+            Style::RegExp => (31, 39),
+        };
+        format!("\u{1b}[{open}m{text}\u{1b}[{close}m")
+    }
+}
+
+/// How an object is printed.
+enum Shape {
+    /// Errors print their stack, or their name and message.
+    Error(String),
+    /// The other objects print a prefix before their entries.
+    Plain(String, Base),
+}
+
+/// The entries printed before the properties of an object.
+enum Base {
+    Object,
+    Array(u64),
+    Map(Vec<(JsValue, JsValue)>),
+    Set(Vec<JsValue>),
+    /// Objects described by a text, like functions and dates, only print their properties when
+    /// they have some.
+    Text(String),
+}
+
+/// The styles of the parts of the output, each having its color.
+#[derive(Clone, Copy)]
+enum Style {
+    Number,
+    String,
+    Symbol,
+    Undefined,
+    Null,
+    Special,
+    Date,
+    RegExp,
+}
+
+/// Gets the enumerable own properties of an object, the index keys first in ascending order.
+fn own_enumerable_properties(object: &JsObject) -> Vec<(PropertyKey, PropertyDescriptor)> {
+    let mut properties = object
+        .borrow()
+        .properties()
+        .iter()
+        .filter(|(_, descriptor)| descriptor.expect_enumerable())
+        .map(|(key, descriptor)| (key, descriptor.clone()))
+        .collect::<Vec<_>>();
+    properties.sort_by_key(|(key, _)| match key {
+        PropertyKey::Index(index) => (0, *index),
+        PropertyKey::String(_) => (1, 0),
+        PropertyKey::Symbol(_) => (2, 0),
+    });
+    properties
+}
+
+/// Gets the name of the constructor of an object, reading the data properties of its prototype
+/// chain without running getters.
+///
+/// Returns `None` for objects with a `null` prototype.
+fn constructor_name(object: &JsObject) -> Option<String> {
+    let mut prototype = object.prototype().clone();
+    while let Some(object) = prototype {
+        let constructor = object
+            .borrow()
+            .properties()
+            .get(&"constructor".into())
+            .as_ref()
+            .and_then(PropertyDescriptor::value)
+            .and_then(JsValue::as_object)
+            .cloned();
+        if let Some(constructor) = constructor {
+            let name = constructor
+                .borrow()
+                .properties()
+                .get(&"name".into())
+                .as_ref()
+                .and_then(PropertyDescriptor::value)
+                .and_then(JsValue::as_string)
+                .filter(|name| !name.is_empty())
+                .map(ToString::to_string);
+            if name.is_some() {
+                return name;
+            }
+        }
+        prototype = object.prototype().clone();
+    }
+    None
+}
+
+/// Returns `true` if `name` can be printed as a property key without quotes.
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map_or(false, |c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Counts the characters of `text`, skipping ANSI escape codes.
+fn visible_length(text: &str) -> usize {
+    let mut length = 0;
+    let mut escaped = false;
+    for c in text.chars() {
+        match c {
+            '\u{1b}' => escaped = true,
+            'm' if escaped => escaped = false,
+            _ if escaped => {}
+            _ => length += 1,
+        }
+    }
+    length
+}
+
+fn plural<T: PartialEq + From<u8>>(count: T) -> &'static str {
+    if count == T::from(1) {
+        ""
+    } else {
+        "s"
+    }
+}
@@ -31,19 +31,25 @@ use std::{
 mod conversions;
 pub(crate) mod display;
 mod equality;
+mod formatter;
 mod hash;
 mod integer;
 mod operations;
 mod serde_json;
+mod try_js;
 mod r#type;
 
+pub use self::serde_json::{BigIntToJson, CycleToJson, JsonFallback, ToJsonOptions};
+pub use boa_macros::{TryFromJs, TryIntoJs};
 pub use conversions::*;
 pub use display::ValueDisplay;
 pub use equality::*;
+pub use formatter::{GetterPolicy, ValueFormatter};
 pub use hash::*;
 pub use integer::IntegerOrInfinity;
 pub use operations::*;
 pub use r#type::Type;
+pub use try_js::{TryFromJs, TryIntoJs};
 
 static TWO_E_64: Lazy<BigInt> = Lazy::new(|| {
     const TWO_E_64: u128 = 2u128.pow(64);
@@ -79,8 +79,10 @@ impl JsValue {
     pub fn mul(&self, other: &Self, context: &mut Context) -> JsResult<Self> {
         Ok(match (self, other) {
             // Fast path:
+            // A zero product with a negative operand is `-0`, which is not an integer.
             (Self::Integer(x), Self::Integer(y)) => x
                 .checked_mul(*y)
+                .filter(|product| *product != 0 || (*x >= 0 && *y >= 0))
                 .map_or_else(|| Self::new(f64::from(*x) * f64::from(*y)), Self::new),
             (Self::Rational(x), Self::Rational(y)) => Self::new(x * y),
             (Self::Integer(x), Self::Rational(y)) => Self::new(f64::from(*x) * y),
@@ -107,7 +109,7 @@ impl JsValue {
             // Fast path:
             (Self::Integer(x), Self::Integer(y)) => x
                 .checked_div(*y)
-                .filter(|div| *y * div == *x)
+                .filter(|div| *y * div == *x && (*div != 0 || *y > 0))
                 .map_or_else(|| Self::new(f64::from(*x) / f64::from(*y)), Self::new),
             (Self::Rational(x), Self::Rational(y)) => Self::new(x / y),
             (Self::Integer(x), Self::Rational(y)) => Self::new(f64::from(*x) / y),
@@ -192,16 +194,23 @@ impl JsValue {
             (Self::Integer(x), Self::Integer(y)) => u32::try_from(*y)
                 .ok()
                 .and_then(|y| x.checked_pow(y))
-                .map_or_else(|| Self::new(f64::from(*x).powi(*y)), Self::new),
-            (Self::Rational(x), Self::Rational(y)) => Self::new(x.powf(*y)),
-            (Self::Integer(x), Self::Rational(y)) => Self::new(f64::from(*x).powf(*y)),
-            (Self::Rational(x), Self::Integer(y)) => Self::new(x.powi(*y)),
+                .map_or_else(
+                    || Self::new(Number::exponentiate(f64::from(*x), f64::from(*y))),
+                    Self::new,
+                ),
+            (Self::Rational(x), Self::Rational(y)) => Self::new(Number::exponentiate(*x, *y)),
+            (Self::Integer(x), Self::Rational(y)) => {
+                Self::new(Number::exponentiate(f64::from(*x), *y))
+            }
+            (Self::Rational(x), Self::Integer(y)) => {
+                Self::new(Number::exponentiate(*x, f64::from(*y)))
+            }
 
             (Self::BigInt(ref a), Self::BigInt(ref b)) => Self::new(JsBigInt::pow(a, b, context)?),
 
             // Slow path:
             (_, _) => match (self.to_numeric(context)?, other.to_numeric(context)?) {
-                (Numeric::Number(a), Numeric::Number(b)) => Self::new(a.powf(b)),
+                (Numeric::Number(a), Numeric::Number(b)) => Self::new(Number::exponentiate(a, b)),
                 (Numeric::BigInt(ref a), Numeric::BigInt(ref b)) => {
                     Self::new(JsBigInt::pow(a, b, context)?)
                 }
@@ -3,7 +3,8 @@
 use super::JsValue;
 use crate::{
     builtins::Array,
-    property::{PropertyDescriptor, PropertyKey},
+    object::JsObject,
+    property::{PropertyDescriptor, PropertyNameKind},
     Context, JsResult,
 };
 use serde_json::{Map, Value};
@@ -77,7 +78,11 @@ impl JsValue {
         }
     }
 
-    /// Converts the `JsValue` to a [`serde_json::Value`].
+    /// Converts the `JsValue` to a [`serde_json::Value`], with the default [`ToJsonOptions`].
+    ///
+    /// Like `JSON.stringify`, the enumerable own properties with string keys are converted,
+    /// getters are called, and `undefined`, symbols and functions are left out of objects and
+    /// converted to `null` in arrays. A `BigInt` or a cyclic object throws a `TypeError`.
     ///
     /// # Example
     ///
@@ -103,53 +108,181 @@ impl JsValue {
     /// #
     /// # assert_eq!(json, back_to_json);
     /// ```
+    #[inline]
     pub fn to_json(&self, context: &mut Context) -> JsResult<Value> {
-        match self {
-            Self::Null => Ok(Value::Null),
-            Self::Undefined => todo!("undefined to JSON"),
-            &Self::Boolean(b) => Ok(b.into()),
-            Self::String(string) => Ok(string.as_str().into()),
-            &Self::Rational(rat) => Ok(rat.into()),
-            &Self::Integer(int) => Ok(int.into()),
-            Self::BigInt(_bigint) => context.throw_type_error("cannot convert bigint to JSON"),
-            Self::Object(obj) => {
-                if obj.is_array() {
-                    let len = obj.length_of_array_like(context)?;
-                    let mut arr = Vec::with_capacity(len);
-
-                    let obj = obj.borrow();
-
-                    for k in 0..len as u32 {
-                        let val = obj.properties().get(&k.into()).map_or(Self::Null, |desc| {
-                            desc.value().cloned().unwrap_or(Self::Null)
-                        });
-                        arr.push(val.to_json(context)?);
-                    }
-
-                    Ok(Value::Array(arr))
-                } else {
-                    let mut map = Map::new();
-                    for (key, property) in obj.borrow().properties().iter() {
-                        let key = match &key {
-                            PropertyKey::String(string) => string.as_str().to_owned(),
-                            PropertyKey::Index(i) => i.to_string(),
-                            PropertyKey::Symbol(_sym) => {
-                                return context.throw_type_error("cannot convert Symbol to JSON")
-                            }
-                        };
-
-                        let value = match property.value() {
-                            Some(val) => val.to_json(context)?,
-                            None => Value::Null,
-                        };
-
-                        map.insert(key, value);
-                    }
-
-                    Ok(Value::Object(map))
+        self.to_json_with(ToJsonOptions::default(), context)
+    }
+
+    /// Converts the `JsValue` to a [`serde_json::Value`], handling the values without a JSON
+    /// equivalent as set by `options`.
+    ///
+    /// A value left out at the top level is converted to `null`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use boa_engine::{
+    ///     value::{BigIntToJson, ToJsonOptions},
+    ///     Context,
+    /// };
+    ///
+    /// let mut context = Context::default();
+    /// let value = context.eval("({ id: 12345678901234567890n })").unwrap();
+    ///
+    /// let options = ToJsonOptions {
+    ///     bigint: BigIntToJson::String,
+    ///     ..ToJsonOptions::default()
+    /// };
+    /// let json = value.to_json_with(options, &mut context).unwrap();
+    /// assert_eq!(json, serde_json::json!({ "id": "12345678901234567890" }));
+    /// ```
+    pub fn to_json_with(&self, options: ToJsonOptions, context: &mut Context) -> JsResult<Value> {
+        let mut converter = ToJson {
+            options,
+            stack: Vec::new(),
+        };
+        Ok(converter.convert(self, context)?.unwrap_or(Value::Null))
+    }
+}
+
+/// The options of [`JsValue::to_json_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ToJsonOptions {
+    /// The conversion of `undefined` and of symbols.
+    pub undefined: JsonFallback,
+    /// The conversion of functions.
+    pub functions: JsonFallback,
+    /// The conversion of `BigInt`s.
+    pub bigint: BigIntToJson,
+    /// The conversion of an object that contains itself.
+    pub cycles: CycleToJson,
+}
+
+impl Default for ToJsonOptions {
+    /// The options matching `JSON.stringify`.
+    fn default() -> Self {
+        Self {
+            undefined: JsonFallback::Omit,
+            functions: JsonFallback::Omit,
+            bigint: BigIntToJson::Throw,
+            cycles: CycleToJson::Throw,
+        }
+    }
+}
+
+/// The conversion to JSON of a value without a JSON equivalent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonFallback {
+    /// The value is left out of objects, and converted to `null` in arrays.
+    Omit,
+    /// The value is converted to `null`.
+    Null,
+    /// A `TypeError` is thrown.
+    Throw,
+}
+
+/// The conversion of `BigInt`s to JSON.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BigIntToJson {
+    /// A `TypeError` is thrown, like `JSON.stringify` does.
+    Throw,
+    /// The `BigInt` is converted to a string of its decimal digits.
+    String,
+    /// The `BigInt` is converted to the nearest number, losing the precision of big values.
+    Number,
+}
+
+/// The conversion to JSON of an object that contains itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CycleToJson {
+    /// A `TypeError` is thrown, like `JSON.stringify` does.
+    Throw,
+    /// The nested occurrences of the object are converted to `null`.
+    Null,
+}
+
+/// The state of a conversion to JSON.
+struct ToJson {
+    options: ToJsonOptions,
+    /// The objects being converted, from the outermost.
+    stack: Vec<JsObject>,
+}
+
+impl ToJson {
+    /// Converts `value`, or returns `None` if it must be left out.
+    fn convert(&mut self, value: &JsValue, context: &mut Context) -> JsResult<Option<Value>> {
+        match value {
+            JsValue::Null => Ok(Some(Value::Null)),
+            JsValue::Undefined => Self::fallback(self.options.undefined, "undefined", context),
+            JsValue::Symbol(_) => Self::fallback(self.options.undefined, "a Symbol", context),
+            &JsValue::Boolean(b) => Ok(Some(b.into())),
+            JsValue::String(string) => Ok(Some(string.as_str().into())),
+            &JsValue::Rational(rat) => Ok(Some(rat.into())),
+            &JsValue::Integer(int) => Ok(Some(int.into())),
+            JsValue::BigInt(bigint) => match self.options.bigint {
+                BigIntToJson::Throw => context.throw_type_error("cannot convert bigint to JSON"),
+                BigIntToJson::String => Ok(Some(bigint.to_string_radix(10).into())),
+                BigIntToJson::Number => Ok(Some(bigint.to_f64().into())),
+            },
+            JsValue::Object(object) => {
+                if object.is_callable() {
+                    return Self::fallback(self.options.functions, "a function", context);
+                }
+                if self.stack.iter().any(|o| JsObject::equals(o, object)) {
+                    return match self.options.cycles {
+                        CycleToJson::Throw => {
+                            context.throw_type_error("cannot convert a cyclic object to JSON")
+                        }
+                        CycleToJson::Null => Ok(Some(Value::Null)),
+                    };
+                }
+
+                self.stack.push(object.clone());
+                let result = self.convert_object(object, context);
+                self.stack.pop();
+                result.map(Some)
+            }
+        }
+    }
+
+    fn convert_object(&mut self, object: &JsObject, context: &mut Context) -> JsResult<Value> {
+        if object.is_array() {
+            let len = object.length_of_array_like(context)?;
+            let mut arr = Vec::with_capacity(len);
+            for index in 0..len {
+                let value = object.get(index, context)?;
+                arr.push(self.convert(&value, context)?.unwrap_or(Value::Null));
+            }
+
+            Ok(Value::Array(arr))
+        } else {
+            let mut map = Map::new();
+            for key in object.enumerable_own_property_names(PropertyNameKind::Key, context)? {
+                let key = key
+                    .as_string()
+                    .expect("enumerable own property names are strings")
+                    .clone();
+                let value = object.get(key.clone(), context)?;
+                if let Some(value) = self.convert(&value, context)? {
+                    map.insert(key.as_str().to_owned(), value);
                 }
             }
-            Self::Symbol(_sym) => context.throw_type_error("cannot convert Symbol to JSON"),
+
+            Ok(Value::Object(map))
+        }
+    }
+
+    fn fallback(
+        fallback: JsonFallback,
+        what: &str,
+        context: &mut Context,
+    ) -> JsResult<Option<Value>> {
+        match fallback {
+            JsonFallback::Omit => Ok(None),
+            JsonFallback::Null => Ok(Some(Value::Null)),
+            JsonFallback::Throw => {
+                context.throw_type_error(format!("cannot convert {what} to JSON"))
+            }
         }
     }
 }
@@ -274,4 +407,61 @@ mod tests {
 
         assert_eq!(pow, 60466176);
     }
+
+    #[test]
+    fn to_json_options() {
+        use crate::value::{BigIntToJson, CycleToJson, JsonFallback, ToJsonOptions};
+        use serde_json::json;
+
+        let mut context = Context::default();
+        let value = context
+            .eval(
+                r#"
+                var value = {
+                    list: [undefined, () => {}, Symbol()],
+                    skipped: undefined,
+                    get computed() { return 1; },
+                };
+                Object.defineProperty(value, "hidden", { value: 2, enumerable: false });
+                value
+            "#,
+            )
+            .unwrap();
+        assert_eq!(
+            value.to_json(&mut context).unwrap(),
+            json!({ "list": [null, null, null], "computed": 1 })
+        );
+
+        let options = ToJsonOptions {
+            undefined: JsonFallback::Null,
+            ..ToJsonOptions::default()
+        };
+        assert_eq!(
+            value.to_json_with(options, &mut context).unwrap(),
+            json!({ "list": [null, null, null], "skipped": null, "computed": 1 })
+        );
+        let options = ToJsonOptions {
+            functions: JsonFallback::Throw,
+            ..ToJsonOptions::default()
+        };
+        assert!(value.to_json_with(options, &mut context).is_err());
+        assert_eq!(
+            JsValue::undefined().to_json(&mut context).unwrap(),
+            serde_json::Value::Null
+        );
+
+        let cyclic = context
+            .eval("var a = { n: 1n }; a.self = a; a.shared = [a.n, a.n]; a")
+            .unwrap();
+        assert!(cyclic.to_json(&mut context).is_err());
+        let options = ToJsonOptions {
+            bigint: BigIntToJson::Number,
+            cycles: CycleToJson::Null,
+            ..ToJsonOptions::default()
+        };
+        assert_eq!(
+            cyclic.to_json_with(options, &mut context).unwrap(),
+            json!({ "n": 1.0, "self": null, "shared": [1.0, 1.0] })
+        );
+    }
 }
@@ -1,7 +1,7 @@
 #![allow(clippy::float_cmp)]
 
 use super::*;
-use crate::{check_output, forward, forward_val, Context, TestAction};
+use crate::{check_output, forward, forward_val, property::Attribute, Context, TestAction};
 
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
@@ -1335,3 +1335,159 @@ mod abstract_relational_comparison {
         check_comparison!(context, "'InvalidBigInt' >= -100n" => false);
     }
 }
+
+#[test]
+fn derive_try_from_js_and_try_into_js() {
+    #[derive(Debug, PartialEq, TryFromJs, TryIntoJs)]
+    struct Config {
+        name: String,
+        #[boa(rename = "maxItems")]
+        max_items: u32,
+        #[boa(default)]
+        verbose: bool,
+        #[boa(optional)]
+        label: Option<String>,
+        tags: Vec<String>,
+    }
+
+    #[derive(Debug, PartialEq, TryFromJs, TryIntoJs)]
+    #[boa(tag = "kind")]
+    enum Shape {
+        Circle {
+            radius: f64,
+        },
+        #[boa(rename = "square")]
+        Square(f64),
+        Empty,
+    }
+
+    let mut context = Context::default();
+    let value = forward_val(
+        &mut context,
+        "({ name: 'boa', maxItems: 3, tags: ['a', 'b'] })",
+    )
+    .unwrap();
+    let config = Config::try_from_js(&value, &mut context).unwrap();
+    assert_eq!(
+        config,
+        Config {
+            name: "boa".to_owned(),
+            max_items: 3,
+            verbose: false,
+            label: None,
+            tags: vec!["a".to_owned(), "b".to_owned()],
+        }
+    );
+
+    let object = config.try_into_js(&mut context).unwrap();
+    context.register_global_property("config", object, Attribute::all());
+    assert_eq!(
+        forward(&mut context, "JSON.stringify(config)"),
+        r#""{\"name\":\"boa\",\"maxItems\":3,\"verbose\":false,\"tags\":[\"a\",\"b\"]}""#
+    );
+
+    let value = forward_val(&mut context, "({ name: 'boa', maxItems: 1.5, tags: [] })").unwrap();
+    assert!(Config::try_from_js(&value, &mut context).is_err());
+
+    let shapes = forward_val(
+        &mut context,
+        "[{ kind: 'Circle', radius: 2 }, { kind: 'square', value: 3 }, { kind: 'Empty' }]",
+    )
+    .unwrap();
+    let shapes = Vec::<Shape>::try_from_js(&shapes, &mut context).unwrap();
+    assert_eq!(
+        shapes,
+        [
+            Shape::Circle { radius: 2.0 },
+            Shape::Square(3.0),
+            Shape::Empty
+        ]
+    );
+
+    let shapes = shapes.try_into_js(&mut context).unwrap();
+    context.register_global_property("shapes", shapes, Attribute::all());
+    assert_eq!(
+        forward(&mut context, "JSON.stringify(shapes)"),
+        r#""[{\"kind\":\"Circle\",\"radius\":2},{\"kind\":\"square\",\"value\":3},{\"kind\":\"Empty\"}]""#
+    );
+
+    let unknown = forward_val(&mut context, "({ kind: 'Triangle' })").unwrap();
+    assert!(Shape::try_from_js(&unknown, &mut context).is_err());
+}
+
+#[test]
+fn value_formatter() {
+    let mut context = Context::default();
+    let mut format = |formatter: ValueFormatter, source: &str| {
+        let value = forward_val(&mut context, source).unwrap();
+        formatter.format(&value, &mut context)
+    };
+    let formatter = ValueFormatter::default();
+
+    assert_eq!(format(formatter, "'it\\'s'"), r"'it\'s'");
+    assert_eq!(format(formatter, "-0"), "-0");
+    assert_eq!(
+        format(formatter, "[1, , , 'a', undefined]"),
+        "[ 1, <2 empty items>, 'a', undefined ]"
+    );
+    assert_eq!(
+        format(formatter, "({ 'a-b': null, [Symbol('s')]: 1n })"),
+        "{ 'a-b': null, [Symbol(s)]: 1n }"
+    );
+    assert_eq!(
+        format(formatter, "new Map([[1, { a: [] }]])"),
+        "Map(1) { 1 => { a: [] } }"
+    );
+    assert_eq!(format(formatter, "new Set(['x'])"), "Set(1) { 'x' }");
+    assert_eq!(format(formatter, "new String('s')"), "[String: 's']");
+    assert_eq!(format(formatter, "/a+/gi"), "/a+/gi");
+    assert_eq!(format(formatter, "(function f() {})"), "[Function: f]");
+    assert_eq!(format(formatter, "(() => {})"), "[Function (anonymous)]");
+    assert_eq!(format(formatter, "function A() {}; new A()"), "A {}");
+    assert_eq!(
+        format(formatter, "Object.create(null)"),
+        "[Object: null prototype] {}"
+    );
+    assert_eq!(
+        format(formatter, "let o = { a: 1 }; o.self = o; o"),
+        "{ a: 1, self: [Circular] }"
+    );
+    assert_eq!(
+        format(formatter, "({ a: { b: { c: { d: 1 } } } })"),
+        "{ a: { b: { c: [Object] } } }"
+    );
+    assert_eq!(
+        format(
+            formatter.depth(usize::MAX),
+            "({ a: { b: { c: { d: 1 } } } })"
+        ),
+        "{ a: { b: { c: { d: 1 } } } }"
+    );
+    assert_eq!(
+        format(formatter.max_array_length(2), "[1, 2, 3, 4]"),
+        "[ 1, 2, ... 2 more items ]"
+    );
+    assert_eq!(
+        format(formatter.max_string_length(3), "'abcdef'"),
+        "'abc'... 3 more characters"
+    );
+    assert_eq!(
+        format(formatter.break_length(10), "({ a: 1, b: [2] })"),
+        "{\n  a: 1,\n  b: [ 2 ]\n}"
+    );
+
+    let getters = "({ get a() { return 1; }, set b(v) {}, get c() { throw 1; } })";
+    assert_eq!(
+        format(formatter, getters),
+        "{ a: [Getter], b: [Setter], c: [Getter] }"
+    );
+    assert_eq!(
+        format(formatter.getters(GetterPolicy::Evaluate), getters),
+        "{ a: [Getter: 1], b: [Setter], c: [Getter: <Inspection threw>] }"
+    );
+
+    assert_eq!(
+        format(formatter.colors(true), "[1, 'a', null]"),
+        "[ \u{1b}[33m1\u{1b}[39m, \u{1b}[32m'a'\u{1b}[39m, \u{1b}[1mnull\u{1b}[22m ]"
+    );
+}
@@ -0,0 +1,276 @@
+//! Conversions between Rust types and JavaScript values that can fail.
+//!
+//! Unlike the `From` conversions of [`JsValue`], these conversions can throw, and can run
+//! JavaScript code, like the getters of the properties of an object. They are implemented for
+//! the primitive types, `Option`, `Vec` and the engine types, and can be derived for structs and
+//! enums with `#[derive(TryFromJs, TryIntoJs)]`:
+//!
+//! - A struct with named fields is converted from and to an object with a property for each
+//!   field. `#[boa(rename = "name")]` changes the name of the property of a field,
+//!   `#[boa(default)]` uses the default value of a field whose property is `undefined`, and
+//!   `#[boa(optional)]` omits the property of an `Option` field that is `None`.
+//! - A struct with a single unnamed field is converted like its field.
+//! - An enum is converted from and to a tagged object, whose `type` property is the name of the
+//!   variant, and which holds the fields of the variant like a struct. The fields of a variant
+//!   with a single unnamed field are stored in its `value` property. `#[boa(tag = "name")]` on
+//!   the enum changes the name of the tag property, and `#[boa(rename = "name")]` on a variant
+//!   changes its tag.
+//!
+//! ```
+//! # use boa_engine::{value::{TryFromJs, TryIntoJs}, Context, JsValue};
+//! #[derive(Debug, PartialEq, TryFromJs, TryIntoJs)]
+//! struct Point {
+//!     x: f64,
+//!     y: f64,
+//!     #[boa(rename = "displayName", optional)]
+//!     display_name: Option<String>,
+//! }
+//!
+//! let mut context = Context::default();
+//! let value = context.eval("({ x: 1, y: 2 })").unwrap();
+//! let point = Point::try_from_js(&value, &mut context).unwrap();
+//! assert_eq!(point, Point { x: 1.0, y: 2.0, display_name: None });
+//! assert!(point.try_into_js(&mut context).unwrap().is_object());
+//! ```
+
+use crate::{
+    object::{JsArray, JsObject},
+    Context, JsBigInt, JsResult, JsString, JsSymbol, JsValue,
+};
+
+/// A type that can be converted from a JavaScript value.
+pub trait TryFromJs: Sized {
+    /// Converts a JavaScript value to the type, or throws a `TypeError` if the value can't be
+    /// converted.
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self>;
+}
+
+/// A type that can be converted to a JavaScript value.
+pub trait TryIntoJs {
+    /// Converts the value to a JavaScript value.
+    fn try_into_js(&self, context: &mut Context) -> JsResult<JsValue>;
+}
+
+impl TryFromJs for JsValue {
+    #[inline]
+    fn try_from_js(value: &JsValue, _: &mut Context) -> JsResult<Self> {
+        Ok(value.clone())
+    }
+}
+
+impl TryIntoJs for JsValue {
+    #[inline]
+    fn try_into_js(&self, _: &mut Context) -> JsResult<JsValue> {
+        Ok(self.clone())
+    }
+}
+
+impl TryFromJs for bool {
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        match value {
+            JsValue::Boolean(boolean) => Ok(*boolean),
+            _ => context.throw_type_error("cannot convert value to a boolean"),
+        }
+    }
+}
+
+impl TryFromJs for f64 {
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.as_number() {
+            Some(number) => Ok(number),
+            None => context.throw_type_error("cannot convert value to a number"),
+        }
+    }
+}
+
+/// Implements `TryFromJs` for integer types, which accept the numbers that are integers in their
+/// range.
+macro_rules! impl_try_from_js_for_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl TryFromJs for $integer {
+                #[allow(
+                    clippy::float_cmp,
+                    clippy::cast_possible_truncation,
+                    clippy::cast_precision_loss,
+                    clippy::cast_sign_loss
+                )]
+                fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+                    let number = f64::try_from_js(value, context)?;
+                    let in_range = number >= <$integer>::MIN as f64
+                        && number < <$integer>::MAX as f64 + 1.0;
+                    if number.fract() != 0.0 || !in_range {
+                        return context.throw_type_error(format!(
+                            "cannot convert {} to {}",
+                            number,
+                            stringify!($integer)
+                        ));
+                    }
+                    Ok(number as $integer)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_js_for_integer!(i8, i16, i32, i64, u8, u16, u32, u64, usize);
+
+impl TryFromJs for String {
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        match value {
+            JsValue::String(string) => Ok(string.to_string()),
+            _ => context.throw_type_error("cannot convert value to a string"),
+        }
+    }
+}
+
+impl TryFromJs for JsString {
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        match value {
+            JsValue::String(string) => Ok(string.clone()),
+            _ => context.throw_type_error("cannot convert value to a string"),
+        }
+    }
+}
+
+impl TryFromJs for JsSymbol {
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        match value {
+            JsValue::Symbol(symbol) => Ok(symbol.clone()),
+            _ => context.throw_type_error("cannot convert value to a symbol"),
+        }
+    }
+}
+
+impl TryFromJs for JsBigInt {
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        match value {
+            JsValue::BigInt(bigint) => Ok(bigint.clone()),
+            _ => context.throw_type_error("cannot convert value to a bigint"),
+        }
+    }
+}
+
+impl TryFromJs for JsObject {
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        match value {
+            JsValue::Object(object) => Ok(object.clone()),
+            _ => context.throw_type_error("cannot convert value to an object"),
+        }
+    }
+}
+
+/// Implements `TryIntoJs` for the types that convert to `JsValue` with `From`.
+macro_rules! impl_try_into_js_with_from {
+    ($($ty:ty),*) => {
+        $(
+            impl TryIntoJs for $ty {
+                #[inline]
+                fn try_into_js(&self, _: &mut Context) -> JsResult<JsValue> {
+                    Ok(self.clone().into())
+                }
+            }
+        )*
+    };
+}
+
+impl_try_into_js_with_from!(String, JsString, JsSymbol, JsBigInt, JsObject);
+
+/// Implements `TryIntoJs` for the `Copy` types that convert to `JsValue` with `From`.
+macro_rules! impl_try_into_js_for_copy {
+    ($($ty:ty),*) => {
+        $(
+            impl TryIntoJs for $ty {
+                #[inline]
+                fn try_into_js(&self, _: &mut Context) -> JsResult<JsValue> {
+                    Ok((*self).into())
+                }
+            }
+        )*
+    };
+}
+
+impl_try_into_js_for_copy!(bool, f32, f64, i32, i64, u32, u64, usize);
+
+/// Implements `TryIntoJs` for the small integer types, which convert losslessly to `i32`.
+macro_rules! impl_try_into_js_for_small_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl TryIntoJs for $integer {
+                #[inline]
+                fn try_into_js(&self, _: &mut Context) -> JsResult<JsValue> {
+                    Ok(i32::from(*self).into())
+                }
+            }
+        )*
+    };
+}
+
+impl_try_into_js_for_small_integer!(i8, i16, u8, u16);
+
+/// `()` is converted to `undefined`.
+impl TryIntoJs for () {
+    #[inline]
+    fn try_into_js(&self, _: &mut Context) -> JsResult<JsValue> {
+        Ok(JsValue::undefined())
+    }
+}
+
+impl TryIntoJs for str {
+    #[inline]
+    fn try_into_js(&self, _: &mut Context) -> JsResult<JsValue> {
+        Ok(self.into())
+    }
+}
+
+/// `undefined` and `null` are converted to `None`.
+impl<T: TryFromJs> TryFromJs for Option<T> {
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        if value.is_null_or_undefined() {
+            return Ok(None);
+        }
+        T::try_from_js(value, context).map(Some)
+    }
+}
+
+/// `None` is converted to `undefined`.
+impl<T: TryIntoJs> TryIntoJs for Option<T> {
+    fn try_into_js(&self, context: &mut Context) -> JsResult<JsValue> {
+        match self {
+            Some(value) => value.try_into_js(context),
+            None => Ok(JsValue::undefined()),
+        }
+    }
+}
+
+/// Array-like objects are converted element by element.
+impl<T: TryFromJs> TryFromJs for Vec<T> {
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        let object = JsObject::try_from_js(value, context)?;
+        let len = object.get("length", context)?.to_length(context)?;
+        (0..len)
+            .map(|index| {
+                let element = object.get(index, context)?;
+                T::try_from_js(&element, context)
+            })
+            .collect()
+    }
+}
+
+/// Vectors are converted to arrays.
+impl<T: TryIntoJs> TryIntoJs for Vec<T> {
+    fn try_into_js(&self, context: &mut Context) -> JsResult<JsValue> {
+        let elements = self
+            .iter()
+            .map(|element| element.try_into_js(context))
+            .collect::<JsResult<Vec<_>>>()?;
+        Ok(JsArray::from_iter(elements, context).into())
+    }
+}
+
+impl<T: TryIntoJs + ?Sized> TryIntoJs for &T {
+    #[inline]
+    fn try_into_js(&self, context: &mut Context) -> JsResult<JsValue> {
+        (**self).try_into_js(context)
+    }
+}
@@ -3,13 +3,15 @@
 //! This module will provides everything needed to implement the `CallFrame`
 
 use super::CodeBlock;
-use crate::JsValue;
+use crate::{builtins::disposable_stack::DisposeCapability, JsValue};
 use boa_gc::{Finalize, Gc, Trace};
 
 #[derive(Clone, Debug, Finalize, Trace)]
 pub struct CallFrame {
     pub(crate) prev: Option<Box<Self>>,
     pub(crate) code: Gc<CodeBlock>,
+    // The values of the local bindings of the code block, `None` if a binding is uninitialized.
+    pub(crate) locals: Vec<Option<JsValue>>,
     pub(crate) pc: usize,
     pub(crate) this: JsValue,
     #[unsafe_ignore_trace]
@@ -32,6 +34,9 @@ pub struct CallFrame {
     pub(crate) arg_count: usize,
     #[unsafe_ignore_trace]
     pub(crate) generator_resume_kind: GeneratorResumeKind,
+
+    // The resources of the `using` declarations in the scopes being executed, innermost last.
+    pub(crate) dispose_stack: Vec<DisposeCapability>,
 }
 
 impl CallFrame {
@@ -10,19 +10,53 @@ use crate::{
         },
         generator::{Generator, GeneratorContext, GeneratorState},
     },
+    bytecompiler::ByteCompiler,
     context::intrinsics::StandardConstructors,
-    environments::{BindingLocator, DeclarativeEnvironmentStack},
+    environments::{BindingLocator, CompileTimeEnvironment, DeclarativeEnvironmentStack},
     object::{internal_methods::get_prototype_from_constructor, JsObject, ObjectData},
     property::PropertyDescriptor,
-    syntax::ast::node::FormalParameterList,
+    syntax::ast::{
+        node::{FormalParameterList, Node},
+        Span,
+    },
     vm::call_frame::GeneratorResumeKind,
-    vm::{call_frame::FinallyReturn, CallFrame, Opcode},
-    Context, JsResult, JsValue,
+    vm::{call_frame::FinallyReturn, CallFrame, JumpTable, JumpTableKey, Opcode},
+    Context, JsResult, JsString, JsValue,
 };
 use boa_gc::{Cell, Finalize, Gc, Trace};
 use boa_interner::{Interner, Sym, ToInternedString};
 use boa_profiler::Profiler;
-use std::{convert::TryInto, mem::size_of};
+use regress::Regex;
+use rustc_hash::FxHashMap;
+use std::{cell::RefCell, convert::TryInto, mem::size_of, rc::Rc};
+
+#[cfg(feature = "jit")]
+use crate::vm::JitSlot;
+
+/// A function whose compilation is deferred to its first call.
+#[derive(Debug, Trace, Finalize)]
+pub(crate) struct LazyFunction {
+    /// The syntax tree of the function.
+    function: Node,
+
+    /// The compile time environments enclosing the definition of the function.
+    #[unsafe_ignore_trace]
+    environments: Vec<Rc<CompileTimeEnvironment>>,
+
+    /// The code block of the function, once it is compiled.
+    compiled: Cell<Option<Gc<CodeBlock>>>,
+}
+
+impl LazyFunction {
+    /// Creates a function to compile later, in the given compile time environments.
+    pub(crate) fn new(function: Node, environments: Vec<Rc<CompileTimeEnvironment>>) -> Self {
+        Self {
+            function,
+            environments,
+            compiled: Cell::new(None),
+        }
+    }
+}
 
 /// This represents whether a value can be read from [`CodeBlock`] code.
 ///
@@ -74,12 +108,22 @@ pub struct CodeBlock {
     /// Bytecode
     pub(crate) code: Vec<u8>,
 
+    /// The line table of the bytecode: the spans of the source code the instructions were compiled
+    /// from, each paired with the address of the first instruction compiled from it, by
+    /// increasing address.
+    #[unsafe_ignore_trace]
+    pub(crate) spans: Vec<(u32, Span)>,
+
     /// Literals
     pub(crate) literals: Vec<JsValue>,
 
     /// Property field names.
     pub(crate) names: Vec<Sym>,
 
+    /// The jump tables of the `switch` statements.
+    #[unsafe_ignore_trace]
+    pub(crate) jump_tables: Vec<JumpTable>,
+
     /// Locators for all bindings in the codeblock.
     #[unsafe_ignore_trace]
     pub(crate) bindings: Vec<BindingLocator>,
@@ -87,6 +131,9 @@ pub struct CodeBlock {
     /// Number of binding for the function environment.
     pub(crate) num_bindings: usize,
 
+    /// Number of local bindings, which are stored in the call frame.
+    pub(crate) num_locals: usize,
+
     /// Functions inside this function
     pub(crate) functions: Vec<Gc<CodeBlock>>,
 
@@ -96,6 +143,22 @@ pub struct CodeBlock {
     /// The `arguments` binding location of the function, if set.
     #[unsafe_ignore_trace]
     pub(crate) arguments_binding: Option<BindingLocator>,
+
+    /// The regular expressions compiled while running this code block, keyed by pattern and flags.
+    #[unsafe_ignore_trace]
+    pub(crate) regexp_cache: RefCell<FxHashMap<(JsString, JsString), Rc<Regex>>>,
+
+    /// The function to compile on the first call, if this code block is the placeholder of a
+    /// function that is compiled lazily.
+    ///
+    /// The placeholder only has the attributes of the function that are needed to create the
+    /// function object, and no bytecode.
+    pub(crate) lazy: Option<Gc<LazyFunction>>,
+
+    /// The invocation count and native code of this code block.
+    #[cfg(feature = "jit")]
+    #[unsafe_ignore_trace]
+    pub(crate) jit: JitSlot,
 }
 
 impl CodeBlock {
@@ -103,10 +166,13 @@ impl CodeBlock {
     pub fn new(name: Sym, length: u32, strict: bool, constructor: bool) -> Self {
         Self {
             code: Vec::new(),
+            spans: Vec::new(),
             literals: Vec::new(),
             names: Vec::new(),
+            jump_tables: Vec::new(),
             bindings: Vec::new(),
             num_bindings: 0,
+            num_locals: 0,
             functions: Vec::new(),
             name,
             length,
@@ -116,9 +182,45 @@ impl CodeBlock {
             params: FormalParameterList::default(),
             lexical_name_argument: false,
             arguments_binding: None,
+            regexp_cache: RefCell::default(),
+            lazy: None,
+            #[cfg(feature = "jit")]
+            jit: JitSlot::default(),
         }
     }
 
+    /// Gets the span of the source code the instruction at address `pc` was compiled from, if it
+    /// is known.
+    pub fn span_at(&self, pc: usize) -> Option<Span> {
+        let index = self
+            .spans
+            .partition_point(|(address, _)| *address as usize <= pc);
+        index.checked_sub(1).map(|index| self.spans[index].1)
+    }
+
+    /// Returns the code block to run for a call of the function of `code`, compiling the
+    /// function if it is compiled lazily and this is its first call.
+    pub(crate) fn compiled(code: Gc<Self>, context: &mut Context) -> JsResult<Gc<Self>> {
+        let lazy = if let Some(lazy) = &code.lazy {
+            lazy.clone()
+        } else {
+            return Ok(code);
+        };
+        if let Some(compiled) = &*lazy.compiled.borrow() {
+            return Ok(compiled.clone());
+        }
+
+        let _timer = Profiler::global().start_event("Lazy compilation", "vm");
+        let compiled = ByteCompiler::compile_lazy_function(
+            &lazy.function,
+            code.strict,
+            lazy.environments.clone(),
+            context,
+        )?;
+        *lazy.compiled.borrow_mut() = Some(compiled.clone());
+        Ok(compiled)
+    }
+
     /// Read type T from code.
     ///
     /// # Safety
@@ -180,6 +282,7 @@ impl CodeBlock {
             | Opcode::JumpIfNotUndefined
             | Opcode::CatchStart
             | Opcode::FinallySetJump
+            | Opcode::AddDisposableResource
             | Opcode::Case
             | Opcode::Default
             | Opcode::LogicalAnd
@@ -187,6 +290,7 @@ impl CodeBlock {
             | Opcode::Coalesce
             | Opcode::Call
             | Opcode::CallWithRest
+            | Opcode::TailCall
             | Opcode::New
             | Opcode::NewWithRest
             | Opcode::ForInLoopInitIterator
@@ -194,7 +298,7 @@ impl CodeBlock {
             | Opcode::ConcatToString
             | Opcode::CopyDataProperties
             | Opcode::GeneratorNextDelegate
-            | Opcode::PushDeclarativeEnvironment => {
+            | Opcode::PushFunctionEnvironment => {
                 let result = self.read::<u32>(*pc).to_string();
                 *pc += size_of::<u32>();
                 result
@@ -206,6 +310,35 @@ impl CodeBlock {
                 *pc += size_of::<u32>();
                 format!("{operand1}, {operand2}")
             }
+            Opcode::PushDeclarativeEnvironment => {
+                let num_bindings = self.read::<u32>(*pc);
+                *pc += size_of::<u32>();
+                let first_local = self.read::<u32>(*pc);
+                *pc += size_of::<u32>();
+                let num_locals = self.read::<u32>(*pc);
+                *pc += size_of::<u32>();
+                format!("{num_bindings}, {first_local}, {num_locals}")
+            }
+            Opcode::TemplateLookup | Opcode::TemplateCreate => {
+                let operand1 = self.read::<u32>(*pc);
+                *pc += size_of::<u32>();
+                let operand2 = self.read::<u64>(*pc);
+                *pc += size_of::<u64>();
+                format!("{operand1}, {operand2}")
+            }
+            Opcode::JumpTable => {
+                let operand = self.read::<u32>(*pc);
+                *pc += size_of::<u32>();
+                let cases = self.jump_tables[operand as usize]
+                    .entries()
+                    .iter()
+                    .map(|(key, address)| match key {
+                        JumpTableKey::Integer(integer) => format!("{integer}: {address}"),
+                        JumpTableKey::String(string) => format!("\"{string}\": {address}"),
+                    })
+                    .collect::<Vec<_>>();
+                format!("{operand:04}: {{ {} }}", cases.join(", "))
+            }
             Opcode::GetFunction | Opcode::GetGenerator => {
                 let operand = self.read::<u32>(*pc);
                 *pc += size_of::<u32>();
@@ -232,6 +365,21 @@ impl CodeBlock {
                     interner.resolve_expect(self.bindings[operand as usize].name()),
                 )
             }
+            Opcode::GetLocal
+            | Opcode::GetLocalOrUndefined
+            | Opcode::SetLocal
+            | Opcode::DefVarLocal
+            | Opcode::DefInitLocal => {
+                let operand = self.read::<u32>(*pc);
+                *pc += size_of::<u32>();
+                let binding = self.bindings[operand as usize];
+                format!(
+                    "{:04}: '{}' (local {})",
+                    operand,
+                    interner.resolve_expect(binding.name()),
+                    binding.binding_index(),
+                )
+            }
             Opcode::GetPropertyByName
             | Opcode::SetPropertyByName
             | Opcode::DefineOwnPropertyByName
@@ -303,9 +451,10 @@ impl CodeBlock {
             | Opcode::CatchEnd2
             | Opcode::FinallyStart
             | Opcode::FinallyEnd
+            | Opcode::PushDisposeScope
+            | Opcode::DisposeResources
             | Opcode::This
             | Opcode::Return
-            | Opcode::PushFunctionEnvironment
             | Opcode::PopEnvironment
             | Opcode::LoopStart
             | Opcode::LoopContinue
@@ -385,6 +534,15 @@ impl ToInternedString for CodeBlock {
             }
         }
 
+        f.push_str("\nLine table:\n");
+        if self.spans.is_empty() {
+            f.push_str("    <empty>\n");
+        } else {
+            for (address, span) in &self.spans {
+                f.push_str(&format!("    {address:06}: {}\n", span.start()));
+            }
+        }
+
         f.push_str("\nFunctions:\n");
         if self.functions.is_empty() {
             f.push_str("    <empty>\n");
@@ -600,96 +758,17 @@ impl JsObject {
                 code,
                 mut environments,
             } => {
+                let code = CodeBlock::compiled(code, context)?;
                 std::mem::swap(&mut environments, &mut context.realm.environments);
 
-                let lexical_this_mode = code.this_mode == ThisMode::Lexical;
-
-                let this = if lexical_this_mode {
-                    if let Some(this) = context.realm.environments.get_last_this() {
-                        this
-                    } else {
-                        context.global_object().clone().into()
-                    }
-                } else if (!code.strict && !context.strict()) && this.is_null_or_undefined() {
-                    context.global_object().clone().into()
-                } else {
-                    this.clone()
-                };
-
-                context
-                    .realm
-                    .environments
-                    .push_function(code.num_bindings, this.clone());
-
-                if let Some(binding) = code.arguments_binding {
-                    let arguments_obj =
-                        if context.strict() || code.strict || !code.params.is_simple() {
-                            Arguments::create_unmapped_arguments_object(args, context)
-                        } else {
-                            let env = context.realm.environments.current();
-                            Arguments::create_mapped_arguments_object(
-                                &this_function_object,
-                                &code.params,
-                                args,
-                                &env,
-                                context,
-                            )
-                        };
-                    context.realm.environments.put_value(
-                        binding.environment_index(),
-                        binding.binding_index(),
-                        arguments_obj.into(),
-                    );
-                }
-
-                let arg_count = args.len();
-
-                // Push function arguments to the stack.
-                let args = if code.params.parameters.len() > args.len() {
-                    let mut v = args.to_vec();
-                    v.extend(vec![
-                        JsValue::Undefined;
-                        code.params.parameters.len() - args.len()
-                    ]);
-                    v
-                } else {
-                    args.to_vec()
-                };
-
-                for arg in args.iter().rev() {
-                    context.vm.push(arg);
-                }
-
-                let param_count = code.params.parameters.len();
-                let has_expressions = code.params.has_expressions();
-
-                context.vm.push_frame(CallFrame {
-                    prev: None,
-                    code,
-                    this,
-                    pc: 0,
-                    catch: Vec::new(),
-                    finally_return: FinallyReturn::None,
-                    finally_jump: Vec::new(),
-                    pop_on_return: 0,
-                    loop_env_stack: vec![0],
-                    try_env_stack: vec![crate::vm::TryStackEntry {
-                        num_env: 0,
-                        num_loop_stack_entries: 0,
-                    }],
-                    param_count,
-                    arg_count,
-                    generator_resume_kind: GeneratorResumeKind::Normal,
-                });
+                let frame = self.prepare_ordinary_call(code, this, args, context);
+                context.vm.push_frame(frame);
 
                 let result = context.run();
                 context.vm.pop_frame().expect("must have frame");
 
-                context.realm.environments.pop();
-                if has_expressions {
-                    context.realm.environments.pop();
-                }
-
+                // The environments of the call are discarded, which may not be the environments
+                // of this function if it made a tail call.
                 std::mem::swap(&mut environments, &mut context.realm.environments);
 
                 let (result, _) = result?;
@@ -699,6 +778,7 @@ impl JsObject {
                 code,
                 mut environments,
             } => {
+                let code = CodeBlock::compiled(code, context)?;
                 std::mem::swap(&mut environments, &mut context.realm.environments);
 
                 let lexical_this_mode = code.this_mode == ThisMode::Lexical;
@@ -760,6 +840,7 @@ impl JsObject {
 
                 let call_frame = CallFrame {
                     prev: None,
+                    locals: vec![None; code.num_locals],
                     code,
                     this,
                     pc: 0,
@@ -775,6 +856,7 @@ impl JsObject {
                     param_count,
                     arg_count,
                     generator_resume_kind: GeneratorResumeKind::Normal,
+                    dispose_stack: Vec::new(),
                 };
                 let mut stack = args;
 
@@ -803,7 +885,7 @@ impl JsObject {
                         state: GeneratorState::SuspendedStart,
                         context: Some(Gc::new(Cell::new(GeneratorContext {
                             environments,
-                            call_frame: *call_frame,
+                            call_frame: Some(call_frame),
                             stack,
                         }))),
                     }),
@@ -816,6 +898,127 @@ impl JsObject {
         }
     }
 
+    /// Prepares a call to this ordinary function, whose captured environments must already be
+    /// the environments of the realm, returning its call frame.
+    ///
+    /// This binds `this`, creates the function environment and the `arguments` object, and
+    /// pushes the arguments to the stack.
+    fn prepare_ordinary_call(
+        &self,
+        code: Gc<CodeBlock>,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> CallFrame {
+        let lexical_this_mode = code.this_mode == ThisMode::Lexical;
+
+        let this = if lexical_this_mode {
+            if let Some(this) = context.realm.environments.get_last_this() {
+                this
+            } else {
+                context.global_object().clone().into()
+            }
+        } else if (!code.strict && !context.strict()) && this.is_null_or_undefined() {
+            context.global_object().clone().into()
+        } else {
+            this.clone()
+        };
+
+        context
+            .realm
+            .environments
+            .push_function(code.num_bindings, this.clone());
+
+        if let Some(binding) = code.arguments_binding {
+            let arguments_obj = if context.strict() || code.strict || !code.params.is_simple() {
+                Arguments::create_unmapped_arguments_object(args, context)
+            } else {
+                let env = context.realm.environments.current();
+                Arguments::create_mapped_arguments_object(self, &code.params, args, &env, context)
+            };
+            context.realm.environments.put_value(
+                binding.environment_index(),
+                binding.binding_index(),
+                arguments_obj.into(),
+            );
+        }
+
+        let arg_count = args.len();
+
+        // Push function arguments to the stack.
+        let args = if code.params.parameters.len() > args.len() {
+            let mut v = args.to_vec();
+            v.extend(vec![
+                JsValue::Undefined;
+                code.params.parameters.len() - args.len()
+            ]);
+            v
+        } else {
+            args.to_vec()
+        };
+
+        for arg in args.iter().rev() {
+            context.vm.push(arg);
+        }
+
+        let param_count = code.params.parameters.len();
+
+        CallFrame {
+            prev: None,
+            locals: vec![None; code.num_locals],
+            code,
+            this,
+            pc: 0,
+            catch: Vec::new(),
+            finally_return: FinallyReturn::None,
+            finally_jump: Vec::new(),
+            pop_on_return: 0,
+            loop_env_stack: vec![0],
+            try_env_stack: vec![crate::vm::TryStackEntry {
+                num_env: 0,
+                num_loop_stack_entries: 0,
+            }],
+            param_count,
+            arg_count,
+            generator_resume_kind: GeneratorResumeKind::Normal,
+            dispose_stack: Vec::new(),
+        }
+    }
+
+    /// Replaces the running call frame with the frame of a call to this object, for a call in
+    /// tail position, so that the call doesn't grow the native stack.
+    ///
+    /// Returns `false`, leaving the running frame unchanged, if this object is not an ordinary
+    /// function, in which case the call must be made with [`Self::call_internal`].
+    pub(crate) fn tail_call_internal(
+        &self,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> bool {
+        let (code, environments) = match self.borrow().as_function() {
+            Some(Function::Ordinary { code, environments }) => (code.clone(), environments.clone()),
+            _ => return false,
+        };
+        // A function that fails to compile is called with `call_internal`, which throws the error.
+        let code = if let Ok(code) = CodeBlock::compiled(code, context) {
+            code
+        } else {
+            return false;
+        };
+
+        // The environments and the frame of the running function are discarded, the caller of
+        // the running function restores its own environments when the call returns.
+        context.realm.environments = environments;
+        let frame = self.prepare_ordinary_call(code, this, args, context);
+        context
+            .vm
+            .pop_frame()
+            .expect("a tail call must be made from a function frame");
+        context.vm.push_frame(frame);
+        true
+    }
+
     pub(crate) fn construct_internal(
         &self,
         args: &[JsValue],
@@ -862,6 +1065,7 @@ impl JsObject {
                 code,
                 mut environments,
             } => {
+                let code = CodeBlock::compiled(code, context)?;
                 std::mem::swap(&mut environments, &mut context.realm.environments);
 
                 let this: JsValue = {
@@ -884,10 +1088,8 @@ impl JsObject {
 
                 let mut arguments_in_parameter_names = false;
                 let mut is_simple_parameter_list = true;
-                let mut has_parameter_expressions = false;
 
                 for param in code.params.parameters.iter() {
-                    has_parameter_expressions = has_parameter_expressions || param.init().is_some();
                     arguments_in_parameter_names =
                         arguments_in_parameter_names || param.names().contains(&Sym::ARGUMENTS);
                     is_simple_parameter_list = is_simple_parameter_list
@@ -945,8 +1147,9 @@ impl JsObject {
 
                 context.vm.push_frame(CallFrame {
                     prev: None,
+                    locals: vec![None; code.num_locals],
                     code,
-                    this,
+                    this: this.clone(),
                     pc: 0,
                     catch: Vec::new(),
                     finally_return: FinallyReturn::None,
@@ -960,25 +1163,24 @@ impl JsObject {
                     param_count,
                     arg_count,
                     generator_resume_kind: GeneratorResumeKind::Normal,
+                    dispose_stack: Vec::new(),
                 });
 
                 let result = context.run();
+                context.vm.pop_frame().expect("must have frame");
 
-                let frame = context.vm.pop_frame().expect("must have frame");
-
-                context.realm.environments.pop();
-                if has_parameter_expressions {
-                    context.realm.environments.pop();
-                }
-
+                // The environments of the call are discarded, which may not be the environments
+                // of this function if it made a tail call.
                 std::mem::swap(&mut environments, &mut context.realm.environments);
 
                 let (result, _) = result?;
 
+                // The running frame may have been replaced by a tail call, so the `this` of the
+                // construction is the one given to the frame of this function.
                 if result.is_object() {
                     Ok(result)
                 } else {
-                    Ok(frame.this.clone())
+                    Ok(this)
                 }
             }
             FunctionBody::Generator { .. } => {
@@ -0,0 +1,2051 @@
+//! The instructions of the VM, and the table used to dispatch them by opcode.
+//!
+//! Each instruction is a function that executes an opcode once it has been read, so the
+//! interpreter loop dispatches with a single indirect call through [`INSTRUCTIONS`], instead of
+//! the chain of comparisons and jumps that a large `match` on the opcode can be compiled to.
+
+// The instructions must have the signature of the dispatch table, even if they can't fail.
+#![allow(clippy::unnecessary_wraps)]
+
+use super::{
+    call_frame::CatchAddresses,
+    code_block::{create_function_object, create_generator_function_object},
+    FinallyReturn, GeneratorResumeKind, Opcode, ShouldExit, TryStackEntry,
+};
+use crate::{
+    builtins::{
+        disposable_stack::{DisposableResource, DisposeCapability, DisposeHint},
+        iterable::IteratorRecord,
+        Array, ForInIterator, Number,
+    },
+    object::IntegrityLevel,
+    property::{DescriptorKind, PropertyDescriptor, PropertyKey},
+    value::Numeric,
+    Context, JsBigInt, JsResult, JsString, JsValue,
+};
+use std::ops::Neg;
+
+/// An instruction of the VM, called after its opcode has been read.
+pub(super) type Instruction = fn(&mut Context) -> JsResult<ShouldExit>;
+
+/// Builds the dispatch table from the instructions of the opcodes, which must be listed in the
+/// order of their values.
+macro_rules! instruction_table {
+    ($($opcode:ident => $instruction:ident,)*) => {{
+        let mut index = 0;
+        $(
+            assert!(Opcode::$opcode as usize == index, "opcodes must be listed in order");
+            index += 1;
+        )*
+        assert!(index == Opcode::COUNT, "all opcodes must be listed");
+        [$($instruction as Instruction,)*]
+    }};
+}
+
+/// The instructions of the VM, indexed by opcode.
+pub(super) static INSTRUCTIONS: [Instruction; Opcode::COUNT] = instruction_table! {
+    Pop => pop,
+    Dup => dup,
+    Swap => swap,
+    PushZero => push_zero,
+    PushOne => push_one,
+    PushInt8 => push_int8,
+    PushInt16 => push_int16,
+    PushInt32 => push_int32,
+    PushRational => push_rational,
+    PushNaN => push_nan,
+    PushPositiveInfinity => push_positive_infinity,
+    PushNegativeInfinity => push_negative_infinity,
+    PushNull => push_null,
+    PushTrue => push_true,
+    PushFalse => push_false,
+    PushUndefined => push_undefined,
+    PushLiteral => push_literal,
+    PushEmptyObject => push_empty_object,
+    PushNewArray => push_new_array,
+    PushValueToArray => push_value_to_array,
+    PushElisionToArray => push_elision_to_array,
+    PushIteratorToArray => push_iterator_to_array,
+    Add => add,
+    Sub => sub,
+    Div => div,
+    Mul => mul,
+    Mod => r#mod,
+    Pow => pow,
+    ShiftRight => shift_right,
+    ShiftLeft => shift_left,
+    UnsignedShiftRight => unsigned_shift_right,
+    BitOr => bit_or,
+    BitAnd => bit_and,
+    BitXor => bit_xor,
+    BitNot => bit_not,
+    In => r#in,
+    Eq => eq,
+    StrictEq => strict_eq,
+    NotEq => not_eq,
+    StrictNotEq => strict_not_eq,
+    GreaterThan => greater_than,
+    GreaterThanOrEq => greater_than_or_eq,
+    LessThan => less_than,
+    LessThanOrEq => less_than_or_eq,
+    InstanceOf => instance_of,
+    LogicalAnd => logical_and,
+    LogicalOr => logical_or,
+    Coalesce => coalesce,
+    TypeOf => type_of,
+    Void => void,
+    LogicalNot => logical_not,
+    Pos => pos,
+    Neg => neg,
+    Inc => inc,
+    IncPost => inc_post,
+    Dec => dec,
+    DecPost => dec_post,
+    DefInitArg => def_init_binding,
+    DefVar => def_var,
+    DefInitVar => def_init_var,
+    DefLet => def_let,
+    DefInitLet => def_init_binding,
+    DefInitConst => def_init_binding,
+    GetName => get_name,
+    GetNameOrUndefined => get_name_or_undefined,
+    SetName => set_name,
+    GetLocal => get_local,
+    GetLocalOrUndefined => get_local_or_undefined,
+    SetLocal => set_local,
+    DefVarLocal => def_var_local,
+    DefInitLocal => def_init_local,
+    GetPropertyByName => get_property_by_name,
+    GetPropertyByValue => get_property_by_value,
+    SetPropertyByName => set_property_by_name,
+    DefineOwnPropertyByName => define_own_property_by_name,
+    SetPropertyByValue => set_property_by_value,
+    DefineOwnPropertyByValue => define_own_property_by_value,
+    SetPropertyGetterByName => set_property_getter_by_name,
+    SetPropertyGetterByValue => set_property_getter_by_value,
+    SetPropertySetterByName => set_property_setter_by_name,
+    SetPropertySetterByValue => set_property_setter_by_value,
+    DeletePropertyByName => delete_property_by_name,
+    DeletePropertyByValue => delete_property_by_value,
+    CopyDataProperties => copy_data_properties,
+    Jump => jump,
+    JumpIfFalse => jump_if_false,
+    JumpIfNotUndefined => jump_if_not_undefined,
+    Throw => throw,
+    TryStart => try_start,
+    TryEnd => try_end,
+    CatchStart => catch_start,
+    CatchEnd => try_end,
+    CatchEnd2 => catch_end2,
+    FinallyStart => finally_start,
+    FinallyEnd => finally_end,
+    FinallySetJump => finally_set_jump,
+    PushDisposeScope => push_dispose_scope,
+    AddDisposableResource => add_disposable_resource,
+    DisposeResources => dispose_resources,
+    ToBoolean => to_boolean,
+    This => this,
+    Case => case,
+    Default => default,
+    JumpTable => jump_table,
+    GetFunction => get_function,
+    GetGenerator => get_generator,
+    Call => call,
+    CallWithRest => call_with_rest,
+    TailCall => tail_call,
+    New => new,
+    NewWithRest => new_with_rest,
+    Return => r#return,
+    PushDeclarativeEnvironment => push_declarative_environment,
+    PushFunctionEnvironment => push_function_environment,
+    PopEnvironment => pop_environment,
+    LoopStart => loop_start,
+    LoopContinue => loop_continue,
+    LoopEnd => loop_end,
+    ForInLoopInitIterator => for_in_loop_init_iterator,
+    InitIterator => init_iterator,
+    IteratorNext => iterator_next,
+    IteratorNextFull => iterator_next_full,
+    IteratorClose => iterator_close,
+    IteratorToArray => iterator_to_array,
+    ForInLoopNext => for_in_loop_next,
+    ConcatToString => concat_to_string,
+    RequireObjectCoercible => require_object_coercible,
+    ValueNotNullOrUndefined => value_not_null_or_undefined,
+    RestParameterInit => rest_parameter_init,
+    RestParameterPop => rest_parameter_pop,
+    PopOnReturnAdd => pop_on_return_add,
+    PopOnReturnSub => pop_on_return_sub,
+    Yield => r#yield,
+    GeneratorNext => generator_next,
+    GeneratorNextDelegate => generator_next_delegate,
+    TemplateLookup => template_lookup,
+    TemplateCreate => template_create,
+    Nop => nop,
+};
+
+/// Defines an instruction that applies a binary operator to the two values on top of the stack.
+macro_rules! bin_op {
+    ($name:ident, $op:ident) => {
+        fn $name(context: &mut Context) -> JsResult<ShouldExit> {
+            let rhs = context.vm.pop();
+            let lhs = context.vm.pop();
+            let value = lhs.$op(&rhs, context)?;
+            context.vm.push(value);
+            Ok(ShouldExit::False)
+        }
+    };
+}
+
+/// Defines an arithmetic instruction. Arithmetic on two `Integer` operands is done in `i32`,
+/// without the conversions of the generic operation, which is only used if the result can't be
+/// represented as an integer.
+macro_rules! int_op {
+    ($name:ident, $op:ident, |$x:ident, $y:ident| $fast:expr) => {
+        fn $name(context: &mut Context) -> JsResult<ShouldExit> {
+            let rhs = context.vm.pop();
+            let lhs = context.vm.pop();
+            let fast = match (&lhs, &rhs) {
+                (JsValue::Integer($x), JsValue::Integer($y)) => $fast,
+                _ => None,
+            };
+            let value = match fast {
+                Some(value) => JsValue::Integer(value),
+                None => lhs.$op(&rhs, context)?,
+            };
+            context.vm.push(value);
+            Ok(ShouldExit::False)
+        }
+    };
+}
+
+/// Defines a relational instruction. Comparisons of two `Integer` operands skip the abstract
+/// relational comparison.
+macro_rules! int_cmp {
+    ($name:ident, $op:ident, $cmp:tt) => {
+        fn $name(context: &mut Context) -> JsResult<ShouldExit> {
+            let rhs = context.vm.pop();
+            let lhs = context.vm.pop();
+            let value = match (&lhs, &rhs) {
+                (JsValue::Integer(x), JsValue::Integer(y)) => x $cmp y,
+                _ => lhs.$op(&rhs, context)?,
+            };
+            context.vm.push(value);
+            Ok(ShouldExit::False)
+        }
+    };
+}
+
+fn nop(_context: &mut Context) -> JsResult<ShouldExit> {
+    Ok(ShouldExit::False)
+}
+
+fn pop(context: &mut Context) -> JsResult<ShouldExit> {
+    let _val = context.vm.pop();
+    Ok(ShouldExit::False)
+}
+
+fn dup(context: &mut Context) -> JsResult<ShouldExit> {
+    let value = context.vm.pop();
+    context.vm.push(value.clone());
+    context.vm.push(value);
+    Ok(ShouldExit::False)
+}
+
+fn swap(context: &mut Context) -> JsResult<ShouldExit> {
+    let first = context.vm.pop();
+    let second = context.vm.pop();
+
+    context.vm.push(first);
+    context.vm.push(second);
+    Ok(ShouldExit::False)
+}
+
+fn push_undefined(context: &mut Context) -> JsResult<ShouldExit> {
+    context.vm.push(JsValue::undefined());
+    Ok(ShouldExit::False)
+}
+
+fn push_null(context: &mut Context) -> JsResult<ShouldExit> {
+    context.vm.push(JsValue::null());
+    Ok(ShouldExit::False)
+}
+
+fn push_true(context: &mut Context) -> JsResult<ShouldExit> {
+    context.vm.push(true);
+    Ok(ShouldExit::False)
+}
+
+fn push_false(context: &mut Context) -> JsResult<ShouldExit> {
+    context.vm.push(false);
+    Ok(ShouldExit::False)
+}
+
+fn push_zero(context: &mut Context) -> JsResult<ShouldExit> {
+    context.vm.push(0);
+    Ok(ShouldExit::False)
+}
+
+fn push_one(context: &mut Context) -> JsResult<ShouldExit> {
+    context.vm.push(1);
+    Ok(ShouldExit::False)
+}
+
+fn push_int8(context: &mut Context) -> JsResult<ShouldExit> {
+    let value = context.vm.read::<i8>();
+    context.vm.push(i32::from(value));
+    Ok(ShouldExit::False)
+}
+
+fn push_int16(context: &mut Context) -> JsResult<ShouldExit> {
+    let value = context.vm.read::<i16>();
+    context.vm.push(i32::from(value));
+    Ok(ShouldExit::False)
+}
+
+fn push_int32(context: &mut Context) -> JsResult<ShouldExit> {
+    let value = context.vm.read::<i32>();
+    context.vm.push(value);
+    Ok(ShouldExit::False)
+}
+
+fn push_rational(context: &mut Context) -> JsResult<ShouldExit> {
+    let value = context.vm.read::<f64>();
+    context.vm.push(value);
+    Ok(ShouldExit::False)
+}
+
+fn push_nan(context: &mut Context) -> JsResult<ShouldExit> {
+    context.vm.push(JsValue::nan());
+    Ok(ShouldExit::False)
+}
+
+fn push_positive_infinity(context: &mut Context) -> JsResult<ShouldExit> {
+    context.vm.push(JsValue::positive_infinity());
+    Ok(ShouldExit::False)
+}
+
+fn push_negative_infinity(context: &mut Context) -> JsResult<ShouldExit> {
+    context.vm.push(JsValue::negative_infinity());
+    Ok(ShouldExit::False)
+}
+
+fn push_literal(context: &mut Context) -> JsResult<ShouldExit> {
+    let index = context.vm.read::<u32>() as usize;
+    let value = context.vm.frame().code.literals[index].clone();
+    context.vm.push(value);
+    Ok(ShouldExit::False)
+}
+
+fn push_empty_object(context: &mut Context) -> JsResult<ShouldExit> {
+    context.vm.push(context.construct_object());
+    Ok(ShouldExit::False)
+}
+
+fn push_new_array(context: &mut Context) -> JsResult<ShouldExit> {
+    let array = Array::array_create(0, None, context)
+        .expect("Array creation with 0 length should never fail");
+    context.vm.push(array);
+    Ok(ShouldExit::False)
+}
+
+fn push_value_to_array(context: &mut Context) -> JsResult<ShouldExit> {
+    let value = context.vm.pop();
+    let array = context.vm.pop();
+    let o = array.as_object().expect("should be an object");
+    let len = o
+        .length_of_array_like(context)
+        .expect("should have 'length' property");
+    o.create_data_property_or_throw(len, value, context)
+        .expect("should be able to create new data property");
+    context.vm.push(array);
+    Ok(ShouldExit::False)
+}
+
+fn push_elision_to_array(context: &mut Context) -> JsResult<ShouldExit> {
+    let array = context.vm.pop();
+    let o = array.as_object().expect("should always be an object");
+
+    let len = o
+        .length_of_array_like(context)
+        .expect("arrays should always have a 'length' property");
+
+    o.set("length", len + 1, true, context)?;
+    context.vm.push(array);
+    Ok(ShouldExit::False)
+}
+
+fn push_iterator_to_array(context: &mut Context) -> JsResult<ShouldExit> {
+    let next_function = context.vm.pop();
+    let iterator = context.vm.pop();
+    let array = context.vm.pop();
+
+    let iterator = IteratorRecord::new(iterator, next_function);
+    while let Some(next) = iterator.step(context)? {
+        Array::push(&array, &[next.value(context)?], context)?;
+    }
+
+    context.vm.push(array);
+    Ok(ShouldExit::False)
+}
+
+int_op!(add, add, |x, y| x.checked_add(*y));
+
+int_op!(sub, sub, |x, y| x.checked_sub(*y));
+
+// A zero product with a negative operand is `-0`, which is not an integer.
+int_op!(mul, mul, |x, y| x
+    .checked_mul(*y)
+    .filter(|product| *product != 0 || (*x >= 0 && *y >= 0)));
+
+bin_op!(div, div);
+
+bin_op!(pow, pow);
+
+bin_op!(r#mod, rem);
+
+bin_op!(bit_and, bitand);
+
+bin_op!(bit_or, bitor);
+
+bin_op!(bit_xor, bitxor);
+
+bin_op!(shift_left, shl);
+
+bin_op!(shift_right, shr);
+
+bin_op!(unsigned_shift_right, ushr);
+
+fn eq(context: &mut Context) -> JsResult<ShouldExit> {
+    let rhs = context.vm.pop();
+    let lhs = context.vm.pop();
+    let value = lhs.equals(&rhs, context)?;
+    context.vm.push(value);
+    Ok(ShouldExit::False)
+}
+
+fn not_eq(context: &mut Context) -> JsResult<ShouldExit> {
+    let rhs = context.vm.pop();
+    let lhs = context.vm.pop();
+    let value = !lhs.equals(&rhs, context)?;
+    context.vm.push(value);
+    Ok(ShouldExit::False)
+}
+
+fn strict_eq(context: &mut Context) -> JsResult<ShouldExit> {
+    let rhs = context.vm.pop();
+    let lhs = context.vm.pop();
+    context.vm.push(lhs.strict_equals(&rhs));
+    Ok(ShouldExit::False)
+}
+
+fn strict_not_eq(context: &mut Context) -> JsResult<ShouldExit> {
+    let rhs = context.vm.pop();
+    let lhs = context.vm.pop();
+    context.vm.push(!lhs.strict_equals(&rhs));
+    Ok(ShouldExit::False)
+}
+
+int_cmp!(greater_than, gt, >);
+
+int_cmp!(greater_than_or_eq, ge, >=);
+
+int_cmp!(less_than, lt, <);
+
+int_cmp!(less_than_or_eq, le, <=);
+
+fn r#in(context: &mut Context) -> JsResult<ShouldExit> {
+    let rhs = context.vm.pop();
+    let lhs = context.vm.pop();
+
+    if !rhs.is_object() {
+        return context.throw_type_error(format!(
+            "right-hand side of 'in' should be an object, got {}",
+            rhs.type_of()
+        ));
+    }
+    let key = lhs.to_property_key(context)?;
+    let value = context.has_property(&rhs, &key)?;
+    context.vm.push(value);
+    Ok(ShouldExit::False)
+}
+
+fn instance_of(context: &mut Context) -> JsResult<ShouldExit> {
+    let target = context.vm.pop();
+    let v = context.vm.pop();
+    let value = v.instance_of(&target, context)?;
+
+    context.vm.push(value);
+    Ok(ShouldExit::False)
+}
+
+fn void(context: &mut Context) -> JsResult<ShouldExit> {
+    let _old = context.vm.pop();
+    context.vm.push(JsValue::undefined());
+    Ok(ShouldExit::False)
+}
+
+fn type_of(context: &mut Context) -> JsResult<ShouldExit> {
+    let value = context.vm.pop();
+    context.vm.push(value.type_of());
+    Ok(ShouldExit::False)
+}
+
+fn pos(context: &mut Context) -> JsResult<ShouldExit> {
+    let value = context.vm.pop();
+    let value = value.to_number(context)?;
+    context.vm.push(value);
+    Ok(ShouldExit::False)
+}
+
+fn neg(context: &mut Context) -> JsResult<ShouldExit> {
+    let value = context.vm.pop();
+    match value.to_numeric(context)? {
+        Numeric::Number(number) => context.vm.push(number.neg()),
+        Numeric::BigInt(bigint) => context.vm.push(JsBigInt::neg(&bigint)),
+    }
+    Ok(ShouldExit::False)
+}
+
+fn inc(context: &mut Context) -> JsResult<ShouldExit> {
+    let value = context.vm.pop();
+    match value {
+        JsValue::Integer(number) if number < i32::MAX => context.vm.push(number + 1),
+        _ => match value.to_numeric(context)? {
+            Numeric::Number(number) => context.vm.push(number + 1f64),
+            Numeric::BigInt(bigint) => {
+                context.vm.push(JsBigInt::add(&bigint, &JsBigInt::one()));
+            }
+        },
+    }
+    Ok(ShouldExit::False)
+}
+
+fn inc_post(context: &mut Context) -> JsResult<ShouldExit> {
+    let value = context.vm.pop();
+    match value {
+        JsValue::Integer(number) if number < i32::MAX => {
+            context.vm.push(number);
+            context.vm.push(number + 1);
+        }
+        _ => {
+            let value = value.to_numeric(context)?;
+            context.vm.push(value.clone());
+            match value {
+                Numeric::Number(number) => context.vm.push(number + 1f64),
+                Numeric::BigInt(bigint) => {
+                    context.vm.push(JsBigInt::add(&bigint, &JsBigInt::one()));
+                }
+            }
+        }
+    }
+    Ok(ShouldExit::False)
+}
+
+fn dec(context: &mut Context) -> JsResult<ShouldExit> {
+    let value = context.vm.pop();
+    match value {
+        JsValue::Integer(number) if number > i32::MIN => context.vm.push(number - 1),
+        _ => match value.to_numeric(context)? {
+            Numeric::Number(number) => context.vm.push(number - 1f64),
+            Numeric::BigInt(bigint) => {
+                context.vm.push(JsBigInt::sub(&bigint, &JsBigInt::one()));
+            }
+        },
+    }
+    Ok(ShouldExit::False)
+}
+
+fn dec_post(context: &mut Context) -> JsResult<ShouldExit> {
+    let value = context.vm.pop();
+    match value {
+        JsValue::Integer(number) if number > i32::MIN => {
+            context.vm.push(number);
+            context.vm.push(number - 1);
+        }
+        _ => {
+            let value = value.to_numeric(context)?;
+            context.vm.push(value.clone());
+            match value {
+                Numeric::Number(number) => context.vm.push(number - 1f64),
+                Numeric::BigInt(bigint) => {
+                    context.vm.push(JsBigInt::sub(&bigint, &JsBigInt::one()));
+                }
+            }
+        }
+    }
+    Ok(ShouldExit::False)
+}
+
+fn logical_not(context: &mut Context) -> JsResult<ShouldExit> {
+    let value = context.vm.pop();
+    context.vm.push(!value.to_boolean());
+    Ok(ShouldExit::False)
+}
+
+fn bit_not(context: &mut Context) -> JsResult<ShouldExit> {
+    let value = context.vm.pop();
+    match value.to_numeric(context)? {
+        Numeric::Number(number) => context.vm.push(Number::not(number)),
+        Numeric::BigInt(bigint) => context.vm.push(JsBigInt::not(&bigint)),
+    }
+    Ok(ShouldExit::False)
+}
+
+fn def_var(context: &mut Context) -> JsResult<ShouldExit> {
+    let index = context.vm.read::<u32>();
+    let binding_locator = context.vm.frame().code.bindings[index as usize];
+
+    if binding_locator.is_global() {
+        let key = context
+            .interner()
+            .resolve_expect(binding_locator.name())
+            .into();
+        context.global_bindings_mut().entry(key).or_insert(
+            PropertyDescriptor::builder()
+                .value(JsValue::Undefined)
+                .writable(true)
+                .enumerable(true)
+                .configurable(true)
+                .build(),
+        );
+    } else {
+        context.realm.environments.put_value_if_uninitialized(
+            binding_locator.environment_index(),
+            binding_locator.binding_index(),
+            JsValue::Undefined,
+        );
+    }
+    Ok(ShouldExit::False)
+}
+
+fn def_init_var(context: &mut Context) -> JsResult<ShouldExit> {
+    let index = context.vm.read::<u32>();
+    let value = context.vm.pop();
+    let binding_locator = context.vm.frame().code.bindings[index as usize];
+    binding_locator.throw_mutate_immutable(context)?;
+
+    if binding_locator.is_global() {
+        let key = context
+            .interner()
+            .resolve_expect(binding_locator.name())
+            .into();
+        crate::object::internal_methods::global::global_set_no_receiver(&key, value, context)?;
+    } else {
+        context.realm.environments.put_value(
+            binding_locator.environment_index(),
+            binding_locator.binding_index(),
+            value,
+        );
+    }
+    Ok(ShouldExit::False)
+}
+
+fn def_let(context: &mut Context) -> JsResult<ShouldExit> {
+    let index = context.vm.read::<u32>();
+    let binding_locator = context.vm.frame().code.bindings[index as usize];
+    context.realm.environments.put_value(
+        binding_locator.environment_index(),
+        binding_locator.binding_index(),
+        JsValue::Undefined,
+    );
+    Ok(ShouldExit::False)
+}
+
+fn def_init_binding(context: &mut Context) -> JsResult<ShouldExit> {
+    let index = context.vm.read::<u32>();
+    let value = context.vm.pop();
+    let binding_locator = context.vm.frame().code.bindings[index as usize];
+    context.realm.environments.put_value(
+        binding_locator.environment_index(),
+        binding_locator.binding_index(),
+        value,
+    );
+    Ok(ShouldExit::False)
+}
+
+fn get_name(context: &mut Context) -> JsResult<ShouldExit> {
+    let index = context.vm.read::<u32>();
+    let binding_locator = context.vm.frame().code.bindings[index as usize];
+    binding_locator.throw_mutate_immutable(context)?;
+
+    let value = if binding_locator.is_global() {
+        let key: JsString = context
+            .interner()
+            .resolve_expect(binding_locator.name())
+            .into();
+        match context.global_bindings_mut().get(&key) {
+            Some(desc) => match desc.kind() {
+                DescriptorKind::Data {
+                    value: Some(value), ..
+                } => value.clone(),
+                DescriptorKind::Accessor { get: Some(get), .. } if !get.is_undefined() => {
+                    let get = get.clone();
+                    context.call(&get, &context.global_object().clone().into(), &[])?
+                }
+                _ => return context.throw_reference_error(format!("{key} is not defined")),
+            },
+            _ => return context.throw_reference_error(format!("{key} is not defined")),
+        }
+    } else if let Some(value) = context.realm.environments.get_value_optional(
+        binding_locator.environment_index(),
+        binding_locator.binding_index(),
+    ) {
+        value
+    } else {
+        let name = JsString::from(context.interner().resolve_expect(binding_locator.name()));
+        return context.throw_reference_error(format!("{name} is not initialized"));
+    };
+
+    context.vm.push(value);
+    Ok(ShouldExit::False)
+}
+
+fn get_name_or_undefined(context: &mut Context) -> JsResult<ShouldExit> {
+    let index = context.vm.read::<u32>();
+    let binding_locator = context.vm.frame().code.bindings[index as usize];
+    binding_locator.throw_mutate_immutable(context)?;
+    let value = if binding_locator.is_global() {
+        let key: JsString = context
+            .interner()
+            .resolve_expect(binding_locator.name())
+            .into();
+        match context.global_bindings_mut().get(&key) {
+            Some(desc) => match desc.kind() {
+                DescriptorKind::Data {
+                    value: Some(value), ..
+                } => value.clone(),
+                DescriptorKind::Accessor { get: Some(get), .. } if !get.is_undefined() => {
+                    let get = get.clone();
+                    context.call(&get, &context.global_object().clone().into(), &[])?
+                }
+                _ => JsValue::undefined(),
+            },
+            _ => JsValue::undefined(),
+        }
+    } else if let Some(value) = context.realm.environments.get_value_optional(
+        binding_locator.environment_index(),
+        binding_locator.binding_index(),
+    ) {
+        value
+    } else {
+        JsValue::undefined()
+    };
+
+    context.vm.push(value);
+    Ok(ShouldExit::False)
+}
+
+fn set_name(context: &mut Context) -> JsResult<ShouldExit> {
+    let index = context.vm.read::<u32>();
+    let binding_locator = context.vm.frame().code.bindings[index as usize];
+    let value = context.vm.pop();
+    binding_locator.throw_mutate_immutable(context)?;
+
+    if binding_locator.is_global() {
+        let key: JsString = context
+            .interner()
+            .resolve_expect(binding_locator.name())
+            .into();
+        let exists = context.global_bindings_mut().contains_key(&key);
+
+        if !exists && (context.strict() || context.vm.frame().code.strict) {
+            return context
+                .throw_reference_error(format!("assignment to undeclared variable {key}"));
+        }
+
+        let success = crate::object::internal_methods::global::global_set_no_receiver(
+            &key.clone().into(),
+            value,
+            context,
+        )?;
+
+        if !success && (context.strict() || context.vm.frame().code.strict) {
+            return context.throw_type_error(format!("cannot set non-writable property: {key}",));
+        }
+    } else if !context.realm.environments.put_value_if_initialized(
+        binding_locator.environment_index(),
+        binding_locator.binding_index(),
+        value,
+    ) {
+        context.throw_reference_error(format!(
+            "cannot access '{}' before initialization",
+            context.interner().resolve_expect(binding_locator.name())
+        ))?;
+    }
+    Ok(ShouldExit::False)
+}
+
+fn get_local(context: &mut Context) -> JsResult<ShouldExit> {
+    let index = context.vm.read::<u32>();
+    let frame = context.vm.frame();
+    let binding_locator = frame.code.bindings[index as usize];
+    if let Some(value) = frame.locals[binding_locator.binding_index()].clone() {
+        context.vm.push(value);
+        Ok(ShouldExit::False)
+    } else {
+        let name = JsString::from(context.interner().resolve_expect(binding_locator.name()));
+        context.throw_reference_error(format!("{name} is not initialized"))
+    }
+}
+
+fn get_local_or_undefined(context: &mut Context) -> JsResult<ShouldExit> {
+    let index = context.vm.read::<u32>();
+    let frame = context.vm.frame();
+    let binding_locator = frame.code.bindings[index as usize];
+    let value = frame.locals[binding_locator.binding_index()]
+        .clone()
+        .unwrap_or_default();
+    context.vm.push(value);
+    Ok(ShouldExit::False)
+}
+
+fn set_local(context: &mut Context) -> JsResult<ShouldExit> {
+    let index = context.vm.read::<u32>();
+    let value = context.vm.pop();
+    let frame = context.vm.frame_mut();
+    let binding_locator = frame.code.bindings[index as usize];
+    let binding = &mut frame.locals[binding_locator.binding_index()];
+    if binding.is_none() {
+        return context.throw_reference_error(format!(
+            "cannot access '{}' before initialization",
+            context.interner().resolve_expect(binding_locator.name())
+        ));
+    }
+    *binding = Some(value);
+    Ok(ShouldExit::False)
+}
+
+fn def_var_local(context: &mut Context) -> JsResult<ShouldExit> {
+    let index = context.vm.read::<u32>();
+    let frame = context.vm.frame_mut();
+    let binding_locator = frame.code.bindings[index as usize];
+    frame.locals[binding_locator.binding_index()].get_or_insert(JsValue::Undefined);
+    Ok(ShouldExit::False)
+}
+
+fn def_init_local(context: &mut Context) -> JsResult<ShouldExit> {
+    let index = context.vm.read::<u32>();
+    let value = context.vm.pop();
+    let frame = context.vm.frame_mut();
+    let binding_locator = frame.code.bindings[index as usize];
+    frame.locals[binding_locator.binding_index()] = Some(value);
+    Ok(ShouldExit::False)
+}
+
+fn jump(context: &mut Context) -> JsResult<ShouldExit> {
+    let address = context.vm.read::<u32>();
+    context.vm.frame_mut().pc = address as usize;
+    Ok(ShouldExit::False)
+}
+
+fn jump_if_false(context: &mut Context) -> JsResult<ShouldExit> {
+    let address = context.vm.read::<u32>();
+    if !context.vm.pop().to_boolean() {
+        context.vm.frame_mut().pc = address as usize;
+    }
+    Ok(ShouldExit::False)
+}
+
+fn jump_if_not_undefined(context: &mut Context) -> JsResult<ShouldExit> {
+    let address = context.vm.read::<u32>();
+    let value = context.vm.pop();
+    if !value.is_undefined() {
+        context.vm.frame_mut().pc = address as usize;
+        context.vm.push(value);
+    }
+    Ok(ShouldExit::False)
+}
+
+fn logical_and(context: &mut Context) -> JsResult<ShouldExit> {
+    let exit = context.vm.read::<u32>();
+    let lhs = context.vm.pop();
+    if !lhs.to_boolean() {
+        context.vm.frame_mut().pc = exit as usize;
+        context.vm.push(lhs);
+    }
+    Ok(ShouldExit::False)
+}
+
+fn logical_or(context: &mut Context) -> JsResult<ShouldExit> {
+    let exit = context.vm.read::<u32>();
+    let lhs = context.vm.pop();
+    if lhs.to_boolean() {
+        context.vm.frame_mut().pc = exit as usize;
+        context.vm.push(lhs);
+    }
+    Ok(ShouldExit::False)
+}
+
+fn coalesce(context: &mut Context) -> JsResult<ShouldExit> {
+    let exit = context.vm.read::<u32>();
+    let lhs = context.vm.pop();
+    if !lhs.is_null_or_undefined() {
+        context.vm.frame_mut().pc = exit as usize;
+        context.vm.push(lhs);
+    }
+    Ok(ShouldExit::False)
+}
+
+fn to_boolean(context: &mut Context) -> JsResult<ShouldExit> {
+    let value = context.vm.pop();
+    context.vm.push(value.to_boolean());
+    Ok(ShouldExit::False)
+}
+
+fn get_property_by_name(context: &mut Context) -> JsResult<ShouldExit> {
+    let index = context.vm.read::<u32>();
+
+    let value = context.vm.pop();
+    let object = if let Some(object) = value.as_object() {
+        object.clone()
+    } else {
+        value.to_object(context)?
+    };
+
+    let name = context.vm.frame().code.names[index as usize];
+    let name: PropertyKey = context.interner().resolve_expect(name).into();
+    let result = object.get(name, context)?;
+
+    context.vm.push(result);
+    Ok(ShouldExit::False)
+}
+
+fn get_property_by_value(context: &mut Context) -> JsResult<ShouldExit> {
+    let object = context.vm.pop();
+    let key = context.vm.pop();
+
+    // Fast path for the packed elements of arrays.
+    if let (Some(array), JsValue::Integer(index)) = (object.as_object(), &key) {
+        if let Some(value) = usize::try_from(*index)
+            .ok()
+            .and_then(|index| Array::get_packed_element(array, index))
+        {
+            context.vm.push(value);
+            return Ok(ShouldExit::False);
+        }
+    }
+
+    let object = if let Some(object) = object.as_object() {
+        object.clone()
+    } else {
+        object.to_object(context)?
+    };
+
+    let key = key.to_property_key(context)?;
+    let value = object.get(key, context)?;
+
+    context.vm.push(value);
+    Ok(ShouldExit::False)
+}
+
+fn set_property_by_name(context: &mut Context) -> JsResult<ShouldExit> {
+    let index = context.vm.read::<u32>();
+
+    let object = context.vm.pop();
+    let value = context.vm.pop();
+    let object = if let Some(object) = object.as_object() {
+        object.clone()
+    } else {
+        object.to_object(context)?
+    };
+
+    let name = context.vm.frame().code.names[index as usize];
+    let name: PropertyKey = context.interner().resolve_expect(name).into();
+
+    object.set(
+        name,
+        value,
+        context.strict() || context.vm.frame().code.strict,
+        context,
+    )?;
+    Ok(ShouldExit::False)
+}
+
+fn define_own_property_by_name(context: &mut Context) -> JsResult<ShouldExit> {
+    let index = context.vm.read::<u32>();
+
+    let object = context.vm.pop();
+    let value = context.vm.pop();
+    let object = if let Some(object) = object.as_object() {
+        object.clone()
+    } else {
+        object.to_object(context)?
+    };
+
+    let name = context.vm.frame().code.names[index as usize];
+    let name = context.interner().resolve_expect(name);
+
+    object.__define_own_property__(
+        name.into(),
+        PropertyDescriptor::builder()
+            .value(value)
+            .writable(true)
+            .enumerable(true)
+            .configurable(true)
+            .build(),
+        context,
+    )?;
+    Ok(ShouldExit::False)
+}
+
+fn set_property_by_value(context: &mut Context) -> JsResult<ShouldExit> {
+    let object = context.vm.pop();
+    let key = context.vm.pop();
+    let value = context.vm.pop();
+
+    // Fast path for the packed elements of arrays.
+    if let (Some(array), JsValue::Integer(index)) = (object.as_object(), &key) {
+        if usize::try_from(*index).map_or(false, |index| {
+            Array::set_packed_element(array, index, &value)
+        }) {
+            return Ok(ShouldExit::False);
+        }
+    }
+
+    let object = if let Some(object) = object.as_object() {
+        object.clone()
+    } else {
+        object.to_object(context)?
+    };
+
+    let key = key.to_property_key(context)?;
+    object.set(
+        key,
+        value,
+        context.strict() || context.vm.frame().code.strict,
+        context,
+    )?;
+    Ok(ShouldExit::False)
+}
+
+fn define_own_property_by_value(context: &mut Context) -> JsResult<ShouldExit> {
+    let value = context.vm.pop();
+    let key = context.vm.pop();
+    let object = context.vm.pop();
+    let object = if let Some(object) = object.as_object() {
+        object.clone()
+    } else {
+        object.to_object(context)?
+    };
+
+    let key = key.to_property_key(context)?;
+
+    object.__define_own_property__(
+        key,
+        PropertyDescriptor::builder()
+            .value(value)
+            .writable(true)
+            .enumerable(true)
+            .configurable(true)
+            .build(),
+        context,
+    )?;
+    Ok(ShouldExit::False)
+}
+
+fn set_property_getter_by_name(context: &mut Context) -> JsResult<ShouldExit> {
+    let index = context.vm.read::<u32>();
+    let object = context.vm.pop();
+    let value = context.vm.pop();
+    let object = object.to_object(context)?;
+
+    let name = context.vm.frame().code.names[index as usize];
+    let name = context.interner().resolve_expect(name).into();
+    let set = object
+        .__get_own_property__(&name, context)?
+        .as_ref()
+        .and_then(PropertyDescriptor::set)
+        .cloned();
+    object.__define_own_property__(
+        name,
+        PropertyDescriptor::builder()
+            .maybe_get(Some(value))
+            .maybe_set(set)
+            .enumerable(true)
+            .configurable(true)
+            .build(),
+        context,
+    )?;
+    Ok(ShouldExit::False)
+}
+
+fn set_property_getter_by_value(context: &mut Context) -> JsResult<ShouldExit> {
+    let value = context.vm.pop();
+    let key = context.vm.pop();
+    let object = context.vm.pop();
+    let object = object.to_object(context)?;
+    let name = key.to_property_key(context)?;
+    let set = object
+        .__get_own_property__(&name, context)?
+        .as_ref()
+        .and_then(PropertyDescriptor::set)
+        .cloned();
+    object.__define_own_property__(
+        name,
+        PropertyDescriptor::builder()
+            .maybe_get(Some(value))
+            .maybe_set(set)
+            .enumerable(true)
+            .configurable(true)
+            .build(),
+        context,
+    )?;
+    Ok(ShouldExit::False)
+}
+
+fn set_property_setter_by_name(context: &mut Context) -> JsResult<ShouldExit> {
+    let index = context.vm.read::<u32>();
+    let object = context.vm.pop();
+    let value = context.vm.pop();
+    let object = object.to_object(context)?;
+    let name = context.vm.frame().code.names[index as usize];
+    let name = context.interner().resolve_expect(name).into();
+    let get = object
+        .__get_own_property__(&name, context)?
+        .as_ref()
+        .and_then(PropertyDescriptor::get)
+        .cloned();
+    object.__define_own_property__(
+        name,
+        PropertyDescriptor::builder()
+            .maybe_set(Some(value))
+            .maybe_get(get)
+            .enumerable(true)
+            .configurable(true)
+            .build(),
+        context,
+    )?;
+    Ok(ShouldExit::False)
+}
+
+fn set_property_setter_by_value(context: &mut Context) -> JsResult<ShouldExit> {
+    let value = context.vm.pop();
+    let key = context.vm.pop();
+    let object = context.vm.pop();
+    let object = object.to_object(context)?;
+    let name = key.to_property_key(context)?;
+    let get = object
+        .__get_own_property__(&name, context)?
+        .as_ref()
+        .and_then(PropertyDescriptor::get)
+        .cloned();
+    object.__define_own_property__(
+        name,
+        PropertyDescriptor::builder()
+            .maybe_set(Some(value))
+            .maybe_get(get)
+            .enumerable(true)
+            .configurable(true)
+            .build(),
+        context,
+    )?;
+    Ok(ShouldExit::False)
+}
+
+fn delete_property_by_name(context: &mut Context) -> JsResult<ShouldExit> {
+    let index = context.vm.read::<u32>();
+    let key = context.vm.frame().code.names[index as usize];
+    let key = context.interner().resolve_expect(key).into();
+    let object = context.vm.pop();
+    let result = object.to_object(context)?.__delete__(&key, context)?;
+    if !result && context.strict() || context.vm.frame().code.strict {
+        return Err(context.construct_type_error("Cannot delete property"));
+    }
+    context.vm.push(result);
+    Ok(ShouldExit::False)
+}
+
+fn delete_property_by_value(context: &mut Context) -> JsResult<ShouldExit> {
+    let object = context.vm.pop();
+    let key = context.vm.pop();
+    let result = object
+        .to_object(context)?
+        .__delete__(&key.to_property_key(context)?, context)?;
+    if !result && context.strict() || context.vm.frame().code.strict {
+        return Err(context.construct_type_error("Cannot delete property"));
+    }
+    context.vm.push(result);
+    Ok(ShouldExit::False)
+}
+
+fn copy_data_properties(context: &mut Context) -> JsResult<ShouldExit> {
+    let excluded_key_count = context.vm.read::<u32>();
+    let mut excluded_keys = Vec::with_capacity(excluded_key_count as usize);
+    for _ in 0..excluded_key_count {
+        excluded_keys.push(context.vm.pop().as_string().expect("not a string").clone());
+    }
+    let value = context.vm.pop();
+    let object = value.as_object().expect("not an object");
+    let source = context.vm.pop();
+    object.copy_data_properties(&source, excluded_keys, context)?;
+    context.vm.push(value);
+    Ok(ShouldExit::False)
+}
+
+fn throw(context: &mut Context) -> JsResult<ShouldExit> {
+    let value = context.vm.pop();
+    Err(value)
+}
+
+fn try_start(context: &mut Context) -> JsResult<ShouldExit> {
+    let next = context.vm.read::<u32>();
+    let finally = context.vm.read::<u32>();
+    let finally = if finally == 0 { None } else { Some(finally) };
+    context
+        .vm
+        .frame_mut()
+        .catch
+        .push(CatchAddresses { next, finally });
+    context.vm.frame_mut().finally_jump.push(None);
+    context.vm.frame_mut().finally_return = FinallyReturn::None;
+    context.vm.frame_mut().try_env_stack.push(TryStackEntry {
+        num_env: 0,
+        num_loop_stack_entries: 0,
+    });
+    Ok(ShouldExit::False)
+}
+
+fn try_end(context: &mut Context) -> JsResult<ShouldExit> {
+    context.vm.frame_mut().catch.pop();
+    let try_stack_entry = context
+        .vm
+        .frame_mut()
+        .try_env_stack
+        .pop()
+        .expect("must exist");
+    for _ in 0..try_stack_entry.num_env {
+        context.realm.environments.pop();
+    }
+    let mut num_env = try_stack_entry.num_env;
+    for _ in 0..try_stack_entry.num_loop_stack_entries {
+        num_env -= context
+            .vm
+            .frame_mut()
+            .loop_env_stack
+            .pop()
+            .expect("must exist");
+    }
+    *context
+        .vm
+        .frame_mut()
+        .loop_env_stack
+        .last_mut()
+        .expect("must exist") -= num_env;
+    context.vm.frame_mut().finally_return = FinallyReturn::None;
+    Ok(ShouldExit::False)
+}
+
+fn catch_start(context: &mut Context) -> JsResult<ShouldExit> {
+    let finally = context.vm.read::<u32>();
+    context.vm.frame_mut().catch.push(CatchAddresses {
+        next: finally,
+        finally: Some(finally),
+    });
+    context.vm.frame_mut().try_env_stack.push(TryStackEntry {
+        num_env: 0,
+        num_loop_stack_entries: 0,
+    });
+    Ok(ShouldExit::False)
+}
+
+fn catch_end2(context: &mut Context) -> JsResult<ShouldExit> {
+    let frame = context.vm.frame_mut();
+    if frame.finally_return == FinallyReturn::Err {
+        frame.finally_return = FinallyReturn::None;
+    }
+    Ok(ShouldExit::False)
+}
+
+fn finally_start(context: &mut Context) -> JsResult<ShouldExit> {
+    *context
+        .vm
+        .frame_mut()
+        .finally_jump
+        .last_mut()
+        .expect("finally jump must exist here") = None;
+    Ok(ShouldExit::False)
+}
+
+fn finally_end(context: &mut Context) -> JsResult<ShouldExit> {
+    let address = context
+        .vm
+        .frame_mut()
+        .finally_jump
+        .pop()
+        .expect("finally jump must exist here");
+    match context.vm.frame_mut().finally_return {
+        FinallyReturn::None => {
+            if let Some(address) = address {
+                context.vm.frame_mut().pc = address as usize;
+            }
+        }
+        FinallyReturn::Ok => {
+            return Ok(ShouldExit::True);
+        }
+        FinallyReturn::Err => {
+            return Err(context.vm.pop());
+        }
+    }
+    Ok(ShouldExit::False)
+}
+
+fn finally_set_jump(context: &mut Context) -> JsResult<ShouldExit> {
+    let address = context.vm.read::<u32>();
+    *context
+        .vm
+        .frame_mut()
+        .finally_jump
+        .last_mut()
+        .expect("finally jump must exist here") = Some(address);
+    Ok(ShouldExit::False)
+}
+
+fn push_dispose_scope(context: &mut Context) -> JsResult<ShouldExit> {
+    context
+        .vm
+        .frame_mut()
+        .dispose_stack
+        .push(DisposeCapability::default());
+    Ok(ShouldExit::False)
+}
+
+fn add_disposable_resource(context: &mut Context) -> JsResult<ShouldExit> {
+    let hint = if context.vm.read::<u32>() == 0 {
+        DisposeHint::Sync
+    } else {
+        DisposeHint::Async
+    };
+    let value = context.vm.pop();
+    let resource = DisposableResource::from_value(&value, hint, context)?;
+    if let Some(resource) = resource {
+        context
+            .vm
+            .frame_mut()
+            .dispose_stack
+            .last_mut()
+            .expect("dispose scope must exist here")
+            .add(resource);
+    }
+    context.vm.push(value);
+    Ok(ShouldExit::False)
+}
+
+fn dispose_resources(context: &mut Context) -> JsResult<ShouldExit> {
+    // Async functions are not compiled yet, so the results of `await using`
+    // disposals are never awaited here.
+    let capability = context
+        .vm
+        .frame_mut()
+        .dispose_stack
+        .pop()
+        .expect("dispose scope must exist here");
+    if context.vm.frame().finally_return == FinallyReturn::Err {
+        let error = context.vm.pop();
+        let error = capability
+            .dispose_resources(Err(error), context)
+            .expect_err("completion must stay abrupt");
+        context.vm.push(error);
+    } else {
+        capability.dispose_resources(Ok(JsValue::undefined()), context)?;
+    }
+    Ok(ShouldExit::False)
+}
+
+fn this(context: &mut Context) -> JsResult<ShouldExit> {
+    let this = context.vm.frame().this.clone();
+    context.vm.push(this);
+    Ok(ShouldExit::False)
+}
+
+fn case(context: &mut Context) -> JsResult<ShouldExit> {
+    let address = context.vm.read::<u32>();
+    let cond = context.vm.pop();
+    let value = context.vm.pop();
+
+    if value.strict_equals(&cond) {
+        context.vm.frame_mut().pc = address as usize;
+    } else {
+        context.vm.push(value);
+    }
+    Ok(ShouldExit::False)
+}
+
+fn default(context: &mut Context) -> JsResult<ShouldExit> {
+    let exit = context.vm.read::<u32>();
+    let _val = context.vm.pop();
+    context.vm.frame_mut().pc = exit as usize;
+    Ok(ShouldExit::False)
+}
+
+fn jump_table(context: &mut Context) -> JsResult<ShouldExit> {
+    let index = context.vm.read::<u32>();
+    let value = context.vm.pop();
+    let frame = context.vm.frame_mut();
+    if let Some(address) = frame.code.jump_tables[index as usize].target(&value) {
+        frame.pc = address as usize;
+    } else {
+        context.vm.push(value);
+    }
+    Ok(ShouldExit::False)
+}
+
+fn get_function(context: &mut Context) -> JsResult<ShouldExit> {
+    let index = context.vm.read::<u32>();
+    let code = context.vm.frame().code.functions[index as usize].clone();
+    let function = create_function_object(code, context);
+    context.vm.push(function);
+    Ok(ShouldExit::False)
+}
+
+fn get_generator(context: &mut Context) -> JsResult<ShouldExit> {
+    let index = context.vm.read::<u32>();
+    let code = context.vm.frame().code.functions[index as usize].clone();
+    let function = create_generator_function_object(code, context);
+    context.vm.push(function);
+    Ok(ShouldExit::False)
+}
+
+fn call(context: &mut Context) -> JsResult<ShouldExit> {
+    if context.vm.stack_size_limit <= context.vm.stack.len() {
+        return context.throw_range_error("Maximum call stack size exceeded");
+    }
+    context.check_heap()?;
+    let argument_count = context.vm.read::<u32>();
+    let mut arguments = Vec::with_capacity(argument_count as usize);
+    for _ in 0..argument_count {
+        arguments.push(context.vm.pop());
+    }
+    arguments.reverse();
+
+    let func = context.vm.pop();
+    let mut this = context.vm.pop();
+
+    let object = match func {
+        JsValue::Object(ref object) if object.is_callable() => object.clone(),
+        _ => return context.throw_type_error("not a callable function"),
+    };
+
+    if this.is_null_or_undefined() {
+        this = context.global_object().clone().into();
+    }
+
+    let result = object.__call__(&this, &arguments, context)?;
+
+    context.vm.push(result);
+    Ok(ShouldExit::False)
+}
+
+fn call_with_rest(context: &mut Context) -> JsResult<ShouldExit> {
+    if context.vm.stack_size_limit <= context.vm.stack.len() {
+        return context.throw_range_error("Maximum call stack size exceeded");
+    }
+    context.check_heap()?;
+    let argument_count = context.vm.read::<u32>();
+    let rest_argument = context.vm.pop();
+    let mut arguments = Vec::with_capacity(argument_count as usize);
+    for _ in 0..(argument_count - 1) {
+        arguments.push(context.vm.pop());
+    }
+    arguments.reverse();
+    let func = context.vm.pop();
+    let mut this = context.vm.pop();
+
+    let iterator_record = rest_argument.get_iterator(context, None, None)?;
+    let mut rest_arguments = Vec::new();
+    while let Some(next) = iterator_record.step(context)? {
+        rest_arguments.push(next.value(context)?);
+    }
+    arguments.append(&mut rest_arguments);
+
+    let object = match func {
+        JsValue::Object(ref object) if object.is_callable() => object.clone(),
+        _ => return context.throw_type_error("not a callable function"),
+    };
+
+    if this.is_null_or_undefined() {
+        this = context.global_object().clone().into();
+    }
+
+    let result = object.__call__(&this, &arguments, context)?;
+
+    context.vm.push(result);
+    Ok(ShouldExit::False)
+}
+
+fn tail_call(context: &mut Context) -> JsResult<ShouldExit> {
+    if context.vm.stack_size_limit <= context.vm.stack.len() {
+        return context.throw_range_error("Maximum call stack size exceeded");
+    }
+    context.check_heap()?;
+    let argument_count = context.vm.read::<u32>();
+    let mut arguments = Vec::with_capacity(argument_count as usize);
+    for _ in 0..argument_count {
+        arguments.push(context.vm.pop());
+    }
+    arguments.reverse();
+
+    let func = context.vm.pop();
+    let mut this = context.vm.pop();
+
+    let object = match func {
+        JsValue::Object(ref object) if object.is_callable() => object.clone(),
+        _ => return context.throw_type_error("not a callable function"),
+    };
+
+    if this.is_null_or_undefined() {
+        this = context.global_object().clone().into();
+    }
+
+    // The `finally` blocks and the disposal of resources must run after the call,
+    // so the frame can only be replaced outside of them. Otherwise, the result of
+    // the call is returned by the `Return` that follows the `TailCall`.
+    let frame = context.vm.frame();
+    let replace_frame = frame.catch.is_empty() && frame.dispose_stack.is_empty();
+    if !replace_frame || !object.tail_call_internal(&this, &arguments, context) {
+        let result = object.__call__(&this, &arguments, context)?;
+        context.vm.push(result);
+    }
+    Ok(ShouldExit::False)
+}
+
+fn new(context: &mut Context) -> JsResult<ShouldExit> {
+    if context.vm.stack_size_limit <= context.vm.stack.len() {
+        return context.throw_range_error("Maximum call stack size exceeded");
+    }
+    context.check_heap()?;
+    let argument_count = context.vm.read::<u32>();
+    let mut arguments = Vec::with_capacity(argument_count as usize);
+    for _ in 0..argument_count {
+        arguments.push(context.vm.pop());
+    }
+    arguments.reverse();
+    let func = context.vm.pop();
+
+    let result = func
+        .as_constructor()
+        .ok_or_else(|| context.construct_type_error("not a constructor"))
+        .and_then(|cons| cons.__construct__(&arguments, &cons.clone().into(), context))?;
+
+    context.vm.push(result);
+    Ok(ShouldExit::False)
+}
+
+fn new_with_rest(context: &mut Context) -> JsResult<ShouldExit> {
+    if context.vm.stack_size_limit <= context.vm.stack.len() {
+        return context.throw_range_error("Maximum call stack size exceeded");
+    }
+    context.check_heap()?;
+    let argument_count = context.vm.read::<u32>();
+    let rest_argument = context.vm.pop();
+    let mut arguments = Vec::with_capacity(argument_count as usize);
+    for _ in 0..(argument_count - 1) {
+        arguments.push(context.vm.pop());
+    }
+    arguments.reverse();
+    let func = context.vm.pop();
+
+    let iterator_record = rest_argument.get_iterator(context, None, None)?;
+    let mut rest_arguments = Vec::new();
+    while let Some(next) = iterator_record.step(context)? {
+        rest_arguments.push(next.value(context)?);
+    }
+    arguments.append(&mut rest_arguments);
+
+    let result = func
+        .as_constructor()
+        .ok_or_else(|| context.construct_type_error("not a constructor"))
+        .and_then(|cons| cons.__construct__(&arguments, &cons.clone().into(), context))?;
+
+    context.vm.push(result);
+    Ok(ShouldExit::False)
+}
+
+fn r#return(context: &mut Context) -> JsResult<ShouldExit> {
+    if let Some(finally_address) = context.vm.frame().catch.last().and_then(|c| c.finally) {
+        let frame = context.vm.frame_mut();
+        frame.pc = finally_address as usize;
+        frame.finally_return = FinallyReturn::Ok;
+        frame.catch.pop();
+        let try_stack_entry = context
+            .vm
+            .frame_mut()
+            .try_env_stack
+            .pop()
+            .expect("must exist");
+        for _ in 0..try_stack_entry.num_env {
+            context.realm.environments.pop();
+        }
+        let mut num_env = try_stack_entry.num_env;
+        for _ in 0..try_stack_entry.num_loop_stack_entries {
+            num_env -= context
+                .vm
+                .frame_mut()
+                .loop_env_stack
+                .pop()
+                .expect("must exist");
+        }
+        *context
+            .vm
+            .frame_mut()
+            .loop_env_stack
+            .last_mut()
+            .expect("must exist") -= num_env;
+    } else {
+        return Ok(ShouldExit::True);
+    }
+    Ok(ShouldExit::False)
+}
+
+fn push_declarative_environment(context: &mut Context) -> JsResult<ShouldExit> {
+    let num_bindings = context.vm.read::<u32>();
+    let first_local = context.vm.read::<u32>() as usize;
+    let num_locals = context.vm.read::<u32>() as usize;
+    context
+        .realm
+        .environments
+        .push_declarative(num_bindings as usize);
+    context.vm.frame_mut().locals[first_local..first_local + num_locals].fill(None);
+    context.vm.frame_mut().loop_env_stack_inc();
+    context.vm.frame_mut().try_env_stack_inc();
+    Ok(ShouldExit::False)
+}
+
+fn push_function_environment(context: &mut Context) -> JsResult<ShouldExit> {
+    let num_bindings = context.vm.read::<u32>();
+    let is_constructor = context.vm.frame().code.constructor;
+    let is_lexical = context.vm.frame().code.this_mode.is_lexical();
+    let this = if is_constructor || !is_lexical {
+        context.vm.frame().this.clone()
+    } else {
+        JsValue::undefined()
+    };
+
+    context
+        .realm
+        .environments
+        .push_function(num_bindings as usize, this);
+    Ok(ShouldExit::False)
+}
+
+fn pop_environment(context: &mut Context) -> JsResult<ShouldExit> {
+    context.realm.environments.pop();
+    context.vm.frame_mut().loop_env_stack_dec();
+    context.vm.frame_mut().try_env_stack_dec();
+    Ok(ShouldExit::False)
+}
+
+fn loop_start(context: &mut Context) -> JsResult<ShouldExit> {
+    context.vm.frame_mut().loop_env_stack.push(0);
+    context.vm.frame_mut().try_env_stack_loop_inc();
+    Ok(ShouldExit::False)
+}
+
+fn loop_continue(context: &mut Context) -> JsResult<ShouldExit> {
+    context.check_heap()?;
+    let env_num = context
+        .vm
+        .frame_mut()
+        .loop_env_stack
+        .last_mut()
+        .expect("loop env stack entry must exist");
+    let env_num_copy = *env_num;
+    *env_num = 0;
+    for _ in 0..env_num_copy {
+        context.realm.environments.pop();
+    }
+    Ok(ShouldExit::False)
+}
+
+fn loop_end(context: &mut Context) -> JsResult<ShouldExit> {
+    let env_num = context
+        .vm
+        .frame_mut()
+        .loop_env_stack
+        .pop()
+        .expect("loop env stack entry must exist");
+    for _ in 0..env_num {
+        context.realm.environments.pop();
+        context.vm.frame_mut().try_env_stack_dec();
+    }
+    context.vm.frame_mut().try_env_stack_loop_dec();
+    Ok(ShouldExit::False)
+}
+
+fn for_in_loop_init_iterator(context: &mut Context) -> JsResult<ShouldExit> {
+    let address = context.vm.read::<u32>();
+
+    let object = context.vm.pop();
+    if object.is_null_or_undefined() {
+        context.vm.frame_mut().pc = address as usize;
+    }
+
+    let object = object.to_object(context)?;
+    let iterator = ForInIterator::create_for_in_iterator(JsValue::new(object), context);
+    let next_function = iterator
+        .get_property("next")
+        .as_ref()
+        .map(PropertyDescriptor::expect_value)
+        .cloned()
+        .ok_or_else(|| context.construct_type_error("Could not find property `next`"))?;
+
+    context.vm.push(iterator);
+    context.vm.push(next_function);
+    Ok(ShouldExit::False)
+}
+
+fn init_iterator(context: &mut Context) -> JsResult<ShouldExit> {
+    let object = context.vm.pop();
+    let iterator = object.get_iterator(context, None, None)?;
+    context.vm.push(iterator.iterator_object());
+    context.vm.push(iterator.next_function());
+    Ok(ShouldExit::False)
+}
+
+fn iterator_next(context: &mut Context) -> JsResult<ShouldExit> {
+    let next_function = context.vm.pop();
+    let iterator = context.vm.pop();
+
+    let iterator_record = IteratorRecord::new(iterator.clone(), next_function.clone());
+    let next = iterator_record.step(context)?;
+
+    context.vm.push(iterator);
+    context.vm.push(next_function);
+    if let Some(next) = next {
+        let value = next.value(context)?;
+        context.vm.push(value);
+    } else {
+        context.vm.push(JsValue::undefined());
+    }
+    Ok(ShouldExit::False)
+}
+
+fn iterator_next_full(context: &mut Context) -> JsResult<ShouldExit> {
+    let next_function = context.vm.pop();
+    let iterator = context.vm.pop();
+
+    let iterator_record = IteratorRecord::new(iterator.clone(), next_function.clone());
+    let next = iterator_record.step(context)?;
+
+    context.vm.push(iterator);
+    context.vm.push(next_function);
+    if let Some(next) = next {
+        let value = next.value(context)?;
+        context.vm.push(false);
+        context.vm.push(value);
+    } else {
+        context.vm.push(true);
+        context.vm.push(JsValue::undefined());
+    }
+    Ok(ShouldExit::False)
+}
+
+fn iterator_close(context: &mut Context) -> JsResult<ShouldExit> {
+    let done = context.vm.pop();
+    let next_function = context.vm.pop();
+    let iterator = context.vm.pop();
+    if !done.as_boolean().expect("not a boolean") {
+        let iterator_record = IteratorRecord::new(iterator, next_function);
+        iterator_record.close(Ok(JsValue::Null), context)?;
+    }
+    Ok(ShouldExit::False)
+}
+
+fn iterator_to_array(context: &mut Context) -> JsResult<ShouldExit> {
+    let next_function = context.vm.pop();
+    let iterator = context.vm.pop();
+
+    let iterator_record = IteratorRecord::new(iterator.clone(), next_function.clone());
+    let mut values = Vec::new();
+
+    while let Some(result) = iterator_record.step(context)? {
+        values.push(result.value(context)?);
+    }
+
+    let array = Array::create_array_from_list(values, context);
+
+    context.vm.push(iterator);
+    context.vm.push(next_function);
+    context.vm.push(array);
+    Ok(ShouldExit::False)
+}
+
+fn for_in_loop_next(context: &mut Context) -> JsResult<ShouldExit> {
+    let address = context.vm.read::<u32>();
+
+    let next_function = context.vm.pop();
+    let iterator = context.vm.pop();
+
+    let iterator_record = IteratorRecord::new(iterator.clone(), next_function.clone());
+    if let Some(next) = iterator_record.step(context)? {
+        context.vm.push(iterator);
+        context.vm.push(next_function);
+        let value = next.value(context)?;
+        context.vm.push(value);
+    } else {
+        context.vm.frame_mut().pc = address as usize;
+        context.vm.frame_mut().loop_env_stack_dec();
+        context.vm.frame_mut().try_env_stack_dec();
+        context.realm.environments.pop();
+        context.vm.push(iterator);
+        context.vm.push(next_function);
+    }
+    Ok(ShouldExit::False)
+}
+
+fn concat_to_string(context: &mut Context) -> JsResult<ShouldExit> {
+    let value_count = context.vm.read::<u32>();
+    let mut strings = Vec::with_capacity(value_count as usize);
+    for _ in 0..value_count {
+        strings.push(context.vm.pop().to_string(context)?);
+    }
+    strings.reverse();
+    let s = JsString::concat_array(&strings.iter().map(JsString::as_str).collect::<Vec<&str>>());
+    context.vm.push(s);
+    Ok(ShouldExit::False)
+}
+
+fn template_lookup(context: &mut Context) -> JsResult<ShouldExit> {
+    let exit = context.vm.read::<u32>();
+    let site = context.vm.read::<u64>();
+
+    if let Some(template) = context.realm.template_map.get(&site) {
+        let template = template.clone();
+        context.vm.push(template);
+        context.vm.frame_mut().pc = exit as usize;
+    }
+    Ok(ShouldExit::False)
+}
+
+fn template_create(context: &mut Context) -> JsResult<ShouldExit> {
+    let count = context.vm.read::<u32>();
+    let site = context.vm.read::<u64>();
+
+    let template = Array::array_create(count as usize, None, context)
+        .expect("Array creation with a valid length should never fail");
+    let raw_obj = Array::array_create(count as usize, None, context)
+        .expect("Array creation with a valid length should never fail");
+
+    for index in (0..count).rev() {
+        let raw_value = context.vm.pop();
+        let cooked_value = context.vm.pop();
+        template
+            .define_property_or_throw(
+                index,
+                PropertyDescriptor::builder()
+                    .value(cooked_value)
+                    .writable(false)
+                    .enumerable(true)
+                    .configurable(false),
+                context,
+            )
+            .expect("should be able to define a property on a new array");
+        raw_obj
+            .define_property_or_throw(
+                index,
+                PropertyDescriptor::builder()
+                    .value(raw_value)
+                    .writable(false)
+                    .enumerable(true)
+                    .configurable(false),
+                context,
+            )
+            .expect("should be able to define a property on a new array");
+    }
+
+    raw_obj
+        .set_integrity_level(IntegrityLevel::Frozen, context)
+        .expect("freezing a new array must not fail");
+
+    template
+        .define_property_or_throw(
+            "raw",
+            PropertyDescriptor::builder()
+                .value(raw_obj)
+                .writable(false)
+                .enumerable(false)
+                .configurable(false),
+            context,
+        )
+        .expect("should be able to define a property on a new array");
+    template
+        .set_integrity_level(IntegrityLevel::Frozen, context)
+        .expect("freezing a new array must not fail");
+
+    context.realm.template_map.insert(site, template.clone());
+    context.vm.push(template);
+    Ok(ShouldExit::False)
+}
+
+fn require_object_coercible(context: &mut Context) -> JsResult<ShouldExit> {
+    let value = context.vm.pop();
+    let value = value.require_object_coercible(context)?;
+    context.vm.push(value);
+    Ok(ShouldExit::False)
+}
+
+fn value_not_null_or_undefined(context: &mut Context) -> JsResult<ShouldExit> {
+    let value = context.vm.pop();
+    if value.is_null() {
+        return context.throw_type_error("Cannot destructure 'null' value");
+    }
+    if value.is_undefined() {
+        return context.throw_type_error("Cannot destructure 'undefined' value");
+    }
+    context.vm.push(value);
+    Ok(ShouldExit::False)
+}
+
+fn rest_parameter_init(context: &mut Context) -> JsResult<ShouldExit> {
+    let arg_count = context.vm.frame().arg_count;
+    let param_count = context.vm.frame().param_count;
+    if arg_count >= param_count {
+        let rest_count = arg_count - param_count + 1;
+        let mut args = Vec::with_capacity(rest_count);
+        for _ in 0..rest_count {
+            args.push(context.vm.pop());
+        }
+        let array: _ = Array::create_array_from_list(args, context);
+
+        context.vm.push(array);
+    } else {
+        context.vm.pop();
+
+        let array = Array::array_create(0, None, context).expect("could not create an empty array");
+        context.vm.push(array);
+    }
+    Ok(ShouldExit::False)
+}
+
+fn rest_parameter_pop(context: &mut Context) -> JsResult<ShouldExit> {
+    let arg_count = context.vm.frame().arg_count;
+    let param_count = context.vm.frame().param_count;
+    if arg_count > param_count {
+        for _ in 0..(arg_count - param_count) {
+            context.vm.pop();
+        }
+    }
+    Ok(ShouldExit::False)
+}
+
+fn pop_on_return_add(context: &mut Context) -> JsResult<ShouldExit> {
+    context.vm.frame_mut().pop_on_return += 1;
+    Ok(ShouldExit::False)
+}
+
+fn pop_on_return_sub(context: &mut Context) -> JsResult<ShouldExit> {
+    context.vm.frame_mut().pop_on_return -= 1;
+    Ok(ShouldExit::False)
+}
+
+fn r#yield(_context: &mut Context) -> JsResult<ShouldExit> {
+    Ok(ShouldExit::Yield)
+}
+
+fn generator_next(context: &mut Context) -> JsResult<ShouldExit> {
+    match context.vm.frame().generator_resume_kind {
+        GeneratorResumeKind::Normal => Ok(ShouldExit::False),
+        GeneratorResumeKind::Throw => {
+            let received = context.vm.pop();
+            Err(received)
+        }
+        GeneratorResumeKind::Return => {
+            let mut finally_left = false;
+
+            while let Some(catch_addresses) = context.vm.frame().catch.last() {
+                if let Some(finally_address) = catch_addresses.finally {
+                    let frame = context.vm.frame_mut();
+                    frame.pc = finally_address as usize;
+                    frame.finally_return = FinallyReturn::Ok;
+                    frame.catch.pop();
+                    finally_left = true;
+                    break;
+                }
+                context.vm.frame_mut().catch.pop();
+            }
+
+            if finally_left {
+                return Ok(ShouldExit::False);
+            }
+            Ok(ShouldExit::True)
+        }
+    }
+}
+
+fn generator_next_delegate(context: &mut Context) -> JsResult<ShouldExit> {
+    let done_address = context.vm.read::<u32>();
+    let received = context.vm.pop();
+    let next_function = context.vm.pop();
+    let iterator = context.vm.pop();
+
+    match context.vm.frame().generator_resume_kind {
+        GeneratorResumeKind::Normal => {
+            let result = context.call(&next_function, &iterator, &[received])?;
+            let result_object = result.as_object().ok_or_else(|| {
+                context.construct_type_error("generator next method returned non-object")
+            })?;
+            let done = result_object.get("done", context)?.to_boolean();
+            if done {
+                context.vm.frame_mut().pc = done_address as usize;
+                let value = result_object.get("value", context)?;
+                context.vm.push(value);
+                return Ok(ShouldExit::False);
+            }
+            let value = result_object.get("value", context)?;
+            context.vm.push(iterator);
+            context.vm.push(next_function);
+            context.vm.push(value);
+            Ok(ShouldExit::Yield)
+        }
+        GeneratorResumeKind::Throw => {
+            let throw = iterator.get_method("throw", context)?;
+            if let Some(throw) = throw {
+                let result = throw.call(&iterator, &[received], context)?;
+                let result_object = result.as_object().ok_or_else(|| {
+                    context.construct_type_error("generator throw method returned non-object")
+                })?;
+                let done = result_object.get("done", context)?.to_boolean();
+                if done {
+                    context.vm.frame_mut().pc = done_address as usize;
+                    let value = result_object.get("value", context)?;
+                    context.vm.push(value);
+                    return Ok(ShouldExit::False);
+                }
+                let value = result_object.get("value", context)?;
+                context.vm.push(iterator);
+                context.vm.push(next_function);
+                context.vm.push(value);
+                return Ok(ShouldExit::Yield);
+            }
+            context.vm.frame_mut().pc = done_address as usize;
+            let iterator_record = IteratorRecord::new(iterator.clone(), next_function.clone());
+            iterator_record.close(Ok(JsValue::Undefined), context)?;
+            let error = context.construct_type_error("iterator does not have a throw method");
+            Err(error)
+        }
+        GeneratorResumeKind::Return => {
+            let r#return = iterator.get_method("return", context)?;
+            if let Some(r#return) = r#return {
+                let result = r#return.call(&iterator, &[received], context)?;
+                let result_object = result.as_object().ok_or_else(|| {
+                    context.construct_type_error("generator return method returned non-object")
+                })?;
+                let done = result_object.get("done", context)?.to_boolean();
+                if done {
+                    context.vm.frame_mut().pc = done_address as usize;
+                    let value = result_object.get("value", context)?;
+                    context.vm.push(value);
+                    return Ok(ShouldExit::True);
+                }
+                let value = result_object.get("value", context)?;
+                context.vm.push(iterator);
+                context.vm.push(next_function);
+                context.vm.push(value);
+                return Ok(ShouldExit::Yield);
+            }
+            context.vm.frame_mut().pc = done_address as usize;
+            context.vm.push(received);
+            Ok(ShouldExit::True)
+        }
+    }
+}
@@ -0,0 +1,595 @@
+//! An experimental baseline JIT compiler, enabled by the `jit` feature.
+//!
+//! Code blocks that are invoked more times than the threshold of the VM are compiled to native
+//! code with Cranelift. The native code of a code block starts at the instruction of the current
+//! frame, turns the static jumps of the bytecode into native branches, and executes the other
+//! instructions by calling the runtime functions of this module, which skips the decoding and
+//! dispatching of the interpreter loop.
+//!
+//! Instructions that can replace the current frame or jump to an address that is not an operand
+//! or in a jump table are not compiled. The native code returns to the interpreter before them,
+//! and is entered again at the next instruction once the interpreter has executed them.
+
+use super::{CodeBlock, Opcode, Operand, ShouldExit};
+use crate::{Context, JsResult, JsValue};
+use cranelift_codegen::{
+    ir::{condcodes::IntCC, types, AbiParam, Block, InstBuilder, SigRef, Signature, Type, Value},
+    isa::CallConv,
+    settings,
+};
+use cranelift_frontend::{FunctionBuilder, FunctionBuilderContext, Switch};
+use cranelift_jit::{JITBuilder, JITModule};
+use cranelift_module::{default_libcall_names, Module};
+use rustc_hash::FxHashMap;
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    fmt,
+    mem::size_of,
+    panic::{self, AssertUnwindSafe},
+    rc::Rc,
+};
+
+/// The default number of invocations of a code block after which it is compiled.
+pub(crate) const DEFAULT_THRESHOLD: u32 = 100;
+
+// The statuses returned by the runtime functions and the native code.
+
+/// The instruction was executed, the native code continues with the next one.
+const CONTINUE: u64 = 0;
+/// The instruction must be executed by the interpreter. Its address is in the upper half of the
+/// status returned by the native code.
+const FALLBACK: u64 = 1;
+/// The end of the code block was reached.
+const END: u64 = 2;
+/// The code block returned.
+const EXIT: u64 = 3;
+/// The generator of the code block yielded.
+const YIELD: u64 = 4;
+/// The instruction threw the error stored in [`Jit::error`].
+const ERROR: u64 = 5;
+/// A runtime function panicked with the payload stored in [`Jit::panic`].
+const PANIC: u64 = 6;
+
+/// The signature of the native code of a code block, which takes the context and the address of
+/// the instruction to start at.
+type NativeFunction = unsafe extern "C" fn(*mut Context, u32) -> u64;
+
+/// The state of the JIT compiler in the VM.
+#[derive(Debug)]
+pub(crate) struct Jit {
+    /// The number of invocations after which a code block is compiled, if the JIT is enabled.
+    pub(crate) threshold: Option<u32>,
+    /// The error thrown by the last instruction executed by the native code.
+    error: Option<JsValue>,
+    /// The payload of a panic in a runtime function, which is resumed once the native code has
+    /// returned.
+    panic: Option<Box<dyn Any + Send>>,
+}
+
+impl Default for Jit {
+    fn default() -> Self {
+        Self {
+            threshold: Some(DEFAULT_THRESHOLD),
+            error: None,
+            panic: None,
+        }
+    }
+}
+
+/// The compilation state of a code block.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct JitSlot {
+    invocations: Cell<u32>,
+    state: RefCell<JitState>,
+}
+
+#[derive(Debug, Clone)]
+enum JitState {
+    /// The code block has not been compiled yet.
+    Interpreted,
+    Compiled(Rc<NativeCode>),
+    /// The code block can't be compiled on this target.
+    Unsupported,
+}
+
+impl Default for JitState {
+    fn default() -> Self {
+        Self::Interpreted
+    }
+}
+
+/// The native code of a code block.
+struct NativeCode {
+    /// The module that owns the memory of the native code.
+    module: Option<JITModule>,
+    function: NativeFunction,
+}
+
+impl fmt::Debug for NativeCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NativeCode").finish_non_exhaustive()
+    }
+}
+
+impl Drop for NativeCode {
+    fn drop(&mut self) {
+        if let Some(module) = self.module.take() {
+            // Safety: the native code is only running while its code block is alive.
+            unsafe { module.free_memory() };
+        }
+    }
+}
+
+impl Context {
+    /// Counts an invocation of the code block of the current frame, if the frame starts, and
+    /// compiles the code block once it reaches the threshold.
+    pub(super) fn record_invocation(&mut self) {
+        let threshold = match self.vm.jit.threshold {
+            Some(threshold) if self.vm.frame().pc == 0 => threshold,
+            _ => return,
+        };
+
+        let code = self.vm.frame().code.clone();
+        let invocations = code.jit.invocations.get().saturating_add(1);
+        code.jit.invocations.set(invocations);
+
+        if invocations >= threshold && matches!(*code.jit.state.borrow(), JitState::Interpreted) {
+            let state = compile(&code).map_or(JitState::Unsupported, |native| {
+                JitState::Compiled(Rc::new(native))
+            });
+            *code.jit.state.borrow_mut() = state;
+        }
+    }
+
+    /// Executes the native code of the code block of the current frame, starting at the current
+    /// instruction.
+    ///
+    /// Returns `None` if the code block is not compiled, or if the interpreter must execute the
+    /// instruction the native code stopped at.
+    pub(super) fn execute_native(&mut self) -> Option<JsResult<ShouldExit>> {
+        let native = match &*self.vm.frame().code.jit.state.borrow() {
+            JitState::Compiled(native) => native.clone(),
+            JitState::Interpreted | JitState::Unsupported => return None,
+        };
+
+        let pc = self.vm.frame().pc as u32;
+
+        // Safety: the native code was compiled for the code block of the current frame, which
+        // starts an instruction at `pc`.
+        let status = unsafe { (native.function)(self, pc) };
+
+        match status & u64::from(u32::MAX) {
+            FALLBACK => {
+                self.vm.frame_mut().pc = (status >> 32) as usize;
+                None
+            }
+            END => {
+                let frame = self.vm.frame_mut();
+                frame.pc = frame.code.code.len();
+                Some(Ok(ShouldExit::False))
+            }
+            EXIT => Some(Ok(ShouldExit::True)),
+            YIELD => Some(Ok(ShouldExit::Yield)),
+            ERROR => Some(Err(self
+                .vm
+                .jit
+                .error
+                .take()
+                .expect("a failed instruction must store its error"))),
+            PANIC => panic::resume_unwind(
+                self.vm
+                    .jit
+                    .panic
+                    .take()
+                    .expect("a panicked runtime function must store its payload"),
+            ),
+            _ => unreachable!("invalid status returned by native code"),
+        }
+    }
+}
+
+/// Compiles a code block to native code, or returns `None` if the target is not supported.
+fn compile(code: &CodeBlock) -> Option<NativeCode> {
+    let isa = cranelift_native::builder()
+        .ok()?
+        .finish(settings::Flags::new(settings::builder()))
+        .ok()?;
+    let mut module = JITModule::new(JITBuilder::with_isa(isa, default_libcall_names()));
+
+    if let Some(function) = define(code, &mut module) {
+        Some(NativeCode {
+            module: Some(module),
+            function,
+        })
+    } else {
+        // Safety: no native code of the module was run.
+        unsafe { module.free_memory() };
+        None
+    }
+}
+
+/// Defines the native code of a code block in the module.
+fn define(code: &CodeBlock, module: &mut JITModule) -> Option<NativeFunction> {
+    let pointer = module.target_config().pointer_type();
+    let call_conv = module.target_config().default_call_conv;
+
+    let mut context = module.make_context();
+    context.func.signature = signature(call_conv, pointer, &[types::I32]);
+
+    let mut builder_context = FunctionBuilderContext::new();
+    let builder = FunctionBuilder::new(&mut context.func, &mut builder_context);
+    Translator::new(builder, code, call_conv, pointer).translate()?;
+
+    let id = module
+        .declare_anonymous_function(&context.func.signature)
+        .ok()?;
+    module.define_function(id, &mut context).ok()?;
+    module.clear_context(&mut context);
+    module.finalize_definitions().ok()?;
+
+    let function = module.get_finalized_function(id);
+
+    // Safety: the function was compiled with the signature of `NativeFunction`.
+    Some(unsafe { std::mem::transmute::<*const u8, NativeFunction>(function) })
+}
+
+/// Returns the signature of a function that takes the context and `params`, and returns a status.
+fn signature(call_conv: CallConv, pointer: Type, params: &[Type]) -> Signature {
+    let mut signature = Signature::new(call_conv);
+    signature.params.push(AbiParam::new(pointer));
+    signature
+        .params
+        .extend(params.iter().map(|param| AbiParam::new(*param)));
+    signature.returns.push(AbiParam::new(types::I64));
+    signature
+}
+
+/// The translation of the bytecode of a code block to Cranelift IR.
+struct Translator<'a> {
+    builder: FunctionBuilder<'a>,
+    code: &'a CodeBlock,
+    pointer: Type,
+    /// The context parameter of the native code.
+    context: Value,
+    /// The blocks of the instructions, and of the end of the code, by address.
+    blocks: FxHashMap<usize, Block>,
+    /// The block that returns the status it takes as parameter.
+    exit: Block,
+    /// The signatures of the runtime functions, by the types of their parameters after the
+    /// context.
+    signatures: FxHashMap<&'static [Type], SigRef>,
+    call_conv: CallConv,
+}
+
+impl<'a> Translator<'a> {
+    fn new(
+        mut builder: FunctionBuilder<'a>,
+        code: &'a CodeBlock,
+        call_conv: CallConv,
+        pointer: Type,
+    ) -> Self {
+        let entry = builder.create_block();
+        builder.append_block_params_for_function_params(entry);
+        builder.switch_to_block(entry);
+        let context = builder.block_params(entry)[0];
+
+        let exit = builder.create_block();
+        builder.append_block_param(exit, types::I64);
+
+        Self {
+            builder,
+            code,
+            pointer,
+            context,
+            blocks: FxHashMap::default(),
+            exit,
+            signatures: FxHashMap::default(),
+            call_conv,
+        }
+    }
+
+    /// Translates the code block, or returns `None` if it jumps to an address that does not
+    /// start an instruction.
+    fn translate(mut self) -> Option<()> {
+        let bytes = &self.code.code;
+
+        let mut instructions = Vec::new();
+        let mut pc = 0;
+        while pc < bytes.len() {
+            let opcode = Opcode::try_from(bytes[pc]).expect("invalid opcode");
+            instructions.push((pc, opcode));
+            self.blocks.insert(pc, self.builder.create_block());
+            pc += size_of::<Opcode>()
+                + opcode
+                    .operands()
+                    .iter()
+                    .copied()
+                    .map(Operand::size)
+                    .sum::<usize>();
+        }
+        let end = self.builder.create_block();
+        self.blocks.insert(bytes.len(), end);
+
+        // The native code starts by dispatching to the instruction at its address parameter.
+        let entry = self
+            .builder
+            .current_block()
+            .expect("the entry block must be current");
+        let start = self.builder.block_params(entry)[1];
+        let invalid = self.builder.create_block();
+        let mut switch = Switch::new();
+        for (pc, block) in &self.blocks {
+            switch.set_entry(*pc as u128, *block);
+        }
+        switch.emit(&mut self.builder, start, invalid);
+
+        // An address that does not start an instruction is left to the interpreter.
+        self.builder.switch_to_block(invalid);
+        let start = self.builder.ins().uextend(types::I64, start);
+        let start = self.builder.ins().ishl_imm(start, 32);
+        let status = self.builder.ins().bor_imm(start, FALLBACK as i64);
+        self.builder.ins().return_(&[status]);
+
+        self.builder.switch_to_block(end);
+        let status = self.builder.ins().iconst(types::I64, END as i64);
+        self.builder.ins().return_(&[status]);
+
+        for (index, &(pc, opcode)) in instructions.iter().enumerate() {
+            let next = instructions
+                .get(index + 1)
+                .map_or(end, |(next, _)| self.blocks[next]);
+            self.builder.switch_to_block(self.blocks[&pc]);
+            self.instruction(pc, opcode, next)?;
+        }
+
+        self.builder.switch_to_block(self.exit);
+        let status = self.builder.block_params(self.exit)[0];
+        self.builder.ins().return_(&[status]);
+
+        self.builder.seal_all_blocks();
+        self.builder.finalize();
+        Some(())
+    }
+
+    /// Translates the instruction at `pc`, which continues to the block `next`.
+    fn instruction(&mut self, pc: usize, opcode: Opcode, next: Block) -> Option<()> {
+        let operand = pc + size_of::<Opcode>();
+        match opcode {
+            Opcode::Jump => {
+                let target = self.target(operand)?;
+                self.builder.ins().jump(target, &[]);
+            }
+            Opcode::JumpIfFalse
+            | Opcode::JumpIfNotUndefined
+            | Opcode::LogicalAnd
+            | Opcode::LogicalOr
+            | Opcode::Coalesce
+            | Opcode::Case
+            | Opcode::Default
+            | Opcode::ForInLoopInitIterator
+            | Opcode::ForInLoopNext
+            | Opcode::TemplateLookup => {
+                // The interpreter decides if the jump is taken, which continues at the address of
+                // the first operand.
+                let address = self.code.read::<u32>(operand);
+                let target = self.target(operand)?;
+                let executed = self.builder.create_block();
+                self.execute(pc, executed);
+
+                self.builder.switch_to_block(executed);
+                let signature = self.signature(&[]);
+                let pc = self.runtime_call(current_pc as usize, signature, &[]);
+                let taken = self
+                    .builder
+                    .ins()
+                    .icmp_imm(IntCC::Equal, pc, i64::from(address));
+                self.builder.ins().brif(taken, target, &[], next, &[]);
+            }
+            Opcode::JumpTable => {
+                // The interpreter looks the value up in the table, and the native code continues
+                // at the address of the matching case, or with the next instruction.
+                let index = self.code.read::<u32>(operand) as usize;
+                let mut addresses = self.code.jump_tables[index]
+                    .entries()
+                    .iter()
+                    .map(|(_, address)| *address)
+                    .collect::<Vec<_>>();
+                addresses.sort_unstable();
+                addresses.dedup();
+                let executed = self.builder.create_block();
+                self.execute(pc, executed);
+
+                self.builder.switch_to_block(executed);
+                let signature = self.signature(&[]);
+                let pc = self.runtime_call(current_pc as usize, signature, &[]);
+                let mut switch = Switch::new();
+                for address in addresses {
+                    let target = self.blocks.get(&(address as usize)).copied()?;
+                    switch.set_entry(u128::from(address), target);
+                }
+                switch.emit(&mut self.builder, pc, next);
+            }
+            Opcode::Return
+            | Opcode::TailCall
+            | Opcode::FinallyEnd
+            | Opcode::GeneratorNext
+            | Opcode::GeneratorNextDelegate => {
+                let status = self
+                    .builder
+                    .ins()
+                    .iconst(types::I64, ((pc as i64) << 32) | FALLBACK as i64);
+                self.builder.ins().return_(&[status]);
+            }
+            Opcode::PushZero | Opcode::PushOne => {
+                let value = i64::from(opcode == Opcode::PushOne);
+                self.push_int(value, next);
+            }
+            Opcode::PushInt8 => self.push_int(self.code.read::<i8>(operand).into(), next),
+            Opcode::PushInt16 => self.push_int(self.code.read::<i16>(operand).into(), next),
+            Opcode::PushInt32 => self.push_int(self.code.read::<i32>(operand).into(), next),
+            Opcode::PushRational => {
+                let value = self.builder.ins().f64const(self.code.read::<f64>(operand));
+                let signature = self.signature(&[types::F64]);
+                self.call(push_rational as usize, signature, &[value], next);
+            }
+            Opcode::PushLiteral => {
+                let index = self
+                    .builder
+                    .ins()
+                    .iconst(types::I32, i64::from(self.code.read::<u32>(operand)));
+                let signature = self.signature(&[types::I32]);
+                self.call(push_literal as usize, signature, &[index], next);
+            }
+            Opcode::Pop => self.call_stack_function(pop as usize, next),
+            Opcode::Dup => self.call_stack_function(dup as usize, next),
+            Opcode::Swap => self.call_stack_function(swap as usize, next),
+            _ => self.execute(pc, next),
+        }
+        Some(())
+    }
+
+    /// Returns the block of the jump target in the operand at `operand`.
+    fn target(&self, operand: usize) -> Option<Block> {
+        let address = self.code.read::<u32>(operand) as usize;
+        self.blocks.get(&address).copied()
+    }
+
+    /// Returns the signature of a runtime function with the given parameters after the context.
+    fn signature(&mut self, params: &'static [Type]) -> SigRef {
+        if let Some(signature) = self.signatures.get(params) {
+            return *signature;
+        }
+        let signature =
+            self.builder
+                .import_signature(signature(self.call_conv, self.pointer, params));
+        self.signatures.insert(params, signature);
+        signature
+    }
+
+    /// Calls a runtime function with the context and `args`, and returns its result.
+    fn runtime_call(&mut self, function: usize, signature: SigRef, args: &[Value]) -> Value {
+        let callee = self.builder.ins().iconst(self.pointer, function as i64);
+        let mut arguments = vec![self.context];
+        arguments.extend_from_slice(args);
+        let call = self
+            .builder
+            .ins()
+            .call_indirect(signature, callee, &arguments);
+        self.builder.inst_results(call)[0]
+    }
+
+    /// Calls a runtime function, and continues to `next` if it returns [`CONTINUE`].
+    fn call(&mut self, function: usize, signature: SigRef, args: &[Value], next: Block) {
+        let status = self.runtime_call(function, signature, args);
+        self.builder
+            .ins()
+            .brif(status, self.exit, &[status], next, &[]);
+    }
+
+    fn call_stack_function(&mut self, function: usize, next: Block) {
+        let signature = self.signature(&[]);
+        self.call(function, signature, &[], next);
+    }
+
+    fn push_int(&mut self, value: i64, next: Block) {
+        let value = self.builder.ins().iconst(types::I32, value);
+        let signature = self.signature(&[types::I32]);
+        self.call(push_int as usize, signature, &[value], next);
+    }
+
+    /// Executes the instruction at `pc` with the interpreter.
+    fn execute(&mut self, pc: usize, next: Block) {
+        let pc = self.builder.ins().iconst(types::I32, pc as i64);
+        let signature = self.signature(&[types::I32]);
+        self.call(execute as usize, signature, &[pc], next);
+    }
+}
+
+/// Runs a runtime function, catching panics so they don't unwind through the native code.
+///
+/// # Safety
+///
+/// `context` must be the context running the native code.
+unsafe fn guard(context: *mut Context, f: impl FnOnce(&mut Context) -> u64) -> u64 {
+    match panic::catch_unwind(AssertUnwindSafe(|| f(&mut *context))) {
+        Ok(status) => status,
+        Err(payload) => {
+            (*context).vm.jit.panic = Some(payload);
+            PANIC
+        }
+    }
+}
+
+/// Executes the instruction at `pc` with the interpreter.
+unsafe extern "C" fn execute(context: *mut Context, pc: u32) -> u64 {
+    guard(context, |context| {
+        context.vm.frame_mut().pc = pc as usize;
+        match context.execute_instruction() {
+            Ok(ShouldExit::False) => CONTINUE,
+            Ok(ShouldExit::True) => EXIT,
+            Ok(ShouldExit::Yield) => YIELD,
+            Err(error) => {
+                context.vm.jit.error = Some(error);
+                ERROR
+            }
+        }
+    })
+}
+
+/// Returns the address of the next instruction of the current frame.
+unsafe extern "C" fn current_pc(context: *mut Context) -> u64 {
+    (*context)
+        .vm
+        .frame
+        .as_ref()
+        .map_or(0, |frame| frame.pc as u64)
+}
+
+unsafe extern "C" fn push_int(context: *mut Context, value: i32) -> u64 {
+    guard(context, |context| {
+        context.vm.push(value);
+        CONTINUE
+    })
+}
+
+unsafe extern "C" fn push_rational(context: *mut Context, value: f64) -> u64 {
+    guard(context, |context| {
+        context.vm.push(value);
+        CONTINUE
+    })
+}
+
+unsafe extern "C" fn push_literal(context: *mut Context, index: u32) -> u64 {
+    guard(context, |context| {
+        let value = context.vm.frame().code.literals[index as usize].clone();
+        context.vm.push(value);
+        CONTINUE
+    })
+}
+
+unsafe extern "C" fn pop(context: *mut Context) -> u64 {
+    guard(context, |context| {
+        let _val = context.vm.pop();
+        CONTINUE
+    })
+}
+
+unsafe extern "C" fn dup(context: *mut Context) -> u64 {
+    guard(context, |context| {
+        let value = context.vm.pop();
+        context.vm.push(value.clone());
+        context.vm.push(value);
+        CONTINUE
+    })
+}
+
+unsafe extern "C" fn swap(context: *mut Context) -> u64 {
+    guard(context, |context| {
+        let first = context.vm.pop();
+        let second = context.vm.pop();
+        context.vm.push(first);
+        context.vm.push(second);
+        CONTINUE
+    })
+}
@@ -0,0 +1,113 @@
+//! Jump tables, which dispatch the `switch` statements whose cases are constants.
+
+use crate::{JsString, JsValue};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// The value of a case in a [`JumpTable`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum JumpTableKey {
+    Integer(i32),
+    String(JsString),
+}
+
+/// A jump table maps the values of the cases of a `switch` statement to the addresses of their
+/// bodies.
+///
+/// Looking a value up in the table has the same result as comparing it with the keys in order
+/// with strict equality: an integer key matches the numbers that are equal to it, and a string
+/// key matches the equal strings.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct JumpTable {
+    /// The keys and their addresses, in the order of the cases, without repeated keys.
+    entries: Vec<(JumpTableKey, u32)>,
+    /// The smallest integer key.
+    first: i32,
+    /// The addresses of the integers from `first`, if the integer keys are dense.
+    dense: Vec<Option<u32>>,
+    /// The addresses of the integer keys, if they are not dense.
+    sparse: FxHashMap<i32, u32>,
+    /// The addresses of the string keys.
+    strings: FxHashMap<JsString, u32>,
+}
+
+impl JumpTable {
+    /// Creates a jump table from the keys of the cases and their addresses, in the order of the
+    /// cases.
+    ///
+    /// Only the first case of a repeated key can be reached, so the other ones are ignored.
+    pub(crate) fn new<I>(cases: I) -> Self
+    where
+        I: IntoIterator<Item = (JumpTableKey, u32)>,
+    {
+        let mut keys = FxHashSet::default();
+        let mut table = Self {
+            entries: cases
+                .into_iter()
+                .filter(|(key, _)| keys.insert(key.clone()))
+                .collect(),
+            ..Self::default()
+        };
+
+        let integers = table.entries.iter().filter_map(|(key, address)| match key {
+            JumpTableKey::Integer(integer) => Some((*integer, *address)),
+            JumpTableKey::String(_) => None,
+        });
+        let count = integers.clone().count();
+        let first = integers.clone().map(|(integer, _)| integer).min();
+        let last = integers.clone().map(|(integer, _)| integer).max();
+
+        // The integer keys are indexed directly if at least half of the integers in their range
+        // are keys.
+        match (first, last) {
+            (Some(first), Some(last)) if i64::from(last) - i64::from(first) < 2 * count as i64 => {
+                table.first = first;
+                table.dense = vec![None; (i64::from(last) - i64::from(first)) as usize + 1];
+                for (integer, address) in integers {
+                    table.dense[(i64::from(integer) - i64::from(first)) as usize] = Some(address);
+                }
+            }
+            _ => table.sparse = integers.collect(),
+        }
+
+        for (key, address) in &table.entries {
+            if let JumpTableKey::String(string) = key {
+                table.strings.insert(string.clone(), *address);
+            }
+        }
+
+        table
+    }
+
+    /// Returns the keys of the table and their addresses, in the order of the cases.
+    #[inline]
+    pub(crate) fn entries(&self) -> &[(JumpTableKey, u32)] {
+        &self.entries
+    }
+
+    /// Returns the address of the case that matches `value`, if there is one.
+    pub(crate) fn target(&self, value: &JsValue) -> Option<u32> {
+        match value {
+            JsValue::Integer(integer) => self.integer(*integer),
+            // A rational can be equal to an integer key, and `-0` is equal to the key `0`.
+            #[allow(clippy::float_cmp)]
+            JsValue::Rational(rational) if f64::from(*rational as i32) == *rational => {
+                self.integer(*rational as i32)
+            }
+            JsValue::String(string) => self.strings.get(string).copied(),
+            _ => None,
+        }
+    }
+
+    /// Returns the address of the case of an integer.
+    fn integer(&self, integer: i32) -> Option<u32> {
+        if self.dense.is_empty() {
+            return self.sparse.get(&integer).copied();
+        }
+        let index = i64::from(integer) - i64::from(self.first);
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| self.dense.get(index))
+            .copied()
+            .flatten()
+    }
+}
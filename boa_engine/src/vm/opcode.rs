@@ -1,3 +1,5 @@
+use std::mem::size_of;
+
 /// The opcodes of the vm.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u8)]
@@ -474,6 +476,41 @@ pub enum Opcode {
     /// Stack: value **=>**
     SetName,
 
+    /// Push the value of a local binding of the call frame.
+    ///
+    /// Operands: binding_index: `u32`
+    ///
+    /// Stack: **=>** value
+    GetLocal,
+
+    /// Push the value of a local binding of the call frame. If the binding is not initialized push undefined.
+    ///
+    /// Operands: binding_index: `u32`
+    ///
+    /// Stack: **=>** value
+    GetLocalOrUndefined,
+
+    /// Assign the value of an initialized local binding of the call frame.
+    ///
+    /// Operands: binding_index: `u32`
+    ///
+    /// Stack: value **=>**
+    SetLocal,
+
+    /// Declare a `var` type local binding of the call frame.
+    ///
+    /// Operands: binding_index: `u32`
+    ///
+    /// Stack: **=>**
+    DefVarLocal,
+
+    /// Declare and initialize a local binding of the call frame.
+    ///
+    /// Operands: binding_index: `u32`
+    ///
+    /// Stack: value **=>**
+    DefInitLocal,
+
     /// Get a property by name from an object an push it on the stack.
     ///
     /// Like `object.name`
@@ -675,6 +712,32 @@ pub enum Opcode {
     /// Stack: **=>**
     FinallySetJump,
 
+    /// Start a new scope for the resources of `using` declarations.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>**
+    PushDisposeScope,
+
+    /// Add the value on top of the stack as a resource of the current dispose scope.
+    ///
+    /// The value is checked to be disposable, except for `null` and `undefined`.
+    ///
+    /// Operands: hint: `u32`, `0` for `using` or `1` for `await using`
+    ///
+    /// Stack: value **=>** value
+    AddDisposableResource,
+
+    /// Dispose the resources of the current dispose scope, in reverse order of addition.
+    ///
+    /// Must be the first instruction of a finally block. If the block was entered with a thrown
+    /// error on the stack, errors thrown while disposing replace it, suppressing it.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>**
+    DisposeResources,
+
     /// Pops value converts it to boolean and pushes it back.
     ///
     /// Operands:
@@ -704,6 +767,14 @@ pub enum Opcode {
     /// Stack: `value` **=>**
     Default,
 
+    /// Looks up the value on top of the stack in a jump table of the code block. If a case of the
+    /// table matches, pops the value and jumps to the address of the case.
+    ///
+    /// Operands: jump_table: `u32`
+    ///
+    /// Stack: value **=>** value (if no case matches)
+    JumpTable,
+
     /// Get function from the pre-compiled inner functions.
     ///
     /// Operands: address: `u32`
@@ -732,6 +803,16 @@ pub enum Opcode {
     /// Stack: func, this, argument_1, ... argument_n **=>** result
     CallWithRest,
 
+    /// Call a function in tail position, replacing the frame of the running function.
+    ///
+    /// The call is done as with `Call` if the function is not an ordinary function, or if the
+    /// running function is in a `try` block or has resources to dispose.
+    ///
+    /// Operands: argument_count: `u32`
+    ///
+    /// Stack: func, this, argument_1, ... argument_n **=>** result
+    TailCall,
+
     /// Call construct on a function.
     ///
     /// Operands: argument_count: `u32`
@@ -755,14 +836,17 @@ pub enum Opcode {
 
     /// Push a declarative environment.
     ///
-    /// Operands: num_bindings: `u32`
+    /// The local bindings of the environment are uninitialized, since the environment may have
+    /// been entered before by the same call frame.
+    ///
+    /// Operands: num_bindings: `u32`, first_local: `u32`, num_locals: `u32`
     ///
     /// Stack: **=>**
     PushDeclarativeEnvironment,
 
     /// Push a function environment.
     ///
-    /// Operands:
+    /// Operands: num_bindings: `u32`
     ///
     /// Stack: **=>**
     PushFunctionEnvironment,
@@ -916,6 +1000,22 @@ pub enum Opcode {
     /// Stack: iterator, next_function, received **=>** iterator, next_function
     GeneratorNextDelegate,
 
+    /// Push the cached template object of a tagged template call site, if it was already created.
+    ///
+    /// If the template object exists, it is pushed and execution jumps to `exit`.
+    ///
+    /// Operands: exit: `u32`, site: `u64`
+    ///
+    /// Stack: **=>** (`template`)
+    TemplateLookup,
+
+    /// Create the frozen template object of a tagged template call site and cache it in the realm.
+    ///
+    /// Operands: count: `u32`, site: `u64`
+    ///
+    /// Stack: `cooked_1`, `raw_1`, ... `cooked_n`, `raw_n` **=>** template
+    TemplateCreate,
+
     /// No-operation instruction, does nothing.
     ///
     /// Operands:
@@ -927,6 +1027,9 @@ pub enum Opcode {
 }
 
 impl Opcode {
+    /// The number of opcodes.
+    pub(crate) const COUNT: usize = Self::Nop as usize + 1;
+
     /// Create opcode from `u8` byte.
     ///
     /// # Safety
@@ -1004,6 +1107,11 @@ impl Opcode {
             Opcode::GetName => "GetName",
             Opcode::GetNameOrUndefined => "GetNameOrUndefined",
             Opcode::SetName => "SetName",
+            Opcode::GetLocal => "GetLocal",
+            Opcode::GetLocalOrUndefined => "GetLocalOrUndefined",
+            Opcode::SetLocal => "SetLocal",
+            Opcode::DefVarLocal => "DefVarLocal",
+            Opcode::DefInitLocal => "DefInitLocal",
             Opcode::GetPropertyByName => "GetPropertyByName",
             Opcode::GetPropertyByValue => "GetPropertyByValue",
             Opcode::SetPropertyByName => "SetPropertyByName",
@@ -1029,14 +1137,19 @@ impl Opcode {
             Opcode::FinallyStart => "FinallyStart",
             Opcode::FinallyEnd => "FinallyEnd",
             Opcode::FinallySetJump => "FinallySetJump",
+            Opcode::PushDisposeScope => "PushDisposeScope",
+            Opcode::AddDisposableResource => "AddDisposableResource",
+            Opcode::DisposeResources => "DisposeResources",
             Opcode::ToBoolean => "ToBoolean",
             Opcode::This => "This",
             Opcode::Case => "Case",
             Opcode::Default => "Default",
+            Opcode::JumpTable => "JumpTable",
             Opcode::GetFunction => "GetFunction",
             Opcode::GetGenerator => "GetGenerator",
             Opcode::Call => "Call",
             Opcode::CallWithRest => "CallWithRest",
+            Opcode::TailCall => "TailCall",
             Opcode::New => "New",
             Opcode::NewWithRest => "NewWithRest",
             Opcode::Return => "Return",
@@ -1063,6 +1176,8 @@ impl Opcode {
             Opcode::Yield => "Yield",
             Opcode::GeneratorNext => "GeneratorNext",
             Opcode::GeneratorNextDelegate => "GeneratorNextDelegate",
+            Opcode::TemplateLookup => "TemplateLookup",
+            Opcode::TemplateCreate => "TemplateCreate",
             Opcode::Nop => "Nop",
         }
     }
@@ -1136,6 +1251,11 @@ impl Opcode {
             Opcode::GetName => "INST - GetName",
             Opcode::GetNameOrUndefined => "INST - GetNameOrUndefined",
             Opcode::SetName => "INST - SetName",
+            Opcode::GetLocal => "INST - GetLocal",
+            Opcode::GetLocalOrUndefined => "INST - GetLocalOrUndefined",
+            Opcode::SetLocal => "INST - SetLocal",
+            Opcode::DefVarLocal => "INST - DefVarLocal",
+            Opcode::DefInitLocal => "INST - DefInitLocal",
             Opcode::GetPropertyByName => "INST - GetPropertyByName",
             Opcode::GetPropertyByValue => "INST - GetPropertyByValue",
             Opcode::SetPropertyByName => "INST - SetPropertyByName",
@@ -1161,14 +1281,19 @@ impl Opcode {
             Opcode::FinallyStart => "INST - FinallyStart",
             Opcode::FinallyEnd => "INST - FinallyEnd",
             Opcode::FinallySetJump => "INST - FinallySetJump",
+            Opcode::PushDisposeScope => "INST - PushDisposeScope",
+            Opcode::AddDisposableResource => "INST - AddDisposableResource",
+            Opcode::DisposeResources => "INST - DisposeResources",
             Opcode::ToBoolean => "INST - ToBoolean",
             Opcode::This => "INST - This",
             Opcode::Case => "INST - Case",
             Opcode::Default => "INST - Default",
+            Opcode::JumpTable => "INST - JumpTable",
             Opcode::GetFunction => "INST - GetFunction",
             Opcode::GetGenerator => "INST - GetGenerator",
             Opcode::Call => "INST - Call",
             Opcode::CallWithRest => "INST - CallWithRest",
+            Opcode::TailCall => "INST - TailCall",
             Opcode::New => "INST - New",
             Opcode::NewWithRest => "INST - NewWithRest",
             Opcode::Return => "INST - Return",
@@ -1195,9 +1320,214 @@ impl Opcode {
             Opcode::Yield => "INST - Yield",
             Opcode::GeneratorNext => "INST - GeneratorNext",
             Opcode::GeneratorNextDelegate => "INST - GeneratorNextDelegate",
+            Opcode::TemplateLookup => "INST - TemplateLookup",
+            Opcode::TemplateCreate => "INST - TemplateCreate",
             Opcode::Nop => "INST - Nop",
         }
     }
+
+    /// Returns the operands of an instruction.
+    pub(crate) fn operands(self) -> &'static [Operand] {
+        match self {
+            Opcode::PushInt8 => &[Operand::I8],
+            Opcode::PushInt16 => &[Operand::I16],
+            Opcode::PushInt32 => &[Operand::I32],
+            Opcode::PushRational => &[Operand::F64],
+            Opcode::PushLiteral => &[Operand::Literal],
+            Opcode::Jump
+            | Opcode::JumpIfFalse
+            | Opcode::JumpIfNotUndefined
+            | Opcode::CatchStart
+            | Opcode::FinallySetJump
+            | Opcode::Case
+            | Opcode::Default
+            | Opcode::LogicalAnd
+            | Opcode::LogicalOr
+            | Opcode::Coalesce
+            | Opcode::ForInLoopInitIterator
+            | Opcode::ForInLoopNext
+            | Opcode::GeneratorNextDelegate => &[Operand::Address],
+            Opcode::TryStart => &[Operand::Address, Operand::Address],
+            Opcode::AddDisposableResource
+            | Opcode::Call
+            | Opcode::CallWithRest
+            | Opcode::TailCall
+            | Opcode::New
+            | Opcode::NewWithRest
+            | Opcode::ConcatToString
+            | Opcode::CopyDataProperties => &[Operand::Count],
+            Opcode::PushDeclarativeEnvironment => &[
+                Operand::EnvironmentSize,
+                Operand::Local,
+                Operand::LocalCount,
+            ],
+            Opcode::PushFunctionEnvironment => &[Operand::EnvironmentSize],
+            Opcode::TemplateLookup => &[Operand::Address, Operand::Site],
+            Opcode::TemplateCreate => &[Operand::Count, Operand::Site],
+            Opcode::GetFunction | Opcode::GetGenerator => &[Operand::Function],
+            Opcode::JumpTable => &[Operand::JumpTable],
+            Opcode::DefInitArg
+            | Opcode::DefVar
+            | Opcode::DefInitVar
+            | Opcode::DefLet
+            | Opcode::DefInitLet
+            | Opcode::DefInitConst
+            | Opcode::GetName
+            | Opcode::GetNameOrUndefined
+            | Opcode::SetName => &[Operand::Binding],
+            Opcode::GetLocal
+            | Opcode::GetLocalOrUndefined
+            | Opcode::SetLocal
+            | Opcode::DefVarLocal
+            | Opcode::DefInitLocal => &[Operand::LocalBinding],
+            Opcode::GetPropertyByName
+            | Opcode::SetPropertyByName
+            | Opcode::DefineOwnPropertyByName
+            | Opcode::SetPropertyGetterByName
+            | Opcode::SetPropertySetterByName
+            | Opcode::DeletePropertyByName => &[Operand::Name],
+            Opcode::Pop
+            | Opcode::Dup
+            | Opcode::Swap
+            | Opcode::PushZero
+            | Opcode::PushOne
+            | Opcode::PushNaN
+            | Opcode::PushPositiveInfinity
+            | Opcode::PushNegativeInfinity
+            | Opcode::PushNull
+            | Opcode::PushTrue
+            | Opcode::PushFalse
+            | Opcode::PushUndefined
+            | Opcode::PushEmptyObject
+            | Opcode::PushNewArray
+            | Opcode::PushValueToArray
+            | Opcode::PushElisionToArray
+            | Opcode::PushIteratorToArray
+            | Opcode::Add
+            | Opcode::Sub
+            | Opcode::Div
+            | Opcode::Mul
+            | Opcode::Mod
+            | Opcode::Pow
+            | Opcode::ShiftRight
+            | Opcode::ShiftLeft
+            | Opcode::UnsignedShiftRight
+            | Opcode::BitOr
+            | Opcode::BitAnd
+            | Opcode::BitXor
+            | Opcode::BitNot
+            | Opcode::In
+            | Opcode::Eq
+            | Opcode::StrictEq
+            | Opcode::NotEq
+            | Opcode::StrictNotEq
+            | Opcode::GreaterThan
+            | Opcode::GreaterThanOrEq
+            | Opcode::LessThan
+            | Opcode::LessThanOrEq
+            | Opcode::InstanceOf
+            | Opcode::TypeOf
+            | Opcode::Void
+            | Opcode::LogicalNot
+            | Opcode::Pos
+            | Opcode::Neg
+            | Opcode::Inc
+            | Opcode::IncPost
+            | Opcode::Dec
+            | Opcode::DecPost
+            | Opcode::GetPropertyByValue
+            | Opcode::SetPropertyByValue
+            | Opcode::DefineOwnPropertyByValue
+            | Opcode::SetPropertyGetterByValue
+            | Opcode::SetPropertySetterByValue
+            | Opcode::DeletePropertyByValue
+            | Opcode::Throw
+            | Opcode::TryEnd
+            | Opcode::CatchEnd
+            | Opcode::CatchEnd2
+            | Opcode::FinallyStart
+            | Opcode::FinallyEnd
+            | Opcode::PushDisposeScope
+            | Opcode::DisposeResources
+            | Opcode::ToBoolean
+            | Opcode::This
+            | Opcode::Return
+            | Opcode::PopEnvironment
+            | Opcode::LoopStart
+            | Opcode::LoopContinue
+            | Opcode::LoopEnd
+            | Opcode::InitIterator
+            | Opcode::IteratorNext
+            | Opcode::IteratorNextFull
+            | Opcode::IteratorClose
+            | Opcode::IteratorToArray
+            | Opcode::RequireObjectCoercible
+            | Opcode::ValueNotNullOrUndefined
+            | Opcode::RestParameterInit
+            | Opcode::RestParameterPop
+            | Opcode::PopOnReturnAdd
+            | Opcode::PopOnReturnSub
+            | Opcode::Yield
+            | Opcode::GeneratorNext
+            | Opcode::Nop => &[],
+        }
+    }
+}
+
+/// The kinds of operands of the instructions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Operand {
+    I8,
+    I16,
+    I32,
+    F64,
+    /// An index into the literals of the code block.
+    Literal,
+    /// An address in the code of the code block.
+    Address,
+    /// A number of values, or a hint.
+    Count,
+    /// The number of bindings of an environment.
+    EnvironmentSize,
+    /// An index into the functions of the code block.
+    Function,
+    /// An index into the jump tables of the code block.
+    JumpTable,
+    /// An index into the binding locators of the code block, of a binding that is not local.
+    Binding,
+    /// An index into the binding locators of the code block, of a local binding.
+    LocalBinding,
+    /// The index of the first local binding of a range in the call frame.
+    Local,
+    /// The number of local bindings of a range, whose first index is the previous operand.
+    LocalCount,
+    /// An index into the property names of the code block.
+    Name,
+    /// A tagged template call site.
+    Site,
+}
+
+impl Operand {
+    /// Returns the size of the operand in the bytecode.
+    pub(crate) fn size(self) -> usize {
+        match self {
+            Self::I8 => size_of::<i8>(),
+            Self::I16 => size_of::<i16>(),
+            Self::F64 | Self::Site => size_of::<u64>(),
+            Self::I32
+            | Self::Literal
+            | Self::Address
+            | Self::Count
+            | Self::EnvironmentSize
+            | Self::Function
+            | Self::JumpTable
+            | Self::Binding
+            | Self::LocalBinding
+            | Self::Local
+            | Self::LocalCount
+            | Self::Name => size_of::<u32>(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
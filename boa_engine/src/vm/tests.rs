@@ -1,4 +1,5 @@
 use crate::{exec, Context, JsValue};
+use std::{cell::Cell, rc::Rc};
 
 #[test]
 fn typeof_string() {
@@ -137,3 +138,688 @@ fn finally_block_binding_env() {
         Ok(JsValue::from("Hey hey people"))
     );
 }
+
+#[test]
+fn integer_fast_paths() {
+    let mut context = Context::default();
+    for (source, expected) in [
+        (
+            "let sum = 0; for (let i = 0; i < 10; i++) { sum += i * 2 - 1; } sum",
+            "80",
+        ),
+        ("2147483647 + 1", "2147483648"),
+        ("-2147483648 - 1", "-2147483649"),
+        ("-2147483648 * -1", "2147483648"),
+        ("65536 * 65536", "4294967296"),
+        ("let a = 2147483647; a++; a", "2147483648"),
+        ("let b = -2147483648; --b", "-2147483649"),
+        ("let c = 2147483647; c++", "2147483647"),
+        ("Object.is(0 * -5, -0)", "true"),
+        ("Object.is(-5 * 0, -0)", "true"),
+        ("Object.is(0 / -5, -0)", "true"),
+        ("Object.is(1 - 1, 0)", "true"),
+        ("2147483647 > -2147483648", "true"),
+        ("3 <= 3", "true"),
+        ("3 < 2.5", "false"),
+        ("'10' > 9", "true"),
+    ] {
+        assert_eq!(
+            context
+                .eval(source)
+                .map(|value| value.display().to_string()),
+            Ok(expected.to_owned()),
+            "{source}"
+        );
+    }
+}
+
+#[test]
+fn tail_calls() {
+    let mut context = Context::default();
+    for (source, expected) in [
+        (
+            r#"
+            'use strict';
+            function isEven(n) { if (n === 0) { return true; } return isOdd(n - 1); }
+            function isOdd(n) { if (n === 0) { return false; } return isEven(n - 1); }
+            isEven(100000)
+            "#,
+            "true",
+        ),
+        (
+            r#"
+            'use strict';
+            function count(n, total) {
+                switch (n) {
+                    case 0: return total;
+                    default: return count(n - 1, total + n, 'extra');
+                }
+            }
+            count(10000, 0)
+            "#,
+            "50005000",
+        ),
+        (
+            r#"
+            'use strict';
+            let log = [];
+            function inner() { log.push('inner'); return 1; }
+            function outer() {
+                try { return inner(); } finally { log.push('finally'); }
+            }
+            outer() + log.join()
+            "#,
+            "\"1inner,finally\"",
+        ),
+        (
+            r#"
+            'use strict';
+            function id(value) { return value; }
+            function Point(x) { this.x = x; return id(x); }
+            new Point(3).x
+            "#,
+            "3",
+        ),
+        (
+            r#"
+            'use strict';
+            const object = { value: 2, get() { return this.value; } };
+            function get() { return object.get(); }
+            get() + Math.max(1, 5)
+            "#,
+            "7",
+        ),
+        (
+            r#"
+            function sloppy(n) { return n === 0 ? 'done' : sloppy(n - 1); }
+            sloppy(10)
+            "#,
+            "\"done\"",
+        ),
+    ] {
+        assert_eq!(
+            context
+                .eval(source)
+                .map(|value| value.display().to_string()),
+            Ok(expected.to_owned()),
+            "{source}"
+        );
+    }
+}
+
+#[cfg(feature = "jit")]
+#[test]
+fn jit() {
+    let mut context = Context::default();
+    context.set_jit_threshold(Some(2));
+
+    for (source, expected) in [
+        (
+            r#"
+            function sum(n) {
+                let total = 0;
+                for (let i = 0; i < n; i++) { total += i % 3 === 0 ? i : -1; }
+                return total;
+            }
+            [sum(10), sum(10), sum(10), sum(1.5)].join()
+            "#,
+            "\"12,12,12,-1\"",
+        ),
+        (
+            r#"
+            function describe(value) {
+                switch (typeof value) {
+                    case 'number': return value > 0 && 'positive' || 'negative';
+                    case 'string': return value ?? 'unreachable';
+                    default: return 'other';
+                }
+            }
+            [1, -1, 'text', null, 2].map(describe).join()
+            "#,
+            "\"positive,negative,text,other,positive\"",
+        ),
+        (
+            r#"
+            function check(value) {
+                try {
+                    if (value < 0) { throw new RangeError('negative'); }
+                    return value * 2.5;
+                } catch (e) {
+                    return e.message;
+                } finally {
+                    value = 0;
+                }
+            }
+            [check(2), check(-1), check(4), check(-2)].join()
+            "#,
+            "\"5,negative,10,negative\"",
+        ),
+        (
+            r#"
+            function* numbers(n) { for (let i = 0; i < n; i++) { yield i * 10; } }
+            let total = 0;
+            for (let i = 0; i < 3; i++) {
+                for (const value of numbers(3)) { total += value; }
+            }
+            total
+            "#,
+            "90",
+        ),
+        (
+            r#"
+            'use strict';
+            function countdown(n) { if (n === 0) { return 'done'; } return countdown(n - 1); }
+            countdown(2000)
+            "#,
+            "\"done\"",
+        ),
+        (
+            r#"
+            function fail(n) { return n.missing.property; }
+            let errors = 0;
+            for (let i = 0; i < 4; i++) {
+                try { fail({}); } catch (e) { errors += e instanceof TypeError; }
+            }
+            errors
+            "#,
+            "4",
+        ),
+    ] {
+        assert_eq!(
+            context
+                .eval(source)
+                .map(|value| value.display().to_string()),
+            Ok(expected.to_owned()),
+            "{source}"
+        );
+    }
+}
+
+#[test]
+fn lazy_compilation() {
+    let mut context = Context::default();
+    context.set_lazy_compilation(true);
+
+    for (source, expected) in [
+        (
+            r#"
+            function makeCounter() {
+                let count = 0;
+                return function () { return ++count; };
+            }
+            const counter = makeCounter();
+            counter();
+            counter();
+            [counter(), makeCounter()()].join()
+            "#,
+            "\"3,1\"",
+        ),
+        (
+            r#"
+            function outer() {
+                function inner() { return value * 2; }
+                const value = 21;
+                return inner();
+            }
+            outer()
+            "#,
+            "42",
+        ),
+        (
+            r#"
+            function unused(a, b) { return a + b; }
+            const arrow = (a = 1) => a;
+            unused.name + unused.length + arrow.name + arrow.length
+            "#,
+            "\"unused2arrow0\"",
+        ),
+        (
+            r#"
+            function* range(n) { for (let i = 0; i < n; i++) { yield i; } }
+            [...range(3), ...range(2)].join()
+            "#,
+            "\"0,1,2,0,1\"",
+        ),
+        (
+            r#"
+            function Point(x) { this.x = x; }
+            Point.prototype.double = function () { return (() => this.x * 2)(); };
+            new Point(4).double()
+            "#,
+            "8",
+        ),
+        (
+            r#"
+            function mapped(a) { arguments[0] = 2; return a; }
+            function strict(a) { 'use strict'; arguments[0] = 2; return a; }
+            function factorial(n) { return n <= 1 ? 1 : n * factorial(n - 1); }
+            [mapped(1), strict(1), factorial(5)].join()
+            "#,
+            "\"2,1,120\"",
+        ),
+        (
+            r#"
+            'use strict';
+            function self() { return this; }
+            function count(n) { return n === 0 ? 'done' : count(n - 1); }
+            [self() === undefined, count(10000)].join()
+            "#,
+            "\"true,done\"",
+        ),
+    ] {
+        assert_eq!(
+            context
+                .eval(source)
+                .map(|value| value.display().to_string()),
+            Ok(expected.to_owned()),
+            "{source}"
+        );
+    }
+}
+
+#[test]
+fn switch_jump_tables() {
+    let mut context = Context::default();
+    context
+        .eval(
+            r#"
+            function dense(value) {
+                let result = '';
+                switch (value) {
+                    case 0: result += 'zero';
+                    case 1: result += 'one'; break;
+                    case 2: result += 'two'; break;
+                    case 3: result += 'three'; break;
+                    case 1: result += 'unreachable'; break;
+                    default: result += 'default';
+                }
+                return result;
+            }
+            function sparse(value) {
+                switch (value) {
+                    case -1000: return 'low';
+                    case 0: return 'zero';
+                    case 1000: return 'high';
+                    case 2.0: return 'two';
+                }
+                return 'none';
+            }
+            function strings(value) {
+                switch (value) {
+                    case 'if': return 'keyword';
+                    case 'else': return 'keyword';
+                    case '': return 'empty';
+                    case 1: return 'number';
+                    case 'x': return 'name';
+                }
+                return 'none';
+            }
+            "#,
+        )
+        .expect("the functions must be defined");
+
+    for (source, expected) in [
+        (
+            "[0, 1, 2, 3, 4, -0, 1.5, '1', undefined].map(dense).join()",
+            "\"zeroone,one,two,three,default,zeroone,default,default,default\"",
+        ),
+        (
+            "[-1000, 0, 1000, 2, 4 / 2, 999, NaN, null].map(sparse).join()",
+            "\"low,zero,high,two,two,none,none,none\"",
+        ),
+        (
+            "['if', 'else', '', 1, '1', 'x', 'y', {}].map(strings).join()",
+            "\"keyword,keyword,empty,number,none,name,none,none\"",
+        ),
+    ] {
+        assert_eq!(
+            context
+                .eval(source)
+                .map(|value| value.display().to_string()),
+            Ok(expected.to_owned()),
+            "{source}"
+        );
+    }
+}
+
+#[test]
+fn for_in_enumeration_cache() {
+    let mut context = Context::default();
+    context
+        .eval(
+            r#"
+            function keys(object) {
+                let result = [];
+                for (let key in object) {
+                    result.push(key);
+                }
+                return result.join();
+            }
+            "#,
+        )
+        .expect("the function must be defined");
+
+    for (source, expected) in [
+        (
+            "let a = { x: 1, y: 2 }; [keys(a), keys(a)].join(' ')",
+            "\"x,y x,y\"",
+        ),
+        (
+            "let b = { x: 1, y: 2 }; keys(b); b.z = 3; keys(b)",
+            "\"x,y,z\"",
+        ),
+        (
+            "let c = { x: 1, y: 2 }; keys(c); delete c.x; keys(c)",
+            "\"y\"",
+        ),
+        (
+            "let d = { x: 1, y: 2 }; keys(d); Object.defineProperty(d, 'x', { enumerable: false }); keys(d)",
+            "\"y\"",
+        ),
+        (
+            "let e = { x: 1 }; keys(e); e.x = 2; keys(e)",
+            "\"x\"",
+        ),
+        (
+            "let f = Object.create({ p: 1 }); f.x = 1; keys(f); Object.getPrototypeOf(f).q = 2; keys(f)",
+            "\"x,p,q\"",
+        ),
+        (
+            "let g = Object.create({ p: 1 }); g.x = 1; keys(g); Object.setPrototypeOf(g, { r: 1 }); keys(g)",
+            "\"x,r\"",
+        ),
+        (
+            "let h = Object.create({ x: 1, p: 2 }); Object.defineProperty(h, 'x', { value: 1 }); keys(h); keys(h)",
+            "\"p\"",
+        ),
+        (
+            "let i = { x: 1 }; keys(i); i[1] = 2; i[0] = 3; keys(i)",
+            "\"0,1,x\"",
+        ),
+        (
+            "let j = { x: 1, y: 2, z: 3 }; keys(j); let seen = []; for (let key in j) { seen.push(key); delete j.z; } seen.join()",
+            "\"x,y\"",
+        ),
+        (
+            "let k = { x: 1, y: 2 }; keys(k); let added = []; for (let key in k) { added.push(key); k.w = 1; } added.join()",
+            "\"x,y\"",
+        ),
+        (
+            "let l = Object.create({ y: 1 }); l.x = 1; keys(l); let shadowed = []; for (let key in l) { shadowed.push(key); Object.defineProperty(l, 'y', { value: 1 }); } shadowed.join()",
+            "\"x\"",
+        ),
+    ] {
+        assert_eq!(
+            context
+                .eval(source)
+                .map(|value| value.display().to_string()),
+            Ok(expected.to_owned()),
+            "{source}"
+        );
+    }
+}
+
+#[test]
+fn generator_resumption() {
+    let mut context = Context::default();
+    context
+        .eval(
+            r#"
+            function* counter(limit) {
+                let total = 0;
+                for (let i = 0; i < limit; i++) {
+                    total += yield i;
+                }
+                return total;
+            }
+            function* guarded() {
+                try {
+                    yield 1;
+                    yield 2;
+                } finally {
+                    yield 'cleanup';
+                }
+            }
+            "#,
+        )
+        .expect("the generators must be defined");
+
+    for (source, expected) in [
+        (
+            "let a = counter(1000); let values = [a.next().value]; for (let i = 1; i < 1000; i++) { values.push(a.next(i).value); } let last = a.next(1000); [values.length, values[999], last.value, last.done].join()",
+            "\"1000,999,500500,true\"",
+        ),
+        (
+            "let b = counter(3), c = counter(3); [b.next().value, c.next().value, b.next(5).value, c.next(7).value, b.next(1).value, c.next(2).value, b.next(1).value, c.next(1).value].join()",
+            "\"0,0,1,1,2,2,7,10\"",
+        ),
+        (
+            "let d = guarded(); [d.next().value, d.return(5).value, d.next().value, d.next().done].join()",
+            "\"1,cleanup,5,true\"",
+        ),
+        (
+            "let e = counter(3); e.next(); try { e.throw(new Error('stop')); } catch (error) { error.message + ',' + e.next().done }",
+            "\"stop,true\"",
+        ),
+        (
+            "let f = counter(2); [f.return(4).value, f.next().done].join()",
+            "\"4,true\"",
+        ),
+    ] {
+        assert_eq!(
+            context
+                .eval(source)
+                .map(|value| value.display().to_string()),
+            Ok(expected.to_owned()),
+            "{source}"
+        );
+    }
+}
+
+#[test]
+fn heap_limit() {
+    let mut context = Context::builder().heap_limit(32 * 1024 * 1024).build();
+    assert_eq!(
+        context.eval(
+            r#"
+            let caught;
+            try {
+                let objects = [];
+                while (true) {
+                    objects.push({ index: objects.length });
+                }
+            } catch (error) {
+                caught = error instanceof RangeError;
+            }
+            caught
+            "#
+        ),
+        Ok(JsValue::new(true))
+    );
+    assert_eq!(context.eval("[1, 2, 3].length"), Ok(JsValue::new(3)));
+
+    let calls = Rc::new(Cell::new(0));
+    let mut context = Context::builder()
+        .heap_limit(4 * 1024 * 1024)
+        .heap_limit_callback({
+            let calls = calls.clone();
+            move |size, _| {
+                calls.set(calls.get() + 1);
+                size + 4 * 1024 * 1024
+            }
+        })
+        .build();
+    assert_eq!(
+        context.eval(
+            r#"
+            let objects = [];
+            for (let i = 0; i < 100000; i++) {
+                objects.push({ index: i });
+            }
+            objects.length
+            "#
+        ),
+        Ok(JsValue::new(100_000))
+    );
+    assert!(calls.get() > 0);
+}
+
+#[test]
+fn gc_hooks() {
+    let mut context = Context::default();
+    let before = Rc::new(Cell::new(0));
+    let after = Rc::new(Cell::new(None));
+    context.set_before_gc_hook({
+        let before = before.clone();
+        move |_| before.set(before.get() + 1)
+    });
+    context.set_after_gc_hook({
+        let after = after.clone();
+        move |stats| after.set(Some(*stats))
+    });
+
+    context
+        .eval("var objects = []; for (let i = 0; i < 1000; i++) { objects.push({}); }")
+        .expect("the objects must be created");
+    let live = boa_gc::stats().live_objects;
+    context
+        .eval("objects = undefined;")
+        .expect("the objects must be released");
+
+    let pause = context.collect_garbage();
+    assert_eq!(before.get(), 1);
+    let stats = after.get().expect("the collection must be reported");
+    assert!(stats.collections > 0);
+    assert_eq!(stats.last_pause, Some(pause));
+    assert!(stats.live_objects + 1000 <= live);
+}
+
+#[test]
+fn persistent_handles() {
+    let mut context = Context::default();
+    let handle = {
+        let object = context.construct_object();
+        object
+            .set("value", 1, true, &mut context)
+            .expect("the property must be set");
+        context.root_named("object", object)
+    };
+    let weak = handle.downgrade();
+
+    boa_gc::force_collect();
+    assert!(weak.upgrade().is_some());
+    assert_eq!(handle.get("value", &mut context), Ok(JsValue::new(1)));
+
+    let clone = handle.clone();
+    let roots = context.roots();
+    assert_eq!(roots.len(), 2);
+    assert!(roots.iter().all(|root| root.name() == Some("object")));
+    assert_ne!(roots[0].id(), roots[1].id());
+
+    drop(handle);
+    assert_eq!(context.roots().len(), 1);
+    assert_eq!(context.roots()[0].id(), clone.id());
+
+    drop(clone);
+    assert!(context.roots().is_empty());
+    boa_gc::force_collect();
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn interrupt() {
+    let mut context = Context::default();
+    let interrupter = context.interrupter();
+    let watchdog = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        interrupter.interrupt();
+    });
+    let result = context.eval(
+        r#"
+        var finallyRan = false;
+        try {
+            while (true) {}
+        } catch (error) {
+            "caught";
+        } finally {
+            finallyRan = true;
+        }
+        "#,
+    );
+    watchdog.join().unwrap();
+    assert!(result.is_err());
+    assert_eq!(context.eval("finallyRan"), Ok(JsValue::new(false)));
+
+    let mut context = Context::builder().catchable_interrupts(true).build();
+    let interrupter = context.interrupter();
+    interrupter.interrupt();
+    assert!(interrupter.is_interrupted());
+    assert_eq!(
+        context.eval("try { while (true) {} } catch (error) { error.message }"),
+        Ok(JsValue::new("script execution was interrupted"))
+    );
+    assert!(!interrupter.is_interrupted());
+}
+
+#[test]
+fn instruction_budget() {
+    let mut context = Context::builder().instruction_budget(10_000).build();
+    assert_eq!(
+        context.eval("let sum = 0; for (let i = 0; i < 10; i++) { sum += i; } sum"),
+        Ok(JsValue::new(45))
+    );
+    assert!(context
+        .eval("try { while (true) {} } catch (error) { 'caught' }")
+        .is_err());
+
+    // The budget is refilled for each evaluation.
+    assert_eq!(context.eval("sum"), Ok(JsValue::new(45)));
+    assert!(context.remaining_fuel().unwrap() < 10_000);
+
+    let refuels = Rc::new(Cell::new(0));
+    let mut context = Context::builder()
+        .instruction_budget(1_000)
+        .fuel_exhausted_callback({
+            let refuels = refuels.clone();
+            move |_| {
+                refuels.set(refuels.get() + 1);
+                if refuels.get() < 5 {
+                    Some(1_000)
+                } else {
+                    None
+                }
+            }
+        })
+        .build();
+    assert!(context.eval("while (true) {}").is_err());
+    assert_eq!(refuels.get(), 5);
+}
+
+#[test]
+fn recursion_limit() {
+    let mut context = Context::builder().recursion_limit(50).build();
+    let script = r#"
+        function depth(n) { return n === 0 ? 0 : 1 + depth(n - 1); }
+        function unbounded() { return [0].map(unbounded); }
+    "#;
+    context.eval(script).unwrap();
+    assert_eq!(context.eval("depth(40)"), Ok(JsValue::new(40)));
+    assert_eq!(
+        context.eval(
+            "try { depth(60) } catch (error) { error instanceof RangeError && error.message }"
+        ),
+        Ok(JsValue::new("Maximum call stack size exceeded"))
+    );
+    // Calls made by builtins count too.
+    assert!(context.eval("unbounded()").is_err());
+    assert_eq!(context.eval("depth(40)"), Ok(JsValue::new(40)));
+}
+
+#[test]
+fn parser_recursion_limit() {
+    let mut context = Context::builder().parser_recursion_limit(64).build();
+    let nested = |levels| format!("{}1{}", "(".repeat(levels), ")".repeat(levels));
+    assert_eq!(context.eval(nested(10)), Ok(JsValue::new(1)));
+    assert!(context.eval(nested(100)).is_err());
+    assert!(context.eval("{".repeat(100) + &"}".repeat(100)).is_err());
+}
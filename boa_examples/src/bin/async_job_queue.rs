@@ -0,0 +1,111 @@
+// This example shows how to run the jobs of a context, like promise reactions, from an async
+// executor instead of running them all when a script finishes.
+
+use boa_engine::{
+    job::{JobQueue, NativeJob},
+    Context, JsResult,
+};
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    task::{Context as TaskContext, Poll, RawWaker, RawWakerVTable, Waker},
+};
+
+/// A job queue whose jobs are run by an async task, which yields to the executor between jobs.
+#[derive(Debug, Default)]
+struct AsyncJobQueue(RefCell<VecDeque<NativeJob>>);
+
+impl AsyncJobQueue {
+    /// Runs the pending jobs, including the jobs enqueued while running them, letting the other
+    /// tasks of the executor run after each job.
+    async fn run_jobs_async(&self, context: &mut Context) -> JsResult<()> {
+        loop {
+            let job = self.0.borrow_mut().pop_front();
+            match job {
+                Some(job) => job.call(context)?,
+                None => return Ok(()),
+            };
+            YieldNow(false).await;
+        }
+    }
+}
+
+impl JobQueue for AsyncJobQueue {
+    fn enqueue_job(&self, job: NativeJob, _: &mut Context) {
+        self.0.borrow_mut().push_back(job);
+    }
+
+    fn run_jobs(&self, _: &mut Context) -> JsResult<()> {
+        // The jobs are run by `run_jobs_async`, so the context doesn't run them itself.
+        Ok(())
+    }
+}
+
+/// A future that returns to the executor once before completing.
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<()> {
+        if self.0 {
+            return Poll::Ready(());
+        }
+        self.0 = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+/// A minimal executor running a single future on the current thread. Applications would use the
+/// executor of their runtime instead, like a `tokio::task::LocalSet`, since contexts can't be
+/// sent to other threads.
+fn block_on<F: Future>(future: F) -> F::Output {
+    fn raw_waker() -> RawWaker {
+        fn clone(_: *const ()) -> RawWaker {
+            raw_waker()
+        }
+        fn noop(_: *const ()) {}
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+        RawWaker::new(std::ptr::null(), &VTABLE)
+    }
+
+    // SAFETY: the functions of the vtable don't use the data pointer.
+    let waker = unsafe { Waker::from_raw(raw_waker()) };
+    let mut cx = TaskContext::from_waker(&waker);
+    let mut future = Box::pin(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+fn main() -> JsResult<()> {
+    // The context shares the queue with us, so we can run its jobs ourselves.
+    let queue = Rc::new(AsyncJobQueue::default());
+    let mut context = Context::builder().job_queue(queue.clone()).build();
+
+    context.eval(
+        r#"
+        var log = [];
+        Promise.resolve(1)
+            .then((v) => log.push("first " + v))
+            .then(() => log.push("second"));
+        "#,
+    )?;
+
+    // The reactions haven't run yet, since the queue waits for the executor.
+    assert_eq!(context.eval("log.length")?, 0.into());
+
+    block_on(queue.run_jobs_async(&mut context))?;
+
+    let log = context.eval("log.join(', ')")?;
+    println!("{}", log.display());
+    assert_eq!(log, "first 1, second".into());
+
+    Ok(())
+}
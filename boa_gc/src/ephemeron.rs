@@ -0,0 +1,95 @@
+//! Ephemeron tables, which store the entries of the weak collections like `WeakMap`.
+//!
+//! An entry of a weak collection is an ephemeron: its value must stay alive only while both the
+//! collection and the key are alive. The collector has no special handling of ephemerons, so a
+//! collection tracing its values would keep alive the keys referenced by its own values. Instead,
+//! each key owns an [`EphemeronTable`], which stores the values associated with the key by each
+//! collection. The values are traced with the key, and a collection is identified in the tables
+//! by a [`WeakGc`] pointing to it, which doesn't keep it alive.
+//!
+//! The values of a collection that was collected stay in the tables of their keys until these
+//! tables are modified, or until the keys are collected.
+
+use crate::WeakGc;
+use gc::{custom_trace, Finalize, Trace};
+use std::{cell::Cell, fmt, mem, rc::Rc};
+
+/// The values associated with a key by the weak collections containing the key.
+pub struct EphemeronTable<V: Trace> {
+    /// The values, with the liveness flags of their collections.
+    entries: Vec<(Rc<Cell<bool>>, V)>,
+}
+
+impl<V: Trace> EphemeronTable<V> {
+    /// Creates an empty table.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the value associated with the key by `collection`.
+    #[inline]
+    pub fn get<T: Trace + ?Sized + 'static>(&self, collection: &WeakGc<T>) -> Option<&V> {
+        self.entries
+            .iter()
+            .find(|(slot, _)| Rc::ptr_eq(slot, &collection.cleared))
+            .map(|(_, value)| value)
+    }
+
+    /// Associates a value with the key in `collection`, returning the previous value.
+    pub fn insert<T: Trace + ?Sized + 'static>(
+        &mut self,
+        collection: &WeakGc<T>,
+        value: V,
+    ) -> Option<V> {
+        // Drop the values of the collections that were collected.
+        self.entries.retain(|(slot, _)| !slot.get());
+
+        if let Some((_, old)) = self
+            .entries
+            .iter_mut()
+            .find(|(slot, _)| Rc::ptr_eq(slot, &collection.cleared))
+        {
+            return Some(mem::replace(old, value));
+        }
+        self.entries.push((collection.cleared.clone(), value));
+        None
+    }
+
+    /// Removes the value associated with the key by `collection`.
+    pub fn remove<T: Trace + ?Sized + 'static>(&mut self, collection: &WeakGc<T>) -> Option<V> {
+        let index = self
+            .entries
+            .iter()
+            .position(|(slot, _)| Rc::ptr_eq(slot, &collection.cleared))?;
+        Some(self.entries.swap_remove(index).1)
+    }
+}
+
+impl<V: Trace> Default for EphemeronTable<V> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<V: Trace> fmt::Debug for EphemeronTable<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EphemeronTable")
+            .field("len", &self.entries.len())
+            .finish()
+    }
+}
+
+impl<V: Trace> Finalize for EphemeronTable<V> {}
+
+// SAFETY: The values are traced, and the liveness flags contain no garbage collected pointers.
+unsafe impl<V: Trace> Trace for EphemeronTable<V> {
+    custom_trace!(this, {
+        for (_, value) in &this.entries {
+            mark(value);
+        }
+    });
+}
@@ -1,6 +1,21 @@
 //! Garbage collector for the Boa JavaScript engine.
+//!
+//! The collector is the mark and sweep collector of the `gc` crate, which marks the heap on the
+//! thread that owns it. Marking can't be split between threads: the garbage collected pointers,
+//! their root counts and the `Trace` implementations of the engine use `Cell` and `Rc`, which are
+//! not thread safe, and the marking phase of the `gc` crate can't be replaced from this crate.
+//! Embedders with large heaps can shorten the pauses by collecting between tasks with
+//! [`collect`], and by raising the threshold of the automatic collections with
+//! [`set_threshold`].
 
+mod ephemeron;
+mod stats;
+mod weak;
+
+pub use ephemeron::EphemeronTable;
 pub use gc::{
     custom_trace, force_collect, unsafe_empty_trace, Finalize, Gc, GcCell as Cell,
     GcCellRef as Ref, GcCellRefMut as RefMut, Trace,
 };
+pub use stats::{allocated_bytes, collect, set_threshold, stats, threshold, GcStats, LiveCounter};
+pub use weak::{notify_cleared, take_cleared, WeakGc, WeakSlot};
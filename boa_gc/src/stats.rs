@@ -0,0 +1,124 @@
+//! Statistics and configuration of the collector.
+//!
+//! The collector runs automatically when an allocation makes the heap grow past its threshold.
+//! These collections are counted, but only the collections started with [`collect`] are timed,
+//! since nothing runs around the automatic ones.
+
+use gc::{unsafe_empty_trace, Finalize, Trace};
+use std::{
+    cell::Cell,
+    fmt,
+    time::{Duration, Instant},
+};
+
+thread_local! {
+    /// The number of live values owning a `LiveCounter`.
+    static LIVE_OBJECTS: Cell<usize> = Cell::new(0);
+    /// The total and the last duration of the collections started with `collect`.
+    static PAUSES: Cell<(Duration, Option<Duration>)> = Cell::new((Duration::ZERO, None));
+}
+
+/// The statistics of the collector of the current thread.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GcStats {
+    /// The number of bytes allocated by the collector.
+    pub bytes_allocated: usize,
+    /// The number of live values that own a [`LiveCounter`].
+    pub live_objects: usize,
+    /// The number of collections performed, including the automatic ones.
+    pub collections: usize,
+    /// The total duration of the collections started with [`collect`].
+    pub total_pause: Duration,
+    /// The duration of the last collection started with [`collect`].
+    pub last_pause: Option<Duration>,
+}
+
+/// Returns the number of bytes allocated by the collector of the current thread.
+#[inline]
+pub fn allocated_bytes() -> usize {
+    gc::stats().bytes_allocated
+}
+
+/// Returns the statistics of the collector of the current thread.
+#[inline]
+pub fn stats() -> GcStats {
+    let gc = gc::stats();
+    let (total_pause, last_pause) = PAUSES.with(Cell::get);
+    GcStats {
+        bytes_allocated: gc.bytes_allocated,
+        live_objects: LIVE_OBJECTS.with(Cell::get),
+        collections: gc.collections_performed,
+        total_pause,
+        last_pause,
+    }
+}
+
+/// Runs a full collection and returns its duration.
+pub fn collect() -> Duration {
+    let start = Instant::now();
+    gc::force_collect();
+    let pause = start.elapsed();
+    PAUSES.with(|pauses| {
+        let (total, _) = pauses.get();
+        pauses.set((total + pause, Some(pause)));
+    });
+    pause
+}
+
+/// Returns the number of allocated bytes that triggers the next automatic collection.
+#[inline]
+pub fn threshold() -> usize {
+    let mut threshold = 0;
+    gc::configure(|config| threshold = config.threshold);
+    threshold
+}
+
+/// Sets the number of allocated bytes that triggers the next automatic collection.
+///
+/// After each collection, the collector raises the threshold if most of the heap is still in use.
+#[inline]
+pub fn set_threshold(bytes: usize) {
+    gc::configure(|config| config.threshold = bytes);
+}
+
+/// Counts the live values that own it in [`GcStats::live_objects`].
+///
+/// The counter must be stored inside the value it counts, so that it is dropped together with it.
+pub struct LiveCounter(());
+
+impl LiveCounter {
+    /// Creates a counter for a new value.
+    #[inline]
+    pub fn new() -> Self {
+        LIVE_OBJECTS.with(|live| live.set(live.get() + 1));
+        Self(())
+    }
+}
+
+impl Default for LiveCounter {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for LiveCounter {
+    #[inline]
+    fn drop(&mut self) {
+        // The thread local might already be destroyed if this runs during thread teardown.
+        let _ = LIVE_OBJECTS.try_with(|live| live.set(live.get().saturating_sub(1)));
+    }
+}
+
+impl fmt::Debug for LiveCounter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("LiveCounter")
+    }
+}
+
+impl Finalize for LiveCounter {}
+
+// SAFETY: A counter contains no garbage collected pointers.
+unsafe impl Trace for LiveCounter {
+    unsafe_empty_trace!();
+}
@@ -0,0 +1,145 @@
+//! Weak references to garbage collected values.
+//!
+//! The collector has no notion of weak edges, so weak references are built on top of
+//! finalization: a value that can be weakly referenced owns a [`WeakSlot`], which is cleared
+//! when the collector finalizes the value. A [`WeakGc`] keeps an untraced, unrooted pointer to
+//! the value together with a handle to its slot, and only hands out the pointer while the slot
+//! has not been cleared.
+
+use gc::{unsafe_empty_trace, Finalize, Gc, Trace};
+use std::{cell::Cell, fmt, rc::Rc};
+
+thread_local! {
+    /// Set whenever a collection clears a `WeakSlot`, until it is taken by `take_cleared`.
+    static CLEARED: Cell<bool> = Cell::new(false);
+}
+
+/// Returns `true` if a collection cleared at least one [`WeakSlot`] since the last call.
+///
+/// This is meant to be polled after running code, to schedule the cleanup work of the values
+/// that were only weakly reachable, since no code can run while the collector is running.
+#[inline]
+pub fn take_cleared() -> bool {
+    CLEARED
+        .try_with(|cleared| cleared.replace(false))
+        .unwrap_or(false)
+}
+
+/// Records that a weakly referenced value was dropped, so that the next [`take_cleared`] call
+/// returns `true`.
+///
+/// Values that are not allocated by the collector, but that the collector can free by dropping
+/// the last value owning them, call this when they are dropped while weak references to them
+/// remain.
+#[inline]
+pub fn notify_cleared() {
+    // The thread local might already be destroyed if this runs during thread teardown, in
+    // which case nobody is left to run the cleanup anyway.
+    let _ = CLEARED.try_with(|cleared| cleared.set(true));
+}
+
+/// The liveness flag of a garbage collected value that can be weakly referenced.
+///
+/// A slot must be stored inside the value it tracks, so that it is finalized together with it.
+#[derive(Default)]
+pub struct WeakSlot {
+    cleared: Rc<Cell<bool>>,
+}
+
+impl WeakSlot {
+    /// Creates a new slot for a live value.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if the value owning this slot was collected.
+    #[inline]
+    pub fn is_cleared(&self) -> bool {
+        self.cleared.get()
+    }
+}
+
+impl fmt::Debug for WeakSlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakSlot")
+            .field("cleared", &self.cleared.get())
+            .finish()
+    }
+}
+
+impl Finalize for WeakSlot {
+    fn finalize(&self) {
+        self.cleared.set(true);
+        notify_cleared();
+    }
+}
+
+// SAFETY: A slot contains no garbage collected pointers.
+unsafe impl Trace for WeakSlot {
+    unsafe_empty_trace!();
+}
+
+/// A weak reference to a value allocated by the garbage collector.
+///
+/// A `WeakGc` never keeps its value alive: it is neither traced nor rooted.
+pub struct WeakGc<T: Trace + ?Sized + 'static> {
+    /// Unrooted pointer to the value, only valid while `cleared` is `false`.
+    inner: Gc<T>,
+    pub(crate) cleared: Rc<Cell<bool>>,
+}
+
+impl<T: Trace + ?Sized + 'static> WeakGc<T> {
+    /// Creates a weak reference to `value`.
+    ///
+    /// # Safety
+    ///
+    /// `slot` must be owned by the value `value` points to, so that the slot is cleared when the
+    /// value is finalized. Otherwise [`WeakGc::upgrade`] may return a dangling pointer.
+    #[inline]
+    pub unsafe fn new(value: &Gc<T>, slot: &WeakSlot) -> Self {
+        let inner = value.clone();
+
+        // `clone` returns a rooted pointer; unroot it so it doesn't keep the value alive. An
+        // unrooted `Gc` doesn't touch its allocation when dropped, which keeps dropping a
+        // cleared `WeakGc` sound.
+        inner.unroot();
+
+        Self {
+            inner,
+            cleared: slot.cleared.clone(),
+        }
+    }
+
+    /// Returns a strong pointer to the value, or `None` if it was collected.
+    #[inline]
+    pub fn upgrade(&self) -> Option<Gc<T>> {
+        if self.cleared.get() {
+            None
+        } else {
+            Some(self.inner.clone())
+        }
+    }
+
+    /// Returns `true` if the value was collected.
+    #[inline]
+    pub fn is_cleared(&self) -> bool {
+        self.cleared.get()
+    }
+}
+
+impl<T: Trace + ?Sized + 'static> fmt::Debug for WeakGc<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakGc")
+            .field("cleared", &self.cleared.get())
+            .finish()
+    }
+}
+
+impl<T: Trace + ?Sized + 'static> Finalize for WeakGc<T> {}
+
+// SAFETY: The pointer is deliberately not traced nor rooted, and the root operations must be
+// no-ops to keep it unrooted when the containing value is moved in and out of the heap.
+unsafe impl<T: Trace + ?Sized + 'static> Trace for WeakGc<T> {
+    unsafe_empty_trace!();
+}
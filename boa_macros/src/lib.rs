@@ -0,0 +1,406 @@
+//! Procedural macros for Boa.
+//!
+//! The derives of this crate are re-exported by `boa_engine::value`, next to the traits they
+//! implement, which document the conversions.
+
+#![warn(
+    clippy::perf,
+    clippy::single_match_else,
+    clippy::dbg_macro,
+    clippy::doc_markdown,
+    clippy::wildcard_imports,
+    clippy::struct_excessive_bools,
+    clippy::semicolon_if_nothing_returned,
+    clippy::pedantic
+)]
+#![deny(
+    clippy::all,
+    clippy::use_self,
+    unused_qualifications,
+    unused_import_braces,
+    unused_lifetimes,
+    unreachable_pub,
+    trivial_numeric_casts,
+    rust_2018_idioms,
+    future_incompatible,
+    nonstandard_style
+)]
+#![allow(clippy::module_name_repetitions, clippy::missing_panics_doc)]
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{quote, quote_spanned};
+use syn::{
+    parse_macro_input, spanned::Spanned, Attribute, Data, DeriveInput, Error, Fields, GenericParam,
+    Generics, Ident, Lit, LitStr, Meta, NestedMeta, Result,
+};
+
+/// Derives `TryFromJs` for a struct or an enum.
+#[proc_macro_derive(TryFromJs, attributes(boa))]
+pub fn derive_try_from_js(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    try_from_js(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derives `TryIntoJs` for a struct or an enum.
+#[proc_macro_derive(TryIntoJs, attributes(boa))]
+pub fn derive_try_into_js(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    try_into_js(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// The options of a `#[boa(...)]` attribute.
+#[derive(Default)]
+struct Options {
+    rename: Option<LitStr>,
+    tag: Option<LitStr>,
+    default: bool,
+    optional: bool,
+}
+
+impl Options {
+    /// Parses the `#[boa(...)]` attributes of an item.
+    fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut options = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("boa")) {
+            let list = match attr.parse_meta()? {
+                Meta::List(list) => list,
+                meta => return Err(Error::new(meta.span(), "expected `#[boa(...)]`")),
+            };
+            for nested in list.nested {
+                match nested {
+                    NestedMeta::Meta(Meta::NameValue(pair)) => {
+                        let value = match pair.lit {
+                            Lit::Str(value) => value,
+                            lit => return Err(Error::new(lit.span(), "expected a string")),
+                        };
+                        if pair.path.is_ident("rename") {
+                            options.rename = Some(value);
+                        } else if pair.path.is_ident("tag") {
+                            options.tag = Some(value);
+                        } else {
+                            return Err(Error::new(pair.path.span(), "unknown `boa` option"));
+                        }
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("default") => {
+                        options.default = true;
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("optional") => {
+                        options.optional = true;
+                    }
+                    nested => return Err(Error::new(nested.span(), "unknown `boa` option")),
+                }
+            }
+        }
+        Ok(options)
+    }
+
+    /// Returns the JavaScript name of a field or a variant.
+    fn name(&self, ident: &Ident) -> LitStr {
+        self.rename
+            .clone()
+            .unwrap_or_else(|| LitStr::new(&ident.to_string(), ident.span()))
+    }
+}
+
+/// A named field, or a field of a variant.
+struct NamedField {
+    ident: Ident,
+    key: LitStr,
+    options: Options,
+}
+
+/// The shape of a struct or a variant.
+enum Shape {
+    Named(Vec<NamedField>),
+    Newtype,
+    Unit,
+}
+
+impl Shape {
+    fn new(fields: &Fields) -> Result<Self> {
+        match fields {
+            Fields::Named(named) => named
+                .named
+                .iter()
+                .map(|field| {
+                    let options = Options::parse(&field.attrs)?;
+                    let ident = field
+                        .ident
+                        .clone()
+                        .expect("named fields have an identifier");
+                    Ok(NamedField {
+                        key: options.name(&ident),
+                        ident,
+                        options,
+                    })
+                })
+                .collect::<Result<_>>()
+                .map(Self::Named),
+            Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => Ok(Self::Newtype),
+            Fields::Unnamed(unnamed) => Err(Error::new(
+                unnamed.span(),
+                "only a single unnamed field is supported",
+            )),
+            Fields::Unit => Ok(Self::Unit),
+        }
+    }
+}
+
+/// Adds a bound on `bound` to the type parameters of `generics`.
+fn add_bounds(generics: &Generics, bound: &TokenStream2) -> Generics {
+    let mut generics = generics.clone();
+    for param in &mut generics.params {
+        if let GenericParam::Type(param) = param {
+            param.bounds.push(syn::parse_quote!(#bound));
+        }
+    }
+    generics
+}
+
+fn try_from_js(input: &DeriveInput) -> Result<TokenStream2> {
+    let name = &input.ident;
+    let trait_path = quote!(::boa_engine::value::TryFromJs);
+    let generics = add_bounds(&input.generics, &trait_path);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => match Shape::new(&data.fields)? {
+            Shape::Named(fields) => {
+                let fields = read_fields(&fields);
+                quote! {
+                    let object = <::boa_engine::object::JsObject as #trait_path>::try_from_js(
+                        value, context,
+                    )?;
+                    ::std::result::Result::Ok(Self { #(#fields,)* })
+                }
+            }
+            Shape::Newtype => quote! {
+                ::std::result::Result::Ok(Self(#trait_path::try_from_js(value, context)?))
+            },
+            Shape::Unit => {
+                return Err(Error::new(
+                    input.span(),
+                    "unit structs can't be converted from a value",
+                ))
+            }
+        },
+        Data::Enum(data) => {
+            let options = Options::parse(&input.attrs)?;
+            let tag_key = options
+                .tag
+                .unwrap_or_else(|| LitStr::new("type", Span::call_site()));
+            let arms = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let ident = &variant.ident;
+                    let tag = Options::parse(&variant.attrs)?.name(ident);
+                    let construct = match Shape::new(&variant.fields)? {
+                        Shape::Named(fields) => {
+                            let fields = read_fields(&fields);
+                            quote!(Self::#ident { #(#fields,)* })
+                        }
+                        Shape::Newtype => quote! {
+                            Self::#ident(#trait_path::try_from_js(
+                                &object.get("value", context)?,
+                                context,
+                            )?)
+                        },
+                        Shape::Unit => quote!(Self::#ident),
+                    };
+                    Ok(quote!(#tag => ::std::result::Result::Ok(#construct),))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            quote! {
+                let object = <::boa_engine::object::JsObject as #trait_path>::try_from_js(
+                    value, context,
+                )?;
+                let tag = object.get(#tag_key, context)?;
+                let tag = <::std::string::String as #trait_path>::try_from_js(&tag, context)?;
+                match tag.as_str() {
+                    #(#arms)*
+                    _ => context.throw_type_error(::std::format!(
+                        "unknown variant `{}` of `{}`",
+                        tag,
+                        ::std::stringify!(#name),
+                    )),
+                }
+            }
+        }
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span(),
+                "unions can't be converted from a value",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics #trait_path for #name #ty_generics #where_clause {
+            fn try_from_js(
+                value: &::boa_engine::JsValue,
+                context: &mut ::boa_engine::Context,
+            ) -> ::boa_engine::JsResult<Self> {
+                #body
+            }
+        }
+    })
+}
+
+/// Returns the initializers of named fields read from the properties of `object`.
+fn read_fields(fields: &[NamedField]) -> Vec<TokenStream2> {
+    fields
+        .iter()
+        .map(|field| {
+            let NamedField {
+                ident,
+                key,
+                options,
+            } = field;
+            let span = ident.span();
+            if options.default {
+                quote_spanned! {span=>
+                    #ident: {
+                        let value = object.get(#key, context)?;
+                        if value.is_undefined() {
+                            ::std::default::Default::default()
+                        } else {
+                            ::boa_engine::value::TryFromJs::try_from_js(&value, context)?
+                        }
+                    }
+                }
+            } else {
+                quote_spanned! {span=>
+                    #ident: ::boa_engine::value::TryFromJs::try_from_js(
+                        &object.get(#key, context)?,
+                        context,
+                    )?
+                }
+            }
+        })
+        .collect()
+}
+
+fn try_into_js(input: &DeriveInput) -> Result<TokenStream2> {
+    let name = &input.ident;
+    let trait_path = quote!(::boa_engine::value::TryIntoJs);
+    let generics = add_bounds(&input.generics, &trait_path);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => match Shape::new(&data.fields)? {
+            Shape::Named(fields) => {
+                let writes = write_fields(&fields, |ident| quote!(&self.#ident));
+                quote! {
+                    let object = context.construct_object();
+                    #(#writes)*
+                    ::std::result::Result::Ok(object.into())
+                }
+            }
+            Shape::Newtype => quote!(#trait_path::try_into_js(&self.0, context)),
+            Shape::Unit => {
+                return Err(Error::new(
+                    input.span(),
+                    "unit structs can't be converted to a value",
+                ))
+            }
+        },
+        Data::Enum(data) => {
+            let options = Options::parse(&input.attrs)?;
+            let tag_key = options
+                .tag
+                .unwrap_or_else(|| LitStr::new("type", Span::call_site()));
+            let arms = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let ident = &variant.ident;
+                    let tag = Options::parse(&variant.attrs)?.name(ident);
+                    let (pattern, writes) = match Shape::new(&variant.fields)? {
+                        Shape::Named(fields) => {
+                            let idents = fields.iter().map(|field| &field.ident);
+                            let pattern = quote!(Self::#ident { #(#idents,)* });
+                            (pattern, write_fields(&fields, |ident| quote!(#ident)))
+                        }
+                        Shape::Newtype => (
+                            quote!(Self::#ident(value)),
+                            vec![quote! {
+                                let value = #trait_path::try_into_js(value, context)?;
+                                object.create_data_property_or_throw("value", value, context)?;
+                            }],
+                        ),
+                        Shape::Unit => (quote!(Self::#ident), Vec::new()),
+                    };
+                    Ok(quote! {
+                        #pattern => {
+                            object.create_data_property_or_throw(#tag_key, #tag, context)?;
+                            #(#writes)*
+                        }
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            quote! {
+                let object = context.construct_object();
+                match self {
+                    #(#arms)*
+                }
+                ::std::result::Result::Ok(object.into())
+            }
+        }
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span(),
+                "unions can't be converted to a value",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics #trait_path for #name #ty_generics #where_clause {
+            fn try_into_js(
+                &self,
+                context: &mut ::boa_engine::Context,
+            ) -> ::boa_engine::JsResult<::boa_engine::JsValue> {
+                #body
+            }
+        }
+    })
+}
+
+/// Returns the statements writing named fields to the properties of `object`, where `access`
+/// returns a reference to a field.
+fn write_fields<F>(fields: &[NamedField], access: F) -> Vec<TokenStream2>
+where
+    F: Fn(&Ident) -> TokenStream2,
+{
+    fields
+        .iter()
+        .map(|field| {
+            let NamedField {
+                ident,
+                key,
+                options,
+            } = field;
+            let span = ident.span();
+            let value = access(ident);
+            let write = quote_spanned! {span=>
+                let value = ::boa_engine::value::TryIntoJs::try_into_js(#value, context)?;
+                object.create_data_property_or_throw(#key, value, context)?;
+            };
+            if options.optional {
+                quote_spanned! {span=>
+                    if ::std::option::Option::is_some(#value) {
+                        #write
+                    }
+                }
+            } else {
+                write
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,129 @@
+//! Conversions between the values of the engine and the values of the host.
+
+use boa_engine::{object::FunctionBuilder, Context};
+use boa_gc::{unsafe_empty_trace, Finalize, Trace};
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Copies a value of the engine to the host.
+///
+/// Objects are copied through JSON, and symbols, `BigInt`s and functions cannot be copied.
+pub(crate) fn to_host(
+    value: &boa_engine::JsValue,
+    context: &mut Context,
+) -> Result<JsValue, JsValue> {
+    match value {
+        boa_engine::JsValue::Undefined => Ok(JsValue::UNDEFINED),
+        boa_engine::JsValue::Null => Ok(JsValue::NULL),
+        boa_engine::JsValue::Boolean(boolean) => Ok(JsValue::from_bool(*boolean)),
+        boa_engine::JsValue::Integer(integer) => Ok(JsValue::from_f64(f64::from(*integer))),
+        boa_engine::JsValue::Rational(number) => Ok(JsValue::from_f64(*number)),
+        boa_engine::JsValue::String(string) => Ok(JsValue::from_str(string.as_str())),
+        boa_engine::JsValue::Symbol(_) => {
+            Err(JsValue::from_str("cannot pass a Symbol to the host"))
+        }
+        boa_engine::JsValue::BigInt(_) => {
+            Err(JsValue::from_str("cannot pass a BigInt to the host"))
+        }
+        boa_engine::JsValue::Object(object) if object.is_callable() => {
+            Err(JsValue::from_str("cannot pass a function to the host"))
+        }
+        boa_engine::JsValue::Object(_) => {
+            let json = value
+                .to_json(context)
+                .map_err(|error| thrown_to_host(&error, context))?;
+            js_sys::JSON::parse(&json.to_string())
+        }
+    }
+}
+
+/// Copies a value of the host to the engine.
+///
+/// Functions are wrapped into function objects calling them, and the other objects are copied
+/// through JSON.
+pub(crate) fn from_host(
+    value: &JsValue,
+    context: &mut Context,
+) -> Result<boa_engine::JsValue, JsValue> {
+    if value.is_undefined() {
+        return Ok(boa_engine::JsValue::undefined());
+    }
+    if value.is_null() {
+        return Ok(boa_engine::JsValue::null());
+    }
+    if let Some(boolean) = value.as_bool() {
+        return Ok(boolean.into());
+    }
+    if let Some(number) = value.as_f64() {
+        return Ok(number.into());
+    }
+    if let Some(string) = value.as_string() {
+        return Ok(string.into());
+    }
+    if let Some(function) = value.dyn_ref::<js_sys::Function>() {
+        return Ok(host_function(function.clone(), context));
+    }
+
+    let json = String::from(js_sys::JSON::stringify(value)?);
+    let json =
+        serde_json::from_str(&json).map_err(|error| JsValue::from_str(&error.to_string()))?;
+    boa_engine::JsValue::from_json(&json, context).map_err(|error| thrown_to_host(&error, context))
+}
+
+/// Converts a value thrown by a script to the message thrown to the host.
+pub(crate) fn thrown_to_host(error: &boa_engine::JsValue, context: &mut Context) -> JsValue {
+    let error = boa_engine::JsError::from_value(error.clone()).capture(context);
+    JsValue::from_str(&error.to_string())
+}
+
+/// Creates a function object calling the host function `function`.
+///
+/// The arguments are copied to the host with [`to_host`], and the return value is copied back
+/// with [`from_host`]. When the host function throws, the function throws an `Error` with the
+/// same message.
+pub(crate) fn host_function(
+    function: js_sys::Function,
+    context: &mut Context,
+) -> boa_engine::JsValue {
+    let name = String::from(function.name());
+    let length = function.length() as usize;
+    FunctionBuilder::closure_with_captures(
+        context,
+        |_, args, function: &mut HostFunction, context| {
+            let host_args = js_sys::Array::new();
+            for arg in args {
+                let arg = match to_host(arg, context) {
+                    Ok(arg) => arg,
+                    Err(error) => return context.throw_type_error(host_message(&error)),
+                };
+                host_args.push(&arg);
+            }
+            match function.0.apply(&JsValue::UNDEFINED, &host_args) {
+                Ok(value) => from_host(&value, context)
+                    .or_else(|error| context.throw_type_error(host_message(&error))),
+                Err(error) => context.throw_error(host_message(&error)),
+            }
+        },
+        HostFunction(function),
+    )
+    .name(name)
+    .length(length)
+    .build()
+    .into()
+}
+
+/// Gets the message of an error thrown by the host.
+fn host_message(error: &JsValue) -> String {
+    if let Some(error) = error.dyn_ref::<js_sys::Error>() {
+        return String::from(error.message());
+    }
+    error.as_string().unwrap_or_else(|| format!("{error:?}"))
+}
+
+/// The captures of the function objects created by [`host_function`].
+#[derive(Debug, Finalize)]
+struct HostFunction(js_sys::Function);
+
+// SAFETY: The host function is a handle to a value of the host, and holds no value of the engine.
+unsafe impl Trace for HostFunction {
+    unsafe_empty_trace!();
+}
@@ -1,3 +1,21 @@
+//! The WebAssembly bindings of the Boa JavaScript engine.
+//!
+//! [`evaluate`] runs a script in a fresh context. A [`Boa`] context keeps its state between the
+//! evaluations, and lets the host expose its own functions and values as globals:
+//!
+//! ```js
+//! import { Boa } from "boa_wasm";
+//!
+//! const boa = new Boa();
+//! boa.registerFunction("alert", (message) => window.alert(message));
+//! boa.setGlobal("config", { lang: navigator.language });
+//! boa.evaluate("alert(`Hello from ${config.lang}`)");
+//! ```
+//!
+//! The arguments and return values of host functions are converted between the engines:
+//! primitives are copied, functions are wrapped, and the other objects are copied through JSON.
+//! A host function that throws throws an `Error` with the same message in the script.
+
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/boa-dev/boa/main/assets/logo.svg",
     html_favicon_url = "https://raw.githubusercontent.com/boa-dev/boa/main/assets/logo.svg"
@@ -59,14 +77,81 @@
     rustdoc::missing_doc_code_examples
 )]
 
-use boa_engine::Context;
+mod convert;
+
+use boa_engine::{property::Attribute, value::ValueFormatter, Context};
 use wasm_bindgen::prelude::*;
 
+/// Evaluates `src` in a new context, returning the formatted result.
 #[wasm_bindgen]
 pub fn evaluate(src: &str) -> Result<String, JsValue> {
-    // Setup executor
-    Context::default()
-        .eval(src)
-        .map_err(|e| JsValue::from(format!("Uncaught {}", e.display())))
-        .map(|v| v.display().to_string())
+    Boa::new().evaluate(src)
+}
+
+/// A JavaScript context, keeping the globals defined by the evaluated scripts.
+#[wasm_bindgen]
+#[derive(Debug, Default)]
+pub struct Boa {
+    context: Context,
+}
+
+#[wasm_bindgen]
+impl Boa {
+    /// Creates a new context.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Evaluates `src`, returning the formatted result.
+    ///
+    /// Throws the formatted value thrown by the script, if any.
+    pub fn evaluate(&mut self, src: &str) -> Result<String, JsValue> {
+        let formatter = ValueFormatter::default();
+        match self.context.eval(src) {
+            Ok(value) => Ok(formatter.format(&value, &mut self.context)),
+            Err(error) => Err(JsValue::from(format!(
+                "Uncaught {}",
+                formatter.format(&error, &mut self.context)
+            ))),
+        }
+    }
+
+    /// Defines the global `name` as a function calling the host function `function`.
+    #[wasm_bindgen(js_name = registerFunction)]
+    pub fn register_function(&mut self, name: &str, function: js_sys::Function) {
+        let function = convert::host_function(function, &mut self.context);
+        self.context.register_global_property(
+            name,
+            function,
+            Attribute::WRITABLE | Attribute::CONFIGURABLE,
+        );
+    }
+
+    /// Defines the global `name` holding a copy of the host value `value`.
+    ///
+    /// Throws if the value cannot be copied, like objects that cannot be converted to JSON.
+    #[wasm_bindgen(js_name = setGlobal)]
+    pub fn set_global(&mut self, name: &str, value: &JsValue) -> Result<(), JsValue> {
+        let value = convert::from_host(value, &mut self.context)?;
+        self.context.register_global_property(
+            name,
+            value,
+            Attribute::WRITABLE | Attribute::CONFIGURABLE,
+        );
+        Ok(())
+    }
+
+    /// Gets a copy of the global `name`, `undefined` if it is not defined.
+    ///
+    /// Throws if the value cannot be copied, like symbols and objects that cannot be converted to
+    /// JSON.
+    #[wasm_bindgen(js_name = getGlobal)]
+    pub fn get_global(&mut self, name: &str) -> Result<JsValue, JsValue> {
+        let global = self.context.global_object().clone();
+        let value = global
+            .get(name, &mut self.context)
+            .map_err(|error| convert::thrown_to_host(&error, &mut self.context))?;
+        convert::to_host(&value, &mut self.context)
+    }
 }